@@ -0,0 +1,90 @@
+use itertools::Itertools;
+use malachite_base::num::basic::signeds::PrimitiveSigned;
+use malachite_base::num::basic::unsigneds::PrimitiveUnsigned;
+use malachite_base::test_util::bench::bucketers::{signed_bit_bucketer, unsigned_bit_bucketer};
+use malachite_base::test_util::bench::{run_benchmark, BenchmarkType};
+use malachite_base::test_util::generators::common::{GenConfig, GenMode};
+use malachite_base::test_util::generators::{signed_gen_var_6, unsigned_gen_var_1};
+use malachite_base::test_util::runner::Runner;
+
+pub(crate) fn register(runner: &mut Runner) {
+    register_unsigned_demos!(runner, demo_divisor_count_unsigned);
+    register_signed_demos!(runner, demo_divisor_count_signed);
+    register_unsigned_demos!(runner, demo_divisors);
+
+    register_unsigned_benches!(runner, benchmark_divisor_count_unsigned);
+    register_signed_benches!(runner, benchmark_divisor_count_signed);
+    register_unsigned_benches!(runner, benchmark_divisors);
+}
+
+fn demo_divisor_count_unsigned<T: PrimitiveUnsigned>(gm: GenMode, config: GenConfig, limit: usize) {
+    for n in unsigned_gen_var_1::<T>().get(gm, &config).take(limit) {
+        println!("divisor_count({}) = {}", n, n.divisor_count());
+    }
+}
+
+fn demo_divisor_count_signed<T: PrimitiveSigned>(gm: GenMode, config: GenConfig, limit: usize) {
+    for n in signed_gen_var_6::<T>().get(gm, &config).take(limit) {
+        println!("divisor_count({}) = {}", n, n.divisor_count());
+    }
+}
+
+fn benchmark_divisor_count_unsigned<T: PrimitiveUnsigned>(
+    gm: GenMode,
+    config: GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        &format!("{}.divisor_count()", T::NAME),
+        BenchmarkType::Single,
+        unsigned_gen_var_1::<T>().get(gm, &config),
+        gm.name(),
+        limit,
+        file_name,
+        &unsigned_bit_bucketer(),
+        &mut [("Malachite", &mut |n| no_out!(n.divisor_count()))],
+    );
+}
+
+fn benchmark_divisor_count_signed<T: PrimitiveSigned>(
+    gm: GenMode,
+    config: GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        &format!("{}.divisor_count()", T::NAME),
+        BenchmarkType::Single,
+        signed_gen_var_6::<T>().get(gm, &config),
+        gm.name(),
+        limit,
+        file_name,
+        &signed_bit_bucketer(),
+        &mut [("Malachite", &mut |n| no_out!(n.divisor_count()))],
+    );
+}
+
+fn demo_divisors<T: PrimitiveUnsigned>(gm: GenMode, config: GenConfig, limit: usize) {
+    for n in unsigned_gen_var_1::<T>().get(gm, &config).take(limit) {
+        println!("divisors({}) = {:?}", n, n.divisors().collect_vec());
+    }
+}
+
+fn benchmark_divisors<T: PrimitiveUnsigned>(
+    gm: GenMode,
+    config: GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        &format!("{}.divisors()", T::NAME),
+        BenchmarkType::Single,
+        unsigned_gen_var_1::<T>().get(gm, &config),
+        gm.name(),
+        limit,
+        file_name,
+        &unsigned_bit_bucketer(),
+        &mut [("Malachite", &mut |n| no_out!(n.divisors().collect_vec()))],
+    );
+}
@@ -0,0 +1,171 @@
+use malachite_base::num::basic::unsigneds::PrimitiveUnsigned;
+use malachite_base::test_util::bench::bucketers::{
+    pair_1_bit_bucketer, quadruple_3_bit_bucketer, unsigned_direct_bucketer,
+};
+use malachite_base::test_util::bench::{run_benchmark, BenchmarkType};
+use malachite_base::test_util::generators::common::{GenConfig, GenMode};
+use malachite_base::test_util::generators::{
+    unsigned_gen, unsigned_pair_gen_var_12, unsigned_quadruple_gen_var_6,
+};
+use malachite_base::test_util::runner::Runner;
+
+pub(crate) fn register(runner: &mut Runner) {
+    register_unsigned_demos!(runner, demo_checked_fibonacci);
+    register_unsigned_demos!(runner, demo_checked_lucas);
+    register_unsigned_demos!(runner, demo_mod_fibonacci);
+    register_unsigned_demos!(runner, demo_mod_lucas);
+    register_unsigned_demos!(runner, demo_mod_lucas_sequence);
+
+    register_unsigned_benches!(runner, benchmark_checked_fibonacci);
+    register_unsigned_benches!(runner, benchmark_checked_lucas);
+    register_unsigned_benches!(runner, benchmark_mod_fibonacci);
+    register_unsigned_benches!(runner, benchmark_mod_lucas);
+    register_unsigned_benches!(runner, benchmark_mod_lucas_sequence);
+}
+
+fn demo_checked_fibonacci<T: PrimitiveUnsigned>(gm: GenMode, config: GenConfig, limit: usize) {
+    for n in unsigned_gen().get(gm, &config).take(limit) {
+        println!("F_{} = {:?}", n, T::checked_fibonacci(n));
+    }
+}
+
+fn demo_checked_lucas<T: PrimitiveUnsigned>(gm: GenMode, config: GenConfig, limit: usize) {
+    for n in unsigned_gen().get(gm, &config).take(limit) {
+        println!("L_{} = {:?}", n, T::checked_lucas(n));
+    }
+}
+
+fn demo_mod_fibonacci<T: PrimitiveUnsigned>(gm: GenMode, config: GenConfig, limit: usize) {
+    for (n, m) in unsigned_pair_gen_var_12::<u64, T>()
+        .get(gm, &config)
+        .take(limit)
+    {
+        println!("F_{} ≡ {} mod {}", n, T::mod_fibonacci(n, m), m);
+    }
+}
+
+fn demo_mod_lucas<T: PrimitiveUnsigned>(gm: GenMode, config: GenConfig, limit: usize) {
+    for (n, m) in unsigned_pair_gen_var_12::<u64, T>()
+        .get(gm, &config)
+        .take(limit)
+    {
+        println!("L_{} ≡ {} mod {}", n, T::mod_lucas(n, m), m);
+    }
+}
+
+fn demo_mod_lucas_sequence<T: PrimitiveUnsigned>(gm: GenMode, config: GenConfig, limit: usize) {
+    for (p, q, n, m) in unsigned_quadruple_gen_var_6::<T, u64>()
+        .get(gm, &config)
+        .take(limit)
+    {
+        println!(
+            "(U_{}({}, {}), V_{}({}, {})) ≡ {:?} mod {}",
+            n,
+            p,
+            q,
+            n,
+            p,
+            q,
+            T::mod_lucas_sequence(p, q, n, m),
+            m
+        );
+    }
+}
+
+fn benchmark_checked_fibonacci<T: PrimitiveUnsigned>(
+    gm: GenMode,
+    config: GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        &format!("{}::checked_fibonacci(u64)", T::NAME),
+        BenchmarkType::Single,
+        unsigned_gen().get(gm, &config),
+        gm.name(),
+        limit,
+        file_name,
+        &unsigned_direct_bucketer(),
+        &mut [("Malachite", &mut |n| no_out!(T::checked_fibonacci(n)))],
+    );
+}
+
+fn benchmark_checked_lucas<T: PrimitiveUnsigned>(
+    gm: GenMode,
+    config: GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        &format!("{}::checked_lucas(u64)", T::NAME),
+        BenchmarkType::Single,
+        unsigned_gen().get(gm, &config),
+        gm.name(),
+        limit,
+        file_name,
+        &unsigned_direct_bucketer(),
+        &mut [("Malachite", &mut |n| no_out!(T::checked_lucas(n)))],
+    );
+}
+
+fn benchmark_mod_fibonacci<T: PrimitiveUnsigned>(
+    gm: GenMode,
+    config: GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        &format!("{}::mod_fibonacci(u64, {})", T::NAME, T::NAME),
+        BenchmarkType::Single,
+        unsigned_pair_gen_var_12::<u64, T>().get(gm, &config),
+        gm.name(),
+        limit,
+        file_name,
+        &pair_1_bit_bucketer("n"),
+        &mut [("Malachite", &mut |(n, m)| no_out!(T::mod_fibonacci(n, m)))],
+    );
+}
+
+fn benchmark_mod_lucas<T: PrimitiveUnsigned>(
+    gm: GenMode,
+    config: GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        &format!("{}::mod_lucas(u64, {})", T::NAME, T::NAME),
+        BenchmarkType::Single,
+        unsigned_pair_gen_var_12::<u64, T>().get(gm, &config),
+        gm.name(),
+        limit,
+        file_name,
+        &pair_1_bit_bucketer("n"),
+        &mut [("Malachite", &mut |(n, m)| no_out!(T::mod_lucas(n, m)))],
+    );
+}
+
+fn benchmark_mod_lucas_sequence<T: PrimitiveUnsigned>(
+    gm: GenMode,
+    config: GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        &format!(
+            "{}::mod_lucas_sequence({}, {}, u64, {})",
+            T::NAME,
+            T::NAME,
+            T::NAME,
+            T::NAME
+        ),
+        BenchmarkType::Single,
+        unsigned_quadruple_gen_var_6::<T, u64>().get(gm, &config),
+        gm.name(),
+        limit,
+        file_name,
+        &quadruple_3_bit_bucketer("n"),
+        &mut [("Malachite", &mut |(p, q, n, m)| {
+            no_out!(T::mod_lucas_sequence(p, q, n, m))
+        })],
+    );
+}
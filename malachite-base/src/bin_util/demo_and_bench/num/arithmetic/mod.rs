@@ -6,6 +6,7 @@ pub(crate) fn register(runner: &mut Runner) {
     arithmetic_checked_shl::register(runner);
     arithmetic_checked_shr::register(runner);
     binomial_coefficient::register(runner);
+    catalan::register(runner);
     ceiling::register(runner);
     checked_add_mul::register(runner);
     checked_square::register(runner);
@@ -16,10 +17,12 @@ pub(crate) fn register(runner: &mut Runner) {
     div_round::register(runner);
     divisible_by::register(runner);
     divisible_by_power_of_2::register(runner);
+    divisors::register(runner);
     eq_mod::register(runner);
     eq_mod_power_of_2::register(runner);
     extended_gcd::register(runner);
     factorial::register(runner);
+    fibonacci::register(runner);
     floor::register(runner);
     gcd::register(runner);
     is_power_of_2::register(runner);
@@ -28,8 +31,11 @@ pub(crate) fn register(runner: &mut Runner) {
     log_base::register(runner);
     log_base_2::register(runner);
     log_base_power_of_2::register(runner);
+    mod_binomial_coefficient::register(runner);
+    mod_factorial::register(runner);
     mod_inverse::register(runner);
     mod_is_reduced::register(runner);
+    mod_log::register(runner);
     mod_add::register(runner);
     mod_mul::register(runner);
     mod_neg::register(runner);
@@ -48,8 +54,12 @@ pub(crate) fn register(runner: &mut Runner) {
     mod_power_of_2_sub::register(runner);
     mod_shl::register(runner);
     mod_shr::register(runner);
+    mod_sqrt::register(runner);
     mod_square::register(runner);
     mod_sub::register(runner);
+    moebius::register(runner);
+    multinomial::register(runner);
+    multiplicative_order::register(runner);
     neg::register(runner);
     next_power_of_2::register(runner);
     overflowing_abs::register(runner);
@@ -63,6 +73,8 @@ pub(crate) fn register(runner: &mut Runner) {
     overflowing_sub::register(runner);
     overflowing_sub_mul::register(runner);
     parity::register(runner);
+    partition_count::register(runner);
+    perfect_power::register(runner);
     pow::register(runner);
     power_of_2::register(runner);
     primorial::register(runner);
@@ -84,7 +96,9 @@ pub(crate) fn register(runner: &mut Runner) {
     sign::register(runner);
     sqrt::register(runner);
     square::register(runner);
+    stirling::register(runner);
     sub_mul::register(runner);
+    totient::register(runner);
     wrapping_abs::register(runner);
     wrapping_add::register(runner);
     wrapping_add_mul::register(runner);
@@ -109,6 +123,7 @@ mod add_mul;
 mod arithmetic_checked_shl;
 mod arithmetic_checked_shr;
 mod binomial_coefficient;
+mod catalan;
 mod ceiling;
 mod checked_add_mul;
 mod checked_square;
@@ -119,10 +134,12 @@ mod div_mod;
 mod div_round;
 mod divisible_by;
 mod divisible_by_power_of_2;
+mod divisors;
 mod eq_mod;
 mod eq_mod_power_of_2;
 mod extended_gcd;
 mod factorial;
+mod fibonacci;
 mod floor;
 mod gcd;
 mod is_power_of_2;
@@ -132,8 +149,11 @@ mod log_base;
 mod log_base_2;
 mod log_base_power_of_2;
 mod mod_add;
+mod mod_binomial_coefficient;
+mod mod_factorial;
 mod mod_inverse;
 mod mod_is_reduced;
+mod mod_log;
 mod mod_mul;
 mod mod_neg;
 mod mod_op;
@@ -151,8 +171,12 @@ mod mod_power_of_2_square;
 mod mod_power_of_2_sub;
 mod mod_shl;
 mod mod_shr;
+mod mod_sqrt;
 mod mod_square;
 mod mod_sub;
+mod moebius;
+mod multinomial;
+mod multiplicative_order;
 mod neg;
 mod next_power_of_2;
 mod overflowing_abs;
@@ -166,6 +190,8 @@ mod overflowing_square;
 mod overflowing_sub;
 mod overflowing_sub_mul;
 mod parity;
+mod partition_count;
+mod perfect_power;
 mod pow;
 mod power_of_2;
 mod primorial;
@@ -187,7 +213,9 @@ mod shr_round;
 mod sign;
 mod sqrt;
 mod square;
+mod stirling;
 mod sub_mul;
+mod totient;
 mod wrapping_abs;
 mod wrapping_add;
 mod wrapping_add_mul;
@@ -0,0 +1,40 @@
+use malachite_base::num::basic::unsigneds::PrimitiveUnsigned;
+use malachite_base::test_util::bench::bucketers::triple_3_bit_bucketer;
+use malachite_base::test_util::bench::{run_benchmark, BenchmarkType};
+use malachite_base::test_util::generators::common::{GenConfig, GenMode};
+use malachite_base::test_util::generators::unsigned_triple_gen_var_24;
+use malachite_base::test_util::runner::Runner;
+
+pub(crate) fn register(runner: &mut Runner) {
+    register_unsigned_demos!(runner, demo_mod_factorial);
+    register_unsigned_benches!(runner, benchmark_mod_factorial);
+}
+
+fn demo_mod_factorial<T: PrimitiveUnsigned>(gm: GenMode, config: GenConfig, limit: usize) {
+    for (n, _, m) in unsigned_triple_gen_var_24::<T>()
+        .get(gm, &config)
+        .take(limit)
+    {
+        println!("mod_factorial({}, {}) = {}", n, m, T::mod_factorial(n, m));
+    }
+}
+
+fn benchmark_mod_factorial<T: PrimitiveUnsigned>(
+    gm: GenMode,
+    config: GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        &format!("{}::mod_factorial({}, {})", T::NAME, T::NAME, T::NAME),
+        BenchmarkType::Single,
+        unsigned_triple_gen_var_24::<T>().get(gm, &config),
+        gm.name(),
+        limit,
+        file_name,
+        &triple_3_bit_bucketer("m"),
+        &mut [("Malachite", &mut |(n, _, m)| {
+            no_out!(T::mod_factorial(n, m))
+        })],
+    );
+}
@@ -0,0 +1,103 @@
+use malachite_base::num::arithmetic::mod_sqrt::{mod_sqrt_cipolla, mod_sqrt_tonelli_shanks};
+use malachite_base::num::basic::unsigneds::PrimitiveUnsigned;
+use malachite_base::test_util::bench::bucketers::{pair_1_bit_bucketer, pair_2_bit_bucketer};
+use malachite_base::test_util::bench::{run_benchmark, BenchmarkType};
+use malachite_base::test_util::generators::common::{GenConfig, GenMode};
+use malachite_base::test_util::generators::unsigned_pair_gen_var_27;
+use malachite_base::test_util::runner::Runner;
+
+pub(crate) fn register(runner: &mut Runner) {
+    register_unsigned_demos!(runner, demo_mod_sqrt);
+    register_unsigned_demos!(runner, demo_mod_sqrt_from_factorization);
+    register_unsigned_benches!(runner, benchmark_mod_sqrt_algorithms);
+    register_unsigned_benches!(runner, benchmark_mod_sqrt_from_factorization);
+}
+
+// Turns pairs `(x, n)` into pairs `(x, p)`, where `p` is the largest prime less than `n` and `x` is
+// reduced modulo `p`.
+fn prime_modulus_pairs<T: PrimitiveUnsigned>(
+    xs: impl Iterator<Item = (T, T)>,
+) -> impl Iterator<Item = (T, T)> {
+    xs.filter(|&(_, n)| n > T::TWO).map(|(x, n)| {
+        let p = n.prev_prime();
+        (x % p, p)
+    })
+}
+
+fn demo_mod_sqrt<T: PrimitiveUnsigned>(gm: GenMode, config: GenConfig, limit: usize) {
+    for (x, p) in prime_modulus_pairs(unsigned_pair_gen_var_27::<T>().get(gm, &config)).take(limit)
+    {
+        println!("{}.mod_sqrt({}) = {:?}", x, p, x.mod_sqrt(p));
+    }
+}
+
+fn demo_mod_sqrt_from_factorization<T: PrimitiveUnsigned>(
+    gm: GenMode,
+    config: GenConfig,
+    limit: usize,
+) {
+    for (x, m) in unsigned_pair_gen_var_27::<T>()
+        .get(gm, &config)
+        .filter(|&(_, m)| m != T::ZERO)
+        .take(limit)
+    {
+        let x = x % m;
+        println!(
+            "{}.mod_sqrt_from_factorization({:?}) = {:?}",
+            x,
+            m.factor(),
+            x.mod_sqrt_from_factorization(&m.factor())
+        );
+    }
+}
+
+fn benchmark_mod_sqrt_algorithms<T: PrimitiveUnsigned>(
+    gm: GenMode,
+    config: GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        &format!("{}.mod_sqrt({})", T::NAME, T::NAME),
+        BenchmarkType::Algorithms,
+        prime_modulus_pairs(unsigned_pair_gen_var_27::<T>().get(gm, &config)),
+        gm.name(),
+        limit,
+        file_name,
+        &pair_2_bit_bucketer("p"),
+        &mut [
+            ("default", &mut |(x, p)| no_out!(x.mod_sqrt(p))),
+            ("Tonelli-Shanks", &mut |(x, p)| {
+                no_out!(mod_sqrt_tonelli_shanks(x, p))
+            }),
+            ("Cipolla", &mut |(x, p)| no_out!(mod_sqrt_cipolla(x, p))),
+        ],
+    );
+}
+
+fn benchmark_mod_sqrt_from_factorization<T: PrimitiveUnsigned>(
+    gm: GenMode,
+    config: GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        &format!(
+            "{}.mod_sqrt_from_factorization(&[({}, u64)])",
+            T::NAME,
+            T::NAME
+        ),
+        BenchmarkType::Single,
+        unsigned_pair_gen_var_27::<T>()
+            .get(gm, &config)
+            .filter(|&(_, m)| m != T::ZERO)
+            .map(|(x, m)| (x % m, m.factor())),
+        gm.name(),
+        limit,
+        file_name,
+        &pair_1_bit_bucketer("x"),
+        &mut [("Malachite", &mut |(x, factors)| {
+            no_out!(x.mod_sqrt_from_factorization(&factors))
+        })],
+    );
+}
@@ -0,0 +1,63 @@
+use malachite_base::num::basic::signeds::PrimitiveSigned;
+use malachite_base::num::basic::unsigneds::PrimitiveUnsigned;
+use malachite_base::test_util::bench::bucketers::{signed_bit_bucketer, unsigned_bit_bucketer};
+use malachite_base::test_util::bench::{run_benchmark, BenchmarkType};
+use malachite_base::test_util::generators::common::{GenConfig, GenMode};
+use malachite_base::test_util::generators::{signed_gen_var_6, unsigned_gen_var_1};
+use malachite_base::test_util::runner::Runner;
+
+pub(crate) fn register(runner: &mut Runner) {
+    register_unsigned_demos!(runner, demo_moebius_unsigned);
+    register_signed_demos!(runner, demo_moebius_signed);
+
+    register_unsigned_benches!(runner, benchmark_moebius_unsigned);
+    register_signed_benches!(runner, benchmark_moebius_signed);
+}
+
+fn demo_moebius_unsigned<T: PrimitiveUnsigned>(gm: GenMode, config: GenConfig, limit: usize) {
+    for n in unsigned_gen_var_1::<T>().get(gm, &config).take(limit) {
+        println!("μ({}) = {}", n, n.moebius());
+    }
+}
+
+fn demo_moebius_signed<T: PrimitiveSigned>(gm: GenMode, config: GenConfig, limit: usize) {
+    for n in signed_gen_var_6::<T>().get(gm, &config).take(limit) {
+        println!("μ({}) = {}", n, n.moebius());
+    }
+}
+
+fn benchmark_moebius_unsigned<T: PrimitiveUnsigned>(
+    gm: GenMode,
+    config: GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        &format!("{}.moebius()", T::NAME),
+        BenchmarkType::Single,
+        unsigned_gen_var_1::<T>().get(gm, &config),
+        gm.name(),
+        limit,
+        file_name,
+        &unsigned_bit_bucketer(),
+        &mut [("Malachite", &mut |n| no_out!(n.moebius()))],
+    );
+}
+
+fn benchmark_moebius_signed<T: PrimitiveSigned>(
+    gm: GenMode,
+    config: GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        &format!("{}.moebius()", T::NAME),
+        BenchmarkType::Single,
+        signed_gen_var_6::<T>().get(gm, &config),
+        gm.name(),
+        limit,
+        file_name,
+        &signed_bit_bucketer(),
+        &mut [("Malachite", &mut |n| no_out!(n.moebius()))],
+    );
+}
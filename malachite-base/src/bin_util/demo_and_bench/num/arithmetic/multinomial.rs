@@ -0,0 +1,39 @@
+use malachite_base::num::basic::unsigneds::PrimitiveUnsigned;
+use malachite_base::test_util::bench::bucketers::vec_len_bucketer;
+use malachite_base::test_util::bench::{run_benchmark, BenchmarkType};
+use malachite_base::test_util::generators::common::{GenConfig, GenMode};
+use malachite_base::test_util::generators::unsigned_vec_gen_var_7;
+use malachite_base::test_util::runner::Runner;
+
+pub(crate) fn register(runner: &mut Runner) {
+    register_unsigned_demos!(runner, demo_checked_multinomial);
+    register_unsigned_benches!(runner, benchmark_checked_multinomial);
+}
+
+fn demo_checked_multinomial<T: PrimitiveUnsigned>(gm: GenMode, config: GenConfig, limit: usize) {
+    for ks in unsigned_vec_gen_var_7().get(gm, &config).take(limit) {
+        println!(
+            "checked_multinomial({:?}) = {:?}",
+            ks,
+            T::checked_multinomial(&ks)
+        );
+    }
+}
+
+fn benchmark_checked_multinomial<T: PrimitiveUnsigned>(
+    gm: GenMode,
+    config: GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        &format!("{}::checked_multinomial(&[u64])", T::NAME),
+        BenchmarkType::Single,
+        unsigned_vec_gen_var_7().get(gm, &config),
+        gm.name(),
+        limit,
+        file_name,
+        &vec_len_bucketer(),
+        &mut [("Malachite", &mut |ks| no_out!(T::checked_multinomial(&ks)))],
+    );
+}
@@ -0,0 +1,71 @@
+use malachite_base::num::basic::unsigneds::PrimitiveUnsigned;
+use malachite_base::test_util::bench::bucketers::{pair_2_bit_bucketer, unsigned_bit_bucketer};
+use malachite_base::test_util::bench::{run_benchmark, BenchmarkType};
+use malachite_base::test_util::generators::common::{GenConfig, GenMode};
+use malachite_base::test_util::generators::{unsigned_gen_var_1, unsigned_pair_gen_var_38};
+use malachite_base::test_util::runner::Runner;
+
+pub(crate) fn register(runner: &mut Runner) {
+    register_unsigned_demos!(runner, demo_multiplicative_order);
+    register_unsigned_demos!(runner, demo_primitive_root);
+
+    register_unsigned_benches!(runner, benchmark_multiplicative_order);
+    register_unsigned_benches!(runner, benchmark_primitive_root);
+}
+
+fn demo_multiplicative_order<T: PrimitiveUnsigned>(gm: GenMode, config: GenConfig, limit: usize) {
+    for (x, m) in unsigned_pair_gen_var_38::<T>().get(gm, &config).take(limit) {
+        match x.multiplicative_order(m) {
+            Some(order) => println!("{} has order {} mod {}", x, order, m),
+            None => println!("{} is not invertible mod {}", x, m),
+        }
+    }
+}
+
+fn demo_primitive_root<T: PrimitiveUnsigned>(gm: GenMode, config: GenConfig, limit: usize) {
+    for m in unsigned_gen_var_1::<T>().get(gm, &config).take(limit) {
+        match m.primitive_root() {
+            Some(root) => println!("The smallest primitive root mod {} is {}", m, root),
+            None => println!("There is no primitive root mod {}", m),
+        }
+    }
+}
+
+fn benchmark_multiplicative_order<T: PrimitiveUnsigned>(
+    gm: GenMode,
+    config: GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        &format!("{}.multiplicative_order({})", T::NAME, T::NAME),
+        BenchmarkType::Single,
+        unsigned_pair_gen_var_38::<T>().get(gm, &config),
+        gm.name(),
+        limit,
+        file_name,
+        &pair_2_bit_bucketer("m"),
+        &mut [(
+            "Malachite",
+            &mut |(x, m)| no_out!(x.multiplicative_order(m)),
+        )],
+    );
+}
+
+fn benchmark_primitive_root<T: PrimitiveUnsigned>(
+    gm: GenMode,
+    config: GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        &format!("{}.primitive_root()", T::NAME),
+        BenchmarkType::Single,
+        unsigned_gen_var_1::<T>().get(gm, &config),
+        gm.name(),
+        limit,
+        file_name,
+        &unsigned_bit_bucketer(),
+        &mut [("Malachite", &mut |m| no_out!(m.primitive_root()))],
+    );
+}
@@ -0,0 +1,49 @@
+use malachite_base::num::basic::unsigneds::PrimitiveUnsigned;
+use malachite_base::test_util::bench::bucketers::unsigned_direct_bucketer;
+use malachite_base::test_util::bench::{run_benchmark, BenchmarkType};
+use malachite_base::test_util::generators::common::{GenConfig, GenMode};
+use malachite_base::test_util::generators::unsigned_gen_var_5;
+use malachite_base::test_util::num::arithmetic::combinatorics::checked_partition_count_naive;
+use malachite_base::test_util::runner::Runner;
+
+pub(crate) fn register(runner: &mut Runner) {
+    register_unsigned_demos!(runner, demo_checked_partition_count);
+    register_unsigned_benches!(runner, benchmark_checked_partition_count_algorithms);
+}
+
+fn demo_checked_partition_count<T: PrimitiveUnsigned>(
+    gm: GenMode,
+    config: GenConfig,
+    limit: usize,
+) {
+    for n in unsigned_gen_var_5().get(gm, &config).take(limit) {
+        println!(
+            "checked_partition_count({}) = {:?}",
+            n,
+            T::checked_partition_count(n)
+        );
+    }
+}
+
+fn benchmark_checked_partition_count_algorithms<T: PrimitiveUnsigned>(
+    gm: GenMode,
+    config: GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        &format!("{}::checked_partition_count(u64)", T::NAME),
+        BenchmarkType::Algorithms,
+        unsigned_gen_var_5().get(gm, &config),
+        gm.name(),
+        limit,
+        file_name,
+        &unsigned_direct_bucketer(),
+        &mut [
+            ("default", &mut |n| no_out!(T::checked_partition_count(n))),
+            ("naive", &mut |n| {
+                no_out!(checked_partition_count_naive::<T>(n))
+            }),
+        ],
+    );
+}
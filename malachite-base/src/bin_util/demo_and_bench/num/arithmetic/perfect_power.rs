@@ -0,0 +1,151 @@
+use malachite_base::num::arithmetic::perfect_power::perfect_power_decomposition_unsigned;
+use malachite_base::num::basic::signeds::PrimitiveSigned;
+use malachite_base::num::basic::unsigneds::PrimitiveUnsigned;
+use malachite_base::test_util::bench::bucketers::{signed_bit_bucketer, unsigned_bit_bucketer};
+use malachite_base::test_util::bench::{run_benchmark, BenchmarkType};
+use malachite_base::test_util::generators::common::{GenConfig, GenMode};
+use malachite_base::test_util::generators::{signed_gen, unsigned_gen};
+use malachite_base::test_util::num::arithmetic::perfect_power::perfect_power_decomposition_naive;
+use malachite_base::test_util::runner::Runner;
+
+pub(crate) fn register(runner: &mut Runner) {
+    register_unsigned_demos!(runner, demo_is_perfect_power_unsigned);
+    register_signed_demos!(runner, demo_is_perfect_power_signed);
+    register_unsigned_demos!(runner, demo_perfect_power_decomposition_unsigned);
+    register_signed_demos!(runner, demo_perfect_power_decomposition_signed);
+
+    register_unsigned_benches!(runner, benchmark_is_perfect_power_unsigned);
+    register_signed_benches!(runner, benchmark_is_perfect_power_signed);
+    register_unsigned_benches!(
+        runner,
+        benchmark_perfect_power_decomposition_algorithms_unsigned
+    );
+    register_signed_benches!(runner, benchmark_perfect_power_decomposition_signed);
+}
+
+fn demo_is_perfect_power_unsigned<T: PrimitiveUnsigned>(
+    gm: GenMode,
+    config: GenConfig,
+    limit: usize,
+) {
+    for n in unsigned_gen::<T>().get(gm, &config).take(limit) {
+        if n.is_perfect_power() {
+            println!("{} is a perfect power", n);
+        } else {
+            println!("{} is not a perfect power", n);
+        }
+    }
+}
+
+fn demo_is_perfect_power_signed<T: PrimitiveSigned>(gm: GenMode, config: GenConfig, limit: usize) {
+    for n in signed_gen::<T>().get(gm, &config).take(limit) {
+        if n.is_perfect_power() {
+            println!("{} is a perfect power", n);
+        } else {
+            println!("{} is not a perfect power", n);
+        }
+    }
+}
+
+fn demo_perfect_power_decomposition_unsigned<T: PrimitiveUnsigned>(
+    gm: GenMode,
+    config: GenConfig,
+    limit: usize,
+) {
+    for n in unsigned_gen::<T>().get(gm, &config).take(limit) {
+        let (base, exponent) = n.perfect_power_decomposition();
+        println!("{} = {}^{}", n, base, exponent);
+    }
+}
+
+fn demo_perfect_power_decomposition_signed<T: PrimitiveSigned>(
+    gm: GenMode,
+    config: GenConfig,
+    limit: usize,
+) {
+    for n in signed_gen::<T>().get(gm, &config).take(limit) {
+        let (base, exponent) = n.perfect_power_decomposition();
+        println!("{} = ({})^{}", n, base, exponent);
+    }
+}
+
+fn benchmark_is_perfect_power_unsigned<T: PrimitiveUnsigned>(
+    gm: GenMode,
+    config: GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        &format!("{}.is_perfect_power()", T::NAME),
+        BenchmarkType::Single,
+        unsigned_gen::<T>().get(gm, &config),
+        gm.name(),
+        limit,
+        file_name,
+        &unsigned_bit_bucketer(),
+        &mut [("Malachite", &mut |n| no_out!(n.is_perfect_power()))],
+    );
+}
+
+fn benchmark_is_perfect_power_signed<T: PrimitiveSigned>(
+    gm: GenMode,
+    config: GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        &format!("{}.is_perfect_power()", T::NAME),
+        BenchmarkType::Single,
+        signed_gen::<T>().get(gm, &config),
+        gm.name(),
+        limit,
+        file_name,
+        &signed_bit_bucketer(),
+        &mut [("Malachite", &mut |n| no_out!(n.is_perfect_power()))],
+    );
+}
+
+fn benchmark_perfect_power_decomposition_algorithms_unsigned<T: PrimitiveUnsigned>(
+    gm: GenMode,
+    config: GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        &format!("{}.perfect_power_decomposition()", T::NAME),
+        BenchmarkType::Algorithms,
+        unsigned_gen::<T>().get(gm, &config),
+        gm.name(),
+        limit,
+        file_name,
+        &unsigned_bit_bucketer(),
+        &mut [
+            ("default", &mut |n| {
+                no_out!(perfect_power_decomposition_unsigned(n))
+            }),
+            ("naive", &mut |n| {
+                no_out!(perfect_power_decomposition_naive(n))
+            }),
+        ],
+    );
+}
+
+fn benchmark_perfect_power_decomposition_signed<T: PrimitiveSigned>(
+    gm: GenMode,
+    config: GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        &format!("{}.perfect_power_decomposition()", T::NAME),
+        BenchmarkType::Single,
+        signed_gen::<T>().get(gm, &config),
+        gm.name(),
+        limit,
+        file_name,
+        &signed_bit_bucketer(),
+        &mut [("Malachite", &mut |n| {
+            no_out!(n.perfect_power_decomposition())
+        })],
+    );
+}
@@ -0,0 +1,130 @@
+use malachite_base::num::basic::unsigneds::PrimitiveUnsigned;
+use malachite_base::test_util::bench::bucketers::{
+    unsigned_direct_bucketer, usize_convertible_pair_max_bucketer,
+};
+use malachite_base::test_util::bench::{run_benchmark, BenchmarkType};
+use malachite_base::test_util::generators::common::{GenConfig, GenMode};
+use malachite_base::test_util::generators::{unsigned_gen_var_5, unsigned_pair_gen_var_28};
+use malachite_base::test_util::num::arithmetic::combinatorics::{
+    checked_bell_naive, checked_stirling_first_naive, checked_stirling_second_naive,
+};
+use malachite_base::test_util::runner::Runner;
+
+pub(crate) fn register(runner: &mut Runner) {
+    register_unsigned_demos!(runner, demo_checked_stirling_first);
+    register_unsigned_demos!(runner, demo_checked_stirling_second);
+    register_unsigned_demos!(runner, demo_checked_bell);
+
+    register_unsigned_benches!(runner, benchmark_checked_stirling_first_algorithms);
+    register_unsigned_benches!(runner, benchmark_checked_stirling_second_algorithms);
+    register_unsigned_benches!(runner, benchmark_checked_bell_algorithms);
+}
+
+fn demo_checked_stirling_first<T: PrimitiveUnsigned>(gm: GenMode, config: GenConfig, limit: usize) {
+    for (n, k) in unsigned_pair_gen_var_28::<u64, u64>()
+        .get(gm, &config)
+        .take(limit)
+    {
+        println!(
+            "checked_stirling_first({}, {}) = {:?}",
+            n,
+            k,
+            T::checked_stirling_first(n, k)
+        );
+    }
+}
+
+fn demo_checked_stirling_second<T: PrimitiveUnsigned>(
+    gm: GenMode,
+    config: GenConfig,
+    limit: usize,
+) {
+    for (n, k) in unsigned_pair_gen_var_28::<u64, u64>()
+        .get(gm, &config)
+        .take(limit)
+    {
+        println!(
+            "checked_stirling_second({}, {}) = {:?}",
+            n,
+            k,
+            T::checked_stirling_second(n, k)
+        );
+    }
+}
+
+fn demo_checked_bell<T: PrimitiveUnsigned>(gm: GenMode, config: GenConfig, limit: usize) {
+    for n in unsigned_gen_var_5().get(gm, &config).take(limit) {
+        println!("checked_bell({}) = {:?}", n, T::checked_bell(n));
+    }
+}
+
+fn benchmark_checked_stirling_first_algorithms<T: PrimitiveUnsigned>(
+    gm: GenMode,
+    config: GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        &format!("{}::checked_stirling_first(u64, u64)", T::NAME),
+        BenchmarkType::Algorithms,
+        unsigned_pair_gen_var_28::<u64, u64>().get(gm, &config),
+        gm.name(),
+        limit,
+        file_name,
+        &usize_convertible_pair_max_bucketer("n", "k"),
+        &mut [
+            ("default", &mut |(n, k)| {
+                no_out!(T::checked_stirling_first(n, k))
+            }),
+            ("naive", &mut |(n, k)| {
+                no_out!(checked_stirling_first_naive::<T>(n, k))
+            }),
+        ],
+    );
+}
+
+fn benchmark_checked_stirling_second_algorithms<T: PrimitiveUnsigned>(
+    gm: GenMode,
+    config: GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        &format!("{}::checked_stirling_second(u64, u64)", T::NAME),
+        BenchmarkType::Algorithms,
+        unsigned_pair_gen_var_28::<u64, u64>().get(gm, &config),
+        gm.name(),
+        limit,
+        file_name,
+        &usize_convertible_pair_max_bucketer("n", "k"),
+        &mut [
+            ("default", &mut |(n, k)| {
+                no_out!(T::checked_stirling_second(n, k))
+            }),
+            ("naive", &mut |(n, k)| {
+                no_out!(checked_stirling_second_naive::<T>(n, k))
+            }),
+        ],
+    );
+}
+
+fn benchmark_checked_bell_algorithms<T: PrimitiveUnsigned>(
+    gm: GenMode,
+    config: GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        &format!("{}::checked_bell(u64)", T::NAME),
+        BenchmarkType::Algorithms,
+        unsigned_gen_var_5().get(gm, &config),
+        gm.name(),
+        limit,
+        file_name,
+        &unsigned_direct_bucketer(),
+        &mut [
+            ("default", &mut |n| no_out!(T::checked_bell(n))),
+            ("naive", &mut |n| no_out!(checked_bell_naive::<T>(n))),
+        ],
+    );
+}
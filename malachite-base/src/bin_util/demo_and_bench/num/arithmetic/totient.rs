@@ -0,0 +1,119 @@
+use malachite_base::num::basic::signeds::PrimitiveSigned;
+use malachite_base::num::basic::unsigneds::PrimitiveUnsigned;
+use malachite_base::test_util::bench::bucketers::{signed_bit_bucketer, unsigned_bit_bucketer};
+use malachite_base::test_util::bench::{run_benchmark, BenchmarkType};
+use malachite_base::test_util::generators::common::{GenConfig, GenMode};
+use malachite_base::test_util::generators::{signed_gen_var_6, unsigned_gen_var_1};
+use malachite_base::test_util::runner::Runner;
+
+pub(crate) fn register(runner: &mut Runner) {
+    register_unsigned_demos!(runner, demo_totient_unsigned);
+    register_signed_demos!(runner, demo_totient_signed);
+    register_unsigned_demos!(runner, demo_carmichael_lambda_unsigned);
+    register_signed_demos!(runner, demo_carmichael_lambda_signed);
+
+    register_unsigned_benches!(runner, benchmark_totient_unsigned);
+    register_signed_benches!(runner, benchmark_totient_signed);
+    register_unsigned_benches!(runner, benchmark_carmichael_lambda_unsigned);
+    register_signed_benches!(runner, benchmark_carmichael_lambda_signed);
+}
+
+fn demo_totient_unsigned<T: PrimitiveUnsigned>(gm: GenMode, config: GenConfig, limit: usize) {
+    for n in unsigned_gen_var_1::<T>().get(gm, &config).take(limit) {
+        println!("φ({}) = {}", n, n.totient());
+    }
+}
+
+fn demo_totient_signed<T: PrimitiveSigned>(gm: GenMode, config: GenConfig, limit: usize) {
+    for n in signed_gen_var_6::<T>().get(gm, &config).take(limit) {
+        println!("φ({}) = {}", n, n.totient());
+    }
+}
+
+fn demo_carmichael_lambda_unsigned<T: PrimitiveUnsigned>(
+    gm: GenMode,
+    config: GenConfig,
+    limit: usize,
+) {
+    for n in unsigned_gen_var_1::<T>().get(gm, &config).take(limit) {
+        println!("λ({}) = {}", n, n.carmichael_lambda());
+    }
+}
+
+fn demo_carmichael_lambda_signed<T: PrimitiveSigned>(gm: GenMode, config: GenConfig, limit: usize) {
+    for n in signed_gen_var_6::<T>().get(gm, &config).take(limit) {
+        println!("λ({}) = {}", n, n.carmichael_lambda());
+    }
+}
+
+fn benchmark_totient_unsigned<T: PrimitiveUnsigned>(
+    gm: GenMode,
+    config: GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        &format!("{}.totient()", T::NAME),
+        BenchmarkType::Single,
+        unsigned_gen_var_1::<T>().get(gm, &config),
+        gm.name(),
+        limit,
+        file_name,
+        &unsigned_bit_bucketer(),
+        &mut [("Malachite", &mut |n| no_out!(n.totient()))],
+    );
+}
+
+fn benchmark_totient_signed<T: PrimitiveSigned>(
+    gm: GenMode,
+    config: GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        &format!("{}.totient()", T::NAME),
+        BenchmarkType::Single,
+        signed_gen_var_6::<T>().get(gm, &config),
+        gm.name(),
+        limit,
+        file_name,
+        &signed_bit_bucketer(),
+        &mut [("Malachite", &mut |n| no_out!(n.totient()))],
+    );
+}
+
+fn benchmark_carmichael_lambda_unsigned<T: PrimitiveUnsigned>(
+    gm: GenMode,
+    config: GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        &format!("{}.carmichael_lambda()", T::NAME),
+        BenchmarkType::Single,
+        unsigned_gen_var_1::<T>().get(gm, &config),
+        gm.name(),
+        limit,
+        file_name,
+        &unsigned_bit_bucketer(),
+        &mut [("Malachite", &mut |n| no_out!(n.carmichael_lambda()))],
+    );
+}
+
+fn benchmark_carmichael_lambda_signed<T: PrimitiveSigned>(
+    gm: GenMode,
+    config: GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        &format!("{}.carmichael_lambda()", T::NAME),
+        BenchmarkType::Single,
+        signed_gen_var_6::<T>().get(gm, &config),
+        gm.name(),
+        limit,
+        file_name,
+        &signed_bit_bucketer(),
+        &mut [("Malachite", &mut |n| no_out!(n.carmichael_lambda()))],
+    );
+}
@@ -1,9 +1,19 @@
 use malachite_base::test_util::runner::Runner;
 
 pub(crate) fn register(runner: &mut Runner) {
+    factor::register(runner);
+    is_prime::register(runner);
+    next_prime::register(runner);
+    prime_count::register(runner);
     primes::register(runner);
+    primes_in_range::register(runner);
     prime_sieve::register(runner);
 }
 
+mod factor;
+mod is_prime;
+mod next_prime;
+mod prime_count;
 mod prime_sieve;
 mod primes;
+mod primes_in_range;
@@ -0,0 +1,73 @@
+use malachite_base::num::basic::unsigneds::PrimitiveUnsigned;
+use malachite_base::test_util::bench::bucketers::unsigned_bit_bucketer;
+use malachite_base::test_util::bench::{run_benchmark, BenchmarkType};
+use malachite_base::test_util::generators::common::{GenConfig, GenMode};
+use malachite_base::test_util::generators::unsigned_gen;
+use malachite_base::test_util::runner::Runner;
+
+pub(crate) fn register(runner: &mut Runner) {
+    register_unsigned_demos!(runner, demo_next_prime);
+    register_unsigned_demos!(runner, demo_prev_prime);
+    register_unsigned_benches!(runner, benchmark_next_prime);
+    register_unsigned_benches!(runner, benchmark_prev_prime);
+}
+
+fn demo_next_prime<T: PrimitiveUnsigned>(gm: GenMode, config: GenConfig, limit: usize) {
+    let largest_prime = T::MAX.prev_prime();
+    for n in unsigned_gen::<T>()
+        .get(gm, &config)
+        .filter(|&n| n < largest_prime)
+        .take(limit)
+    {
+        println!("{}.next_prime() = {}", n, n.next_prime());
+    }
+}
+
+fn demo_prev_prime<T: PrimitiveUnsigned>(gm: GenMode, config: GenConfig, limit: usize) {
+    for n in unsigned_gen::<T>()
+        .get(gm, &config)
+        .filter(|&n| n > T::TWO)
+        .take(limit)
+    {
+        println!("{}.prev_prime() = {}", n, n.prev_prime());
+    }
+}
+
+fn benchmark_next_prime<T: PrimitiveUnsigned>(
+    gm: GenMode,
+    config: GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    let largest_prime = T::MAX.prev_prime();
+    run_benchmark(
+        &format!("{}.next_prime()", T::NAME),
+        BenchmarkType::Single,
+        unsigned_gen::<T>()
+            .get(gm, &config)
+            .filter(|&n| n < largest_prime),
+        gm.name(),
+        limit,
+        file_name,
+        &unsigned_bit_bucketer(),
+        &mut [("Malachite", &mut |n| no_out!(n.next_prime()))],
+    );
+}
+
+fn benchmark_prev_prime<T: PrimitiveUnsigned>(
+    gm: GenMode,
+    config: GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        &format!("{}.prev_prime()", T::NAME),
+        BenchmarkType::Single,
+        unsigned_gen::<T>().get(gm, &config).filter(|&n| n > T::TWO),
+        gm.name(),
+        limit,
+        file_name,
+        &unsigned_bit_bucketer(),
+        &mut [("Malachite", &mut |n| no_out!(n.prev_prime()))],
+    );
+}
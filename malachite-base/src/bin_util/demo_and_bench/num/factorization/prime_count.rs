@@ -0,0 +1,64 @@
+use malachite_base::num::basic::unsigneds::PrimitiveUnsigned;
+use malachite_base::num::factorization::traits::NthPrime;
+use malachite_base::test_util::bench::bucketers::{
+    unsigned_bit_bucketer, unsigned_direct_bucketer,
+};
+use malachite_base::test_util::bench::{run_benchmark, BenchmarkType};
+use malachite_base::test_util::generators::common::{GenConfig, GenMode};
+use malachite_base::test_util::generators::{unsigned_gen_var_26, unsigned_gen_var_5};
+use malachite_base::test_util::runner::Runner;
+
+pub(crate) fn register(runner: &mut Runner) {
+    register_unsigned_demos!(runner, demo_prime_count);
+    register_demo!(runner, demo_u64_nth_prime);
+    register_unsigned_benches!(runner, benchmark_prime_count_algorithms);
+    register_bench!(runner, benchmark_u64_nth_prime);
+}
+
+fn demo_prime_count<T: PrimitiveUnsigned>(gm: GenMode, config: GenConfig, limit: usize) {
+    for n in unsigned_gen_var_5::<T>().get(gm, &config).take(limit) {
+        println!("{}.prime_count() = {}", n, n.prime_count());
+    }
+}
+
+fn demo_u64_nth_prime(gm: GenMode, config: GenConfig, limit: usize) {
+    for n in unsigned_gen_var_26::<u64>().get(gm, &config).take(limit) {
+        println!("u64::nth_prime({}) = {}", n, u64::nth_prime(n));
+    }
+}
+
+fn benchmark_prime_count_algorithms<T: PrimitiveUnsigned>(
+    gm: GenMode,
+    config: GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        &format!("{}.prime_count()", T::NAME),
+        BenchmarkType::Algorithms,
+        unsigned_gen_var_5::<T>().get(gm, &config),
+        gm.name(),
+        limit,
+        file_name,
+        &unsigned_bit_bucketer(),
+        &mut [
+            ("default", &mut |n| no_out!(n.prime_count())),
+            ("using primes", &mut |n| {
+                no_out!(T::primes_less_than_or_equal_to(&n).count())
+            }),
+        ],
+    );
+}
+
+fn benchmark_u64_nth_prime(gm: GenMode, config: GenConfig, limit: usize, file_name: &str) {
+    run_benchmark(
+        "u64::nth_prime(u64)",
+        BenchmarkType::Single,
+        unsigned_gen_var_26::<u64>().get(gm, &config),
+        gm.name(),
+        limit,
+        file_name,
+        &unsigned_direct_bucketer(),
+        &mut [("Malachite", &mut |n| no_out!(u64::nth_prime(n)))],
+    );
+}
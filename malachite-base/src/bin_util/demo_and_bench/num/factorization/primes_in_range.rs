@@ -0,0 +1,90 @@
+use itertools::Itertools;
+use malachite_base::num::basic::unsigneds::PrimitiveUnsigned;
+use malachite_base::test_util::bench::bucketers::{pair_max_bit_bucketer, unsigned_bit_bucketer};
+use malachite_base::test_util::bench::{run_benchmark, BenchmarkType};
+use malachite_base::test_util::generators::common::{GenConfig, GenMode};
+use malachite_base::test_util::generators::{unsigned_gen, unsigned_pair_gen_var_27};
+use malachite_base::test_util::runner::Runner;
+
+pub(crate) fn register(runner: &mut Runner) {
+    register_unsigned_demos!(runner, demo_primes_in_range);
+    register_unsigned_demos!(runner, demo_primes_greater_than_or_equal_to);
+    register_unsigned_benches!(runner, benchmark_primes_in_range);
+    register_unsigned_benches!(runner, benchmark_primes_greater_than_or_equal_to);
+}
+
+fn demo_primes_in_range<T: PrimitiveUnsigned>(gm: GenMode, config: GenConfig, limit: usize) {
+    for (a, b) in unsigned_pair_gen_var_27::<T>().get(gm, &config).take(limit) {
+        println!(
+            "primes_in_range({}, {}) = {:?}",
+            a,
+            b,
+            T::primes_in_range(&a, &b).take(10).collect_vec()
+        );
+    }
+}
+
+fn demo_primes_greater_than_or_equal_to<T: PrimitiveUnsigned>(
+    gm: GenMode,
+    config: GenConfig,
+    limit: usize,
+) {
+    for a in unsigned_gen::<T>().get(gm, &config).take(limit) {
+        println!(
+            "primes_greater_than_or_equal_to({}) = {:?}",
+            a,
+            T::primes_greater_than_or_equal_to(&a)
+                .take(10)
+                .collect_vec()
+        );
+    }
+}
+
+fn benchmark_primes_in_range<T: PrimitiveUnsigned>(
+    gm: GenMode,
+    config: GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        &format!(
+            "{}::primes_in_range(&{}, &{}).take(10)",
+            T::NAME,
+            T::NAME,
+            T::NAME
+        ),
+        BenchmarkType::Single,
+        unsigned_pair_gen_var_27::<T>().get(gm, &config),
+        gm.name(),
+        limit,
+        file_name,
+        &pair_max_bit_bucketer("a", "b"),
+        &mut [("Malachite", &mut |(a, b)| {
+            no_out!(T::primes_in_range(&a, &b).take(10).count())
+        })],
+    );
+}
+
+fn benchmark_primes_greater_than_or_equal_to<T: PrimitiveUnsigned>(
+    gm: GenMode,
+    config: GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        &format!(
+            "{}::primes_greater_than_or_equal_to(&{}).take(10)",
+            T::NAME,
+            T::NAME
+        ),
+        BenchmarkType::Single,
+        unsigned_gen::<T>().get(gm, &config),
+        gm.name(),
+        limit,
+        file_name,
+        &unsigned_bit_bucketer(),
+        &mut [("Malachite", &mut |a| {
+            no_out!(T::primes_greater_than_or_equal_to(&a).take(10).count())
+        })],
+    );
+}
@@ -0,0 +1,80 @@
+use crate::num::arithmetic::traits::{Catalan, CheckedCatalan};
+use crate::num::basic::unsigneds::PrimitiveUnsigned;
+
+// Uses $C_{i+1} = 2(2i + 1)C_i/(i + 2)$. Dividing $C_i$ and $i + 2$ by their GCD first ensures that
+// no intermediate result exceeds $C_{i+1}$; the reduced $i + 2$ must divide $2(2i + 1)$, since it
+// divides $2(2i + 1)C_i$ and is coprime to the reduced $C_i$.
+fn checked_catalan<T: PrimitiveUnsigned>(n: u64) -> Option<T> {
+    let mut c = T::ONE;
+    for i in 0..n {
+        // These are much smaller than $C_{i+1}$, so they fit if it does.
+        let numerator = T::try_from((i << 2) + 2).ok()?;
+        let denominator = T::try_from(i + 2).ok()?;
+        let gcd = c.gcd(denominator);
+        c = (c / gcd).checked_mul(numerator / (denominator / gcd))?;
+    }
+    Some(c)
+}
+
+macro_rules! impl_catalan {
+    ($t:ident) => {
+        impl CheckedCatalan for $t {
+            /// Computes the $n$th Catalan number.
+            ///
+            /// The Catalan number $C_n$ counts, among many other things, the binary trees with $n$
+            /// internal nodes.
+            ///
+            /// If the output is too large to be represented, the function returns `None`.
+            ///
+            /// $$
+            /// f(n) = \\begin{cases}
+            ///     \operatorname{Some}(C_n) & \text{if} \\quad C_n < 2^W, \\\\
+            ///     \operatorname{None} & \text{if} \\quad C_n \geq 2^W,
+            /// \\end{cases}
+            /// $$
+            /// where $W$ is `Self::WIDTH`.
+            ///
+            /// $C_n = O(4^n n^{-3/2})$.
+            ///
+            /// # Worst-case complexity
+            /// Constant time and additional memory.
+            ///
+            /// # Examples
+            /// See [here](super::catalan#checked_catalan).
+            #[inline]
+            fn checked_catalan(n: u64) -> Option<$t> {
+                checked_catalan(n)
+            }
+        }
+
+        impl Catalan for $t {
+            /// Computes the $n$th Catalan number.
+            ///
+            /// The Catalan number $C_n$ counts, among many other things, the binary trees with $n$
+            /// internal nodes.
+            ///
+            /// If the output is too large to be represented, the function panics. For a function
+            /// that returns `None` instead, try [`checked_catalan`](CheckedCatalan::checked_catalan).
+            ///
+            /// $$
+            /// f(n) = C_n = \frac{1}{n + 1}\binom{2n}{n}.
+            /// $$
+            ///
+            /// $C_n = O(4^n n^{-3/2})$.
+            ///
+            /// # Worst-case complexity
+            /// Constant time and additional memory.
+            ///
+            /// # Panics
+            /// Panics if the output is too large to be represented.
+            ///
+            /// # Examples
+            /// See [here](super::catalan#catalan).
+            #[inline]
+            fn catalan(n: u64) -> $t {
+                $t::checked_catalan(n).unwrap()
+            }
+        }
+    };
+}
+apply_to_unsigneds!(impl_catalan);
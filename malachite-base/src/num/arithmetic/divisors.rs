@@ -0,0 +1,363 @@
+use crate::num::arithmetic::traits::{DivisorCount, DivisorSum, Divisors};
+use crate::num::basic::unsigneds::PrimitiveUnsigned;
+use crate::num::conversion::traits::ExactFrom;
+use crate::num::factorization::traits::Factor;
+
+const DIVISOR_SUM_OVERFLOW_MESSAGE: &str = "The divisor sum is not representable";
+
+fn divisor_count_from_factorization<T: PrimitiveUnsigned>(factors: &[(T, u64)]) -> T {
+    factors
+        .iter()
+        .fold(T::ONE, |acc, &(_, e)| acc * T::exact_from(e + 1))
+}
+
+// Returns $1 + p^k + p^{2k} + \ldots + p^{ek}$, or `None` if it is not representable.
+fn prime_power_divisor_sum<T: PrimitiveUnsigned>(p: T, e: u64, k: u64) -> Option<T> {
+    let p_k = p.checked_pow(k)?;
+    let mut power = T::ONE;
+    let mut sum = T::ONE;
+    for _ in 0..e {
+        power = power.checked_mul(p_k)?;
+        sum = sum.checked_add(power)?;
+    }
+    Some(sum)
+}
+
+fn divisor_sum_from_factorization<T: PrimitiveUnsigned>(factors: &[(T, u64)], k: u64) -> T {
+    factors
+        .iter()
+        .try_fold(T::ONE, |acc, &(p, e)| {
+            acc.checked_mul(prime_power_divisor_sum(p, e, k)?)
+        })
+        .expect(DIVISOR_SUM_OVERFLOW_MESSAGE)
+}
+
+fn divisors_from_factorization<T: PrimitiveUnsigned>(factors: &[(T, u64)]) -> Vec<T> {
+    let mut divisors = vec![T::ONE];
+    for &(p, e) in factors {
+        let previous_len = divisors.len();
+        let mut power = T::ONE;
+        for _ in 0..e {
+            power *= p;
+            let start = divisors.len();
+            divisors.extend_from_within(..previous_len);
+            for d in &mut divisors[start..] {
+                *d *= power;
+            }
+        }
+    }
+    divisors.sort_unstable();
+    divisors
+}
+
+/// An iterator that generates the positive divisors of a number, in ascending order.
+///
+/// This `struct` is created by [`Divisors::divisors`] and
+/// [`Divisors::divisors_from_factorization`]; see their documentation for more.
+#[derive(Clone, Debug)]
+pub struct DivisorsIterator<T>(std::vec::IntoIter<T>);
+
+impl<T> Iterator for DivisorsIterator<T> {
+    type Item = T;
+
+    #[inline]
+    fn next(&mut self) -> Option<T> {
+        self.0.next()
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.0.size_hint()
+    }
+}
+
+impl<T> DoubleEndedIterator for DivisorsIterator<T> {
+    #[inline]
+    fn next_back(&mut self) -> Option<T> {
+        self.0.next_back()
+    }
+}
+
+impl<T> ExactSizeIterator for DivisorsIterator<T> {}
+
+macro_rules! impl_divisors_unsigned {
+    ($t:ident) => {
+        impl DivisorCount for $t {
+            /// Counts the positive divisors of a number.
+            ///
+            /// If $n = \prod_i p_i^{e_i}$, then the number of divisors is $\prod_i (e_i + 1)$.
+            ///
+            /// # Worst-case complexity
+            /// Constant time and additional memory.
+            ///
+            /// # Panics
+            /// Panics if `self` is 0.
+            ///
+            /// # Examples
+            /// See [here](super::divisors#divisor_count).
+            #[inline]
+            fn divisor_count(&self) -> $t {
+                divisor_count_from_factorization(&self.factor())
+            }
+
+            /// Counts the positive divisors of a number, given its prime factorization.
+            ///
+            /// `factors` must be the prime factorization of the number, in the format returned
+            /// by [`factor`](Factor::factor); this is not checked. The empty factorization
+            /// represents 1.
+            ///
+            /// # Worst-case complexity
+            /// $T(n) = O(n)$
+            ///
+            /// $M(n) = O(1)$
+            ///
+            /// where $T$ is time, $M$ is additional memory, and $n$ is `factors.len()`.
+            ///
+            /// # Examples
+            /// See [here](super::divisors#divisor_count_from_factorization).
+            #[inline]
+            fn divisor_count_from_factorization(factors: &[($t, u64)]) -> $t {
+                divisor_count_from_factorization(factors)
+            }
+        }
+
+        impl DivisorSum for $t {
+            /// Computes the sum of the $k$th powers of the positive divisors of a number.
+            ///
+            /// $$
+            /// \sigma_k(n) = \sum_{d \mid n} d^k = \prod_i \sum_{j=0}^{e_i} p_i^{jk},
+            /// $$
+            /// where $n = \prod_i p_i^{e_i}$. In particular, $\sigma_0(n)$ is the number of
+            /// divisors of $n$ and $\sigma_1(n)$ is their sum.
+            ///
+            /// # Worst-case complexity
+            /// Constant time and additional memory.
+            ///
+            /// # Panics
+            /// Panics if `self` is 0 or if the result is not representable.
+            ///
+            /// # Examples
+            /// See [here](super::divisors#divisor_sum).
+            #[inline]
+            fn divisor_sum(&self, k: u64) -> $t {
+                divisor_sum_from_factorization(&self.factor(), k)
+            }
+
+            /// Computes the sum of the $k$th powers of the positive divisors of a number, given
+            /// its prime factorization.
+            ///
+            /// `factors` must be the prime factorization of the number, in the format returned
+            /// by [`factor`](Factor::factor); this is not checked. The empty factorization
+            /// represents 1.
+            ///
+            /// # Worst-case complexity
+            /// $T(n) = O(n)$
+            ///
+            /// $M(n) = O(1)$
+            ///
+            /// where $T$ is time, $M$ is additional memory, and $n$ is `factors.len()`.
+            ///
+            /// # Panics
+            /// Panics if the result is not representable.
+            ///
+            /// # Examples
+            /// See [here](super::divisors#divisor_sum_from_factorization).
+            #[inline]
+            fn divisor_sum_from_factorization(factors: &[($t, u64)], k: u64) -> $t {
+                divisor_sum_from_factorization(factors, k)
+            }
+        }
+
+        impl Divisors for $t {
+            type I = DivisorsIterator<$t>;
+
+            /// Returns an iterator that generates the positive divisors of a number, in ascending
+            /// order.
+            ///
+            /// # Worst-case complexity
+            /// Constant time and additional memory.
+            ///
+            /// # Panics
+            /// Panics if `self` is 0.
+            ///
+            /// # Examples
+            /// See [here](super::divisors#divisors).
+            #[inline]
+            fn divisors(&self) -> DivisorsIterator<$t> {
+                DivisorsIterator(divisors_from_factorization(&self.factor()).into_iter())
+            }
+
+            /// Returns an iterator that generates the positive divisors of a number, in ascending
+            /// order, given the number's prime factorization.
+            ///
+            /// `factors` must be the prime factorization of the number, in the format returned
+            /// by [`factor`](Factor::factor); this is not checked. The empty factorization
+            /// represents 1.
+            ///
+            /// # Worst-case complexity
+            /// $T(n) = O(n \log n)$
+            ///
+            /// $M(n) = O(n)$
+            ///
+            /// where $T$ is time, $M$ is additional memory, and $n$ is the number of divisors.
+            ///
+            /// # Examples
+            /// See [here](super::divisors#divisors_from_factorization).
+            #[inline]
+            fn divisors_from_factorization(factors: &[($t, u64)]) -> DivisorsIterator<$t> {
+                DivisorsIterator(divisors_from_factorization(factors).into_iter())
+            }
+        }
+    };
+}
+apply_to_unsigneds!(impl_divisors_unsigned);
+
+macro_rules! impl_divisors_signed {
+    ($u:ident, $s:ident) => {
+        impl DivisorCount for $s {
+            /// Counts the positive divisors of the absolute value of a number.
+            ///
+            /// See the unsigned implementation for details.
+            ///
+            /// # Worst-case complexity
+            /// Constant time and additional memory.
+            ///
+            /// # Panics
+            /// Panics if `self` is 0.
+            ///
+            /// # Examples
+            /// See [here](super::divisors#divisor_count).
+            #[inline]
+            fn divisor_count(&self) -> $s {
+                $s::exact_from(self.unsigned_abs().divisor_count())
+            }
+
+            /// Counts the positive divisors of the absolute value of a number, given the prime
+            /// factorization of the absolute value.
+            ///
+            /// `factors` must be the prime factorization of the number's absolute value, in the
+            /// format returned by [`factor`](Factor::factor); this is not checked. The empty
+            /// factorization represents 1.
+            ///
+            /// # Worst-case complexity
+            /// $T(n) = O(n)$
+            ///
+            /// $M(n) = O(1)$
+            ///
+            /// where $T$ is time, $M$ is additional memory, and $n$ is `factors.len()`.
+            ///
+            /// # Examples
+            /// See [here](super::divisors#divisor_count_from_factorization).
+            fn divisor_count_from_factorization(factors: &[($s, u64)]) -> $s {
+                factors
+                    .iter()
+                    .fold(1, |acc, &(_, e)| acc * $s::exact_from(e + 1))
+            }
+        }
+
+        impl DivisorSum for $s {
+            /// Computes the sum of the $k$th powers of the positive divisors of the absolute value
+            /// of a number.
+            ///
+            /// See the unsigned implementation for details.
+            ///
+            /// # Worst-case complexity
+            /// Constant time and additional memory.
+            ///
+            /// # Panics
+            /// Panics if `self` is 0 or if the result is not representable.
+            ///
+            /// # Examples
+            /// See [here](super::divisors#divisor_sum).
+            #[inline]
+            fn divisor_sum(&self, k: u64) -> $s {
+                $s::exact_from(self.unsigned_abs().divisor_sum(k))
+            }
+
+            /// Computes the sum of the $k$th powers of the positive divisors of the absolute value
+            /// of a number, given the prime factorization of the absolute value.
+            ///
+            /// `factors` must be the prime factorization of the number's absolute value, in the
+            /// format returned by [`factor`](Factor::factor); this is not checked. The empty
+            /// factorization represents 1.
+            ///
+            /// # Worst-case complexity
+            /// $T(n) = O(n)$
+            ///
+            /// $M(n) = O(n)$
+            ///
+            /// where $T$ is time, $M$ is additional memory, and $n$ is `factors.len()`.
+            ///
+            /// # Panics
+            /// Panics if the result is not representable.
+            ///
+            /// # Examples
+            /// See [here](super::divisors#divisor_sum_from_factorization).
+            fn divisor_sum_from_factorization(factors: &[($s, u64)], k: u64) -> $s {
+                let factors: Vec<($u, u64)> = factors
+                    .iter()
+                    .map(|&(p, e)| ($u::exact_from(p), e))
+                    .collect();
+                $s::exact_from(divisor_sum_from_factorization(&factors, k))
+            }
+        }
+
+        impl Divisors for $s {
+            type I = DivisorsIterator<$s>;
+
+            /// Returns an iterator that generates the positive divisors of the absolute value of a
+            /// number, in ascending order.
+            ///
+            /// # Worst-case complexity
+            /// Constant time and additional memory.
+            ///
+            /// # Panics
+            /// Panics if `self` is 0 or `Self::MIN`, since the absolute value of `Self::MIN` is not
+            /// representable.
+            ///
+            /// # Examples
+            /// See [here](super::divisors#divisors).
+            #[inline]
+            fn divisors(&self) -> DivisorsIterator<$s> {
+                DivisorsIterator(
+                    divisors_from_factorization(&self.unsigned_abs().factor())
+                        .into_iter()
+                        .map($s::exact_from)
+                        .collect::<Vec<_>>()
+                        .into_iter(),
+                )
+            }
+
+            /// Returns an iterator that generates the positive divisors of the absolute value of a
+            /// number, in ascending order, given the prime factorization of the absolute value.
+            ///
+            /// `factors` must be the prime factorization of the number's absolute value, in the
+            /// format returned by [`factor`](Factor::factor); this is not checked. The empty
+            /// factorization represents 1.
+            ///
+            /// # Worst-case complexity
+            /// $T(n) = O(n \log n)$
+            ///
+            /// $M(n) = O(n)$
+            ///
+            /// where $T$ is time, $M$ is additional memory, and $n$ is the number of divisors.
+            ///
+            /// # Examples
+            /// See [here](super::divisors#divisors_from_factorization).
+            fn divisors_from_factorization(factors: &[($s, u64)]) -> DivisorsIterator<$s> {
+                let factors: Vec<($u, u64)> = factors
+                    .iter()
+                    .map(|&(p, e)| ($u::exact_from(p), e))
+                    .collect();
+                DivisorsIterator(
+                    divisors_from_factorization(&factors)
+                        .into_iter()
+                        .map($s::exact_from)
+                        .collect::<Vec<_>>()
+                        .into_iter(),
+                )
+            }
+        }
+    };
+}
+apply_to_unsigned_signed_pairs!(impl_divisors_signed);
@@ -0,0 +1,239 @@
+use crate::num::arithmetic::traits::{
+    CheckedFibonacci, CheckedLucas, Fibonacci, Lucas, ModFibonacci, ModLucas, ModLucasSequence,
+};
+use crate::num::basic::unsigneds::PrimitiveUnsigned;
+use crate::num::logic::traits::BitIterable;
+
+// Returns the $n$th term of the sequence $a_0, a_1, a_0 + a_1, \ldots$, or `None` if it is too
+// large to be represented. Each term is the sum of the previous two, so the loop stops after at
+// most `T::WIDTH * 3 / 2` iterations: either the output is reached or there is an overflow.
+fn checked_fibonacci_like<T: PrimitiveUnsigned>(a_0: T, a_1: T, n: u64) -> Option<T> {
+    if n == 0 {
+        return Some(a_0);
+    }
+    let (mut previous, mut current) = (a_0, a_1);
+    for _ in 1..n {
+        let next = previous.checked_add(current)?;
+        previous = current;
+        current = next;
+    }
+    Some(current)
+}
+
+// Uses the identities
+//
+// $$
+// U_{2k} = U_k(2U_{k+1} - PU_k), \quad U_{2k+1} = U_{k+1}^2 - QU_k^2,
+// \quad U_{2k+2} = PU_{2k+1} - QU_{2k},
+// $$
+//
+// to compute $(U_n, U_{n+1})$ one bit of $n$ at a time, and then recovers $V_n$ as
+// $2U_{n+1} - PU_n$. Unlike the more common formulas for $U_{2k+1}$ and $V_{2k+1}$, these involve
+// no division by 2, so they work for even $m$ as well.
+fn mod_lucas_sequence<T: PrimitiveUnsigned>(p: T, q: T, n: u64, m: T) -> (T, T) {
+    assert!(p < m, "p must be reduced mod m, but {} >= {}", p, m);
+    assert!(q < m, "q must be reduced mod m, but {} >= {}", q, m);
+    let mut u = T::ZERO;
+    let mut u_next = T::ONE % m;
+    for bit in n.bits().rev() {
+        let u_2k = u.mod_mul(u_next.mod_add(u_next, m).mod_sub(p.mod_mul(u, m), m), m);
+        let u_2k_plus_1 = u_next
+            .mod_square(m)
+            .mod_sub(q.mod_mul(u.mod_square(m), m), m);
+        if bit {
+            u_next = p.mod_mul(u_2k_plus_1, m).mod_sub(q.mod_mul(u_2k, m), m);
+            u = u_2k_plus_1;
+        } else {
+            u = u_2k;
+            u_next = u_2k_plus_1;
+        }
+    }
+    (u, u_next.mod_add(u_next, m).mod_sub(p.mod_mul(u, m), m))
+}
+
+// The Fibonacci and Lucas numbers are $U_n(1, -1)$ and $V_n(1, -1)$.
+fn mod_fibonacci_and_lucas<T: PrimitiveUnsigned>(n: u64, m: T) -> (T, T) {
+    mod_lucas_sequence(T::ONE % m, m - T::ONE, n, m)
+}
+
+macro_rules! impl_fibonacci {
+    ($t:ident) => {
+        impl CheckedFibonacci for $t {
+            /// Computes the $n$th Fibonacci number.
+            ///
+            /// If the output is too large to be represented, the function returns `None`.
+            ///
+            /// $$
+            /// f(n) = \\begin{cases}
+            ///     \operatorname{Some}(F_n) & \text{if} \\quad F_n < 2^W, \\\\
+            ///     \operatorname{None} & \text{if} \\quad F_n \geq 2^W,
+            /// \\end{cases}
+            /// $$
+            /// where $F_0 = 0$, $F_1 = 1$, $F_{n+2} = F_{n+1} + F_n$, and $W$ is `Self::WIDTH`.
+            ///
+            /// $F_n = O(\varphi^n)$, where $\varphi = (1 + \sqrt{5})/2$.
+            ///
+            /// # Worst-case complexity
+            /// Constant time and additional memory.
+            ///
+            /// # Examples
+            /// See [here](super::fibonacci#checked_fibonacci).
+            #[inline]
+            fn checked_fibonacci(n: u64) -> Option<$t> {
+                checked_fibonacci_like(0, 1, n)
+            }
+        }
+
+        impl Fibonacci for $t {
+            /// Computes the $n$th Fibonacci number.
+            ///
+            /// If the output is too large to be represented, the function panics. For a function
+            /// that returns `None` instead, try
+            /// [`checked_fibonacci`](CheckedFibonacci::checked_fibonacci).
+            ///
+            /// $f(n) = F_n$, where $F_0 = 0$, $F_1 = 1$, and $F_{n+2} = F_{n+1} + F_n$.
+            ///
+            /// $F_n = O(\varphi^n)$, where $\varphi = (1 + \sqrt{5})/2$.
+            ///
+            /// # Worst-case complexity
+            /// Constant time and additional memory.
+            ///
+            /// # Panics
+            /// Panics if the output is too large to be represented.
+            ///
+            /// # Examples
+            /// See [here](super::fibonacci#fibonacci).
+            #[inline]
+            fn fibonacci(n: u64) -> $t {
+                $t::checked_fibonacci(n).unwrap()
+            }
+        }
+
+        impl CheckedLucas for $t {
+            /// Computes the $n$th Lucas number.
+            ///
+            /// If the output is too large to be represented, the function returns `None`.
+            ///
+            /// $$
+            /// f(n) = \\begin{cases}
+            ///     \operatorname{Some}(L_n) & \text{if} \\quad L_n < 2^W, \\\\
+            ///     \operatorname{None} & \text{if} \\quad L_n \geq 2^W,
+            /// \\end{cases}
+            /// $$
+            /// where $L_0 = 2$, $L_1 = 1$, $L_{n+2} = L_{n+1} + L_n$, and $W$ is `Self::WIDTH`.
+            ///
+            /// $L_n = O(\varphi^n)$, where $\varphi = (1 + \sqrt{5})/2$.
+            ///
+            /// # Worst-case complexity
+            /// Constant time and additional memory.
+            ///
+            /// # Examples
+            /// See [here](super::fibonacci#checked_lucas).
+            #[inline]
+            fn checked_lucas(n: u64) -> Option<$t> {
+                checked_fibonacci_like(2, 1, n)
+            }
+        }
+
+        impl Lucas for $t {
+            /// Computes the $n$th Lucas number.
+            ///
+            /// If the output is too large to be represented, the function panics. For a function
+            /// that returns `None` instead, try [`checked_lucas`](CheckedLucas::checked_lucas).
+            ///
+            /// $f(n) = L_n$, where $L_0 = 2$, $L_1 = 1$, and $L_{n+2} = L_{n+1} + L_n$.
+            ///
+            /// $L_n = O(\varphi^n)$, where $\varphi = (1 + \sqrt{5})/2$.
+            ///
+            /// # Worst-case complexity
+            /// Constant time and additional memory.
+            ///
+            /// # Panics
+            /// Panics if the output is too large to be represented.
+            ///
+            /// # Examples
+            /// See [here](super::fibonacci#lucas).
+            #[inline]
+            fn lucas(n: u64) -> $t {
+                $t::checked_lucas(n).unwrap()
+            }
+        }
+
+        impl ModFibonacci for $t {
+            /// Computes the $n$th Fibonacci number modulo $m$.
+            ///
+            /// $f(n, m) = x$, where $x < m$ and $F_n \equiv x \mod m$.
+            ///
+            /// # Worst-case complexity
+            /// $T(n) = O(n)$
+            ///
+            /// $M(n) = O(1)$
+            ///
+            /// where $T$ is time, $M$ is additional memory, and $n$ is `n.significant_bits()`.
+            ///
+            /// # Panics
+            /// Panics if `m` is 0.
+            ///
+            /// # Examples
+            /// See [here](super::fibonacci#mod_fibonacci).
+            #[inline]
+            fn mod_fibonacci(n: u64, m: $t) -> $t {
+                mod_fibonacci_and_lucas(n, m).0
+            }
+        }
+
+        impl ModLucas for $t {
+            /// Computes the $n$th Lucas number modulo $m$.
+            ///
+            /// $f(n, m) = x$, where $x < m$ and $L_n \equiv x \mod m$.
+            ///
+            /// # Worst-case complexity
+            /// $T(n) = O(n)$
+            ///
+            /// $M(n) = O(1)$
+            ///
+            /// where $T$ is time, $M$ is additional memory, and $n$ is `n.significant_bits()`.
+            ///
+            /// # Panics
+            /// Panics if `m` is 0.
+            ///
+            /// # Examples
+            /// See [here](super::fibonacci#mod_lucas).
+            #[inline]
+            fn mod_lucas(n: u64, m: $t) -> $t {
+                mod_fibonacci_and_lucas(n, m).1
+            }
+        }
+
+        impl ModLucasSequence for $t {
+            /// Computes the $n$th terms of the Lucas sequences with parameters $P$ and $Q$, modulo
+            /// $m$. Assumes $P$ and $Q$ are already reduced modulo $m$; to use a negative
+            /// parameter, pass its residue modulo $m$.
+            ///
+            /// $f(P, Q, n, m) = (x, y)$, where $x, y < m$, $U_n(P, Q) \equiv x \mod m$, and
+            /// $V_n(P, Q) \equiv y \mod m$. The sequences are defined by $U_0 = 0$, $U_1 = 1$,
+            /// $V_0 = 2$, $V_1 = P$, and $X_{n+2} = PX_{n+1} - QX_n$.
+            ///
+            /// The Fibonacci and Lucas numbers are $U_n(1, -1)$ and $V_n(1, -1)$. These sequences
+            /// are used in Lucas probable prime tests.
+            ///
+            /// # Worst-case complexity
+            /// $T(n) = O(n)$
+            ///
+            /// $M(n) = O(1)$
+            ///
+            /// where $T$ is time, $M$ is additional memory, and $n$ is `n.significant_bits()`.
+            ///
+            /// # Panics
+            /// Panics if `p` or `q` is greater than or equal to `m`.
+            ///
+            /// # Examples
+            /// See [here](super::fibonacci#mod_lucas_sequence).
+            #[inline]
+            fn mod_lucas_sequence(p: $t, q: $t, n: u64, m: $t) -> ($t, $t) {
+                mod_lucas_sequence(p, q, n, m)
+            }
+        }
+    };
+}
+apply_to_unsigneds!(impl_fibonacci);
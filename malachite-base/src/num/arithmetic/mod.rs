@@ -173,6 +173,36 @@ pub mod arithmetic_checked_shr;
 /// assert_eq!(i8::checked_binomial_coefficient(11, 5), None);
 /// ```
 pub mod binomial_coefficient;
+/// [`Catalan`](traits::Catalan) and [`CheckedCatalan`](traits::CheckedCatalan), traits for
+/// computing Catalan numbers.
+///
+/// # catalan
+/// ```
+/// use malachite_base::num::arithmetic::traits::Catalan;
+///
+/// assert_eq!(u8::catalan(0), 1);
+/// assert_eq!(u8::catalan(1), 1);
+/// assert_eq!(u8::catalan(2), 2);
+/// assert_eq!(u8::catalan(3), 5);
+/// assert_eq!(u8::catalan(6), 132);
+/// assert_eq!(u64::catalan(36), 11959798385860453492);
+/// ```
+///
+/// # checked_catalan
+/// ```
+/// use malachite_base::num::arithmetic::traits::CheckedCatalan;
+///
+/// assert_eq!(u8::checked_catalan(0), Some(1));
+/// assert_eq!(u8::checked_catalan(6), Some(132));
+/// assert_eq!(u8::checked_catalan(7), None);
+/// assert_eq!(u64::checked_catalan(37), None);
+/// assert_eq!(
+///     u128::checked_catalan(67),
+///     Some(22033725021956517463358552614056949950)
+/// );
+/// assert_eq!(u128::checked_catalan(70), None);
+/// ```
+pub mod catalan;
 /// [`Ceiling`](traits::Ceiling) and [`CeilingAssign`](traits::CeilingAssign), traits for computing
 /// the ceiling of a number.
 ///
@@ -655,6 +685,72 @@ pub mod divisible_by;
 /// assert_eq!(96i32.divisible_by_power_of_2(6), false);
 /// ```
 pub mod divisible_by_power_of_2;
+/// [`DivisorCount`](traits::DivisorCount), [`DivisorSum`](traits::DivisorSum), and
+/// [`Divisors`](traits::Divisors), traits for counting, summing, and generating the divisors of a
+/// number.
+///
+/// # divisor_count
+/// ```
+/// use malachite_base::num::arithmetic::traits::DivisorCount;
+///
+/// assert_eq!(1u8.divisor_count(), 1);
+/// assert_eq!(12u8.divisor_count(), 6);
+/// assert_eq!(97u32.divisor_count(), 2);
+/// assert_eq!(1000000u64.divisor_count(), 49);
+/// assert_eq!((-36i16).divisor_count(), 9);
+/// ```
+///
+/// # divisor_count_from_factorization
+/// ```
+/// use malachite_base::num::arithmetic::traits::DivisorCount;
+///
+/// assert_eq!(u8::divisor_count_from_factorization(&[]), 1);
+/// assert_eq!(u32::divisor_count_from_factorization(&[(2, 6), (5, 6)]), 49);
+/// ```
+///
+/// # divisor_sum
+/// ```
+/// use malachite_base::num::arithmetic::traits::DivisorSum;
+///
+/// assert_eq!(1u8.divisor_sum(1), 1);
+/// assert_eq!(12u8.divisor_sum(0), 6);
+/// assert_eq!(12u8.divisor_sum(1), 28);
+/// assert_eq!(12u8.divisor_sum(2), 210);
+/// assert_eq!(1000000u64.divisor_sum(2), 1388804117611);
+/// assert_eq!((-100i32).divisor_sum(1), 217);
+/// ```
+///
+/// # divisor_sum_from_factorization
+/// ```
+/// use malachite_base::num::arithmetic::traits::DivisorSum;
+///
+/// assert_eq!(u8::divisor_sum_from_factorization(&[], 3), 1);
+/// assert_eq!(u64::divisor_sum_from_factorization(&[(2, 6), (5, 6)], 1), 2480437);
+/// ```
+///
+/// # divisors
+/// ```
+/// use itertools::Itertools;
+/// use malachite_base::num::arithmetic::traits::Divisors;
+///
+/// assert_eq!(1u8.divisors().collect_vec(), &[1]);
+/// assert_eq!(97u8.divisors().collect_vec(), &[1, 97]);
+/// assert_eq!(60u32.divisors().collect_vec(), &[1, 2, 3, 4, 5, 6, 10, 12, 15, 20, 30, 60]);
+/// assert_eq!((-100i64).divisors().collect_vec(), &[1, 2, 4, 5, 10, 20, 25, 50, 100]);
+/// ```
+///
+/// # divisors_from_factorization
+/// ```
+/// use itertools::Itertools;
+/// use malachite_base::num::arithmetic::traits::Divisors;
+///
+/// assert_eq!(u8::divisors_from_factorization(&[]).collect_vec(), &[1]);
+/// assert_eq!(
+///     u32::divisors_from_factorization(&[(2, 2), (5, 2)]).collect_vec(),
+///     &[1, 2, 4, 5, 10, 20, 25, 50, 100]
+/// );
+/// ```
+pub mod divisors;
 /// [`EqMod`](traits::EqMod), a trait for determining whether one number is equal by another
 /// modulo a third.
 ///
@@ -869,6 +965,97 @@ pub mod extended_gcd;
 /// assert_eq!(u32::checked_subfactorial(100), None);
 /// ```
 pub mod factorial;
+/// Traits for computing Fibonacci numbers, Lucas numbers, and general Lucas sequences, and their
+/// residues modulo a number.
+///
+/// The traits are [`Fibonacci`](traits::Fibonacci), [`CheckedFibonacci`](traits::CheckedFibonacci),
+/// [`Lucas`](traits::Lucas), [`CheckedLucas`](traits::CheckedLucas),
+/// [`LucasSequence`](traits::LucasSequence), [`ModFibonacci`](traits::ModFibonacci),
+/// [`ModLucas`](traits::ModLucas), and [`ModLucasSequence`](traits::ModLucasSequence).
+///
+/// # fibonacci
+/// ```
+/// use malachite_base::num::arithmetic::traits::Fibonacci;
+///
+/// assert_eq!(u8::fibonacci(0), 0);
+/// assert_eq!(u8::fibonacci(1), 1);
+/// assert_eq!(u8::fibonacci(2), 1);
+/// assert_eq!(u8::fibonacci(10), 55);
+/// assert_eq!(u8::fibonacci(13), 233);
+/// assert_eq!(u64::fibonacci(93), 12200160415121876738);
+/// ```
+///
+/// # checked_fibonacci
+/// ```
+/// use malachite_base::num::arithmetic::traits::CheckedFibonacci;
+///
+/// assert_eq!(u8::checked_fibonacci(0), Some(0));
+/// assert_eq!(u8::checked_fibonacci(13), Some(233));
+/// assert_eq!(u8::checked_fibonacci(14), None);
+/// assert_eq!(u64::checked_fibonacci(94), None);
+/// assert_eq!(
+///     u128::checked_fibonacci(186),
+///     Some(332825110087067562321196029789634457848)
+/// );
+/// assert_eq!(u128::checked_fibonacci(187), None);
+/// ```
+///
+/// # lucas
+/// ```
+/// use malachite_base::num::arithmetic::traits::Lucas;
+///
+/// assert_eq!(u8::lucas(0), 2);
+/// assert_eq!(u8::lucas(1), 1);
+/// assert_eq!(u8::lucas(2), 3);
+/// assert_eq!(u8::lucas(10), 123);
+/// assert_eq!(u8::lucas(11), 199);
+/// ```
+///
+/// # checked_lucas
+/// ```
+/// use malachite_base::num::arithmetic::traits::CheckedLucas;
+///
+/// assert_eq!(u8::checked_lucas(0), Some(2));
+/// assert_eq!(u8::checked_lucas(11), Some(199));
+/// assert_eq!(u8::checked_lucas(12), None);
+/// assert!(u64::checked_lucas(92).is_some());
+/// assert_eq!(u64::checked_lucas(93), None);
+/// ```
+///
+/// # mod_fibonacci
+/// ```
+/// use malachite_base::num::arithmetic::traits::ModFibonacci;
+///
+/// assert_eq!(u8::mod_fibonacci(10, 16), 7);
+/// assert_eq!(u8::mod_fibonacci(100, 255), 30);
+/// assert_eq!(u32::mod_fibonacci(100, 1000000007), 687995182);
+///
+/// // 1000000007 is a prime congruent to 2 mod 5, so it divides the 1000000008th Fibonacci number
+/// assert_eq!(u32::mod_fibonacci(1000000008, 1000000007), 0);
+/// ```
+///
+/// # mod_lucas
+/// ```
+/// use malachite_base::num::arithmetic::traits::ModLucas;
+///
+/// assert_eq!(u8::mod_lucas(10, 16), 11);
+/// assert_eq!(u8::mod_lucas(100, 255), 217);
+/// assert_eq!(u32::mod_lucas(100, 1000000007), 876413006);
+/// assert_eq!(u32::mod_lucas(1000000008, 1000000007), 1000000005);
+/// ```
+///
+/// # mod_lucas_sequence
+/// ```
+/// use malachite_base::num::arithmetic::traits::ModLucasSequence;
+///
+/// assert_eq!(u8::mod_lucas_sequence(3, 1, 10, 100), (65, 27));
+/// assert_eq!(u8::mod_lucas_sequence(4, 2, 1000, 7), (6, 3));
+/// assert_eq!(
+///     u32::mod_lucas_sequence(5, 3, 100, 1000000007),
+///     (150049008, 448234207)
+/// );
+/// ```
+pub mod fibonacci;
 /// [`Floor`](traits::Floor) and [`FloorAssign`](traits::FloorAssign), traits for computing the
 /// floor of a number.
 ///
@@ -1146,6 +1333,41 @@ pub mod log_base_power_of_2;
 /// assert_eq!(n, 2);
 /// ```
 pub mod mod_add;
+/// [`ModBinomialCoefficient`](traits::ModBinomialCoefficient), a trait for computing the binomial
+/// coefficient of two numbers modulo another number.
+///
+/// # mod_binomial_coefficient
+/// ```
+/// use malachite_base::num::arithmetic::traits::ModBinomialCoefficient;
+///
+/// assert_eq!(u8::mod_binomial_coefficient(10, 3, 7), 1);
+/// assert_eq!(u8::mod_binomial_coefficient(3, 10, 7), 0);
+/// assert_eq!(u16::mod_binomial_coefficient(100, 50, 1000), 256);
+/// assert_eq!(u32::mod_binomial_coefficient(1000, 500, 59049), 2754);
+/// assert_eq!(
+///     u64::mod_binomial_coefficient(1000000000000000000, 3, 1000000007),
+///     18424
+/// );
+/// assert_eq!(
+///     u64::mod_binomial_coefficient(1000, 500, 10000000000),
+///     9821216320
+/// );
+/// ```
+pub mod mod_binomial_coefficient;
+/// [`ModFactorial`](traits::ModFactorial), a trait for computing the factorial of a number modulo
+/// another number.
+///
+/// # mod_factorial
+/// ```
+/// use malachite_base::num::arithmetic::traits::ModFactorial;
+///
+/// assert_eq!(u8::mod_factorial(6, 7), 6);
+/// assert_eq!(u8::mod_factorial(10, 7), 0);
+/// assert_eq!(u16::mod_factorial(12, 1001), 77);
+/// assert_eq!(u32::mod_factorial(100, 1000000007), 437918130);
+/// assert_eq!(u64::mod_factorial(1000000000, 1000000007), 698611116);
+/// ```
+pub mod mod_factorial;
 /// [`ModInverse`](traits::ModInverse), a trait for finding the multiplicative inverse of a number
 /// modulo another number.
 ///
@@ -1170,6 +1392,25 @@ pub mod mod_inverse;
 /// assert_eq!(100u16.mod_is_reduced(&101), true);
 /// ```
 pub mod mod_is_reduced;
+/// [`ModLog`](traits::ModLog), a trait for computing discrete logarithms modulo a number.
+///
+/// # mod_log
+/// ```
+/// use malachite_base::num::arithmetic::traits::ModLog;
+///
+/// assert_eq!(3u8.mod_log(2, 11), Some(8));
+/// assert_eq!(3u8.mod_log(4, 7), None);
+/// assert_eq!(1u8.mod_log(4, 7), Some(0));
+/// assert_eq!(56u8.mod_log(6, 100), Some(6));
+/// assert_eq!(10u8.mod_log(6, 100), None);
+/// assert_eq!(0u16.mod_log(2, 16), Some(4));
+/// assert_eq!(7u32.mod_log(3, 1000000007), Some(70669385));
+/// assert_eq!(
+///     12345u64.mod_log(5, 18446744073709551557),
+///     Some(1641943267442573239)
+/// );
+/// ```
+pub mod mod_log;
 /// Traits for multiplying two numbers modulo another number.
 ///
 /// The traits are [`ModMul`](traits::ModMul), [`ModMulAssign`](traits::ModMulAssign),
@@ -1992,6 +2233,47 @@ pub mod mod_shl;
 /// assert_eq!(n, 2);
 /// ```
 pub mod mod_shr;
+/// [`ModSqrt`](traits::ModSqrt) and
+/// [`ModSqrtFromFactorization`](traits::ModSqrtFromFactorization), traits for finding square roots
+/// modulo a prime, a prime power, or a number with known factorization.
+///
+/// # mod_sqrt
+/// ```
+/// use malachite_base::num::arithmetic::traits::ModSqrt;
+///
+/// assert_eq!(2u8.mod_sqrt(7), Some(3));
+/// assert_eq!(3u8.mod_sqrt(7), None);
+/// assert_eq!(0u8.mod_sqrt(7), Some(0));
+/// assert_eq!(10u16.mod_sqrt(13), Some(6));
+/// assert_eq!(1000000u64.mod_sqrt(18446744073709551557), Some(1000));
+/// assert_eq!(2u64.mod_sqrt(18446744073709551557), None);
+/// ```
+///
+/// # mod_sqrt_prime_power
+/// ```
+/// use malachite_base::num::arithmetic::traits::ModSqrt;
+///
+/// assert_eq!(7u8.mod_sqrt_prime_power(3, 3), Some(13));
+/// assert_eq!(9u8.mod_sqrt_prime_power(3, 4), Some(3));
+/// assert_eq!(18u8.mod_sqrt_prime_power(3, 3), None);
+/// assert_eq!(17u32.mod_sqrt_prime_power(2, 5), Some(7));
+/// ```
+///
+/// # mod_sqrt_from_factorization
+/// ```
+/// use malachite_base::num::arithmetic::traits::ModSqrtFromFactorization;
+///
+/// assert_eq!(4u8.mod_sqrt_from_factorization(&[(3, 1), (5, 1)]).map(|r| r * r % 15), Some(4));
+/// assert_eq!(2u8.mod_sqrt_from_factorization(&[(3, 1), (5, 1)]), None);
+/// assert_eq!(
+///     46u32
+///         .mod_sqrt_from_factorization(&[(3, 1), (5, 1), (7, 1)])
+///         .map(|r| r * r % 105),
+///     Some(46)
+/// );
+/// assert_eq!(0u32.mod_sqrt_from_factorization(&[]), Some(0));
+/// ```
+pub mod mod_sqrt;
 /// Traits for squaring a number modulo another number.
 ///
 /// The traits are [`ModSquare`](traits::ModSquare), [`ModSquareAssign`](traits::ModSquareAssign),
@@ -2073,6 +2355,257 @@ pub mod mod_square;
 /// assert_eq!(n, 8);
 /// ```
 pub mod mod_sub;
+/// [`ModU8`](mod_unsigned::ModU8), [`ModU16`](mod_unsigned::ModU16),
+/// [`ModU32`](mod_unsigned::ModU32), [`ModU64`](mod_unsigned::ModU64),
+/// [`ModU128`](mod_unsigned::ModU128), and [`ModUsize`](mod_unsigned::ModUsize), residues modulo
+/// an unsigned primitive integer given as a const generic parameter, which support the arithmetic
+/// operators.
+///
+/// # new
+/// ```
+/// use malachite_base::num::arithmetic::mod_unsigned::ModU8;
+///
+/// assert_eq!(ModU8::<10>::new(7).to_string(), "7 mod 10");
+/// ```
+///
+/// # reduce
+/// ```
+/// use malachite_base::num::arithmetic::mod_unsigned::{ModU64, ModU8};
+///
+/// assert_eq!(ModU8::<10>::reduce(123).to_string(), "3 mod 10");
+/// assert_eq!(
+///     ModU64::<1000000007>::reduce(u64::MAX).to_string(),
+///     "582344007 mod 1000000007"
+/// );
+/// ```
+///
+/// # value
+/// ```
+/// use malachite_base::num::arithmetic::mod_unsigned::ModU8;
+///
+/// assert_eq!(ModU8::<10>::reduce(123).value(), 3);
+/// ```
+///
+/// # modulus
+/// ```
+/// use malachite_base::num::arithmetic::mod_unsigned::ModU8;
+///
+/// assert_eq!(ModU8::<10>::modulus(), 10);
+/// ```
+///
+/// # inverse
+/// ```
+/// use malachite_base::num::arithmetic::mod_unsigned::{ModU64, ModU8};
+/// use malachite_base::strings::ToDebugString;
+///
+/// assert_eq!(ModU8::<10>::new(3).inverse().to_debug_string(), "Some(7 mod 10)");
+/// assert_eq!(ModU8::<10>::new(4).inverse().to_debug_string(), "None");
+/// assert_eq!(
+///     ModU64::<1000000007>::new(2).inverse().to_debug_string(),
+///     "Some(500000004 mod 1000000007)"
+/// );
+/// ```
+///
+/// # add
+/// ```
+/// use malachite_base::num::arithmetic::mod_unsigned::ModU8;
+///
+/// type Z10 = ModU8<10>;
+/// assert_eq!((Z10::new(7) + Z10::new(5)).to_string(), "2 mod 10");
+/// assert_eq!((Z10::new(3) + Z10::new(4)).to_string(), "7 mod 10");
+/// ```
+///
+/// # add_assign
+/// ```
+/// use malachite_base::num::arithmetic::mod_unsigned::ModU8;
+///
+/// let mut x = ModU8::<10>::new(7);
+/// x += ModU8::new(5);
+/// assert_eq!(x.to_string(), "2 mod 10");
+/// ```
+///
+/// # sub
+/// ```
+/// use malachite_base::num::arithmetic::mod_unsigned::ModU8;
+///
+/// type Z10 = ModU8<10>;
+/// assert_eq!((Z10::new(7) - Z10::new(5)).to_string(), "2 mod 10");
+/// assert_eq!((Z10::new(3) - Z10::new(4)).to_string(), "9 mod 10");
+/// ```
+///
+/// # sub_assign
+/// ```
+/// use malachite_base::num::arithmetic::mod_unsigned::ModU8;
+///
+/// let mut x = ModU8::<10>::new(3);
+/// x -= ModU8::new(4);
+/// assert_eq!(x.to_string(), "9 mod 10");
+/// ```
+///
+/// # mul
+/// ```
+/// use malachite_base::num::arithmetic::mod_unsigned::{ModU64, ModU8};
+///
+/// type Z10 = ModU8<10>;
+/// assert_eq!((Z10::new(7) * Z10::new(5)).to_string(), "5 mod 10");
+/// assert_eq!((Z10::new(3) * Z10::new(4)).to_string(), "2 mod 10");
+///
+/// type Fp = ModU64<1000000007>;
+/// assert_eq!(
+///     (Fp::new(1000000006) * Fp::new(1000000006)).to_string(),
+///     "1 mod 1000000007"
+/// );
+/// ```
+///
+/// # mul_assign
+/// ```
+/// use malachite_base::num::arithmetic::mod_unsigned::ModU8;
+///
+/// let mut x = ModU8::<10>::new(7);
+/// x *= ModU8::new(5);
+/// assert_eq!(x.to_string(), "5 mod 10");
+/// ```
+///
+/// # div
+/// ```
+/// use malachite_base::num::arithmetic::mod_unsigned::ModU8;
+///
+/// type Z10 = ModU8<10>;
+/// assert_eq!((Z10::new(7) / Z10::new(3)).to_string(), "9 mod 10");
+/// assert_eq!((Z10::new(2) / Z10::new(9)).to_string(), "8 mod 10");
+/// ```
+///
+/// # div_assign
+/// ```
+/// use malachite_base::num::arithmetic::mod_unsigned::ModU8;
+///
+/// let mut x = ModU8::<10>::new(7);
+/// x /= ModU8::new(3);
+/// assert_eq!(x.to_string(), "9 mod 10");
+/// ```
+///
+/// # neg
+/// ```
+/// use malachite_base::num::arithmetic::mod_unsigned::ModU8;
+///
+/// assert_eq!((-ModU8::<10>::new(0)).to_string(), "0 mod 10");
+/// assert_eq!((-ModU8::<10>::new(7)).to_string(), "3 mod 10");
+/// ```
+///
+/// # pow
+/// ```
+/// use malachite_base::num::arithmetic::mod_unsigned::{ModU64, ModU8};
+/// use malachite_base::num::arithmetic::traits::Pow;
+///
+/// assert_eq!(ModU8::<10>::new(3).pow(0).to_string(), "1 mod 10");
+/// assert_eq!(ModU8::<10>::new(3).pow(5).to_string(), "3 mod 10");
+/// assert_eq!(
+///     ModU64::<1000000007>::new(2).pow(1000000006).to_string(),
+///     "1 mod 1000000007"
+/// );
+/// ```
+///
+/// # pow_assign
+/// ```
+/// use malachite_base::num::arithmetic::mod_unsigned::ModU8;
+/// use malachite_base::num::arithmetic::traits::PowAssign;
+///
+/// let mut x = ModU8::<10>::new(3);
+/// x.pow_assign(5);
+/// assert_eq!(x.to_string(), "3 mod 10");
+/// ```
+///
+/// # fmt
+/// ```
+/// use malachite_base::num::arithmetic::mod_unsigned::ModU8;
+/// use malachite_base::strings::ToDebugString;
+///
+/// assert_eq!(ModU8::<10>::new(7).to_string(), "7 mod 10");
+/// assert_eq!(ModU8::<10>::new(7).to_debug_string(), "7 mod 10");
+/// ```
+pub mod mod_unsigned;
+/// [`Moebius`](traits::Moebius), a trait for computing the Möbius function of a number.
+///
+/// # moebius
+/// ```
+/// use malachite_base::num::arithmetic::traits::Moebius;
+///
+/// assert_eq!(1u8.moebius(), 1);
+/// assert_eq!(12u8.moebius(), 0);
+/// assert_eq!(97u16.moebius(), -1);
+/// assert_eq!(561u32.moebius(), -1);
+/// assert_eq!(18446744073709551615u64.moebius(), -1);
+/// assert_eq!((-6i8).moebius(), 1);
+/// ```
+///
+/// # moebius_from_factorization
+/// ```
+/// use malachite_base::num::arithmetic::traits::Moebius;
+///
+/// assert_eq!(u8::moebius_from_factorization(&[]), 1);
+/// assert_eq!(u32::moebius_from_factorization(&[(2, 1), (3, 1)]), 1);
+/// assert_eq!(u32::moebius_from_factorization(&[(2, 1), (3, 2)]), 0);
+/// ```
+pub mod moebius;
+/// [`Multinomial`](traits::Multinomial) and [`CheckedMultinomial`](traits::CheckedMultinomial),
+/// traits for computing multinomial coefficients.
+///
+/// # multinomial
+/// ```
+/// use malachite_base::num::arithmetic::traits::Multinomial;
+///
+/// assert_eq!(u8::multinomial(&[]), 1);
+/// assert_eq!(u8::multinomial(&[5]), 1);
+/// assert_eq!(u8::multinomial(&[2, 3]), 10);
+/// assert_eq!(u8::multinomial(&[1, 2, 3]), 60);
+/// assert_eq!(u32::multinomial(&[1, 4, 4, 2]), 34650);
+/// ```
+///
+/// # checked_multinomial
+/// ```
+/// use malachite_base::num::arithmetic::traits::CheckedMultinomial;
+///
+/// assert_eq!(u8::checked_multinomial(&[2, 3]), Some(10));
+/// assert_eq!(u8::checked_multinomial(&[1, 4, 4, 2]), None);
+/// assert_eq!(u16::checked_multinomial(&[1, 4, 4, 2]), Some(34650));
+/// assert_eq!(u8::checked_multinomial(&[1000, 0, 0]), Some(1));
+/// ```
+pub mod multinomial;
+/// [`MultiplicativeOrder`](traits::MultiplicativeOrder) and
+/// [`PrimitiveRoot`](traits::PrimitiveRoot), traits for computing the multiplicative order of a
+/// number modulo another number and for finding primitive roots.
+///
+/// # multiplicative_order
+/// ```
+/// use malachite_base::num::arithmetic::traits::MultiplicativeOrder;
+///
+/// assert_eq!(0u8.multiplicative_order(1), Some(1));
+/// assert_eq!(2u8.multiplicative_order(7), Some(3));
+/// assert_eq!(3u8.multiplicative_order(7), Some(6));
+/// assert_eq!(4u8.multiplicative_order(9), Some(3));
+/// assert_eq!(6u8.multiplicative_order(9), None);
+/// assert_eq!(3u32.multiplicative_order(1000000007), Some(500000003));
+/// assert_eq!(
+///     7u64.multiplicative_order(18446744073709551557),
+///     Some(18446744073709551556)
+/// );
+/// ```
+///
+/// # primitive_root
+/// ```
+/// use malachite_base::num::arithmetic::traits::PrimitiveRoot;
+///
+/// assert_eq!(1u8.primitive_root(), Some(0));
+/// assert_eq!(2u8.primitive_root(), Some(1));
+/// assert_eq!(4u8.primitive_root(), Some(3));
+/// assert_eq!(7u8.primitive_root(), Some(3));
+/// assert_eq!(8u8.primitive_root(), None);
+/// assert_eq!(18u8.primitive_root(), Some(5));
+/// assert_eq!(40u8.primitive_root(), None);
+/// assert_eq!(1000000007u32.primitive_root(), Some(5));
+/// assert_eq!(18446744073709551557u64.primitive_root(), Some(2));
+/// ```
+pub mod multiplicative_order;
 /// [`NegAssign`](traits::NegAssign), a trait for negating a number in place.
 ///
 /// # neg_assign
@@ -2398,6 +2931,75 @@ pub mod overflowing_sub_mul;
 /// assert_eq!(4u32.odd(), false);
 /// ```
 pub mod parity;
+/// [`PartitionCount`](traits::PartitionCount) and
+/// [`CheckedPartitionCount`](traits::CheckedPartitionCount), traits for counting the partitions of
+/// a number.
+///
+/// # partition_count
+/// ```
+/// use malachite_base::num::arithmetic::traits::PartitionCount;
+///
+/// assert_eq!(u8::partition_count(0), 1);
+/// assert_eq!(u8::partition_count(1), 1);
+/// assert_eq!(u8::partition_count(2), 2);
+/// assert_eq!(u8::partition_count(5), 7);
+/// assert_eq!(u8::partition_count(16), 231);
+/// assert_eq!(u64::partition_count(416), 17873792969689876004);
+/// ```
+///
+/// # checked_partition_count
+/// ```
+/// use malachite_base::num::arithmetic::traits::CheckedPartitionCount;
+///
+/// assert_eq!(u8::checked_partition_count(0), Some(1));
+/// assert_eq!(u8::checked_partition_count(16), Some(231));
+/// assert_eq!(u8::checked_partition_count(17), None);
+/// assert_eq!(u64::checked_partition_count(417), None);
+/// ```
+pub mod partition_count;
+/// [`IsPerfectPower`](traits::IsPerfectPower) and
+/// [`PerfectPowerDecomposition`](traits::PerfectPowerDecomposition), traits for determining whether
+/// a number is a perfect power and for writing it as a perfect power with the largest possible
+/// exponent.
+///
+/// # is_perfect_power
+/// ```
+/// use malachite_base::num::arithmetic::traits::IsPerfectPower;
+///
+/// assert_eq!(0u8.is_perfect_power(), true);
+/// assert_eq!(1u8.is_perfect_power(), true);
+/// assert_eq!(2u8.is_perfect_power(), false);
+/// assert_eq!(64u8.is_perfect_power(), true);
+/// assert_eq!(100u8.is_perfect_power(), true);
+/// assert_eq!(1000000u32.is_perfect_power(), true);
+/// assert_eq!(1000001u32.is_perfect_power(), false);
+/// assert_eq!((-8i8).is_perfect_power(), true);
+/// assert_eq!((-4i8).is_perfect_power(), false);
+/// assert_eq!((-1i8).is_perfect_power(), true);
+/// ```
+///
+/// # perfect_power_decomposition
+/// ```
+/// use malachite_base::num::arithmetic::traits::PerfectPowerDecomposition;
+///
+/// assert_eq!(0u8.perfect_power_decomposition(), (0, 1));
+/// assert_eq!(1u8.perfect_power_decomposition(), (1, 1));
+/// assert_eq!(10u8.perfect_power_decomposition(), (10, 1));
+/// assert_eq!(64u8.perfect_power_decomposition(), (2, 6));
+/// assert_eq!(1000000u32.perfect_power_decomposition(), (10, 6));
+/// assert_eq!(
+///     18446744073709551557u64.perfect_power_decomposition(),
+///     (18446744073709551557, 1)
+/// );
+/// assert_eq!(
+///     3433683820292512484657849089281u128.perfect_power_decomposition(),
+///     (3, 64)
+/// );
+/// assert_eq!((-64i8).perfect_power_decomposition(), (-4, 3));
+/// assert_eq!((-128i8).perfect_power_decomposition(), (-2, 7));
+/// assert_eq!((-1i8).perfect_power_decomposition(), (-1, 1));
+/// ```
+pub mod perfect_power;
 /// [`Pow`](traits::Pow) and [`PowAssign`](traits::PowAssign), traits for raising a number to a
 /// power.
 ///
@@ -3532,6 +4134,79 @@ pub mod sqrt;
 /// assert_eq!(x, 2.25);
 /// ```
 pub mod square;
+/// [`StirlingFirst`](traits::StirlingFirst),
+/// [`CheckedStirlingFirst`](traits::CheckedStirlingFirst),
+/// [`StirlingSecond`](traits::StirlingSecond),
+/// [`CheckedStirlingSecond`](traits::CheckedStirlingSecond), [`Bell`](traits::Bell), and
+/// [`CheckedBell`](traits::CheckedBell), traits for computing Stirling numbers of the first and
+/// second kinds and Bell numbers.
+///
+/// # stirling_first
+/// ```
+/// use malachite_base::num::arithmetic::traits::StirlingFirst;
+///
+/// assert_eq!(u8::stirling_first(0, 0), 1);
+/// assert_eq!(u8::stirling_first(3, 0), 0);
+/// assert_eq!(u8::stirling_first(3, 4), 0);
+/// assert_eq!(u8::stirling_first(5, 2), 50);
+/// assert_eq!(u32::stirling_first(10, 3), 1172700);
+/// assert_eq!(u64::stirling_first(20, 10), 381922055502195);
+/// ```
+///
+/// # checked_stirling_first
+/// ```
+/// use malachite_base::num::arithmetic::traits::CheckedStirlingFirst;
+///
+/// assert_eq!(u8::checked_stirling_first(5, 2), Some(50));
+/// assert_eq!(u8::checked_stirling_first(6, 5), Some(15));
+/// assert_eq!(u8::checked_stirling_first(6, 2), None);
+/// assert_eq!(u16::checked_stirling_first(6, 2), Some(274));
+/// ```
+///
+/// # stirling_second
+/// ```
+/// use malachite_base::num::arithmetic::traits::StirlingSecond;
+///
+/// assert_eq!(u8::stirling_second(0, 0), 1);
+/// assert_eq!(u8::stirling_second(3, 0), 0);
+/// assert_eq!(u8::stirling_second(3, 4), 0);
+/// assert_eq!(u8::stirling_second(5, 2), 15);
+/// assert_eq!(u32::stirling_second(10, 3), 9330);
+/// assert_eq!(u64::stirling_second(25, 10), 1203163392175387500);
+/// ```
+///
+/// # checked_stirling_second
+/// ```
+/// use malachite_base::num::arithmetic::traits::CheckedStirlingSecond;
+///
+/// assert_eq!(u8::checked_stirling_second(5, 2), Some(15));
+/// assert_eq!(u8::checked_stirling_second(10, 5), None);
+/// assert_eq!(u16::checked_stirling_second(10, 5), Some(42525));
+/// assert_eq!(u8::checked_stirling_second(1000, 1), Some(1));
+/// ```
+///
+/// # bell
+/// ```
+/// use malachite_base::num::arithmetic::traits::Bell;
+///
+/// assert_eq!(u8::bell(0), 1);
+/// assert_eq!(u8::bell(1), 1);
+/// assert_eq!(u8::bell(2), 2);
+/// assert_eq!(u8::bell(3), 5);
+/// assert_eq!(u8::bell(6), 203);
+/// assert_eq!(u64::bell(25), 4638590332229999353);
+/// ```
+///
+/// # checked_bell
+/// ```
+/// use malachite_base::num::arithmetic::traits::CheckedBell;
+///
+/// assert_eq!(u8::checked_bell(0), Some(1));
+/// assert_eq!(u8::checked_bell(6), Some(203));
+/// assert_eq!(u8::checked_bell(7), None);
+/// assert_eq!(u64::checked_bell(26), None);
+/// ```
+pub mod stirling;
 /// [`SubMul`](traits::SubMul) and [`SubMulAssign`](traits::SubMulAssign), traits for subtracting
 /// the product of two numbers from a number.
 ///
@@ -3563,6 +4238,49 @@ pub mod square;
 pub mod sub_mul;
 /// Various traits for performing arithmetic operations on numbers.
 pub mod traits;
+/// [`Totient`](traits::Totient) and [`CarmichaelLambda`](traits::CarmichaelLambda), traits for
+/// computing Euler's totient function and the Carmichael function of a number.
+///
+/// # totient
+/// ```
+/// use malachite_base::num::arithmetic::traits::Totient;
+///
+/// assert_eq!(1u8.totient(), 1);
+/// assert_eq!(12u8.totient(), 4);
+/// assert_eq!(97u16.totient(), 96);
+/// assert_eq!(1000000u32.totient(), 400000);
+/// assert_eq!(18446744073709551615u64.totient(), 9208981628670443520);
+/// assert_eq!((-100i32).totient(), 40);
+/// ```
+///
+/// # totient_from_factorization
+/// ```
+/// use malachite_base::num::arithmetic::traits::Totient;
+///
+/// assert_eq!(u8::totient_from_factorization(&[]), 1);
+/// assert_eq!(u32::totient_from_factorization(&[(2, 6), (5, 6)]), 400000);
+/// ```
+///
+/// # carmichael_lambda
+/// ```
+/// use malachite_base::num::arithmetic::traits::CarmichaelLambda;
+///
+/// assert_eq!(1u8.carmichael_lambda(), 1);
+/// assert_eq!(12u8.carmichael_lambda(), 2);
+/// assert_eq!(97u16.carmichael_lambda(), 96);
+/// assert_eq!(561u32.carmichael_lambda(), 80);
+/// assert_eq!(18446744073709551615u64.carmichael_lambda(), 17153064960);
+/// assert_eq!((-100i32).carmichael_lambda(), 20);
+/// ```
+///
+/// # carmichael_lambda_from_factorization
+/// ```
+/// use malachite_base::num::arithmetic::traits::CarmichaelLambda;
+///
+/// assert_eq!(u8::carmichael_lambda_from_factorization(&[]), 1);
+/// assert_eq!(u32::carmichael_lambda_from_factorization(&[(2, 6), (5, 6)]), 50000);
+/// ```
+pub mod totient;
 /// [`WrappingAbs`](traits::WrappingAbs) and [`WrappingAbsAssign`](traits::WrappingAbsAssign),
 /// traits for computing the absolute value of a number and wrapping at the boundary of the type.
 ///
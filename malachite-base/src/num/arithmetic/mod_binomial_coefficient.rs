@@ -0,0 +1,128 @@
+use crate::num::arithmetic::mod_factorial::{
+    crt_from_prime_powers, p_free_factorial, p_free_interval_product, p_free_period_product,
+};
+use crate::num::arithmetic::traits::ModBinomialCoefficient;
+use crate::num::basic::unsigneds::PrimitiveUnsigned;
+use std::cmp::min;
+
+// Returns $\binom{n}{k} \mod p$ for $k \leq n < p$.
+fn small_binomial_coefficient_mod_prime<T: PrimitiveUnsigned>(n: T, k: T, p: T) -> T {
+    let k = min(k, n - k);
+    let mut numerator = T::ONE;
+    let mut denominator = T::ONE;
+    let mut i = T::ZERO;
+    while i < k {
+        numerator.mod_mul_assign(n - i, p);
+        i += T::ONE;
+        denominator.mod_mul_assign(i, p);
+    }
+    numerator.mod_mul(denominator.mod_inverse(p).unwrap(), p)
+}
+
+// Uses Lucas' theorem: if $n_i$ and $k_i$ are the base-$p$ digits of $n$ and $k$, then
+// $\binom{n}{k} \equiv \prod_i \binom{n_i}{k_i} \mod p$.
+fn mod_binomial_coefficient_prime<T: PrimitiveUnsigned>(mut n: T, mut k: T, p: T) -> T {
+    let mut product = T::ONE;
+    while k != T::ZERO {
+        let n_digit = n % p;
+        let k_digit = k % p;
+        if k_digit > n_digit {
+            return T::ZERO;
+        }
+        product.mod_mul_assign(small_binomial_coefficient_mod_prime(n_digit, k_digit, p), p);
+        n /= p;
+        k /= p;
+    }
+    product
+}
+
+// Uses Granville's generalization of Lucas' theorem. Writing $x! = p^{v(x)} u(x)$ with
+// $p \nmid u(x)$, we have
+//
+// $$
+// \binom{n}{k} = p^{v(n) - v(k) - v(n - k)} \frac{u(n)}{u(k)u(n - k)},
+// $$
+//
+// and $u(x)$ is the product of the factorials of $\lfloor x/p^i \rfloor$ with multiples of $p$
+// removed. Since $\lfloor n/p^i \rfloor$ exceeds $\lfloor k/p^i \rfloor + \lfloor (n - k)/p^i
+// \rfloor$ by at most 1, each ratio of these factorials is a short product divided by a factorial,
+// and can be computed modulo $p^e$ with work proportional to the smaller of the two denominators.
+fn mod_binomial_coefficient_prime_power<T: PrimitiveUnsigned>(n: T, k: T, p: T, e: u64) -> T {
+    let r = n - k;
+    // By Kummer's theorem, the power of $p$ dividing the result is the number of carries when
+    // adding $k$ and $n - k$ in base $p$.
+    let mut v = 0;
+    let (mut n_high, mut k_high, mut r_high) = (n / p, k / p, r / p);
+    while n_high != T::ZERO {
+        if n_high != k_high + r_high {
+            v += 1;
+        }
+        n_high /= p;
+        k_high /= p;
+        r_high /= p;
+    }
+    if v >= e {
+        return T::ZERO;
+    }
+    let q = p.pow(e);
+    let s = p_free_period_product(p, e);
+    let mut product = p.pow(v);
+    let (mut n, mut k, mut r) = (n, k, r);
+    while n != T::ZERO {
+        let carry = n - k - r;
+        let (large, small) = if k >= r { (k, r) } else { (r, k) };
+        product.mod_mul_assign(p_free_interval_product(large, small + carry, p, q, s), q);
+        product.mod_mul_assign(p_free_factorial(small, p, q, s).mod_inverse(q).unwrap(), q);
+        n /= p;
+        k /= p;
+        r /= p;
+    }
+    product
+}
+
+fn mod_binomial_coefficient<T: PrimitiveUnsigned>(n: T, k: T, m: T) -> T {
+    assert_ne!(m, T::ZERO);
+    if k > n || m == T::ONE {
+        return T::ZERO;
+    }
+    crt_from_prime_powers(m, &m.factor(), &mut |p, e| {
+        if e == 1 {
+            mod_binomial_coefficient_prime(n, k, p)
+        } else {
+            mod_binomial_coefficient_prime_power(n, k, p, e)
+        }
+    })
+}
+
+macro_rules! impl_mod_binomial_coefficient {
+    ($t:ident) => {
+        impl ModBinomialCoefficient for $t {
+            /// Computes the binomial coefficient of two numbers modulo a third number $m$.
+            ///
+            /// The binomial coefficient is computed separately modulo each prime power $p^e$
+            /// dividing $m$, and the results are combined using the Chinese remainder theorem.
+            /// Modulo a prime, Lucas' theorem is used; modulo a higher prime power, Granville's
+            /// generalization of it.
+            ///
+            /// $f(n, k, m) = \binom{n}{k} \mod m$.
+            ///
+            /// # Worst-case complexity
+            /// $T(k, m) = O(\min(k, m))$
+            ///
+            /// $M(m) = O(\log m)$
+            ///
+            /// where $T$ is time, $M$ is additional memory, $k$ is `k`, and $m$ is `m`.
+            ///
+            /// # Panics
+            /// Panics if `m` is 0.
+            ///
+            /// # Examples
+            /// See [here](super::mod_binomial_coefficient#mod_binomial_coefficient).
+            #[inline]
+            fn mod_binomial_coefficient(n: $t, k: $t, m: $t) -> $t {
+                mod_binomial_coefficient(n, k, m)
+            }
+        }
+    };
+}
+apply_to_unsigneds!(impl_mod_binomial_coefficient);
@@ -0,0 +1,145 @@
+use crate::num::arithmetic::traits::ModFactorial;
+use crate::num::basic::unsigneds::PrimitiveUnsigned;
+
+// Returns the product of all integers coprime to $p$ among any $q = p^e$ consecutive integers,
+// modulo $q$. This is $-1$, except when $p = 2$ and $e \geq 3$, when it is 1.
+pub(crate) fn p_free_period_product<T: PrimitiveUnsigned>(p: T, e: u64) -> T {
+    if p == T::TWO && e >= 3 {
+        T::ONE
+    } else {
+        p.pow(e) - T::ONE
+    }
+}
+
+// Returns $\prod_{a < j \leq a + l, p \nmid j} j \mod q$, where $q = p^e$ and $s$ is the product
+// returned by `p_free_period_product`. Only $l \mod q$ of the factors need to be multiplied
+// together; each complete run of $q$ factors contributes $s$.
+pub(crate) fn p_free_interval_product<T: PrimitiveUnsigned>(a: T, l: T, p: T, q: T, s: T) -> T {
+    let mut product = if (l / q).odd() { s } else { T::ONE };
+    let mut j = a % q;
+    let mut i = l % q;
+    while i != T::ZERO {
+        j = if j == q - T::ONE { T::ZERO } else { j + T::ONE };
+        if !j.divisible_by(p) {
+            product.mod_mul_assign(j, q);
+        }
+        i -= T::ONE;
+    }
+    product
+}
+
+// Returns $\prod_{1 \leq j \leq x, p \nmid j} j \mod q$, where $q = p^e$.
+//
+// If $x \mod q$ is more than halfway to $q$, the product is instead computed as $s$ divided by the
+// product of the remaining factors below $q$. When $e = 1$, this is Wilson's theorem.
+pub(crate) fn p_free_factorial<T: PrimitiveUnsigned>(x: T, p: T, q: T, s: T) -> T {
+    let mut product = if (x / q).odd() { s } else { T::ONE };
+    let r = x % q;
+    let remaining = q - T::ONE - r;
+    let partial = if r <= remaining {
+        p_free_interval_product(T::ZERO, r, p, q, s)
+    } else {
+        s.mod_mul(
+            p_free_interval_product(r, remaining, p, q, s)
+                .mod_inverse(q)
+                .unwrap(),
+            q,
+        )
+    };
+    product.mod_mul_assign(partial, q);
+    product
+}
+
+// Returns the exponent of $p$ in $n!$, by Legendre's formula. The result saturates at `u64::MAX`.
+fn factorial_p_adic_valuation<T: PrimitiveUnsigned>(mut n: T, p: T) -> u64 {
+    let mut v = 0u64;
+    while n != T::ZERO {
+        n /= p;
+        v = v.saturating_add(n.saturating_into());
+    }
+    v
+}
+
+// Returns $n! \mod p^e$. Writing $n! = p^v u$ with $p \nmid u$, $u$ is the product of
+// `p_free_factorial` applied to $\lfloor n/p^i \rfloor$ for $i \geq 0$.
+fn mod_factorial_prime_power<T: PrimitiveUnsigned>(n: T, p: T, e: u64) -> T {
+    let v = factorial_p_adic_valuation(n, p);
+    if v >= e {
+        return T::ZERO;
+    }
+    let q = p.pow(e);
+    let s = p_free_period_product(p, e);
+    let mut product = p.pow(v);
+    let mut x = n;
+    while x != T::ZERO {
+        product.mod_mul_assign(p_free_factorial(x, p, q, s), q);
+        x /= p;
+    }
+    product
+}
+
+// Combines residues modulo the prime powers dividing $m$ using the Chinese remainder theorem.
+pub(crate) fn crt_from_prime_powers<T: PrimitiveUnsigned>(
+    m: T,
+    factors: &[(T, u64)],
+    f: &mut dyn FnMut(T, u64) -> T,
+) -> T {
+    let mut x = T::ZERO;
+    for &(p, e) in factors {
+        let q = p.pow(e);
+        let r = f(p, e);
+        if r == T::ZERO {
+            continue;
+        }
+        let cofactor = m / q;
+        let inverse = (cofactor % q).mod_inverse(q).unwrap();
+        x.mod_add_assign(r.mod_mul(inverse, q) * cofactor, m);
+    }
+    x
+}
+
+fn mod_factorial<T: PrimitiveUnsigned>(n: T, m: T) -> T {
+    assert_ne!(m, T::ZERO);
+    // If $n \geq m$, then $m \mid n!$.
+    if n >= m {
+        return T::ZERO;
+    }
+    crt_from_prime_powers(m, &m.factor(), &mut |p, e| {
+        mod_factorial_prime_power(n, p, e)
+    })
+}
+
+macro_rules! impl_mod_factorial {
+    ($t:ident) => {
+        impl ModFactorial for $t {
+            /// Computes the factorial of a number modulo another number $m$.
+            ///
+            /// The factorial is computed separately modulo each prime power $p^e$ dividing $m$,
+            /// and the results are combined using the Chinese remainder theorem. Modulo $p^e$,
+            /// the power of $p$ dividing $n!$ is found using Legendre's formula, and the rest of
+            /// $n!$ is a product of factorials of $\lfloor n/p^i \rfloor$ with the multiples of $p$
+            /// removed. These are computed using the fact that the product of the integers coprime
+            /// to $p$ in any $p^e$ consecutive integers is congruent to $\pm 1$.
+            ///
+            /// $f(n, m) = n! \mod m$.
+            ///
+            /// # Worst-case complexity
+            /// $T(m) = O(m)$
+            ///
+            /// $M(m) = O(\log m)$
+            ///
+            /// where $T$ is time, $M$ is additional memory, and $m$ is `m`.
+            ///
+            /// # Panics
+            /// Panics if `m` is 0.
+            ///
+            /// # Examples
+            /// See [here](super::mod_factorial#mod_factorial).
+            #[inline]
+            fn mod_factorial(n: $t, m: $t) -> $t {
+                mod_factorial(n, m)
+            }
+        }
+    };
+}
+apply_to_unsigneds!(impl_mod_factorial);
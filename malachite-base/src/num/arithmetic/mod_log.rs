@@ -0,0 +1,258 @@
+use crate::num::arithmetic::multiplicative_order::{
+    carmichael_lambda_and_factors, mod_order_from_multiple,
+};
+use crate::num::arithmetic::traits::ModLog;
+use crate::num::basic::unsigneds::PrimitiveUnsigned;
+use crate::num::factorization::is_prime::mod_pow_wide;
+use std::collections::HashMap;
+
+// Subproblems in groups of prime order $q$ are solved using baby-step giant-step if $q$ has at most
+// this many bits, and using Pollard's rho otherwise. Baby-step giant-step stores about $\sqrt q$
+// group elements.
+const BABY_STEP_GIANT_STEP_MAX_BITS: u64 = 40;
+
+// Combines $x \equiv a \mod m$ and $x \equiv b \mod n$ into a single congruence modulo
+// $\operatorname{lcm}(m, n)$, or returns `None` if there is no solution. Assumes that $a < m$, $b <
+// n$, and that $\operatorname{lcm}(m, n)$ is representable.
+fn chinese_remainder_pair<T: PrimitiveUnsigned>(a: T, m: T, b: T, n: T) -> Option<(T, T)> {
+    let g = m.gcd(n);
+    let (diff, diff_is_negative) = if b >= a {
+        (b - a, false)
+    } else {
+        (a - b, true)
+    };
+    if !diff.divisible_by(g) {
+        return None;
+    }
+    let n_over_g = n / g;
+    if n_over_g == T::ONE {
+        return Some((a, m));
+    }
+    let mut k = (diff / g) % n_over_g;
+    if diff_is_negative {
+        k.mod_neg_assign(n_over_g);
+    }
+    let inverse = (m / g % n_over_g).mod_inverse(n_over_g).unwrap();
+    k.mod_mul_assign(inverse, n_over_g);
+    Some((a + m * k, m * n_over_g))
+}
+
+// Finds the smallest $x < n$ such that $g^x \equiv h \mod m$, or returns `None` if there is none.
+// Assumes that $g$ is coprime to $m$.
+pub_test! {mod_log_baby_step_giant_step<T: PrimitiveUnsigned>(
+    g: T,
+    h: T,
+    n: T,
+    m: T
+) -> Option<T> {
+    let s = n.ceiling_sqrt();
+    // Maps g^j to the smallest such j < s.
+    let mut baby_steps = HashMap::new();
+    let mut x = T::ONE % m;
+    let mut j = T::ZERO;
+    while j < s {
+        baby_steps.entry(x).or_insert(j);
+        x.mod_mul_assign(g, m);
+        j += T::ONE;
+    }
+    // x is now g^s.
+    let giant_step = x.mod_inverse(m).unwrap();
+    let mut y = h;
+    let mut i = T::ZERO;
+    while i < s {
+        if let Some(&j) = baby_steps.get(&y) {
+            let x = i * s + j;
+            return if x < n { Some(x) } else { None };
+        }
+        y.mod_mul_assign(giant_step, m);
+        i += T::ONE;
+    }
+    None
+}}
+
+// Finds the $x < q$ such that $g^x \equiv h \mod m$ using Pollard's rho, where $g$ has prime order
+// $q$ modulo $m$. Assumes that $h$ is a power of $g$; otherwise, this function never terminates.
+//
+// The walk visits elements of the form $g^a h^b$, and the group is split into three parts by the
+// element's residue modulo 3. A collision $g^{a_1} h^{b_1} = g^{a_2} h^{b_2}$ with $b_1 \neq b_2$
+// determines $x$. Collisions with $b_1 = b_2$ happen with probability about $1/q$; in that case the
+// walk is restarted from a different element.
+pub_test! {mod_log_pollard_rho<T: PrimitiveUnsigned>(g: T, h: T, q: T, m: T) -> T {
+    let three = T::wrapping_from(3u8);
+    let step = |(x, a, b): (T, T, T)| {
+        let part = x % three;
+        if part == T::ZERO {
+            (x.mod_mul(h, m), a, b.mod_add(T::ONE, q))
+        } else if part == T::ONE {
+            (x.mod_square(m), a.mod_add(a, q), b.mod_add(b, q))
+        } else {
+            (x.mod_mul(g, m), a.mod_add(T::ONE, q), b)
+        }
+    };
+    let mut start = T::ZERO;
+    loop {
+        // Floyd's cycle-finding algorithm.
+        let initial = (mod_pow_wide(g, start, m).mod_mul(h, m), start, T::ONE);
+        let mut tortoise = step(initial);
+        let mut hare = step(tortoise);
+        while tortoise.0 != hare.0 {
+            tortoise = step(tortoise);
+            hare = step(step(hare));
+        }
+        let (_, a_1, b_1) = tortoise;
+        let (_, a_2, b_2) = hare;
+        if b_1 != b_2 {
+            // a_1 + b_1 x ≡ a_2 + b_2 x mod q
+            let x = a_2
+                .mod_sub(a_1, q)
+                .mod_mul(b_1.mod_sub(b_2, q).mod_inverse(q).unwrap(), q);
+            if mod_pow_wide(g, x, m) == h {
+                return x;
+            }
+        }
+        start += T::ONE;
+    }
+}}
+
+// Finds the smallest $x$ such that $g^x \equiv h \mod m$, where $g$ has order $q^e$ modulo $m$ and
+// $q$ is prime, or returns `None` if there is none. The digits of $x$ in base $q$ are found one at
+// a time by solving discrete logarithms in the subgroup of order $q$.
+fn mod_log_prime_power_order<T: PrimitiveUnsigned>(g: T, h: T, q: T, e: u64, m: T) -> Option<T> {
+    let q_e_minus_1 = q.pow(e - 1);
+    let gamma = mod_pow_wide(g, q_e_minus_1, m);
+    let g_inverse = g.mod_inverse(m).unwrap();
+    let mut x = T::ZERO;
+    let mut q_j = T::ONE;
+    let mut q_e_minus_j_minus_1 = q_e_minus_1;
+    for j in 0..e {
+        // h_j = (g^(-x) h)^(q^(e - j - 1)) has order dividing q if h is a power of g.
+        let h_j = mod_pow_wide(
+            mod_pow_wide(g_inverse, x, m).mod_mul(h, m),
+            q_e_minus_j_minus_1,
+            m,
+        );
+        let digit = if q.significant_bits() <= BABY_STEP_GIANT_STEP_MAX_BITS {
+            mod_log_baby_step_giant_step(gamma, h_j, q, m)?
+        } else if mod_pow_wide(h_j, q, m) == T::ONE {
+            mod_log_pollard_rho(gamma, h_j, q, m)
+        } else {
+            return None;
+        };
+        x += digit * q_j;
+        if j != e - 1 {
+            q_j *= q;
+            q_e_minus_j_minus_1 /= q;
+        }
+    }
+    Some(x)
+}
+
+// Finds the smallest $x$ such that $g^x \equiv h \mod p^k$, returning $x$ and the order of $g$, or
+// returns `None` if there is no such $x$. Assumes that $m = p^k$, where $p$ is prime, and that $g$
+// and $h$ are reduced modulo $m$ and coprime to it.
+//
+// The order of $g$ is found from the Carmichael function of $p^k$. Then the Pohlig-Hellman
+// algorithm reduces the problem to one discrete logarithm for each prime power dividing the order.
+fn mod_log_prime_power<T: PrimitiveUnsigned>(g: T, h: T, p: T, k: u64, m: T) -> Option<(T, T)> {
+    let (lambda, lambda_factors) = carmichael_lambda_and_factors(&[(p, k)]);
+    let (order, order_factors) = mod_order_from_multiple(g, m, lambda, &lambda_factors);
+    if mod_pow_wide(h, order, m) != T::ONE {
+        return None;
+    }
+    let mut x = T::ZERO;
+    let mut modulus = T::ONE;
+    for (q, e) in order_factors {
+        let q_e = q.pow(e);
+        let cofactor = order / q_e;
+        let x_q = mod_log_prime_power_order(
+            mod_pow_wide(g, cofactor, m),
+            mod_pow_wide(h, cofactor, m),
+            q,
+            e,
+            m,
+        )?;
+        (x, modulus) = chinese_remainder_pair(x, modulus, x_q, q_e).unwrap();
+    }
+    // Modulo powers of 2 the group is not cyclic, so h may fail to be a power of g even though
+    // h^order = 1.
+    if mod_pow_wide(g, x, m) == h {
+        Some((x, order))
+    } else {
+        None
+    }
+}
+
+fn mod_log<T: PrimitiveUnsigned>(h: T, g: T, m: T) -> Option<T> {
+    assert!(h < m, "h must be reduced mod m, but {} >= {}", h, m);
+    assert!(g < m, "g must be reduced mod m, but {} >= {}", g, m);
+    // Write g^x ≡ h mod m as c g^(x - offset) ≡ h mod m, and divide out common factors of g and m
+    // until g is coprime to m. Solutions smaller than the final offset are checked along the way.
+    let mut m = m;
+    let mut h = h;
+    let mut c = T::ONE % m;
+    let mut offset = T::ZERO;
+    loop {
+        if h == c {
+            return Some(offset);
+        }
+        let d = g.gcd(m);
+        if d == T::ONE {
+            break;
+        }
+        if !h.divisible_by(d) {
+            return None;
+        }
+        h /= d;
+        m /= d;
+        offset += T::ONE;
+        c = (c % m).mod_mul(g / d % m, m);
+    }
+    // Now m > 1 and c is coprime to m, so it is enough to solve g^y ≡ h c^(-1) mod m.
+    let g = g % m;
+    let h = h.mod_mul(c.mod_inverse(m).unwrap(), m);
+    let mut x = T::ZERO;
+    let mut order = T::ONE;
+    for (p, k) in m.factor() {
+        let p_k = p.pow(k);
+        let (x_p, order_p) = mod_log_prime_power(g % p_k, h % p_k, p, k, p_k)?;
+        (x, order) = chinese_remainder_pair(x, order, x_p, order_p)?;
+    }
+    Some(x + offset)
+}
+
+macro_rules! impl_mod_log {
+    ($t:ident) => {
+        impl ModLog<$t> for $t {
+            type Output = $t;
+
+            /// Computes the discrete logarithm of a number modulo another number $m$. Assumes the
+            /// number and the base are already reduced modulo $m$.
+            ///
+            /// Returns the smallest $x \geq 0$ such that $g^x \equiv h \mod m$, where $h$ is
+            /// `self` and $g$ is `base`, or `None` if there is no such $x$.
+            ///
+            /// Common factors of $g$ and $m$ are divided out first. The modulus is then factored,
+            /// and the order of $g$ modulo each prime power is found from the Carmichael function.
+            /// The Pohlig-Hellman algorithm reduces each problem to problems in groups of prime
+            /// order, which are solved using baby-step giant-step or, if the prime is large,
+            /// Pollard's rho. Finally, the solutions are combined using the Chinese remainder
+            /// theorem.
+            ///
+            /// # Worst-case complexity
+            /// The running time is dominated by the factorization of $m$ and of $p - 1$ for each
+            /// prime $p$ dividing $m$, and by the square root of the largest prime factor of the
+            /// order of $g$.
+            ///
+            /// # Panics
+            /// Panics if `self` or `base` is greater than or equal to `m`.
+            ///
+            /// # Examples
+            /// See [here](super::mod_log#mod_log).
+            #[inline]
+            fn mod_log(self, base: $t, m: $t) -> Option<$t> {
+                mod_log(self, base, m)
+            }
+        }
+    };
+}
+apply_to_unsigneds!(impl_mod_log);
@@ -0,0 +1,309 @@
+use crate::num::arithmetic::traits::{ModSqrt, ModSqrtFromFactorization, Parity};
+use crate::num::basic::unsigneds::PrimitiveUnsigned;
+use crate::num::factorization::is_prime::mod_pow_wide;
+
+const POWER_OVERFLOW_MESSAGE: &str = "The modulus is not representable";
+
+// Returns the smaller of the two square roots $r$ and $p - r$.
+fn smaller_root<T: PrimitiveUnsigned>(r: T, p: T) -> T {
+    if r == T::ZERO {
+        r
+    } else {
+        let s = p - r;
+        if s < r {
+            s
+        } else {
+            r
+        }
+    }
+}
+
+// Finds the smallest quadratic non-residue modulo `p`. Assumes that `p` is an odd prime.
+//
+// # Worst-case complexity
+// Constant time and additional memory.
+fn smallest_non_residue<T: PrimitiveUnsigned>(p: T) -> T {
+    let mut z = T::TWO;
+    while z.legendre_symbol(p) != -1 {
+        z += T::ONE;
+    }
+    z
+}
+
+// Computes a square root of `x` modulo the odd prime `p` using the Tonelli-Shanks algorithm.
+// Assumes that `x` is a nonzero quadratic residue and that `x < p`. If $p - 1 = 2^s q$ with $q$
+// odd, the algorithm needs $O(s^2)$ multiplications after the initial exponentiations.
+//
+// # Worst-case complexity
+// Constant time and additional memory.
+fn tonelli_shanks_helper<T: PrimitiveUnsigned>(x: T, p: T) -> T {
+    let p_minus_1 = p - T::ONE;
+    let mut m = p_minus_1.trailing_zeros();
+    let q = p_minus_1 >> m;
+    if m == 1 {
+        // p ≡ 3 mod 4
+        return mod_pow_wide(x, (p >> 2) + T::ONE, p);
+    }
+    let mut c = mod_pow_wide(smallest_non_residue(p), q, p);
+    let mut t = mod_pow_wide(x, q, p);
+    let mut r = mod_pow_wide(x, (q >> 1) + T::ONE, p);
+    while t != T::ONE {
+        // Find the least i such that t^(2^i) = 1. Since t has order dividing 2^(m - 1), 0 < i < m.
+        let mut i = 1;
+        let mut t_power = t.mod_square(p);
+        while t_power != T::ONE {
+            t_power.mod_square_assign(p);
+            i += 1;
+        }
+        let mut b = c;
+        for _ in 0..m - i - 1 {
+            b.mod_square_assign(p);
+        }
+        m = i;
+        c = b.mod_square(p);
+        t.mod_mul_assign(c, p);
+        r.mod_mul_assign(b, p);
+    }
+    r
+}
+
+// Computes a square root of `x` modulo the odd prime `p` using Cipolla's algorithm. Assumes that
+// `x` is a nonzero quadratic residue and that `x < p`. The algorithm finds an $a$ such that $a^2 -
+// x$ is a non-residue, and then computes $(a + \omega)^{(p + 1)/2}$ in $\mathbb{F}_{p^2} =
+// \mathbb{F}_p(\omega)$, where $\omega^2 = a^2 - x$.
+//
+// # Worst-case complexity
+// Constant time and additional memory.
+fn cipolla_helper<T: PrimitiveUnsigned>(x: T, p: T) -> T {
+    let mut a = T::ONE;
+    let w = loop {
+        let w = a.mod_square(p).mod_sub(x, p);
+        if w.legendre_symbol(p) == -1 {
+            break w;
+        }
+        a += T::ONE;
+    };
+    // Multiplies u_1 + v_1 ω by u_2 + v_2 ω.
+    let mul = |(u_1, v_1): (T, T), (u_2, v_2): (T, T)| {
+        (
+            u_1.mod_mul(u_2, p)
+                .mod_add(v_1.mod_mul(v_2, p).mod_mul(w, p), p),
+            u_1.mod_mul(v_2, p).mod_add(u_2.mod_mul(v_1, p), p),
+        )
+    };
+    let exp: T = (p >> 1) + T::ONE;
+    let mut out = (T::ONE, T::ZERO);
+    for bit in exp.bits().rev() {
+        out = mul(out, out);
+        if bit {
+            out = mul(out, (a, T::ONE));
+        }
+    }
+    out.0
+}
+
+pub_test! {mod_sqrt_tonelli_shanks<T: PrimitiveUnsigned>(x: T, p: T) -> Option<T> {
+    assert!(x < p);
+    if x == T::ZERO || p == T::TWO {
+        Some(x)
+    } else if x.legendre_symbol(p) == -1 {
+        None
+    } else {
+        Some(smaller_root(tonelli_shanks_helper(x, p), p))
+    }
+}}
+
+pub_test! {mod_sqrt_cipolla<T: PrimitiveUnsigned>(x: T, p: T) -> Option<T> {
+    assert!(x < p);
+    if x == T::ZERO || p == T::TWO {
+        Some(x)
+    } else if x.legendre_symbol(p) == -1 {
+        None
+    } else {
+        Some(smaller_root(cipolla_helper(x, p), p))
+    }
+}}
+
+// Tonelli-Shanks needs $O(s^2)$ multiplications, where $2^s$ is the largest power of 2 dividing $p
+// - 1$, and Cipolla's algorithm needs about $6 \log_2 p$. Tonelli-Shanks is faster unless $s$ is
+// large.
+fn mod_sqrt_prime<T: PrimitiveUnsigned>(x: T, p: T) -> Option<T> {
+    let s = (p - T::ONE).trailing_zeros();
+    if s * s <= p.significant_bits() << 2 {
+        mod_sqrt_tonelli_shanks(x, p)
+    } else {
+        mod_sqrt_cipolla(x, p)
+    }
+}
+
+// Computes a square root of the unit `y` modulo `p^e`, where `p` is prime and `y < p^e`.
+fn mod_sqrt_unit_prime_power<T: PrimitiveUnsigned>(y: T, p: T, e: u64) -> Option<T> {
+    if p == T::TWO {
+        // Odd squares are 1 mod 8. A root mod 2^k is lifted to a root mod 2^(k + 1) by adding
+        // 2^(k - 1) if necessary.
+        return match e {
+            1 => Some(T::ONE),
+            2 => {
+                if y.mod_power_of_2(2) == T::ONE {
+                    Some(T::ONE)
+                } else {
+                    None
+                }
+            }
+            _ => {
+                if y.mod_power_of_2(3) != T::ONE {
+                    return None;
+                }
+                let mut r = T::ONE;
+                for k in 3..e {
+                    if r.wrapping_mul(r).wrapping_sub(y).mod_power_of_2(k + 1) != T::ZERO {
+                        r += T::power_of_2(k - 1);
+                    }
+                }
+                // The roots are ±r mod 2^(e - 1); r < 2^(e - 1) here.
+                Some(smaller_root(r, T::power_of_2(e - 1)))
+            }
+        };
+    }
+    let mut r = mod_sqrt_prime(y % p, p)?;
+    // Hensel lifting: if r^2 ≡ y mod p^k, then r - (r^2 - y) / (2r) is a root mod p^(2k).
+    let mut k = 1;
+    while k < e {
+        k = core::cmp::min(k << 1, e);
+        let modulus = p.pow(k);
+        let y = y % modulus;
+        let inverse = r.mod_add(r, modulus).mod_inverse(modulus).unwrap();
+        let correction = r
+            .mod_square(modulus)
+            .mod_sub(y, modulus)
+            .mod_mul(inverse, modulus);
+        r.mod_sub_assign(correction, modulus);
+    }
+    Some(smaller_root(r, p.pow(e)))
+}
+
+fn mod_sqrt_prime_power<T: PrimitiveUnsigned>(x: T, p: T, e: u64) -> Option<T> {
+    assert_ne!(e, 0);
+    let modulus = p.checked_pow(e).expect(POWER_OVERFLOW_MESSAGE);
+    assert!(x < modulus);
+    if x == T::ZERO {
+        return Some(T::ZERO);
+    }
+    // Write x = p^v y, where y is not divisible by p. Since x < p^e, v < e.
+    let mut v = 0;
+    let mut y = x;
+    while y.divisible_by(p) {
+        y /= p;
+        v += 1;
+    }
+    if v.odd() {
+        return None;
+    }
+    Some(mod_sqrt_unit_prime_power(y, p, e - v)? * p.pow(v >> 1))
+}
+
+fn mod_sqrt_from_factorization<T: PrimitiveUnsigned>(x: T, factors: &[(T, u64)]) -> Option<T> {
+    let m = factors.iter().fold(T::ONE, |acc, &(p, e)| {
+        acc.checked_mul(p.checked_pow(e).expect(POWER_OVERFLOW_MESSAGE))
+            .expect(POWER_OVERFLOW_MESSAGE)
+    });
+    assert!(x < m);
+    // Combine the roots modulo each prime power using the Chinese remainder theorem.
+    let mut root = T::ZERO;
+    for &(p, e) in factors {
+        let q = p.pow(e);
+        let r = mod_sqrt_prime_power(x % q, p, e)?;
+        let cofactor = m / q;
+        let inverse = (cofactor % q).mod_inverse(q).unwrap();
+        root.mod_add_assign(r.mod_mul(inverse, q) * cofactor, m);
+    }
+    Some(root)
+}
+
+macro_rules! impl_mod_sqrt {
+    ($t:ident) => {
+        impl ModSqrt<$t> for $t {
+            type Output = $t;
+
+            /// Finds a square root of a number modulo a prime $p$. Assumes the number is already
+            /// reduced modulo $p$.
+            ///
+            /// Returns `None` if the number is not a square modulo $p$. Otherwise, of the two
+            /// square roots $r$ and $p - r$, the smaller is returned. Whether $p$ is prime is not
+            /// checked.
+            ///
+            /// Depending on the power of 2 dividing $p - 1$, either the Tonelli-Shanks algorithm
+            /// or Cipolla's algorithm is used.
+            ///
+            /// $f(x, p) = \min\\{r, p - r\\}$, where $r^2 \equiv x \mod p$.
+            ///
+            /// # Worst-case complexity
+            /// Constant time and additional memory.
+            ///
+            /// # Panics
+            /// Panics if `self` is greater than or equal to `p`.
+            ///
+            /// # Examples
+            /// See [here](super::mod_sqrt#mod_sqrt).
+            #[inline]
+            fn mod_sqrt(self, p: $t) -> Option<$t> {
+                mod_sqrt_prime(self, p)
+            }
+
+            /// Finds a square root of a number modulo $p^e$, where $p$ is prime. Assumes the
+            /// number is already reduced modulo $p^e$.
+            ///
+            /// Returns `None` if the number is not a square modulo $p^e$. Otherwise, the smallest
+            /// square root is returned. A square root modulo $p$ is found and then lifted to a
+            /// square root modulo $p^e$ using Hensel's lemma. Whether $p$ is prime is not checked.
+            ///
+            /// # Worst-case complexity
+            /// Constant time and additional memory.
+            ///
+            /// # Panics
+            /// Panics if `e` is 0, if $p^e$ is not representable, or if `self` is greater than or
+            /// equal to $p^e$.
+            ///
+            /// # Examples
+            /// See [here](super::mod_sqrt#mod_sqrt_prime_power).
+            #[inline]
+            fn mod_sqrt_prime_power(self, p: $t, e: u64) -> Option<$t> {
+                mod_sqrt_prime_power(self, p, e)
+            }
+        }
+
+        impl ModSqrtFromFactorization for $t {
+            type Output = $t;
+
+            /// Finds a square root of a number modulo $m$, given the prime factorization of $m$.
+            /// Assumes the number is already reduced modulo $m$.
+            ///
+            /// `factors` must be the prime factorization of $m$, in the format returned by
+            /// [`factor`](crate::num::factorization::traits::Factor::factor); this is not checked.
+            /// The empty factorization represents 1.
+            ///
+            /// Returns `None` if the number is not a square modulo $m$. Square roots modulo each
+            /// prime power dividing $m$ are found using
+            /// [`mod_sqrt_prime_power`](ModSqrt::mod_sqrt_prime_power) and then combined using
+            /// the Chinese remainder theorem.
+            ///
+            /// # Worst-case complexity
+            /// $T(n) = O(n)$
+            ///
+            /// $M(n) = O(1)$
+            ///
+            /// where $T$ is time, $M$ is additional memory, and $n$ is `factors.len()`.
+            ///
+            /// # Panics
+            /// Panics if $m$ is not representable or if `self` is greater than or equal to $m$.
+            ///
+            /// # Examples
+            /// See [here](super::mod_sqrt#mod_sqrt_from_factorization).
+            #[inline]
+            fn mod_sqrt_from_factorization(self, factors: &[($t, u64)]) -> Option<$t> {
+                mod_sqrt_from_factorization(self, factors)
+            }
+        }
+    };
+}
+apply_to_unsigneds!(impl_mod_sqrt);
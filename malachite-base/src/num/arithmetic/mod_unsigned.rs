@@ -0,0 +1,352 @@
+use crate::num::arithmetic::traits::{
+    ModAdd, ModAddAssign, ModInverse, ModIsReduced, ModMul, ModMulAssign, ModNeg, ModPow,
+    ModPowAssign, ModSub, ModSubAssign, Pow, PowAssign,
+};
+use std::fmt::{Debug, Display, Formatter, Result};
+use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+macro_rules! impl_mod_unsigned {
+    ($name:ident, $t:ident) => {
+        /// A residue modulo $m$, where $m$ is an unsigned primitive integer given as a const
+        /// generic parameter.
+        ///
+        /// The value is always reduced modulo $m$; this is checked when a residue is created. The
+        /// arithmetic operators work modulo $m$. Since the modulus is part of the type, residues
+        /// with different moduli cannot be combined.
+        #[derive(Clone, Copy, Eq, Hash, PartialEq)]
+        pub struct $name<const M: $t>($t);
+
+        impl<const M: $t> $name<M> {
+            /// Creates a residue from a value that is already reduced modulo $m$.
+            ///
+            /// # Worst-case complexity
+            /// Constant time and additional memory.
+            ///
+            /// # Panics
+            /// Panics if $m$ is zero or if `x` is greater than or equal to $m$.
+            ///
+            /// # Examples
+            /// See [here](super::mod_unsigned#new).
+            pub fn new(x: $t) -> $name<M> {
+                assert!(x.mod_is_reduced(&M), "{} is not reduced modulo {}", x, M);
+                $name(x)
+            }
+
+            /// Creates a residue by reducing a value modulo $m$.
+            ///
+            /// # Worst-case complexity
+            /// Constant time and additional memory.
+            ///
+            /// # Panics
+            /// Panics if $m$ is zero.
+            ///
+            /// # Examples
+            /// See [here](super::mod_unsigned#reduce).
+            #[inline]
+            pub const fn reduce(x: $t) -> $name<M> {
+                $name(x % M)
+            }
+
+            /// Returns the value of a residue, which is less than $m$.
+            ///
+            /// # Worst-case complexity
+            /// Constant time and additional memory.
+            ///
+            /// # Examples
+            /// See [here](super::mod_unsigned#value).
+            #[inline]
+            pub const fn value(self) -> $t {
+                self.0
+            }
+
+            /// Returns the modulus $m$.
+            ///
+            /// # Worst-case complexity
+            /// Constant time and additional memory.
+            ///
+            /// # Examples
+            /// See [here](super::mod_unsigned#modulus).
+            #[inline]
+            pub const fn modulus() -> $t {
+                M
+            }
+
+            /// Computes the multiplicative inverse of a residue.
+            ///
+            /// Returns `None` if the value and $m$ are not coprime. Modulo 1, the inverse of 0 is
+            /// 0.
+            ///
+            /// $f(x) = y$, where $y < m$ and $xy \equiv 1 \mod m$.
+            ///
+            /// # Worst-case complexity
+            /// $T(n) = O(n^2)$
+            ///
+            /// $M(n) = O(n)$
+            ///
+            /// where $T$ is time, $M$ is additional memory, and $n$ is `M.significant_bits()`.
+            ///
+            /// # Examples
+            /// See [here](super::mod_unsigned#inverse).
+            pub fn inverse(self) -> Option<$name<M>> {
+                if M == 1 {
+                    Some(self)
+                } else if self.0 == 0 {
+                    None
+                } else {
+                    self.0.mod_inverse(M).map($name)
+                }
+            }
+        }
+
+        impl<const M: $t> Add for $name<M> {
+            type Output = $name<M>;
+
+            /// Adds two residues modulo $m$.
+            ///
+            /// $f(x, y) = z$, where $z < m$ and $x + y \equiv z \mod m$.
+            ///
+            /// # Worst-case complexity
+            /// Constant time and additional memory.
+            ///
+            /// # Examples
+            /// See [here](super::mod_unsigned#add).
+            #[inline]
+            fn add(self, other: $name<M>) -> $name<M> {
+                $name(self.0.mod_add(other.0, M))
+            }
+        }
+
+        impl<const M: $t> AddAssign for $name<M> {
+            /// Adds a residue to another residue modulo $m$, in place.
+            ///
+            /// $x \gets z$, where $z < m$ and $x + y \equiv z \mod m$.
+            ///
+            /// # Worst-case complexity
+            /// Constant time and additional memory.
+            ///
+            /// # Examples
+            /// See [here](super::mod_unsigned#add_assign).
+            #[inline]
+            fn add_assign(&mut self, other: $name<M>) {
+                self.0.mod_add_assign(other.0, M);
+            }
+        }
+
+        impl<const M: $t> Sub for $name<M> {
+            type Output = $name<M>;
+
+            /// Subtracts a residue by another residue modulo $m$.
+            ///
+            /// $f(x, y) = z$, where $z < m$ and $x - y \equiv z \mod m$.
+            ///
+            /// # Worst-case complexity
+            /// Constant time and additional memory.
+            ///
+            /// # Examples
+            /// See [here](super::mod_unsigned#sub).
+            #[inline]
+            fn sub(self, other: $name<M>) -> $name<M> {
+                $name(self.0.mod_sub(other.0, M))
+            }
+        }
+
+        impl<const M: $t> SubAssign for $name<M> {
+            /// Subtracts a residue by another residue modulo $m$, in place.
+            ///
+            /// $x \gets z$, where $z < m$ and $x - y \equiv z \mod m$.
+            ///
+            /// # Worst-case complexity
+            /// Constant time and additional memory.
+            ///
+            /// # Examples
+            /// See [here](super::mod_unsigned#sub_assign).
+            #[inline]
+            fn sub_assign(&mut self, other: $name<M>) {
+                self.0.mod_sub_assign(other.0, M);
+            }
+        }
+
+        impl<const M: $t> Mul for $name<M> {
+            type Output = $name<M>;
+
+            /// Multiplies two residues modulo $m$.
+            ///
+            /// $f(x, y) = z$, where $z < m$ and $xy \equiv z \mod m$.
+            ///
+            /// # Worst-case complexity
+            /// Constant time and additional memory.
+            ///
+            /// # Examples
+            /// See [here](super::mod_unsigned#mul).
+            #[inline]
+            fn mul(self, other: $name<M>) -> $name<M> {
+                $name(self.0.mod_mul(other.0, M))
+            }
+        }
+
+        impl<const M: $t> MulAssign for $name<M> {
+            /// Multiplies a residue by another residue modulo $m$, in place.
+            ///
+            /// $x \gets z$, where $z < m$ and $xy \equiv z \mod m$.
+            ///
+            /// # Worst-case complexity
+            /// Constant time and additional memory.
+            ///
+            /// # Examples
+            /// See [here](super::mod_unsigned#mul_assign).
+            #[inline]
+            fn mul_assign(&mut self, other: $name<M>) {
+                self.0.mod_mul_assign(other.0, M);
+            }
+        }
+
+        impl<const M: $t> Div for $name<M> {
+            type Output = $name<M>;
+
+            /// Divides a residue by another residue modulo $m$, by multiplying by the inverse of
+            /// the second residue.
+            ///
+            /// $f(x, y) = z$, where $z < m$ and $yz \equiv x \mod m$.
+            ///
+            /// # Worst-case complexity
+            /// $T(n) = O(n^2)$
+            ///
+            /// $M(n) = O(n)$
+            ///
+            /// where $T$ is time, $M$ is additional memory, and $n$ is `M.significant_bits()`.
+            ///
+            /// # Panics
+            /// Panics if `other` is not invertible modulo $m$.
+            ///
+            /// # Examples
+            /// See [here](super::mod_unsigned#div).
+            fn div(self, other: $name<M>) -> $name<M> {
+                let inverse = other
+                    .inverse()
+                    .expect("Division by a non-invertible residue");
+                $name(self.0.mod_mul(inverse.0, M))
+            }
+        }
+
+        impl<const M: $t> DivAssign for $name<M> {
+            /// Divides a residue by another residue modulo $m$, in place, by multiplying by the
+            /// inverse of the second residue.
+            ///
+            /// $x \gets z$, where $z < m$ and $yz \equiv x \mod m$.
+            ///
+            /// # Worst-case complexity
+            /// $T(n) = O(n^2)$
+            ///
+            /// $M(n) = O(n)$
+            ///
+            /// where $T$ is time, $M$ is additional memory, and $n$ is `M.significant_bits()`.
+            ///
+            /// # Panics
+            /// Panics if `other` is not invertible modulo $m$.
+            ///
+            /// # Examples
+            /// See [here](super::mod_unsigned#div_assign).
+            fn div_assign(&mut self, other: $name<M>) {
+                let inverse = other
+                    .inverse()
+                    .expect("Division by a non-invertible residue");
+                self.0.mod_mul_assign(inverse.0, M);
+            }
+        }
+
+        impl<const M: $t> Neg for $name<M> {
+            type Output = $name<M>;
+
+            /// Negates a residue modulo $m$.
+            ///
+            /// $f(x) = y$, where $y < m$ and $-x \equiv y \mod m$.
+            ///
+            /// # Worst-case complexity
+            /// Constant time and additional memory.
+            ///
+            /// # Examples
+            /// See [here](super::mod_unsigned#neg).
+            #[inline]
+            fn neg(self) -> $name<M> {
+                $name(self.0.mod_neg(M))
+            }
+        }
+
+        impl<const M: $t> Pow<u64> for $name<M> {
+            type Output = $name<M>;
+
+            /// Raises a residue to a power modulo $m$.
+            ///
+            /// $f(x, n) = y$, where $y < m$ and $x^n \equiv y \mod m$.
+            ///
+            /// # Worst-case complexity
+            /// $T(n) = O(n)$
+            ///
+            /// $M(n) = O(1)$
+            ///
+            /// where $T$ is time, $M$ is additional memory, and $n$ is `exp.significant_bits()`.
+            ///
+            /// # Examples
+            /// See [here](super::mod_unsigned#pow).
+            #[inline]
+            fn pow(self, exp: u64) -> $name<M> {
+                $name(self.0.mod_pow(exp, M))
+            }
+        }
+
+        impl<const M: $t> PowAssign<u64> for $name<M> {
+            /// Raises a residue to a power modulo $m$, in place.
+            ///
+            /// $x \gets y$, where $y < m$ and $x^n \equiv y \mod m$.
+            ///
+            /// # Worst-case complexity
+            /// $T(n) = O(n)$
+            ///
+            /// $M(n) = O(1)$
+            ///
+            /// where $T$ is time, $M$ is additional memory, and $n$ is `exp.significant_bits()`.
+            ///
+            /// # Examples
+            /// See [here](super::mod_unsigned#pow_assign).
+            #[inline]
+            fn pow_assign(&mut self, exp: u64) {
+                self.0.mod_pow_assign(exp, M);
+            }
+        }
+
+        impl<const M: $t> Display for $name<M> {
+            /// Converts a residue to a [`String`], in the form `"x mod m"`.
+            ///
+            /// # Worst-case complexity
+            /// Constant time and additional memory.
+            ///
+            /// # Examples
+            /// See [here](super::mod_unsigned#fmt).
+            fn fmt(&self, f: &mut Formatter) -> Result {
+                write!(f, "{} mod {}", self.0, M)
+            }
+        }
+
+        impl<const M: $t> Debug for $name<M> {
+            /// Converts a residue to a [`String`], in the form `"x mod m"`.
+            ///
+            /// This is the same as the [`Display::fmt`] implementation.
+            ///
+            /// # Worst-case complexity
+            /// Constant time and additional memory.
+            ///
+            /// # Examples
+            /// See [here](super::mod_unsigned#fmt).
+            #[inline]
+            fn fmt(&self, f: &mut Formatter) -> Result {
+                Display::fmt(self, f)
+            }
+        }
+    };
+}
+impl_mod_unsigned!(ModU8, u8);
+impl_mod_unsigned!(ModU16, u16);
+impl_mod_unsigned!(ModU32, u32);
+impl_mod_unsigned!(ModU64, u64);
+impl_mod_unsigned!(ModU128, u128);
+impl_mod_unsigned!(ModUsize, usize);
@@ -0,0 +1,97 @@
+use crate::num::arithmetic::traits::{Moebius, Parity};
+use crate::num::factorization::traits::Factor;
+
+fn moebius_from_factorization<T>(factors: &[(T, u64)]) -> i8 {
+    if factors.iter().any(|&(_, e)| e > 1) {
+        0
+    } else if factors.len().even() {
+        1
+    } else {
+        -1
+    }
+}
+
+macro_rules! impl_moebius {
+    ($u:ident, $s:ident) => {
+        impl Moebius for $u {
+            /// Computes the Möbius function of a number.
+            ///
+            /// $\mu(n)$ is 0 if $n$ is divisible by the square of a prime. Otherwise, it is 1 if
+            /// $n$ has an even number of prime factors and $-1$ if it has an odd number.
+            ///
+            /// # Worst-case complexity
+            /// Constant time and additional memory.
+            ///
+            /// # Panics
+            /// Panics if `self` is 0.
+            ///
+            /// # Examples
+            /// See [here](super::moebius#moebius).
+            #[inline]
+            fn moebius(&self) -> i8 {
+                moebius_from_factorization(&self.factor())
+            }
+
+            /// Computes the Möbius function of a number, given its prime factorization.
+            ///
+            /// `factors` must be the prime factorization of the number, in the format returned
+            /// by [`factor`](Factor::factor); this is not checked. The empty factorization
+            /// represents 1.
+            ///
+            /// # Worst-case complexity
+            /// $T(n) = O(n)$
+            ///
+            /// $M(n) = O(1)$
+            ///
+            /// where $T$ is time, $M$ is additional memory, and $n$ is `factors.len()`.
+            ///
+            /// # Examples
+            /// See [here](super::moebius#moebius_from_factorization).
+            #[inline]
+            fn moebius_from_factorization(factors: &[($u, u64)]) -> i8 {
+                moebius_from_factorization(factors)
+            }
+        }
+
+        impl Moebius for $s {
+            /// Computes the Möbius function of the absolute value of a number.
+            ///
+            /// See the unsigned implementation for details.
+            ///
+            /// # Worst-case complexity
+            /// Constant time and additional memory.
+            ///
+            /// # Panics
+            /// Panics if `self` is 0.
+            ///
+            /// # Examples
+            /// See [here](super::moebius#moebius).
+            #[inline]
+            fn moebius(&self) -> i8 {
+                self.unsigned_abs().moebius()
+            }
+
+            /// Computes the Möbius function of the absolute value of a number, given the prime
+            /// factorization of the absolute value.
+            ///
+            /// `factors` must be the prime factorization of the number's absolute value, in the
+            /// format returned by [`factor`](Factor::factor); this is not checked. The empty
+            /// factorization represents 1.
+            ///
+            /// # Worst-case complexity
+            /// $T(n) = O(n)$
+            ///
+            /// $M(n) = O(1)$
+            ///
+            /// where $T$ is time, $M$ is additional memory, and $n$ is `factors.len()`.
+            ///
+            /// # Examples
+            /// See [here](super::moebius#moebius_from_factorization).
+            #[inline]
+            fn moebius_from_factorization(factors: &[($s, u64)]) -> i8 {
+                moebius_from_factorization(factors)
+            }
+        }
+    };
+}
+apply_to_unsigned_signed_pairs!(impl_moebius);
@@ -0,0 +1,105 @@
+use crate::num::arithmetic::traits::{CheckedMultinomial, Multinomial};
+use crate::num::basic::unsigneds::PrimitiveUnsigned;
+
+// Uses
+//
+// $$
+// \binom{k_1 + k_2 + \cdots + k_m}{k_1, k_2, \ldots, k_m} =
+// \prod_{i=1}^m \binom{k_1 + k_2 + \cdots + k_i}{k_i}.
+// $$
+//
+// Every factor is at least 1, so every partial product is at most the result. The partial sums are
+// accumulated as `u128`s, which cannot overflow because there are fewer than $2^{64}$ of them.
+fn checked_multinomial<T: PrimitiveUnsigned>(ks: &[u64]) -> Option<T> {
+    let mut product = T::ONE;
+    let mut sum = 0u128;
+    for &k in ks {
+        if k == 0 {
+            continue;
+        }
+        let previous_sum = sum;
+        sum += u128::from(k);
+        if previous_sum != 0 {
+            // $\binom{s}{k} \geq s$ when $0 < k < s$, so if $s$ doesn't fit, neither does the
+            // result.
+            product = product.checked_mul(T::checked_binomial_coefficient(
+                T::try_from(sum).ok()?,
+                T::try_from(k).ok()?,
+            )?)?;
+        }
+    }
+    Some(product)
+}
+
+macro_rules! impl_multinomial {
+    ($t:ident) => {
+        impl CheckedMultinomial for $t {
+            /// Computes a multinomial coefficient.
+            ///
+            /// The multinomial coefficient $\binom{n}{k_1, k_2, \ldots, k_m}$, where
+            /// $n = k_1 + k_2 + \cdots + k_m$, counts the ways of dividing a set of $n$ elements
+            /// into $m$ labeled subsets of sizes $k_1, k_2, \ldots, k_m$.
+            ///
+            /// If the output is too large to be represented, the function returns `None`.
+            ///
+            /// $$
+            /// f(k_1, k_2, \ldots, k_m) = \\begin{cases}
+            ///     \operatorname{Some}(\binom{n}{k_1, k_2, \ldots, k_m}) & \text{if}
+            ///         \\quad \binom{n}{k_1, k_2, \ldots, k_m} < 2^W, \\\\
+            ///     \operatorname{None} & \text{if}
+            ///         \\quad \binom{n}{k_1, k_2, \ldots, k_m} \geq 2^W,
+            /// \\end{cases}
+            /// $$
+            /// where $W$ is `Self::WIDTH`.
+            ///
+            /// # Worst-case complexity
+            /// $T(n) = O(n)$
+            ///
+            /// $M(n) = O(1)$
+            ///
+            /// where $T$ is time, $M$ is additional memory, and $n$ is `ks.len()`.
+            ///
+            /// # Examples
+            /// See [here](super::multinomial#checked_multinomial).
+            #[inline]
+            fn checked_multinomial(ks: &[u64]) -> Option<$t> {
+                checked_multinomial(ks)
+            }
+        }
+
+        impl Multinomial for $t {
+            /// Computes a multinomial coefficient.
+            ///
+            /// The multinomial coefficient $\binom{n}{k_1, k_2, \ldots, k_m}$, where
+            /// $n = k_1 + k_2 + \cdots + k_m$, counts the ways of dividing a set of $n$ elements
+            /// into $m$ labeled subsets of sizes $k_1, k_2, \ldots, k_m$.
+            ///
+            /// If the output is too large to be represented, the function panics. For a function
+            /// that returns `None` instead, try
+            /// [`checked_multinomial`](CheckedMultinomial::checked_multinomial).
+            ///
+            /// $$
+            /// f(k_1, k_2, \ldots, k_m) = \binom{n}{k_1, k_2, \ldots, k_m} =
+            /// \frac{n!}{k_1!k_2!\cdots k_m!}.
+            /// $$
+            ///
+            /// # Worst-case complexity
+            /// $T(n) = O(n)$
+            ///
+            /// $M(n) = O(1)$
+            ///
+            /// where $T$ is time, $M$ is additional memory, and $n$ is `ks.len()`.
+            ///
+            /// # Panics
+            /// Panics if the output is too large to be represented.
+            ///
+            /// # Examples
+            /// See [here](super::multinomial#multinomial).
+            #[inline]
+            fn multinomial(ks: &[u64]) -> $t {
+                $t::checked_multinomial(ks).unwrap()
+            }
+        }
+    };
+}
+apply_to_unsigneds!(impl_multinomial);
@@ -0,0 +1,165 @@
+use crate::num::arithmetic::traits::{MultiplicativeOrder, PrimitiveRoot};
+use crate::num::basic::unsigneds::PrimitiveUnsigned;
+use crate::num::factorization::is_prime::mod_pow_wide;
+use std::collections::BTreeMap;
+
+// Returns the Carmichael function of a number, together with its prime factorization, given the
+// prime factorization of the number. The factorization of $\lambda(m)$ is assembled from the
+// factorizations of $p - 1$ for the odd primes $p$ dividing $m$, so $\lambda(m)$ itself is never
+// factored.
+pub(crate) fn carmichael_lambda_and_factors<T: PrimitiveUnsigned>(
+    factors: &[(T, u64)],
+) -> (T, Vec<(T, u64)>) {
+    let mut lambda_factors = BTreeMap::new();
+    let mut insert = |q: T, e: u64| {
+        let exponent = lambda_factors.entry(q).or_insert(0);
+        *exponent = (*exponent).max(e);
+    };
+    for &(p, k) in factors {
+        if p == T::TWO {
+            match k {
+                1 => {}
+                2 => insert(T::TWO, 1),
+                _ => insert(T::TWO, k - 2),
+            }
+        } else {
+            for (q, e) in (p - T::ONE).factor() {
+                insert(q, e);
+            }
+            if k > 1 {
+                insert(p, k - 1);
+            }
+        }
+    }
+    let lambda = lambda_factors
+        .iter()
+        .fold(T::ONE, |acc, (&q, &e)| acc * q.pow(e));
+    (lambda, lambda_factors.into_iter().collect())
+}
+
+// Returns the order of $g$ modulo $m$, together with its factorization, given the factorization of
+// a multiple of the order. Assumes that $g$ is coprime to $m$.
+pub(crate) fn mod_order_from_multiple<T: PrimitiveUnsigned>(
+    g: T,
+    m: T,
+    multiple: T,
+    factors: &[(T, u64)],
+) -> (T, Vec<(T, u64)>) {
+    let mut order = multiple;
+    let mut order_factors = Vec::with_capacity(factors.len());
+    for &(q, e) in factors {
+        let mut e_out = e;
+        while e_out != 0 && mod_pow_wide(g, order / q, m) == T::ONE {
+            order /= q;
+            e_out -= 1;
+        }
+        if e_out != 0 {
+            order_factors.push((q, e_out));
+        }
+    }
+    (order, order_factors)
+}
+
+fn multiplicative_order<T: PrimitiveUnsigned>(x: T, m: T) -> Option<T> {
+    assert!(x < m, "x must be reduced mod m, but {} >= {}", x, m);
+    if !x.coprime_with(m) {
+        return None;
+    }
+    let (lambda, lambda_factors) = carmichael_lambda_and_factors(&m.factor());
+    Some(mod_order_from_multiple(x, m, lambda, &lambda_factors).0)
+}
+
+fn primitive_root<T: PrimitiveUnsigned>(m: T) -> Option<T> {
+    assert_ne!(m, T::ZERO);
+    if m == T::ONE {
+        return Some(T::ZERO);
+    } else if m == T::TWO {
+        return Some(T::ONE);
+    } else if m == T::wrapping_from(4u8) {
+        return Some(T::wrapping_from(3u8));
+    }
+    // There is a primitive root iff m is 1, 2, 4, p^k, or 2p^k, where p is an odd prime.
+    let factors = m.factor();
+    let odd_factors = if m.even() {
+        if factors[0] != (T::TWO, 1) {
+            return None;
+        }
+        &factors[1..]
+    } else {
+        &factors[..]
+    };
+    if odd_factors.len() != 1 {
+        return None;
+    }
+    // The group of units is cyclic of order lambda(m), so g generates it iff g^(lambda(m) / q) is
+    // not 1 for any prime q dividing lambda(m).
+    let (lambda, lambda_factors) = carmichael_lambda_and_factors(&factors);
+    let exponents: Vec<T> = lambda_factors.iter().map(|&(q, _)| lambda / q).collect();
+    let mut g = T::TWO;
+    loop {
+        if g.coprime_with(m) && exponents.iter().all(|&e| mod_pow_wide(g, e, m) != T::ONE) {
+            return Some(g);
+        }
+        g += T::ONE;
+    }
+}
+
+macro_rules! impl_multiplicative_order {
+    ($t:ident) => {
+        impl MultiplicativeOrder<$t> for $t {
+            type Output = $t;
+
+            /// Computes the multiplicative order of a number modulo another number $m$. Assumes
+            /// the number is already reduced modulo $m$.
+            ///
+            /// Returns the smallest $k > 0$ such that $x^k \equiv 1 \mod m$, or `None` if $x$ is
+            /// not coprime to $m$.
+            ///
+            /// The modulus is factored, and the factorization of $\lambda(m)$, a multiple of the
+            /// order, is assembled from it, where $\lambda$ is the Carmichael function. Each prime
+            /// factor is then divided out of $\lambda(m)$ for as long as the result is still a
+            /// multiple of the order.
+            ///
+            /// # Worst-case complexity
+            /// The running time is dominated by the factorization of $m$ and of $p - 1$ for each
+            /// prime $p$ dividing $m$.
+            ///
+            /// # Panics
+            /// Panics if `self` is greater than or equal to `m`.
+            ///
+            /// # Examples
+            /// See [here](super::multiplicative_order#multiplicative_order).
+            #[inline]
+            fn multiplicative_order(self, m: $t) -> Option<$t> {
+                multiplicative_order(self, m)
+            }
+        }
+
+        impl PrimitiveRoot for $t {
+            /// Finds the smallest primitive root modulo a number $m$.
+            ///
+            /// A primitive root modulo $m$ is a number whose powers are exactly the residues
+            /// coprime to $m$. It exists iff $m$ is 1, 2, 4, $p^k$, or $2p^k$, where $p$ is an odd
+            /// prime; otherwise, `None` is returned. Modulo 1, the primitive root is 0.
+            ///
+            /// Candidates $g = 2, 3, \ldots$ are tried in order; $g$ is a primitive root iff it is
+            /// coprime to $m$ and $g^{\lambda(m)/q} \not\equiv 1 \mod m$ for every prime $q$
+            /// dividing $\lambda(m)$, where $\lambda$ is the Carmichael function.
+            ///
+            /// # Worst-case complexity
+            /// The running time is dominated by the factorization of $m$ and of $p - 1$, where
+            /// $p$ is the odd prime dividing $m$. The smallest primitive root is small in practice.
+            ///
+            /// # Panics
+            /// Panics if `self` is 0.
+            ///
+            /// # Examples
+            /// See [here](super::multiplicative_order#primitive_root).
+            #[inline]
+            fn primitive_root(&self) -> Option<$t> {
+                primitive_root(*self)
+            }
+        }
+    };
+}
+apply_to_unsigneds!(impl_multiplicative_order);
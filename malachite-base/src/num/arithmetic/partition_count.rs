@@ -0,0 +1,106 @@
+use crate::num::arithmetic::traits::{CheckedPartitionCount, Parity, PartitionCount};
+use crate::num::basic::unsigneds::PrimitiveUnsigned;
+use crate::num::conversion::traits::ExactFrom;
+
+// Uses Euler's pentagonal number recurrence,
+//
+// $$
+// p(n) = \sum_{k \geq 1} (-1)^{k+1} \left ( p\left ( n - \frac{k(3k - 1)}{2} \right ) +
+// p\left ( n - \frac{k(3k + 1)}{2} \right ) \right ),
+// $$
+//
+// with wrapping arithmetic, so each $p(i)$ is computed exactly modulo $2^W$ even though the
+// alternating partial sums may not fit. Since $p(i - 1) \leq p(i) \leq 2p(i - 1)$ (removing a part
+// equal to 1 is an injection from the partitions of $i$ containing a 1 to the partitions of
+// $i - 1$, and decrementing the smallest part is an injection from the others), the first $p(i)$
+// that overflows wraps around to a value smaller than $p(i - 1)$, which is how overflow is
+// detected.
+fn checked_partition_count<T: PrimitiveUnsigned>(n: u64) -> Option<T> {
+    let mut ps = vec![T::ONE];
+    for i in 1..=n {
+        let mut p = T::ZERO;
+        for k in 1.. {
+            let g = (k * (3 * k - 1)) >> 1;
+            if g > i {
+                break;
+            }
+            let mut term = ps[usize::exact_from(i - g)];
+            let g = g + k;
+            if g <= i {
+                term.wrapping_add_assign(ps[usize::exact_from(i - g)]);
+            }
+            if k.odd() {
+                p.wrapping_add_assign(term);
+            } else {
+                p.wrapping_sub_assign(term);
+            }
+        }
+        if p < *ps.last().unwrap() {
+            return None;
+        }
+        ps.push(p);
+    }
+    ps.pop()
+}
+
+macro_rules! impl_partition_count {
+    ($t:ident) => {
+        impl CheckedPartitionCount for $t {
+            /// Computes the number of partitions of a number.
+            ///
+            /// A partition of $n$ is a way of writing $n$ as a sum of positive integers, where the
+            /// order of the summands does not matter.
+            ///
+            /// If the output is too large to be represented, the function returns `None`.
+            ///
+            /// $$
+            /// f(n) = \\begin{cases}
+            ///     \operatorname{Some}(p(n)) & \text{if} \\quad p(n) < 2^W, \\\\
+            ///     \operatorname{None} & \text{if} \\quad p(n) \geq 2^W,
+            /// \\end{cases}
+            /// $$
+            /// where $W$ is `Self::WIDTH`.
+            ///
+            /// $p(n) = O(e^{\pi\sqrt{2n/3}}/n)$.
+            ///
+            /// # Worst-case complexity
+            /// Constant time and additional memory.
+            ///
+            /// # Examples
+            /// See [here](super::partition_count#checked_partition_count).
+            #[inline]
+            fn checked_partition_count(n: u64) -> Option<$t> {
+                checked_partition_count(n)
+            }
+        }
+
+        impl PartitionCount for $t {
+            /// Computes the number of partitions of a number.
+            ///
+            /// A partition of $n$ is a way of writing $n$ as a sum of positive integers, where the
+            /// order of the summands does not matter.
+            ///
+            /// If the output is too large to be represented, the function panics. For a function
+            /// that returns `None` instead, try
+            /// [`checked_partition_count`](CheckedPartitionCount::checked_partition_count).
+            ///
+            /// $f(n) = p(n)$.
+            ///
+            /// $p(n) = O(e^{\pi\sqrt{2n/3}}/n)$.
+            ///
+            /// # Worst-case complexity
+            /// Constant time and additional memory.
+            ///
+            /// # Panics
+            /// Panics if the output is too large to be represented.
+            ///
+            /// # Examples
+            /// See [here](super::partition_count#partition_count).
+            #[inline]
+            fn partition_count(n: u64) -> $t {
+                $t::checked_partition_count(n).unwrap()
+            }
+        }
+    };
+}
+apply_to_unsigneds!(impl_partition_count);
@@ -0,0 +1,163 @@
+use crate::num::arithmetic::traits::{
+    IsPerfectPower, Parity, PerfectPowerDecomposition, SquareAssign,
+};
+use crate::num::basic::unsigneds::PrimitiveUnsigned;
+use crate::num::conversion::traits::WrappingFrom;
+use crate::num::factorization::traits::Primes;
+
+// For each small prime $p$, some primes $q \equiv 1 \mod p$. If $n$ is a $p$th power and $q$ does
+// not divide $n$, then $n^{(q-1)/p} \equiv 1 \mod q$. Each $q$ lets through only about $1/p$ of the
+// numbers that are not $p$th powers.
+const PERFECT_POWER_FILTERS: [(u64, &[u8]); 4] = [
+    (2, &[3, 5, 7, 11, 13, 17, 19, 23]),
+    (3, &[7, 13, 19, 31, 37]),
+    (5, &[11, 31, 41, 61]),
+    (7, &[29, 43, 71, 113]),
+];
+
+// Returns whether `n` might be a `p`th power, where `p` is prime and `n` has `twos` trailing zeros.
+// If this returns `false`, `n` is certainly not a `p`th power.
+fn may_be_perfect_power<T: PrimitiveUnsigned>(n: T, twos: u64, p: u64) -> bool {
+    if twos % p != 0 {
+        return false;
+    }
+    PERFECT_POWER_FILTERS
+        .iter()
+        .find(|&&(k, _)| k == p)
+        .map_or(true, |(_, qs)| {
+            qs.iter().all(|&q| {
+                let exponent = (u64::from(q) - 1) / p;
+                let q = T::wrapping_from(q);
+                let r = n % q;
+                r == T::ZERO || r.mod_pow(exponent, q) == T::ONE
+            })
+        })
+}
+
+// Returns a prime $p$ and the $p$th root of `n`, if `n` is a perfect $p$th power for some prime
+// $p$. Assumes `n` is greater than 1.
+fn prime_root<T: PrimitiveUnsigned>(n: T) -> Option<(T, u64)> {
+    let twos = n.trailing_zeros();
+    u64::primes_less_than(&n.significant_bits())
+        .filter(|&p| may_be_perfect_power(n, twos, p))
+        .find_map(|p| n.checked_root(p).map(|root| (root, p)))
+}
+
+pub_test! {perfect_power_decomposition_unsigned<T: PrimitiveUnsigned>(n: T) -> (T, u64) {
+    let mut base = n;
+    let mut exponent = 1;
+    while base > T::ONE {
+        if let Some((root, p)) = prime_root(base) {
+            base = root;
+            exponent *= p;
+        } else {
+            break;
+        }
+    }
+    (base, exponent)
+}}
+
+macro_rules! impl_perfect_power_unsigned {
+    ($t:ident) => {
+        impl IsPerfectPower for $t {
+            /// Determines whether a number is a perfect power.
+            ///
+            /// A number is a perfect power if it equals $b^k$ for some integer $b$ and some $k >
+            /// 1$. In particular, 0 and 1 are perfect powers.
+            ///
+            /// Only prime exponents $k$ less than the bit length of the number need to be checked.
+            /// Most are ruled out by the number's 2-adic valuation and by its residues modulo a
+            /// few small primes; for the rest,
+            /// [`checked_root`](super::traits::CheckedRoot::checked_root) is called.
+            ///
+            /// # Worst-case complexity
+            /// Constant time and additional memory.
+            ///
+            /// # Examples
+            /// See [here](super::perfect_power#is_perfect_power).
+            #[inline]
+            fn is_perfect_power(&self) -> bool {
+                *self <= 1 || prime_root(*self).is_some()
+            }
+        }
+
+        impl PerfectPowerDecomposition for $t {
+            /// Writes a number as a perfect power with the largest possible exponent.
+            ///
+            /// Returns $(b, k)$ such that $b^k$ is the number and $k$ is as large as possible. If
+            /// the number is not a perfect power, $k$ is 1. Since 0 and 1 are $k$th powers for
+            /// every $k$, their decomposition is $(0, 1)$ or $(1, 1)$.
+            ///
+            /// Prime roots are taken for as long as the base is a perfect power; see
+            /// [`is_perfect_power`](IsPerfectPower::is_perfect_power).
+            ///
+            /// # Worst-case complexity
+            /// Constant time and additional memory.
+            ///
+            /// # Examples
+            /// See [here](super::perfect_power#perfect_power_decomposition).
+            #[inline]
+            fn perfect_power_decomposition(&self) -> ($t, u64) {
+                perfect_power_decomposition_unsigned(*self)
+            }
+        }
+    };
+}
+apply_to_unsigneds!(impl_perfect_power_unsigned);
+
+macro_rules! impl_perfect_power_signed {
+    ($u:ident, $s:ident) => {
+        impl IsPerfectPower for $s {
+            /// Determines whether a number is a perfect power.
+            ///
+            /// A number is a perfect power if it equals $b^k$ for some integer $b$ and some $k >
+            /// 1$. In particular, 0, 1, and $-1$ are perfect powers. A negative number is a
+            /// perfect power iff its absolute value is a perfect power with an odd exponent.
+            ///
+            /// # Worst-case complexity
+            /// Constant time and additional memory.
+            ///
+            /// # Examples
+            /// See [here](super::perfect_power#is_perfect_power).
+            #[inline]
+            fn is_perfect_power(&self) -> bool {
+                if *self >= 0 {
+                    self.unsigned_abs().is_perfect_power()
+                } else {
+                    *self == -1 || self.perfect_power_decomposition().1 != 1
+                }
+            }
+        }
+
+        impl PerfectPowerDecomposition for $s {
+            /// Writes a number as a perfect power with the largest possible exponent.
+            ///
+            /// Returns $(b, k)$ such that $b^k$ is the number and $k$ is as large as possible. If
+            /// the number is not a perfect power, $k$ is 1. Since 0, 1, and $-1$ are $k$th powers
+            /// for infinitely many $k$, their decomposition is $(0, 1)$, $(1, 1)$, or $(-1, 1)$.
+            ///
+            /// If the number is negative, $k$ is odd and $b$ is negative.
+            ///
+            /// # Worst-case complexity
+            /// Constant time and additional memory.
+            ///
+            /// # Examples
+            /// See [here](super::perfect_power#perfect_power_decomposition).
+            fn perfect_power_decomposition(&self) -> ($s, u64) {
+                let (mut base, mut exponent) =
+                    perfect_power_decomposition_unsigned(self.unsigned_abs());
+                if *self >= 0 {
+                    return ($s::wrapping_from(base), exponent);
+                }
+                // An odd power of a negative number is negative, so move the factors of 2 from the
+                // exponent into the base.
+                while exponent.even() {
+                    base.square_assign();
+                    exponent >>= 1;
+                }
+                ($s::wrapping_from(base).wrapping_neg(), exponent)
+            }
+        }
+    };
+}
+apply_to_unsigned_signed_pairs!(impl_perfect_power_signed);
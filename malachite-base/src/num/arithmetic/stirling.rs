@@ -0,0 +1,293 @@
+use crate::num::arithmetic::traits::{
+    Bell, CheckedBell, CheckedStirlingFirst, CheckedStirlingSecond, StirlingFirst, StirlingSecond,
+};
+use crate::num::basic::unsigneds::PrimitiveUnsigned;
+use crate::num::conversion::traits::{ExactFrom, WrappingFrom};
+use std::cmp::min;
+
+// Both kinds of Stirling numbers satisfy a recurrence of the form
+//
+// $$
+// X(i, j) = m(i, j)X(i - 1, j) + X(i - 1, j - 1),
+// $$
+//
+// with $m(i, j) = i - 1$ for the first kind and $m(i, j) = j$ for the second. Writing
+// $i = j + d$, only the cells with $0 \leq j \leq k$ and $0 \leq d \leq n - k$ contribute to
+// $X(n, k)$, and every one of them is at most $X(n, k)$ (each step of the recurrence can only
+// increase the value). So we can sweep over the rectangle, storing only a single row or column,
+// whichever is shorter, and any overflow means that the result itself overflows.
+//
+// Assumes $1 \leq k < n$.
+fn checked_stirling<T: PrimitiveUnsigned>(
+    n: u64,
+    k: u64,
+    multiplier: &dyn Fn(u64, u64) -> u64,
+) -> Option<T> {
+    let n_minus_k = n - k;
+    if n_minus_k == 1 {
+        // Both kinds of Stirling numbers are equal to $\binom{n}{2}$ here.
+        return T::checked_binomial_coefficient(T::try_from(n).ok()?, T::TWO);
+    }
+    // If $1 \leq k < n$, then $X(n, k) \geq n - 1$ and the multipliers are at most $n - 1$.
+    T::try_from(n - 1).ok()?;
+    // Moving toward smaller $i$ and $j$ never increases $X(i, j)$, so with $m = \min(k, n - k)$ we
+    // have $X(n, k) \geq \left\{{2m \atop m}\right\} \geq 2^m$, the latter when $m \geq 2$.
+    // This bounds the size of the row or column below.
+    if min(k, n_minus_k) >= T::WIDTH {
+        return None;
+    }
+    if n_minus_k <= k {
+        // a[d] holds X(j + d, j)
+        let mut a = vec![T::ZERO; usize::exact_from(n_minus_k + 1)];
+        a[0] = T::ONE;
+        for j in 1..=k {
+            for d in 1..=n_minus_k {
+                let d_usize = usize::wrapping_from(d);
+                a[d_usize] = T::wrapping_from(multiplier(j, d))
+                    .checked_mul(a[d_usize - 1])?
+                    .checked_add(a[d_usize])?;
+            }
+        }
+        Some(a[usize::wrapping_from(n_minus_k)])
+    } else {
+        // b[j] holds X(j + d, j)
+        let mut b = vec![T::ONE; usize::exact_from(k + 1)];
+        for d in 1..=n_minus_k {
+            b[0] = T::ZERO;
+            for j in 1..=k {
+                let j_usize = usize::wrapping_from(j);
+                b[j_usize] = T::wrapping_from(multiplier(j, d))
+                    .checked_mul(b[j_usize])?
+                    .checked_add(b[j_usize - 1])?;
+            }
+        }
+        Some(b[usize::wrapping_from(k)])
+    }
+}
+
+fn checked_stirling_first<T: PrimitiveUnsigned>(n: u64, k: u64) -> Option<T> {
+    if k > n {
+        Some(T::ZERO)
+    } else if k == n {
+        Some(T::ONE)
+    } else if k == 0 {
+        Some(T::ZERO)
+    } else {
+        checked_stirling(n, k, &|j, d| j + d - 1)
+    }
+}
+
+fn checked_stirling_second<T: PrimitiveUnsigned>(n: u64, k: u64) -> Option<T> {
+    if k > n {
+        Some(T::ZERO)
+    } else if k == n || k == 1 {
+        Some(T::ONE)
+    } else if k == 0 {
+        Some(T::ZERO)
+    } else {
+        checked_stirling(n, k, &|j, _| j)
+    }
+}
+
+// Uses the Bell triangle: each row starts with the last element of the previous row, and each
+// subsequent element is the sum of the element before it and the element above that one. The last
+// element of row $n - 1$ is $B_n$, and no element of that row or any previous row exceeds it.
+fn checked_bell<T: PrimitiveUnsigned>(n: u64) -> Option<T> {
+    let mut row = vec![T::ONE];
+    for _ in 1..n {
+        let mut next = Vec::with_capacity(row.len() + 1);
+        let mut x = *row.last().unwrap();
+        next.push(x);
+        for &y in &row {
+            x = x.checked_add(y)?;
+            next.push(x);
+        }
+        row = next;
+    }
+    Some(*row.last().unwrap())
+}
+
+macro_rules! impl_stirling {
+    ($t:ident) => {
+        impl CheckedStirlingFirst for $t {
+            /// Computes an unsigned Stirling number of the first kind.
+            ///
+            /// The unsigned Stirling number of the first kind $\left[{n \atop k}\right]$ counts the
+            /// permutations of $n$ elements with exactly $k$ cycles.
+            ///
+            /// If the output is too large to be represented, the function returns `None`.
+            ///
+            /// $$
+            /// f(n, k) = \\begin{cases}
+            ///     \operatorname{Some}(\left[{n \atop k}\right]) & \text{if}
+            ///         \\quad \left[{n \atop k}\right] < 2^W, \\\\
+            ///     \operatorname{None} & \text{if} \\quad \left[{n \atop k}\right] \geq 2^W,
+            /// \\end{cases}
+            /// $$
+            /// where $W$ is `Self::WIDTH`.
+            ///
+            /// # Worst-case complexity
+            /// $T(n, k) = O(k(n - k))$
+            ///
+            /// $M(n, k) = O(\min(k, n - k))$
+            ///
+            /// where $T$ is time, $M$ is additional memory, $n$ is `n`, and $k$ is `k`.
+            ///
+            /// # Examples
+            /// See [here](super::stirling#checked_stirling_first).
+            #[inline]
+            fn checked_stirling_first(n: u64, k: u64) -> Option<$t> {
+                checked_stirling_first(n, k)
+            }
+        }
+
+        impl StirlingFirst for $t {
+            /// Computes an unsigned Stirling number of the first kind.
+            ///
+            /// The unsigned Stirling number of the first kind $\left[{n \atop k}\right]$ counts the
+            /// permutations of $n$ elements with exactly $k$ cycles.
+            ///
+            /// If the output is too large to be represented, the function panics. For a function
+            /// that returns `None` instead, try
+            /// [`checked_stirling_first`](CheckedStirlingFirst::checked_stirling_first).
+            ///
+            /// $f(n, k) = \left[{n \atop k}\right]$, where
+            /// $\left[{n + 1 \atop k}\right] = n\left[{n \atop k}\right] +
+            /// \left[{n \atop k - 1}\right]$.
+            ///
+            /// # Worst-case complexity
+            /// $T(n, k) = O(k(n - k))$
+            ///
+            /// $M(n, k) = O(\min(k, n - k))$
+            ///
+            /// where $T$ is time, $M$ is additional memory, $n$ is `n`, and $k$ is `k`.
+            ///
+            /// # Panics
+            /// Panics if the output is too large to be represented.
+            ///
+            /// # Examples
+            /// See [here](super::stirling#stirling_first).
+            #[inline]
+            fn stirling_first(n: u64, k: u64) -> $t {
+                $t::checked_stirling_first(n, k).unwrap()
+            }
+        }
+
+        impl CheckedStirlingSecond for $t {
+            /// Computes a Stirling number of the second kind.
+            ///
+            /// The Stirling number of the second kind $\left\\{{n \atop k}\right\\}$ counts the
+            /// partitions of a set of $n$ elements into exactly $k$ nonempty subsets.
+            ///
+            /// If the output is too large to be represented, the function returns `None`.
+            ///
+            /// $$
+            /// f(n, k) = \\begin{cases}
+            ///     \operatorname{Some}(\left\\{{n \atop k}\right\\}) & \text{if}
+            ///         \\quad \left\\{{n \atop k}\right\\} < 2^W, \\\\
+            ///     \operatorname{None} & \text{if} \\quad \left\\{{n \atop k}\right\\} \geq 2^W,
+            /// \\end{cases}
+            /// $$
+            /// where $W$ is `Self::WIDTH`.
+            ///
+            /// # Worst-case complexity
+            /// $T(n, k) = O(k(n - k))$
+            ///
+            /// $M(n, k) = O(\min(k, n - k))$
+            ///
+            /// where $T$ is time, $M$ is additional memory, $n$ is `n`, and $k$ is `k`.
+            ///
+            /// # Examples
+            /// See [here](super::stirling#checked_stirling_second).
+            #[inline]
+            fn checked_stirling_second(n: u64, k: u64) -> Option<$t> {
+                checked_stirling_second(n, k)
+            }
+        }
+
+        impl StirlingSecond for $t {
+            /// Computes a Stirling number of the second kind.
+            ///
+            /// The Stirling number of the second kind $\left\\{{n \atop k}\right\\}$ counts the
+            /// partitions of a set of $n$ elements into exactly $k$ nonempty subsets.
+            ///
+            /// If the output is too large to be represented, the function panics. For a function
+            /// that returns `None` instead, try
+            /// [`checked_stirling_second`](CheckedStirlingSecond::checked_stirling_second).
+            ///
+            /// $f(n, k) = \left\\{{n \atop k}\right\\}$, where
+            /// $\left\\{{n + 1 \atop k}\right\\} = k\left\\{{n \atop k}\right\\} +
+            /// \left\\{{n \atop k - 1}\right\\}$.
+            ///
+            /// # Worst-case complexity
+            /// $T(n, k) = O(k(n - k))$
+            ///
+            /// $M(n, k) = O(\min(k, n - k))$
+            ///
+            /// where $T$ is time, $M$ is additional memory, $n$ is `n`, and $k$ is `k`.
+            ///
+            /// # Panics
+            /// Panics if the output is too large to be represented.
+            ///
+            /// # Examples
+            /// See [here](super::stirling#stirling_second).
+            #[inline]
+            fn stirling_second(n: u64, k: u64) -> $t {
+                $t::checked_stirling_second(n, k).unwrap()
+            }
+        }
+
+        impl CheckedBell for $t {
+            /// Computes the $n$th Bell number.
+            ///
+            /// The Bell number $B_n$ counts the partitions of a set of $n$ elements.
+            ///
+            /// If the output is too large to be represented, the function returns `None`.
+            ///
+            /// $$
+            /// f(n) = \\begin{cases}
+            ///     \operatorname{Some}(B_n) & \text{if} \\quad B_n < 2^W, \\\\
+            ///     \operatorname{None} & \text{if} \\quad B_n \geq 2^W,
+            /// \\end{cases}
+            /// $$
+            /// where $W$ is `Self::WIDTH`.
+            ///
+            /// # Worst-case complexity
+            /// Constant time and additional memory.
+            ///
+            /// # Examples
+            /// See [here](super::stirling#checked_bell).
+            #[inline]
+            fn checked_bell(n: u64) -> Option<$t> {
+                checked_bell(n)
+            }
+        }
+
+        impl Bell for $t {
+            /// Computes the $n$th Bell number.
+            ///
+            /// The Bell number $B_n$ counts the partitions of a set of $n$ elements.
+            ///
+            /// If the output is too large to be represented, the function panics. For a function
+            /// that returns `None` instead, try [`checked_bell`](CheckedBell::checked_bell).
+            ///
+            /// $$
+            /// f(n) = B_n = \sum_{k=0}^n \left\\{{n \atop k}\right\\}.
+            /// $$
+            ///
+            /// # Worst-case complexity
+            /// Constant time and additional memory.
+            ///
+            /// # Panics
+            /// Panics if the output is too large to be represented.
+            ///
+            /// # Examples
+            /// See [here](super::stirling#bell).
+            #[inline]
+            fn bell(n: u64) -> $t {
+                $t::checked_bell(n).unwrap()
+            }
+        }
+    };
+}
+apply_to_unsigneds!(impl_stirling);
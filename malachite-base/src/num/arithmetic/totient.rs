@@ -0,0 +1,208 @@
+use crate::num::arithmetic::traits::{CarmichaelLambda, Totient};
+use crate::num::basic::unsigneds::PrimitiveUnsigned;
+use crate::num::conversion::traits::ExactFrom;
+use crate::num::factorization::traits::Factor;
+
+fn totient_from_factorization<T: PrimitiveUnsigned>(factors: &[(T, u64)]) -> T {
+    factors
+        .iter()
+        .fold(T::ONE, |acc, &(p, e)| acc * p.pow(e - 1) * (p - T::ONE))
+}
+
+// Returns the Carmichael function of $p^e$, where $p$ is prime and $e > 0$.
+fn carmichael_lambda_of_prime_power<T: PrimitiveUnsigned>(p: T, e: u64) -> T {
+    if p == T::TWO && e > 2 {
+        T::power_of_2(e - 2)
+    } else {
+        p.pow(e - 1) * (p - T::ONE)
+    }
+}
+
+fn carmichael_lambda_from_factorization<T: PrimitiveUnsigned>(factors: &[(T, u64)]) -> T {
+    factors.iter().fold(T::ONE, |acc, &(p, e)| {
+        acc.lcm(carmichael_lambda_of_prime_power(p, e))
+    })
+}
+
+macro_rules! impl_totient_unsigned {
+    ($t:ident) => {
+        impl Totient for $t {
+            /// Computes Euler's totient function of a number.
+            ///
+            /// $\varphi(n)$ is the number of integers in $[1, n]$ that are coprime to $n$. If $n =
+            /// \prod_i p_i^{e_i}$, then
+            ///
+            /// $$
+            /// \varphi(n) = \prod_i p_i^{e_i - 1}(p_i - 1).
+            /// $$
+            ///
+            /// # Worst-case complexity
+            /// Constant time and additional memory.
+            ///
+            /// # Panics
+            /// Panics if `self` is 0.
+            ///
+            /// # Examples
+            /// See [here](super::totient#totient).
+            #[inline]
+            fn totient(&self) -> $t {
+                totient_from_factorization(&self.factor())
+            }
+
+            /// Computes Euler's totient function of a number, given its prime factorization.
+            ///
+            /// `factors` must be the prime factorization of the number, in the format returned
+            /// by [`factor`](Factor::factor); this is not checked. The empty factorization
+            /// represents 1.
+            ///
+            /// # Worst-case complexity
+            /// $T(n) = O(n)$
+            ///
+            /// $M(n) = O(1)$
+            ///
+            /// where $T$ is time, $M$ is additional memory, and $n$ is `factors.len()`.
+            ///
+            /// # Examples
+            /// See [here](super::totient#totient_from_factorization).
+            #[inline]
+            fn totient_from_factorization(factors: &[($t, u64)]) -> $t {
+                totient_from_factorization(factors)
+            }
+        }
+
+        impl CarmichaelLambda for $t {
+            /// Computes the Carmichael function of a number.
+            ///
+            /// $\lambda(n)$ is the exponent of the multiplicative group of integers modulo $n$:
+            /// the smallest positive $m$ such that $a^m \equiv 1 \mod n$ for every $a$ coprime to
+            /// $n$. It is the least common multiple of $\lambda(p^e)$ over the prime powers $p^e$
+            /// dividing $n$, where $\lambda(2^e) = 2^{e-2}$ for $e > 2$, and $\lambda(p^e) =
+            /// \varphi(p^e)$ otherwise.
+            ///
+            /// # Worst-case complexity
+            /// Constant time and additional memory.
+            ///
+            /// # Panics
+            /// Panics if `self` is 0.
+            ///
+            /// # Examples
+            /// See [here](super::totient#carmichael_lambda).
+            #[inline]
+            fn carmichael_lambda(&self) -> $t {
+                carmichael_lambda_from_factorization(&self.factor())
+            }
+
+            /// Computes the Carmichael function of a number, given its prime factorization.
+            ///
+            /// `factors` must be the prime factorization of the number, in the format returned
+            /// by [`factor`](Factor::factor); this is not checked. The empty factorization
+            /// represents 1.
+            ///
+            /// # Worst-case complexity
+            /// $T(n) = O(n)$
+            ///
+            /// $M(n) = O(1)$
+            ///
+            /// where $T$ is time, $M$ is additional memory, and $n$ is `factors.len()`.
+            ///
+            /// # Examples
+            /// See [here](super::totient#carmichael_lambda_from_factorization).
+            #[inline]
+            fn carmichael_lambda_from_factorization(factors: &[($t, u64)]) -> $t {
+                carmichael_lambda_from_factorization(factors)
+            }
+        }
+    };
+}
+apply_to_unsigneds!(impl_totient_unsigned);
+
+macro_rules! impl_totient_signed {
+    ($u:ident, $s:ident) => {
+        impl Totient for $s {
+            /// Computes Euler's totient function of the absolute value of a number.
+            ///
+            /// See the unsigned implementation for details.
+            ///
+            /// # Worst-case complexity
+            /// Constant time and additional memory.
+            ///
+            /// # Panics
+            /// Panics if `self` is 0.
+            ///
+            /// # Examples
+            /// See [here](super::totient#totient).
+            #[inline]
+            fn totient(&self) -> $s {
+                $s::exact_from(self.unsigned_abs().totient())
+            }
+
+            /// Computes Euler's totient function of the absolute value of a number, given the
+            /// prime factorization of the absolute value.
+            ///
+            /// `factors` must be the prime factorization of the number's absolute value, in the
+            /// format returned by [`factor`](Factor::factor); this is not checked. The empty
+            /// factorization represents 1.
+            ///
+            /// # Worst-case complexity
+            /// $T(n) = O(n)$
+            ///
+            /// $M(n) = O(n)$
+            ///
+            /// where $T$ is time, $M$ is additional memory, and $n$ is `factors.len()`.
+            ///
+            /// # Examples
+            /// See [here](super::totient#totient_from_factorization).
+            fn totient_from_factorization(factors: &[($s, u64)]) -> $s {
+                let factors: Vec<($u, u64)> = factors
+                    .iter()
+                    .map(|&(p, e)| ($u::exact_from(p), e))
+                    .collect();
+                $s::exact_from(totient_from_factorization(&factors))
+            }
+        }
+
+        impl CarmichaelLambda for $s {
+            /// Computes the Carmichael function of the absolute value of a number.
+            ///
+            /// See the unsigned implementation for details.
+            ///
+            /// # Worst-case complexity
+            /// Constant time and additional memory.
+            ///
+            /// # Panics
+            /// Panics if `self` is 0.
+            ///
+            /// # Examples
+            /// See [here](super::totient#carmichael_lambda).
+            #[inline]
+            fn carmichael_lambda(&self) -> $s {
+                $s::exact_from(self.unsigned_abs().carmichael_lambda())
+            }
+
+            /// Computes the Carmichael function of the absolute value of a number, given the prime
+            /// factorization of the absolute value.
+            ///
+            /// `factors` must be the prime factorization of the number's absolute value, in the
+            /// format returned by [`factor`](Factor::factor); this is not checked. The empty
+            /// factorization represents 1.
+            ///
+            /// # Worst-case complexity
+            /// $T(n) = O(n)$
+            ///
+            /// $M(n) = O(n)$
+            ///
+            /// where $T$ is time, $M$ is additional memory, and $n$ is `factors.len()`.
+            ///
+            /// # Examples
+            /// See [here](super::totient#carmichael_lambda_from_factorization).
+            fn carmichael_lambda_from_factorization(factors: &[($s, u64)]) -> $s {
+                let factors: Vec<($u, u64)> = factors
+                    .iter()
+                    .map(|&(p, e)| ($u::exact_from(p), e))
+                    .collect();
+                $s::exact_from(carmichael_lambda_from_factorization(&factors))
+            }
+        }
+    };
+}
+apply_to_unsigned_signed_pairs!(impl_totient_signed);
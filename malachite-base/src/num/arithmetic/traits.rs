@@ -151,6 +151,15 @@ pub trait CheckedSubMul<Y = Self, Z = Self> {
     fn checked_sub_mul(self, y: Y, z: Z) -> Option<Self::Output>;
 }
 
+/// Uses the Chinese remainder theorem to find the unique residue, modulo the least common multiple
+/// of several moduli, that is congruent to each of several residues modulo the corresponding
+/// modulus. The moduli need not be coprime. Returns `None` if the congruences are inconsistent.
+pub trait ChineseRemainder: Sized {
+    type Modulus;
+
+    fn chinese_remainder(residues: &[(Self, Self::Modulus)]) -> Option<(Self, Self::Modulus)>;
+}
+
 /// Determines whether two numbers are coprime.
 pub trait CoprimeWith<RHS = Self> {
     fn coprime_with(self, other: RHS) -> bool;
@@ -345,6 +354,155 @@ pub trait CheckedSubfactorial: Sized {
     fn checked_subfactorial(n: u64) -> Option<Self>;
 }
 
+/// Computes the $n$th Fibonacci number, $F_n$.
+pub trait Fibonacci {
+    fn fibonacci(n: u64) -> Self;
+}
+
+/// Computes the $n$th Fibonacci number, $F_n$, returning `None` if it is too large to be
+/// represented.
+pub trait CheckedFibonacci: Sized {
+    fn checked_fibonacci(n: u64) -> Option<Self>;
+}
+
+/// Computes the $n$th Lucas number, $L_n$.
+pub trait Lucas {
+    fn lucas(n: u64) -> Self;
+}
+
+/// Computes the $n$th Lucas number, $L_n$, returning `None` if it is too large to be represented.
+pub trait CheckedLucas: Sized {
+    fn checked_lucas(n: u64) -> Option<Self>;
+}
+
+/// Computes $(U_n(P, Q), V_n(P, Q))$, the $n$th terms of the Lucas sequences with parameters $P$
+/// and $Q$.
+pub trait LucasSequence<T = Self>: Sized {
+    fn lucas_sequence(p: T, q: T, n: u64) -> (Self, Self);
+}
+
+/// Computes the $n$th Fibonacci number modulo $m$.
+pub trait ModFibonacci<N = u64> {
+    fn mod_fibonacci(n: N, m: Self) -> Self;
+}
+
+/// Computes the $n$th Lucas number modulo $m$.
+pub trait ModLucas<N = u64> {
+    fn mod_lucas(n: N, m: Self) -> Self;
+}
+
+/// Computes $(U_n(P, Q), V_n(P, Q))$ modulo $m$, the $n$th terms of the Lucas sequences with
+/// parameters $P$ and $Q$. Assumes $P$ and $Q$ are already reduced modulo $m$.
+pub trait ModLucasSequence<N = u64>: Sized {
+    fn mod_lucas_sequence(p: Self, q: Self, n: N, m: Self) -> (Self, Self);
+}
+
+/// Computes an unsigned Stirling number of the first kind, the number of permutations of $n$
+/// elements with exactly $k$ cycles.
+pub trait StirlingFirst {
+    fn stirling_first(n: u64, k: u64) -> Self;
+}
+
+/// Computes an unsigned Stirling number of the first kind, returning `None` if it is too large to
+/// be represented.
+pub trait CheckedStirlingFirst: Sized {
+    fn checked_stirling_first(n: u64, k: u64) -> Option<Self>;
+}
+
+/// Computes a Stirling number of the second kind, the number of partitions of a set of $n$
+/// elements into exactly $k$ nonempty subsets.
+pub trait StirlingSecond {
+    fn stirling_second(n: u64, k: u64) -> Self;
+}
+
+/// Computes a Stirling number of the second kind, returning `None` if it is too large to be
+/// represented.
+pub trait CheckedStirlingSecond: Sized {
+    fn checked_stirling_second(n: u64, k: u64) -> Option<Self>;
+}
+
+/// Computes the $n$th Bell number, $B_n$, the number of partitions of a set of $n$ elements.
+pub trait Bell {
+    fn bell(n: u64) -> Self;
+}
+
+/// Computes the $n$th Bell number, $B_n$, returning `None` if it is too large to be represented.
+pub trait CheckedBell: Sized {
+    fn checked_bell(n: u64) -> Option<Self>;
+}
+
+/// Computes the $n$th Catalan number, $C_n$.
+pub trait Catalan {
+    fn catalan(n: u64) -> Self;
+}
+
+/// Computes the $n$th Catalan number, $C_n$, returning `None` if it is too large to be
+/// represented.
+pub trait CheckedCatalan: Sized {
+    fn checked_catalan(n: u64) -> Option<Self>;
+}
+
+/// Computes $p(n)$, the number of partitions of $n$.
+pub trait PartitionCount {
+    fn partition_count(n: u64) -> Self;
+}
+
+/// Computes $p(n)$, the number of partitions of $n$, returning `None` if it is too large to be
+/// represented.
+pub trait CheckedPartitionCount: Sized {
+    fn checked_partition_count(n: u64) -> Option<Self>;
+}
+
+/// Computes the multinomial coefficient $\binom{k_1 + k_2 + \cdots + k_m}{k_1, k_2, \ldots, k_m}$.
+pub trait Multinomial {
+    fn multinomial(ks: &[u64]) -> Self;
+}
+
+/// Computes the multinomial coefficient $\binom{k_1 + k_2 + \cdots + k_m}{k_1, k_2, \ldots, k_m}$,
+/// returning `None` if it is too large to be represented.
+pub trait CheckedMultinomial: Sized {
+    fn checked_multinomial(ks: &[u64]) -> Option<Self>;
+}
+
+/// Computes the binomial coefficient of two numbers modulo a third number $m$.
+pub trait ModBinomialCoefficient<T = Self> {
+    fn mod_binomial_coefficient(n: T, k: T, m: T) -> Self;
+}
+
+/// Computes the factorial of a number modulo another number $m$.
+pub trait ModFactorial<T = Self> {
+    fn mod_factorial(n: T, m: T) -> Self;
+}
+
+/// Computes the $n$th Bernoulli number $B_n$, or generates the Bernoulli numbers in order. The
+/// convention $B_1 = -\frac{1}{2}$ is used.
+pub trait BernoulliNumber: Sized {
+    type I: Iterator<Item = Self>;
+
+    fn bernoulli_number(n: u64) -> Self;
+
+    fn bernoulli_numbers() -> Self::I;
+}
+
+/// Computes the $n$th Euler number $E_n$, or generates the Euler numbers in order.
+pub trait EulerNumber: Sized {
+    type I: Iterator<Item = Self>;
+
+    fn euler_number(n: u64) -> Self;
+
+    fn euler_numbers() -> Self::I;
+}
+
+/// Computes the $n$th harmonic number $H_n = \sum_{i=1}^n \frac{1}{i}$, or generates the harmonic
+/// numbers in order.
+pub trait HarmonicNumber: Sized {
+    type I: Iterator<Item = Self>;
+
+    fn harmonic_number(n: u64) -> Self;
+
+    fn harmonic_numbers() -> Self::I;
+}
+
 /// Takes the floor of a number.
 pub trait Floor {
     type Output;
@@ -374,6 +532,17 @@ pub trait IsPowerOf2 {
     fn is_power_of_2(&self) -> bool;
 }
 
+/// Determines whether a number is a perfect power: whether it equals $b^k$ for some integer $b$
+/// and some $k > 1$.
+pub trait IsPerfectPower {
+    fn is_perfect_power(&self) -> bool;
+}
+
+/// Writes a number as $b^k$, where $k$ is as large as possible.
+pub trait PerfectPowerDecomposition: Sized {
+    fn perfect_power_decomposition(&self) -> (Self, u64);
+}
+
 /// Calculates the LCM (least common multiple) of two numbers.
 pub trait Lcm<RHS = Self> {
     type Output;
@@ -410,6 +579,52 @@ pub trait KroneckerSymbol<RHS = Self> {
     fn kronecker_symbol(self, other: RHS) -> i8;
 }
 
+/// Computes Euler's totient function of a number: the number of integers in $[1, n]$ that are
+/// coprime to $n$.
+pub trait Totient: Sized {
+    fn totient(&self) -> Self;
+
+    fn totient_from_factorization(factors: &[(Self, u64)]) -> Self;
+}
+
+/// Computes the Carmichael function of a number: the smallest positive $m$ such that $a^m \equiv 1
+/// \mod n$ for every $a$ coprime to $n$.
+pub trait CarmichaelLambda: Sized {
+    fn carmichael_lambda(&self) -> Self;
+
+    fn carmichael_lambda_from_factorization(factors: &[(Self, u64)]) -> Self;
+}
+
+/// Computes the Möbius function of a number.
+pub trait Moebius: Sized {
+    fn moebius(&self) -> i8;
+
+    fn moebius_from_factorization(factors: &[(Self, u64)]) -> i8;
+}
+
+/// Counts the positive divisors of a number.
+pub trait DivisorCount: Sized {
+    fn divisor_count(&self) -> Self;
+
+    fn divisor_count_from_factorization(factors: &[(Self, u64)]) -> Self;
+}
+
+/// Computes the sum of the $k$th powers of the positive divisors of a number.
+pub trait DivisorSum: Sized {
+    fn divisor_sum(&self, k: u64) -> Self;
+
+    fn divisor_sum_from_factorization(factors: &[(Self, u64)], k: u64) -> Self;
+}
+
+/// Generates the positive divisors of a number, in ascending order.
+pub trait Divisors: Sized {
+    type I: Iterator<Item = Self>;
+
+    fn divisors(&self) -> Self::I;
+
+    fn divisors_from_factorization(factors: &[(Self, u64)]) -> Self::I;
+}
+
 /// Calculates the base-$b$ logarithm of a number, or returns `None` if the number is not a perfect
 /// power of $b$.
 pub trait CheckedLogBase<B = Self> {
@@ -502,6 +717,30 @@ pub trait ModIsReduced<M = Self> {
     fn mod_is_reduced(&self, m: &M) -> bool;
 }
 
+/// Computes the discrete logarithm of a number modulo another number $m$: the smallest $x \geq 0$
+/// such that $g^x \equiv h \mod m$, where $h$ is the number and $g$ is the base. Assumes the inputs
+/// are already reduced modulo $m$. Returns `None` if there is no such $x$.
+pub trait ModLog<B = Self, M = Self> {
+    type Output;
+
+    fn mod_log(self, base: B, m: M) -> Option<Self::Output>;
+}
+
+/// Computes the multiplicative order of a number modulo another number $m$: the smallest $k > 0$
+/// such that $x^k \equiv 1 \mod m$. Assumes the input is already reduced modulo $m$. Returns `None`
+/// if the number is not coprime to $m$.
+pub trait MultiplicativeOrder<M = Self> {
+    type Output;
+
+    fn multiplicative_order(self, m: M) -> Option<Self::Output>;
+}
+
+/// Finds the smallest primitive root modulo a number $m$: a number whose powers are exactly the
+/// residues coprime to $m$. Returns `None` if there is no primitive root.
+pub trait PrimitiveRoot: Sized {
+    fn primitive_root(&self) -> Option<Self>;
+}
+
 /// Multiplies two numbers modulo a third number $m$. Assumes the inputs are already reduced mod
 /// $m$.
 pub trait ModMul<RHS = Self, M = Self> {
@@ -881,6 +1120,26 @@ pub trait ModShrAssign<RHS, M = Self> {
     fn mod_shr_assign(&mut self, other: RHS, m: M);
 }
 
+/// Finds a square root of a number modulo a prime or a prime power. Assumes the input is already
+/// reduced modulo the modulus. Returns `None` if the number is not a square modulo the modulus.
+pub trait ModSqrt<M = Self> {
+    type Output;
+
+    fn mod_sqrt(self, p: M) -> Option<Self::Output>;
+
+    fn mod_sqrt_prime_power(self, p: M, e: u64) -> Option<Self::Output>;
+}
+
+/// Finds a square root of a number modulo a number whose prime factorization is known. Assumes the
+/// input is already reduced modulo the modulus. Returns `None` if the number is not a square modulo
+/// the modulus.
+pub trait ModSqrtFromFactorization {
+    type Output;
+
+    fn mod_sqrt_from_factorization(self, factors: &[(Self::Output, u64)])
+        -> Option<Self::Output>;
+}
+
 /// Squares a number modulo another number $m$. Assumes the input is already reduced modulo $m$.
 pub trait ModSquare<M = Self> {
     type Output;
@@ -1,8 +1,10 @@
+use crate::num::arithmetic::divisors::DivisorsIterator;
 use crate::num::arithmetic::traits::{
-    Abs, AbsAssign, CeilingDivAssignMod, CeilingDivMod, CeilingMod, CeilingModAssign,
-    CeilingModPowerOf2, CeilingModPowerOf2Assign, CheckedAbs, ExtendedGcd, NegAssign,
-    OverflowingAbs, OverflowingAbsAssign, SaturatingAbs, SaturatingAbsAssign, SaturatingNeg,
-    SaturatingNegAssign, UnsignedAbs, WrappingAbs, WrappingAbsAssign,
+    Abs, AbsAssign, CarmichaelLambda, CeilingDivAssignMod, CeilingDivMod, CeilingMod,
+    CeilingModAssign, CeilingModPowerOf2, CeilingModPowerOf2Assign, CheckedAbs, DivisorCount,
+    DivisorSum, Divisors, ExtendedGcd, IsPerfectPower, Moebius, NegAssign, OverflowingAbs,
+    OverflowingAbsAssign, PerfectPowerDecomposition, SaturatingAbs, SaturatingAbsAssign,
+    SaturatingNeg, SaturatingNegAssign, Totient, UnsignedAbs, WrappingAbs, WrappingAbsAssign,
 };
 use crate::num::basic::integers::PrimitiveInt;
 use crate::num::basic::traits::NegativeOne;
@@ -14,6 +16,7 @@ use std::ops::Neg;
 pub trait PrimitiveSigned:
     Abs<Output = Self>
     + AbsAssign
+    + CarmichaelLambda
     + CeilingDivAssignMod<Self, ModOutput = Self>
     + CeilingDivMod<Self, DivOutput = Self, ModOutput = Self>
     + CeilingMod<Self, Output = Self>
@@ -22,20 +25,27 @@ pub trait PrimitiveSigned:
     + CeilingModPowerOf2Assign
     + CheckedAbs<Output = Self>
     + CheckedHammingDistance
+    + DivisorCount
+    + DivisorSum
+    + Divisors<I = DivisorsIterator<Self>>
     + ExtendedGcd<Self, Cofactor = Self>
     + From<i8>
     + HasRandomSignedRange
+    + IsPerfectPower
+    + Moebius
     + Neg<Output = Self>
     + NegAssign
     + NegativeOne
     + OverflowingAbs<Output = Self>
     + OverflowingAbsAssign
+    + PerfectPowerDecomposition
     + PrimitiveInt
     + RandomSignedChunkable
     + SaturatingAbs<Output = Self>
     + SaturatingAbsAssign
     + SaturatingNeg<Output = Self>
     + SaturatingNegAssign
+    + Totient
     + UnsignedAbs
     + WrappingAbs<Output = Self>
     + WrappingAbsAssign
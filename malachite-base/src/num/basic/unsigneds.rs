@@ -1,20 +1,28 @@
+use crate::num::arithmetic::divisors::DivisorsIterator;
 use crate::num::arithmetic::traits::{
-    CeilingDivAssignNegMod, CeilingDivNegMod, CeilingLogBase, CeilingLogBase2,
-    CeilingLogBasePowerOf2, CheckedDoubleFactorial, CheckedFactorial, CheckedLcm, CheckedLogBase,
-    CheckedLogBase2, CheckedLogBasePowerOf2, CheckedMultifactorial, CheckedNextPowerOf2,
-    CheckedPrimorial, CheckedSubfactorial, CoprimeWith, DoubleFactorial, ExtendedGcd, Factorial,
-    FloorLogBase, FloorLogBase2, FloorLogBasePowerOf2, Gcd, GcdAssign, IsPowerOf2, Lcm, LcmAssign,
-    ModAdd, ModAddAssign, ModInverse, ModIsReduced, ModMul, ModMulAssign, ModMulPrecomputed,
+    Bell, CarmichaelLambda, Catalan, CeilingDivAssignNegMod, CeilingDivNegMod, CeilingLogBase,
+    CeilingLogBase2, CeilingLogBasePowerOf2, CheckedBell, CheckedCatalan, CheckedDoubleFactorial,
+    CheckedFactorial, CheckedFibonacci, CheckedLcm, CheckedLogBase, CheckedLogBase2,
+    CheckedLogBasePowerOf2, CheckedLucas, CheckedMultifactorial, CheckedMultinomial,
+    CheckedNextPowerOf2, CheckedPartitionCount, CheckedPrimorial, CheckedStirlingFirst,
+    CheckedStirlingSecond, CheckedSubfactorial, CoprimeWith, DivisorCount, DivisorSum, Divisors,
+    DoubleFactorial, ExtendedGcd, Factorial, Fibonacci, FloorLogBase, FloorLogBase2,
+    FloorLogBasePowerOf2, Gcd, GcdAssign, IsPerfectPower, IsPowerOf2, Lcm, LcmAssign, Lucas,
+    ModAdd, ModAddAssign, ModBinomialCoefficient, ModFactorial, ModFibonacci, ModInverse,
+    ModIsReduced, ModLog, ModLucas, ModLucasSequence, ModMul, ModMulAssign, ModMulPrecomputed,
     ModMulPrecomputedAssign, ModNeg, ModNegAssign, ModPow, ModPowAssign, ModPowPrecomputed,
     ModPowPrecomputedAssign, ModPowerOf2, ModPowerOf2Add, ModPowerOf2AddAssign, ModPowerOf2Inverse,
     ModPowerOf2IsReduced, ModPowerOf2Mul, ModPowerOf2MulAssign, ModPowerOf2Neg,
     ModPowerOf2NegAssign, ModPowerOf2Pow, ModPowerOf2PowAssign, ModPowerOf2Shl,
     ModPowerOf2ShlAssign, ModPowerOf2Shr, ModPowerOf2ShrAssign, ModPowerOf2Square,
-    ModPowerOf2SquareAssign, ModPowerOf2Sub, ModPowerOf2SubAssign, ModSquare, ModSquareAssign,
-    ModSquarePrecomputed, ModSquarePrecomputedAssign, ModSub, ModSubAssign, Multifactorial, NegMod,
-    NegModAssign, NegModPowerOf2, NegModPowerOf2Assign, NextPowerOf2, NextPowerOf2Assign,
-    Primorial, RootAssignRem, RootRem, SqrtAssignRem, SqrtRem, Subfactorial, XMulYToZZ,
-    XXAddYYToZZ, XXDivModYToQR, XXSubYYToZZ, XXXAddYYYToZZZ, XXXSubYYYToZZZ, XXXXAddYYYYToZZZZ,
+    ModPowerOf2SquareAssign, ModPowerOf2Sub, ModPowerOf2SubAssign, ModSqrt,
+    ModSqrtFromFactorization, ModSquare, ModSquareAssign, ModSquarePrecomputed,
+    ModSquarePrecomputedAssign, ModSub, ModSubAssign, Moebius, Multifactorial, Multinomial,
+    MultiplicativeOrder, NegMod, NegModAssign, NegModPowerOf2, NegModPowerOf2Assign, NextPowerOf2,
+    NextPowerOf2Assign, PartitionCount, PerfectPowerDecomposition, PrimitiveRoot, Primorial,
+    RootAssignRem, RootRem, SqrtAssignRem, SqrtRem, StirlingFirst, StirlingSecond, Subfactorial,
+    Totient, XMulYToZZ, XXAddYYToZZ, XXDivModYToQR, XXSubYYToZZ, XXXAddYYYToZZZ, XXXSubYYYToZZZ,
+    XXXXAddYYYYToZZZZ,
 };
 use crate::num::basic::integers::PrimitiveInt;
 use crate::num::conversion::traits::{
@@ -22,21 +30,36 @@ use crate::num::conversion::traits::{
     SciMantissaAndExponent, VecFromOtherType, VecFromOtherTypeSlice,
 };
 use crate::num::factorization::primes::{PrimesIterator, PrimesLessThanIterator};
-use crate::num::factorization::traits::Primes;
+use crate::num::factorization::primes_in_range::PrimesInRangeIterator;
+use crate::num::factorization::traits::{
+    Factor, IsPrime, IsProbablePrime, NextPrime, NthPrime, PrevPrime, PrimeCount, Primes,
+    PrimesInRange,
+};
 use crate::num::logic::traits::{BitBlockAccess, HammingDistance};
 
 /// Defines functions on primitive unsigned integer types: uxx and usize.
 pub trait PrimitiveUnsigned:
     BitBlockAccess<Bits = Self>
+    + Bell
+    + CarmichaelLambda
+    + Catalan
     + CeilingLogBase<Output = u64>
     + CeilingLogBase2<Output = u64>
     + CeilingLogBasePowerOf2<u64, Output = u64>
     + CeilingDivAssignNegMod<Self, ModOutput = Self>
     + CeilingDivNegMod<Self, DivOutput = Self, ModOutput = Self>
+    + CheckedBell
+    + CheckedCatalan
     + CheckedDoubleFactorial
     + CheckedFactorial
+    + CheckedFibonacci
+    + CheckedLucas
+    + CheckedMultinomial
     + CheckedMultifactorial
+    + CheckedPartitionCount
     + CheckedPrimorial
+    + CheckedStirlingFirst
+    + CheckedStirlingSecond
     + CheckedSubfactorial
     + CheckedLcm<Self, Output = Self>
     + CheckedLogBase<Output = u64>
@@ -51,8 +74,13 @@ pub trait PrimitiveUnsigned:
     + Digits<u64>
     + Digits<u128>
     + Digits<usize>
+    + DivisorCount
+    + DivisorSum
+    + Divisors<I = DivisorsIterator<Self>>
     + ExtendedGcd<Self, Gcd = Self>
+    + Factor
     + Factorial
+    + Fibonacci
     + FloorLogBase<Output = u64>
     + FloorLogBase2<Output = u64>
     + FloorLogBasePowerOf2<u64, Output = u64>
@@ -67,12 +95,22 @@ pub trait PrimitiveUnsigned:
     + GcdAssign<Self>
     + HammingDistance
     + IntegerMantissaAndExponent<Self, u64>
+    + IsPerfectPower
     + IsPowerOf2
+    + IsPrime
+    + IsProbablePrime
     + Lcm<Self, Output = Self>
     + LcmAssign<Self>
+    + Lucas
     + ModIsReduced<Self>
+    + ModLog<Self, Output = Self>
+    + ModLucas
+    + ModLucasSequence
     + ModAdd<Self, Self, Output = Self>
     + ModAddAssign<Self, Self>
+    + ModBinomialCoefficient
+    + ModFactorial
+    + ModFibonacci
     + ModInverse<Self, Output = Self>
     + ModMul<Self, Self, Output = Self>
     + ModMulAssign<Self, Self>
@@ -132,19 +170,28 @@ pub trait PrimitiveUnsigned:
     + ModPowerOf2SubAssign<Self>
     + ModPowPrecomputed<u64, Self, Output = Self>
     + ModPowPrecomputedAssign<u64, Self>
+    + ModSqrt<Self, Output = Self>
+    + ModSqrtFromFactorization<Output = Self>
     + ModSquare<Self, Output = Self>
     + ModSquareAssign<Self>
     + ModSquarePrecomputed<u64, Self, Output = Self>
     + ModSquarePrecomputedAssign<u64, Self>
     + ModSub<Self, Self, Output = Self>
     + ModSubAssign<Self, Self>
+    + Moebius
     + Multifactorial
+    + Multinomial
+    + MultiplicativeOrder<Self, Output = Self>
     + NegMod<Self, Output = Self>
     + NegModAssign<Self>
     + NegModPowerOf2<Output = Self>
     + NegModPowerOf2Assign
     + NextPowerOf2<Output = Self>
     + NextPowerOf2Assign
+    + NextPrime
+    + NthPrime
+    + PartitionCount
+    + PerfectPowerDecomposition
     + PowerOf2Digits<u8>
     + PowerOf2Digits<u16>
     + PowerOf2Digits<u32>
@@ -157,8 +204,12 @@ pub trait PrimitiveUnsigned:
     + PowerOf2DigitIterable<u64>
     + PowerOf2DigitIterable<u128>
     + PowerOf2DigitIterable<usize>
+    + PrevPrime
+    + PrimeCount
     + Primes<I = PrimesIterator<Self>, LI = PrimesLessThanIterator<Self>>
+    + PrimesInRange<I = PrimesInRangeIterator<Self>>
     + PrimitiveInt
+    + PrimitiveRoot
     + Primorial
     + RootRem<u64, RootOutput = Self, RemOutput = Self>
     + RootAssignRem<u64, RemOutput = Self>
@@ -166,7 +217,10 @@ pub trait PrimitiveUnsigned:
     + SciMantissaAndExponent<f64, u64>
     + SqrtRem<SqrtOutput = Self, RemOutput = Self>
     + SqrtAssignRem<RemOutput = Self>
+    + StirlingFirst
+    + StirlingSecond
     + Subfactorial
+    + Totient
     + VecFromOtherType<u8>
     + VecFromOtherType<u16>
     + VecFromOtherType<u32>
@@ -0,0 +1,479 @@
+use crate::num::arithmetic::traits::ModMulPrecomputed;
+use crate::num::basic::integers::PrimitiveInt;
+use crate::num::basic::unsigneds::PrimitiveUnsigned;
+use crate::num::conversion::traits::WrappingFrom;
+use crate::num::factorization::traits::{Factor, Primes};
+use crate::num::logic::traits::{BitIterable, SignificantBits};
+
+// Every number is first checked for divisibility by the primes less than this bound.
+const TRIAL_DIVISION_LIMIT: u64 = 1 << 10;
+
+// The base-2 logarithm of `TRIAL_DIVISION_LIMIT`. After trial division, every remaining prime
+// factor has at least this many bits.
+const TRIAL_DIVISION_BITS: u64 = 10;
+
+// The number of iterations of Pollard-Brent rho that are performed for each polynomial before the
+// polynomial is abandoned.
+const RHO_ITERATION_LIMIT: u64 = 1 << 16;
+
+// The number of polynomials that Pollard-Brent rho tries before ECM is used.
+const RHO_ATTEMPTS: u64 = 3;
+
+// The number of iterations of Pollard-Brent rho whose differences are multiplied together before
+// a gcd is taken.
+const RHO_BATCH_SIZE: u64 = 128;
+
+// Pairs of (stage 1 bound, number of curves) for ECM. The bounds are those recommended by GMP-ECM
+// for finding factors of 15, 20, 25, and 30 digits. If all the curves fail, the last bound is
+// repeatedly increased.
+const ECM_SCHEDULE: [(u64, u64); 4] = [(2000, 25), (11000, 90), (50000, 300), (250000, 700)];
+
+// Generates the (stage 1 bound, number of curves) pairs to try, in order: first those in
+// `ECM_SCHEDULE`, and then the last entry of `ECM_SCHEDULE` with the bound doubled, quadrupled,
+// and so on.
+fn ecm_schedule() -> impl Iterator<Item = (u64, u64)> {
+    let (last_b1, last_curves) = ECM_SCHEDULE[ECM_SCHEDULE.len() - 1];
+    ECM_SCHEDULE
+        .iter()
+        .copied()
+        .chain((1..).map(move |i| (last_b1 << i, last_curves)))
+}
+
+// The ratio between the stage 2 bound and the stage 1 bound used by ECM.
+const ECM_STAGE_2_RATIO: u64 = 50;
+
+// The giant-step size used by stage 2 of ECM. This is the product of the first four primes.
+const ECM_STAGE_2_STEP: u64 = 210;
+
+// Finds all odd $j < D/2$ coprime to $D$, where $D$ is `ECM_STAGE_2_STEP`.
+fn ecm_stage_2_baby_steps() -> impl Iterator<Item = u64> {
+    (1..ECM_STAGE_2_STEP >> 1)
+        .step_by(2)
+        .filter(|j| j % 3 != 0 && j % 5 != 0 && j % 7 != 0)
+}
+
+// Divides out the primes less than `TRIAL_DIVISION_LIMIT` from `n`, pushing them onto `factors`.
+// Returns the cofactor, along with whether the cofactor is known to be prime (or 1).
+//
+// # Worst-case complexity
+// Constant time and additional memory.
+fn trial_division<T: PrimitiveUnsigned>(mut n: T, factors: &mut Vec<(T, u64)>) -> (T, bool) {
+    for p in T::primes_less_than(&T::saturating_from(TRIAL_DIVISION_LIMIT)) {
+        if n / p < p {
+            return (n, true);
+        }
+        if n.divisible_by(p) {
+            let mut exponent = 0;
+            while n.divisible_by(p) {
+                n /= p;
+                exponent += 1;
+            }
+            factors.push((p, exponent));
+        }
+    }
+    (n, n == T::ONE)
+}
+
+// Returns `n` as a perfect power $r^k$ with $k > 1$ prime, or `None` if `n` is not a perfect power.
+// `n` must have no prime factors less than `TRIAL_DIVISION_LIMIT`.
+//
+// # Worst-case complexity
+// Constant time and additional memory.
+fn perfect_power<T: PrimitiveUnsigned>(n: T) -> Option<(T, u64)> {
+    u64::primes_less_than_or_equal_to(&(n.significant_bits() / TRIAL_DIVISION_BITS))
+        .find_map(|k| n.checked_root(k).map(|r| (r, k)))
+}
+
+// Attempts to find a nontrivial factor of the odd composite number `n` using Brent's variant of
+// Pollard's rho algorithm, iterating the polynomial $x^2 + c$ starting from 2. Gives up after
+// `RHO_ITERATION_LIMIT` iterations.
+//
+// # Worst-case complexity
+// Constant time and additional memory.
+pub_test! {pollard_brent_rho<T: PrimitiveUnsigned>(n: T, c: T) -> Option<T> {
+    let data = T::precompute_mod_mul_data(&n);
+    let f = |x: T| x.mod_mul_precomputed(x, n, &data).mod_add(c, n);
+    let diff = |x: T, y: T| if x >= y { x - y } else { y - x };
+    let mut y = T::TWO;
+    let mut x;
+    let mut saved_y = y;
+    let mut q = T::ONE;
+    let mut g = T::ONE;
+    let mut r = 1;
+    let mut iterations = 0;
+    loop {
+        x = y;
+        for _ in 0..r {
+            y = f(y);
+        }
+        let mut k = 0;
+        while k < r && g == T::ONE {
+            saved_y = y;
+            for _ in 0..RHO_BATCH_SIZE.min(r - k) {
+                y = f(y);
+                q = q.mod_mul_precomputed(diff(x, y), n, &data);
+            }
+            g = q.gcd(n);
+            k += RHO_BATCH_SIZE;
+        }
+        if g != T::ONE {
+            break;
+        }
+        iterations += r;
+        if iterations >= RHO_ITERATION_LIMIT {
+            return None;
+        }
+        r <<= 1;
+    }
+    if g == n {
+        // The batch overshot; step through it one iteration at a time.
+        loop {
+            saved_y = f(saved_y);
+            g = diff(x, saved_y).gcd(n);
+            if g != T::ONE {
+                break;
+            }
+        }
+    }
+    if g == n {
+        None
+    } else {
+        Some(g)
+    }
+}}
+
+// A Montgomery curve $By^2 = x^3 + Ax^2 + x$ over $\Z/n\Z$, represented by $(A + 2) / 4$. Points
+// are represented in projective $(X : Z)$ coordinates, with the $y$-coordinate dropped.
+struct MontgomeryCurve<T: PrimitiveUnsigned> {
+    n: T,
+    a24: T,
+    data: <T as ModMulPrecomputed<T, T>>::Data,
+}
+
+impl<T: PrimitiveUnsigned> MontgomeryCurve<T> {
+    // Constructs the curve with Suyama parameter `sigma`, returning the curve and a starting point
+    // on it. If the construction fails because some number is not invertible modulo `n`, a factor
+    // of `n` may be returned instead.
+    fn suyama(n: T, sigma: T) -> Result<(MontgomeryCurve<T>, (T, T)), Option<T>> {
+        let data = T::precompute_mod_mul_data(&n);
+        let mul = |x: T, y: T| x.mod_mul_precomputed(y, n, &data);
+        let u = mul(sigma, sigma).mod_sub(T::from(5u8), n);
+        let v = mul(T::from(4u8), sigma);
+        let x = mul(mul(u, u), u);
+        let z = mul(mul(v, v), v);
+        let v_minus_u = v.mod_sub(u, n);
+        let numerator = mul(
+            mul(mul(v_minus_u, v_minus_u), v_minus_u),
+            mul(T::from(3u8), u).mod_add(v, n),
+        );
+        let denominator = mul(T::from(16u8), mul(x, v));
+        if denominator == T::ZERO {
+            return Err(None);
+        }
+        match denominator.mod_inverse(n) {
+            Some(inverse) => Ok((
+                MontgomeryCurve {
+                    n,
+                    a24: mul(numerator, inverse),
+                    data,
+                },
+                (x, z),
+            )),
+            None => Err(Some(denominator.gcd(n))),
+        }
+    }
+
+    fn mul(&self, x: T, y: T) -> T {
+        x.mod_mul_precomputed(y, self.n, &self.data)
+    }
+
+    // Computes $2P$.
+    fn double(&self, (x, z): (T, T)) -> (T, T) {
+        let n = self.n;
+        let sum = x.mod_add(z, n);
+        let difference = x.mod_sub(z, n);
+        let sum_squared = self.mul(sum, sum);
+        let difference_squared = self.mul(difference, difference);
+        let t = sum_squared.mod_sub(difference_squared, n);
+        (
+            self.mul(sum_squared, difference_squared),
+            self.mul(t, difference_squared.mod_add(self.mul(self.a24, t), n)),
+        )
+    }
+
+    // Computes $P + Q$, given $P - Q$.
+    fn add(&self, (xp, zp): (T, T), (xq, zq): (T, T), (xd, zd): (T, T)) -> (T, T) {
+        let n = self.n;
+        let u = self.mul(xp.mod_sub(zp, n), xq.mod_add(zq, n));
+        let v = self.mul(xp.mod_add(zp, n), xq.mod_sub(zq, n));
+        let sum = u.mod_add(v, n);
+        let difference = u.mod_sub(v, n);
+        (
+            self.mul(zd, self.mul(sum, sum)),
+            self.mul(xd, self.mul(difference, difference)),
+        )
+    }
+
+    // Computes $kP$, where $k > 0$, using the Montgomery ladder.
+    fn multiply(&self, k: u64, p: (T, T)) -> (T, T) {
+        let mut r0 = p;
+        let mut r1 = self.double(p);
+        for bit in k.bits().rev().skip(1) {
+            if bit {
+                r0 = self.add(r0, r1, p);
+                r1 = self.double(r1);
+            } else {
+                r1 = self.add(r0, r1, p);
+                r0 = self.double(r0);
+            }
+        }
+        r0
+    }
+}
+
+// Attempts to find a nontrivial factor of the odd composite number `n` using Lenstra's
+// elliptic-curve method with a single curve, given by the Suyama parameter `sigma`. Stage 1 uses
+// the bound `b1`, and stage 2 uses the bound `ECM_STAGE_2_RATIO * b1`.
+//
+// # Worst-case complexity
+// $T(n) = O(n)$
+//
+// $M(n) = O(1)$
+//
+// where $T$ is time, $M$ is additional memory, and $n$ is `b1`.
+pub_test! {ecm_one_curve<T: PrimitiveUnsigned>(n: T, sigma: u64, b1: u64) -> Option<T> {
+    let (curve, mut p) = match MontgomeryCurve::suyama(n, T::exact_from(sigma) % n) {
+        Ok(result) => result,
+        Err(factor) => return factor.filter(|&g| g != n),
+    };
+    // Stage 1: multiply by every prime power no greater than b1.
+    for q in u64::primes_less_than_or_equal_to(&b1) {
+        let mut power = q;
+        while power <= b1 / q {
+            power *= q;
+        }
+        p = curve.multiply(power, p);
+    }
+    let g = p.1.gcd(n);
+    if g != T::ONE {
+        return if g == n { None } else { Some(g) };
+    }
+    // Stage 2: look for a prime q with b1 < q <= b2 such that qP is the identity modulo a factor of
+    // n. Every such q is of the form mD ± j, where D is the step size and j is coprime to D.
+    let b2 = b1 * ECM_STAGE_2_RATIO;
+    let two_p = curve.double(p);
+    let mut baby_steps = Vec::new();
+    let mut previous = p;
+    let mut current = p;
+    let mut j = 1;
+    for target in ecm_stage_2_baby_steps() {
+        while j < target {
+            let next = if j == 1 {
+                curve.add(two_p, p, p)
+            } else {
+                curve.add(current, two_p, previous)
+            };
+            previous = current;
+            current = next;
+            j += 2;
+        }
+        baby_steps.push(current);
+    }
+    let m_start = (b1 / ECM_STAGE_2_STEP).max(1);
+    let step = curve.multiply(ECM_STAGE_2_STEP, p);
+    let mut current = curve.multiply(m_start * ECM_STAGE_2_STEP, p);
+    // When m_start is 1, the previous giant step is never used, since 2 * step is computed by
+    // doubling.
+    let mut previous = if m_start == 1 {
+        current
+    } else {
+        curve.multiply((m_start - 1) * ECM_STAGE_2_STEP, p)
+    };
+    let mut product = T::ONE;
+    for m in m_start..=b2 / ECM_STAGE_2_STEP + 1 {
+        for &(xs, zs) in &baby_steps {
+            let term = curve.mul(current.0, zs).mod_sub(curve.mul(xs, current.1), n);
+            product = curve.mul(product, term);
+        }
+        let next = if m == 1 {
+            curve.double(current)
+        } else {
+            curve.add(current, step, previous)
+        };
+        previous = current;
+        current = next;
+    }
+    let g = product.gcd(n);
+    if g == T::ONE || g == n {
+        None
+    } else {
+        Some(g)
+    }
+}}
+
+// Finds a nontrivial factor of the odd composite number `n`, which must have no prime factors less
+// than `TRIAL_DIVISION_LIMIT` and must not be a perfect power.
+//
+// # Worst-case complexity
+// Constant time and additional memory.
+fn find_factor<T: PrimitiveUnsigned>(n: T) -> T {
+    for c in 1..=RHO_ATTEMPTS {
+        if let Some(factor) = pollard_brent_rho(n, T::exact_from(c)) {
+            return factor;
+        }
+    }
+    let mut sigma = 6;
+    for (b1, curves) in ecm_schedule() {
+        for _ in 0..curves {
+            if let Some(factor) = ecm_one_curve(n, sigma, b1) {
+                return factor;
+            }
+            sigma += 1;
+        }
+    }
+    unreachable!()
+}
+
+// Factors `n`, which must be nonzero.
+//
+// # Worst-case complexity
+// Constant time and additional memory.
+fn factor_generic<T: PrimitiveUnsigned>(n: T) -> Vec<(T, u64)> {
+    assert_ne!(n, T::ZERO, "Cannot factor 0");
+    let mut factors = Vec::new();
+    let (n, known_prime) = trial_division(n, &mut factors);
+    if n == T::ONE {
+        return factors;
+    } else if known_prime {
+        factors.push((n, 1));
+        return factors;
+    }
+    let mut large_primes = Vec::new();
+    let mut composites = vec![n];
+    while let Some(n) = composites.pop() {
+        if n.is_prime() {
+            large_primes.push(n);
+        } else if let Some((root, k)) = perfect_power(n) {
+            for _ in 0..k {
+                composites.push(root);
+            }
+        } else {
+            let factor = find_factor(n);
+            composites.push(factor);
+            composites.push(n / factor);
+        }
+    }
+    large_primes.sort_unstable();
+    for p in large_primes {
+        match factors.last_mut() {
+            Some((q, exponent)) if *q == p => *exponent += 1,
+            _ => factors.push((p, 1)),
+        }
+    }
+    factors
+}
+
+macro_rules! impl_factor_promoted {
+    ($t:ident) => {
+        impl Factor for $t {
+            /// Finds the prime factorization of a number.
+            ///
+            /// The factorization is returned as a list of (prime, exponent) pairs, sorted by
+            /// prime. Small factors are found using trial division, and larger ones using
+            /// Pollard-Brent rho.
+            ///
+            /// # Worst-case complexity
+            /// Constant time and additional memory.
+            ///
+            /// # Panics
+            /// Panics if `self` is 0.
+            ///
+            /// # Examples
+            /// See [here](super::factor#factor).
+            fn factor(&self) -> Vec<($t, u64)> {
+                u64::from(*self)
+                    .factor()
+                    .into_iter()
+                    .map(|(p, e)| ($t::wrapping_from(p), e))
+                    .collect()
+            }
+        }
+    };
+}
+impl_factor_promoted!(u8);
+impl_factor_promoted!(u16);
+impl_factor_promoted!(u32);
+
+impl Factor for u64 {
+    /// Finds the prime factorization of a number.
+    ///
+    /// The factorization is returned as a list of (prime, exponent) pairs, sorted by prime. Small
+    /// factors are found using trial division, and larger ones using Pollard-Brent rho, falling
+    /// back to Lenstra's elliptic-curve method if rho is unlucky.
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Panics
+    /// Panics if `self` is 0.
+    ///
+    /// # Examples
+    /// See [here](super::factor#factor).
+    #[inline]
+    fn factor(&self) -> Vec<(u64, u64)> {
+        factor_generic(*self)
+    }
+}
+
+impl Factor for usize {
+    /// Finds the prime factorization of a number.
+    ///
+    /// The factorization is returned as a list of (prime, exponent) pairs, sorted by prime. Small
+    /// factors are found using trial division, and larger ones using Pollard-Brent rho, falling
+    /// back to Lenstra's elliptic-curve method if rho is unlucky.
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Panics
+    /// Panics if `self` is 0.
+    ///
+    /// # Examples
+    /// See [here](super::factor#factor).
+    fn factor(&self) -> Vec<(usize, u64)> {
+        u64::wrapping_from(*self)
+            .factor()
+            .into_iter()
+            .map(|(p, e)| (usize::wrapping_from(p), e))
+            .collect()
+    }
+}
+
+impl Factor for u128 {
+    /// Finds the prime factorization of a number.
+    ///
+    /// The factorization is returned as a list of (prime, exponent) pairs, sorted by prime. Small
+    /// factors are found using trial division, medium-sized ones using Pollard-Brent rho, and
+    /// large ones using Lenstra's elliptic-curve method. Every reported factor is checked for
+    /// primality.
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Panics
+    /// Panics if `self` is 0.
+    ///
+    /// # Examples
+    /// See [here](super::factor#factor).
+    fn factor(&self) -> Vec<(u128, u64)> {
+        if self.significant_bits() <= u64::WIDTH {
+            u64::wrapping_from(*self)
+                .factor()
+                .into_iter()
+                .map(|(p, e)| (u128::from(p), e))
+                .collect()
+        } else {
+            factor_generic(*self)
+        }
+    }
+}
@@ -0,0 +1,338 @@
+use crate::num::arithmetic::traits::{ModMulAssign, ModPowPrecomputed};
+use crate::num::basic::integers::PrimitiveInt;
+use crate::num::basic::unsigneds::PrimitiveUnsigned;
+use crate::num::conversion::traits::WrappingFrom;
+use crate::num::factorization::traits::{IsPrime, IsProbablePrime};
+use crate::num::logic::traits::{SignificantBits, TrailingZeros};
+
+// The odd primes less than 64. Every candidate is first checked for divisibility by these.
+pub(crate) const SMALL_ODD_PRIMES: [u8; 17] =
+    [3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37, 41, 43, 47, 53, 59, 61];
+
+// If every base in this set is a witness for the primality of an odd number less than $2^{32}$,
+// that number is prime. See Jaeschke, "On strong pseudoprimes to several bases", 1993.
+const MILLER_RABIN_BASES_U32: [u64; 3] = [2, 7, 61];
+
+// If every base in this set is a witness for the primality of an odd number less than $2^{64}$,
+// that number is prime. This set was found by Jim Sinclair in 2011.
+const MILLER_RABIN_BASES_U64: [u64; 7] = [2, 325, 9375, 28178, 450775, 9780504, 1795265022];
+
+// Checks `n` for divisibility by the primes less than 64. Returns `Some(true)` if `n` is one of
+// these primes, `Some(false)` if `n` is divisible by one of them (or is less than 2), and `None` if
+// the result is inconclusive.
+fn trial_division<T: PrimitiveUnsigned>(n: T) -> Option<bool> {
+    if n < T::TWO {
+        return Some(false);
+    } else if n.even() {
+        return Some(n == T::TWO);
+    }
+    for &p in &SMALL_ODD_PRIMES {
+        let p = T::from(p);
+        if n == p {
+            return Some(true);
+        } else if n.divisible_by(p) {
+            return Some(false);
+        }
+    }
+    if n < T::saturating_from(4096u16) {
+        // n is odd, has no prime factor less than 64, and is less than 64^2
+        Some(true)
+    } else {
+        None
+    }
+}
+
+// Raises `x` to the power `exp` modulo `m`, where `exp` may be as large as `m`. Uses a simple
+// left-to-right binary method.
+//
+// # Worst-case complexity
+// Constant time and additional memory.
+pub(crate) fn mod_pow_wide<T: PrimitiveUnsigned>(x: T, exp: T, m: T) -> T {
+    let data = T::precompute_mod_mul_data(&m);
+    let mut out = T::ONE;
+    for bit in exp.bits().rev() {
+        out.mod_mul_precomputed_assign(out, m, &data);
+        if bit {
+            out.mod_mul_precomputed_assign(x, m, &data);
+        }
+    }
+    out
+}
+
+// Returns whether `n` is a strong probable prime to base `a`. Assumes that `n` is odd and greater
+// than 2, and that $n - 1 = 2^sd$ with $d$ odd. A base that is divisible by `n` is treated as a
+// witness.
+//
+// # Worst-case complexity
+// Constant time and additional memory.
+fn u64_is_strong_probable_prime(
+    n: u64,
+    d: u64,
+    s: u64,
+    a: u64,
+    data: &<u64 as ModPowPrecomputed<u64, u64>>::Data,
+) -> bool {
+    let a = a % n;
+    if a == 0 {
+        return true;
+    }
+    let n_minus_1 = n - 1;
+    let mut x = a.mod_pow_precomputed(d, n, data);
+    if x == 1 || x == n_minus_1 {
+        return true;
+    }
+    for _ in 1..s {
+        x.mod_mul_assign(x, n);
+        if x == n_minus_1 {
+            return true;
+        } else if x == 1 {
+            return false;
+        }
+    }
+    false
+}
+
+// Returns whether `n` is prime, using trial division followed by the Miller-Rabin test with a set
+// of bases that is known to be deterministic for all 64-bit integers.
+//
+// # Worst-case complexity
+// Constant time and additional memory.
+pub_crate_test! {u64_is_prime(n: u64) -> bool {
+    if let Some(result) = trial_division(n) {
+        return result;
+    }
+    let n_minus_1 = n - 1;
+    let s = TrailingZeros::trailing_zeros(n_minus_1);
+    let d = n_minus_1 >> s;
+    let data = u64::precompute_mod_pow_data(&n);
+    let bases: &[u64] = if n >> 32 == 0 {
+        &MILLER_RABIN_BASES_U32
+    } else {
+        &MILLER_RABIN_BASES_U64
+    };
+    bases
+        .iter()
+        .all(|&a| u64_is_strong_probable_prime(n, d, s, a, &data))
+}}
+
+// Returns whether the odd number `n`, where $n > 2$, is a strong probable prime to base 2.
+//
+// # Worst-case complexity
+// Constant time and additional memory.
+pub_test! {is_strong_probable_prime_base_2<T: PrimitiveUnsigned>(n: T) -> bool {
+    let n_minus_1 = n - T::ONE;
+    let s = n_minus_1.trailing_zeros();
+    let d = n_minus_1 >> s;
+    let mut x = mod_pow_wide(T::TWO, d, n);
+    if x == T::ONE || x == n_minus_1 {
+        return true;
+    }
+    for _ in 1..s {
+        x.mod_square_assign(n);
+        if x == n_minus_1 {
+            return true;
+        } else if x == T::ONE {
+            return false;
+        }
+    }
+    false
+}}
+
+// Divides `x` by 2 modulo the odd number `n`, assuming `x < n`.
+fn mod_half<T: PrimitiveUnsigned>(x: T, n: T) -> T {
+    if x.even() {
+        x >> 1
+    } else {
+        // (x + n) / 2, computed without overflow
+        (x >> 1) + (n >> 1) + T::ONE
+    }
+}
+
+// Returns whether the odd number `n`, where $n > 2$ and `n` is not a perfect square, is a strong
+// Lucas probable prime, using Selfridge's method A to choose the parameters $P$ and $Q$.
+//
+// # Worst-case complexity
+// Constant time and additional memory.
+pub_test! {is_strong_lucas_probable_prime<T: PrimitiveUnsigned>(n: T) -> bool {
+    // Find the first D in 5, -7, 9, -11, 13, ... for which (D / n) = -1.
+    let mut abs_d = 5u64;
+    let mut negative = false;
+    let d = loop {
+        let abs_d_mod_n = T::exact_from(abs_d) % n;
+        let d = if negative && abs_d_mod_n != T::ZERO {
+            n - abs_d_mod_n
+        } else {
+            abs_d_mod_n
+        };
+        match d.jacobi_symbol(n) {
+            -1 => break d,
+            0 if abs_d_mod_n != T::ZERO => return false,
+            _ => {}
+        }
+        abs_d += 2;
+        negative = !negative;
+    };
+    // P = 1 and Q = (1 - D) / 4.
+    let q_abs = if negative { abs_d + 1 } else { abs_d - 1 } >> 2;
+    let q_abs = T::exact_from(q_abs) % n;
+    let q = if negative || q_abs == T::ZERO {
+        q_abs
+    } else {
+        n - q_abs
+    };
+    let two_q = q.mod_add(q, n);
+    let n_plus_1 = n + T::ONE;
+    let s = n_plus_1.trailing_zeros();
+    let k = n_plus_1 >> s;
+    // Compute U_k, V_k, and Q^k, scanning the bits of k from most to least significant.
+    let mut u = T::ONE;
+    let mut v = T::ONE;
+    let mut q_k = q;
+    let mut two_q_k = two_q;
+    for bit in k.bits().rev().skip(1) {
+        u.mod_mul_assign(v, n);
+        v.mod_square_assign(n);
+        v.mod_sub_assign(two_q_k, n);
+        q_k.mod_square_assign(n);
+        if bit {
+            let new_u = mod_half(u.mod_add(v, n), n);
+            v = mod_half(d.mod_mul(u, n).mod_add(v, n), n);
+            u = new_u;
+            q_k.mod_mul_assign(q, n);
+        }
+        two_q_k = q_k.mod_add(q_k, n);
+    }
+    if u == T::ZERO || v == T::ZERO {
+        return true;
+    }
+    for _ in 1..s {
+        v.mod_square_assign(n);
+        v.mod_sub_assign(two_q_k, n);
+        if v == T::ZERO {
+            return true;
+        }
+        q_k.mod_square_assign(n);
+        two_q_k = q_k.mod_add(q_k, n);
+    }
+    false
+}}
+
+// Returns whether `n` is a Baillie-PSW probable prime: that is, whether it is a strong probable
+// prime to base 2 and a strong Lucas probable prime. No composite number that passes this test is
+// known, and it is known that none exist below $2^{64}$.
+//
+// # Worst-case complexity
+// Constant time and additional memory.
+pub_crate_test! {is_baillie_psw_probable_prime<T: PrimitiveUnsigned>(n: T) -> bool {
+    if let Some(result) = trial_division(n) {
+        return result;
+    }
+    is_strong_probable_prime_base_2(n)
+        && n.checked_sqrt().is_none()
+        && is_strong_lucas_probable_prime(n)
+}}
+
+macro_rules! impl_is_prime_promoted {
+    ($t:ident) => {
+        impl IsPrime for $t {
+            /// Determines whether a number is prime.
+            ///
+            /// The result is always correct: every number of this type is first checked for
+            /// small factors, and then subjected to the Miller-Rabin test with a set of bases
+            /// that is known to detect every composite number less than $2^{32}$.
+            ///
+            /// # Worst-case complexity
+            /// Constant time and additional memory.
+            ///
+            /// # Examples
+            /// See [here](super::is_prime#is_prime).
+            #[inline]
+            fn is_prime(&self) -> bool {
+                u64_is_prime(u64::from(*self))
+            }
+        }
+    };
+}
+impl_is_prime_promoted!(u8);
+impl_is_prime_promoted!(u16);
+impl_is_prime_promoted!(u32);
+
+impl IsPrime for u64 {
+    /// Determines whether a number is prime.
+    ///
+    /// The result is always correct: the number is first checked for small factors, and then
+    /// subjected to the Miller-Rabin test with a set of bases that is known to detect every
+    /// composite number less than $2^{64}$.
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Examples
+    /// See [here](super::is_prime#is_prime).
+    #[inline]
+    fn is_prime(&self) -> bool {
+        u64_is_prime(*self)
+    }
+}
+
+impl IsPrime for usize {
+    /// Determines whether a number is prime.
+    ///
+    /// The result is always correct: the number is first checked for small factors, and then
+    /// subjected to the Miller-Rabin test with a set of bases that is known to detect every
+    /// composite number less than $2^{64}$.
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Examples
+    /// See [here](super::is_prime#is_prime).
+    #[inline]
+    fn is_prime(&self) -> bool {
+        u64_is_prime(u64::wrapping_from(*self))
+    }
+}
+
+impl IsPrime for u128 {
+    /// Determines whether a number is prime.
+    ///
+    /// If the number is less than $2^{64}$, the result is always correct. Otherwise, the
+    /// Baillie-PSW test is used. No composite number is known to pass this test, but it has not
+    /// been proven that none exist.
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Examples
+    /// See [here](super::is_prime#is_prime).
+    fn is_prime(&self) -> bool {
+        if self.significant_bits() <= u64::WIDTH {
+            u64_is_prime(u64::wrapping_from(*self))
+        } else {
+            is_baillie_psw_probable_prime(*self)
+        }
+    }
+}
+
+macro_rules! impl_is_probable_prime {
+    ($t:ident) => {
+        impl IsProbablePrime for $t {
+            /// Determines whether a number is a probable prime.
+            ///
+            /// For primitive integers, [`is_prime`](IsPrime::is_prime) is already as strong as
+            /// any number of additional Miller-Rabin rounds, so `extra_rounds` is ignored and the
+            /// result is identical to that of [`is_prime`](IsPrime::is_prime).
+            ///
+            /// # Worst-case complexity
+            /// Constant time and additional memory.
+            ///
+            /// # Examples
+            /// See [here](super::is_prime#is_probable_prime).
+            #[inline]
+            fn is_probable_prime(&self, _extra_rounds: u64) -> bool {
+                self.is_prime()
+            }
+        }
+    };
+}
+apply_to_unsigneds!(impl_is_probable_prime);
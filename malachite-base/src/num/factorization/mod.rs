@@ -1,3 +1,110 @@
+/// [`Factor`](traits::Factor), a trait for finding the prime factorization of a number.
+///
+/// # factor
+/// ```
+/// use malachite_base::num::factorization::traits::Factor;
+///
+/// assert_eq!(1u8.factor(), &[]);
+/// assert_eq!(12u8.factor(), &[(2, 2), (3, 1)]);
+/// assert_eq!(251u8.factor(), &[(251, 1)]);
+/// assert_eq!(1000000u32.factor(), &[(2, 6), (5, 6)]);
+/// assert_eq!(
+///     18446744073709551615u64.factor(),
+///     &[(3, 1), (5, 1), (17, 1), (257, 1), (641, 1), (65537, 1), (6700417, 1)]
+/// );
+/// assert_eq!(
+///     340282366920938463463374607431768211455u128.factor(),
+///     &[
+///         (3, 1),
+///         (5, 1),
+///         (17, 1),
+///         (257, 1),
+///         (641, 1),
+///         (65537, 1),
+///         (274177, 1),
+///         (6700417, 1),
+///         (67280421310721, 1)
+///     ]
+/// );
+/// ```
+pub mod factor;
+/// [`IsPrime`](traits::IsPrime) and [`IsProbablePrime`](traits::IsProbablePrime), traits for
+/// testing whether a number is prime.
+///
+/// # is_prime
+/// ```
+/// use malachite_base::num::factorization::traits::IsPrime;
+///
+/// assert_eq!(0u8.is_prime(), false);
+/// assert_eq!(1u16.is_prime(), false);
+/// assert_eq!(2u32.is_prime(), true);
+/// assert_eq!(97u64.is_prime(), true);
+/// assert_eq!(561u64.is_prime(), false);
+/// assert_eq!(4294967291u32.is_prime(), true);
+/// assert_eq!(18446744073709551557u64.is_prime(), true);
+/// assert_eq!(3825123056546413051u64.is_prime(), false);
+/// assert_eq!(170141183460469231731687303715884105727u128.is_prime(), true);
+/// ```
+///
+/// # is_probable_prime
+/// ```
+/// use malachite_base::num::factorization::traits::IsProbablePrime;
+///
+/// assert_eq!(0u8.is_probable_prime(0), false);
+/// assert_eq!(97u64.is_probable_prime(0), true);
+/// assert_eq!(561u64.is_probable_prime(5), false);
+/// assert_eq!(170141183460469231731687303715884105727u128.is_probable_prime(5), true);
+/// ```
+pub mod is_prime;
+/// [`NextPrime`](traits::NextPrime) and [`PrevPrime`](traits::PrevPrime), traits for finding the
+/// nearest prime greater than or less than a number.
+///
+/// # next_prime
+/// ```
+/// use malachite_base::num::factorization::traits::NextPrime;
+///
+/// assert_eq!(0u8.next_prime(), 2);
+/// assert_eq!(2u16.next_prime(), 3);
+/// assert_eq!(10u32.next_prime(), 11);
+/// assert_eq!(1000000u64.next_prime(), 1000003);
+/// assert_eq!(4294967290u64.next_prime(), 4294967291);
+/// ```
+///
+/// # prev_prime
+/// ```
+/// use malachite_base::num::factorization::traits::PrevPrime;
+///
+/// assert_eq!(3u8.prev_prime(), 2);
+/// assert_eq!(10u16.prev_prime(), 7);
+/// assert_eq!(1000003u32.prev_prime(), 999983);
+/// assert_eq!(u64::MAX.prev_prime(), 18446744073709551557);
+/// ```
+pub mod next_prime;
+/// [`PrimeCount`](traits::PrimeCount) and [`NthPrime`](traits::NthPrime), traits for counting the
+/// primes up to a number and for finding the $n$th prime.
+///
+/// # prime_count
+/// ```
+/// use malachite_base::num::factorization::traits::PrimeCount;
+///
+/// assert_eq!(0u8.prime_count(), 0);
+/// assert_eq!(10u8.prime_count(), 4);
+/// assert_eq!(100u16.prime_count(), 25);
+/// assert_eq!(1000000u32.prime_count(), 78498);
+/// assert_eq!(10000000000u64.prime_count(), 455052511);
+/// ```
+///
+/// # nth_prime
+/// ```
+/// use malachite_base::num::factorization::traits::NthPrime;
+///
+/// assert_eq!(u8::nth_prime(1), 2);
+/// assert_eq!(u8::nth_prime(54), 251);
+/// assert_eq!(u16::nth_prime(1000), 7919);
+/// assert_eq!(u32::nth_prime(1000000), 15485863);
+/// assert_eq!(u64::nth_prime(1000000000), 22801763489);
+/// ```
+pub mod prime_count;
 /// An efficient prime sieve.
 pub mod prime_sieve;
 /// [`Primes`](traits::Primes), a trait for generating prime numbers.
@@ -49,5 +156,32 @@ pub mod prime_sieve;
 /// );
 /// ```
 pub mod primes;
+/// [`PrimesInRange`](traits::PrimesInRange), a trait for generating the primes in a range.
+///
+/// # primes_in_range
+/// ```
+/// use itertools::Itertools;
+/// use malachite_base::num::factorization::traits::PrimesInRange;
+///
+/// assert_eq!(u8::primes_in_range(&10, &30).collect_vec(), &[11, 13, 17, 19, 23, 29]);
+/// assert_eq!(u16::primes_in_range(&30, &10).collect_vec(), &[]);
+/// assert_eq!(
+///     u64::primes_in_range(&1000000000000, &1000000000100).collect_vec(),
+///     &[1000000000039, 1000000000061, 1000000000063, 1000000000091]
+/// );
+/// ```
+///
+/// # primes_greater_than_or_equal_to
+/// ```
+/// use itertools::Itertools;
+/// use malachite_base::num::factorization::traits::PrimesInRange;
+///
+/// assert_eq!(u8::primes_greater_than_or_equal_to(&240).collect_vec(), &[241, 251]);
+/// assert_eq!(
+///     u32::primes_greater_than_or_equal_to(&1000000).take(3).collect_vec(),
+///     &[1000003, 1000033, 1000037]
+/// );
+/// ```
+pub mod primes_in_range;
 /// Various traits for generating primes, primality testing, and factorization.
 pub mod traits;
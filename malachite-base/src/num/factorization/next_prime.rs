@@ -0,0 +1,58 @@
+use crate::num::factorization::traits::{IsPrime, NextPrime, PrevPrime};
+
+macro_rules! impl_next_prime {
+    ($t:ident) => {
+        impl NextPrime for $t {
+            /// Finds the smallest prime greater than a number.
+            ///
+            /// Odd candidates are tested in turn using [`is_prime`](IsPrime::is_prime).
+            ///
+            /// # Worst-case complexity
+            /// Constant time and additional memory.
+            ///
+            /// # Panics
+            /// Panics if no prime greater than `self` is representable.
+            ///
+            /// # Examples
+            /// See [here](super::next_prime#next_prime).
+            fn next_prime(&self) -> $t {
+                if *self < 2 {
+                    return 2;
+                }
+                const OVERFLOW_MESSAGE: &str = "No prime greater than the input is representable";
+                let mut n = self.checked_add(1).expect(OVERFLOW_MESSAGE) | 1;
+                while !n.is_prime() {
+                    n = n.checked_add(2).expect(OVERFLOW_MESSAGE);
+                }
+                n
+            }
+        }
+
+        impl PrevPrime for $t {
+            /// Finds the largest prime less than a number.
+            ///
+            /// Odd candidates are tested in turn using [`is_prime`](IsPrime::is_prime).
+            ///
+            /// # Worst-case complexity
+            /// Constant time and additional memory.
+            ///
+            /// # Panics
+            /// Panics if `self` is less than or equal to 2.
+            ///
+            /// # Examples
+            /// See [here](super::next_prime#prev_prime).
+            fn prev_prime(&self) -> $t {
+                assert!(*self > 2, "There are no primes less than {}", self);
+                if *self == 3 {
+                    return 2;
+                }
+                let mut n = (*self - 2) | 1;
+                while !n.is_prime() {
+                    n -= 2;
+                }
+                n
+            }
+        }
+    };
+}
+apply_to_unsigneds!(impl_next_prime);
@@ -0,0 +1,386 @@
+use crate::num::arithmetic::traits::{FloorRoot, FloorSqrt};
+use crate::num::conversion::traits::ExactFrom;
+use crate::num::factorization::prime_sieve::{limbs_prime_sieve_size, limbs_prime_sieve_u64};
+use crate::num::factorization::traits::{NthPrime, PrimeCount, Primes, PrimesInRange};
+use crate::num::logic::traits::SignificantBits;
+
+// Below this bound, $\pi(x)$ is computed by sieving all of $[1, x]$.
+const SIEVE_THRESHOLD: u64 = 1 << 16;
+
+// The number of small primes that are crossed out before the segments are sieved.
+const PRESIEVED_PRIMES: usize = 6;
+
+// Returns $\pi(x)$ by sieving.
+fn prime_count_sieve(x: u64) -> u64 {
+    if x < 5 {
+        [0, 0, 1, 2, 2][usize::exact_from(x)]
+    } else {
+        let mut sieve = vec![0; limbs_prime_sieve_size::<u64>(x)];
+        // The sieve counts the primes in $[4, x]$.
+        limbs_prime_sieve_u64(&mut sieve, x) + 2
+    }
+}
+
+// A binary indexed tree over a segment of the sieve, used to count the numbers in a prefix of the
+// segment that have not been crossed out.
+struct FenwickTree {
+    tree: Vec<u32>,
+}
+
+impl FenwickTree {
+    // Creates a tree in which each position is 1 if the corresponding element of `xs` is `true`,
+    // and 0 otherwise.
+    fn new(xs: &[bool]) -> FenwickTree {
+        let len = xs.len();
+        let mut tree: Vec<u32> = xs.iter().map(|&x| u32::from(x)).collect();
+        for i in 0..len {
+            let j = i | (i + 1);
+            if j < len {
+                tree[j] += tree[i];
+            }
+        }
+        FenwickTree { tree }
+    }
+
+    fn decrement(&mut self, mut i: usize) {
+        while i < self.tree.len() {
+            self.tree[i] -= 1;
+            i |= i + 1;
+        }
+    }
+
+    // Returns the sum of positions $0$ through $i$, inclusive.
+    fn prefix_sum(&self, i: usize) -> u64 {
+        let mut sum = 0;
+        let mut i = i + 1;
+        while i != 0 {
+            sum += u64::from(self.tree[i - 1]);
+            i &= i - 1;
+        }
+        sum
+    }
+}
+
+// Returns $\pi(x)$ using the combinatorial algorithm of Lagarias, Miller, and Odlyzko.
+//
+// Let $y \geq x^{1/3}$, $z = \lfloor x/y \rfloor$, and $a = \pi(y)$. Then
+// $$
+// \pi(x) = \phi(x, a) + a - 1 - P_2(x, a),
+// $$
+// where $\phi(x, a)$ counts the integers in $[1, x]$ with no prime factor among the first $a$
+// primes, and
+// $$
+// P_2(x, a) = \sum_{y < p \leq \sqrt x} \left ( \pi \left ( \left \lfloor \frac{x}{p}
+// \right \rfloor \right ) - \pi(p) + 1 \right ).
+// $$
+// For a small $c$, $\phi(x, a)$ is split into the ordinary leaves
+// $$
+// \sum_{\substack{n \leq y \\ \operatorname{lpf}(n) > p_c}} \mu(n)
+// \phi \left ( \left \lfloor \frac{x}{n} \right \rfloor, c \right ),
+// $$
+// which are cheap because $\phi(t, c)$ is periodic in $t$, and the special leaves
+// $$
+// -\sum_{p_c < p \leq y} \sum_{\substack{m \leq y < mp \\ \operatorname{lpf}(m) > p}} \mu(m)
+// \phi \left ( \left \lfloor \frac{x}{mp} \right \rfloor, \pi(p) - 1 \right ),
+// $$
+// whose arguments are all at most $z$. The interval $[1, z]$ is sieved in segments by the primes
+// up to $y$, one prime at a time, and a binary indexed tree over each segment answers the $\phi$
+// queries. Once all the primes up to $y \geq \sqrt z$ have been crossed out, the numbers that remain
+// are 1 and the primes greater than $y$, so the same sieve also gives the values of $\pi$ needed
+// for $P_2$.
+fn prime_count_lmo(x: u64) -> u64 {
+    // Taking $y$ somewhat larger than $x^{1/3}$ balances the sieving against the special leaves.
+    let y = (x.floor_root(3) * (1 + x.significant_bits() / 10)).min(x.floor_sqrt());
+    let z = x / y;
+    let primes: Vec<u64> = u64::primes_less_than_or_equal_to(&y).collect();
+    let a = primes.len();
+    // The first `c` primes are crossed out in advance, using the fact that the pattern of integers
+    // coprime to their product $P$ repeats with period $P$. `phi_table[r]` is the number of
+    // integers in $[1, r]$ coprime to $P$, so that $\phi(t, c) = \lfloor t/P \rfloor \phi(P, c)
+    // + \phi(t \bmod P, c)$.
+    // Since $x \geq 2^{16}$, there are always at least `PRESIEVED_PRIMES` primes up to $y$.
+    let c = PRESIEVED_PRIMES;
+    let period: u64 = primes[..c].iter().product();
+    let period_usize = usize::exact_from(period);
+    let mut coprime = vec![true; period_usize];
+    for &p in &primes[..c] {
+        for r in (0..period_usize).step_by(usize::exact_from(p)) {
+            coprime[r] = false;
+        }
+    }
+    let mut phi_table = vec![0; period_usize + 1];
+    for r in 1..=period_usize {
+        phi_table[r] = phi_table[r - 1] + u64::from(coprime[r % period_usize]);
+    }
+    let phi_c =
+        |t: u64| t / period * phi_table[period_usize] + phi_table[usize::exact_from(t % period)];
+    // The least prime factor and Möbius function of each $n \leq y$. For $n = 1$ the least prime
+    // factor is taken to be infinite.
+    let y_usize = usize::exact_from(y);
+    let mut lpf = vec![0; y_usize + 1];
+    let mut mu = vec![1i8; y_usize + 1];
+    lpf[1] = u64::MAX;
+    for &p in &primes {
+        let p_usize = usize::exact_from(p);
+        for n in (p_usize..=y_usize).step_by(p_usize) {
+            if lpf[n] == 0 {
+                lpf[n] = p;
+            }
+            mu[n] = -mu[n];
+        }
+        if let Some(square) = p_usize.checked_mul(p_usize) {
+            for n in (square..=y_usize).step_by(square) {
+                mu[n] = 0;
+            }
+        }
+    }
+    // The ordinary leaves
+    let p_c = primes[c - 1];
+    let mut sum = 0i128;
+    for n in 1..=y {
+        let n_usize = usize::exact_from(n);
+        if lpf[n_usize] > p_c {
+            sum += i128::from(mu[n_usize]) * i128::from(phi_c(x / n));
+        }
+    }
+    // `phi[b]` is the number of integers in $[1, \mathit{low})$ with no prime factor among the
+    // first $b$ primes, and `m_max[b]` is the largest $m$ whose leaf with $p = p_{b+1}$ has not yet
+    // been processed.
+    let mut phi = vec![0u64; a + 1];
+    let mut m_max: Vec<u64> = primes
+        .iter()
+        .map(|&p| {
+            if p * p <= y {
+                y
+            } else {
+                u64::exact_from(a - 1)
+            }
+        })
+        .collect();
+    // Leaves whose argument $t$ satisfies $t \leq y$ and $t < p^2$ are easy: the integers in
+    // $[1, t]$ with no prime factor less than $p$ are 1 and the primes in $[p, t]$, so $\phi(t, b)$
+    // can be read off a table of $\pi$ up to $y$. Since $t$ increases as $m$ decreases, these
+    // leaves come first, and they are all handled before any sieving.
+    let mut pi_table = vec![0u64; y_usize + 1];
+    for &p in &primes {
+        pi_table[usize::exact_from(p)] = 1;
+    }
+    for n in 1..=y_usize {
+        pi_table[n] += pi_table[n - 1];
+    }
+    let easy_phi = |t: u64, p: u64, b: usize| {
+        if t < p {
+            1
+        } else {
+            1 + pi_table[usize::exact_from(t)] - u64::exact_from(b)
+        }
+    };
+    for (b, &p) in primes.iter().enumerate().skip(c) {
+        let easy_limit = y.min(p.saturating_mul(p) - 1);
+        if p * p <= y {
+            let mut m = m_max[b];
+            while m * p > y {
+                let m_usize = usize::exact_from(m);
+                if mu[m_usize] != 0 && lpf[m_usize] > p {
+                    let t = x / (m * p);
+                    if t > easy_limit {
+                        break;
+                    }
+                    sum -= i128::from(mu[m_usize]) * i128::from(easy_phi(t, p, b));
+                }
+                m -= 1;
+            }
+            m_max[b] = m;
+        } else {
+            let mut i = usize::exact_from(m_max[b]);
+            while i > b {
+                let t = x / (primes[i] * p);
+                if t > easy_limit {
+                    break;
+                }
+                sum += i128::from(easy_phi(t, p, b));
+                i -= 1;
+            }
+            m_max[b] = u64::exact_from(i);
+        }
+    }
+    let sqrt = x.floor_sqrt();
+    let mut p2_count = 0;
+    let segment_length = z.floor_sqrt().max(y).next_power_of_two();
+    let mut low = 1;
+    while low <= z {
+        let high = (low + segment_length).min(z + 1);
+        let len = usize::exact_from(high - low);
+        let mut sieve = Vec::with_capacity(len);
+        let mut r = usize::exact_from(low % period);
+        while sieve.len() < len {
+            let chunk = (period_usize - r).min(len - sieve.len());
+            sieve.extend_from_slice(&coprime[r..r + chunk]);
+            r = 0;
+        }
+        let mut tree = FenwickTree::new(&sieve);
+        for (b, &p) in primes.iter().enumerate().skip(c) {
+            if p * p <= y {
+                let mut m = m_max[b];
+                while m * p > y {
+                    let m_usize = usize::exact_from(m);
+                    if mu[m_usize] != 0 && lpf[m_usize] > p {
+                        let t = x / (m * p);
+                        if t >= high {
+                            break;
+                        }
+                        let count = phi[b] + tree.prefix_sum(usize::exact_from(t - low));
+                        sum -= i128::from(mu[m_usize]) * i128::from(count);
+                    }
+                    m -= 1;
+                }
+                m_max[b] = m;
+            } else {
+                // Here $m$ must be a prime $q$ with $p < q \leq y$, so $\mu(m) = -1$, and
+                // `m_max[b]` holds the index of $q$ in `primes` instead.
+                let mut i = usize::exact_from(m_max[b]);
+                while i > b {
+                    let t = x / (primes[i] * p);
+                    if t >= high {
+                        break;
+                    }
+                    sum += i128::from(phi[b] + tree.prefix_sum(usize::exact_from(t - low)));
+                    i -= 1;
+                }
+                m_max[b] = u64::exact_from(i);
+            }
+            phi[b] += tree.prefix_sum(len - 1);
+            let mut i = usize::exact_from(((low - 1) / p + 1) * p - low);
+            let p_usize = usize::exact_from(p);
+            while i < len {
+                if sieve[i] {
+                    sieve[i] = false;
+                    tree.decrement(i);
+                }
+                i += p_usize;
+            }
+        }
+        // Every prime $p$ with $y < p \leq \sqrt x$ and $\mathit{low} \leq x/p < \mathit{high}$
+        let p_min = y.max(x / high) + 1;
+        let p_max = sqrt.min(x / low);
+        if p_min <= p_max {
+            let ps: Vec<u64> = u64::primes_in_range(&p_min, &(p_max + 1)).collect();
+            for &p in ps.iter().rev() {
+                let count = phi[a] + tree.prefix_sum(usize::exact_from(x / p - low));
+                // $\pi(x/p) = a - 1 + \phi(x/p, a)$
+                sum -= i128::from(count + u64::exact_from(a) - 1);
+                p2_count += 1;
+            }
+        }
+        phi[a] += tree.prefix_sum(len - 1);
+        low = high;
+    }
+    // Add back $\sum_{y < p \leq \sqrt x} (\pi(p) - 1) = \sum_{k=a+1}^{a+c} (k - 1)$, where $c$ is
+    // the number of primes in $(y, \sqrt x]$.
+    let a = u64::exact_from(a);
+    let first = i128::from(a);
+    let last = i128::from(a + p2_count - 1);
+    sum += (first + last) * i128::from(p2_count) / 2;
+    u64::exact_from(sum + i128::from(a) - 1)
+}
+
+fn prime_count(x: u64) -> u64 {
+    if x < SIEVE_THRESHOLD {
+        prime_count_sieve(x)
+    } else {
+        prime_count_lmo(x)
+    }
+}
+
+// Returns the $n$th prime, where $p_1 = 2$.
+//
+// For small $n$, the primes are simply enumerated. Otherwise $x = n(\ln n + \ln \ln n - 1)$, which
+// is close to $p_n$, is computed, $\pi(x)$ is found using `prime_count`, and the primes between $x$
+// and $p_n$ are generated by a segmented sieve.
+fn nth_prime(n: u64) -> u64 {
+    assert_ne!(n, 0, "There is no 0th prime");
+    if n < 1 << 12 {
+        return u64::primes().nth(usize::exact_from(n - 1)).unwrap();
+    }
+    let ln_n = (n as f64).ln();
+    let x = (n as f64 * (ln_n + ln_n.ln() - 1.0 + (ln_n.ln() - 2.0) / ln_n)) as u64;
+    let mut count = prime_count(x);
+    if count < n {
+        // Walk forward from $x$.
+        u64::primes_greater_than_or_equal_to(&(x + 1))
+            .nth(usize::exact_from(n - count - 1))
+            .expect("The nth prime is not representable")
+    } else {
+        // Walk backward from $x$, one window at a time.
+        let mut high = x + 1;
+        loop {
+            let low = high.saturating_sub(1 << 16);
+            let ps: Vec<u64> = u64::primes_in_range(&low, &high).collect();
+            let len = u64::exact_from(ps.len());
+            if count - len < n {
+                return ps[usize::exact_from(n - (count - len) - 1)];
+            }
+            count -= len;
+            high = low;
+        }
+    }
+}
+
+macro_rules! impl_prime_count {
+    ($t:ident) => {
+        impl PrimeCount for $t {
+            /// Counts the primes less than or equal to a number.
+            ///
+            /// $f(x) = \pi(x)$.
+            ///
+            /// Small inputs are handled by a prime sieve. Larger ones use the combinatorial
+            /// algorithm of Lagarias, Miller, and Odlyzko, which only needs to sieve up to about
+            /// $x^{2/3}$.
+            ///
+            /// # Worst-case complexity
+            /// $T(n) = O(2^{2n/3})$
+            ///
+            /// $M(n) = O(2^{n/3})$
+            ///
+            /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+            ///
+            /// # Panics
+            /// Panics if `self` is greater than `u64::MAX`.
+            ///
+            /// # Examples
+            /// See [here](super::prime_count#prime_count).
+            #[inline]
+            fn prime_count(&self) -> u64 {
+                prime_count(u64::exact_from(*self))
+            }
+        }
+
+        impl NthPrime for $t {
+            /// Finds the $n$th prime, where the first prime is 2.
+            ///
+            /// $f(n) = p_n$.
+            ///
+            /// An estimate $x$ of $p_n$ is computed, $\pi(x)$ is found using
+            /// [`prime_count`](PrimeCount::prime_count), and the primes between $x$ and $p_n$ are
+            /// generated by a segmented sieve.
+            ///
+            /// # Worst-case complexity
+            /// $T(n) = O(n^{2/3})$
+            ///
+            /// $M(n) = O(n^{1/3})$
+            ///
+            /// where $T$ is time, $M$ is additional memory, and $n$ is `n`.
+            ///
+            /// # Panics
+            /// Panics if `n` is zero, or if the $n$th prime is not representable.
+            ///
+            /// # Examples
+            /// See [here](super::prime_count#nth_prime).
+            #[inline]
+            fn nth_prime(n: u64) -> $t {
+                $t::exact_from(nth_prime(n))
+            }
+        }
+    };
+}
+apply_to_unsigneds!(impl_prime_count);
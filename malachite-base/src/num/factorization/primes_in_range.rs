@@ -0,0 +1,166 @@
+use crate::num::basic::unsigneds::PrimitiveUnsigned;
+use crate::num::factorization::traits::PrimesInRange;
+
+// The number of integers in each segment of the sieve.
+const SEGMENT_LENGTH: usize = 1 << 12;
+
+// Each segment is sieved using the primes less than this bound. If the range extends beyond the
+// square of this bound, the numbers that survive sieving are then checked using `is_prime`.
+const SIEVE_LIMIT: u32 = 1 << 10;
+
+// Returns a vector whose `i`th entry is `false` if `start + i` is less than 2, or is divisible by
+// one of the `primes` without being equal to it. The square of each prime must be representable.
+//
+// # Worst-case complexity
+// $T(n, m) = O(n + m)$
+//
+// $M(n) = O(n)$
+//
+// where $T$ is time, $M$ is additional memory, $n$ is `len`, and $m$ is `primes.len()`.
+fn sieve_segment<T: PrimitiveUnsigned>(start: T, len: usize, primes: &[T]) -> Vec<bool> {
+    let mut segment = vec![true; len];
+    if start < T::TWO {
+        let small: usize = (T::TWO - start).exact_into();
+        for x in segment.iter_mut().take(small) {
+            *x = false;
+        }
+    }
+    for &p in primes {
+        // Smaller multiples of p have smaller prime factors, so crossing out starts at p^2.
+        let square = p * p;
+        let mut i: usize = if start <= square {
+            (square - start).saturating_into()
+        } else {
+            let remainder = start % p;
+            if remainder == T::ZERO {
+                0
+            } else {
+                (p - remainder).exact_into()
+            }
+        };
+        let p_usize: usize = p.exact_into();
+        while i < len {
+            segment[i] = false;
+            i += p_usize;
+        }
+    }
+    segment
+}
+
+/// An iterator that generates all primes in a range.
+///
+/// This `struct` is created by [`PrimesInRange::primes_in_range`] and
+/// [`PrimesInRange::primes_greater_than_or_equal_to`]; see their documentation for more.
+#[derive(Clone, Debug)]
+pub struct PrimesInRangeIterator<T: PrimitiveUnsigned> {
+    // The first number of the current segment
+    start: T,
+    // The end of the range, which is excluded
+    end: T,
+    sieve_primes: Vec<T>,
+    // Whether every number that survives sieving is prime
+    exact: bool,
+    segment: Vec<bool>,
+    i: usize,
+}
+
+impl<T: PrimitiveUnsigned> PrimesInRangeIterator<T> {
+    fn new(a: T, b: T) -> PrimesInRangeIterator<T> {
+        let (sieve_primes, exact) = if b <= a || b <= T::TWO {
+            (Vec::new(), true)
+        } else {
+            let sqrt = (b - T::ONE).floor_sqrt();
+            let limit = T::saturating_from(SIEVE_LIMIT);
+            (
+                T::primes_less_than_or_equal_to(&sqrt.min(limit)).collect(),
+                sqrt < limit,
+            )
+        };
+        PrimesInRangeIterator {
+            start: a,
+            end: b,
+            sieve_primes,
+            exact,
+            segment: Vec::new(),
+            i: 0,
+        }
+    }
+}
+
+impl<T: PrimitiveUnsigned> Iterator for PrimesInRangeIterator<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        loop {
+            while self.i < self.segment.len() {
+                let i = self.i;
+                self.i += 1;
+                if self.segment[i] {
+                    let n = self.start + T::wrapping_from(i);
+                    if self.exact || n.is_prime() {
+                        return Some(n);
+                    }
+                }
+            }
+            self.start += T::wrapping_from(self.segment.len());
+            if self.start >= self.end {
+                return None;
+            }
+            let len: usize = (self.end - self.start).saturating_into();
+            let len = len.min(SEGMENT_LENGTH);
+            self.segment = sieve_segment(self.start, len, &self.sieve_primes);
+            self.i = 0;
+        }
+    }
+}
+
+macro_rules! impl_primes_in_range {
+    ($t:ident) => {
+        impl PrimesInRange for $t {
+            type I = PrimesInRangeIterator<$t>;
+
+            /// Returns an iterator that generates all primes $p$ with $a \leq p < b$.
+            ///
+            /// The range is sieved in segments, using the primes up to a fixed bound, so it is
+            /// efficient even when $a$ is large. If $b$ is larger than the square of that bound,
+            /// each number that survives sieving is then checked with
+            /// [`is_prime`](crate::num::factorization::traits::IsPrime::is_prime).
+            ///
+            /// # Worst-case complexity (amortized)
+            /// $T(i) = O(\log i)$
+            ///
+            /// $M(i) = O(1)$
+            ///
+            /// where $T$ is time, $M$ is additional memory, and $i$ is the iteration index.
+            ///
+            /// # Examples
+            /// See [here](super::primes_in_range#primes_in_range).
+            #[inline]
+            fn primes_in_range(a: &$t, b: &$t) -> PrimesInRangeIterator<$t> {
+                PrimesInRangeIterator::new(*a, *b)
+            }
+
+            /// Returns an iterator that generates all primes greater than or equal to a given
+            /// value that fit into the type.
+            ///
+            /// The iterator produced by `primes_greater_than_or_equal_to(a)` generates the same
+            /// primes as the iterator produced by `primes_in_range(a, T::MAX)`; `T::MAX` is never
+            /// prime.
+            ///
+            /// # Worst-case complexity (amortized)
+            /// $T(i) = O(\log i)$
+            ///
+            /// $M(i) = O(1)$
+            ///
+            /// where $T$ is time, $M$ is additional memory, and $i$ is the iteration index.
+            ///
+            /// # Examples
+            /// See [here](super::primes_in_range#primes_greater_than_or_equal_to).
+            #[inline]
+            fn primes_greater_than_or_equal_to(a: &$t) -> PrimesInRangeIterator<$t> {
+                PrimesInRangeIterator::new(*a, $t::MAX)
+            }
+        }
+    };
+}
+apply_to_unsigneds!(impl_primes_in_range);
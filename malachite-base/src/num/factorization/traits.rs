@@ -8,3 +8,50 @@ pub trait Primes {
 
     fn primes() -> Self::I;
 }
+
+/// Determines whether a number is prime.
+pub trait IsPrime {
+    fn is_prime(&self) -> bool;
+}
+
+/// Determines whether a number is a probable prime, optionally performing some additional
+/// Miller-Rabin rounds on top of the default test.
+pub trait IsProbablePrime {
+    fn is_probable_prime(&self, extra_rounds: u64) -> bool;
+}
+
+/// Finds the prime factorization of a number.
+///
+/// The factorization is returned as a list of (prime, exponent) pairs, sorted by prime.
+pub trait Factor: Sized {
+    fn factor(&self) -> Vec<(Self, u64)>;
+}
+
+/// Finds the smallest prime greater than a number.
+pub trait NextPrime {
+    fn next_prime(&self) -> Self;
+}
+
+/// Finds the largest prime less than a number.
+pub trait PrevPrime {
+    fn prev_prime(&self) -> Self;
+}
+
+/// Counts the primes less than or equal to a number.
+pub trait PrimeCount {
+    fn prime_count(&self) -> u64;
+}
+
+/// Finds the $n$th prime, where the first prime is 2.
+pub trait NthPrime {
+    fn nth_prime(n: u64) -> Self;
+}
+
+/// Generates the primes in a range, which may start at an arbitrary value.
+pub trait PrimesInRange: Sized {
+    type I: Iterator<Item = Self>;
+
+    fn primes_in_range(a: &Self, b: &Self) -> Self::I;
+
+    fn primes_greater_than_or_equal_to(a: &Self) -> Self::I;
+}
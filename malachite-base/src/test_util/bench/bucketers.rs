@@ -587,6 +587,15 @@ where
     }
 }
 
+pub fn quadruple_3_bit_bucketer<T, U, V: Copy + SignificantBits, W>(
+    z_name: &str,
+) -> Bucketer<(T, U, V, W)> {
+    Bucketer {
+        bucketing_function: &|&(_, _, z, _)| usize::exact_from(z.significant_bits()),
+        bucketing_label: format!("{}.significant_bits()", z_name),
+    }
+}
+
 pub fn pair_1_vec_len_bucketer<T, U>(xs_name: &str) -> Bucketer<(Vec<T>, U)> {
     Bucketer {
         bucketing_function: &|&(ref xs, _)| xs.len(),
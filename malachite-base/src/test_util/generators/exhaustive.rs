@@ -2787,6 +2787,16 @@ pub fn exhaustive_unsigned_triple_gen_var_20<T: PrimitiveUnsigned>() -> It<(T, T
     ))
 }
 
+pub fn exhaustive_unsigned_triple_gen_var_21<T: PrimitiveUnsigned>() -> It<(T, T, T)> {
+    Box::new(exhaustive_triples_xxy_custom_output(
+        exhaustive_unsigneds(),
+        exhaustive_positive_primitive_ints(),
+        BitDistributorOutputType::tiny(),
+        BitDistributorOutputType::tiny(),
+        BitDistributorOutputType::normal(1),
+    ))
+}
+
 // -- (PrimitiveUnsigned, PrimitiveUnsigned, PrimitiveUnsigned, PrimitiveUnsigned) --
 
 pub fn exhaustive_unsigned_quadruple_gen_var_1<T: PrimitiveUnsigned, U: PrimitiveUnsigned>(
@@ -2433,6 +2433,15 @@ pub fn unsigned_triple_gen_var_23<T: PrimitiveUnsigned>() -> Generator<(T, T, T)
     )
 }
 
+// All `(T, T, T)` where `T` is unsigned, the first two elements are small, and the third is small
+// and positive.
+pub fn unsigned_triple_gen_var_24<T: PrimitiveUnsigned>() -> Generator<(T, T, T)> {
+    Generator::new_no_special(
+        &exhaustive_unsigned_triple_gen_var_21,
+        &random_unsigned_triple_gen_var_11,
+    )
+}
+
 // -- (PrimitiveUnsigned, PrimitiveUnsigned, PrimitiveUnsigned, PrimitiveUnsigned) --
 
 // All `(T, u64, u64, U)` where `T` and `U` are unsigned, both `u64`s are small, and the four values
@@ -3231,6 +3240,11 @@ pub fn unsigned_vec_gen_var_6<T: PrimitiveUnsigned>() -> Generator<Vec<T>> {
     )
 }
 
+// All `Vec`s of small unsigneds.
+pub fn unsigned_vec_gen_var_7<T: PrimitiveUnsigned>() -> Generator<Vec<T>> {
+    Generator::new_no_special(&exhaustive_unsigned_vec_gen, &random_unsigned_vec_gen_var_1)
+}
+
 // -- (Vec<PrimitiveUnsigned>, PrimitiveUnsigned) --
 
 pub fn unsigned_vec_unsigned_pair_gen<T: PrimitiveUnsigned, U: PrimitiveUnsigned>(
@@ -3956,6 +3956,28 @@ pub fn random_unsigned_triple_gen_var_10<T: PrimitiveUnsigned>(
     ))
 }
 
+pub fn random_unsigned_triple_gen_var_11<T: PrimitiveUnsigned>(
+    config: &GenConfig,
+) -> It<(T, T, T)> {
+    Box::new(random_triples_xxy(
+        EXAMPLE_SEED,
+        &|seed| {
+            geometric_random_unsigneds(
+                seed,
+                config.get_or("mean_small_n", 32),
+                config.get_or("mean_small_d", 1),
+            )
+        },
+        &|seed| {
+            geometric_random_positive_unsigneds(
+                seed,
+                config.get_or("mean_modulus_n", 1024),
+                config.get_or("mean_modulus_d", 1),
+            )
+        },
+    ))
+}
+
 // -- (PrimitiveUnsigned, PrimitiveUnsigned, PrimitiveUnsigned, PrimitiveUnsigned) --
 
 struct ModPowerOfTwoTripleGenerator<T: PrimitiveUnsigned> {
@@ -5077,6 +5099,21 @@ pub fn random_primitive_int_vec_gen_var_5<T: PrimitiveInt>(config: &GenConfig) -
     ))
 }
 
+pub fn random_unsigned_vec_gen_var_1<T: PrimitiveUnsigned>(config: &GenConfig) -> It<Vec<T>> {
+    Box::new(random_vecs(
+        EXAMPLE_SEED,
+        &|seed| {
+            geometric_random_unsigneds(
+                seed,
+                config.get_or("mean_small_n", 32),
+                config.get_or("mean_small_d", 1),
+            )
+        },
+        config.get_or("mean_length_n", 4),
+        config.get_or("mean_length_d", 1),
+    ))
+}
+
 // --(Vec<PrimitiveInt>, PrimitiveInt) --
 
 pub fn random_primitive_int_vec_primitive_int_pair_gen<T: PrimitiveInt, U: PrimitiveInt>(
@@ -0,0 +1,92 @@
+use crate::num::basic::unsigneds::PrimitiveUnsigned;
+use crate::num::conversion::traits::ExactFrom;
+
+fn stirling_step<T: PrimitiveUnsigned>(
+    m: u64,
+    current: Option<T>,
+    previous: Option<T>,
+) -> Option<T> {
+    let current = current?;
+    let product = if current == T::ZERO {
+        T::ZERO
+    } else {
+        T::try_from(m).ok()?.checked_mul(current)?
+    };
+    product.checked_add(previous?)
+}
+
+// Computes the whole triangle of values $X(i, j)$ for $i \leq n$ and $j \leq \min(i, k)$, where
+// $X(i + 1, j) = m(i, j)X(i, j) + X(i, j - 1)$. A `None` marks a value that overflowed, and it
+// propagates to every value that depends on it.
+fn checked_stirling_naive<T: PrimitiveUnsigned>(
+    n: u64,
+    k: u64,
+    multiplier: &dyn Fn(u64, u64) -> u64,
+) -> Option<T> {
+    if k > n {
+        return Some(T::ZERO);
+    }
+    let k = usize::exact_from(k);
+    let mut row = vec![Some(T::ONE)];
+    for i in 0..n {
+        let mut next = vec![Some(T::ZERO); row.len() + 1];
+        for (j, x) in next.iter_mut().enumerate().take(k + 1) {
+            let previous = if j == 0 { Some(T::ZERO) } else { row[j - 1] };
+            let current = row.get(j).copied().unwrap_or(Some(T::ZERO));
+            *x = stirling_step(multiplier(i, u64::exact_from(j)), current, previous);
+        }
+        next.truncate(k + 1);
+        row = next;
+    }
+    row[k]
+}
+
+pub fn checked_stirling_first_naive<T: PrimitiveUnsigned>(n: u64, k: u64) -> Option<T> {
+    checked_stirling_naive(n, k, &|i, _| i)
+}
+
+pub fn checked_stirling_second_naive<T: PrimitiveUnsigned>(n: u64, k: u64) -> Option<T> {
+    checked_stirling_naive(n, k, &|_, j| j)
+}
+
+// Uses $B_{n+1} = \sum_{k=0}^n \binom{n}{k} B_k$.
+pub fn checked_bell_naive<T: PrimitiveUnsigned>(n: u64) -> Option<T> {
+    let mut bells = vec![T::ONE];
+    for i in 0..n {
+        let mut b = T::ZERO;
+        for (k, &x) in bells.iter().enumerate() {
+            let binomial =
+                T::checked_binomial_coefficient(T::try_from(i).ok()?, T::try_from(k).ok()?)?;
+            b = b.checked_add(binomial.checked_mul(x)?)?;
+        }
+        bells.push(b);
+    }
+    bells.pop()
+}
+
+// Uses $C_{n+1} = \sum_{i=0}^n C_iC_{n-i}$.
+pub fn checked_catalan_naive<T: PrimitiveUnsigned>(n: u64) -> Option<T> {
+    let mut catalans = vec![T::ONE];
+    for _ in 0..n {
+        let mut c = T::ZERO;
+        for (&x, &y) in catalans.iter().zip(catalans.iter().rev()) {
+            c = c.checked_add(x.checked_mul(y)?)?;
+        }
+        catalans.push(c);
+    }
+    catalans.pop()
+}
+
+// Counts the partitions of $i$ into parts no larger than $j$, for each $j$ in turn, using
+// $p_j(i) = p_{j-1}(i) + p_j(i - j)$.
+pub fn checked_partition_count_naive<T: PrimitiveUnsigned>(n: u64) -> Option<T> {
+    let n = usize::exact_from(n);
+    let mut ps = vec![T::ZERO; n + 1];
+    ps[0] = T::ONE;
+    for j in 1..=n {
+        for i in j..=n {
+            ps[i] = ps[i].checked_add(ps[i - j])?;
+        }
+    }
+    Some(ps[n])
+}
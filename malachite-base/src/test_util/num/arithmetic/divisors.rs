@@ -0,0 +1,17 @@
+use crate::num::basic::unsigneds::PrimitiveUnsigned;
+use crate::num::exhaustive::primitive_int_increasing_inclusive_range;
+
+pub fn divisors_naive<T: PrimitiveUnsigned>(n: T) -> Vec<T> {
+    assert_ne!(n, T::ZERO);
+    primitive_int_increasing_inclusive_range(T::ONE, n)
+        .filter(|&d| n.divisible_by(d))
+        .collect()
+}
+
+pub fn checked_divisor_sum_naive<T: PrimitiveUnsigned>(n: T, k: u64) -> Option<T> {
+    let mut sum = T::ZERO;
+    for d in divisors_naive(n) {
+        sum = sum.checked_add(d.checked_pow(k)?)?;
+    }
+    Some(sum)
+}
@@ -0,0 +1,17 @@
+use crate::num::basic::unsigneds::PrimitiveUnsigned;
+
+pub fn mod_lucas_sequence_naive<T: PrimitiveUnsigned>(p: T, q: T, n: u64, m: T) -> (T, T) {
+    assert!(p < m);
+    assert!(q < m);
+    let (mut u, mut u_next) = (T::ZERO, T::ONE % m);
+    let (mut v, mut v_next) = (T::TWO % m, p);
+    for _ in 0..n {
+        let u_next_next = p.mod_mul(u_next, m).mod_sub(q.mod_mul(u, m), m);
+        let v_next_next = p.mod_mul(v_next, m).mod_sub(q.mod_mul(v, m), m);
+        u = u_next;
+        u_next = u_next_next;
+        v = v_next;
+        v_next = v_next_next;
+    }
+    (u, v)
+}
@@ -1,8 +1,18 @@
+pub mod combinatorics;
+pub mod divisors;
 pub mod extended_gcd;
 pub mod factorial;
+pub mod fibonacci;
 pub mod kronecker_symbol;
+pub mod mod_factorial;
 pub mod mod_inverse;
+pub mod mod_log;
 pub mod mod_mul;
 pub mod mod_pow;
 pub mod mod_power_of_2_inverse;
+pub mod mod_sqrt;
+pub mod moebius;
+pub mod multiplicative_order;
+pub mod perfect_power;
 pub mod primorial;
+pub mod totient;
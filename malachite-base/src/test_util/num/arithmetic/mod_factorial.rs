@@ -0,0 +1,31 @@
+use crate::num::basic::unsigneds::PrimitiveUnsigned;
+use crate::num::exhaustive::primitive_int_increasing_range;
+
+pub fn mod_factorial_naive<T: PrimitiveUnsigned>(n: T, m: T) -> T {
+    assert_ne!(m, T::ZERO);
+    let mut f = T::ONE % m;
+    for i in primitive_int_increasing_range(T::ZERO, n) {
+        f.mod_mul_assign((i + T::ONE) % m, m);
+    }
+    f
+}
+
+// Computes row $n$ of Pascal's triangle modulo $m$.
+pub fn mod_binomial_coefficient_naive<T: PrimitiveUnsigned>(n: T, k: T, m: T) -> T {
+    assert_ne!(m, T::ZERO);
+    if k > n {
+        return T::ZERO;
+    }
+    let mut row = vec![T::ONE % m];
+    for _ in primitive_int_increasing_range(T::ZERO, n) {
+        let mut next = Vec::with_capacity(row.len() + 1);
+        next.push(T::ONE % m);
+        for (&x, &y) in row.iter().zip(row.iter().skip(1)) {
+            next.push(x.mod_add(y, m));
+        }
+        next.push(T::ONE % m);
+        row = next;
+    }
+    let k: usize = k.exact_into();
+    row[k]
+}
@@ -0,0 +1,16 @@
+use crate::num::basic::unsigneds::PrimitiveUnsigned;
+use crate::num::exhaustive::primitive_int_increasing_inclusive_range;
+
+pub fn mod_log_naive<T: PrimitiveUnsigned>(x: T, base: T, m: T) -> Option<T> {
+    assert!(x < m);
+    assert!(base < m);
+    // The powers of the base are periodic after at most log_2(m) steps, with period at most m - 1.
+    let mut power = T::ONE % m;
+    for i in primitive_int_increasing_inclusive_range(T::ZERO, m) {
+        if power == x {
+            return Some(i);
+        }
+        power.mod_mul_assign(base, m);
+    }
+    None
+}
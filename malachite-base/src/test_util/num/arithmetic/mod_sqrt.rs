@@ -0,0 +1,7 @@
+use crate::num::basic::unsigneds::PrimitiveUnsigned;
+use crate::num::exhaustive::primitive_int_increasing_range;
+
+pub fn mod_sqrt_naive<T: PrimitiveUnsigned>(x: T, m: T) -> Option<T> {
+    assert!(x < m);
+    primitive_int_increasing_range(T::ZERO, m).find(|&r| r.mod_square(m) == x)
+}
@@ -0,0 +1,16 @@
+use crate::num::basic::unsigneds::PrimitiveUnsigned;
+use crate::num::exhaustive::primitive_int_increasing_inclusive_range;
+
+pub fn moebius_naive<T: PrimitiveUnsigned>(n: T) -> i8 {
+    assert_ne!(n, T::ZERO);
+    let mut mu = 1;
+    for p in primitive_int_increasing_inclusive_range(T::ONE, n) {
+        if p.is_prime() && n.divisible_by(p) {
+            if (n / p).divisible_by(p) {
+                return 0;
+            }
+            mu = -mu;
+        }
+    }
+    mu
+}
@@ -0,0 +1,23 @@
+use crate::num::basic::unsigneds::PrimitiveUnsigned;
+use crate::num::exhaustive::primitive_int_increasing_range;
+
+pub fn multiplicative_order_naive<T: PrimitiveUnsigned>(x: T, m: T) -> Option<T> {
+    assert!(x < m);
+    if !x.coprime_with(m) {
+        return None;
+    }
+    let one = T::ONE % m;
+    let mut power = x;
+    let mut order = T::ONE;
+    while power != one {
+        power.mod_mul_assign(x, m);
+        order += T::ONE;
+    }
+    Some(order)
+}
+
+pub fn primitive_root_naive<T: PrimitiveUnsigned>(m: T) -> Option<T> {
+    let totient = m.totient();
+    primitive_int_increasing_range(T::ZERO, m)
+        .find(|&g| multiplicative_order_naive(g, m) == Some(totient))
+}
@@ -0,0 +1,14 @@
+use crate::num::basic::unsigneds::PrimitiveUnsigned;
+
+pub fn perfect_power_decomposition_naive<T: PrimitiveUnsigned>(n: T) -> (T, u64) {
+    if n <= T::ONE {
+        return (n, 1);
+    }
+    for k in (2..n.significant_bits()).rev() {
+        let root = n.floor_root(k);
+        if root.pow(k) == n {
+            return (root, k);
+        }
+    }
+    (n, 1)
+}
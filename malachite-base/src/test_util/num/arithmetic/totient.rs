@@ -0,0 +1,32 @@
+use crate::num::basic::unsigneds::PrimitiveUnsigned;
+use crate::num::exhaustive::primitive_int_increasing_range;
+
+pub fn totient_naive<T: PrimitiveUnsigned>(n: T) -> T {
+    assert_ne!(n, T::ZERO);
+    let mut count = T::ZERO;
+    let mut k = T::ONE;
+    loop {
+        if k.coprime_with(n) {
+            count += T::ONE;
+        }
+        if k == n {
+            return count;
+        }
+        k += T::ONE;
+    }
+}
+
+pub fn carmichael_lambda_naive<T: PrimitiveUnsigned>(n: T) -> T {
+    assert_ne!(n, T::ZERO);
+    if n == T::ONE {
+        return T::ONE;
+    }
+    let units: Vec<T> = primitive_int_increasing_range(T::ONE, n)
+        .filter(|&a| a.coprime_with(n))
+        .collect();
+    let mut m = 1;
+    while !units.iter().all(|&a| a.mod_pow(m, n) == T::ONE) {
+        m += 1;
+    }
+    T::exact_from(m)
+}
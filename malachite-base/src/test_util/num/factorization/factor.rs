@@ -0,0 +1,22 @@
+use crate::num::basic::unsigneds::PrimitiveUnsigned;
+
+pub fn factor_naive<T: PrimitiveUnsigned>(mut n: T) -> Vec<(T, u64)> {
+    assert_ne!(n, T::ZERO);
+    let mut factors = Vec::new();
+    let mut p = T::TWO;
+    while n / p >= p {
+        let mut exponent = 0;
+        while n.divisible_by(p) {
+            n /= p;
+            exponent += 1;
+        }
+        if exponent != 0 {
+            factors.push((p, exponent));
+        }
+        p += T::ONE;
+    }
+    if n != T::ONE {
+        factors.push((n, 1));
+    }
+    factors
+}
@@ -1,2 +1,3 @@
+pub mod factor;
 pub mod prime_sieve;
 pub mod primes;
@@ -5,8 +5,8 @@ use crate::num::factorization::prime_sieve::{id_to_n, limbs_count_ones, n_to_bit
 use crate::num::logic::traits::{NotAssign, TrailingZeros};
 use crate::slices::slice_leading_zeros;
 
-// Replace with good primality test once we have one
-fn is_prime_simple(n: u64) -> bool {
+// A trial-division test, kept independent of `IsPrime` so that it can serve as an oracle for it
+pub fn is_prime_simple(n: u64) -> bool {
     match n {
         0 | 1 => false,
         2 => true,
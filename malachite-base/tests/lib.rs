@@ -177,6 +177,7 @@ pub mod num {
         pub mod arithmetic_checked_shl;
         pub mod arithmetic_checked_shr;
         pub mod binomial_coefficient;
+        pub mod catalan;
         pub mod ceiling;
         pub mod checked_abs;
         pub mod checked_add_mul;
@@ -190,10 +191,12 @@ pub mod num {
         pub mod div_round;
         pub mod divisible_by;
         pub mod divisible_by_power_of_2;
+        pub mod divisors;
         pub mod eq_mod;
         pub mod eq_mod_power_of_2;
         pub mod extended_gcd;
         pub mod factorial;
+        pub mod fibonacci;
         pub mod floor;
         pub mod gcd;
         pub mod is_power_of_2;
@@ -203,8 +206,11 @@ pub mod num {
         pub mod log_base_2;
         pub mod log_base_power_of_2;
         pub mod mod_add;
+        pub mod mod_binomial_coefficient;
+        pub mod mod_factorial;
         pub mod mod_inverse;
         pub mod mod_is_reduced;
+        pub mod mod_log;
         pub mod mod_mul;
         pub mod mod_neg;
         pub mod mod_op;
@@ -222,8 +228,13 @@ pub mod num {
         pub mod mod_power_of_2_sub;
         pub mod mod_shl;
         pub mod mod_shr;
+        pub mod mod_sqrt;
         pub mod mod_square;
         pub mod mod_sub;
+        pub mod mod_unsigned;
+        pub mod moebius;
+        pub mod multinomial;
+        pub mod multiplicative_order;
         pub mod neg;
         pub mod next_power_of_2;
         pub mod overflowing_abs;
@@ -237,6 +248,8 @@ pub mod num {
         pub mod overflowing_sub;
         pub mod overflowing_sub_mul;
         pub mod parity;
+        pub mod partition_count;
+        pub mod perfect_power;
         pub mod pow;
         pub mod power_of_2;
         pub mod primorial;
@@ -258,7 +271,9 @@ pub mod num {
         pub mod sign;
         pub mod sqrt;
         pub mod square;
+        pub mod stirling;
         pub mod sub_mul;
+        pub mod totient;
         pub mod wrapping_abs;
         pub mod wrapping_add;
         pub mod wrapping_add_mul;
@@ -373,8 +388,13 @@ pub mod num {
         pub mod primitive_int_increasing_range;
     }
     pub mod factorization {
+        pub mod factor;
+        pub mod is_prime;
+        pub mod next_prime;
+        pub mod prime_count;
         pub mod prime_sieve;
         pub mod primes;
+        pub mod primes_in_range;
     }
     pub mod float {
         pub mod basic {
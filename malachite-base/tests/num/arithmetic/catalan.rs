@@ -0,0 +1,80 @@
+use malachite_base::num::basic::unsigneds::PrimitiveUnsigned;
+use malachite_base::test_util::generators::{unsigned_gen, unsigned_gen_var_5};
+use malachite_base::test_util::num::arithmetic::combinatorics::checked_catalan_naive;
+use std::panic::catch_unwind;
+
+#[test]
+fn test_catalan() {
+    fn test<T: PrimitiveUnsigned>(n: u64, out: T) {
+        assert_eq!(T::catalan(n), out);
+    }
+    test::<u8>(0, 1);
+    test::<u8>(1, 1);
+    test::<u8>(2, 2);
+    test::<u8>(3, 5);
+    test::<u8>(4, 14);
+    test::<u8>(5, 42);
+    test::<u8>(6, 132);
+    test::<u16>(11, 58786);
+    test::<u32>(19, 1767263190);
+    test::<u64>(36, 11959798385860453492);
+    test::<u128>(69, 337485502510215975556783793455058624700);
+}
+
+fn catalan_fail_helper<T: PrimitiveUnsigned>() {
+    assert_panic!(T::catalan(100));
+}
+
+#[test]
+fn catalan_fail() {
+    apply_fn_to_unsigneds!(catalan_fail_helper);
+}
+
+#[test]
+fn test_checked_catalan() {
+    fn test<T: PrimitiveUnsigned>(n: u64, out: Option<T>) {
+        assert_eq!(T::checked_catalan(n), out);
+        assert_eq!(checked_catalan_naive(n), out);
+    }
+    test::<u8>(0, Some(1));
+    test::<u8>(6, Some(132));
+    test::<u8>(7, None);
+    test::<u16>(11, Some(58786));
+    test::<u16>(12, None);
+    test::<u64>(36, Some(11959798385860453492));
+    test::<u64>(37, None);
+    test::<u128>(70, None);
+}
+
+fn catalan_properties_helper<T: PrimitiveUnsigned>() {
+    unsigned_gen().test_properties(|n| {
+        let c = T::checked_catalan(n);
+        assert!(c.map_or(true, |c| c != T::ZERO));
+        if let Some(c) = c {
+            assert_eq!(T::catalan(n), c);
+            if n != 0 {
+                assert!(T::checked_catalan(n - 1).unwrap() <= c);
+            }
+        } else if n != u64::MAX {
+            assert!(T::checked_catalan(n + 1).is_none());
+        }
+    });
+
+    unsigned_gen_var_5().test_properties(|n| {
+        let c = T::checked_catalan(n);
+        assert_eq!(checked_catalan_naive(n), c);
+        // $C_n = \binom{2n}{n} - \binom{2n}{n + 1}$
+        if let Some(b) = T::try_from(n << 1)
+            .ok()
+            .and_then(|two_n| T::checked_binomial_coefficient(two_n, T::exact_from(n)))
+        {
+            let b_next = T::binomial_coefficient(T::exact_from(n << 1), T::exact_from(n + 1));
+            assert_eq!(c, Some(b - b_next));
+        }
+    });
+}
+
+#[test]
+fn catalan_properties() {
+    apply_fn_to_unsigneds!(catalan_properties_helper);
+}
@@ -0,0 +1,217 @@
+use itertools::Itertools;
+use malachite_base::iterators::comparison::is_strictly_ascending;
+use malachite_base::num::arithmetic::traits::{DivisorCount, DivisorSum, Divisors, UnsignedAbs};
+use malachite_base::num::basic::signeds::PrimitiveSigned;
+use malachite_base::num::basic::unsigneds::PrimitiveUnsigned;
+use malachite_base::test_util::generators::{
+    signed_gen_var_6, unsigned_gen_var_1, unsigned_pair_gen_var_27,
+};
+use malachite_base::test_util::num::arithmetic::divisors::{
+    checked_divisor_sum_naive, divisors_naive,
+};
+use std::panic::catch_unwind;
+
+#[test]
+fn test_divisor_count() {
+    fn test<T: PrimitiveUnsigned>(n: T, out: T) {
+        assert_eq!(n.divisor_count(), out);
+        assert_eq!(T::divisor_count_from_factorization(&n.factor()), out);
+    }
+    test::<u8>(1, 1);
+    test::<u8>(2, 2);
+    test::<u8>(8, 4);
+    test::<u8>(12, 6);
+    test::<u8>(36, 9);
+    test::<u8>(255, 8);
+    test::<u32>(1000000, 49);
+    test::<u32>(u32::MAX, 32);
+    test::<u64>(600851475143, 16);
+    test::<u64>(u64::MAX, 128);
+    test::<u128>(u128::MAX, 512);
+
+    assert_eq!((-36i8).divisor_count(), 9);
+    assert_eq!(i8::MIN.divisor_count(), 8);
+    assert_eq!(i64::divisor_count_from_factorization(&[(2, 6), (5, 6)]), 49);
+}
+
+#[test]
+fn divisor_count_fail() {
+    assert_panic!(0u8.divisor_count());
+    assert_panic!(0u64.divisor_count());
+    assert_panic!(0i32.divisor_count());
+}
+
+#[test]
+fn test_divisor_sum() {
+    fn test<T: PrimitiveUnsigned>(n: T, k: u64, out: T) {
+        assert_eq!(n.divisor_sum(k), out);
+        assert_eq!(T::divisor_sum_from_factorization(&n.factor(), k), out);
+    }
+    test::<u8>(1, 0, 1);
+    test::<u8>(1, 5, 1);
+    test::<u8>(2, 1, 3);
+    test::<u8>(12, 0, 6);
+    test::<u8>(12, 1, 28);
+    test::<u8>(12, 2, 210);
+    test::<u16>(255, 1, 432);
+    test::<u32>(255, 3, 17336592);
+    test::<u64>(1000000, 1, 2480437);
+    test::<u64>(1000000, 2, 1388804117611);
+    test::<u64>(u32::MAX.into(), 1, 7304603328);
+    test::<u64>(u64::MAX, 0, 128);
+    test::<u128>(u64::MAX.into(), 1, 31421980989189888768);
+
+    assert_eq!((-12i8).divisor_sum(1), 28);
+    assert_eq!(
+        i64::divisor_sum_from_factorization(&[(2, 2), (3, 1)], 2),
+        210
+    );
+}
+
+#[test]
+fn divisor_sum_fail() {
+    assert_panic!(0u8.divisor_sum(1));
+    assert_panic!(0u64.divisor_sum(1));
+    assert_panic!(0i32.divisor_sum(1));
+    assert_panic!(255u8.divisor_sum(1));
+    assert_panic!(2u8.divisor_sum(8));
+    assert_panic!(u128::MAX.divisor_sum(1));
+    assert_panic!(100i8.divisor_sum(1));
+}
+
+#[test]
+fn test_divisors() {
+    fn test<T: PrimitiveUnsigned>(n: T, out: &[T]) {
+        assert_eq!(n.divisors().collect_vec(), out);
+        assert_eq!(
+            T::divisors_from_factorization(&n.factor()).collect_vec(),
+            out
+        );
+    }
+    test::<u8>(1, &[1]);
+    test::<u8>(2, &[1, 2]);
+    test::<u8>(12, &[1, 2, 3, 4, 6, 12]);
+    test::<u8>(36, &[1, 2, 3, 4, 6, 9, 12, 18, 36]);
+    test::<u8>(255, &[1, 3, 5, 15, 17, 51, 85, 255]);
+    test::<u64>(
+        600851475143,
+        &[
+            1,
+            71,
+            839,
+            1471,
+            6857,
+            59569,
+            104441,
+            486847,
+            1234169,
+            5753023,
+            10086647,
+            87625999,
+            408464633,
+            716151937,
+            8462696833,
+            600851475143,
+        ],
+    );
+    test::<u128>(
+        170141183460469231731687303715884105727,
+        &[1, 170141183460469231731687303715884105727],
+    );
+
+    assert_eq!((-12i8).divisors().collect_vec(), &[1, 2, 3, 4, 6, 12]);
+    assert_eq!(
+        i64::divisors_from_factorization(&[(2, 1), (3, 1)]).collect_vec(),
+        &[1, 2, 3, 6]
+    );
+    assert_eq!(12u32.divisors().rev().collect_vec(), &[12, 6, 4, 3, 2, 1]);
+    assert_eq!(12u32.divisors().len(), 6);
+}
+
+#[test]
+fn divisors_fail() {
+    assert_panic!(0u8.divisors());
+    assert_panic!(0u64.divisors());
+    assert_panic!(0i32.divisors());
+    assert_panic!(i8::MIN.divisors());
+}
+
+fn divisors_properties_helper_unsigned<T: PrimitiveUnsigned>() {
+    unsigned_gen_var_1::<T>().test_properties(|n| {
+        let factors = n.factor();
+        let divisors = n.divisors().collect_vec();
+        assert!(is_strictly_ascending(divisors.iter()));
+        assert_eq!(divisors.first(), Some(&T::ONE));
+        assert_eq!(divisors.last(), Some(&n));
+        assert!(divisors.iter().all(|&d| n.divisible_by(d)));
+        assert_eq!(
+            T::divisors_from_factorization(&factors).collect_vec(),
+            divisors
+        );
+
+        let count = T::divisor_count_from_factorization(&factors);
+        assert_eq!(T::exact_from(divisors.len()), count);
+        assert_eq!(T::divisor_sum_from_factorization(&factors, 0), count);
+        assert_eq!(count == T::TWO, factors == [(n, 1)]);
+
+        let sum = divisors
+            .iter()
+            .try_fold(T::ZERO, |acc, &d| acc.checked_add(d));
+        if let Some(sum) = sum {
+            assert_eq!(T::divisor_sum_from_factorization(&factors, 1), sum);
+        }
+    });
+
+    unsigned_pair_gen_var_27::<T>().test_properties(|(m, n)| {
+        if m != T::ZERO && n != T::ZERO && m.coprime_with(n) {
+            if let Some(product) = m.checked_mul(n) {
+                assert_eq!(
+                    product.divisor_count(),
+                    m.divisor_count() * n.divisor_count()
+                );
+            }
+        }
+    });
+}
+
+fn divisors_properties_helper_signed<
+    U: PrimitiveUnsigned,
+    S: PrimitiveSigned + TryFrom<U> + UnsignedAbs<Output = U>,
+>() {
+    signed_gen_var_6::<S>().test_properties(|n| {
+        let factors = n.unsigned_abs().factor();
+        if let Ok(count) = S::try_from(U::divisor_count_from_factorization(&factors)) {
+            assert_eq!(n.divisor_count(), count);
+        }
+        if n != S::MIN {
+            assert!(n
+                .divisors()
+                .zip(U::divisors_from_factorization(&factors))
+                .all(|(d, e)| d.unsigned_abs() == e));
+        }
+    });
+}
+
+#[test]
+fn divisors_properties() {
+    // Random u128s often have two large prime factors, which are too slow to find in tests
+    divisors_properties_helper_unsigned::<u8>();
+    divisors_properties_helper_unsigned::<u16>();
+    divisors_properties_helper_unsigned::<u32>();
+    divisors_properties_helper_unsigned::<u64>();
+    divisors_properties_helper_unsigned::<usize>();
+    divisors_properties_helper_signed::<u8, i8>();
+    divisors_properties_helper_signed::<u16, i16>();
+    divisors_properties_helper_signed::<u32, i32>();
+    divisors_properties_helper_signed::<u64, i64>();
+    divisors_properties_helper_signed::<usize, isize>();
+
+    for n in 1..=1000u32 {
+        assert_eq!(n.divisors().collect_vec(), divisors_naive(n));
+        for k in 0..4 {
+            if let Some(sum) = checked_divisor_sum_naive(n, k) {
+                assert_eq!(n.divisor_sum(k), sum);
+            }
+        }
+    }
+}
@@ -0,0 +1,271 @@
+use malachite_base::num::arithmetic::traits::{CheckedFibonacci, CheckedLucas};
+use malachite_base::num::basic::unsigneds::PrimitiveUnsigned;
+use malachite_base::test_util::generators::{
+    unsigned_gen, unsigned_pair_gen_var_12, unsigned_quadruple_gen_var_6,
+};
+use malachite_base::test_util::num::arithmetic::fibonacci::mod_lucas_sequence_naive;
+use std::panic::catch_unwind;
+
+#[test]
+fn test_fibonacci() {
+    fn test<T: PrimitiveUnsigned>(n: u64, out: T) {
+        assert_eq!(T::fibonacci(n), out);
+    }
+    test::<u8>(0, 0);
+    test::<u8>(1, 1);
+    test::<u8>(2, 1);
+    test::<u8>(3, 2);
+    test::<u8>(4, 3);
+    test::<u8>(5, 5);
+    test::<u8>(10, 55);
+    test::<u8>(13, 233);
+    test::<u64>(93, 12200160415121876738);
+    test::<u128>(186, 332825110087067562321196029789634457848);
+}
+
+fn fibonacci_fail_helper<T: PrimitiveUnsigned>() {
+    assert_panic!(T::fibonacci(200));
+}
+
+#[test]
+fn fibonacci_fail() {
+    apply_fn_to_unsigneds!(fibonacci_fail_helper);
+}
+
+#[test]
+fn test_checked_fibonacci() {
+    fn test<T: PrimitiveUnsigned>(n: u64, out: Option<T>) {
+        assert_eq!(T::checked_fibonacci(n), out);
+    }
+    test::<u8>(0, Some(0));
+    test::<u8>(1, Some(1));
+    test::<u8>(13, Some(233));
+    test::<u64>(93, Some(12200160415121876738));
+
+    test::<u8>(14, None);
+    test::<u64>(94, None);
+    test::<u128>(187, None);
+    test::<u32>(u64::MAX, None);
+}
+
+#[test]
+fn test_lucas() {
+    fn test<T: PrimitiveUnsigned>(n: u64, out: T) {
+        assert_eq!(T::lucas(n), out);
+    }
+    test::<u8>(0, 2);
+    test::<u8>(1, 1);
+    test::<u8>(2, 3);
+    test::<u8>(3, 4);
+    test::<u8>(4, 7);
+    test::<u8>(5, 11);
+    test::<u8>(10, 123);
+    test::<u8>(11, 199);
+    test::<u64>(50, 28143753123);
+}
+
+fn lucas_fail_helper<T: PrimitiveUnsigned>() {
+    assert_panic!(T::lucas(200));
+}
+
+#[test]
+fn lucas_fail() {
+    apply_fn_to_unsigneds!(lucas_fail_helper);
+}
+
+#[test]
+fn test_checked_lucas() {
+    fn test<T: PrimitiveUnsigned>(n: u64, out: Option<T>) {
+        assert_eq!(T::checked_lucas(n), out);
+    }
+    test::<u8>(0, Some(2));
+    test::<u8>(11, Some(199));
+
+    test::<u8>(12, None);
+    test::<u64>(93, None);
+    test::<u128>(185, None);
+    test::<u32>(u64::MAX, None);
+}
+
+#[test]
+fn test_mod_fibonacci() {
+    fn test<T: PrimitiveUnsigned>(n: u64, m: T, out: T) {
+        assert_eq!(T::mod_fibonacci(n, m), out);
+    }
+    test::<u8>(0, 1, 0);
+    test::<u8>(100, 1, 0);
+    test::<u8>(0, 10, 0);
+    test::<u8>(1, 10, 1);
+    test::<u8>(10, 16, 7);
+    test::<u8>(100, 255, 30);
+    test::<u32>(100, 1000000007, 687995182);
+    test::<u32>(1000000008, 1000000007, 0);
+    test::<u64>(u64::MAX, 2, 0);
+    test::<u64>(186, u64::MAX, 14054302963838737288);
+}
+
+fn mod_fibonacci_fail_helper<T: PrimitiveUnsigned>() {
+    assert_panic!(T::mod_fibonacci(10, T::ZERO));
+}
+
+#[test]
+fn mod_fibonacci_fail() {
+    apply_fn_to_unsigneds!(mod_fibonacci_fail_helper);
+}
+
+#[test]
+fn test_mod_lucas() {
+    fn test<T: PrimitiveUnsigned>(n: u64, m: T, out: T) {
+        assert_eq!(T::mod_lucas(n, m), out);
+    }
+    test::<u8>(0, 1, 0);
+    test::<u8>(0, 10, 2);
+    test::<u8>(1, 10, 1);
+    test::<u8>(10, 16, 11);
+    test::<u8>(100, 255, 217);
+    test::<u32>(100, 1000000007, 876413006);
+    test::<u32>(1000000008, 1000000007, 1000000005);
+}
+
+fn mod_lucas_fail_helper<T: PrimitiveUnsigned>() {
+    assert_panic!(T::mod_lucas(10, T::ZERO));
+}
+
+#[test]
+fn mod_lucas_fail() {
+    apply_fn_to_unsigneds!(mod_lucas_fail_helper);
+}
+
+#[test]
+fn test_mod_lucas_sequence() {
+    fn test<T: PrimitiveUnsigned>(p: T, q: T, n: u64, m: T, out: (T, T)) {
+        assert_eq!(T::mod_lucas_sequence(p, q, n, m), out);
+        assert_eq!(mod_lucas_sequence_naive(p, q, n, m), out);
+    }
+    test::<u8>(0, 0, 0, 1, (0, 0));
+    test::<u8>(0, 0, 0, 3, (0, 2));
+    test::<u8>(0, 0, 1, 3, (1, 0));
+    test::<u8>(3, 1, 10, 100, (65, 27));
+    test::<u8>(4, 2, 1000, 7, (6, 3));
+    test::<u16>(2, 65534, 10, 65535, (2378, 6726));
+    test::<u32>(5, 3, 100, 1000000007, (150049008, 448234207));
+    // U_n(2, 1) = n and V_n(2, 1) = 2
+    test::<u64>(2, 1, 12345, 1000000, (12345, 2));
+}
+
+fn mod_lucas_sequence_fail_helper<T: PrimitiveUnsigned>() {
+    assert_panic!(T::mod_lucas_sequence(T::TWO, T::ONE, 10, T::TWO));
+    assert_panic!(T::mod_lucas_sequence(T::ONE, T::TWO, 10, T::TWO));
+}
+
+#[test]
+fn mod_lucas_sequence_fail() {
+    apply_fn_to_unsigneds!(mod_lucas_sequence_fail_helper);
+}
+
+fn checked_fibonacci_properties_helper<T: PrimitiveUnsigned>() {
+    unsigned_gen().test_properties(|n| {
+        let of = T::checked_fibonacci(n);
+        assert_eq!(
+            of,
+            u128::checked_fibonacci(n).and_then(|f| T::try_from(f).ok())
+        );
+        if let Some(f) = of {
+            assert_eq!(T::fibonacci(n), f);
+            if n != 0 {
+                assert!(T::checked_fibonacci(n - 1).is_some());
+            }
+        } else {
+            assert!(n > 2);
+            assert!(T::checked_fibonacci(n.saturating_add(1)).is_none());
+        }
+    });
+
+    let mut n = 0;
+    while let Some(f) = T::checked_fibonacci(n + 2) {
+        assert_eq!(f, T::fibonacci(n + 1) + T::fibonacci(n));
+        n += 1;
+    }
+}
+
+#[test]
+fn checked_fibonacci_properties() {
+    apply_fn_to_unsigneds!(checked_fibonacci_properties_helper);
+}
+
+fn checked_lucas_properties_helper<T: PrimitiveUnsigned>() {
+    unsigned_gen().test_properties(|n| {
+        let ol = T::checked_lucas(n);
+        assert_eq!(ol, u128::checked_lucas(n).and_then(|l| T::try_from(l).ok()));
+        if let Some(l) = ol {
+            assert_eq!(T::lucas(n), l);
+        } else {
+            assert!(T::checked_lucas(n.saturating_add(1)).is_none());
+        }
+    });
+
+    // L_n = F_{n - 1} + F_{n + 1}
+    let mut n = 1;
+    while let Some(f) = T::checked_fibonacci(n + 1) {
+        if let Some(l) = T::checked_lucas(n) {
+            assert_eq!(l, T::fibonacci(n - 1) + f);
+        }
+        n += 1;
+    }
+}
+
+#[test]
+fn checked_lucas_properties() {
+    apply_fn_to_unsigneds!(checked_lucas_properties_helper);
+}
+
+fn mod_fibonacci_and_lucas_properties_helper<T: PrimitiveUnsigned>() {
+    unsigned_pair_gen_var_12::<u64, T>().test_properties(|(n, m)| {
+        let f = T::mod_fibonacci(n, m);
+        let l = T::mod_lucas(n, m);
+        assert!(f < m);
+        assert!(l < m);
+        assert_eq!(T::mod_lucas_sequence(T::ONE % m, m - T::ONE, n, m), (f, l));
+        if let Some(exact) = T::checked_fibonacci(n) {
+            assert_eq!(f, exact % m);
+        }
+        if let Some(exact) = T::checked_lucas(n) {
+            assert_eq!(l, exact % m);
+        }
+        if n < u64::MAX - 1 {
+            assert_eq!(
+                T::mod_fibonacci(n + 2, m),
+                T::mod_fibonacci(n + 1, m).mod_add(f, m)
+            );
+            assert_eq!(T::mod_lucas(n + 2, m), T::mod_lucas(n + 1, m).mod_add(l, m));
+        }
+    });
+}
+
+#[test]
+fn mod_fibonacci_and_lucas_properties() {
+    apply_fn_to_unsigneds!(mod_fibonacci_and_lucas_properties_helper);
+}
+
+fn mod_lucas_sequence_properties_helper<T: PrimitiveUnsigned>() {
+    unsigned_quadruple_gen_var_6::<T, u64>().test_properties(|(p, q, n, m)| {
+        let (u, v) = T::mod_lucas_sequence(p, q, n, m);
+        assert!(u < m);
+        assert!(v < m);
+        if n < 1000 {
+            assert_eq!(mod_lucas_sequence_naive(p, q, n, m), (u, v));
+        }
+        // U_{2n} = U_n V_n and V_{2n} = V_n^2 - 2Q^n
+        if n <= u64::MAX >> 1 {
+            let (u_double, v_double) = T::mod_lucas_sequence(p, q, n << 1, m);
+            assert_eq!(u_double, u.mod_mul(v, m));
+            let two_q_n = q.mod_pow(n, m).mod_add(q.mod_pow(n, m), m);
+            assert_eq!(v_double, v.mod_square(m).mod_sub(two_q_n, m));
+        }
+    });
+}
+
+#[test]
+fn mod_lucas_sequence_properties() {
+    apply_fn_to_unsigneds!(mod_lucas_sequence_properties_helper);
+}
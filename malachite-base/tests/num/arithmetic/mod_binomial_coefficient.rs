@@ -0,0 +1,62 @@
+use malachite_base::num::basic::unsigneds::PrimitiveUnsigned;
+use malachite_base::test_util::generators::{
+    unsigned_gen_var_1, unsigned_pair_gen_var_28, unsigned_triple_gen_var_24,
+};
+use malachite_base::test_util::num::arithmetic::mod_factorial::mod_binomial_coefficient_naive;
+use std::panic::catch_unwind;
+
+#[test]
+fn test_mod_binomial_coefficient() {
+    fn test<T: PrimitiveUnsigned>(n: T, k: T, m: T, out: T) {
+        assert_eq!(T::mod_binomial_coefficient(n, k, m), out);
+    }
+    test::<u8>(0, 0, 1, 0);
+    test::<u8>(0, 0, 2, 1);
+    test::<u8>(5, 6, 7, 0);
+    test::<u8>(10, 3, 7, 1);
+    test::<u8>(10, 3, 8, 0);
+    test::<u8>(10, 3, 9, 3);
+    test::<u16>(100, 50, 1000, 256);
+    test::<u16>(100, 50, 1024, 360);
+    test::<u32>(1000, 500, 59049, 2754);
+    test::<u32>(1000, 500, 2147483647, 438308119);
+    test::<u32>(123456789, 98765, 97, 0);
+    test::<u64>(1000, 500, 10000000000, 9821216320);
+    test::<u64>(1000000000000000000, 3, 1000000007, 18424);
+}
+
+fn mod_binomial_coefficient_fail_helper<T: PrimitiveUnsigned>() {
+    assert_panic!(T::mod_binomial_coefficient(T::ONE, T::ONE, T::ZERO));
+}
+
+#[test]
+fn mod_binomial_coefficient_fail() {
+    apply_fn_to_unsigneds!(mod_binomial_coefficient_fail_helper);
+}
+
+fn mod_binomial_coefficient_properties_helper<T: PrimitiveUnsigned>() {
+    unsigned_triple_gen_var_24::<T>().test_properties(|(n, k, m)| {
+        let b = T::mod_binomial_coefficient(n, k, m);
+        assert!(b < m);
+        assert_eq!(mod_binomial_coefficient_naive(n, k, m), b);
+        if let Some(b_full) = T::checked_binomial_coefficient(n, k) {
+            assert_eq!(b_full % m, b);
+        }
+        if k <= n {
+            assert_eq!(T::mod_binomial_coefficient(n, n - k, m), b);
+        }
+    });
+
+    unsigned_pair_gen_var_28::<T, T>().test_properties(|(n, k)| {
+        assert_eq!(T::mod_binomial_coefficient(n, k, T::ONE), T::ZERO);
+    });
+
+    unsigned_gen_var_1::<T>().test_properties(|m| {
+        assert_eq!(T::mod_binomial_coefficient(T::ZERO, T::ZERO, m), T::ONE % m);
+    });
+}
+
+#[test]
+fn mod_binomial_coefficient_properties() {
+    apply_fn_to_unsigneds!(mod_binomial_coefficient_properties_helper);
+}
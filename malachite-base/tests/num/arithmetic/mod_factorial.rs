@@ -0,0 +1,57 @@
+use malachite_base::num::basic::unsigneds::PrimitiveUnsigned;
+use malachite_base::test_util::generators::{unsigned_pair_gen_var_27, unsigned_triple_gen_var_24};
+use malachite_base::test_util::num::arithmetic::mod_factorial::mod_factorial_naive;
+use std::panic::catch_unwind;
+
+#[test]
+fn test_mod_factorial() {
+    fn test<T: PrimitiveUnsigned>(n: T, m: T, out: T) {
+        assert_eq!(T::mod_factorial(n, m), out);
+    }
+    test::<u8>(0, 1, 0);
+    test::<u8>(0, 2, 1);
+    test::<u8>(6, 7, 6);
+    test::<u8>(7, 7, 0);
+    test::<u8>(10, 7, 0);
+    test::<u8>(5, 128, 120);
+    test::<u16>(12, 1001, 77);
+    test::<u32>(100, 1000000007, 437918130);
+    test::<u64>(25, 3486784401, 2705448033);
+    test::<u64>(30, 1 << 40, 950597058560);
+    test::<u64>(1000000000, 1000000007, 698611116);
+}
+
+fn mod_factorial_fail_helper<T: PrimitiveUnsigned>() {
+    assert_panic!(T::mod_factorial(T::ONE, T::ZERO));
+}
+
+#[test]
+fn mod_factorial_fail() {
+    apply_fn_to_unsigneds!(mod_factorial_fail_helper);
+}
+
+fn mod_factorial_properties_helper<T: PrimitiveUnsigned>() {
+    unsigned_triple_gen_var_24::<T>().test_properties(|(n, _, m)| {
+        let f = T::mod_factorial(n, m);
+        assert!(f < m);
+        assert_eq!(mod_factorial_naive(n, m), f);
+        if let Some(f_full) = T::checked_factorial(n.exact_into()) {
+            assert_eq!(f_full % m, f);
+        }
+        // $(n + 1)! \equiv (n + 1)n! \mod m$
+        if let Some(n_plus_1) = n.checked_add(T::ONE) {
+            assert_eq!(T::mod_factorial(n_plus_1, m), f.mod_mul(n_plus_1 % m, m));
+        }
+    });
+
+    unsigned_pair_gen_var_27::<T>().test_properties(|(n, m)| {
+        if m != T::ZERO && n >= m {
+            assert_eq!(T::mod_factorial(n, m), T::ZERO);
+        }
+    });
+}
+
+#[test]
+fn mod_factorial_properties() {
+    apply_fn_to_unsigneds!(mod_factorial_properties_helper);
+}
@@ -0,0 +1,123 @@
+use malachite_base::num::arithmetic::mod_log::{mod_log_baby_step_giant_step, mod_log_pollard_rho};
+use malachite_base::num::arithmetic::traits::ModLog;
+use malachite_base::num::basic::unsigneds::PrimitiveUnsigned;
+use malachite_base::test_util::generators::{unsigned_pair_gen_var_27, unsigned_triple_gen_var_12};
+use malachite_base::test_util::num::arithmetic::mod_log::mod_log_naive;
+use std::panic::catch_unwind;
+
+#[test]
+fn test_mod_log() {
+    fn test<T: PrimitiveUnsigned>(x: T, base: T, m: T, out: Option<T>) {
+        assert_eq!(x.mod_log(base, m), out);
+    }
+    test::<u8>(0, 0, 1, Some(0));
+    test::<u8>(0, 0, 2, Some(1));
+    test::<u8>(1, 0, 2, Some(0));
+    test::<u8>(0, 0, 5, Some(1));
+    test::<u8>(1, 3, 10, Some(0));
+    test::<u8>(9, 3, 10, Some(2));
+    test::<u8>(5, 3, 10, None);
+    test::<u8>(3, 2, 11, Some(8));
+    test::<u8>(3, 4, 7, None);
+    test::<u8>(0, 2, 8, Some(3));
+    test::<u8>(4, 2, 8, Some(2));
+    test::<u8>(6, 2, 8, None);
+    test::<u8>(1, 7, 8, Some(0));
+    test::<u8>(3, 3, 8, Some(1));
+    test::<u8>(5, 3, 8, None);
+    test::<u8>(16, 2, 48, Some(4));
+    test::<u8>(32, 2, 48, Some(5));
+    test::<u8>(33, 3, 64, Some(8));
+    test::<u8>(81, 3, 128, Some(4));
+    test::<u8>(25, 5, 250, Some(2));
+    test::<u8>(125, 5, 250, Some(3));
+    test::<u8>(56, 6, 100, Some(6));
+    test::<u8>(10, 6, 100, None);
+    test::<u16>(200, 10, 1000, None);
+    test::<u16>(0, 10, 1000, Some(3));
+    test::<u16>(100, 10, 999, Some(2));
+    test::<u32>(1000, 2, 4294967291, Some(99));
+    test::<u32>(5, 3, 998244353, Some(109353319));
+    test::<u32>(7, 3, 1000000007, Some(70669385));
+    test::<u64>(12345, 5, 18446744073709551557, Some(1641943267442573239));
+    test::<u64>(12345, 7, 18446744069414584321, Some(8671865010899094532));
+    test::<u128>(
+        12345,
+        43,
+        170141183460469231731687303715884105727,
+        Some(62722898202088724190779602817757027275),
+    );
+    test::<u128>(12345, 3, 170141183460469231731687303715884105727, None);
+}
+
+#[test]
+fn mod_log_fail() {
+    assert_panic!(7u8.mod_log(2, 7));
+    assert_panic!(1u8.mod_log(7, 7));
+    assert_panic!(0u32.mod_log(0, 0));
+}
+
+#[test]
+fn test_mod_log_baby_step_giant_step() {
+    fn test<T: PrimitiveUnsigned>(g: T, h: T, n: T, m: T, out: Option<T>) {
+        assert_eq!(mod_log_baby_step_giant_step(g, h, n, m), out);
+    }
+    test::<u8>(2, 3, 10, 11, Some(8));
+    test::<u8>(2, 3, 8, 11, None);
+    test::<u8>(4, 3, 3, 7, None);
+    test::<u8>(4, 1, 3, 7, Some(0));
+    test::<u32>(3, 7, 1000000006, 1000000007, Some(70669385));
+}
+
+#[test]
+fn test_mod_log_pollard_rho() {
+    // 7293648916228302286 has order 5594472617641 modulo 2^64 - 59.
+    assert_eq!(
+        mod_log_pollard_rho(
+            7293648916228302286u64,
+            9961191577463352832,
+            5594472617641,
+            18446744073709551557
+        ),
+        1234567890123
+    );
+    assert_eq!(mod_log_pollard_rho(4u8, 2, 3, 7), 2);
+}
+
+fn mod_log_properties_helper<T: PrimitiveUnsigned>() {
+    unsigned_triple_gen_var_12::<T>().test_properties(|(x, base, m)| {
+        let result = x.mod_log(base, m);
+        assert_eq!(result, mod_log_naive(x, base, m));
+        if let Some(log) = result {
+            assert_eq!(base.mod_pow(log.exact_into(), m), x);
+        }
+    });
+
+    unsigned_pair_gen_var_27::<T>().test_properties(|(base, m)| {
+        if m == T::ZERO {
+            return;
+        }
+        let base = base % m;
+        assert_eq!((T::ONE % m).mod_log(base, m), Some(T::ZERO));
+        if m != T::ONE {
+            assert_eq!(
+                base.mod_log(base, m),
+                Some(if base == T::ONE { T::ZERO } else { T::ONE })
+            );
+        }
+    });
+}
+
+#[test]
+fn mod_log_properties() {
+    mod_log_properties_helper::<u8>();
+    mod_log_properties_helper::<u16>();
+
+    for m in 1..=100u16 {
+        for base in 0..m {
+            for x in 0..m {
+                assert_eq!(x.mod_log(base, m), mod_log_naive(x, base, m));
+            }
+        }
+    }
+}
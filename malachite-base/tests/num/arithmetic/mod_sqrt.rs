@@ -0,0 +1,200 @@
+use malachite_base::num::arithmetic::mod_sqrt::{mod_sqrt_cipolla, mod_sqrt_tonelli_shanks};
+use malachite_base::num::arithmetic::traits::{ModSqrt, ModSqrtFromFactorization, ModSquare};
+use malachite_base::num::basic::unsigneds::PrimitiveUnsigned;
+use malachite_base::num::factorization::traits::Factor;
+use malachite_base::test_util::generators::unsigned_pair_gen_var_27;
+use malachite_base::test_util::num::arithmetic::mod_sqrt::mod_sqrt_naive;
+use std::panic::catch_unwind;
+
+#[test]
+fn test_mod_sqrt() {
+    fn test<T: PrimitiveUnsigned>(x: T, p: T, out: Option<T>) {
+        assert_eq!(x.mod_sqrt(p), out);
+        assert_eq!(mod_sqrt_tonelli_shanks(x, p), out);
+        assert_eq!(mod_sqrt_cipolla(x, p), out);
+        assert_eq!(x.mod_sqrt_prime_power(p, 1), out);
+        assert_eq!(x.mod_sqrt_from_factorization(&[(p, 1)]), out);
+    }
+    test::<u8>(0, 2, Some(0));
+    test::<u8>(1, 2, Some(1));
+    test::<u8>(0, 7, Some(0));
+    test::<u8>(2, 7, Some(3));
+    test::<u8>(3, 7, None);
+    test::<u8>(10, 13, Some(6));
+    test::<u8>(5, 13, None);
+    test::<u8>(4, 251, Some(2));
+    test::<u32>(2, 998244353, Some(116195171));
+    test::<u32>(5, 998244353, None);
+    test::<u32>(2, 4294967291, None);
+    test::<u32>(3, 4294967291, Some(257244144));
+    test::<u32>(5, 4294967291, Some(65536));
+    test::<u32>(7, 4294967291, Some(1771222288));
+    test::<u64>(1000000, 18446744073709551557, Some(1000));
+    test::<u64>(5, 18446744073709551557, None);
+    test::<u64>(11, 18446744073709551557, Some(6461983710974175130));
+    test::<u64>(7, 18446744069414584321, None);
+    test::<u64>(2, 18446744069414584321, Some(1099494850304));
+    test::<u64>(3, 18446744069414584321, Some(281474976579584));
+    test::<u128>(
+        2,
+        170141183460469231731687303715884105727,
+        Some(18446744073709551616),
+    );
+    test::<u128>(
+        11,
+        170141183460469231731687303715884105727,
+        Some(18756936442469208154408315811770624301),
+    );
+    test::<u128>(3, 170141183460469231731687303715884105727, None);
+}
+
+#[test]
+fn mod_sqrt_fail() {
+    assert_panic!(7u8.mod_sqrt(7));
+    assert_panic!(8u32.mod_sqrt(7));
+    assert_panic!(mod_sqrt_tonelli_shanks(7u8, 7));
+    assert_panic!(mod_sqrt_cipolla(7u8, 7));
+}
+
+#[test]
+fn test_mod_sqrt_prime_power() {
+    fn test<T: PrimitiveUnsigned>(x: T, p: T, e: u64, out: Option<T>) {
+        assert_eq!(x.mod_sqrt_prime_power(p, e), out);
+        assert_eq!(x.mod_sqrt_from_factorization(&[(p, e)]), out);
+    }
+    test::<u8>(1, 2, 1, Some(1));
+    test::<u8>(1, 2, 2, Some(1));
+    test::<u8>(3, 2, 2, None);
+    test::<u8>(9, 2, 4, Some(3));
+    test::<u8>(5, 2, 4, None);
+    test::<u8>(17, 2, 5, Some(7));
+    test::<u16>(2, 7, 3, Some(108));
+    test::<u16>(49, 7, 3, Some(7));
+    test::<u16>(7, 7, 3, None);
+    test::<u16>(0, 7, 3, Some(0));
+    test::<u32>(17, 2, 31, Some(204265751));
+    test::<u32>(2, 3, 20, None);
+    test::<u32>(4, 3, 20, Some(2));
+    test::<u32>(7, 3, 20, Some(488245388));
+    test::<u32>(567, 3, 20, Some(132583113));
+    test::<u64>(10, 5, 27, None);
+    test::<u64>(11, 5, 27, Some(452712815972115681));
+    test::<u64>(6, 5, 27, Some(3330125840611989266));
+    test::<u64>(150, 5, 27, Some(259351889827524455));
+}
+
+#[test]
+fn mod_sqrt_prime_power_fail() {
+    assert_panic!(1u8.mod_sqrt_prime_power(3, 0));
+    assert_panic!(9u8.mod_sqrt_prime_power(3, 2));
+    assert_panic!(1u8.mod_sqrt_prime_power(2, 8));
+    assert_panic!(1u64.mod_sqrt_prime_power(3, 41));
+}
+
+#[test]
+fn test_mod_sqrt_from_factorization() {
+    fn test<T: PrimitiveUnsigned>(x: T, factors: &[(T, u64)], is_square: bool) {
+        let m = factors.iter().fold(T::ONE, |acc, &(p, e)| acc * p.pow(e));
+        let result = x.mod_sqrt_from_factorization(factors);
+        assert_eq!(result.is_some(), is_square);
+        if let Some(r) = result {
+            assert!(r < m);
+            assert_eq!(r.mod_square(m), x);
+        }
+    }
+    test::<u8>(0, &[], true);
+    test::<u8>(4, &[(3, 1), (5, 1)], true);
+    test::<u8>(2, &[(3, 1), (5, 1)], false);
+    test::<u8>(1, &[(2, 3), (3, 1)], true);
+    test::<u32>(46, &[(3, 1), (5, 1), (7, 1)], true);
+    test::<u32>(100, &[(7, 1), (11, 1), (13, 1)], true);
+    test::<u32>(1000, &[(7, 1), (11, 1), (13, 1)], false);
+    test::<u64>(4, &[(3, 1), (5, 1), (17, 1), (257, 1), (65537, 1)], true);
+    test::<u64>(
+        1000,
+        &[(3, 1), (5, 1), (17, 1), (257, 1), (641, 1), (65537, 1), (6700417, 1)],
+        false,
+    );
+    test::<u64>(
+        1000000,
+        &[(3, 1), (5, 1), (17, 1), (257, 1), (641, 1), (65537, 1), (6700417, 1)],
+        true,
+    );
+}
+
+#[test]
+fn mod_sqrt_from_factorization_fail() {
+    assert_panic!(15u8.mod_sqrt_from_factorization(&[(3, 1), (5, 1)]));
+    assert_panic!(1u8.mod_sqrt_from_factorization(&[]));
+    assert_panic!(1u8.mod_sqrt_from_factorization(&[(2, 4), (17, 1)]));
+}
+
+fn mod_sqrt_properties_helper<T: PrimitiveUnsigned>() {
+    unsigned_pair_gen_var_27::<T>().test_properties(|(x, n)| {
+        if n <= T::TWO {
+            return;
+        }
+        let p = n.prev_prime();
+        let x = x % p;
+        let result = x.mod_sqrt(p);
+        assert_eq!(mod_sqrt_tonelli_shanks(x, p), result);
+        assert_eq!(mod_sqrt_cipolla(x, p), result);
+        assert_eq!(x.mod_sqrt_prime_power(p, 1), result);
+        assert_eq!(x.mod_sqrt_from_factorization(&[(p, 1)]), result);
+        if p != T::TWO {
+            assert_eq!(result.is_none(), x.legendre_symbol(p) == -1);
+        }
+        if let Some(r) = result {
+            assert!(r < p);
+            assert_eq!(r.mod_square(p), x);
+            assert!(r == T::ZERO || r <= p - r);
+        }
+        assert!(x.mod_square(p).mod_sqrt(p).is_some());
+    });
+
+    unsigned_pair_gen_var_27::<T>().test_properties(|(x, n)| {
+        if n == T::ZERO {
+            return;
+        }
+        let factors = n.factor();
+        let x = x % n;
+        if let Some(r) = x.mod_sqrt_from_factorization(&factors) {
+            assert!(r < n);
+            assert_eq!(r.mod_square(n), x);
+        }
+        assert!(x
+            .mod_square(n)
+            .mod_sqrt_from_factorization(&factors)
+            .is_some());
+    });
+}
+
+#[test]
+fn mod_sqrt_properties() {
+    // Random u128s often have two large prime factors, which are too slow to find in tests
+    mod_sqrt_properties_helper::<u8>();
+    mod_sqrt_properties_helper::<u16>();
+    mod_sqrt_properties_helper::<u32>();
+    mod_sqrt_properties_helper::<u64>();
+    mod_sqrt_properties_helper::<usize>();
+
+    for m in 1..=300u16 {
+        let factors = m.factor();
+        let is_prime_power = factors.len() == 1;
+        for x in 0..m {
+            let naive = mod_sqrt_naive(x, m);
+            let result = x.mod_sqrt_from_factorization(&factors);
+            assert_eq!(result.is_some(), naive.is_some());
+            if let Some(r) = result {
+                assert_eq!(r.mod_square(m), x);
+            }
+            if is_prime_power {
+                let (p, e) = factors[0];
+                assert_eq!(x.mod_sqrt_prime_power(p, e), naive);
+                if e == 1 {
+                    assert_eq!(x.mod_sqrt(p), naive);
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,150 @@
+use malachite_base::num::arithmetic::mod_unsigned::{
+    ModU128, ModU16, ModU32, ModU64, ModU8, ModUsize,
+};
+use malachite_base::num::arithmetic::traits::{
+    ModAdd, ModInverse, ModMul, ModNeg, ModPow, ModSub, Pow, PowAssign,
+};
+use malachite_base::test_util::generators::{
+    unsigned_gen, unsigned_pair_gen, unsigned_pair_gen_var_27,
+};
+use std::panic::catch_unwind;
+
+#[test]
+fn test_mod_unsigned() {
+    let x = ModU8::<10>::new(7);
+    let y = ModU8::<10>::new(3);
+    assert_eq!(x.value(), 7);
+    assert_eq!(ModU8::<10>::modulus(), 10);
+    assert_eq!(x.to_string(), "7 mod 10");
+    assert_eq!(format!("{:?}", y), "3 mod 10");
+    assert_eq!((x + y).value(), 0);
+    assert_eq!((x - y).value(), 4);
+    assert_eq!((y - x).value(), 6);
+    assert_eq!((x * y).value(), 1);
+    assert_eq!((x / y).value(), 9);
+    assert_eq!((-x).value(), 3);
+    assert_eq!(x.pow(3).value(), 3);
+    assert_eq!(x.inverse(), Some(y));
+    assert_eq!(ModU8::<10>::new(4).inverse(), None);
+    assert_eq!(ModU8::<10>::new(0).inverse(), None);
+
+    let mut z = x;
+    z += y;
+    assert_eq!(z.value(), 0);
+    z -= y;
+    assert_eq!(z.value(), 7);
+    z *= y;
+    assert_eq!(z.value(), 1);
+    z /= x;
+    assert_eq!(z, y);
+    z.pow_assign(4);
+    assert_eq!(z.value(), 1);
+
+    assert_eq!(ModU8::<1>::new(0).inverse(), Some(ModU8::<1>::new(0)));
+    assert_eq!(ModU16::<1000>::reduce(12345).value(), 345);
+    assert_eq!(ModU32::<1000000007>::new(2).pow(100).value(), 976371285);
+    assert_eq!(ModU64::<{ u64::MAX }>::reduce(u64::MAX).value(), 0);
+    assert_eq!(
+        (ModU64::<{ u64::MAX }>::new(u64::MAX - 1) + ModU64::<{ u64::MAX }>::new(2)).value(),
+        1
+    );
+    assert_eq!(
+        (ModU128::<{ u128::MAX }>::new(u128::MAX - 1) * ModU128::<{ u128::MAX }>::new(2))
+            .to_string(),
+        "340282366920938463463374607431768211453 mod 340282366920938463463374607431768211455"
+    );
+    assert_eq!((-ModUsize::<7>::new(0)).value(), 0);
+}
+
+#[test]
+fn mod_unsigned_fail() {
+    assert_panic!(ModU8::<10>::new(10));
+    assert_panic!(ModU8::<0>::new(0));
+    assert_panic!(ModU8::<0>::reduce(0));
+    assert_panic!(ModU8::<10>::new(1) / ModU8::<10>::new(5));
+    assert_panic!({
+        let mut x = ModU32::<6>::new(1);
+        x /= ModU32::<6>::new(3);
+    });
+}
+
+macro_rules! mod_unsigned_properties_helper {
+    ($name:ident, $t:ident, $m:expr) => {{
+        type R = $name<{ $m }>;
+        let m: $t = $m;
+        unsigned_pair_gen_var_27::<$t>().test_properties(|(x, y)| {
+            let x_r = R::reduce(x);
+            let y_r = R::reduce(y);
+            let x = x % m;
+            let y = y % m;
+            assert_eq!(x_r, R::new(x));
+            assert_eq!(x_r.value(), x);
+
+            let sum = x_r + y_r;
+            assert_eq!(sum.value(), x.mod_add(y, m));
+            let mut sum_alt = x_r;
+            sum_alt += y_r;
+            assert_eq!(sum_alt, sum);
+
+            let diff = x_r - y_r;
+            assert_eq!(diff.value(), x.mod_sub(y, m));
+            let mut diff_alt = x_r;
+            diff_alt -= y_r;
+            assert_eq!(diff_alt, diff);
+            assert_eq!(diff + y_r, x_r);
+
+            let product = x_r * y_r;
+            assert_eq!(product.value(), x.mod_mul(y, m));
+            let mut product_alt = x_r;
+            product_alt *= y_r;
+            assert_eq!(product_alt, product);
+
+            let inverse = y_r.inverse();
+            assert_eq!(
+                inverse.map(R::value),
+                if y == 0 { None } else { y.mod_inverse(m) }
+            );
+            if let Some(inverse) = inverse {
+                assert_eq!((inverse * y_r).value(), 1);
+                let quotient = x_r / y_r;
+                assert_eq!(quotient, x_r * inverse);
+                let mut quotient_alt = x_r;
+                quotient_alt /= y_r;
+                assert_eq!(quotient_alt, quotient);
+                assert_eq!(quotient * y_r, x_r);
+            }
+
+            let neg = -x_r;
+            assert_eq!(neg.value(), x.mod_neg(m));
+            assert_eq!((x_r + neg).value(), 0);
+            assert_eq!(-neg, x_r);
+
+            assert_eq!(x_r.to_string(), format!("{} mod {}", x, m));
+        });
+
+        unsigned_pair_gen::<$t, u64>().test_properties(|(x, exp)| {
+            let x_r = R::reduce(x);
+            let power = x_r.pow(exp);
+            assert_eq!(power.value(), (x % m).mod_pow(exp, m));
+            let mut power_alt = x_r;
+            power_alt.pow_assign(exp);
+            assert_eq!(power_alt, power);
+        });
+
+        unsigned_gen::<$t>().test_properties(|x| {
+            assert_eq!(R::reduce(x).value(), x % m);
+        });
+    }};
+}
+
+#[test]
+fn mod_unsigned_properties() {
+    mod_unsigned_properties_helper!(ModU8, u8, 251);
+    mod_unsigned_properties_helper!(ModU8, u8, 12);
+    mod_unsigned_properties_helper!(ModU16, u16, 65535);
+    mod_unsigned_properties_helper!(ModU32, u32, 1000000007);
+    mod_unsigned_properties_helper!(ModU64, u64, u64::MAX);
+    mod_unsigned_properties_helper!(ModU64, u64, 18446744073709551557);
+    mod_unsigned_properties_helper!(ModU128, u128, 1 << 100);
+    mod_unsigned_properties_helper!(ModUsize, usize, 1000);
+}
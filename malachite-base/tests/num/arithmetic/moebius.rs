@@ -0,0 +1,92 @@
+use malachite_base::num::arithmetic::traits::{Moebius, UnsignedAbs};
+use malachite_base::num::basic::signeds::PrimitiveSigned;
+use malachite_base::num::basic::unsigneds::PrimitiveUnsigned;
+use malachite_base::test_util::generators::{
+    signed_gen_var_6, unsigned_gen_var_1, unsigned_pair_gen_var_27,
+};
+use malachite_base::test_util::num::arithmetic::moebius::moebius_naive;
+use std::panic::catch_unwind;
+
+#[test]
+fn test_moebius() {
+    fn test<T: PrimitiveUnsigned>(n: T, out: i8) {
+        assert_eq!(n.moebius(), out);
+        assert_eq!(T::moebius_from_factorization(&n.factor()), out);
+    }
+    test::<u8>(1, 1);
+    test::<u8>(2, -1);
+    test::<u8>(6, 1);
+    test::<u8>(8, 0);
+    test::<u8>(12, 0);
+    test::<u8>(30, -1);
+    test::<u8>(255, -1);
+    test::<u16>(561, -1);
+    test::<u32>(1000000, 0);
+    test::<u32>(u32::MAX, -1);
+    test::<u64>(600851475143, 1);
+    test::<u64>(1000000000039, -1);
+    test::<u64>(u64::MAX, -1);
+    test::<u128>(u128::MAX, -1);
+
+    assert_eq!((-30i8).moebius(), -1);
+    assert_eq!(i8::MIN.moebius(), 0);
+    assert_eq!(i64::moebius_from_factorization(&[(2, 1), (3, 1)]), 1);
+}
+
+#[test]
+fn moebius_fail() {
+    assert_panic!(0u8.moebius());
+    assert_panic!(0u64.moebius());
+    assert_panic!(0i32.moebius());
+}
+
+fn moebius_properties_helper_unsigned<T: PrimitiveUnsigned>() {
+    unsigned_gen_var_1::<T>().test_properties(|n| {
+        let mu = n.moebius();
+        assert!(mu == -1 || mu == 0 || mu == 1);
+        let factors = n.factor();
+        assert_eq!(T::moebius_from_factorization(&factors), mu);
+        if factors == [(n, 1)] {
+            assert_eq!(mu, -1);
+        }
+    });
+
+    unsigned_pair_gen_var_27::<T>().test_properties(|(m, n)| {
+        if m != T::ZERO && n != T::ZERO && m.coprime_with(n) {
+            if let Some(product) = m.checked_mul(n) {
+                assert_eq!(product.moebius(), m.moebius() * n.moebius());
+            }
+        }
+    });
+}
+
+fn moebius_properties_helper_signed<
+    U: PrimitiveUnsigned,
+    S: PrimitiveSigned + UnsignedAbs<Output = U>,
+>() {
+    signed_gen_var_6::<S>().test_properties(|n| {
+        assert_eq!(
+            n.moebius(),
+            U::moebius_from_factorization(&n.unsigned_abs().factor())
+        );
+    });
+}
+
+#[test]
+fn moebius_properties() {
+    // Random u128s often have two large prime factors, which are too slow to find in tests
+    moebius_properties_helper_unsigned::<u8>();
+    moebius_properties_helper_unsigned::<u16>();
+    moebius_properties_helper_unsigned::<u32>();
+    moebius_properties_helper_unsigned::<u64>();
+    moebius_properties_helper_unsigned::<usize>();
+    moebius_properties_helper_signed::<u8, i8>();
+    moebius_properties_helper_signed::<u16, i16>();
+    moebius_properties_helper_signed::<u32, i32>();
+    moebius_properties_helper_signed::<u64, i64>();
+    moebius_properties_helper_signed::<usize, isize>();
+
+    for n in 1..=1000u16 {
+        assert_eq!(n.moebius(), moebius_naive(n));
+    }
+}
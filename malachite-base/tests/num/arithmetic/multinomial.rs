@@ -0,0 +1,85 @@
+use malachite_base::num::basic::unsigneds::PrimitiveUnsigned;
+use malachite_base::test_util::generators::{unsigned_pair_gen_var_28, unsigned_vec_gen_var_7};
+use malachite_base::test_util::num::arithmetic::factorial::checked_factorial_naive;
+use std::panic::catch_unwind;
+
+#[test]
+fn test_multinomial() {
+    fn test<T: PrimitiveUnsigned>(ks: &[u64], out: T) {
+        assert_eq!(T::multinomial(ks), out);
+    }
+    test::<u8>(&[], 1);
+    test::<u8>(&[0], 1);
+    test::<u8>(&[5], 1);
+    test::<u8>(&[0, 0, 3, 0], 1);
+    test::<u8>(&[2, 3], 10);
+    test::<u8>(&[1, 1, 1, 1, 1], 120);
+    test::<u32>(&[1, 4, 4, 2], 34650);
+    test::<u64>(&[10, 10, 10], 5550996791340);
+    test::<u64>(&[1000000, 1], 1000001);
+}
+
+fn multinomial_fail_helper<T: PrimitiveUnsigned>() {
+    assert_panic!(T::multinomial(&[100, 100, 100]));
+}
+
+#[test]
+fn multinomial_fail() {
+    apply_fn_to_unsigneds!(multinomial_fail_helper);
+}
+
+#[test]
+fn test_checked_multinomial() {
+    fn test<T: PrimitiveUnsigned>(ks: &[u64], out: Option<T>) {
+        assert_eq!(T::checked_multinomial(ks), out);
+    }
+    test::<u8>(&[], Some(1));
+    test::<u8>(&[2, 3], Some(10));
+    test::<u8>(&[1, 1, 1, 1, 1], Some(120));
+    test::<u8>(&[1, 1, 1, 1, 1, 1], None);
+    test::<u8>(&[255, 0], Some(1));
+    test::<u8>(&[255, 1], None);
+    test::<u64>(&[10, 10, 10], Some(5550996791340));
+    test::<u64>(&[u64::MAX, 0, 0], Some(1));
+    test::<u64>(&[u64::MAX, 1], None);
+    test::<u128>(&[u64::MAX, 1], Some(1 << 64));
+    test::<u128>(&[u64::MAX, u64::MAX, 1], None);
+}
+
+fn multinomial_properties_helper<T: PrimitiveUnsigned>() {
+    unsigned_vec_gen_var_7().test_properties(|ks| {
+        let m = T::checked_multinomial(&ks);
+        assert!(m.map_or(true, |m| m != T::ZERO));
+        if let Some(m) = m {
+            assert_eq!(T::multinomial(&ks), m);
+        }
+        let mut ks_sorted = ks.clone();
+        ks_sorted.sort_unstable();
+        assert_eq!(T::checked_multinomial(&ks_sorted), m);
+        let ks_nonzero: Vec<u64> = ks.iter().copied().filter(|&k| k != 0).collect();
+        assert_eq!(T::checked_multinomial(&ks_nonzero), m);
+        // $n! = \binom{n}{k_1, k_2, \ldots, k_m}k_1!k_2!\cdots k_m!$
+        let n = ks.iter().sum();
+        if let Some(f) = checked_factorial_naive::<T>(n) {
+            let mut product = m.unwrap();
+            for &k in &ks {
+                product *= T::factorial(k);
+            }
+            assert_eq!(product, f);
+        }
+    });
+
+    unsigned_pair_gen_var_28::<u64, u64>().test_properties(|(n, k)| {
+        assert_eq!(
+            T::checked_multinomial(&[n, k]),
+            T::try_from(n + k)
+                .ok()
+                .and_then(|sum| T::checked_binomial_coefficient(sum, T::exact_from(k)))
+        );
+    });
+}
+
+#[test]
+fn multinomial_properties() {
+    apply_fn_to_unsigneds!(multinomial_properties_helper);
+}
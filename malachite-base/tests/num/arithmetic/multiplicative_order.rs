@@ -0,0 +1,142 @@
+use malachite_base::num::arithmetic::traits::{MultiplicativeOrder, PrimitiveRoot};
+use malachite_base::num::basic::unsigneds::PrimitiveUnsigned;
+use malachite_base::test_util::generators::{unsigned_gen_var_1, unsigned_pair_gen_var_38};
+use malachite_base::test_util::num::arithmetic::multiplicative_order::{
+    multiplicative_order_naive, primitive_root_naive,
+};
+use std::panic::catch_unwind;
+
+#[test]
+fn test_multiplicative_order() {
+    fn test<T: PrimitiveUnsigned>(x: T, m: T, out: Option<T>) {
+        assert_eq!(x.multiplicative_order(m), out);
+    }
+    test::<u8>(0, 1, Some(1));
+    test::<u8>(0, 2, None);
+    test::<u8>(1, 2, Some(1));
+    test::<u8>(1, 10, Some(1));
+    test::<u8>(9, 10, Some(2));
+    test::<u8>(3, 10, Some(4));
+    test::<u8>(5, 10, None);
+    test::<u8>(2, 7, Some(3));
+    test::<u8>(3, 7, Some(6));
+    test::<u8>(4, 9, Some(3));
+    test::<u8>(6, 9, None);
+    test::<u8>(3, 8, Some(2));
+    test::<u8>(3, 16, Some(4));
+    test::<u8>(5, 128, Some(32));
+    test::<u8>(127, 128, Some(2));
+    test::<u16>(10, 999, Some(3));
+    test::<u32>(3, 1000000007, Some(500000003));
+    test::<u32>(2, 4294967291, Some(4294967290));
+    test::<u64>(7, 18446744073709551557, Some(18446744073709551556));
+    test::<u64>(2, 18446744073709551615, Some(64));
+    test::<u128>(
+        3,
+        170141183460469231731687303715884105727,
+        Some(56713727820156410577229101238628035242),
+    );
+}
+
+#[test]
+fn multiplicative_order_fail() {
+    assert_panic!(7u8.multiplicative_order(7));
+    assert_panic!(0u32.multiplicative_order(0));
+}
+
+#[test]
+fn test_primitive_root() {
+    fn test<T: PrimitiveUnsigned>(m: T, out: Option<T>) {
+        assert_eq!(m.primitive_root(), out);
+    }
+    test::<u8>(1, Some(0));
+    test::<u8>(2, Some(1));
+    test::<u8>(3, Some(2));
+    test::<u8>(4, Some(3));
+    test::<u8>(5, Some(2));
+    test::<u8>(6, Some(5));
+    test::<u8>(7, Some(3));
+    test::<u8>(8, None);
+    test::<u8>(9, Some(2));
+    test::<u8>(12, None);
+    test::<u8>(15, None);
+    test::<u8>(18, Some(5));
+    test::<u8>(23, Some(5));
+    test::<u8>(25, Some(2));
+    test::<u8>(40, None);
+    test::<u8>(41, Some(6));
+    test::<u8>(250, Some(3));
+    test::<u16>(65521, Some(17));
+    test::<u32>(998244353, Some(3));
+    test::<u32>(1000000007, Some(5));
+    test::<u64>(18446744073709551557, Some(2));
+    test::<u64>(18446744069414584321, Some(7));
+    test::<u64>(18446744073709551615, None);
+    test::<u128>(170141183460469231731687303715884105727, Some(43));
+}
+
+#[test]
+fn primitive_root_fail() {
+    assert_panic!(0u8.primitive_root());
+    assert_panic!(0u64.primitive_root());
+}
+
+fn multiplicative_order_properties_helper<T: PrimitiveUnsigned>() {
+    unsigned_pair_gen_var_38::<T>().test_properties(|(x, m)| {
+        let order = x.multiplicative_order(m);
+        assert_eq!(order.is_some(), x.coprime_with(m));
+        if let Some(order) = order {
+            assert_ne!(order, T::ZERO);
+            assert_eq!(x.mod_pow(order.exact_into(), m), T::ONE % m);
+            assert!(m.carmichael_lambda().divisible_by(order));
+        }
+    });
+
+    unsigned_gen_var_1::<T>().test_properties(|m| {
+        assert_eq!((T::ONE % m).multiplicative_order(m), Some(T::ONE));
+        if m > T::TWO {
+            assert_eq!((m - T::ONE).multiplicative_order(m), Some(T::TWO));
+        }
+    });
+}
+
+fn primitive_root_properties_helper<T: PrimitiveUnsigned>() {
+    unsigned_gen_var_1::<T>().test_properties(|m| {
+        let root = m.primitive_root();
+        assert_eq!(root.is_some(), m.carmichael_lambda() == m.totient());
+        if let Some(root) = root {
+            assert!(root < m);
+            assert_eq!(root.multiplicative_order(m), Some(m.totient()));
+        }
+    });
+}
+
+#[test]
+fn multiplicative_order_properties() {
+    multiplicative_order_properties_helper::<u8>();
+    multiplicative_order_properties_helper::<u16>();
+    multiplicative_order_properties_helper::<u32>();
+    multiplicative_order_properties_helper::<u64>();
+
+    unsigned_pair_gen_var_38::<u16>().test_properties(|(x, m)| {
+        assert_eq!(x.multiplicative_order(m), multiplicative_order_naive(x, m));
+    });
+
+    for m in 1..=100u8 {
+        for x in 0..m {
+            assert_eq!(x.multiplicative_order(m), multiplicative_order_naive(x, m));
+        }
+    }
+}
+
+#[test]
+fn primitive_root_properties() {
+    primitive_root_properties_helper::<u8>();
+    primitive_root_properties_helper::<u16>();
+    primitive_root_properties_helper::<u32>();
+    primitive_root_properties_helper::<u64>();
+
+    for m in 1..=300u16 {
+        assert_eq!(m.primitive_root(), primitive_root_naive(m));
+    }
+}
@@ -0,0 +1,77 @@
+use malachite_base::num::basic::unsigneds::PrimitiveUnsigned;
+use malachite_base::test_util::generators::{unsigned_gen, unsigned_gen_var_5};
+use malachite_base::test_util::num::arithmetic::combinatorics::checked_partition_count_naive;
+use std::panic::catch_unwind;
+
+#[test]
+fn test_partition_count() {
+    fn test<T: PrimitiveUnsigned>(n: u64, out: T) {
+        assert_eq!(T::partition_count(n), out);
+    }
+    test::<u8>(0, 1);
+    test::<u8>(1, 1);
+    test::<u8>(2, 2);
+    test::<u8>(3, 3);
+    test::<u8>(4, 5);
+    test::<u8>(5, 7);
+    test::<u8>(10, 42);
+    test::<u8>(16, 231);
+    test::<u16>(20, 627);
+    test::<u32>(100, 190569292);
+    test::<u64>(400, 6727090051741041926);
+}
+
+fn partition_count_fail_helper<T: PrimitiveUnsigned>() {
+    assert_panic!(T::partition_count(2000));
+}
+
+#[test]
+fn partition_count_fail() {
+    apply_fn_to_unsigneds!(partition_count_fail_helper);
+}
+
+#[test]
+fn test_checked_partition_count() {
+    fn test<T: PrimitiveUnsigned>(n: u64, out: Option<T>) {
+        assert_eq!(T::checked_partition_count(n), out);
+        assert_eq!(checked_partition_count_naive(n), out);
+    }
+    test::<u8>(0, Some(1));
+    test::<u8>(10, Some(42));
+    test::<u8>(16, Some(231));
+    test::<u8>(17, None);
+    test::<u16>(43, Some(63261));
+    test::<u16>(44, None);
+    test::<u64>(400, Some(6727090051741041926));
+    test::<u64>(417, None);
+    test::<u128>(1459, None);
+}
+
+fn partition_count_properties_helper<T: PrimitiveUnsigned>() {
+    unsigned_gen().test_properties(|n| {
+        let p = T::checked_partition_count(n);
+        assert!(p.map_or(true, |p| p != T::ZERO));
+        if let Some(p) = p {
+            assert_eq!(T::partition_count(n), p);
+            if n != 0 {
+                let p_previous = T::checked_partition_count(n - 1).unwrap();
+                assert!(p_previous <= p);
+                assert!(p - p_previous <= p_previous);
+            }
+        } else if n != u64::MAX {
+            assert!(T::checked_partition_count(n + 1).is_none());
+        }
+    });
+
+    unsigned_gen_var_5().test_properties(|n| {
+        assert_eq!(
+            checked_partition_count_naive(n),
+            T::checked_partition_count(n)
+        );
+    });
+}
+
+#[test]
+fn partition_count_properties() {
+    apply_fn_to_unsigneds!(partition_count_properties_helper);
+}
@@ -0,0 +1,142 @@
+use malachite_base::num::arithmetic::perfect_power::perfect_power_decomposition_unsigned;
+use malachite_base::num::arithmetic::traits::{
+    DivisibleBy, Parity, PerfectPowerDecomposition, UnsignedAbs,
+};
+use malachite_base::num::basic::signeds::PrimitiveSigned;
+use malachite_base::num::basic::unsigneds::PrimitiveUnsigned;
+use malachite_base::test_util::generators::{signed_gen, unsigned_gen, unsigned_pair_gen_var_29};
+use malachite_base::test_util::num::arithmetic::perfect_power::perfect_power_decomposition_naive;
+
+#[test]
+fn test_perfect_power_decomposition() {
+    fn test_u<T: PrimitiveUnsigned>(n: T, base: T, exponent: u64) {
+        assert_eq!(n.perfect_power_decomposition(), (base, exponent));
+        assert_eq!(n.is_perfect_power(), exponent != 1 || n <= T::ONE);
+        assert_eq!(perfect_power_decomposition_naive(n), (base, exponent));
+    }
+    test_u::<u8>(0, 0, 1);
+    test_u::<u8>(1, 1, 1);
+    test_u::<u8>(2, 2, 1);
+    test_u::<u8>(4, 2, 2);
+    test_u::<u8>(8, 2, 3);
+    test_u::<u8>(12, 12, 1);
+    test_u::<u8>(16, 2, 4);
+    test_u::<u8>(36, 6, 2);
+    test_u::<u8>(64, 2, 6);
+    test_u::<u8>(81, 3, 4);
+    test_u::<u8>(128, 2, 7);
+    test_u::<u8>(216, 6, 3);
+    test_u::<u8>(243, 3, 5);
+    test_u::<u8>(255, 255, 1);
+    test_u::<u16>(65535, 65535, 1);
+    test_u::<u16>(32768, 2, 15);
+    test_u::<u16>(15625, 5, 6);
+    test_u::<u32>(1000000, 10, 6);
+    test_u::<u32>(1000001, 1000001, 1);
+    test_u::<u32>(4294967291, 4294967291, 1);
+    test_u::<u32>(3486784401, 3, 20);
+    test_u::<u32>(4293001441, 65521, 2);
+    test_u::<u64>(1 << 63, 2, 63);
+    test_u::<u64>(12157665459056928801, 3, 40);
+    test_u::<u64>(18446744030759878681, 4294967291, 2);
+    test_u::<u64>(18446744073709551615, 18446744073709551615, 1);
+    test_u::<u128>(3433683820292512484657849089281, 3, 64);
+    test_u::<u128>(
+        340282366920938463463374607431768211455,
+        340282366920938463463374607431768211455,
+        1,
+    );
+    test_u::<u128>(
+        340282366920938463463374607431768211297,
+        340282366920938463463374607431768211297,
+        1,
+    );
+
+    fn test_i<T: PrimitiveSigned>(n: T, base: T, exponent: u64) {
+        assert_eq!(n.perfect_power_decomposition(), (base, exponent));
+        assert_eq!(
+            n.is_perfect_power(),
+            exponent != 1 || n == T::ZERO || n == T::ONE || n == T::NEGATIVE_ONE
+        );
+    }
+    test_i::<i8>(0, 0, 1);
+    test_i::<i8>(1, 1, 1);
+    test_i::<i8>(-1, -1, 1);
+    test_i::<i8>(-2, -2, 1);
+    test_i::<i8>(4, 2, 2);
+    test_i::<i8>(-4, -4, 1);
+    test_i::<i8>(-8, -2, 3);
+    test_i::<i8>(-16, -16, 1);
+    test_i::<i8>(-27, -3, 3);
+    test_i::<i8>(64, 2, 6);
+    test_i::<i8>(-64, -4, 3);
+    test_i::<i8>(-128, -2, 7);
+    test_i::<i8>(127, 127, 1);
+    test_i::<i16>(-32768, -2, 15);
+    test_i::<i32>(-1000000, -100, 3);
+    test_i::<i64>(-9223372036854775808, -2, 63);
+    test_i::<i64>(-1853020188851841, -1853020188851841, 1);
+    test_i::<i64>(-5559060566555523, -3, 33);
+    test_i::<i128>(-12157665459056928801, -6561, 5);
+    test_i::<i128>(
+        -3433683820292512484657849089281,
+        -3433683820292512484657849089281,
+        1,
+    );
+}
+
+fn perfect_power_properties_helper_unsigned<T: PrimitiveUnsigned>() {
+    unsigned_gen::<T>().test_properties(|n| {
+        let (base, exponent) = n.perfect_power_decomposition();
+        assert_eq!(perfect_power_decomposition_unsigned(n), (base, exponent));
+        assert_eq!(base.pow(exponent), n);
+        assert_eq!(n.is_perfect_power(), exponent != 1 || n <= T::ONE);
+        if n > T::ONE {
+            assert!(!base.is_perfect_power());
+        }
+    });
+
+    unsigned_pair_gen_var_29::<T>().test_properties(|(x, k)| {
+        if x <= T::ONE || k == 0 {
+            return;
+        }
+        let n = x.pow(k);
+        let (base, exponent) = n.perfect_power_decomposition();
+        assert!(exponent.divisible_by(k));
+        assert_eq!(x.perfect_power_decomposition().1 * k, exponent);
+        assert_eq!(base.pow(exponent), n);
+    });
+}
+
+fn perfect_power_properties_helper_signed<
+    U: PrimitiveUnsigned,
+    T: PrimitiveSigned + UnsignedAbs<Output = U>,
+>() {
+    signed_gen::<T>().test_properties(|n| {
+        let (base, exponent) = n.perfect_power_decomposition();
+        assert_eq!(base.pow(exponent), n);
+        assert_eq!(base < T::ZERO, n < T::ZERO);
+        if n < T::ZERO {
+            assert!(exponent.odd());
+        } else {
+            assert_eq!(n.unsigned_abs().perfect_power_decomposition().1, exponent);
+        }
+        assert_eq!(
+            n.is_perfect_power(),
+            exponent != 1 || n == T::ZERO || n == T::ONE || n == T::NEGATIVE_ONE
+        );
+    });
+}
+
+#[test]
+fn perfect_power_properties() {
+    apply_fn_to_unsigneds!(perfect_power_properties_helper_unsigned);
+    apply_fn_to_unsigned_signed_pairs!(perfect_power_properties_helper_signed);
+
+    for n in 0..=u16::MAX {
+        assert_eq!(
+            n.perfect_power_decomposition(),
+            perfect_power_decomposition_naive(n)
+        );
+    }
+}
@@ -0,0 +1,222 @@
+use malachite_base::num::basic::unsigneds::PrimitiveUnsigned;
+use malachite_base::test_util::generators::{
+    unsigned_gen, unsigned_gen_var_5, unsigned_pair_gen_var_28,
+};
+use malachite_base::test_util::num::arithmetic::combinatorics::{
+    checked_bell_naive, checked_stirling_first_naive, checked_stirling_second_naive,
+};
+use std::panic::catch_unwind;
+
+#[test]
+fn test_stirling_first() {
+    fn test<T: PrimitiveUnsigned>(n: u64, k: u64, out: T) {
+        assert_eq!(T::stirling_first(n, k), out);
+    }
+    test::<u8>(0, 0, 1);
+    test::<u8>(1, 0, 0);
+    test::<u8>(1, 1, 1);
+    test::<u8>(3, 5, 0);
+    test::<u8>(4, 1, 6);
+    test::<u8>(4, 2, 11);
+    test::<u8>(4, 3, 6);
+    test::<u8>(5, 3, 35);
+    test::<u16>(6, 3, 225);
+    test::<u32>(10, 3, 1172700);
+    test::<u64>(20, 5, 371384787345228000);
+    test::<u64>(20, 10, 381922055502195);
+    test::<u64>(1000000, 999999, 499999500000);
+}
+
+fn stirling_first_fail_helper<T: PrimitiveUnsigned>() {
+    assert_panic!(T::stirling_first(100, 50));
+}
+
+#[test]
+fn stirling_first_fail() {
+    apply_fn_to_unsigneds!(stirling_first_fail_helper);
+}
+
+#[test]
+fn test_checked_stirling_first() {
+    fn test<T: PrimitiveUnsigned>(n: u64, k: u64, out: Option<T>) {
+        assert_eq!(T::checked_stirling_first(n, k), out);
+        assert_eq!(checked_stirling_first_naive(n, k), out);
+    }
+    test::<u8>(0, 0, Some(1));
+    test::<u8>(1, 0, Some(0));
+    test::<u8>(4, 2, Some(11));
+    test::<u8>(5, 3, Some(35));
+    test::<u8>(6, 5, Some(15));
+    test::<u8>(6, 2, None);
+    test::<u8>(23, 22, Some(253));
+    test::<u8>(24, 22, None);
+    test::<u64>(20, 5, Some(371384787345228000));
+    test::<u64>(30, 5, None);
+}
+
+#[test]
+fn test_stirling_second() {
+    fn test<T: PrimitiveUnsigned>(n: u64, k: u64, out: T) {
+        assert_eq!(T::stirling_second(n, k), out);
+    }
+    test::<u8>(0, 0, 1);
+    test::<u8>(1, 0, 0);
+    test::<u8>(1, 1, 1);
+    test::<u8>(3, 5, 0);
+    test::<u8>(4, 1, 1);
+    test::<u8>(4, 2, 7);
+    test::<u8>(4, 3, 6);
+    test::<u8>(5, 3, 25);
+    test::<u8>(6, 3, 90);
+    test::<u16>(10, 3, 9330);
+    test::<u64>(25, 5, 2436684974110751);
+    test::<u64>(25, 12, 362262620784874680);
+    test::<u64>(1000, 1, 1);
+    test::<u64>(1000000, 999999, 499999500000);
+}
+
+fn stirling_second_fail_helper<T: PrimitiveUnsigned>() {
+    assert_panic!(T::stirling_second(100, 50));
+}
+
+#[test]
+fn stirling_second_fail() {
+    apply_fn_to_unsigneds!(stirling_second_fail_helper);
+}
+
+#[test]
+fn test_checked_stirling_second() {
+    fn test<T: PrimitiveUnsigned>(n: u64, k: u64, out: Option<T>) {
+        assert_eq!(T::checked_stirling_second(n, k), out);
+        assert_eq!(checked_stirling_second_naive(n, k), out);
+    }
+    test::<u8>(0, 0, Some(1));
+    test::<u8>(1, 0, Some(0));
+    test::<u8>(4, 2, Some(7));
+    test::<u8>(6, 3, Some(90));
+    test::<u8>(7, 3, None);
+    test::<u8>(8, 2, Some(127));
+    test::<u8>(9, 2, Some(255));
+    test::<u8>(10, 2, None);
+    test::<u8>(1000, 1, Some(1));
+    test::<u64>(25, 12, Some(362262620784874680));
+    test::<u64>(30, 12, None);
+}
+
+#[test]
+fn test_bell() {
+    fn test<T: PrimitiveUnsigned>(n: u64, out: T) {
+        assert_eq!(T::bell(n), out);
+    }
+    test::<u8>(0, 1);
+    test::<u8>(1, 1);
+    test::<u8>(2, 2);
+    test::<u8>(3, 5);
+    test::<u8>(4, 15);
+    test::<u8>(5, 52);
+    test::<u8>(6, 203);
+    test::<u32>(15, 1382958545);
+    test::<u64>(25, 4638590332229999353);
+    test::<u128>(26, 49631246523618756274);
+}
+
+fn bell_fail_helper<T: PrimitiveUnsigned>() {
+    assert_panic!(T::bell(100));
+}
+
+#[test]
+fn bell_fail() {
+    apply_fn_to_unsigneds!(bell_fail_helper);
+}
+
+#[test]
+fn test_checked_bell() {
+    fn test<T: PrimitiveUnsigned>(n: u64, out: Option<T>) {
+        assert_eq!(T::checked_bell(n), out);
+        assert_eq!(checked_bell_naive(n), out);
+    }
+    test::<u8>(0, Some(1));
+    test::<u8>(5, Some(52));
+    test::<u8>(6, Some(203));
+    test::<u8>(7, None);
+    test::<u64>(25, Some(4638590332229999353));
+    test::<u64>(26, None);
+    test::<u128>(100, None);
+}
+
+fn stirling_properties_helper<T: PrimitiveUnsigned>() {
+    unsigned_pair_gen_var_28::<u64, u64>().test_properties(|(n, k)| {
+        let c = T::checked_stirling_first(n, k);
+        assert_eq!(checked_stirling_first_naive(n, k), c);
+        if let Some(c) = c {
+            assert_eq!(T::stirling_first(n, k), c);
+        }
+        let s = T::checked_stirling_second(n, k);
+        assert_eq!(checked_stirling_second_naive(n, k), s);
+        if let Some(s) = s {
+            assert_eq!(T::stirling_second(n, k), s);
+            // The second kind never exceeds the first.
+            assert!(c.map_or(true, |c| s <= c));
+        }
+        if k > n {
+            assert_eq!(c, Some(T::ZERO));
+            assert_eq!(s, Some(T::ZERO));
+        }
+        if n != 0 && k == n - 1 {
+            let b = T::try_from(n)
+                .ok()
+                .and_then(|n| T::checked_binomial_coefficient(n, T::TWO));
+            assert_eq!(c, b);
+            assert_eq!(s, b);
+        }
+    });
+
+    unsigned_gen_var_5().test_properties(|n| {
+        assert_eq!(T::checked_stirling_first(n, n), Some(T::ONE));
+        assert_eq!(T::checked_stirling_second(n, n), Some(T::ONE));
+        if n != 0 {
+            assert_eq!(T::checked_stirling_first(n, 0), Some(T::ZERO));
+            assert_eq!(T::checked_stirling_second(n, 0), Some(T::ZERO));
+            assert_eq!(T::checked_stirling_second(n, 1), Some(T::ONE));
+            assert_eq!(T::checked_stirling_first(n, 1), T::checked_factorial(n - 1));
+        }
+    });
+}
+
+#[test]
+fn stirling_properties() {
+    apply_fn_to_unsigneds!(stirling_properties_helper);
+}
+
+fn bell_properties_helper<T: PrimitiveUnsigned>() {
+    unsigned_gen().test_properties(|n| {
+        let b = T::checked_bell(n);
+        assert!(b.map_or(true, |b| b != T::ZERO));
+        if let Some(b) = b {
+            assert_eq!(T::bell(n), b);
+            if n != 0 {
+                assert!(T::checked_bell(n - 1).unwrap() <= b);
+            }
+        } else if n != u64::MAX {
+            assert!(T::checked_bell(n + 1).is_none());
+        }
+    });
+
+    unsigned_gen_var_5().test_properties(|n| {
+        let b = T::checked_bell(n);
+        assert_eq!(checked_bell_naive(n), b);
+        if let Some(b) = b {
+            // $B_n = \sum_{k=0}^n \left\{{n \atop k}\right\}$
+            let mut sum = T::ZERO;
+            for k in 0..=n {
+                sum += T::stirling_second(n, k);
+            }
+            assert_eq!(sum, b);
+        }
+    });
+}
+
+#[test]
+fn bell_properties() {
+    apply_fn_to_unsigneds!(bell_properties_helper);
+}
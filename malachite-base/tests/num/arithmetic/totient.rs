@@ -0,0 +1,149 @@
+use malachite_base::num::arithmetic::traits::{CarmichaelLambda, Totient, UnsignedAbs};
+use malachite_base::num::basic::signeds::PrimitiveSigned;
+use malachite_base::num::basic::unsigneds::PrimitiveUnsigned;
+use malachite_base::test_util::generators::{
+    signed_gen_var_6, unsigned_gen_var_1, unsigned_pair_gen_var_27,
+};
+use malachite_base::test_util::num::arithmetic::totient::{carmichael_lambda_naive, totient_naive};
+use std::panic::catch_unwind;
+
+#[test]
+fn test_totient() {
+    fn test<T: PrimitiveUnsigned>(n: T, out: T) {
+        assert_eq!(n.totient(), out);
+        assert_eq!(T::totient_from_factorization(&n.factor()), out);
+    }
+    test::<u8>(1, 1);
+    test::<u8>(2, 1);
+    test::<u8>(8, 4);
+    test::<u8>(9, 6);
+    test::<u8>(12, 4);
+    test::<u8>(100, 40);
+    test::<u8>(255, 128);
+    test::<u16>(561, 320);
+    test::<u32>(1000000, 400000);
+    test::<u32>(u32::MAX, 2147483648);
+    test::<u64>(600851475143, 591194251200);
+    test::<u64>(1000000000039, 1000000000038);
+    test::<u64>(u64::MAX, 9208981628670443520);
+    test::<u128>(
+        170141183460469231731687303715884105727,
+        170141183460469231731687303715884105726,
+    );
+    test::<u128>(u128::MAX, 169875107699410294159549716941399654400);
+
+    assert_eq!((-100i8).totient(), 40);
+    assert_eq!(i8::MIN.totient(), 64);
+    assert_eq!(i64::totient_from_factorization(&[(2, 2), (5, 2)]), 40);
+}
+
+#[test]
+fn totient_fail() {
+    assert_panic!(0u8.totient());
+    assert_panic!(0u64.totient());
+    assert_panic!(0i32.totient());
+    // The totient of 381 is 252, which is not representable as an `i8`
+    assert_panic!(i8::totient_from_factorization(&[(3, 1), (127, 1)]));
+}
+
+#[test]
+fn test_carmichael_lambda() {
+    fn test<T: PrimitiveUnsigned>(n: T, out: T) {
+        assert_eq!(n.carmichael_lambda(), out);
+        assert_eq!(T::carmichael_lambda_from_factorization(&n.factor()), out);
+    }
+    test::<u8>(1, 1);
+    test::<u8>(2, 1);
+    test::<u8>(8, 2);
+    test::<u8>(9, 6);
+    test::<u8>(12, 2);
+    test::<u8>(100, 20);
+    test::<u8>(255, 16);
+    test::<u16>(561, 80);
+    test::<u32>(1000000, 50000);
+    test::<u32>(u32::MAX, 65536);
+    test::<u64>(600851475143, 2111408040);
+    test::<u64>(1000000000039, 1000000000038);
+    test::<u64>(u64::MAX, 17153064960);
+    test::<u128>(u128::MAX, 321876063366081731297280);
+
+    assert_eq!((-100i8).carmichael_lambda(), 20);
+    assert_eq!(i8::MIN.carmichael_lambda(), 32);
+    assert_eq!(
+        i64::carmichael_lambda_from_factorization(&[(2, 2), (5, 2)]),
+        20
+    );
+}
+
+#[test]
+fn carmichael_lambda_fail() {
+    assert_panic!(0u8.carmichael_lambda());
+    assert_panic!(0u64.carmichael_lambda());
+    assert_panic!(0i32.carmichael_lambda());
+}
+
+fn totient_properties_helper_unsigned<T: PrimitiveUnsigned>() {
+    unsigned_gen_var_1::<T>().test_properties(|n| {
+        let totient = n.totient();
+        let factors = n.factor();
+        assert_eq!(T::totient_from_factorization(&factors), totient);
+        assert_ne!(totient, T::ZERO);
+        assert!(totient <= n);
+        if n != T::ONE {
+            assert_eq!(totient == n - T::ONE, factors == [(n, 1)]);
+        }
+        let lambda = T::carmichael_lambda_from_factorization(&factors);
+        assert!(lambda <= totient);
+        assert!(totient.divisible_by(lambda));
+    });
+
+    unsigned_pair_gen_var_27::<T>().test_properties(|(m, n)| {
+        if m != T::ZERO && n != T::ZERO && m.coprime_with(n) {
+            if let Some(product) = m.checked_mul(n) {
+                assert_eq!(product.totient(), m.totient() * n.totient());
+                assert_eq!(
+                    product.carmichael_lambda(),
+                    m.carmichael_lambda().lcm(n.carmichael_lambda())
+                );
+            }
+        }
+    });
+}
+
+fn totient_properties_helper_signed<
+    U: PrimitiveUnsigned,
+    S: PrimitiveSigned + TryFrom<U> + UnsignedAbs<Output = U>,
+>() {
+    signed_gen_var_6::<S>().test_properties(|n| {
+        let factors = n.unsigned_abs().factor();
+        if let Ok(totient) = S::try_from(U::totient_from_factorization(&factors)) {
+            assert_eq!(n.totient(), totient);
+        }
+        if let Ok(lambda) = S::try_from(U::carmichael_lambda_from_factorization(&factors)) {
+            assert_eq!(n.carmichael_lambda(), lambda);
+        }
+    });
+}
+
+#[test]
+fn totient_properties() {
+    // Random u128s often have two large prime factors, which are too slow to find in tests
+    totient_properties_helper_unsigned::<u8>();
+    totient_properties_helper_unsigned::<u16>();
+    totient_properties_helper_unsigned::<u32>();
+    totient_properties_helper_unsigned::<u64>();
+    totient_properties_helper_unsigned::<usize>();
+    totient_properties_helper_signed::<u8, i8>();
+    totient_properties_helper_signed::<u16, i16>();
+    totient_properties_helper_signed::<u32, i32>();
+    totient_properties_helper_signed::<u64, i64>();
+    totient_properties_helper_signed::<usize, isize>();
+
+    for n in 1..=u8::MAX {
+        assert_eq!(n.totient(), totient_naive(n));
+        assert_eq!(n.carmichael_lambda(), carmichael_lambda_naive(n));
+    }
+    for n in 1..=1000u16 {
+        assert_eq!(n.totient(), totient_naive(n));
+    }
+}
@@ -0,0 +1,191 @@
+use malachite_base::iterators::comparison::is_strictly_ascending;
+use malachite_base::num::basic::unsigneds::PrimitiveUnsigned;
+use malachite_base::num::factorization::factor::{ecm_one_curve, pollard_brent_rho};
+use malachite_base::num::factorization::traits::Factor;
+use malachite_base::test_util::generators::{unsigned_gen, unsigned_gen_var_1, unsigned_gen_var_5};
+use malachite_base::test_util::num::factorization::factor::factor_naive;
+use std::panic::catch_unwind;
+
+fn test_factor_helper<T: PrimitiveUnsigned>() {
+    let test = |n: u8, out: &[(u8, u64)]| {
+        let n = T::from(n);
+        let out = out
+            .iter()
+            .map(|&(p, e)| (T::from(p), e))
+            .collect::<Vec<_>>();
+        assert_eq!(n.factor(), out);
+        assert_eq!(factor_naive(n), out);
+    };
+    test(1, &[]);
+    test(2, &[(2, 1)]);
+    test(3, &[(3, 1)]);
+    test(4, &[(2, 2)]);
+    test(6, &[(2, 1), (3, 1)]);
+    test(12, &[(2, 2), (3, 1)]);
+    test(97, &[(97, 1)]);
+    test(100, &[(2, 2), (5, 2)]);
+    test(128, &[(2, 7)]);
+    test(210, &[(2, 1), (3, 1), (5, 1), (7, 1)]);
+    test(251, &[(251, 1)]);
+    test(255, &[(3, 1), (5, 1), (17, 1)]);
+}
+
+#[test]
+fn test_factor() {
+    apply_fn_to_unsigneds!(test_factor_helper);
+
+    let test = |n: u64, out: &[(u64, u64)]| {
+        assert_eq!(n.factor(), out);
+        assert_eq!(
+            u128::from(n).factor(),
+            out.iter()
+                .map(|&(p, e)| (u128::from(p), e))
+                .collect::<Vec<_>>()
+        );
+    };
+    test(1000000, &[(2, 6), (5, 6)]);
+    test(1048573, &[(1048573, 1)]);
+    test(1050625, &[(5, 4), (41, 2)]);
+    // 1031^2 has no factors small enough to be found by trial division
+    test(1062961, &[(1031, 2)]);
+    test(1031 * 1033 * 1039, &[(1031, 1), (1033, 1), (1039, 1)]);
+    test(600851475143, &[(71, 1), (839, 1), (1471, 1), (6857, 1)]);
+    test(4294967291, &[(4294967291, 1)]);
+    test(4294967296, &[(2, 32)]);
+    test(998244359987710471, &[(998244353, 1), (1000000007, 1)]);
+    test(18446743979220271189, &[(4294967279, 1), (4294967291, 1)]);
+    test(18446744073709551557, &[(18446744073709551557, 1)]);
+    test(
+        u64::MAX,
+        &[(3, 1), (5, 1), (17, 1), (257, 1), (641, 1), (65537, 1), (6700417, 1)],
+    );
+
+    let test = |s: &str, out: &[(&str, u64)]| {
+        let n: u128 = s.parse().unwrap();
+        let out = out
+            .iter()
+            .map(|&(p, e)| (p.parse().unwrap(), e))
+            .collect::<Vec<(u128, u64)>>();
+        assert_eq!(n.factor(), out);
+    };
+    test(
+        "100000000000000000000000000000000000000",
+        &[("2", 38), ("5", 38)],
+    );
+    // 2^127 - 1
+    test(
+        "170141183460469231731687303715884105727",
+        &[("170141183460469231731687303715884105727", 1)],
+    );
+    test(
+        "1000000028000000294000001372000002401",
+        &[("1000000007", 4)],
+    );
+    test(
+        "340282366920938463463374607431768211295",
+        &[
+            ("5", 1),
+            ("179", 1),
+            ("293", 1),
+            ("1109", 1),
+            ("2351", 1),
+            ("118457", 1),
+            ("344857", 1),
+            ("12183297200205767", 1),
+        ],
+    );
+    // (2^61 - 1) * (2^64 - 59)
+    test(
+        "42535295865117307778430344311653531707",
+        &[("2305843009213693951", 1), ("18446744073709551557", 1)],
+    );
+}
+
+#[test]
+fn factor_fail() {
+    assert_panic!(0u8.factor());
+    assert_panic!(0u64.factor());
+    assert_panic!(0u128.factor());
+}
+
+#[test]
+fn test_pollard_brent_rho() {
+    let test = |n: u64, c: u64| {
+        let factor = pollard_brent_rho(n, c).unwrap();
+        assert!(factor > 1 && factor < n);
+        assert_eq!(n % factor, 0);
+    };
+    test(1062961, 1);
+    test(600851475143, 1);
+    test(998244359987710471, 1);
+    test(998244359987710471, 2);
+}
+
+#[test]
+fn test_ecm_one_curve() {
+    let test = |n: u128, sigma: u64, b1: u64| {
+        let factor = ecm_one_curve(n, sigma, b1).unwrap();
+        assert!(factor > 1 && factor < n);
+        assert_eq!(n % factor, 0);
+    };
+    test(998244359987710471, 7, 2000);
+    test(18446743979220271189, 6, 2000);
+    test(18446743979220271189, 7, 11000);
+    test(42535295865117307778430344311653531707, 37, 11000);
+}
+
+fn factor_properties_helper<T: PrimitiveUnsigned>() {
+    unsigned_gen_var_1::<T>().test_properties(|n| {
+        let factors = n.factor();
+        assert!(is_strictly_ascending(factors.iter().map(|&(p, _)| p)));
+        let mut product = T::ONE;
+        for &(p, e) in &factors {
+            assert!(p.is_prime());
+            assert_ne!(e, 0);
+            product *= p.pow(e);
+        }
+        assert_eq!(product, n);
+    });
+
+    unsigned_gen_var_5::<T>().test_properties(|n| {
+        if n != T::ZERO {
+            assert_eq!(n.factor(), factor_naive(n));
+        }
+    });
+}
+
+#[test]
+fn factor_properties() {
+    // Random u128s often have two large prime factors, which are too slow to find in tests
+    factor_properties_helper::<u8>();
+    factor_properties_helper::<u16>();
+    factor_properties_helper::<u32>();
+    factor_properties_helper::<u64>();
+    factor_properties_helper::<usize>();
+
+    for n in 1..=u16::MAX {
+        assert_eq!(n.factor(), factor_naive(n));
+    }
+
+    unsigned_gen::<u64>().test_properties(|n| {
+        if n != 0 {
+            let factors = n.factor();
+            if let Ok(m) = u32::try_from(n) {
+                assert_eq!(
+                    m.factor(),
+                    factors
+                        .iter()
+                        .map(|&(p, e)| (u32::try_from(p).unwrap(), e))
+                        .collect::<Vec<_>>()
+                );
+            }
+            assert_eq!(
+                u128::from(n).factor(),
+                factors
+                    .iter()
+                    .map(|&(p, e)| (u128::from(p), e))
+                    .collect::<Vec<_>>()
+            );
+        }
+    });
+}
@@ -0,0 +1,198 @@
+use malachite_base::num::basic::unsigneds::PrimitiveUnsigned;
+use malachite_base::num::conversion::traits::ExactInto;
+use malachite_base::num::factorization::is_prime::{
+    is_baillie_psw_probable_prime, is_strong_lucas_probable_prime,
+    is_strong_probable_prime_base_2,
+};
+use malachite_base::num::factorization::traits::{IsPrime, IsProbablePrime, Primes};
+use malachite_base::test_util::generators::{
+    unsigned_gen, unsigned_gen_var_5, unsigned_pair_gen_var_33,
+};
+use malachite_base::test_util::num::factorization::prime_sieve::is_prime_simple;
+
+fn test_is_prime_helper<T: PrimitiveUnsigned>() {
+    let test = |n: u8, out: bool| {
+        let n = T::from(n);
+        assert_eq!(n.is_prime(), out);
+        assert_eq!(n.is_probable_prime(0), out);
+        assert_eq!(is_prime_simple(n.exact_into()), out);
+    };
+    test(0, false);
+    test(1, false);
+    test(2, true);
+    test(3, true);
+    test(4, false);
+    test(5, true);
+    test(6, false);
+    test(7, true);
+    test(8, false);
+    test(9, false);
+    test(10, false);
+    test(97, true);
+    test(100, false);
+    test(251, true);
+    test(255, false);
+}
+
+#[test]
+fn test_is_prime() {
+    apply_fn_to_unsigneds!(test_is_prime_helper);
+
+    let test = |n: u64, out: bool| {
+        assert_eq!(n.is_prime(), out);
+        assert_eq!(n.is_probable_prime(0), out);
+        if let Ok(n) = u32::try_from(n) {
+            assert_eq!(n.is_prime(), out);
+        }
+        assert_eq!(u128::from(n).is_prime(), out);
+        if n > 2 {
+            assert_eq!(is_baillie_psw_probable_prime(n), out);
+            assert_eq!(is_baillie_psw_probable_prime(u128::from(n)), out);
+        }
+    };
+    // Carmichael numbers
+    test(561, false);
+    test(1105, false);
+    test(1729, false);
+    test(41041, false);
+    // strong pseudoprimes to base 2
+    test(2047, false);
+    test(3277, false);
+    test(4033, false);
+    test(4681, false);
+    test(8321, false);
+    // strong pseudoprimes to bases 2, 3, 5, and 7
+    test(3215031751, false);
+    // strong pseudoprimes to every prime base up to 23
+    test(3825123056546413051, false);
+    // strong Lucas pseudoprimes
+    test(5459, false);
+    test(5777, false);
+    test(10877, false);
+    test(16109, false);
+    test(18971, false);
+    // perfect squares
+    test(4097 * 4097, false);
+    test(4294967291 * 4294967291, false);
+
+    test(65521, true);
+    test(65537, true);
+    test(4294967291, true);
+    test(4294967311, true);
+    // 2^61 - 1
+    test(2305843009213693951, true);
+    test(18446744073709551557, true);
+    test(u64::MAX, false);
+
+    let test = |s: &str, out: bool| {
+        let n: u128 = s.parse().unwrap();
+        assert_eq!(n.is_prime(), out);
+        assert_eq!(n.is_probable_prime(10), out);
+    };
+    // strong pseudoprimes to every prime base up to 37
+    test("318665857834031151167461", false);
+    test("3317044064679887385961981", false);
+    // 2^89 - 1
+    test("618970019642690137449562111", true);
+    // 2^127 - 1
+    test("170141183460469231731687303715884105727", true);
+    // 2^128 - 159, the largest prime less than 2^128
+    test("340282366920938463463374607431768211297", true);
+    test("340282366920938463463374607431768211455", false);
+    // (2^64 - 59)^2
+    test("340282366920938461286658806734041124249", false);
+    // (2^61 - 1) * (2^64 - 59)
+    test("42535295865117307778430344311653531707", false);
+}
+
+#[test]
+fn test_is_strong_probable_prime_base_2() {
+    let test = |n: u64, out: bool| {
+        assert_eq!(is_strong_probable_prime_base_2(n), out);
+        assert_eq!(is_strong_probable_prime_base_2(u128::from(n)), out);
+    };
+    test(3, true);
+    test(9, false);
+    test(97, true);
+    test(561, false);
+    test(2047, true);
+    test(3277, true);
+    test(5459, false);
+    test(3215031751, true);
+    test(3825123056546413051, true);
+}
+
+#[test]
+fn test_is_strong_lucas_probable_prime() {
+    let test = |n: u64, out: bool| {
+        assert_eq!(is_strong_lucas_probable_prime(n), out);
+        assert_eq!(is_strong_lucas_probable_prime(u128::from(n)), out);
+    };
+    test(3, true);
+    test(97, true);
+    test(561, false);
+    test(2047, false);
+    test(3277, false);
+    test(5459, true);
+    test(5777, true);
+    test(10877, true);
+    test(16109, true);
+    test(18971, true);
+    test(22499, true);
+    test(4294967291, true);
+    test(18446744073709551557, true);
+}
+
+fn is_prime_properties_helper<T: PrimitiveUnsigned>() {
+    unsigned_gen::<T>().test_properties(|n| {
+        let is_prime = n.is_prime();
+        assert_eq!(n.is_probable_prime(0), is_prime);
+        assert_eq!(n.is_probable_prime(10), is_prime);
+        if n > T::TWO {
+            assert_eq!(is_baillie_psw_probable_prime(n), is_prime);
+        }
+        if is_prime && n > T::TWO {
+            assert!(n.odd());
+        }
+    });
+
+    unsigned_gen_var_5::<T>().test_properties(|n| {
+        assert_eq!(n.is_prime(), is_prime_simple(n.exact_into()));
+    });
+
+    unsigned_pair_gen_var_33::<T>().test_properties(|(x, y)| {
+        if x > T::ONE && y > T::ONE {
+            if let Some(p) = x.checked_mul(y) {
+                assert!(!p.is_prime());
+            }
+        }
+    });
+}
+
+#[test]
+fn is_prime_properties() {
+    apply_fn_to_unsigneds!(is_prime_properties_helper);
+
+    for n in 0..=u16::MAX {
+        assert_eq!(n.is_prime(), is_prime_simple(n.exact_into()));
+    }
+
+    let mut previous = 0;
+    for p in u32::primes_less_than(&100000) {
+        assert!(p.is_prime());
+        for n in previous + 1..p {
+            assert!(!n.is_prime());
+        }
+        previous = p;
+    }
+
+    unsigned_gen::<u64>().test_properties(|n| {
+        if let Ok(m) = u32::try_from(n) {
+            assert_eq!(m.is_prime(), n.is_prime());
+        }
+        assert_eq!(u128::from(n).is_prime(), n.is_prime());
+        if let Ok(m) = usize::try_from(n) {
+            assert_eq!(m.is_prime(), n.is_prime());
+        }
+    });
+}
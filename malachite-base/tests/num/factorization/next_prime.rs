@@ -0,0 +1,180 @@
+use malachite_base::num::basic::unsigneds::PrimitiveUnsigned;
+use malachite_base::num::factorization::traits::{NextPrime, PrevPrime, Primes};
+use malachite_base::test_util::generators::{unsigned_gen, unsigned_gen_var_5};
+use std::panic::catch_unwind;
+
+fn test_next_prime_helper<T: PrimitiveUnsigned>() {
+    let test = |n: u8, out: u8| {
+        assert_eq!(T::from(n).next_prime(), T::from(out));
+    };
+    test(0, 2);
+    test(1, 2);
+    test(2, 3);
+    test(3, 5);
+    test(4, 5);
+    test(5, 7);
+    test(10, 11);
+    test(13, 17);
+    test(100, 101);
+    test(241, 251);
+}
+
+#[test]
+fn test_next_prime() {
+    apply_fn_to_unsigneds!(test_next_prime_helper);
+
+    let test = |n: u64, out: u64| {
+        assert_eq!(n.next_prime(), out);
+        if let Ok(m) = u32::try_from(out) {
+            assert_eq!(u32::try_from(n).unwrap().next_prime(), m);
+        }
+        assert_eq!(u128::from(n).next_prime(), u128::from(out));
+    };
+    test(1000000, 1000003);
+    test(65521, 65537);
+    test(4294967290, 4294967291);
+    test(4294967291, 4294967311);
+    test(1000000000000, 1000000000039);
+    test(18446744073709551556, 18446744073709551557);
+
+    assert_eq!(u128::from(u64::MAX).next_prime(), 18446744073709551629);
+    assert_eq!(
+        "340282366920938463463374607431768211200"
+            .parse::<u128>()
+            .unwrap()
+            .next_prime(),
+        340282366920938463463374607431768211219
+    );
+}
+
+fn next_prime_fail_helper<T: PrimitiveUnsigned>() {
+    assert_panic!(T::MAX.next_prime());
+    assert_panic!((T::MAX - T::ONE).next_prime());
+}
+
+#[test]
+fn next_prime_fail() {
+    apply_fn_to_unsigneds!(next_prime_fail_helper);
+    assert_panic!(251u8.next_prime());
+    assert_panic!(18446744073709551557u64.next_prime());
+}
+
+fn test_prev_prime_helper<T: PrimitiveUnsigned>() {
+    let test = |n: u8, out: u8| {
+        assert_eq!(T::from(n).prev_prime(), T::from(out));
+    };
+    test(3, 2);
+    test(4, 3);
+    test(5, 3);
+    test(6, 5);
+    test(7, 5);
+    test(8, 7);
+    test(100, 97);
+    test(255, 251);
+}
+
+#[test]
+fn test_prev_prime() {
+    apply_fn_to_unsigneds!(test_prev_prime_helper);
+
+    let test = |n: u64, out: u64| {
+        assert_eq!(n.prev_prime(), out);
+        if let Ok(m) = u32::try_from(n) {
+            assert_eq!(m.prev_prime(), u32::try_from(out).unwrap());
+        }
+        assert_eq!(u128::from(n).prev_prime(), u128::from(out));
+    };
+    test(1000003, 999983);
+    test(65537, 65521);
+    test(4294967295, 4294967291);
+    test(1000000000039, 999999999989);
+    test(u64::MAX, 18446744073709551557);
+
+    assert_eq!(
+        u128::MAX.prev_prime(),
+        340282366920938463463374607431768211297
+    );
+}
+
+fn prev_prime_fail_helper<T: PrimitiveUnsigned>() {
+    assert_panic!(T::ZERO.prev_prime());
+    assert_panic!(T::ONE.prev_prime());
+    assert_panic!(T::TWO.prev_prime());
+}
+
+#[test]
+fn prev_prime_fail() {
+    apply_fn_to_unsigneds!(prev_prime_fail_helper);
+}
+
+fn next_prime_properties_helper<T: PrimitiveUnsigned>() {
+    let largest_prime = T::MAX.prev_prime();
+    unsigned_gen::<T>().test_properties(|n| {
+        if n >= largest_prime {
+            return;
+        }
+        let p = n.next_prime();
+        assert!(p > n);
+        assert!(p.is_prime());
+        if p > T::TWO {
+            assert!(p.prev_prime() <= n);
+        }
+    });
+
+    unsigned_gen_var_5::<T>().test_properties(|n| {
+        if n < largest_prime {
+            assert_eq!(n.next_prime(), T::primes().find(|&p| p > n).unwrap());
+        }
+    });
+}
+
+#[test]
+fn next_prime_properties() {
+    // Random u128s are too slow to test in debug builds
+    next_prime_properties_helper::<u8>();
+    next_prime_properties_helper::<u16>();
+    next_prime_properties_helper::<u32>();
+    next_prime_properties_helper::<u64>();
+    next_prime_properties_helper::<usize>();
+
+    let mut previous = 2u32;
+    for p in u32::primes_less_than(&100000).skip(1) {
+        assert_eq!(previous.next_prime(), p);
+        assert_eq!(p.prev_prime(), previous);
+        for n in previous + 1..p {
+            assert_eq!(n.next_prime(), p);
+            assert_eq!(n.prev_prime(), previous);
+        }
+        previous = p;
+    }
+}
+
+fn prev_prime_properties_helper<T: PrimitiveUnsigned>() {
+    let largest_prime = T::MAX.prev_prime();
+    unsigned_gen::<T>().test_properties(|n| {
+        if n <= T::TWO {
+            return;
+        }
+        let p = n.prev_prime();
+        assert!(p < n);
+        assert!(p.is_prime());
+        if p < largest_prime {
+            assert!(p.next_prime() >= n);
+        }
+    });
+
+    unsigned_gen_var_5::<T>().test_properties(|n| {
+        if n > T::TWO {
+            assert_eq!(n.prev_prime(), T::primes_less_than(&n).last().unwrap());
+        }
+    });
+}
+
+#[test]
+fn prev_prime_properties() {
+    prev_prime_properties_helper::<u8>();
+    prev_prime_properties_helper::<u16>();
+    prev_prime_properties_helper::<u32>();
+    prev_prime_properties_helper::<u64>();
+    prev_prime_properties_helper::<usize>();
+}
@@ -0,0 +1,134 @@
+use malachite_base::num::basic::unsigneds::PrimitiveUnsigned;
+use malachite_base::num::factorization::traits::{NthPrime, PrimeCount, Primes};
+use malachite_base::test_util::generators::unsigned_gen_var_5;
+use std::panic::catch_unwind;
+
+fn test_prime_count_helper<T: PrimitiveUnsigned>() {
+    let test = |n: u8, out: u64| {
+        assert_eq!(T::from(n).prime_count(), out);
+    };
+    test(0, 0);
+    test(1, 0);
+    test(2, 1);
+    test(3, 2);
+    test(4, 2);
+    test(5, 3);
+    test(10, 4);
+    test(100, 25);
+    test(251, 54);
+    test(255, 54);
+}
+
+#[test]
+fn test_prime_count() {
+    apply_fn_to_unsigneds!(test_prime_count_helper);
+
+    let test = |n: u64, out: u64| {
+        assert_eq!(n.prime_count(), out);
+        assert_eq!(u128::from(n).prime_count(), out);
+    };
+    test(1000, 168);
+    test(65535, 6542);
+    test(65536, 6542);
+    test(65537, 6543);
+    test(1000000, 78498);
+    test(10000000, 664579);
+    test(100000000, 5761455);
+    test(1000000000, 50847534);
+    test(4294967295, 203280221);
+    test(10000000000, 455052511);
+}
+
+#[test]
+fn prime_count_fail() {
+    assert_panic!((u128::from(u64::MAX) + 1).prime_count());
+}
+
+fn test_nth_prime_helper<T: PrimitiveUnsigned>() {
+    let test = |n: u64, out: u8| {
+        assert_eq!(T::nth_prime(n), T::from(out));
+    };
+    test(1, 2);
+    test(2, 3);
+    test(3, 5);
+    test(4, 7);
+    test(25, 97);
+    test(54, 251);
+}
+
+#[test]
+fn test_nth_prime() {
+    apply_fn_to_unsigneds!(test_nth_prime_helper);
+
+    let test = |n: u64, out: u64| {
+        assert_eq!(u64::nth_prime(n), out);
+        assert_eq!(u128::nth_prime(n), u128::from(out));
+    };
+    test(1000, 7919);
+    test(4096, 38873);
+    test(6542, 65521);
+    test(6543, 65537);
+    test(78498, 999983);
+    test(1000000, 15485863);
+    test(5761455, 99999989);
+    test(50847534, 999999937);
+    test(203280221, 4294967291);
+    test(1000000000, 22801763489);
+}
+
+fn nth_prime_fail_helper<T: PrimitiveUnsigned>() {
+    assert_panic!(T::nth_prime(0));
+}
+
+#[test]
+fn nth_prime_fail() {
+    apply_fn_to_unsigneds!(nth_prime_fail_helper);
+    assert_panic!(u8::nth_prime(55));
+    assert_panic!(u16::nth_prime(6543));
+    assert_panic!(u32::nth_prime(203280222));
+}
+
+fn prime_count_properties_helper<T: PrimitiveUnsigned>() {
+    unsigned_gen_var_5::<T>().test_properties(|n| {
+        let count = n.prime_count();
+        assert_eq!(
+            count,
+            u64::try_from(T::primes_less_than_or_equal_to(&n).count()).unwrap()
+        );
+        if n.is_prime() {
+            assert_eq!(T::nth_prime(count), n);
+        }
+    });
+}
+
+#[test]
+fn prime_count_properties() {
+    prime_count_properties_helper::<u8>();
+    prime_count_properties_helper::<u16>();
+    prime_count_properties_helper::<u32>();
+    prime_count_properties_helper::<u64>();
+    prime_count_properties_helper::<usize>();
+
+    // Check the combinatorial algorithm against a sieve just above the point where it takes over
+    let mut count = 0;
+    let mut ps = u64::primes().peekable();
+    for x in 0..300000u64 {
+        while *ps.peek().unwrap() <= x {
+            ps.next();
+            count += 1;
+        }
+        if x % 97 == 0 || (65000..66000).contains(&x) {
+            assert_eq!(x.prime_count(), count);
+        }
+    }
+}
+
+#[test]
+fn nth_prime_properties() {
+    for (n, p) in (1..).zip(u64::primes().take(100000)) {
+        if n % 37 == 0 || (4000..5000).contains(&n) {
+            assert_eq!(u64::nth_prime(n), p);
+            assert_eq!(p.prime_count(), n);
+        }
+    }
+}
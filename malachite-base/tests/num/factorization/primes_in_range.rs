@@ -0,0 +1,202 @@
+use itertools::Itertools;
+use malachite_base::iterators::comparison::is_strictly_ascending;
+use malachite_base::num::basic::unsigneds::PrimitiveUnsigned;
+use malachite_base::num::factorization::traits::{Primes, PrimesInRange};
+use malachite_base::test_util::generators::{
+    unsigned_gen, unsigned_pair_gen_var_27, unsigned_pair_gen_var_28,
+};
+
+fn test_primes_in_range_helper<T: PrimitiveUnsigned>() {
+    let test = |a: u8, b: u8, out: &[u8]| {
+        let a = T::from(a);
+        let b = T::from(b);
+        let out = out.iter().map(|&x| T::from(x)).collect_vec();
+        assert_eq!(T::primes_in_range(&a, &b).collect_vec(), out);
+        assert_eq!(
+            T::primes()
+                .skip_while(|&p| p < a)
+                .take_while(|&p| p < b)
+                .collect_vec(),
+            out
+        );
+    };
+    test(0, 0, &[]);
+    test(0, 2, &[]);
+    test(0, 3, &[2]);
+    test(2, 3, &[2]);
+    test(3, 3, &[]);
+    test(5, 3, &[]);
+    test(0, 20, &[2, 3, 5, 7, 11, 13, 17, 19]);
+    test(4, 20, &[5, 7, 11, 13, 17, 19]);
+    test(10, 30, &[11, 13, 17, 19, 23, 29]);
+    test(90, 97, &[]);
+    test(90, 98, &[97]);
+    test(200, 255, &[211, 223, 227, 229, 233, 239, 241, 251]);
+}
+
+#[test]
+fn test_primes_in_range() {
+    apply_fn_to_unsigneds!(test_primes_in_range_helper);
+
+    let test = |a: u64, b: u64, out: &[u64]| {
+        assert_eq!(u64::primes_in_range(&a, &b).collect_vec(), out);
+        assert_eq!(
+            u128::primes_in_range(&u128::from(a), &u128::from(b)).collect_vec(),
+            out.iter().map(|&p| u128::from(p)).collect_vec()
+        );
+    };
+    test(
+        65500,
+        65600,
+        &[65519, 65521, 65537, 65539, 65543, 65551, 65557, 65563, 65579, 65581, 65587, 65599],
+    );
+    test(
+        4294967200,
+        4294967400,
+        &[
+            4294967231, 4294967279, 4294967291, 4294967311, 4294967357, 4294967371, 4294967377,
+            4294967387, 4294967389,
+        ],
+    );
+    test(
+        1000000000000,
+        1000000000100,
+        &[1000000000039, 1000000000061, 1000000000063, 1000000000091],
+    );
+    test(
+        18446744073709551400,
+        u64::MAX,
+        &[
+            18446744073709551427,
+            18446744073709551437,
+            18446744073709551521,
+            18446744073709551533,
+            18446744073709551557,
+        ],
+    );
+    // The range spans several segments of the sieve.
+    assert_eq!(u32::primes_in_range(&1000000, &1100000).count(), 7216);
+    assert_eq!(
+        u64::primes_in_range(&1000000000000, &1000000100000).count(),
+        3614
+    );
+}
+
+fn test_primes_greater_than_or_equal_to_helper<T: PrimitiveUnsigned>() {
+    let test = |a: u8, out: &[u8]| {
+        let a = T::from(a);
+        let out = out.iter().map(|&x| T::from(x)).collect_vec();
+        assert_eq!(
+            T::primes_greater_than_or_equal_to(&a).take(5).collect_vec(),
+            out
+        );
+    };
+    test(0, &[2, 3, 5, 7, 11]);
+    test(2, &[2, 3, 5, 7, 11]);
+    test(3, &[3, 5, 7, 11, 13]);
+    test(4, &[5, 7, 11, 13, 17]);
+    test(100, &[101, 103, 107, 109, 113]);
+}
+
+#[test]
+fn test_primes_greater_than_or_equal_to() {
+    apply_fn_to_unsigneds!(test_primes_greater_than_or_equal_to_helper);
+
+    assert_eq!(
+        u8::primes_greater_than_or_equal_to(&240).collect_vec(),
+        &[241, 251]
+    );
+    assert_eq!(u8::primes_greater_than_or_equal_to(&252).collect_vec(), &[]);
+    assert_eq!(
+        u16::primes_greater_than_or_equal_to(&65500).collect_vec(),
+        &[65519, 65521]
+    );
+    assert_eq!(
+        u64::primes_greater_than_or_equal_to(&18446744073709551500).collect_vec(),
+        &[18446744073709551521, 18446744073709551533, 18446744073709551557]
+    );
+    assert_eq!(
+        u128::primes_greater_than_or_equal_to(&u128::from(u64::MAX))
+            .take(3)
+            .collect_vec(),
+        &[18446744073709551629, 18446744073709551653, 18446744073709551667]
+    );
+}
+
+fn primes_in_range_properties_helper<T: PrimitiveUnsigned>() {
+    unsigned_pair_gen_var_27::<T>().test_properties(|(a, b)| {
+        let primes = T::primes_in_range(&a, &b).take(5).collect_vec();
+        assert!(is_strictly_ascending(primes.iter()));
+        // Check that no primes are skipped
+        let mut n = a;
+        for &p in &primes {
+            assert!(p < b);
+            while n < p {
+                assert!(!n.is_prime());
+                n += T::ONE;
+            }
+            assert!(p.is_prime());
+            n += T::ONE;
+        }
+        if primes.len() < 5 {
+            while n < b {
+                assert!(!n.is_prime());
+                n += T::ONE;
+            }
+        }
+    });
+
+    unsigned_pair_gen_var_28::<T, T>().test_properties(|(a, b)| {
+        assert_eq!(
+            T::primes_in_range(&a, &b).collect_vec(),
+            T::primes()
+                .skip_while(|&p| p < a)
+                .take_while(|&p| p < b)
+                .collect_vec()
+        );
+    });
+}
+
+#[test]
+fn primes_in_range_properties() {
+    // Random u128s are too slow to test in debug builds
+    primes_in_range_properties_helper::<u8>();
+    primes_in_range_properties_helper::<u16>();
+    primes_in_range_properties_helper::<u32>();
+    primes_in_range_properties_helper::<u64>();
+    primes_in_range_properties_helper::<usize>();
+}
+
+fn primes_greater_than_or_equal_to_properties_helper<T: PrimitiveUnsigned>() {
+    let largest_prime = T::MAX.prev_prime();
+    unsigned_gen::<T>().test_properties(|a| {
+        let mut primes = T::primes_greater_than_or_equal_to(&a);
+        if a > largest_prime {
+            assert_eq!(primes.next(), None);
+        } else {
+            let p = primes.next().unwrap();
+            assert!(p.is_prime());
+            if a == T::ZERO {
+                assert_eq!(p, T::TWO);
+            } else {
+                assert_eq!((a - T::ONE).next_prime(), p);
+            }
+            if let Some(q) = primes.next() {
+                assert_eq!(p.next_prime(), q);
+            }
+        }
+    });
+}
+
+#[test]
+fn primes_greater_than_or_equal_to_properties() {
+    primes_greater_than_or_equal_to_properties_helper::<u8>();
+    primes_greater_than_or_equal_to_properties_helper::<u16>();
+    primes_greater_than_or_equal_to_properties_helper::<u32>();
+    primes_greater_than_or_equal_to_properties_helper::<u64>();
+    primes_greater_than_or_equal_to_properties_helper::<usize>();
+
+    assert!(u32::primes_greater_than_or_equal_to(&0)
+        .take_while(|&p| p < 1000000)
+        .eq(u32::primes_less_than(&1000000)));
+}
@@ -0,0 +1,108 @@
+use crate::{Float, InnerFloat};
+use malachite_base::num::arithmetic::traits::{Abs, AbsAssign};
+
+impl Abs for Float {
+    type Output = Float;
+
+    /// Takes the absolute value of a [`Float`], taking the [`Float`] by value.
+    ///
+    /// NaN is mapped to itself, and negative zero and negative infinity are mapped to positive
+    /// zero and positive infinity. Taking the absolute value is always exact, and the precision is
+    /// unchanged.
+    ///
+    /// $$
+    /// f(x) = |x|.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::Abs;
+    /// use malachite_base::num::basic::traits::NegativeOne;
+    /// use malachite_float::Float;
+    ///
+    /// assert!(Float::NAN.abs().is_nan());
+    /// assert_eq!(Float::NEGATIVE_INFINITY.abs().to_string(), "Infinity");
+    /// assert_eq!(Float::NEGATIVE_ZERO.abs().to_string(), "0.0");
+    /// assert_eq!(Float::NEGATIVE_ONE.abs().to_string(), "1.0");
+    /// ```
+    fn abs(mut self) -> Float {
+        self.abs_assign();
+        self
+    }
+}
+
+impl<'a> Abs for &'a Float {
+    type Output = Float;
+
+    /// Takes the absolute value of a [`Float`], taking the [`Float`] by reference.
+    ///
+    /// NaN is mapped to itself, and negative zero and negative infinity are mapped to positive
+    /// zero and positive infinity. Taking the absolute value is always exact, and the precision is
+    /// unchanged.
+    ///
+    /// $$
+    /// f(x) = |x|.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.get_prec()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::Abs;
+    /// use malachite_base::num::basic::traits::NegativeOne;
+    /// use malachite_float::Float;
+    ///
+    /// assert!((&Float::NAN).abs().is_nan());
+    /// assert_eq!((&Float::NEGATIVE_INFINITY).abs().to_string(), "Infinity");
+    /// assert_eq!((&Float::NEGATIVE_ZERO).abs().to_string(), "0.0");
+    /// assert_eq!((&Float::NEGATIVE_ONE).abs().to_string(), "1.0");
+    /// ```
+    fn abs(self) -> Float {
+        self.clone().abs()
+    }
+}
+
+impl AbsAssign for Float {
+    /// Replaces a [`Float`] with its absolute value.
+    ///
+    /// NaN is mapped to itself, and negative zero and negative infinity are mapped to positive
+    /// zero and positive infinity. Taking the absolute value is always exact, and the precision is
+    /// unchanged.
+    ///
+    /// $$
+    /// x \gets |x|.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::AbsAssign;
+    /// use malachite_float::Float;
+    ///
+    /// let mut x = Float::NEGATIVE_ZERO;
+    /// x.abs_assign();
+    /// assert_eq!(x.to_string(), "0.0");
+    ///
+    /// let mut x = Float::from(-1.5f64);
+    /// x.abs_assign();
+    /// assert_eq!(x.to_string(), "1.5");
+    /// ```
+    fn abs_assign(&mut self) {
+        match &mut self.0 {
+            InnerFloat::NaN => {}
+            InnerFloat::Infinity { sign }
+            | InnerFloat::Zero { sign }
+            | InnerFloat::Finite { sign, .. } => *sign = true,
+        }
+    }
+}
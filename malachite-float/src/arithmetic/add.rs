@@ -0,0 +1,859 @@
+use crate::arithmetic::{max_prec, round_natural};
+use crate::{Float, InnerFloat};
+use malachite_base::num::basic::traits::{One, Zero};
+use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_base::rounding_modes::RoundingMode;
+use malachite_nz::natural::Natural;
+use std::cmp::{max, Ordering};
+use std::ops::{Add, AddAssign};
+
+// Computes $x + y$ if `negate_y` is `false`, and $x - y$ if it is `true`.
+pub(crate) fn add_helper(
+    x: &Float,
+    y: &Float,
+    negate_y: bool,
+    prec: u64,
+    rm: RoundingMode,
+) -> (Float, Ordering) {
+    assert_ne!(prec, 0, "Precision must be positive");
+    match (&x.0, &y.0) {
+        (InnerFloat::NaN, _) | (_, InnerFloat::NaN) => (Float::NAN, Ordering::Equal),
+        (InnerFloat::Infinity { sign: x_sign }, InnerFloat::Infinity { sign: y_sign }) => {
+            if *x_sign == (*y_sign != negate_y) {
+                (x.clone(), Ordering::Equal)
+            } else {
+                (Float::NAN, Ordering::Equal)
+            }
+        }
+        (InnerFloat::Infinity { .. }, _) => (x.clone(), Ordering::Equal),
+        (_, InnerFloat::Infinity { sign }) => (
+            Float(InnerFloat::Infinity {
+                sign: *sign != negate_y,
+            }),
+            Ordering::Equal,
+        ),
+        (InnerFloat::Zero { sign: x_sign }, InnerFloat::Zero { sign: y_sign }) => {
+            let y_sign = *y_sign != negate_y;
+            (
+                Float(InnerFloat::Zero {
+                    sign: if *x_sign == y_sign {
+                        y_sign
+                    } else {
+                        rm != RoundingMode::Floor
+                    },
+                }),
+                Ordering::Equal,
+            )
+        }
+        (
+            InnerFloat::Zero { .. },
+            InnerFloat::Finite {
+                sign,
+                exponent,
+                precision,
+                significand,
+            },
+        ) => round_natural(
+            *sign != negate_y,
+            significand.clone(),
+            i128::from(*exponent) - i128::from(*precision),
+            prec,
+            rm,
+        ),
+        (
+            InnerFloat::Finite {
+                sign,
+                exponent,
+                precision,
+                significand,
+            },
+            InnerFloat::Zero { .. },
+        ) => round_natural(
+            *sign,
+            significand.clone(),
+            i128::from(*exponent) - i128::from(*precision),
+            prec,
+            rm,
+        ),
+        (
+            InnerFloat::Finite {
+                sign: x_sign,
+                exponent: x_exponent,
+                precision: x_precision,
+                significand: x_significand,
+            },
+            InnerFloat::Finite {
+                sign: y_sign,
+                exponent: y_exponent,
+                precision: y_precision,
+                significand: y_significand,
+            },
+        ) => {
+            let x = (*x_sign, *x_exponent, *x_precision, x_significand);
+            let y = (
+                *y_sign != negate_y,
+                *y_exponent,
+                *y_precision,
+                y_significand,
+            );
+            // Make `x` the operand with the larger exponent.
+            let (x, y) = if x.1 >= y.1 { (x, y) } else { (y, x) };
+            add_finite(x, y, prec, rm)
+        }
+    }
+}
+
+// Adds two finite, nonzero values, each given as (sign, exponent, precision, significand), where
+// the first has the larger exponent.
+fn add_finite(
+    (x_sign, x_exponent, x_precision, x_significand): (bool, i64, u64, &Natural),
+    (y_sign, y_exponent, y_precision, y_significand): (bool, i64, u64, &Natural),
+    prec: u64,
+    rm: RoundingMode,
+) -> (Float, Ordering) {
+    let x_low = i128::from(x_exponent) - i128::from(x_precision);
+    let y_low = i128::from(y_exponent) - i128::from(y_precision);
+    // If $y$ lies entirely below the rounding position of the result, it only matters that it is
+    // nonzero. Replacing it by a single bit far enough below $x$ gives the same rounded result,
+    // while avoiding a shift by the (possibly enormous) difference of the exponents.
+    let shift = max(i128::from(prec) - i128::from(x_precision), 0) + 3;
+    if i128::from(y_exponent) <= x_low - shift {
+        let n = x_significand << u64::exact_from(shift);
+        let n = if x_sign == y_sign {
+            n + Natural::ONE
+        } else {
+            n - Natural::ONE
+        };
+        return round_natural(x_sign, n, x_low - shift, prec, rm);
+    }
+    let low = std::cmp::min(x_low, y_low);
+    let x_n = x_significand << u64::exact_from(x_low - low);
+    let y_n = y_significand << u64::exact_from(y_low - low);
+    if x_sign == y_sign {
+        round_natural(x_sign, x_n + y_n, low, prec, rm)
+    } else {
+        match x_n.cmp(&y_n) {
+            Ordering::Equal => (
+                if rm == RoundingMode::Floor {
+                    Float::NEGATIVE_ZERO
+                } else {
+                    Float::ZERO
+                },
+                Ordering::Equal,
+            ),
+            Ordering::Greater => round_natural(x_sign, x_n - y_n, low, prec, rm),
+            Ordering::Less => round_natural(y_sign, y_n - x_n, low, prec, rm),
+        }
+    }
+}
+
+impl Float {
+    /// Adds two [`Float`]s, rounding the result to the specified precision and with the specified
+    /// rounding mode. Both [`Float`]s are taken by value. An [`Ordering`] is also returned,
+    /// indicating whether the rounded sum is less than, equal to, or greater than the exact sum.
+    /// Although `NaN`s are not comparable to any [`Float`], whenever this function returns a `NaN`
+    /// it also returns `Equal`.
+    ///
+    /// See [`RoundingMode`](malachite_base::rounding_modes::RoundingMode) for a description of the
+    /// possible rounding modes.
+    ///
+    /// $$
+    /// f(x,y,p,m) = x+y+\varepsilon.
+    /// $$
+    /// - If $x+y$ is infinite, zero, or NaN, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $x+y$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |x+y|\rfloor-p+1}$.
+    /// - If $x+y$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| \leq
+    ///   2^{\lfloor\log_2 |x+y|\rfloor-p}$.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},x)=f(x,\text{NaN})=f(\infty,-\infty)=f(-\infty,\infty)=\text{NaN}$
+    /// - $f(\infty,x)=f(x,\infty)=\infty$ if $x$ is not NaN or $-\infty$
+    /// - $f(-\infty,x)=f(x,-\infty)=-\infty$ if $x$ is not NaN or $\infty$
+    /// - $f(0.0,0.0)=0.0$
+    /// - $f(-0.0,-0.0)=-0.0$
+    /// - $f(0.0,-0.0)=f(-0.0,0.0)=0.0$ if `rm` is not `Floor`, and $-0.0$ if it is
+    /// - $f(x,-x)=0.0$ if $x$ is finite and nonzero and `rm` is not `Floor`, and $-0.0$ if it is
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.get_prec(),
+    /// other.get_prec(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero, or if `rm` is `Exact` but the sum cannot be represented exactly
+    /// with the given precision.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    /// use std::f64::consts::{E, PI};
+    ///
+    /// let (sum, o) = Float::from(PI).add_prec_round(Float::from(E), 5, RoundingMode::Floor);
+    /// assert_eq!(sum.to_string(), "5.8");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (sum, o) = Float::from(PI).add_prec_round(Float::from(E), 5, RoundingMode::Ceiling);
+    /// assert_eq!(sum.to_string(), "6.0");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (sum, o) = Float::from(PI).add_prec_round(Float::from(E), 5, RoundingMode::Nearest);
+    /// assert_eq!(sum.to_string(), "5.8");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (sum, o) = Float::from(PI).add_prec_round(Float::from(E), 20, RoundingMode::Nearest);
+    /// assert_eq!(sum.to_string(), "5.85987");
+    /// assert_eq!(o, Ordering::Less);
+    /// ```
+    #[inline]
+    #[allow(clippy::needless_pass_by_value)]
+    pub fn add_prec_round(self, other: Float, prec: u64, rm: RoundingMode) -> (Float, Ordering) {
+        self.add_prec_round_ref_ref(&other, prec, rm)
+    }
+
+    /// Adds two [`Float`]s, rounding the result to the specified precision and with the specified
+    /// rounding mode. Both [`Float`]s are taken by reference. An [`Ordering`] is also returned,
+    /// indicating whether the rounded sum is less than, equal to, or greater than the exact sum.
+    /// Although `NaN`s are not comparable to any [`Float`], whenever this function returns a `NaN`
+    /// it also returns `Equal`.
+    ///
+    /// See [`RoundingMode`](malachite_base::rounding_modes::RoundingMode) for a description of the
+    /// possible rounding modes.
+    ///
+    /// $$
+    /// f(x,y,p,m) = x+y+\varepsilon.
+    /// $$
+    /// - If $x+y$ is infinite, zero, or NaN, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $x+y$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |x+y|\rfloor-p+1}$.
+    /// - If $x+y$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| \leq
+    ///   2^{\lfloor\log_2 |x+y|\rfloor-p}$.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},x)=f(x,\text{NaN})=f(\infty,-\infty)=f(-\infty,\infty)=\text{NaN}$
+    /// - $f(\infty,x)=f(x,\infty)=\infty$ if $x$ is not NaN or $-\infty$
+    /// - $f(-\infty,x)=f(x,-\infty)=-\infty$ if $x$ is not NaN or $\infty$
+    /// - $f(0.0,0.0)=0.0$
+    /// - $f(-0.0,-0.0)=-0.0$
+    /// - $f(0.0,-0.0)=f(-0.0,0.0)=0.0$ if `rm` is not `Floor`, and $-0.0$ if it is
+    /// - $f(x,-x)=0.0$ if $x$ is finite and nonzero and `rm` is not `Floor`, and $-0.0$ if it is
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.get_prec(),
+    /// other.get_prec(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero, or if `rm` is `Exact` but the sum cannot be represented exactly
+    /// with the given precision.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    /// use std::f64::consts::{E, PI};
+    ///
+    /// let (sum, o) =
+    ///     Float::from(PI).add_prec_round_ref_ref(&Float::from(E), 5, RoundingMode::Floor);
+    /// assert_eq!(sum.to_string(), "5.8");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (sum, o) =
+    ///     Float::from(PI).add_prec_round_ref_ref(&Float::from(E), 5, RoundingMode::Ceiling);
+    /// assert_eq!(sum.to_string(), "6.0");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (sum, o) =
+    ///     Float::from(PI).add_prec_round_ref_ref(&Float::from(E), 5, RoundingMode::Nearest);
+    /// assert_eq!(sum.to_string(), "5.8");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (sum, o) =
+    ///     Float::from(PI).add_prec_round_ref_ref(&Float::from(E), 20, RoundingMode::Nearest);
+    /// assert_eq!(sum.to_string(), "5.85987");
+    /// assert_eq!(o, Ordering::Less);
+    /// ```
+    pub fn add_prec_round_ref_ref(
+        &self,
+        other: &Float,
+        prec: u64,
+        rm: RoundingMode,
+    ) -> (Float, Ordering) {
+        add_helper(self, other, false, prec, rm)
+    }
+
+    /// Adds two [`Float`]s, rounding the result to the nearest value of the specified precision.
+    /// Both [`Float`]s are taken by value. An [`Ordering`] is also returned, indicating whether the
+    /// rounded sum is less than, equal to, or greater than the exact sum. Although `NaN`s are not
+    /// comparable to any [`Float`], whenever this function returns a `NaN` it also returns `Equal`.
+    ///
+    /// If the sum is equidistant from two [`Float`]s with the specified precision, the [`Float`]
+    /// with fewer 1s in its binary expansion is chosen. See
+    /// [`RoundingMode`](malachite_base::rounding_modes::RoundingMode) for a description of the
+    /// `Nearest` rounding mode.
+    ///
+    /// $$
+    /// f(x,y,p) = x+y+\varepsilon.
+    /// $$
+    /// - If $x+y$ is infinite, zero, or NaN, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $x+y$ is finite and nonzero, then $|\varepsilon| \leq 2^{\lfloor\log_2
+    ///   |x+y|\rfloor-p}$.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},x)=f(x,\text{NaN})=f(\infty,-\infty)=f(-\infty,\infty)=\text{NaN}$
+    /// - $f(\infty,x)=f(x,\infty)=\infty$ if $x$ is not NaN or $-\infty$
+    /// - $f(-\infty,x)=f(x,-\infty)=-\infty$ if $x$ is not NaN or $\infty$
+    /// - $f(0.0,0.0)=0.0$
+    /// - $f(-0.0,-0.0)=-0.0$
+    /// - $f(0.0,-0.0)=f(-0.0,0.0)=0.0$ if `rm` is not `Floor`, and $-0.0$ if it is
+    /// - $f(x,-x)=0.0$ if $x$ is finite and nonzero and `rm` is not `Floor`, and $-0.0$ if it is
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.get_prec(),
+    /// other.get_prec(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    /// use std::f64::consts::{E, PI};
+    ///
+    /// let (sum, o) = Float::from(PI).add_prec(Float::from(E), 5);
+    /// assert_eq!(sum.to_string(), "5.8");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (sum, o) = Float::from(PI).add_prec(Float::from(E), 20);
+    /// assert_eq!(sum.to_string(), "5.85987");
+    /// assert_eq!(o, Ordering::Less);
+    /// ```
+    #[inline]
+    pub fn add_prec(self, other: Float, prec: u64) -> (Float, Ordering) {
+        self.add_prec_round(other, prec, RoundingMode::Nearest)
+    }
+
+    /// Adds two [`Float`]s, rounding the result to the nearest value of the specified precision.
+    /// Both [`Float`]s are taken by reference. An [`Ordering`] is also returned, indicating whether
+    /// the rounded sum is less than, equal to, or greater than the exact sum. Although `NaN`s are
+    /// not comparable to any [`Float`], whenever this function returns a `NaN` it also returns
+    /// `Equal`.
+    ///
+    /// If the sum is equidistant from two [`Float`]s with the specified precision, the [`Float`]
+    /// with fewer 1s in its binary expansion is chosen. See
+    /// [`RoundingMode`](malachite_base::rounding_modes::RoundingMode) for a description of the
+    /// `Nearest` rounding mode.
+    ///
+    /// $$
+    /// f(x,y,p) = x+y+\varepsilon.
+    /// $$
+    /// - If $x+y$ is infinite, zero, or NaN, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $x+y$ is finite and nonzero, then $|\varepsilon| \leq 2^{\lfloor\log_2
+    ///   |x+y|\rfloor-p}$.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},x)=f(x,\text{NaN})=f(\infty,-\infty)=f(-\infty,\infty)=\text{NaN}$
+    /// - $f(\infty,x)=f(x,\infty)=\infty$ if $x$ is not NaN or $-\infty$
+    /// - $f(-\infty,x)=f(x,-\infty)=-\infty$ if $x$ is not NaN or $\infty$
+    /// - $f(0.0,0.0)=0.0$
+    /// - $f(-0.0,-0.0)=-0.0$
+    /// - $f(0.0,-0.0)=f(-0.0,0.0)=0.0$ if `rm` is not `Floor`, and $-0.0$ if it is
+    /// - $f(x,-x)=0.0$ if $x$ is finite and nonzero and `rm` is not `Floor`, and $-0.0$ if it is
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.get_prec(),
+    /// other.get_prec(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    /// use std::f64::consts::{E, PI};
+    ///
+    /// let (sum, o) = Float::from(PI).add_prec_ref_ref(&Float::from(E), 5);
+    /// assert_eq!(sum.to_string(), "5.8");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (sum, o) = Float::from(PI).add_prec_ref_ref(&Float::from(E), 20);
+    /// assert_eq!(sum.to_string(), "5.85987");
+    /// assert_eq!(o, Ordering::Less);
+    /// ```
+    #[inline]
+    pub fn add_prec_ref_ref(&self, other: &Float, prec: u64) -> (Float, Ordering) {
+        self.add_prec_round_ref_ref(other, prec, RoundingMode::Nearest)
+    }
+
+    /// Adds two [`Float`]s, rounding the result with the specified rounding mode. Both [`Float`]s
+    /// are taken by value. An [`Ordering`] is also returned, indicating whether the rounded sum is
+    /// less than, equal to, or greater than the exact sum. Although `NaN`s are not comparable to
+    /// any [`Float`], whenever this function returns a `NaN` it also returns `Equal`.
+    ///
+    /// The precision of the output is the maximum of the precision of the inputs. See
+    /// [`RoundingMode`](malachite_base::rounding_modes::RoundingMode) for a description of the
+    /// possible rounding modes.
+    ///
+    /// $$
+    /// f(x,y,m) = x+y+\varepsilon.
+    /// $$
+    /// - If $x+y$ is infinite, zero, or NaN, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $x+y$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |x+y|\rfloor-p+1}$, where $p$ is the maximum precision of the inputs.
+    /// - If $x+y$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| \leq
+    ///   2^{\lfloor\log_2 |x+y|\rfloor-p}$, where $p$ is the maximum precision of the inputs.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},x)=f(x,\text{NaN})=f(\infty,-\infty)=f(-\infty,\infty)=\text{NaN}$
+    /// - $f(\infty,x)=f(x,\infty)=\infty$ if $x$ is not NaN or $-\infty$
+    /// - $f(-\infty,x)=f(x,-\infty)=-\infty$ if $x$ is not NaN or $\infty$
+    /// - $f(0.0,0.0)=0.0$
+    /// - $f(-0.0,-0.0)=-0.0$
+    /// - $f(0.0,-0.0)=f(-0.0,0.0)=0.0$ if `rm` is not `Floor`, and $-0.0$ if it is
+    /// - $f(x,-x)=0.0$ if $x$ is finite and nonzero and `rm` is not `Floor`, and $-0.0$ if it is
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.get_prec(),
+    /// other.get_prec())`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the sum cannot be represented exactly with the maximum
+    /// precision of the inputs.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    /// use std::f64::consts::{E, PI};
+    ///
+    /// let (sum, o) = Float::from(PI).add_round(Float::from(E), RoundingMode::Floor);
+    /// assert_eq!(sum.to_string(), "5.859874482048838");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (sum, o) = Float::from(PI).add_round(Float::from(E), RoundingMode::Ceiling);
+    /// assert_eq!(sum.to_string(), "5.859874482048839");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (sum, o) = Float::from(PI).add_round(Float::from(E), RoundingMode::Nearest);
+    /// assert_eq!(sum.to_string(), "5.859874482048838");
+    /// assert_eq!(o, Ordering::Less);
+    /// ```
+    #[inline]
+    #[allow(clippy::needless_pass_by_value)]
+    pub fn add_round(self, other: Float, rm: RoundingMode) -> (Float, Ordering) {
+        self.add_round_ref_ref(&other, rm)
+    }
+
+    /// Adds two [`Float`]s, rounding the result with the specified rounding mode. Both [`Float`]s
+    /// are taken by reference. An [`Ordering`] is also returned, indicating whether the rounded sum
+    /// is less than, equal to, or greater than the exact sum. Although `NaN`s are not comparable to
+    /// any [`Float`], whenever this function returns a `NaN` it also returns `Equal`.
+    ///
+    /// The precision of the output is the maximum of the precision of the inputs. See
+    /// [`RoundingMode`](malachite_base::rounding_modes::RoundingMode) for a description of the
+    /// possible rounding modes.
+    ///
+    /// $$
+    /// f(x,y,m) = x+y+\varepsilon.
+    /// $$
+    /// - If $x+y$ is infinite, zero, or NaN, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $x+y$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |x+y|\rfloor-p+1}$, where $p$ is the maximum precision of the inputs.
+    /// - If $x+y$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| \leq
+    ///   2^{\lfloor\log_2 |x+y|\rfloor-p}$, where $p$ is the maximum precision of the inputs.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},x)=f(x,\text{NaN})=f(\infty,-\infty)=f(-\infty,\infty)=\text{NaN}$
+    /// - $f(\infty,x)=f(x,\infty)=\infty$ if $x$ is not NaN or $-\infty$
+    /// - $f(-\infty,x)=f(x,-\infty)=-\infty$ if $x$ is not NaN or $\infty$
+    /// - $f(0.0,0.0)=0.0$
+    /// - $f(-0.0,-0.0)=-0.0$
+    /// - $f(0.0,-0.0)=f(-0.0,0.0)=0.0$ if `rm` is not `Floor`, and $-0.0$ if it is
+    /// - $f(x,-x)=0.0$ if $x$ is finite and nonzero and `rm` is not `Floor`, and $-0.0$ if it is
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.get_prec(),
+    /// other.get_prec())`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the sum cannot be represented exactly with the maximum
+    /// precision of the inputs.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    /// use std::f64::consts::{E, PI};
+    ///
+    /// let (sum, o) = Float::from(PI).add_round_ref_ref(&Float::from(E), RoundingMode::Floor);
+    /// assert_eq!(sum.to_string(), "5.859874482048838");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (sum, o) = Float::from(PI).add_round_ref_ref(&Float::from(E), RoundingMode::Ceiling);
+    /// assert_eq!(sum.to_string(), "5.859874482048839");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (sum, o) = Float::from(PI).add_round_ref_ref(&Float::from(E), RoundingMode::Nearest);
+    /// assert_eq!(sum.to_string(), "5.859874482048838");
+    /// assert_eq!(o, Ordering::Less);
+    /// ```
+    pub fn add_round_ref_ref(&self, other: &Float, rm: RoundingMode) -> (Float, Ordering) {
+        let prec = max_prec(self, other);
+        self.add_prec_round_ref_ref(other, prec, rm)
+    }
+}
+
+impl Add<Float> for Float {
+    type Output = Float;
+
+    /// Adds two [`Float`]s, taking both by value.
+    ///
+    /// If the output has a precision, it is the maximum of the precisions of the inputs. If the sum
+    /// is equidistant from two [`Float`]s with that precision, the [`Float`] with fewer 1s in its
+    /// binary expansion is chosen. See
+    /// [`RoundingMode`](malachite_base::rounding_modes::RoundingMode) for a description of the
+    /// `Nearest` rounding mode.
+    ///
+    /// $$
+    /// f(x,y) = x+y+\varepsilon.
+    /// $$
+    /// - If $x+y$ is infinite, zero, or NaN, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $x+y$ is finite and nonzero, then $|\varepsilon| \leq 2^{\lfloor\log_2
+    ///   |x+y|\rfloor-p}$, where $p$ is the maximum precision of the inputs.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},x)=f(x,\text{NaN})=f(\infty,-\infty)=f(-\infty,\infty)=\text{NaN}$
+    /// - $f(\infty,x)=f(x,\infty)=\infty$ if $x$ is not NaN or $-\infty$
+    /// - $f(-\infty,x)=f(x,-\infty)=-\infty$ if $x$ is not NaN or $\infty$
+    /// - $f(0.0,0.0)=0.0$
+    /// - $f(-0.0,-0.0)=-0.0$
+    /// - $f(0.0,-0.0)=f(-0.0,0.0)=0.0$ if `rm` is not `Floor`, and $-0.0$ if it is
+    /// - $f(x,-x)=0.0$ if $x$ is finite and nonzero and `rm` is not `Floor`, and $-0.0$ if it is
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.get_prec(),
+    /// other.get_prec())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::{NegativeOne, One, Zero};
+    /// use malachite_float::Float;
+    /// use std::f64::consts::{E, PI};
+    ///
+    /// assert!((Float::NAN + Float::ONE).is_nan());
+    /// assert_eq!((Float::INFINITY + Float::ONE).to_string(), "Infinity");
+    /// assert!((Float::INFINITY + Float::NEGATIVE_INFINITY).is_nan());
+    /// assert_eq!((Float::NEGATIVE_ZERO + Float::ZERO).to_string(), "0.0");
+    /// assert_eq!((Float::ONE + Float::NEGATIVE_ONE).to_string(), "0.0");
+    /// assert_eq!((Float::from(PI) + Float::from(E)).to_string(), "5.859874482048838");
+    /// assert_eq!((Float::from(PI) + Float::from(-E)).to_string(), "0.423310825130748");
+    /// ```
+    #[inline]
+    fn add(self, other: Float) -> Float {
+        self.add_round_ref_ref(&other, RoundingMode::Nearest).0
+    }
+}
+
+impl<'a> Add<&'a Float> for Float {
+    type Output = Float;
+
+    /// Adds two [`Float`]s, taking the first by value and the second by reference.
+    ///
+    /// If the output has a precision, it is the maximum of the precisions of the inputs. If the sum
+    /// is equidistant from two [`Float`]s with that precision, the [`Float`] with fewer 1s in its
+    /// binary expansion is chosen. See
+    /// [`RoundingMode`](malachite_base::rounding_modes::RoundingMode) for a description of the
+    /// `Nearest` rounding mode.
+    ///
+    /// $$
+    /// f(x,y) = x+y+\varepsilon.
+    /// $$
+    /// - If $x+y$ is infinite, zero, or NaN, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $x+y$ is finite and nonzero, then $|\varepsilon| \leq 2^{\lfloor\log_2
+    ///   |x+y|\rfloor-p}$, where $p$ is the maximum precision of the inputs.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},x)=f(x,\text{NaN})=f(\infty,-\infty)=f(-\infty,\infty)=\text{NaN}$
+    /// - $f(\infty,x)=f(x,\infty)=\infty$ if $x$ is not NaN or $-\infty$
+    /// - $f(-\infty,x)=f(x,-\infty)=-\infty$ if $x$ is not NaN or $\infty$
+    /// - $f(0.0,0.0)=0.0$
+    /// - $f(-0.0,-0.0)=-0.0$
+    /// - $f(0.0,-0.0)=f(-0.0,0.0)=0.0$ if `rm` is not `Floor`, and $-0.0$ if it is
+    /// - $f(x,-x)=0.0$ if $x$ is finite and nonzero and `rm` is not `Floor`, and $-0.0$ if it is
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.get_prec(),
+    /// other.get_prec())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::{NegativeOne, One, Zero};
+    /// use malachite_float::Float;
+    /// use std::f64::consts::{E, PI};
+    ///
+    /// assert!((Float::NAN + &Float::ONE).is_nan());
+    /// assert_eq!((Float::INFINITY + &Float::ONE).to_string(), "Infinity");
+    /// assert!((Float::INFINITY + &Float::NEGATIVE_INFINITY).is_nan());
+    /// assert_eq!((Float::NEGATIVE_ZERO + &Float::ZERO).to_string(), "0.0");
+    /// assert_eq!((Float::ONE + &Float::NEGATIVE_ONE).to_string(), "0.0");
+    /// assert_eq!((Float::from(PI) + &Float::from(E)).to_string(), "5.859874482048838");
+    /// assert_eq!((Float::from(PI) + &Float::from(-E)).to_string(), "0.423310825130748");
+    /// ```
+    #[inline]
+    fn add(self, other: &'a Float) -> Float {
+        self.add_round_ref_ref(other, RoundingMode::Nearest).0
+    }
+}
+
+impl<'a> Add<Float> for &'a Float {
+    type Output = Float;
+
+    /// Adds two [`Float`]s, taking the first by reference and the second by value.
+    ///
+    /// If the output has a precision, it is the maximum of the precisions of the inputs. If the sum
+    /// is equidistant from two [`Float`]s with that precision, the [`Float`] with fewer 1s in its
+    /// binary expansion is chosen. See
+    /// [`RoundingMode`](malachite_base::rounding_modes::RoundingMode) for a description of the
+    /// `Nearest` rounding mode.
+    ///
+    /// $$
+    /// f(x,y) = x+y+\varepsilon.
+    /// $$
+    /// - If $x+y$ is infinite, zero, or NaN, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $x+y$ is finite and nonzero, then $|\varepsilon| \leq 2^{\lfloor\log_2
+    ///   |x+y|\rfloor-p}$, where $p$ is the maximum precision of the inputs.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},x)=f(x,\text{NaN})=f(\infty,-\infty)=f(-\infty,\infty)=\text{NaN}$
+    /// - $f(\infty,x)=f(x,\infty)=\infty$ if $x$ is not NaN or $-\infty$
+    /// - $f(-\infty,x)=f(x,-\infty)=-\infty$ if $x$ is not NaN or $\infty$
+    /// - $f(0.0,0.0)=0.0$
+    /// - $f(-0.0,-0.0)=-0.0$
+    /// - $f(0.0,-0.0)=f(-0.0,0.0)=0.0$ if `rm` is not `Floor`, and $-0.0$ if it is
+    /// - $f(x,-x)=0.0$ if $x$ is finite and nonzero and `rm` is not `Floor`, and $-0.0$ if it is
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.get_prec(),
+    /// other.get_prec())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::{NegativeOne, One, Zero};
+    /// use malachite_float::Float;
+    /// use std::f64::consts::{E, PI};
+    ///
+    /// assert!((&Float::NAN + Float::ONE).is_nan());
+    /// assert_eq!((&Float::INFINITY + Float::ONE).to_string(), "Infinity");
+    /// assert!((&Float::INFINITY + Float::NEGATIVE_INFINITY).is_nan());
+    /// assert_eq!((&Float::NEGATIVE_ZERO + Float::ZERO).to_string(), "0.0");
+    /// assert_eq!((&Float::ONE + Float::NEGATIVE_ONE).to_string(), "0.0");
+    /// assert_eq!((&Float::from(PI) + Float::from(E)).to_string(), "5.859874482048838");
+    /// assert_eq!((&Float::from(PI) + Float::from(-E)).to_string(), "0.423310825130748");
+    /// ```
+    #[inline]
+    fn add(self, other: Float) -> Float {
+        self.add_round_ref_ref(&other, RoundingMode::Nearest).0
+    }
+}
+
+impl<'a, 'b> Add<&'a Float> for &'b Float {
+    type Output = Float;
+
+    /// Adds two [`Float`]s, taking both by reference.
+    ///
+    /// If the output has a precision, it is the maximum of the precisions of the inputs. If the sum
+    /// is equidistant from two [`Float`]s with that precision, the [`Float`] with fewer 1s in its
+    /// binary expansion is chosen. See
+    /// [`RoundingMode`](malachite_base::rounding_modes::RoundingMode) for a description of the
+    /// `Nearest` rounding mode.
+    ///
+    /// $$
+    /// f(x,y) = x+y+\varepsilon.
+    /// $$
+    /// - If $x+y$ is infinite, zero, or NaN, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $x+y$ is finite and nonzero, then $|\varepsilon| \leq 2^{\lfloor\log_2
+    ///   |x+y|\rfloor-p}$, where $p$ is the maximum precision of the inputs.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},x)=f(x,\text{NaN})=f(\infty,-\infty)=f(-\infty,\infty)=\text{NaN}$
+    /// - $f(\infty,x)=f(x,\infty)=\infty$ if $x$ is not NaN or $-\infty$
+    /// - $f(-\infty,x)=f(x,-\infty)=-\infty$ if $x$ is not NaN or $\infty$
+    /// - $f(0.0,0.0)=0.0$
+    /// - $f(-0.0,-0.0)=-0.0$
+    /// - $f(0.0,-0.0)=f(-0.0,0.0)=0.0$ if `rm` is not `Floor`, and $-0.0$ if it is
+    /// - $f(x,-x)=0.0$ if $x$ is finite and nonzero and `rm` is not `Floor`, and $-0.0$ if it is
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.get_prec(),
+    /// other.get_prec())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::{NegativeOne, One, Zero};
+    /// use malachite_float::Float;
+    /// use std::f64::consts::{E, PI};
+    ///
+    /// assert!((&Float::NAN + &Float::ONE).is_nan());
+    /// assert_eq!((&Float::INFINITY + &Float::ONE).to_string(), "Infinity");
+    /// assert!((&Float::INFINITY + &Float::NEGATIVE_INFINITY).is_nan());
+    /// assert_eq!((&Float::NEGATIVE_ZERO + &Float::ZERO).to_string(), "0.0");
+    /// assert_eq!((&Float::ONE + &Float::NEGATIVE_ONE).to_string(), "0.0");
+    /// assert_eq!((&Float::from(PI) + &Float::from(E)).to_string(), "5.859874482048838");
+    /// assert_eq!((&Float::from(PI) + &Float::from(-E)).to_string(), "0.423310825130748");
+    /// ```
+    #[inline]
+    fn add(self, other: &'a Float) -> Float {
+        self.add_round_ref_ref(other, RoundingMode::Nearest).0
+    }
+}
+
+impl AddAssign<Float> for Float {
+    /// Adds a [`Float`] to a [`Float`] in place, taking the [`Float`] on the right-hand side by
+    /// value.
+    ///
+    /// If the output has a precision, it is the maximum of the precisions of the inputs. If the sum
+    /// is equidistant from two [`Float`]s with that precision, the [`Float`] with fewer 1s in its
+    /// binary expansion is chosen. See
+    /// [`RoundingMode`](malachite_base::rounding_modes::RoundingMode) for a description of the
+    /// `Nearest` rounding mode.
+    ///
+    /// $$
+    /// x \gets x + y+\varepsilon.
+    /// $$
+    /// - If $x+y$ is infinite, zero, or NaN, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $x+y$ is finite and nonzero, then $|\varepsilon| \leq 2^{\lfloor\log_2
+    ///   |x+y|\rfloor-p}$, where $p$ is the maximum precision of the inputs.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},x)=f(x,\text{NaN})=f(\infty,-\infty)=f(-\infty,\infty)=\text{NaN}$
+    /// - $f(\infty,x)=f(x,\infty)=\infty$ if $x$ is not NaN or $-\infty$
+    /// - $f(-\infty,x)=f(x,-\infty)=-\infty$ if $x$ is not NaN or $\infty$
+    /// - $f(0.0,0.0)=0.0$
+    /// - $f(-0.0,-0.0)=-0.0$
+    /// - $f(0.0,-0.0)=f(-0.0,0.0)=0.0$ if `rm` is not `Floor`, and $-0.0$ if it is
+    /// - $f(x,-x)=0.0$ if $x$ is finite and nonzero and `rm` is not `Floor`, and $-0.0$ if it is
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.get_prec(),
+    /// other.get_prec())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::Float;
+    /// use std::f64::consts::{E, PI};
+    ///
+    /// let mut x = Float::from(PI);
+    /// x += Float::from(E);
+    /// assert_eq!(x.to_string(), "5.859874482048838");
+    ///
+    /// let mut x = Float::from(PI);
+    /// x += Float::from(-E);
+    /// assert_eq!(x.to_string(), "0.423310825130748");
+    /// ```
+    #[inline]
+    fn add_assign(&mut self, other: Float) {
+        *self = self.add_round_ref_ref(&other, RoundingMode::Nearest).0;
+    }
+}
+
+impl<'a> AddAssign<&'a Float> for Float {
+    /// Adds a [`Float`] to a [`Float`] in place, taking the [`Float`] on the right-hand side by
+    /// reference.
+    ///
+    /// If the output has a precision, it is the maximum of the precisions of the inputs. If the sum
+    /// is equidistant from two [`Float`]s with that precision, the [`Float`] with fewer 1s in its
+    /// binary expansion is chosen. See
+    /// [`RoundingMode`](malachite_base::rounding_modes::RoundingMode) for a description of the
+    /// `Nearest` rounding mode.
+    ///
+    /// $$
+    /// x \gets x + y+\varepsilon.
+    /// $$
+    /// - If $x+y$ is infinite, zero, or NaN, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $x+y$ is finite and nonzero, then $|\varepsilon| \leq 2^{\lfloor\log_2
+    ///   |x+y|\rfloor-p}$, where $p$ is the maximum precision of the inputs.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},x)=f(x,\text{NaN})=f(\infty,-\infty)=f(-\infty,\infty)=\text{NaN}$
+    /// - $f(\infty,x)=f(x,\infty)=\infty$ if $x$ is not NaN or $-\infty$
+    /// - $f(-\infty,x)=f(x,-\infty)=-\infty$ if $x$ is not NaN or $\infty$
+    /// - $f(0.0,0.0)=0.0$
+    /// - $f(-0.0,-0.0)=-0.0$
+    /// - $f(0.0,-0.0)=f(-0.0,0.0)=0.0$ if `rm` is not `Floor`, and $-0.0$ if it is
+    /// - $f(x,-x)=0.0$ if $x$ is finite and nonzero and `rm` is not `Floor`, and $-0.0$ if it is
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.get_prec(),
+    /// other.get_prec())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::Float;
+    /// use std::f64::consts::{E, PI};
+    ///
+    /// let mut x = Float::from(PI);
+    /// x += &Float::from(E);
+    /// assert_eq!(x.to_string(), "5.859874482048838");
+    ///
+    /// let mut x = Float::from(PI);
+    /// x += &Float::from(-E);
+    /// assert_eq!(x.to_string(), "0.423310825130748");
+    /// ```
+    #[inline]
+    fn add_assign(&mut self, other: &'a Float) {
+        *self = self.add_round_ref_ref(other, RoundingMode::Nearest).0;
+    }
+}
@@ -0,0 +1,774 @@
+use crate::arithmetic::{max_prec, round_quotient};
+use crate::{Float, InnerFloat};
+use malachite_base::rounding_modes::RoundingMode;
+use std::cmp::Ordering;
+use std::ops::{Div, DivAssign};
+
+fn div_helper(x: &Float, y: &Float, prec: u64, rm: RoundingMode) -> (Float, Ordering) {
+    assert_ne!(prec, 0, "Precision must be positive");
+    match (&x.0, &y.0) {
+        (InnerFloat::NaN, _)
+        | (_, InnerFloat::NaN)
+        | (InnerFloat::Infinity { .. }, InnerFloat::Infinity { .. })
+        | (InnerFloat::Zero { .. }, InnerFloat::Zero { .. }) => (Float::NAN, Ordering::Equal),
+        (
+            InnerFloat::Infinity { sign: x_sign },
+            InnerFloat::Zero { sign: y_sign } | InnerFloat::Finite { sign: y_sign, .. },
+        )
+        | (InnerFloat::Finite { sign: x_sign, .. }, InnerFloat::Zero { sign: y_sign }) => (
+            Float(InnerFloat::Infinity {
+                sign: x_sign == y_sign,
+            }),
+            Ordering::Equal,
+        ),
+        (
+            InnerFloat::Zero { sign: x_sign } | InnerFloat::Finite { sign: x_sign, .. },
+            InnerFloat::Infinity { sign: y_sign },
+        )
+        | (InnerFloat::Zero { sign: x_sign }, InnerFloat::Finite { sign: y_sign, .. }) => (
+            Float(InnerFloat::Zero {
+                sign: x_sign == y_sign,
+            }),
+            Ordering::Equal,
+        ),
+        (
+            InnerFloat::Finite {
+                sign: x_sign,
+                exponent: x_exponent,
+                precision: x_precision,
+                significand: x_significand,
+            },
+            InnerFloat::Finite {
+                sign: y_sign,
+                exponent: y_exponent,
+                precision: y_precision,
+                significand: y_significand,
+            },
+        ) => round_quotient(
+            x_sign == y_sign,
+            x_significand,
+            y_significand,
+            i128::from(*x_exponent) - i128::from(*x_precision) - i128::from(*y_exponent)
+                + i128::from(*y_precision),
+            prec,
+            rm,
+        ),
+    }
+}
+
+impl Float {
+    /// Divides a [`Float`] by another [`Float`], rounding the result to the specified precision and
+    /// with the specified rounding mode. Both [`Float`]s are taken by value. An [`Ordering`] is
+    /// also returned, indicating whether the rounded quotient is less than, equal to, or greater
+    /// than the exact quotient. Although `NaN`s are not comparable to any [`Float`], whenever this
+    /// function returns a `NaN` it also returns `Equal`.
+    ///
+    /// See [`RoundingMode`](malachite_base::rounding_modes::RoundingMode) for a description of the
+    /// possible rounding modes.
+    ///
+    /// $$
+    /// f(x,y,p,m) = x/y+\varepsilon.
+    /// $$
+    /// - If $x/y$ is infinite, zero, or NaN, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $x/y$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |x/y|\rfloor-p+1}$.
+    /// - If $x/y$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| \leq
+    ///   2^{\lfloor\log_2 |x/y|\rfloor-p}$.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},x)=f(x,\text{NaN})=f(\pm\infty,\pm\infty)=f(\pm0.0,\pm0.0)=\text{NaN}$
+    /// - $f(\pm\infty,x)=\pm\infty$ if $x$ is finite, and $f(x,\pm0.0)=\pm\infty$ if $x$ is nonzero
+    ///   and not NaN
+    /// - $f(x,\pm\infty)=\pm0.0$ if $x$ is finite, and $f(\pm0.0,x)=\pm0.0$ if $x$ is nonzero and
+    ///   not NaN
+    ///
+    /// In each case, the sign of the result is the product of the signs of the inputs.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.get_prec(),
+    /// other.get_prec(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero, or if `rm` is `Exact` but the quotient cannot be represented
+    /// exactly with the given precision.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    /// use std::f64::consts::{E, PI};
+    ///
+    /// let (quotient, o) = Float::from(PI).div_prec_round(Float::from(E), 5, RoundingMode::Floor);
+    /// assert_eq!(quotient.to_string(), "1.1");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (quotient, o) =
+    ///     Float::from(PI).div_prec_round(Float::from(E), 5, RoundingMode::Ceiling);
+    /// assert_eq!(quotient.to_string(), "1.2");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (quotient, o) =
+    ///     Float::from(PI).div_prec_round(Float::from(E), 5, RoundingMode::Nearest);
+    /// assert_eq!(quotient.to_string(), "1.1");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (quotient, o) =
+    ///     Float::from(PI).div_prec_round(Float::from(E), 20, RoundingMode::Nearest);
+    /// assert_eq!(quotient.to_string(), "1.155727");
+    /// assert_eq!(o, Ordering::Greater);
+    /// ```
+    #[inline]
+    #[allow(clippy::needless_pass_by_value)]
+    pub fn div_prec_round(self, other: Float, prec: u64, rm: RoundingMode) -> (Float, Ordering) {
+        self.div_prec_round_ref_ref(&other, prec, rm)
+    }
+
+    /// Divides a [`Float`] by another [`Float`], rounding the result to the specified precision and
+    /// with the specified rounding mode. Both [`Float`]s are taken by reference. An [`Ordering`] is
+    /// also returned, indicating whether the rounded quotient is less than, equal to, or greater
+    /// than the exact quotient. Although `NaN`s are not comparable to any [`Float`], whenever this
+    /// function returns a `NaN` it also returns `Equal`.
+    ///
+    /// See [`RoundingMode`](malachite_base::rounding_modes::RoundingMode) for a description of the
+    /// possible rounding modes.
+    ///
+    /// $$
+    /// f(x,y,p,m) = x/y+\varepsilon.
+    /// $$
+    /// - If $x/y$ is infinite, zero, or NaN, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $x/y$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |x/y|\rfloor-p+1}$.
+    /// - If $x/y$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| \leq
+    ///   2^{\lfloor\log_2 |x/y|\rfloor-p}$.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},x)=f(x,\text{NaN})=f(\pm\infty,\pm\infty)=f(\pm0.0,\pm0.0)=\text{NaN}$
+    /// - $f(\pm\infty,x)=\pm\infty$ if $x$ is finite, and $f(x,\pm0.0)=\pm\infty$ if $x$ is nonzero
+    ///   and not NaN
+    /// - $f(x,\pm\infty)=\pm0.0$ if $x$ is finite, and $f(\pm0.0,x)=\pm0.0$ if $x$ is nonzero and
+    ///   not NaN
+    ///
+    /// In each case, the sign of the result is the product of the signs of the inputs.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.get_prec(),
+    /// other.get_prec(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero, or if `rm` is `Exact` but the quotient cannot be represented
+    /// exactly with the given precision.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    /// use std::f64::consts::{E, PI};
+    ///
+    /// let (quotient, o) =
+    ///     Float::from(PI).div_prec_round_ref_ref(&Float::from(E), 5, RoundingMode::Floor);
+    /// assert_eq!(quotient.to_string(), "1.1");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (quotient, o) =
+    ///     Float::from(PI).div_prec_round_ref_ref(&Float::from(E), 5, RoundingMode::Ceiling);
+    /// assert_eq!(quotient.to_string(), "1.2");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (quotient, o) =
+    ///     Float::from(PI).div_prec_round_ref_ref(&Float::from(E), 5, RoundingMode::Nearest);
+    /// assert_eq!(quotient.to_string(), "1.1");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (quotient, o) =
+    ///     Float::from(PI).div_prec_round_ref_ref(&Float::from(E), 20, RoundingMode::Nearest);
+    /// assert_eq!(quotient.to_string(), "1.155727");
+    /// assert_eq!(o, Ordering::Greater);
+    /// ```
+    pub fn div_prec_round_ref_ref(
+        &self,
+        other: &Float,
+        prec: u64,
+        rm: RoundingMode,
+    ) -> (Float, Ordering) {
+        div_helper(self, other, prec, rm)
+    }
+
+    /// Divides a [`Float`] by another [`Float`], rounding the result to the nearest value of the
+    /// specified precision. Both [`Float`]s are taken by value. An [`Ordering`] is also returned,
+    /// indicating whether the rounded quotient is less than, equal to, or greater than the exact
+    /// quotient. Although `NaN`s are not comparable to any [`Float`], whenever this function
+    /// returns a `NaN` it also returns `Equal`.
+    ///
+    /// If the quotient is equidistant from two [`Float`]s with the specified precision, the
+    /// [`Float`] with fewer 1s in its binary expansion is chosen. See
+    /// [`RoundingMode`](malachite_base::rounding_modes::RoundingMode) for a description of the
+    /// `Nearest` rounding mode.
+    ///
+    /// $$
+    /// f(x,y,p) = x/y+\varepsilon.
+    /// $$
+    /// - If $x/y$ is infinite, zero, or NaN, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $x/y$ is finite and nonzero, then $|\varepsilon| \leq 2^{\lfloor\log_2
+    ///   |x/y|\rfloor-p}$.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},x)=f(x,\text{NaN})=f(\pm\infty,\pm\infty)=f(\pm0.0,\pm0.0)=\text{NaN}$
+    /// - $f(\pm\infty,x)=\pm\infty$ if $x$ is finite, and $f(x,\pm0.0)=\pm\infty$ if $x$ is nonzero
+    ///   and not NaN
+    /// - $f(x,\pm\infty)=\pm0.0$ if $x$ is finite, and $f(\pm0.0,x)=\pm0.0$ if $x$ is nonzero and
+    ///   not NaN
+    ///
+    /// In each case, the sign of the result is the product of the signs of the inputs.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.get_prec(),
+    /// other.get_prec(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    /// use std::f64::consts::{E, PI};
+    ///
+    /// let (quotient, o) = Float::from(PI).div_prec(Float::from(E), 5);
+    /// assert_eq!(quotient.to_string(), "1.1");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (quotient, o) = Float::from(PI).div_prec(Float::from(E), 20);
+    /// assert_eq!(quotient.to_string(), "1.155727");
+    /// assert_eq!(o, Ordering::Greater);
+    /// ```
+    #[inline]
+    pub fn div_prec(self, other: Float, prec: u64) -> (Float, Ordering) {
+        self.div_prec_round(other, prec, RoundingMode::Nearest)
+    }
+
+    /// Divides a [`Float`] by another [`Float`], rounding the result to the nearest value of the
+    /// specified precision. Both [`Float`]s are taken by reference. An [`Ordering`] is also
+    /// returned, indicating whether the rounded quotient is less than, equal to, or greater than
+    /// the exact quotient. Although `NaN`s are not comparable to any [`Float`], whenever this
+    /// function returns a `NaN` it also returns `Equal`.
+    ///
+    /// If the quotient is equidistant from two [`Float`]s with the specified precision, the
+    /// [`Float`] with fewer 1s in its binary expansion is chosen. See
+    /// [`RoundingMode`](malachite_base::rounding_modes::RoundingMode) for a description of the
+    /// `Nearest` rounding mode.
+    ///
+    /// $$
+    /// f(x,y,p) = x/y+\varepsilon.
+    /// $$
+    /// - If $x/y$ is infinite, zero, or NaN, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $x/y$ is finite and nonzero, then $|\varepsilon| \leq 2^{\lfloor\log_2
+    ///   |x/y|\rfloor-p}$.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},x)=f(x,\text{NaN})=f(\pm\infty,\pm\infty)=f(\pm0.0,\pm0.0)=\text{NaN}$
+    /// - $f(\pm\infty,x)=\pm\infty$ if $x$ is finite, and $f(x,\pm0.0)=\pm\infty$ if $x$ is nonzero
+    ///   and not NaN
+    /// - $f(x,\pm\infty)=\pm0.0$ if $x$ is finite, and $f(\pm0.0,x)=\pm0.0$ if $x$ is nonzero and
+    ///   not NaN
+    ///
+    /// In each case, the sign of the result is the product of the signs of the inputs.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.get_prec(),
+    /// other.get_prec(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    /// use std::f64::consts::{E, PI};
+    ///
+    /// let (quotient, o) = Float::from(PI).div_prec_ref_ref(&Float::from(E), 5);
+    /// assert_eq!(quotient.to_string(), "1.1");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (quotient, o) = Float::from(PI).div_prec_ref_ref(&Float::from(E), 20);
+    /// assert_eq!(quotient.to_string(), "1.155727");
+    /// assert_eq!(o, Ordering::Greater);
+    /// ```
+    #[inline]
+    pub fn div_prec_ref_ref(&self, other: &Float, prec: u64) -> (Float, Ordering) {
+        self.div_prec_round_ref_ref(other, prec, RoundingMode::Nearest)
+    }
+
+    /// Divides a [`Float`] by another [`Float`], rounding the result with the specified rounding
+    /// mode. Both [`Float`]s are taken by value. An [`Ordering`] is also returned, indicating
+    /// whether the rounded quotient is less than, equal to, or greater than the exact quotient.
+    /// Although `NaN`s are not comparable to any [`Float`], whenever this function returns a `NaN`
+    /// it also returns `Equal`.
+    ///
+    /// The precision of the output is the maximum of the precision of the inputs. See
+    /// [`RoundingMode`](malachite_base::rounding_modes::RoundingMode) for a description of the
+    /// possible rounding modes.
+    ///
+    /// $$
+    /// f(x,y,m) = x/y+\varepsilon.
+    /// $$
+    /// - If $x/y$ is infinite, zero, or NaN, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $x/y$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |x/y|\rfloor-p+1}$, where $p$ is the maximum precision of the inputs.
+    /// - If $x/y$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| \leq
+    ///   2^{\lfloor\log_2 |x/y|\rfloor-p}$, where $p$ is the maximum precision of the inputs.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},x)=f(x,\text{NaN})=f(\pm\infty,\pm\infty)=f(\pm0.0,\pm0.0)=\text{NaN}$
+    /// - $f(\pm\infty,x)=\pm\infty$ if $x$ is finite, and $f(x,\pm0.0)=\pm\infty$ if $x$ is nonzero
+    ///   and not NaN
+    /// - $f(x,\pm\infty)=\pm0.0$ if $x$ is finite, and $f(\pm0.0,x)=\pm0.0$ if $x$ is nonzero and
+    ///   not NaN
+    ///
+    /// In each case, the sign of the result is the product of the signs of the inputs.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.get_prec(),
+    /// other.get_prec())`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the quotient cannot be represented exactly with the maximum
+    /// precision of the inputs.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    /// use std::f64::consts::{E, PI};
+    ///
+    /// let (quotient, o) = Float::from(PI).div_round(Float::from(E), RoundingMode::Floor);
+    /// assert_eq!(quotient.to_string(), "1.1557273497909217");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (quotient, o) = Float::from(PI).div_round(Float::from(E), RoundingMode::Ceiling);
+    /// assert_eq!(quotient.to_string(), "1.155727349790922");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (quotient, o) = Float::from(PI).div_round(Float::from(E), RoundingMode::Nearest);
+    /// assert_eq!(quotient.to_string(), "1.1557273497909217");
+    /// assert_eq!(o, Ordering::Less);
+    /// ```
+    #[inline]
+    #[allow(clippy::needless_pass_by_value)]
+    pub fn div_round(self, other: Float, rm: RoundingMode) -> (Float, Ordering) {
+        self.div_round_ref_ref(&other, rm)
+    }
+
+    /// Divides a [`Float`] by another [`Float`], rounding the result with the specified rounding
+    /// mode. Both [`Float`]s are taken by reference. An [`Ordering`] is also returned, indicating
+    /// whether the rounded quotient is less than, equal to, or greater than the exact quotient.
+    /// Although `NaN`s are not comparable to any [`Float`], whenever this function returns a `NaN`
+    /// it also returns `Equal`.
+    ///
+    /// The precision of the output is the maximum of the precision of the inputs. See
+    /// [`RoundingMode`](malachite_base::rounding_modes::RoundingMode) for a description of the
+    /// possible rounding modes.
+    ///
+    /// $$
+    /// f(x,y,m) = x/y+\varepsilon.
+    /// $$
+    /// - If $x/y$ is infinite, zero, or NaN, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $x/y$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |x/y|\rfloor-p+1}$, where $p$ is the maximum precision of the inputs.
+    /// - If $x/y$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| \leq
+    ///   2^{\lfloor\log_2 |x/y|\rfloor-p}$, where $p$ is the maximum precision of the inputs.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},x)=f(x,\text{NaN})=f(\pm\infty,\pm\infty)=f(\pm0.0,\pm0.0)=\text{NaN}$
+    /// - $f(\pm\infty,x)=\pm\infty$ if $x$ is finite, and $f(x,\pm0.0)=\pm\infty$ if $x$ is nonzero
+    ///   and not NaN
+    /// - $f(x,\pm\infty)=\pm0.0$ if $x$ is finite, and $f(\pm0.0,x)=\pm0.0$ if $x$ is nonzero and
+    ///   not NaN
+    ///
+    /// In each case, the sign of the result is the product of the signs of the inputs.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.get_prec(),
+    /// other.get_prec())`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the quotient cannot be represented exactly with the maximum
+    /// precision of the inputs.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    /// use std::f64::consts::{E, PI};
+    ///
+    /// let (quotient, o) = Float::from(PI).div_round_ref_ref(&Float::from(E), RoundingMode::Floor);
+    /// assert_eq!(quotient.to_string(), "1.1557273497909217");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (quotient, o) =
+    ///     Float::from(PI).div_round_ref_ref(&Float::from(E), RoundingMode::Ceiling);
+    /// assert_eq!(quotient.to_string(), "1.155727349790922");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (quotient, o) =
+    ///     Float::from(PI).div_round_ref_ref(&Float::from(E), RoundingMode::Nearest);
+    /// assert_eq!(quotient.to_string(), "1.1557273497909217");
+    /// assert_eq!(o, Ordering::Less);
+    /// ```
+    pub fn div_round_ref_ref(&self, other: &Float, rm: RoundingMode) -> (Float, Ordering) {
+        let prec = max_prec(self, other);
+        self.div_prec_round_ref_ref(other, prec, rm)
+    }
+}
+
+impl Div<Float> for Float {
+    type Output = Float;
+
+    /// Divides a [`Float`] by another [`Float`], taking both by value.
+    ///
+    /// If the output has a precision, it is the maximum of the precisions of the inputs. If the
+    /// quotient is equidistant from two [`Float`]s with that precision, the [`Float`] with fewer 1s
+    /// in its binary expansion is chosen. See
+    /// [`RoundingMode`](malachite_base::rounding_modes::RoundingMode) for a description of the
+    /// `Nearest` rounding mode.
+    ///
+    /// $$
+    /// f(x,y) = x/y+\varepsilon.
+    /// $$
+    /// - If $x/y$ is infinite, zero, or NaN, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $x/y$ is finite and nonzero, then $|\varepsilon| \leq 2^{\lfloor\log_2
+    ///   |x/y|\rfloor-p}$, where $p$ is the maximum precision of the inputs.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},x)=f(x,\text{NaN})=f(\pm\infty,\pm\infty)=f(\pm0.0,\pm0.0)=\text{NaN}$
+    /// - $f(\pm\infty,x)=\pm\infty$ if $x$ is finite, and $f(x,\pm0.0)=\pm\infty$ if $x$ is nonzero
+    ///   and not NaN
+    /// - $f(x,\pm\infty)=\pm0.0$ if $x$ is finite, and $f(\pm0.0,x)=\pm0.0$ if $x$ is nonzero and
+    ///   not NaN
+    ///
+    /// In each case, the sign of the result is the product of the signs of the inputs.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.get_prec(),
+    /// other.get_prec())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::{NegativeOne, One, Zero};
+    /// use malachite_float::Float;
+    /// use std::f64::consts::{E, PI};
+    ///
+    /// assert!((Float::NAN / Float::ONE).is_nan());
+    /// assert_eq!((Float::ONE / Float::NEGATIVE_ZERO).to_string(), "-Infinity");
+    /// assert!((Float::ZERO / Float::ZERO).is_nan());
+    /// assert_eq!((Float::NEGATIVE_ONE / Float::INFINITY).to_string(), "-0.0");
+    /// assert_eq!((Float::from(PI) / Float::from(E)).to_string(), "1.1557273497909217");
+    /// assert_eq!((Float::from(PI) / Float::from(-E)).to_string(), "-1.1557273497909217");
+    /// ```
+    #[inline]
+    fn div(self, other: Float) -> Float {
+        self.div_round_ref_ref(&other, RoundingMode::Nearest).0
+    }
+}
+
+impl<'a> Div<&'a Float> for Float {
+    type Output = Float;
+
+    /// Divides a [`Float`] by another [`Float`], taking the first by value and the second by
+    /// reference.
+    ///
+    /// If the output has a precision, it is the maximum of the precisions of the inputs. If the
+    /// quotient is equidistant from two [`Float`]s with that precision, the [`Float`] with fewer 1s
+    /// in its binary expansion is chosen. See
+    /// [`RoundingMode`](malachite_base::rounding_modes::RoundingMode) for a description of the
+    /// `Nearest` rounding mode.
+    ///
+    /// $$
+    /// f(x,y) = x/y+\varepsilon.
+    /// $$
+    /// - If $x/y$ is infinite, zero, or NaN, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $x/y$ is finite and nonzero, then $|\varepsilon| \leq 2^{\lfloor\log_2
+    ///   |x/y|\rfloor-p}$, where $p$ is the maximum precision of the inputs.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},x)=f(x,\text{NaN})=f(\pm\infty,\pm\infty)=f(\pm0.0,\pm0.0)=\text{NaN}$
+    /// - $f(\pm\infty,x)=\pm\infty$ if $x$ is finite, and $f(x,\pm0.0)=\pm\infty$ if $x$ is nonzero
+    ///   and not NaN
+    /// - $f(x,\pm\infty)=\pm0.0$ if $x$ is finite, and $f(\pm0.0,x)=\pm0.0$ if $x$ is nonzero and
+    ///   not NaN
+    ///
+    /// In each case, the sign of the result is the product of the signs of the inputs.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.get_prec(),
+    /// other.get_prec())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::{NegativeOne, One, Zero};
+    /// use malachite_float::Float;
+    /// use std::f64::consts::{E, PI};
+    ///
+    /// assert!((Float::NAN / &Float::ONE).is_nan());
+    /// assert_eq!((Float::ONE / &Float::NEGATIVE_ZERO).to_string(), "-Infinity");
+    /// assert!((Float::ZERO / &Float::ZERO).is_nan());
+    /// assert_eq!((Float::NEGATIVE_ONE / &Float::INFINITY).to_string(), "-0.0");
+    /// assert_eq!((Float::from(PI) / &Float::from(E)).to_string(), "1.1557273497909217");
+    /// assert_eq!((Float::from(PI) / &Float::from(-E)).to_string(), "-1.1557273497909217");
+    /// ```
+    #[inline]
+    fn div(self, other: &'a Float) -> Float {
+        self.div_round_ref_ref(other, RoundingMode::Nearest).0
+    }
+}
+
+impl<'a> Div<Float> for &'a Float {
+    type Output = Float;
+
+    /// Divides a [`Float`] by another [`Float`], taking the first by reference and the second by
+    /// value.
+    ///
+    /// If the output has a precision, it is the maximum of the precisions of the inputs. If the
+    /// quotient is equidistant from two [`Float`]s with that precision, the [`Float`] with fewer 1s
+    /// in its binary expansion is chosen. See
+    /// [`RoundingMode`](malachite_base::rounding_modes::RoundingMode) for a description of the
+    /// `Nearest` rounding mode.
+    ///
+    /// $$
+    /// f(x,y) = x/y+\varepsilon.
+    /// $$
+    /// - If $x/y$ is infinite, zero, or NaN, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $x/y$ is finite and nonzero, then $|\varepsilon| \leq 2^{\lfloor\log_2
+    ///   |x/y|\rfloor-p}$, where $p$ is the maximum precision of the inputs.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},x)=f(x,\text{NaN})=f(\pm\infty,\pm\infty)=f(\pm0.0,\pm0.0)=\text{NaN}$
+    /// - $f(\pm\infty,x)=\pm\infty$ if $x$ is finite, and $f(x,\pm0.0)=\pm\infty$ if $x$ is nonzero
+    ///   and not NaN
+    /// - $f(x,\pm\infty)=\pm0.0$ if $x$ is finite, and $f(\pm0.0,x)=\pm0.0$ if $x$ is nonzero and
+    ///   not NaN
+    ///
+    /// In each case, the sign of the result is the product of the signs of the inputs.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.get_prec(),
+    /// other.get_prec())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::{NegativeOne, One, Zero};
+    /// use malachite_float::Float;
+    /// use std::f64::consts::{E, PI};
+    ///
+    /// assert!((&Float::NAN / Float::ONE).is_nan());
+    /// assert_eq!((&Float::ONE / Float::NEGATIVE_ZERO).to_string(), "-Infinity");
+    /// assert!((&Float::ZERO / Float::ZERO).is_nan());
+    /// assert_eq!((&Float::NEGATIVE_ONE / Float::INFINITY).to_string(), "-0.0");
+    /// assert_eq!((&Float::from(PI) / Float::from(E)).to_string(), "1.1557273497909217");
+    /// assert_eq!((&Float::from(PI) / Float::from(-E)).to_string(), "-1.1557273497909217");
+    /// ```
+    #[inline]
+    fn div(self, other: Float) -> Float {
+        self.div_round_ref_ref(&other, RoundingMode::Nearest).0
+    }
+}
+
+impl<'a, 'b> Div<&'a Float> for &'b Float {
+    type Output = Float;
+
+    /// Divides a [`Float`] by another [`Float`], taking both by reference.
+    ///
+    /// If the output has a precision, it is the maximum of the precisions of the inputs. If the
+    /// quotient is equidistant from two [`Float`]s with that precision, the [`Float`] with fewer 1s
+    /// in its binary expansion is chosen. See
+    /// [`RoundingMode`](malachite_base::rounding_modes::RoundingMode) for a description of the
+    /// `Nearest` rounding mode.
+    ///
+    /// $$
+    /// f(x,y) = x/y+\varepsilon.
+    /// $$
+    /// - If $x/y$ is infinite, zero, or NaN, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $x/y$ is finite and nonzero, then $|\varepsilon| \leq 2^{\lfloor\log_2
+    ///   |x/y|\rfloor-p}$, where $p$ is the maximum precision of the inputs.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},x)=f(x,\text{NaN})=f(\pm\infty,\pm\infty)=f(\pm0.0,\pm0.0)=\text{NaN}$
+    /// - $f(\pm\infty,x)=\pm\infty$ if $x$ is finite, and $f(x,\pm0.0)=\pm\infty$ if $x$ is nonzero
+    ///   and not NaN
+    /// - $f(x,\pm\infty)=\pm0.0$ if $x$ is finite, and $f(\pm0.0,x)=\pm0.0$ if $x$ is nonzero and
+    ///   not NaN
+    ///
+    /// In each case, the sign of the result is the product of the signs of the inputs.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.get_prec(),
+    /// other.get_prec())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::{NegativeOne, One, Zero};
+    /// use malachite_float::Float;
+    /// use std::f64::consts::{E, PI};
+    ///
+    /// assert!((&Float::NAN / &Float::ONE).is_nan());
+    /// assert_eq!((&Float::ONE / &Float::NEGATIVE_ZERO).to_string(), "-Infinity");
+    /// assert!((&Float::ZERO / &Float::ZERO).is_nan());
+    /// assert_eq!((&Float::NEGATIVE_ONE / &Float::INFINITY).to_string(), "-0.0");
+    /// assert_eq!((&Float::from(PI) / &Float::from(E)).to_string(), "1.1557273497909217");
+    /// assert_eq!((&Float::from(PI) / &Float::from(-E)).to_string(), "-1.1557273497909217");
+    /// ```
+    #[inline]
+    fn div(self, other: &'a Float) -> Float {
+        self.div_round_ref_ref(other, RoundingMode::Nearest).0
+    }
+}
+
+impl DivAssign<Float> for Float {
+    /// Divides a [`Float`] by a [`Float`] in place, taking the [`Float`] on the right-hand side by
+    /// value.
+    ///
+    /// If the output has a precision, it is the maximum of the precisions of the inputs. If the
+    /// quotient is equidistant from two [`Float`]s with that precision, the [`Float`] with fewer 1s
+    /// in its binary expansion is chosen. See
+    /// [`RoundingMode`](malachite_base::rounding_modes::RoundingMode) for a description of the
+    /// `Nearest` rounding mode.
+    ///
+    /// $$
+    /// x \gets x / y+\varepsilon.
+    /// $$
+    /// - If $x/y$ is infinite, zero, or NaN, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $x/y$ is finite and nonzero, then $|\varepsilon| \leq 2^{\lfloor\log_2
+    ///   |x/y|\rfloor-p}$, where $p$ is the maximum precision of the inputs.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},x)=f(x,\text{NaN})=f(\pm\infty,\pm\infty)=f(\pm0.0,\pm0.0)=\text{NaN}$
+    /// - $f(\pm\infty,x)=\pm\infty$ if $x$ is finite, and $f(x,\pm0.0)=\pm\infty$ if $x$ is nonzero
+    ///   and not NaN
+    /// - $f(x,\pm\infty)=\pm0.0$ if $x$ is finite, and $f(\pm0.0,x)=\pm0.0$ if $x$ is nonzero and
+    ///   not NaN
+    ///
+    /// In each case, the sign of the result is the product of the signs of the inputs.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.get_prec(),
+    /// other.get_prec())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::Float;
+    /// use std::f64::consts::{E, PI};
+    ///
+    /// let mut x = Float::from(PI);
+    /// x /= Float::from(E);
+    /// assert_eq!(x.to_string(), "1.1557273497909217");
+    ///
+    /// let mut x = Float::from(PI);
+    /// x /= Float::from(-E);
+    /// assert_eq!(x.to_string(), "-1.1557273497909217");
+    /// ```
+    #[inline]
+    fn div_assign(&mut self, other: Float) {
+        *self = self.div_round_ref_ref(&other, RoundingMode::Nearest).0;
+    }
+}
+
+impl<'a> DivAssign<&'a Float> for Float {
+    /// Divides a [`Float`] by a [`Float`] in place, taking the [`Float`] on the right-hand side by
+    /// reference.
+    ///
+    /// If the output has a precision, it is the maximum of the precisions of the inputs. If the
+    /// quotient is equidistant from two [`Float`]s with that precision, the [`Float`] with fewer 1s
+    /// in its binary expansion is chosen. See
+    /// [`RoundingMode`](malachite_base::rounding_modes::RoundingMode) for a description of the
+    /// `Nearest` rounding mode.
+    ///
+    /// $$
+    /// x \gets x / y+\varepsilon.
+    /// $$
+    /// - If $x/y$ is infinite, zero, or NaN, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $x/y$ is finite and nonzero, then $|\varepsilon| \leq 2^{\lfloor\log_2
+    ///   |x/y|\rfloor-p}$, where $p$ is the maximum precision of the inputs.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},x)=f(x,\text{NaN})=f(\pm\infty,\pm\infty)=f(\pm0.0,\pm0.0)=\text{NaN}$
+    /// - $f(\pm\infty,x)=\pm\infty$ if $x$ is finite, and $f(x,\pm0.0)=\pm\infty$ if $x$ is nonzero
+    ///   and not NaN
+    /// - $f(x,\pm\infty)=\pm0.0$ if $x$ is finite, and $f(\pm0.0,x)=\pm0.0$ if $x$ is nonzero and
+    ///   not NaN
+    ///
+    /// In each case, the sign of the result is the product of the signs of the inputs.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.get_prec(),
+    /// other.get_prec())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::Float;
+    /// use std::f64::consts::{E, PI};
+    ///
+    /// let mut x = Float::from(PI);
+    /// x /= &Float::from(E);
+    /// assert_eq!(x.to_string(), "1.1557273497909217");
+    ///
+    /// let mut x = Float::from(PI);
+    /// x /= &Float::from(-E);
+    /// assert_eq!(x.to_string(), "-1.1557273497909217");
+    /// ```
+    #[inline]
+    fn div_assign(&mut self, other: &'a Float) {
+        *self = self.div_round_ref_ref(other, RoundingMode::Nearest).0;
+    }
+}
@@ -0,0 +1,219 @@
+use crate::{Float, InnerFloat};
+use malachite_base::num::arithmetic::traits::{DivMod, DivisibleByPowerOf2, IsPowerOf2, PowerOf2};
+use malachite_base::num::basic::traits::One;
+use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_base::num::logic::traits::{BitAccess, LowMask, SignificantBits};
+use malachite_base::rounding_modes::RoundingMode;
+use malachite_nz::natural::Natural;
+use std::cmp::{max, Ordering};
+
+// Returns the result of rounding a nonzero value with sign `sign` and an exponent greater than
+// `Float::MAX_EXPONENT`, using the rounding mode `rm`: either the infinity with that sign or the
+// finite value of precision `prec` with the largest absolute value. Also returns whether the result
+// is less than or greater than the exact value.
+fn overflow(sign: bool, prec: u64, rm: RoundingMode) -> (Float, Ordering) {
+    let to_infinity = match rm {
+        RoundingMode::Down => false,
+        RoundingMode::Up | RoundingMode::Nearest => true,
+        RoundingMode::Floor => !sign,
+        RoundingMode::Ceiling => sign,
+        RoundingMode::Exact => panic!("Inexact Float rounding: overflow"),
+    };
+    if to_infinity {
+        (
+            Float(InnerFloat::Infinity { sign }),
+            if sign {
+                Ordering::Greater
+            } else {
+                Ordering::Less
+            },
+        )
+    } else {
+        (
+            Float(InnerFloat::Finite {
+                sign,
+                exponent: Float::MAX_EXPONENT,
+                precision: prec,
+                significand: Natural::low_mask(prec),
+            }),
+            if sign {
+                Ordering::Less
+            } else {
+                Ordering::Greater
+            },
+        )
+    }
+}
+
+// Returns the result of rounding a nonzero value with sign `sign` and an exponent less than
+// `Float::MIN_EXPONENT`, using the rounding mode `rm`: either the zero with that sign or the value
+// of precision `prec` with the smallest positive absolute value, $2^{E-1}$, where $E$ is
+// `Float::MIN_EXPONENT`. `above_half` indicates whether the absolute value is greater than
+// $2^{E-2}$, which is only relevant when `rm` is `Nearest`; a value equal to $2^{E-2}$ is
+// rounded to zero, as in the round-half-to-even rule. Also returns whether the result is less
+// than or greater than the exact value.
+fn underflow(sign: bool, above_half: bool, prec: u64, rm: RoundingMode) -> (Float, Ordering) {
+    let to_zero = match rm {
+        RoundingMode::Down => true,
+        RoundingMode::Up => false,
+        RoundingMode::Nearest => !above_half,
+        RoundingMode::Floor => sign,
+        RoundingMode::Ceiling => !sign,
+        RoundingMode::Exact => panic!("Inexact Float rounding: underflow"),
+    };
+    if to_zero {
+        (
+            Float(InnerFloat::Zero { sign }),
+            if sign {
+                Ordering::Less
+            } else {
+                Ordering::Greater
+            },
+        )
+    } else {
+        (
+            Float(InnerFloat::Finite {
+                sign,
+                exponent: Float::MIN_EXPONENT,
+                precision: prec,
+                significand: Natural::power_of_2(prec - 1),
+            }),
+            if sign {
+                Ordering::Greater
+            } else {
+                Ordering::Less
+            },
+        )
+    }
+}
+
+// Rounds $n2^e$, where $n$ is nonzero, to a `Float` with precision `prec` and sign `sign`, using
+// the rounding mode `rm`. The rounding mode applies to the signed value. Also returns whether the
+// result is less than, equal to, or greater than the exact signed value.
+//
+// The exponent $e$ is an `i128` so that callers can combine the exponents and precisions of
+// `Float`s without overflow. If the exponent of the result would be greater than
+// `Float::MAX_EXPONENT` or less than `Float::MIN_EXPONENT`, the result overflows or underflows.
+pub(crate) fn round_natural(
+    sign: bool,
+    n: Natural,
+    e: i128,
+    prec: u64,
+    rm: RoundingMode,
+) -> (Float, Ordering) {
+    let bits = n.significant_bits();
+    let mut exponent = e + i128::from(bits);
+    if exponent < i128::from(Float::MIN_EXPONENT) {
+        let above_half = exponent == i128::from(Float::MIN_EXPONENT) - 1 && !n.is_power_of_2();
+        return underflow(sign, above_half, prec, rm);
+    }
+    if bits <= prec {
+        if exponent > i128::from(Float::MAX_EXPONENT) {
+            return overflow(sign, prec, rm);
+        }
+        return (
+            Float(InnerFloat::Finite {
+                sign,
+                exponent: i64::exact_from(exponent),
+                precision: prec,
+                significand: n << (prec - bits),
+            }),
+            Ordering::Equal,
+        );
+    }
+    let shift = bits - prec;
+    let mut significand = &n >> shift;
+    let up = if (&n).divisible_by_power_of_2(shift) {
+        None
+    } else {
+        Some(match rm {
+            RoundingMode::Down => false,
+            RoundingMode::Up => true,
+            RoundingMode::Floor => !sign,
+            RoundingMode::Ceiling => sign,
+            RoundingMode::Nearest => {
+                n.get_bit(shift - 1)
+                    && (significand.get_bit(0) || !(&n).divisible_by_power_of_2(shift - 1))
+            }
+            RoundingMode::Exact => panic!("Inexact Float rounding: {} bits", bits),
+        })
+    };
+    let o = match up {
+        None => Ordering::Equal,
+        Some(up) => {
+            if up {
+                significand += Natural::ONE;
+                if significand.significant_bits() > prec {
+                    significand >>= 1;
+                    exponent += 1;
+                }
+            }
+            if up == sign {
+                Ordering::Greater
+            } else {
+                Ordering::Less
+            }
+        }
+    };
+    if exponent > i128::from(Float::MAX_EXPONENT) {
+        return overflow(sign, prec, rm);
+    }
+    (
+        Float(InnerFloat::Finite {
+            sign,
+            exponent: i64::exact_from(exponent),
+            precision: prec,
+            significand,
+        }),
+        o,
+    )
+}
+
+// Rounds $(n/d)2^e$, where $n$ and $d$ are nonzero, to a `Float` with precision `prec` and sign
+// `sign`, using the rounding mode `rm`.
+//
+// The quotient is computed with at least `prec + 2` bits. If it is inexact, a 1 bit is appended to
+// it; since that bit lies strictly below the rounding position, the rounded result is the same as
+// if the exact quotient had been rounded.
+pub(crate) fn round_quotient(
+    sign: bool,
+    n: &Natural,
+    d: &Natural,
+    e: i128,
+    prec: u64,
+    rm: RoundingMode,
+) -> (Float, Ordering) {
+    let k = u64::exact_from(max(
+        i64::exact_from(prec + 2 + d.significant_bits()) - i64::exact_from(n.significant_bits()),
+        0,
+    ));
+    let (q, r) = (n << k).div_mod(d);
+    let e = e - i128::from(k);
+    if r == 0u32 {
+        round_natural(sign, q, e, prec, rm)
+    } else {
+        round_natural(sign, (q << 1) | Natural::ONE, e - 1, prec, rm)
+    }
+}
+
+// Returns the larger of the precisions of two `Float`s. If neither has a precision, returns 1.
+pub(crate) fn max_prec(x: &Float, y: &Float) -> u64 {
+    max(max(x.get_prec(), y.get_prec()), Some(1)).unwrap()
+}
+
+/// Implementations of [`Abs`](malachite_base::num::arithmetic::traits::Abs) and
+/// [`AbsAssign`](malachite_base::num::arithmetic::traits::AbsAssign), traits for getting the
+/// absolute value of a number.
+pub mod abs;
+/// Addition of [`Float`](super::Float)s.
+pub mod add;
+/// Division of [`Float`](super::Float)s.
+pub mod div;
+/// Multiplication of [`Float`](super::Float)s.
+pub mod mul;
+/// Negation of [`Float`](super::Float)s.
+pub mod neg;
+/// Square roots of [`Float`](super::Float)s.
+pub mod sqrt;
+/// Subtraction of [`Float`](super::Float)s.
+pub mod sub;
@@ -0,0 +1,743 @@
+use crate::arithmetic::{max_prec, round_natural};
+use crate::{Float, InnerFloat};
+use malachite_base::rounding_modes::RoundingMode;
+use std::cmp::Ordering;
+use std::ops::{Mul, MulAssign};
+
+fn mul_helper(x: &Float, y: &Float, prec: u64, rm: RoundingMode) -> (Float, Ordering) {
+    assert_ne!(prec, 0, "Precision must be positive");
+    match (&x.0, &y.0) {
+        (InnerFloat::NaN, _)
+        | (_, InnerFloat::NaN)
+        | (InnerFloat::Infinity { .. }, InnerFloat::Zero { .. })
+        | (InnerFloat::Zero { .. }, InnerFloat::Infinity { .. }) => (Float::NAN, Ordering::Equal),
+        (
+            InnerFloat::Infinity { sign: x_sign },
+            InnerFloat::Infinity { sign: y_sign } | InnerFloat::Finite { sign: y_sign, .. },
+        )
+        | (InnerFloat::Finite { sign: x_sign, .. }, InnerFloat::Infinity { sign: y_sign }) => (
+            Float(InnerFloat::Infinity {
+                sign: x_sign == y_sign,
+            }),
+            Ordering::Equal,
+        ),
+        (
+            InnerFloat::Zero { sign: x_sign },
+            InnerFloat::Zero { sign: y_sign } | InnerFloat::Finite { sign: y_sign, .. },
+        )
+        | (InnerFloat::Finite { sign: x_sign, .. }, InnerFloat::Zero { sign: y_sign }) => (
+            Float(InnerFloat::Zero {
+                sign: x_sign == y_sign,
+            }),
+            Ordering::Equal,
+        ),
+        (
+            InnerFloat::Finite {
+                sign: x_sign,
+                exponent: x_exponent,
+                precision: x_precision,
+                significand: x_significand,
+            },
+            InnerFloat::Finite {
+                sign: y_sign,
+                exponent: y_exponent,
+                precision: y_precision,
+                significand: y_significand,
+            },
+        ) => round_natural(
+            x_sign == y_sign,
+            x_significand * y_significand,
+            i128::from(*x_exponent) - i128::from(*x_precision) + i128::from(*y_exponent)
+                - i128::from(*y_precision),
+            prec,
+            rm,
+        ),
+    }
+}
+
+impl Float {
+    /// Multiplies two [`Float`]s, rounding the result to the specified precision and with the
+    /// specified rounding mode. Both [`Float`]s are taken by value. An [`Ordering`] is also
+    /// returned, indicating whether the rounded product is less than, equal to, or greater than the
+    /// exact product. Although `NaN`s are not comparable to any [`Float`], whenever this function
+    /// returns a `NaN` it also returns `Equal`.
+    ///
+    /// See [`RoundingMode`](malachite_base::rounding_modes::RoundingMode) for a description of the
+    /// possible rounding modes.
+    ///
+    /// $$
+    /// f(x,y,p,m) = xy+\varepsilon.
+    /// $$
+    /// - If $xy$ is infinite, zero, or NaN, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $xy$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |xy|\rfloor-p+1}$.
+    /// - If $xy$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| \leq
+    ///   2^{\lfloor\log_2 |xy|\rfloor-p}$.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},x)=f(x,\text{NaN})=f(\pm\infty,\pm0.0)=f(\pm0.0,\pm\infty)=\text{NaN}$
+    /// - $f(\pm\infty,x)=f(x,\pm\infty)=\pm\infty$ if $x$ is nonzero and not NaN, where the sign of
+    ///   the result is the product of the signs of the inputs
+    /// - $f(\pm0.0,x)=f(x,\pm0.0)=\pm0.0$ if $x$ is finite, where the sign of the result is the
+    ///   product of the signs of the inputs
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.get_prec(),
+    /// other.get_prec(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero, or if `rm` is `Exact` but the product cannot be represented
+    /// exactly with the given precision.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    /// use std::f64::consts::{E, PI};
+    ///
+    /// let (product, o) = Float::from(PI).mul_prec_round(Float::from(E), 5, RoundingMode::Floor);
+    /// assert_eq!(product.to_string(), "8.5");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (product, o) = Float::from(PI).mul_prec_round(Float::from(E), 5, RoundingMode::Ceiling);
+    /// assert_eq!(product.to_string(), "9.0");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (product, o) = Float::from(PI).mul_prec_round(Float::from(E), 5, RoundingMode::Nearest);
+    /// assert_eq!(product.to_string(), "8.5");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (product, o) =
+    ///     Float::from(PI).mul_prec_round(Float::from(E), 20, RoundingMode::Nearest);
+    /// assert_eq!(product.to_string(), "8.53973");
+    /// assert_eq!(o, Ordering::Less);
+    /// ```
+    #[inline]
+    #[allow(clippy::needless_pass_by_value)]
+    pub fn mul_prec_round(self, other: Float, prec: u64, rm: RoundingMode) -> (Float, Ordering) {
+        self.mul_prec_round_ref_ref(&other, prec, rm)
+    }
+
+    /// Multiplies two [`Float`]s, rounding the result to the specified precision and with the
+    /// specified rounding mode. Both [`Float`]s are taken by reference. An [`Ordering`] is also
+    /// returned, indicating whether the rounded product is less than, equal to, or greater than the
+    /// exact product. Although `NaN`s are not comparable to any [`Float`], whenever this function
+    /// returns a `NaN` it also returns `Equal`.
+    ///
+    /// See [`RoundingMode`](malachite_base::rounding_modes::RoundingMode) for a description of the
+    /// possible rounding modes.
+    ///
+    /// $$
+    /// f(x,y,p,m) = xy+\varepsilon.
+    /// $$
+    /// - If $xy$ is infinite, zero, or NaN, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $xy$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |xy|\rfloor-p+1}$.
+    /// - If $xy$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| \leq
+    ///   2^{\lfloor\log_2 |xy|\rfloor-p}$.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},x)=f(x,\text{NaN})=f(\pm\infty,\pm0.0)=f(\pm0.0,\pm\infty)=\text{NaN}$
+    /// - $f(\pm\infty,x)=f(x,\pm\infty)=\pm\infty$ if $x$ is nonzero and not NaN, where the sign of
+    ///   the result is the product of the signs of the inputs
+    /// - $f(\pm0.0,x)=f(x,\pm0.0)=\pm0.0$ if $x$ is finite, where the sign of the result is the
+    ///   product of the signs of the inputs
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.get_prec(),
+    /// other.get_prec(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero, or if `rm` is `Exact` but the product cannot be represented
+    /// exactly with the given precision.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    /// use std::f64::consts::{E, PI};
+    ///
+    /// let (product, o) =
+    ///     Float::from(PI).mul_prec_round_ref_ref(&Float::from(E), 5, RoundingMode::Floor);
+    /// assert_eq!(product.to_string(), "8.5");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (product, o) =
+    ///     Float::from(PI).mul_prec_round_ref_ref(&Float::from(E), 5, RoundingMode::Ceiling);
+    /// assert_eq!(product.to_string(), "9.0");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (product, o) =
+    ///     Float::from(PI).mul_prec_round_ref_ref(&Float::from(E), 5, RoundingMode::Nearest);
+    /// assert_eq!(product.to_string(), "8.5");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (product, o) =
+    ///     Float::from(PI).mul_prec_round_ref_ref(&Float::from(E), 20, RoundingMode::Nearest);
+    /// assert_eq!(product.to_string(), "8.53973");
+    /// assert_eq!(o, Ordering::Less);
+    /// ```
+    pub fn mul_prec_round_ref_ref(
+        &self,
+        other: &Float,
+        prec: u64,
+        rm: RoundingMode,
+    ) -> (Float, Ordering) {
+        mul_helper(self, other, prec, rm)
+    }
+
+    /// Multiplies two [`Float`]s, rounding the result to the nearest value of the specified
+    /// precision. Both [`Float`]s are taken by value. An [`Ordering`] is also returned, indicating
+    /// whether the rounded product is less than, equal to, or greater than the exact product.
+    /// Although `NaN`s are not comparable to any [`Float`], whenever this function returns a `NaN`
+    /// it also returns `Equal`.
+    ///
+    /// If the product is equidistant from two [`Float`]s with the specified precision, the
+    /// [`Float`] with fewer 1s in its binary expansion is chosen. See
+    /// [`RoundingMode`](malachite_base::rounding_modes::RoundingMode) for a description of the
+    /// `Nearest` rounding mode.
+    ///
+    /// $$
+    /// f(x,y,p) = xy+\varepsilon.
+    /// $$
+    /// - If $xy$ is infinite, zero, or NaN, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $xy$ is finite and nonzero, then $|\varepsilon| \leq 2^{\lfloor\log_2 |xy|\rfloor-p}$.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},x)=f(x,\text{NaN})=f(\pm\infty,\pm0.0)=f(\pm0.0,\pm\infty)=\text{NaN}$
+    /// - $f(\pm\infty,x)=f(x,\pm\infty)=\pm\infty$ if $x$ is nonzero and not NaN, where the sign of
+    ///   the result is the product of the signs of the inputs
+    /// - $f(\pm0.0,x)=f(x,\pm0.0)=\pm0.0$ if $x$ is finite, where the sign of the result is the
+    ///   product of the signs of the inputs
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.get_prec(),
+    /// other.get_prec(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    /// use std::f64::consts::{E, PI};
+    ///
+    /// let (product, o) = Float::from(PI).mul_prec(Float::from(E), 5);
+    /// assert_eq!(product.to_string(), "8.5");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (product, o) = Float::from(PI).mul_prec(Float::from(E), 20);
+    /// assert_eq!(product.to_string(), "8.53973");
+    /// assert_eq!(o, Ordering::Less);
+    /// ```
+    #[inline]
+    pub fn mul_prec(self, other: Float, prec: u64) -> (Float, Ordering) {
+        self.mul_prec_round(other, prec, RoundingMode::Nearest)
+    }
+
+    /// Multiplies two [`Float`]s, rounding the result to the nearest value of the specified
+    /// precision. Both [`Float`]s are taken by reference. An [`Ordering`] is also returned,
+    /// indicating whether the rounded product is less than, equal to, or greater than the exact
+    /// product. Although `NaN`s are not comparable to any [`Float`], whenever this function returns
+    /// a `NaN` it also returns `Equal`.
+    ///
+    /// If the product is equidistant from two [`Float`]s with the specified precision, the
+    /// [`Float`] with fewer 1s in its binary expansion is chosen. See
+    /// [`RoundingMode`](malachite_base::rounding_modes::RoundingMode) for a description of the
+    /// `Nearest` rounding mode.
+    ///
+    /// $$
+    /// f(x,y,p) = xy+\varepsilon.
+    /// $$
+    /// - If $xy$ is infinite, zero, or NaN, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $xy$ is finite and nonzero, then $|\varepsilon| \leq 2^{\lfloor\log_2 |xy|\rfloor-p}$.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},x)=f(x,\text{NaN})=f(\pm\infty,\pm0.0)=f(\pm0.0,\pm\infty)=\text{NaN}$
+    /// - $f(\pm\infty,x)=f(x,\pm\infty)=\pm\infty$ if $x$ is nonzero and not NaN, where the sign of
+    ///   the result is the product of the signs of the inputs
+    /// - $f(\pm0.0,x)=f(x,\pm0.0)=\pm0.0$ if $x$ is finite, where the sign of the result is the
+    ///   product of the signs of the inputs
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.get_prec(),
+    /// other.get_prec(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    /// use std::f64::consts::{E, PI};
+    ///
+    /// let (product, o) = Float::from(PI).mul_prec_ref_ref(&Float::from(E), 5);
+    /// assert_eq!(product.to_string(), "8.5");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (product, o) = Float::from(PI).mul_prec_ref_ref(&Float::from(E), 20);
+    /// assert_eq!(product.to_string(), "8.53973");
+    /// assert_eq!(o, Ordering::Less);
+    /// ```
+    #[inline]
+    pub fn mul_prec_ref_ref(&self, other: &Float, prec: u64) -> (Float, Ordering) {
+        self.mul_prec_round_ref_ref(other, prec, RoundingMode::Nearest)
+    }
+
+    /// Multiplies two [`Float`]s, rounding the result with the specified rounding mode. Both
+    /// [`Float`]s are taken by value. An [`Ordering`] is also returned, indicating whether the
+    /// rounded product is less than, equal to, or greater than the exact product. Although `NaN`s
+    /// are not comparable to any [`Float`], whenever this function returns a `NaN` it also returns
+    /// `Equal`.
+    ///
+    /// The precision of the output is the maximum of the precision of the inputs. See
+    /// [`RoundingMode`](malachite_base::rounding_modes::RoundingMode) for a description of the
+    /// possible rounding modes.
+    ///
+    /// $$
+    /// f(x,y,m) = xy+\varepsilon.
+    /// $$
+    /// - If $xy$ is infinite, zero, or NaN, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $xy$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |xy|\rfloor-p+1}$, where $p$ is the maximum precision of the inputs.
+    /// - If $xy$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| \leq
+    ///   2^{\lfloor\log_2 |xy|\rfloor-p}$, where $p$ is the maximum precision of the inputs.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},x)=f(x,\text{NaN})=f(\pm\infty,\pm0.0)=f(\pm0.0,\pm\infty)=\text{NaN}$
+    /// - $f(\pm\infty,x)=f(x,\pm\infty)=\pm\infty$ if $x$ is nonzero and not NaN, where the sign of
+    ///   the result is the product of the signs of the inputs
+    /// - $f(\pm0.0,x)=f(x,\pm0.0)=\pm0.0$ if $x$ is finite, where the sign of the result is the
+    ///   product of the signs of the inputs
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.get_prec(),
+    /// other.get_prec())`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the product cannot be represented exactly with the maximum
+    /// precision of the inputs.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    /// use std::f64::consts::{E, PI};
+    ///
+    /// let (product, o) = Float::from(PI).mul_round(Float::from(E), RoundingMode::Floor);
+    /// assert_eq!(product.to_string(), "8.539734222673566");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (product, o) = Float::from(PI).mul_round(Float::from(E), RoundingMode::Ceiling);
+    /// assert_eq!(product.to_string(), "8.539734222673568");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (product, o) = Float::from(PI).mul_round(Float::from(E), RoundingMode::Nearest);
+    /// assert_eq!(product.to_string(), "8.539734222673566");
+    /// assert_eq!(o, Ordering::Less);
+    /// ```
+    #[inline]
+    #[allow(clippy::needless_pass_by_value)]
+    pub fn mul_round(self, other: Float, rm: RoundingMode) -> (Float, Ordering) {
+        self.mul_round_ref_ref(&other, rm)
+    }
+
+    /// Multiplies two [`Float`]s, rounding the result with the specified rounding mode. Both
+    /// [`Float`]s are taken by reference. An [`Ordering`] is also returned, indicating whether the
+    /// rounded product is less than, equal to, or greater than the exact product. Although `NaN`s
+    /// are not comparable to any [`Float`], whenever this function returns a `NaN` it also returns
+    /// `Equal`.
+    ///
+    /// The precision of the output is the maximum of the precision of the inputs. See
+    /// [`RoundingMode`](malachite_base::rounding_modes::RoundingMode) for a description of the
+    /// possible rounding modes.
+    ///
+    /// $$
+    /// f(x,y,m) = xy+\varepsilon.
+    /// $$
+    /// - If $xy$ is infinite, zero, or NaN, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $xy$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |xy|\rfloor-p+1}$, where $p$ is the maximum precision of the inputs.
+    /// - If $xy$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| \leq
+    ///   2^{\lfloor\log_2 |xy|\rfloor-p}$, where $p$ is the maximum precision of the inputs.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},x)=f(x,\text{NaN})=f(\pm\infty,\pm0.0)=f(\pm0.0,\pm\infty)=\text{NaN}$
+    /// - $f(\pm\infty,x)=f(x,\pm\infty)=\pm\infty$ if $x$ is nonzero and not NaN, where the sign of
+    ///   the result is the product of the signs of the inputs
+    /// - $f(\pm0.0,x)=f(x,\pm0.0)=\pm0.0$ if $x$ is finite, where the sign of the result is the
+    ///   product of the signs of the inputs
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.get_prec(),
+    /// other.get_prec())`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the product cannot be represented exactly with the maximum
+    /// precision of the inputs.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    /// use std::f64::consts::{E, PI};
+    ///
+    /// let (product, o) = Float::from(PI).mul_round_ref_ref(&Float::from(E), RoundingMode::Floor);
+    /// assert_eq!(product.to_string(), "8.539734222673566");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (product, o) =
+    ///     Float::from(PI).mul_round_ref_ref(&Float::from(E), RoundingMode::Ceiling);
+    /// assert_eq!(product.to_string(), "8.539734222673568");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (product, o) =
+    ///     Float::from(PI).mul_round_ref_ref(&Float::from(E), RoundingMode::Nearest);
+    /// assert_eq!(product.to_string(), "8.539734222673566");
+    /// assert_eq!(o, Ordering::Less);
+    /// ```
+    pub fn mul_round_ref_ref(&self, other: &Float, rm: RoundingMode) -> (Float, Ordering) {
+        let prec = max_prec(self, other);
+        self.mul_prec_round_ref_ref(other, prec, rm)
+    }
+}
+
+impl Mul<Float> for Float {
+    type Output = Float;
+
+    /// Multiplies two [`Float`]s, taking both by value.
+    ///
+    /// If the output has a precision, it is the maximum of the precisions of the inputs. If the
+    /// product is equidistant from two [`Float`]s with that precision, the [`Float`] with fewer 1s
+    /// in its binary expansion is chosen. See
+    /// [`RoundingMode`](malachite_base::rounding_modes::RoundingMode) for a description of the
+    /// `Nearest` rounding mode.
+    ///
+    /// $$
+    /// f(x,y) = xy+\varepsilon.
+    /// $$
+    /// - If $xy$ is infinite, zero, or NaN, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $xy$ is finite and nonzero, then $|\varepsilon| \leq 2^{\lfloor\log_2 |xy|\rfloor-p}$,
+    ///   where $p$ is the maximum precision of the inputs.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},x)=f(x,\text{NaN})=f(\pm\infty,\pm0.0)=f(\pm0.0,\pm\infty)=\text{NaN}$
+    /// - $f(\pm\infty,x)=f(x,\pm\infty)=\pm\infty$ if $x$ is nonzero and not NaN, where the sign of
+    ///   the result is the product of the signs of the inputs
+    /// - $f(\pm0.0,x)=f(x,\pm0.0)=\pm0.0$ if $x$ is finite, where the sign of the result is the
+    ///   product of the signs of the inputs
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.get_prec(),
+    /// other.get_prec())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::{NegativeOne, One, Zero};
+    /// use malachite_float::Float;
+    /// use std::f64::consts::{E, PI};
+    ///
+    /// assert!((Float::NAN * Float::ONE).is_nan());
+    /// assert_eq!((Float::INFINITY * Float::NEGATIVE_ONE).to_string(), "-Infinity");
+    /// assert!((Float::INFINITY * Float::ZERO).is_nan());
+    /// assert_eq!((Float::NEGATIVE_ZERO * Float::ONE).to_string(), "-0.0");
+    /// assert_eq!((Float::from(PI) * Float::from(E)).to_string(), "8.539734222673566");
+    /// assert_eq!((Float::from(PI) * Float::from(-E)).to_string(), "-8.539734222673566");
+    /// ```
+    #[inline]
+    fn mul(self, other: Float) -> Float {
+        self.mul_round_ref_ref(&other, RoundingMode::Nearest).0
+    }
+}
+
+impl<'a> Mul<&'a Float> for Float {
+    type Output = Float;
+
+    /// Multiplies two [`Float`]s, taking the first by value and the second by reference.
+    ///
+    /// If the output has a precision, it is the maximum of the precisions of the inputs. If the
+    /// product is equidistant from two [`Float`]s with that precision, the [`Float`] with fewer 1s
+    /// in its binary expansion is chosen. See
+    /// [`RoundingMode`](malachite_base::rounding_modes::RoundingMode) for a description of the
+    /// `Nearest` rounding mode.
+    ///
+    /// $$
+    /// f(x,y) = xy+\varepsilon.
+    /// $$
+    /// - If $xy$ is infinite, zero, or NaN, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $xy$ is finite and nonzero, then $|\varepsilon| \leq 2^{\lfloor\log_2 |xy|\rfloor-p}$,
+    ///   where $p$ is the maximum precision of the inputs.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},x)=f(x,\text{NaN})=f(\pm\infty,\pm0.0)=f(\pm0.0,\pm\infty)=\text{NaN}$
+    /// - $f(\pm\infty,x)=f(x,\pm\infty)=\pm\infty$ if $x$ is nonzero and not NaN, where the sign of
+    ///   the result is the product of the signs of the inputs
+    /// - $f(\pm0.0,x)=f(x,\pm0.0)=\pm0.0$ if $x$ is finite, where the sign of the result is the
+    ///   product of the signs of the inputs
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.get_prec(),
+    /// other.get_prec())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::{NegativeOne, One, Zero};
+    /// use malachite_float::Float;
+    /// use std::f64::consts::{E, PI};
+    ///
+    /// assert!((Float::NAN * &Float::ONE).is_nan());
+    /// assert_eq!((Float::INFINITY * &Float::NEGATIVE_ONE).to_string(), "-Infinity");
+    /// assert!((Float::INFINITY * &Float::ZERO).is_nan());
+    /// assert_eq!((Float::NEGATIVE_ZERO * &Float::ONE).to_string(), "-0.0");
+    /// assert_eq!((Float::from(PI) * &Float::from(E)).to_string(), "8.539734222673566");
+    /// assert_eq!((Float::from(PI) * &Float::from(-E)).to_string(), "-8.539734222673566");
+    /// ```
+    #[inline]
+    fn mul(self, other: &'a Float) -> Float {
+        self.mul_round_ref_ref(other, RoundingMode::Nearest).0
+    }
+}
+
+impl<'a> Mul<Float> for &'a Float {
+    type Output = Float;
+
+    /// Multiplies two [`Float`]s, taking the first by reference and the second by value.
+    ///
+    /// If the output has a precision, it is the maximum of the precisions of the inputs. If the
+    /// product is equidistant from two [`Float`]s with that precision, the [`Float`] with fewer 1s
+    /// in its binary expansion is chosen. See
+    /// [`RoundingMode`](malachite_base::rounding_modes::RoundingMode) for a description of the
+    /// `Nearest` rounding mode.
+    ///
+    /// $$
+    /// f(x,y) = xy+\varepsilon.
+    /// $$
+    /// - If $xy$ is infinite, zero, or NaN, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $xy$ is finite and nonzero, then $|\varepsilon| \leq 2^{\lfloor\log_2 |xy|\rfloor-p}$,
+    ///   where $p$ is the maximum precision of the inputs.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},x)=f(x,\text{NaN})=f(\pm\infty,\pm0.0)=f(\pm0.0,\pm\infty)=\text{NaN}$
+    /// - $f(\pm\infty,x)=f(x,\pm\infty)=\pm\infty$ if $x$ is nonzero and not NaN, where the sign of
+    ///   the result is the product of the signs of the inputs
+    /// - $f(\pm0.0,x)=f(x,\pm0.0)=\pm0.0$ if $x$ is finite, where the sign of the result is the
+    ///   product of the signs of the inputs
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.get_prec(),
+    /// other.get_prec())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::{NegativeOne, One, Zero};
+    /// use malachite_float::Float;
+    /// use std::f64::consts::{E, PI};
+    ///
+    /// assert!((&Float::NAN * Float::ONE).is_nan());
+    /// assert_eq!((&Float::INFINITY * Float::NEGATIVE_ONE).to_string(), "-Infinity");
+    /// assert!((&Float::INFINITY * Float::ZERO).is_nan());
+    /// assert_eq!((&Float::NEGATIVE_ZERO * Float::ONE).to_string(), "-0.0");
+    /// assert_eq!((&Float::from(PI) * Float::from(E)).to_string(), "8.539734222673566");
+    /// assert_eq!((&Float::from(PI) * Float::from(-E)).to_string(), "-8.539734222673566");
+    /// ```
+    #[inline]
+    fn mul(self, other: Float) -> Float {
+        self.mul_round_ref_ref(&other, RoundingMode::Nearest).0
+    }
+}
+
+impl<'a, 'b> Mul<&'a Float> for &'b Float {
+    type Output = Float;
+
+    /// Multiplies two [`Float`]s, taking both by reference.
+    ///
+    /// If the output has a precision, it is the maximum of the precisions of the inputs. If the
+    /// product is equidistant from two [`Float`]s with that precision, the [`Float`] with fewer 1s
+    /// in its binary expansion is chosen. See
+    /// [`RoundingMode`](malachite_base::rounding_modes::RoundingMode) for a description of the
+    /// `Nearest` rounding mode.
+    ///
+    /// $$
+    /// f(x,y) = xy+\varepsilon.
+    /// $$
+    /// - If $xy$ is infinite, zero, or NaN, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $xy$ is finite and nonzero, then $|\varepsilon| \leq 2^{\lfloor\log_2 |xy|\rfloor-p}$,
+    ///   where $p$ is the maximum precision of the inputs.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},x)=f(x,\text{NaN})=f(\pm\infty,\pm0.0)=f(\pm0.0,\pm\infty)=\text{NaN}$
+    /// - $f(\pm\infty,x)=f(x,\pm\infty)=\pm\infty$ if $x$ is nonzero and not NaN, where the sign of
+    ///   the result is the product of the signs of the inputs
+    /// - $f(\pm0.0,x)=f(x,\pm0.0)=\pm0.0$ if $x$ is finite, where the sign of the result is the
+    ///   product of the signs of the inputs
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.get_prec(),
+    /// other.get_prec())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::{NegativeOne, One, Zero};
+    /// use malachite_float::Float;
+    /// use std::f64::consts::{E, PI};
+    ///
+    /// assert!((&Float::NAN * &Float::ONE).is_nan());
+    /// assert_eq!((&Float::INFINITY * &Float::NEGATIVE_ONE).to_string(), "-Infinity");
+    /// assert!((&Float::INFINITY * &Float::ZERO).is_nan());
+    /// assert_eq!((&Float::NEGATIVE_ZERO * &Float::ONE).to_string(), "-0.0");
+    /// assert_eq!((&Float::from(PI) * &Float::from(E)).to_string(), "8.539734222673566");
+    /// assert_eq!((&Float::from(PI) * &Float::from(-E)).to_string(), "-8.539734222673566");
+    /// ```
+    #[inline]
+    fn mul(self, other: &'a Float) -> Float {
+        self.mul_round_ref_ref(other, RoundingMode::Nearest).0
+    }
+}
+
+impl MulAssign<Float> for Float {
+    /// Multiplies a [`Float`] by a [`Float`] in place, taking the [`Float`] on the right-hand side
+    /// by value.
+    ///
+    /// If the output has a precision, it is the maximum of the precisions of the inputs. If the
+    /// product is equidistant from two [`Float`]s with that precision, the [`Float`] with fewer 1s
+    /// in its binary expansion is chosen. See
+    /// [`RoundingMode`](malachite_base::rounding_modes::RoundingMode) for a description of the
+    /// `Nearest` rounding mode.
+    ///
+    /// $$
+    /// x \gets x \cdot y+\varepsilon.
+    /// $$
+    /// - If $xy$ is infinite, zero, or NaN, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $xy$ is finite and nonzero, then $|\varepsilon| \leq 2^{\lfloor\log_2 |xy|\rfloor-p}$,
+    ///   where $p$ is the maximum precision of the inputs.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},x)=f(x,\text{NaN})=f(\pm\infty,\pm0.0)=f(\pm0.0,\pm\infty)=\text{NaN}$
+    /// - $f(\pm\infty,x)=f(x,\pm\infty)=\pm\infty$ if $x$ is nonzero and not NaN, where the sign of
+    ///   the result is the product of the signs of the inputs
+    /// - $f(\pm0.0,x)=f(x,\pm0.0)=\pm0.0$ if $x$ is finite, where the sign of the result is the
+    ///   product of the signs of the inputs
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.get_prec(),
+    /// other.get_prec())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::Float;
+    /// use std::f64::consts::{E, PI};
+    ///
+    /// let mut x = Float::from(PI);
+    /// x *= Float::from(E);
+    /// assert_eq!(x.to_string(), "8.539734222673566");
+    ///
+    /// let mut x = Float::from(PI);
+    /// x *= Float::from(-E);
+    /// assert_eq!(x.to_string(), "-8.539734222673566");
+    /// ```
+    #[inline]
+    fn mul_assign(&mut self, other: Float) {
+        *self = self.mul_round_ref_ref(&other, RoundingMode::Nearest).0;
+    }
+}
+
+impl<'a> MulAssign<&'a Float> for Float {
+    /// Multiplies a [`Float`] by a [`Float`] in place, taking the [`Float`] on the right-hand side
+    /// by reference.
+    ///
+    /// If the output has a precision, it is the maximum of the precisions of the inputs. If the
+    /// product is equidistant from two [`Float`]s with that precision, the [`Float`] with fewer 1s
+    /// in its binary expansion is chosen. See
+    /// [`RoundingMode`](malachite_base::rounding_modes::RoundingMode) for a description of the
+    /// `Nearest` rounding mode.
+    ///
+    /// $$
+    /// x \gets x \cdot y+\varepsilon.
+    /// $$
+    /// - If $xy$ is infinite, zero, or NaN, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $xy$ is finite and nonzero, then $|\varepsilon| \leq 2^{\lfloor\log_2 |xy|\rfloor-p}$,
+    ///   where $p$ is the maximum precision of the inputs.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},x)=f(x,\text{NaN})=f(\pm\infty,\pm0.0)=f(\pm0.0,\pm\infty)=\text{NaN}$
+    /// - $f(\pm\infty,x)=f(x,\pm\infty)=\pm\infty$ if $x$ is nonzero and not NaN, where the sign of
+    ///   the result is the product of the signs of the inputs
+    /// - $f(\pm0.0,x)=f(x,\pm0.0)=\pm0.0$ if $x$ is finite, where the sign of the result is the
+    ///   product of the signs of the inputs
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.get_prec(),
+    /// other.get_prec())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::Float;
+    /// use std::f64::consts::{E, PI};
+    ///
+    /// let mut x = Float::from(PI);
+    /// x *= &Float::from(E);
+    /// assert_eq!(x.to_string(), "8.539734222673566");
+    ///
+    /// let mut x = Float::from(PI);
+    /// x *= &Float::from(-E);
+    /// assert_eq!(x.to_string(), "-8.539734222673566");
+    /// ```
+    #[inline]
+    fn mul_assign(&mut self, other: &'a Float) {
+        *self = self.mul_round_ref_ref(other, RoundingMode::Nearest).0;
+    }
+}
@@ -0,0 +1,107 @@
+use crate::{Float, InnerFloat};
+use malachite_base::num::arithmetic::traits::NegAssign;
+use std::ops::Neg;
+
+impl Neg for Float {
+    type Output = Float;
+
+    /// Negates a [`Float`], taking it by value.
+    ///
+    /// NaN is mapped to itself, and the signs of zeros and infinities are flipped. Negation is
+    /// always exact, and the precision is unchanged.
+    ///
+    /// $$
+    /// f(x) = -x.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::{One, Zero};
+    /// use malachite_float::Float;
+    ///
+    /// assert!((-Float::NAN).is_nan());
+    /// assert_eq!((-Float::INFINITY).to_string(), "-Infinity");
+    /// assert_eq!((-Float::ZERO).to_string(), "-0.0");
+    /// assert_eq!((-Float::ONE).to_string(), "-1.0");
+    /// assert_eq!((-Float::from(-2.5f64)).to_string(), "2.5");
+    /// ```
+    fn neg(mut self) -> Float {
+        self.neg_assign();
+        self
+    }
+}
+
+impl<'a> Neg for &'a Float {
+    type Output = Float;
+
+    /// Negates a [`Float`], taking it by reference.
+    ///
+    /// NaN is mapped to itself, and the signs of zeros and infinities are flipped. Negation is
+    /// always exact, and the precision is unchanged.
+    ///
+    /// $$
+    /// f(x) = -x.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.get_prec()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::{One, Zero};
+    /// use malachite_float::Float;
+    ///
+    /// assert!((-&Float::NAN).is_nan());
+    /// assert_eq!((-&Float::INFINITY).to_string(), "-Infinity");
+    /// assert_eq!((-&Float::ZERO).to_string(), "-0.0");
+    /// assert_eq!((-&Float::ONE).to_string(), "-1.0");
+    /// assert_eq!((-&Float::from(-2.5f64)).to_string(), "2.5");
+    /// ```
+    fn neg(self) -> Float {
+        -self.clone()
+    }
+}
+
+impl NegAssign for Float {
+    /// Negates a [`Float`] in place.
+    ///
+    /// NaN is mapped to itself, and the signs of zeros and infinities are flipped. Negation is
+    /// always exact, and the precision is unchanged.
+    ///
+    /// $$
+    /// x \gets -x.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::NegAssign;
+    /// use malachite_base::num::basic::traits::{One, Zero};
+    /// use malachite_float::Float;
+    ///
+    /// let mut x = Float::ZERO;
+    /// x.neg_assign();
+    /// assert_eq!(x.to_string(), "-0.0");
+    ///
+    /// let mut x = Float::ONE;
+    /// x.neg_assign();
+    /// assert_eq!(x.to_string(), "-1.0");
+    /// ```
+    fn neg_assign(&mut self) {
+        match &mut self.0 {
+            InnerFloat::NaN => {}
+            InnerFloat::Infinity { sign }
+            | InnerFloat::Zero { sign }
+            | InnerFloat::Finite { sign, .. } => *sign = !*sign,
+        }
+    }
+}
@@ -0,0 +1,551 @@
+use crate::arithmetic::round_natural;
+use crate::{Float, InnerFloat};
+use malachite_base::num::arithmetic::traits::{Sqrt, SqrtAssign, SqrtRem};
+use malachite_base::num::basic::traits::One;
+use malachite_base::num::logic::traits::SignificantBits;
+use malachite_base::rounding_modes::RoundingMode;
+use malachite_nz::natural::Natural;
+use std::cmp::Ordering;
+
+fn sqrt_helper(x: &Float, prec: u64, rm: RoundingMode) -> (Float, Ordering) {
+    assert_ne!(prec, 0, "Precision must be positive");
+    match &x.0 {
+        InnerFloat::NaN
+        | InnerFloat::Infinity { sign: false }
+        | InnerFloat::Finite { sign: false, .. } => (Float::NAN, Ordering::Equal),
+        InnerFloat::Infinity { sign: true } | InnerFloat::Zero { .. } => {
+            (x.clone(), Ordering::Equal)
+        }
+        InnerFloat::Finite {
+            exponent,
+            precision,
+            significand,
+            ..
+        } => {
+            // Write $x = n2^e$ with $e$ even, then scale $n$ by an even power of 2 so that its
+            // square root has at least `prec + 2` bits.
+            let mut n = significand.clone();
+            let mut e = i128::from(*exponent) - i128::from(*precision);
+            if e & 1 != 0 {
+                n <<= 1;
+                e -= 1;
+            }
+            let k = ((2 * prec + 3).saturating_sub(n.significant_bits()) + 1) >> 1;
+            n <<= k << 1;
+            let (s, r) = n.sqrt_rem();
+            let e = (e >> 1) - i128::from(k);
+            if r == 0u32 {
+                round_natural(true, s, e, prec, rm)
+            } else {
+                round_natural(true, (s << 1) | Natural::ONE, e - 1, prec, rm)
+            }
+        }
+    }
+}
+
+impl Float {
+    /// Computes the square root of a [`Float`], rounding the result to the specified precision and
+    /// with the specified rounding mode. The [`Float`] is taken by value. An [`Ordering`] is also
+    /// returned, indicating whether the rounded square root is less than, equal to, or greater
+    /// than the exact square root. Although `NaN`s are not comparable to any [`Float`], whenever
+    /// this function returns a `NaN` it also returns `Equal`.
+    ///
+    /// See [`RoundingMode`](malachite_base::rounding_modes::RoundingMode) for a description of the
+    /// possible rounding modes.
+    ///
+    /// $$
+    /// f(x,p,m) = \sqrt x+\varepsilon.
+    /// $$
+    /// - If $\sqrt x$ is infinite, zero, or NaN, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $\sqrt x$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 \sqrt x\rfloor-p+1}$.
+    /// - If $\sqrt x$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| \leq
+    ///   2^{\lfloor\log_2 \sqrt x\rfloor-p}$.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN})=f(-\infty)=\text{NaN}$
+    /// - $f(x)=\text{NaN}$ if $x$ is finite and less than zero
+    /// - $f(\infty)=\infty$
+    /// - $f(0.0)=0.0$
+    /// - $f(-0.0)=-0.0$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.get_prec(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero, or if `rm` is `Exact` but the square root cannot be
+    /// represented exactly with the given precision.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    ///
+    /// let (sqrt, o) = Float::from(2.0f64).sqrt_prec_round(5, RoundingMode::Floor);
+    /// assert_eq!(sqrt.to_string(), "1.4");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (sqrt, o) = Float::from(2.0f64).sqrt_prec_round(5, RoundingMode::Ceiling);
+    /// assert_eq!(sqrt.to_string(), "1.44");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (sqrt, o) = Float::from(2.0f64).sqrt_prec_round(5, RoundingMode::Nearest);
+    /// assert_eq!(sqrt.to_string(), "1.44");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (sqrt, o) = Float::from(2.0f64).sqrt_prec_round(20, RoundingMode::Nearest);
+    /// assert_eq!(sqrt.to_string(), "1.414213");
+    /// assert_eq!(o, Ordering::Less);
+    /// ```
+    #[inline]
+    pub fn sqrt_prec_round(self, prec: u64, rm: RoundingMode) -> (Float, Ordering) {
+        sqrt_helper(&self, prec, rm)
+    }
+
+    /// Computes the square root of a [`Float`], rounding the result to the specified precision and
+    /// with the specified rounding mode. The [`Float`] is taken by reference. An [`Ordering`] is
+    /// also returned, indicating whether the rounded square root is less than, equal to, or
+    /// greater than the exact square root. Although `NaN`s are not comparable to any [`Float`],
+    /// whenever this function returns a `NaN` it also returns `Equal`.
+    ///
+    /// See [`RoundingMode`](malachite_base::rounding_modes::RoundingMode) for a description of the
+    /// possible rounding modes.
+    ///
+    /// $$
+    /// f(x,p,m) = \sqrt x+\varepsilon.
+    /// $$
+    /// - If $\sqrt x$ is infinite, zero, or NaN, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $\sqrt x$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 \sqrt x\rfloor-p+1}$.
+    /// - If $\sqrt x$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| \leq
+    ///   2^{\lfloor\log_2 \sqrt x\rfloor-p}$.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN})=f(-\infty)=\text{NaN}$
+    /// - $f(x)=\text{NaN}$ if $x$ is finite and less than zero
+    /// - $f(\infty)=\infty$
+    /// - $f(0.0)=0.0$
+    /// - $f(-0.0)=-0.0$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.get_prec(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero, or if `rm` is `Exact` but the square root cannot be
+    /// represented exactly with the given precision.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    ///
+    /// let (sqrt, o) = Float::from(2.0f64).sqrt_prec_round_ref(5, RoundingMode::Floor);
+    /// assert_eq!(sqrt.to_string(), "1.4");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (sqrt, o) = Float::from(2.0f64).sqrt_prec_round_ref(5, RoundingMode::Ceiling);
+    /// assert_eq!(sqrt.to_string(), "1.44");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (sqrt, o) = Float::from(2.0f64).sqrt_prec_round_ref(5, RoundingMode::Nearest);
+    /// assert_eq!(sqrt.to_string(), "1.44");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (sqrt, o) = Float::from(2.0f64).sqrt_prec_round_ref(20, RoundingMode::Nearest);
+    /// assert_eq!(sqrt.to_string(), "1.414213");
+    /// assert_eq!(o, Ordering::Less);
+    /// ```
+    #[inline]
+    pub fn sqrt_prec_round_ref(&self, prec: u64, rm: RoundingMode) -> (Float, Ordering) {
+        sqrt_helper(self, prec, rm)
+    }
+
+    /// Computes the square root of a [`Float`], rounding the result to the nearest value of the
+    /// specified precision. The [`Float`] is taken by value. An [`Ordering`] is also returned,
+    /// indicating whether the rounded square root is less than, equal to, or greater than the
+    /// exact square root. Although `NaN`s are not comparable to any [`Float`], whenever this
+    /// function returns a `NaN` it also returns `Equal`.
+    ///
+    /// If the square root is equidistant from two [`Float`]s with the specified precision, the
+    /// [`Float`] with fewer 1s in its binary expansion is chosen. See
+    /// [`RoundingMode`](malachite_base::rounding_modes::RoundingMode) for a description of the
+    /// `Nearest` rounding mode.
+    ///
+    /// $$
+    /// f(x,p) = \sqrt x+\varepsilon.
+    /// $$
+    /// - If $\sqrt x$ is infinite, zero, or NaN, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $\sqrt x$ is finite and nonzero, then $|\varepsilon| \leq
+    ///   2^{\lfloor\log_2 \sqrt x\rfloor-p}$.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN})=f(-\infty)=\text{NaN}$
+    /// - $f(x)=\text{NaN}$ if $x$ is finite and less than zero
+    /// - $f(\infty)=\infty$
+    /// - $f(0.0)=0.0$
+    /// - $f(-0.0)=-0.0$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.get_prec(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    ///
+    /// let (sqrt, o) = Float::from(2.0f64).sqrt_prec(5);
+    /// assert_eq!(sqrt.to_string(), "1.44");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (sqrt, o) = Float::from(2.0f64).sqrt_prec(20);
+    /// assert_eq!(sqrt.to_string(), "1.414213");
+    /// assert_eq!(o, Ordering::Less);
+    /// ```
+    #[inline]
+    pub fn sqrt_prec(self, prec: u64) -> (Float, Ordering) {
+        sqrt_helper(&self, prec, RoundingMode::Nearest)
+    }
+
+    /// Computes the square root of a [`Float`], rounding the result to the nearest value of the
+    /// specified precision. The [`Float`] is taken by reference. An [`Ordering`] is also returned,
+    /// indicating whether the rounded square root is less than, equal to, or greater than the
+    /// exact square root. Although `NaN`s are not comparable to any [`Float`], whenever this
+    /// function returns a `NaN` it also returns `Equal`.
+    ///
+    /// If the square root is equidistant from two [`Float`]s with the specified precision, the
+    /// [`Float`] with fewer 1s in its binary expansion is chosen. See
+    /// [`RoundingMode`](malachite_base::rounding_modes::RoundingMode) for a description of the
+    /// `Nearest` rounding mode.
+    ///
+    /// $$
+    /// f(x,p) = \sqrt x+\varepsilon.
+    /// $$
+    /// - If $\sqrt x$ is infinite, zero, or NaN, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $\sqrt x$ is finite and nonzero, then $|\varepsilon| \leq
+    ///   2^{\lfloor\log_2 \sqrt x\rfloor-p}$.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN})=f(-\infty)=\text{NaN}$
+    /// - $f(x)=\text{NaN}$ if $x$ is finite and less than zero
+    /// - $f(\infty)=\infty$
+    /// - $f(0.0)=0.0$
+    /// - $f(-0.0)=-0.0$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.get_prec(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    ///
+    /// let (sqrt, o) = Float::from(2.0f64).sqrt_prec_ref(5);
+    /// assert_eq!(sqrt.to_string(), "1.44");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (sqrt, o) = Float::from(2.0f64).sqrt_prec_ref(20);
+    /// assert_eq!(sqrt.to_string(), "1.414213");
+    /// assert_eq!(o, Ordering::Less);
+    /// ```
+    #[inline]
+    pub fn sqrt_prec_ref(&self, prec: u64) -> (Float, Ordering) {
+        sqrt_helper(self, prec, RoundingMode::Nearest)
+    }
+
+    /// Computes the square root of a [`Float`], rounding the result with the specified rounding
+    /// mode. The [`Float`] is taken by value. An [`Ordering`] is also returned, indicating
+    /// whether the rounded square root is less than, equal to, or greater than the exact square
+    /// root. Although `NaN`s are not comparable to any [`Float`], whenever this function returns
+    /// a `NaN` it also returns `Equal`.
+    ///
+    /// The precision of the output is the precision of the input. See
+    /// [`RoundingMode`](malachite_base::rounding_modes::RoundingMode) for a description of the
+    /// possible rounding modes.
+    ///
+    /// $$
+    /// f(x,m) = \sqrt x+\varepsilon.
+    /// $$
+    /// - If $\sqrt x$ is infinite, zero, or NaN, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $\sqrt x$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 \sqrt x\rfloor-p+1}$, where $p$ is the precision of the input.
+    /// - If $\sqrt x$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| \leq
+    ///   2^{\lfloor\log_2 \sqrt x\rfloor-p}$, where $p$ is the precision of the input.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN})=f(-\infty)=\text{NaN}$
+    /// - $f(x)=\text{NaN}$ if $x$ is finite and less than zero
+    /// - $f(\infty)=\infty$
+    /// - $f(0.0)=0.0$
+    /// - $f(-0.0)=-0.0$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.get_prec()`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the square root cannot be represented exactly with the
+    /// precision of the input.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    ///
+    /// let (sqrt, o) = Float::from(10.0f64).sqrt_round(RoundingMode::Floor);
+    /// assert_eq!(sqrt.to_string(), "3.0");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (sqrt, o) = Float::from(10.0f64).sqrt_round(RoundingMode::Ceiling);
+    /// assert_eq!(sqrt.to_string(), "3.5");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (sqrt, o) = Float::from(10.0f64).sqrt_round(RoundingMode::Nearest);
+    /// assert_eq!(sqrt.to_string(), "3.0");
+    /// assert_eq!(o, Ordering::Less);
+    /// ```
+    #[inline]
+    pub fn sqrt_round(self, rm: RoundingMode) -> (Float, Ordering) {
+        self.sqrt_round_ref(rm)
+    }
+
+    /// Computes the square root of a [`Float`], rounding the result with the specified rounding
+    /// mode. The [`Float`] is taken by reference. An [`Ordering`] is also returned, indicating
+    /// whether the rounded square root is less than, equal to, or greater than the exact square
+    /// root. Although `NaN`s are not comparable to any [`Float`], whenever this function returns
+    /// a `NaN` it also returns `Equal`.
+    ///
+    /// The precision of the output is the precision of the input. See
+    /// [`RoundingMode`](malachite_base::rounding_modes::RoundingMode) for a description of the
+    /// possible rounding modes.
+    ///
+    /// $$
+    /// f(x,m) = \sqrt x+\varepsilon.
+    /// $$
+    /// - If $\sqrt x$ is infinite, zero, or NaN, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $\sqrt x$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 \sqrt x\rfloor-p+1}$, where $p$ is the precision of the input.
+    /// - If $\sqrt x$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| \leq
+    ///   2^{\lfloor\log_2 \sqrt x\rfloor-p}$, where $p$ is the precision of the input.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN})=f(-\infty)=\text{NaN}$
+    /// - $f(x)=\text{NaN}$ if $x$ is finite and less than zero
+    /// - $f(\infty)=\infty$
+    /// - $f(0.0)=0.0$
+    /// - $f(-0.0)=-0.0$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.get_prec()`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the square root cannot be represented exactly with the
+    /// precision of the input.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    ///
+    /// let (sqrt, o) = Float::from(10.0f64).sqrt_round_ref(RoundingMode::Floor);
+    /// assert_eq!(sqrt.to_string(), "3.0");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (sqrt, o) = Float::from(10.0f64).sqrt_round_ref(RoundingMode::Ceiling);
+    /// assert_eq!(sqrt.to_string(), "3.5");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (sqrt, o) = Float::from(10.0f64).sqrt_round_ref(RoundingMode::Nearest);
+    /// assert_eq!(sqrt.to_string(), "3.0");
+    /// assert_eq!(o, Ordering::Less);
+    /// ```
+    #[inline]
+    pub fn sqrt_round_ref(&self, rm: RoundingMode) -> (Float, Ordering) {
+        sqrt_helper(self, self.get_prec().unwrap_or(1), rm)
+    }
+}
+
+impl Sqrt for Float {
+    type Output = Float;
+
+    /// Computes the square root of a [`Float`], taking it by value.
+    ///
+    /// If the output has a precision, it is the precision of the input. If the square root is
+    /// equidistant from two [`Float`]s with that precision, the [`Float`] with fewer 1s in its
+    /// binary expansion is chosen. See
+    /// [`RoundingMode`](malachite_base::rounding_modes::RoundingMode) for a description of the
+    /// `Nearest` rounding mode.
+    ///
+    /// $$
+    /// f(x) = \sqrt x+\varepsilon.
+    /// $$
+    /// - If $\sqrt x$ is infinite, zero, or NaN, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $\sqrt x$ is finite and nonzero, then $|\varepsilon| \leq
+    ///   2^{\lfloor\log_2 \sqrt x\rfloor-p}$, where $p$ is the precision of the input.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN})=f(-\infty)=\text{NaN}$
+    /// - $f(x)=\text{NaN}$ if $x$ is finite and less than zero
+    /// - $f(\infty)=\infty$
+    /// - $f(0.0)=0.0$
+    /// - $f(-0.0)=-0.0$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.get_prec()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::Sqrt;
+    /// use malachite_base::num::basic::traits::{NegativeOne, Zero};
+    /// use malachite_float::Float;
+    ///
+    /// assert!((Float::NAN).sqrt().is_nan());
+    /// assert!((Float::NEGATIVE_ONE).sqrt().is_nan());
+    /// assert_eq!((Float::INFINITY).sqrt().to_string(), "Infinity");
+    /// assert_eq!((Float::NEGATIVE_ZERO).sqrt().to_string(), "-0.0");
+    /// assert_eq!((Float::from(6.25f64)).sqrt().to_string(), "2.5");
+    /// assert_eq!((Float::from(2.0f64)).sqrt().to_string(), "1.0");
+    /// assert_eq!((Float::from(std::f64::consts::PI)).sqrt().to_string(), "1.772453850905515");
+    /// ```
+    #[inline]
+    fn sqrt(self) -> Float {
+        self.sqrt_round_ref(RoundingMode::Nearest).0
+    }
+}
+
+impl<'a> Sqrt for &'a Float {
+    type Output = Float;
+
+    /// Computes the square root of a [`Float`], taking it by reference.
+    ///
+    /// If the output has a precision, it is the precision of the input. If the square root is
+    /// equidistant from two [`Float`]s with that precision, the [`Float`] with fewer 1s in its
+    /// binary expansion is chosen. See
+    /// [`RoundingMode`](malachite_base::rounding_modes::RoundingMode) for a description of the
+    /// `Nearest` rounding mode.
+    ///
+    /// $$
+    /// f(x) = \sqrt x+\varepsilon.
+    /// $$
+    /// - If $\sqrt x$ is infinite, zero, or NaN, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $\sqrt x$ is finite and nonzero, then $|\varepsilon| \leq
+    ///   2^{\lfloor\log_2 \sqrt x\rfloor-p}$, where $p$ is the precision of the input.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN})=f(-\infty)=\text{NaN}$
+    /// - $f(x)=\text{NaN}$ if $x$ is finite and less than zero
+    /// - $f(\infty)=\infty$
+    /// - $f(0.0)=0.0$
+    /// - $f(-0.0)=-0.0$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.get_prec()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::Sqrt;
+    /// use malachite_base::num::basic::traits::{NegativeOne, Zero};
+    /// use malachite_float::Float;
+    ///
+    /// assert!((&Float::NAN).sqrt().is_nan());
+    /// assert!((&Float::NEGATIVE_ONE).sqrt().is_nan());
+    /// assert_eq!((&Float::INFINITY).sqrt().to_string(), "Infinity");
+    /// assert_eq!((&Float::NEGATIVE_ZERO).sqrt().to_string(), "-0.0");
+    /// assert_eq!((&Float::from(6.25f64)).sqrt().to_string(), "2.5");
+    /// assert_eq!((&Float::from(2.0f64)).sqrt().to_string(), "1.0");
+    /// assert_eq!((&Float::from(std::f64::consts::PI)).sqrt().to_string(), "1.772453850905515");
+    /// ```
+    #[inline]
+    fn sqrt(self) -> Float {
+        self.sqrt_round_ref(RoundingMode::Nearest).0
+    }
+}
+
+impl SqrtAssign for Float {
+    /// Replaces a [`Float`] with its square root.
+    ///
+    /// If the output has a precision, it is the precision of the input. If the square root is
+    /// equidistant from two [`Float`]s with that precision, the [`Float`] with fewer 1s in its
+    /// binary expansion is chosen. See
+    /// [`RoundingMode`](malachite_base::rounding_modes::RoundingMode) for a description of the
+    /// `Nearest` rounding mode.
+    ///
+    /// $$
+    /// x \gets \sqrt x+\varepsilon.
+    /// $$
+    /// - If $\sqrt x$ is infinite, zero, or NaN, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $\sqrt x$ is finite and nonzero, then $|\varepsilon| \leq
+    ///   2^{\lfloor\log_2 \sqrt x\rfloor-p}$, where $p$ is the precision of the input.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN})=f(-\infty)=\text{NaN}$
+    /// - $f(x)=\text{NaN}$ if $x$ is finite and less than zero
+    /// - $f(\infty)=\infty$
+    /// - $f(0.0)=0.0$
+    /// - $f(-0.0)=-0.0$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.get_prec()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::SqrtAssign;
+    /// use malachite_float::Float;
+    ///
+    /// let mut x = Float::from(6.25f64);
+    /// x.sqrt_assign();
+    /// assert_eq!(x.to_string(), "2.5");
+    ///
+    /// let mut x = Float::from(std::f64::consts::PI);
+    /// x.sqrt_assign();
+    /// assert_eq!(x.to_string(), "1.772453850905515");
+    /// ```
+    #[inline]
+    fn sqrt_assign(&mut self) {
+        *self = self.sqrt_round_ref(RoundingMode::Nearest).0;
+    }
+}
@@ -0,0 +1,729 @@
+use crate::arithmetic::add::add_helper;
+use crate::arithmetic::max_prec;
+use crate::Float;
+use malachite_base::rounding_modes::RoundingMode;
+use std::cmp::Ordering;
+use std::ops::{Sub, SubAssign};
+
+impl Float {
+    /// Subtracts a [`Float`] by another [`Float`], rounding the result to the specified precision
+    /// and with the specified rounding mode. Both [`Float`]s are taken by value. An [`Ordering`] is
+    /// also returned, indicating whether the rounded difference is less than, equal to, or greater
+    /// than the exact difference. Although `NaN`s are not comparable to any [`Float`], whenever
+    /// this function returns a `NaN` it also returns `Equal`.
+    ///
+    /// See [`RoundingMode`](malachite_base::rounding_modes::RoundingMode) for a description of the
+    /// possible rounding modes.
+    ///
+    /// $$
+    /// f(x,y,p,m) = x-y+\varepsilon.
+    /// $$
+    /// - If $x-y$ is infinite, zero, or NaN, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $x-y$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |x-y|\rfloor-p+1}$.
+    /// - If $x-y$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| \leq
+    ///   2^{\lfloor\log_2 |x-y|\rfloor-p}$.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},x)=f(x,\text{NaN})=f(\infty,\infty)=f(-\infty,-\infty)=\text{NaN}$
+    /// - $f(\infty,x)=f(x,-\infty)=\infty$ if $x$ is not NaN or $\infty$
+    /// - $f(-\infty,x)=f(x,\infty)=-\infty$ if $x$ is not NaN or $-\infty$
+    /// - $f(0.0,-0.0)=0.0$
+    /// - $f(-0.0,0.0)=-0.0$
+    /// - $f(0.0,0.0)=f(-0.0,-0.0)=0.0$ if `rm` is not `Floor`, and $-0.0$ if it is
+    /// - $f(x,x)=0.0$ if $x$ is finite and nonzero and `rm` is not `Floor`, and $-0.0$ if it is
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.get_prec(),
+    /// other.get_prec(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero, or if `rm` is `Exact` but the difference cannot be represented
+    /// exactly with the given precision.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    /// use std::f64::consts::{E, PI};
+    ///
+    /// let (difference, o) =
+    ///     Float::from(PI).sub_prec_round(Float::from(E), 5, RoundingMode::Floor);
+    /// assert_eq!(difference.to_string(), "0.42");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (difference, o) =
+    ///     Float::from(PI).sub_prec_round(Float::from(E), 5, RoundingMode::Ceiling);
+    /// assert_eq!(difference.to_string(), "0.44");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (difference, o) =
+    ///     Float::from(PI).sub_prec_round(Float::from(E), 5, RoundingMode::Nearest);
+    /// assert_eq!(difference.to_string(), "0.42");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (difference, o) =
+    ///     Float::from(PI).sub_prec_round(Float::from(E), 20, RoundingMode::Nearest);
+    /// assert_eq!(difference.to_string(), "0.4233108");
+    /// assert_eq!(o, Ordering::Less);
+    /// ```
+    #[inline]
+    #[allow(clippy::needless_pass_by_value)]
+    pub fn sub_prec_round(self, other: Float, prec: u64, rm: RoundingMode) -> (Float, Ordering) {
+        self.sub_prec_round_ref_ref(&other, prec, rm)
+    }
+
+    /// Subtracts a [`Float`] by another [`Float`], rounding the result to the specified precision
+    /// and with the specified rounding mode. Both [`Float`]s are taken by reference. An
+    /// [`Ordering`] is also returned, indicating whether the rounded difference is less than, equal
+    /// to, or greater than the exact difference. Although `NaN`s are not comparable to any
+    /// [`Float`], whenever this function returns a `NaN` it also returns `Equal`.
+    ///
+    /// See [`RoundingMode`](malachite_base::rounding_modes::RoundingMode) for a description of the
+    /// possible rounding modes.
+    ///
+    /// $$
+    /// f(x,y,p,m) = x-y+\varepsilon.
+    /// $$
+    /// - If $x-y$ is infinite, zero, or NaN, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $x-y$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |x-y|\rfloor-p+1}$.
+    /// - If $x-y$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| \leq
+    ///   2^{\lfloor\log_2 |x-y|\rfloor-p}$.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},x)=f(x,\text{NaN})=f(\infty,\infty)=f(-\infty,-\infty)=\text{NaN}$
+    /// - $f(\infty,x)=f(x,-\infty)=\infty$ if $x$ is not NaN or $\infty$
+    /// - $f(-\infty,x)=f(x,\infty)=-\infty$ if $x$ is not NaN or $-\infty$
+    /// - $f(0.0,-0.0)=0.0$
+    /// - $f(-0.0,0.0)=-0.0$
+    /// - $f(0.0,0.0)=f(-0.0,-0.0)=0.0$ if `rm` is not `Floor`, and $-0.0$ if it is
+    /// - $f(x,x)=0.0$ if $x$ is finite and nonzero and `rm` is not `Floor`, and $-0.0$ if it is
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.get_prec(),
+    /// other.get_prec(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero, or if `rm` is `Exact` but the difference cannot be represented
+    /// exactly with the given precision.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    /// use std::f64::consts::{E, PI};
+    ///
+    /// let (difference, o) =
+    ///     Float::from(PI).sub_prec_round_ref_ref(&Float::from(E), 5, RoundingMode::Floor);
+    /// assert_eq!(difference.to_string(), "0.42");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (difference, o) =
+    ///     Float::from(PI).sub_prec_round_ref_ref(&Float::from(E), 5, RoundingMode::Ceiling);
+    /// assert_eq!(difference.to_string(), "0.44");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (difference, o) =
+    ///     Float::from(PI).sub_prec_round_ref_ref(&Float::from(E), 5, RoundingMode::Nearest);
+    /// assert_eq!(difference.to_string(), "0.42");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (difference, o) =
+    ///     Float::from(PI).sub_prec_round_ref_ref(&Float::from(E), 20, RoundingMode::Nearest);
+    /// assert_eq!(difference.to_string(), "0.4233108");
+    /// assert_eq!(o, Ordering::Less);
+    /// ```
+    pub fn sub_prec_round_ref_ref(
+        &self,
+        other: &Float,
+        prec: u64,
+        rm: RoundingMode,
+    ) -> (Float, Ordering) {
+        add_helper(self, other, true, prec, rm)
+    }
+
+    /// Subtracts a [`Float`] by another [`Float`], rounding the result to the nearest value of the
+    /// specified precision. Both [`Float`]s are taken by value. An [`Ordering`] is also returned,
+    /// indicating whether the rounded difference is less than, equal to, or greater than the exact
+    /// difference. Although `NaN`s are not comparable to any [`Float`], whenever this function
+    /// returns a `NaN` it also returns `Equal`.
+    ///
+    /// If the difference is equidistant from two [`Float`]s with the specified precision, the
+    /// [`Float`] with fewer 1s in its binary expansion is chosen. See
+    /// [`RoundingMode`](malachite_base::rounding_modes::RoundingMode) for a description of the
+    /// `Nearest` rounding mode.
+    ///
+    /// $$
+    /// f(x,y,p) = x-y+\varepsilon.
+    /// $$
+    /// - If $x-y$ is infinite, zero, or NaN, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $x-y$ is finite and nonzero, then $|\varepsilon| \leq 2^{\lfloor\log_2
+    ///   |x-y|\rfloor-p}$.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},x)=f(x,\text{NaN})=f(\infty,\infty)=f(-\infty,-\infty)=\text{NaN}$
+    /// - $f(\infty,x)=f(x,-\infty)=\infty$ if $x$ is not NaN or $\infty$
+    /// - $f(-\infty,x)=f(x,\infty)=-\infty$ if $x$ is not NaN or $-\infty$
+    /// - $f(0.0,-0.0)=0.0$
+    /// - $f(-0.0,0.0)=-0.0$
+    /// - $f(0.0,0.0)=f(-0.0,-0.0)=0.0$ if `rm` is not `Floor`, and $-0.0$ if it is
+    /// - $f(x,x)=0.0$ if $x$ is finite and nonzero and `rm` is not `Floor`, and $-0.0$ if it is
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.get_prec(),
+    /// other.get_prec(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    /// use std::f64::consts::{E, PI};
+    ///
+    /// let (difference, o) = Float::from(PI).sub_prec(Float::from(E), 5);
+    /// assert_eq!(difference.to_string(), "0.42");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (difference, o) = Float::from(PI).sub_prec(Float::from(E), 20);
+    /// assert_eq!(difference.to_string(), "0.4233108");
+    /// assert_eq!(o, Ordering::Less);
+    /// ```
+    #[inline]
+    pub fn sub_prec(self, other: Float, prec: u64) -> (Float, Ordering) {
+        self.sub_prec_round(other, prec, RoundingMode::Nearest)
+    }
+
+    /// Subtracts a [`Float`] by another [`Float`], rounding the result to the nearest value of the
+    /// specified precision. Both [`Float`]s are taken by reference. An [`Ordering`] is also
+    /// returned, indicating whether the rounded difference is less than, equal to, or greater than
+    /// the exact difference. Although `NaN`s are not comparable to any [`Float`], whenever this
+    /// function returns a `NaN` it also returns `Equal`.
+    ///
+    /// If the difference is equidistant from two [`Float`]s with the specified precision, the
+    /// [`Float`] with fewer 1s in its binary expansion is chosen. See
+    /// [`RoundingMode`](malachite_base::rounding_modes::RoundingMode) for a description of the
+    /// `Nearest` rounding mode.
+    ///
+    /// $$
+    /// f(x,y,p) = x-y+\varepsilon.
+    /// $$
+    /// - If $x-y$ is infinite, zero, or NaN, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $x-y$ is finite and nonzero, then $|\varepsilon| \leq 2^{\lfloor\log_2
+    ///   |x-y|\rfloor-p}$.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},x)=f(x,\text{NaN})=f(\infty,\infty)=f(-\infty,-\infty)=\text{NaN}$
+    /// - $f(\infty,x)=f(x,-\infty)=\infty$ if $x$ is not NaN or $\infty$
+    /// - $f(-\infty,x)=f(x,\infty)=-\infty$ if $x$ is not NaN or $-\infty$
+    /// - $f(0.0,-0.0)=0.0$
+    /// - $f(-0.0,0.0)=-0.0$
+    /// - $f(0.0,0.0)=f(-0.0,-0.0)=0.0$ if `rm` is not `Floor`, and $-0.0$ if it is
+    /// - $f(x,x)=0.0$ if $x$ is finite and nonzero and `rm` is not `Floor`, and $-0.0$ if it is
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.get_prec(),
+    /// other.get_prec(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    /// use std::f64::consts::{E, PI};
+    ///
+    /// let (difference, o) = Float::from(PI).sub_prec_ref_ref(&Float::from(E), 5);
+    /// assert_eq!(difference.to_string(), "0.42");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (difference, o) = Float::from(PI).sub_prec_ref_ref(&Float::from(E), 20);
+    /// assert_eq!(difference.to_string(), "0.4233108");
+    /// assert_eq!(o, Ordering::Less);
+    /// ```
+    #[inline]
+    pub fn sub_prec_ref_ref(&self, other: &Float, prec: u64) -> (Float, Ordering) {
+        self.sub_prec_round_ref_ref(other, prec, RoundingMode::Nearest)
+    }
+
+    /// Subtracts a [`Float`] by another [`Float`], rounding the result with the specified rounding
+    /// mode. Both [`Float`]s are taken by value. An [`Ordering`] is also returned, indicating
+    /// whether the rounded difference is less than, equal to, or greater than the exact difference.
+    /// Although `NaN`s are not comparable to any [`Float`], whenever this function returns a `NaN`
+    /// it also returns `Equal`.
+    ///
+    /// The precision of the output is the maximum of the precision of the inputs. See
+    /// [`RoundingMode`](malachite_base::rounding_modes::RoundingMode) for a description of the
+    /// possible rounding modes.
+    ///
+    /// $$
+    /// f(x,y,m) = x-y+\varepsilon.
+    /// $$
+    /// - If $x-y$ is infinite, zero, or NaN, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $x-y$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |x-y|\rfloor-p+1}$, where $p$ is the maximum precision of the inputs.
+    /// - If $x-y$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| \leq
+    ///   2^{\lfloor\log_2 |x-y|\rfloor-p}$, where $p$ is the maximum precision of the inputs.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},x)=f(x,\text{NaN})=f(\infty,\infty)=f(-\infty,-\infty)=\text{NaN}$
+    /// - $f(\infty,x)=f(x,-\infty)=\infty$ if $x$ is not NaN or $\infty$
+    /// - $f(-\infty,x)=f(x,\infty)=-\infty$ if $x$ is not NaN or $-\infty$
+    /// - $f(0.0,-0.0)=0.0$
+    /// - $f(-0.0,0.0)=-0.0$
+    /// - $f(0.0,0.0)=f(-0.0,-0.0)=0.0$ if `rm` is not `Floor`, and $-0.0$ if it is
+    /// - $f(x,x)=0.0$ if $x$ is finite and nonzero and `rm` is not `Floor`, and $-0.0$ if it is
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.get_prec(),
+    /// other.get_prec())`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the difference cannot be represented exactly with the maximum
+    /// precision of the inputs.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    /// use std::f64::consts::{E, PI};
+    ///
+    /// let (difference, o) = Float::from(PI).sub_round(Float::from(E), RoundingMode::Floor);
+    /// assert_eq!(difference.to_string(), "0.423310825130748");
+    /// assert_eq!(o, Ordering::Equal);
+    ///
+    /// let (difference, o) = Float::from(PI).sub_round(Float::from(E), RoundingMode::Ceiling);
+    /// assert_eq!(difference.to_string(), "0.423310825130748");
+    /// assert_eq!(o, Ordering::Equal);
+    ///
+    /// let (difference, o) = Float::from(PI).sub_round(Float::from(E), RoundingMode::Nearest);
+    /// assert_eq!(difference.to_string(), "0.423310825130748");
+    /// assert_eq!(o, Ordering::Equal);
+    /// ```
+    #[inline]
+    #[allow(clippy::needless_pass_by_value)]
+    pub fn sub_round(self, other: Float, rm: RoundingMode) -> (Float, Ordering) {
+        self.sub_round_ref_ref(&other, rm)
+    }
+
+    /// Subtracts a [`Float`] by another [`Float`], rounding the result with the specified rounding
+    /// mode. Both [`Float`]s are taken by reference. An [`Ordering`] is also returned, indicating
+    /// whether the rounded difference is less than, equal to, or greater than the exact difference.
+    /// Although `NaN`s are not comparable to any [`Float`], whenever this function returns a `NaN`
+    /// it also returns `Equal`.
+    ///
+    /// The precision of the output is the maximum of the precision of the inputs. See
+    /// [`RoundingMode`](malachite_base::rounding_modes::RoundingMode) for a description of the
+    /// possible rounding modes.
+    ///
+    /// $$
+    /// f(x,y,m) = x-y+\varepsilon.
+    /// $$
+    /// - If $x-y$ is infinite, zero, or NaN, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $x-y$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |x-y|\rfloor-p+1}$, where $p$ is the maximum precision of the inputs.
+    /// - If $x-y$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| \leq
+    ///   2^{\lfloor\log_2 |x-y|\rfloor-p}$, where $p$ is the maximum precision of the inputs.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},x)=f(x,\text{NaN})=f(\infty,\infty)=f(-\infty,-\infty)=\text{NaN}$
+    /// - $f(\infty,x)=f(x,-\infty)=\infty$ if $x$ is not NaN or $\infty$
+    /// - $f(-\infty,x)=f(x,\infty)=-\infty$ if $x$ is not NaN or $-\infty$
+    /// - $f(0.0,-0.0)=0.0$
+    /// - $f(-0.0,0.0)=-0.0$
+    /// - $f(0.0,0.0)=f(-0.0,-0.0)=0.0$ if `rm` is not `Floor`, and $-0.0$ if it is
+    /// - $f(x,x)=0.0$ if $x$ is finite and nonzero and `rm` is not `Floor`, and $-0.0$ if it is
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.get_prec(),
+    /// other.get_prec())`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the difference cannot be represented exactly with the maximum
+    /// precision of the inputs.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    /// use std::f64::consts::{E, PI};
+    ///
+    /// let (difference, o) =
+    ///     Float::from(PI).sub_round_ref_ref(&Float::from(E), RoundingMode::Floor);
+    /// assert_eq!(difference.to_string(), "0.423310825130748");
+    /// assert_eq!(o, Ordering::Equal);
+    ///
+    /// let (difference, o) =
+    ///     Float::from(PI).sub_round_ref_ref(&Float::from(E), RoundingMode::Ceiling);
+    /// assert_eq!(difference.to_string(), "0.423310825130748");
+    /// assert_eq!(o, Ordering::Equal);
+    ///
+    /// let (difference, o) =
+    ///     Float::from(PI).sub_round_ref_ref(&Float::from(E), RoundingMode::Nearest);
+    /// assert_eq!(difference.to_string(), "0.423310825130748");
+    /// assert_eq!(o, Ordering::Equal);
+    /// ```
+    pub fn sub_round_ref_ref(&self, other: &Float, rm: RoundingMode) -> (Float, Ordering) {
+        let prec = max_prec(self, other);
+        self.sub_prec_round_ref_ref(other, prec, rm)
+    }
+}
+
+impl Sub<Float> for Float {
+    type Output = Float;
+
+    /// Subtracts a [`Float`] by another [`Float`], taking both by value.
+    ///
+    /// If the output has a precision, it is the maximum of the precisions of the inputs. If the
+    /// difference is equidistant from two [`Float`]s with that precision, the [`Float`] with fewer
+    /// 1s in its binary expansion is chosen. See
+    /// [`RoundingMode`](malachite_base::rounding_modes::RoundingMode) for a description of the
+    /// `Nearest` rounding mode.
+    ///
+    /// $$
+    /// f(x,y) = x-y+\varepsilon.
+    /// $$
+    /// - If $x-y$ is infinite, zero, or NaN, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $x-y$ is finite and nonzero, then $|\varepsilon| \leq 2^{\lfloor\log_2
+    ///   |x-y|\rfloor-p}$, where $p$ is the maximum precision of the inputs.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},x)=f(x,\text{NaN})=f(\infty,\infty)=f(-\infty,-\infty)=\text{NaN}$
+    /// - $f(\infty,x)=f(x,-\infty)=\infty$ if $x$ is not NaN or $\infty$
+    /// - $f(-\infty,x)=f(x,\infty)=-\infty$ if $x$ is not NaN or $-\infty$
+    /// - $f(0.0,-0.0)=0.0$
+    /// - $f(-0.0,0.0)=-0.0$
+    /// - $f(0.0,0.0)=f(-0.0,-0.0)=0.0$ if `rm` is not `Floor`, and $-0.0$ if it is
+    /// - $f(x,x)=0.0$ if $x$ is finite and nonzero and `rm` is not `Floor`, and $-0.0$ if it is
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.get_prec(),
+    /// other.get_prec())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::{NegativeOne, One, Zero};
+    /// use malachite_float::Float;
+    /// use std::f64::consts::{E, PI};
+    ///
+    /// assert!((Float::NAN - Float::ONE).is_nan());
+    /// assert_eq!((Float::INFINITY - Float::ONE).to_string(), "Infinity");
+    /// assert!((Float::INFINITY - Float::INFINITY).is_nan());
+    /// assert_eq!((Float::NEGATIVE_ZERO - Float::ZERO).to_string(), "-0.0");
+    /// assert_eq!((Float::ONE - Float::ONE).to_string(), "0.0");
+    /// assert_eq!((Float::from(PI) - Float::from(E)).to_string(), "0.423310825130748");
+    /// assert_eq!((Float::from(PI) - Float::from(-E)).to_string(), "5.859874482048838");
+    /// ```
+    #[inline]
+    fn sub(self, other: Float) -> Float {
+        self.sub_round_ref_ref(&other, RoundingMode::Nearest).0
+    }
+}
+
+impl<'a> Sub<&'a Float> for Float {
+    type Output = Float;
+
+    /// Subtracts a [`Float`] by another [`Float`], taking the first by value and the second by
+    /// reference.
+    ///
+    /// If the output has a precision, it is the maximum of the precisions of the inputs. If the
+    /// difference is equidistant from two [`Float`]s with that precision, the [`Float`] with fewer
+    /// 1s in its binary expansion is chosen. See
+    /// [`RoundingMode`](malachite_base::rounding_modes::RoundingMode) for a description of the
+    /// `Nearest` rounding mode.
+    ///
+    /// $$
+    /// f(x,y) = x-y+\varepsilon.
+    /// $$
+    /// - If $x-y$ is infinite, zero, or NaN, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $x-y$ is finite and nonzero, then $|\varepsilon| \leq 2^{\lfloor\log_2
+    ///   |x-y|\rfloor-p}$, where $p$ is the maximum precision of the inputs.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},x)=f(x,\text{NaN})=f(\infty,\infty)=f(-\infty,-\infty)=\text{NaN}$
+    /// - $f(\infty,x)=f(x,-\infty)=\infty$ if $x$ is not NaN or $\infty$
+    /// - $f(-\infty,x)=f(x,\infty)=-\infty$ if $x$ is not NaN or $-\infty$
+    /// - $f(0.0,-0.0)=0.0$
+    /// - $f(-0.0,0.0)=-0.0$
+    /// - $f(0.0,0.0)=f(-0.0,-0.0)=0.0$ if `rm` is not `Floor`, and $-0.0$ if it is
+    /// - $f(x,x)=0.0$ if $x$ is finite and nonzero and `rm` is not `Floor`, and $-0.0$ if it is
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.get_prec(),
+    /// other.get_prec())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::{NegativeOne, One, Zero};
+    /// use malachite_float::Float;
+    /// use std::f64::consts::{E, PI};
+    ///
+    /// assert!((Float::NAN - &Float::ONE).is_nan());
+    /// assert_eq!((Float::INFINITY - &Float::ONE).to_string(), "Infinity");
+    /// assert!((Float::INFINITY - &Float::INFINITY).is_nan());
+    /// assert_eq!((Float::NEGATIVE_ZERO - &Float::ZERO).to_string(), "-0.0");
+    /// assert_eq!((Float::ONE - &Float::ONE).to_string(), "0.0");
+    /// assert_eq!((Float::from(PI) - &Float::from(E)).to_string(), "0.423310825130748");
+    /// assert_eq!((Float::from(PI) - &Float::from(-E)).to_string(), "5.859874482048838");
+    /// ```
+    #[inline]
+    fn sub(self, other: &'a Float) -> Float {
+        self.sub_round_ref_ref(other, RoundingMode::Nearest).0
+    }
+}
+
+impl<'a> Sub<Float> for &'a Float {
+    type Output = Float;
+
+    /// Subtracts a [`Float`] by another [`Float`], taking the first by reference and the second by
+    /// value.
+    ///
+    /// If the output has a precision, it is the maximum of the precisions of the inputs. If the
+    /// difference is equidistant from two [`Float`]s with that precision, the [`Float`] with fewer
+    /// 1s in its binary expansion is chosen. See
+    /// [`RoundingMode`](malachite_base::rounding_modes::RoundingMode) for a description of the
+    /// `Nearest` rounding mode.
+    ///
+    /// $$
+    /// f(x,y) = x-y+\varepsilon.
+    /// $$
+    /// - If $x-y$ is infinite, zero, or NaN, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $x-y$ is finite and nonzero, then $|\varepsilon| \leq 2^{\lfloor\log_2
+    ///   |x-y|\rfloor-p}$, where $p$ is the maximum precision of the inputs.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},x)=f(x,\text{NaN})=f(\infty,\infty)=f(-\infty,-\infty)=\text{NaN}$
+    /// - $f(\infty,x)=f(x,-\infty)=\infty$ if $x$ is not NaN or $\infty$
+    /// - $f(-\infty,x)=f(x,\infty)=-\infty$ if $x$ is not NaN or $-\infty$
+    /// - $f(0.0,-0.0)=0.0$
+    /// - $f(-0.0,0.0)=-0.0$
+    /// - $f(0.0,0.0)=f(-0.0,-0.0)=0.0$ if `rm` is not `Floor`, and $-0.0$ if it is
+    /// - $f(x,x)=0.0$ if $x$ is finite and nonzero and `rm` is not `Floor`, and $-0.0$ if it is
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.get_prec(),
+    /// other.get_prec())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::{NegativeOne, One, Zero};
+    /// use malachite_float::Float;
+    /// use std::f64::consts::{E, PI};
+    ///
+    /// assert!((&Float::NAN - Float::ONE).is_nan());
+    /// assert_eq!((&Float::INFINITY - Float::ONE).to_string(), "Infinity");
+    /// assert!((&Float::INFINITY - Float::INFINITY).is_nan());
+    /// assert_eq!((&Float::NEGATIVE_ZERO - Float::ZERO).to_string(), "-0.0");
+    /// assert_eq!((&Float::ONE - Float::ONE).to_string(), "0.0");
+    /// assert_eq!((&Float::from(PI) - Float::from(E)).to_string(), "0.423310825130748");
+    /// assert_eq!((&Float::from(PI) - Float::from(-E)).to_string(), "5.859874482048838");
+    /// ```
+    #[inline]
+    fn sub(self, other: Float) -> Float {
+        self.sub_round_ref_ref(&other, RoundingMode::Nearest).0
+    }
+}
+
+impl<'a, 'b> Sub<&'a Float> for &'b Float {
+    type Output = Float;
+
+    /// Subtracts a [`Float`] by another [`Float`], taking both by reference.
+    ///
+    /// If the output has a precision, it is the maximum of the precisions of the inputs. If the
+    /// difference is equidistant from two [`Float`]s with that precision, the [`Float`] with fewer
+    /// 1s in its binary expansion is chosen. See
+    /// [`RoundingMode`](malachite_base::rounding_modes::RoundingMode) for a description of the
+    /// `Nearest` rounding mode.
+    ///
+    /// $$
+    /// f(x,y) = x-y+\varepsilon.
+    /// $$
+    /// - If $x-y$ is infinite, zero, or NaN, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $x-y$ is finite and nonzero, then $|\varepsilon| \leq 2^{\lfloor\log_2
+    ///   |x-y|\rfloor-p}$, where $p$ is the maximum precision of the inputs.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},x)=f(x,\text{NaN})=f(\infty,\infty)=f(-\infty,-\infty)=\text{NaN}$
+    /// - $f(\infty,x)=f(x,-\infty)=\infty$ if $x$ is not NaN or $\infty$
+    /// - $f(-\infty,x)=f(x,\infty)=-\infty$ if $x$ is not NaN or $-\infty$
+    /// - $f(0.0,-0.0)=0.0$
+    /// - $f(-0.0,0.0)=-0.0$
+    /// - $f(0.0,0.0)=f(-0.0,-0.0)=0.0$ if `rm` is not `Floor`, and $-0.0$ if it is
+    /// - $f(x,x)=0.0$ if $x$ is finite and nonzero and `rm` is not `Floor`, and $-0.0$ if it is
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.get_prec(),
+    /// other.get_prec())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::{NegativeOne, One, Zero};
+    /// use malachite_float::Float;
+    /// use std::f64::consts::{E, PI};
+    ///
+    /// assert!((&Float::NAN - &Float::ONE).is_nan());
+    /// assert_eq!((&Float::INFINITY - &Float::ONE).to_string(), "Infinity");
+    /// assert!((&Float::INFINITY - &Float::INFINITY).is_nan());
+    /// assert_eq!((&Float::NEGATIVE_ZERO - &Float::ZERO).to_string(), "-0.0");
+    /// assert_eq!((&Float::ONE - &Float::ONE).to_string(), "0.0");
+    /// assert_eq!((&Float::from(PI) - &Float::from(E)).to_string(), "0.423310825130748");
+    /// assert_eq!((&Float::from(PI) - &Float::from(-E)).to_string(), "5.859874482048838");
+    /// ```
+    #[inline]
+    fn sub(self, other: &'a Float) -> Float {
+        self.sub_round_ref_ref(other, RoundingMode::Nearest).0
+    }
+}
+
+impl SubAssign<Float> for Float {
+    /// Subtracts a [`Float`] by a [`Float`] in place, taking the [`Float`] on the right-hand side
+    /// by value.
+    ///
+    /// If the output has a precision, it is the maximum of the precisions of the inputs. If the
+    /// difference is equidistant from two [`Float`]s with that precision, the [`Float`] with fewer
+    /// 1s in its binary expansion is chosen. See
+    /// [`RoundingMode`](malachite_base::rounding_modes::RoundingMode) for a description of the
+    /// `Nearest` rounding mode.
+    ///
+    /// $$
+    /// x \gets x - y+\varepsilon.
+    /// $$
+    /// - If $x-y$ is infinite, zero, or NaN, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $x-y$ is finite and nonzero, then $|\varepsilon| \leq 2^{\lfloor\log_2
+    ///   |x-y|\rfloor-p}$, where $p$ is the maximum precision of the inputs.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},x)=f(x,\text{NaN})=f(\infty,\infty)=f(-\infty,-\infty)=\text{NaN}$
+    /// - $f(\infty,x)=f(x,-\infty)=\infty$ if $x$ is not NaN or $\infty$
+    /// - $f(-\infty,x)=f(x,\infty)=-\infty$ if $x$ is not NaN or $-\infty$
+    /// - $f(0.0,-0.0)=0.0$
+    /// - $f(-0.0,0.0)=-0.0$
+    /// - $f(0.0,0.0)=f(-0.0,-0.0)=0.0$ if `rm` is not `Floor`, and $-0.0$ if it is
+    /// - $f(x,x)=0.0$ if $x$ is finite and nonzero and `rm` is not `Floor`, and $-0.0$ if it is
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.get_prec(),
+    /// other.get_prec())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::Float;
+    /// use std::f64::consts::{E, PI};
+    ///
+    /// let mut x = Float::from(PI);
+    /// x -= Float::from(E);
+    /// assert_eq!(x.to_string(), "0.423310825130748");
+    ///
+    /// let mut x = Float::from(PI);
+    /// x -= Float::from(-E);
+    /// assert_eq!(x.to_string(), "5.859874482048838");
+    /// ```
+    #[inline]
+    fn sub_assign(&mut self, other: Float) {
+        *self = self.sub_round_ref_ref(&other, RoundingMode::Nearest).0;
+    }
+}
+
+impl<'a> SubAssign<&'a Float> for Float {
+    /// Subtracts a [`Float`] by a [`Float`] in place, taking the [`Float`] on the right-hand side
+    /// by reference.
+    ///
+    /// If the output has a precision, it is the maximum of the precisions of the inputs. If the
+    /// difference is equidistant from two [`Float`]s with that precision, the [`Float`] with fewer
+    /// 1s in its binary expansion is chosen. See
+    /// [`RoundingMode`](malachite_base::rounding_modes::RoundingMode) for a description of the
+    /// `Nearest` rounding mode.
+    ///
+    /// $$
+    /// x \gets x - y+\varepsilon.
+    /// $$
+    /// - If $x-y$ is infinite, zero, or NaN, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $x-y$ is finite and nonzero, then $|\varepsilon| \leq 2^{\lfloor\log_2
+    ///   |x-y|\rfloor-p}$, where $p$ is the maximum precision of the inputs.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},x)=f(x,\text{NaN})=f(\infty,\infty)=f(-\infty,-\infty)=\text{NaN}$
+    /// - $f(\infty,x)=f(x,-\infty)=\infty$ if $x$ is not NaN or $\infty$
+    /// - $f(-\infty,x)=f(x,\infty)=-\infty$ if $x$ is not NaN or $-\infty$
+    /// - $f(0.0,-0.0)=0.0$
+    /// - $f(-0.0,0.0)=-0.0$
+    /// - $f(0.0,0.0)=f(-0.0,-0.0)=0.0$ if `rm` is not `Floor`, and $-0.0$ if it is
+    /// - $f(x,x)=0.0$ if $x$ is finite and nonzero and `rm` is not `Floor`, and $-0.0$ if it is
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.get_prec(),
+    /// other.get_prec())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::Float;
+    /// use std::f64::consts::{E, PI};
+    ///
+    /// let mut x = Float::from(PI);
+    /// x -= &Float::from(E);
+    /// assert_eq!(x.to_string(), "0.423310825130748");
+    ///
+    /// let mut x = Float::from(PI);
+    /// x -= &Float::from(-E);
+    /// assert_eq!(x.to_string(), "5.859874482048838");
+    /// ```
+    #[inline]
+    fn sub_assign(&mut self, other: &'a Float) {
+        *self = self.sub_round_ref_ref(other, RoundingMode::Nearest).0;
+    }
+}
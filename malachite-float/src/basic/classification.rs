@@ -0,0 +1,217 @@
+use crate::{Float, InnerFloat};
+use malachite_nz::natural::Natural;
+
+impl Float {
+    /// Determines whether a [`Float`] is NaN.
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::{One, Zero};
+    /// use malachite_float::Float;
+    ///
+    /// assert_eq!(Float::NAN.is_nan(), true);
+    /// assert_eq!(Float::INFINITY.is_nan(), false);
+    /// assert_eq!(Float::ZERO.is_nan(), false);
+    /// assert_eq!(Float::ONE.is_nan(), false);
+    /// ```
+    pub const fn is_nan(&self) -> bool {
+        matches!(self, Float(InnerFloat::NaN))
+    }
+
+    /// Determines whether a [`Float`] is positive or negative infinity.
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::{One, Zero};
+    /// use malachite_float::Float;
+    ///
+    /// assert_eq!(Float::NAN.is_infinite(), false);
+    /// assert_eq!(Float::INFINITY.is_infinite(), true);
+    /// assert_eq!(Float::NEGATIVE_INFINITY.is_infinite(), true);
+    /// assert_eq!(Float::ZERO.is_infinite(), false);
+    /// assert_eq!(Float::ONE.is_infinite(), false);
+    /// ```
+    pub const fn is_infinite(&self) -> bool {
+        matches!(self, Float(InnerFloat::Infinity { .. }))
+    }
+
+    /// Determines whether a [`Float`] is finite; that is, whether it is neither NaN nor infinite.
+    /// Both zeros are finite.
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::{One, Zero};
+    /// use malachite_float::Float;
+    ///
+    /// assert_eq!(Float::NAN.is_finite(), false);
+    /// assert_eq!(Float::INFINITY.is_finite(), false);
+    /// assert_eq!(Float::ZERO.is_finite(), true);
+    /// assert_eq!(Float::ONE.is_finite(), true);
+    /// ```
+    pub const fn is_finite(&self) -> bool {
+        matches!(
+            self,
+            Float(InnerFloat::Zero { .. } | InnerFloat::Finite { .. })
+        )
+    }
+
+    /// Determines whether a [`Float`] is positive or negative zero.
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::{One, Zero};
+    /// use malachite_float::Float;
+    ///
+    /// assert_eq!(Float::NAN.is_zero(), false);
+    /// assert_eq!(Float::ZERO.is_zero(), true);
+    /// assert_eq!(Float::NEGATIVE_ZERO.is_zero(), true);
+    /// assert_eq!(Float::ONE.is_zero(), false);
+    /// ```
+    pub const fn is_zero(&self) -> bool {
+        matches!(self, Float(InnerFloat::Zero { .. }))
+    }
+
+    /// Determines whether a [`Float`] has a positive sign. This is true for positive zero and
+    /// positive infinity, and false for NaN.
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::{NegativeOne, One, Zero};
+    /// use malachite_float::Float;
+    ///
+    /// assert_eq!(Float::NAN.is_sign_positive(), false);
+    /// assert_eq!(Float::INFINITY.is_sign_positive(), true);
+    /// assert_eq!(Float::ZERO.is_sign_positive(), true);
+    /// assert_eq!(Float::NEGATIVE_ZERO.is_sign_positive(), false);
+    /// assert_eq!(Float::ONE.is_sign_positive(), true);
+    /// assert_eq!(Float::NEGATIVE_ONE.is_sign_positive(), false);
+    /// ```
+    pub const fn is_sign_positive(&self) -> bool {
+        match self {
+            Float(
+                InnerFloat::Infinity { sign }
+                | InnerFloat::Zero { sign }
+                | InnerFloat::Finite { sign, .. },
+            ) => *sign,
+            Float(InnerFloat::NaN) => false,
+        }
+    }
+
+    /// Determines whether a [`Float`] has a negative sign. This is true for negative zero and
+    /// negative infinity, and false for NaN.
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::{NegativeOne, One, Zero};
+    /// use malachite_float::Float;
+    ///
+    /// assert_eq!(Float::NAN.is_sign_negative(), false);
+    /// assert_eq!(Float::NEGATIVE_INFINITY.is_sign_negative(), true);
+    /// assert_eq!(Float::ZERO.is_sign_negative(), false);
+    /// assert_eq!(Float::NEGATIVE_ZERO.is_sign_negative(), true);
+    /// assert_eq!(Float::ONE.is_sign_negative(), false);
+    /// assert_eq!(Float::NEGATIVE_ONE.is_sign_negative(), true);
+    /// ```
+    pub const fn is_sign_negative(&self) -> bool {
+        match self {
+            Float(
+                InnerFloat::Infinity { sign }
+                | InnerFloat::Zero { sign }
+                | InnerFloat::Finite { sign, .. },
+            ) => !*sign,
+            Float(InnerFloat::NaN) => false,
+        }
+    }
+
+    /// Returns the precision of a [`Float`]: the number of bits in its significand. NaN,
+    /// infinities, and zeros have no precision, so `None` is returned for them.
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::{One, Zero};
+    /// use malachite_float::Float;
+    ///
+    /// assert_eq!(Float::NAN.get_prec(), None);
+    /// assert_eq!(Float::ZERO.get_prec(), None);
+    /// assert_eq!(Float::ONE.get_prec(), Some(1));
+    /// assert_eq!(Float::from(1.5f64).get_prec(), Some(2));
+    /// ```
+    pub const fn get_prec(&self) -> Option<u64> {
+        match self {
+            Float(InnerFloat::Finite { precision, .. }) => Some(*precision),
+            _ => None,
+        }
+    }
+
+    /// Returns the exponent of a [`Float`]. If the [`Float`] is finite and nonzero, its absolute
+    /// value $x$ satisfies $2^{e-1} \leq x < 2^e$, where $e$ is the exponent. NaN, infinities,
+    /// and zeros have no exponent, so `None` is returned for them.
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::{One, Zero};
+    /// use malachite_float::Float;
+    ///
+    /// assert_eq!(Float::NAN.get_exponent(), None);
+    /// assert_eq!(Float::ZERO.get_exponent(), None);
+    /// assert_eq!(Float::ONE.get_exponent(), Some(1));
+    /// assert_eq!(Float::from(0.75f64).get_exponent(), Some(0));
+    /// assert_eq!(Float::from(-100.0f64).get_exponent(), Some(7));
+    /// ```
+    pub const fn get_exponent(&self) -> Option<i64> {
+        match self {
+            Float(InnerFloat::Finite { exponent, .. }) => Some(*exponent),
+            _ => None,
+        }
+    }
+
+    /// Returns a reference to the significand of a [`Float`]. If the [`Float`] is finite and
+    /// nonzero, its significand is a [`Natural`] $m$ with exactly $p$ significant bits, where $p$
+    /// is the precision, and its absolute value is $m2^{e-p}$, where $e$ is the exponent. NaN,
+    /// infinities, and zeros have no significand, so `None` is returned for them.
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::{One, Zero};
+    /// use malachite_float::Float;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!(Float::NAN.significand_ref(), None);
+    /// assert_eq!(Float::ZERO.significand_ref(), None);
+    /// assert_eq!(Float::ONE.significand_ref(), Some(&Natural::from(1u32)));
+    /// assert_eq!(Float::from(-0.375f64).significand_ref(), Some(&Natural::from(3u32)));
+    /// ```
+    pub const fn significand_ref(&self) -> Option<&Natural> {
+        match self {
+            Float(InnerFloat::Finite { significand, .. }) => Some(significand),
+            _ => None,
+        }
+    }
+}
@@ -0,0 +1,3 @@
+/// Functions for determining whether a [`Float`](crate::Float) is NaN, infinite, zero, or finite,
+/// and for getting its sign, precision, exponent, and significand.
+pub mod classification;
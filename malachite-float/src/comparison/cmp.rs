@@ -0,0 +1,81 @@
+use crate::{Float, InnerFloat};
+use std::cmp::Ordering;
+
+impl PartialOrd for Float {
+    /// Compares two [`Float`]s.
+    ///
+    /// This function follows the IEEE 754 standard: NaN is not comparable to anything, not even
+    /// itself, and positive zero is equal to negative zero. The precisions of finite [`Float`]s
+    /// are ignored; only their numerical values are compared.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(1)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.get_prec(),
+    /// other.get_prec())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::{NegativeOne, One, Zero};
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    ///
+    /// assert_eq!(Float::NAN.partial_cmp(&Float::NAN), None);
+    /// assert_eq!(Float::ZERO.partial_cmp(&Float::NEGATIVE_ZERO), Some(Ordering::Equal));
+    /// assert!(Float::NEGATIVE_INFINITY < Float::NEGATIVE_ONE);
+    /// assert!(Float::NEGATIVE_ONE < Float::ZERO);
+    /// assert!(Float::ONE < Float::from(1.5f64));
+    /// assert!(Float::from(1.5f64) < Float::INFINITY);
+    /// assert!(Float::from(-1.5f64) < Float::from(-1.25f64));
+    /// ```
+    fn partial_cmp(&self, other: &Float) -> Option<Ordering> {
+        match (&self.0, &other.0) {
+            (InnerFloat::NaN, _) | (_, InnerFloat::NaN) => None,
+            (InnerFloat::Infinity { sign: x }, InnerFloat::Infinity { sign: y }) => Some(x.cmp(y)),
+            (InnerFloat::Infinity { sign }, _)
+            | (InnerFloat::Finite { sign, .. }, InnerFloat::Zero { .. }) => Some(if *sign {
+                Ordering::Greater
+            } else {
+                Ordering::Less
+            }),
+            (_, InnerFloat::Infinity { sign })
+            | (InnerFloat::Zero { .. }, InnerFloat::Finite { sign, .. }) => Some(if *sign {
+                Ordering::Less
+            } else {
+                Ordering::Greater
+            }),
+            (InnerFloat::Zero { .. }, InnerFloat::Zero { .. }) => Some(Ordering::Equal),
+            (
+                InnerFloat::Finite {
+                    sign: x_sign,
+                    exponent: x_exponent,
+                    precision: x_precision,
+                    significand: x_significand,
+                },
+                InnerFloat::Finite {
+                    sign: y_sign,
+                    exponent: y_exponent,
+                    precision: y_precision,
+                    significand: y_significand,
+                },
+            ) => Some(if x_sign != y_sign {
+                x_sign.cmp(y_sign)
+            } else {
+                let abs_cmp = x_exponent.cmp(y_exponent).then_with(|| {
+                    if x_precision <= y_precision {
+                        (x_significand << (y_precision - x_precision)).cmp(y_significand)
+                    } else {
+                        x_significand.cmp(&(y_significand << (x_precision - y_precision)))
+                    }
+                });
+                if *x_sign {
+                    abs_cmp
+                } else {
+                    abs_cmp.reverse()
+                }
+            }),
+        }
+    }
+}
@@ -0,0 +1,161 @@
+use crate::{ComparableFloat, Float, InnerFloat};
+use std::hash::{Hash, Hasher};
+
+impl PartialEq for Float {
+    /// Compares two [`Float`]s for equality.
+    ///
+    /// This function follows the IEEE 754 standard: NaN is not equal to anything, not even itself,
+    /// and positive zero is equal to negative zero. Two finite [`Float`]s are equal if they have
+    /// the same numerical value, even if their precisions are different. To compare [`Float`]s
+    /// structurally, use [`ComparableFloat`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(1)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.get_prec(),
+    /// other.get_prec())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::{One, Zero};
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    ///
+    /// assert_ne!(Float::NAN, Float::NAN);
+    /// assert_eq!(Float::INFINITY, Float::INFINITY);
+    /// assert_ne!(Float::INFINITY, Float::NEGATIVE_INFINITY);
+    /// assert_eq!(Float::ZERO, Float::NEGATIVE_ZERO);
+    /// assert_eq!(Float::ONE, Float::from(1.0f64));
+    /// assert_eq!(Float::ONE, Float::ONE.add_prec_round(Float::ZERO, 100, RoundingMode::Exact).0);
+    /// assert_ne!(Float::ONE, Float::from(1.5f64));
+    /// ```
+    fn eq(&self, other: &Float) -> bool {
+        match (&self.0, &other.0) {
+            (InnerFloat::Infinity { sign: x }, InnerFloat::Infinity { sign: y }) => x == y,
+            (InnerFloat::Zero { .. }, InnerFloat::Zero { .. }) => true,
+            (
+                InnerFloat::Finite {
+                    sign: x_sign,
+                    exponent: x_exponent,
+                    precision: x_precision,
+                    significand: x_significand,
+                },
+                InnerFloat::Finite {
+                    sign: y_sign,
+                    exponent: y_exponent,
+                    precision: y_precision,
+                    significand: y_significand,
+                },
+            ) => {
+                x_sign == y_sign
+                    && x_exponent == y_exponent
+                    && if x_precision <= y_precision {
+                        x_significand << (y_precision - x_precision) == *y_significand
+                    } else {
+                        y_significand << (x_precision - y_precision) == *x_significand
+                    }
+            }
+            _ => false,
+        }
+    }
+}
+
+impl PartialEq for ComparableFloat {
+    /// Compares two [`ComparableFloat`]s for equality.
+    ///
+    /// Unlike [`Float`]'s [`PartialEq`] implementation, this comparison is structural: NaN is
+    /// equal to itself, positive zero is not equal to negative zero, and two finite [`Float`]s are
+    /// only equal if they have the same value and the same precision.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(1)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `min(self.0.get_prec(),
+    /// other.0.get_prec())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::{One, Zero};
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::{ComparableFloat, Float};
+    ///
+    /// assert_eq!(ComparableFloat(Float::NAN), ComparableFloat(Float::NAN));
+    /// assert_ne!(ComparableFloat(Float::ZERO), ComparableFloat(Float::NEGATIVE_ZERO));
+    /// assert_eq!(ComparableFloat(Float::ONE), ComparableFloat(Float::from(1.0f64)));
+    /// assert_ne!(
+    ///     ComparableFloat(Float::ONE),
+    ///     ComparableFloat(Float::ONE.add_prec_round(Float::ZERO, 100, RoundingMode::Exact).0)
+    /// );
+    /// ```
+    fn eq(&self, other: &ComparableFloat) -> bool {
+        match (&self.0 .0, &other.0 .0) {
+            (InnerFloat::NaN, InnerFloat::NaN) => true,
+            (InnerFloat::Infinity { sign: x }, InnerFloat::Infinity { sign: y })
+            | (InnerFloat::Zero { sign: x }, InnerFloat::Zero { sign: y }) => x == y,
+            (
+                InnerFloat::Finite {
+                    sign: x_sign,
+                    exponent: x_exponent,
+                    precision: x_precision,
+                    significand: x_significand,
+                },
+                InnerFloat::Finite {
+                    sign: y_sign,
+                    exponent: y_exponent,
+                    precision: y_precision,
+                    significand: y_significand,
+                },
+            ) => {
+                x_sign == y_sign
+                    && x_exponent == y_exponent
+                    && x_precision == y_precision
+                    && x_significand == y_significand
+            }
+            _ => false,
+        }
+    }
+}
+
+impl Eq for ComparableFloat {}
+
+impl Hash for ComparableFloat {
+    /// Computes a hash of a [`ComparableFloat`].
+    ///
+    /// The hash is compatible with [`ComparableFloat`]'s structural equality.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(1)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.0.get_prec()`.
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        match &self.0 .0 {
+            InnerFloat::NaN => 0u8.hash(state),
+            InnerFloat::Infinity { sign } => {
+                1u8.hash(state);
+                sign.hash(state);
+            }
+            InnerFloat::Zero { sign } => {
+                2u8.hash(state);
+                sign.hash(state);
+            }
+            InnerFloat::Finite {
+                sign,
+                exponent,
+                precision,
+                significand,
+            } => {
+                3u8.hash(state);
+                sign.hash(state);
+                exponent.hash(state);
+                precision.hash(state);
+                significand.hash(state);
+            }
+        }
+    }
+}
@@ -0,0 +1,6 @@
+/// Implementations of [`PartialOrd`] for [`Float`](crate::Float)s, comparing numerical values.
+pub mod cmp;
+/// Implementations of [`PartialEq`] for [`Float`](crate::Float)s and
+/// [`ComparableFloat`](crate::ComparableFloat)s, and of [`Eq`] and [`Hash`](std::hash::Hash) for
+/// [`ComparableFloat`](crate::ComparableFloat)s.
+pub mod eq;
@@ -0,0 +1,76 @@
+use crate::conversion::from_natural_and_exponent;
+use crate::Float;
+use malachite_base::num::arithmetic::traits::UnsignedAbs;
+use malachite_base::num::basic::traits::Zero;
+use malachite_nz::integer::Integer;
+use malachite_nz::natural::Natural;
+
+impl From<Natural> for Float {
+    /// Converts a [`Natural`] to a [`Float`], taking the [`Natural`] by value.
+    ///
+    /// The conversion is exact. If the [`Natural`] is nonzero, the precision of the output is the
+    /// smallest precision that can represent it exactly; that is, its number of significant bits
+    /// minus its number of trailing zeros. Zero is converted to positive zero.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `x.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::Zero;
+    /// use malachite_float::Float;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!(Float::from(Natural::ZERO).to_string(), "0.0");
+    /// assert_eq!(Float::from(Natural::from(123u32)).to_string(), "123.0");
+    /// assert_eq!(Float::from(Natural::from(123u32)).get_prec(), Some(7));
+    /// assert_eq!(Float::from(Natural::from(1024u32)).get_prec(), Some(1));
+    /// ```
+    fn from(x: Natural) -> Float {
+        if x == 0u32 {
+            Float::ZERO
+        } else {
+            from_natural_and_exponent(true, x, 0)
+        }
+    }
+}
+
+impl From<Integer> for Float {
+    /// Converts an [`Integer`] to a [`Float`], taking the [`Integer`] by value.
+    ///
+    /// The conversion is exact. If the [`Integer`] is nonzero, the precision of the output is the
+    /// smallest precision that can represent it exactly; that is, the number of significant bits
+    /// of its absolute value minus its number of trailing zeros. Zero is converted to positive
+    /// zero.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `x.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::Zero;
+    /// use malachite_float::Float;
+    /// use malachite_nz::integer::Integer;
+    ///
+    /// assert_eq!(Float::from(Integer::ZERO).to_string(), "0.0");
+    /// assert_eq!(Float::from(Integer::from(123)).to_string(), "123.0");
+    /// assert_eq!(Float::from(Integer::from(-123)).to_string(), "-123.0");
+    /// assert_eq!(Float::from(Integer::from(-123)).get_prec(), Some(7));
+    /// ```
+    fn from(x: Integer) -> Float {
+        if x == 0u32 {
+            Float::ZERO
+        } else {
+            let sign = x > 0u32;
+            from_natural_and_exponent(sign, x.unsigned_abs(), 0)
+        }
+    }
+}
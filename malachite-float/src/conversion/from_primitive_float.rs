@@ -0,0 +1,40 @@
+use crate::conversion::from_natural_and_exponent;
+use crate::{Float, InnerFloat};
+use malachite_base::num::conversion::traits::IntegerMantissaAndExponent;
+use malachite_nz::natural::Natural;
+
+macro_rules! impl_from_primitive_float {
+    ($f: ident) => {
+        impl From<$f> for Float {
+            /// Converts a primitive float to a [`Float`].
+            ///
+            /// The conversion is exact. NaN, the infinities, and the zeros are converted to the
+            /// corresponding [`Float`] values. If the float is finite and nonzero, the precision
+            /// of the output is the smallest precision that can represent it exactly.
+            ///
+            /// # Worst-case complexity
+            /// Constant time and additional memory.
+            ///
+            /// # Examples
+            /// See [here](super::from_primitive_float#from).
+            fn from(x: $f) -> Float {
+                if x.is_nan() {
+                    Float::NAN
+                } else if x.is_infinite() {
+                    Float(InnerFloat::Infinity {
+                        sign: x.is_sign_positive(),
+                    })
+                } else if x == 0.0 {
+                    Float(InnerFloat::Zero {
+                        sign: x.is_sign_positive(),
+                    })
+                } else {
+                    let (m, e) = x.abs().integer_mantissa_and_exponent();
+                    from_natural_and_exponent(x.is_sign_positive(), Natural::from(m), e)
+                }
+            }
+        }
+    };
+}
+impl_from_primitive_float!(f32);
+impl_from_primitive_float!(f64);
@@ -0,0 +1,321 @@
+use crate::arithmetic::round_quotient;
+use crate::conversion::from_natural_and_exponent;
+use crate::Float;
+use malachite_base::num::arithmetic::traits::IsPowerOf2;
+use malachite_base::num::basic::traits::Zero;
+use malachite_base::num::conversion::traits::{ConvertibleFrom, ExactFrom};
+use malachite_base::num::logic::traits::SignificantBits;
+use malachite_base::rounding_modes::RoundingMode;
+use malachite_q::Rational;
+use std::cmp::Ordering;
+
+impl Float {
+    /// Converts a [`Rational`] to a [`Float`], taking the [`Rational`] by value and rounding to
+    /// the specified precision with the specified rounding mode. An [`Ordering`] is also
+    /// returned, indicating whether the output is less than, equal to, or greater than the
+    /// [`Rational`].
+    ///
+    /// Zero is converted to positive zero.
+    ///
+    /// $$
+    /// f(x,p,m) = x+\varepsilon.
+    /// $$
+    /// - If $x$ is zero, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $x$ is nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |x|\rfloor-p+1}$.
+    /// - If $x$ is nonzero, and $m$ is `Nearest`, then $|\varepsilon| \leq
+    ///   2^{\lfloor\log_2 |x|\rfloor-p}$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(x.significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero, or if `rm` is `Exact` but the [`Rational`] cannot be represented
+    /// exactly with the given precision.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use malachite_q::Rational;
+    /// use std::cmp::Ordering;
+    ///
+    /// let (x, o) =
+    ///     Float::from_rational_prec_round(Rational::from_signeds(1, 3), 10, RoundingMode::Floor);
+    /// assert_eq!(x.to_string(), "0.333");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from_rational_prec_round(
+    ///     Rational::from_signeds(1, 3),
+    ///     10,
+    ///     RoundingMode::Ceiling,
+    /// );
+    /// assert_eq!(x.to_string(), "0.3335");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from_rational_prec_round(
+    ///     Rational::from_signeds(-1, 3),
+    ///     10,
+    ///     RoundingMode::Ceiling,
+    /// );
+    /// assert_eq!(x.to_string(), "-0.333");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) =
+    ///     Float::from_rational_prec_round(Rational::from_signeds(3, 8), 10, RoundingMode::Exact);
+    /// assert_eq!(x.to_string(), "0.375");
+    /// assert_eq!(o, Ordering::Equal);
+    /// ```
+    #[inline]
+    #[allow(clippy::needless_pass_by_value)]
+    pub fn from_rational_prec_round(x: Rational, prec: u64, rm: RoundingMode) -> (Float, Ordering) {
+        Float::from_rational_prec_round_ref(&x, prec, rm)
+    }
+
+    /// Converts a [`Rational`] to a [`Float`], taking the [`Rational`] by reference and rounding
+    /// to the specified precision with the specified rounding mode. An [`Ordering`] is also
+    /// returned, indicating whether the output is less than, equal to, or greater than the
+    /// [`Rational`].
+    ///
+    /// Zero is converted to positive zero.
+    ///
+    /// $$
+    /// f(x,p,m) = x+\varepsilon.
+    /// $$
+    /// - If $x$ is zero, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $x$ is nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |x|\rfloor-p+1}$.
+    /// - If $x$ is nonzero, and $m$ is `Nearest`, then $|\varepsilon| \leq
+    ///   2^{\lfloor\log_2 |x|\rfloor-p}$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(x.significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero, or if `rm` is `Exact` but the [`Rational`] cannot be represented
+    /// exactly with the given precision.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use malachite_q::Rational;
+    /// use std::cmp::Ordering;
+    ///
+    /// let (x, o) = Float::from_rational_prec_round_ref(
+    ///     &Rational::from_signeds(1, 3),
+    ///     10,
+    ///     RoundingMode::Floor,
+    /// );
+    /// assert_eq!(x.to_string(), "0.333");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from_rational_prec_round_ref(
+    ///     &Rational::from_signeds(1, 3),
+    ///     10,
+    ///     RoundingMode::Ceiling,
+    /// );
+    /// assert_eq!(x.to_string(), "0.3335");
+    /// assert_eq!(o, Ordering::Greater);
+    /// ```
+    pub fn from_rational_prec_round_ref(
+        x: &Rational,
+        prec: u64,
+        rm: RoundingMode,
+    ) -> (Float, Ordering) {
+        assert_ne!(prec, 0, "Precision must be positive");
+        if *x == 0u32 {
+            (Float::ZERO, Ordering::Equal)
+        } else {
+            let (n, d) = x.to_numerator_and_denominator();
+            round_quotient(*x > 0u32, &n, &d, 0, prec, rm)
+        }
+    }
+
+    /// Converts a [`Rational`] to a [`Float`], taking the [`Rational`] by value and rounding to
+    /// the nearest value of the specified precision. An [`Ordering`] is also returned,
+    /// indicating whether the output is less than, equal to, or greater than the [`Rational`].
+    ///
+    /// If the [`Rational`] is equidistant from two [`Float`]s with the specified precision, the
+    /// [`Float`] with fewer 1s in its binary expansion is chosen. Zero is converted to positive
+    /// zero.
+    ///
+    /// $$
+    /// f(x,p) = x+\varepsilon.
+    /// $$
+    /// - If $x$ is zero, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $x$ is nonzero, then $|\varepsilon| \leq 2^{\lfloor\log_2 |x|\rfloor-p}$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(x.significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::Float;
+    /// use malachite_q::Rational;
+    /// use std::cmp::Ordering;
+    ///
+    /// let (x, o) = Float::from_rational_prec(Rational::from_signeds(1, 3), 10);
+    /// assert_eq!(x.to_string(), "0.3335");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from_rational_prec(Rational::from_signeds(1, 3), 100);
+    /// assert_eq!(x.to_string(), "0.3333333333333333333333333333335");
+    /// assert_eq!(o, Ordering::Greater);
+    /// ```
+    #[inline]
+    #[allow(clippy::needless_pass_by_value)]
+    pub fn from_rational_prec(x: Rational, prec: u64) -> (Float, Ordering) {
+        Float::from_rational_prec_round_ref(&x, prec, RoundingMode::Nearest)
+    }
+
+    /// Converts a [`Rational`] to a [`Float`], taking the [`Rational`] by reference and rounding
+    /// to the nearest value of the specified precision. An [`Ordering`] is also returned,
+    /// indicating whether the output is less than, equal to, or greater than the [`Rational`].
+    ///
+    /// If the [`Rational`] is equidistant from two [`Float`]s with the specified precision, the
+    /// [`Float`] with fewer 1s in its binary expansion is chosen. Zero is converted to positive
+    /// zero.
+    ///
+    /// $$
+    /// f(x,p) = x+\varepsilon.
+    /// $$
+    /// - If $x$ is zero, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $x$ is nonzero, then $|\varepsilon| \leq 2^{\lfloor\log_2 |x|\rfloor-p}$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(x.significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::Float;
+    /// use malachite_q::Rational;
+    /// use std::cmp::Ordering;
+    ///
+    /// let (x, o) = Float::from_rational_prec_ref(&Rational::from_signeds(-1, 3), 10);
+    /// assert_eq!(x.to_string(), "-0.3335");
+    /// assert_eq!(o, Ordering::Less);
+    /// ```
+    #[inline]
+    pub fn from_rational_prec_ref(x: &Rational, prec: u64) -> (Float, Ordering) {
+        Float::from_rational_prec_round_ref(x, prec, RoundingMode::Nearest)
+    }
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct FloatFromRationalError;
+
+impl TryFrom<Rational> for Float {
+    type Error = FloatFromRationalError;
+
+    /// Converts a [`Rational`] to a [`Float`] exactly, taking the [`Rational`] by value.
+    ///
+    /// A [`Rational`] can be represented exactly by a [`Float`] if and only if its denominator is
+    /// a power of 2. In that case, the precision of the output is the smallest precision that can
+    /// represent it exactly; otherwise, an error is returned. Zero is converted to positive zero.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `x.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::conversion::from_rational::FloatFromRationalError;
+    /// use malachite_float::Float;
+    /// use malachite_q::Rational;
+    ///
+    /// let x = Float::try_from(Rational::from_signeds(-3, 8)).unwrap();
+    /// assert_eq!(x.get_prec(), Some(2));
+    /// assert_eq!(Rational::try_from(x).unwrap(), Rational::from_signeds(-3, 8));
+    /// assert_eq!(Float::try_from(Rational::from_signeds(1, 3)), Err(FloatFromRationalError));
+    /// ```
+    #[inline]
+    fn try_from(x: Rational) -> Result<Float, Self::Error> {
+        Float::try_from(&x)
+    }
+}
+
+impl<'a> TryFrom<&'a Rational> for Float {
+    type Error = FloatFromRationalError;
+
+    /// Converts a [`Rational`] to a [`Float`] exactly, taking the [`Rational`] by reference.
+    ///
+    /// A [`Rational`] can be represented exactly by a [`Float`] if and only if its denominator is
+    /// a power of 2. In that case, the precision of the output is the smallest precision that can
+    /// represent it exactly; otherwise, an error is returned. Zero is converted to positive zero.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `x.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::conversion::from_rational::FloatFromRationalError;
+    /// use malachite_float::Float;
+    /// use malachite_q::Rational;
+    ///
+    /// assert_eq!(Float::try_from(&Rational::from(100)).unwrap().to_string(), "100.0");
+    /// assert_eq!(Float::try_from(&Rational::from_signeds(22, 7)), Err(FloatFromRationalError));
+    /// ```
+    fn try_from(x: &'a Rational) -> Result<Float, Self::Error> {
+        if *x == 0u32 {
+            return Ok(Float::ZERO);
+        }
+        let (n, d) = x.to_numerator_and_denominator();
+        if d.is_power_of_2() {
+            let e = -i64::exact_from(d.significant_bits() - 1);
+            Ok(from_natural_and_exponent(*x > 0u32, n, e))
+        } else {
+            Err(FloatFromRationalError)
+        }
+    }
+}
+
+impl<'a> ConvertibleFrom<&'a Rational> for Float {
+    /// Determines whether a [`Rational`] can be converted to a [`Float`] exactly; that is,
+    /// whether its denominator is a power of 2.
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::conversion::traits::ConvertibleFrom;
+    /// use malachite_float::Float;
+    /// use malachite_q::Rational;
+    ///
+    /// assert_eq!(Float::convertible_from(&Rational::from_signeds(-3, 8)), true);
+    /// assert_eq!(Float::convertible_from(&Rational::from_signeds(1, 3)), false);
+    /// ```
+    fn convertible_from(x: &'a Rational) -> bool {
+        x.to_denominator().is_power_of_2()
+    }
+}
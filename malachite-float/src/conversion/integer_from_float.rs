@@ -0,0 +1,101 @@
+use crate::Float;
+use malachite_base::num::conversion::traits::{ConvertibleFrom, RoundingFrom};
+use malachite_base::rounding_modes::RoundingMode;
+use malachite_nz::integer::Integer;
+use malachite_q::Rational;
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct IntegerFromFloatError;
+
+impl<'a> TryFrom<&'a Float> for Integer {
+    type Error = IntegerFromFloatError;
+
+    /// Converts a [`Float`] to an [`Integer`], taking the [`Float`] by reference. If the
+    /// [`Float`] is not equal to an integer, an error is returned. Both zeros are converted to
+    /// zero.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `x.get_prec()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::conversion::integer_from_float::IntegerFromFloatError;
+    /// use malachite_float::Float;
+    /// use malachite_nz::integer::Integer;
+    ///
+    /// assert_eq!(Integer::try_from(&Float::from(-100.0f64)).unwrap(), -100);
+    /// assert_eq!(Integer::try_from(&Float::from(1.5f64)), Err(IntegerFromFloatError));
+    /// assert_eq!(Integer::try_from(&Float::INFINITY), Err(IntegerFromFloatError));
+    /// ```
+    fn try_from(x: &'a Float) -> Result<Integer, Self::Error> {
+        Rational::try_from(x)
+            .ok()
+            .and_then(|q| Integer::try_from(&q).ok())
+            .ok_or(IntegerFromFloatError)
+    }
+}
+
+impl<'a> ConvertibleFrom<&'a Float> for Integer {
+    /// Determines whether a [`Float`] can be converted to an [`Integer`]; that is, whether it is
+    /// a finite integer.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `x.get_prec()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::conversion::traits::ConvertibleFrom;
+    /// use malachite_float::Float;
+    /// use malachite_nz::integer::Integer;
+    ///
+    /// assert_eq!(Integer::convertible_from(&Float::from(-100.0f64)), true);
+    /// assert_eq!(Integer::convertible_from(&Float::from(1.5f64)), false);
+    /// assert_eq!(Integer::convertible_from(&Float::NAN), false);
+    /// ```
+    #[inline]
+    fn convertible_from(x: &'a Float) -> bool {
+        Integer::try_from(x).is_ok()
+    }
+}
+
+impl<'a> RoundingFrom<&'a Float> for Integer {
+    /// Converts a [`Float`] to an [`Integer`], using a specified [`RoundingMode`] and taking the
+    /// [`Float`] by reference.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `x.get_prec()`.
+    ///
+    /// # Panics
+    /// Panics if the [`Float`] is NaN or infinite, or if `rm` is `Exact` but the [`Float`] is
+    /// not an integer.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::conversion::traits::RoundingFrom;
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use malachite_nz::integer::Integer;
+    ///
+    /// assert_eq!(Integer::rounding_from(&Float::from(1.5f64), RoundingMode::Floor), 1);
+    /// assert_eq!(Integer::rounding_from(&Float::from(1.5f64), RoundingMode::Ceiling), 2);
+    /// assert_eq!(Integer::rounding_from(&Float::from(2.5f64), RoundingMode::Nearest), 2);
+    /// assert_eq!(Integer::rounding_from(&Float::from(-2.5f64), RoundingMode::Down), -2);
+    /// ```
+    fn rounding_from(x: &'a Float, rm: RoundingMode) -> Integer {
+        let q =
+            Rational::try_from(x).expect("Cannot convert a NaN or infinite Float to an Integer");
+        Integer::rounding_from(&q, rm)
+    }
+}
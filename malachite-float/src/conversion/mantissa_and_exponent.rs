@@ -0,0 +1,128 @@
+use crate::conversion::from_natural_and_exponent;
+use crate::{Float, InnerFloat};
+use malachite_base::num::conversion::traits::{
+    ExactFrom, IntegerMantissaAndExponent, SciMantissaAndExponent,
+};
+use malachite_nz::natural::Natural;
+
+macro_rules! impl_mantissa_and_exponent {
+    ($t: ident) => {
+        impl<'a> SciMantissaAndExponent<$t, i64, Float> for &'a Float {
+            /// Returns a [`Float`]'s scientific mantissa and exponent, taking the [`Float`] by
+            /// reference.
+            ///
+            /// When $x$ is finite and nonzero, we can write $|x| = 2^{e_s}m_s$, where $e_s$ is an
+            /// integer and $m_s$ is a rational number with $1 \leq m_s < 2$. We represent the
+            /// rational mantissa as a primitive float. The conversion might not be exact, so we
+            /// round to the nearest float using the `Nearest` rounding mode. To use other
+            /// rounding modes, convert the [`Float`] to a
+            /// [`Rational`](malachite_q::Rational) and use
+            /// [`Rational::sci_mantissa_and_exponent_with_rounding`](malachite_q::Rational::sci_mantissa_and_exponent_with_rounding).
+            ///
+            /// The mantissa is computed from the significand alone, so the time taken does not
+            /// depend on the exponent. If the mantissa rounds up to 2, it is returned as 1 and the
+            /// exponent is incremented.
+            /// $$
+            /// f(x) \approx \left (\frac{|x|}{2^{\lfloor \log_2 |x| \rfloor}},
+            ///     \lfloor \log_2 |x| \rfloor\right ).
+            /// $$
+            ///
+            /// # Worst-case complexity
+            /// $T(n) = O(n)$
+            ///
+            /// $M(n) = O(1)$
+            ///
+            /// where $T$ is time, $M$ is additional memory, and $n$ is `self.get_prec()`.
+            ///
+            /// # Panics
+            /// Panics if `self` is zero, infinite, or NaN.
+            ///
+            /// # Examples
+            /// See [here](super::mantissa_and_exponent#sci_mantissa_and_exponent).
+            fn sci_mantissa_and_exponent(self) -> ($t, i64) {
+                match self {
+                    Float(InnerFloat::Finite {
+                        exponent,
+                        precision,
+                        significand,
+                        ..
+                    }) => {
+                        // |x| = m * 2^(e - p), and m = m_s * 2^(e_m), where e_m is p - 1, or p if
+                        // m_s was rounded up to 2.
+                        let (mantissa, significand_exponent) =
+                            significand.sci_mantissa_and_exponent();
+                        (
+                            mantissa,
+                            exponent - i64::exact_from(*precision)
+                                + i64::exact_from(significand_exponent),
+                        )
+                    }
+                    _ => panic!("Cannot get the mantissa of a zero, infinite, or NaN Float"),
+                }
+            }
+
+            /// Returns a [`Float`]'s scientific exponent, taking the [`Float`] by reference.
+            ///
+            /// When $x$ is finite and nonzero, we can write $|x| = 2^{e_s}m_s$, where $e_s$ is an
+            /// integer and $m_s$ is a rational number with $1 \leq m_s < 2$. We represent the
+            /// rational mantissa as a primitive float; the exponent is exact and does not
+            /// depend on the float type.
+            /// $$
+            /// f(x) = \lfloor \log_2 |x| \rfloor.
+            /// $$
+            ///
+            /// # Worst-case complexity
+            /// Constant time and additional memory.
+            ///
+            /// # Panics
+            /// Panics if `self` is zero, infinite, or NaN.
+            ///
+            /// # Examples
+            /// See [here](super::mantissa_and_exponent#sci_exponent).
+            fn sci_exponent(self) -> i64 {
+                match self {
+                    Float(InnerFloat::Finite { exponent, .. }) => exponent - 1,
+                    _ => panic!("Cannot get the exponent of a zero, infinite, or NaN Float"),
+                }
+            }
+
+            /// Constructs a [`Float`] from its scientific mantissa and exponent.
+            ///
+            /// When $x$ is positive, we can write $x = 2^{e_s}m_s$, where $e_s$ is an integer and
+            /// $m_s$ is a rational number with $1 \leq m_s < 2$. Here, the rational mantissa is
+            /// provided as a primitive float. Since every such value is exactly representable,
+            /// the output is exact, and has the smallest precision that can represent it. If the
+            /// mantissa is not in $[1, 2)$, or if the exponent of the result, `sci_exponent + 1`,
+            /// is not between [`Float::MIN_EXPONENT`] and [`Float::MAX_EXPONENT`], `None` is
+            /// returned.
+            ///
+            /// $$
+            /// f(x) = 2^{e_s}m_s.
+            /// $$
+            ///
+            /// # Worst-case complexity
+            /// Constant time and additional memory.
+            ///
+            /// # Examples
+            /// See [here](super::mantissa_and_exponent#from_sci_mantissa_and_exponent).
+            fn from_sci_mantissa_and_exponent(
+                sci_mantissa: $t,
+                sci_exponent: i64,
+            ) -> Option<Float> {
+                if !(1.0..2.0).contains(&sci_mantissa)
+                    || !(Float::MIN_EXPONENT - 1..Float::MAX_EXPONENT).contains(&sci_exponent)
+                {
+                    return None;
+                }
+                let (m, e) = sci_mantissa.integer_mantissa_and_exponent();
+                Some(from_natural_and_exponent(
+                    true,
+                    Natural::from(m),
+                    e + sci_exponent,
+                ))
+            }
+        }
+    };
+}
+impl_mantissa_and_exponent!(f32);
+impl_mantissa_and_exponent!(f64);
@@ -0,0 +1,149 @@
+use crate::arithmetic::round_natural;
+use crate::Float;
+use malachite_base::num::logic::traits::SignificantBits;
+use malachite_base::rounding_modes::RoundingMode;
+use malachite_nz::natural::Natural;
+
+// Returns the `Float` equal to $\pm n2^e$, where $n$ is nonzero, with the smallest precision that
+// can represent it exactly.
+pub(crate) fn from_natural_and_exponent(sign: bool, n: Natural, e: i64) -> Float {
+    let zeros = n.trailing_zeros().unwrap();
+    let n = n >> zeros;
+    let prec = n.significant_bits();
+    round_natural(
+        sign,
+        n,
+        i128::from(e) + i128::from(zeros),
+        prec,
+        RoundingMode::Exact,
+    )
+    .0
+}
+
+/// Implementations of [`From`] for converting [`Natural`]s and
+/// [`Integer`](malachite_nz::integer::Integer)s to [`Float`]s exactly.
+pub mod from_integer;
+/// Implementations of [`From`] for converting primitive floats to [`Float`]s exactly.
+///
+/// # from
+/// ```
+/// use malachite_float::Float;
+///
+/// assert_eq!(Float::from(f64::NAN).to_string(), "NaN");
+/// assert_eq!(Float::from(f32::NEG_INFINITY).to_string(), "-Infinity");
+/// assert_eq!(Float::from(-0.0f64).to_string(), "-0.0");
+/// assert_eq!(Float::from(1.5f32).to_string(), "1.5");
+/// assert_eq!(Float::from(1.5f32).get_prec(), Some(2));
+/// assert_eq!(Float::from(0.1f64).get_prec(), Some(52));
+/// ```
+pub mod from_primitive_float;
+/// Functions and implementations of traits for converting a
+/// [`Rational`](malachite_q::Rational) to a [`Float`], either exactly or with rounding.
+pub mod from_rational;
+/// Implementations of traits for converting a [`Float`] to an
+/// [`Integer`](malachite_nz::integer::Integer), either exactly or with rounding.
+pub mod integer_from_float;
+/// Implementations of
+/// [`SciMantissaAndExponent`](malachite_base::num::conversion::traits::SciMantissaAndExponent),
+/// a trait for converting between a [`Float`] and a scientific mantissa and exponent.
+///
+/// # sci_mantissa_and_exponent
+/// ```
+/// use malachite_base::num::conversion::traits::SciMantissaAndExponent;
+/// use malachite_base::num::float::NiceFloat;
+/// use malachite_float::Float;
+///
+/// let test = |x: Float, mantissa: f32, exponent: i64| {
+///     let (m, e) = (&x).sci_mantissa_and_exponent();
+///     assert_eq!(NiceFloat(m), NiceFloat(mantissa));
+///     assert_eq!(e, exponent);
+/// };
+/// test(Float::from(3.0f64), 1.5, 1);
+/// test(Float::from(-123.0f64), 1.921875, 6);
+/// test(Float::from(0.1f64), 1.6, -4);
+/// ```
+///
+/// # sci_exponent
+/// ```
+/// use malachite_base::num::conversion::traits::SciMantissaAndExponent;
+/// use malachite_float::Float;
+///
+/// assert_eq!(SciMantissaAndExponent::<f64, _, _>::sci_exponent(&Float::from(3.0f64)), 1);
+/// assert_eq!(SciMantissaAndExponent::<f64, _, _>::sci_exponent(&Float::from(-0.1f64)), -4);
+/// ```
+///
+/// # from_sci_mantissa_and_exponent
+/// ```
+/// use malachite_base::num::conversion::traits::SciMantissaAndExponent;
+/// use malachite_float::Float;
+///
+/// let test = |mantissa: f32, exponent: i64, out: Option<f64>| {
+///     assert_eq!(
+///         <&Float as SciMantissaAndExponent<_, _, _>>::from_sci_mantissa_and_exponent(
+///             mantissa, exponent
+///         ),
+///         out.map(Float::from)
+///     );
+/// };
+/// test(1.5, 1, Some(3.0));
+/// test(1.921875, 6, Some(123.0));
+/// test(1.5, -3, Some(0.1875));
+///
+/// test(2.0, 1, None);
+/// test(0.5, 1, None);
+/// ```
+pub mod mantissa_and_exponent;
+/// Implementations of traits for converting a [`Float`] to a primitive float, either exactly or
+/// with rounding.
+///
+/// # try_from
+/// ```
+/// use malachite_float::conversion::primitive_float_from_float::PrimitiveFloatFromFloatError;
+/// use malachite_float::Float;
+/// use malachite_q::Rational;
+///
+/// assert_eq!(f64::try_from(&Float::from(-2.5f64)), Ok(-2.5));
+/// assert_eq!(f32::try_from(&Float::INFINITY), Ok(f32::INFINITY));
+/// assert!(f64::try_from(&Float::NAN).unwrap().is_nan());
+///
+/// let x = Float::from_rational_prec(Rational::from_signeds(1, 3), 100).0;
+/// assert_eq!(f64::try_from(&x), Err(PrimitiveFloatFromFloatError));
+/// ```
+///
+/// # convertible_from
+/// ```
+/// use malachite_base::num::conversion::traits::ConvertibleFrom;
+/// use malachite_float::Float;
+/// use malachite_q::Rational;
+///
+/// assert_eq!(f32::convertible_from(&Float::from(0.1f32)), true);
+/// assert_eq!(f32::convertible_from(&Float::from(0.1f64)), false);
+/// assert_eq!(f64::convertible_from(&Float::NAN), true);
+/// assert_eq!(
+///     f64::convertible_from(&Float::from_rational_prec(Rational::from_signeds(1, 3), 100).0),
+///     false
+/// );
+/// ```
+///
+/// # rounding_from
+/// ```
+/// use malachite_base::num::conversion::traits::RoundingFrom;
+/// use malachite_base::num::float::NiceFloat;
+/// use malachite_base::rounding_modes::RoundingMode;
+/// use malachite_float::Float;
+///
+/// let x = Float::from(0.1f64);
+/// assert_eq!(NiceFloat(f32::rounding_from(&x, RoundingMode::Floor)), NiceFloat(0.099999994));
+/// assert_eq!(NiceFloat(f32::rounding_from(&x, RoundingMode::Ceiling)), NiceFloat(0.1));
+/// assert_eq!(NiceFloat(f32::rounding_from(&x, RoundingMode::Nearest)), NiceFloat(0.1));
+/// assert_eq!(
+///     NiceFloat(f32::rounding_from(&Float::NEGATIVE_ZERO, RoundingMode::Exact)),
+///     NiceFloat(-0.0)
+/// );
+/// ```
+pub mod primitive_float_from_float;
+/// Implementations of traits for converting a [`Float`] to a
+/// [`Rational`](malachite_q::Rational) exactly.
+pub mod rational_from_float;
+/// Implementations of [`Display`](std::fmt::Display) and [`Debug`] for [`Float`]s.
+pub mod string;
@@ -0,0 +1,141 @@
+use crate::{Float, InnerFloat};
+use malachite_base::num::arithmetic::traits::NegAssign;
+use malachite_base::num::basic::floats::PrimitiveFloat;
+use malachite_base::num::conversion::traits::{ConvertibleFrom, RoundingFrom};
+use malachite_base::rounding_modes::RoundingMode;
+use malachite_q::Rational;
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct PrimitiveFloatFromFloatError;
+
+// Given the exponent $e$ of a finite, nonzero `Float`, returns `Some(true)` if the `Float` is too
+// large to be represented by any finite value of `T` (that is, $2^{e-1} > 2^M$, where $M$ is
+// `T::MAX_EXPONENT`), `Some(false)` if it is less than half the smallest positive subnormal of
+// `T`, and `None` otherwise. In the first two cases, converting the `Float` to `T` only depends on
+// its sign and the rounding mode, so the `Float` doesn't need to be converted to a `Rational`,
+// which would take time and memory proportional to $|e|$.
+const fn out_of_range<T: PrimitiveFloat>(exponent: i64) -> Option<bool> {
+    if exponent > T::MAX_EXPONENT + 1 {
+        Some(true)
+    } else if exponent < T::MIN_EXPONENT {
+        Some(false)
+    } else {
+        None
+    }
+}
+
+// Rounds a finite, nonzero `Float` that is out of range, as determined by `out_of_range`, to `T`.
+// The result is the same as the result of rounding the equivalent `Rational`.
+fn rounding_from_out_of_range<T: PrimitiveFloat>(
+    sign: bool,
+    overflow: bool,
+    mut rm: RoundingMode,
+) -> T {
+    if !sign {
+        rm.neg_assign();
+    }
+    let f = match (overflow, rm) {
+        (_, RoundingMode::Exact) => panic!("Value cannot be represented exactly as a float"),
+        (true, RoundingMode::Floor | RoundingMode::Down | RoundingMode::Nearest) => T::MAX_FINITE,
+        (true, _) => T::POSITIVE_INFINITY,
+        (false, RoundingMode::Ceiling | RoundingMode::Up) => T::MIN_POSITIVE_SUBNORMAL,
+        (false, _) => T::ZERO,
+    };
+    if sign {
+        f
+    } else {
+        -f
+    }
+}
+
+macro_rules! impl_primitive_float_from_float {
+    ($f: ident) => {
+        impl<'a> TryFrom<&'a Float> for $f {
+            type Error = PrimitiveFloatFromFloatError;
+
+            /// Converts a [`Float`] to a primitive float, taking the [`Float`] by reference. If
+            /// the [`Float`] cannot be represented exactly, an error is returned. NaN, the
+            /// infinities, and the zeros are converted to the corresponding primitive floats.
+            ///
+            /// # Worst-case complexity
+            /// $T(n) = O(n)$
+            ///
+            /// $M(n) = O(n)$
+            ///
+            /// where $T$ is time, $M$ is additional memory, and $n$ is `x.get_prec()`.
+            ///
+            /// # Examples
+            /// See [here](super::primitive_float_from_float#try_from).
+            fn try_from(x: &'a Float) -> Result<$f, Self::Error> {
+                match x {
+                    Float(InnerFloat::NaN) => Ok($f::NAN),
+                    Float(InnerFloat::Infinity { sign: true }) => Ok($f::POSITIVE_INFINITY),
+                    Float(InnerFloat::Infinity { sign: false }) => Ok($f::NEGATIVE_INFINITY),
+                    Float(InnerFloat::Zero { sign: true }) => Ok(0.0),
+                    Float(InnerFloat::Zero { sign: false }) => Ok(-0.0),
+                    Float(InnerFloat::Finite { exponent, .. }) => {
+                        if out_of_range::<$f>(*exponent).is_some() {
+                            Err(PrimitiveFloatFromFloatError)
+                        } else {
+                            $f::try_from(&Rational::try_from(x).unwrap())
+                                .map_err(|_| PrimitiveFloatFromFloatError)
+                        }
+                    }
+                }
+            }
+        }
+
+        impl<'a> ConvertibleFrom<&'a Float> for $f {
+            /// Determines whether a [`Float`] can be converted to a primitive float exactly.
+            ///
+            /// # Worst-case complexity
+            /// $T(n) = O(n)$
+            ///
+            /// $M(n) = O(n)$
+            ///
+            /// where $T$ is time, $M$ is additional memory, and $n$ is `x.get_prec()`.
+            ///
+            /// # Examples
+            /// See [here](super::primitive_float_from_float#convertible_from).
+            #[inline]
+            fn convertible_from(x: &'a Float) -> bool {
+                $f::try_from(x).is_ok()
+            }
+        }
+
+        impl<'a> RoundingFrom<&'a Float> for $f {
+            /// Converts a [`Float`] to a primitive float, using a specified [`RoundingMode`] and
+            /// taking the [`Float`] by reference. NaN, the infinities, and the zeros are
+            /// converted to the corresponding primitive floats. Values that are too large or too
+            /// small to be represented are rounded in the same way as by the conversion from
+            /// [`Rational`]; for example, with `Nearest`, a [`Float`] too large to be represented
+            /// is rounded to the largest finite float of the same sign.
+            ///
+            /// # Worst-case complexity
+            /// $T(n) = O(n)$
+            ///
+            /// $M(n) = O(n)$
+            ///
+            /// where $T$ is time, $M$ is additional memory, and $n$ is `x.get_prec()`.
+            ///
+            /// # Panics
+            /// Panics if `rm` is `Exact` but the [`Float`] cannot be represented exactly.
+            ///
+            /// # Examples
+            /// See [here](super::primitive_float_from_float#rounding_from).
+            fn rounding_from(x: &'a Float, rm: RoundingMode) -> $f {
+                match x {
+                    Float(InnerFloat::Finite { sign, exponent, .. }) => {
+                        match out_of_range::<$f>(*exponent) {
+                            Some(overflow) => rounding_from_out_of_range(*sign, overflow, rm),
+                            None => $f::rounding_from(&Rational::try_from(x).unwrap(), rm),
+                        }
+                    }
+                    _ => $f::try_from(x).unwrap(),
+                }
+            }
+        }
+    };
+}
+impl_primitive_float_from_float!(f32);
+impl_primitive_float_from_float!(f64);
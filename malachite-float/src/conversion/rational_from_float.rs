@@ -0,0 +1,102 @@
+use crate::{Float, InnerFloat};
+use malachite_base::num::basic::traits::Zero;
+use malachite_base::num::conversion::traits::{ConvertibleFrom, ExactFrom};
+use malachite_q::Rational;
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct RationalFromFloatError;
+
+impl TryFrom<Float> for Rational {
+    type Error = RationalFromFloatError;
+
+    /// Converts a [`Float`] to a [`Rational`], taking the [`Float`] by value. If the [`Float`] is
+    /// finite, the conversion is exact; both zeros are converted to zero. If the [`Float`] is NaN
+    /// or infinite, an error is returned.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `x.get_prec()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::conversion::rational_from_float::RationalFromFloatError;
+    /// use malachite_float::Float;
+    /// use malachite_q::Rational;
+    ///
+    /// assert_eq!(Rational::try_from(Float::from(-0.375f64)).unwrap().to_string(), "-3/8");
+    /// assert_eq!(Rational::try_from(Float::NEGATIVE_ZERO).unwrap().to_string(), "0");
+    /// assert_eq!(Rational::try_from(Float::INFINITY), Err(RationalFromFloatError));
+    /// ```
+    #[inline]
+    fn try_from(x: Float) -> Result<Rational, Self::Error> {
+        Rational::try_from(&x)
+    }
+}
+
+impl<'a> TryFrom<&'a Float> for Rational {
+    type Error = RationalFromFloatError;
+
+    /// Converts a [`Float`] to a [`Rational`], taking the [`Float`] by reference. If the
+    /// [`Float`] is finite, the conversion is exact; both zeros are converted to zero. If the
+    /// [`Float`] is NaN or infinite, an error is returned.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `x.get_prec()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::conversion::rational_from_float::RationalFromFloatError;
+    /// use malachite_float::Float;
+    /// use malachite_q::Rational;
+    ///
+    /// assert_eq!(Rational::try_from(&Float::from(100.0f64)).unwrap().to_string(), "100");
+    /// assert_eq!(Rational::try_from(&Float::NAN), Err(RationalFromFloatError));
+    /// ```
+    fn try_from(x: &'a Float) -> Result<Rational, Self::Error> {
+        match x {
+            Float(InnerFloat::NaN) | Float(InnerFloat::Infinity { .. }) => {
+                Err(RationalFromFloatError)
+            }
+            Float(InnerFloat::Zero { .. }) => Ok(Rational::ZERO),
+            Float(InnerFloat::Finite {
+                sign,
+                exponent,
+                precision,
+                significand,
+            }) => {
+                let abs = Rational::from(significand) << (exponent - i64::exact_from(*precision));
+                Ok(if *sign { abs } else { -abs })
+            }
+        }
+    }
+}
+
+impl<'a> ConvertibleFrom<&'a Float> for Rational {
+    /// Determines whether a [`Float`] can be converted to a [`Rational`]; that is, whether it is
+    /// finite.
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::conversion::traits::ConvertibleFrom;
+    /// use malachite_float::Float;
+    /// use malachite_q::Rational;
+    ///
+    /// assert_eq!(Rational::convertible_from(&Float::from(1.5f64)), true);
+    /// assert_eq!(Rational::convertible_from(&Float::NEGATIVE_ZERO), true);
+    /// assert_eq!(Rational::convertible_from(&Float::NAN), false);
+    /// ```
+    #[inline]
+    fn convertible_from(x: &'a Float) -> bool {
+        x.is_finite()
+    }
+}
@@ -0,0 +1,373 @@
+use crate::{Float, InnerFloat};
+use malachite_base::num::arithmetic::traits::{Abs, FloorLogBase, IsPowerOf2, Pow, ShrRound};
+use malachite_base::num::basic::traits::One;
+use malachite_base::num::conversion::traits::{ExactFrom, RoundingFrom};
+use malachite_base::num::logic::traits::{BitAccess, SignificantBits};
+use malachite_base::rounding_modes::RoundingMode;
+use malachite_nz::natural::Natural;
+use malachite_q::Rational;
+use std::cmp::Ordering;
+use std::fmt::{Debug, Display, Formatter, Result, Write};
+
+impl Display for Float {
+    /// Converts a [`Float`] to a [`String`].
+    ///
+    /// NaN, the infinities, and the zeros are written as `NaN`, `Infinity`, `-Infinity`, `0.0`,
+    /// and `-0.0`. A finite, nonzero [`Float`] is written as the shortest decimal that rounds
+    /// back to it, under `Nearest` rounding at the [`Float`]'s precision. At least one digit
+    /// always follows the decimal point. Values whose decimal exponent is less than $-5$ or at
+    /// least 16 are written in scientific notation.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.get_prec()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::{NegativeOne, One, Zero};
+    /// use malachite_float::Float;
+    ///
+    /// assert_eq!(Float::NAN.to_string(), "NaN");
+    /// assert_eq!(Float::INFINITY.to_string(), "Infinity");
+    /// assert_eq!(Float::NEGATIVE_INFINITY.to_string(), "-Infinity");
+    /// assert_eq!(Float::ZERO.to_string(), "0.0");
+    /// assert_eq!(Float::NEGATIVE_ZERO.to_string(), "-0.0");
+    /// assert_eq!(Float::ONE.to_string(), "1.0");
+    /// assert_eq!(Float::NEGATIVE_ONE.to_string(), "-1.0");
+    /// assert_eq!(Float::from(0.1f64).to_string(), "0.1");
+    /// assert_eq!(Float::from(std::f64::consts::PI).to_string(), "3.141592653589793");
+    /// assert_eq!(Float::from(1.0e100f64).to_string(), "1.0e100");
+    /// ```
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        match self {
+            Float(InnerFloat::NaN) => f.write_str("NaN"),
+            Float(InnerFloat::Infinity { sign: true }) => f.write_str("Infinity"),
+            Float(InnerFloat::Infinity { sign: false }) => f.write_str("-Infinity"),
+            Float(InnerFloat::Zero { sign: true }) => f.write_str("0.0"),
+            Float(InnerFloat::Zero { sign: false }) => f.write_str("-0.0"),
+            Float(InnerFloat::Finite {
+                sign,
+                exponent,
+                precision,
+                significand,
+            }) => {
+                if !sign {
+                    f.write_char('-')?;
+                }
+                let power_of_2 = significand.is_power_of_2();
+                let (digits, e) = if exponent.unsigned_abs() <= max_exact_exponent(*precision) {
+                    shortest_digits(
+                        &Rational::try_from(self).unwrap().abs(),
+                        *exponent,
+                        *precision,
+                        power_of_2,
+                    )
+                } else {
+                    shortest_digits_approx(significand, *exponent, *precision, power_of_2)
+                };
+                write_digits(f, &digits, e)
+            }
+        }
+    }
+}
+
+// A `Float` whose exponent's absolute value is at most this is formatted using exact `Rational`
+// arithmetic, which takes time and memory proportional to the exponent. Larger exponents use
+// approximations of powers of 5 instead. When the exponent is larger than this, none of the values
+// that `shortest_digits_approx` scales by a power of 10 can become a multiple of 1/2, so
+// sufficiently precise approximations always determine their floors.
+const fn max_exact_exponent(precision: u64) -> u64 {
+    precision.saturating_mul(3).saturating_add(64)
+}
+
+// Rounds the positive `Rational` `x` to `n` significant decimal digits, where `e` is
+// $\lfloor \log_{10} x \rfloor$. Returns the digits as a `Natural` with `n` or `n + 1` digits,
+// along with the rounded value.
+fn round_to_digits(x: &Rational, e: i64, n: u64) -> (Natural, Rational) {
+    let scale = Rational::from(10u32).pow(i64::exact_from(n) - 1 - e);
+    let m = Natural::rounding_from(&(x * &scale), RoundingMode::Nearest);
+    let rounded = Rational::from(&m) / scale;
+    (m, rounded)
+}
+
+// Given a function that rounds a positive `Float` with precision `precision` and decimal exponent
+// `e` to some number of significant digits, and a function that determines whether that many
+// digits round back to the `Float`, returns the shortest such digits (with no trailing zeros) and
+// their decimal exponent.
+fn shortest_digits_helper<R: Fn(u64) -> Natural, F: Fn(u64) -> bool>(
+    precision: u64,
+    e: i64,
+    round: R,
+    fits: F,
+) -> (String, i64) {
+    // This many digits always suffice to distinguish the `Float` from its neighbors.
+    let mut hi = ((precision * 1233) >> 12) + 2;
+    let mut lo = 1;
+    while lo < hi {
+        let mid = (lo + hi) >> 1;
+        if fits(mid) {
+            hi = mid;
+        } else {
+            lo = mid + 1;
+        }
+    }
+    let mut digits = round(lo).to_string();
+    // If rounding carried into a new digit, as in 9.99 -> 10.0, the exponent increases.
+    let e = if u64::exact_from(digits.len()) > lo {
+        e + 1
+    } else {
+        e
+    };
+    let trimmed = digits.trim_end_matches('0').len();
+    digits.truncate(trimmed);
+    (digits, e)
+}
+
+// Finds the shortest decimal that rounds to `x` (positive, with the given exponent and precision)
+// under `Nearest` rounding, and returns its digits (with no trailing zeros) and its decimal
+// exponent.
+fn shortest_digits(x: &Rational, exponent: i64, precision: u64, power_of_2: bool) -> (String, i64) {
+    let e = x.floor_log_base(&Rational::from(10u32));
+    // half the distance between `x` and its upper neighbor with the same precision
+    let half_ulp = Rational::ONE << (exponent - i64::exact_from(precision) - 1);
+    // If `x` is a power of 2, its lower neighbor is in the binade below, and is twice as close.
+    let lower_half_ulp = if power_of_2 {
+        &half_ulp >> 1u32
+    } else {
+        half_ulp.clone()
+    };
+    shortest_digits_helper(
+        precision,
+        e,
+        |n| round_to_digits(x, e, n).0,
+        |n| {
+            let (_, rounded) = round_to_digits(x, e, n);
+            match rounded.cmp(x) {
+                Ordering::Equal => true,
+                Ordering::Less => x - rounded < lower_half_ulp,
+                Ordering::Greater => rounded - x < half_ulp,
+            }
+        },
+    )
+}
+
+// $\lfloor 2^{64} \log_{10} 2 \rfloor$
+const LOG_10_2: u64 = 0x4d104d427de7fbcc;
+
+// Multiplies two values of the form $n2^e$, rounding the product's `n` to at most `prec` bits using
+// the rounding mode `rm`.
+fn mul_bound(
+    x: &(Natural, i128),
+    y: &(Natural, i128),
+    prec: u64,
+    rm: RoundingMode,
+) -> (Natural, i128) {
+    let n = &x.0 * &y.0;
+    let e = x.1 + y.1;
+    let bits = n.significant_bits();
+    if bits <= prec {
+        (n, e)
+    } else {
+        let shift = bits - prec;
+        (n.shr_round(shift, rm), e + i128::from(shift))
+    }
+}
+
+// Returns a lower and an upper bound for $5^k$, each of the form $n2^e$, where $n$ has about `prec`
+// bits.
+fn power_of_5_bounds(k: u64, prec: u64) -> ((Natural, i128), (Natural, i128)) {
+    let five = (Natural::from(5u32), 0);
+    let mut lo = (Natural::ONE, 0);
+    let mut hi = (Natural::ONE, 0);
+    for i in (0..k.significant_bits()).rev() {
+        lo = mul_bound(&lo, &lo, prec, RoundingMode::Floor);
+        hi = mul_bound(&hi, &hi, prec, RoundingMode::Ceiling);
+        if k.get_bit(i) {
+            lo = mul_bound(&lo, &five, prec, RoundingMode::Floor);
+            hi = mul_bound(&hi, &five, prec, RoundingMode::Ceiling);
+        }
+    }
+    (lo, hi)
+}
+
+// Returns $\lfloor n2^s \rfloor$.
+fn floor_shl(n: Natural, s: i128) -> Natural {
+    if s >= 0 {
+        n << u64::exact_from(s)
+    } else {
+        n >> u64::exact_from(-s)
+    }
+}
+
+// Returns $\lfloor n2^s/d \rfloor$.
+fn floor_div_shl(n: &Natural, s: i128, d: &Natural) -> Natural {
+    if s >= 0 {
+        (n << u64::exact_from(s)) / d
+    } else {
+        n / (d << u64::exact_from(-s))
+    }
+}
+
+// Returns $\lfloor 2a2^b10^j \rfloor$, where $a$ is positive and $a2^b10^j$ is not a multiple of
+// 1/2. The power of 5 in $10^j$ is approximated with increasing precision, starting at `prec` bits,
+// until the bounds it gives for the result have the same floor.
+fn floor_twice_scaled(a: &Natural, b: i128, j: i128, mut prec: u64) -> Natural {
+    let c = b + j + 1;
+    let k = u64::exact_from(j.unsigned_abs());
+    loop {
+        let (lo, hi) = power_of_5_bounds(k, prec);
+        let (floor_lo, floor_hi) = if j >= 0 {
+            (floor_shl(a * lo.0, c + lo.1), floor_shl(a * hi.0, c + hi.1))
+        } else {
+            (
+                floor_div_shl(a, c - hi.1, &hi.0),
+                floor_div_shl(a, c - lo.1, &lo.0),
+            )
+        };
+        if floor_lo == floor_hi {
+            return floor_lo;
+        }
+        prec <<= 1;
+    }
+}
+
+// Finds the shortest decimal that rounds to $m2^{e-p}$ under `Nearest` rounding, where $m$ is the
+// significand, $e$ the exponent, and $p$ the precision of a positive `Float`, and returns its digits
+// (with no trailing zeros) and its decimal exponent.
+//
+// Unlike `shortest_digits`, this function never computes the `Float` or a power of 10 exactly, so
+// its cost depends on the precision but hardly at all on the exponent. It requires that the
+// exponent's absolute value exceed `max_exact_exponent(precision)`.
+fn shortest_digits_approx(
+    m: &Natural,
+    exponent: i64,
+    precision: u64,
+    power_of_2: bool,
+) -> (String, i64) {
+    let q = i128::from(exponent) - i128::from(precision);
+    let floor_twice = |a: &Natural, b: i128, j: i128| floor_twice_scaled(a, b, j, precision + 64);
+    // $2^{e-1} \leq x < 2^e$, so the decimal exponent is $\lfloor (e - 1)\log_{10} 2 \rfloor$ or
+    // slightly more.
+    let mut e = (i128::from(exponent - 1) * i128::from(LOG_10_2)) >> 64;
+    while floor_twice(m, q, -e) < 2u32 {
+        e -= 1;
+    }
+    while floor_twice(m, q, -e - 1) >= 2u32 {
+        e += 1;
+    }
+    // The neighbors of `x` are `x + 2 * half_ulp` and `x - 2 * lower_half_ulp`; see
+    // `shortest_digits`.
+    let (lo_a, lo_b) = if power_of_2 {
+        ((m << 2) - Natural::ONE, q - 2)
+    } else {
+        ((m << 1) - Natural::ONE, q - 1)
+    };
+    let (hi_a, hi_b) = ((m << 1) | Natural::ONE, q - 1);
+    let round = |n: u64| (floor_twice(m, q, i128::from(n) - 1 - e) + Natural::ONE) >> 1;
+    shortest_digits_helper(precision, i64::exact_from(e), round, |n| {
+        let j = i128::from(n) - 1 - e;
+        let rounded = round(n);
+        floor_twice(&lo_a, lo_b, j) >> 1 < rounded && rounded <= floor_twice(&hi_a, hi_b, j) >> 1
+    })
+}
+
+// Writes the decimal with the given significant digits and decimal exponent, in positional
+// notation if the exponent is small enough and in scientific notation otherwise. At least one
+// digit is always written after the decimal point.
+fn write_digits(f: &mut Formatter, digits: &str, e: i64) -> Result {
+    if (-5..16).contains(&e) {
+        if e < 0 {
+            f.write_str("0.")?;
+            for _ in 0..-e - 1 {
+                f.write_char('0')?;
+            }
+            f.write_str(digits)
+        } else {
+            let int_len = usize::exact_from(e) + 1;
+            if digits.len() > int_len {
+                f.write_str(&digits[..int_len])?;
+                f.write_char('.')?;
+                f.write_str(&digits[int_len..])
+            } else {
+                f.write_str(digits)?;
+                for _ in digits.len()..int_len {
+                    f.write_char('0')?;
+                }
+                f.write_str(".0")
+            }
+        }
+    } else {
+        f.write_str(&digits[..1])?;
+        f.write_char('.')?;
+        f.write_str(if digits.len() > 1 { &digits[1..] } else { "0" })?;
+        write!(f, "e{}", e)
+    }
+}
+
+impl Debug for Float {
+    /// Converts a [`Float`] to a [`String`].
+    ///
+    /// This is the same as the [`Display::fmt`] implementation.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.get_prec()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::Float;
+    ///
+    /// assert_eq!(format!("{:?}", Float::from(-2.5f64)), "-2.5");
+    /// assert_eq!(format!("{:?}", Float::NAN), "NaN");
+    /// ```
+    #[inline]
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        Display::fmt(self, f)
+    }
+}
+
+impl Display for crate::ComparableFloat {
+    /// Converts a [`ComparableFloat`](crate::ComparableFloat) to a [`String`].
+    ///
+    /// This is the same as the [`Display::fmt`] implementation for the wrapped [`Float`].
+    ///
+    /// # Worst-case complexity
+    /// Same as the [`Display`] implementation for [`Float`].
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::{ComparableFloat, Float};
+    ///
+    /// assert_eq!(ComparableFloat(Float::from(0.5f64)).to_string(), "0.5");
+    /// ```
+    #[inline]
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        Display::fmt(&self.0, f)
+    }
+}
+
+impl Debug for crate::ComparableFloat {
+    /// Converts a [`ComparableFloat`](crate::ComparableFloat) to a [`String`].
+    ///
+    /// This is the same as the [`Debug::fmt`] implementation for the wrapped [`Float`].
+    ///
+    /// # Worst-case complexity
+    /// Same as the [`Debug`] implementation for [`Float`].
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::{ComparableFloat, Float};
+    ///
+    /// assert_eq!(format!("{:?}", ComparableFloat(Float::from(0.5f64))), "0.5");
+    /// ```
+    #[inline]
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        Debug::fmt(&self.0, f)
+    }
+}
@@ -0,0 +1,214 @@
+//! This crate defines [`Float`]s, arbitrary-precision binary floating-point numbers.
+//! - Every finite, nonzero [`Float`] has its own precision: the number of bits in its significand.
+//!   Precisions may be arbitrarily large.
+//! - Like the IEEE 754 floats, [`Float`]s include signed zeros, positive and negative infinity,
+//!   and NaN.
+//! - Addition, subtraction, multiplication, division, and square root are correctly rounded under
+//!   every [`RoundingMode`](malachite_base::rounding_modes::RoundingMode): the result is the
+//!   exact result, rounded once. Each rounding function also returns an [`Ordering`] that tells
+//!   whether the result is less than, equal to, or greater than the exact result.
+//! - [`Float`]s can be converted exactly to and from
+//!   [`Rational`](malachite_q::Rational)s, [`Integer`](malachite_nz::integer::Integer)s,
+//!   [`Natural`]s, and primitive floats, when the value is representable by the target type, and
+//!   with rounding otherwise.
+//!
+//! Unlike the primitive floats, [`Float`]s have no subnormals. Their exponents lie in the range
+//! from [`Float::MIN_EXPONENT`] $= 1 - 2^{62}$ to [`Float::MAX_EXPONENT`] $= 2^{62} - 1$, the
+//! widest exponent range supported by MPFR. A result whose exponent would be greater than
+//! [`Float::MAX_EXPONENT`] overflows, becoming an infinity or the finite [`Float`] of largest
+//! absolute value, and one whose exponent would be less than [`Float::MIN_EXPONENT`] underflows,
+//! becoming a zero or the [`Float`] of smallest positive absolute value. Which of the two is
+//! chosen depends on the rounding mode, and the returned [`Ordering`] reflects the choice.
+//!
+//! [`Ordering`]: std::cmp::Ordering
+
+#![allow(
+    unstable_name_collisions,
+    clippy::assertions_on_constants,
+    clippy::cognitive_complexity,
+    clippy::many_single_char_names,
+    clippy::range_plus_one,
+    clippy::suspicious_arithmetic_impl,
+    clippy::suspicious_op_assign_impl,
+    clippy::too_many_arguments,
+    clippy::type_complexity,
+    clippy::upper_case_acronyms
+)]
+#![warn(
+    clippy::cast_lossless,
+    clippy::explicit_into_iter_loop,
+    clippy::explicit_iter_loop,
+    clippy::filter_map_next,
+    clippy::large_digit_groups,
+    clippy::manual_filter_map,
+    clippy::manual_find_map,
+    clippy::map_flatten,
+    clippy::map_unwrap_or,
+    clippy::match_same_arms,
+    clippy::missing_const_for_fn,
+    clippy::mut_mut,
+    clippy::needless_borrow,
+    clippy::needless_continue,
+    clippy::needless_pass_by_value,
+    clippy::print_stdout,
+    clippy::redundant_closure_for_method_calls,
+    clippy::single_match_else,
+    clippy::trait_duplication_in_bounds,
+    clippy::type_repetition_in_bounds,
+    clippy::unused_self
+)]
+
+#[macro_use]
+extern crate malachite_base;
+extern crate malachite_nz;
+extern crate malachite_q;
+
+use malachite_base::named::Named;
+use malachite_base::num::basic::traits::{NegativeOne, One, Two, Zero};
+use malachite_base::num::logic::traits::SignificantBits;
+use malachite_nz::natural::Natural;
+
+/// An arbitrary-precision binary floating-point number.
+///
+/// A finite, nonzero [`Float`] $x$ has a sign, a precision $p \geq 1$, an exponent $e$, and a
+/// significand $m$, which is an integer with exactly $p$ significant bits, such that
+/// $$
+/// |x| = m2^{e-p}.
+/// $$
+/// Equivalently, $2^{e-1} \leq |x| < 2^e$. This is the same convention that MPFR uses.
+///
+/// There are also two zeros, two infinities, and NaN. These special values have no precision.
+///
+/// [`Float`]'s [`PartialEq`] and [`PartialOrd`] implementations compare numerical values, so NaN
+/// is not equal to itself and $0.0 = -0.0$. To compare [`Float`]s structurally, wrap them in
+/// [`ComparableFloat`].
+#[derive(Clone)]
+pub struct Float(pub(crate) InnerFloat);
+
+#[derive(Clone)]
+pub(crate) enum InnerFloat {
+    NaN,
+    // `sign` is `true` for positive infinity
+    Infinity {
+        sign: bool,
+    },
+    // `sign` is `true` for positive zero
+    Zero {
+        sign: bool,
+    },
+    Finite {
+        // whether the `Float` is positive
+        sign: bool,
+        exponent: i64,
+        precision: u64,
+        // always has exactly `precision` significant bits
+        significand: Natural,
+    },
+}
+
+/// A wrapper around a [`Float`] whose equality is structural rather than numerical.
+///
+/// Two [`ComparableFloat`]s are equal if and only if they are both NaN, or they are both
+/// infinities or zeros with the same sign, or they are both finite and nonzero, with the same
+/// sign, precision, exponent, and significand. Unlike [`Float`], [`ComparableFloat`] implements
+/// [`Eq`] and [`Hash`](std::hash::Hash).
+#[derive(Clone)]
+pub struct ComparableFloat(pub Float);
+
+impl Float {
+    /// The constant NaN.
+    pub const NAN: Float = Float(InnerFloat::NaN);
+
+    /// The constant $\infty$.
+    pub const INFINITY: Float = Float(InnerFloat::Infinity { sign: true });
+
+    /// The constant $-\infty$.
+    pub const NEGATIVE_INFINITY: Float = Float(InnerFloat::Infinity { sign: false });
+
+    /// The constant -0.0.
+    pub const NEGATIVE_ZERO: Float = Float(InnerFloat::Zero { sign: false });
+
+    /// The smallest exponent of a finite, nonzero [`Float`], $1 - 2^{62}$. The smallest positive
+    /// [`Float`] is $2^{1 - 2^{62} - 1} = 2^{-2^{62}}$.
+    pub const MIN_EXPONENT: i64 = 1 - (1 << 62);
+
+    /// The largest exponent of a finite, nonzero [`Float`], $2^{62} - 1$. Every finite [`Float`]
+    /// has an absolute value less than $2^{2^{62} - 1}$.
+    pub const MAX_EXPONENT: i64 = (1 << 62) - 1;
+
+    // Returns true iff `self` is valid.
+    //
+    // To be valid, a finite, nonzero `Float` must have a positive precision, its significand must
+    // have exactly that many significant bits, and its exponent must be between `MIN_EXPONENT` and
+    // `MAX_EXPONENT`, inclusive. All `Float`s must be valid.
+    #[doc(hidden)]
+    pub fn is_valid(&self) -> bool {
+        match self {
+            Float(InnerFloat::Finite {
+                exponent,
+                precision,
+                significand,
+                ..
+            }) => {
+                *precision != 0
+                    && significand.significant_bits() == *precision
+                    && (Float::MIN_EXPONENT..=Float::MAX_EXPONENT).contains(exponent)
+            }
+            _ => true,
+        }
+    }
+}
+
+/// The constant 0.0 (positive zero).
+impl Zero for Float {
+    const ZERO: Float = Float(InnerFloat::Zero { sign: true });
+}
+
+/// The constant 1.0, with precision 1.
+impl One for Float {
+    const ONE: Float = Float(InnerFloat::Finite {
+        sign: true,
+        exponent: 1,
+        precision: 1,
+        significand: Natural::ONE,
+    });
+}
+
+/// The constant 2.0, with precision 1.
+impl Two for Float {
+    const TWO: Float = Float(InnerFloat::Finite {
+        sign: true,
+        exponent: 2,
+        precision: 1,
+        significand: Natural::ONE,
+    });
+}
+
+/// The constant -1.0, with precision 1.
+impl NegativeOne for Float {
+    const NEGATIVE_ONE: Float = Float(InnerFloat::Finite {
+        sign: false,
+        exponent: 1,
+        precision: 1,
+        significand: Natural::ONE,
+    });
+}
+
+impl Default for Float {
+    /// The default value of a [`Float`], 0.0.
+    fn default() -> Float {
+        Float::ZERO
+    }
+}
+
+// Implements `Named` for `Float`.
+impl_named!(Float);
+
+/// Traits for arithmetic.
+pub mod arithmetic;
+/// Functions for classifying [`Float`]s and for getting their components.
+pub mod basic;
+/// Traits for comparing [`Float`]s for equality or order.
+pub mod comparison;
+/// Traits for converting to and from [`Float`]s, and for converting [`Float`]s to strings.
+pub mod conversion;
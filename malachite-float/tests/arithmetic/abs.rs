@@ -0,0 +1,49 @@
+use malachite_base::num::arithmetic::traits::{Abs, AbsAssign};
+use malachite_float::{ComparableFloat, Float};
+use malachite_q::test_util::generators::rational_gen;
+use malachite_q::Rational;
+
+#[test]
+fn test_abs() {
+    let test = |x: f64, out: &str| {
+        let x = Float::from(x);
+
+        let abs = x.clone().abs();
+        assert!(abs.is_valid());
+        assert_eq!(abs.to_string(), out);
+
+        let abs_alt = (&x).abs();
+        assert!(abs_alt.is_valid());
+        assert_eq!(ComparableFloat(abs_alt), ComparableFloat(abs.clone()));
+
+        let mut abs_alt = x;
+        abs_alt.abs_assign();
+        assert!(abs_alt.is_valid());
+        assert_eq!(ComparableFloat(abs_alt), ComparableFloat(abs));
+    };
+    test(f64::NAN, "NaN");
+    test(f64::INFINITY, "Infinity");
+    test(f64::NEG_INFINITY, "Infinity");
+    test(0.0, "0.0");
+    test(-0.0, "0.0");
+    test(1.0, "1.0");
+    test(-2.5, "2.5");
+    test(-0.1, "0.1");
+}
+
+#[test]
+fn abs_properties() {
+    rational_gen().test_properties(|x| {
+        let x = Float::from_rational_prec(x, 20).0;
+        let abs = (&x).abs();
+        assert!(abs.is_valid());
+        assert_eq!(abs.get_prec(), x.get_prec());
+        assert!(abs.is_sign_positive());
+        assert_eq!(ComparableFloat((&abs).abs()), ComparableFloat(abs.clone()));
+        assert_eq!(ComparableFloat((-&x).abs()), ComparableFloat(abs.clone()));
+        assert_eq!(
+            Rational::try_from(&abs).unwrap(),
+            Rational::try_from(&x).unwrap().abs()
+        );
+    });
+}
@@ -0,0 +1,261 @@
+use malachite_base::num::basic::traits::One;
+use malachite_base::num::conversion::traits::SciMantissaAndExponent;
+use malachite_base::rounding_modes::RoundingMode;
+use malachite_float::{ComparableFloat, Float};
+use malachite_q::test_util::generators::rational_pair_gen;
+use malachite_q::Rational;
+use std::cmp::Ordering;
+use std::f64::consts::{E, PI};
+
+const PRECS: [u64; 6] = [1, 2, 5, 10, 64, 100];
+const RMS: [RoundingMode; 5] = [
+    RoundingMode::Down,
+    RoundingMode::Up,
+    RoundingMode::Floor,
+    RoundingMode::Ceiling,
+    RoundingMode::Nearest,
+];
+
+#[test]
+fn test_add_prec_round() {
+    let test = |x: f64, y: f64, prec: u64, rm: RoundingMode, out: &str, o_out: Ordering| {
+        let x = Float::from(x);
+        let y = Float::from(y);
+
+        let (sum, o) = x.clone().add_prec_round(y.clone(), prec, rm);
+        assert!(sum.is_valid());
+        assert_eq!(sum.to_string(), out);
+        assert_eq!(o, o_out);
+
+        let (sum_alt, o_alt) = x.add_prec_round_ref_ref(&y, prec, rm);
+        assert!(sum_alt.is_valid());
+        assert_eq!(ComparableFloat(sum_alt), ComparableFloat(sum.clone()));
+        assert_eq!(o_alt, o);
+
+        if rm == RoundingMode::Nearest {
+            let (sum_alt, o_alt) = x.add_prec_ref_ref(&y, prec);
+            assert_eq!(ComparableFloat(sum_alt), ComparableFloat(sum));
+            assert_eq!(o_alt, o);
+        }
+    };
+    test(PI, E, 5, RoundingMode::Floor, "5.8", Ordering::Less);
+    test(PI, E, 5, RoundingMode::Ceiling, "6.0", Ordering::Greater);
+    test(PI, E, 5, RoundingMode::Nearest, "5.8", Ordering::Less);
+    test(PI, E, 20, RoundingMode::Nearest, "5.85987", Ordering::Less);
+    test(1.0, 2.0, 1, RoundingMode::Floor, "2.0", Ordering::Less);
+    test(1.0, 2.0, 1, RoundingMode::Ceiling, "4.0", Ordering::Greater);
+    test(1.0, 2.0, 1, RoundingMode::Nearest, "4.0", Ordering::Greater);
+    test(1.0, 2.0, 2, RoundingMode::Exact, "3.0", Ordering::Equal);
+    test(
+        1.0,
+        1.0e-100,
+        10,
+        RoundingMode::Nearest,
+        "1.0",
+        Ordering::Less,
+    );
+    test(
+        1.0,
+        1.0e-100,
+        10,
+        RoundingMode::Up,
+        "1.002",
+        Ordering::Greater,
+    );
+    test(
+        1.0,
+        -1.0e-100,
+        10,
+        RoundingMode::Down,
+        "0.999",
+        Ordering::Less,
+    );
+    test(1.0, -1.0, 10, RoundingMode::Nearest, "0.0", Ordering::Equal);
+    test(1.0, -1.0, 10, RoundingMode::Floor, "-0.0", Ordering::Equal);
+    test(0.0, -0.0, 10, RoundingMode::Nearest, "0.0", Ordering::Equal);
+    test(0.0, -0.0, 10, RoundingMode::Floor, "-0.0", Ordering::Equal);
+    test(
+        -0.0,
+        -0.0,
+        10,
+        RoundingMode::Ceiling,
+        "-0.0",
+        Ordering::Equal,
+    );
+    test(0.0, 1.5, 10, RoundingMode::Nearest, "1.5", Ordering::Equal);
+    test(
+        f64::INFINITY,
+        1.0,
+        10,
+        RoundingMode::Nearest,
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        f64::INFINITY,
+        f64::NEG_INFINITY,
+        10,
+        RoundingMode::Nearest,
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        f64::NAN,
+        1.0,
+        10,
+        RoundingMode::Nearest,
+        "NaN",
+        Ordering::Equal,
+    );
+}
+
+#[test]
+#[should_panic]
+fn add_prec_round_fail_1() {
+    Float::from(PI).add_prec_round(Float::from(E), 0, RoundingMode::Floor);
+}
+
+#[test]
+#[should_panic]
+fn add_prec_round_fail_2() {
+    Float::from(PI).add_prec_round(Float::from(E), 10, RoundingMode::Exact);
+}
+
+// Returns $m2^e$, where $1 \leq m < 2$.
+fn sci(m: f64, e: i64) -> Float {
+    <&Float as SciMantissaAndExponent<_, _, _>>::from_sci_mantissa_and_exponent(m, e).unwrap()
+}
+
+#[test]
+fn test_add_prec_round_overflow() {
+    let test = |x: &Float, y: &Float, prec: u64, rm: RoundingMode, out: &Float, o_out: Ordering| {
+        let (sum, o) = x.add_prec_round_ref_ref(y, prec, rm);
+        assert!(sum.is_valid());
+        assert_eq!(ComparableFloat(sum), ComparableFloat(out.clone()));
+        assert_eq!(o, o_out);
+
+        let (sum, o) = (-x).add_prec_round_ref_ref(&-y, prec, -rm);
+        assert!(sum.is_valid());
+        assert_eq!(ComparableFloat(sum), ComparableFloat(-out));
+        assert_eq!(o, o_out.reverse());
+    };
+    // The largest finite value with precision 3, plus itself
+    let max = sci(1.75, Float::MAX_EXPONENT - 1);
+    test(&max, &max, 3, RoundingMode::Down, &max, Ordering::Less);
+    test(&max, &max, 3, RoundingMode::Floor, &max, Ordering::Less);
+    test(
+        &max,
+        &max,
+        3,
+        RoundingMode::Up,
+        &Float::INFINITY,
+        Ordering::Greater,
+    );
+    test(
+        &max,
+        &max,
+        3,
+        RoundingMode::Ceiling,
+        &Float::INFINITY,
+        Ordering::Greater,
+    );
+    test(
+        &max,
+        &max,
+        3,
+        RoundingMode::Nearest,
+        &Float::INFINITY,
+        Ordering::Greater,
+    );
+
+    // Adding a tiny value to the largest finite value only overflows when rounding up
+    let tiny = Float::ONE;
+    test(&max, &tiny, 3, RoundingMode::Nearest, &max, Ordering::Less);
+    test(
+        &max,
+        &tiny,
+        3,
+        RoundingMode::Up,
+        &Float::INFINITY,
+        Ordering::Greater,
+    );
+
+    // Values near the smallest positive value
+    let smallest = sci(1.0, Float::MIN_EXPONENT - 1);
+    test(
+        &smallest,
+        &smallest,
+        1,
+        RoundingMode::Exact,
+        &sci(1.0, Float::MIN_EXPONENT),
+        Ordering::Equal,
+    );
+}
+
+#[test]
+fn test_add() {
+    let x = Float::from(PI);
+    let y = Float::from(E);
+    let (sum, _) = x.add_prec_round_ref_ref(&y, 53, RoundingMode::Nearest);
+    let sum = ComparableFloat(sum);
+
+    assert_eq!(ComparableFloat(x.clone() + y.clone()), sum);
+    assert_eq!(ComparableFloat(x.clone() + &y), sum);
+    assert_eq!(ComparableFloat(&x + y.clone()), sum);
+    assert_eq!(ComparableFloat(&x + &y), sum);
+
+    let mut z = x.clone();
+    z += y.clone();
+    assert_eq!(ComparableFloat(z), sum);
+
+    let mut z = x;
+    z += &y;
+    assert_eq!(ComparableFloat(z), sum);
+}
+
+#[test]
+fn add_prec_round_properties() {
+    rational_pair_gen().test_properties(|(x, y)| {
+        let x = Float::from_rational_prec(x, 20).0;
+        let y = Float::from_rational_prec(y, 10).0;
+        let x_q = Rational::try_from(&x).unwrap();
+        let y_q = Rational::try_from(&y).unwrap();
+        for prec in PRECS {
+            for rm in RMS {
+                let (sum, o) = x.add_prec_round_ref_ref(&y, prec, rm);
+                assert!(sum.is_valid());
+                let exact = &x_q + &y_q;
+                if exact == 0u32 {
+                    assert!(sum.is_zero());
+                    assert_eq!(o, Ordering::Equal);
+                    continue;
+                }
+                assert_eq!(sum.get_prec(), Some(prec));
+                assert_eq!(Rational::try_from(&sum).unwrap().cmp(&exact), o);
+                let (expected, o_alt) = Float::from_rational_prec_round_ref(&exact, prec, rm);
+                assert_eq!(ComparableFloat(sum.clone()), ComparableFloat(expected));
+                assert_eq!(o, o_alt);
+                match rm {
+                    RoundingMode::Floor => assert_ne!(o, Ordering::Greater),
+                    RoundingMode::Ceiling => assert_ne!(o, Ordering::Less),
+                    _ => {}
+                }
+            }
+        }
+    });
+
+    rational_pair_gen().test_properties(|(x, _)| {
+        let x = Float::from_rational_prec(x, 20).0;
+        for prec in PRECS {
+            for rm in RMS {
+                // x + -x is zero, and its sign depends only on the rounding mode
+                let (sum, o) = x.add_prec_round_ref_ref(&-&x, prec, rm);
+                if x.is_finite() {
+                    assert!(sum.is_zero());
+                    assert_eq!(sum.is_sign_negative(), rm == RoundingMode::Floor);
+                    assert_eq!(o, Ordering::Equal);
+                }
+            }
+        }
+    });
+}
@@ -0,0 +1,294 @@
+use malachite_base::num::basic::traits::{One, Zero};
+use malachite_base::num::conversion::traits::SciMantissaAndExponent;
+use malachite_base::rounding_modes::RoundingMode;
+use malachite_float::{ComparableFloat, Float};
+use malachite_q::test_util::generators::rational_pair_gen;
+use malachite_q::Rational;
+use std::cmp::Ordering;
+use std::f64::consts::{E, PI};
+
+const PRECS: [u64; 6] = [1, 2, 5, 10, 64, 100];
+const RMS: [RoundingMode; 5] = [
+    RoundingMode::Down,
+    RoundingMode::Up,
+    RoundingMode::Floor,
+    RoundingMode::Ceiling,
+    RoundingMode::Nearest,
+];
+
+#[test]
+fn test_div_prec_round() {
+    let test = |x: f64, y: f64, prec: u64, rm: RoundingMode, out: &str, o_out: Ordering| {
+        let x = Float::from(x);
+        let y = Float::from(y);
+
+        let (quotient, o) = x.clone().div_prec_round(y.clone(), prec, rm);
+        assert!(quotient.is_valid());
+        assert_eq!(quotient.to_string(), out);
+        assert_eq!(o, o_out);
+
+        let (quotient_alt, o_alt) = x.div_prec_round_ref_ref(&y, prec, rm);
+        assert!(quotient_alt.is_valid());
+        assert_eq!(
+            ComparableFloat(quotient_alt),
+            ComparableFloat(quotient.clone())
+        );
+        assert_eq!(o_alt, o);
+
+        if rm == RoundingMode::Nearest {
+            let (quotient_alt, o_alt) = x.div_prec_ref_ref(&y, prec);
+            assert_eq!(ComparableFloat(quotient_alt), ComparableFloat(quotient));
+            assert_eq!(o_alt, o);
+        }
+    };
+    test(PI, E, 5, RoundingMode::Floor, "1.1", Ordering::Less);
+    test(PI, E, 5, RoundingMode::Ceiling, "1.2", Ordering::Greater);
+    test(PI, E, 5, RoundingMode::Nearest, "1.1", Ordering::Less);
+    test(
+        PI,
+        E,
+        20,
+        RoundingMode::Nearest,
+        "1.155727",
+        Ordering::Greater,
+    );
+    test(1.0, 3.0, 10, RoundingMode::Floor, "0.333", Ordering::Less);
+    test(
+        1.0,
+        3.0,
+        10,
+        RoundingMode::Ceiling,
+        "0.3335",
+        Ordering::Greater,
+    );
+    test(
+        -1.0,
+        3.0,
+        10,
+        RoundingMode::Down,
+        "-0.333",
+        Ordering::Greater,
+    );
+    test(3.0, 4.0, 2, RoundingMode::Exact, "0.8", Ordering::Equal);
+    test(
+        1.0,
+        0.0,
+        10,
+        RoundingMode::Nearest,
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        1.0,
+        -0.0,
+        10,
+        RoundingMode::Nearest,
+        "-Infinity",
+        Ordering::Equal,
+    );
+    test(0.0, 0.0, 10, RoundingMode::Nearest, "NaN", Ordering::Equal);
+    test(
+        -0.0,
+        1.0,
+        10,
+        RoundingMode::Nearest,
+        "-0.0",
+        Ordering::Equal,
+    );
+    test(
+        1.0,
+        f64::NEG_INFINITY,
+        10,
+        RoundingMode::Nearest,
+        "-0.0",
+        Ordering::Equal,
+    );
+    test(
+        f64::INFINITY,
+        f64::INFINITY,
+        10,
+        RoundingMode::Nearest,
+        "NaN",
+        Ordering::Equal,
+    );
+}
+
+#[test]
+#[should_panic]
+fn div_prec_round_fail_1() {
+    Float::from(PI).div_prec_round(Float::from(E), 0, RoundingMode::Floor);
+}
+
+#[test]
+#[should_panic]
+fn div_prec_round_fail_2() {
+    Float::from(PI).div_prec_round(Float::from(E), 10, RoundingMode::Exact);
+}
+
+// Returns $m2^e$, where $1 \leq m < 2$.
+fn sci(m: f64, e: i64) -> Float {
+    <&Float as SciMantissaAndExponent<_, _, _>>::from_sci_mantissa_and_exponent(m, e).unwrap()
+}
+
+#[test]
+fn test_div_prec_round_overflow_and_underflow() {
+    let test = |x: &Float, y: &Float, prec: u64, rm: RoundingMode, out: &Float, o_out: Ordering| {
+        let (quotient, o) = x.div_prec_round_ref_ref(y, prec, rm);
+        assert!(quotient.is_valid());
+        assert_eq!(ComparableFloat(quotient), ComparableFloat(out.clone()));
+        assert_eq!(o, o_out);
+
+        let (quotient, o) = (-x).div_prec_round_ref_ref(y, prec, -rm);
+        assert!(quotient.is_valid());
+        assert_eq!(ComparableFloat(quotient), ComparableFloat(-out));
+        assert_eq!(o, o_out.reverse());
+    };
+    let largest = sci(1.0, Float::MAX_EXPONENT - 1);
+    let smallest = sci(1.0, Float::MIN_EXPONENT - 1);
+    let third = Float::from_rational_prec(Rational::from_signeds(1, 3), 10).0;
+    test(
+        &largest,
+        &smallest,
+        1,
+        RoundingMode::Down,
+        &largest,
+        Ordering::Less,
+    );
+    test(
+        &largest,
+        &smallest,
+        1,
+        RoundingMode::Up,
+        &Float::INFINITY,
+        Ordering::Greater,
+    );
+    test(
+        &largest,
+        &third,
+        1,
+        RoundingMode::Nearest,
+        &Float::INFINITY,
+        Ordering::Greater,
+    );
+    test(
+        &Float::ONE,
+        &largest,
+        1,
+        RoundingMode::Exact,
+        &sci(1.0, 1 - Float::MAX_EXPONENT),
+        Ordering::Equal,
+    );
+    test(
+        &smallest,
+        &largest,
+        1,
+        RoundingMode::Nearest,
+        &Float::ZERO,
+        Ordering::Less,
+    );
+    test(
+        &smallest,
+        &largest,
+        1,
+        RoundingMode::Up,
+        &smallest,
+        Ordering::Greater,
+    );
+    test(
+        &smallest,
+        &Float::from(3.0f64),
+        1,
+        RoundingMode::Nearest,
+        &Float::ZERO,
+        Ordering::Less,
+    );
+    test(
+        &smallest,
+        &Float::from(1.5f64),
+        1,
+        RoundingMode::Nearest,
+        &smallest,
+        Ordering::Greater,
+    );
+    test(
+        &smallest,
+        &Float::from(2.0f64),
+        1,
+        RoundingMode::Nearest,
+        &Float::ZERO,
+        Ordering::Less,
+    );
+}
+
+#[test]
+fn test_div() {
+    let x = Float::from(PI);
+    let y = Float::from(E);
+    let (quotient, _) = x.div_prec_round_ref_ref(&y, 53, RoundingMode::Nearest);
+    let quotient = ComparableFloat(quotient);
+
+    assert_eq!(ComparableFloat(x.clone() / y.clone()), quotient);
+    assert_eq!(ComparableFloat(x.clone() / &y), quotient);
+    assert_eq!(ComparableFloat(&x / y.clone()), quotient);
+    assert_eq!(ComparableFloat(&x / &y), quotient);
+
+    let mut z = x.clone();
+    z /= y.clone();
+    assert_eq!(ComparableFloat(z), quotient);
+
+    let mut z = x;
+    z /= &y;
+    assert_eq!(ComparableFloat(z), quotient);
+}
+
+#[test]
+fn div_prec_round_properties() {
+    rational_pair_gen().test_properties(|(x, y)| {
+        let x = Float::from_rational_prec(x, 20).0;
+        let y = Float::from_rational_prec(y, 10).0;
+        let x_q = Rational::try_from(&x).unwrap();
+        let y_q = Rational::try_from(&y).unwrap();
+        for prec in PRECS {
+            for rm in RMS {
+                let (quotient, o) = x.div_prec_round_ref_ref(&y, prec, rm);
+                assert!(quotient.is_valid());
+                if y_q == 0u32 {
+                    continue;
+                }
+                let exact = &x_q / &y_q;
+                if exact == 0u32 {
+                    assert!(quotient.is_zero());
+                    assert_eq!(o, Ordering::Equal);
+                    continue;
+                }
+                assert_eq!(quotient.get_prec(), Some(prec));
+                assert_eq!(Rational::try_from(&quotient).unwrap().cmp(&exact), o);
+                let (expected, o_alt) = Float::from_rational_prec_round_ref(&exact, prec, rm);
+                assert_eq!(ComparableFloat(quotient.clone()), ComparableFloat(expected));
+                assert_eq!(o, o_alt);
+                match rm {
+                    RoundingMode::Floor => assert_ne!(o, Ordering::Greater),
+                    RoundingMode::Ceiling => assert_ne!(o, Ordering::Less),
+                    _ => {}
+                }
+            }
+        }
+    });
+
+    rational_pair_gen().test_properties(|(x, _)| {
+        let x = Float::from_rational_prec(x, 20).0;
+        for prec in PRECS {
+            for rm in RMS {
+                // dividing by 1 rounds
+                let (quotient, o) = x.div_prec_round_ref_ref(&Float::ONE, prec, rm);
+                if let Ok(q) = Rational::try_from(&x) {
+                    if q != 0u32 {
+                        let (expected, o_alt) = Float::from_rational_prec_round(q, prec, rm);
+                        assert_eq!(ComparableFloat(quotient), ComparableFloat(expected));
+                        assert_eq!(o, o_alt);
+                    }
+                }
+            }
+        }
+    });
+}
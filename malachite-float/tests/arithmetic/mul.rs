@@ -0,0 +1,360 @@
+use malachite_base::num::basic::traits::{One, Two, Zero};
+use malachite_base::num::conversion::traits::SciMantissaAndExponent;
+use malachite_base::rounding_modes::RoundingMode;
+use malachite_float::{ComparableFloat, Float};
+use malachite_q::test_util::generators::rational_pair_gen;
+use malachite_q::Rational;
+use std::cmp::Ordering;
+use std::f64::consts::{E, PI};
+
+const PRECS: [u64; 6] = [1, 2, 5, 10, 64, 100];
+const RMS: [RoundingMode; 5] = [
+    RoundingMode::Down,
+    RoundingMode::Up,
+    RoundingMode::Floor,
+    RoundingMode::Ceiling,
+    RoundingMode::Nearest,
+];
+
+#[test]
+fn test_mul_prec_round() {
+    let test = |x: f64, y: f64, prec: u64, rm: RoundingMode, out: &str, o_out: Ordering| {
+        let x = Float::from(x);
+        let y = Float::from(y);
+
+        let (product, o) = x.clone().mul_prec_round(y.clone(), prec, rm);
+        assert!(product.is_valid());
+        assert_eq!(product.to_string(), out);
+        assert_eq!(o, o_out);
+
+        let (product_alt, o_alt) = x.mul_prec_round_ref_ref(&y, prec, rm);
+        assert!(product_alt.is_valid());
+        assert_eq!(
+            ComparableFloat(product_alt),
+            ComparableFloat(product.clone())
+        );
+        assert_eq!(o_alt, o);
+
+        if rm == RoundingMode::Nearest {
+            let (product_alt, o_alt) = x.mul_prec_ref_ref(&y, prec);
+            assert_eq!(ComparableFloat(product_alt), ComparableFloat(product));
+            assert_eq!(o_alt, o);
+        }
+    };
+    test(PI, E, 5, RoundingMode::Floor, "8.5", Ordering::Less);
+    test(PI, E, 5, RoundingMode::Ceiling, "9.0", Ordering::Greater);
+    test(PI, E, 5, RoundingMode::Nearest, "8.5", Ordering::Less);
+    test(PI, E, 20, RoundingMode::Nearest, "8.53973", Ordering::Less);
+    test(3.0, 3.0, 4, RoundingMode::Exact, "9.0", Ordering::Equal);
+    test(3.0, 3.0, 3, RoundingMode::Nearest, "8.0", Ordering::Less);
+    test(3.0, 3.0, 3, RoundingMode::Up, "10.0", Ordering::Greater);
+    test(-3.0, 3.0, 3, RoundingMode::Floor, "-10.0", Ordering::Less);
+    test(-3.0, 3.0, 3, RoundingMode::Down, "-8.0", Ordering::Greater);
+    test(
+        0.0,
+        -1.5,
+        10,
+        RoundingMode::Nearest,
+        "-0.0",
+        Ordering::Equal,
+    );
+    test(
+        -0.0,
+        -1.5,
+        10,
+        RoundingMode::Nearest,
+        "0.0",
+        Ordering::Equal,
+    );
+    test(
+        f64::INFINITY,
+        -1.5,
+        10,
+        RoundingMode::Nearest,
+        "-Infinity",
+        Ordering::Equal,
+    );
+    test(
+        f64::INFINITY,
+        0.0,
+        10,
+        RoundingMode::Nearest,
+        "NaN",
+        Ordering::Equal,
+    );
+}
+
+#[test]
+#[should_panic]
+fn mul_prec_round_fail_1() {
+    Float::from(PI).mul_prec_round(Float::from(E), 0, RoundingMode::Floor);
+}
+
+#[test]
+#[should_panic]
+fn mul_prec_round_fail_2() {
+    Float::from(PI).mul_prec_round(Float::from(E), 10, RoundingMode::Exact);
+}
+
+// Returns $m2^e$, where $1 \leq m < 2$.
+fn sci(m: f64, e: i64) -> Float {
+    <&Float as SciMantissaAndExponent<_, _, _>>::from_sci_mantissa_and_exponent(m, e).unwrap()
+}
+
+// Returns the smallest positive `Float` with precision `prec`.
+fn smallest_positive(prec: u64) -> Float {
+    sci(1.0, Float::MIN_EXPONENT - 1)
+        .mul_prec_round(Float::ONE, prec, RoundingMode::Exact)
+        .0
+}
+
+#[test]
+fn test_mul_prec_round_overflow_and_underflow() {
+    let test = |x: &Float, y: &Float, prec: u64, rm: RoundingMode, out: &Float, o_out: Ordering| {
+        let (product, o) = x.mul_prec_round_ref_ref(y, prec, rm);
+        assert!(product.is_valid());
+        assert_eq!(ComparableFloat(product), ComparableFloat(out.clone()));
+        assert_eq!(o, o_out);
+
+        let (product, o) = (-x).mul_prec_round_ref_ref(y, prec, -rm);
+        assert!(product.is_valid());
+        assert_eq!(ComparableFloat(product), ComparableFloat(-out));
+        assert_eq!(o, o_out.reverse());
+    };
+    // 2^(2^61) * 2^(2^61) = 2^(2^62), whose exponent is MAX_EXPONENT + 2
+    let big = sci(1.0, 1 << 61);
+    let max = sci(1.75, Float::MAX_EXPONENT - 1);
+    test(&big, &big, 3, RoundingMode::Down, &max, Ordering::Less);
+    test(&big, &big, 3, RoundingMode::Floor, &max, Ordering::Less);
+    test(
+        &big,
+        &big,
+        3,
+        RoundingMode::Up,
+        &Float::INFINITY,
+        Ordering::Greater,
+    );
+    test(
+        &big,
+        &big,
+        3,
+        RoundingMode::Ceiling,
+        &Float::INFINITY,
+        Ordering::Greater,
+    );
+    test(
+        &big,
+        &big,
+        3,
+        RoundingMode::Nearest,
+        &Float::INFINITY,
+        Ordering::Greater,
+    );
+
+    // The largest power of 2 is representable, but rounding 1.5 times it up overflows
+    let largest = sci(1.0, Float::MAX_EXPONENT - 1);
+    let x = sci(1.0, (Float::MAX_EXPONENT - 1) / 2);
+    test(&x, &x, 1, RoundingMode::Exact, &largest, Ordering::Equal);
+    let y = sci(1.5, (Float::MAX_EXPONENT - 1) / 2);
+    test(&x, &y, 1, RoundingMode::Down, &largest, Ordering::Less);
+    test(
+        &x,
+        &y,
+        1,
+        RoundingMode::Up,
+        &Float::INFINITY,
+        Ordering::Greater,
+    );
+    test(
+        &x,
+        &y,
+        1,
+        RoundingMode::Nearest,
+        &Float::INFINITY,
+        Ordering::Greater,
+    );
+
+    // The smallest positive value is 2^(MIN_EXPONENT - 1)
+    let x = sci(1.0, (Float::MIN_EXPONENT - 1) / 2);
+    test(
+        &x,
+        &x,
+        1,
+        RoundingMode::Exact,
+        &smallest_positive(1),
+        Ordering::Equal,
+    );
+
+    // 2^(-2^61 - 1) * 2^(-2^61 - 1) = 2^(-2^62 - 2), whose exponent is MIN_EXPONENT - 2
+    let tiny = sci(1.0, -(1 << 61) - 1);
+    test(
+        &tiny,
+        &tiny,
+        10,
+        RoundingMode::Down,
+        &Float::ZERO,
+        Ordering::Less,
+    );
+    test(
+        &tiny,
+        &tiny,
+        10,
+        RoundingMode::Floor,
+        &Float::ZERO,
+        Ordering::Less,
+    );
+    test(
+        &tiny,
+        &tiny,
+        10,
+        RoundingMode::Nearest,
+        &Float::ZERO,
+        Ordering::Less,
+    );
+    test(
+        &tiny,
+        &tiny,
+        10,
+        RoundingMode::Up,
+        &smallest_positive(10),
+        Ordering::Greater,
+    );
+    test(
+        &tiny,
+        &tiny,
+        10,
+        RoundingMode::Ceiling,
+        &smallest_positive(10),
+        Ordering::Greater,
+    );
+
+    // Half of the smallest positive value is a tie, which is rounded to zero, but anything larger
+    // is rounded to the smallest positive value
+    let smallest = smallest_positive(1);
+    let half = Float::from(0.5f64);
+    let three_quarters = Float::from(0.75f64);
+    test(
+        &smallest,
+        &half,
+        3,
+        RoundingMode::Nearest,
+        &Float::ZERO,
+        Ordering::Less,
+    );
+    test(
+        &smallest,
+        &half,
+        3,
+        RoundingMode::Ceiling,
+        &smallest_positive(3),
+        Ordering::Greater,
+    );
+    test(
+        &smallest,
+        &three_quarters,
+        3,
+        RoundingMode::Nearest,
+        &smallest_positive(3),
+        Ordering::Greater,
+    );
+
+    // Repeatedly squaring 2 eventually overflows, and repeatedly squaring 1/2 eventually underflows
+    let mut x = Float::TWO;
+    let mut y = half;
+    for _ in 0..61 {
+        x = &x * &x;
+        y = &y * &y;
+    }
+    assert_eq!(x.get_exponent(), Some((1 << 61) + 1));
+    assert_eq!(y.get_exponent(), Some(1 - (1 << 61)));
+    x = &x * &x;
+    y = &y * &y;
+    assert_eq!(ComparableFloat(x), ComparableFloat(Float::INFINITY));
+    assert_eq!(ComparableFloat(y.clone()), ComparableFloat(smallest));
+    y = &y * &y;
+    assert_eq!(ComparableFloat(y), ComparableFloat(Float::ZERO));
+}
+
+#[test]
+#[should_panic]
+fn mul_prec_round_fail_3() {
+    let big = sci(1.0, 1 << 61);
+    big.mul_prec_round_ref_ref(&big, 10, RoundingMode::Exact);
+}
+
+#[test]
+#[should_panic]
+fn mul_prec_round_fail_4() {
+    let tiny = sci(1.0, -(1 << 61) - 1);
+    tiny.mul_prec_round_ref_ref(&tiny, 10, RoundingMode::Exact);
+}
+
+#[test]
+fn test_mul() {
+    let x = Float::from(PI);
+    let y = Float::from(E);
+    let (product, _) = x.mul_prec_round_ref_ref(&y, 53, RoundingMode::Nearest);
+    let product = ComparableFloat(product);
+
+    assert_eq!(ComparableFloat(x.clone() * y.clone()), product);
+    assert_eq!(ComparableFloat(x.clone() * &y), product);
+    assert_eq!(ComparableFloat(&x * y.clone()), product);
+    assert_eq!(ComparableFloat(&x * &y), product);
+
+    let mut z = x.clone();
+    z *= y.clone();
+    assert_eq!(ComparableFloat(z), product);
+
+    let mut z = x;
+    z *= &y;
+    assert_eq!(ComparableFloat(z), product);
+}
+
+#[test]
+fn mul_prec_round_properties() {
+    rational_pair_gen().test_properties(|(x, y)| {
+        let x = Float::from_rational_prec(x, 20).0;
+        let y = Float::from_rational_prec(y, 10).0;
+        let x_q = Rational::try_from(&x).unwrap();
+        let y_q = Rational::try_from(&y).unwrap();
+        for prec in PRECS {
+            for rm in RMS {
+                let (product, o) = x.mul_prec_round_ref_ref(&y, prec, rm);
+                assert!(product.is_valid());
+                let exact = &x_q * &y_q;
+                if exact == 0u32 {
+                    assert!(product.is_zero());
+                    assert_eq!(o, Ordering::Equal);
+                    continue;
+                }
+                assert_eq!(product.get_prec(), Some(prec));
+                assert_eq!(Rational::try_from(&product).unwrap().cmp(&exact), o);
+                let (expected, o_alt) = Float::from_rational_prec_round_ref(&exact, prec, rm);
+                assert_eq!(ComparableFloat(product.clone()), ComparableFloat(expected));
+                assert_eq!(o, o_alt);
+                match rm {
+                    RoundingMode::Floor => assert_ne!(o, Ordering::Greater),
+                    RoundingMode::Ceiling => assert_ne!(o, Ordering::Less),
+                    _ => {}
+                }
+            }
+        }
+    });
+
+    rational_pair_gen().test_properties(|(x, _)| {
+        let x = Float::from_rational_prec(x, 20).0;
+        for prec in PRECS {
+            for rm in RMS {
+                // multiplying by 1 rounds
+                let (product, o) = x.mul_prec_round_ref_ref(&Float::ONE, prec, rm);
+                if let Ok(q) = Rational::try_from(&x) {
+                    if q != 0u32 {
+                        let (expected, o_alt) = Float::from_rational_prec_round(q, prec, rm);
+                        assert_eq!(ComparableFloat(product), ComparableFloat(expected));
+                        assert_eq!(o, o_alt);
+                    }
+                }
+            }
+        }
+    });
+}
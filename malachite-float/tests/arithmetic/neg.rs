@@ -0,0 +1,48 @@
+use malachite_base::num::arithmetic::traits::NegAssign;
+use malachite_float::{ComparableFloat, Float};
+use malachite_q::test_util::generators::rational_gen;
+use malachite_q::Rational;
+
+#[test]
+fn test_neg() {
+    let test = |x: f64, out: &str| {
+        let x = Float::from(x);
+
+        let neg = -x.clone();
+        assert!(neg.is_valid());
+        assert_eq!(neg.to_string(), out);
+
+        let neg_alt = -&x;
+        assert!(neg_alt.is_valid());
+        assert_eq!(ComparableFloat(neg_alt), ComparableFloat(neg.clone()));
+
+        let mut neg_alt = x;
+        neg_alt.neg_assign();
+        assert!(neg_alt.is_valid());
+        assert_eq!(ComparableFloat(neg_alt), ComparableFloat(neg));
+    };
+    test(f64::NAN, "NaN");
+    test(f64::INFINITY, "-Infinity");
+    test(f64::NEG_INFINITY, "Infinity");
+    test(0.0, "-0.0");
+    test(-0.0, "0.0");
+    test(1.0, "-1.0");
+    test(-2.5, "2.5");
+    test(0.1, "-0.1");
+}
+
+#[test]
+fn neg_properties() {
+    rational_gen().test_properties(|x| {
+        let x = Float::from_rational_prec(x, 20).0;
+        let neg = -&x;
+        assert!(neg.is_valid());
+        assert_eq!(neg.get_prec(), x.get_prec());
+        assert_eq!(ComparableFloat(-neg.clone()), ComparableFloat(x.clone()));
+        assert_eq!(
+            Rational::try_from(&neg).unwrap(),
+            -Rational::try_from(&x).unwrap()
+        );
+        assert_ne!(neg.is_sign_negative(), x.is_sign_negative());
+    });
+}
@@ -0,0 +1,130 @@
+use malachite_base::num::arithmetic::traits::{Abs, Sqrt, SqrtAssign, Square};
+use malachite_base::num::basic::traits::One;
+use malachite_base::rounding_modes::RoundingMode;
+use malachite_float::{ComparableFloat, Float};
+use malachite_q::test_util::generators::rational_gen;
+use malachite_q::Rational;
+use std::cmp::Ordering;
+
+const PRECS: [u64; 6] = [1, 2, 5, 10, 64, 100];
+const RMS: [RoundingMode; 5] = [
+    RoundingMode::Down,
+    RoundingMode::Up,
+    RoundingMode::Floor,
+    RoundingMode::Ceiling,
+    RoundingMode::Nearest,
+];
+
+#[test]
+fn test_sqrt_prec_round() {
+    let test = |x: f64, prec: u64, rm: RoundingMode, out: &str, o_out: Ordering| {
+        let x = Float::from(x);
+
+        let (sqrt, o) = x.clone().sqrt_prec_round(prec, rm);
+        assert!(sqrt.is_valid());
+        assert_eq!(sqrt.to_string(), out);
+        assert_eq!(o, o_out);
+
+        let (sqrt_alt, o_alt) = x.sqrt_prec_round_ref(prec, rm);
+        assert!(sqrt_alt.is_valid());
+        assert_eq!(ComparableFloat(sqrt_alt), ComparableFloat(sqrt.clone()));
+        assert_eq!(o_alt, o);
+
+        if rm == RoundingMode::Nearest {
+            let (sqrt_alt, o_alt) = x.sqrt_prec_ref(prec);
+            assert_eq!(ComparableFloat(sqrt_alt), ComparableFloat(sqrt));
+            assert_eq!(o_alt, o);
+        }
+    };
+    test(2.0, 5, RoundingMode::Floor, "1.4", Ordering::Less);
+    test(2.0, 5, RoundingMode::Ceiling, "1.44", Ordering::Greater);
+    test(2.0, 5, RoundingMode::Nearest, "1.44", Ordering::Greater);
+    test(2.0, 20, RoundingMode::Nearest, "1.414213", Ordering::Less);
+    test(4.0, 1, RoundingMode::Exact, "2.0", Ordering::Equal);
+    test(2.25, 2, RoundingMode::Exact, "1.5", Ordering::Equal);
+    test(0.5, 10, RoundingMode::Down, "0.707", Ordering::Less);
+    test(9.0, 1, RoundingMode::Nearest, "4.0", Ordering::Greater);
+    test(9.0, 1, RoundingMode::Down, "2.0", Ordering::Less);
+    test(0.0, 10, RoundingMode::Nearest, "0.0", Ordering::Equal);
+    test(-0.0, 10, RoundingMode::Nearest, "-0.0", Ordering::Equal);
+    test(-1.0, 10, RoundingMode::Nearest, "NaN", Ordering::Equal);
+    test(
+        f64::INFINITY,
+        10,
+        RoundingMode::Nearest,
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        f64::NEG_INFINITY,
+        10,
+        RoundingMode::Nearest,
+        "NaN",
+        Ordering::Equal,
+    );
+    test(f64::NAN, 10, RoundingMode::Nearest, "NaN", Ordering::Equal);
+}
+
+#[test]
+#[should_panic]
+fn sqrt_prec_round_fail_1() {
+    Float::from(2.0f64).sqrt_prec_round(0, RoundingMode::Floor);
+}
+
+#[test]
+#[should_panic]
+fn sqrt_prec_round_fail_2() {
+    Float::from(2.0f64).sqrt_prec_round(10, RoundingMode::Exact);
+}
+
+#[test]
+fn test_sqrt() {
+    let x = Float::from(2.0f64);
+    let (sqrt, _) = x.sqrt_round_ref(RoundingMode::Nearest);
+    assert_eq!(sqrt.get_prec(), Some(1));
+    let sqrt = ComparableFloat(sqrt);
+
+    assert_eq!(ComparableFloat(x.clone().sqrt()), sqrt);
+    assert_eq!(ComparableFloat((&x).sqrt()), sqrt);
+
+    let mut y = x;
+    y.sqrt_assign();
+    assert_eq!(ComparableFloat(y), sqrt);
+}
+
+#[test]
+fn sqrt_prec_round_properties() {
+    rational_gen().test_properties(|x| {
+        let x = Float::from_rational_prec(x.abs(), 20).0;
+        let x_q = Rational::try_from(&x).unwrap();
+        for prec in PRECS {
+            for rm in RMS {
+                let (sqrt, o) = x.sqrt_prec_round_ref(prec, rm);
+                assert!(sqrt.is_valid());
+                if x_q == 0u32 {
+                    assert!(sqrt.is_zero());
+                    assert_eq!(o, Ordering::Equal);
+                    continue;
+                }
+                assert_eq!(sqrt.get_prec(), Some(prec));
+                let sqrt_q = Rational::try_from(&sqrt).unwrap();
+                assert_eq!((&sqrt_q).square().cmp(&x_q), o);
+                if rm == RoundingMode::Floor {
+                    // the next Float up is too large
+                    let ulp = Rational::ONE << (sqrt.get_exponent().unwrap() - prec as i64);
+                    assert_eq!((sqrt_q + ulp).square().cmp(&x_q), Ordering::Greater);
+                }
+                match rm {
+                    RoundingMode::Floor | RoundingMode::Down => assert_ne!(o, Ordering::Greater),
+                    RoundingMode::Ceiling | RoundingMode::Up => assert_ne!(o, Ordering::Less),
+                    _ => {}
+                }
+            }
+            let (floor, _) = x.sqrt_prec_round_ref(prec, RoundingMode::Floor);
+            let (ceiling, _) = x.sqrt_prec_round_ref(prec, RoundingMode::Ceiling);
+            let (nearest, _) = x.sqrt_prec_ref(prec);
+            let nearest = ComparableFloat(nearest);
+            assert!(nearest == ComparableFloat(floor) || nearest == ComparableFloat(ceiling));
+        }
+    });
+}
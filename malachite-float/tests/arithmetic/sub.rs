@@ -0,0 +1,181 @@
+use malachite_base::rounding_modes::RoundingMode;
+use malachite_float::{ComparableFloat, Float};
+use malachite_q::test_util::generators::rational_pair_gen;
+use malachite_q::Rational;
+use std::cmp::Ordering;
+use std::f64::consts::{E, PI};
+
+const PRECS: [u64; 6] = [1, 2, 5, 10, 64, 100];
+const RMS: [RoundingMode; 5] = [
+    RoundingMode::Down,
+    RoundingMode::Up,
+    RoundingMode::Floor,
+    RoundingMode::Ceiling,
+    RoundingMode::Nearest,
+];
+
+#[test]
+fn test_sub_prec_round() {
+    let test = |x: f64, y: f64, prec: u64, rm: RoundingMode, out: &str, o_out: Ordering| {
+        let x = Float::from(x);
+        let y = Float::from(y);
+
+        let (difference, o) = x.clone().sub_prec_round(y.clone(), prec, rm);
+        assert!(difference.is_valid());
+        assert_eq!(difference.to_string(), out);
+        assert_eq!(o, o_out);
+
+        let (difference_alt, o_alt) = x.sub_prec_round_ref_ref(&y, prec, rm);
+        assert!(difference_alt.is_valid());
+        assert_eq!(
+            ComparableFloat(difference_alt),
+            ComparableFloat(difference.clone())
+        );
+        assert_eq!(o_alt, o);
+
+        if rm == RoundingMode::Nearest {
+            let (difference_alt, o_alt) = x.sub_prec_ref_ref(&y, prec);
+            assert_eq!(ComparableFloat(difference_alt), ComparableFloat(difference));
+            assert_eq!(o_alt, o);
+        }
+    };
+    test(PI, E, 5, RoundingMode::Floor, "0.42", Ordering::Less);
+    test(PI, E, 5, RoundingMode::Ceiling, "0.44", Ordering::Greater);
+    test(PI, E, 5, RoundingMode::Nearest, "0.42", Ordering::Less);
+    test(
+        PI,
+        E,
+        20,
+        RoundingMode::Nearest,
+        "0.4233108",
+        Ordering::Less,
+    );
+    test(3.0, 1.0, 1, RoundingMode::Exact, "2.0", Ordering::Equal);
+    test(
+        1.0,
+        1.0e-100,
+        10,
+        RoundingMode::Nearest,
+        "1.0",
+        Ordering::Greater,
+    );
+    test(
+        1.0,
+        1.0e-100,
+        10,
+        RoundingMode::Down,
+        "0.999",
+        Ordering::Less,
+    );
+    test(1.5, 1.5, 10, RoundingMode::Nearest, "0.0", Ordering::Equal);
+    test(1.5, 1.5, 10, RoundingMode::Floor, "-0.0", Ordering::Equal);
+    test(0.0, 0.0, 10, RoundingMode::Floor, "-0.0", Ordering::Equal);
+    test(
+        -0.0,
+        0.0,
+        10,
+        RoundingMode::Nearest,
+        "-0.0",
+        Ordering::Equal,
+    );
+    test(0.0, 1.5, 10, RoundingMode::Nearest, "-1.5", Ordering::Equal);
+    test(
+        f64::INFINITY,
+        f64::INFINITY,
+        10,
+        RoundingMode::Nearest,
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        1.0,
+        f64::INFINITY,
+        10,
+        RoundingMode::Nearest,
+        "-Infinity",
+        Ordering::Equal,
+    );
+}
+
+#[test]
+#[should_panic]
+fn sub_prec_round_fail_1() {
+    Float::from(PI).sub_prec_round(Float::from(E), 0, RoundingMode::Floor);
+}
+
+#[test]
+#[should_panic]
+fn sub_prec_round_fail_2() {
+    Float::from(PI).sub_prec_round(Float::from(E), 10, RoundingMode::Exact);
+}
+
+#[test]
+fn test_sub() {
+    let x = Float::from(PI);
+    let y = Float::from(E);
+    let (difference, _) = x.sub_prec_round_ref_ref(&y, 53, RoundingMode::Nearest);
+    let difference = ComparableFloat(difference);
+
+    assert_eq!(ComparableFloat(x.clone() - y.clone()), difference);
+    assert_eq!(ComparableFloat(x.clone() - &y), difference);
+    assert_eq!(ComparableFloat(&x - y.clone()), difference);
+    assert_eq!(ComparableFloat(&x - &y), difference);
+
+    let mut z = x.clone();
+    z -= y.clone();
+    assert_eq!(ComparableFloat(z), difference);
+
+    let mut z = x;
+    z -= &y;
+    assert_eq!(ComparableFloat(z), difference);
+}
+
+#[test]
+fn sub_prec_round_properties() {
+    rational_pair_gen().test_properties(|(x, y)| {
+        let x = Float::from_rational_prec(x, 20).0;
+        let y = Float::from_rational_prec(y, 10).0;
+        let x_q = Rational::try_from(&x).unwrap();
+        let y_q = Rational::try_from(&y).unwrap();
+        for prec in PRECS {
+            for rm in RMS {
+                let (difference, o) = x.sub_prec_round_ref_ref(&y, prec, rm);
+                assert!(difference.is_valid());
+                let exact = &x_q - &y_q;
+                if exact == 0u32 {
+                    assert!(difference.is_zero());
+                    assert_eq!(o, Ordering::Equal);
+                    continue;
+                }
+                assert_eq!(difference.get_prec(), Some(prec));
+                assert_eq!(Rational::try_from(&difference).unwrap().cmp(&exact), o);
+                let (expected, o_alt) = Float::from_rational_prec_round_ref(&exact, prec, rm);
+                assert_eq!(
+                    ComparableFloat(difference.clone()),
+                    ComparableFloat(expected)
+                );
+                assert_eq!(o, o_alt);
+                match rm {
+                    RoundingMode::Floor => assert_ne!(o, Ordering::Greater),
+                    RoundingMode::Ceiling => assert_ne!(o, Ordering::Less),
+                    _ => {}
+                }
+            }
+        }
+    });
+
+    rational_pair_gen().test_properties(|(x, _)| {
+        let x = Float::from_rational_prec(x, 20).0;
+        for prec in PRECS {
+            for rm in RMS {
+                // x - x is zero, and its sign depends only on the rounding mode
+                let (difference, o) = x.sub_prec_round_ref_ref(&x, prec, rm);
+                if x.is_finite() {
+                    assert!(difference.is_zero());
+                    assert_eq!(difference.is_sign_negative(), rm == RoundingMode::Floor);
+                    assert_eq!(o, Ordering::Equal);
+                }
+            }
+        }
+    });
+}
@@ -0,0 +1,78 @@
+use malachite_base::num::basic::traits::{NegativeOne, One, Two, Zero};
+use malachite_float::Float;
+use malachite_nz::natural::Natural;
+use malachite_q::test_util::generators::rational_gen;
+
+#[test]
+fn test_classification() {
+    let test = |x: Float,
+                nan: bool,
+                infinite: bool,
+                finite: bool,
+                zero: bool,
+                positive: bool,
+                negative: bool| {
+        assert!(x.is_valid());
+        assert_eq!(x.is_nan(), nan);
+        assert_eq!(x.is_infinite(), infinite);
+        assert_eq!(x.is_finite(), finite);
+        assert_eq!(x.is_zero(), zero);
+        assert_eq!(x.is_sign_positive(), positive);
+        assert_eq!(x.is_sign_negative(), negative);
+    };
+    test(Float::NAN, true, false, false, false, false, false);
+    test(Float::INFINITY, false, true, false, false, true, false);
+    test(
+        Float::NEGATIVE_INFINITY,
+        false,
+        true,
+        false,
+        false,
+        false,
+        true,
+    );
+    test(Float::ZERO, false, false, true, true, true, false);
+    test(Float::NEGATIVE_ZERO, false, false, true, true, false, true);
+    test(Float::ONE, false, false, true, false, true, false);
+    test(Float::NEGATIVE_ONE, false, false, true, false, false, true);
+    test(Float::from(0.1f64), false, false, true, false, true, false);
+}
+
+#[test]
+fn test_components() {
+    let test = |x: Float, prec: Option<u64>, exponent: Option<i64>, significand: Option<u32>| {
+        assert_eq!(x.get_prec(), prec);
+        assert_eq!(x.get_exponent(), exponent);
+        assert_eq!(x.significand_ref().cloned(), significand.map(Natural::from));
+    };
+    test(Float::NAN, None, None, None);
+    test(Float::INFINITY, None, None, None);
+    test(Float::NEGATIVE_ZERO, None, None, None);
+    test(Float::ONE, Some(1), Some(1), Some(1));
+    test(Float::TWO, Some(1), Some(2), Some(1));
+    test(Float::NEGATIVE_ONE, Some(1), Some(1), Some(1));
+    test(Float::from(1.5f64), Some(2), Some(1), Some(3));
+    test(Float::from(0.75f64), Some(2), Some(0), Some(3));
+    test(Float::from(-100.0f64), Some(5), Some(7), Some(25));
+    test(Float::from(-0.375f64), Some(2), Some(-1), Some(3));
+}
+
+#[test]
+fn classification_properties() {
+    rational_gen().test_properties(|x| {
+        let zero = x == 0u32;
+        let positive = x >= 0u32;
+        let x = Float::from_rational_prec(x, 10).0;
+        assert!(!x.is_nan());
+        assert!(!x.is_infinite());
+        assert!(x.is_finite());
+        assert_eq!(x.is_zero(), zero);
+        assert_eq!(x.is_sign_positive(), positive);
+        assert_ne!(x.is_sign_positive(), x.is_sign_negative());
+        assert_eq!(x.get_prec().is_some(), !zero);
+        assert_eq!(x.get_exponent().is_some(), !zero);
+        if let Some(prec) = x.get_prec() {
+            assert_eq!(prec, 10);
+        }
+    });
+}
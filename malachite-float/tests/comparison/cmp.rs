@@ -0,0 +1,49 @@
+use malachite_float::Float;
+use malachite_q::test_util::generators::rational_pair_gen;
+use malachite_q::Rational;
+use std::cmp::Ordering;
+
+#[test]
+fn test_partial_cmp() {
+    let xs = [
+        Float::NEGATIVE_INFINITY,
+        Float::from(-100.0f64),
+        Float::from(-1.5f64),
+        Float::NEGATIVE_ZERO,
+        Float::from(0.1f64),
+        Float::from(0.1f32),
+        Float::from(1.0f64),
+        Float::from(1.0e100f64),
+        Float::INFINITY,
+    ];
+    for (i, x) in xs.iter().enumerate() {
+        for (j, y) in xs.iter().enumerate() {
+            assert_eq!(x.partial_cmp(y), Some(i.cmp(&j)));
+        }
+        assert_eq!(x.partial_cmp(&Float::NAN), None);
+        assert_eq!(Float::NAN.partial_cmp(x), None);
+    }
+    assert_eq!(
+        Float::NEGATIVE_ZERO.partial_cmp(&Float::from(0.0f64)),
+        Some(Ordering::Equal)
+    );
+    assert_eq!(Float::NAN.partial_cmp(&Float::NAN), None);
+}
+
+#[test]
+fn partial_cmp_properties() {
+    rational_pair_gen().test_properties(|(x, y)| {
+        let x = Float::from_rational_prec(x, 20).0;
+        let y = Float::from_rational_prec(y, 10).0;
+        let ord = x.partial_cmp(&y);
+        assert_eq!(
+            ord,
+            Rational::try_from(&x)
+                .unwrap()
+                .partial_cmp(&Rational::try_from(&y).unwrap())
+        );
+        assert_eq!(y.partial_cmp(&x), ord.map(Ordering::reverse));
+        assert_eq!((-&y).partial_cmp(&-&x), ord);
+        assert_eq!(x < y, ord == Some(Ordering::Less));
+    });
+}
@@ -0,0 +1,54 @@
+use malachite_float::{ComparableFloat, Float};
+use malachite_q::test_util::generators::rational_pair_gen;
+use malachite_q::Rational;
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+
+fn hash<T: Hash>(x: &T) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    x.hash(&mut hasher);
+    hasher.finish()
+}
+
+#[test]
+fn test_eq() {
+    let test = |x: Float, y: Float, eq: bool, comparable_eq: bool| {
+        assert_eq!(x == y, eq);
+        assert_eq!(y == x, eq);
+        let x = ComparableFloat(x);
+        let y = ComparableFloat(y);
+        assert_eq!(x == y, comparable_eq);
+        assert_eq!(y == x, comparable_eq);
+        if comparable_eq {
+            assert_eq!(hash(&x), hash(&y));
+        }
+    };
+    test(Float::NAN, Float::NAN, false, true);
+    test(Float::INFINITY, Float::INFINITY, true, true);
+    test(Float::INFINITY, Float::NEGATIVE_INFINITY, false, false);
+    test(Float::from(0.0f64), Float::NEGATIVE_ZERO, true, false);
+    test(Float::NEGATIVE_ZERO, Float::NEGATIVE_ZERO, true, true);
+    test(Float::from(1.5f64), Float::from(1.5f32), true, true);
+    test(Float::from(1.5f64), Float::from(-1.5f64), false, false);
+    test(Float::from(0.1f64), Float::from(0.1f32), false, false);
+    // the same value with different precisions
+    let x = Float::from_rational_prec(Rational::from(3), 10).0;
+    test(x, Float::from(3.0f64), true, false);
+}
+
+#[test]
+fn eq_properties() {
+    rational_pair_gen().test_properties(|(x, y)| {
+        let x = Float::from_rational_prec(x, 20).0;
+        let y = Float::from_rational_prec(y, 10).0;
+        assert_eq!(
+            x == y,
+            Rational::try_from(&x).unwrap() == Rational::try_from(&y).unwrap()
+        );
+        assert_eq!(x, x.clone());
+        assert_eq!(ComparableFloat(x.clone()), ComparableFloat(x.clone()));
+        if ComparableFloat(x.clone()) == ComparableFloat(y.clone()) {
+            assert_eq!(x, y);
+        }
+    });
+}
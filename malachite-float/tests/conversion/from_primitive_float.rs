@@ -0,0 +1,54 @@
+use malachite_base::num::basic::floats::PrimitiveFloat;
+use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_base::num::float::NiceFloat;
+use malachite_base::test_util::generators::{primitive_float_gen, primitive_float_gen_var_8};
+use malachite_float::{ComparableFloat, Float};
+use malachite_q::Rational;
+
+#[test]
+fn test_from_primitive_float() {
+    let test = |x: f64, out: &str, prec: Option<u64>| {
+        let f = Float::from(x);
+        assert!(f.is_valid());
+        assert_eq!(f.to_string(), out);
+        assert_eq!(f.get_prec(), prec);
+    };
+    test(f64::NAN, "NaN", None);
+    test(f64::INFINITY, "Infinity", None);
+    test(f64::NEG_INFINITY, "-Infinity", None);
+    test(0.0, "0.0", None);
+    test(-0.0, "-0.0", None);
+    test(1.0, "1.0", Some(1));
+    test(-123.0, "-123.0", Some(7));
+    test(0.1, "0.1", Some(52));
+    test(std::f64::consts::PI, "3.141592653589793", Some(50));
+    test(1.0e100, "1.0e100", Some(53));
+    test(f64::MIN_POSITIVE_SUBNORMAL, "5.0e-324", Some(1));
+    test(f64::MAX_FINITE, "1.7976931348623157e308", Some(53));
+
+    let f = Float::from(0.1f32);
+    assert_eq!(f.to_string(), "0.1");
+    assert_eq!(f.get_prec(), Some(24));
+}
+
+#[test]
+fn from_primitive_float_properties() {
+    primitive_float_gen::<f64>().test_properties(|x| {
+        let f = Float::from(x);
+        assert!(f.is_valid());
+        assert_eq!(f.is_nan(), x.is_nan());
+        assert_eq!(f.is_sign_negative(), x.is_sign_negative() && !x.is_nan());
+        assert_eq!(NiceFloat(f64::try_from(&f).unwrap()), NiceFloat(x));
+        assert_eq!(ComparableFloat(Float::from(x)), ComparableFloat(f));
+    });
+
+    primitive_float_gen_var_8::<f64>().test_properties(|x| {
+        let f = Float::from(x);
+        if x == 0.0 {
+            assert!(f.is_zero());
+            return;
+        }
+        assert_eq!(Rational::try_from(&f).unwrap(), Rational::exact_from(x));
+        assert!(f.get_prec().unwrap() <= 53);
+    });
+}
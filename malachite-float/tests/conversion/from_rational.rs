@@ -0,0 +1,153 @@
+use malachite_base::num::basic::traits::Zero;
+use malachite_base::num::conversion::traits::ConvertibleFrom;
+use malachite_base::rounding_modes::RoundingMode;
+use malachite_float::conversion::from_rational::FloatFromRationalError;
+use malachite_float::{ComparableFloat, Float};
+use malachite_q::test_util::generators::rational_gen;
+use malachite_q::Rational;
+use std::cmp::Ordering;
+use std::str::FromStr;
+
+#[test]
+fn test_from_rational_prec_round() {
+    let test = |s: &str, prec: u64, rm: RoundingMode, out: &str, o_out: Ordering| {
+        let x = Rational::from_str(s).unwrap();
+
+        let (f, o) = Float::from_rational_prec_round(x.clone(), prec, rm);
+        assert!(f.is_valid());
+        assert_eq!(f.to_string(), out);
+        assert_eq!(o, o_out);
+
+        let (f_alt, o_alt) = Float::from_rational_prec_round_ref(&x, prec, rm);
+        assert!(f_alt.is_valid());
+        assert_eq!(ComparableFloat(f_alt), ComparableFloat(f.clone()));
+        assert_eq!(o_alt, o);
+
+        if rm == RoundingMode::Nearest {
+            let (f_alt, o_alt) = Float::from_rational_prec(x.clone(), prec);
+            assert_eq!(ComparableFloat(f_alt), ComparableFloat(f.clone()));
+            assert_eq!(o_alt, o);
+
+            let (f_alt, o_alt) = Float::from_rational_prec_ref(&x, prec);
+            assert_eq!(ComparableFloat(f_alt), ComparableFloat(f));
+            assert_eq!(o_alt, o);
+        }
+    };
+    test("0", 1, RoundingMode::Exact, "0.0", Ordering::Equal);
+    test("1", 1, RoundingMode::Exact, "1.0", Ordering::Equal);
+    test("-3/8", 2, RoundingMode::Exact, "-0.4", Ordering::Equal);
+    test("1/3", 10, RoundingMode::Floor, "0.333", Ordering::Less);
+    test(
+        "1/3",
+        10,
+        RoundingMode::Ceiling,
+        "0.3335",
+        Ordering::Greater,
+    );
+    test(
+        "1/3",
+        10,
+        RoundingMode::Nearest,
+        "0.3335",
+        Ordering::Greater,
+    );
+    test("-1/3", 10, RoundingMode::Floor, "-0.3335", Ordering::Less);
+    test("-1/3", 10, RoundingMode::Up, "-0.3335", Ordering::Less);
+    test("3", 1, RoundingMode::Nearest, "4.0", Ordering::Greater);
+    test("5", 2, RoundingMode::Nearest, "4.0", Ordering::Less);
+    test("7", 2, RoundingMode::Nearest, "8.0", Ordering::Greater);
+    test("255", 7, RoundingMode::Down, "254.0", Ordering::Less);
+    test("255", 7, RoundingMode::Up, "256.0", Ordering::Greater);
+    test(
+        "22/7",
+        100,
+        RoundingMode::Nearest,
+        "3.14285714285714285714285714286",
+        Ordering::Greater,
+    );
+}
+
+#[test]
+#[should_panic]
+fn from_rational_prec_round_fail_1() {
+    Float::from_rational_prec_round(Rational::from(3), 0, RoundingMode::Floor);
+}
+
+#[test]
+#[should_panic]
+fn from_rational_prec_round_fail_2() {
+    Float::from_rational_prec_round(Rational::from_signeds(1, 3), 10, RoundingMode::Exact);
+}
+
+#[test]
+fn test_try_from_rational() {
+    let test = |s: &str, out: Result<&str, FloatFromRationalError>, prec: Option<u64>| {
+        let x = Rational::from_str(s).unwrap();
+        let f = Float::try_from(x.clone());
+        assert_eq!(
+            f.as_ref().map(ToString::to_string).map_err(|e| *e),
+            out.map(ToString::to_string)
+        );
+        assert_eq!(Float::convertible_from(&x), out.is_ok());
+        if let Ok(f) = f {
+            assert!(f.is_valid());
+            assert_eq!(f.get_prec(), prec);
+            assert_eq!(
+                ComparableFloat(Float::try_from(&x).unwrap()),
+                ComparableFloat(f)
+            );
+        }
+    };
+    test("0", Ok("0.0"), None);
+    test("1", Ok("1.0"), Some(1));
+    test("-123", Ok("-123.0"), Some(7));
+    test("1/1024", Ok("0.001"), Some(1));
+    test("-255/256", Ok("-0.996"), Some(8));
+    test("1/3", Err(FloatFromRationalError), None);
+    test("22/7", Err(FloatFromRationalError), None);
+}
+
+#[test]
+fn from_rational_prec_round_properties() {
+    rational_gen().test_properties(|x| {
+        for prec in [1, 2, 10, 64, 100] {
+            let (floor, o_floor) =
+                Float::from_rational_prec_round_ref(&x, prec, RoundingMode::Floor);
+            let (ceiling, o_ceiling) =
+                Float::from_rational_prec_round_ref(&x, prec, RoundingMode::Ceiling);
+            let (nearest, o_nearest) = Float::from_rational_prec_ref(&x, prec);
+            assert!(floor.is_valid());
+            assert!(ceiling.is_valid());
+            assert!(nearest.is_valid());
+            let floor_q = Rational::try_from(&floor).unwrap();
+            let ceiling_q = Rational::try_from(&ceiling).unwrap();
+            let nearest_q = Rational::try_from(&nearest).unwrap();
+            assert_eq!(floor_q.cmp(&x), o_floor);
+            assert_eq!(ceiling_q.cmp(&x), o_ceiling);
+            assert_eq!(nearest_q.cmp(&x), o_nearest);
+            assert!(floor_q <= x);
+            assert!(ceiling_q >= x);
+            assert!(nearest_q == floor_q || nearest_q == ceiling_q);
+            if x == 0u32 {
+                assert_eq!(ComparableFloat(nearest), ComparableFloat(Float::ZERO));
+                continue;
+            }
+            assert_eq!(floor.get_prec(), Some(prec));
+            if o_floor == Ordering::Equal {
+                assert_eq!(ComparableFloat(floor), ComparableFloat(ceiling));
+                assert!(Float::convertible_from(&x) || prec < 100);
+            } else {
+                // floor and ceiling are adjacent
+                assert_eq!(
+                    (&ceiling_q - &floor_q).cmp(&(&x - &floor_q)),
+                    Ordering::Greater
+                );
+            }
+            // exact results are stable under conversion
+            let (f, o) =
+                Float::from_rational_prec_round(floor_q.clone(), prec, RoundingMode::Exact);
+            assert_eq!(Rational::try_from(&f).unwrap(), floor_q);
+            assert_eq!(o, Ordering::Equal);
+        }
+    });
+}
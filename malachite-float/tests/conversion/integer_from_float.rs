@@ -0,0 +1,76 @@
+use malachite_base::num::conversion::traits::{ConvertibleFrom, RoundingFrom};
+use malachite_base::rounding_modes::RoundingMode;
+use malachite_float::conversion::integer_from_float::IntegerFromFloatError;
+use malachite_float::Float;
+use malachite_nz::integer::Integer;
+use malachite_q::test_util::generators::rational_gen;
+use malachite_q::Rational;
+
+#[test]
+fn test_try_from_float() {
+    let test = |x: f64, out: Result<&str, IntegerFromFloatError>| {
+        let x = Float::from(x);
+        let n = Integer::try_from(&x);
+        assert_eq!(
+            n.as_ref().map(ToString::to_string).map_err(|e| *e),
+            out.map(ToString::to_string)
+        );
+        assert_eq!(Integer::convertible_from(&x), out.is_ok());
+    };
+    test(f64::NAN, Err(IntegerFromFloatError));
+    test(f64::INFINITY, Err(IntegerFromFloatError));
+    test(-0.0, Ok("0"));
+    test(123.0, Ok("123"));
+    test(-1.0e20, Ok("-100000000000000000000"));
+    test(1.5, Err(IntegerFromFloatError));
+}
+
+#[test]
+fn test_rounding_from_float() {
+    let test = |x: f64, rm: RoundingMode, out: &str| {
+        assert_eq!(Integer::rounding_from(&Float::from(x), rm).to_string(), out);
+    };
+    test(-0.0, RoundingMode::Exact, "0");
+    test(1.5, RoundingMode::Floor, "1");
+    test(1.5, RoundingMode::Ceiling, "2");
+    test(1.5, RoundingMode::Down, "1");
+    test(1.5, RoundingMode::Up, "2");
+    test(1.5, RoundingMode::Nearest, "2");
+    test(2.5, RoundingMode::Nearest, "2");
+    test(-2.5, RoundingMode::Floor, "-3");
+    test(-2.5, RoundingMode::Down, "-2");
+    test(123.0, RoundingMode::Exact, "123");
+}
+
+#[test]
+#[should_panic]
+fn rounding_from_float_fail_1() {
+    Integer::rounding_from(&Float::NAN, RoundingMode::Floor);
+}
+
+#[test]
+#[should_panic]
+fn rounding_from_float_fail_2() {
+    Integer::rounding_from(&Float::from(1.5f64), RoundingMode::Exact);
+}
+
+#[test]
+fn rounding_from_float_properties() {
+    rational_gen().test_properties(|x| {
+        let f = Float::from_rational_prec(x, 30).0;
+        let q = Rational::try_from(&f).unwrap();
+        for rm in [
+            RoundingMode::Down,
+            RoundingMode::Up,
+            RoundingMode::Floor,
+            RoundingMode::Ceiling,
+            RoundingMode::Nearest,
+        ] {
+            assert_eq!(
+                Integer::rounding_from(&f, rm),
+                Integer::rounding_from(&q, rm)
+            );
+        }
+        assert_eq!(Integer::convertible_from(&f), Integer::convertible_from(&q));
+    });
+}
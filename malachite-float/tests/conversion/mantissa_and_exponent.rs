@@ -0,0 +1,148 @@
+use malachite_base::num::arithmetic::traits::PowerOf2;
+use malachite_base::num::basic::floats::PrimitiveFloat;
+use malachite_base::num::conversion::traits::SciMantissaAndExponent;
+use malachite_base::num::float::NiceFloat;
+use malachite_base::test_util::generators::primitive_float_gen_var_12;
+use malachite_float::{ComparableFloat, Float};
+use malachite_q::test_util::generators::rational_gen;
+use malachite_q::Rational;
+use std::str::FromStr;
+
+fn from_sci(m: f64, e: i64) -> Option<Float> {
+    <&Float as SciMantissaAndExponent<_, _, _>>::from_sci_mantissa_and_exponent(m, e)
+}
+
+#[test]
+fn test_sci_mantissa_and_exponent() {
+    let test = |x: Float, mantissa: f64, exponent: i64| {
+        let (m, e): (f64, i64) = (&x).sci_mantissa_and_exponent();
+        assert_eq!(NiceFloat(m), NiceFloat(mantissa));
+        assert_eq!(e, exponent);
+        // Avoid converting `Float`s with huge exponents to `Rational`s.
+        if exponent.abs() < 10000 {
+            let q = Rational::try_from(&x).unwrap();
+            let (m_alt, e_alt): (f64, i64) = (&q).sci_mantissa_and_exponent();
+            assert_eq!(NiceFloat(m_alt), NiceFloat(mantissa.abs()));
+            assert_eq!(e_alt, exponent);
+        }
+    };
+    test(Float::from(1.0f64), 1.0, 0);
+    test(Float::from(3.0f64), 1.5, 1);
+    test(Float::from(-123.0f64), 1.921875, 6);
+    test(Float::from(0.1f64), 1.6, -4);
+    test(Float::from(f64::MIN_POSITIVE_SUBNORMAL), 1.0, -1074);
+    test(Float::from(f64::MAX_FINITE), 1.9999999999999998, 1023);
+    test(
+        Float::from_rational_prec(Rational::from_str("1/3").unwrap(), 100).0,
+        1.3333333333333333,
+        -2,
+    );
+    // 2 - 2^-99 rounds to a mantissa of 2, which is returned as 1 with an incremented exponent.
+    test(
+        Float::from_rational_prec(Rational::from(2u32) - Rational::power_of_2(-99i64), 100).0,
+        1.0,
+        1,
+    );
+    test(from_sci(1.5, 1 << 50).unwrap(), 1.5, 1 << 50);
+    test(from_sci(1.25, -(1 << 50)).unwrap(), 1.25, -(1 << 50));
+
+    let mut x = Float::from(3.0f64);
+    for _ in 0..40 {
+        x = x.mul_prec_ref_ref(&x, 53).0;
+    }
+    let (m, e): (f64, i64) = (&x).sci_mantissa_and_exponent();
+    assert!((1.0..2.0).contains(&m));
+    assert_eq!(e, SciMantissaAndExponent::<f64, _, _>::sci_exponent(&x));
+    assert!(e > 1 << 40);
+
+    let (m, e): (f32, i64) = (&Float::from(0.1f64)).sci_mantissa_and_exponent();
+    assert_eq!(NiceFloat(m), NiceFloat(1.6));
+    assert_eq!(e, -4);
+}
+
+#[test]
+#[should_panic]
+fn sci_mantissa_and_exponent_fail_1() {
+    let _: (f64, i64) = (&Float::NEGATIVE_ZERO).sci_mantissa_and_exponent();
+}
+
+#[test]
+#[should_panic]
+fn sci_mantissa_and_exponent_fail_2() {
+    let _: (f64, i64) = (&Float::INFINITY).sci_mantissa_and_exponent();
+}
+
+#[test]
+#[should_panic]
+fn sci_mantissa_and_exponent_fail_3() {
+    let _: (f32, i64) = (&Float::NAN).sci_mantissa_and_exponent();
+}
+
+#[test]
+fn test_from_sci_mantissa_and_exponent() {
+    let test = |m: f64, e: i64, out: Option<f64>| {
+        let x = from_sci(m, e);
+        assert!(x.as_ref().map_or(true, Float::is_valid));
+        assert_eq!(
+            x.map(ComparableFloat),
+            out.map(|f| ComparableFloat(Float::from(f)))
+        );
+    };
+    test(1.0, 0, Some(1.0));
+    test(1.5, 1, Some(3.0));
+    test(1.921875, 6, Some(123.0));
+    test(1.5, -3, Some(0.1875));
+    test(1.0, -1074, Some(f64::MIN_POSITIVE_SUBNORMAL));
+    test(2.0, 1, None);
+    test(0.5, 1, None);
+    test(-1.5, 1, None);
+    test(f64::NAN, 0, None);
+    test(f64::INFINITY, 0, None);
+    test(1.0, i64::MAX, None);
+    test(1.0, i64::MIN, None);
+    test(1.0, Float::MAX_EXPONENT, None);
+    test(1.0, Float::MIN_EXPONENT - 2, None);
+
+    let x = from_sci(1.5, 1 << 50).unwrap();
+    assert_eq!(x.get_exponent(), Some((1 << 50) + 1));
+    assert_eq!(x.get_prec(), Some(2));
+    let x = from_sci(1.5, Float::MAX_EXPONENT - 1).unwrap();
+    assert_eq!(x.get_exponent(), Some(Float::MAX_EXPONENT));
+    assert_eq!(x.get_prec(), Some(2));
+    let x = from_sci(1.0, Float::MIN_EXPONENT - 1).unwrap();
+    assert_eq!(x.get_exponent(), Some(Float::MIN_EXPONENT));
+}
+
+#[test]
+fn sci_mantissa_and_exponent_properties() {
+    primitive_float_gen_var_12::<f64>().test_properties(|x| {
+        let f = Float::from(x);
+        let (m, e): (f64, i64) = (&f).sci_mantissa_and_exponent();
+        let (m_alt, e_alt) = x.abs().sci_mantissa_and_exponent();
+        assert_eq!(NiceFloat(m), NiceFloat(m_alt));
+        assert_eq!(e, e_alt);
+        assert_eq!(e, SciMantissaAndExponent::<f64, _, _>::sci_exponent(&f));
+        assert_eq!(
+            ComparableFloat(from_sci(m, e).unwrap()),
+            ComparableFloat(Float::from(x.abs()))
+        );
+    });
+
+    rational_gen().test_properties(|x| {
+        if x == 0u32 {
+            return;
+        }
+        let f = Float::from_rational_prec_ref(&x, 100).0;
+        let q = Rational::try_from(&f).unwrap();
+        let (m, e): (f64, i64) = (&f).sci_mantissa_and_exponent();
+        let (m_alt, e_alt): (f64, i64) = (&q).sci_mantissa_and_exponent();
+        assert_eq!(NiceFloat(m), NiceFloat(m_alt));
+        assert_eq!(e, e_alt);
+        assert!((1.0..2.0).contains(&m));
+        // Shifting the `Float` only changes the exponent.
+        for shift in [1 << 40, -(1 << 40)] {
+            let g = f.mul_prec_ref_ref(&from_sci(1.0, shift).unwrap(), 100).0;
+            assert_eq!((&g).sci_mantissa_and_exponent(), (m, e + shift));
+        }
+    });
+}
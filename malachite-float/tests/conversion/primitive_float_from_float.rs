@@ -0,0 +1,278 @@
+use malachite_base::num::arithmetic::traits::PowerOf2;
+use malachite_base::num::basic::floats::PrimitiveFloat;
+use malachite_base::num::conversion::traits::{
+    ConvertibleFrom, RoundingFrom, SciMantissaAndExponent,
+};
+use malachite_base::num::float::NiceFloat;
+use malachite_base::rounding_modes::exhaustive::exhaustive_rounding_modes;
+use malachite_base::rounding_modes::RoundingMode;
+use malachite_base::test_util::generators::primitive_float_gen;
+use malachite_float::conversion::primitive_float_from_float::PrimitiveFloatFromFloatError;
+use malachite_float::Float;
+use malachite_q::test_util::generators::rational_gen;
+use malachite_q::Rational;
+use std::str::FromStr;
+
+// Returns the `Float` equal to $m2^e$, where $1 \leq |m| < 2$.
+fn sci(m: f64, e: i64) -> Float {
+    let x = <&Float as SciMantissaAndExponent<_, _, _>>::from_sci_mantissa_and_exponent(m.abs(), e)
+        .unwrap();
+    if m < 0.0 {
+        -x
+    } else {
+        x
+    }
+}
+
+fn rational(s: &str, prec: u64) -> Float {
+    Float::from_rational_prec(Rational::from_str(s).unwrap(), prec).0
+}
+
+// Returns a `Float` with an exponent of about 1.7e12, by squaring 3 forty times.
+fn huge() -> Float {
+    let mut x = Float::from(3.0f64);
+    for _ in 0..40 {
+        x = x.mul_prec_ref_ref(&x, 53).0;
+    }
+    x
+}
+
+#[test]
+fn test_try_from_float() {
+    let test = |x: Float, out: Result<f64, PrimitiveFloatFromFloatError>| {
+        assert_eq!(f64::try_from(&x).map(NiceFloat), out.map(NiceFloat));
+        assert_eq!(f64::convertible_from(&x), out.is_ok());
+    };
+    test(Float::NAN, Ok(f64::NAN));
+    test(Float::INFINITY, Ok(f64::INFINITY));
+    test(Float::NEGATIVE_INFINITY, Ok(f64::NEGATIVE_INFINITY));
+    test(Float::from(0.0f64), Ok(0.0));
+    test(Float::NEGATIVE_ZERO, Ok(-0.0));
+    test(Float::from(123.0f64), Ok(123.0));
+    test(Float::from(-0.1f64), Ok(-0.1));
+    test(rational("1/3", 100), Err(PrimitiveFloatFromFloatError));
+    test(Float::from(f64::MAX_FINITE), Ok(f64::MAX_FINITE));
+    test(
+        Float::from(-f64::MIN_POSITIVE_SUBNORMAL),
+        Ok(-f64::MIN_POSITIVE_SUBNORMAL),
+    );
+    test(sci(1.5, -1074), Err(PrimitiveFloatFromFloatError));
+    test(sci(1.0, 1023), Ok(f64::power_of_2(1023)));
+    test(sci(1.0, 1024), Err(PrimitiveFloatFromFloatError));
+    test(sci(-1.0, 1024), Err(PrimitiveFloatFromFloatError));
+    test(sci(1.0, -1075), Err(PrimitiveFloatFromFloatError));
+    test(sci(1.0, 1 << 50), Err(PrimitiveFloatFromFloatError));
+    test(sci(-1.0, -(1 << 50)), Err(PrimitiveFloatFromFloatError));
+    test(huge(), Err(PrimitiveFloatFromFloatError));
+
+    let test = |x: Float, out: Result<f32, PrimitiveFloatFromFloatError>| {
+        assert_eq!(f32::try_from(&x).map(NiceFloat), out.map(NiceFloat));
+        assert_eq!(f32::convertible_from(&x), out.is_ok());
+    };
+    test(Float::NEGATIVE_ZERO, Ok(-0.0));
+    test(Float::from(0.1f32), Ok(0.1));
+    test(Float::from(0.1f64), Err(PrimitiveFloatFromFloatError));
+    test(sci(1.0, 127), Ok(f32::power_of_2(127)));
+    test(sci(1.0, 128), Err(PrimitiveFloatFromFloatError));
+    test(sci(1.0, -149), Ok(f32::MIN_POSITIVE_SUBNORMAL));
+    test(sci(1.0, -150), Err(PrimitiveFloatFromFloatError));
+}
+
+#[test]
+fn test_rounding_from_float() {
+    let test = |x: Float, rm: RoundingMode, out: f64| {
+        assert_eq!(NiceFloat(f64::rounding_from(&x, rm)), NiceFloat(out));
+        // Avoid converting `Float`s with huge exponents to `Rational`s.
+        if x.get_exponent().map_or(false, |e| e.abs() < 10000) {
+            let q = Rational::try_from(&x).unwrap();
+            assert_eq!(NiceFloat(f64::rounding_from(&q, rm)), NiceFloat(out));
+        }
+    };
+    test(Float::NAN, RoundingMode::Floor, f64::NAN);
+    test(Float::INFINITY, RoundingMode::Down, f64::INFINITY);
+    test(
+        Float::NEGATIVE_INFINITY,
+        RoundingMode::Up,
+        f64::NEGATIVE_INFINITY,
+    );
+    test(Float::from(0.0f64), RoundingMode::Exact, 0.0);
+    test(Float::NEGATIVE_ZERO, RoundingMode::Nearest, -0.0);
+    test(Float::from(-0.1f64), RoundingMode::Exact, -0.1);
+
+    let x = rational("1/3", 100);
+    test(x.clone(), RoundingMode::Floor, 0.3333333333333333);
+    test(x.clone(), RoundingMode::Ceiling, 0.33333333333333337);
+    test(x.clone(), RoundingMode::Down, 0.3333333333333333);
+    test(x.clone(), RoundingMode::Up, 0.33333333333333337);
+    test(x, RoundingMode::Nearest, 0.3333333333333333);
+    let x = rational("-1/3", 100);
+    test(x.clone(), RoundingMode::Floor, -0.33333333333333337);
+    test(x.clone(), RoundingMode::Ceiling, -0.3333333333333333);
+    test(x.clone(), RoundingMode::Down, -0.3333333333333333);
+    test(x.clone(), RoundingMode::Up, -0.33333333333333337);
+    test(x, RoundingMode::Nearest, -0.3333333333333333);
+
+    // Just below the overflow boundary: 2^1024 - 2^900 has exponent 1024.
+    let x = Float::from_rational_prec(
+        Rational::power_of_2(1024i64) - Rational::power_of_2(900i64),
+        200,
+    )
+    .0;
+    test(x.clone(), RoundingMode::Floor, f64::MAX_FINITE);
+    test(x.clone(), RoundingMode::Ceiling, f64::INFINITY);
+    test(x.clone(), RoundingMode::Nearest, f64::MAX_FINITE);
+    test(-x, RoundingMode::Up, f64::NEGATIVE_INFINITY);
+
+    // Just beyond the overflow boundary, and far beyond it.
+    for x in [sci(1.0, 1024), sci(1.75, 1024), sci(1.0, 1 << 50), huge()] {
+        test(x.clone(), RoundingMode::Floor, f64::MAX_FINITE);
+        test(x.clone(), RoundingMode::Ceiling, f64::INFINITY);
+        test(x.clone(), RoundingMode::Down, f64::MAX_FINITE);
+        test(x.clone(), RoundingMode::Up, f64::INFINITY);
+        test(x.clone(), RoundingMode::Nearest, f64::MAX_FINITE);
+        let x = -x;
+        test(x.clone(), RoundingMode::Floor, f64::NEGATIVE_INFINITY);
+        test(x.clone(), RoundingMode::Ceiling, -f64::MAX_FINITE);
+        test(x.clone(), RoundingMode::Down, -f64::MAX_FINITE);
+        test(x.clone(), RoundingMode::Up, f64::NEGATIVE_INFINITY);
+        test(x, RoundingMode::Nearest, -f64::MAX_FINITE);
+    }
+
+    // Subnormal results.
+    test(
+        sci(1.5, -1073),
+        RoundingMode::Exact,
+        3.0 * f64::MIN_POSITIVE_SUBNORMAL,
+    );
+    let x = rational("1/3", 100) * sci(1.0, -1070);
+    test(
+        x.clone(),
+        RoundingMode::Floor,
+        5.0 * f64::MIN_POSITIVE_SUBNORMAL,
+    );
+    test(
+        x.clone(),
+        RoundingMode::Ceiling,
+        6.0 * f64::MIN_POSITIVE_SUBNORMAL,
+    );
+    test(x, RoundingMode::Nearest, 5.0 * f64::MIN_POSITIVE_SUBNORMAL);
+
+    // Half the smallest subnormal is a tie, which rounds to the even value 0; anything larger
+    // rounds up.
+    test(sci(1.0, -1075), RoundingMode::Nearest, 0.0);
+    test(
+        sci(1.0, -1075),
+        RoundingMode::Ceiling,
+        f64::MIN_POSITIVE_SUBNORMAL,
+    );
+    test(
+        sci(1.5, -1075),
+        RoundingMode::Nearest,
+        f64::MIN_POSITIVE_SUBNORMAL,
+    );
+    test(
+        sci(-1.5, -1075),
+        RoundingMode::Nearest,
+        -f64::MIN_POSITIVE_SUBNORMAL,
+    );
+
+    // Just beyond the underflow boundary, and far beyond it.
+    for x in [sci(1.75, -1076), sci(1.0, -1076), sci(1.0, -(1 << 50))] {
+        test(x.clone(), RoundingMode::Floor, 0.0);
+        test(
+            x.clone(),
+            RoundingMode::Ceiling,
+            f64::MIN_POSITIVE_SUBNORMAL,
+        );
+        test(x.clone(), RoundingMode::Down, 0.0);
+        test(x.clone(), RoundingMode::Up, f64::MIN_POSITIVE_SUBNORMAL);
+        test(x.clone(), RoundingMode::Nearest, 0.0);
+        let x = -x;
+        test(x.clone(), RoundingMode::Floor, -f64::MIN_POSITIVE_SUBNORMAL);
+        test(x.clone(), RoundingMode::Ceiling, -0.0);
+        test(x.clone(), RoundingMode::Down, -0.0);
+        test(x.clone(), RoundingMode::Up, -f64::MIN_POSITIVE_SUBNORMAL);
+        test(x, RoundingMode::Nearest, -0.0);
+    }
+
+    let test = |x: Float, rm: RoundingMode, out: f32| {
+        assert_eq!(NiceFloat(f32::rounding_from(&x, rm)), NiceFloat(out));
+    };
+    test(Float::from(0.1f64), RoundingMode::Floor, 0.099999994);
+    test(Float::from(0.1f64), RoundingMode::Ceiling, 0.1);
+    test(sci(1.0, 128), RoundingMode::Nearest, f32::MAX_FINITE);
+    test(sci(1.0, 128), RoundingMode::Up, f32::INFINITY);
+    test(
+        sci(-1.0, 1 << 50),
+        RoundingMode::Floor,
+        f32::NEGATIVE_INFINITY,
+    );
+    test(
+        sci(1.0, -151),
+        RoundingMode::Up,
+        f32::MIN_POSITIVE_SUBNORMAL,
+    );
+    test(sci(-1.0, -151), RoundingMode::Nearest, -0.0);
+    test(
+        sci(-1.0, -(1 << 50)),
+        RoundingMode::Floor,
+        -f32::MIN_POSITIVE_SUBNORMAL,
+    );
+}
+
+#[test]
+#[should_panic]
+fn rounding_from_float_fail_1() {
+    f64::rounding_from(&rational("1/3", 100), RoundingMode::Exact);
+}
+
+#[test]
+#[should_panic]
+fn rounding_from_float_fail_2() {
+    f64::rounding_from(&sci(1.0, 1024), RoundingMode::Exact);
+}
+
+#[test]
+#[should_panic]
+fn rounding_from_float_fail_3() {
+    f64::rounding_from(&sci(-1.0, -(1 << 50)), RoundingMode::Exact);
+}
+
+#[test]
+fn primitive_float_from_float_properties() {
+    primitive_float_gen::<f64>().test_properties(|x| {
+        let f = Float::from(x);
+        assert_eq!(NiceFloat(f64::try_from(&f).unwrap()), NiceFloat(x));
+        assert!(f64::convertible_from(&f));
+        for rm in exhaustive_rounding_modes() {
+            assert_eq!(NiceFloat(f64::rounding_from(&f, rm)), NiceFloat(x));
+        }
+    });
+
+    // Out-of-range values are rounded as their `Rational` equivalents are.
+    rational_gen().test_properties(|x| {
+        let f = Float::from_rational_prec_ref(&x, 100).0;
+        if f.is_zero() {
+            return;
+        }
+        for e in [0, 1000, -1000, 1100, -1100, 5000, -5000] {
+            let g = f.mul_prec_ref_ref(&sci(1.0, e), 100).0;
+            let q = Rational::try_from(&g).unwrap();
+            for rm in exhaustive_rounding_modes() {
+                if rm == RoundingMode::Exact {
+                    assert_eq!(f64::convertible_from(&g), f64::convertible_from(&q));
+                    assert_eq!(f32::convertible_from(&g), f32::convertible_from(&q));
+                } else {
+                    assert_eq!(
+                        NiceFloat(f64::rounding_from(&g, rm)),
+                        NiceFloat(f64::rounding_from(&q, rm))
+                    );
+                    assert_eq!(
+                        NiceFloat(f32::rounding_from(&g, rm)),
+                        NiceFloat(f32::rounding_from(&q, rm))
+                    );
+                }
+            }
+        }
+    });
+}
@@ -0,0 +1,47 @@
+use malachite_base::num::conversion::traits::ConvertibleFrom;
+use malachite_float::conversion::rational_from_float::RationalFromFloatError;
+use malachite_float::{ComparableFloat, Float};
+use malachite_q::test_util::generators::rational_gen;
+use malachite_q::Rational;
+
+#[test]
+fn test_try_from_float() {
+    let test = |x: Float, out: Result<&str, RationalFromFloatError>| {
+        let q = Rational::try_from(&x);
+        assert_eq!(
+            q.as_ref().map(ToString::to_string).map_err(|e| *e),
+            out.map(ToString::to_string)
+        );
+        assert_eq!(Rational::try_from(x.clone()), q);
+        assert_eq!(Rational::convertible_from(&x), out.is_ok());
+    };
+    test(Float::NAN, Err(RationalFromFloatError));
+    test(Float::INFINITY, Err(RationalFromFloatError));
+    test(Float::NEGATIVE_INFINITY, Err(RationalFromFloatError));
+    test(Float::from(0.0f64), Ok("0"));
+    test(Float::NEGATIVE_ZERO, Ok("0"));
+    test(Float::from(123.0f64), Ok("123"));
+    test(Float::from(-0.375f64), Ok("-3/8"));
+    test(
+        Float::from(0.1f64),
+        Ok("3602879701896397/36028797018963968"),
+    );
+    test(Float::from(1.0e-10f32), Ok("14411519/144115188075855872"));
+}
+
+#[test]
+fn try_from_float_properties() {
+    rational_gen().test_properties(|x| {
+        let f = Float::from_rational_prec_ref(&x, 30).0;
+        let q = Rational::try_from(&f).unwrap();
+        let f_alt = Float::try_from(&q).unwrap();
+        // converting back gives the minimal precision
+        assert!(f_alt.get_prec() <= f.get_prec());
+        assert_eq!(f_alt, f);
+        assert_eq!(Rational::try_from(&f_alt).unwrap(), q);
+        assert_eq!(
+            ComparableFloat(Float::from_rational_prec_ref(&q, 30).0),
+            ComparableFloat(f)
+        );
+    });
+}
@@ -0,0 +1,109 @@
+use malachite_base::num::arithmetic::traits::Pow;
+use malachite_base::num::basic::traits::{NegativeOne, One, Zero};
+use malachite_base::num::conversion::traits::{FromSciString, SciMantissaAndExponent};
+use malachite_base::rounding_modes::RoundingMode;
+use malachite_float::{ComparableFloat, Float};
+use malachite_q::test_util::generators::rational_gen;
+use malachite_q::Rational;
+
+#[test]
+fn test_to_string() {
+    let test = |x: Float, out: &str| {
+        assert_eq!(x.to_string(), out);
+        assert_eq!(format!("{:?}", x), out);
+        assert_eq!(ComparableFloat(x).to_string(), out);
+    };
+    test(Float::NAN, "NaN");
+    test(Float::INFINITY, "Infinity");
+    test(Float::NEGATIVE_INFINITY, "-Infinity");
+    test(Float::ZERO, "0.0");
+    test(Float::NEGATIVE_ZERO, "-0.0");
+    test(Float::ONE, "1.0");
+    test(Float::NEGATIVE_ONE, "-1.0");
+    test(Float::from(0.5f64), "0.5");
+    test(Float::from(100.0f64), "100.0");
+    test(Float::from(-123.25f64), "-123.2");
+    test(Float::from(-123.75f64), "-123.8");
+    test(
+        Float::from_rational_prec(Rational::from(-493), 12).0,
+        "-493.0",
+    );
+    test(Float::from(0.1f64), "0.1");
+    test(Float::from(0.1f32), "0.1");
+    test(Float::from(1.0e-5f64), "0.00001");
+    test(Float::from(1.0e-6f64), "1.0e-6");
+    test(Float::from(1.0e15f64), "1000000000000000.0");
+    test(Float::from(1.0e16f64), "1.0e16");
+    test(Float::from(-1.5e300f64), "-1.5e300");
+    test(Float::from(std::f64::consts::E), "2.718281828459045");
+    // 3/8 with precision 2 is the only Float with that precision that 0.4 rounds to
+    test(Float::from(0.375f64), "0.4");
+    test(Float::from(0.375f32), "0.4");
+    test(
+        Float::from_rational_prec(Rational::from(99999), 10).0,
+        "100000.0",
+    );
+    test(
+        Float::from_rational_prec(Rational::from(999), 4).0,
+        "1000.0",
+    );
+    test(
+        Float::from_rational_prec(Rational::from(-10).pow(20u64), 4).0,
+        "1.0e20",
+    );
+    test(
+        Float::from_rational_prec(Rational::from_signeds(1, 3), 100).0,
+        "0.3333333333333333333333333333335",
+    );
+}
+
+// Returns $m2^e$, where $1 \leq m < 2$.
+fn sci(m: f64, e: i64) -> Float {
+    <&Float as SciMantissaAndExponent<_, _, _>>::from_sci_mantissa_and_exponent(m, e).unwrap()
+}
+
+#[test]
+fn test_to_string_huge_exponent() {
+    let test = |x: Float, out: &str| {
+        assert_eq!(x.to_string(), out);
+        assert_eq!((-x).to_string(), format!("-{}", out));
+    };
+    test(sci(1.0, 1 << 36), "7.0e20686623783");
+    test(sci(1.0, -(1 << 36)), "1.4e-20686623784");
+    test(sci(1.5, (1 << 40) + 1), "2.4e330985980542");
+    test(
+        sci(2.0 - f64::EPSILON, Float::MAX_EXPONENT - 1),
+        "5.875653789111587e1388255822130839282",
+    );
+    test(
+        sci(1.0, Float::MIN_EXPONENT - 1),
+        "9.0e-1388255822130839284",
+    );
+}
+
+#[test]
+fn to_string_properties() {
+    rational_gen().test_properties(|x| {
+        for prec in [1, 2, 10, 53, 100] {
+            let f = Float::from_rational_prec_ref(&x, prec).0;
+            let s = f.to_string();
+            assert!(s.contains('.'));
+            // the string rounds back to the same Float
+            let t = Rational::from_sci_string(&s).unwrap();
+            let g = Float::from_rational_prec_round(t, prec, RoundingMode::Nearest).0;
+            assert_eq!(ComparableFloat(g), ComparableFloat(f.clone()));
+        }
+    });
+    // Exponents just above and far above the threshold for exact formatting
+    rational_gen().test_properties(|x| {
+        for prec in [1, 2, 10, 53] {
+            for pow in [-1000i64, -300, 300, 1000] {
+                let f = Float::from_rational_prec_ref(&(&x << pow), prec).0;
+                let s = f.to_string();
+                let t = Rational::from_sci_string(&s).unwrap();
+                let g = Float::from_rational_prec_round(t, prec, RoundingMode::Nearest).0;
+                assert_eq!(ComparableFloat(g), ComparableFloat(f));
+            }
+        }
+    });
+}
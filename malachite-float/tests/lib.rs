@@ -0,0 +1,69 @@
+#![allow(
+    unstable_name_collisions,
+    clippy::bool_assert_comparison,
+    clippy::assertions_on_constants,
+    clippy::cognitive_complexity,
+    clippy::excessive_precision,
+    clippy::many_single_char_names,
+    clippy::range_plus_one,
+    clippy::suspicious_arithmetic_impl,
+    clippy::suspicious_op_assign_impl,
+    clippy::too_many_arguments,
+    clippy::float_cmp,
+    clippy::type_complexity
+)]
+#![warn(
+    clippy::cast_lossless,
+    clippy::explicit_into_iter_loop,
+    clippy::explicit_iter_loop,
+    clippy::filter_map_next,
+    clippy::large_digit_groups,
+    clippy::manual_filter_map,
+    clippy::manual_find_map,
+    clippy::map_flatten,
+    clippy::map_unwrap_or,
+    clippy::match_same_arms,
+    clippy::missing_const_for_fn,
+    clippy::mut_mut,
+    clippy::needless_borrow,
+    clippy::needless_continue,
+    clippy::needless_pass_by_value,
+    clippy::option_if_let_else,
+    clippy::print_stdout,
+    clippy::redundant_closure_for_method_calls,
+    clippy::single_match_else,
+    clippy::trait_duplication_in_bounds,
+    clippy::type_repetition_in_bounds,
+    clippy::unused_self
+)]
+
+extern crate malachite_base;
+extern crate malachite_float;
+extern crate malachite_nz;
+extern crate malachite_q;
+
+pub mod arithmetic {
+    pub mod abs;
+    pub mod add;
+    pub mod div;
+    pub mod mul;
+    pub mod neg;
+    pub mod sqrt;
+    pub mod sub;
+}
+pub mod basic {
+    pub mod classification;
+}
+pub mod comparison {
+    pub mod cmp;
+    pub mod eq;
+}
+pub mod conversion {
+    pub mod from_primitive_float;
+    pub mod from_rational;
+    pub mod integer_from_float;
+    pub mod mantissa_and_exponent;
+    pub mod primitive_float_from_float;
+    pub mod rational_from_float;
+    pub mod string;
+}
@@ -0,0 +1,45 @@
+use malachite_base::num::arithmetic::traits::DivRem;
+use malachite_base::test_util::bench::{run_benchmark, BenchmarkType};
+use malachite_base::test_util::generators::common::{GenConfig, GenMode};
+use malachite_base::test_util::runner::Runner;
+use malachite_nz::gaussian_integer::GaussianInteger;
+use malachite_nz::test_util::bench::bucketers::quadruple_integer_max_bit_bucketer;
+use malachite_nz::test_util::generators::integer_quadruple_gen;
+
+pub(crate) fn register(runner: &mut Runner) {
+    register_demo!(runner, demo_gaussian_integer_div_rem);
+    register_bench!(runner, benchmark_gaussian_integer_div_rem);
+}
+
+fn demo_gaussian_integer_div_rem(gm: GenMode, config: GenConfig, limit: usize) {
+    for (a, b, c, d) in integer_quadruple_gen().get(gm, &config).take(limit) {
+        let x = GaussianInteger::new(a, b);
+        let y = GaussianInteger::new(c, d);
+        if y.norm() != 0u32 {
+            println!("({}).div_rem({}) = {:?}", x, y, (&x).div_rem(&y));
+        }
+    }
+}
+
+fn benchmark_gaussian_integer_div_rem(
+    gm: GenMode,
+    config: GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "GaussianInteger.div_rem(GaussianInteger)",
+        BenchmarkType::Single,
+        integer_quadruple_gen().get(gm, &config),
+        gm.name(),
+        limit,
+        file_name,
+        &quadruple_integer_max_bit_bucketer("a", "b", "c", "d"),
+        &mut [("Malachite", &mut |(a, b, c, d)| {
+            let y = GaussianInteger::new(c, d);
+            if y.norm() != 0u32 {
+                no_out!(GaussianInteger::new(a, b).div_rem(y))
+            }
+        })],
+    );
+}
@@ -0,0 +1,44 @@
+use malachite_base::num::factorization::traits::Factor;
+use malachite_base::test_util::bench::{run_benchmark, BenchmarkType};
+use malachite_base::test_util::generators::common::{GenConfig, GenMode};
+use malachite_base::test_util::runner::Runner;
+use malachite_nz::gaussian_integer::GaussianInteger;
+use malachite_nz::test_util::bench::bucketers::pair_integer_max_bit_bucketer;
+use malachite_nz::test_util::generators::integer_pair_gen;
+
+pub(crate) fn register(runner: &mut Runner) {
+    register_demo!(runner, demo_gaussian_integer_factor);
+    register_bench!(runner, benchmark_gaussian_integer_factor);
+}
+
+fn demo_gaussian_integer_factor(gm: GenMode, config: GenConfig, limit: usize) {
+    for (a, b) in integer_pair_gen().get(gm, &config).take(limit) {
+        let x = GaussianInteger::new(a, b);
+        if x.norm() != 0u32 {
+            println!("({}).factor() = {:?}", x, x.factor());
+        }
+    }
+}
+
+fn benchmark_gaussian_integer_factor(
+    gm: GenMode,
+    config: GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "GaussianInteger.factor()",
+        BenchmarkType::Single,
+        integer_pair_gen().get(gm, &config),
+        gm.name(),
+        limit,
+        file_name,
+        &pair_integer_max_bit_bucketer("a", "b"),
+        &mut [("Malachite", &mut |(a, b)| {
+            let x = GaussianInteger::new(a, b);
+            if x.norm() != 0u32 {
+                no_out!(x.factor())
+            }
+        })],
+    );
+}
@@ -0,0 +1,44 @@
+use malachite_base::num::arithmetic::traits::{ExtendedGcd, Gcd};
+use malachite_base::test_util::bench::{run_benchmark, BenchmarkType};
+use malachite_base::test_util::generators::common::{GenConfig, GenMode};
+use malachite_base::test_util::runner::Runner;
+use malachite_nz::gaussian_integer::GaussianInteger;
+use malachite_nz::test_util::bench::bucketers::quadruple_integer_max_bit_bucketer;
+use malachite_nz::test_util::generators::integer_quadruple_gen;
+
+pub(crate) fn register(runner: &mut Runner) {
+    register_demo!(runner, demo_gaussian_integer_gcd);
+    register_demo!(runner, demo_gaussian_integer_extended_gcd);
+    register_bench!(runner, benchmark_gaussian_integer_gcd);
+}
+
+fn demo_gaussian_integer_gcd(gm: GenMode, config: GenConfig, limit: usize) {
+    for (a, b, c, d) in integer_quadruple_gen().get(gm, &config).take(limit) {
+        let x = GaussianInteger::new(a, b);
+        let y = GaussianInteger::new(c, d);
+        println!("gcd({}, {}) = {}", x, y, (&x).gcd(&y));
+    }
+}
+
+fn demo_gaussian_integer_extended_gcd(gm: GenMode, config: GenConfig, limit: usize) {
+    for (a, b, c, d) in integer_quadruple_gen().get(gm, &config).take(limit) {
+        let x = GaussianInteger::new(a, b);
+        let y = GaussianInteger::new(c, d);
+        println!("extended_gcd({}, {}) = {:?}", x, y, (&x).extended_gcd(&y));
+    }
+}
+
+fn benchmark_gaussian_integer_gcd(gm: GenMode, config: GenConfig, limit: usize, file_name: &str) {
+    run_benchmark(
+        "GaussianInteger.gcd(GaussianInteger)",
+        BenchmarkType::Single,
+        integer_quadruple_gen().get(gm, &config),
+        gm.name(),
+        limit,
+        file_name,
+        &quadruple_integer_max_bit_bucketer("a", "b", "c", "d"),
+        &mut [("Malachite", &mut |(a, b, c, d)| {
+            no_out!(GaussianInteger::new(a, b).gcd(GaussianInteger::new(c, d)))
+        })],
+    );
+}
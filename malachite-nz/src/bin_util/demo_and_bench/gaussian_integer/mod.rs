@@ -0,0 +1,11 @@
+use malachite_base::test_util::runner::Runner;
+
+pub(crate) fn register(runner: &mut Runner) {
+    div_rem::register(runner);
+    factor::register(runner);
+    gcd::register(runner);
+}
+
+mod div_rem;
+mod factor;
+mod gcd;
@@ -0,0 +1,54 @@
+use malachite_base::num::arithmetic::traits::ChineseRemainder;
+use malachite_base::num::basic::traits::One;
+use malachite_base::test_util::bench::{run_benchmark, BenchmarkType};
+use malachite_base::test_util::generators::common::{GenConfig, GenMode};
+use malachite_base::test_util::runner::Runner;
+use malachite_nz::integer::Integer;
+use malachite_nz::natural::Natural;
+use malachite_nz::test_util::bench::bucketers::pair_2_natural_bit_bucketer;
+use malachite_nz::test_util::generators::integer_natural_pair_gen;
+
+pub(crate) fn register(runner: &mut Runner) {
+    register_demo!(runner, demo_integer_chinese_remainder);
+    register_bench!(runner, benchmark_integer_chinese_remainder);
+}
+
+// Turns pairs `(x, n)` into residue-modulus pairs `(x, n + 1)` and `(-x, n + 2)`.
+fn residues(x: Integer, n: Natural) -> Vec<(Integer, Natural)> {
+    let n_plus_1 = n + Natural::ONE;
+    let n_plus_2 = &n_plus_1 + Natural::ONE;
+    vec![(-&x, n_plus_2), (x, n_plus_1)]
+}
+
+fn demo_integer_chinese_remainder(gm: GenMode, config: GenConfig, limit: usize) {
+    for (x, n) in integer_natural_pair_gen().get(gm, &config).take(limit) {
+        let residues = residues(x, n);
+        println!(
+            "Integer::chinese_remainder({:?}) = {:?}",
+            residues,
+            Integer::chinese_remainder(&residues)
+        );
+    }
+}
+
+fn benchmark_integer_chinese_remainder(
+    gm: GenMode,
+    config: GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "Integer::chinese_remainder(&[(Integer, Natural)])",
+        BenchmarkType::Single,
+        integer_natural_pair_gen()
+            .get(gm, &config)
+            .map(|(x, n)| (residues(x, n.clone()), n)),
+        gm.name(),
+        limit,
+        file_name,
+        &pair_2_natural_bit_bucketer("n"),
+        &mut [("Malachite", &mut |(residues, _)| {
+            no_out!(Integer::chinese_remainder(&residues))
+        })],
+    );
+}
@@ -0,0 +1,38 @@
+use malachite_base::num::arithmetic::traits::EulerNumber;
+use malachite_base::test_util::bench::bucketers::unsigned_direct_bucketer;
+use malachite_base::test_util::bench::{run_benchmark, BenchmarkType};
+use malachite_base::test_util::generators::common::{GenConfig, GenMode};
+use malachite_base::test_util::generators::unsigned_gen_var_5;
+use malachite_base::test_util::runner::Runner;
+use malachite_nz::integer::Integer;
+
+pub(crate) fn register(runner: &mut Runner) {
+    register_demo!(runner, demo_euler_number);
+    register_demo!(runner, demo_euler_numbers);
+    register_bench!(runner, benchmark_euler_number);
+}
+
+fn demo_euler_number(gm: GenMode, config: GenConfig, limit: usize) {
+    for n in unsigned_gen_var_5().get(gm, &config).take(limit) {
+        println!("euler_number({}) = {}", n, Integer::euler_number(n));
+    }
+}
+
+fn demo_euler_numbers(_gm: GenMode, _config: GenConfig, limit: usize) {
+    for (n, x) in Integer::euler_numbers().enumerate().take(limit) {
+        println!("euler_number({}) = {}", n, x);
+    }
+}
+
+fn benchmark_euler_number(gm: GenMode, config: GenConfig, limit: usize, file_name: &str) {
+    run_benchmark(
+        "Integer::euler_number(u64)",
+        BenchmarkType::Single,
+        unsigned_gen_var_5().get(gm, &config),
+        gm.name(),
+        limit,
+        file_name,
+        &unsigned_direct_bucketer(),
+        &mut [("Malachite", &mut |n| no_out!(Integer::euler_number(n)))],
+    );
+}
@@ -0,0 +1,86 @@
+use malachite_base::num::arithmetic::traits::{Gcd, GcdAssign};
+use malachite_base::test_util::bench::{run_benchmark, BenchmarkType};
+use malachite_base::test_util::generators::common::{GenConfig, GenMode};
+use malachite_base::test_util::runner::Runner;
+use malachite_nz::test_util::bench::bucketers::pair_integer_max_bit_bucketer;
+use malachite_nz::test_util::generators::integer_pair_gen;
+
+pub(crate) fn register(runner: &mut Runner) {
+    register_demo!(runner, demo_integer_gcd);
+    register_demo!(runner, demo_integer_gcd_val_ref);
+    register_demo!(runner, demo_integer_gcd_ref_val);
+    register_demo!(runner, demo_integer_gcd_ref_ref);
+    register_demo!(runner, demo_integer_gcd_assign);
+    register_demo!(runner, demo_integer_gcd_assign_ref);
+
+    register_bench!(runner, benchmark_integer_gcd_evaluation_strategy);
+}
+
+fn demo_integer_gcd(gm: GenMode, config: GenConfig, limit: usize) {
+    for (x, y) in integer_pair_gen().get(gm, &config).take(limit) {
+        println!("{}.gcd({}) = {}", x, y, x.clone().gcd(y.clone()));
+    }
+}
+
+fn demo_integer_gcd_val_ref(gm: GenMode, config: GenConfig, limit: usize) {
+    for (x, y) in integer_pair_gen().get(gm, &config).take(limit) {
+        println!("{}.gcd(&{}) = {}", x, y, x.clone().gcd(&y));
+    }
+}
+
+fn demo_integer_gcd_ref_val(gm: GenMode, config: GenConfig, limit: usize) {
+    for (x, y) in integer_pair_gen().get(gm, &config).take(limit) {
+        println!("(&{}).gcd({}) = {}", x, y, (&x).gcd(y.clone()));
+    }
+}
+
+fn demo_integer_gcd_ref_ref(gm: GenMode, config: GenConfig, limit: usize) {
+    for (x, y) in integer_pair_gen().get(gm, &config).take(limit) {
+        println!("(&{}).gcd(&{}) = {}", x, y, (&x).gcd(&y));
+    }
+}
+
+fn demo_integer_gcd_assign(gm: GenMode, config: GenConfig, limit: usize) {
+    for (mut x, y) in integer_pair_gen().get(gm, &config).take(limit) {
+        let x_old = x.clone();
+        let y_old = y.clone();
+        x.gcd_assign(y);
+        println!("x := {}; x.gcd_assign({}); x = {}", x_old, y_old, x);
+    }
+}
+
+fn demo_integer_gcd_assign_ref(gm: GenMode, config: GenConfig, limit: usize) {
+    for (mut x, y) in integer_pair_gen().get(gm, &config).take(limit) {
+        let x_old = x.clone();
+        x.gcd_assign(&y);
+        println!("x := {}; x.gcd_assign(&{}); x = {}", x_old, y, x);
+    }
+}
+
+fn benchmark_integer_gcd_evaluation_strategy(
+    gm: GenMode,
+    config: GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "Integer.gcd(Integer)",
+        BenchmarkType::EvaluationStrategy,
+        integer_pair_gen().get(gm, &config),
+        gm.name(),
+        limit,
+        file_name,
+        &pair_integer_max_bit_bucketer("x", "y"),
+        &mut [
+            ("Integer.gcd(Integer)", &mut |(x, y)| no_out!(x.gcd(y))),
+            ("Integer.gcd(&Integer)", &mut |(x, y)| no_out!(x.gcd(&y))),
+            (
+                "(&Integer).gcd(Integer)",
+                &mut |(x, y)| no_out!((&x).gcd(y)),
+            ),
+            ("(&Integer).gcd(&Integer)", &mut |(x, y)| {
+                no_out!((&x).gcd(&y))
+            }),
+        ],
+    );
+}
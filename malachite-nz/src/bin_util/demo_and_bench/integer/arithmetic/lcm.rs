@@ -0,0 +1,86 @@
+use malachite_base::num::arithmetic::traits::{Lcm, LcmAssign};
+use malachite_base::test_util::bench::{run_benchmark, BenchmarkType};
+use malachite_base::test_util::generators::common::{GenConfig, GenMode};
+use malachite_base::test_util::runner::Runner;
+use malachite_nz::test_util::bench::bucketers::pair_integer_max_bit_bucketer;
+use malachite_nz::test_util::generators::integer_pair_gen;
+
+pub(crate) fn register(runner: &mut Runner) {
+    register_demo!(runner, demo_integer_lcm);
+    register_demo!(runner, demo_integer_lcm_val_ref);
+    register_demo!(runner, demo_integer_lcm_ref_val);
+    register_demo!(runner, demo_integer_lcm_ref_ref);
+    register_demo!(runner, demo_integer_lcm_assign);
+    register_demo!(runner, demo_integer_lcm_assign_ref);
+
+    register_bench!(runner, benchmark_integer_lcm_evaluation_strategy);
+}
+
+fn demo_integer_lcm(gm: GenMode, config: GenConfig, limit: usize) {
+    for (x, y) in integer_pair_gen().get(gm, &config).take(limit) {
+        println!("{}.lcm({}) = {}", x, y, x.clone().lcm(y.clone()));
+    }
+}
+
+fn demo_integer_lcm_val_ref(gm: GenMode, config: GenConfig, limit: usize) {
+    for (x, y) in integer_pair_gen().get(gm, &config).take(limit) {
+        println!("{}.lcm(&{}) = {}", x, y, x.clone().lcm(&y));
+    }
+}
+
+fn demo_integer_lcm_ref_val(gm: GenMode, config: GenConfig, limit: usize) {
+    for (x, y) in integer_pair_gen().get(gm, &config).take(limit) {
+        println!("(&{}).lcm({}) = {}", x, y, (&x).lcm(y.clone()));
+    }
+}
+
+fn demo_integer_lcm_ref_ref(gm: GenMode, config: GenConfig, limit: usize) {
+    for (x, y) in integer_pair_gen().get(gm, &config).take(limit) {
+        println!("(&{}).lcm(&{}) = {}", x, y, (&x).lcm(&y));
+    }
+}
+
+fn demo_integer_lcm_assign(gm: GenMode, config: GenConfig, limit: usize) {
+    for (mut x, y) in integer_pair_gen().get(gm, &config).take(limit) {
+        let x_old = x.clone();
+        let y_old = y.clone();
+        x.lcm_assign(y);
+        println!("x := {}; x.lcm_assign({}); x = {}", x_old, y_old, x);
+    }
+}
+
+fn demo_integer_lcm_assign_ref(gm: GenMode, config: GenConfig, limit: usize) {
+    for (mut x, y) in integer_pair_gen().get(gm, &config).take(limit) {
+        let x_old = x.clone();
+        x.lcm_assign(&y);
+        println!("x := {}; x.lcm_assign(&{}); x = {}", x_old, y, x);
+    }
+}
+
+fn benchmark_integer_lcm_evaluation_strategy(
+    gm: GenMode,
+    config: GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "Integer.lcm(Integer)",
+        BenchmarkType::EvaluationStrategy,
+        integer_pair_gen().get(gm, &config),
+        gm.name(),
+        limit,
+        file_name,
+        &pair_integer_max_bit_bucketer("x", "y"),
+        &mut [
+            ("Integer.lcm(Integer)", &mut |(x, y)| no_out!(x.lcm(y))),
+            ("Integer.lcm(&Integer)", &mut |(x, y)| no_out!(x.lcm(&y))),
+            (
+                "(&Integer).lcm(Integer)",
+                &mut |(x, y)| no_out!((&x).lcm(y)),
+            ),
+            ("(&Integer).lcm(&Integer)", &mut |(x, y)| {
+                no_out!((&x).lcm(&y))
+            }),
+        ],
+    );
+}
@@ -0,0 +1,75 @@
+use malachite_base::num::arithmetic::traits::LucasSequence;
+use malachite_base::test_util::bench::{run_benchmark, BenchmarkType};
+use malachite_base::test_util::generators::common::{GenConfig, GenMode};
+use malachite_base::test_util::runner::Runner;
+use malachite_nz::integer::Integer;
+use malachite_nz::test_util::bench::bucketers::triple_1_2_integer_max_bit_bucketer;
+use malachite_nz::test_util::generators::integer_integer_unsigned_triple_gen_var_1;
+
+pub(crate) fn register(runner: &mut Runner) {
+    register_demo!(runner, demo_integer_lucas_sequence);
+    register_demo!(runner, demo_integer_lucas_sequence_ref);
+
+    register_bench!(runner, benchmark_integer_lucas_sequence_evaluation_strategy);
+}
+
+fn demo_integer_lucas_sequence(gm: GenMode, config: GenConfig, limit: usize) {
+    for (p, q, n) in integer_integer_unsigned_triple_gen_var_1::<u64>()
+        .get(gm, &config)
+        .take(limit)
+    {
+        let (p_old, q_old) = (p.clone(), q.clone());
+        println!(
+            "(U_{}({}, {}), V_{}({}, {})) = {:?}",
+            n,
+            p_old,
+            q_old,
+            n,
+            p_old,
+            q_old,
+            Integer::lucas_sequence(p, q, n)
+        );
+    }
+}
+
+fn demo_integer_lucas_sequence_ref(gm: GenMode, config: GenConfig, limit: usize) {
+    for (p, q, n) in integer_integer_unsigned_triple_gen_var_1::<u64>()
+        .get(gm, &config)
+        .take(limit)
+    {
+        println!(
+            "Integer::lucas_sequence(&{}, &{}, {}) = {:?}",
+            p,
+            q,
+            n,
+            Integer::lucas_sequence(&p, &q, n)
+        );
+    }
+}
+
+fn benchmark_integer_lucas_sequence_evaluation_strategy(
+    gm: GenMode,
+    config: GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "Integer::lucas_sequence(Integer, Integer, u64)",
+        BenchmarkType::EvaluationStrategy,
+        integer_integer_unsigned_triple_gen_var_1::<u64>().get(gm, &config),
+        gm.name(),
+        limit,
+        file_name,
+        &triple_1_2_integer_max_bit_bucketer("p", "q"),
+        &mut [
+            (
+                "Integer::lucas_sequence(Integer, Integer, u64)",
+                &mut |(p, q, n)| no_out!(Integer::lucas_sequence(p, q, n)),
+            ),
+            (
+                "Integer::lucas_sequence(&Integer, &Integer, u64)",
+                &mut |(p, q, n)| no_out!(Integer::lucas_sequence(&p, &q, n)),
+            ),
+        ],
+    );
+}
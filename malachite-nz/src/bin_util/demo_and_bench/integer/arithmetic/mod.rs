@@ -5,6 +5,7 @@ pub(crate) fn register(runner: &mut Runner) {
     add::register(runner);
     add_mul::register(runner);
     binomial_coefficient::register(runner);
+    chinese_remainder::register(runner);
     div::register(runner);
     div_exact::register(runner);
     div_mod::register(runner);
@@ -13,13 +14,22 @@ pub(crate) fn register(runner: &mut Runner) {
     divisible_by_power_of_2::register(runner);
     eq_mod::register(runner);
     eq_mod_power_of_2::register(runner);
+    euler_number::register(runner);
     extended_gcd::register(runner);
+    gcd::register(runner);
     kronecker_symbol::register(runner);
+    lcm::register(runner);
+    lucas_sequence::register(runner);
+    mod_add::register(runner);
+    mod_inverse::register(runner);
+    mod_mul::register(runner);
     mod_op::register(runner);
+    mod_pow::register(runner);
     mod_power_of_2::register(runner);
     mul::register(runner);
     neg::register(runner);
     parity::register(runner);
+    perfect_power::register(runner);
     pow::register(runner);
     power_of_2::register(runner);
     root::register(runner);
@@ -40,6 +50,7 @@ mod abs;
 mod add;
 mod add_mul;
 mod binomial_coefficient;
+mod chinese_remainder;
 mod div;
 mod div_exact;
 mod div_mod;
@@ -48,13 +59,22 @@ mod divisible_by;
 mod divisible_by_power_of_2;
 mod eq_mod;
 mod eq_mod_power_of_2;
+mod euler_number;
 mod extended_gcd;
+mod gcd;
 mod kronecker_symbol;
+mod lcm;
+mod lucas_sequence;
+mod mod_add;
+mod mod_inverse;
+mod mod_mul;
 mod mod_op;
+mod mod_pow;
 mod mod_power_of_2;
 mod mul;
 mod neg;
 mod parity;
+mod perfect_power;
 mod pow;
 mod power_of_2;
 mod root;
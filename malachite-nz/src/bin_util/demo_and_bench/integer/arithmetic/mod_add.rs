@@ -0,0 +1,214 @@
+use malachite_base::num::arithmetic::traits::{ModAdd, ModAddAssign};
+use malachite_base::test_util::bench::{run_benchmark, BenchmarkType};
+use malachite_base::test_util::generators::common::{GenConfig, GenMode};
+use malachite_base::test_util::runner::Runner;
+use malachite_nz::test_util::bench::bucketers::triple_3_natural_bit_bucketer;
+use malachite_nz::test_util::generators::integer_integer_natural_triple_gen;
+
+pub(crate) fn register(runner: &mut Runner) {
+    register_demo!(runner, demo_integer_mod_add);
+    register_demo!(runner, demo_integer_mod_add_val_val_ref);
+    register_demo!(runner, demo_integer_mod_add_val_ref_val);
+    register_demo!(runner, demo_integer_mod_add_val_ref_ref);
+    register_demo!(runner, demo_integer_mod_add_ref_ref_ref);
+    register_demo!(runner, demo_integer_mod_add_assign);
+    register_demo!(runner, demo_integer_mod_add_assign_ref_ref);
+
+    register_bench!(runner, benchmark_integer_mod_add_evaluation_strategy);
+    register_bench!(runner, benchmark_integer_mod_add_assign_evaluation_strategy);
+}
+
+fn demo_integer_mod_add(gm: GenMode, config: GenConfig, limit: usize) {
+    for (x, y, m) in integer_integer_natural_triple_gen()
+        .get(gm, &config)
+        .filter(|(_, _, m)| *m != 0u32)
+        .take(limit)
+    {
+        println!(
+            "{}.mod_add({}, {}) = {}",
+            x,
+            y,
+            m,
+            x.clone().mod_add(y.clone(), m.clone())
+        );
+    }
+}
+
+fn demo_integer_mod_add_val_val_ref(gm: GenMode, config: GenConfig, limit: usize) {
+    for (x, y, m) in integer_integer_natural_triple_gen()
+        .get(gm, &config)
+        .filter(|(_, _, m)| *m != 0u32)
+        .take(limit)
+    {
+        println!(
+            "{}.mod_add({}, &{}) = {}",
+            x,
+            y,
+            m,
+            x.clone().mod_add(y.clone(), &m)
+        );
+    }
+}
+
+fn demo_integer_mod_add_val_ref_val(gm: GenMode, config: GenConfig, limit: usize) {
+    for (x, y, m) in integer_integer_natural_triple_gen()
+        .get(gm, &config)
+        .filter(|(_, _, m)| *m != 0u32)
+        .take(limit)
+    {
+        println!(
+            "{}.mod_add(&{}, {}) = {}",
+            x,
+            y,
+            m,
+            x.clone().mod_add(&y, m.clone())
+        );
+    }
+}
+
+fn demo_integer_mod_add_val_ref_ref(gm: GenMode, config: GenConfig, limit: usize) {
+    for (x, y, m) in integer_integer_natural_triple_gen()
+        .get(gm, &config)
+        .filter(|(_, _, m)| *m != 0u32)
+        .take(limit)
+    {
+        println!(
+            "{}.mod_add(&{}, &{}) = {}",
+            x,
+            y,
+            m,
+            x.clone().mod_add(&y, &m)
+        );
+    }
+}
+
+fn demo_integer_mod_add_ref_ref_ref(gm: GenMode, config: GenConfig, limit: usize) {
+    for (x, y, m) in integer_integer_natural_triple_gen()
+        .get(gm, &config)
+        .filter(|(_, _, m)| *m != 0u32)
+        .take(limit)
+    {
+        println!(
+            "(&{}).mod_add(&{}, &{}) = {}",
+            x,
+            y,
+            m,
+            (&x).mod_add(&y, &m)
+        );
+    }
+}
+
+fn demo_integer_mod_add_assign(gm: GenMode, config: GenConfig, limit: usize) {
+    for (mut x, y, m) in integer_integer_natural_triple_gen()
+        .get(gm, &config)
+        .filter(|(_, _, m)| *m != 0u32)
+        .take(limit)
+    {
+        let x_old = x.clone();
+        let y_old = y.clone();
+        let m_old = m.clone();
+        x.mod_add_assign(y, m);
+        println!(
+            "x := {}; x.mod_add_assign({}, {}); x = {}",
+            x_old, y_old, m_old, x
+        );
+    }
+}
+
+fn demo_integer_mod_add_assign_ref_ref(gm: GenMode, config: GenConfig, limit: usize) {
+    for (mut x, y, m) in integer_integer_natural_triple_gen()
+        .get(gm, &config)
+        .filter(|(_, _, m)| *m != 0u32)
+        .take(limit)
+    {
+        let x_old = x.clone();
+        x.mod_add_assign(&y, &m);
+        println!(
+            "x := {}; x.mod_add_assign(&{}, &{}); x = {}",
+            x_old, y, m, x
+        );
+    }
+}
+
+fn benchmark_integer_mod_add_evaluation_strategy(
+    gm: GenMode,
+    config: GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "Integer.mod_add(Integer, Natural)",
+        BenchmarkType::EvaluationStrategy,
+        integer_integer_natural_triple_gen()
+            .get(gm, &config)
+            .filter(|(_, _, m)| *m != 0u32),
+        gm.name(),
+        limit,
+        file_name,
+        &triple_3_natural_bit_bucketer("m"),
+        &mut [
+            ("Integer.mod_add(Integer, Natural)", &mut |(x, y, m)| {
+                no_out!(x.mod_add(y, m))
+            }),
+            ("Integer.mod_add(Integer, &Natural)", &mut |(x, y, m)| {
+                no_out!(x.mod_add(y, &m))
+            }),
+            ("Integer.mod_add(&Integer, Natural)", &mut |(x, y, m)| {
+                no_out!(x.mod_add(&y, m))
+            }),
+            ("Integer.mod_add(&Integer, &Natural)", &mut |(x, y, m)| {
+                no_out!(x.mod_add(&y, &m))
+            }),
+            ("(&Integer).mod_add(Integer, Natural)", &mut |(x, y, m)| {
+                no_out!((&x).mod_add(y, m))
+            }),
+            ("(&Integer).mod_add(Integer, &Natural)", &mut |(x, y, m)| {
+                no_out!((&x).mod_add(y, &m))
+            }),
+            ("(&Integer).mod_add(&Integer, Natural)", &mut |(x, y, m)| {
+                no_out!((&x).mod_add(&y, m))
+            }),
+            (
+                "(&Integer).mod_add(&Integer, &Natural)",
+                &mut |(x, y, m)| no_out!((&x).mod_add(&y, &m)),
+            ),
+        ],
+    );
+}
+
+fn benchmark_integer_mod_add_assign_evaluation_strategy(
+    gm: GenMode,
+    config: GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "Integer.mod_add_assign(Integer, Natural)",
+        BenchmarkType::EvaluationStrategy,
+        integer_integer_natural_triple_gen()
+            .get(gm, &config)
+            .filter(|(_, _, m)| *m != 0u32),
+        gm.name(),
+        limit,
+        file_name,
+        &triple_3_natural_bit_bucketer("m"),
+        &mut [
+            (
+                "Integer.mod_add_assign(Integer, Natural)",
+                &mut |(mut x, y, m)| x.mod_add_assign(y, m),
+            ),
+            (
+                "Integer.mod_add_assign(Integer, &Natural)",
+                &mut |(mut x, y, m)| x.mod_add_assign(y, &m),
+            ),
+            (
+                "Integer.mod_add_assign(&Integer, Natural)",
+                &mut |(mut x, y, m)| x.mod_add_assign(&y, m),
+            ),
+            (
+                "Integer.mod_add_assign(&Integer, &Natural)",
+                &mut |(mut x, y, m)| x.mod_add_assign(&y, &m),
+            ),
+        ],
+    );
+}
@@ -0,0 +1,103 @@
+use malachite_base::num::arithmetic::traits::ModInverse;
+use malachite_base::test_util::bench::{run_benchmark, BenchmarkType};
+use malachite_base::test_util::generators::common::{GenConfig, GenMode};
+use malachite_base::test_util::runner::Runner;
+use malachite_nz::test_util::bench::bucketers::pair_2_natural_bit_bucketer;
+use malachite_nz::test_util::generators::integer_natural_pair_gen;
+
+pub(crate) fn register(runner: &mut Runner) {
+    register_demo!(runner, demo_integer_mod_inverse);
+    register_demo!(runner, demo_integer_mod_inverse_val_ref);
+    register_demo!(runner, demo_integer_mod_inverse_ref_val);
+    register_demo!(runner, demo_integer_mod_inverse_ref_ref);
+
+    register_bench!(runner, benchmark_integer_mod_inverse_evaluation_strategy);
+}
+
+fn demo_integer_mod_inverse(gm: GenMode, config: GenConfig, limit: usize) {
+    for (x, m) in integer_natural_pair_gen()
+        .get(gm, &config)
+        .filter(|(_, m)| *m != 0u32)
+        .take(limit)
+    {
+        println!(
+            "{}.mod_inverse({}) = {:?}",
+            x,
+            m,
+            x.clone().mod_inverse(m.clone())
+        );
+    }
+}
+
+fn demo_integer_mod_inverse_val_ref(gm: GenMode, config: GenConfig, limit: usize) {
+    for (x, m) in integer_natural_pair_gen()
+        .get(gm, &config)
+        .filter(|(_, m)| *m != 0u32)
+        .take(limit)
+    {
+        println!(
+            "{}.mod_inverse(&{}) = {:?}",
+            x,
+            m,
+            x.clone().mod_inverse(&m)
+        );
+    }
+}
+
+fn demo_integer_mod_inverse_ref_val(gm: GenMode, config: GenConfig, limit: usize) {
+    for (x, m) in integer_natural_pair_gen()
+        .get(gm, &config)
+        .filter(|(_, m)| *m != 0u32)
+        .take(limit)
+    {
+        println!(
+            "(&{}).mod_inverse({}) = {:?}",
+            x,
+            m,
+            (&x).mod_inverse(m.clone())
+        );
+    }
+}
+
+fn demo_integer_mod_inverse_ref_ref(gm: GenMode, config: GenConfig, limit: usize) {
+    for (x, m) in integer_natural_pair_gen()
+        .get(gm, &config)
+        .filter(|(_, m)| *m != 0u32)
+        .take(limit)
+    {
+        println!("(&{}).mod_inverse(&{}) = {:?}", x, m, (&x).mod_inverse(&m));
+    }
+}
+
+fn benchmark_integer_mod_inverse_evaluation_strategy(
+    gm: GenMode,
+    config: GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "Integer.mod_inverse(Natural)",
+        BenchmarkType::EvaluationStrategy,
+        integer_natural_pair_gen()
+            .get(gm, &config)
+            .filter(|(_, m)| *m != 0u32),
+        gm.name(),
+        limit,
+        file_name,
+        &pair_2_natural_bit_bucketer("m"),
+        &mut [
+            ("Integer.mod_inverse(Natural)", &mut |(x, m)| {
+                no_out!(x.mod_inverse(m))
+            }),
+            ("Integer.mod_inverse(&Natural)", &mut |(x, m)| {
+                no_out!(x.mod_inverse(&m))
+            }),
+            ("(&Integer).mod_inverse(Natural)", &mut |(x, m)| {
+                no_out!((&x).mod_inverse(m))
+            }),
+            ("(&Integer).mod_inverse(&Natural)", &mut |(x, m)| {
+                no_out!((&x).mod_inverse(&m))
+            }),
+        ],
+    );
+}
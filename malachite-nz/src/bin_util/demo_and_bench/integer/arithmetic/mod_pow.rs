@@ -0,0 +1,221 @@
+use malachite_base::num::arithmetic::traits::{CoprimeWith, ModPow, ModPowAssign};
+use malachite_base::test_util::bench::{run_benchmark, BenchmarkType};
+use malachite_base::test_util::generators::common::{GenConfig, GenMode};
+use malachite_base::test_util::runner::Runner;
+use malachite_nz::integer::Integer;
+use malachite_nz::natural::Natural;
+use malachite_nz::test_util::bench::bucketers::triple_3_natural_bit_bucketer;
+use malachite_nz::test_util::generators::integer_integer_natural_triple_gen;
+
+pub(crate) fn register(runner: &mut Runner) {
+    register_demo!(runner, demo_integer_mod_pow);
+    register_demo!(runner, demo_integer_mod_pow_val_val_ref);
+    register_demo!(runner, demo_integer_mod_pow_val_ref_val);
+    register_demo!(runner, demo_integer_mod_pow_val_ref_ref);
+    register_demo!(runner, demo_integer_mod_pow_ref_ref_ref);
+    register_demo!(runner, demo_integer_mod_pow_assign);
+    register_demo!(runner, demo_integer_mod_pow_assign_ref_ref);
+
+    register_bench!(runner, benchmark_integer_mod_pow_evaluation_strategy);
+    register_bench!(runner, benchmark_integer_mod_pow_assign_evaluation_strategy);
+}
+
+// Skips zero moduli, and negative exponents of residues that are not invertible.
+fn valid_input((x, exp, m): &(Integer, Integer, Natural)) -> bool {
+    *m != 0u32 && (*exp >= 0u32 || x.unsigned_abs_ref().coprime_with(m))
+}
+
+fn demo_integer_mod_pow(gm: GenMode, config: GenConfig, limit: usize) {
+    for (x, y, m) in integer_integer_natural_triple_gen()
+        .get(gm, &config)
+        .filter(valid_input)
+        .take(limit)
+    {
+        println!(
+            "{}.mod_pow({}, {}) = {}",
+            x,
+            y,
+            m,
+            x.clone().mod_pow(y.clone(), m.clone())
+        );
+    }
+}
+
+fn demo_integer_mod_pow_val_val_ref(gm: GenMode, config: GenConfig, limit: usize) {
+    for (x, y, m) in integer_integer_natural_triple_gen()
+        .get(gm, &config)
+        .filter(valid_input)
+        .take(limit)
+    {
+        println!(
+            "{}.mod_pow({}, &{}) = {}",
+            x,
+            y,
+            m,
+            x.clone().mod_pow(y.clone(), &m)
+        );
+    }
+}
+
+fn demo_integer_mod_pow_val_ref_val(gm: GenMode, config: GenConfig, limit: usize) {
+    for (x, y, m) in integer_integer_natural_triple_gen()
+        .get(gm, &config)
+        .filter(valid_input)
+        .take(limit)
+    {
+        println!(
+            "{}.mod_pow(&{}, {}) = {}",
+            x,
+            y,
+            m,
+            x.clone().mod_pow(&y, m.clone())
+        );
+    }
+}
+
+fn demo_integer_mod_pow_val_ref_ref(gm: GenMode, config: GenConfig, limit: usize) {
+    for (x, y, m) in integer_integer_natural_triple_gen()
+        .get(gm, &config)
+        .filter(valid_input)
+        .take(limit)
+    {
+        println!(
+            "{}.mod_pow(&{}, &{}) = {}",
+            x,
+            y,
+            m,
+            x.clone().mod_pow(&y, &m)
+        );
+    }
+}
+
+fn demo_integer_mod_pow_ref_ref_ref(gm: GenMode, config: GenConfig, limit: usize) {
+    for (x, y, m) in integer_integer_natural_triple_gen()
+        .get(gm, &config)
+        .filter(valid_input)
+        .take(limit)
+    {
+        println!(
+            "(&{}).mod_pow(&{}, &{}) = {}",
+            x,
+            y,
+            m,
+            (&x).mod_pow(&y, &m)
+        );
+    }
+}
+
+fn demo_integer_mod_pow_assign(gm: GenMode, config: GenConfig, limit: usize) {
+    for (mut x, y, m) in integer_integer_natural_triple_gen()
+        .get(gm, &config)
+        .filter(valid_input)
+        .take(limit)
+    {
+        let x_old = x.clone();
+        let y_old = y.clone();
+        let m_old = m.clone();
+        x.mod_pow_assign(y, m);
+        println!(
+            "x := {}; x.mod_pow_assign({}, {}); x = {}",
+            x_old, y_old, m_old, x
+        );
+    }
+}
+
+fn demo_integer_mod_pow_assign_ref_ref(gm: GenMode, config: GenConfig, limit: usize) {
+    for (mut x, y, m) in integer_integer_natural_triple_gen()
+        .get(gm, &config)
+        .filter(valid_input)
+        .take(limit)
+    {
+        let x_old = x.clone();
+        x.mod_pow_assign(&y, &m);
+        println!(
+            "x := {}; x.mod_pow_assign(&{}, &{}); x = {}",
+            x_old, y, m, x
+        );
+    }
+}
+
+fn benchmark_integer_mod_pow_evaluation_strategy(
+    gm: GenMode,
+    config: GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "Integer.mod_pow(Integer, Natural)",
+        BenchmarkType::EvaluationStrategy,
+        integer_integer_natural_triple_gen()
+            .get(gm, &config)
+            .filter(valid_input),
+        gm.name(),
+        limit,
+        file_name,
+        &triple_3_natural_bit_bucketer("m"),
+        &mut [
+            ("Integer.mod_pow(Integer, Natural)", &mut |(x, y, m)| {
+                no_out!(x.mod_pow(y, m))
+            }),
+            ("Integer.mod_pow(Integer, &Natural)", &mut |(x, y, m)| {
+                no_out!(x.mod_pow(y, &m))
+            }),
+            ("Integer.mod_pow(&Integer, Natural)", &mut |(x, y, m)| {
+                no_out!(x.mod_pow(&y, m))
+            }),
+            ("Integer.mod_pow(&Integer, &Natural)", &mut |(x, y, m)| {
+                no_out!(x.mod_pow(&y, &m))
+            }),
+            ("(&Integer).mod_pow(Integer, Natural)", &mut |(x, y, m)| {
+                no_out!((&x).mod_pow(y, m))
+            }),
+            ("(&Integer).mod_pow(Integer, &Natural)", &mut |(x, y, m)| {
+                no_out!((&x).mod_pow(y, &m))
+            }),
+            ("(&Integer).mod_pow(&Integer, Natural)", &mut |(x, y, m)| {
+                no_out!((&x).mod_pow(&y, m))
+            }),
+            (
+                "(&Integer).mod_pow(&Integer, &Natural)",
+                &mut |(x, y, m)| no_out!((&x).mod_pow(&y, &m)),
+            ),
+        ],
+    );
+}
+
+fn benchmark_integer_mod_pow_assign_evaluation_strategy(
+    gm: GenMode,
+    config: GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "Integer.mod_pow_assign(Integer, Natural)",
+        BenchmarkType::EvaluationStrategy,
+        integer_integer_natural_triple_gen()
+            .get(gm, &config)
+            .filter(valid_input),
+        gm.name(),
+        limit,
+        file_name,
+        &triple_3_natural_bit_bucketer("m"),
+        &mut [
+            (
+                "Integer.mod_pow_assign(Integer, Natural)",
+                &mut |(mut x, y, m)| x.mod_pow_assign(y, m),
+            ),
+            (
+                "Integer.mod_pow_assign(Integer, &Natural)",
+                &mut |(mut x, y, m)| x.mod_pow_assign(y, &m),
+            ),
+            (
+                "Integer.mod_pow_assign(&Integer, Natural)",
+                &mut |(mut x, y, m)| x.mod_pow_assign(&y, m),
+            ),
+            (
+                "Integer.mod_pow_assign(&Integer, &Natural)",
+                &mut |(mut x, y, m)| x.mod_pow_assign(&y, &m),
+            ),
+        ],
+    );
+}
@@ -0,0 +1,72 @@
+use malachite_base::num::arithmetic::traits::{IsPerfectPower, PerfectPowerDecomposition};
+use malachite_base::test_util::bench::{run_benchmark, BenchmarkType};
+use malachite_base::test_util::generators::common::{GenConfig, GenMode};
+use malachite_base::test_util::runner::Runner;
+use malachite_nz::test_util::bench::bucketers::integer_bit_bucketer;
+use malachite_nz::test_util::generators::integer_gen;
+
+pub(crate) fn register(runner: &mut Runner) {
+    register_demo!(runner, demo_integer_is_perfect_power);
+    register_demo!(runner, demo_integer_perfect_power_decomposition);
+
+    register_bench!(runner, benchmark_integer_is_perfect_power);
+    register_bench!(runner, benchmark_integer_perfect_power_decomposition);
+}
+
+fn demo_integer_is_perfect_power(gm: GenMode, config: GenConfig, limit: usize) {
+    for n in integer_gen().get(gm, &config).take(limit) {
+        if n.is_perfect_power() {
+            println!("{} is a perfect power", n);
+        } else {
+            println!("{} is not a perfect power", n);
+        }
+    }
+}
+
+fn demo_integer_perfect_power_decomposition(gm: GenMode, config: GenConfig, limit: usize) {
+    for n in integer_gen().get(gm, &config).take(limit) {
+        println!(
+            "{}.perfect_power_decomposition() = {:?}",
+            n,
+            n.perfect_power_decomposition()
+        );
+    }
+}
+
+fn benchmark_integer_is_perfect_power(
+    gm: GenMode,
+    config: GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "Integer.is_perfect_power()",
+        BenchmarkType::Single,
+        integer_gen().get(gm, &config),
+        gm.name(),
+        limit,
+        file_name,
+        &integer_bit_bucketer("n"),
+        &mut [("Malachite", &mut |n| no_out!(n.is_perfect_power()))],
+    );
+}
+
+fn benchmark_integer_perfect_power_decomposition(
+    gm: GenMode,
+    config: GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "Integer.perfect_power_decomposition()",
+        BenchmarkType::Single,
+        integer_gen().get(gm, &config),
+        gm.name(),
+        limit,
+        file_name,
+        &integer_bit_bucketer("n"),
+        &mut [("Malachite", &mut |n| {
+            no_out!(n.perfect_power_decomposition())
+        })],
+    );
+}
@@ -0,0 +1,78 @@
+use malachite_base::num::arithmetic::traits::ChineseRemainder;
+use malachite_base::num::basic::traits::One;
+use malachite_base::test_util::bench::{run_benchmark, BenchmarkType};
+use malachite_base::test_util::generators::common::{GenConfig, GenMode};
+use malachite_base::test_util::runner::Runner;
+use malachite_nz::natural::arithmetic::chinese_remainder::ChineseRemainderBasis;
+use malachite_nz::natural::Natural;
+use malachite_nz::test_util::bench::bucketers::pair_2_natural_bit_bucketer;
+use malachite_nz::test_util::generators::natural_pair_gen_var_2;
+
+pub(crate) fn register(runner: &mut Runner) {
+    register_demo!(runner, demo_natural_chinese_remainder);
+    register_demo!(runner, demo_chinese_remainder_basis_recombine);
+    register_bench!(runner, benchmark_natural_chinese_remainder_algorithms);
+}
+
+// Turns pairs `(x, n)` into residue-modulus pairs `(x, n)`, `(x + 1, n + 1)`, and `(x + 2, 2n + 1)`.
+// The moduli are pairwise coprime.
+fn coprime_residues(x: Natural, n: Natural) -> Vec<(Natural, Natural)> {
+    let n_plus_1 = &n + Natural::ONE;
+    let two_n_plus_1 = (&n << 1u32) + Natural::ONE;
+    vec![(&x + Natural::ONE, n_plus_1), (&x + Natural::from(2u32), two_n_plus_1), (x, n)]
+}
+
+fn demo_natural_chinese_remainder(gm: GenMode, config: GenConfig, limit: usize) {
+    for (x, n) in natural_pair_gen_var_2().get(gm, &config).take(limit) {
+        let residues = coprime_residues(x, n);
+        println!(
+            "Natural::chinese_remainder({:?}) = {:?}",
+            residues,
+            Natural::chinese_remainder(&residues)
+        );
+    }
+}
+
+fn demo_chinese_remainder_basis_recombine(gm: GenMode, config: GenConfig, limit: usize) {
+    for (x, n) in natural_pair_gen_var_2().get(gm, &config).take(limit) {
+        let (rs, moduli): (Vec<Natural>, Vec<Natural>) = coprime_residues(x, n).into_iter().unzip();
+        let basis = ChineseRemainderBasis::new(&moduli).unwrap();
+        println!(
+            "ChineseRemainderBasis::new({:?}).recombine({:?}) = {}",
+            moduli,
+            rs,
+            basis.recombine(&rs)
+        );
+    }
+}
+
+fn benchmark_natural_chinese_remainder_algorithms(
+    gm: GenMode,
+    config: GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "Natural::chinese_remainder(&[(Natural, Natural)])",
+        BenchmarkType::Algorithms,
+        natural_pair_gen_var_2().get(gm, &config).map(|(x, n)| {
+            let residues = coprime_residues(x, n.clone());
+            let moduli: Vec<Natural> = residues.iter().map(|(_, m)| m.clone()).collect();
+            let basis = ChineseRemainderBasis::new(&moduli).unwrap();
+            ((residues, basis), n)
+        }),
+        gm.name(),
+        limit,
+        file_name,
+        &pair_2_natural_bit_bucketer("n"),
+        &mut [
+            ("chinese_remainder", &mut |((residues, _), _)| {
+                no_out!(Natural::chinese_remainder(&residues))
+            }),
+            ("precomputed basis", &mut |((residues, basis), _)| {
+                let rs: Vec<Natural> = residues.into_iter().map(|(r, _)| r).collect();
+                no_out!(basis.recombine(&rs))
+            }),
+        ],
+    );
+}
@@ -0,0 +1,73 @@
+use itertools::Itertools;
+use malachite_base::num::arithmetic::traits::{DivisorCount, DivisorSum, Divisors};
+use malachite_base::test_util::bench::{run_benchmark, BenchmarkType};
+use malachite_base::test_util::generators::common::{GenConfig, GenMode};
+use malachite_base::test_util::runner::Runner;
+use malachite_nz::test_util::bench::bucketers::natural_bit_bucketer;
+use malachite_nz::test_util::generators::natural_gen_var_2;
+
+pub(crate) fn register(runner: &mut Runner) {
+    register_demo!(runner, demo_natural_divisor_count);
+    register_demo!(runner, demo_natural_divisor_sum);
+    register_demo!(runner, demo_natural_divisors);
+    register_bench!(runner, benchmark_natural_divisor_count);
+    register_bench!(runner, benchmark_natural_divisor_sum);
+    register_bench!(runner, benchmark_natural_divisors);
+}
+
+fn demo_natural_divisor_count(gm: GenMode, config: GenConfig, limit: usize) {
+    for n in natural_gen_var_2().get(gm, &config).take(limit) {
+        println!("{}.divisor_count() = {}", n, n.divisor_count());
+    }
+}
+
+fn demo_natural_divisor_sum(gm: GenMode, config: GenConfig, limit: usize) {
+    for n in natural_gen_var_2().get(gm, &config).take(limit) {
+        println!("{}.divisor_sum(1) = {}", n, n.divisor_sum(1));
+    }
+}
+
+fn demo_natural_divisors(gm: GenMode, config: GenConfig, limit: usize) {
+    for n in natural_gen_var_2().get(gm, &config).take(limit) {
+        println!("{}.divisors() = {:?}", n, n.divisors().collect_vec());
+    }
+}
+
+fn benchmark_natural_divisor_count(gm: GenMode, config: GenConfig, limit: usize, file_name: &str) {
+    run_benchmark(
+        "Natural.divisor_count()",
+        BenchmarkType::Single,
+        natural_gen_var_2().get(gm, &config),
+        gm.name(),
+        limit,
+        file_name,
+        &natural_bit_bucketer("n"),
+        &mut [("Malachite", &mut |n| no_out!(n.divisor_count()))],
+    );
+}
+
+fn benchmark_natural_divisor_sum(gm: GenMode, config: GenConfig, limit: usize, file_name: &str) {
+    run_benchmark(
+        "Natural.divisor_sum(1)",
+        BenchmarkType::Single,
+        natural_gen_var_2().get(gm, &config),
+        gm.name(),
+        limit,
+        file_name,
+        &natural_bit_bucketer("n"),
+        &mut [("Malachite", &mut |n| no_out!(n.divisor_sum(1)))],
+    );
+}
+
+fn benchmark_natural_divisors(gm: GenMode, config: GenConfig, limit: usize, file_name: &str) {
+    run_benchmark(
+        "Natural.divisors()",
+        BenchmarkType::Single,
+        natural_gen_var_2().get(gm, &config),
+        gm.name(),
+        limit,
+        file_name,
+        &natural_bit_bucketer("n"),
+        &mut [("Malachite", &mut |n| no_out!(n.divisors().collect_vec()))],
+    );
+}
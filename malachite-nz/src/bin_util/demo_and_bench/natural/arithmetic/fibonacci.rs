@@ -0,0 +1,94 @@
+use malachite_base::num::arithmetic::traits::{Fibonacci, Lucas, ModLucasSequence};
+use malachite_base::test_util::bench::bucketers::unsigned_direct_bucketer;
+use malachite_base::test_util::bench::{run_benchmark, BenchmarkType};
+use malachite_base::test_util::generators::common::{GenConfig, GenMode};
+use malachite_base::test_util::generators::unsigned_gen_var_5;
+use malachite_base::test_util::runner::Runner;
+use malachite_nz::natural::Natural;
+use malachite_nz::test_util::bench::bucketers::quadruple_4_natural_bit_bucketer;
+use malachite_nz::test_util::generators::natural_quadruple_gen_var_2;
+
+pub(crate) fn register(runner: &mut Runner) {
+    register_demo!(runner, demo_natural_fibonacci);
+    register_demo!(runner, demo_natural_lucas);
+    register_demo!(runner, demo_natural_mod_lucas_sequence);
+
+    register_bench!(runner, benchmark_natural_fibonacci);
+    register_bench!(runner, benchmark_natural_lucas);
+    register_bench!(runner, benchmark_natural_mod_lucas_sequence);
+}
+
+fn demo_natural_fibonacci(gm: GenMode, config: GenConfig, limit: usize) {
+    for n in unsigned_gen_var_5().get(gm, &config).take(limit) {
+        println!("F_{} = {}", n, Natural::fibonacci(n));
+    }
+}
+
+fn demo_natural_lucas(gm: GenMode, config: GenConfig, limit: usize) {
+    for n in unsigned_gen_var_5().get(gm, &config).take(limit) {
+        println!("L_{} = {}", n, Natural::lucas(n));
+    }
+}
+
+fn demo_natural_mod_lucas_sequence(gm: GenMode, config: GenConfig, limit: usize) {
+    for (p, q, n, m) in natural_quadruple_gen_var_2().get(gm, &config).take(limit) {
+        let (p_old, q_old, n_old, m_old) = (p.clone(), q.clone(), n.clone(), m.clone());
+        println!(
+            "(U_{}({}, {}), V_{}({}, {})) ≡ {:?} mod {}",
+            n_old,
+            p_old,
+            q_old,
+            n_old,
+            p_old,
+            q_old,
+            Natural::mod_lucas_sequence(p, q, n, m),
+            m_old
+        );
+    }
+}
+
+fn benchmark_natural_fibonacci(gm: GenMode, config: GenConfig, limit: usize, file_name: &str) {
+    run_benchmark(
+        "Natural::fibonacci(u64)",
+        BenchmarkType::Single,
+        unsigned_gen_var_5().get(gm, &config),
+        gm.name(),
+        limit,
+        file_name,
+        &unsigned_direct_bucketer(),
+        &mut [("Malachite", &mut |n| no_out!(Natural::fibonacci(n)))],
+    );
+}
+
+fn benchmark_natural_lucas(gm: GenMode, config: GenConfig, limit: usize, file_name: &str) {
+    run_benchmark(
+        "Natural::lucas(u64)",
+        BenchmarkType::Single,
+        unsigned_gen_var_5().get(gm, &config),
+        gm.name(),
+        limit,
+        file_name,
+        &unsigned_direct_bucketer(),
+        &mut [("Malachite", &mut |n| no_out!(Natural::lucas(n)))],
+    );
+}
+
+fn benchmark_natural_mod_lucas_sequence(
+    gm: GenMode,
+    config: GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "Natural::mod_lucas_sequence(Natural, Natural, Natural, Natural)",
+        BenchmarkType::Single,
+        natural_quadruple_gen_var_2().get(gm, &config),
+        gm.name(),
+        limit,
+        file_name,
+        &quadruple_4_natural_bit_bucketer("m"),
+        &mut [("Malachite", &mut |(p, q, n, m)| {
+            no_out!(Natural::mod_lucas_sequence(p, q, n, m))
+        })],
+    );
+}
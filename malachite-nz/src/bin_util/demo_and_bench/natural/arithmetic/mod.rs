@@ -4,8 +4,10 @@ pub(crate) fn register(runner: &mut Runner) {
     add::register(runner);
     add_mul::register(runner);
     binomial_coefficient::register(runner);
+    catalan::register(runner);
     checked_sub::register(runner);
     checked_sub_mul::register(runner);
+    chinese_remainder::register(runner);
     coprime_with::register(runner);
     div::register(runner);
     div_exact::register(runner);
@@ -13,10 +15,12 @@ pub(crate) fn register(runner: &mut Runner) {
     div_round::register(runner);
     divisible_by::register(runner);
     divisible_by_power_of_2::register(runner);
+    divisors::register(runner);
     eq_mod::register(runner);
     eq_mod_power_of_2::register(runner);
     extended_gcd::register(runner);
     factorial::register(runner);
+    fibonacci::register(runner);
     gcd::register(runner);
     is_power_of_2::register(runner);
     kronecker_symbol::register(runner);
@@ -25,9 +29,13 @@ pub(crate) fn register(runner: &mut Runner) {
     log_base_2::register(runner);
     log_base_power_of_2::register(runner);
     mod_add::register(runner);
+    mod_binomial_coefficient::register(runner);
+    mod_factorial::register(runner);
     mod_inverse::register(runner);
     mod_is_reduced::register(runner);
+    mod_log::register(runner);
     mod_mul::register(runner);
+    mod_natural::register(runner);
     mod_neg::register(runner);
     mod_op::register(runner);
     mod_pow::register(runner);
@@ -44,14 +52,22 @@ pub(crate) fn register(runner: &mut Runner) {
     mod_power_of_2_sub::register(runner);
     mod_shl::register(runner);
     mod_shr::register(runner);
+    mod_sqrt::register(runner);
     mod_square::register(runner);
     mod_sub::register(runner);
+    moebius::register(runner);
+    montgomery::register(runner);
     mul::register(runner);
+    multinomial::register(runner);
+    multiplicative_order::register(runner);
     neg::register(runner);
     next_power_of_2::register(runner);
     parity::register(runner);
+    partition_count::register(runner);
+    perfect_power::register(runner);
     pow::register(runner);
     power_of_2::register(runner);
+    precomputed_divisor::register(runner);
     primorial::register(runner);
     root::register(runner);
     round_to_multiple::register(runner);
@@ -65,15 +81,19 @@ pub(crate) fn register(runner: &mut Runner) {
     sign::register(runner);
     sqrt::register(runner);
     square::register(runner);
+    stirling::register(runner);
     sub::register(runner);
     sub_mul::register(runner);
+    totient::register(runner);
 }
 
 mod add;
 mod add_mul;
 mod binomial_coefficient;
+mod catalan;
 mod checked_sub;
 mod checked_sub_mul;
+mod chinese_remainder;
 mod coprime_with;
 mod div;
 mod div_exact;
@@ -81,10 +101,12 @@ mod div_mod;
 mod div_round;
 mod divisible_by;
 mod divisible_by_power_of_2;
+mod divisors;
 mod eq_mod;
 mod eq_mod_power_of_2;
 mod extended_gcd;
 mod factorial;
+mod fibonacci;
 mod gcd;
 mod is_power_of_2;
 mod kronecker_symbol;
@@ -93,9 +115,13 @@ mod log_base;
 mod log_base_2;
 mod log_base_power_of_2;
 mod mod_add;
+mod mod_binomial_coefficient;
+mod mod_factorial;
 mod mod_inverse;
 mod mod_is_reduced;
+mod mod_log;
 mod mod_mul;
+mod mod_natural;
 mod mod_neg;
 mod mod_op;
 mod mod_pow;
@@ -112,14 +138,22 @@ mod mod_power_of_2_square;
 mod mod_power_of_2_sub;
 mod mod_shl;
 mod mod_shr;
+mod mod_sqrt;
 mod mod_square;
 mod mod_sub;
+mod moebius;
+mod montgomery;
 mod mul;
+mod multinomial;
+mod multiplicative_order;
 mod neg;
 mod next_power_of_2;
 mod parity;
+mod partition_count;
+mod perfect_power;
 mod pow;
 mod power_of_2;
+mod precomputed_divisor;
 mod primorial;
 mod root;
 mod round_to_multiple;
@@ -133,5 +167,7 @@ mod shr_round;
 mod sign;
 mod sqrt;
 mod square;
+mod stirling;
 mod sub;
 mod sub_mul;
+mod totient;
@@ -0,0 +1,69 @@
+use malachite_base::num::arithmetic::traits::ModBinomialCoefficient;
+use malachite_base::test_util::bench::bucketers::triple_3_bit_bucketer;
+use malachite_base::test_util::bench::{run_benchmark, BenchmarkType};
+use malachite_base::test_util::generators::common::{GenConfig, GenMode};
+use malachite_base::test_util::generators::unsigned_triple_gen_var_24;
+use malachite_base::test_util::runner::Runner;
+use malachite_nz::natural::Natural;
+
+pub(crate) fn register(runner: &mut Runner) {
+    register_demo!(runner, demo_natural_mod_binomial_coefficient);
+    register_bench!(
+        runner,
+        benchmark_natural_mod_binomial_coefficient_evaluation_strategy
+    );
+}
+
+fn demo_natural_mod_binomial_coefficient(gm: GenMode, config: GenConfig, limit: usize) {
+    for (n, k, m) in unsigned_triple_gen_var_24::<u64>()
+        .get(gm, &config)
+        .take(limit)
+    {
+        println!(
+            "mod_binomial_coefficient({}, {}, {}) = {}",
+            n,
+            k,
+            m,
+            Natural::mod_binomial_coefficient(Natural::from(n), Natural::from(k), Natural::from(m))
+        );
+    }
+}
+
+fn benchmark_natural_mod_binomial_coefficient_evaluation_strategy(
+    gm: GenMode,
+    config: GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "Natural::mod_binomial_coefficient(Natural, Natural, Natural)",
+        BenchmarkType::EvaluationStrategy,
+        unsigned_triple_gen_var_24::<u64>().get(gm, &config),
+        gm.name(),
+        limit,
+        file_name,
+        &triple_3_bit_bucketer("m"),
+        &mut [
+            (
+                "Natural::mod_binomial_coefficient(Natural, Natural, Natural)",
+                &mut |(n, k, m)| {
+                    no_out!(Natural::mod_binomial_coefficient(
+                        Natural::from(n),
+                        Natural::from(k),
+                        Natural::from(m)
+                    ))
+                },
+            ),
+            (
+                "Natural::mod_binomial_coefficient(&Natural, &Natural, &Natural)",
+                &mut |(n, k, m)| {
+                    no_out!(Natural::mod_binomial_coefficient(
+                        &Natural::from(n),
+                        &Natural::from(k),
+                        &Natural::from(m)
+                    ))
+                },
+            ),
+        ],
+    );
+}
@@ -0,0 +1,57 @@
+use malachite_base::num::arithmetic::traits::ModFactorial;
+use malachite_base::test_util::bench::bucketers::triple_3_bit_bucketer;
+use malachite_base::test_util::bench::{run_benchmark, BenchmarkType};
+use malachite_base::test_util::generators::common::{GenConfig, GenMode};
+use malachite_base::test_util::generators::unsigned_triple_gen_var_24;
+use malachite_base::test_util::runner::Runner;
+use malachite_nz::natural::Natural;
+
+pub(crate) fn register(runner: &mut Runner) {
+    register_demo!(runner, demo_natural_mod_factorial);
+    register_bench!(runner, benchmark_natural_mod_factorial_evaluation_strategy);
+}
+
+fn demo_natural_mod_factorial(gm: GenMode, config: GenConfig, limit: usize) {
+    for (n, _, m) in unsigned_triple_gen_var_24::<u64>()
+        .get(gm, &config)
+        .take(limit)
+    {
+        println!(
+            "mod_factorial({}, {}) = {}",
+            n,
+            m,
+            Natural::mod_factorial(Natural::from(n), Natural::from(m))
+        );
+    }
+}
+
+fn benchmark_natural_mod_factorial_evaluation_strategy(
+    gm: GenMode,
+    config: GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "Natural::mod_factorial(Natural, Natural)",
+        BenchmarkType::EvaluationStrategy,
+        unsigned_triple_gen_var_24::<u64>().get(gm, &config),
+        gm.name(),
+        limit,
+        file_name,
+        &triple_3_bit_bucketer("m"),
+        &mut [
+            (
+                "Natural::mod_factorial(Natural, Natural)",
+                &mut |(n, _, m)| {
+                    no_out!(Natural::mod_factorial(Natural::from(n), Natural::from(m)))
+                },
+            ),
+            (
+                "Natural::mod_factorial(&Natural, &Natural)",
+                &mut |(n, _, m)| {
+                    no_out!(Natural::mod_factorial(&Natural::from(n), &Natural::from(m)))
+                },
+            ),
+        ],
+    );
+}
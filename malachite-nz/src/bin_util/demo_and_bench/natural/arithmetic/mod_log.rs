@@ -0,0 +1,105 @@
+use malachite_base::num::arithmetic::traits::ModLog;
+use malachite_base::test_util::bench::bucketers::triple_3_bit_bucketer;
+use malachite_base::test_util::bench::{run_benchmark, BenchmarkType};
+use malachite_base::test_util::generators::common::{GenConfig, GenMode};
+use malachite_base::test_util::generators::unsigned_triple_gen_var_12;
+use malachite_base::test_util::runner::Runner;
+use malachite_nz::natural::Natural;
+use malachite_nz::test_util::bench::bucketers::triple_3_natural_bit_bucketer;
+
+pub(crate) fn register(runner: &mut Runner) {
+    register_demo!(runner, demo_natural_mod_log);
+    register_demo!(runner, demo_natural_mod_log_ref_ref);
+
+    register_bench!(runner, benchmark_natural_mod_log_evaluation_strategy);
+    register_bench!(runner, benchmark_natural_mod_log_library_comparison);
+}
+
+// Random moduli with many bits are slow to factor, so inputs are taken from 64-bit triples.
+fn demo_natural_mod_log(gm: GenMode, config: GenConfig, limit: usize) {
+    for (x, base, m) in unsigned_triple_gen_var_12::<u64>()
+        .get(gm, &config)
+        .take(limit)
+    {
+        let x = Natural::from(x);
+        let base = Natural::from(base);
+        let m = Natural::from(m);
+        let x_old = x.clone();
+        match x.mod_log(&base, &m) {
+            Some(log) => println!("{}.mod_log({}, {}) = {}", x_old, base, m, log),
+            None => println!("{} is not a power of {} mod {}", x_old, base, m),
+        }
+    }
+}
+
+fn demo_natural_mod_log_ref_ref(gm: GenMode, config: GenConfig, limit: usize) {
+    for (x, base, m) in unsigned_triple_gen_var_12::<u64>()
+        .get(gm, &config)
+        .take(limit)
+    {
+        let x = Natural::from(x);
+        let base = Natural::from(base);
+        let m = Natural::from(m);
+        match (&x).mod_log(&base, &m) {
+            Some(log) => println!("(&{}).mod_log(&{}, &{}) = {}", x, base, m, log),
+            None => println!("{} is not a power of {} mod {}", x, base, m),
+        }
+    }
+}
+
+fn benchmark_natural_mod_log_evaluation_strategy(
+    gm: GenMode,
+    config: GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "Natural.mod_log(Natural, Natural)",
+        BenchmarkType::EvaluationStrategy,
+        unsigned_triple_gen_var_12::<u64>()
+            .get(gm, &config)
+            .map(|(x, base, m)| (Natural::from(x), Natural::from(base), Natural::from(m))),
+        gm.name(),
+        limit,
+        file_name,
+        &triple_3_natural_bit_bucketer("m"),
+        &mut [
+            ("Natural.mod_log(Natural, Natural)", &mut |(x, b, m)| {
+                no_out!(x.mod_log(b, m))
+            }),
+            ("Natural.mod_log(&Natural, &Natural)", &mut |(x, b, m)| {
+                no_out!(x.mod_log(&b, &m))
+            }),
+            ("(&Natural).mod_log(Natural, Natural)", &mut |(x, b, m)| {
+                no_out!((&x).mod_log(b, m))
+            }),
+            (
+                "(&Natural).mod_log(&Natural, &Natural)",
+                &mut |(x, b, m)| no_out!((&x).mod_log(&b, &m)),
+            ),
+        ],
+    );
+}
+
+fn benchmark_natural_mod_log_library_comparison(
+    gm: GenMode,
+    config: GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "Natural.mod_log(Natural, Natural)",
+        BenchmarkType::LibraryComparison,
+        unsigned_triple_gen_var_12::<u64>().get(gm, &config),
+        gm.name(),
+        limit,
+        file_name,
+        &triple_3_bit_bucketer("m"),
+        &mut [
+            ("Malachite", &mut |(x, base, m)| {
+                no_out!(Natural::from(x).mod_log(Natural::from(base), Natural::from(m)))
+            }),
+            ("u64", &mut |(x, base, m)| no_out!(x.mod_log(base, m))),
+        ],
+    );
+}
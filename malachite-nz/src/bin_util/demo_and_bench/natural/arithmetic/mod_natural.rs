@@ -0,0 +1,117 @@
+use malachite_base::num::arithmetic::traits::ModMul;
+use malachite_base::test_util::bench::{run_benchmark, BenchmarkType};
+use malachite_base::test_util::generators::common::{GenConfig, GenMode};
+use malachite_base::test_util::runner::Runner;
+use malachite_nz::natural::arithmetic::mod_natural::ModNatural;
+use malachite_nz::test_util::bench::bucketers::triple_3_natural_bit_bucketer;
+use malachite_nz::test_util::generators::{natural_pair_gen_var_8, natural_triple_gen_var_3};
+
+pub(crate) fn register(runner: &mut Runner) {
+    register_demo!(runner, demo_mod_natural_add);
+    register_demo!(runner, demo_mod_natural_sub);
+    register_demo!(runner, demo_mod_natural_mul);
+    register_demo!(runner, demo_mod_natural_div);
+    register_demo!(runner, demo_mod_natural_neg);
+
+    register_bench!(runner, benchmark_mod_natural_mul_evaluation_strategy);
+    register_bench!(runner, benchmark_mod_natural_mul_algorithms);
+}
+
+fn demo_mod_natural_add(gm: GenMode, config: GenConfig, limit: usize) {
+    for (x, y, m) in natural_triple_gen_var_3().get(gm, &config).take(limit) {
+        let x = ModNatural::new(x, &m);
+        let y = ModNatural::new(y, &m);
+        println!("({}) + ({}) = {}", x, y, &x + &y);
+    }
+}
+
+fn demo_mod_natural_sub(gm: GenMode, config: GenConfig, limit: usize) {
+    for (x, y, m) in natural_triple_gen_var_3().get(gm, &config).take(limit) {
+        let x = ModNatural::new(x, &m);
+        let y = ModNatural::new(y, &m);
+        println!("({}) - ({}) = {}", x, y, &x - &y);
+    }
+}
+
+fn demo_mod_natural_mul(gm: GenMode, config: GenConfig, limit: usize) {
+    for (x, y, m) in natural_triple_gen_var_3().get(gm, &config).take(limit) {
+        let x = ModNatural::new(x, &m);
+        let y = ModNatural::new(y, &m);
+        println!("({}) * ({}) = {}", x, y, &x * &y);
+    }
+}
+
+fn demo_mod_natural_div(gm: GenMode, config: GenConfig, limit: usize) {
+    for (x, y, m) in natural_triple_gen_var_3().get(gm, &config).take(limit) {
+        let x = ModNatural::new(x, &m);
+        let y = ModNatural::new(y, &m);
+        if y.inverse().is_some() {
+            println!("({}) / ({}) = {}", x, y, &x / &y);
+        } else {
+            println!("({}) / ({}) is undefined", x, y);
+        }
+    }
+}
+
+fn demo_mod_natural_neg(gm: GenMode, config: GenConfig, limit: usize) {
+    for (x, m) in natural_pair_gen_var_8().get(gm, &config).take(limit) {
+        let x = ModNatural::new(x, &m);
+        println!("-({}) = {}", x, -&x);
+    }
+}
+
+fn benchmark_mod_natural_mul_evaluation_strategy(
+    gm: GenMode,
+    config: GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "ModNatural * ModNatural",
+        BenchmarkType::EvaluationStrategy,
+        natural_triple_gen_var_3().get(gm, &config),
+        gm.name(),
+        limit,
+        file_name,
+        &triple_3_natural_bit_bucketer("m"),
+        &mut [
+            ("ModNatural * ModNatural", &mut |(x, y, m)| {
+                no_out!(ModNatural::new(x, &m) * ModNatural::new(y, &m))
+            }),
+            ("ModNatural * &ModNatural", &mut |(x, y, m)| {
+                no_out!(ModNatural::new(x, &m) * &ModNatural::new(y, &m))
+            }),
+            ("&ModNatural * ModNatural", &mut |(x, y, m)| {
+                no_out!(&ModNatural::new(x, &m) * ModNatural::new(y, &m))
+            }),
+            ("&ModNatural * &ModNatural", &mut |(x, y, m)| {
+                no_out!(&ModNatural::new(x, &m) * &ModNatural::new(y, &m))
+            }),
+        ],
+    );
+}
+
+fn benchmark_mod_natural_mul_algorithms(
+    gm: GenMode,
+    config: GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "ModNatural * ModNatural",
+        BenchmarkType::Algorithms,
+        natural_triple_gen_var_3().get(gm, &config),
+        gm.name(),
+        limit,
+        file_name,
+        &triple_3_natural_bit_bucketer("m"),
+        &mut [
+            ("ModNatural * ModNatural", &mut |(x, y, m)| {
+                no_out!(ModNatural::new(x, &m) * ModNatural::new(y, &m))
+            }),
+            ("Natural.mod_mul(Natural, &Natural)", &mut |(x, y, m)| {
+                no_out!(x.mod_mul(y, &m))
+            }),
+        ],
+    );
+}
@@ -0,0 +1,102 @@
+use malachite_base::num::arithmetic::traits::{ModSqrt, ModSqrtFromFactorization};
+use malachite_base::num::factorization::traits::{Factor, NextPrime};
+use malachite_base::test_util::bench::{run_benchmark, BenchmarkType};
+use malachite_base::test_util::generators::common::{GenConfig, GenMode};
+use malachite_base::test_util::runner::Runner;
+use malachite_nz::natural::Natural;
+use malachite_nz::test_util::bench::bucketers::{
+    pair_1_natural_bit_bucketer, pair_2_natural_bit_bucketer,
+};
+use malachite_nz::test_util::generators::{natural_pair_gen, natural_pair_gen_var_2};
+
+pub(crate) fn register(runner: &mut Runner) {
+    register_demo!(runner, demo_natural_mod_sqrt);
+    register_demo!(runner, demo_natural_mod_sqrt_from_factorization);
+    register_bench!(runner, benchmark_natural_mod_sqrt_evaluation_strategy);
+    register_bench!(runner, benchmark_natural_mod_sqrt_from_factorization);
+}
+
+// Turns pairs `(x, n)` into pairs `(x, p)`, where `p` is the smallest prime greater than `n` and `x`
+// is reduced modulo `p`.
+fn prime_modulus_pairs(
+    xs: impl Iterator<Item = (Natural, Natural)>,
+) -> impl Iterator<Item = (Natural, Natural)> {
+    xs.map(|(x, n)| {
+        let p = n.next_prime();
+        (x % &p, p)
+    })
+}
+
+fn demo_natural_mod_sqrt(gm: GenMode, config: GenConfig, limit: usize) {
+    for (x, p) in prime_modulus_pairs(natural_pair_gen().get(gm, &config)).take(limit) {
+        let x_old = x.clone();
+        let p_old = p.clone();
+        println!("{}.mod_sqrt({}) = {:?}", x_old, p_old, x.mod_sqrt(p));
+    }
+}
+
+fn demo_natural_mod_sqrt_from_factorization(gm: GenMode, config: GenConfig, limit: usize) {
+    for (x, m) in natural_pair_gen_var_2().get(gm, &config).take(limit) {
+        let x = x % &m;
+        let factors = m.factor();
+        println!(
+            "{}.mod_sqrt_from_factorization({:?}) = {:?}",
+            x,
+            factors,
+            (&x).mod_sqrt_from_factorization(&factors)
+        );
+    }
+}
+
+fn benchmark_natural_mod_sqrt_evaluation_strategy(
+    gm: GenMode,
+    config: GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "Natural.mod_sqrt(Natural)",
+        BenchmarkType::EvaluationStrategy,
+        prime_modulus_pairs(natural_pair_gen().get(gm, &config)),
+        gm.name(),
+        limit,
+        file_name,
+        &pair_2_natural_bit_bucketer("p"),
+        &mut [
+            ("Natural.mod_sqrt(Natural)", &mut |(x, p)| {
+                no_out!(x.mod_sqrt(p))
+            }),
+            ("Natural.mod_sqrt(&Natural)", &mut |(x, p)| {
+                no_out!(x.mod_sqrt(&p))
+            }),
+            ("(&Natural).mod_sqrt(Natural)", &mut |(x, p)| {
+                no_out!((&x).mod_sqrt(p))
+            }),
+            ("(&Natural).mod_sqrt(&Natural)", &mut |(x, p)| {
+                no_out!((&x).mod_sqrt(&p))
+            }),
+        ],
+    );
+}
+
+fn benchmark_natural_mod_sqrt_from_factorization(
+    gm: GenMode,
+    config: GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "Natural.mod_sqrt_from_factorization(&[(Natural, u64)])",
+        BenchmarkType::Single,
+        natural_pair_gen_var_2()
+            .get(gm, &config)
+            .map(|(x, m)| (x % &m, m.factor())),
+        gm.name(),
+        limit,
+        file_name,
+        &pair_1_natural_bit_bucketer("x"),
+        &mut [("Malachite", &mut |(x, factors)| {
+            no_out!(x.mod_sqrt_from_factorization(&factors))
+        })],
+    );
+}
@@ -0,0 +1,125 @@
+use malachite_base::num::arithmetic::traits::{ModMul, ModPow, Parity};
+use malachite_base::test_util::bench::{run_benchmark, BenchmarkType};
+use malachite_base::test_util::generators::common::{GenConfig, GenMode};
+use malachite_base::test_util::runner::Runner;
+use malachite_nz::natural::arithmetic::montgomery::MontgomeryModulus;
+use malachite_nz::test_util::bench::bucketers::triple_3_natural_bit_bucketer;
+use malachite_nz::test_util::generators::natural_triple_gen_var_3;
+
+pub(crate) fn register(runner: &mut Runner) {
+    register_demo!(runner, demo_montgomery_modulus_mul);
+    register_demo!(runner, demo_montgomery_modulus_pow);
+
+    register_bench!(runner, benchmark_montgomery_modulus_mul_chain_algorithms);
+    register_bench!(runner, benchmark_montgomery_modulus_pow_algorithms);
+}
+
+// The number of multiplications in each chain in
+// `benchmark_montgomery_modulus_mul_chain_algorithms`.
+const CHAIN_LENGTH: usize = 100;
+
+fn demo_montgomery_modulus_mul(gm: GenMode, config: GenConfig, limit: usize) {
+    for (x, y, m) in natural_triple_gen_var_3()
+        .get(gm, &config)
+        .filter(|(_, _, m)| m.odd())
+        .take(limit)
+    {
+        let mut ctx = MontgomeryModulus::new(&m);
+        let x_m = ctx.to_montgomery(&x);
+        let y_m = ctx.to_montgomery(&y);
+        let product_m = ctx.mul(&x_m, &y_m);
+        println!(
+            "MontgomeryModulus::new({}): {} * {} = {}",
+            m,
+            x,
+            y,
+            ctx.from_montgomery(&product_m)
+        );
+    }
+}
+
+fn demo_montgomery_modulus_pow(gm: GenMode, config: GenConfig, limit: usize) {
+    for (x, exp, m) in natural_triple_gen_var_3()
+        .get(gm, &config)
+        .filter(|(_, _, m)| m.odd())
+        .take(limit)
+    {
+        let mut ctx = MontgomeryModulus::new(&m);
+        let x_m = ctx.to_montgomery(&x);
+        let power_m = ctx.pow(&x_m, &exp);
+        println!(
+            "MontgomeryModulus::new({}): {} ^ {} = {}",
+            m,
+            x,
+            exp,
+            ctx.from_montgomery(&power_m)
+        );
+    }
+}
+
+fn benchmark_montgomery_modulus_mul_chain_algorithms(
+    gm: GenMode,
+    config: GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "MontgomeryModulus.mul(&Natural, &Natural)",
+        BenchmarkType::Algorithms,
+        natural_triple_gen_var_3()
+            .get(gm, &config)
+            .filter(|(_, _, m)| m.odd()),
+        gm.name(),
+        limit,
+        file_name,
+        &triple_3_natural_bit_bucketer("m"),
+        &mut [
+            ("Natural.mod_mul", &mut |(x, y, m)| {
+                let mut product = x;
+                for _ in 0..CHAIN_LENGTH {
+                    product = product.mod_mul(&y, &m);
+                }
+                no_out!(product)
+            }),
+            ("MontgomeryModulus.mul", &mut |(x, y, m)| {
+                let mut ctx = MontgomeryModulus::new(&m);
+                let mut product = ctx.to_montgomery(&x);
+                let y = ctx.to_montgomery(&y);
+                for _ in 0..CHAIN_LENGTH {
+                    product = ctx.mul(&product, &y);
+                }
+                no_out!(ctx.from_montgomery(&product))
+            }),
+        ],
+    );
+}
+
+fn benchmark_montgomery_modulus_pow_algorithms(
+    gm: GenMode,
+    config: GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "MontgomeryModulus.pow(&Natural, &Natural)",
+        BenchmarkType::Algorithms,
+        natural_triple_gen_var_3()
+            .get(gm, &config)
+            .filter(|(_, _, m)| m.odd()),
+        gm.name(),
+        limit,
+        file_name,
+        &triple_3_natural_bit_bucketer("m"),
+        &mut [
+            ("Natural.mod_pow", &mut |(x, exp, m)| {
+                no_out!(x.mod_pow(exp, m))
+            }),
+            ("MontgomeryModulus.pow", &mut |(x, exp, m)| {
+                let mut ctx = MontgomeryModulus::new(&m);
+                let x = ctx.to_montgomery(&x);
+                let power = ctx.pow(&x, &exp);
+                no_out!(ctx.from_montgomery(&power))
+            }),
+        ],
+    );
+}
@@ -0,0 +1,31 @@
+use malachite_base::num::arithmetic::traits::Multinomial;
+use malachite_base::test_util::bench::bucketers::vec_len_bucketer;
+use malachite_base::test_util::bench::{run_benchmark, BenchmarkType};
+use malachite_base::test_util::generators::common::{GenConfig, GenMode};
+use malachite_base::test_util::generators::unsigned_vec_gen_var_7;
+use malachite_base::test_util::runner::Runner;
+use malachite_nz::natural::Natural;
+
+pub(crate) fn register(runner: &mut Runner) {
+    register_demo!(runner, demo_multinomial);
+    register_bench!(runner, benchmark_multinomial);
+}
+
+fn demo_multinomial(gm: GenMode, config: GenConfig, limit: usize) {
+    for ks in unsigned_vec_gen_var_7().get(gm, &config).take(limit) {
+        println!("multinomial({:?}) = {}", ks, Natural::multinomial(&ks));
+    }
+}
+
+fn benchmark_multinomial(gm: GenMode, config: GenConfig, limit: usize, file_name: &str) {
+    run_benchmark(
+        "Natural::multinomial(&[u64])",
+        BenchmarkType::Single,
+        unsigned_vec_gen_var_7().get(gm, &config),
+        gm.name(),
+        limit,
+        file_name,
+        &vec_len_bucketer(),
+        &mut [("Malachite", &mut |ks| no_out!(Natural::multinomial(&ks)))],
+    );
+}
@@ -0,0 +1,128 @@
+use malachite_base::num::arithmetic::traits::{MultiplicativeOrder, PrimitiveRoot};
+use malachite_base::test_util::bench::bucketers::{pair_2_bit_bucketer, unsigned_bit_bucketer};
+use malachite_base::test_util::bench::{run_benchmark, BenchmarkType};
+use malachite_base::test_util::generators::common::{GenConfig, GenMode};
+use malachite_base::test_util::generators::{unsigned_gen_var_1, unsigned_pair_gen_var_38};
+use malachite_base::test_util::runner::Runner;
+use malachite_nz::natural::Natural;
+use malachite_nz::test_util::bench::bucketers::pair_2_natural_bit_bucketer;
+
+pub(crate) fn register(runner: &mut Runner) {
+    register_demo!(runner, demo_natural_multiplicative_order);
+    register_demo!(runner, demo_natural_primitive_root);
+
+    register_bench!(
+        runner,
+        benchmark_natural_multiplicative_order_evaluation_strategy
+    );
+    register_bench!(
+        runner,
+        benchmark_natural_multiplicative_order_library_comparison
+    );
+    register_bench!(runner, benchmark_natural_primitive_root_library_comparison);
+}
+
+// Random moduli with many bits are slow to factor, so inputs are taken from 64-bit values.
+fn demo_natural_multiplicative_order(gm: GenMode, config: GenConfig, limit: usize) {
+    for (x, m) in unsigned_pair_gen_var_38::<u64>()
+        .get(gm, &config)
+        .take(limit)
+    {
+        let x = Natural::from(x);
+        let m = Natural::from(m);
+        match (&x).multiplicative_order(&m) {
+            Some(order) => println!("{} has order {} mod {}", x, order, m),
+            None => println!("{} is not invertible mod {}", x, m),
+        }
+    }
+}
+
+fn demo_natural_primitive_root(gm: GenMode, config: GenConfig, limit: usize) {
+    for m in unsigned_gen_var_1::<u64>().get(gm, &config).take(limit) {
+        let m = Natural::from(m);
+        match m.primitive_root() {
+            Some(root) => println!("The smallest primitive root mod {} is {}", m, root),
+            None => println!("There is no primitive root mod {}", m),
+        }
+    }
+}
+
+fn benchmark_natural_multiplicative_order_evaluation_strategy(
+    gm: GenMode,
+    config: GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "Natural.multiplicative_order(Natural)",
+        BenchmarkType::EvaluationStrategy,
+        unsigned_pair_gen_var_38::<u64>()
+            .get(gm, &config)
+            .map(|(x, m)| (Natural::from(x), Natural::from(m))),
+        gm.name(),
+        limit,
+        file_name,
+        &pair_2_natural_bit_bucketer("m"),
+        &mut [
+            ("Natural.multiplicative_order(Natural)", &mut |(x, m)| {
+                no_out!(x.multiplicative_order(m))
+            }),
+            ("Natural.multiplicative_order(&Natural)", &mut |(x, m)| {
+                no_out!(x.multiplicative_order(&m))
+            }),
+            ("(&Natural).multiplicative_order(Natural)", &mut |(x, m)| {
+                no_out!((&x).multiplicative_order(m))
+            }),
+            (
+                "(&Natural).multiplicative_order(&Natural)",
+                &mut |(x, m)| no_out!((&x).multiplicative_order(&m)),
+            ),
+        ],
+    );
+}
+
+fn benchmark_natural_multiplicative_order_library_comparison(
+    gm: GenMode,
+    config: GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "Natural.multiplicative_order(Natural)",
+        BenchmarkType::LibraryComparison,
+        unsigned_pair_gen_var_38::<u64>().get(gm, &config),
+        gm.name(),
+        limit,
+        file_name,
+        &pair_2_bit_bucketer("m"),
+        &mut [
+            ("Malachite", &mut |(x, m)| {
+                no_out!(Natural::from(x).multiplicative_order(Natural::from(m)))
+            }),
+            ("u64", &mut |(x, m)| no_out!(x.multiplicative_order(m))),
+        ],
+    );
+}
+
+fn benchmark_natural_primitive_root_library_comparison(
+    gm: GenMode,
+    config: GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "Natural.primitive_root()",
+        BenchmarkType::LibraryComparison,
+        unsigned_gen_var_1::<u64>().get(gm, &config),
+        gm.name(),
+        limit,
+        file_name,
+        &unsigned_bit_bucketer(),
+        &mut [
+            ("Malachite", &mut |m| {
+                no_out!(Natural::from(m).primitive_root())
+            }),
+            ("u64", &mut |m| no_out!(m.primitive_root())),
+        ],
+    );
+}
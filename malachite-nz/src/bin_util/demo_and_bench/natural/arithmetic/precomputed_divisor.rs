@@ -0,0 +1,74 @@
+use malachite_base::num::arithmetic::traits::DivMod;
+use malachite_base::test_util::bench::{run_benchmark, BenchmarkType};
+use malachite_base::test_util::generators::common::{GenConfig, GenMode};
+use malachite_base::test_util::runner::Runner;
+use malachite_nz::natural::arithmetic::precomputed_divisor::PrecomputedDivisor;
+use malachite_nz::test_util::bench::bucketers::pair_2_natural_bit_bucketer;
+use malachite_nz::test_util::generators::natural_pair_gen_var_5;
+
+pub(crate) fn register(runner: &mut Runner) {
+    register_demo!(runner, demo_precomputed_divisor_div_mod);
+    register_demo!(runner, demo_precomputed_divisor_divisible_by);
+
+    register_bench!(
+        runner,
+        benchmark_precomputed_divisor_div_mod_repeated_algorithms
+    );
+}
+
+// The number of divisions by the same divisor in
+// `benchmark_precomputed_divisor_div_mod_repeated_algorithms`.
+const DIVISION_COUNT: usize = 100;
+
+fn demo_precomputed_divisor_div_mod(gm: GenMode, config: GenConfig, limit: usize) {
+    for (x, y) in natural_pair_gen_var_5().get(gm, &config).take(limit) {
+        let d = PrecomputedDivisor::new(&y);
+        println!(
+            "PrecomputedDivisor::new({}).div_mod({}) = {:?}",
+            y,
+            x,
+            d.div_mod(&x)
+        );
+    }
+}
+
+fn demo_precomputed_divisor_divisible_by(gm: GenMode, config: GenConfig, limit: usize) {
+    for (x, y) in natural_pair_gen_var_5().get(gm, &config).take(limit) {
+        let d = PrecomputedDivisor::new(&y);
+        if d.divisible_by(&x) {
+            println!("{} is divisible by {}", x, y);
+        } else {
+            println!("{} is not divisible by {}", x, y);
+        }
+    }
+}
+
+fn benchmark_precomputed_divisor_div_mod_repeated_algorithms(
+    gm: GenMode,
+    config: GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "PrecomputedDivisor.div_mod(&Natural)",
+        BenchmarkType::Algorithms,
+        natural_pair_gen_var_5().get(gm, &config),
+        gm.name(),
+        limit,
+        file_name,
+        &pair_2_natural_bit_bucketer("y"),
+        &mut [
+            ("Natural.div_mod", &mut |(x, y)| {
+                for _ in 0..DIVISION_COUNT {
+                    no_out!((&x).div_mod(&y))
+                }
+            }),
+            ("PrecomputedDivisor.div_mod", &mut |(x, y)| {
+                let d = PrecomputedDivisor::new(&y);
+                for _ in 0..DIVISION_COUNT {
+                    no_out!(d.div_mod(&x))
+                }
+            }),
+        ],
+    );
+}
@@ -0,0 +1,56 @@
+use malachite_base::num::arithmetic::traits::{CarmichaelLambda, Totient};
+use malachite_base::test_util::bench::{run_benchmark, BenchmarkType};
+use malachite_base::test_util::generators::common::{GenConfig, GenMode};
+use malachite_base::test_util::runner::Runner;
+use malachite_nz::test_util::bench::bucketers::natural_bit_bucketer;
+use malachite_nz::test_util::generators::natural_gen_var_2;
+
+pub(crate) fn register(runner: &mut Runner) {
+    register_demo!(runner, demo_natural_totient);
+    register_demo!(runner, demo_natural_carmichael_lambda);
+    register_bench!(runner, benchmark_natural_totient);
+    register_bench!(runner, benchmark_natural_carmichael_lambda);
+}
+
+fn demo_natural_totient(gm: GenMode, config: GenConfig, limit: usize) {
+    for n in natural_gen_var_2().get(gm, &config).take(limit) {
+        println!("{}.totient() = {}", n, n.totient());
+    }
+}
+
+fn demo_natural_carmichael_lambda(gm: GenMode, config: GenConfig, limit: usize) {
+    for n in natural_gen_var_2().get(gm, &config).take(limit) {
+        println!("{}.carmichael_lambda() = {}", n, n.carmichael_lambda());
+    }
+}
+
+fn benchmark_natural_totient(gm: GenMode, config: GenConfig, limit: usize, file_name: &str) {
+    run_benchmark(
+        "Natural.totient()",
+        BenchmarkType::Single,
+        natural_gen_var_2().get(gm, &config),
+        gm.name(),
+        limit,
+        file_name,
+        &natural_bit_bucketer("n"),
+        &mut [("Malachite", &mut |n| no_out!(n.totient()))],
+    );
+}
+
+fn benchmark_natural_carmichael_lambda(
+    gm: GenMode,
+    config: GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "Natural.carmichael_lambda()",
+        BenchmarkType::Single,
+        natural_gen_var_2().get(gm, &config),
+        gm.name(),
+        limit,
+        file_name,
+        &natural_bit_bucketer("n"),
+        &mut [("Malachite", &mut |n| no_out!(n.carmichael_lambda()))],
+    );
+}
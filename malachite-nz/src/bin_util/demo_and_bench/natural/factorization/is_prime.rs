@@ -0,0 +1,64 @@
+use malachite_base::num::factorization::traits::{IsPrime, IsProbablePrime};
+use malachite_base::test_util::bench::{run_benchmark, BenchmarkType};
+use malachite_base::test_util::generators::common::{GenConfig, GenMode};
+use malachite_base::test_util::runner::Runner;
+use malachite_nz::test_util::bench::bucketers::natural_bit_bucketer;
+use malachite_nz::test_util::generators::natural_gen;
+
+pub(crate) fn register(runner: &mut Runner) {
+    register_demo!(runner, demo_natural_is_prime);
+    register_demo!(runner, demo_natural_is_probable_prime);
+
+    register_bench!(runner, benchmark_natural_is_prime);
+    register_bench!(runner, benchmark_natural_is_probable_prime_algorithms);
+}
+
+fn demo_natural_is_prime(gm: GenMode, config: GenConfig, limit: usize) {
+    for n in natural_gen().get(gm, &config).take(limit) {
+        if n.is_prime() {
+            println!("{} is prime", n);
+        } else {
+            println!("{} is not prime", n);
+        }
+    }
+}
+
+fn demo_natural_is_probable_prime(gm: GenMode, config: GenConfig, limit: usize) {
+    for n in natural_gen().get(gm, &config).take(limit) {
+        println!("{}.is_probable_prime(10) = {}", n, n.is_probable_prime(10));
+    }
+}
+
+fn benchmark_natural_is_prime(gm: GenMode, config: GenConfig, limit: usize, file_name: &str) {
+    run_benchmark(
+        "Natural.is_prime()",
+        BenchmarkType::Single,
+        natural_gen().get(gm, &config),
+        gm.name(),
+        limit,
+        file_name,
+        &natural_bit_bucketer("n"),
+        &mut [("Malachite", &mut |n| no_out!(n.is_prime()))],
+    );
+}
+
+fn benchmark_natural_is_probable_prime_algorithms(
+    gm: GenMode,
+    config: GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "Natural.is_probable_prime(u64)",
+        BenchmarkType::Algorithms,
+        natural_gen().get(gm, &config),
+        gm.name(),
+        limit,
+        file_name,
+        &natural_bit_bucketer("n"),
+        &mut [
+            ("0 extra rounds", &mut |n| no_out!(n.is_probable_prime(0))),
+            ("10 extra rounds", &mut |n| no_out!(n.is_probable_prime(10))),
+        ],
+    );
+}
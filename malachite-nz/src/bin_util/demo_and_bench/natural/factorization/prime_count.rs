@@ -0,0 +1,57 @@
+use malachite_base::num::factorization::traits::{NthPrime, PrimeCount};
+use malachite_base::test_util::bench::bucketers::unsigned_direct_bucketer;
+use malachite_base::test_util::bench::{run_benchmark, BenchmarkType};
+use malachite_base::test_util::generators::common::{GenConfig, GenMode};
+use malachite_base::test_util::generators::unsigned_gen_var_26;
+use malachite_base::test_util::runner::Runner;
+use malachite_nz::natural::Natural;
+
+pub(crate) fn register(runner: &mut Runner) {
+    register_demo!(runner, demo_natural_prime_count);
+    register_demo!(runner, demo_natural_nth_prime);
+
+    register_bench!(runner, benchmark_natural_prime_count);
+    register_bench!(runner, benchmark_natural_nth_prime);
+}
+
+fn demo_natural_prime_count(gm: GenMode, config: GenConfig, limit: usize) {
+    for n in unsigned_gen_var_26::<u64>().get(gm, &config).take(limit) {
+        let x = Natural::from(n);
+        println!("{}.prime_count() = {}", x, x.prime_count());
+    }
+}
+
+fn demo_natural_nth_prime(gm: GenMode, config: GenConfig, limit: usize) {
+    for n in unsigned_gen_var_26::<u64>().get(gm, &config).take(limit) {
+        println!("Natural::nth_prime({}) = {}", n, Natural::nth_prime(n));
+    }
+}
+
+fn benchmark_natural_prime_count(gm: GenMode, config: GenConfig, limit: usize, file_name: &str) {
+    run_benchmark(
+        "Natural.prime_count()",
+        BenchmarkType::Single,
+        unsigned_gen_var_26::<u64>().get(gm, &config),
+        gm.name(),
+        limit,
+        file_name,
+        &unsigned_direct_bucketer(),
+        &mut [(
+            "Malachite",
+            &mut |n| no_out!(Natural::from(n).prime_count()),
+        )],
+    );
+}
+
+fn benchmark_natural_nth_prime(gm: GenMode, config: GenConfig, limit: usize, file_name: &str) {
+    run_benchmark(
+        "Natural::nth_prime(u64)",
+        BenchmarkType::Single,
+        unsigned_gen_var_26::<u64>().get(gm, &config),
+        gm.name(),
+        limit,
+        file_name,
+        &unsigned_direct_bucketer(),
+        &mut [("Malachite", &mut |n| no_out!(Natural::nth_prime(n)))],
+    );
+}
@@ -0,0 +1,83 @@
+use itertools::Itertools;
+use malachite_base::num::factorization::traits::PrimesInRange;
+use malachite_base::test_util::bench::{run_benchmark, BenchmarkType};
+use malachite_base::test_util::generators::common::{GenConfig, GenMode};
+use malachite_base::test_util::runner::Runner;
+use malachite_nz::natural::Natural;
+use malachite_nz::test_util::bench::bucketers::{
+    natural_bit_bucketer, pair_natural_max_bit_bucketer,
+};
+use malachite_nz::test_util::generators::{natural_gen, natural_pair_gen};
+
+pub(crate) fn register(runner: &mut Runner) {
+    register_demo!(runner, demo_natural_primes_in_range);
+    register_demo!(runner, demo_natural_primes_greater_than_or_equal_to);
+
+    register_bench!(runner, benchmark_natural_primes_in_range);
+    register_bench!(runner, benchmark_natural_primes_greater_than_or_equal_to);
+}
+
+fn demo_natural_primes_in_range(gm: GenMode, config: GenConfig, limit: usize) {
+    for (a, b) in natural_pair_gen().get(gm, &config).take(limit) {
+        println!(
+            "primes_in_range({}, {}) = {:?}",
+            a,
+            b,
+            Natural::primes_in_range(&a, &b).take(10).collect_vec()
+        );
+    }
+}
+
+fn demo_natural_primes_greater_than_or_equal_to(gm: GenMode, config: GenConfig, limit: usize) {
+    for a in natural_gen().get(gm, &config).take(limit) {
+        println!(
+            "primes_greater_than_or_equal_to({}) = {:?}",
+            a,
+            Natural::primes_greater_than_or_equal_to(&a)
+                .take(10)
+                .collect_vec()
+        );
+    }
+}
+
+fn benchmark_natural_primes_in_range(
+    gm: GenMode,
+    config: GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "Natural::primes_in_range(&Natural, &Natural).take(10)",
+        BenchmarkType::Single,
+        natural_pair_gen().get(gm, &config),
+        gm.name(),
+        limit,
+        file_name,
+        &pair_natural_max_bit_bucketer("a", "b"),
+        &mut [("Malachite", &mut |(a, b)| {
+            no_out!(Natural::primes_in_range(&a, &b).take(10).count())
+        })],
+    );
+}
+
+fn benchmark_natural_primes_greater_than_or_equal_to(
+    gm: GenMode,
+    config: GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "Natural::primes_greater_than_or_equal_to(&Natural).take(10)",
+        BenchmarkType::Single,
+        natural_gen().get(gm, &config),
+        gm.name(),
+        limit,
+        file_name,
+        &natural_bit_bucketer("a"),
+        &mut [("Malachite", &mut |a| {
+            no_out!(Natural::primes_greater_than_or_equal_to(&a)
+                .take(10)
+                .count())
+        })],
+    );
+}
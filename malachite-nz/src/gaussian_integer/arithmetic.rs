@@ -0,0 +1,509 @@
+use crate::gaussian_integer::GaussianInteger;
+use crate::natural::Natural;
+use malachite_base::num::arithmetic::traits::Square;
+use std::ops::{Add, Mul, Neg, Sub};
+
+fn add_helper(x: &GaussianInteger, y: &GaussianInteger) -> GaussianInteger {
+    GaussianInteger {
+        real: &x.real + &y.real,
+        imag: &x.imag + &y.imag,
+    }
+}
+
+fn sub_helper(x: &GaussianInteger, y: &GaussianInteger) -> GaussianInteger {
+    GaussianInteger {
+        real: &x.real - &y.real,
+        imag: &x.imag - &y.imag,
+    }
+}
+
+// Uses $(a + bi)(c + di) = (ac - bd) + ((a + b)(c + d) - ac - bd)i$, which needs three
+// multiplications instead of four.
+fn mul_helper(x: &GaussianInteger, y: &GaussianInteger) -> GaussianInteger {
+    let ac = &x.real * &y.real;
+    let bd = &x.imag * &y.imag;
+    let cross = (&x.real + &x.imag) * (&y.real + &y.imag);
+    GaussianInteger {
+        imag: cross - &ac - &bd,
+        real: ac - bd,
+    }
+}
+
+impl Add<GaussianInteger> for GaussianInteger {
+    type Output = GaussianInteger;
+
+    /// Adds two [`GaussianInteger`]s, taking both by value.
+    ///
+    /// $f(a + bi, c + di) = (a + c) + (b + d)i$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the largest number of significant
+    /// bits of any of the real and imaginary parts.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::gaussian_integer::GaussianInteger;
+    /// use malachite_nz::integer::Integer;
+    ///
+    /// let x = GaussianInteger::new(Integer::from(1), Integer::from(2));
+    /// let y = GaussianInteger::new(Integer::from(3), Integer::from(-5));
+    /// assert_eq!((x + y).to_string(), "4-3i");
+    /// ```
+    #[inline]
+    fn add(self, other: GaussianInteger) -> GaussianInteger {
+        add_helper(&self, &other)
+    }
+}
+
+impl<'a> Add<&'a GaussianInteger> for GaussianInteger {
+    type Output = GaussianInteger;
+
+    /// Adds two [`GaussianInteger`]s, taking the first by value and the second by reference.
+    ///
+    /// $f(a + bi, c + di) = (a + c) + (b + d)i$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the largest number of significant
+    /// bits of any of the real and imaginary parts.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::gaussian_integer::GaussianInteger;
+    /// use malachite_nz::integer::Integer;
+    ///
+    /// let x = GaussianInteger::new(Integer::from(1), Integer::from(2));
+    /// let y = GaussianInteger::new(Integer::from(3), Integer::from(-5));
+    /// assert_eq!((x + &y).to_string(), "4-3i");
+    /// ```
+    #[inline]
+    fn add(self, other: &'a GaussianInteger) -> GaussianInteger {
+        add_helper(&self, other)
+    }
+}
+
+impl<'a> Add<GaussianInteger> for &'a GaussianInteger {
+    type Output = GaussianInteger;
+
+    /// Adds two [`GaussianInteger`]s, taking the first by reference and the second by value.
+    ///
+    /// $f(a + bi, c + di) = (a + c) + (b + d)i$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the largest number of significant
+    /// bits of any of the real and imaginary parts.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::gaussian_integer::GaussianInteger;
+    /// use malachite_nz::integer::Integer;
+    ///
+    /// let x = GaussianInteger::new(Integer::from(1), Integer::from(2));
+    /// let y = GaussianInteger::new(Integer::from(3), Integer::from(-5));
+    /// assert_eq!((&x + y).to_string(), "4-3i");
+    /// ```
+    #[inline]
+    fn add(self, other: GaussianInteger) -> GaussianInteger {
+        add_helper(self, &other)
+    }
+}
+
+impl<'a, 'b> Add<&'a GaussianInteger> for &'b GaussianInteger {
+    type Output = GaussianInteger;
+
+    /// Adds two [`GaussianInteger`]s, taking both by reference.
+    ///
+    /// $f(a + bi, c + di) = (a + c) + (b + d)i$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the largest number of significant
+    /// bits of any of the real and imaginary parts.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::gaussian_integer::GaussianInteger;
+    /// use malachite_nz::integer::Integer;
+    ///
+    /// let x = GaussianInteger::new(Integer::from(1), Integer::from(2));
+    /// let y = GaussianInteger::new(Integer::from(3), Integer::from(-5));
+    /// assert_eq!((&x + &y).to_string(), "4-3i");
+    /// ```
+    #[inline]
+    fn add(self, other: &'a GaussianInteger) -> GaussianInteger {
+        add_helper(self, other)
+    }
+}
+
+impl Sub<GaussianInteger> for GaussianInteger {
+    type Output = GaussianInteger;
+
+    /// Subtracts two [`GaussianInteger`]s, taking both by value.
+    ///
+    /// $f(a + bi, c + di) = (a - c) + (b - d)i$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the largest number of significant
+    /// bits of any of the real and imaginary parts.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::gaussian_integer::GaussianInteger;
+    /// use malachite_nz::integer::Integer;
+    ///
+    /// let x = GaussianInteger::new(Integer::from(1), Integer::from(2));
+    /// let y = GaussianInteger::new(Integer::from(3), Integer::from(-5));
+    /// assert_eq!((x - y).to_string(), "-2+7i");
+    /// ```
+    #[inline]
+    fn sub(self, other: GaussianInteger) -> GaussianInteger {
+        sub_helper(&self, &other)
+    }
+}
+
+impl<'a> Sub<&'a GaussianInteger> for GaussianInteger {
+    type Output = GaussianInteger;
+
+    /// Subtracts two [`GaussianInteger`]s, taking the first by value and the second by reference.
+    ///
+    /// $f(a + bi, c + di) = (a - c) + (b - d)i$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the largest number of significant
+    /// bits of any of the real and imaginary parts.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::gaussian_integer::GaussianInteger;
+    /// use malachite_nz::integer::Integer;
+    ///
+    /// let x = GaussianInteger::new(Integer::from(1), Integer::from(2));
+    /// let y = GaussianInteger::new(Integer::from(3), Integer::from(-5));
+    /// assert_eq!((x - &y).to_string(), "-2+7i");
+    /// ```
+    #[inline]
+    fn sub(self, other: &'a GaussianInteger) -> GaussianInteger {
+        sub_helper(&self, other)
+    }
+}
+
+impl<'a> Sub<GaussianInteger> for &'a GaussianInteger {
+    type Output = GaussianInteger;
+
+    /// Subtracts two [`GaussianInteger`]s, taking the first by reference and the second by value.
+    ///
+    /// $f(a + bi, c + di) = (a - c) + (b - d)i$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the largest number of significant
+    /// bits of any of the real and imaginary parts.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::gaussian_integer::GaussianInteger;
+    /// use malachite_nz::integer::Integer;
+    ///
+    /// let x = GaussianInteger::new(Integer::from(1), Integer::from(2));
+    /// let y = GaussianInteger::new(Integer::from(3), Integer::from(-5));
+    /// assert_eq!((&x - y).to_string(), "-2+7i");
+    /// ```
+    #[inline]
+    fn sub(self, other: GaussianInteger) -> GaussianInteger {
+        sub_helper(self, &other)
+    }
+}
+
+impl<'a, 'b> Sub<&'a GaussianInteger> for &'b GaussianInteger {
+    type Output = GaussianInteger;
+
+    /// Subtracts two [`GaussianInteger`]s, taking both by reference.
+    ///
+    /// $f(a + bi, c + di) = (a - c) + (b - d)i$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the largest number of significant
+    /// bits of any of the real and imaginary parts.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::gaussian_integer::GaussianInteger;
+    /// use malachite_nz::integer::Integer;
+    ///
+    /// let x = GaussianInteger::new(Integer::from(1), Integer::from(2));
+    /// let y = GaussianInteger::new(Integer::from(3), Integer::from(-5));
+    /// assert_eq!((&x - &y).to_string(), "-2+7i");
+    /// ```
+    #[inline]
+    fn sub(self, other: &'a GaussianInteger) -> GaussianInteger {
+        sub_helper(self, other)
+    }
+}
+
+impl Mul<GaussianInteger> for GaussianInteger {
+    type Output = GaussianInteger;
+
+    /// Multiplies two [`GaussianInteger`]s, taking both by value.
+    ///
+    /// $f(a + bi, c + di) = (ac - bd) + (ad + bc)i$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the largest number of significant
+    /// bits of any of the real and imaginary parts.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::gaussian_integer::GaussianInteger;
+    /// use malachite_nz::integer::Integer;
+    ///
+    /// let x = GaussianInteger::new(Integer::from(1), Integer::from(2));
+    /// let y = GaussianInteger::new(Integer::from(3), Integer::from(-5));
+    /// assert_eq!((x * y).to_string(), "13+i");
+    /// ```
+    #[inline]
+    fn mul(self, other: GaussianInteger) -> GaussianInteger {
+        mul_helper(&self, &other)
+    }
+}
+
+impl<'a> Mul<&'a GaussianInteger> for GaussianInteger {
+    type Output = GaussianInteger;
+
+    /// Multiplies two [`GaussianInteger`]s, taking the first by value and the second by reference.
+    ///
+    /// $f(a + bi, c + di) = (ac - bd) + (ad + bc)i$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the largest number of significant
+    /// bits of any of the real and imaginary parts.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::gaussian_integer::GaussianInteger;
+    /// use malachite_nz::integer::Integer;
+    ///
+    /// let x = GaussianInteger::new(Integer::from(1), Integer::from(2));
+    /// let y = GaussianInteger::new(Integer::from(3), Integer::from(-5));
+    /// assert_eq!((x * &y).to_string(), "13+i");
+    /// ```
+    #[inline]
+    fn mul(self, other: &'a GaussianInteger) -> GaussianInteger {
+        mul_helper(&self, other)
+    }
+}
+
+impl<'a> Mul<GaussianInteger> for &'a GaussianInteger {
+    type Output = GaussianInteger;
+
+    /// Multiplies two [`GaussianInteger`]s, taking the first by reference and the second by value.
+    ///
+    /// $f(a + bi, c + di) = (ac - bd) + (ad + bc)i$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the largest number of significant
+    /// bits of any of the real and imaginary parts.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::gaussian_integer::GaussianInteger;
+    /// use malachite_nz::integer::Integer;
+    ///
+    /// let x = GaussianInteger::new(Integer::from(1), Integer::from(2));
+    /// let y = GaussianInteger::new(Integer::from(3), Integer::from(-5));
+    /// assert_eq!((&x * y).to_string(), "13+i");
+    /// ```
+    #[inline]
+    fn mul(self, other: GaussianInteger) -> GaussianInteger {
+        mul_helper(self, &other)
+    }
+}
+
+impl<'a, 'b> Mul<&'a GaussianInteger> for &'b GaussianInteger {
+    type Output = GaussianInteger;
+
+    /// Multiplies two [`GaussianInteger`]s, taking both by reference.
+    ///
+    /// $f(a + bi, c + di) = (ac - bd) + (ad + bc)i$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the largest number of significant
+    /// bits of any of the real and imaginary parts.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::gaussian_integer::GaussianInteger;
+    /// use malachite_nz::integer::Integer;
+    ///
+    /// let x = GaussianInteger::new(Integer::from(1), Integer::from(2));
+    /// let y = GaussianInteger::new(Integer::from(3), Integer::from(-5));
+    /// assert_eq!((&x * &y).to_string(), "13+i");
+    /// assert_eq!((&GaussianInteger::I * &GaussianInteger::I).to_string(), "-1");
+    /// ```
+    #[inline]
+    fn mul(self, other: &'a GaussianInteger) -> GaussianInteger {
+        mul_helper(self, other)
+    }
+}
+
+impl Neg for GaussianInteger {
+    type Output = GaussianInteger;
+
+    /// Negates a [`GaussianInteger`], taking it by value.
+    ///
+    /// $f(a + bi) = -a - bi$.
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::gaussian_integer::GaussianInteger;
+    /// use malachite_nz::integer::Integer;
+    ///
+    /// let x = GaussianInteger::new(Integer::from(3), Integer::from(-4));
+    /// assert_eq!((-x).to_string(), "-3+4i");
+    /// ```
+    #[inline]
+    fn neg(self) -> GaussianInteger {
+        GaussianInteger {
+            real: -self.real,
+            imag: -self.imag,
+        }
+    }
+}
+
+impl<'a> Neg for &'a GaussianInteger {
+    type Output = GaussianInteger;
+
+    /// Negates a [`GaussianInteger`], taking it by reference.
+    ///
+    /// $f(a + bi) = -a - bi$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the larger number of significant
+    /// bits of the real and imaginary parts.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::gaussian_integer::GaussianInteger;
+    /// use malachite_nz::integer::Integer;
+    ///
+    /// let x = GaussianInteger::new(Integer::from(3), Integer::from(-4));
+    /// assert_eq!((-&x).to_string(), "-3+4i");
+    /// ```
+    #[inline]
+    fn neg(self) -> GaussianInteger {
+        GaussianInteger {
+            real: -&self.real,
+            imag: -&self.imag,
+        }
+    }
+}
+
+impl GaussianInteger {
+    /// Returns the complex conjugate of a [`GaussianInteger`].
+    ///
+    /// $f(a + bi) = a - bi$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the larger number of significant
+    /// bits of the real and imaginary parts.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::gaussian_integer::GaussianInteger;
+    /// use malachite_nz::integer::Integer;
+    ///
+    /// let x = GaussianInteger::new(Integer::from(3), Integer::from(-4));
+    /// assert_eq!(x.conjugate().to_string(), "3+4i");
+    /// assert_eq!((&x * x.conjugate()).to_string(), "25");
+    /// ```
+    pub fn conjugate(&self) -> GaussianInteger {
+        GaussianInteger {
+            real: self.real.clone(),
+            imag: -&self.imag,
+        }
+    }
+
+    /// Returns the norm of a [`GaussianInteger`]; that is, the square of its absolute value.
+    ///
+    /// $f(a + bi) = a^2 + b^2$.
+    ///
+    /// The norm is multiplicative, and a [`GaussianInteger`] is a unit if and only if its norm
+    /// is 1.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the larger number of significant
+    /// bits of the real and imaginary parts.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::Zero;
+    /// use malachite_nz::gaussian_integer::GaussianInteger;
+    /// use malachite_nz::integer::Integer;
+    ///
+    /// assert_eq!(GaussianInteger::ZERO.norm(), 0);
+    /// assert_eq!(GaussianInteger::I.norm(), 1);
+    /// assert_eq!(GaussianInteger::new(Integer::from(3), Integer::from(-4)).norm(), 25);
+    /// ```
+    pub fn norm(&self) -> Natural {
+        self.real.unsigned_abs_ref().square() + self.imag.unsigned_abs_ref().square()
+    }
+}
@@ -0,0 +1,145 @@
+use crate::gaussian_integer::GaussianInteger;
+use crate::integer::Integer;
+use malachite_base::num::arithmetic::traits::{DivRem, DivRound};
+use malachite_base::rounding_modes::RoundingMode;
+
+// Returns $q$ and $r$ such that $x = qy + r$, where $q$ is $x/y$ with both of its parts rounded
+// to the nearest integer.
+pub(crate) fn div_rem_helper(
+    x: &GaussianInteger,
+    y: &GaussianInteger,
+) -> (GaussianInteger, GaussianInteger) {
+    let norm = Integer::from(y.norm());
+    if norm == 0u32 {
+        panic!("division by zero");
+    }
+    // x * conj(y) = (ac + bd) + (bc - ad)i
+    let numerator_real = &x.real * &y.real + &x.imag * &y.imag;
+    let numerator_imag = &x.imag * &y.real - &x.real * &y.imag;
+    let q = GaussianInteger {
+        real: numerator_real.div_round(&norm, RoundingMode::Nearest),
+        imag: numerator_imag.div_round(&norm, RoundingMode::Nearest),
+    };
+    let r = x - &q * y;
+    (q, r)
+}
+
+impl DivRem<GaussianInteger> for GaussianInteger {
+    type DivOutput = GaussianInteger;
+    type RemOutput = GaussianInteger;
+
+    /// Divides a [`GaussianInteger`] by another [`GaussianInteger`], taking both by value and
+    /// returning the quotient and remainder. The real and imaginary parts of the exact quotient
+    /// are each rounded to the nearest integer.
+    ///
+    /// The quotient and remainder satisfy $x = qy + r$ and $N(r) \leq N(y)/2$, where $N$ is the
+    /// [`norm`](GaussianInteger::norm).
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the largest number of significant
+    /// bits of any of the real and imaginary parts.
+    ///
+    /// # Panics
+    /// Panics if `other` is zero.
+    ///
+    /// # Examples
+    /// See [here](super::div_rem#div_rem).
+    #[inline]
+    fn div_rem(self, other: GaussianInteger) -> (GaussianInteger, GaussianInteger) {
+        div_rem_helper(&self, &other)
+    }
+}
+
+impl<'a> DivRem<&'a GaussianInteger> for GaussianInteger {
+    type DivOutput = GaussianInteger;
+    type RemOutput = GaussianInteger;
+
+    /// Divides a [`GaussianInteger`] by another [`GaussianInteger`], taking the first by value
+    /// and the second by reference and returning the quotient and remainder. The real and
+    /// imaginary parts of the exact quotient are each rounded to the nearest integer.
+    ///
+    /// The quotient and remainder satisfy $x = qy + r$ and $N(r) \leq N(y)/2$, where $N$ is the
+    /// [`norm`](GaussianInteger::norm).
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the largest number of significant
+    /// bits of any of the real and imaginary parts.
+    ///
+    /// # Panics
+    /// Panics if `other` is zero.
+    ///
+    /// # Examples
+    /// See [here](super::div_rem#div_rem).
+    #[inline]
+    fn div_rem(self, other: &'a GaussianInteger) -> (GaussianInteger, GaussianInteger) {
+        div_rem_helper(&self, other)
+    }
+}
+
+impl<'a> DivRem<GaussianInteger> for &'a GaussianInteger {
+    type DivOutput = GaussianInteger;
+    type RemOutput = GaussianInteger;
+
+    /// Divides a [`GaussianInteger`] by another [`GaussianInteger`], taking the first by
+    /// reference and the second by value and returning the quotient and remainder. The real and
+    /// imaginary parts of the exact quotient are each rounded to the nearest integer.
+    ///
+    /// The quotient and remainder satisfy $x = qy + r$ and $N(r) \leq N(y)/2$, where $N$ is the
+    /// [`norm`](GaussianInteger::norm).
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the largest number of significant
+    /// bits of any of the real and imaginary parts.
+    ///
+    /// # Panics
+    /// Panics if `other` is zero.
+    ///
+    /// # Examples
+    /// See [here](super::div_rem#div_rem).
+    #[inline]
+    fn div_rem(self, other: GaussianInteger) -> (GaussianInteger, GaussianInteger) {
+        div_rem_helper(self, &other)
+    }
+}
+
+impl<'a, 'b> DivRem<&'b GaussianInteger> for &'a GaussianInteger {
+    type DivOutput = GaussianInteger;
+    type RemOutput = GaussianInteger;
+
+    /// Divides a [`GaussianInteger`] by another [`GaussianInteger`], taking both by reference
+    /// and returning the quotient and remainder. The real and imaginary parts of the exact
+    /// quotient are each rounded to the nearest integer.
+    ///
+    /// The quotient and remainder satisfy $x = qy + r$ and $N(r) \leq N(y)/2$, where $N$ is the
+    /// [`norm`](GaussianInteger::norm).
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the largest number of significant
+    /// bits of any of the real and imaginary parts.
+    ///
+    /// # Panics
+    /// Panics if `other` is zero.
+    ///
+    /// # Examples
+    /// See [here](super::div_rem#div_rem).
+    #[inline]
+    fn div_rem(self, other: &'b GaussianInteger) -> (GaussianInteger, GaussianInteger) {
+        div_rem_helper(self, other)
+    }
+}
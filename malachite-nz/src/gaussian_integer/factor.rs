@@ -0,0 +1,87 @@
+use crate::gaussian_integer::div_rem::div_rem_helper;
+use crate::gaussian_integer::gcd::gcd_helper;
+use crate::gaussian_integer::GaussianInteger;
+use crate::integer::Integer;
+use crate::natural::Natural;
+use malachite_base::num::arithmetic::traits::ModSqrt;
+use malachite_base::num::basic::traits::{One, Zero};
+use malachite_base::num::factorization::traits::Factor;
+use malachite_base::num::logic::traits::BitAccess;
+
+// Returns the exponent of the prime `p` in `x`, removing that power of `p` from `x`. The exponent
+// is at most `max_exponent`.
+fn remove_factor(x: &mut GaussianInteger, p: &GaussianInteger, max_exponent: u64) -> u64 {
+    let mut exponent = 0;
+    while exponent < max_exponent {
+        let (q, r) = div_rem_helper(x, p);
+        if r != GaussianInteger::ZERO {
+            break;
+        }
+        *x = q;
+        exponent += 1;
+    }
+    exponent
+}
+
+impl Factor for GaussianInteger {
+    /// Finds the prime factorization of a [`GaussianInteger`].
+    ///
+    /// The factorization is returned as a list of (prime, exponent) pairs. Each prime is
+    /// normalized to lie in the first quadrant (see [`normalize`](GaussianInteger::normalize)),
+    /// and the pairs are sorted by the norm of the prime and then by its real part. The
+    /// [`GaussianInteger`] is equal to the product of the prime powers times a unit; units have
+    /// an empty factorization.
+    ///
+    /// The rational primes dividing the [`norm`](GaussianInteger::norm) are found using
+    /// [`Natural`]'s [`factor`](Factor::factor). A rational prime $p$ gives rise to the Gaussian
+    /// prime $1 + i$ if $p = 2$, to $p$ itself if $p \equiv 3 \pmod 4$, and otherwise to the pair
+    /// of conjugate primes $\gcd(p, x + i)$ and its conjugate, where $x^2 \equiv -1 \pmod p$.
+    ///
+    /// # Worst-case complexity
+    /// The running time is dominated by the factorization of the norm; see
+    /// [`Natural::factor`](Factor::factor).
+    ///
+    /// # Panics
+    /// Panics if `self` is 0.
+    ///
+    /// # Examples
+    /// See [here](super::factor#factor).
+    fn factor(&self) -> Vec<(GaussianInteger, u64)> {
+        let norm = self.norm();
+        assert_ne!(norm, 0u32, "Cannot factor 0");
+        let mut remaining = self.clone();
+        let mut factors = Vec::new();
+        for (p, e) in norm.factor() {
+            if p == 2u32 {
+                let one_plus_i = GaussianInteger {
+                    real: Integer::ONE,
+                    imag: Integer::ONE,
+                };
+                factors.push((one_plus_i, e));
+            } else if p.get_bit(1) {
+                // p = 3 mod 4, so p is inert and N(x) contains an even power of p
+                factors.push((GaussianInteger::from(Integer::from(p)), e >> 1));
+            } else {
+                // p = 1 mod 4, so p splits into two conjugate primes
+                let root = (&p - Natural::ONE).mod_sqrt(&p).unwrap();
+                let prime = gcd_helper(
+                    GaussianInteger::from(Integer::from(p)),
+                    GaussianInteger {
+                        real: Integer::from(root),
+                        imag: Integer::ONE,
+                    },
+                );
+                let conjugate = prime.conjugate().normalize();
+                let prime_exponent = remove_factor(&mut remaining, &prime, e);
+                if prime_exponent != 0 {
+                    factors.push((prime, prime_exponent));
+                }
+                if prime_exponent != e {
+                    factors.push((conjugate, e - prime_exponent));
+                }
+            }
+        }
+        factors.sort_by(|(x, _), (y, _)| x.norm().cmp(&y.norm()).then_with(|| x.real.cmp(&y.real)));
+        factors
+    }
+}
@@ -0,0 +1,266 @@
+use crate::gaussian_integer::div_rem::div_rem_helper;
+use crate::gaussian_integer::GaussianInteger;
+use malachite_base::num::arithmetic::traits::{ExtendedGcd, Gcd};
+use malachite_base::num::basic::traits::{One, Zero};
+use std::mem::replace;
+
+pub(crate) fn gcd_helper(mut x: GaussianInteger, mut y: GaussianInteger) -> GaussianInteger {
+    while y != GaussianInteger::ZERO {
+        let r = div_rem_helper(&x, &y).1;
+        x = y;
+        y = r;
+    }
+    x.normalize_in_place();
+    x
+}
+
+fn extended_gcd_helper(
+    x: GaussianInteger,
+    y: GaussianInteger,
+) -> (GaussianInteger, GaussianInteger, GaussianInteger) {
+    if x == GaussianInteger::ZERO && y == GaussianInteger::ZERO {
+        return (
+            GaussianInteger::ZERO,
+            GaussianInteger::ZERO,
+            GaussianInteger::ZERO,
+        );
+    }
+    let (mut r_0, mut r_1) = (x, y);
+    let (mut s_0, mut s_1) = (GaussianInteger::ONE, GaussianInteger::ZERO);
+    let (mut t_0, mut t_1) = (GaussianInteger::ZERO, GaussianInteger::ONE);
+    while r_1 != GaussianInteger::ZERO {
+        let (q, r) = div_rem_helper(&r_0, &r_1);
+        let s = &s_0 - &q * &s_1;
+        let t = &t_0 - q * &t_1;
+        r_0 = replace(&mut r_1, r);
+        s_0 = replace(&mut s_1, s);
+        t_0 = replace(&mut t_1, t);
+    }
+    // r_0 = u * gcd for some unit u, and the inverse of u is its conjugate
+    let inverse = r_0.unit_part().conjugate();
+    (r_0 * &inverse, s_0 * &inverse, t_0 * inverse)
+}
+
+impl Gcd<GaussianInteger> for GaussianInteger {
+    type Output = GaussianInteger;
+
+    /// Computes the GCD (greatest common divisor) of two [`GaussianInteger`]s, taking both by
+    /// value.
+    ///
+    /// The GCD is only defined up to a unit; the result is the associate that lies in the first
+    /// quadrant (see [`normalize`](GaussianInteger::normalize)). The GCD of 0 and 0 is 0.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the largest number of significant
+    /// bits of any of the real and imaginary parts.
+    ///
+    /// # Examples
+    /// See [here](super::gcd#gcd).
+    #[inline]
+    fn gcd(self, other: GaussianInteger) -> GaussianInteger {
+        gcd_helper(self, other)
+    }
+}
+
+impl<'a> Gcd<&'a GaussianInteger> for GaussianInteger {
+    type Output = GaussianInteger;
+
+    /// Computes the GCD (greatest common divisor) of two [`GaussianInteger`]s, taking the first
+    /// by value and the second by reference.
+    ///
+    /// The GCD is only defined up to a unit; the result is the associate that lies in the first
+    /// quadrant (see [`normalize`](GaussianInteger::normalize)). The GCD of 0 and 0 is 0.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the largest number of significant
+    /// bits of any of the real and imaginary parts.
+    ///
+    /// # Examples
+    /// See [here](super::gcd#gcd).
+    #[inline]
+    fn gcd(self, other: &'a GaussianInteger) -> GaussianInteger {
+        gcd_helper(self, other.clone())
+    }
+}
+
+impl<'a> Gcd<GaussianInteger> for &'a GaussianInteger {
+    type Output = GaussianInteger;
+
+    /// Computes the GCD (greatest common divisor) of two [`GaussianInteger`]s, taking the first
+    /// by reference and the second by value.
+    ///
+    /// The GCD is only defined up to a unit; the result is the associate that lies in the first
+    /// quadrant (see [`normalize`](GaussianInteger::normalize)). The GCD of 0 and 0 is 0.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the largest number of significant
+    /// bits of any of the real and imaginary parts.
+    ///
+    /// # Examples
+    /// See [here](super::gcd#gcd).
+    #[inline]
+    fn gcd(self, other: GaussianInteger) -> GaussianInteger {
+        gcd_helper(self.clone(), other)
+    }
+}
+
+impl<'a, 'b> Gcd<&'b GaussianInteger> for &'a GaussianInteger {
+    type Output = GaussianInteger;
+
+    /// Computes the GCD (greatest common divisor) of two [`GaussianInteger`]s, taking both by
+    /// reference.
+    ///
+    /// The GCD is only defined up to a unit; the result is the associate that lies in the first
+    /// quadrant (see [`normalize`](GaussianInteger::normalize)). The GCD of 0 and 0 is 0.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the largest number of significant
+    /// bits of any of the real and imaginary parts.
+    ///
+    /// # Examples
+    /// See [here](super::gcd#gcd).
+    #[inline]
+    fn gcd(self, other: &'b GaussianInteger) -> GaussianInteger {
+        gcd_helper(self.clone(), other.clone())
+    }
+}
+
+impl ExtendedGcd<GaussianInteger> for GaussianInteger {
+    type Gcd = GaussianInteger;
+    type Cofactor = GaussianInteger;
+
+    /// Computes the GCD (greatest common divisor) of two [`GaussianInteger`]s $a$ and $b$, and
+    /// also the coefficients $x$ and $y$ in Bézout's identity $ax+by=\gcd(a,b)$. Both
+    /// [`GaussianInteger`]s are taken by value.
+    ///
+    /// The GCD is normalized as in [`gcd`](Gcd::gcd), and the coefficients are the ones produced
+    /// by the extended Euclidean algorithm using nearest-rounding division. If $a$ and $b$ are
+    /// both 0, the result is $(0, 0, 0)$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the largest number of significant
+    /// bits of any of the real and imaginary parts.
+    ///
+    /// # Examples
+    /// See [here](super::gcd#extended_gcd).
+    #[inline]
+    fn extended_gcd(
+        self,
+        other: GaussianInteger,
+    ) -> (GaussianInteger, GaussianInteger, GaussianInteger) {
+        extended_gcd_helper(self, other)
+    }
+}
+
+impl<'a> ExtendedGcd<&'a GaussianInteger> for GaussianInteger {
+    type Gcd = GaussianInteger;
+    type Cofactor = GaussianInteger;
+
+    /// Computes the GCD (greatest common divisor) of two [`GaussianInteger`]s $a$ and $b$, and
+    /// also the coefficients $x$ and $y$ in Bézout's identity $ax+by=\gcd(a,b)$. The first
+    /// [`GaussianInteger`] is taken by value and the second by reference.
+    ///
+    /// The GCD is normalized as in [`gcd`](Gcd::gcd), and the coefficients are the ones produced
+    /// by the extended Euclidean algorithm using nearest-rounding division. If $a$ and $b$ are
+    /// both 0, the result is $(0, 0, 0)$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the largest number of significant
+    /// bits of any of the real and imaginary parts.
+    ///
+    /// # Examples
+    /// See [here](super::gcd#extended_gcd).
+    #[inline]
+    fn extended_gcd(
+        self,
+        other: &'a GaussianInteger,
+    ) -> (GaussianInteger, GaussianInteger, GaussianInteger) {
+        extended_gcd_helper(self, other.clone())
+    }
+}
+
+impl<'a> ExtendedGcd<GaussianInteger> for &'a GaussianInteger {
+    type Gcd = GaussianInteger;
+    type Cofactor = GaussianInteger;
+
+    /// Computes the GCD (greatest common divisor) of two [`GaussianInteger`]s $a$ and $b$, and
+    /// also the coefficients $x$ and $y$ in Bézout's identity $ax+by=\gcd(a,b)$. The first
+    /// [`GaussianInteger`] is taken by reference and the second by value.
+    ///
+    /// The GCD is normalized as in [`gcd`](Gcd::gcd), and the coefficients are the ones produced
+    /// by the extended Euclidean algorithm using nearest-rounding division. If $a$ and $b$ are
+    /// both 0, the result is $(0, 0, 0)$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the largest number of significant
+    /// bits of any of the real and imaginary parts.
+    ///
+    /// # Examples
+    /// See [here](super::gcd#extended_gcd).
+    #[inline]
+    fn extended_gcd(
+        self,
+        other: GaussianInteger,
+    ) -> (GaussianInteger, GaussianInteger, GaussianInteger) {
+        extended_gcd_helper(self.clone(), other)
+    }
+}
+
+impl<'a, 'b> ExtendedGcd<&'b GaussianInteger> for &'a GaussianInteger {
+    type Gcd = GaussianInteger;
+    type Cofactor = GaussianInteger;
+
+    /// Computes the GCD (greatest common divisor) of two [`GaussianInteger`]s $a$ and $b$, and
+    /// also the coefficients $x$ and $y$ in Bézout's identity $ax+by=\gcd(a,b)$. Both
+    /// [`GaussianInteger`]s are taken by reference.
+    ///
+    /// The GCD is normalized as in [`gcd`](Gcd::gcd), and the coefficients are the ones produced
+    /// by the extended Euclidean algorithm using nearest-rounding division. If $a$ and $b$ are
+    /// both 0, the result is $(0, 0, 0)$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the largest number of significant
+    /// bits of any of the real and imaginary parts.
+    ///
+    /// # Examples
+    /// See [here](super::gcd#extended_gcd).
+    #[inline]
+    fn extended_gcd(
+        self,
+        other: &'b GaussianInteger,
+    ) -> (GaussianInteger, GaussianInteger, GaussianInteger) {
+        extended_gcd_helper(self.clone(), other.clone())
+    }
+}
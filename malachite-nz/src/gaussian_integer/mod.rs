@@ -0,0 +1,320 @@
+use crate::integer::Integer;
+use malachite_base::num::basic::traits::{One, Zero};
+use std::fmt::{Debug, Display, Formatter, Result, Write};
+
+/// A Gaussian integer; that is, a complex number $a + bi$ whose real and imaginary parts $a$ and
+/// $b$ are [`Integer`]s.
+///
+/// The Gaussian integers form a Euclidean domain, so they support division with a remainder
+/// that is smaller than the divisor, GCDs, and unique factorization into Gaussian primes. Since
+/// the units are 1, $i$, $-1$, and $-i$, GCDs and prime factors are only determined up to a unit.
+/// This type always reports the associate that lies in the first quadrant; that is, the one with
+/// a positive real part and a nonnegative imaginary part.
+#[derive(Clone, Hash, Eq, PartialEq)]
+pub struct GaussianInteger {
+    pub(crate) real: Integer,
+    pub(crate) imag: Integer,
+}
+
+impl GaussianInteger {
+    /// The imaginary unit, $i$.
+    pub const I: GaussianInteger = GaussianInteger {
+        real: Integer::ZERO,
+        imag: Integer::ONE,
+    };
+
+    /// Creates a [`GaussianInteger`] from its real and imaginary parts.
+    ///
+    /// $f(a, b) = a + bi$.
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::gaussian_integer::GaussianInteger;
+    /// use malachite_nz::integer::Integer;
+    ///
+    /// assert_eq!(GaussianInteger::new(Integer::from(3), Integer::from(4)).to_string(), "3+4i");
+    /// assert_eq!(GaussianInteger::new(Integer::from(3), Integer::from(-1)).to_string(), "3-i");
+    /// assert_eq!(GaussianInteger::new(Integer::from(0), Integer::from(-2)).to_string(), "-2i");
+    /// ```
+    pub const fn new(real: Integer, imag: Integer) -> GaussianInteger {
+        GaussianInteger { real, imag }
+    }
+
+    /// Returns a reference to the real part of a [`GaussianInteger`].
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::gaussian_integer::GaussianInteger;
+    /// use malachite_nz::integer::Integer;
+    ///
+    /// assert_eq!(*GaussianInteger::new(Integer::from(3), Integer::from(4)).real(), 3);
+    /// ```
+    pub const fn real(&self) -> &Integer {
+        &self.real
+    }
+
+    /// Returns a reference to the imaginary part of a [`GaussianInteger`].
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::gaussian_integer::GaussianInteger;
+    /// use malachite_nz::integer::Integer;
+    ///
+    /// assert_eq!(*GaussianInteger::new(Integer::from(3), Integer::from(4)).imag(), 4);
+    /// ```
+    pub const fn imag(&self) -> &Integer {
+        &self.imag
+    }
+
+    /// Consumes a [`GaussianInteger`] and returns its real and imaginary parts.
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::gaussian_integer::GaussianInteger;
+    /// use malachite_nz::integer::Integer;
+    ///
+    /// let (real, imag) = GaussianInteger::new(Integer::from(3), Integer::from(-4)).into_parts();
+    /// assert_eq!(real, 3);
+    /// assert_eq!(imag, -4);
+    /// ```
+    #[allow(clippy::missing_const_for_fn)]
+    pub fn into_parts(self) -> (Integer, Integer) {
+        (self.real, self.imag)
+    }
+}
+
+/// The constant 0.
+impl Zero for GaussianInteger {
+    const ZERO: GaussianInteger = GaussianInteger {
+        real: Integer::ZERO,
+        imag: Integer::ZERO,
+    };
+}
+
+/// The constant 1.
+impl One for GaussianInteger {
+    const ONE: GaussianInteger = GaussianInteger {
+        real: Integer::ONE,
+        imag: Integer::ZERO,
+    };
+}
+
+impl From<Integer> for GaussianInteger {
+    /// Converts an [`Integer`] to a [`GaussianInteger`] with imaginary part 0, taking the
+    /// [`Integer`] by value.
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::gaussian_integer::GaussianInteger;
+    /// use malachite_nz::integer::Integer;
+    ///
+    /// assert_eq!(GaussianInteger::from(Integer::from(-5)).to_string(), "-5");
+    /// ```
+    #[inline]
+    fn from(real: Integer) -> GaussianInteger {
+        GaussianInteger {
+            real,
+            imag: Integer::ZERO,
+        }
+    }
+}
+
+impl<'a> From<&'a Integer> for GaussianInteger {
+    /// Converts an [`Integer`] to a [`GaussianInteger`] with imaginary part 0, taking the
+    /// [`Integer`] by reference.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `x.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::gaussian_integer::GaussianInteger;
+    /// use malachite_nz::integer::Integer;
+    ///
+    /// assert_eq!(GaussianInteger::from(&Integer::from(-5)).to_string(), "-5");
+    /// ```
+    #[inline]
+    fn from(x: &'a Integer) -> GaussianInteger {
+        GaussianInteger {
+            real: x.clone(),
+            imag: Integer::ZERO,
+        }
+    }
+}
+
+impl Display for GaussianInteger {
+    /// Converts a [`GaussianInteger`] to a [`String`].
+    ///
+    /// The number is written as `a+bi` or `a-bi`. A zero real or imaginary part is omitted
+    /// (unless the number is 0), and an imaginary part of 1 or $-1$ is written as `i` or `-i`.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the number of significant bits of
+    /// the larger of the real and imaginary parts.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::Zero;
+    /// use malachite_nz::gaussian_integer::GaussianInteger;
+    /// use malachite_nz::integer::Integer;
+    ///
+    /// assert_eq!(GaussianInteger::ZERO.to_string(), "0");
+    /// assert_eq!(GaussianInteger::I.to_string(), "i");
+    /// assert_eq!((-GaussianInteger::I).to_string(), "-i");
+    /// assert_eq!(GaussianInteger::new(Integer::from(-3), Integer::from(1)).to_string(), "-3+i");
+    /// assert_eq!(GaussianInteger::new(Integer::from(2), Integer::from(-7)).to_string(), "2-7i");
+    /// ```
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        if self.imag == 0u32 {
+            return Display::fmt(&self.real, f);
+        }
+        if self.real != 0u32 {
+            Display::fmt(&self.real, f)?;
+            if self.imag > 0u32 {
+                f.write_char('+')?;
+            }
+        }
+        if self.imag == -1i32 {
+            f.write_char('-')?;
+        } else if self.imag != 1u32 {
+            Display::fmt(&self.imag, f)?;
+        }
+        f.write_char('i')
+    }
+}
+
+impl Debug for GaussianInteger {
+    /// Converts a [`GaussianInteger`] to a [`String`].
+    ///
+    /// This is the same as the [`Display::fmt`] implementation.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the number of significant bits of
+    /// the larger of the real and imaginary parts.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::strings::ToDebugString;
+    /// use malachite_nz::gaussian_integer::GaussianInteger;
+    /// use malachite_nz::integer::Integer;
+    ///
+    /// assert_eq!(
+    ///     GaussianInteger::new(Integer::from(3), Integer::from(4)).to_debug_string(),
+    ///     "3+4i"
+    /// );
+    /// ```
+    #[inline]
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        Display::fmt(self, f)
+    }
+}
+
+/// Addition, subtraction, multiplication, negation, conjugation, and norms of
+/// [`GaussianInteger`]s.
+pub mod arithmetic;
+/// Euclidean division of [`GaussianInteger`]s, with the quotient rounded to the nearest
+/// [`GaussianInteger`].
+///
+/// # div_rem
+/// ```
+/// use malachite_base::num::arithmetic::traits::DivRem;
+/// use malachite_base::strings::ToDebugString;
+/// use malachite_nz::gaussian_integer::GaussianInteger;
+/// use malachite_nz::integer::Integer;
+///
+/// let x = GaussianInteger::new(Integer::from(27), Integer::from(-23));
+/// let y = GaussianInteger::new(Integer::from(8), Integer::from(1));
+/// assert_eq!((&x).div_rem(&y).to_debug_string(), "(3-3i, -2i)");
+/// assert_eq!(x.div_rem(y).to_debug_string(), "(3-3i, -2i)");
+/// ```
+pub mod div_rem;
+/// Prime factorization of [`GaussianInteger`]s.
+///
+/// # factor
+/// ```
+/// use malachite_base::num::factorization::traits::Factor;
+/// use malachite_base::strings::ToDebugString;
+/// use malachite_nz::gaussian_integer::GaussianInteger;
+/// use malachite_nz::integer::Integer;
+///
+/// assert_eq!(
+///     GaussianInteger::from(Integer::from(10)).factor().to_debug_string(),
+///     "[(1+i, 2), (1+2i, 1), (2+i, 1)]"
+/// );
+/// assert_eq!(
+///     GaussianInteger::new(Integer::from(3), Integer::from(-4)).factor().to_debug_string(),
+///     "[(1+2i, 2)]"
+/// );
+/// assert_eq!(
+///     GaussianInteger::from(Integer::from(-21)).factor().to_debug_string(),
+///     "[(3, 1), (7, 1)]"
+/// );
+/// ```
+pub mod factor;
+/// GCDs of [`GaussianInteger`]s, and the coefficients of Bézout's identity.
+///
+/// # gcd
+/// ```
+/// use malachite_base::num::arithmetic::traits::Gcd;
+/// use malachite_nz::gaussian_integer::GaussianInteger;
+/// use malachite_nz::integer::Integer;
+///
+/// let x = GaussianInteger::new(Integer::from(11), Integer::from(3));
+/// let y = GaussianInteger::new(Integer::from(1), Integer::from(8));
+/// assert_eq!((&x).gcd(&y).to_string(), "2+i");
+/// assert_eq!(x.gcd(y).to_string(), "2+i");
+/// ```
+///
+/// # extended_gcd
+/// ```
+/// use malachite_base::num::arithmetic::traits::ExtendedGcd;
+/// use malachite_nz::gaussian_integer::GaussianInteger;
+/// use malachite_nz::integer::Integer;
+///
+/// let x = GaussianInteger::new(Integer::from(11), Integer::from(3));
+/// let y = GaussianInteger::new(Integer::from(1), Integer::from(8));
+/// let (gcd, s, t) = (&x).extended_gcd(&y);
+/// assert_eq!(gcd.to_string(), "2+i");
+/// assert_eq!(s * x + t * y, gcd);
+/// ```
+pub mod gcd;
+/// Units and associates of [`GaussianInteger`]s.
+///
+/// # normalize
+/// ```
+/// use malachite_nz::gaussian_integer::GaussianInteger;
+/// use malachite_nz::integer::Integer;
+///
+/// let x = GaussianInteger::new(Integer::from(-2), Integer::from(-5));
+/// assert_eq!(x.normalize().to_string(), "2+5i");
+/// assert_eq!(x.unit_part().to_string(), "-1");
+/// assert_eq!(x.unit_part() * x.normalize(), x);
+/// ```
+pub mod units;
@@ -0,0 +1,159 @@
+use crate::gaussian_integer::GaussianInteger;
+use crate::integer::Integer;
+use malachite_base::num::arithmetic::traits::NegAssign;
+use malachite_base::num::basic::traits::{NegativeOne, One, Zero};
+
+impl GaussianInteger {
+    /// Determines whether a [`GaussianInteger`] is a unit; that is, whether it is one of 1, $i$,
+    /// $-1$, and $-i$.
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::{One, Zero};
+    /// use malachite_nz::gaussian_integer::GaussianInteger;
+    /// use malachite_nz::integer::Integer;
+    ///
+    /// assert!(GaussianInteger::ONE.is_unit());
+    /// assert!((-GaussianInteger::I).is_unit());
+    /// assert!(!GaussianInteger::ZERO.is_unit());
+    /// assert!(!GaussianInteger::new(Integer::from(1), Integer::from(1)).is_unit());
+    /// ```
+    pub fn is_unit(&self) -> bool {
+        if self.real == 0u32 {
+            *self.imag.unsigned_abs_ref() == 1u32
+        } else {
+            self.imag == 0u32 && *self.real.unsigned_abs_ref() == 1u32
+        }
+    }
+
+    /// Returns the four units 1, $i$, $-1$, and $-i$, in that order.
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::strings::ToDebugString;
+    /// use malachite_nz::gaussian_integer::GaussianInteger;
+    ///
+    /// assert_eq!(GaussianInteger::units().to_debug_string(), "[1, i, -1, -i]");
+    /// ```
+    pub const fn units() -> [GaussianInteger; 4] {
+        [
+            GaussianInteger::ONE,
+            GaussianInteger::I,
+            GaussianInteger {
+                real: Integer::NEGATIVE_ONE,
+                imag: Integer::ZERO,
+            },
+            GaussianInteger {
+                real: Integer::ZERO,
+                imag: Integer::NEGATIVE_ONE,
+            },
+        ]
+    }
+
+    /// Returns the four associates of a [`GaussianInteger`] $x$; that is, $x$, $ix$, $-x$, and
+    /// $-ix$, in that order.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the larger number of significant
+    /// bits of the real and imaginary parts.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::strings::ToDebugString;
+    /// use malachite_nz::gaussian_integer::GaussianInteger;
+    /// use malachite_nz::integer::Integer;
+    ///
+    /// assert_eq!(
+    ///     GaussianInteger::new(Integer::from(2), Integer::from(1)).associates().to_debug_string(),
+    ///     "[2+i, -1+2i, -2-i, 1-2i]"
+    /// );
+    /// ```
+    pub fn associates(&self) -> [GaussianInteger; 4] {
+        [
+            self.clone(),
+            GaussianInteger {
+                real: -&self.imag,
+                imag: self.real.clone(),
+            },
+            -self,
+            GaussianInteger {
+                real: self.imag.clone(),
+                imag: -&self.real,
+            },
+        ]
+    }
+
+    /// Returns the unit $u$ such that a [`GaussianInteger`] $x$ is equal to $u$ times
+    /// [`normalize`](GaussianInteger::normalize)`(x)`. If $x$ is 0, 1 is returned.
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Examples
+    /// See [here](super::units#normalize).
+    pub fn unit_part(&self) -> GaussianInteger {
+        let [one, i, negative_one, negative_i] = GaussianInteger::units();
+        if self.real > 0u32 && self.imag >= 0u32 {
+            one
+        } else if self.real <= 0u32 && self.imag > 0u32 {
+            i
+        } else if self.real < 0u32 && self.imag <= 0u32 {
+            negative_one
+        } else if self.real >= 0u32 && self.imag < 0u32 {
+            negative_i
+        } else {
+            // self is 0
+            one
+        }
+    }
+
+    /// Returns the associate of a [`GaussianInteger`] that lies in the first quadrant; that is,
+    /// the unique associate whose real part is positive and whose imaginary part is nonnegative.
+    /// If the [`GaussianInteger`] is 0, 0 is returned.
+    ///
+    /// Two [`GaussianInteger`]s are associates if and only if they have the same normalization.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the larger number of significant
+    /// bits of the real and imaginary parts.
+    ///
+    /// # Examples
+    /// See [here](super::units#normalize).
+    pub fn normalize(&self) -> GaussianInteger {
+        let mut x = self.clone();
+        x.normalize_in_place();
+        x
+    }
+
+    pub(crate) fn normalize_in_place(&mut self) {
+        if self.real > 0u32 && self.imag >= 0u32 {
+            return;
+        }
+        if self.real <= 0u32 && self.imag > 0u32 {
+            // self = i * (b - ai)
+            std::mem::swap(&mut self.real, &mut self.imag);
+            self.imag.neg_assign();
+        } else if self.real < 0u32 && self.imag <= 0u32 {
+            self.real.neg_assign();
+            self.imag.neg_assign();
+        } else if self.imag < 0u32 {
+            // self = -i * (-b + ai)
+            std::mem::swap(&mut self.real, &mut self.imag);
+            self.real.neg_assign();
+        }
+    }
+}
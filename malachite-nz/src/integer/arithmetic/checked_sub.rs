@@ -0,0 +1,152 @@
+use crate::integer::Integer;
+use malachite_base::num::arithmetic::traits::CheckedSub;
+
+impl CheckedSub<Integer> for Integer {
+    type Output = Integer;
+
+    /// Subtracts an [`Integer`] by another [`Integer`]. Since every difference of [`Integer`]s is
+    /// an [`Integer`], the result is always `Some`; this implementation lets code that is generic
+    /// over `CheckedSub` accept [`Integer`]s. Both [`Integer`]s are taken by value.
+    ///
+    /// $$
+    /// f(x, y) = \operatorname{Some}(x - y).
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), other.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::CheckedSub;
+    /// use malachite_base::strings::ToDebugString;
+    /// use malachite_nz::integer::Integer;
+    ///
+    /// assert_eq!(Integer::from(3).checked_sub(Integer::from(10)).to_debug_string(), "Some(-7)");
+    /// assert_eq!(Integer::from(-5).checked_sub(Integer::from(-8)).to_debug_string(), "Some(3)");
+    /// ```
+    #[inline]
+    fn checked_sub(self, other: Integer) -> Option<Integer> {
+        Some(self - other)
+    }
+}
+
+impl<'a> CheckedSub<&'a Integer> for Integer {
+    type Output = Integer;
+
+    /// Subtracts an [`Integer`] by another [`Integer`]. Since every difference of [`Integer`]s is
+    /// an [`Integer`], the result is always `Some`; this implementation lets code that is generic
+    /// over `CheckedSub` accept [`Integer`]s. The first [`Integer`] is taken by value and the
+    /// second by reference.
+    ///
+    /// $$
+    /// f(x, y) = \operatorname{Some}(x - y).
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), other.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::CheckedSub;
+    /// use malachite_base::strings::ToDebugString;
+    /// use malachite_nz::integer::Integer;
+    ///
+    /// assert_eq!(Integer::from(3).checked_sub(&Integer::from(10)).to_debug_string(), "Some(-7)");
+    /// assert_eq!(Integer::from(-5).checked_sub(&Integer::from(-8)).to_debug_string(), "Some(3)");
+    /// ```
+    #[inline]
+    fn checked_sub(self, other: &'a Integer) -> Option<Integer> {
+        Some(self - other)
+    }
+}
+
+impl<'a> CheckedSub<Integer> for &'a Integer {
+    type Output = Integer;
+
+    /// Subtracts an [`Integer`] by another [`Integer`]. Since every difference of [`Integer`]s is
+    /// an [`Integer`], the result is always `Some`; this implementation lets code that is generic
+    /// over `CheckedSub` accept [`Integer`]s. The first [`Integer`] is taken by reference and the
+    /// second by value.
+    ///
+    /// $$
+    /// f(x, y) = \operatorname{Some}(x - y).
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), other.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::CheckedSub;
+    /// use malachite_base::strings::ToDebugString;
+    /// use malachite_nz::integer::Integer;
+    ///
+    /// assert_eq!(
+    ///     (&Integer::from(3)).checked_sub(Integer::from(10)).to_debug_string(),
+    ///     "Some(-7)"
+    /// );
+    /// assert_eq!(
+    ///     (&Integer::from(-5)).checked_sub(Integer::from(-8)).to_debug_string(),
+    ///     "Some(3)"
+    /// );
+    /// ```
+    #[inline]
+    fn checked_sub(self, other: Integer) -> Option<Integer> {
+        Some(self - other)
+    }
+}
+
+impl<'a, 'b> CheckedSub<&'b Integer> for &'a Integer {
+    type Output = Integer;
+
+    /// Subtracts an [`Integer`] by another [`Integer`]. Since every difference of [`Integer`]s is
+    /// an [`Integer`], the result is always `Some`; this implementation lets code that is generic
+    /// over `CheckedSub` accept [`Integer`]s. Both [`Integer`]s are taken by reference.
+    ///
+    /// $$
+    /// f(x, y) = \operatorname{Some}(x - y).
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), other.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::CheckedSub;
+    /// use malachite_base::strings::ToDebugString;
+    /// use malachite_nz::integer::Integer;
+    ///
+    /// assert_eq!(
+    ///     (&Integer::from(3)).checked_sub(&Integer::from(10)).to_debug_string(),
+    ///     "Some(-7)"
+    /// );
+    /// assert_eq!(
+    ///     (&Integer::from(-5)).checked_sub(&Integer::from(-8)).to_debug_string(),
+    ///     "Some(3)"
+    /// );
+    /// ```
+    #[inline]
+    fn checked_sub(self, other: &'b Integer) -> Option<Integer> {
+        Some(self - other)
+    }
+}
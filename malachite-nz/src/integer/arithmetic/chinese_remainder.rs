@@ -0,0 +1,65 @@
+use crate::integer::Integer;
+use crate::natural::Natural;
+use malachite_base::num::arithmetic::traits::{ChineseRemainder, Mod, UnsignedAbs};
+
+impl ChineseRemainder for Integer {
+    type Modulus = Natural;
+
+    /// Uses the Chinese remainder theorem to combine several congruences $x \equiv a_i \mod m_i$,
+    /// where the $a_i$ are [`Integer`]s and the $m_i$ are [`Natural`]s, into a single congruence
+    /// $x \equiv a \mod m$, where $m = \operatorname{lcm}(m_1, \ldots, m_k)$ and $0 \leq a < m$.
+    ///
+    /// The moduli need not be coprime. If they are not, the congruences may be inconsistent, in
+    /// which case `None` is returned. The residues need not be reduced, and may be negative. If
+    /// `residues` is empty, $(0, 1)$ is returned.
+    ///
+    /// See the [`Natural`] implementation for more details.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, k) = O(kn (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is the number of bits of the result's
+    /// modulus, and $k$ is `residues.len()`.
+    ///
+    /// # Panics
+    /// Panics if any modulus is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::ChineseRemainder;
+    /// use malachite_base::strings::ToDebugString;
+    /// use malachite_nz::integer::Integer;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// let residues = |xs: &[(i32, u32)]| {
+    ///     xs.iter()
+    ///         .map(|&(a, m)| (Integer::from(a), Natural::from(m)))
+    ///         .collect::<Vec<_>>()
+    /// };
+    /// assert_eq!(
+    ///     Integer::chinese_remainder(&residues(&[(-1, 3), (-1, 5)])).to_debug_string(),
+    ///     "Some((14, 15))"
+    /// );
+    /// assert_eq!(
+    ///     Integer::chinese_remainder(&residues(&[(-2, 4), (4, 6)])).to_debug_string(),
+    ///     "Some((10, 12))"
+    /// );
+    /// assert_eq!(
+    ///     Integer::chinese_remainder(&residues(&[(1, 4), (-4, 6)])).to_debug_string(),
+    ///     "None"
+    /// );
+    /// ```
+    fn chinese_remainder(residues: &[(Integer, Natural)]) -> Option<(Integer, Natural)> {
+        let residues: Vec<(Natural, Natural)> = residues
+            .iter()
+            .map(|(a, m)| {
+                assert_ne!(*m, 0u32);
+                (a.mod_op(Integer::from(m)).unsigned_abs(), m.clone())
+            })
+            .collect();
+        let (x, m) = Natural::chinese_remainder(&residues)?;
+        Some((Integer::from(x), m))
+    }
+}
@@ -0,0 +1,127 @@
+use crate::integer::Integer;
+use malachite_base::num::arithmetic::traits::{CoprimeWith, UnsignedAbs};
+
+impl CoprimeWith<Integer> for Integer {
+    /// Returns whether two [`Integer`]s are coprime; that is, whether they have no common factor
+    /// other than 1 and $-1$. Both [`Integer`]s are taken by value.
+    ///
+    /// Every [`Integer`] is coprime with 1 and $-1$. No [`Integer`] is coprime with 0, except 1 and
+    /// $-1$.
+    ///
+    /// $f(x, y) = (\gcd(|x|, |y|) = 1)$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), other.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::CoprimeWith;
+    /// use malachite_nz::integer::Integer;
+    ///
+    /// assert_eq!(Integer::from(-3).coprime_with(Integer::from(5)), true);
+    /// assert_eq!(Integer::from(12).coprime_with(Integer::from(-90)), false);
+    /// ```
+    #[inline]
+    fn coprime_with(self, other: Integer) -> bool {
+        self.unsigned_abs().coprime_with(other.unsigned_abs())
+    }
+}
+
+impl<'a> CoprimeWith<&'a Integer> for Integer {
+    /// Returns whether two [`Integer`]s are coprime; that is, whether they have no common factor
+    /// other than 1 and $-1$. The first [`Integer`] is taken by value and the second by reference.
+    ///
+    /// Every [`Integer`] is coprime with 1 and $-1$. No [`Integer`] is coprime with 0, except 1 and
+    /// $-1$.
+    ///
+    /// $f(x, y) = (\gcd(|x|, |y|) = 1)$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), other.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::CoprimeWith;
+    /// use malachite_nz::integer::Integer;
+    ///
+    /// assert_eq!(Integer::from(-3).coprime_with(&Integer::from(5)), true);
+    /// assert_eq!(Integer::from(12).coprime_with(&Integer::from(-90)), false);
+    /// ```
+    #[inline]
+    fn coprime_with(self, other: &'a Integer) -> bool {
+        self.unsigned_abs().coprime_with(other.unsigned_abs_ref())
+    }
+}
+
+impl<'a> CoprimeWith<Integer> for &'a Integer {
+    /// Returns whether two [`Integer`]s are coprime; that is, whether they have no common factor
+    /// other than 1 and $-1$. The first [`Integer`] is taken by reference and the second by value.
+    ///
+    /// Every [`Integer`] is coprime with 1 and $-1$. No [`Integer`] is coprime with 0, except 1 and
+    /// $-1$.
+    ///
+    /// $f(x, y) = (\gcd(|x|, |y|) = 1)$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), other.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::CoprimeWith;
+    /// use malachite_nz::integer::Integer;
+    ///
+    /// assert_eq!((&Integer::from(-3)).coprime_with(Integer::from(5)), true);
+    /// assert_eq!((&Integer::from(12)).coprime_with(Integer::from(-90)), false);
+    /// ```
+    #[inline]
+    fn coprime_with(self, other: Integer) -> bool {
+        self.unsigned_abs_ref().coprime_with(other.unsigned_abs())
+    }
+}
+
+impl<'a, 'b> CoprimeWith<&'b Integer> for &'a Integer {
+    /// Returns whether two [`Integer`]s are coprime; that is, whether they have no common factor
+    /// other than 1 and $-1$. Both [`Integer`]s are taken by reference.
+    ///
+    /// Every [`Integer`] is coprime with 1 and $-1$. No [`Integer`] is coprime with 0, except 1 and
+    /// $-1$.
+    ///
+    /// $f(x, y) = (\gcd(|x|, |y|) = 1)$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), other.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::CoprimeWith;
+    /// use malachite_nz::integer::Integer;
+    ///
+    /// assert_eq!((&Integer::from(-3)).coprime_with(&Integer::from(5)), true);
+    /// assert_eq!((&Integer::from(12)).coprime_with(&Integer::from(-90)), false);
+    /// ```
+    #[inline]
+    fn coprime_with(self, other: &'b Integer) -> bool {
+        self.unsigned_abs_ref()
+            .coprime_with(other.unsigned_abs_ref())
+    }
+}
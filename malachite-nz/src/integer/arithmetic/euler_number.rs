@@ -0,0 +1,204 @@
+use crate::integer::Integer;
+use crate::natural::arithmetic::chinese_remainder::ChineseRemainderBasis;
+use crate::natural::Natural;
+use malachite_base::num::arithmetic::traits::{
+    EulerNumber, Factorial, FloorLogBase2, ModPow, Parity, Pow, PrimitiveRoot,
+};
+use malachite_base::num::basic::traits::{One, Zero};
+use malachite_base::num::factorization::traits::PrimesInRange;
+use malachite_base::num::logic::traits::SignificantBits;
+
+// Returns $E_n \mod p$, where $n$ is even and positive and $p > n + 1$ is prime.
+//
+// Let $\chi$ be the nontrivial character modulo 4, so that $B_{n+1,\chi} = -(n+1)E_n/2$. Expanding
+// $\sum_{x=1}^{4p} \chi(x)x^{n+1} \equiv 4pB_{n+1,\chi} \mod p^2$ with $x = a + jp$ gives
+//
+// $$
+// E_n \equiv -\frac{1}{2} \sum_{a=1}^{p-1} w(a)a^n \mod p,
+// $$
+//
+// where $w(a) = \sum_{j=0}^3 j\chi(a + jp)$ depends only on $a \mod 4$. The residues $a$ are
+// enumerated as powers of a primitive root $g$, so that each $a^n = (g^n)^i$ costs a single
+// multiplication. Since $p < 2^{32}$ in practice, the products fit in a `u64`.
+fn euler_number_mod_prime(n: u64, p: u64) -> u64 {
+    let mut weights = [0; 4];
+    for (r, weight) in weights.iter_mut().enumerate() {
+        let mut w = 0i64;
+        for j in 1..4 {
+            match (r as u64 + j * p) & 3 {
+                1 => w += j as i64,
+                3 => w -= j as i64,
+                _ => {}
+            }
+        }
+        *weight = if w < 0 {
+            p - w.unsigned_abs()
+        } else {
+            w.unsigned_abs()
+        };
+    }
+    let g = p.primitive_root().unwrap();
+    let g_pow = g.mod_pow(n, p);
+    let mut a = 1;
+    let mut a_pow = 1;
+    let mut sum = 0;
+    for _ in 1..p {
+        sum = (sum + weights[(a & 3) as usize] * a_pow) % p;
+        a = a * g % p;
+        a_pow = a_pow * g_pow % p;
+    }
+    // Multiply by $-1/2 \equiv (p - 1)/2$.
+    sum * (p >> 1) % p
+}
+
+// Uses a multi-modular algorithm. Since
+//
+// $$
+// |E_n| = \frac{2^{n+2} n! \beta(n+1)}{\pi^{n+1}} < \frac{2^{n+2} n!}{3^{n+1}},
+// $$
+//
+// where $\beta$ is the Dirichlet beta function, $|E_n|$ is computed modulo enough primes greater
+// than $n + 1$ for their product to exceed this bound, and the residues are combined using the
+// Chinese remainder theorem. The sign of $E_n$ is $(-1)^{n/2}$.
+fn euler_number(n: u64) -> Integer {
+    if n == 0 {
+        return Integer::ONE;
+    } else if n.odd() {
+        return Integer::ZERO;
+    }
+    let bound_bits =
+        ((Natural::factorial(n) << (n + 2)) / Natural::from(3u32).pow(n + 1)).significant_bits();
+    let negative = (n >> 1).odd();
+    let mut moduli = Vec::new();
+    let mut residues = Vec::new();
+    let mut bits = 0;
+    for p in u64::primes_greater_than_or_equal_to(&(n + 2)) {
+        if bits >= bound_bits {
+            break;
+        }
+        let mut r = euler_number_mod_prime(n, p);
+        if negative && r != 0 {
+            r = p - r;
+        }
+        moduli.push(Natural::from(p));
+        residues.push(Natural::from(r));
+        bits += p.floor_log_base_2();
+    }
+    let abs = ChineseRemainderBasis::new(&moduli)
+        .unwrap()
+        .recombine(&residues);
+    if negative {
+        -Integer::from(abs)
+    } else {
+        Integer::from(abs)
+    }
+}
+
+/// An iterator that generates the Euler numbers.
+///
+/// This `struct` is created by [`Integer::euler_numbers`]; see its documentation for more.
+#[derive(Clone, Debug)]
+pub struct EulerNumbersIterator {
+    // The most recently computed row of the Seidel-Entringer-Arnold triangle. Its last element is
+    // the zigzag number $A_n$, and $E_n = (-1)^{n/2}A_n$ when $n$ is even.
+    row: Vec<Natural>,
+}
+
+impl Iterator for EulerNumbersIterator {
+    type Item = Integer;
+
+    fn next(&mut self) -> Option<Integer> {
+        if self.row.is_empty() {
+            self.row.push(Natural::ONE);
+            return Some(Integer::ONE);
+        }
+        // Row $n + 1$ is 0, followed by the partial sums of row $n$ read from right to left.
+        let mut sum = Natural::ZERO;
+        for x in self.row.iter_mut().rev() {
+            sum += &*x;
+            x.clone_from(&sum);
+        }
+        self.row.reverse();
+        self.row.insert(0, Natural::ZERO);
+        let n = self.row.len() - 1;
+        Some(if n.odd() {
+            Integer::ZERO
+        } else if (n >> 1).odd() {
+            -Integer::from(&sum)
+        } else {
+            Integer::from(sum)
+        })
+    }
+}
+
+impl EulerNumber for Integer {
+    type I = EulerNumbersIterator;
+
+    /// Computes the $n$th Euler number.
+    ///
+    /// The Euler numbers are the coefficients of the Taylor series
+    /// $$
+    /// \operatorname{sech} x = \sum_{n=0}^\infty \frac{E_n}{n!} x^n.
+    /// $$
+    /// $E_n$ is zero when $n$ is odd, and its sign is $(-1)^{n/2}$ when $n$ is even.
+    ///
+    /// The result is computed modulo many primes larger than $n + 1$, using a congruence that
+    /// takes $O(p)$ operations modulo each prime $p$, and the residues are combined using the
+    /// Chinese remainder theorem.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `n`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::EulerNumber;
+    /// use malachite_nz::integer::Integer;
+    ///
+    /// assert_eq!(Integer::euler_number(0), 1);
+    /// assert_eq!(Integer::euler_number(1), 0);
+    /// assert_eq!(Integer::euler_number(2), -1);
+    /// assert_eq!(Integer::euler_number(4), 5);
+    /// assert_eq!(Integer::euler_number(10), -50521);
+    /// assert_eq!(
+    ///     Integer::euler_number(30).to_string(),
+    ///     "-441543893249023104553682821"
+    /// );
+    /// ```
+    #[inline]
+    fn euler_number(n: u64) -> Integer {
+        euler_number(n)
+    }
+
+    /// Returns an iterator that generates the Euler numbers $E_0, E_1, E_2, \ldots$.
+    ///
+    /// The values are read off the Seidel-Entringer-Arnold triangle, whose $n$th row ends with the
+    /// zigzag number $A_n$, and $E_n = (-1)^{n/2}A_n$ when $n$ is even. Generating the first $n$
+    /// Euler numbers this way is much faster than computing each of them separately.
+    ///
+    /// # Worst-case complexity per iteration
+    /// $T(i) = O(i^2 \log i)$
+    ///
+    /// $M(i) = O(i^2 \log i)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $i$ is the iteration index.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::iterators::prefix_to_string;
+    /// use malachite_base::num::arithmetic::traits::EulerNumber;
+    /// use malachite_nz::integer::Integer;
+    ///
+    /// assert_eq!(
+    ///     prefix_to_string(Integer::euler_numbers(), 12),
+    ///     "[1, 0, -1, 0, 5, 0, -61, 0, 1385, 0, -50521, 0, ...]"
+    /// );
+    /// ```
+    #[inline]
+    fn euler_numbers() -> EulerNumbersIterator {
+        EulerNumbersIterator { row: Vec::new() }
+    }
+}
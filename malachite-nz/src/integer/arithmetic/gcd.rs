@@ -0,0 +1,215 @@
+use crate::integer::Integer;
+use crate::natural::Natural;
+use malachite_base::num::arithmetic::traits::{Gcd, GcdAssign, UnsignedAbs};
+
+impl Gcd<Integer> for Integer {
+    type Output = Natural;
+
+    /// Computes the GCD (greatest common divisor) of two [`Integer`]s. The result is the GCD of
+    /// their absolute values, so it is a [`Natural`]. Both [`Integer`]s are taken by value.
+    ///
+    /// The GCD of 0 and $n$, for any $n$, is $|n|$. In particular, $\gcd(0, 0) = 0$.
+    ///
+    /// $$
+    /// f(x, y) = \gcd(|x|, |y|).
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), other.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::Gcd;
+    /// use malachite_nz::integer::Integer;
+    ///
+    /// assert_eq!(Integer::from(-3).gcd(Integer::from(5)), 1);
+    /// assert_eq!(Integer::from(12).gcd(Integer::from(-90)), 6);
+    /// ```
+    #[inline]
+    fn gcd(self, other: Integer) -> Natural {
+        self.unsigned_abs().gcd(other.unsigned_abs())
+    }
+}
+
+impl<'a> Gcd<&'a Integer> for Integer {
+    type Output = Natural;
+
+    /// Computes the GCD (greatest common divisor) of two [`Integer`]s. The result is the GCD of
+    /// their absolute values, so it is a [`Natural`]. The first [`Integer`] is taken by value and
+    /// the second by reference.
+    ///
+    /// The GCD of 0 and $n$, for any $n$, is $|n|$. In particular, $\gcd(0, 0) = 0$.
+    ///
+    /// $$
+    /// f(x, y) = \gcd(|x|, |y|).
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), other.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::Gcd;
+    /// use malachite_nz::integer::Integer;
+    ///
+    /// assert_eq!(Integer::from(-3).gcd(&Integer::from(5)), 1);
+    /// assert_eq!(Integer::from(12).gcd(&Integer::from(-90)), 6);
+    /// ```
+    #[inline]
+    fn gcd(self, other: &'a Integer) -> Natural {
+        self.unsigned_abs().gcd(other.unsigned_abs_ref())
+    }
+}
+
+impl<'a> Gcd<Integer> for &'a Integer {
+    type Output = Natural;
+
+    /// Computes the GCD (greatest common divisor) of two [`Integer`]s. The result is the GCD of
+    /// their absolute values, so it is a [`Natural`]. The first [`Integer`] is taken by reference
+    /// and the second by value.
+    ///
+    /// The GCD of 0 and $n$, for any $n$, is $|n|$. In particular, $\gcd(0, 0) = 0$.
+    ///
+    /// $$
+    /// f(x, y) = \gcd(|x|, |y|).
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), other.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::Gcd;
+    /// use malachite_nz::integer::Integer;
+    ///
+    /// assert_eq!((&Integer::from(-3)).gcd(Integer::from(5)), 1);
+    /// assert_eq!((&Integer::from(12)).gcd(Integer::from(-90)), 6);
+    /// ```
+    #[inline]
+    fn gcd(self, other: Integer) -> Natural {
+        self.unsigned_abs_ref().gcd(other.unsigned_abs())
+    }
+}
+
+impl<'a, 'b> Gcd<&'b Integer> for &'a Integer {
+    type Output = Natural;
+
+    /// Computes the GCD (greatest common divisor) of two [`Integer`]s. The result is the GCD of
+    /// their absolute values, so it is a [`Natural`]. Both [`Integer`]s are taken by reference.
+    ///
+    /// The GCD of 0 and $n$, for any $n$, is $|n|$. In particular, $\gcd(0, 0) = 0$.
+    ///
+    /// $$
+    /// f(x, y) = \gcd(|x|, |y|).
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), other.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::Gcd;
+    /// use malachite_nz::integer::Integer;
+    ///
+    /// assert_eq!((&Integer::from(-3)).gcd(&Integer::from(5)), 1);
+    /// assert_eq!((&Integer::from(12)).gcd(&Integer::from(-90)), 6);
+    /// ```
+    #[inline]
+    fn gcd(self, other: &'b Integer) -> Natural {
+        self.unsigned_abs_ref().gcd(other.unsigned_abs_ref())
+    }
+}
+
+impl GcdAssign<Integer> for Integer {
+    /// Replaces an [`Integer`] by its GCD (greatest common divisor) with another [`Integer`]. The
+    /// result is non-negative. The [`Integer`] on the right-hand side is taken by value.
+    ///
+    /// The GCD of 0 and $n$, for any $n$, is $|n|$. In particular, $\gcd(0, 0) = 0$.
+    ///
+    /// $$
+    /// x \gets \gcd(|x|, |y|).
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), other.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::GcdAssign;
+    /// use malachite_nz::integer::Integer;
+    ///
+    /// let mut x = Integer::from(-3);
+    /// x.gcd_assign(Integer::from(5));
+    /// assert_eq!(x, 1);
+    ///
+    /// let mut x = Integer::from(12);
+    /// x.gcd_assign(Integer::from(-90));
+    /// assert_eq!(x, 6);
+    /// ```
+    #[inline]
+    fn gcd_assign(&mut self, other: Integer) {
+        *self = Integer::from(self.unsigned_abs_ref().gcd(other.unsigned_abs()));
+    }
+}
+
+impl<'a> GcdAssign<&'a Integer> for Integer {
+    /// Replaces an [`Integer`] by its GCD (greatest common divisor) with another [`Integer`]. The
+    /// result is non-negative. The [`Integer`] on the right-hand side is taken by reference.
+    ///
+    /// The GCD of 0 and $n$, for any $n$, is $|n|$. In particular, $\gcd(0, 0) = 0$.
+    ///
+    /// $$
+    /// x \gets \gcd(|x|, |y|).
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), other.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::GcdAssign;
+    /// use malachite_nz::integer::Integer;
+    ///
+    /// let mut x = Integer::from(-3);
+    /// x.gcd_assign(&Integer::from(5));
+    /// assert_eq!(x, 1);
+    ///
+    /// let mut x = Integer::from(12);
+    /// x.gcd_assign(&Integer::from(-90));
+    /// assert_eq!(x, 6);
+    /// ```
+    #[inline]
+    fn gcd_assign(&mut self, other: &'a Integer) {
+        *self = Integer::from(self.unsigned_abs_ref().gcd(other.unsigned_abs_ref()));
+    }
+}
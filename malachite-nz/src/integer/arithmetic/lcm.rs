@@ -0,0 +1,203 @@
+use crate::integer::Integer;
+use crate::natural::Natural;
+use malachite_base::num::arithmetic::traits::{Lcm, LcmAssign, UnsignedAbs};
+
+impl Lcm<Integer> for Integer {
+    type Output = Natural;
+
+    /// Computes the LCM (least common multiple) of two [`Integer`]s. The result is the LCM of their
+    /// absolute values, so it is a [`Natural`]. Both [`Integer`]s are taken by value.
+    ///
+    /// $$
+    /// f(x, y) = \operatorname{lcm}(|x|, |y|).
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), other.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::Lcm;
+    /// use malachite_nz::integer::Integer;
+    ///
+    /// assert_eq!(Integer::from(-3).lcm(Integer::from(5)), 15);
+    /// assert_eq!(Integer::from(12).lcm(Integer::from(-90)), 180);
+    /// ```
+    #[inline]
+    fn lcm(self, other: Integer) -> Natural {
+        self.unsigned_abs().lcm(other.unsigned_abs())
+    }
+}
+
+impl<'a> Lcm<&'a Integer> for Integer {
+    type Output = Natural;
+
+    /// Computes the LCM (least common multiple) of two [`Integer`]s. The result is the LCM of their
+    /// absolute values, so it is a [`Natural`]. The first [`Integer`] is taken by value and the
+    /// second by reference.
+    ///
+    /// $$
+    /// f(x, y) = \operatorname{lcm}(|x|, |y|).
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), other.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::Lcm;
+    /// use malachite_nz::integer::Integer;
+    ///
+    /// assert_eq!(Integer::from(-3).lcm(&Integer::from(5)), 15);
+    /// assert_eq!(Integer::from(12).lcm(&Integer::from(-90)), 180);
+    /// ```
+    #[inline]
+    fn lcm(self, other: &'a Integer) -> Natural {
+        self.unsigned_abs().lcm(other.unsigned_abs_ref())
+    }
+}
+
+impl<'a> Lcm<Integer> for &'a Integer {
+    type Output = Natural;
+
+    /// Computes the LCM (least common multiple) of two [`Integer`]s. The result is the LCM of their
+    /// absolute values, so it is a [`Natural`]. The first [`Integer`] is taken by reference and the
+    /// second by value.
+    ///
+    /// $$
+    /// f(x, y) = \operatorname{lcm}(|x|, |y|).
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), other.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::Lcm;
+    /// use malachite_nz::integer::Integer;
+    ///
+    /// assert_eq!((&Integer::from(-3)).lcm(Integer::from(5)), 15);
+    /// assert_eq!((&Integer::from(12)).lcm(Integer::from(-90)), 180);
+    /// ```
+    #[inline]
+    fn lcm(self, other: Integer) -> Natural {
+        self.unsigned_abs_ref().lcm(other.unsigned_abs())
+    }
+}
+
+impl<'a, 'b> Lcm<&'b Integer> for &'a Integer {
+    type Output = Natural;
+
+    /// Computes the LCM (least common multiple) of two [`Integer`]s. The result is the LCM of their
+    /// absolute values, so it is a [`Natural`]. Both [`Integer`]s are taken by reference.
+    ///
+    /// $$
+    /// f(x, y) = \operatorname{lcm}(|x|, |y|).
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), other.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::Lcm;
+    /// use malachite_nz::integer::Integer;
+    ///
+    /// assert_eq!((&Integer::from(-3)).lcm(&Integer::from(5)), 15);
+    /// assert_eq!((&Integer::from(12)).lcm(&Integer::from(-90)), 180);
+    /// ```
+    #[inline]
+    fn lcm(self, other: &'b Integer) -> Natural {
+        self.unsigned_abs_ref().lcm(other.unsigned_abs_ref())
+    }
+}
+
+impl LcmAssign<Integer> for Integer {
+    /// Replaces an [`Integer`] by its LCM (least common multiple) with another [`Integer`]. The
+    /// result is non-negative. The [`Integer`] on the right-hand side is taken by value.
+    ///
+    /// $$
+    /// x \gets \operatorname{lcm}(|x|, |y|).
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), other.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::LcmAssign;
+    /// use malachite_nz::integer::Integer;
+    ///
+    /// let mut x = Integer::from(-3);
+    /// x.lcm_assign(Integer::from(5));
+    /// assert_eq!(x, 15);
+    ///
+    /// let mut x = Integer::from(12);
+    /// x.lcm_assign(Integer::from(-90));
+    /// assert_eq!(x, 180);
+    /// ```
+    #[inline]
+    fn lcm_assign(&mut self, other: Integer) {
+        *self = Integer::from(self.unsigned_abs_ref().lcm(other.unsigned_abs()));
+    }
+}
+
+impl<'a> LcmAssign<&'a Integer> for Integer {
+    /// Replaces an [`Integer`] by its LCM (least common multiple) with another [`Integer`]. The
+    /// result is non-negative. The [`Integer`] on the right-hand side is taken by reference.
+    ///
+    /// $$
+    /// x \gets \operatorname{lcm}(|x|, |y|).
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), other.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::LcmAssign;
+    /// use malachite_nz::integer::Integer;
+    ///
+    /// let mut x = Integer::from(-3);
+    /// x.lcm_assign(&Integer::from(5));
+    /// assert_eq!(x, 15);
+    ///
+    /// let mut x = Integer::from(12);
+    /// x.lcm_assign(&Integer::from(-90));
+    /// assert_eq!(x, 180);
+    /// ```
+    #[inline]
+    fn lcm_assign(&mut self, other: &'a Integer) {
+        *self = Integer::from(self.unsigned_abs_ref().lcm(other.unsigned_abs_ref()));
+    }
+}
@@ -0,0 +1,117 @@
+use crate::integer::Integer;
+use malachite_base::num::arithmetic::traits::{LucasSequence, Square};
+use malachite_base::num::basic::traits::{One, Zero};
+use malachite_base::num::logic::traits::BitIterable;
+
+// Uses the identities
+//
+// $$
+// U_{2k} = U_k(2U_{k+1} - PU_k), \quad U_{2k+1} = U_{k+1}^2 - QU_k^2,
+// \quad U_{2k+2} = PU_{2k+1} - QU_{2k},
+// $$
+//
+// to compute $(U_n, U_{n+1})$ one bit of $n$ at a time, and then recovers $V_n$ as
+// $2U_{n+1} - PU_n$.
+//
+// # Worst-case complexity
+// $T(n, m) = O(nm \log (nm) \log\log (nm))$
+//
+// $M(n, m) = O(nm \log (nm))$
+//
+// where $T$ is time, $M$ is additional memory, $n$ is `n`, and $m$ is
+// `max(p.significant_bits(), q.significant_bits())`.
+fn lucas_sequence(p: &Integer, q: &Integer, n: u64) -> (Integer, Integer) {
+    let mut u = Integer::ZERO;
+    let mut u_next = Integer::ONE;
+    for bit in n.bits().rev() {
+        let u_2k = &u * ((&u_next << 1) - p * &u);
+        let u_2k_plus_1 = u_next.square() - q * u.square();
+        if bit {
+            u_next = p * &u_2k_plus_1 - q * u_2k;
+            u = u_2k_plus_1;
+        } else {
+            u = u_2k;
+            u_next = u_2k_plus_1;
+        }
+    }
+    let v = (u_next << 1) - p * &u;
+    (u, v)
+}
+
+impl LucasSequence for Integer {
+    /// Computes the $n$th terms of the Lucas sequences with parameters $P$ and $Q$, taking both
+    /// [`Integer`]s by value.
+    ///
+    /// $f(P, Q, n) = (U_n(P, Q), V_n(P, Q))$, where $U_0 = 0$, $U_1 = 1$, $V_0 = 2$, $V_1 = P$,
+    /// and $X_{n+2} = PX_{n+1} - QX_n$.
+    ///
+    /// The Fibonacci and Lucas numbers are $U_n(1, -1)$ and $V_n(1, -1)$, and $U_n(2, 1) = n$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(nm \log (nm) \log\log (nm))$
+    ///
+    /// $M(n, m) = O(nm \log (nm))$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `n`, and $m$ is
+    /// `max(p.significant_bits(), q.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::LucasSequence;
+    /// use malachite_nz::integer::Integer;
+    ///
+    /// assert_eq!(
+    ///     Integer::lucas_sequence(Integer::from(1), Integer::from(-1), 10),
+    ///     (Integer::from(55), Integer::from(123))
+    /// );
+    /// assert_eq!(
+    ///     Integer::lucas_sequence(Integer::from(3), Integer::from(1), 10),
+    ///     (Integer::from(6765), Integer::from(15127))
+    /// );
+    /// assert_eq!(
+    ///     Integer::lucas_sequence(Integer::from(1), Integer::from(2), 20),
+    ///     (Integer::from(-627), Integer::from(1201))
+    /// );
+    /// ```
+    #[inline]
+    fn lucas_sequence(p: Integer, q: Integer, n: u64) -> (Integer, Integer) {
+        lucas_sequence(&p, &q, n)
+    }
+}
+
+impl<'a> LucasSequence<&'a Integer> for Integer {
+    /// Computes the $n$th terms of the Lucas sequences with parameters $P$ and $Q$, taking both
+    /// [`Integer`]s by reference.
+    ///
+    /// $f(P, Q, n) = (U_n(P, Q), V_n(P, Q))$, where $U_0 = 0$, $U_1 = 1$, $V_0 = 2$, $V_1 = P$,
+    /// and $X_{n+2} = PX_{n+1} - QX_n$.
+    ///
+    /// The Fibonacci and Lucas numbers are $U_n(1, -1)$ and $V_n(1, -1)$, and $U_n(2, 1) = n$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(nm \log (nm) \log\log (nm))$
+    ///
+    /// $M(n, m) = O(nm \log (nm))$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `n`, and $m$ is
+    /// `max(p.significant_bits(), q.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::LucasSequence;
+    /// use malachite_nz::integer::Integer;
+    ///
+    /// assert_eq!(
+    ///     Integer::lucas_sequence(&Integer::from(2), &Integer::from(-1), 10),
+    ///     (Integer::from(2378), Integer::from(6726))
+    /// );
+    /// assert_eq!(
+    ///     Integer::lucas_sequence(&Integer::from(2), &Integer::from(1), 12345),
+    ///     (Integer::from(12345), Integer::from(2))
+    /// );
+    /// ```
+    #[inline]
+    fn lucas_sequence(p: &'a Integer, q: &'a Integer, n: u64) -> (Integer, Integer) {
+        lucas_sequence(p, q, n)
+    }
+}
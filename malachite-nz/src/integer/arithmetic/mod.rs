@@ -11,6 +11,16 @@ pub mod add_mul;
 /// [`BinomialCoefficient`](malachite_base::num::arithmetic::traits::BinomialCoefficient), a trait
 /// for computing the binomial coefficient of two numbers.
 pub mod binomial_coefficient;
+/// Implementations of [`CheckedSub`](malachite_base::num::arithmetic::traits::CheckedSub), a trait
+/// for subtracting two numbers and checking whether the result is representable.
+pub mod checked_sub;
+/// Implementations of
+/// [`ChineseRemainder`](malachite_base::num::arithmetic::traits::ChineseRemainder), a trait for
+/// combining congruences using the Chinese remainder theorem.
+pub mod chinese_remainder;
+/// Implementations of [`CoprimeWith`](malachite_base::num::arithmetic::traits::CoprimeWith), a
+/// trait for determining whether two numbers are coprime.
+pub mod coprime_with;
 /// Division of [`Integer`](super::Integer)s.
 pub mod div;
 /// Implementations of [`DivExact`](malachite_base::num::arithmetic::traits::DivExact) and
@@ -47,14 +57,44 @@ pub mod eq_mod;
 /// Implementations of [`EqModPowerOf2`](malachite_base::num::arithmetic::traits::EqModPowerOf2),
 /// a trait for determining whether one number is equal to another modulo $2^k$.
 pub mod eq_mod_power_of_2;
+/// An implementation of [`EulerNumber`](malachite_base::num::arithmetic::traits::EulerNumber),
+/// a trait for computing Euler numbers.
+pub mod euler_number;
 /// Implementations of [`ExtendedGcd`](malachite_base::num::arithmetic::traits::ExtendedGcd), a
 /// trait for computing the extended GCD of two numbers.
 pub mod extended_gcd;
+/// Implementations of [`Gcd`](malachite_base::num::arithmetic::traits::Gcd) and
+/// [`GcdAssign`](malachite_base::num::arithmetic::traits::GcdAssign), traits for computing the GCD
+/// (greatest common divisor) of two numbers.
+pub mod gcd;
 /// Implementations of [`LegendreSymbol`](malachite_base::num::arithmetic::traits::LegendreSymbol),
 /// [`JacobiSymbol`](malachite_base::num::arithmetic::traits::JacobiSymbol), and
 /// [`KroneckerSymbol`](malachite_base::num::arithmetic::traits::KroneckerSymbol), traits for
 /// computing the Legendre, Jacobi, and Kronecker symbols of two numbers.
 pub mod kronecker_symbol;
+/// Implementations of [`Lcm`](malachite_base::num::arithmetic::traits::Lcm) and
+/// [`LcmAssign`](malachite_base::num::arithmetic::traits::LcmAssign), traits for computing the LCM
+/// (least common multiple) of two numbers.
+pub mod lcm;
+/// Implementations of [`LucasSequence`](malachite_base::num::arithmetic::traits::LucasSequence), a
+/// trait for computing the terms of the Lucas sequences $U_n(P, Q)$ and $V_n(P, Q)$.
+pub mod lucas_sequence;
+/// Implementations of [`ModAdd`](malachite_base::num::arithmetic::traits::ModAdd) and
+/// [`ModAddAssign`](malachite_base::num::arithmetic::traits::ModAddAssign), traits for adding two
+/// numbers modulo another number.
+pub mod mod_add;
+/// Implementations of
+/// [`ModInverse`](malachite_base::num::arithmetic::traits::ModInverse), a trait for finding the
+/// multiplicative inverse of a number modulo another number.
+pub mod mod_inverse;
+/// Implementations of [`ModMul`](malachite_base::num::arithmetic::traits::ModMul) and
+/// [`ModMulAssign`](malachite_base::num::arithmetic::traits::ModMulAssign), traits for multiplying
+/// two numbers modulo another number.
+pub mod mod_mul;
+/// Implementations of [`ModNeg`](malachite_base::num::arithmetic::traits::ModNeg) and
+/// [`ModNegAssign`](malachite_base::num::arithmetic::traits::ModNegAssign), traits for negating a
+/// number modulo another number.
+pub mod mod_neg;
 /// Implementations of traits for finding the remainder of two numbers, subject to various rounding
 /// rules.
 ///
@@ -67,6 +107,10 @@ pub mod kronecker_symbol;
 ///
 /// The [`Rem`](std::ops::Rem) trait in the standard library rounds towards 0.
 pub mod mod_op;
+/// Implementations of [`ModPow`](malachite_base::num::arithmetic::traits::ModPow) and
+/// [`ModPowAssign`](malachite_base::num::arithmetic::traits::ModPowAssign), traits for raising a
+/// number to a power modulo another number.
+pub mod mod_pow;
 /// Implementations of traits for finding the remainder of a number divided by $2^k$, subject to
 /// various rounding rules.
 ///
@@ -78,6 +122,165 @@ pub mod mod_op;
 /// | towards 0 | [`RemPowerOf2`](malachite_base::num::arithmetic::traits::RemPowerOf2) | [`RemPowerOf2Assign`](malachite_base::num::arithmetic::traits::RemPowerOf2Assign)       |
 /// | towards $\infty$  | [`CeilingModPowerOf2`](malachite_base::num::arithmetic::traits::CeilingModPowerOf2) | [`CeilingModPowerOf2Assign`](malachite_base::num::arithmetic::traits::CeilingModPowerOf2Assign) |
 pub mod mod_power_of_2;
+/// Implementations of [`ModPowerOf2Add`](malachite_base::num::arithmetic::traits::ModPowerOf2Add)
+/// and [`ModPowerOf2AddAssign`](malachite_base::num::arithmetic::traits::ModPowerOf2AddAssign),
+/// traits for adding two numbers modulo $2^k$.
+pub mod mod_power_of_2_add;
+/// Implementations of
+/// [`ModPowerOf2Inverse`](malachite_base::num::arithmetic::traits::ModPowerOf2Inverse), a trait
+/// for finding the multiplicative inverse of a number modulo $2^k$.
+pub mod mod_power_of_2_inverse;
+/// Implementations of [`ModPowerOf2Mul`](malachite_base::num::arithmetic::traits::ModPowerOf2Mul)
+/// and [`ModPowerOf2MulAssign`](malachite_base::num::arithmetic::traits::ModPowerOf2MulAssign),
+/// traits for multiplying two numbers modulo $2^k$.
+pub mod mod_power_of_2_mul;
+/// Implementations of [`ModPowerOf2Neg`](malachite_base::num::arithmetic::traits::ModPowerOf2Neg)
+/// and [`ModPowerOf2NegAssign`](malachite_base::num::arithmetic::traits::ModPowerOf2NegAssign),
+/// traits for negating a number modulo $2^k$.
+pub mod mod_power_of_2_neg;
+/// Implementations of [`ModPowerOf2Pow`](malachite_base::num::arithmetic::traits::ModPowerOf2Pow)
+/// and [`ModPowerOf2PowAssign`](malachite_base::num::arithmetic::traits::ModPowerOf2PowAssign),
+/// traits for raising a number to a power modulo $2^k$.
+pub mod mod_power_of_2_pow;
+/// Implementations of [`ModPowerOf2Shl`](malachite_base::num::arithmetic::traits::ModPowerOf2Shl)
+/// and [`ModPowerOf2ShlAssign`](malachite_base::num::arithmetic::traits::ModPowerOf2ShlAssign),
+/// traits for left-shifting a number modulo $2^k$.
+///
+/// # mod_power_of_2_shl
+/// ```
+/// use malachite_base::num::arithmetic::traits::ModPowerOf2Shl;
+/// use malachite_nz::integer::Integer;
+///
+/// assert_eq!(Integer::from(-3).mod_power_of_2_shl(2u16, 4), 4);
+/// assert_eq!(Integer::from(-12).mod_power_of_2_shl(-2i8, 4), 1);
+/// assert_eq!((&Integer::from(-3)).mod_power_of_2_shl(2u16, 4), 4);
+/// assert_eq!((&Integer::from(-12)).mod_power_of_2_shl(-2i8, 4), 1);
+/// ```
+///
+/// # mod_power_of_2_shl_assign
+/// ```
+/// use malachite_base::num::arithmetic::traits::ModPowerOf2ShlAssign;
+/// use malachite_nz::integer::Integer;
+///
+/// let mut x = Integer::from(-3);
+/// x.mod_power_of_2_shl_assign(2u16, 4);
+/// assert_eq!(x, 4);
+///
+/// let mut x = Integer::from(-12);
+/// x.mod_power_of_2_shl_assign(-2i8, 4);
+/// assert_eq!(x, 1);
+/// ```
+pub mod mod_power_of_2_shl;
+/// Implementations of [`ModPowerOf2Shr`](malachite_base::num::arithmetic::traits::ModPowerOf2Shr)
+/// and [`ModPowerOf2ShrAssign`](malachite_base::num::arithmetic::traits::ModPowerOf2ShrAssign),
+/// traits for right-shifting a number modulo $2^k$.
+///
+/// # mod_power_of_2_shr
+/// ```
+/// use malachite_base::num::arithmetic::traits::ModPowerOf2Shr;
+/// use malachite_nz::integer::Integer;
+///
+/// assert_eq!(Integer::from(-12).mod_power_of_2_shr(2i8, 4), 1);
+/// assert_eq!(Integer::from(-3).mod_power_of_2_shr(-2i16, 4), 4);
+/// assert_eq!((&Integer::from(-12)).mod_power_of_2_shr(2i8, 4), 1);
+/// assert_eq!((&Integer::from(-3)).mod_power_of_2_shr(-2i16, 4), 4);
+/// ```
+///
+/// # mod_power_of_2_shr_assign
+/// ```
+/// use malachite_base::num::arithmetic::traits::ModPowerOf2ShrAssign;
+/// use malachite_nz::integer::Integer;
+///
+/// let mut x = Integer::from(-12);
+/// x.mod_power_of_2_shr_assign(2i8, 4);
+/// assert_eq!(x, 1);
+///
+/// let mut x = Integer::from(-3);
+/// x.mod_power_of_2_shr_assign(-2i16, 4);
+/// assert_eq!(x, 4);
+/// ```
+pub mod mod_power_of_2_shr;
+/// Implementations of
+/// [`ModPowerOf2Square`](malachite_base::num::arithmetic::traits::ModPowerOf2Square) and
+/// [`ModPowerOf2SquareAssign`](malachite_base::num::arithmetic::traits::ModPowerOf2SquareAssign),
+/// traits for squaring a number modulo $2^k$.
+pub mod mod_power_of_2_square;
+/// Implementations of [`ModPowerOf2Sub`](malachite_base::num::arithmetic::traits::ModPowerOf2Sub)
+/// and [`ModPowerOf2SubAssign`](malachite_base::num::arithmetic::traits::ModPowerOf2SubAssign),
+/// traits for subtracting one number by another modulo $2^k$.
+pub mod mod_power_of_2_sub;
+/// Implementations of [`ModShl`](malachite_base::num::arithmetic::traits::ModShl) and
+/// [`ModShlAssign`](malachite_base::num::arithmetic::traits::ModShlAssign), traits for
+/// left-shifting a number modulo another number.
+///
+/// # mod_shl
+/// ```
+/// use malachite_base::num::arithmetic::traits::ModShl;
+/// use malachite_nz::integer::Integer;
+/// use malachite_nz::natural::Natural;
+///
+/// assert_eq!(Integer::from(-3).mod_shl(2u16, Natural::from(5u32)), 3);
+/// assert_eq!(Integer::from(-12).mod_shl(-2i8, Natural::from(10u32)), 2);
+/// assert_eq!(Integer::from(-3).mod_shl(2u16, &Natural::from(5u32)), 3);
+/// assert_eq!((&Integer::from(-3)).mod_shl(2u16, Natural::from(5u32)), 3);
+/// assert_eq!((&Integer::from(-12)).mod_shl(-2i8, &Natural::from(10u32)), 2);
+/// ```
+///
+/// # mod_shl_assign
+/// ```
+/// use malachite_base::num::arithmetic::traits::ModShlAssign;
+/// use malachite_nz::integer::Integer;
+/// use malachite_nz::natural::Natural;
+///
+/// let mut x = Integer::from(-3);
+/// x.mod_shl_assign(2u16, Natural::from(5u32));
+/// assert_eq!(x, 3);
+///
+/// let mut x = Integer::from(-12);
+/// x.mod_shl_assign(-2i8, &Natural::from(10u32));
+/// assert_eq!(x, 2);
+/// ```
+pub mod mod_shl;
+/// Implementations of [`ModShr`](malachite_base::num::arithmetic::traits::ModShr) and
+/// [`ModShrAssign`](malachite_base::num::arithmetic::traits::ModShrAssign), traits for
+/// right-shifting a number modulo another number.
+///
+/// # mod_shr
+/// ```
+/// use malachite_base::num::arithmetic::traits::ModShr;
+/// use malachite_nz::integer::Integer;
+/// use malachite_nz::natural::Natural;
+///
+/// assert_eq!(Integer::from(-12).mod_shr(2i8, Natural::from(10u32)), 2);
+/// assert_eq!(Integer::from(-3).mod_shr(-2i16, Natural::from(5u32)), 3);
+/// assert_eq!(Integer::from(-12).mod_shr(2i8, &Natural::from(10u32)), 2);
+/// assert_eq!((&Integer::from(-12)).mod_shr(2i8, Natural::from(10u32)), 2);
+/// assert_eq!((&Integer::from(-3)).mod_shr(-2i16, &Natural::from(5u32)), 3);
+/// ```
+///
+/// # mod_shr_assign
+/// ```
+/// use malachite_base::num::arithmetic::traits::ModShrAssign;
+/// use malachite_nz::integer::Integer;
+/// use malachite_nz::natural::Natural;
+///
+/// let mut x = Integer::from(-12);
+/// x.mod_shr_assign(2i8, Natural::from(10u32));
+/// assert_eq!(x, 2);
+///
+/// let mut x = Integer::from(-3);
+/// x.mod_shr_assign(-2i16, &Natural::from(5u32));
+/// assert_eq!(x, 3);
+/// ```
+pub mod mod_shr;
+/// Implementations of [`ModSquare`](malachite_base::num::arithmetic::traits::ModSquare) and
+/// [`ModSquareAssign`](malachite_base::num::arithmetic::traits::ModSquareAssign), traits for
+/// squaring a number modulo another number.
+pub mod mod_square;
+/// Implementations of [`ModSub`](malachite_base::num::arithmetic::traits::ModSub) and
+/// [`ModSubAssign`](malachite_base::num::arithmetic::traits::ModSubAssign), traits for subtracting
+/// two numbers modulo another number.
+pub mod mod_sub;
 /// Multiplication of [`Integer`](super::Integer)s.
 pub mod mul;
 /// Negation of an [`Integer`](super::Integer).
@@ -85,6 +288,11 @@ pub mod neg;
 /// Implementations of [`Parity`](malachite_base::num::arithmetic::traits::Parity), a trait for
 /// determining whether a number is even or odd.
 pub mod parity;
+/// Implementations of [`IsPerfectPower`](malachite_base::num::arithmetic::traits::IsPerfectPower)
+/// and
+/// [`PerfectPowerDecomposition`](malachite_base::num::arithmetic::traits::PerfectPowerDecomposition),
+/// traits for determining whether a number is a perfect power and for writing it as one.
+pub mod perfect_power;
 /// Implementations of [`Pow`](malachite_base::num::arithmetic::traits::Pow) and
 /// [`PowAssign`](malachite_base::num::arithmetic::traits::PowAssign), traits for raising a number
 /// to a power.
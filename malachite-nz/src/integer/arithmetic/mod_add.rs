@@ -0,0 +1,438 @@
+use crate::integer::Integer;
+use crate::natural::Natural;
+use malachite_base::num::arithmetic::traits::{ModAdd, ModAddAssign};
+
+impl ModAdd<Integer, Natural> for Integer {
+    type Output = Natural;
+
+    /// Adds two [`Integer`]s modulo a [`Natural`] $m$, returning a result in $[0, m)$. The
+    /// [`Integer`]s need not be reduced modulo $m$; in particular, they may be negative. All three
+    /// arguments are taken by value.
+    ///
+    /// $f(x, y, m) = z$, where $0 \leq z < m$ and $x + y \equiv z \mod m$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), other.significant_bits(), m.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `m` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::ModAdd;
+    /// use malachite_nz::integer::Integer;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!(Integer::from(-3).mod_add(Integer::from(10), Natural::from(5u32)), 2);
+    /// assert_eq!(Integer::from(-7).mod_add(Integer::from(-8), Natural::from(10u32)), 5);
+    /// ```
+    #[inline]
+    fn mod_add(self, other: Integer, m: Natural) -> Natural {
+        self.mod_natural(&m).mod_add(other.mod_natural(&m), m)
+    }
+}
+
+impl<'a> ModAdd<Integer, &'a Natural> for Integer {
+    type Output = Natural;
+
+    /// Adds two [`Integer`]s modulo a [`Natural`] $m$, returning a result in $[0, m)$. The
+    /// [`Integer`]s need not be reduced modulo $m$; in particular, they may be negative. The
+    /// [`Integer`]s are taken by value and the [`Natural`] by reference.
+    ///
+    /// $f(x, y, m) = z$, where $0 \leq z < m$ and $x + y \equiv z \mod m$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), other.significant_bits(), m.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `m` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::ModAdd;
+    /// use malachite_nz::integer::Integer;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!(Integer::from(-3).mod_add(Integer::from(10), &Natural::from(5u32)), 2);
+    /// assert_eq!(Integer::from(-7).mod_add(Integer::from(-8), &Natural::from(10u32)), 5);
+    /// ```
+    #[inline]
+    fn mod_add(self, other: Integer, m: &'a Natural) -> Natural {
+        self.mod_natural(m).mod_add(other.mod_natural(m), m)
+    }
+}
+
+impl<'a> ModAdd<&'a Integer, Natural> for Integer {
+    type Output = Natural;
+
+    /// Adds two [`Integer`]s modulo a [`Natural`] $m$, returning a result in $[0, m)$. The
+    /// [`Integer`]s need not be reduced modulo $m$; in particular, they may be negative. The first
+    /// [`Integer`] and the [`Natural`] are taken by value and the second [`Integer`] by reference.
+    ///
+    /// $f(x, y, m) = z$, where $0 \leq z < m$ and $x + y \equiv z \mod m$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), other.significant_bits(), m.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `m` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::ModAdd;
+    /// use malachite_nz::integer::Integer;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!(Integer::from(-3).mod_add(&Integer::from(10), Natural::from(5u32)), 2);
+    /// assert_eq!(Integer::from(-7).mod_add(&Integer::from(-8), Natural::from(10u32)), 5);
+    /// ```
+    #[inline]
+    fn mod_add(self, other: &'a Integer, m: Natural) -> Natural {
+        self.mod_natural(&m).mod_add(other.mod_natural_ref(&m), m)
+    }
+}
+
+impl<'a, 'b> ModAdd<&'a Integer, &'b Natural> for Integer {
+    type Output = Natural;
+
+    /// Adds two [`Integer`]s modulo a [`Natural`] $m$, returning a result in $[0, m)$. The
+    /// [`Integer`]s need not be reduced modulo $m$; in particular, they may be negative. The first
+    /// [`Integer`] is taken by value and the second [`Integer`] and the [`Natural`] by reference.
+    ///
+    /// $f(x, y, m) = z$, where $0 \leq z < m$ and $x + y \equiv z \mod m$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), other.significant_bits(), m.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `m` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::ModAdd;
+    /// use malachite_nz::integer::Integer;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!(Integer::from(-3).mod_add(&Integer::from(10), &Natural::from(5u32)), 2);
+    /// assert_eq!(Integer::from(-7).mod_add(&Integer::from(-8), &Natural::from(10u32)), 5);
+    /// ```
+    #[inline]
+    fn mod_add(self, other: &'a Integer, m: &'b Natural) -> Natural {
+        self.mod_natural(m).mod_add(other.mod_natural_ref(m), m)
+    }
+}
+
+impl<'a> ModAdd<Integer, Natural> for &'a Integer {
+    type Output = Natural;
+
+    /// Adds two [`Integer`]s modulo a [`Natural`] $m$, returning a result in $[0, m)$. The
+    /// [`Integer`]s need not be reduced modulo $m$; in particular, they may be negative. The first
+    /// [`Integer`] is taken by reference and the second [`Integer`] and the [`Natural`] by value.
+    ///
+    /// $f(x, y, m) = z$, where $0 \leq z < m$ and $x + y \equiv z \mod m$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), other.significant_bits(), m.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `m` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::ModAdd;
+    /// use malachite_nz::integer::Integer;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!((&Integer::from(-3)).mod_add(Integer::from(10), Natural::from(5u32)), 2);
+    /// assert_eq!((&Integer::from(-7)).mod_add(Integer::from(-8), Natural::from(10u32)), 5);
+    /// ```
+    #[inline]
+    fn mod_add(self, other: Integer, m: Natural) -> Natural {
+        self.mod_natural_ref(&m).mod_add(other.mod_natural(&m), m)
+    }
+}
+
+impl<'a, 'b> ModAdd<Integer, &'b Natural> for &'a Integer {
+    type Output = Natural;
+
+    /// Adds two [`Integer`]s modulo a [`Natural`] $m$, returning a result in $[0, m)$. The
+    /// [`Integer`]s need not be reduced modulo $m$; in particular, they may be negative. The first
+    /// [`Integer`] and the [`Natural`] are taken by reference and the second [`Integer`] by value.
+    ///
+    /// $f(x, y, m) = z$, where $0 \leq z < m$ and $x + y \equiv z \mod m$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), other.significant_bits(), m.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `m` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::ModAdd;
+    /// use malachite_nz::integer::Integer;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!((&Integer::from(-3)).mod_add(Integer::from(10), &Natural::from(5u32)), 2);
+    /// assert_eq!((&Integer::from(-7)).mod_add(Integer::from(-8), &Natural::from(10u32)), 5);
+    /// ```
+    #[inline]
+    fn mod_add(self, other: Integer, m: &'b Natural) -> Natural {
+        self.mod_natural_ref(m).mod_add(other.mod_natural(m), m)
+    }
+}
+
+impl<'a, 'b> ModAdd<&'b Integer, Natural> for &'a Integer {
+    type Output = Natural;
+
+    /// Adds two [`Integer`]s modulo a [`Natural`] $m$, returning a result in $[0, m)$. The
+    /// [`Integer`]s need not be reduced modulo $m$; in particular, they may be negative. The
+    /// [`Integer`]s are taken by reference and the [`Natural`] by value.
+    ///
+    /// $f(x, y, m) = z$, where $0 \leq z < m$ and $x + y \equiv z \mod m$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), other.significant_bits(), m.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `m` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::ModAdd;
+    /// use malachite_nz::integer::Integer;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!((&Integer::from(-3)).mod_add(&Integer::from(10), Natural::from(5u32)), 2);
+    /// assert_eq!((&Integer::from(-7)).mod_add(&Integer::from(-8), Natural::from(10u32)), 5);
+    /// ```
+    #[inline]
+    fn mod_add(self, other: &'b Integer, m: Natural) -> Natural {
+        self.mod_natural_ref(&m)
+            .mod_add(other.mod_natural_ref(&m), m)
+    }
+}
+
+impl<'a, 'b, 'c> ModAdd<&'b Integer, &'c Natural> for &'a Integer {
+    type Output = Natural;
+
+    /// Adds two [`Integer`]s modulo a [`Natural`] $m$, returning a result in $[0, m)$. The
+    /// [`Integer`]s need not be reduced modulo $m$; in particular, they may be negative. All three
+    /// arguments are taken by reference.
+    ///
+    /// $f(x, y, m) = z$, where $0 \leq z < m$ and $x + y \equiv z \mod m$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), other.significant_bits(), m.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `m` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::ModAdd;
+    /// use malachite_nz::integer::Integer;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!((&Integer::from(-3)).mod_add(&Integer::from(10), &Natural::from(5u32)), 2);
+    /// assert_eq!((&Integer::from(-7)).mod_add(&Integer::from(-8), &Natural::from(10u32)), 5);
+    /// ```
+    #[inline]
+    fn mod_add(self, other: &'b Integer, m: &'c Natural) -> Natural {
+        self.mod_natural_ref(m).mod_add(other.mod_natural_ref(m), m)
+    }
+}
+
+impl ModAddAssign<Integer, Natural> for Integer {
+    /// Adds two [`Integer`]s modulo a [`Natural`] $m$, in place. The [`Integer`]s need not be
+    /// reduced modulo $m$; in particular, they may be negative, but the result is always in $[0,
+    /// m)$. The [`Integer`] and [`Natural`] on the right-hand side are both taken by value.
+    ///
+    /// $x \gets z$, where $0 \leq z < m$ and $x + y \equiv z \mod m$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), other.significant_bits(), m.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `m` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::ModAddAssign;
+    /// use malachite_nz::integer::Integer;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// let mut x = Integer::from(-3);
+    /// x.mod_add_assign(Integer::from(10), Natural::from(5u32));
+    /// assert_eq!(x, 2);
+    ///
+    /// let mut x = Integer::from(-7);
+    /// x.mod_add_assign(Integer::from(-8), Natural::from(10u32));
+    /// assert_eq!(x, 5);
+    /// ```
+    #[inline]
+    fn mod_add_assign(&mut self, other: Integer, m: Natural) {
+        *self = Integer::from((&*self).mod_add(other, m));
+    }
+}
+
+impl<'a> ModAddAssign<Integer, &'a Natural> for Integer {
+    /// Adds two [`Integer`]s modulo a [`Natural`] $m$, in place. The [`Integer`]s need not be
+    /// reduced modulo $m$; in particular, they may be negative, but the result is always in $[0,
+    /// m)$. The [`Integer`] on the right-hand side is taken by value and the [`Natural`] by
+    /// reference.
+    ///
+    /// $x \gets z$, where $0 \leq z < m$ and $x + y \equiv z \mod m$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), other.significant_bits(), m.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `m` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::ModAddAssign;
+    /// use malachite_nz::integer::Integer;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// let mut x = Integer::from(-3);
+    /// x.mod_add_assign(Integer::from(10), &Natural::from(5u32));
+    /// assert_eq!(x, 2);
+    ///
+    /// let mut x = Integer::from(-7);
+    /// x.mod_add_assign(Integer::from(-8), &Natural::from(10u32));
+    /// assert_eq!(x, 5);
+    /// ```
+    #[inline]
+    fn mod_add_assign(&mut self, other: Integer, m: &'a Natural) {
+        *self = Integer::from((&*self).mod_add(other, m));
+    }
+}
+
+impl<'a> ModAddAssign<&'a Integer, Natural> for Integer {
+    /// Adds two [`Integer`]s modulo a [`Natural`] $m$, in place. The [`Integer`]s need not be
+    /// reduced modulo $m$; in particular, they may be negative, but the result is always in $[0,
+    /// m)$. The [`Integer`] on the right-hand side is taken by reference and the [`Natural`] by
+    /// value.
+    ///
+    /// $x \gets z$, where $0 \leq z < m$ and $x + y \equiv z \mod m$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), other.significant_bits(), m.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `m` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::ModAddAssign;
+    /// use malachite_nz::integer::Integer;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// let mut x = Integer::from(-3);
+    /// x.mod_add_assign(&Integer::from(10), Natural::from(5u32));
+    /// assert_eq!(x, 2);
+    ///
+    /// let mut x = Integer::from(-7);
+    /// x.mod_add_assign(&Integer::from(-8), Natural::from(10u32));
+    /// assert_eq!(x, 5);
+    /// ```
+    #[inline]
+    fn mod_add_assign(&mut self, other: &'a Integer, m: Natural) {
+        *self = Integer::from((&*self).mod_add(other, m));
+    }
+}
+
+impl<'a, 'b> ModAddAssign<&'a Integer, &'b Natural> for Integer {
+    /// Adds two [`Integer`]s modulo a [`Natural`] $m$, in place. The [`Integer`]s need not be
+    /// reduced modulo $m$; in particular, they may be negative, but the result is always in $[0,
+    /// m)$. The [`Integer`] and [`Natural`] on the right-hand side are both taken by reference.
+    ///
+    /// $x \gets z$, where $0 \leq z < m$ and $x + y \equiv z \mod m$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), other.significant_bits(), m.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `m` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::ModAddAssign;
+    /// use malachite_nz::integer::Integer;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// let mut x = Integer::from(-3);
+    /// x.mod_add_assign(&Integer::from(10), &Natural::from(5u32));
+    /// assert_eq!(x, 2);
+    ///
+    /// let mut x = Integer::from(-7);
+    /// x.mod_add_assign(&Integer::from(-8), &Natural::from(10u32));
+    /// assert_eq!(x, 5);
+    /// ```
+    #[inline]
+    fn mod_add_assign(&mut self, other: &'a Integer, m: &'b Natural) {
+        *self = Integer::from((&*self).mod_add(other, m));
+    }
+}
@@ -0,0 +1,171 @@
+use crate::integer::Integer;
+use crate::natural::Natural;
+use malachite_base::num::arithmetic::traits::ModInverse;
+use malachite_base::num::basic::traits::Zero;
+
+// Inverts a residue in $[0, m)$. Zero is invertible only modulo 1.
+pub(crate) fn mod_inverse_helper(x: Natural, m: &Natural) -> Option<Natural> {
+    if x == 0u32 {
+        if *m == 1u32 {
+            Some(Natural::ZERO)
+        } else {
+            None
+        }
+    } else {
+        x.mod_inverse(m)
+    }
+}
+
+impl ModInverse<Natural> for Integer {
+    type Output = Natural;
+
+    /// Computes the multiplicative inverse of an [`Integer`] modulo a [`Natural`] $m$, returning a
+    /// result in $[0, m)$. The [`Integer`] need not be reduced modulo $m$; in particular, it may be
+    /// negative. The [`Integer`] and the [`Natural`] are both taken by value.
+    ///
+    /// Returns `None` if $x$ and $m$ are not coprime.
+    ///
+    /// $f(x, m) = y$, where $0 \leq y < m$ and $xy \equiv 1 \mod m$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), m.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `m` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::ModInverse;
+    /// use malachite_nz::integer::Integer;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!(Integer::from(-3).mod_inverse(Natural::from(10u32)), Some(Natural::from(3u32)));
+    /// assert_eq!(Integer::from(-4).mod_inverse(Natural::from(10u32)), None);
+    /// ```
+    #[inline]
+    fn mod_inverse(self, m: Natural) -> Option<Natural> {
+        mod_inverse_helper(self.mod_natural(&m), &m)
+    }
+}
+
+impl<'a> ModInverse<&'a Natural> for Integer {
+    type Output = Natural;
+
+    /// Computes the multiplicative inverse of an [`Integer`] modulo a [`Natural`] $m$, returning a
+    /// result in $[0, m)$. The [`Integer`] need not be reduced modulo $m$; in particular, it may be
+    /// negative. The [`Integer`] is taken by value and the [`Natural`] by reference.
+    ///
+    /// Returns `None` if $x$ and $m$ are not coprime.
+    ///
+    /// $f(x, m) = y$, where $0 \leq y < m$ and $xy \equiv 1 \mod m$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), m.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `m` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::ModInverse;
+    /// use malachite_nz::integer::Integer;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!(Integer::from(-3).mod_inverse(&Natural::from(10u32)), Some(Natural::from(3u32)));
+    /// assert_eq!(Integer::from(-4).mod_inverse(&Natural::from(10u32)), None);
+    /// ```
+    #[inline]
+    fn mod_inverse(self, m: &'a Natural) -> Option<Natural> {
+        mod_inverse_helper(self.mod_natural(m), m)
+    }
+}
+
+impl<'a> ModInverse<Natural> for &'a Integer {
+    type Output = Natural;
+
+    /// Computes the multiplicative inverse of an [`Integer`] modulo a [`Natural`] $m$, returning a
+    /// result in $[0, m)$. The [`Integer`] need not be reduced modulo $m$; in particular, it may be
+    /// negative. The [`Integer`] is taken by reference and the [`Natural`] by value.
+    ///
+    /// Returns `None` if $x$ and $m$ are not coprime.
+    ///
+    /// $f(x, m) = y$, where $0 \leq y < m$ and $xy \equiv 1 \mod m$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), m.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `m` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::ModInverse;
+    /// use malachite_nz::integer::Integer;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!(
+    ///     (&Integer::from(-3)).mod_inverse(Natural::from(10u32)),
+    ///     Some(Natural::from(3u32))
+    /// );
+    /// assert_eq!((&Integer::from(-4)).mod_inverse(Natural::from(10u32)), None);
+    /// ```
+    #[inline]
+    fn mod_inverse(self, m: Natural) -> Option<Natural> {
+        mod_inverse_helper(self.mod_natural_ref(&m), &m)
+    }
+}
+
+impl<'a, 'b> ModInverse<&'b Natural> for &'a Integer {
+    type Output = Natural;
+
+    /// Computes the multiplicative inverse of an [`Integer`] modulo a [`Natural`] $m$, returning a
+    /// result in $[0, m)$. The [`Integer`] need not be reduced modulo $m$; in particular, it may be
+    /// negative. The [`Integer`] and the [`Natural`] are both taken by reference.
+    ///
+    /// Returns `None` if $x$ and $m$ are not coprime.
+    ///
+    /// $f(x, m) = y$, where $0 \leq y < m$ and $xy \equiv 1 \mod m$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), m.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `m` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::ModInverse;
+    /// use malachite_nz::integer::Integer;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!(
+    ///     (&Integer::from(-3)).mod_inverse(&Natural::from(10u32)),
+    ///     Some(Natural::from(3u32))
+    /// );
+    /// assert_eq!((&Integer::from(-4)).mod_inverse(&Natural::from(10u32)), None);
+    /// ```
+    #[inline]
+    fn mod_inverse(self, m: &'b Natural) -> Option<Natural> {
+        mod_inverse_helper(self.mod_natural_ref(m), m)
+    }
+}
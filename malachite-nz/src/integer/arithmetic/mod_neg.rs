@@ -0,0 +1,219 @@
+use crate::integer::Integer;
+use crate::natural::Natural;
+use malachite_base::num::arithmetic::traits::{ModNeg, ModNegAssign};
+
+impl ModNeg<Natural> for Integer {
+    type Output = Natural;
+
+    /// Negates an [`Integer`] modulo a [`Natural`] $m$, returning a result in $[0, m)$. The
+    /// [`Integer`] need not be reduced modulo $m$; in particular, it may be negative. The
+    /// [`Integer`] and the [`Natural`] are both taken by value.
+    ///
+    /// $f(x, m) = y$, where $0 \leq y < m$ and $-x \equiv y \mod m$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), m.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `m` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::ModNeg;
+    /// use malachite_nz::integer::Integer;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!(Integer::from(-3).mod_neg(Natural::from(5u32)), 3);
+    /// assert_eq!(Integer::from(12).mod_neg(Natural::from(10u32)), 8);
+    /// ```
+    #[inline]
+    fn mod_neg(self, m: Natural) -> Natural {
+        self.mod_natural(&m).mod_neg(m)
+    }
+}
+
+impl<'a> ModNeg<&'a Natural> for Integer {
+    type Output = Natural;
+
+    /// Negates an [`Integer`] modulo a [`Natural`] $m$, returning a result in $[0, m)$. The
+    /// [`Integer`] need not be reduced modulo $m$; in particular, it may be negative. The
+    /// [`Integer`] is taken by value and the [`Natural`] by reference.
+    ///
+    /// $f(x, m) = y$, where $0 \leq y < m$ and $-x \equiv y \mod m$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), m.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `m` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::ModNeg;
+    /// use malachite_nz::integer::Integer;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!(Integer::from(-3).mod_neg(&Natural::from(5u32)), 3);
+    /// assert_eq!(Integer::from(12).mod_neg(&Natural::from(10u32)), 8);
+    /// ```
+    #[inline]
+    fn mod_neg(self, m: &'a Natural) -> Natural {
+        self.mod_natural(m).mod_neg(m)
+    }
+}
+
+impl<'a> ModNeg<Natural> for &'a Integer {
+    type Output = Natural;
+
+    /// Negates an [`Integer`] modulo a [`Natural`] $m$, returning a result in $[0, m)$. The
+    /// [`Integer`] need not be reduced modulo $m$; in particular, it may be negative. The
+    /// [`Integer`] is taken by reference and the [`Natural`] by value.
+    ///
+    /// $f(x, m) = y$, where $0 \leq y < m$ and $-x \equiv y \mod m$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), m.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `m` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::ModNeg;
+    /// use malachite_nz::integer::Integer;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!((&Integer::from(-3)).mod_neg(Natural::from(5u32)), 3);
+    /// assert_eq!((&Integer::from(12)).mod_neg(Natural::from(10u32)), 8);
+    /// ```
+    #[inline]
+    fn mod_neg(self, m: Natural) -> Natural {
+        self.mod_natural_ref(&m).mod_neg(m)
+    }
+}
+
+impl<'a, 'b> ModNeg<&'b Natural> for &'a Integer {
+    type Output = Natural;
+
+    /// Negates an [`Integer`] modulo a [`Natural`] $m$, returning a result in $[0, m)$. The
+    /// [`Integer`] need not be reduced modulo $m$; in particular, it may be negative. The
+    /// [`Integer`] and the [`Natural`] are both taken by reference.
+    ///
+    /// $f(x, m) = y$, where $0 \leq y < m$ and $-x \equiv y \mod m$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), m.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `m` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::ModNeg;
+    /// use malachite_nz::integer::Integer;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!((&Integer::from(-3)).mod_neg(&Natural::from(5u32)), 3);
+    /// assert_eq!((&Integer::from(12)).mod_neg(&Natural::from(10u32)), 8);
+    /// ```
+    #[inline]
+    fn mod_neg(self, m: &'b Natural) -> Natural {
+        self.mod_natural_ref(m).mod_neg(m)
+    }
+}
+
+impl ModNegAssign<Natural> for Integer {
+    /// Negates an [`Integer`] modulo a [`Natural`] $m$, in place. The [`Integer`] need not be
+    /// reduced modulo $m$; in particular, it may be negative, but the result is always in $[0, m)$.
+    /// The [`Natural`] on the right-hand side is taken by value.
+    ///
+    /// $x \gets y$, where $0 \leq y < m$ and $-x \equiv y \mod m$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), m.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `m` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::ModNegAssign;
+    /// use malachite_nz::integer::Integer;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// let mut x = Integer::from(-3);
+    /// x.mod_neg_assign(Natural::from(5u32));
+    /// assert_eq!(x, 3);
+    ///
+    /// let mut x = Integer::from(12);
+    /// x.mod_neg_assign(Natural::from(10u32));
+    /// assert_eq!(x, 8);
+    /// ```
+    #[inline]
+    fn mod_neg_assign(&mut self, m: Natural) {
+        *self = Integer::from((&*self).mod_neg(m));
+    }
+}
+
+impl<'a> ModNegAssign<&'a Natural> for Integer {
+    /// Negates an [`Integer`] modulo a [`Natural`] $m$, in place. The [`Integer`] need not be
+    /// reduced modulo $m$; in particular, it may be negative, but the result is always in $[0, m)$.
+    /// The [`Natural`] on the right-hand side is taken by reference.
+    ///
+    /// $x \gets y$, where $0 \leq y < m$ and $-x \equiv y \mod m$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), m.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `m` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::ModNegAssign;
+    /// use malachite_nz::integer::Integer;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// let mut x = Integer::from(-3);
+    /// x.mod_neg_assign(&Natural::from(5u32));
+    /// assert_eq!(x, 3);
+    ///
+    /// let mut x = Integer::from(12);
+    /// x.mod_neg_assign(&Natural::from(10u32));
+    /// assert_eq!(x, 8);
+    /// ```
+    #[inline]
+    fn mod_neg_assign(&mut self, m: &'a Natural) {
+        *self = Integer::from((&*self).mod_neg(m));
+    }
+}
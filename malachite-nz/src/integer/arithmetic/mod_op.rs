@@ -1,4 +1,5 @@
 use crate::integer::Integer;
+use crate::natural::Natural;
 use malachite_base::num::arithmetic::traits::{
     CeilingMod, CeilingModAssign, Mod, ModAssign, NegMod, NegModAssign,
 };
@@ -935,3 +936,22 @@ impl<'a> CeilingModAssign<&'a Integer> for Integer {
         self.sign = !other.sign || self.abs == 0;
     }
 }
+
+impl Integer {
+    // Reduces an `Integer` modulo a nonzero `Natural` $m$, returning a `Natural` in $[0, m)$.
+    pub(crate) fn mod_natural(self, m: &Natural) -> Natural {
+        if self.sign {
+            self.abs % m
+        } else {
+            self.abs.neg_mod(m)
+        }
+    }
+
+    pub(crate) fn mod_natural_ref(&self, m: &Natural) -> Natural {
+        if self.sign {
+            &self.abs % m
+        } else {
+            (&self.abs).neg_mod(m)
+        }
+    }
+}
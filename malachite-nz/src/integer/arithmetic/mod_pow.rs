@@ -0,0 +1,486 @@
+use crate::integer::arithmetic::mod_inverse::mod_inverse_helper;
+use crate::integer::Integer;
+use crate::natural::Natural;
+use malachite_base::num::arithmetic::traits::{ModPow, ModPowAssign};
+
+fn mod_pow_helper(x: Natural, exp: &Integer, m: &Natural) -> Natural {
+    if exp.sign {
+        x.mod_pow(&exp.abs, m)
+    } else if let Some(inverse) = mod_inverse_helper(x, m) {
+        inverse.mod_pow(&exp.abs, m)
+    } else {
+        panic!("Cannot raise a non-invertible residue to a negative power");
+    }
+}
+
+impl ModPow<Integer, Natural> for Integer {
+    type Output = Natural;
+
+    /// Raises an [`Integer`] to an [`Integer`] power modulo a [`Natural`] $m$, returning a result
+    /// in $[0, m)$. The base need not be reduced modulo $m$; in particular, it may be negative. A
+    /// negative exponent raises the inverse of the base modulo $m$ to the absolute value of the
+    /// exponent. All three arguments are taken by value.
+    ///
+    /// $f(x, n, m) = y$, where $0 \leq y < m$ and $x^n \equiv y \mod m$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(mn \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is
+    /// `max(self.significant_bits(), m.significant_bits())`, and $m$ is `exp.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `m` is zero, or if `exp` is negative and `self` is not coprime to `m`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::ModPow;
+    /// use malachite_nz::integer::Integer;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!(Integer::from(-2).mod_pow(Integer::from(3), Natural::from(5u32)), 2);
+    /// assert_eq!(Integer::from(3).mod_pow(Integer::from(-1), Natural::from(10u32)), 7);
+    /// assert_eq!(Integer::from(-3).mod_pow(Integer::from(-2), Natural::from(10u32)), 9);
+    /// ```
+    #[inline]
+    fn mod_pow(self, exp: Integer, m: Natural) -> Natural {
+        mod_pow_helper(self.mod_natural(&m), &exp, &m)
+    }
+}
+
+impl<'a> ModPow<Integer, &'a Natural> for Integer {
+    type Output = Natural;
+
+    /// Raises an [`Integer`] to an [`Integer`] power modulo a [`Natural`] $m$, returning a result
+    /// in $[0, m)$. The base need not be reduced modulo $m$; in particular, it may be negative. A
+    /// negative exponent raises the inverse of the base modulo $m$ to the absolute value of the
+    /// exponent. The [`Integer`]s are taken by value and the [`Natural`] by reference.
+    ///
+    /// $f(x, n, m) = y$, where $0 \leq y < m$ and $x^n \equiv y \mod m$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(mn \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is
+    /// `max(self.significant_bits(), m.significant_bits())`, and $m$ is `exp.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `m` is zero, or if `exp` is negative and `self` is not coprime to `m`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::ModPow;
+    /// use malachite_nz::integer::Integer;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!(Integer::from(-2).mod_pow(Integer::from(3), &Natural::from(5u32)), 2);
+    /// assert_eq!(Integer::from(3).mod_pow(Integer::from(-1), &Natural::from(10u32)), 7);
+    /// assert_eq!(Integer::from(-3).mod_pow(Integer::from(-2), &Natural::from(10u32)), 9);
+    /// ```
+    #[inline]
+    fn mod_pow(self, exp: Integer, m: &'a Natural) -> Natural {
+        mod_pow_helper(self.mod_natural(m), &exp, m)
+    }
+}
+
+impl<'a> ModPow<&'a Integer, Natural> for Integer {
+    type Output = Natural;
+
+    /// Raises an [`Integer`] to an [`Integer`] power modulo a [`Natural`] $m$, returning a result
+    /// in $[0, m)$. The base need not be reduced modulo $m$; in particular, it may be negative. A
+    /// negative exponent raises the inverse of the base modulo $m$ to the absolute value of the
+    /// exponent. The first [`Integer`] and the [`Natural`] are taken by value and the second
+    /// [`Integer`] by reference.
+    ///
+    /// $f(x, n, m) = y$, where $0 \leq y < m$ and $x^n \equiv y \mod m$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(mn \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is
+    /// `max(self.significant_bits(), m.significant_bits())`, and $m$ is `exp.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `m` is zero, or if `exp` is negative and `self` is not coprime to `m`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::ModPow;
+    /// use malachite_nz::integer::Integer;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!(Integer::from(-2).mod_pow(&Integer::from(3), Natural::from(5u32)), 2);
+    /// assert_eq!(Integer::from(3).mod_pow(&Integer::from(-1), Natural::from(10u32)), 7);
+    /// assert_eq!(Integer::from(-3).mod_pow(&Integer::from(-2), Natural::from(10u32)), 9);
+    /// ```
+    #[inline]
+    fn mod_pow(self, exp: &'a Integer, m: Natural) -> Natural {
+        mod_pow_helper(self.mod_natural(&m), exp, &m)
+    }
+}
+
+impl<'a, 'b> ModPow<&'a Integer, &'b Natural> for Integer {
+    type Output = Natural;
+
+    /// Raises an [`Integer`] to an [`Integer`] power modulo a [`Natural`] $m$, returning a result
+    /// in $[0, m)$. The base need not be reduced modulo $m$; in particular, it may be negative. A
+    /// negative exponent raises the inverse of the base modulo $m$ to the absolute value of the
+    /// exponent. The first [`Integer`] is taken by value and the second [`Integer`] and the
+    /// [`Natural`] by reference.
+    ///
+    /// $f(x, n, m) = y$, where $0 \leq y < m$ and $x^n \equiv y \mod m$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(mn \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is
+    /// `max(self.significant_bits(), m.significant_bits())`, and $m$ is `exp.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `m` is zero, or if `exp` is negative and `self` is not coprime to `m`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::ModPow;
+    /// use malachite_nz::integer::Integer;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!(Integer::from(-2).mod_pow(&Integer::from(3), &Natural::from(5u32)), 2);
+    /// assert_eq!(Integer::from(3).mod_pow(&Integer::from(-1), &Natural::from(10u32)), 7);
+    /// assert_eq!(Integer::from(-3).mod_pow(&Integer::from(-2), &Natural::from(10u32)), 9);
+    /// ```
+    #[inline]
+    fn mod_pow(self, exp: &'a Integer, m: &'b Natural) -> Natural {
+        mod_pow_helper(self.mod_natural(m), exp, m)
+    }
+}
+
+impl<'a> ModPow<Integer, Natural> for &'a Integer {
+    type Output = Natural;
+
+    /// Raises an [`Integer`] to an [`Integer`] power modulo a [`Natural`] $m$, returning a result
+    /// in $[0, m)$. The base need not be reduced modulo $m$; in particular, it may be negative. A
+    /// negative exponent raises the inverse of the base modulo $m$ to the absolute value of the
+    /// exponent. The first [`Integer`] is taken by reference and the second [`Integer`] and the
+    /// [`Natural`] by value.
+    ///
+    /// $f(x, n, m) = y$, where $0 \leq y < m$ and $x^n \equiv y \mod m$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(mn \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is
+    /// `max(self.significant_bits(), m.significant_bits())`, and $m$ is `exp.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `m` is zero, or if `exp` is negative and `self` is not coprime to `m`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::ModPow;
+    /// use malachite_nz::integer::Integer;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!((&Integer::from(-2)).mod_pow(Integer::from(3), Natural::from(5u32)), 2);
+    /// assert_eq!((&Integer::from(3)).mod_pow(Integer::from(-1), Natural::from(10u32)), 7);
+    /// assert_eq!((&Integer::from(-3)).mod_pow(Integer::from(-2), Natural::from(10u32)), 9);
+    /// ```
+    #[inline]
+    fn mod_pow(self, exp: Integer, m: Natural) -> Natural {
+        mod_pow_helper(self.mod_natural_ref(&m), &exp, &m)
+    }
+}
+
+impl<'a, 'b> ModPow<Integer, &'b Natural> for &'a Integer {
+    type Output = Natural;
+
+    /// Raises an [`Integer`] to an [`Integer`] power modulo a [`Natural`] $m$, returning a result
+    /// in $[0, m)$. The base need not be reduced modulo $m$; in particular, it may be negative. A
+    /// negative exponent raises the inverse of the base modulo $m$ to the absolute value of the
+    /// exponent. The first [`Integer`] and the [`Natural`] are taken by reference and the second
+    /// [`Integer`] by value.
+    ///
+    /// $f(x, n, m) = y$, where $0 \leq y < m$ and $x^n \equiv y \mod m$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(mn \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is
+    /// `max(self.significant_bits(), m.significant_bits())`, and $m$ is `exp.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `m` is zero, or if `exp` is negative and `self` is not coprime to `m`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::ModPow;
+    /// use malachite_nz::integer::Integer;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!((&Integer::from(-2)).mod_pow(Integer::from(3), &Natural::from(5u32)), 2);
+    /// assert_eq!((&Integer::from(3)).mod_pow(Integer::from(-1), &Natural::from(10u32)), 7);
+    /// assert_eq!((&Integer::from(-3)).mod_pow(Integer::from(-2), &Natural::from(10u32)), 9);
+    /// ```
+    #[inline]
+    fn mod_pow(self, exp: Integer, m: &'b Natural) -> Natural {
+        mod_pow_helper(self.mod_natural_ref(m), &exp, m)
+    }
+}
+
+impl<'a, 'b> ModPow<&'b Integer, Natural> for &'a Integer {
+    type Output = Natural;
+
+    /// Raises an [`Integer`] to an [`Integer`] power modulo a [`Natural`] $m$, returning a result
+    /// in $[0, m)$. The base need not be reduced modulo $m$; in particular, it may be negative. A
+    /// negative exponent raises the inverse of the base modulo $m$ to the absolute value of the
+    /// exponent. The [`Integer`]s are taken by reference and the [`Natural`] by value.
+    ///
+    /// $f(x, n, m) = y$, where $0 \leq y < m$ and $x^n \equiv y \mod m$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(mn \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is
+    /// `max(self.significant_bits(), m.significant_bits())`, and $m$ is `exp.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `m` is zero, or if `exp` is negative and `self` is not coprime to `m`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::ModPow;
+    /// use malachite_nz::integer::Integer;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!((&Integer::from(-2)).mod_pow(&Integer::from(3), Natural::from(5u32)), 2);
+    /// assert_eq!((&Integer::from(3)).mod_pow(&Integer::from(-1), Natural::from(10u32)), 7);
+    /// assert_eq!((&Integer::from(-3)).mod_pow(&Integer::from(-2), Natural::from(10u32)), 9);
+    /// ```
+    #[inline]
+    fn mod_pow(self, exp: &'b Integer, m: Natural) -> Natural {
+        mod_pow_helper(self.mod_natural_ref(&m), exp, &m)
+    }
+}
+
+impl<'a, 'b, 'c> ModPow<&'b Integer, &'c Natural> for &'a Integer {
+    type Output = Natural;
+
+    /// Raises an [`Integer`] to an [`Integer`] power modulo a [`Natural`] $m$, returning a result
+    /// in $[0, m)$. The base need not be reduced modulo $m$; in particular, it may be negative. A
+    /// negative exponent raises the inverse of the base modulo $m$ to the absolute value of the
+    /// exponent. All three arguments are taken by reference.
+    ///
+    /// $f(x, n, m) = y$, where $0 \leq y < m$ and $x^n \equiv y \mod m$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(mn \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is
+    /// `max(self.significant_bits(), m.significant_bits())`, and $m$ is `exp.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `m` is zero, or if `exp` is negative and `self` is not coprime to `m`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::ModPow;
+    /// use malachite_nz::integer::Integer;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!((&Integer::from(-2)).mod_pow(&Integer::from(3), &Natural::from(5u32)), 2);
+    /// assert_eq!((&Integer::from(3)).mod_pow(&Integer::from(-1), &Natural::from(10u32)), 7);
+    /// assert_eq!((&Integer::from(-3)).mod_pow(&Integer::from(-2), &Natural::from(10u32)), 9);
+    /// ```
+    #[inline]
+    fn mod_pow(self, exp: &'b Integer, m: &'c Natural) -> Natural {
+        mod_pow_helper(self.mod_natural_ref(m), exp, m)
+    }
+}
+
+impl ModPowAssign<Integer, Natural> for Integer {
+    /// Raises an [`Integer`] to an [`Integer`] power modulo a [`Natural`] $m$, in place. The base
+    /// need not be reduced modulo $m$; in particular, it may be negative. A negative exponent
+    /// raises the inverse of the base modulo $m$ to the absolute value of the exponent. The
+    /// [`Integer`] and [`Natural`] on the right-hand side are both taken by value.
+    ///
+    /// $x \gets y$, where $0 \leq y < m$ and $x^n \equiv y \mod m$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(mn \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is
+    /// `max(self.significant_bits(), m.significant_bits())`, and $m$ is `exp.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `m` is zero, or if `exp` is negative and `self` is not coprime to `m`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::ModPowAssign;
+    /// use malachite_nz::integer::Integer;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// let mut x = Integer::from(-2);
+    /// x.mod_pow_assign(Integer::from(3), Natural::from(5u32));
+    /// assert_eq!(x, 2);
+    ///
+    /// let mut x = Integer::from(3);
+    /// x.mod_pow_assign(Integer::from(-1), Natural::from(10u32));
+    /// assert_eq!(x, 7);
+    ///
+    /// let mut x = Integer::from(-3);
+    /// x.mod_pow_assign(Integer::from(-2), Natural::from(10u32));
+    /// assert_eq!(x, 9);
+    /// ```
+    #[inline]
+    fn mod_pow_assign(&mut self, exp: Integer, m: Natural) {
+        *self = Integer::from((&*self).mod_pow(exp, m));
+    }
+}
+
+impl<'a> ModPowAssign<Integer, &'a Natural> for Integer {
+    /// Raises an [`Integer`] to an [`Integer`] power modulo a [`Natural`] $m$, in place. The base
+    /// need not be reduced modulo $m$; in particular, it may be negative. A negative exponent
+    /// raises the inverse of the base modulo $m$ to the absolute value of the exponent. The
+    /// [`Integer`] on the right-hand side is taken by value and the [`Natural`] by reference.
+    ///
+    /// $x \gets y$, where $0 \leq y < m$ and $x^n \equiv y \mod m$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(mn \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is
+    /// `max(self.significant_bits(), m.significant_bits())`, and $m$ is `exp.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `m` is zero, or if `exp` is negative and `self` is not coprime to `m`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::ModPowAssign;
+    /// use malachite_nz::integer::Integer;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// let mut x = Integer::from(-2);
+    /// x.mod_pow_assign(Integer::from(3), &Natural::from(5u32));
+    /// assert_eq!(x, 2);
+    ///
+    /// let mut x = Integer::from(3);
+    /// x.mod_pow_assign(Integer::from(-1), &Natural::from(10u32));
+    /// assert_eq!(x, 7);
+    ///
+    /// let mut x = Integer::from(-3);
+    /// x.mod_pow_assign(Integer::from(-2), &Natural::from(10u32));
+    /// assert_eq!(x, 9);
+    /// ```
+    #[inline]
+    fn mod_pow_assign(&mut self, exp: Integer, m: &'a Natural) {
+        *self = Integer::from((&*self).mod_pow(exp, m));
+    }
+}
+
+impl<'a> ModPowAssign<&'a Integer, Natural> for Integer {
+    /// Raises an [`Integer`] to an [`Integer`] power modulo a [`Natural`] $m$, in place. The base
+    /// need not be reduced modulo $m$; in particular, it may be negative. A negative exponent
+    /// raises the inverse of the base modulo $m$ to the absolute value of the exponent. The
+    /// [`Integer`] on the right-hand side is taken by reference and the [`Natural`] by value.
+    ///
+    /// $x \gets y$, where $0 \leq y < m$ and $x^n \equiv y \mod m$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(mn \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is
+    /// `max(self.significant_bits(), m.significant_bits())`, and $m$ is `exp.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `m` is zero, or if `exp` is negative and `self` is not coprime to `m`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::ModPowAssign;
+    /// use malachite_nz::integer::Integer;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// let mut x = Integer::from(-2);
+    /// x.mod_pow_assign(&Integer::from(3), Natural::from(5u32));
+    /// assert_eq!(x, 2);
+    ///
+    /// let mut x = Integer::from(3);
+    /// x.mod_pow_assign(&Integer::from(-1), Natural::from(10u32));
+    /// assert_eq!(x, 7);
+    ///
+    /// let mut x = Integer::from(-3);
+    /// x.mod_pow_assign(&Integer::from(-2), Natural::from(10u32));
+    /// assert_eq!(x, 9);
+    /// ```
+    #[inline]
+    fn mod_pow_assign(&mut self, exp: &'a Integer, m: Natural) {
+        *self = Integer::from((&*self).mod_pow(exp, m));
+    }
+}
+
+impl<'a, 'b> ModPowAssign<&'a Integer, &'b Natural> for Integer {
+    /// Raises an [`Integer`] to an [`Integer`] power modulo a [`Natural`] $m$, in place. The base
+    /// need not be reduced modulo $m$; in particular, it may be negative. A negative exponent
+    /// raises the inverse of the base modulo $m$ to the absolute value of the exponent. The
+    /// [`Integer`] and [`Natural`] on the right-hand side are both taken by reference.
+    ///
+    /// $x \gets y$, where $0 \leq y < m$ and $x^n \equiv y \mod m$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(mn \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is
+    /// `max(self.significant_bits(), m.significant_bits())`, and $m$ is `exp.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `m` is zero, or if `exp` is negative and `self` is not coprime to `m`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::ModPowAssign;
+    /// use malachite_nz::integer::Integer;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// let mut x = Integer::from(-2);
+    /// x.mod_pow_assign(&Integer::from(3), &Natural::from(5u32));
+    /// assert_eq!(x, 2);
+    ///
+    /// let mut x = Integer::from(3);
+    /// x.mod_pow_assign(&Integer::from(-1), &Natural::from(10u32));
+    /// assert_eq!(x, 7);
+    ///
+    /// let mut x = Integer::from(-3);
+    /// x.mod_pow_assign(&Integer::from(-2), &Natural::from(10u32));
+    /// assert_eq!(x, 9);
+    /// ```
+    #[inline]
+    fn mod_pow_assign(&mut self, exp: &'a Integer, m: &'b Natural) {
+        *self = Integer::from((&*self).mod_pow(exp, m));
+    }
+}
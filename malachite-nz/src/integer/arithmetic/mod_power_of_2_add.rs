@@ -0,0 +1,199 @@
+use crate::integer::Integer;
+use crate::natural::Natural;
+use malachite_base::num::arithmetic::traits::{ModPowerOf2, ModPowerOf2Add, ModPowerOf2AddAssign};
+
+impl ModPowerOf2Add<Integer> for Integer {
+    type Output = Natural;
+
+    /// Adds two [`Integer`]s modulo $2^k$, returning a result in $[0, 2^k)$. The [`Integer`]s need
+    /// not be reduced modulo $2^k$; in particular, they may be negative. Both [`Integer`]s are
+    /// taken by value.
+    ///
+    /// $f(x, y, k) = z$, where $0 \leq z < 2^k$ and $x + y \equiv z \mod 2^k$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), other.significant_bits(), pow)`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::ModPowerOf2Add;
+    /// use malachite_nz::integer::Integer;
+    ///
+    /// assert_eq!(Integer::from(-3).mod_power_of_2_add(Integer::from(10), 4), 7);
+    /// assert_eq!(Integer::from(-7).mod_power_of_2_add(Integer::from(-8), 4), 1);
+    /// ```
+    #[inline]
+    fn mod_power_of_2_add(self, other: Integer, pow: u64) -> Natural {
+        self.mod_power_of_2(pow)
+            .mod_power_of_2_add(other.mod_power_of_2(pow), pow)
+    }
+}
+
+impl<'a> ModPowerOf2Add<&'a Integer> for Integer {
+    type Output = Natural;
+
+    /// Adds two [`Integer`]s modulo $2^k$, returning a result in $[0, 2^k)$. The [`Integer`]s need
+    /// not be reduced modulo $2^k$; in particular, they may be negative. The first [`Integer`] is
+    /// taken by value and the second by reference.
+    ///
+    /// $f(x, y, k) = z$, where $0 \leq z < 2^k$ and $x + y \equiv z \mod 2^k$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), other.significant_bits(), pow)`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::ModPowerOf2Add;
+    /// use malachite_nz::integer::Integer;
+    ///
+    /// assert_eq!(Integer::from(-3).mod_power_of_2_add(&Integer::from(10), 4), 7);
+    /// assert_eq!(Integer::from(-7).mod_power_of_2_add(&Integer::from(-8), 4), 1);
+    /// ```
+    #[inline]
+    fn mod_power_of_2_add(self, other: &'a Integer, pow: u64) -> Natural {
+        self.mod_power_of_2(pow)
+            .mod_power_of_2_add(other.mod_power_of_2(pow), pow)
+    }
+}
+
+impl<'a> ModPowerOf2Add<Integer> for &'a Integer {
+    type Output = Natural;
+
+    /// Adds two [`Integer`]s modulo $2^k$, returning a result in $[0, 2^k)$. The [`Integer`]s need
+    /// not be reduced modulo $2^k$; in particular, they may be negative. The first [`Integer`] is
+    /// taken by reference and the second by value.
+    ///
+    /// $f(x, y, k) = z$, where $0 \leq z < 2^k$ and $x + y \equiv z \mod 2^k$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), other.significant_bits(), pow)`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::ModPowerOf2Add;
+    /// use malachite_nz::integer::Integer;
+    ///
+    /// assert_eq!((&Integer::from(-3)).mod_power_of_2_add(Integer::from(10), 4), 7);
+    /// assert_eq!((&Integer::from(-7)).mod_power_of_2_add(Integer::from(-8), 4), 1);
+    /// ```
+    #[inline]
+    fn mod_power_of_2_add(self, other: Integer, pow: u64) -> Natural {
+        self.mod_power_of_2(pow)
+            .mod_power_of_2_add(other.mod_power_of_2(pow), pow)
+    }
+}
+
+impl<'a, 'b> ModPowerOf2Add<&'b Integer> for &'a Integer {
+    type Output = Natural;
+
+    /// Adds two [`Integer`]s modulo $2^k$, returning a result in $[0, 2^k)$. The [`Integer`]s need
+    /// not be reduced modulo $2^k$; in particular, they may be negative. Both [`Integer`]s are
+    /// taken by reference.
+    ///
+    /// $f(x, y, k) = z$, where $0 \leq z < 2^k$ and $x + y \equiv z \mod 2^k$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), other.significant_bits(), pow)`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::ModPowerOf2Add;
+    /// use malachite_nz::integer::Integer;
+    ///
+    /// assert_eq!((&Integer::from(-3)).mod_power_of_2_add(&Integer::from(10), 4), 7);
+    /// assert_eq!((&Integer::from(-7)).mod_power_of_2_add(&Integer::from(-8), 4), 1);
+    /// ```
+    #[inline]
+    fn mod_power_of_2_add(self, other: &'b Integer, pow: u64) -> Natural {
+        self.mod_power_of_2(pow)
+            .mod_power_of_2_add(other.mod_power_of_2(pow), pow)
+    }
+}
+
+impl ModPowerOf2AddAssign<Integer> for Integer {
+    /// Adds two [`Integer`]s modulo $2^k$, in place. The [`Integer`]s need not be reduced modulo
+    /// $2^k$; in particular, they may be negative, but the result is always in $[0, 2^k)$. The
+    /// [`Integer`] on the right-hand side is taken by value.
+    ///
+    /// $x \gets z$, where $0 \leq z < 2^k$ and $x + y \equiv z \mod 2^k$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), other.significant_bits(), pow)`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::ModPowerOf2AddAssign;
+    /// use malachite_nz::integer::Integer;
+    ///
+    /// let mut x = Integer::from(-3);
+    /// x.mod_power_of_2_add_assign(Integer::from(10), 4);
+    /// assert_eq!(x, 7);
+    ///
+    /// let mut x = Integer::from(-7);
+    /// x.mod_power_of_2_add_assign(Integer::from(-8), 4);
+    /// assert_eq!(x, 1);
+    /// ```
+    #[inline]
+    fn mod_power_of_2_add_assign(&mut self, other: Integer, pow: u64) {
+        *self = Integer::from((&*self).mod_power_of_2_add(other, pow));
+    }
+}
+
+impl<'a> ModPowerOf2AddAssign<&'a Integer> for Integer {
+    /// Adds two [`Integer`]s modulo $2^k$, in place. The [`Integer`]s need not be reduced modulo
+    /// $2^k$; in particular, they may be negative, but the result is always in $[0, 2^k)$. The
+    /// [`Integer`] on the right-hand side is taken by reference.
+    ///
+    /// $x \gets z$, where $0 \leq z < 2^k$ and $x + y \equiv z \mod 2^k$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), other.significant_bits(), pow)`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::ModPowerOf2AddAssign;
+    /// use malachite_nz::integer::Integer;
+    ///
+    /// let mut x = Integer::from(-3);
+    /// x.mod_power_of_2_add_assign(&Integer::from(10), 4);
+    /// assert_eq!(x, 7);
+    ///
+    /// let mut x = Integer::from(-7);
+    /// x.mod_power_of_2_add_assign(&Integer::from(-8), 4);
+    /// assert_eq!(x, 1);
+    /// ```
+    #[inline]
+    fn mod_power_of_2_add_assign(&mut self, other: &'a Integer, pow: u64) {
+        *self = Integer::from((&*self).mod_power_of_2_add(other, pow));
+    }
+}
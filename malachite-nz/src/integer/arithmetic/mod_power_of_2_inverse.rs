@@ -0,0 +1,85 @@
+use crate::integer::Integer;
+use crate::natural::Natural;
+use malachite_base::num::arithmetic::traits::{ModPowerOf2, ModPowerOf2Inverse};
+use malachite_base::num::basic::traits::Zero;
+
+// Inverts a residue in $[0, 2^k)$. Zero is invertible only modulo 1.
+pub(crate) fn mod_power_of_2_inverse_helper(x: Natural, pow: u64) -> Option<Natural> {
+    if x == 0u32 {
+        if pow == 0 {
+            Some(Natural::ZERO)
+        } else {
+            None
+        }
+    } else {
+        x.mod_power_of_2_inverse(pow)
+    }
+}
+
+impl ModPowerOf2Inverse for Integer {
+    type Output = Natural;
+
+    /// Computes the multiplicative inverse of an [`Integer`] modulo $2^k$, returning a result in
+    /// $[0, 2^k)$. The [`Integer`] need not be reduced modulo $2^k$; in particular, it may be
+    /// negative. The [`Integer`] is taken by value.
+    ///
+    /// Returns `None` if $x$ is even and $k$ is positive.
+    ///
+    /// $f(x, k) = y$, where $0 \leq y < 2^k$ and $xy \equiv 1 \mod 2^k$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), pow)`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::ModPowerOf2Inverse;
+    /// use malachite_nz::integer::Integer;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!(Integer::from(-3).mod_power_of_2_inverse(4), Some(Natural::from(5u32)));
+    /// assert_eq!(Integer::from(-4).mod_power_of_2_inverse(4), None);
+    /// ```
+    #[inline]
+    fn mod_power_of_2_inverse(self, pow: u64) -> Option<Natural> {
+        mod_power_of_2_inverse_helper(self.mod_power_of_2(pow), pow)
+    }
+}
+
+impl<'a> ModPowerOf2Inverse for &'a Integer {
+    type Output = Natural;
+
+    /// Computes the multiplicative inverse of an [`Integer`] modulo $2^k$, returning a result in
+    /// $[0, 2^k)$. The [`Integer`] need not be reduced modulo $2^k$; in particular, it may be
+    /// negative. The [`Integer`] is taken by reference.
+    ///
+    /// Returns `None` if $x$ is even and $k$ is positive.
+    ///
+    /// $f(x, k) = y$, where $0 \leq y < 2^k$ and $xy \equiv 1 \mod 2^k$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), pow)`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::ModPowerOf2Inverse;
+    /// use malachite_nz::integer::Integer;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!((&Integer::from(-3)).mod_power_of_2_inverse(4), Some(Natural::from(5u32)));
+    /// assert_eq!((&Integer::from(-4)).mod_power_of_2_inverse(4), None);
+    /// ```
+    #[inline]
+    fn mod_power_of_2_inverse(self, pow: u64) -> Option<Natural> {
+        mod_power_of_2_inverse_helper(self.mod_power_of_2(pow), pow)
+    }
+}
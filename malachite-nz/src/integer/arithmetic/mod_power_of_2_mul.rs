@@ -0,0 +1,199 @@
+use crate::integer::Integer;
+use crate::natural::Natural;
+use malachite_base::num::arithmetic::traits::{ModPowerOf2, ModPowerOf2Mul, ModPowerOf2MulAssign};
+
+impl ModPowerOf2Mul<Integer> for Integer {
+    type Output = Natural;
+
+    /// Multiplies two [`Integer`]s modulo $2^k$, returning a result in $[0, 2^k)$. The [`Integer`]s
+    /// need not be reduced modulo $2^k$; in particular, they may be negative. Both [`Integer`]s are
+    /// taken by value.
+    ///
+    /// $f(x, y, k) = z$, where $0 \leq z < 2^k$ and $xy \equiv z \mod 2^k$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), other.significant_bits(), pow)`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::ModPowerOf2Mul;
+    /// use malachite_nz::integer::Integer;
+    ///
+    /// assert_eq!(Integer::from(-3).mod_power_of_2_mul(Integer::from(10), 4), 2);
+    /// assert_eq!(Integer::from(-4).mod_power_of_2_mul(Integer::from(-6), 4), 8);
+    /// ```
+    #[inline]
+    fn mod_power_of_2_mul(self, other: Integer, pow: u64) -> Natural {
+        self.mod_power_of_2(pow)
+            .mod_power_of_2_mul(other.mod_power_of_2(pow), pow)
+    }
+}
+
+impl<'a> ModPowerOf2Mul<&'a Integer> for Integer {
+    type Output = Natural;
+
+    /// Multiplies two [`Integer`]s modulo $2^k$, returning a result in $[0, 2^k)$. The [`Integer`]s
+    /// need not be reduced modulo $2^k$; in particular, they may be negative. The first [`Integer`]
+    /// is taken by value and the second by reference.
+    ///
+    /// $f(x, y, k) = z$, where $0 \leq z < 2^k$ and $xy \equiv z \mod 2^k$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), other.significant_bits(), pow)`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::ModPowerOf2Mul;
+    /// use malachite_nz::integer::Integer;
+    ///
+    /// assert_eq!(Integer::from(-3).mod_power_of_2_mul(&Integer::from(10), 4), 2);
+    /// assert_eq!(Integer::from(-4).mod_power_of_2_mul(&Integer::from(-6), 4), 8);
+    /// ```
+    #[inline]
+    fn mod_power_of_2_mul(self, other: &'a Integer, pow: u64) -> Natural {
+        self.mod_power_of_2(pow)
+            .mod_power_of_2_mul(other.mod_power_of_2(pow), pow)
+    }
+}
+
+impl<'a> ModPowerOf2Mul<Integer> for &'a Integer {
+    type Output = Natural;
+
+    /// Multiplies two [`Integer`]s modulo $2^k$, returning a result in $[0, 2^k)$. The [`Integer`]s
+    /// need not be reduced modulo $2^k$; in particular, they may be negative. The first [`Integer`]
+    /// is taken by reference and the second by value.
+    ///
+    /// $f(x, y, k) = z$, where $0 \leq z < 2^k$ and $xy \equiv z \mod 2^k$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), other.significant_bits(), pow)`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::ModPowerOf2Mul;
+    /// use malachite_nz::integer::Integer;
+    ///
+    /// assert_eq!((&Integer::from(-3)).mod_power_of_2_mul(Integer::from(10), 4), 2);
+    /// assert_eq!((&Integer::from(-4)).mod_power_of_2_mul(Integer::from(-6), 4), 8);
+    /// ```
+    #[inline]
+    fn mod_power_of_2_mul(self, other: Integer, pow: u64) -> Natural {
+        self.mod_power_of_2(pow)
+            .mod_power_of_2_mul(other.mod_power_of_2(pow), pow)
+    }
+}
+
+impl<'a, 'b> ModPowerOf2Mul<&'b Integer> for &'a Integer {
+    type Output = Natural;
+
+    /// Multiplies two [`Integer`]s modulo $2^k$, returning a result in $[0, 2^k)$. The [`Integer`]s
+    /// need not be reduced modulo $2^k$; in particular, they may be negative. Both [`Integer`]s are
+    /// taken by reference.
+    ///
+    /// $f(x, y, k) = z$, where $0 \leq z < 2^k$ and $xy \equiv z \mod 2^k$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), other.significant_bits(), pow)`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::ModPowerOf2Mul;
+    /// use malachite_nz::integer::Integer;
+    ///
+    /// assert_eq!((&Integer::from(-3)).mod_power_of_2_mul(&Integer::from(10), 4), 2);
+    /// assert_eq!((&Integer::from(-4)).mod_power_of_2_mul(&Integer::from(-6), 4), 8);
+    /// ```
+    #[inline]
+    fn mod_power_of_2_mul(self, other: &'b Integer, pow: u64) -> Natural {
+        self.mod_power_of_2(pow)
+            .mod_power_of_2_mul(other.mod_power_of_2(pow), pow)
+    }
+}
+
+impl ModPowerOf2MulAssign<Integer> for Integer {
+    /// Multiplies two [`Integer`]s modulo $2^k$, in place. The [`Integer`]s need not be reduced
+    /// modulo $2^k$; in particular, they may be negative, but the result is always in $[0, 2^k)$.
+    /// The [`Integer`] on the right-hand side is taken by value.
+    ///
+    /// $x \gets z$, where $0 \leq z < 2^k$ and $xy \equiv z \mod 2^k$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), other.significant_bits(), pow)`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::ModPowerOf2MulAssign;
+    /// use malachite_nz::integer::Integer;
+    ///
+    /// let mut x = Integer::from(-3);
+    /// x.mod_power_of_2_mul_assign(Integer::from(10), 4);
+    /// assert_eq!(x, 2);
+    ///
+    /// let mut x = Integer::from(-4);
+    /// x.mod_power_of_2_mul_assign(Integer::from(-6), 4);
+    /// assert_eq!(x, 8);
+    /// ```
+    #[inline]
+    fn mod_power_of_2_mul_assign(&mut self, other: Integer, pow: u64) {
+        *self = Integer::from((&*self).mod_power_of_2_mul(other, pow));
+    }
+}
+
+impl<'a> ModPowerOf2MulAssign<&'a Integer> for Integer {
+    /// Multiplies two [`Integer`]s modulo $2^k$, in place. The [`Integer`]s need not be reduced
+    /// modulo $2^k$; in particular, they may be negative, but the result is always in $[0, 2^k)$.
+    /// The [`Integer`] on the right-hand side is taken by reference.
+    ///
+    /// $x \gets z$, where $0 \leq z < 2^k$ and $xy \equiv z \mod 2^k$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), other.significant_bits(), pow)`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::ModPowerOf2MulAssign;
+    /// use malachite_nz::integer::Integer;
+    ///
+    /// let mut x = Integer::from(-3);
+    /// x.mod_power_of_2_mul_assign(&Integer::from(10), 4);
+    /// assert_eq!(x, 2);
+    ///
+    /// let mut x = Integer::from(-4);
+    /// x.mod_power_of_2_mul_assign(&Integer::from(-6), 4);
+    /// assert_eq!(x, 8);
+    /// ```
+    #[inline]
+    fn mod_power_of_2_mul_assign(&mut self, other: &'a Integer, pow: u64) {
+        *self = Integer::from((&*self).mod_power_of_2_mul(other, pow));
+    }
+}
@@ -0,0 +1,230 @@
+use crate::integer::arithmetic::mod_power_of_2_inverse::mod_power_of_2_inverse_helper;
+use crate::integer::Integer;
+use crate::natural::Natural;
+use malachite_base::num::arithmetic::traits::{ModPowerOf2, ModPowerOf2Pow, ModPowerOf2PowAssign};
+
+fn mod_power_of_2_pow_helper(x: Natural, exp: &Integer, pow: u64) -> Natural {
+    if exp.sign {
+        x.mod_power_of_2_pow(&exp.abs, pow)
+    } else if let Some(inverse) = mod_power_of_2_inverse_helper(x, pow) {
+        inverse.mod_power_of_2_pow(&exp.abs, pow)
+    } else {
+        panic!("Cannot raise a non-invertible residue to a negative power");
+    }
+}
+
+impl ModPowerOf2Pow<Integer> for Integer {
+    type Output = Natural;
+
+    /// Raises an [`Integer`] to an [`Integer`] power modulo $2^k$, returning a result in $[0,
+    /// 2^k)$. The base need not be reduced modulo $2^k$; in particular, it may be negative. A
+    /// negative exponent raises the inverse of the base modulo $2^k$ to the absolute value of the
+    /// exponent. Both [`Integer`]s are taken by value.
+    ///
+    /// $f(x, n, k) = y$, where $0 \leq y < 2^k$ and $x^n \equiv y \mod 2^k$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(mn \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is
+    /// `max(self.significant_bits(), pow)`, and $m$ is `exp.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `exp` is negative, `self` is even, and `pow` is positive.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::ModPowerOf2Pow;
+    /// use malachite_nz::integer::Integer;
+    ///
+    /// assert_eq!(Integer::from(-3).mod_power_of_2_pow(Integer::from(3), 4), 5);
+    /// assert_eq!(Integer::from(3).mod_power_of_2_pow(Integer::from(-1), 4), 11);
+    /// ```
+    #[inline]
+    fn mod_power_of_2_pow(self, exp: Integer, pow: u64) -> Natural {
+        mod_power_of_2_pow_helper(self.mod_power_of_2(pow), &exp, pow)
+    }
+}
+
+impl<'a> ModPowerOf2Pow<&'a Integer> for Integer {
+    type Output = Natural;
+
+    /// Raises an [`Integer`] to an [`Integer`] power modulo $2^k$, returning a result in $[0,
+    /// 2^k)$. The base need not be reduced modulo $2^k$; in particular, it may be negative. A
+    /// negative exponent raises the inverse of the base modulo $2^k$ to the absolute value of the
+    /// exponent. The first [`Integer`] is taken by value and the second by reference.
+    ///
+    /// $f(x, n, k) = y$, where $0 \leq y < 2^k$ and $x^n \equiv y \mod 2^k$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(mn \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is
+    /// `max(self.significant_bits(), pow)`, and $m$ is `exp.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `exp` is negative, `self` is even, and `pow` is positive.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::ModPowerOf2Pow;
+    /// use malachite_nz::integer::Integer;
+    ///
+    /// assert_eq!(Integer::from(-3).mod_power_of_2_pow(&Integer::from(3), 4), 5);
+    /// assert_eq!(Integer::from(3).mod_power_of_2_pow(&Integer::from(-1), 4), 11);
+    /// ```
+    #[inline]
+    fn mod_power_of_2_pow(self, exp: &'a Integer, pow: u64) -> Natural {
+        mod_power_of_2_pow_helper(self.mod_power_of_2(pow), exp, pow)
+    }
+}
+
+impl<'a> ModPowerOf2Pow<Integer> for &'a Integer {
+    type Output = Natural;
+
+    /// Raises an [`Integer`] to an [`Integer`] power modulo $2^k$, returning a result in $[0,
+    /// 2^k)$. The base need not be reduced modulo $2^k$; in particular, it may be negative. A
+    /// negative exponent raises the inverse of the base modulo $2^k$ to the absolute value of the
+    /// exponent. The first [`Integer`] is taken by reference and the second by value.
+    ///
+    /// $f(x, n, k) = y$, where $0 \leq y < 2^k$ and $x^n \equiv y \mod 2^k$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(mn \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is
+    /// `max(self.significant_bits(), pow)`, and $m$ is `exp.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `exp` is negative, `self` is even, and `pow` is positive.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::ModPowerOf2Pow;
+    /// use malachite_nz::integer::Integer;
+    ///
+    /// assert_eq!((&Integer::from(-3)).mod_power_of_2_pow(Integer::from(3), 4), 5);
+    /// assert_eq!((&Integer::from(3)).mod_power_of_2_pow(Integer::from(-1), 4), 11);
+    /// ```
+    #[inline]
+    fn mod_power_of_2_pow(self, exp: Integer, pow: u64) -> Natural {
+        mod_power_of_2_pow_helper(self.mod_power_of_2(pow), &exp, pow)
+    }
+}
+
+impl<'a, 'b> ModPowerOf2Pow<&'b Integer> for &'a Integer {
+    type Output = Natural;
+
+    /// Raises an [`Integer`] to an [`Integer`] power modulo $2^k$, returning a result in $[0,
+    /// 2^k)$. The base need not be reduced modulo $2^k$; in particular, it may be negative. A
+    /// negative exponent raises the inverse of the base modulo $2^k$ to the absolute value of the
+    /// exponent. Both [`Integer`]s are taken by reference.
+    ///
+    /// $f(x, n, k) = y$, where $0 \leq y < 2^k$ and $x^n \equiv y \mod 2^k$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(mn \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is
+    /// `max(self.significant_bits(), pow)`, and $m$ is `exp.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `exp` is negative, `self` is even, and `pow` is positive.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::ModPowerOf2Pow;
+    /// use malachite_nz::integer::Integer;
+    ///
+    /// assert_eq!((&Integer::from(-3)).mod_power_of_2_pow(&Integer::from(3), 4), 5);
+    /// assert_eq!((&Integer::from(3)).mod_power_of_2_pow(&Integer::from(-1), 4), 11);
+    /// ```
+    #[inline]
+    fn mod_power_of_2_pow(self, exp: &'b Integer, pow: u64) -> Natural {
+        mod_power_of_2_pow_helper(self.mod_power_of_2(pow), exp, pow)
+    }
+}
+
+impl ModPowerOf2PowAssign<Integer> for Integer {
+    /// Raises an [`Integer`] to an [`Integer`] power modulo $2^k$, in place. The base need not be
+    /// reduced modulo $2^k$; in particular, it may be negative. A negative exponent raises the
+    /// inverse of the base modulo $2^k$ to the absolute value of the exponent. The [`Integer`] on
+    /// the right-hand side is taken by value.
+    ///
+    /// $x \gets y$, where $0 \leq y < 2^k$ and $x^n \equiv y \mod 2^k$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(mn \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is
+    /// `max(self.significant_bits(), pow)`, and $m$ is `exp.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `exp` is negative, `self` is even, and `pow` is positive.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::ModPowerOf2PowAssign;
+    /// use malachite_nz::integer::Integer;
+    ///
+    /// let mut x = Integer::from(-3);
+    /// x.mod_power_of_2_pow_assign(Integer::from(3), 4);
+    /// assert_eq!(x, 5);
+    ///
+    /// let mut x = Integer::from(3);
+    /// x.mod_power_of_2_pow_assign(Integer::from(-1), 4);
+    /// assert_eq!(x, 11);
+    /// ```
+    #[inline]
+    fn mod_power_of_2_pow_assign(&mut self, exp: Integer, pow: u64) {
+        *self = Integer::from((&*self).mod_power_of_2_pow(exp, pow));
+    }
+}
+
+impl<'a> ModPowerOf2PowAssign<&'a Integer> for Integer {
+    /// Raises an [`Integer`] to an [`Integer`] power modulo $2^k$, in place. The base need not be
+    /// reduced modulo $2^k$; in particular, it may be negative. A negative exponent raises the
+    /// inverse of the base modulo $2^k$ to the absolute value of the exponent. The [`Integer`] on
+    /// the right-hand side is taken by reference.
+    ///
+    /// $x \gets y$, where $0 \leq y < 2^k$ and $x^n \equiv y \mod 2^k$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(mn \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is
+    /// `max(self.significant_bits(), pow)`, and $m$ is `exp.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `exp` is negative, `self` is even, and `pow` is positive.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::ModPowerOf2PowAssign;
+    /// use malachite_nz::integer::Integer;
+    ///
+    /// let mut x = Integer::from(-3);
+    /// x.mod_power_of_2_pow_assign(&Integer::from(3), 4);
+    /// assert_eq!(x, 5);
+    ///
+    /// let mut x = Integer::from(3);
+    /// x.mod_power_of_2_pow_assign(&Integer::from(-1), 4);
+    /// assert_eq!(x, 11);
+    /// ```
+    #[inline]
+    fn mod_power_of_2_pow_assign(&mut self, exp: &'a Integer, pow: u64) {
+        *self = Integer::from((&*self).mod_power_of_2_pow(exp, pow));
+    }
+}
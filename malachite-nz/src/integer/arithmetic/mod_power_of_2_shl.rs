@@ -0,0 +1,84 @@
+use crate::integer::Integer;
+use crate::natural::Natural;
+use malachite_base::num::arithmetic::traits::{ModPowerOf2, ModPowerOf2Shl, ModPowerOf2ShlAssign};
+
+macro_rules! impl_mod_power_of_2_shl {
+    ($t:ident) => {
+        impl ModPowerOf2Shl<$t> for Integer {
+            type Output = Natural;
+
+            /// Left-shifts an [`Integer`] (multiplies it by a power of 2) modulo $2^k$, returning a
+            /// result in $[0, 2^k)$. The [`Integer`] need not be reduced modulo $2^k$; in
+            /// particular, it may be negative. The [`Integer`] is taken by value.
+            ///
+            /// $f(x, n, k) = y$, where $x'$ is the residue of $x$ in $[0, 2^k)$, $0 \leq y < 2^k$,
+            /// and $\lfloor 2^n x' \rfloor \equiv y \mod 2^k$.
+            ///
+            /// # Worst-case complexity
+            /// $T(n) = O(n)$
+            ///
+            /// $M(n) = O(n)$
+            ///
+            /// where $T$ is time, $M$ is additional memory, and $n$ is
+            /// `max(self.significant_bits(), pow)`.
+            ///
+            /// # Examples
+            /// See [here](super::mod_power_of_2_shl#mod_power_of_2_shl).
+            #[inline]
+            fn mod_power_of_2_shl(self, bits: $t, pow: u64) -> Natural {
+                self.mod_power_of_2(pow).mod_power_of_2_shl(bits, pow)
+            }
+        }
+
+        impl<'a> ModPowerOf2Shl<$t> for &'a Integer {
+            type Output = Natural;
+
+            /// Left-shifts an [`Integer`] (multiplies it by a power of 2) modulo $2^k$, returning a
+            /// result in $[0, 2^k)$. The [`Integer`] need not be reduced modulo $2^k$; in
+            /// particular, it may be negative. The [`Integer`] is taken by reference.
+            ///
+            /// $f(x, n, k) = y$, where $x'$ is the residue of $x$ in $[0, 2^k)$, $0 \leq y < 2^k$,
+            /// and $\lfloor 2^n x' \rfloor \equiv y \mod 2^k$.
+            ///
+            /// # Worst-case complexity
+            /// $T(n) = O(n)$
+            ///
+            /// $M(n) = O(n)$
+            ///
+            /// where $T$ is time, $M$ is additional memory, and $n$ is
+            /// `max(self.significant_bits(), pow)`.
+            ///
+            /// # Examples
+            /// See [here](super::mod_power_of_2_shl#mod_power_of_2_shl).
+            #[inline]
+            fn mod_power_of_2_shl(self, bits: $t, pow: u64) -> Natural {
+                self.mod_power_of_2(pow).mod_power_of_2_shl(bits, pow)
+            }
+        }
+
+        impl ModPowerOf2ShlAssign<$t> for Integer {
+            /// Left-shifts an [`Integer`] (multiplies it by a power of 2) modulo $2^k$, in place.
+            /// The [`Integer`] need not be reduced modulo $2^k$; in particular, it may be negative,
+            /// but the result is always in $[0, 2^k)$.
+            ///
+            /// $x \gets y$, where $x'$ is the residue of $x$ in $[0, 2^k)$, $0 \leq y < 2^k$, and
+            /// $\lfloor 2^n x' \rfloor \equiv y \mod 2^k$.
+            ///
+            /// # Worst-case complexity
+            /// $T(n) = O(n)$
+            ///
+            /// $M(n) = O(n)$
+            ///
+            /// where $T$ is time, $M$ is additional memory, and $n$ is
+            /// `max(self.significant_bits(), pow)`.
+            ///
+            /// # Examples
+            /// See [here](super::mod_power_of_2_shl#mod_power_of_2_shl_assign).
+            #[inline]
+            fn mod_power_of_2_shl_assign(&mut self, bits: $t, pow: u64) {
+                *self = Integer::from((&*self).mod_power_of_2_shl(bits, pow));
+            }
+        }
+    };
+}
+apply_to_primitive_ints!(impl_mod_power_of_2_shl);
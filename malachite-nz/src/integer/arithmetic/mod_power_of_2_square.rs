@@ -0,0 +1,100 @@
+use crate::integer::Integer;
+use crate::natural::Natural;
+use malachite_base::num::arithmetic::traits::{
+    ModPowerOf2, ModPowerOf2Square, ModPowerOf2SquareAssign,
+};
+
+impl ModPowerOf2Square for Integer {
+    type Output = Natural;
+
+    /// Squares an [`Integer`] modulo $2^k$, returning a result in $[0, 2^k)$. The [`Integer`] need
+    /// not be reduced modulo $2^k$; in particular, it may be negative. The [`Integer`] is taken by
+    /// value.
+    ///
+    /// $f(x, k) = y$, where $0 \leq y < 2^k$ and $x^2 \equiv y \mod 2^k$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), pow)`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::ModPowerOf2Square;
+    /// use malachite_nz::integer::Integer;
+    ///
+    /// assert_eq!(Integer::from(-3).mod_power_of_2_square(4), 9);
+    /// assert_eq!(Integer::from(-12).mod_power_of_2_square(4), 0);
+    /// ```
+    #[inline]
+    fn mod_power_of_2_square(self, pow: u64) -> Natural {
+        self.mod_power_of_2(pow).mod_power_of_2_square(pow)
+    }
+}
+
+impl<'a> ModPowerOf2Square for &'a Integer {
+    type Output = Natural;
+
+    /// Squares an [`Integer`] modulo $2^k$, returning a result in $[0, 2^k)$. The [`Integer`] need
+    /// not be reduced modulo $2^k$; in particular, it may be negative. The [`Integer`] is taken by
+    /// reference.
+    ///
+    /// $f(x, k) = y$, where $0 \leq y < 2^k$ and $x^2 \equiv y \mod 2^k$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), pow)`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::ModPowerOf2Square;
+    /// use malachite_nz::integer::Integer;
+    ///
+    /// assert_eq!((&Integer::from(-3)).mod_power_of_2_square(4), 9);
+    /// assert_eq!((&Integer::from(-12)).mod_power_of_2_square(4), 0);
+    /// ```
+    #[inline]
+    fn mod_power_of_2_square(self, pow: u64) -> Natural {
+        self.mod_power_of_2(pow).mod_power_of_2_square(pow)
+    }
+}
+
+impl ModPowerOf2SquareAssign for Integer {
+    /// Squares an [`Integer`] modulo $2^k$, in place. The [`Integer`] need not be reduced modulo
+    /// $2^k$; in particular, it may be negative, but the result is always in $[0, 2^k)$.
+    ///
+    /// $x \gets y$, where $0 \leq y < 2^k$ and $x^2 \equiv y \mod 2^k$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), pow)`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::ModPowerOf2SquareAssign;
+    /// use malachite_nz::integer::Integer;
+    ///
+    /// let mut x = Integer::from(-3);
+    /// x.mod_power_of_2_square_assign(4);
+    /// assert_eq!(x, 9);
+    ///
+    /// let mut x = Integer::from(-12);
+    /// x.mod_power_of_2_square_assign(4);
+    /// assert_eq!(x, 0);
+    /// ```
+    #[inline]
+    fn mod_power_of_2_square_assign(&mut self, pow: u64) {
+        *self = Integer::from((&*self).mod_power_of_2_square(pow));
+    }
+}
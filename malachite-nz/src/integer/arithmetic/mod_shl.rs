@@ -0,0 +1,183 @@
+use crate::integer::Integer;
+use crate::natural::Natural;
+use malachite_base::num::arithmetic::traits::{ModShl, ModShlAssign};
+
+macro_rules! impl_mod_shl {
+    ($t:ident) => {
+        impl ModShl<$t, Natural> for Integer {
+            type Output = Natural;
+
+            /// Left-shifts an [`Integer`] (multiplies it by a power of 2) modulo a [`Natural`] $m$,
+            /// returning a result in $[0, m)$. The [`Integer`] need not be reduced modulo $m$; in
+            /// particular, it may be negative. The [`Integer`] and the [`Natural`] are both taken
+            /// by value.
+            ///
+            /// $f(x, n, m) = y$, where $x'$ is the residue of $x$ in $[0, m)$, $0 \leq y < m$, and
+            /// $\lfloor 2^n x' \rfloor \equiv y \mod m$.
+            ///
+            /// # Worst-case complexity
+            /// $T(n, m) = O(mn \log n \log\log n)$
+            ///
+            /// $M(n) = O(n \log n)$
+            ///
+            /// where $T$ is time, $M$ is additional memory, $n$ is
+            /// `max(self.significant_bits(), m.significant_bits())`, and $m$ is `bits`.
+            ///
+            /// # Panics
+            /// Panics if `m` is zero.
+            ///
+            /// # Examples
+            /// See [here](super::mod_shl#mod_shl).
+            #[inline]
+            fn mod_shl(self, bits: $t, m: Natural) -> Natural {
+                self.mod_natural(&m).mod_shl(bits, m)
+            }
+        }
+
+        impl<'a> ModShl<$t, &'a Natural> for Integer {
+            type Output = Natural;
+
+            /// Left-shifts an [`Integer`] (multiplies it by a power of 2) modulo a [`Natural`] $m$,
+            /// returning a result in $[0, m)$. The [`Integer`] need not be reduced modulo $m$; in
+            /// particular, it may be negative. The [`Integer`] is taken by value and the
+            /// [`Natural`] by reference.
+            ///
+            /// $f(x, n, m) = y$, where $x'$ is the residue of $x$ in $[0, m)$, $0 \leq y < m$, and
+            /// $\lfloor 2^n x' \rfloor \equiv y \mod m$.
+            ///
+            /// # Worst-case complexity
+            /// $T(n, m) = O(mn \log n \log\log n)$
+            ///
+            /// $M(n) = O(n \log n)$
+            ///
+            /// where $T$ is time, $M$ is additional memory, $n$ is
+            /// `max(self.significant_bits(), m.significant_bits())`, and $m$ is `bits`.
+            ///
+            /// # Panics
+            /// Panics if `m` is zero.
+            ///
+            /// # Examples
+            /// See [here](super::mod_shl#mod_shl).
+            #[inline]
+            fn mod_shl(self, bits: $t, m: &'a Natural) -> Natural {
+                self.mod_natural(m).mod_shl(bits, m)
+            }
+        }
+
+        impl<'a> ModShl<$t, Natural> for &'a Integer {
+            type Output = Natural;
+
+            /// Left-shifts an [`Integer`] (multiplies it by a power of 2) modulo a [`Natural`] $m$,
+            /// returning a result in $[0, m)$. The [`Integer`] need not be reduced modulo $m$; in
+            /// particular, it may be negative. The [`Integer`] is taken by reference and the
+            /// [`Natural`] by value.
+            ///
+            /// $f(x, n, m) = y$, where $x'$ is the residue of $x$ in $[0, m)$, $0 \leq y < m$, and
+            /// $\lfloor 2^n x' \rfloor \equiv y \mod m$.
+            ///
+            /// # Worst-case complexity
+            /// $T(n, m) = O(mn \log n \log\log n)$
+            ///
+            /// $M(n) = O(n \log n)$
+            ///
+            /// where $T$ is time, $M$ is additional memory, $n$ is
+            /// `max(self.significant_bits(), m.significant_bits())`, and $m$ is `bits`.
+            ///
+            /// # Panics
+            /// Panics if `m` is zero.
+            ///
+            /// # Examples
+            /// See [here](super::mod_shl#mod_shl).
+            #[inline]
+            fn mod_shl(self, bits: $t, m: Natural) -> Natural {
+                self.mod_natural_ref(&m).mod_shl(bits, m)
+            }
+        }
+
+        impl<'a, 'b> ModShl<$t, &'b Natural> for &'a Integer {
+            type Output = Natural;
+
+            /// Left-shifts an [`Integer`] (multiplies it by a power of 2) modulo a [`Natural`] $m$,
+            /// returning a result in $[0, m)$. The [`Integer`] need not be reduced modulo $m$; in
+            /// particular, it may be negative. The [`Integer`] and the [`Natural`] are both taken
+            /// by reference.
+            ///
+            /// $f(x, n, m) = y$, where $x'$ is the residue of $x$ in $[0, m)$, $0 \leq y < m$, and
+            /// $\lfloor 2^n x' \rfloor \equiv y \mod m$.
+            ///
+            /// # Worst-case complexity
+            /// $T(n, m) = O(mn \log n \log\log n)$
+            ///
+            /// $M(n) = O(n \log n)$
+            ///
+            /// where $T$ is time, $M$ is additional memory, $n$ is
+            /// `max(self.significant_bits(), m.significant_bits())`, and $m$ is `bits`.
+            ///
+            /// # Panics
+            /// Panics if `m` is zero.
+            ///
+            /// # Examples
+            /// See [here](super::mod_shl#mod_shl).
+            #[inline]
+            fn mod_shl(self, bits: $t, m: &'b Natural) -> Natural {
+                self.mod_natural_ref(m).mod_shl(bits, m)
+            }
+        }
+
+        impl ModShlAssign<$t, Natural> for Integer {
+            /// Left-shifts an [`Integer`] (multiplies it by a power of 2) modulo a [`Natural`] $m$,
+            /// in place. The [`Integer`] need not be reduced modulo $m$; in particular, it may be
+            /// negative, but the result is always in $[0, m)$. The [`Natural`] is taken by value.
+            ///
+            /// $x \gets y$, where $x'$ is the residue of $x$ in $[0, m)$, $0 \leq y < m$, and
+            /// $\lfloor 2^n x' \rfloor \equiv y \mod m$.
+            ///
+            /// # Worst-case complexity
+            /// $T(n, m) = O(mn \log n \log\log n)$
+            ///
+            /// $M(n) = O(n \log n)$
+            ///
+            /// where $T$ is time, $M$ is additional memory, $n$ is
+            /// `max(self.significant_bits(), m.significant_bits())`, and $m$ is `bits`.
+            ///
+            /// # Panics
+            /// Panics if `m` is zero.
+            ///
+            /// # Examples
+            /// See [here](super::mod_shl#mod_shl_assign).
+            #[inline]
+            fn mod_shl_assign(&mut self, bits: $t, m: Natural) {
+                *self = Integer::from((&*self).mod_shl(bits, m));
+            }
+        }
+
+        impl<'a> ModShlAssign<$t, &'a Natural> for Integer {
+            /// Left-shifts an [`Integer`] (multiplies it by a power of 2) modulo a [`Natural`] $m$,
+            /// in place. The [`Integer`] need not be reduced modulo $m$; in particular, it may be
+            /// negative, but the result is always in $[0, m)$. The [`Natural`] is taken by
+            /// reference.
+            ///
+            /// $x \gets y$, where $x'$ is the residue of $x$ in $[0, m)$, $0 \leq y < m$, and
+            /// $\lfloor 2^n x' \rfloor \equiv y \mod m$.
+            ///
+            /// # Worst-case complexity
+            /// $T(n, m) = O(mn \log n \log\log n)$
+            ///
+            /// $M(n) = O(n \log n)$
+            ///
+            /// where $T$ is time, $M$ is additional memory, $n$ is
+            /// `max(self.significant_bits(), m.significant_bits())`, and $m$ is `bits`.
+            ///
+            /// # Panics
+            /// Panics if `m` is zero.
+            ///
+            /// # Examples
+            /// See [here](super::mod_shl#mod_shl_assign).
+            #[inline]
+            fn mod_shl_assign(&mut self, bits: $t, m: &'a Natural) {
+                *self = Integer::from((&*self).mod_shl(bits, m));
+            }
+        }
+    };
+}
+apply_to_primitive_ints!(impl_mod_shl);
@@ -0,0 +1,219 @@
+use crate::integer::Integer;
+use crate::natural::Natural;
+use malachite_base::num::arithmetic::traits::{ModSquare, ModSquareAssign};
+
+impl ModSquare<Natural> for Integer {
+    type Output = Natural;
+
+    /// Squares an [`Integer`] modulo a [`Natural`] $m$, returning a result in $[0, m)$. The
+    /// [`Integer`] need not be reduced modulo $m$; in particular, it may be negative. The
+    /// [`Integer`] and the [`Natural`] are both taken by value.
+    ///
+    /// $f(x, m) = y$, where $0 \leq y < m$ and $x^2 \equiv y \mod m$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), m.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `m` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::ModSquare;
+    /// use malachite_nz::integer::Integer;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!(Integer::from(-3).mod_square(Natural::from(5u32)), 4);
+    /// assert_eq!(Integer::from(-12).mod_square(Natural::from(10u32)), 4);
+    /// ```
+    #[inline]
+    fn mod_square(self, m: Natural) -> Natural {
+        self.mod_natural(&m).mod_square(m)
+    }
+}
+
+impl<'a> ModSquare<&'a Natural> for Integer {
+    type Output = Natural;
+
+    /// Squares an [`Integer`] modulo a [`Natural`] $m$, returning a result in $[0, m)$. The
+    /// [`Integer`] need not be reduced modulo $m$; in particular, it may be negative. The
+    /// [`Integer`] is taken by value and the [`Natural`] by reference.
+    ///
+    /// $f(x, m) = y$, where $0 \leq y < m$ and $x^2 \equiv y \mod m$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), m.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `m` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::ModSquare;
+    /// use malachite_nz::integer::Integer;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!(Integer::from(-3).mod_square(&Natural::from(5u32)), 4);
+    /// assert_eq!(Integer::from(-12).mod_square(&Natural::from(10u32)), 4);
+    /// ```
+    #[inline]
+    fn mod_square(self, m: &'a Natural) -> Natural {
+        self.mod_natural(m).mod_square(m)
+    }
+}
+
+impl<'a> ModSquare<Natural> for &'a Integer {
+    type Output = Natural;
+
+    /// Squares an [`Integer`] modulo a [`Natural`] $m$, returning a result in $[0, m)$. The
+    /// [`Integer`] need not be reduced modulo $m$; in particular, it may be negative. The
+    /// [`Integer`] is taken by reference and the [`Natural`] by value.
+    ///
+    /// $f(x, m) = y$, where $0 \leq y < m$ and $x^2 \equiv y \mod m$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), m.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `m` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::ModSquare;
+    /// use malachite_nz::integer::Integer;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!((&Integer::from(-3)).mod_square(Natural::from(5u32)), 4);
+    /// assert_eq!((&Integer::from(-12)).mod_square(Natural::from(10u32)), 4);
+    /// ```
+    #[inline]
+    fn mod_square(self, m: Natural) -> Natural {
+        self.mod_natural_ref(&m).mod_square(m)
+    }
+}
+
+impl<'a, 'b> ModSquare<&'b Natural> for &'a Integer {
+    type Output = Natural;
+
+    /// Squares an [`Integer`] modulo a [`Natural`] $m$, returning a result in $[0, m)$. The
+    /// [`Integer`] need not be reduced modulo $m$; in particular, it may be negative. The
+    /// [`Integer`] and the [`Natural`] are both taken by reference.
+    ///
+    /// $f(x, m) = y$, where $0 \leq y < m$ and $x^2 \equiv y \mod m$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), m.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `m` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::ModSquare;
+    /// use malachite_nz::integer::Integer;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!((&Integer::from(-3)).mod_square(&Natural::from(5u32)), 4);
+    /// assert_eq!((&Integer::from(-12)).mod_square(&Natural::from(10u32)), 4);
+    /// ```
+    #[inline]
+    fn mod_square(self, m: &'b Natural) -> Natural {
+        self.mod_natural_ref(m).mod_square(m)
+    }
+}
+
+impl ModSquareAssign<Natural> for Integer {
+    /// Squares an [`Integer`] modulo a [`Natural`] $m$, in place. The [`Integer`] need not be
+    /// reduced modulo $m$; in particular, it may be negative, but the result is always in $[0, m)$.
+    /// The [`Natural`] on the right-hand side is taken by value.
+    ///
+    /// $x \gets y$, where $0 \leq y < m$ and $x^2 \equiv y \mod m$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), m.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `m` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::ModSquareAssign;
+    /// use malachite_nz::integer::Integer;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// let mut x = Integer::from(-3);
+    /// x.mod_square_assign(Natural::from(5u32));
+    /// assert_eq!(x, 4);
+    ///
+    /// let mut x = Integer::from(-12);
+    /// x.mod_square_assign(Natural::from(10u32));
+    /// assert_eq!(x, 4);
+    /// ```
+    #[inline]
+    fn mod_square_assign(&mut self, m: Natural) {
+        *self = Integer::from((&*self).mod_square(m));
+    }
+}
+
+impl<'a> ModSquareAssign<&'a Natural> for Integer {
+    /// Squares an [`Integer`] modulo a [`Natural`] $m$, in place. The [`Integer`] need not be
+    /// reduced modulo $m$; in particular, it may be negative, but the result is always in $[0, m)$.
+    /// The [`Natural`] on the right-hand side is taken by reference.
+    ///
+    /// $x \gets y$, where $0 \leq y < m$ and $x^2 \equiv y \mod m$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), m.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `m` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::ModSquareAssign;
+    /// use malachite_nz::integer::Integer;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// let mut x = Integer::from(-3);
+    /// x.mod_square_assign(&Natural::from(5u32));
+    /// assert_eq!(x, 4);
+    ///
+    /// let mut x = Integer::from(-12);
+    /// x.mod_square_assign(&Natural::from(10u32));
+    /// assert_eq!(x, 4);
+    /// ```
+    #[inline]
+    fn mod_square_assign(&mut self, m: &'a Natural) {
+        *self = Integer::from((&*self).mod_square(m));
+    }
+}
@@ -0,0 +1,444 @@
+use crate::integer::Integer;
+use crate::natural::Natural;
+use malachite_base::num::arithmetic::traits::{ModSub, ModSubAssign};
+
+impl ModSub<Integer, Natural> for Integer {
+    type Output = Natural;
+
+    /// Subtracts an [`Integer`] by another [`Integer`] modulo a [`Natural`] $m$, returning a result
+    /// in $[0, m)$. The [`Integer`]s need not be reduced modulo $m$; in particular, they may be
+    /// negative. All three arguments are taken by value.
+    ///
+    /// $f(x, y, m) = z$, where $0 \leq z < m$ and $x - y \equiv z \mod m$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), other.significant_bits(), m.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `m` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::ModSub;
+    /// use malachite_nz::integer::Integer;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!(Integer::from(-3).mod_sub(Integer::from(10), Natural::from(5u32)), 2);
+    /// assert_eq!(Integer::from(7).mod_sub(Integer::from(-8), Natural::from(10u32)), 5);
+    /// ```
+    #[inline]
+    fn mod_sub(self, other: Integer, m: Natural) -> Natural {
+        self.mod_natural(&m).mod_sub(other.mod_natural(&m), m)
+    }
+}
+
+impl<'a> ModSub<Integer, &'a Natural> for Integer {
+    type Output = Natural;
+
+    /// Subtracts an [`Integer`] by another [`Integer`] modulo a [`Natural`] $m$, returning a result
+    /// in $[0, m)$. The [`Integer`]s need not be reduced modulo $m$; in particular, they may be
+    /// negative. The [`Integer`]s are taken by value and the [`Natural`] by reference.
+    ///
+    /// $f(x, y, m) = z$, where $0 \leq z < m$ and $x - y \equiv z \mod m$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), other.significant_bits(), m.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `m` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::ModSub;
+    /// use malachite_nz::integer::Integer;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!(Integer::from(-3).mod_sub(Integer::from(10), &Natural::from(5u32)), 2);
+    /// assert_eq!(Integer::from(7).mod_sub(Integer::from(-8), &Natural::from(10u32)), 5);
+    /// ```
+    #[inline]
+    fn mod_sub(self, other: Integer, m: &'a Natural) -> Natural {
+        self.mod_natural(m).mod_sub(other.mod_natural(m), m)
+    }
+}
+
+impl<'a> ModSub<&'a Integer, Natural> for Integer {
+    type Output = Natural;
+
+    /// Subtracts an [`Integer`] by another [`Integer`] modulo a [`Natural`] $m$, returning a result
+    /// in $[0, m)$. The [`Integer`]s need not be reduced modulo $m$; in particular, they may be
+    /// negative. The first [`Integer`] and the [`Natural`] are taken by value and the second
+    /// [`Integer`] by reference.
+    ///
+    /// $f(x, y, m) = z$, where $0 \leq z < m$ and $x - y \equiv z \mod m$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), other.significant_bits(), m.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `m` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::ModSub;
+    /// use malachite_nz::integer::Integer;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!(Integer::from(-3).mod_sub(&Integer::from(10), Natural::from(5u32)), 2);
+    /// assert_eq!(Integer::from(7).mod_sub(&Integer::from(-8), Natural::from(10u32)), 5);
+    /// ```
+    #[inline]
+    fn mod_sub(self, other: &'a Integer, m: Natural) -> Natural {
+        self.mod_natural(&m).mod_sub(other.mod_natural_ref(&m), m)
+    }
+}
+
+impl<'a, 'b> ModSub<&'a Integer, &'b Natural> for Integer {
+    type Output = Natural;
+
+    /// Subtracts an [`Integer`] by another [`Integer`] modulo a [`Natural`] $m$, returning a result
+    /// in $[0, m)$. The [`Integer`]s need not be reduced modulo $m$; in particular, they may be
+    /// negative. The first [`Integer`] is taken by value and the second [`Integer`] and the
+    /// [`Natural`] by reference.
+    ///
+    /// $f(x, y, m) = z$, where $0 \leq z < m$ and $x - y \equiv z \mod m$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), other.significant_bits(), m.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `m` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::ModSub;
+    /// use malachite_nz::integer::Integer;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!(Integer::from(-3).mod_sub(&Integer::from(10), &Natural::from(5u32)), 2);
+    /// assert_eq!(Integer::from(7).mod_sub(&Integer::from(-8), &Natural::from(10u32)), 5);
+    /// ```
+    #[inline]
+    fn mod_sub(self, other: &'a Integer, m: &'b Natural) -> Natural {
+        self.mod_natural(m).mod_sub(other.mod_natural_ref(m), m)
+    }
+}
+
+impl<'a> ModSub<Integer, Natural> for &'a Integer {
+    type Output = Natural;
+
+    /// Subtracts an [`Integer`] by another [`Integer`] modulo a [`Natural`] $m$, returning a result
+    /// in $[0, m)$. The [`Integer`]s need not be reduced modulo $m$; in particular, they may be
+    /// negative. The first [`Integer`] is taken by reference and the second [`Integer`] and the
+    /// [`Natural`] by value.
+    ///
+    /// $f(x, y, m) = z$, where $0 \leq z < m$ and $x - y \equiv z \mod m$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), other.significant_bits(), m.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `m` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::ModSub;
+    /// use malachite_nz::integer::Integer;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!((&Integer::from(-3)).mod_sub(Integer::from(10), Natural::from(5u32)), 2);
+    /// assert_eq!((&Integer::from(7)).mod_sub(Integer::from(-8), Natural::from(10u32)), 5);
+    /// ```
+    #[inline]
+    fn mod_sub(self, other: Integer, m: Natural) -> Natural {
+        self.mod_natural_ref(&m).mod_sub(other.mod_natural(&m), m)
+    }
+}
+
+impl<'a, 'b> ModSub<Integer, &'b Natural> for &'a Integer {
+    type Output = Natural;
+
+    /// Subtracts an [`Integer`] by another [`Integer`] modulo a [`Natural`] $m$, returning a result
+    /// in $[0, m)$. The [`Integer`]s need not be reduced modulo $m$; in particular, they may be
+    /// negative. The first [`Integer`] and the [`Natural`] are taken by reference and the second
+    /// [`Integer`] by value.
+    ///
+    /// $f(x, y, m) = z$, where $0 \leq z < m$ and $x - y \equiv z \mod m$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), other.significant_bits(), m.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `m` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::ModSub;
+    /// use malachite_nz::integer::Integer;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!((&Integer::from(-3)).mod_sub(Integer::from(10), &Natural::from(5u32)), 2);
+    /// assert_eq!((&Integer::from(7)).mod_sub(Integer::from(-8), &Natural::from(10u32)), 5);
+    /// ```
+    #[inline]
+    fn mod_sub(self, other: Integer, m: &'b Natural) -> Natural {
+        self.mod_natural_ref(m).mod_sub(other.mod_natural(m), m)
+    }
+}
+
+impl<'a, 'b> ModSub<&'b Integer, Natural> for &'a Integer {
+    type Output = Natural;
+
+    /// Subtracts an [`Integer`] by another [`Integer`] modulo a [`Natural`] $m$, returning a result
+    /// in $[0, m)$. The [`Integer`]s need not be reduced modulo $m$; in particular, they may be
+    /// negative. The [`Integer`]s are taken by reference and the [`Natural`] by value.
+    ///
+    /// $f(x, y, m) = z$, where $0 \leq z < m$ and $x - y \equiv z \mod m$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), other.significant_bits(), m.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `m` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::ModSub;
+    /// use malachite_nz::integer::Integer;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!((&Integer::from(-3)).mod_sub(&Integer::from(10), Natural::from(5u32)), 2);
+    /// assert_eq!((&Integer::from(7)).mod_sub(&Integer::from(-8), Natural::from(10u32)), 5);
+    /// ```
+    #[inline]
+    fn mod_sub(self, other: &'b Integer, m: Natural) -> Natural {
+        self.mod_natural_ref(&m)
+            .mod_sub(other.mod_natural_ref(&m), m)
+    }
+}
+
+impl<'a, 'b, 'c> ModSub<&'b Integer, &'c Natural> for &'a Integer {
+    type Output = Natural;
+
+    /// Subtracts an [`Integer`] by another [`Integer`] modulo a [`Natural`] $m$, returning a result
+    /// in $[0, m)$. The [`Integer`]s need not be reduced modulo $m$; in particular, they may be
+    /// negative. All three arguments are taken by reference.
+    ///
+    /// $f(x, y, m) = z$, where $0 \leq z < m$ and $x - y \equiv z \mod m$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), other.significant_bits(), m.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `m` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::ModSub;
+    /// use malachite_nz::integer::Integer;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!((&Integer::from(-3)).mod_sub(&Integer::from(10), &Natural::from(5u32)), 2);
+    /// assert_eq!((&Integer::from(7)).mod_sub(&Integer::from(-8), &Natural::from(10u32)), 5);
+    /// ```
+    #[inline]
+    fn mod_sub(self, other: &'b Integer, m: &'c Natural) -> Natural {
+        self.mod_natural_ref(m).mod_sub(other.mod_natural_ref(m), m)
+    }
+}
+
+impl ModSubAssign<Integer, Natural> for Integer {
+    /// Subtracts an [`Integer`] by another [`Integer`] modulo a [`Natural`] $m$, in place. The
+    /// [`Integer`]s need not be reduced modulo $m$; in particular, they may be negative, but the
+    /// result is always in $[0, m)$. The [`Integer`] and [`Natural`] on the right-hand side are
+    /// both taken by value.
+    ///
+    /// $x \gets z$, where $0 \leq z < m$ and $x - y \equiv z \mod m$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), other.significant_bits(), m.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `m` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::ModSubAssign;
+    /// use malachite_nz::integer::Integer;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// let mut x = Integer::from(-3);
+    /// x.mod_sub_assign(Integer::from(10), Natural::from(5u32));
+    /// assert_eq!(x, 2);
+    ///
+    /// let mut x = Integer::from(7);
+    /// x.mod_sub_assign(Integer::from(-8), Natural::from(10u32));
+    /// assert_eq!(x, 5);
+    /// ```
+    #[inline]
+    fn mod_sub_assign(&mut self, other: Integer, m: Natural) {
+        *self = Integer::from((&*self).mod_sub(other, m));
+    }
+}
+
+impl<'a> ModSubAssign<Integer, &'a Natural> for Integer {
+    /// Subtracts an [`Integer`] by another [`Integer`] modulo a [`Natural`] $m$, in place. The
+    /// [`Integer`]s need not be reduced modulo $m$; in particular, they may be negative, but the
+    /// result is always in $[0, m)$. The [`Integer`] on the right-hand side is taken by value and
+    /// the [`Natural`] by reference.
+    ///
+    /// $x \gets z$, where $0 \leq z < m$ and $x - y \equiv z \mod m$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), other.significant_bits(), m.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `m` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::ModSubAssign;
+    /// use malachite_nz::integer::Integer;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// let mut x = Integer::from(-3);
+    /// x.mod_sub_assign(Integer::from(10), &Natural::from(5u32));
+    /// assert_eq!(x, 2);
+    ///
+    /// let mut x = Integer::from(7);
+    /// x.mod_sub_assign(Integer::from(-8), &Natural::from(10u32));
+    /// assert_eq!(x, 5);
+    /// ```
+    #[inline]
+    fn mod_sub_assign(&mut self, other: Integer, m: &'a Natural) {
+        *self = Integer::from((&*self).mod_sub(other, m));
+    }
+}
+
+impl<'a> ModSubAssign<&'a Integer, Natural> for Integer {
+    /// Subtracts an [`Integer`] by another [`Integer`] modulo a [`Natural`] $m$, in place. The
+    /// [`Integer`]s need not be reduced modulo $m$; in particular, they may be negative, but the
+    /// result is always in $[0, m)$. The [`Integer`] on the right-hand side is taken by reference
+    /// and the [`Natural`] by value.
+    ///
+    /// $x \gets z$, where $0 \leq z < m$ and $x - y \equiv z \mod m$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), other.significant_bits(), m.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `m` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::ModSubAssign;
+    /// use malachite_nz::integer::Integer;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// let mut x = Integer::from(-3);
+    /// x.mod_sub_assign(&Integer::from(10), Natural::from(5u32));
+    /// assert_eq!(x, 2);
+    ///
+    /// let mut x = Integer::from(7);
+    /// x.mod_sub_assign(&Integer::from(-8), Natural::from(10u32));
+    /// assert_eq!(x, 5);
+    /// ```
+    #[inline]
+    fn mod_sub_assign(&mut self, other: &'a Integer, m: Natural) {
+        *self = Integer::from((&*self).mod_sub(other, m));
+    }
+}
+
+impl<'a, 'b> ModSubAssign<&'a Integer, &'b Natural> for Integer {
+    /// Subtracts an [`Integer`] by another [`Integer`] modulo a [`Natural`] $m$, in place. The
+    /// [`Integer`]s need not be reduced modulo $m$; in particular, they may be negative, but the
+    /// result is always in $[0, m)$. The [`Integer`] and [`Natural`] on the right-hand side are
+    /// both taken by reference.
+    ///
+    /// $x \gets z$, where $0 \leq z < m$ and $x - y \equiv z \mod m$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), other.significant_bits(), m.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `m` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::ModSubAssign;
+    /// use malachite_nz::integer::Integer;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// let mut x = Integer::from(-3);
+    /// x.mod_sub_assign(&Integer::from(10), &Natural::from(5u32));
+    /// assert_eq!(x, 2);
+    ///
+    /// let mut x = Integer::from(7);
+    /// x.mod_sub_assign(&Integer::from(-8), &Natural::from(10u32));
+    /// assert_eq!(x, 5);
+    /// ```
+    #[inline]
+    fn mod_sub_assign(&mut self, other: &'a Integer, m: &'b Natural) {
+        *self = Integer::from((&*self).mod_sub(other, m));
+    }
+}
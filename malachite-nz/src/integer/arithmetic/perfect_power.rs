@@ -0,0 +1,85 @@
+use crate::integer::Integer;
+use crate::natural::arithmetic::perfect_power::perfect_power_decomposition;
+use malachite_base::num::arithmetic::traits::{
+    IsPerfectPower, Parity, PerfectPowerDecomposition, SquareAssign,
+};
+
+impl IsPerfectPower for Integer {
+    /// Determines whether an [`Integer`] is a perfect power.
+    ///
+    /// A number is a perfect power if it equals $b^k$ for some integer $b$ and some $k > 1$. In
+    /// particular, 0, 1, and $-1$ are perfect powers. A negative number is a perfect power iff its
+    /// absolute value is a perfect power with an odd exponent.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::IsPerfectPower;
+    /// use malachite_nz::integer::Integer;
+    ///
+    /// assert_eq!(Integer::from(0).is_perfect_power(), true);
+    /// assert_eq!(Integer::from(-1).is_perfect_power(), true);
+    /// assert_eq!(Integer::from(100).is_perfect_power(), true);
+    /// assert_eq!(Integer::from(-100).is_perfect_power(), false);
+    /// assert_eq!(Integer::from(-1000).is_perfect_power(), true);
+    /// assert_eq!(Integer::from(-64).is_perfect_power(), true);
+    /// ```
+    fn is_perfect_power(&self) -> bool {
+        if self.sign {
+            self.abs.is_perfect_power()
+        } else {
+            self.abs == 1u32 || self.perfect_power_decomposition().1 != 1
+        }
+    }
+}
+
+impl PerfectPowerDecomposition for Integer {
+    /// Writes an [`Integer`] as a perfect power with the largest possible exponent.
+    ///
+    /// Returns $(b, k)$ such that $b^k$ is the number and $k$ is as large as possible. If the
+    /// number is not a perfect power, $k$ is 1. Since 0, 1, and $-1$ are $k$th powers for
+    /// infinitely many $k$, their decomposition is $(0, 1)$, $(1, 1)$, or $(-1, 1)$.
+    ///
+    /// If the number is negative, $k$ is odd and $b$ is negative.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::{PerfectPowerDecomposition, Pow};
+    /// use malachite_nz::integer::Integer;
+    ///
+    /// assert_eq!(Integer::from(-10).perfect_power_decomposition(), (Integer::from(-10), 1));
+    /// assert_eq!(Integer::from(1000000).perfect_power_decomposition(), (Integer::from(10), 6));
+    /// assert_eq!(Integer::from(-1000000).perfect_power_decomposition(), (Integer::from(-100), 3));
+    /// assert_eq!(Integer::from(-64).perfect_power_decomposition(), (Integer::from(-4), 3));
+    /// assert_eq!(
+    ///     Integer::from(-3).pow(101).perfect_power_decomposition(),
+    ///     (Integer::from(-3), 101)
+    /// );
+    /// ```
+    fn perfect_power_decomposition(&self) -> (Integer, u64) {
+        let (mut base, mut exponent) = perfect_power_decomposition(&self.abs);
+        if self.sign {
+            return (Integer::from(base), exponent);
+        }
+        // An odd power of a negative number is negative, so move the factors of 2 from the exponent
+        // into the base.
+        while exponent.even() {
+            base.square_assign();
+            exponent >>= 1;
+        }
+        (Integer::from_sign_and_abs(false, base), exponent)
+    }
+}
@@ -176,6 +176,9 @@ pub mod natural;
 /// [`Integer`](integer::Integer), a type representing integers with arbitrarily large absolute
 /// values.
 pub mod integer;
+/// [`GaussianInteger`](gaussian_integer::GaussianInteger), a type representing complex numbers
+/// whose real and imaginary parts are [`Integer`](integer::Integer)s.
+pub mod gaussian_integer;
 
 #[cfg(feature = "test_build")]
 pub mod test_util;
@@ -0,0 +1,340 @@
+use crate::integer::Integer;
+use crate::natural::Natural;
+use malachite_base::num::arithmetic::traits::{
+    ChineseRemainder, DivExact, DivisibleBy, ExtendedGcd, Mod, ModInverse, Square, UnsignedAbs,
+};
+use malachite_base::num::basic::traits::{One, Zero};
+
+// Combines $x \equiv a \mod m$ and $x \equiv b \mod n$ into a single congruence modulo
+// $\operatorname{lcm}(m, n)$, or returns `None` if there is no solution. Assumes that $a < m$ and
+// $b < n$.
+//
+// If $g = \gcd(m, n) = sm + tn$, a solution exists iff $g \mid b - a$, and then $x = a + mk$, where
+// $k \equiv s(b - a)/g \mod n/g$.
+pub(crate) fn chinese_remainder_pair(
+    a: Natural,
+    m: Natural,
+    b: &Natural,
+    n: &Natural,
+) -> Option<(Natural, Natural)> {
+    let (g, s, _) = (&m).extended_gcd(n);
+    let diff = Integer::from(b) - Integer::from(&a);
+    let g = Integer::from(g);
+    if !(&diff).divisible_by(&g) {
+        return None;
+    }
+    let n_over_g = Integer::from(n).div_exact(&g);
+    let k = (diff.div_exact(g) * s).mod_op(&n_over_g).unsigned_abs();
+    Some((a + &m * k, m * n_over_g.unsigned_abs()))
+}
+
+fn chinese_remainder(residues: &[(Natural, Natural)]) -> Option<(Natural, Natural)> {
+    let mut x = Natural::ZERO;
+    let mut m = Natural::ONE;
+    for (a, n) in residues {
+        assert_ne!(*n, 0u32);
+        (x, m) = chinese_remainder_pair(x, m, &(a % n), n)?;
+    }
+    Some((x, m))
+}
+
+impl ChineseRemainder for Natural {
+    type Modulus = Natural;
+
+    /// Uses the Chinese remainder theorem to combine several congruences $x \equiv a_i \mod m_i$
+    /// into a single congruence $x \equiv a \mod m$, where $m = \operatorname{lcm}(m_1, \ldots,
+    /// m_k)$ and $0 \leq a < m$.
+    ///
+    /// The moduli need not be coprime. If they are not, the congruences may be inconsistent, in
+    /// which case `None` is returned. The residues need not be reduced. If `residues` is empty, $(0,
+    /// 1)$ is returned.
+    ///
+    /// The congruences are combined one at a time, using
+    /// [`extended_gcd`](ExtendedGcd::extended_gcd). To combine many residues against the same
+    /// pairwise coprime moduli, use [`ChineseRemainderBasis`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n, k) = O(kn (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is the number of bits of the result's
+    /// modulus, and $k$ is `residues.len()`.
+    ///
+    /// # Panics
+    /// Panics if any modulus is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::ChineseRemainder;
+    /// use malachite_base::strings::ToDebugString;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// let residues = |xs: &[(u32, u32)]| {
+    ///     xs.iter()
+    ///         .map(|&(a, m)| (Natural::from(a), Natural::from(m)))
+    ///         .collect::<Vec<_>>()
+    /// };
+    /// assert_eq!(
+    ///     Natural::chinese_remainder(&residues(&[(2, 3), (3, 5), (2, 7)])).to_debug_string(),
+    ///     "Some((23, 105))"
+    /// );
+    /// assert_eq!(
+    ///     Natural::chinese_remainder(&residues(&[(2, 4), (4, 6)])).to_debug_string(),
+    ///     "Some((10, 12))"
+    /// );
+    /// assert_eq!(
+    ///     Natural::chinese_remainder(&residues(&[(1, 4), (2, 6)])).to_debug_string(),
+    ///     "None"
+    /// );
+    /// assert_eq!(
+    ///     Natural::chinese_remainder(&[]).to_debug_string(),
+    ///     "Some((0, 1))"
+    /// );
+    /// ```
+    #[inline]
+    fn chinese_remainder(residues: &[(Natural, Natural)]) -> Option<(Natural, Natural)> {
+        chinese_remainder(residues)
+    }
+}
+
+/// A precomputed basis for the Chinese remainder theorem over a fixed list of pairwise coprime
+/// moduli $m_1, \ldots, m_k$.
+///
+/// The basis stores a product tree of the moduli and the inverses $(M/m_i)^{-1} \mod m_i$, where
+/// $M = \prod_i m_i$. With these, [`recombine`](Self::recombine) finds the residue modulo $M$
+/// corresponding to residues modulo each $m_i$, and [`reduce`](Self::reduce) does the opposite
+/// using a remainder tree. Both take $O(\log k)$ levels of multiplications or divisions, which is
+/// much faster than combining the residues one at a time when $k$ is large.
+///
+/// This is useful for multi-modular algorithms, which reduce their inputs modulo many small
+/// moduli, compute modulo each of them, and recombine the results.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ChineseRemainderBasis {
+    // product_tree[0] holds the moduli, and each subsequent level holds the products of adjacent
+    // pairs from the previous level, with the last element of an odd-length level carried over
+    // unchanged. The last level has a single element, unless there are no moduli.
+    product_tree: Vec<Vec<Natural>>,
+    // inverses[i] is (M / m_i)^(-1) mod m_i, or 0 if m_i is 1.
+    inverses: Vec<Natural>,
+    modulus: Natural,
+}
+
+fn build_product_tree(moduli: Vec<Natural>) -> Vec<Vec<Natural>> {
+    let mut tree = vec![moduli];
+    while tree.last().unwrap().len() > 1 {
+        let level = tree.last().unwrap();
+        let next = level
+            .chunks(2)
+            .map(|pair| {
+                if pair.len() == 2 {
+                    &pair[0] * &pair[1]
+                } else {
+                    pair[0].clone()
+                }
+            })
+            .collect();
+        tree.push(next);
+    }
+    tree
+}
+
+// Reduces `x` modulo each leaf of `tree`, descending from the root. Assumes that `x` is less than
+// the root.
+fn remainder_tree(x: Natural, tree: &[Vec<Natural>]) -> Vec<Natural> {
+    let mut remainders = vec![x];
+    for level in tree.iter().rev().skip(1) {
+        remainders = level
+            .iter()
+            .enumerate()
+            .map(|(i, m)| &remainders[i >> 1] % m)
+            .collect();
+    }
+    remainders
+}
+
+impl ChineseRemainderBasis {
+    /// Creates a [`ChineseRemainderBasis`] from a list of moduli. Returns `None` if the moduli are
+    /// not pairwise coprime.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^3 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the total number of bits of the
+    /// moduli.
+    ///
+    /// # Panics
+    /// Panics if any modulus is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::natural::arithmetic::chinese_remainder::ChineseRemainderBasis;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// let moduli = [3u32, 5, 7].map(Natural::from);
+    /// assert_eq!(ChineseRemainderBasis::new(&moduli).unwrap().modulus(), &105u32);
+    /// assert!(ChineseRemainderBasis::new(&[Natural::from(4u32), Natural::from(6u32)]).is_none());
+    /// ```
+    pub fn new(moduli: &[Natural]) -> Option<ChineseRemainderBasis> {
+        assert!(moduli.iter().all(|m| *m != 0u32));
+        let product_tree = build_product_tree(moduli.to_vec());
+        let modulus = match product_tree.last().unwrap().first() {
+            Some(m) => m.clone(),
+            None => Natural::ONE,
+        };
+        // M mod m_i^2 is found using a remainder tree over the squares of the products; dividing
+        // it by m_i gives (M / m_i) mod m_i.
+        let square_tree: Vec<Vec<Natural>> = product_tree
+            .iter()
+            .map(|level| level.iter().map(Square::square).collect())
+            .collect();
+        let mut inverses = Vec::with_capacity(moduli.len());
+        if !moduli.is_empty() {
+            for (r, m) in remainder_tree(modulus.clone(), &square_tree)
+                .into_iter()
+                .zip(moduli.iter())
+            {
+                let cofactor = r.div_exact(m);
+                if *m == 1u32 {
+                    inverses.push(Natural::ZERO);
+                } else if cofactor == 0u32 {
+                    return None;
+                } else {
+                    inverses.push(cofactor.mod_inverse(m)?);
+                }
+            }
+        }
+        Some(ChineseRemainderBasis {
+            product_tree,
+            inverses,
+            modulus,
+        })
+    }
+
+    /// Returns the moduli of a [`ChineseRemainderBasis`].
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::strings::ToDebugString;
+    /// use malachite_nz::natural::arithmetic::chinese_remainder::ChineseRemainderBasis;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// let moduli = [3u32, 5, 7].map(Natural::from);
+    /// assert_eq!(
+    ///     ChineseRemainderBasis::new(&moduli).unwrap().moduli().to_debug_string(),
+    ///     "[3, 5, 7]"
+    /// );
+    /// ```
+    #[inline]
+    pub fn moduli(&self) -> &[Natural] {
+        &self.product_tree[0]
+    }
+
+    /// Returns the product of the moduli of a [`ChineseRemainderBasis`].
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::natural::arithmetic::chinese_remainder::ChineseRemainderBasis;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// let moduli = [3u32, 5, 7].map(Natural::from);
+    /// assert_eq!(ChineseRemainderBasis::new(&moduli).unwrap().modulus(), &105u32);
+    /// assert_eq!(ChineseRemainderBasis::new(&[]).unwrap().modulus(), &1u32);
+    /// ```
+    #[inline]
+    pub const fn modulus(&self) -> &Natural {
+        &self.modulus
+    }
+
+    /// Finds the unique residue $x$ modulo the product $M$ of the moduli such that $x \equiv a_i
+    /// \mod m_i$ for each $i$.
+    ///
+    /// The residues need not be reduced.
+    ///
+    /// $f((a_i)_{i=1}^k) = \sum_{i=1}^k a_i c_i M/m_i \mod M$, where $c_i = (M/m_i)^{-1} \mod m_i$.
+    /// The sum is computed using the product tree, so that each level of the tree takes time
+    /// comparable to a multiplication of size $M$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.modulus().significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `residues` does not have the same length as the moduli.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::natural::arithmetic::chinese_remainder::ChineseRemainderBasis;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// let basis = ChineseRemainderBasis::new(&[3u32, 5, 7].map(Natural::from)).unwrap();
+    /// assert_eq!(basis.recombine(&[2u32, 3, 2].map(Natural::from)), 23);
+    /// assert_eq!(basis.recombine(&[5u32, 8, 100].map(Natural::from)), 23);
+    /// ```
+    pub fn recombine(&self, residues: &[Natural]) -> Natural {
+        let moduli = self.moduli();
+        assert_eq!(residues.len(), moduli.len());
+        if residues.is_empty() {
+            return Natural::ZERO;
+        }
+        let mut values: Vec<Natural> = residues
+            .iter()
+            .zip(moduli.iter())
+            .zip(self.inverses.iter())
+            .map(|((a, m), c)| (a % m) * c % m)
+            .collect();
+        for level in &self.product_tree[..self.product_tree.len() - 1] {
+            let mut next = Vec::with_capacity((values.len() + 1) >> 1);
+            let mut values_iter = values.into_iter();
+            for products in level.chunks(2) {
+                let left = values_iter.next().unwrap();
+                next.push(if products.len() == 2 {
+                    left * &products[1] + values_iter.next().unwrap() * &products[0]
+                } else {
+                    left
+                });
+            }
+            values = next;
+        }
+        values.pop().unwrap() % &self.modulus
+    }
+
+    /// Reduces a [`Natural`] modulo each of the moduli of a [`ChineseRemainderBasis`], using a
+    /// remainder tree.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(x.significant_bits(), self.modulus().significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::strings::ToDebugString;
+    /// use malachite_nz::natural::arithmetic::chinese_remainder::ChineseRemainderBasis;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// let basis = ChineseRemainderBasis::new(&[3u32, 5, 7].map(Natural::from)).unwrap();
+    /// assert_eq!(basis.reduce(&Natural::from(23u32)).to_debug_string(), "[2, 3, 2]");
+    /// assert_eq!(basis.reduce(&Natural::from(1000u32)).to_debug_string(), "[1, 0, 6]");
+    /// ```
+    pub fn reduce(&self, x: &Natural) -> Vec<Natural> {
+        if self.moduli().is_empty() {
+            return Vec::new();
+        }
+        remainder_tree(x % &self.modulus, &self.product_tree)
+    }
+}
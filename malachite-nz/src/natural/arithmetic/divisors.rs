@@ -0,0 +1,288 @@
+use crate::natural::Natural;
+use malachite_base::num::arithmetic::traits::{DivExact, DivisorCount, DivisorSum, Divisors, Pow};
+use malachite_base::num::basic::traits::One;
+use malachite_base::num::factorization::traits::Factor;
+
+fn divisor_count_from_factorization(factors: &[(Natural, u64)]) -> Natural {
+    let mut count = Natural::ONE;
+    for &(_, e) in factors {
+        count *= Natural::from(e + 1);
+    }
+    count
+}
+
+fn divisor_sum_from_factorization(factors: &[(Natural, u64)], k: u64) -> Natural {
+    if k == 0 {
+        return divisor_count_from_factorization(factors);
+    }
+    let mut sum = Natural::ONE;
+    for (p, e) in factors {
+        // $1 + p^k + p^{2k} + \ldots + p^{ek} = (p^{k(e+1)} - 1) / (p^k - 1)$
+        let numerator = p.pow(k * (e + 1)) - Natural::ONE;
+        sum *= numerator.div_exact(p.pow(k) - Natural::ONE);
+    }
+    sum
+}
+
+fn divisors_from_factorization(factors: &[(Natural, u64)]) -> Vec<Natural> {
+    let mut divisors = vec![Natural::ONE];
+    for (p, e) in factors {
+        let previous_len = divisors.len();
+        let mut power = Natural::ONE;
+        for _ in 0..*e {
+            power *= p;
+            for i in 0..previous_len {
+                let d = &divisors[i] * &power;
+                divisors.push(d);
+            }
+        }
+    }
+    divisors.sort_unstable();
+    divisors
+}
+
+/// An iterator that generates the positive divisors of a [`Natural`], in ascending order.
+///
+/// This `struct` is created by [`Divisors::divisors`] and
+/// [`Divisors::divisors_from_factorization`]; see their documentation for more.
+#[derive(Clone, Debug)]
+pub struct NaturalDivisorsIterator(std::vec::IntoIter<Natural>);
+
+impl Iterator for NaturalDivisorsIterator {
+    type Item = Natural;
+
+    #[inline]
+    fn next(&mut self) -> Option<Natural> {
+        self.0.next()
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.0.size_hint()
+    }
+}
+
+impl DoubleEndedIterator for NaturalDivisorsIterator {
+    #[inline]
+    fn next_back(&mut self) -> Option<Natural> {
+        self.0.next_back()
+    }
+}
+
+impl ExactSizeIterator for NaturalDivisorsIterator {}
+
+impl DivisorCount for Natural {
+    /// Counts the positive divisors of a [`Natural`].
+    ///
+    /// If $n = \prod_i p_i^{e_i}$, then the number of divisors is $\prod_i (e_i + 1)$.
+    ///
+    /// # Worst-case complexity
+    /// The running time is dominated by the factorization of `self`; see
+    /// [`factor`](Factor::factor).
+    ///
+    /// # Panics
+    /// Panics if `self` is 0.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::DivisorCount;
+    /// use malachite_nz::natural::Natural;
+    /// use std::str::FromStr;
+    ///
+    /// assert_eq!(Natural::from(1u32).divisor_count(), 1);
+    /// assert_eq!(Natural::from(360u32).divisor_count(), 24);
+    /// assert_eq!(
+    ///     Natural::from_str("1000000000000000000000000000000").unwrap().divisor_count(),
+    ///     961
+    /// );
+    /// ```
+    #[inline]
+    fn divisor_count(&self) -> Natural {
+        divisor_count_from_factorization(&self.factor())
+    }
+
+    /// Counts the positive divisors of a [`Natural`], given its prime factorization.
+    ///
+    /// `factors` must be the prime factorization of the number, in the format returned by
+    /// [`factor`](Factor::factor); this is not checked. The empty factorization represents 1.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `factors.len()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::DivisorCount;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!(Natural::divisor_count_from_factorization(&[]), 1);
+    /// assert_eq!(
+    ///     Natural::divisor_count_from_factorization(&[
+    ///         (Natural::from(2u32), 3),
+    ///         (Natural::from(3u32), 2),
+    ///         (Natural::from(5u32), 1)
+    ///     ]),
+    ///     24
+    /// );
+    /// ```
+    #[inline]
+    fn divisor_count_from_factorization(factors: &[(Natural, u64)]) -> Natural {
+        divisor_count_from_factorization(factors)
+    }
+}
+
+impl DivisorSum for Natural {
+    /// Computes the sum of the $k$th powers of the positive divisors of a [`Natural`].
+    ///
+    /// $$
+    /// \sigma_k(n) = \sum_{d \mid n} d^k = \prod_i \sum_{j=0}^{e_i} p_i^{jk},
+    /// $$
+    /// where $n = \prod_i p_i^{e_i}$. In particular, $\sigma_0(n)$ is the number of divisors of
+    /// $n$ and $\sigma_1(n)$ is their sum.
+    ///
+    /// # Worst-case complexity
+    /// The running time is dominated by the factorization of `self`; see
+    /// [`factor`](Factor::factor).
+    ///
+    /// # Panics
+    /// Panics if `self` is 0.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::DivisorSum;
+    /// use malachite_nz::natural::Natural;
+    /// use std::str::FromStr;
+    ///
+    /// assert_eq!(Natural::from(1u32).divisor_sum(1), 1);
+    /// assert_eq!(Natural::from(360u32).divisor_sum(0), 24);
+    /// assert_eq!(Natural::from(360u32).divisor_sum(1), 1170);
+    /// assert_eq!(Natural::from(360u32).divisor_sum(2), 201110);
+    /// assert_eq!(
+    ///     Natural::from_str("18446744073709551617").unwrap().divisor_sum(2),
+    ///     Natural::from_str("340282366925465118592016216137989881860").unwrap()
+    /// );
+    /// ```
+    #[inline]
+    fn divisor_sum(&self, k: u64) -> Natural {
+        divisor_sum_from_factorization(&self.factor(), k)
+    }
+
+    /// Computes the sum of the $k$th powers of the positive divisors of a [`Natural`], given its
+    /// prime factorization.
+    ///
+    /// `factors` must be the prime factorization of the number, in the format returned by
+    /// [`factor`](Factor::factor); this is not checked. The empty factorization represents 1.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the number of bits in the result.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::DivisorSum;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!(Natural::divisor_sum_from_factorization(&[], 3), 1);
+    /// assert_eq!(
+    ///     Natural::divisor_sum_from_factorization(
+    ///         &[(Natural::from(2u32), 3), (Natural::from(3u32), 2), (Natural::from(5u32), 1)],
+    ///         1
+    ///     ),
+    ///     1170
+    /// );
+    /// ```
+    #[inline]
+    fn divisor_sum_from_factorization(factors: &[(Natural, u64)], k: u64) -> Natural {
+        divisor_sum_from_factorization(factors, k)
+    }
+}
+
+impl Divisors for Natural {
+    type I = NaturalDivisorsIterator;
+
+    /// Returns an iterator that generates the positive divisors of a [`Natural`], in ascending
+    /// order.
+    ///
+    /// All the divisors are computed before the iterator is returned.
+    ///
+    /// # Worst-case complexity
+    /// The running time is dominated by the factorization of `self` (see
+    /// [`factor`](Factor::factor)) and by the number of divisors.
+    ///
+    /// # Panics
+    /// Panics if `self` is 0.
+    ///
+    /// # Examples
+    /// ```
+    /// use itertools::Itertools;
+    /// use malachite_base::num::arithmetic::traits::Divisors;
+    /// use malachite_base::strings::ToDebugString;
+    /// use malachite_nz::natural::Natural;
+    /// use std::str::FromStr;
+    ///
+    /// assert_eq!(Natural::from(1u32).divisors().collect_vec().to_debug_string(), "[1]");
+    /// assert_eq!(
+    ///     Natural::from(360u32).divisors().collect_vec().to_debug_string(),
+    ///     "[1, 2, 3, 4, 5, 6, 8, 9, 10, 12, 15, 18, 20, 24, 30, 36, 40, 45, 60, 72, 90, 120, 180, \
+    ///     360]"
+    /// );
+    /// assert_eq!(
+    ///     Natural::from_str("18446744073709551617")
+    ///         .unwrap()
+    ///         .divisors()
+    ///         .collect_vec()
+    ///         .to_debug_string(),
+    ///     "[1, 274177, 67280421310721, 18446744073709551617]"
+    /// );
+    /// ```
+    #[inline]
+    fn divisors(&self) -> NaturalDivisorsIterator {
+        NaturalDivisorsIterator(divisors_from_factorization(&self.factor()).into_iter())
+    }
+
+    /// Returns an iterator that generates the positive divisors of a [`Natural`], in ascending
+    /// order, given the [`Natural`]'s prime factorization.
+    ///
+    /// `factors` must be the prime factorization of the number, in the format returned by
+    /// [`factor`](Factor::factor); this is not checked. The empty factorization represents 1.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n \log n \cdot m \log m \log\log m)$
+    ///
+    /// $M(n, m) = O(nm)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is the number of divisors, and $m$ is the
+    /// number of bits in the factored number.
+    ///
+    /// # Examples
+    /// ```
+    /// use itertools::Itertools;
+    /// use malachite_base::num::arithmetic::traits::Divisors;
+    /// use malachite_base::strings::ToDebugString;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!(
+    ///     Natural::divisors_from_factorization(&[]).collect_vec().to_debug_string(),
+    ///     "[1]"
+    /// );
+    /// assert_eq!(
+    ///     Natural::divisors_from_factorization(&[
+    ///         (Natural::from(2u32), 2),
+    ///         (Natural::from(3u32), 1)
+    ///     ])
+    ///     .collect_vec()
+    ///     .to_debug_string(),
+    ///     "[1, 2, 3, 4, 6, 12]"
+    /// );
+    /// ```
+    #[inline]
+    fn divisors_from_factorization(factors: &[(Natural, u64)]) -> NaturalDivisorsIterator {
+        NaturalDivisorsIterator(divisors_from_factorization(factors).into_iter())
+    }
+}
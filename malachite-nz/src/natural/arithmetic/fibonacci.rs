@@ -0,0 +1,432 @@
+use crate::natural::Natural;
+use malachite_base::num::arithmetic::traits::{
+    CheckedFibonacci, CheckedLucas, Fibonacci, Lucas, ModAdd, ModFibonacci, ModLucas,
+    ModLucasSequence, ModMul, ModSquare, ModSub, Parity, Square,
+};
+use malachite_base::num::basic::traits::{One, Two, Zero};
+use malachite_base::num::logic::traits::BitIterable;
+
+// Returns $(F_n, F_{n-1})$. Assumes $n > 0$.
+//
+// Starting from $(F_1, F_0)$, each bit of $n$ doubles the index using only two squarings:
+//
+// $$
+// F_{2k+1} = 4F_k^2 - F_{k-1}^2 + 2(-1)^k, \quad F_{2k-1} = F_k^2 + F_{k-1}^2,
+// \quad F_{2k} = F_{2k+1} - F_{2k-1}.
+// $$
+//
+// # Worst-case complexity
+// $T(n) = O(n \log n \log\log n)$
+//
+// $M(n) = O(n \log n)$
+//
+// where $T$ is time, $M$ is additional memory, and $n$ is `n`.
+fn fibonacci_2(n: u64) -> (Natural, Natural) {
+    assert_ne!(n, 0);
+    let mut f = Natural::ONE;
+    let mut f_prev = Natural::ZERO;
+    let mut k_odd = true;
+    for bit in n.bits().rev().skip(1) {
+        let f_squared = (&f).square();
+        let f_prev_squared = f_prev.square();
+        let f_2k_minus_1 = &f_squared + &f_prev_squared;
+        let mut f_2k_plus_1 = (f_squared << 2) - f_prev_squared;
+        if k_odd {
+            f_2k_plus_1 -= Natural::TWO;
+        } else {
+            f_2k_plus_1 += Natural::TWO;
+        }
+        let f_2k = &f_2k_plus_1 - &f_2k_minus_1;
+        if bit {
+            f = f_2k_plus_1;
+            f_prev = f_2k;
+        } else {
+            f = f_2k;
+            f_prev = f_2k_minus_1;
+        }
+        k_odd = bit;
+    }
+    (f, f_prev)
+}
+
+// Uses the identities
+//
+// $$
+// U_{2k} = U_k(2U_{k+1} - PU_k), \quad U_{2k+1} = U_{k+1}^2 - QU_k^2,
+// \quad U_{2k+2} = PU_{2k+1} - QU_{2k},
+// $$
+//
+// to compute $(U_n, U_{n+1})$ modulo $m$, one bit of $n$ at a time, most significant bit first,
+// and then recovers $V_n$ as $2U_{n+1} - PU_n$. No division by 2 is needed, so $m$ may be even.
+//
+// # Worst-case complexity
+// $T(n, k) = O(kn \log n \log\log n)$
+//
+// $M(n) = O(n \log n)$
+//
+// where $T$ is time, $M$ is additional memory, $n$ is `m.significant_bits()`, and $k$ is the number
+// of bits.
+fn mod_lucas_sequence<I: Iterator<Item = bool>>(
+    p: &Natural,
+    q: &Natural,
+    bits: I,
+    m: &Natural,
+) -> (Natural, Natural) {
+    assert!(p < m, "p must be reduced mod m, but {} >= {}", p, m);
+    assert!(q < m, "q must be reduced mod m, but {} >= {}", q, m);
+    let mut u = Natural::ZERO;
+    let mut u_next = Natural::ONE % m;
+    for bit in bits {
+        let u_2k = (&u).mod_mul(
+            (&u_next).mod_add(&u_next, m).mod_sub(p.mod_mul(&u, m), m),
+            m,
+        );
+        let u_2k_plus_1 = (&u_next)
+            .mod_square(m)
+            .mod_sub(q.mod_mul(u.mod_square(m), m), m);
+        if bit {
+            u_next = p.mod_mul(&u_2k_plus_1, m).mod_sub(q.mod_mul(u_2k, m), m);
+            u = u_2k_plus_1;
+        } else {
+            u = u_2k;
+            u_next = u_2k_plus_1;
+        }
+    }
+    let v = (&u_next).mod_add(&u_next, m).mod_sub(p.mod_mul(&u, m), m);
+    (u, v)
+}
+
+// The Fibonacci and Lucas numbers are $U_n(1, -1)$ and $V_n(1, -1)$.
+fn mod_fibonacci_and_lucas<I: Iterator<Item = bool>>(bits: I, m: &Natural) -> (Natural, Natural) {
+    mod_lucas_sequence(&(Natural::ONE % m), &(m - Natural::ONE), bits, m)
+}
+
+impl Fibonacci for Natural {
+    /// Computes the $n$th Fibonacci number.
+    ///
+    /// $f(n) = F_n$, where $F_0 = 0$, $F_1 = 1$, and $F_{n+2} = F_{n+1} + F_n$.
+    ///
+    /// $F_n = O(\varphi^n)$, where $\varphi = (1 + \sqrt{5})/2$.
+    ///
+    /// The index is halved repeatedly, and $(F_k, F_{k-1})$ is computed for each prefix $k$ of
+    /// $n$ using two squarings per step. The last step takes a single multiplication.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `n`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::Fibonacci;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!(Natural::fibonacci(0), 0);
+    /// assert_eq!(Natural::fibonacci(1), 1);
+    /// assert_eq!(Natural::fibonacci(2), 1);
+    /// assert_eq!(Natural::fibonacci(10), 55);
+    /// assert_eq!(
+    ///     Natural::fibonacci(100).to_string(),
+    ///     "354224848179261915075"
+    /// );
+    /// assert_eq!(
+    ///     Natural::fibonacci(300).to_string(),
+    ///     "222232244629420445529739893461909967206666939096499764990979600"
+    /// );
+    /// ```
+    fn fibonacci(n: u64) -> Natural {
+        if let Some(f) = u64::checked_fibonacci(n) {
+            return Natural::from(f);
+        }
+        let k = n >> 1;
+        let (f, f_prev) = fibonacci_2(k);
+        if n.odd() {
+            // F_{2k+1} = (2F_k + F_{k-1})(2F_k - F_{k-1}) + 2(-1)^k
+            let twice_f = f << 1;
+            let mut result = (&twice_f + &f_prev) * (twice_f - f_prev);
+            if k.odd() {
+                result -= Natural::TWO;
+            } else {
+                result += Natural::TWO;
+            }
+            result
+        } else {
+            // F_{2k} = F_k(F_k + 2F_{k-1})
+            let sum = &f + (f_prev << 1);
+            f * sum
+        }
+    }
+}
+
+impl Lucas for Natural {
+    /// Computes the $n$th Lucas number.
+    ///
+    /// $f(n) = L_n$, where $L_0 = 2$, $L_1 = 1$, and $L_{n+2} = L_{n+1} + L_n$.
+    ///
+    /// $L_n = O(\varphi^n)$, where $\varphi = (1 + \sqrt{5})/2$.
+    ///
+    /// $L_n$ is computed from $F_k$ and $F_{k-1}$, where $k = \lfloor n/2 \rfloor$, using
+    /// $L_{2k} = L_k^2 - 2(-1)^k$ and $L_{2k+1} = L_kL_{k+1} - (-1)^k$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `n`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::Lucas;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!(Natural::lucas(0), 2);
+    /// assert_eq!(Natural::lucas(1), 1);
+    /// assert_eq!(Natural::lucas(2), 3);
+    /// assert_eq!(Natural::lucas(10), 123);
+    /// assert_eq!(Natural::lucas(100).to_string(), "792070839848372253127");
+    /// ```
+    fn lucas(n: u64) -> Natural {
+        if let Some(l) = u64::checked_lucas(n) {
+            return Natural::from(l);
+        }
+        let k = n >> 1;
+        let (f, f_prev) = fibonacci_2(k);
+        // L_k = F_k + 2F_{k-1}
+        let l: Natural = &f + (&f_prev << 1);
+        let mut result = if n.odd() {
+            // L_{k+1} = 3F_k + F_{k-1}
+            let l_next = f * Natural::from(3u32) + f_prev;
+            l * l_next
+        } else {
+            l.square()
+        };
+        let correction = if n.odd() { Natural::ONE } else { Natural::TWO };
+        if k.odd() {
+            result += correction;
+        } else {
+            result -= correction;
+        }
+        result
+    }
+}
+
+impl ModFibonacci for Natural {
+    /// Computes the $n$th Fibonacci number modulo $m$.
+    ///
+    /// $f(n, m) = x$, where $x < m$ and $F_n \equiv x \mod m$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, k) = O(kn \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `m.significant_bits()`, and $k$ is
+    /// `n.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `m` is 0.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::ModFibonacci;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!(Natural::mod_fibonacci(10, Natural::from(16u32)), 7);
+    /// assert_eq!(
+    ///     Natural::mod_fibonacci(100, Natural::from(1000000007u32)),
+    ///     687995182
+    /// );
+    /// ```
+    #[inline]
+    fn mod_fibonacci(n: u64, m: Natural) -> Natural {
+        mod_fibonacci_and_lucas(n.bits().rev(), &m).0
+    }
+}
+
+impl ModFibonacci<Natural> for Natural {
+    /// Computes the $n$th Fibonacci number modulo $m$, where $n$ is a [`Natural`].
+    ///
+    /// $f(n, m) = x$, where $x < m$ and $F_n \equiv x \mod m$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, k) = O(kn \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `m.significant_bits()`, and $k$ is
+    /// `n.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `m` is 0.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::ModFibonacci;
+    /// use malachite_nz::natural::Natural;
+    /// use std::str::FromStr;
+    ///
+    /// // 2^89 - 1 is a prime congruent to 1 mod 5, so it divides F_{2^89 - 2}
+    /// let p = Natural::from_str("618970019642690137449562111").unwrap();
+    /// assert_eq!(Natural::mod_fibonacci(&p - Natural::from(1u32), p), 0);
+    /// ```
+    #[inline]
+    fn mod_fibonacci(n: Natural, m: Natural) -> Natural {
+        mod_fibonacci_and_lucas(n.bits().rev(), &m).0
+    }
+}
+
+impl ModLucas for Natural {
+    /// Computes the $n$th Lucas number modulo $m$.
+    ///
+    /// $f(n, m) = x$, where $x < m$ and $L_n \equiv x \mod m$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, k) = O(kn \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `m.significant_bits()`, and $k$ is
+    /// `n.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `m` is 0.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::ModLucas;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!(Natural::mod_lucas(10, Natural::from(16u32)), 11);
+    /// assert_eq!(
+    ///     Natural::mod_lucas(100, Natural::from(1000000007u32)),
+    ///     876413006
+    /// );
+    /// ```
+    #[inline]
+    fn mod_lucas(n: u64, m: Natural) -> Natural {
+        mod_fibonacci_and_lucas(n.bits().rev(), &m).1
+    }
+}
+
+impl ModLucas<Natural> for Natural {
+    /// Computes the $n$th Lucas number modulo $m$, where $n$ is a [`Natural`].
+    ///
+    /// $f(n, m) = x$, where $x < m$ and $L_n \equiv x \mod m$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, k) = O(kn \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `m.significant_bits()`, and $k$ is
+    /// `n.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `m` is 0.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::ModLucas;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!(
+    ///     Natural::mod_lucas(Natural::from(100u32), Natural::from(1000000007u32)),
+    ///     876413006
+    /// );
+    /// ```
+    #[inline]
+    fn mod_lucas(n: Natural, m: Natural) -> Natural {
+        mod_fibonacci_and_lucas(n.bits().rev(), &m).1
+    }
+}
+
+impl ModLucasSequence for Natural {
+    /// Computes the $n$th terms of the Lucas sequences with parameters $P$ and $Q$, modulo $m$.
+    /// Assumes $P$ and $Q$ are already reduced modulo $m$; to use a negative parameter, pass its
+    /// residue modulo $m$.
+    ///
+    /// $f(P, Q, n, m) = (x, y)$, where $x, y < m$, $U_n(P, Q) \equiv x \mod m$, and
+    /// $V_n(P, Q) \equiv y \mod m$. The sequences are defined by $U_0 = 0$, $U_1 = 1$, $V_0 = 2$,
+    /// $V_1 = P$, and $X_{n+2} = PX_{n+1} - QX_n$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, k) = O(kn \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `m.significant_bits()`, and $k$ is
+    /// `n.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `p` or `q` is greater than or equal to `m`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::ModLucasSequence;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!(
+    ///     Natural::mod_lucas_sequence(
+    ///         Natural::from(5u32),
+    ///         Natural::from(3u32),
+    ///         100,
+    ///         Natural::from(1000000007u32)
+    ///     ),
+    ///     (Natural::from(150049008u32), Natural::from(448234207u32))
+    /// );
+    /// ```
+    #[inline]
+    fn mod_lucas_sequence(p: Natural, q: Natural, n: u64, m: Natural) -> (Natural, Natural) {
+        mod_lucas_sequence(&p, &q, n.bits().rev(), &m)
+    }
+}
+
+impl ModLucasSequence<Natural> for Natural {
+    /// Computes the $n$th terms of the Lucas sequences with parameters $P$ and $Q$, modulo $m$,
+    /// where $n$ is a [`Natural`]. Assumes $P$ and $Q$ are already reduced modulo $m$; to use a
+    /// negative parameter, pass its residue modulo $m$.
+    ///
+    /// $f(P, Q, n, m) = (x, y)$, where $x, y < m$, $U_n(P, Q) \equiv x \mod m$, and
+    /// $V_n(P, Q) \equiv y \mod m$. The sequences are defined by $U_0 = 0$, $U_1 = 1$, $V_0 = 2$,
+    /// $V_1 = P$, and $X_{n+2} = PX_{n+1} - QX_n$.
+    ///
+    /// In a Lucas probable prime test of an odd $n$ with $D = P^2 - 4Q$ and
+    /// $\left ( \frac{D}{n} \right ) = -1$, $U_{n+1}(P, Q)$ is divisible by $n$ whenever $n$ is
+    /// prime.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, k) = O(kn \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `m.significant_bits()`, and $k$ is
+    /// `n.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `p` or `q` is greater than or equal to `m`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::ModLucasSequence;
+    /// use malachite_base::num::basic::traits::One;
+    /// use malachite_nz::natural::Natural;
+    /// use std::str::FromStr;
+    ///
+    /// // D = 1^2 - 4 * (-1) = 5 is not a square mod 2^127 - 1, which is prime
+    /// let n = Natural::from_str("170141183460469231731687303715884105727").unwrap();
+    /// let (u, _) = Natural::mod_lucas_sequence(
+    ///     Natural::ONE,
+    ///     &n - Natural::ONE,
+    ///     &n + Natural::ONE,
+    ///     n.clone(),
+    /// );
+    /// assert_eq!(u, 0);
+    /// ```
+    #[inline]
+    fn mod_lucas_sequence(p: Natural, q: Natural, n: Natural, m: Natural) -> (Natural, Natural) {
+        mod_lucas_sequence(&p, &q, n.bits().rev(), &m)
+    }
+}
@@ -8,6 +8,9 @@ pub mod add_mul;
 /// [`BinomialCoefficient`](malachite_base::num::arithmetic::traits::BinomialCoefficient), a trait
 /// for computing the binomial coefficient of two numbers.
 pub mod binomial_coefficient;
+/// An implementation of [`Catalan`](malachite_base::num::arithmetic::traits::Catalan), a trait for
+/// computing Catalan numbers.
+pub mod catalan;
 /// Implementations of [`CheckedSub`](malachite_base::num::arithmetic::traits::CheckedSub), a trait
 /// for subtracting two numbers and checking whether the result is representable.
 pub mod checked_sub;
@@ -15,6 +18,12 @@ pub mod checked_sub;
 /// trait for subtracting the product of two numbers from another number, and checking whether the
 /// result is representable.
 pub mod checked_sub_mul;
+/// Implementations of
+/// [`ChineseRemainder`](malachite_base::num::arithmetic::traits::ChineseRemainder), a trait for
+/// combining congruences using the Chinese remainder theorem, and
+/// [`ChineseRemainderBasis`](chinese_remainder::ChineseRemainderBasis), a precomputed basis for
+/// combining residues modulo a fixed list of pairwise coprime moduli.
+pub mod chinese_remainder;
 /// Implementations of [`CoprimeWith`](malachite_base::num::arithmetic::traits::CoprimeWith), a
 /// trait for determining whether two numbers are coprime.
 pub mod coprime_with;
@@ -52,6 +61,11 @@ pub mod divisible_by;
 /// for determining whether a number
 /// is divisible by $2^k$.
 pub mod divisible_by_power_of_2;
+/// Implementations of [`DivisorCount`](malachite_base::num::arithmetic::traits::DivisorCount),
+/// [`DivisorSum`](malachite_base::num::arithmetic::traits::DivisorSum), and
+/// [`Divisors`](malachite_base::num::arithmetic::traits::Divisors), traits for counting, summing,
+/// and generating the divisors of a number.
+pub mod divisors;
 /// Implementations of [`EqMod`](malachite_base::num::arithmetic::traits::EqMod), a trait for
 /// determining whether one number is equal by another modulo a third.
 pub mod eq_mod;
@@ -63,6 +77,13 @@ pub mod eq_mod_power_of_2;
 /// [`Multifactorial`](malachite_base::num::arithmetic::traits::Multifactorial), and
 /// [`Subfactorial`](malachite_base::num::arithmetic::traits::Subfactorial).
 pub mod factorial;
+/// Implementations of [`Fibonacci`](malachite_base::num::arithmetic::traits::Fibonacci),
+/// [`Lucas`](malachite_base::num::arithmetic::traits::Lucas),
+/// [`ModFibonacci`](malachite_base::num::arithmetic::traits::ModFibonacci),
+/// [`ModLucas`](malachite_base::num::arithmetic::traits::ModLucas), and
+/// [`ModLucasSequence`](malachite_base::num::arithmetic::traits::ModLucasSequence), traits for
+/// computing Fibonacci numbers, Lucas numbers, and Lucas sequences.
+pub mod fibonacci;
 /// Implementations of [`Gcd`](malachite_base::num::arithmetic::traits::Gcd) and
 /// [`GcdAssign`](malachite_base::num::arithmetic::traits::GcdAssign), traits for computing the GCD
 /// (greatest common divisor) of two numbers.
@@ -104,6 +125,13 @@ pub mod log_base_power_of_2;
 /// [`ModAddAssign`](malachite_base::num::arithmetic::traits::ModAddAssign), traits for adding two
 /// numbers modulo another number.
 pub mod mod_add;
+/// An implementation of
+/// [`ModBinomialCoefficient`](malachite_base::num::arithmetic::traits::ModBinomialCoefficient), a
+/// trait for computing the binomial coefficient of two numbers modulo another number.
+pub mod mod_binomial_coefficient;
+/// An implementation of [`ModFactorial`](malachite_base::num::arithmetic::traits::ModFactorial), a
+/// trait for computing the factorial of a number modulo another number.
+pub mod mod_factorial;
 /// Implementations of
 /// [`ModInverse`](malachite_base::num::arithmetic::traits::ModInverse), a trait for finding the
 /// multiplicative inverse of a number modulo another number.
@@ -111,6 +139,9 @@ pub mod mod_inverse;
 /// Implementations of [`ModIsReduced`](malachite_base::num::arithmetic::traits::ModIsReduced), a
 /// trait for checking whether a number is reduced modulo another number.
 pub mod mod_is_reduced;
+/// Implementations of [`ModLog`](malachite_base::num::arithmetic::traits::ModLog), a trait for
+/// computing discrete logarithms modulo a number.
+pub mod mod_log;
 /// Implementations of traits for multiplying two numbers modulo another number.
 ///
 /// The traits are [`ModMul`](malachite_base::num::arithmetic::traits::ModMul),
@@ -121,6 +152,9 @@ pub mod mod_is_reduced;
 /// [`ModMulPrecomputedAssign`](malachite_base::num::arithmetic::traits::ModMulPrecomputedAssign)
 /// are useful when having to make several multiplications modulo the same modulus.
 pub mod mod_mul;
+/// [`ModNatural`](mod_natural::ModNatural), a residue modulo a [`Natural`](super::Natural) that
+/// supports the arithmetic operators.
+pub mod mod_natural;
 /// Implementations of [`ModNeg`](malachite_base::num::arithmetic::traits::ModNeg) and
 /// [`ModNegAssign`](malachite_base::num::arithmetic::traits::ModNegAssign), traits for negating a
 /// number modulo another number.
@@ -478,6 +512,11 @@ pub mod mod_shl;
 /// assert_eq!(x, 7436663564915145u64);
 /// ```
 pub mod mod_shr;
+/// Implementations of [`ModSqrt`](malachite_base::num::arithmetic::traits::ModSqrt) and
+/// [`ModSqrtFromFactorization`](malachite_base::num::arithmetic::traits::ModSqrtFromFactorization),
+/// traits for finding square roots modulo a prime, a prime power, or a number with known
+/// factorization.
+pub mod mod_sqrt;
 /// Implementations of traits for squaring a number modulo another number.
 ///
 /// The traits are [`ModSquare`](malachite_base::num::arithmetic::traits::ModSquare),
@@ -490,8 +529,22 @@ pub mod mod_square;
 /// [`ModSubAssign`](malachite_base::num::arithmetic::traits::ModSubAssign), traits for subtracting
 /// two numbers modulo another number.
 pub mod mod_sub;
+/// An implementation of [`Moebius`](malachite_base::num::arithmetic::traits::Moebius), a trait for
+/// computing the Möbius function of a number.
+pub mod moebius;
+/// [`MontgomeryModulus`](montgomery::MontgomeryModulus), a precomputed context for Montgomery
+/// multiplication modulo a fixed odd number.
+pub mod montgomery;
 /// Multiplication of [`Natural`](super::Natural)s.
 pub mod mul;
+/// An implementation of [`Multinomial`](malachite_base::num::arithmetic::traits::Multinomial), a
+/// trait for computing multinomial coefficients.
+pub mod multinomial;
+/// Implementations of
+/// [`MultiplicativeOrder`](malachite_base::num::arithmetic::traits::MultiplicativeOrder) and
+/// [`PrimitiveRoot`](malachite_base::num::arithmetic::traits::PrimitiveRoot), traits for computing
+/// the multiplicative order of a number modulo another number and for finding primitive roots.
+pub mod multiplicative_order;
 /// Negation of a [`Natural`](super::Natural), returning an [`Integer`](crate::integer::Integer).
 pub mod neg;
 /// Implementations of [`NextPowerOf2`](malachite_base::num::arithmetic::traits::NextPowerOf2) and
@@ -501,6 +554,15 @@ pub mod next_power_of_2;
 /// Implementations of [`Parity`](malachite_base::num::arithmetic::traits::Parity), a trait for
 /// determining whether a number is even or odd.
 pub mod parity;
+/// An implementation of
+/// [`PartitionCount`](malachite_base::num::arithmetic::traits::PartitionCount), a trait for
+/// counting the partitions of a number.
+pub mod partition_count;
+/// Implementations of [`IsPerfectPower`](malachite_base::num::arithmetic::traits::IsPerfectPower)
+/// and
+/// [`PerfectPowerDecomposition`](malachite_base::num::arithmetic::traits::PerfectPowerDecomposition),
+/// traits for determining whether a number is a perfect power and for writing it as one.
+pub mod perfect_power;
 /// Implementations of [`Pow`](malachite_base::num::arithmetic::traits::Pow) and
 /// [`PowAssign`](malachite_base::num::arithmetic::traits::PowAssign), traits for raising a number
 /// to a power.
@@ -508,6 +570,9 @@ pub mod pow;
 /// Implementations of [`PowerOf2`](malachite_base::num::arithmetic::traits::PowerOf2), a trait for
 /// computing a power of 2.
 pub mod power_of_2;
+/// [`PrecomputedDivisor`](precomputed_divisor::PrecomputedDivisor), a divisor with a cached inverse
+/// for fast repeated division by the same number.
+pub mod precomputed_divisor;
 /// An implementation of [`Primorial`](malachite_base::num::arithmetic::traits::Primorial), a trait
 /// for computing the primorial of a number.
 pub mod primorial;
@@ -910,9 +975,18 @@ pub mod sqrt;
 /// [`SquareAssign`](malachite_base::num::arithmetic::traits::SquareAssign), traits for squaring a
 /// number.
 pub mod square;
+/// Implementations of [`StirlingFirst`](malachite_base::num::arithmetic::traits::StirlingFirst),
+/// [`StirlingSecond`](malachite_base::num::arithmetic::traits::StirlingSecond), and
+/// [`Bell`](malachite_base::num::arithmetic::traits::Bell), traits for computing Stirling numbers
+/// and Bell numbers.
+pub mod stirling;
 /// Subtraction of [`Natural`](super::Natural)s.
 pub mod sub;
 /// Implementations of [`SubMul`](malachite_base::num::arithmetic::traits::SubMul) and
 /// [`SubMulAssign`](malachite_base::num::arithmetic::traits::SubMulAssign), traits for subtracting
 /// the product of two numbers from a number.
 pub mod sub_mul;
+/// Implementations of [`Totient`](malachite_base::num::arithmetic::traits::Totient) and
+/// [`CarmichaelLambda`](malachite_base::num::arithmetic::traits::CarmichaelLambda), traits for
+/// computing Euler's totient function and the Carmichael function of a number.
+pub mod totient;
@@ -0,0 +1,209 @@
+use crate::natural::arithmetic::mod_factorial::{
+    crt_from_prime_powers, p_free_factorial, p_free_interval_product, p_free_period_product,
+};
+use crate::natural::Natural;
+use malachite_base::num::arithmetic::traits::{
+    ModBinomialCoefficient, ModInverse, ModMul, ModMulAssign, Pow,
+};
+use malachite_base::num::basic::traits::{One, Zero};
+use malachite_base::num::factorization::traits::Factor;
+use std::cmp::min;
+
+// Returns $\binom{n}{k} \mod p$ for $k \leq n < p$.
+fn small_binomial_coefficient_mod_prime(n: &Natural, k: &Natural, p: &Natural) -> Natural {
+    let k = min(k.clone(), n - k);
+    let mut numerator = Natural::ONE;
+    let mut denominator = Natural::ONE;
+    let mut i = Natural::ZERO;
+    while i < k {
+        numerator.mod_mul_assign(n - &i, p);
+        i += Natural::ONE;
+        denominator.mod_mul_assign(&i, p);
+    }
+    numerator.mod_mul(denominator.mod_inverse(p).unwrap(), p)
+}
+
+// Uses Lucas' theorem; see the comment on `mod_binomial_coefficient_prime` in malachite-base.
+fn mod_binomial_coefficient_prime(n: &Natural, k: &Natural, p: &Natural) -> Natural {
+    let mut n = n.clone();
+    let mut k = k.clone();
+    let mut product = Natural::ONE;
+    while k != 0u32 {
+        let n_digit = &n % p;
+        let k_digit = &k % p;
+        if k_digit > n_digit {
+            return Natural::ZERO;
+        }
+        product.mod_mul_assign(
+            small_binomial_coefficient_mod_prime(&n_digit, &k_digit, p),
+            p,
+        );
+        n /= p;
+        k /= p;
+    }
+    product
+}
+
+// Uses Granville's generalization of Lucas' theorem; see the comment on
+// `mod_binomial_coefficient_prime_power` in malachite-base.
+fn mod_binomial_coefficient_prime_power(n: &Natural, k: &Natural, p: &Natural, e: u64) -> Natural {
+    let mut r = n - k;
+    // By Kummer's theorem, the power of $p$ dividing the result is the number of carries when
+    // adding $k$ and $n - k$ in base $p$.
+    let mut v = 0;
+    let (mut n_high, mut k_high, mut r_high) = (n / p, k / p, &r / p);
+    while n_high != 0u32 {
+        if n_high != &k_high + &r_high {
+            v += 1;
+        }
+        n_high /= p;
+        k_high /= p;
+        r_high /= p;
+    }
+    if v >= e {
+        return Natural::ZERO;
+    }
+    let q = p.pow(e);
+    let s = p_free_period_product(p, &q, e);
+    let mut product = p.pow(v);
+    let mut n = n.clone();
+    let mut k = k.clone();
+    while n != 0u32 {
+        let carry = &n - &k - &r;
+        let (large, small) = if k >= r { (&k, &r) } else { (&r, &k) };
+        product.mod_mul_assign(
+            p_free_interval_product(large, &(small + carry), p, &q, &s),
+            &q,
+        );
+        product.mod_mul_assign(
+            p_free_factorial(small, p, &q, &s).mod_inverse(&q).unwrap(),
+            &q,
+        );
+        n /= p;
+        k /= p;
+        r /= p;
+    }
+    product
+}
+
+fn mod_binomial_coefficient(n: &Natural, k: &Natural, m: &Natural) -> Natural {
+    assert_ne!(*m, 0u32);
+    if k > n || *m == 1u32 {
+        return Natural::ZERO;
+    }
+    if let (Ok(n), Ok(k), Ok(m)) = (u64::try_from(n), u64::try_from(k), u64::try_from(m)) {
+        return Natural::from(u64::mod_binomial_coefficient(n, k, m));
+    }
+    crt_from_prime_powers(m, &m.factor(), &mut |p, e| {
+        if e == 1 {
+            mod_binomial_coefficient_prime(n, k, p)
+        } else {
+            mod_binomial_coefficient_prime_power(n, k, p, e)
+        }
+    })
+}
+
+impl ModBinomialCoefficient for Natural {
+    /// Computes the binomial coefficient of two [`Natural`]s modulo a third [`Natural`] $m$,
+    /// taking all three by value.
+    ///
+    /// The binomial coefficient is computed separately modulo each prime power $p^e$ dividing
+    /// $m$, and the results are combined using the Chinese remainder theorem. Modulo a prime,
+    /// Lucas' theorem is used; modulo a higher prime power, Granville's generalization of it.
+    ///
+    /// $f(n, k, m) = \binom{n}{k} \mod m$.
+    ///
+    /// # Worst-case complexity
+    /// $T(k, m) = O(\min(k, m) \log m)$
+    ///
+    /// $M(m) = O(\log m)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $k$ is `k`, and $m$ is `m`.
+    ///
+    /// # Panics
+    /// Panics if `m` is 0.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::{ModBinomialCoefficient, Pow};
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!(
+    ///     Natural::mod_binomial_coefficient(
+    ///         Natural::from(10u32),
+    ///         Natural::from(3u32),
+    ///         Natural::from(7u32)
+    ///     ),
+    ///     1
+    /// );
+    /// assert_eq!(
+    ///     Natural::mod_binomial_coefficient(
+    ///         Natural::from(1000u32),
+    ///         Natural::from(500u32),
+    ///         Natural::from(10u32).pow(10)
+    ///     ),
+    ///     9821216320u64
+    /// );
+    /// assert_eq!(
+    ///     Natural::mod_binomial_coefficient(
+    ///         Natural::from(10u32).pow(30),
+    ///         Natural::from(3u32),
+    ///         Natural::from(1000000007u32)
+    ///     ),
+    ///     55798620
+    /// );
+    /// ```
+    #[inline]
+    fn mod_binomial_coefficient(n: Natural, k: Natural, m: Natural) -> Natural {
+        mod_binomial_coefficient(&n, &k, &m)
+    }
+}
+
+impl<'a> ModBinomialCoefficient<&'a Natural> for Natural {
+    /// Computes the binomial coefficient of two [`Natural`]s modulo a third [`Natural`] $m$,
+    /// taking all three by reference.
+    ///
+    /// The binomial coefficient is computed separately modulo each prime power $p^e$ dividing
+    /// $m$, and the results are combined using the Chinese remainder theorem. Modulo a prime,
+    /// Lucas' theorem is used; modulo a higher prime power, Granville's generalization of it.
+    ///
+    /// $f(n, k, m) = \binom{n}{k} \mod m$.
+    ///
+    /// # Worst-case complexity
+    /// $T(k, m) = O(\min(k, m) \log m)$
+    ///
+    /// $M(m) = O(\log m)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $k$ is `k`, and $m$ is `m`.
+    ///
+    /// # Panics
+    /// Panics if `m` is 0.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::{ModBinomialCoefficient, Pow};
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!(
+    ///     Natural::mod_binomial_coefficient(
+    ///         &Natural::from(10u32),
+    ///         &Natural::from(3u32),
+    ///         &Natural::from(7u32)
+    ///     ),
+    ///     1
+    /// );
+    /// assert_eq!(
+    ///     Natural::mod_binomial_coefficient(
+    ///         &Natural::from(100u32),
+    ///         &Natural::from(50u32),
+    ///         &Natural::from(3u32).pow(50)
+    ///     )
+    ///     .to_string(),
+    ///     "115049314306066808013543"
+    /// );
+    /// ```
+    #[inline]
+    fn mod_binomial_coefficient(n: &'a Natural, k: &'a Natural, m: &'a Natural) -> Natural {
+        mod_binomial_coefficient(n, k, m)
+    }
+}
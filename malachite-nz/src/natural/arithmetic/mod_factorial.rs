@@ -0,0 +1,215 @@
+use crate::natural::Natural;
+use malachite_base::num::arithmetic::traits::{
+    DivisibleBy, ModAddAssign, ModFactorial, ModInverse, ModMul, ModMulAssign, Parity, Pow,
+};
+use malachite_base::num::basic::traits::{One, Zero};
+use malachite_base::num::conversion::traits::SaturatingFrom;
+use malachite_base::num::factorization::traits::Factor;
+
+// Returns the product of all integers coprime to $p$ among any $q = p^e$ consecutive integers,
+// modulo $q$. This is $-1$, except when $p = 2$ and $e \geq 3$, when it is 1.
+pub(crate) fn p_free_period_product(p: &Natural, q: &Natural, e: u64) -> Natural {
+    if *p == 2u32 && e >= 3 {
+        Natural::ONE
+    } else {
+        q - Natural::ONE
+    }
+}
+
+// Returns $\prod_{a < j \leq a + l, p \nmid j} j \mod q$, where $q = p^e$ and $s$ is the product
+// returned by `p_free_period_product`.
+pub(crate) fn p_free_interval_product(
+    a: &Natural,
+    l: &Natural,
+    p: &Natural,
+    q: &Natural,
+    s: &Natural,
+) -> Natural {
+    let mut product = if (l / q).odd() {
+        s.clone()
+    } else {
+        Natural::ONE
+    };
+    let q_minus_1 = q - Natural::ONE;
+    let mut j = a % q;
+    let mut i = l % q;
+    while i != 0u32 {
+        if j == q_minus_1 {
+            j = Natural::ZERO;
+        } else {
+            j += Natural::ONE;
+        }
+        if !(&j).divisible_by(p) {
+            product.mod_mul_assign(&j, q);
+        }
+        i -= Natural::ONE;
+    }
+    product
+}
+
+// Returns $\prod_{1 \leq j \leq x, p \nmid j} j \mod q$, where $q = p^e$.
+pub(crate) fn p_free_factorial(x: &Natural, p: &Natural, q: &Natural, s: &Natural) -> Natural {
+    let product = if (x / q).odd() {
+        s.clone()
+    } else {
+        Natural::ONE
+    };
+    let r = x % q;
+    let remaining = q - Natural::ONE - &r;
+    let partial = if r <= remaining {
+        p_free_interval_product(&Natural::ZERO, &r, p, q, s)
+    } else {
+        s.mod_mul(
+            p_free_interval_product(&r, &remaining, p, q, s)
+                .mod_inverse(q)
+                .unwrap(),
+            q,
+        )
+    };
+    product.mod_mul(partial, q)
+}
+
+// Returns the exponent of $p$ in $n!$, by Legendre's formula. The result saturates at `u64::MAX`.
+fn factorial_p_adic_valuation(n: &Natural, p: &Natural) -> u64 {
+    let mut v = 0u64;
+    let mut n = n / p;
+    while n != 0u32 {
+        v = v.saturating_add(u64::saturating_from(&n));
+        n /= p;
+    }
+    v
+}
+
+fn mod_factorial_prime_power(n: &Natural, p: &Natural, e: u64) -> Natural {
+    let v = factorial_p_adic_valuation(n, p);
+    if v >= e {
+        return Natural::ZERO;
+    }
+    let q = p.pow(e);
+    let s = p_free_period_product(p, &q, e);
+    let mut product = p.pow(v);
+    let mut x = n.clone();
+    while x != 0u32 {
+        product.mod_mul_assign(p_free_factorial(&x, p, &q, &s), &q);
+        x /= p;
+    }
+    product
+}
+
+// Combines residues modulo the prime powers dividing $m$ using the Chinese remainder theorem.
+pub(crate) fn crt_from_prime_powers(
+    m: &Natural,
+    factors: &[(Natural, u64)],
+    f: &mut dyn FnMut(&Natural, u64) -> Natural,
+) -> Natural {
+    let mut x = Natural::ZERO;
+    for (p, e) in factors {
+        let q = p.pow(*e);
+        let r = f(p, *e);
+        if r == 0u32 {
+            continue;
+        }
+        let cofactor = m / &q;
+        let inverse = (&cofactor % &q).mod_inverse(&q).unwrap();
+        x.mod_add_assign(r.mod_mul(inverse, &q) * cofactor, m);
+    }
+    x
+}
+
+fn mod_factorial(n: &Natural, m: &Natural) -> Natural {
+    assert_ne!(*m, 0u32);
+    if n >= m {
+        return Natural::ZERO;
+    }
+    if let Ok(m) = u64::try_from(m) {
+        return Natural::from(u64::mod_factorial(u64::try_from(n).unwrap(), m));
+    }
+    crt_from_prime_powers(m, &m.factor(), &mut |p, e| {
+        mod_factorial_prime_power(n, p, e)
+    })
+}
+
+impl ModFactorial for Natural {
+    /// Computes the factorial of a [`Natural`] modulo another [`Natural`] $m$, taking both by
+    /// value.
+    ///
+    /// The factorial is computed separately modulo each prime power $p^e$ dividing $m$, and the
+    /// results are combined using the Chinese remainder theorem. Modulo $p^e$, the power of $p$
+    /// dividing $n!$ is found using Legendre's formula, and the rest of $n!$ is a product of
+    /// factorials of $\lfloor n/p^i \rfloor$ with the multiples of $p$ removed.
+    ///
+    /// $f(n, m) = n! \mod m$.
+    ///
+    /// # Worst-case complexity
+    /// $T(m) = O(m \log m)$
+    ///
+    /// $M(m) = O(\log m)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $m$ is `m`.
+    ///
+    /// # Panics
+    /// Panics if `m` is 0.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::{ModFactorial, Pow};
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!(Natural::mod_factorial(Natural::from(6u32), Natural::from(7u32)), 6);
+    /// assert_eq!(Natural::mod_factorial(Natural::from(10u32), Natural::from(7u32)), 0);
+    /// assert_eq!(
+    ///     Natural::mod_factorial(Natural::from(30u32), Natural::from(1u32) << 40),
+    ///     950597058560u64
+    /// );
+    /// assert_eq!(
+    ///     Natural::mod_factorial(
+    ///         Natural::from(1000u32),
+    ///         Natural::from(10u32).pow(30)
+    ///     ),
+    ///     0
+    /// );
+    /// ```
+    #[inline]
+    fn mod_factorial(n: Natural, m: Natural) -> Natural {
+        mod_factorial(&n, &m)
+    }
+}
+
+impl<'a> ModFactorial<&'a Natural> for Natural {
+    /// Computes the factorial of a [`Natural`] modulo another [`Natural`] $m$, taking both by
+    /// reference.
+    ///
+    /// The factorial is computed separately modulo each prime power $p^e$ dividing $m$, and the
+    /// results are combined using the Chinese remainder theorem. Modulo $p^e$, the power of $p$
+    /// dividing $n!$ is found using Legendre's formula, and the rest of $n!$ is a product of
+    /// factorials of $\lfloor n/p^i \rfloor$ with the multiples of $p$ removed.
+    ///
+    /// $f(n, m) = n! \mod m$.
+    ///
+    /// # Worst-case complexity
+    /// $T(m) = O(m \log m)$
+    ///
+    /// $M(m) = O(\log m)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $m$ is `m`.
+    ///
+    /// # Panics
+    /// Panics if `m` is 0.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::ModFactorial;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!(Natural::mod_factorial(&Natural::from(6u32), &Natural::from(7u32)), 6);
+    /// assert_eq!(Natural::mod_factorial(&Natural::from(10u32), &Natural::from(7u32)), 0);
+    /// assert_eq!(
+    ///     Natural::mod_factorial(&Natural::from(30u32), &(Natural::from(1u32) << 40)),
+    ///     950597058560u64
+    /// );
+    /// ```
+    #[inline]
+    fn mod_factorial(n: &'a Natural, m: &'a Natural) -> Natural {
+        mod_factorial(n, m)
+    }
+}
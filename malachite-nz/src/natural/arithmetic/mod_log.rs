@@ -0,0 +1,384 @@
+use crate::natural::arithmetic::chinese_remainder::chinese_remainder_pair;
+use crate::natural::arithmetic::multiplicative_order::{
+    carmichael_lambda_and_factors, mod_order_from_multiple,
+};
+use crate::natural::Natural;
+use malachite_base::num::arithmetic::traits::{
+    CeilingSqrt, DivisibleBy, Gcd, ModAdd, ModInverse, ModLog, ModMul, ModPow, ModSquare, ModSub,
+    Pow,
+};
+use malachite_base::num::basic::traits::{One, Zero};
+use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_base::num::factorization::traits::Factor;
+use malachite_base::num::logic::traits::SignificantBits;
+use std::collections::HashMap;
+
+// Subproblems in groups of prime order $q$ are solved using baby-step giant-step if $q$ has at most
+// this many bits, and using Pollard's rho otherwise. Baby-step giant-step stores about $\sqrt q$
+// group elements.
+const BABY_STEP_GIANT_STEP_MAX_BITS: u64 = 40;
+
+// Finds the smallest $x < n$ such that $g^x \equiv h \mod m$, or returns `None` if there is none.
+// Assumes that $g$ is coprime to $m$ and that $n$ has at most `BABY_STEP_GIANT_STEP_MAX_BITS` bits.
+pub_test! {mod_log_baby_step_giant_step(
+    g: &Natural,
+    h: &Natural,
+    n: &Natural,
+    m: &Natural,
+) -> Option<Natural> {
+    let n = u64::exact_from(n);
+    let s = n.ceiling_sqrt();
+    // Maps g^j to the smallest such j < s.
+    let mut baby_steps = HashMap::new();
+    let mut x = Natural::ONE % m;
+    for j in 0..s {
+        let next = (&x).mod_mul(g, m);
+        baby_steps.entry(x).or_insert(j);
+        x = next;
+    }
+    // x is now g^s.
+    let giant_step = x.mod_inverse(m).unwrap();
+    let mut y = h.clone();
+    for i in 0..s {
+        if let Some(&j) = baby_steps.get(&y) {
+            let x = i * s + j;
+            return if x < n { Some(Natural::from(x)) } else { None };
+        }
+        y = y.mod_mul(&giant_step, m);
+    }
+    None
+}}
+
+// Finds the $x < q$ such that $g^x \equiv h \mod m$ using Pollard's rho, where $g$ has prime order
+// $q$ modulo $m$. Assumes that $h$ is a power of $g$; otherwise, this function never terminates.
+//
+// The walk visits elements of the form $g^a h^b$, and the group is split into three parts by the
+// element's residue modulo 3. Collisions that do not determine $x$ are rare; after one, the walk is
+// restarted from a different element.
+pub_test! {mod_log_pollard_rho(g: &Natural, h: &Natural, q: &Natural, m: &Natural) -> Natural {
+    let three = Natural::from(3u32);
+    let step = |(x, a, b): (Natural, Natural, Natural)| match u64::exact_from(&(&x % &three)) {
+        0 => (x.mod_mul(h, m), a, b.mod_add(Natural::ONE, q)),
+        1 => (x.mod_square(m), (&a).mod_add(&a, q), (&b).mod_add(&b, q)),
+        _ => (x.mod_mul(g, m), a.mod_add(Natural::ONE, q), b),
+    };
+    let mut start = Natural::ZERO;
+    loop {
+        // Floyd's cycle-finding algorithm.
+        let initial = (g.mod_pow(&start, m).mod_mul(h, m), start.clone(), Natural::ONE);
+        let mut tortoise = step(initial);
+        let mut hare = step(tortoise.clone());
+        while tortoise.0 != hare.0 {
+            tortoise = step(tortoise);
+            hare = step(step(hare));
+        }
+        let (_, a_1, b_1) = tortoise;
+        let (_, a_2, b_2) = hare;
+        if b_1 != b_2 {
+            // a_1 + b_1 x ≡ a_2 + b_2 x mod q
+            let x = a_2
+                .mod_sub(a_1, q)
+                .mod_mul(b_1.mod_sub(b_2, q).mod_inverse(q).unwrap(), q);
+            if g.mod_pow(&x, m) == *h {
+                return x;
+            }
+        }
+        start += Natural::ONE;
+    }
+}}
+
+// Finds the smallest $x$ such that $g^x \equiv h \mod m$, where $g$ has order $q^e$ modulo $m$ and
+// $q$ is prime, or returns `None` if there is none. The digits of $x$ in base $q$ are found one at
+// a time by solving discrete logarithms in the subgroup of order $q$.
+fn mod_log_prime_power_order(
+    g: &Natural,
+    h: &Natural,
+    q: &Natural,
+    e: u64,
+    m: &Natural,
+) -> Option<Natural> {
+    let mut q_e_minus_j_minus_1 = q.pow(e - 1);
+    let gamma = g.mod_pow(&q_e_minus_j_minus_1, m);
+    let g_inverse = g.mod_inverse(m).unwrap();
+    let mut x = Natural::ZERO;
+    let mut q_j = Natural::ONE;
+    for j in 0..e {
+        // h_j = (g^(-x) h)^(q^(e - j - 1)) has order dividing q if h is a power of g.
+        let h_j = (&g_inverse)
+            .mod_pow(&x, m)
+            .mod_mul(h, m)
+            .mod_pow(&q_e_minus_j_minus_1, m);
+        let digit = if q.significant_bits() <= BABY_STEP_GIANT_STEP_MAX_BITS {
+            mod_log_baby_step_giant_step(&gamma, &h_j, q, m)?
+        } else if (&h_j).mod_pow(q, m) == 1u32 {
+            mod_log_pollard_rho(&gamma, &h_j, q, m)
+        } else {
+            return None;
+        };
+        x += digit * &q_j;
+        if j != e - 1 {
+            q_j *= q;
+            q_e_minus_j_minus_1 /= q;
+        }
+    }
+    Some(x)
+}
+
+// Finds the smallest $x$ such that $g^x \equiv h \mod p^k$, returning $x$ and the order of $g$, or
+// returns `None` if there is no such $x$. Assumes that $m = p^k$, where $p$ is prime, and that $g$
+// and $h$ are reduced modulo $m$ and coprime to it.
+//
+// The order of $g$ is found from the Carmichael function of $p^k$. Then the Pohlig-Hellman
+// algorithm reduces the problem to one discrete logarithm for each prime power dividing the order.
+fn mod_log_prime_power(
+    g: &Natural,
+    h: &Natural,
+    p: &Natural,
+    k: u64,
+    m: &Natural,
+) -> Option<(Natural, Natural)> {
+    let (lambda, lambda_factors) = carmichael_lambda_and_factors(&[(p.clone(), k)]);
+    let (order, order_factors) = mod_order_from_multiple(g, m, lambda, lambda_factors);
+    if h.mod_pow(&order, m) != 1u32 {
+        return None;
+    }
+    let mut x = Natural::ZERO;
+    let mut modulus = Natural::ONE;
+    for (q, e) in order_factors {
+        let q_e = (&q).pow(e);
+        let cofactor = &order / &q_e;
+        let x_q = mod_log_prime_power_order(
+            &g.mod_pow(&cofactor, m),
+            &h.mod_pow(&cofactor, m),
+            &q,
+            e,
+            m,
+        )?;
+        (x, modulus) = chinese_remainder_pair(x, modulus, &x_q, &q_e).unwrap();
+    }
+    // Modulo powers of 2 the group is not cyclic, so h may fail to be a power of g even though
+    // h^order = 1.
+    if g.mod_pow(&x, m) == *h {
+        Some((x, order))
+    } else {
+        None
+    }
+}
+
+fn mod_log(h: &Natural, g: &Natural, m: &Natural) -> Option<Natural> {
+    assert!(h < m, "h must be reduced mod m, but {} >= {}", h, m);
+    assert!(g < m, "g must be reduced mod m, but {} >= {}", g, m);
+    // Write g^x ≡ h mod m as c g^(x - offset) ≡ h mod m, and divide out common factors of g and m
+    // until g is coprime to m. Solutions smaller than the final offset are checked along the way.
+    let mut m = m.clone();
+    let mut h = h.clone();
+    let mut c = Natural::ONE % &m;
+    let mut offset = 0u64;
+    loop {
+        if h == c {
+            return Some(Natural::from(offset));
+        }
+        let d = g.gcd(&m);
+        if d == 1u32 {
+            break;
+        }
+        if !(&h).divisible_by(&d) {
+            return None;
+        }
+        h /= &d;
+        m /= &d;
+        offset += 1;
+        c = (c % &m).mod_mul(g / d % &m, &m);
+    }
+    // Now m > 1 and c is coprime to m, so it is enough to solve g^y ≡ h c^(-1) mod m.
+    let g = g % &m;
+    let h = h.mod_mul(c.mod_inverse(&m).unwrap(), &m);
+    let mut x = Natural::ZERO;
+    let mut order = Natural::ONE;
+    for (p, k) in m.factor() {
+        let p_k = (&p).pow(k);
+        let (x_p, order_p) = mod_log_prime_power(&(&g % &p_k), &(&h % &p_k), &p, k, &p_k)?;
+        (x, order) = chinese_remainder_pair(x, order, &x_p, &order_p)?;
+    }
+    Some(x + Natural::from(offset))
+}
+
+impl ModLog<Natural, Natural> for Natural {
+    type Output = Natural;
+
+    /// Computes the discrete logarithm of a [`Natural`] modulo another [`Natural`] $m$. Assumes
+    /// the number and the base are already reduced modulo $m$. All three [`Natural`]s are taken by
+    /// value.
+    ///
+    /// Returns the smallest $x \geq 0$ such that $g^x \equiv h \mod m$, where $h$ is `self` and $g$
+    /// is `base`, or `None` if there is no such $x$.
+    ///
+    /// Common factors of $g$ and $m$ are divided out first. The modulus is then factored, and the
+    /// order of $g$ modulo each prime power is found from the Carmichael function. The
+    /// Pohlig-Hellman algorithm reduces each problem to problems in groups of prime order, which
+    /// are solved using baby-step giant-step or, if the prime is large, Pollard's rho. Finally,
+    /// the solutions are combined using the Chinese remainder theorem.
+    ///
+    /// # Worst-case complexity
+    /// The running time is dominated by the factorization of $m$ and of $p - 1$ for each prime $p$
+    /// dividing $m$, and by the square root of the largest prime factor of the order of $g$.
+    ///
+    /// # Panics
+    /// Panics if `self` or `base` is greater than or equal to `m`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::ModLog;
+    /// use malachite_nz::natural::Natural;
+    /// use std::str::FromStr;
+    ///
+    /// assert_eq!(
+    ///     Natural::from(3u32).mod_log(Natural::from(2u32), Natural::from(11u32)),
+    ///     Some(Natural::from(8u32))
+    /// );
+    /// assert_eq!(
+    ///     Natural::from(10u32).mod_log(Natural::from(6u32), Natural::from(100u32)),
+    ///     None
+    /// );
+    /// assert_eq!(
+    ///     Natural::from(12345u32).mod_log(
+    ///         Natural::from(3u32),
+    ///         Natural::from_str("618970019642690137449562111").unwrap()
+    ///     ),
+    ///     Some(Natural::from_str("313568898202173276348660521").unwrap())
+    /// );
+    /// ```
+    #[inline]
+    fn mod_log(self, base: Natural, m: Natural) -> Option<Natural> {
+        mod_log(&self, &base, &m)
+    }
+}
+
+impl<'a, 'b> ModLog<&'a Natural, &'b Natural> for Natural {
+    type Output = Natural;
+
+    /// Computes the discrete logarithm of a [`Natural`] modulo another [`Natural`] $m$. Assumes
+    /// the number and the base are already reduced modulo $m$. The first [`Natural`] is taken by
+    /// value and the second and third by reference.
+    ///
+    /// Returns the smallest $x \geq 0$ such that $g^x \equiv h \mod m$, where $h$ is `self` and $g$
+    /// is `base`, or `None` if there is no such $x$.
+    ///
+    /// Common factors of $g$ and $m$ are divided out first. The modulus is then factored, and the
+    /// order of $g$ modulo each prime power is found from the Carmichael function. The
+    /// Pohlig-Hellman algorithm reduces each problem to problems in groups of prime order, which
+    /// are solved using baby-step giant-step or, if the prime is large, Pollard's rho. Finally,
+    /// the solutions are combined using the Chinese remainder theorem.
+    ///
+    /// # Worst-case complexity
+    /// The running time is dominated by the factorization of $m$ and of $p - 1$ for each prime $p$
+    /// dividing $m$, and by the square root of the largest prime factor of the order of $g$.
+    ///
+    /// # Panics
+    /// Panics if `self` or `base` is greater than or equal to `m`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::ModLog;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!(
+    ///     Natural::from(3u32).mod_log(&Natural::from(2u32), &Natural::from(11u32)),
+    ///     Some(Natural::from(8u32))
+    /// );
+    /// assert_eq!(
+    ///     Natural::from(56u32).mod_log(&Natural::from(6u32), &Natural::from(100u32)),
+    ///     Some(Natural::from(6u32))
+    /// );
+    /// ```
+    #[inline]
+    fn mod_log(self, base: &'a Natural, m: &'b Natural) -> Option<Natural> {
+        mod_log(&self, base, m)
+    }
+}
+
+impl<'a> ModLog<Natural, Natural> for &'a Natural {
+    type Output = Natural;
+
+    /// Computes the discrete logarithm of a [`Natural`] modulo another [`Natural`] $m$. Assumes
+    /// the number and the base are already reduced modulo $m$. The first [`Natural`] is taken by
+    /// reference and the second and third by value.
+    ///
+    /// Returns the smallest $x \geq 0$ such that $g^x \equiv h \mod m$, where $h$ is `self` and $g$
+    /// is `base`, or `None` if there is no such $x$.
+    ///
+    /// Common factors of $g$ and $m$ are divided out first. The modulus is then factored, and the
+    /// order of $g$ modulo each prime power is found from the Carmichael function. The
+    /// Pohlig-Hellman algorithm reduces each problem to problems in groups of prime order, which
+    /// are solved using baby-step giant-step or, if the prime is large, Pollard's rho. Finally,
+    /// the solutions are combined using the Chinese remainder theorem.
+    ///
+    /// # Worst-case complexity
+    /// The running time is dominated by the factorization of $m$ and of $p - 1$ for each prime $p$
+    /// dividing $m$, and by the square root of the largest prime factor of the order of $g$.
+    ///
+    /// # Panics
+    /// Panics if `self` or `base` is greater than or equal to `m`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::ModLog;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!(
+    ///     (&Natural::from(3u32)).mod_log(Natural::from(2u32), Natural::from(11u32)),
+    ///     Some(Natural::from(8u32))
+    /// );
+    /// assert_eq!(
+    ///     (&Natural::from(0u32)).mod_log(Natural::from(2u32), Natural::from(16u32)),
+    ///     Some(Natural::from(4u32))
+    /// );
+    /// ```
+    #[inline]
+    fn mod_log(self, base: Natural, m: Natural) -> Option<Natural> {
+        mod_log(self, &base, &m)
+    }
+}
+
+impl<'a, 'b, 'c> ModLog<&'b Natural, &'c Natural> for &'a Natural {
+    type Output = Natural;
+
+    /// Computes the discrete logarithm of a [`Natural`] modulo another [`Natural`] $m$. Assumes
+    /// the number and the base are already reduced modulo $m$. All three [`Natural`]s are taken by
+    /// reference.
+    ///
+    /// Returns the smallest $x \geq 0$ such that $g^x \equiv h \mod m$, where $h$ is `self` and $g$
+    /// is `base`, or `None` if there is no such $x$.
+    ///
+    /// Common factors of $g$ and $m$ are divided out first. The modulus is then factored, and the
+    /// order of $g$ modulo each prime power is found from the Carmichael function. The
+    /// Pohlig-Hellman algorithm reduces each problem to problems in groups of prime order, which
+    /// are solved using baby-step giant-step or, if the prime is large, Pollard's rho. Finally,
+    /// the solutions are combined using the Chinese remainder theorem.
+    ///
+    /// # Worst-case complexity
+    /// The running time is dominated by the factorization of $m$ and of $p - 1$ for each prime $p$
+    /// dividing $m$, and by the square root of the largest prime factor of the order of $g$.
+    ///
+    /// # Panics
+    /// Panics if `self` or `base` is greater than or equal to `m`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::ModLog;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!(
+    ///     (&Natural::from(3u32)).mod_log(&Natural::from(4u32), &Natural::from(7u32)),
+    ///     None
+    /// );
+    /// assert_eq!(
+    ///     (&Natural::from(7u32)).mod_log(&Natural::from(3u32), &Natural::from(1000000007u32)),
+    ///     Some(Natural::from(70669385u32))
+    /// );
+    /// ```
+    #[inline]
+    fn mod_log(self, base: &'b Natural, m: &'c Natural) -> Option<Natural> {
+        mod_log(self, base, m)
+    }
+}
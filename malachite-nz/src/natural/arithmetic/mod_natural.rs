@@ -0,0 +1,1328 @@
+use crate::natural::Natural;
+use malachite_base::num::arithmetic::traits::{
+    ModAdd, ModAddAssign, ModInverse, ModIsReduced, ModMul, ModMulAssign, ModNeg, ModPow,
+    ModPowAssign, ModSub, ModSubAssign, Pow, PowAssign,
+};
+use std::fmt::{Debug, Display, Formatter, Result};
+use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+/// A residue modulo a [`Natural`] $m$, which borrows its modulus.
+///
+/// The value is always reduced modulo $m$; this is checked when a [`ModNatural`] is created. The
+/// arithmetic operators work modulo $m$, so finite-field code can be written as `a * b + c`
+/// instead of chains of [`ModMul`] and [`ModAdd`] calls. Both operands of a binary operation must
+/// have the same modulus. Since many residues usually share one modulus, the modulus is borrowed
+/// rather than cloned into each one.
+#[derive(Clone, Eq, Hash, PartialEq)]
+pub struct ModNatural<'a> {
+    value: Natural,
+    modulus: &'a Natural,
+}
+
+// Panics unless `x` and `y` have the same modulus. Comparing the references first avoids comparing
+// the moduli limb by limb in the usual case, where both residues borrow the same `Natural`.
+fn assert_same_modulus(x: &ModNatural, y: &ModNatural) {
+    assert!(
+        std::ptr::eq(x.modulus, y.modulus) || x.modulus == y.modulus,
+        "Cannot combine residues with different moduli: {} and {}",
+        x.modulus,
+        y.modulus
+    );
+}
+
+impl<'a> ModNatural<'a> {
+    /// Creates a [`ModNatural`] from a [`Natural`] that is already reduced modulo $m$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(1)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `m.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `m` is zero or if `x` is greater than or equal to `m`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::natural::arithmetic::mod_natural::ModNatural;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// let m = Natural::from(10u32);
+    /// assert_eq!(ModNatural::new(Natural::from(7u32), &m).to_string(), "7 mod 10");
+    /// ```
+    pub fn new(x: Natural, m: &'a Natural) -> ModNatural<'a> {
+        assert!(x.mod_is_reduced(m), "{} is not reduced modulo {}", x, m);
+        ModNatural {
+            value: x,
+            modulus: m,
+        }
+    }
+
+    /// Creates a [`ModNatural`] by reducing a [`Natural`] modulo $m$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `x.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `m` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::natural::arithmetic::mod_natural::ModNatural;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// let m = Natural::from(10u32);
+    /// assert_eq!(ModNatural::reduce(Natural::from(123u32), &m).to_string(), "3 mod 10");
+    /// ```
+    pub fn reduce(x: Natural, m: &'a Natural) -> ModNatural<'a> {
+        ModNatural {
+            value: x % m,
+            modulus: m,
+        }
+    }
+
+    /// Returns the value of a [`ModNatural`], which is less than its modulus.
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::natural::arithmetic::mod_natural::ModNatural;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// let m = Natural::from(10u32);
+    /// assert_eq!(*ModNatural::reduce(Natural::from(123u32), &m).value(), 3);
+    /// ```
+    pub const fn value(&self) -> &Natural {
+        &self.value
+    }
+
+    /// Returns the value of a [`ModNatural`], which is less than its modulus, taking the
+    /// [`ModNatural`] by value.
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::natural::arithmetic::mod_natural::ModNatural;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// let m = Natural::from(10u32);
+    /// assert_eq!(ModNatural::reduce(Natural::from(123u32), &m).into_value(), 3);
+    /// ```
+    pub fn into_value(self) -> Natural {
+        self.value
+    }
+
+    /// Returns the modulus of a [`ModNatural`].
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::natural::arithmetic::mod_natural::ModNatural;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// let m = Natural::from(10u32);
+    /// assert_eq!(*ModNatural::reduce(Natural::from(123u32), &m).modulus(), 10);
+    /// ```
+    pub const fn modulus(&self) -> &'a Natural {
+        self.modulus
+    }
+
+    /// Computes the multiplicative inverse of a [`ModNatural`].
+    ///
+    /// Returns `None` if the value and the modulus are not coprime. Modulo 1, the inverse of 0 is
+    /// 0.
+    ///
+    /// $f(x) = y$, where $y < m$ and $xy \equiv 1 \mod m$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `m.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::strings::ToDebugString;
+    /// use malachite_nz::natural::arithmetic::mod_natural::ModNatural;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// let m = Natural::from(10u32);
+    /// assert_eq!(
+    ///     ModNatural::new(Natural::from(3u32), &m).inverse().to_debug_string(),
+    ///     "Some(7 mod 10)"
+    /// );
+    /// assert_eq!(
+    ///     ModNatural::new(Natural::from(4u32), &m).inverse().to_debug_string(),
+    ///     "None"
+    /// );
+    /// ```
+    pub fn inverse(&self) -> Option<ModNatural<'a>> {
+        let inverse = if *self.modulus == 1u32 {
+            Some(self.value.clone())
+        } else if self.value == 0u32 {
+            None
+        } else {
+            (&self.value).mod_inverse(self.modulus)
+        };
+        inverse.map(|value| ModNatural {
+            value,
+            modulus: self.modulus,
+        })
+    }
+}
+
+impl<'b> Add<ModNatural<'b>> for ModNatural<'b> {
+    type Output = ModNatural<'b>;
+
+    /// Adds two [`ModNatural`]s with the same modulus $m$. Both [`ModNatural`]s are taken by value.
+    ///
+    /// $f(x, y) = z$, where $z < m$ and $x + y \equiv z \mod m$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `m.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `self` and `other` have different moduli.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::natural::arithmetic::mod_natural::ModNatural;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// let m = Natural::from(10u32);
+    /// let x = ModNatural::new(Natural::from(7u32), &m);
+    /// let y = ModNatural::new(Natural::from(5u32), &m);
+    /// assert_eq!((x + y).to_string(), "2 mod 10");
+    /// let x = ModNatural::new(Natural::from(3u32), &m);
+    /// let y = ModNatural::new(Natural::from(4u32), &m);
+    /// assert_eq!((x + y).to_string(), "7 mod 10");
+    /// ```
+    #[inline]
+    fn add(self, other: ModNatural<'b>) -> ModNatural<'b> {
+        assert_same_modulus(&self, &other);
+        ModNatural {
+            value: self.value.mod_add(other.value, self.modulus),
+            modulus: self.modulus,
+        }
+    }
+}
+
+impl<'a, 'b> Add<&'a ModNatural<'b>> for ModNatural<'b> {
+    type Output = ModNatural<'b>;
+
+    /// Adds two [`ModNatural`]s with the same modulus $m$. The first [`ModNatural`] is taken by
+    /// value and the second by reference.
+    ///
+    /// $f(x, y) = z$, where $z < m$ and $x + y \equiv z \mod m$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `m.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `self` and `other` have different moduli.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::natural::arithmetic::mod_natural::ModNatural;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// let m = Natural::from(10u32);
+    /// let x = ModNatural::new(Natural::from(7u32), &m);
+    /// let y = ModNatural::new(Natural::from(5u32), &m);
+    /// assert_eq!((x + &y).to_string(), "2 mod 10");
+    /// let x = ModNatural::new(Natural::from(3u32), &m);
+    /// let y = ModNatural::new(Natural::from(4u32), &m);
+    /// assert_eq!((x + &y).to_string(), "7 mod 10");
+    /// ```
+    #[inline]
+    fn add(self, other: &'a ModNatural<'b>) -> ModNatural<'b> {
+        assert_same_modulus(&self, other);
+        ModNatural {
+            value: self.value.mod_add(&other.value, self.modulus),
+            modulus: self.modulus,
+        }
+    }
+}
+
+impl<'a, 'b> Add<ModNatural<'b>> for &'a ModNatural<'b> {
+    type Output = ModNatural<'b>;
+
+    /// Adds two [`ModNatural`]s with the same modulus $m$. The first [`ModNatural`] is taken by
+    /// reference and the second by value.
+    ///
+    /// $f(x, y) = z$, where $z < m$ and $x + y \equiv z \mod m$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `m.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `self` and `other` have different moduli.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::natural::arithmetic::mod_natural::ModNatural;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// let m = Natural::from(10u32);
+    /// let x = ModNatural::new(Natural::from(7u32), &m);
+    /// let y = ModNatural::new(Natural::from(5u32), &m);
+    /// assert_eq!((&x + y).to_string(), "2 mod 10");
+    /// let x = ModNatural::new(Natural::from(3u32), &m);
+    /// let y = ModNatural::new(Natural::from(4u32), &m);
+    /// assert_eq!((&x + y).to_string(), "7 mod 10");
+    /// ```
+    #[inline]
+    fn add(self, other: ModNatural<'b>) -> ModNatural<'b> {
+        assert_same_modulus(self, &other);
+        ModNatural {
+            value: (&self.value).mod_add(other.value, self.modulus),
+            modulus: self.modulus,
+        }
+    }
+}
+
+impl<'a, 'b, 'c> Add<&'c ModNatural<'b>> for &'a ModNatural<'b> {
+    type Output = ModNatural<'b>;
+
+    /// Adds two [`ModNatural`]s with the same modulus $m$. Both [`ModNatural`]s are taken by
+    /// reference.
+    ///
+    /// $f(x, y) = z$, where $z < m$ and $x + y \equiv z \mod m$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `m.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `self` and `other` have different moduli.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::natural::arithmetic::mod_natural::ModNatural;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// let m = Natural::from(10u32);
+    /// let x = ModNatural::new(Natural::from(7u32), &m);
+    /// let y = ModNatural::new(Natural::from(5u32), &m);
+    /// assert_eq!((&x + &y).to_string(), "2 mod 10");
+    /// let x = ModNatural::new(Natural::from(3u32), &m);
+    /// let y = ModNatural::new(Natural::from(4u32), &m);
+    /// assert_eq!((&x + &y).to_string(), "7 mod 10");
+    /// ```
+    #[inline]
+    fn add(self, other: &'c ModNatural<'b>) -> ModNatural<'b> {
+        assert_same_modulus(self, other);
+        ModNatural {
+            value: (&self.value).mod_add(&other.value, self.modulus),
+            modulus: self.modulus,
+        }
+    }
+}
+
+impl<'b> AddAssign<ModNatural<'b>> for ModNatural<'b> {
+    /// Adds a [`ModNatural`] to another [`ModNatural`] with the same modulus $m$, in place. The
+    /// [`ModNatural`] on the right-hand side is taken by value.
+    ///
+    /// $x \gets z$, where $z < m$ and $x + y \equiv z \mod m$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `m.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `self` and `other` have different moduli.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::natural::arithmetic::mod_natural::ModNatural;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// let m = Natural::from(10u32);
+    /// let mut x = ModNatural::new(Natural::from(7u32), &m);
+    /// x += ModNatural::new(Natural::from(5u32), &m);
+    /// assert_eq!(x.to_string(), "2 mod 10");
+    /// ```
+    #[inline]
+    fn add_assign(&mut self, other: ModNatural<'b>) {
+        assert_same_modulus(self, &other);
+        self.value.mod_add_assign(other.value, self.modulus);
+    }
+}
+
+impl<'a, 'b> AddAssign<&'a ModNatural<'b>> for ModNatural<'b> {
+    /// Adds a [`ModNatural`] to another [`ModNatural`] with the same modulus $m$, in place. The
+    /// [`ModNatural`] on the right-hand side is taken by reference.
+    ///
+    /// $x \gets z$, where $z < m$ and $x + y \equiv z \mod m$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `m.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `self` and `other` have different moduli.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::natural::arithmetic::mod_natural::ModNatural;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// let m = Natural::from(10u32);
+    /// let mut x = ModNatural::new(Natural::from(7u32), &m);
+    /// x += &ModNatural::new(Natural::from(5u32), &m);
+    /// assert_eq!(x.to_string(), "2 mod 10");
+    /// ```
+    #[inline]
+    fn add_assign(&mut self, other: &'a ModNatural<'b>) {
+        assert_same_modulus(self, other);
+        self.value.mod_add_assign(&other.value, self.modulus);
+    }
+}
+
+impl<'b> Sub<ModNatural<'b>> for ModNatural<'b> {
+    type Output = ModNatural<'b>;
+
+    /// Subtracts a [`ModNatural`] by another [`ModNatural`] with the same modulus $m$. Both
+    /// [`ModNatural`]s are taken by value.
+    ///
+    /// $f(x, y) = z$, where $z < m$ and $x - y \equiv z \mod m$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `m.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `self` and `other` have different moduli.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::natural::arithmetic::mod_natural::ModNatural;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// let m = Natural::from(10u32);
+    /// let x = ModNatural::new(Natural::from(7u32), &m);
+    /// let y = ModNatural::new(Natural::from(5u32), &m);
+    /// assert_eq!((x - y).to_string(), "2 mod 10");
+    /// let x = ModNatural::new(Natural::from(3u32), &m);
+    /// let y = ModNatural::new(Natural::from(4u32), &m);
+    /// assert_eq!((x - y).to_string(), "9 mod 10");
+    /// ```
+    #[inline]
+    fn sub(self, other: ModNatural<'b>) -> ModNatural<'b> {
+        assert_same_modulus(&self, &other);
+        ModNatural {
+            value: self.value.mod_sub(other.value, self.modulus),
+            modulus: self.modulus,
+        }
+    }
+}
+
+impl<'a, 'b> Sub<&'a ModNatural<'b>> for ModNatural<'b> {
+    type Output = ModNatural<'b>;
+
+    /// Subtracts a [`ModNatural`] by another [`ModNatural`] with the same modulus $m$. The first
+    /// [`ModNatural`] is taken by value and the second by reference.
+    ///
+    /// $f(x, y) = z$, where $z < m$ and $x - y \equiv z \mod m$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `m.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `self` and `other` have different moduli.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::natural::arithmetic::mod_natural::ModNatural;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// let m = Natural::from(10u32);
+    /// let x = ModNatural::new(Natural::from(7u32), &m);
+    /// let y = ModNatural::new(Natural::from(5u32), &m);
+    /// assert_eq!((x - &y).to_string(), "2 mod 10");
+    /// let x = ModNatural::new(Natural::from(3u32), &m);
+    /// let y = ModNatural::new(Natural::from(4u32), &m);
+    /// assert_eq!((x - &y).to_string(), "9 mod 10");
+    /// ```
+    #[inline]
+    fn sub(self, other: &'a ModNatural<'b>) -> ModNatural<'b> {
+        assert_same_modulus(&self, other);
+        ModNatural {
+            value: self.value.mod_sub(&other.value, self.modulus),
+            modulus: self.modulus,
+        }
+    }
+}
+
+impl<'a, 'b> Sub<ModNatural<'b>> for &'a ModNatural<'b> {
+    type Output = ModNatural<'b>;
+
+    /// Subtracts a [`ModNatural`] by another [`ModNatural`] with the same modulus $m$. The first
+    /// [`ModNatural`] is taken by reference and the second by value.
+    ///
+    /// $f(x, y) = z$, where $z < m$ and $x - y \equiv z \mod m$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `m.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `self` and `other` have different moduli.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::natural::arithmetic::mod_natural::ModNatural;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// let m = Natural::from(10u32);
+    /// let x = ModNatural::new(Natural::from(7u32), &m);
+    /// let y = ModNatural::new(Natural::from(5u32), &m);
+    /// assert_eq!((&x - y).to_string(), "2 mod 10");
+    /// let x = ModNatural::new(Natural::from(3u32), &m);
+    /// let y = ModNatural::new(Natural::from(4u32), &m);
+    /// assert_eq!((&x - y).to_string(), "9 mod 10");
+    /// ```
+    #[inline]
+    fn sub(self, other: ModNatural<'b>) -> ModNatural<'b> {
+        assert_same_modulus(self, &other);
+        ModNatural {
+            value: (&self.value).mod_sub(other.value, self.modulus),
+            modulus: self.modulus,
+        }
+    }
+}
+
+impl<'a, 'b, 'c> Sub<&'c ModNatural<'b>> for &'a ModNatural<'b> {
+    type Output = ModNatural<'b>;
+
+    /// Subtracts a [`ModNatural`] by another [`ModNatural`] with the same modulus $m$. Both
+    /// [`ModNatural`]s are taken by reference.
+    ///
+    /// $f(x, y) = z$, where $z < m$ and $x - y \equiv z \mod m$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `m.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `self` and `other` have different moduli.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::natural::arithmetic::mod_natural::ModNatural;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// let m = Natural::from(10u32);
+    /// let x = ModNatural::new(Natural::from(7u32), &m);
+    /// let y = ModNatural::new(Natural::from(5u32), &m);
+    /// assert_eq!((&x - &y).to_string(), "2 mod 10");
+    /// let x = ModNatural::new(Natural::from(3u32), &m);
+    /// let y = ModNatural::new(Natural::from(4u32), &m);
+    /// assert_eq!((&x - &y).to_string(), "9 mod 10");
+    /// ```
+    #[inline]
+    fn sub(self, other: &'c ModNatural<'b>) -> ModNatural<'b> {
+        assert_same_modulus(self, other);
+        ModNatural {
+            value: (&self.value).mod_sub(&other.value, self.modulus),
+            modulus: self.modulus,
+        }
+    }
+}
+
+impl<'b> SubAssign<ModNatural<'b>> for ModNatural<'b> {
+    /// Subtracts a [`ModNatural`] by another [`ModNatural`] with the same modulus $m$, in place.
+    /// The [`ModNatural`] on the right-hand side is taken by value.
+    ///
+    /// $x \gets z$, where $z < m$ and $x - y \equiv z \mod m$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `m.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `self` and `other` have different moduli.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::natural::arithmetic::mod_natural::ModNatural;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// let m = Natural::from(10u32);
+    /// let mut x = ModNatural::new(Natural::from(7u32), &m);
+    /// x -= ModNatural::new(Natural::from(5u32), &m);
+    /// assert_eq!(x.to_string(), "2 mod 10");
+    /// ```
+    #[inline]
+    fn sub_assign(&mut self, other: ModNatural<'b>) {
+        assert_same_modulus(self, &other);
+        self.value.mod_sub_assign(other.value, self.modulus);
+    }
+}
+
+impl<'a, 'b> SubAssign<&'a ModNatural<'b>> for ModNatural<'b> {
+    /// Subtracts a [`ModNatural`] by another [`ModNatural`] with the same modulus $m$, in place.
+    /// The [`ModNatural`] on the right-hand side is taken by reference.
+    ///
+    /// $x \gets z$, where $z < m$ and $x - y \equiv z \mod m$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `m.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `self` and `other` have different moduli.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::natural::arithmetic::mod_natural::ModNatural;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// let m = Natural::from(10u32);
+    /// let mut x = ModNatural::new(Natural::from(7u32), &m);
+    /// x -= &ModNatural::new(Natural::from(5u32), &m);
+    /// assert_eq!(x.to_string(), "2 mod 10");
+    /// ```
+    #[inline]
+    fn sub_assign(&mut self, other: &'a ModNatural<'b>) {
+        assert_same_modulus(self, other);
+        self.value.mod_sub_assign(&other.value, self.modulus);
+    }
+}
+
+impl<'b> Mul<ModNatural<'b>> for ModNatural<'b> {
+    type Output = ModNatural<'b>;
+
+    /// Multiplies two [`ModNatural`]s with the same modulus $m$. Both [`ModNatural`]s are taken by
+    /// value.
+    ///
+    /// $f(x, y) = z$, where $z < m$ and $xy \equiv z \mod m$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `m.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `self` and `other` have different moduli.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::natural::arithmetic::mod_natural::ModNatural;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// let m = Natural::from(10u32);
+    /// let x = ModNatural::new(Natural::from(7u32), &m);
+    /// let y = ModNatural::new(Natural::from(5u32), &m);
+    /// assert_eq!((x * y).to_string(), "5 mod 10");
+    /// let x = ModNatural::new(Natural::from(3u32), &m);
+    /// let y = ModNatural::new(Natural::from(4u32), &m);
+    /// assert_eq!((x * y).to_string(), "2 mod 10");
+    /// ```
+    #[inline]
+    fn mul(self, other: ModNatural<'b>) -> ModNatural<'b> {
+        assert_same_modulus(&self, &other);
+        ModNatural {
+            value: self.value.mod_mul(other.value, self.modulus),
+            modulus: self.modulus,
+        }
+    }
+}
+
+impl<'a, 'b> Mul<&'a ModNatural<'b>> for ModNatural<'b> {
+    type Output = ModNatural<'b>;
+
+    /// Multiplies two [`ModNatural`]s with the same modulus $m$. The first [`ModNatural`] is taken
+    /// by value and the second by reference.
+    ///
+    /// $f(x, y) = z$, where $z < m$ and $xy \equiv z \mod m$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `m.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `self` and `other` have different moduli.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::natural::arithmetic::mod_natural::ModNatural;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// let m = Natural::from(10u32);
+    /// let x = ModNatural::new(Natural::from(7u32), &m);
+    /// let y = ModNatural::new(Natural::from(5u32), &m);
+    /// assert_eq!((x * &y).to_string(), "5 mod 10");
+    /// let x = ModNatural::new(Natural::from(3u32), &m);
+    /// let y = ModNatural::new(Natural::from(4u32), &m);
+    /// assert_eq!((x * &y).to_string(), "2 mod 10");
+    /// ```
+    #[inline]
+    fn mul(self, other: &'a ModNatural<'b>) -> ModNatural<'b> {
+        assert_same_modulus(&self, other);
+        ModNatural {
+            value: self.value.mod_mul(&other.value, self.modulus),
+            modulus: self.modulus,
+        }
+    }
+}
+
+impl<'a, 'b> Mul<ModNatural<'b>> for &'a ModNatural<'b> {
+    type Output = ModNatural<'b>;
+
+    /// Multiplies two [`ModNatural`]s with the same modulus $m$. The first [`ModNatural`] is taken
+    /// by reference and the second by value.
+    ///
+    /// $f(x, y) = z$, where $z < m$ and $xy \equiv z \mod m$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `m.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `self` and `other` have different moduli.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::natural::arithmetic::mod_natural::ModNatural;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// let m = Natural::from(10u32);
+    /// let x = ModNatural::new(Natural::from(7u32), &m);
+    /// let y = ModNatural::new(Natural::from(5u32), &m);
+    /// assert_eq!((&x * y).to_string(), "5 mod 10");
+    /// let x = ModNatural::new(Natural::from(3u32), &m);
+    /// let y = ModNatural::new(Natural::from(4u32), &m);
+    /// assert_eq!((&x * y).to_string(), "2 mod 10");
+    /// ```
+    #[inline]
+    fn mul(self, other: ModNatural<'b>) -> ModNatural<'b> {
+        assert_same_modulus(self, &other);
+        ModNatural {
+            value: (&self.value).mod_mul(other.value, self.modulus),
+            modulus: self.modulus,
+        }
+    }
+}
+
+impl<'a, 'b, 'c> Mul<&'c ModNatural<'b>> for &'a ModNatural<'b> {
+    type Output = ModNatural<'b>;
+
+    /// Multiplies two [`ModNatural`]s with the same modulus $m$. Both [`ModNatural`]s are taken by
+    /// reference.
+    ///
+    /// $f(x, y) = z$, where $z < m$ and $xy \equiv z \mod m$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `m.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `self` and `other` have different moduli.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::natural::arithmetic::mod_natural::ModNatural;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// let m = Natural::from(10u32);
+    /// let x = ModNatural::new(Natural::from(7u32), &m);
+    /// let y = ModNatural::new(Natural::from(5u32), &m);
+    /// assert_eq!((&x * &y).to_string(), "5 mod 10");
+    /// let x = ModNatural::new(Natural::from(3u32), &m);
+    /// let y = ModNatural::new(Natural::from(4u32), &m);
+    /// assert_eq!((&x * &y).to_string(), "2 mod 10");
+    /// ```
+    #[inline]
+    fn mul(self, other: &'c ModNatural<'b>) -> ModNatural<'b> {
+        assert_same_modulus(self, other);
+        ModNatural {
+            value: (&self.value).mod_mul(&other.value, self.modulus),
+            modulus: self.modulus,
+        }
+    }
+}
+
+impl<'b> MulAssign<ModNatural<'b>> for ModNatural<'b> {
+    /// Multiplies a [`ModNatural`] by another [`ModNatural`] with the same modulus $m$, in place.
+    /// The [`ModNatural`] on the right-hand side is taken by value.
+    ///
+    /// $x \gets z$, where $z < m$ and $xy \equiv z \mod m$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `m.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `self` and `other` have different moduli.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::natural::arithmetic::mod_natural::ModNatural;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// let m = Natural::from(10u32);
+    /// let mut x = ModNatural::new(Natural::from(7u32), &m);
+    /// x *= ModNatural::new(Natural::from(5u32), &m);
+    /// assert_eq!(x.to_string(), "5 mod 10");
+    /// ```
+    #[inline]
+    fn mul_assign(&mut self, other: ModNatural<'b>) {
+        assert_same_modulus(self, &other);
+        self.value.mod_mul_assign(other.value, self.modulus);
+    }
+}
+
+impl<'a, 'b> MulAssign<&'a ModNatural<'b>> for ModNatural<'b> {
+    /// Multiplies a [`ModNatural`] by another [`ModNatural`] with the same modulus $m$, in place.
+    /// The [`ModNatural`] on the right-hand side is taken by reference.
+    ///
+    /// $x \gets z$, where $z < m$ and $xy \equiv z \mod m$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `m.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `self` and `other` have different moduli.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::natural::arithmetic::mod_natural::ModNatural;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// let m = Natural::from(10u32);
+    /// let mut x = ModNatural::new(Natural::from(7u32), &m);
+    /// x *= &ModNatural::new(Natural::from(5u32), &m);
+    /// assert_eq!(x.to_string(), "5 mod 10");
+    /// ```
+    #[inline]
+    fn mul_assign(&mut self, other: &'a ModNatural<'b>) {
+        assert_same_modulus(self, other);
+        self.value.mod_mul_assign(&other.value, self.modulus);
+    }
+}
+
+impl<'b> Div<ModNatural<'b>> for ModNatural<'b> {
+    type Output = ModNatural<'b>;
+
+    /// Divides a [`ModNatural`] by another [`ModNatural`] with the same modulus $m$, by multiplying
+    /// by the inverse of the second [`ModNatural`]. Both [`ModNatural`]s are taken by value.
+    ///
+    /// $f(x, y) = z$, where $z < m$ and $yz \equiv x \mod m$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `m.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `self` and `other` have different moduli, or if `other` is not invertible modulo
+    /// $m$.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::natural::arithmetic::mod_natural::ModNatural;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// let m = Natural::from(10u32);
+    /// let x = ModNatural::new(Natural::from(7u32), &m);
+    /// let y = ModNatural::new(Natural::from(3u32), &m);
+    /// assert_eq!((x / y).to_string(), "9 mod 10");
+    /// let x = ModNatural::new(Natural::from(2u32), &m);
+    /// let y = ModNatural::new(Natural::from(9u32), &m);
+    /// assert_eq!((x / y).to_string(), "8 mod 10");
+    /// ```
+    fn div(self, other: ModNatural<'b>) -> ModNatural<'b> {
+        assert_same_modulus(&self, &other);
+        let inverse = other
+            .inverse()
+            .expect("Division by a non-invertible residue");
+        self * inverse
+    }
+}
+
+impl<'a, 'b> Div<&'a ModNatural<'b>> for ModNatural<'b> {
+    type Output = ModNatural<'b>;
+
+    /// Divides a [`ModNatural`] by another [`ModNatural`] with the same modulus $m$, by multiplying
+    /// by the inverse of the second [`ModNatural`]. The first [`ModNatural`] is taken by value and
+    /// the second by reference.
+    ///
+    /// $f(x, y) = z$, where $z < m$ and $yz \equiv x \mod m$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `m.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `self` and `other` have different moduli, or if `other` is not invertible modulo
+    /// $m$.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::natural::arithmetic::mod_natural::ModNatural;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// let m = Natural::from(10u32);
+    /// let x = ModNatural::new(Natural::from(7u32), &m);
+    /// let y = ModNatural::new(Natural::from(3u32), &m);
+    /// assert_eq!((x / &y).to_string(), "9 mod 10");
+    /// let x = ModNatural::new(Natural::from(2u32), &m);
+    /// let y = ModNatural::new(Natural::from(9u32), &m);
+    /// assert_eq!((x / &y).to_string(), "8 mod 10");
+    /// ```
+    fn div(self, other: &'a ModNatural<'b>) -> ModNatural<'b> {
+        assert_same_modulus(&self, other);
+        let inverse = other
+            .inverse()
+            .expect("Division by a non-invertible residue");
+        self * inverse
+    }
+}
+
+impl<'a, 'b> Div<ModNatural<'b>> for &'a ModNatural<'b> {
+    type Output = ModNatural<'b>;
+
+    /// Divides a [`ModNatural`] by another [`ModNatural`] with the same modulus $m$, by multiplying
+    /// by the inverse of the second [`ModNatural`]. The first [`ModNatural`] is taken by reference
+    /// and the second by value.
+    ///
+    /// $f(x, y) = z$, where $z < m$ and $yz \equiv x \mod m$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `m.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `self` and `other` have different moduli, or if `other` is not invertible modulo
+    /// $m$.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::natural::arithmetic::mod_natural::ModNatural;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// let m = Natural::from(10u32);
+    /// let x = ModNatural::new(Natural::from(7u32), &m);
+    /// let y = ModNatural::new(Natural::from(3u32), &m);
+    /// assert_eq!((&x / y).to_string(), "9 mod 10");
+    /// let x = ModNatural::new(Natural::from(2u32), &m);
+    /// let y = ModNatural::new(Natural::from(9u32), &m);
+    /// assert_eq!((&x / y).to_string(), "8 mod 10");
+    /// ```
+    fn div(self, other: ModNatural<'b>) -> ModNatural<'b> {
+        assert_same_modulus(self, &other);
+        let inverse = other
+            .inverse()
+            .expect("Division by a non-invertible residue");
+        self * inverse
+    }
+}
+
+impl<'a, 'b, 'c> Div<&'c ModNatural<'b>> for &'a ModNatural<'b> {
+    type Output = ModNatural<'b>;
+
+    /// Divides a [`ModNatural`] by another [`ModNatural`] with the same modulus $m$, by multiplying
+    /// by the inverse of the second [`ModNatural`]. Both [`ModNatural`]s are taken by reference.
+    ///
+    /// $f(x, y) = z$, where $z < m$ and $yz \equiv x \mod m$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `m.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `self` and `other` have different moduli, or if `other` is not invertible modulo
+    /// $m$.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::natural::arithmetic::mod_natural::ModNatural;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// let m = Natural::from(10u32);
+    /// let x = ModNatural::new(Natural::from(7u32), &m);
+    /// let y = ModNatural::new(Natural::from(3u32), &m);
+    /// assert_eq!((&x / &y).to_string(), "9 mod 10");
+    /// let x = ModNatural::new(Natural::from(2u32), &m);
+    /// let y = ModNatural::new(Natural::from(9u32), &m);
+    /// assert_eq!((&x / &y).to_string(), "8 mod 10");
+    /// ```
+    fn div(self, other: &'c ModNatural<'b>) -> ModNatural<'b> {
+        assert_same_modulus(self, other);
+        let inverse = other
+            .inverse()
+            .expect("Division by a non-invertible residue");
+        self * inverse
+    }
+}
+
+impl<'b> DivAssign<ModNatural<'b>> for ModNatural<'b> {
+    /// Divides a [`ModNatural`] by another [`ModNatural`] with the same modulus $m$, in place. The
+    /// [`ModNatural`] on the right-hand side is taken by value.
+    ///
+    /// $x \gets z$, where $z < m$ and $yz \equiv x \mod m$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `m.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `self` and `other` have different moduli, or if `other` is not invertible modulo
+    /// $m$.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::natural::arithmetic::mod_natural::ModNatural;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// let m = Natural::from(10u32);
+    /// let mut x = ModNatural::new(Natural::from(7u32), &m);
+    /// x /= ModNatural::new(Natural::from(3u32), &m);
+    /// assert_eq!(x.to_string(), "9 mod 10");
+    /// ```
+    fn div_assign(&mut self, other: ModNatural<'b>) {
+        assert_same_modulus(self, &other);
+        let inverse = other
+            .inverse()
+            .expect("Division by a non-invertible residue");
+        self.value.mod_mul_assign(inverse.value, self.modulus);
+    }
+}
+
+impl<'a, 'b> DivAssign<&'a ModNatural<'b>> for ModNatural<'b> {
+    /// Divides a [`ModNatural`] by another [`ModNatural`] with the same modulus $m$, in place. The
+    /// [`ModNatural`] on the right-hand side is taken by reference.
+    ///
+    /// $x \gets z$, where $z < m$ and $yz \equiv x \mod m$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `m.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `self` and `other` have different moduli, or if `other` is not invertible modulo
+    /// $m$.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::natural::arithmetic::mod_natural::ModNatural;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// let m = Natural::from(10u32);
+    /// let mut x = ModNatural::new(Natural::from(7u32), &m);
+    /// x /= &ModNatural::new(Natural::from(3u32), &m);
+    /// assert_eq!(x.to_string(), "9 mod 10");
+    /// ```
+    fn div_assign(&mut self, other: &'a ModNatural<'b>) {
+        assert_same_modulus(self, other);
+        let inverse = other
+            .inverse()
+            .expect("Division by a non-invertible residue");
+        self.value.mod_mul_assign(inverse.value, self.modulus);
+    }
+}
+
+impl<'b> Neg for ModNatural<'b> {
+    type Output = ModNatural<'b>;
+
+    /// Negates a [`ModNatural`] modulo its modulus $m$, taking it by value.
+    ///
+    /// $f(x) = y$, where $y < m$ and $-x \equiv y \mod m$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(1)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `m.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::natural::arithmetic::mod_natural::ModNatural;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// let m = Natural::from(10u32);
+    /// assert_eq!((-ModNatural::new(Natural::from(0u32), &m)).to_string(), "0 mod 10");
+    /// assert_eq!((-ModNatural::new(Natural::from(7u32), &m)).to_string(), "3 mod 10");
+    /// ```
+    #[inline]
+    fn neg(self) -> ModNatural<'b> {
+        ModNatural {
+            value: self.value.mod_neg(self.modulus),
+            modulus: self.modulus,
+        }
+    }
+}
+
+impl<'a, 'b> Neg for &'a ModNatural<'b> {
+    type Output = ModNatural<'b>;
+
+    /// Negates a [`ModNatural`] modulo its modulus $m$, taking it by reference.
+    ///
+    /// $f(x) = y$, where $y < m$ and $-x \equiv y \mod m$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `m.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::natural::arithmetic::mod_natural::ModNatural;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// let m = Natural::from(10u32);
+    /// assert_eq!((-&ModNatural::new(Natural::from(0u32), &m)).to_string(), "0 mod 10");
+    /// assert_eq!((-&ModNatural::new(Natural::from(7u32), &m)).to_string(), "3 mod 10");
+    /// ```
+    #[inline]
+    fn neg(self) -> ModNatural<'b> {
+        ModNatural {
+            value: (&self.value).mod_neg(self.modulus),
+            modulus: self.modulus,
+        }
+    }
+}
+
+impl<'b> Pow<u64> for ModNatural<'b> {
+    type Output = ModNatural<'b>;
+
+    /// Raises a [`ModNatural`] to a power modulo its modulus $m$, taking it by value.
+    ///
+    /// $f(x, n) = y$, where $y < m$ and $x^n \equiv y \mod m$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(mn \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `m.significant_bits()`, and $m$ is
+    /// `exp.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::Pow;
+    /// use malachite_nz::natural::arithmetic::mod_natural::ModNatural;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// let m = Natural::from(10u32);
+    /// assert_eq!(ModNatural::new(Natural::from(3u32), &m).pow(0).to_string(), "1 mod 10");
+    /// assert_eq!(ModNatural::new(Natural::from(3u32), &m).pow(5).to_string(), "3 mod 10");
+    /// assert_eq!(ModNatural::new(Natural::from(4u32), &m).pow(3).to_string(), "4 mod 10");
+    /// ```
+    #[inline]
+    fn pow(self, exp: u64) -> ModNatural<'b> {
+        ModNatural {
+            value: self.value.mod_pow(Natural::from(exp), self.modulus),
+            modulus: self.modulus,
+        }
+    }
+}
+
+impl<'a, 'b> Pow<u64> for &'a ModNatural<'b> {
+    type Output = ModNatural<'b>;
+
+    /// Raises a [`ModNatural`] to a power modulo its modulus $m$, taking it by reference.
+    ///
+    /// $f(x, n) = y$, where $y < m$ and $x^n \equiv y \mod m$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(mn \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `m.significant_bits()`, and $m$ is
+    /// `exp.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::Pow;
+    /// use malachite_nz::natural::arithmetic::mod_natural::ModNatural;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// let m = Natural::from(10u32);
+    /// assert_eq!((&ModNatural::new(Natural::from(3u32), &m)).pow(0).to_string(), "1 mod 10");
+    /// assert_eq!((&ModNatural::new(Natural::from(3u32), &m)).pow(5).to_string(), "3 mod 10");
+    /// assert_eq!((&ModNatural::new(Natural::from(4u32), &m)).pow(3).to_string(), "4 mod 10");
+    /// ```
+    #[inline]
+    fn pow(self, exp: u64) -> ModNatural<'b> {
+        ModNatural {
+            value: (&self.value).mod_pow(Natural::from(exp), self.modulus),
+            modulus: self.modulus,
+        }
+    }
+}
+
+impl<'b> PowAssign<u64> for ModNatural<'b> {
+    /// Raises a [`ModNatural`] to a power modulo its modulus $m$, in place.
+    ///
+    /// $x \gets y$, where $y < m$ and $x^n \equiv y \mod m$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(mn \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `m.significant_bits()`, and $m$ is
+    /// `exp.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::PowAssign;
+    /// use malachite_nz::natural::arithmetic::mod_natural::ModNatural;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// let m = Natural::from(10u32);
+    /// let mut x = ModNatural::new(Natural::from(3u32), &m);
+    /// x.pow_assign(5);
+    /// assert_eq!(x.to_string(), "3 mod 10");
+    /// ```
+    #[inline]
+    fn pow_assign(&mut self, exp: u64) {
+        self.value.mod_pow_assign(Natural::from(exp), self.modulus);
+    }
+}
+
+impl<'a> Display for ModNatural<'a> {
+    /// Converts a [`ModNatural`] to a [`String`], in the form `"x mod m"`.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `m.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::natural::arithmetic::mod_natural::ModNatural;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// let m = Natural::from(10u32);
+    /// assert_eq!(ModNatural::new(Natural::from(7u32), &m).to_string(), "7 mod 10");
+    /// ```
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        write!(f, "{} mod {}", self.value, self.modulus)
+    }
+}
+
+impl<'a> Debug for ModNatural<'a> {
+    /// Converts a [`ModNatural`] to a [`String`], in the form `"x mod m"`.
+    ///
+    /// This is the same as the [`Display::fmt`] implementation.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `m.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::strings::ToDebugString;
+    /// use malachite_nz::natural::arithmetic::mod_natural::ModNatural;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// let m = Natural::from(10u32);
+    /// assert_eq!(
+    ///     ModNatural::new(Natural::from(7u32), &m).to_debug_string(),
+    ///     "7 mod 10"
+    /// );
+    /// ```
+    #[inline]
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        Display::fmt(self, f)
+    }
+}
@@ -1447,7 +1447,7 @@ impl Natural {
         }
     }
 
-    fn rem_limb_ref(&self, other: Limb) -> Limb {
+    pub(crate) fn rem_limb_ref(&self, other: Limb) -> Limb {
         match (self, other) {
             (_, 0) => panic!("division by zero"),
             (Natural(Small(small)), other) => small % other,
@@ -0,0 +1,607 @@
+use crate::natural::Natural;
+use malachite_base::num::arithmetic::traits::{
+    ChineseRemainder, DivisibleBy, LegendreSymbol, ModAdd, ModInverse, ModMul, ModPow, ModPowerOf2,
+    ModSqrt, ModSqrtFromFactorization, ModSquare, ModSub, ModSubAssign, Parity, Pow, PowerOf2,
+    Square,
+};
+use malachite_base::num::basic::traits::{One, Two, Zero};
+use malachite_base::num::logic::traits::{BitIterable, SignificantBits};
+use std::cmp::min;
+
+// Returns the smaller of the two square roots $r$ and $p - r$.
+fn smaller_root(r: Natural, p: &Natural) -> Natural {
+    if r == 0u32 {
+        r
+    } else {
+        let s = p - &r;
+        min(r, s)
+    }
+}
+
+// Computes a square root of `x` modulo the odd prime `p` using the Tonelli-Shanks algorithm, where
+// $p - 1 = 2^s q$ with $q$ odd. Assumes that `x` is a nonzero quadratic residue and that `x < p`.
+fn tonelli_shanks_helper(x: &Natural, p: &Natural, s: u64) -> Natural {
+    if s == 1 {
+        // p ≡ 3 mod 4
+        return x.mod_pow((p >> 2u32) + Natural::ONE, p);
+    }
+    let q = (p - Natural::ONE) >> s;
+    let mut z = Natural::TWO;
+    while (&z).legendre_symbol(p) != -1 {
+        z += Natural::ONE;
+    }
+    let mut m = s;
+    let mut c = z.mod_pow(&q, p);
+    let mut t = x.mod_pow(&q, p);
+    let mut r = x.mod_pow((q >> 1u32) + Natural::ONE, p);
+    while t != 1u32 {
+        // Find the least i such that t^(2^i) = 1. Since t has order dividing 2^(m - 1), 0 < i < m.
+        let mut i = 1;
+        let mut t_power = (&t).mod_square(p);
+        while t_power != 1u32 {
+            t_power = t_power.mod_square(p);
+            i += 1;
+        }
+        let mut b = c;
+        for _ in 0..m - i - 1 {
+            b = b.mod_square(p);
+        }
+        m = i;
+        c = (&b).mod_square(p);
+        t = t.mod_mul(&c, p);
+        r = r.mod_mul(b, p);
+    }
+    r
+}
+
+// Computes a square root of `x` modulo the odd prime `p` using Cipolla's algorithm. Assumes that
+// `x` is a nonzero quadratic residue and that `x < p`.
+fn cipolla_helper(x: &Natural, p: &Natural) -> Natural {
+    let mut a = Natural::ONE;
+    let w = loop {
+        let w = (&a).mod_square(p).mod_sub(x, p);
+        if (&w).legendre_symbol(p) == -1 {
+            break w;
+        }
+        a += Natural::ONE;
+    };
+    // Multiplies u_1 + v_1 ω by u_2 + v_2 ω, where ω^2 = w.
+    let mul = |(u_1, v_1): (&Natural, &Natural), (u_2, v_2): (&Natural, &Natural)| {
+        (
+            u_1.mod_mul(u_2, p)
+                .mod_add(v_1.mod_mul(v_2, p).mod_mul(&w, p), p),
+            u_1.mod_mul(v_2, p).mod_add(u_2.mod_mul(v_1, p), p),
+        )
+    };
+    let exp = (p >> 1u32) + Natural::ONE;
+    let mut u = Natural::ONE;
+    let mut v = Natural::ZERO;
+    for bit in exp.bits().rev() {
+        (u, v) = mul((&u, &v), (&u, &v));
+        if bit {
+            (u, v) = mul((&u, &v), (&a, &Natural::ONE));
+        }
+    }
+    u
+}
+
+fn mod_sqrt_prime(x: &Natural, p: &Natural) -> Option<Natural> {
+    assert!(x < p);
+    if *x == 0u32 || *p == 2u32 {
+        return Some(x.clone());
+    } else if x.legendre_symbol(p) == -1 {
+        return None;
+    }
+    // Tonelli-Shanks needs $O(s^2)$ multiplications, where $2^s$ is the largest power of 2
+    // dividing $p - 1$, and Cipolla's algorithm needs about $6 \log_2 p$. Tonelli-Shanks is faster
+    // unless $s$ is large.
+    let s = (p - Natural::ONE).trailing_zeros().unwrap();
+    let r = if s * s <= p.significant_bits() << 2 {
+        tonelli_shanks_helper(x, p, s)
+    } else {
+        cipolla_helper(x, p)
+    };
+    Some(smaller_root(r, p))
+}
+
+// Computes a square root of the unit `y` modulo `p^e`, where `p` is prime and `y < p^e`.
+fn mod_sqrt_unit_prime_power(y: &Natural, p: &Natural, e: u64) -> Option<Natural> {
+    if *p == 2u32 {
+        // Odd squares are 1 mod 8. A root mod 2^k is lifted to a root mod 2^(k + 1) by adding
+        // 2^(k - 1) if necessary.
+        return match e {
+            1 => Some(Natural::ONE),
+            2 => {
+                if y.mod_power_of_2(2) == 1u32 {
+                    Some(Natural::ONE)
+                } else {
+                    None
+                }
+            }
+            _ => {
+                if y.mod_power_of_2(3) != 1u32 {
+                    return None;
+                }
+                let mut r = Natural::ONE;
+                for k in 3..e {
+                    if (&r).square().mod_power_of_2(k + 1) != y.mod_power_of_2(k + 1) {
+                        r += Natural::power_of_2(k - 1);
+                    }
+                }
+                // The roots are ±r mod 2^(e - 1); r < 2^(e - 1) here.
+                Some(smaller_root(r, &Natural::power_of_2(e - 1)))
+            }
+        };
+    }
+    let mut r = mod_sqrt_prime(&(y % p), p)?;
+    // Hensel lifting: if r^2 ≡ y mod p^k, then r - (r^2 - y) / (2r) is a root mod p^(2k).
+    let mut k = 1;
+    let mut modulus = p.clone();
+    while k < e {
+        k = min(k << 1, e);
+        modulus = p.pow(k);
+        let y = y % &modulus;
+        let inverse = (&r << 1u32).mod_inverse(&modulus).unwrap();
+        let correction = (&r)
+            .mod_square(&modulus)
+            .mod_sub(y, &modulus)
+            .mod_mul(inverse, &modulus);
+        r.mod_sub_assign(correction, &modulus);
+    }
+    Some(smaller_root(r, &modulus))
+}
+
+fn mod_sqrt_prime_power(x: &Natural, p: &Natural, e: u64) -> Option<Natural> {
+    assert_ne!(e, 0);
+    assert!(*x < p.pow(e));
+    if *x == 0u32 {
+        return Some(Natural::ZERO);
+    }
+    // Write x = p^v y, where y is not divisible by p. Since x < p^e, v < e.
+    let mut v = 0;
+    let mut y = x.clone();
+    while (&y).divisible_by(p) {
+        y /= p;
+        v += 1;
+    }
+    if v.odd() {
+        return None;
+    }
+    Some(mod_sqrt_unit_prime_power(&y, p, e - v)? * p.pow(v >> 1))
+}
+
+fn mod_sqrt_from_factorization(x: &Natural, factors: &[(Natural, u64)]) -> Option<Natural> {
+    let mut m = Natural::ONE;
+    for (p, e) in factors {
+        m *= p.pow(*e);
+    }
+    assert!(*x < m);
+    // Combine the roots modulo each prime power using the Chinese remainder theorem.
+    let mut roots = Vec::with_capacity(factors.len());
+    for (p, e) in factors {
+        let q = p.pow(*e);
+        roots.push((mod_sqrt_prime_power(&(x % &q), p, *e)?, q));
+    }
+    Some(Natural::chinese_remainder(&roots).unwrap().0)
+}
+
+impl ModSqrt<Natural> for Natural {
+    type Output = Natural;
+
+    /// Finds a square root of a [`Natural`] modulo a prime [`Natural`] $p$. Assumes the first
+    /// [`Natural`] is already reduced modulo $p$. Both [`Natural`]s are taken by value.
+    ///
+    /// Returns `None` if the number is not a square modulo $p$. Otherwise, of the two square roots
+    /// $r$ and $p - r$, the smaller is returned. Whether $p$ is prime is not checked.
+    ///
+    /// Depending on the power of 2 dividing $p - 1$, either the Tonelli-Shanks algorithm or
+    /// Cipolla's algorithm is used.
+    ///
+    /// $f(x, p) = \min\\{r, p - r\\}$, where $r^2 \equiv x \mod p$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^3)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `p.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `self` is greater than or equal to `p`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::ModSqrt;
+    /// use malachite_nz::natural::Natural;
+    /// use std::str::FromStr;
+    ///
+    /// assert_eq!(Natural::from(2u32).mod_sqrt(Natural::from(7u32)).unwrap(), 3);
+    /// assert_eq!(Natural::from(3u32).mod_sqrt(Natural::from(7u32)), None);
+    /// assert_eq!(
+    ///     Natural::from(2u32)
+    ///         .mod_sqrt(Natural::from_str("1000000000000000000000000000057").unwrap())
+    ///         .unwrap(),
+    ///     Natural::from_str("395746790562060615678394010553").unwrap()
+    /// );
+    /// ```
+    #[inline]
+    fn mod_sqrt(self, p: Natural) -> Option<Natural> {
+        mod_sqrt_prime(&self, &p)
+    }
+
+    /// Finds a square root of a [`Natural`] modulo $p^e$, where $p$ is a prime [`Natural`]. Assumes
+    /// the first [`Natural`] is already reduced modulo $p^e$. Both [`Natural`]s are taken by value.
+    ///
+    /// Returns `None` if the number is not a square modulo $p^e$. Otherwise, the smallest square
+    /// root is returned. A square root modulo $p$ is found and then lifted to a square root modulo
+    /// $p^e$ using Hensel's lemma. Whether $p$ is prime is not checked.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^3)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is $e$ times `p.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `e` is 0 or if `self` is greater than or equal to $p^e$.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::ModSqrt;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!(Natural::from(2u32).mod_sqrt_prime_power(Natural::from(7u32), 3).unwrap(), 108);
+    /// assert_eq!(Natural::from(7u32).mod_sqrt_prime_power(Natural::from(7u32), 3), None);
+    /// assert_eq!(
+    ///     Natural::from(17u32).mod_sqrt_prime_power(Natural::from(2u32), 100).unwrap(),
+    ///     217788382556221841343574235415u128
+    /// );
+    /// ```
+    #[inline]
+    fn mod_sqrt_prime_power(self, p: Natural, e: u64) -> Option<Natural> {
+        mod_sqrt_prime_power(&self, &p, e)
+    }
+}
+
+impl<'a> ModSqrt<&'a Natural> for Natural {
+    type Output = Natural;
+
+    /// Finds a square root of a [`Natural`] modulo a prime [`Natural`] $p$. Assumes the first
+    /// [`Natural`] is already reduced modulo $p$. The first [`Natural`] is taken by value and the
+    /// second by reference.
+    ///
+    /// Returns `None` if the number is not a square modulo $p$. Otherwise, of the two square roots
+    /// $r$ and $p - r$, the smaller is returned. Whether $p$ is prime is not checked.
+    ///
+    /// Depending on the power of 2 dividing $p - 1$, either the Tonelli-Shanks algorithm or
+    /// Cipolla's algorithm is used.
+    ///
+    /// $f(x, p) = \min\\{r, p - r\\}$, where $r^2 \equiv x \mod p$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^3)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `p.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `self` is greater than or equal to `p`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::ModSqrt;
+    /// use malachite_nz::natural::Natural;
+    /// use std::str::FromStr;
+    ///
+    /// assert_eq!(Natural::from(2u32).mod_sqrt(&Natural::from(7u32)).unwrap(), 3);
+    /// assert_eq!(Natural::from(3u32).mod_sqrt(&Natural::from(7u32)), None);
+    /// assert_eq!(
+    ///     Natural::from(2u32)
+    ///         .mod_sqrt(&Natural::from_str("1000000000000000000000000000057").unwrap())
+    ///         .unwrap(),
+    ///     Natural::from_str("395746790562060615678394010553").unwrap()
+    /// );
+    /// ```
+    #[inline]
+    fn mod_sqrt(self, p: &'a Natural) -> Option<Natural> {
+        mod_sqrt_prime(&self, p)
+    }
+
+    /// Finds a square root of a [`Natural`] modulo $p^e$, where $p$ is a prime [`Natural`]. Assumes
+    /// the first [`Natural`] is already reduced modulo $p^e$. The first [`Natural`] is taken by
+    /// value and the second by reference.
+    ///
+    /// Returns `None` if the number is not a square modulo $p^e$. Otherwise, the smallest square
+    /// root is returned. A square root modulo $p$ is found and then lifted to a square root modulo
+    /// $p^e$ using Hensel's lemma. Whether $p$ is prime is not checked.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^3)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is $e$ times `p.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `e` is 0 or if `self` is greater than or equal to $p^e$.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::ModSqrt;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!(Natural::from(2u32).mod_sqrt_prime_power(&Natural::from(7u32), 3).unwrap(), 108);
+    /// assert_eq!(Natural::from(7u32).mod_sqrt_prime_power(&Natural::from(7u32), 3), None);
+    /// assert_eq!(
+    ///     Natural::from(17u32).mod_sqrt_prime_power(&Natural::from(2u32), 100).unwrap(),
+    ///     217788382556221841343574235415u128
+    /// );
+    /// ```
+    #[inline]
+    fn mod_sqrt_prime_power(self, p: &'a Natural, e: u64) -> Option<Natural> {
+        mod_sqrt_prime_power(&self, p, e)
+    }
+}
+
+impl<'a> ModSqrt<Natural> for &'a Natural {
+    type Output = Natural;
+
+    /// Finds a square root of a [`Natural`] modulo a prime [`Natural`] $p$. Assumes the first
+    /// [`Natural`] is already reduced modulo $p$. The first [`Natural`] is taken by reference and
+    /// the second by value.
+    ///
+    /// Returns `None` if the number is not a square modulo $p$. Otherwise, of the two square roots
+    /// $r$ and $p - r$, the smaller is returned. Whether $p$ is prime is not checked.
+    ///
+    /// Depending on the power of 2 dividing $p - 1$, either the Tonelli-Shanks algorithm or
+    /// Cipolla's algorithm is used.
+    ///
+    /// $f(x, p) = \min\\{r, p - r\\}$, where $r^2 \equiv x \mod p$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^3)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `p.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `self` is greater than or equal to `p`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::ModSqrt;
+    /// use malachite_nz::natural::Natural;
+    /// use std::str::FromStr;
+    ///
+    /// assert_eq!((&Natural::from(2u32)).mod_sqrt(Natural::from(7u32)).unwrap(), 3);
+    /// assert_eq!((&Natural::from(3u32)).mod_sqrt(Natural::from(7u32)), None);
+    /// assert_eq!(
+    ///     (&Natural::from(2u32))
+    ///         .mod_sqrt(Natural::from_str("1000000000000000000000000000057").unwrap())
+    ///         .unwrap(),
+    ///     Natural::from_str("395746790562060615678394010553").unwrap()
+    /// );
+    /// ```
+    #[inline]
+    fn mod_sqrt(self, p: Natural) -> Option<Natural> {
+        mod_sqrt_prime(self, &p)
+    }
+
+    /// Finds a square root of a [`Natural`] modulo $p^e$, where $p$ is a prime [`Natural`]. Assumes
+    /// the first [`Natural`] is already reduced modulo $p^e$. The first [`Natural`] is taken by
+    /// reference and the second by value.
+    ///
+    /// Returns `None` if the number is not a square modulo $p^e$. Otherwise, the smallest square
+    /// root is returned. A square root modulo $p$ is found and then lifted to a square root modulo
+    /// $p^e$ using Hensel's lemma. Whether $p$ is prime is not checked.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^3)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is $e$ times `p.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `e` is 0 or if `self` is greater than or equal to $p^e$.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::ModSqrt;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!(
+    ///     (&Natural::from(2u32))
+    ///         .mod_sqrt_prime_power(Natural::from(7u32), 3)
+    ///         .unwrap(),
+    ///     108
+    /// );
+    /// assert_eq!((&Natural::from(7u32)).mod_sqrt_prime_power(Natural::from(7u32), 3), None);
+    /// assert_eq!(
+    ///     (&Natural::from(17u32)).mod_sqrt_prime_power(Natural::from(2u32), 100).unwrap(),
+    ///     217788382556221841343574235415u128
+    /// );
+    /// ```
+    #[inline]
+    fn mod_sqrt_prime_power(self, p: Natural, e: u64) -> Option<Natural> {
+        mod_sqrt_prime_power(self, &p, e)
+    }
+}
+
+impl<'a, 'b> ModSqrt<&'b Natural> for &'a Natural {
+    type Output = Natural;
+
+    /// Finds a square root of a [`Natural`] modulo a prime [`Natural`] $p$. Assumes the first
+    /// [`Natural`] is already reduced modulo $p$. Both [`Natural`]s are taken by reference.
+    ///
+    /// Returns `None` if the number is not a square modulo $p$. Otherwise, of the two square roots
+    /// $r$ and $p - r$, the smaller is returned. Whether $p$ is prime is not checked.
+    ///
+    /// Depending on the power of 2 dividing $p - 1$, either the Tonelli-Shanks algorithm or
+    /// Cipolla's algorithm is used.
+    ///
+    /// $f(x, p) = \min\\{r, p - r\\}$, where $r^2 \equiv x \mod p$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^3)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `p.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `self` is greater than or equal to `p`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::ModSqrt;
+    /// use malachite_nz::natural::Natural;
+    /// use std::str::FromStr;
+    ///
+    /// assert_eq!((&Natural::from(2u32)).mod_sqrt(&Natural::from(7u32)).unwrap(), 3);
+    /// assert_eq!((&Natural::from(3u32)).mod_sqrt(&Natural::from(7u32)), None);
+    /// assert_eq!(
+    ///     (&Natural::from(2u32))
+    ///         .mod_sqrt(&Natural::from_str("1000000000000000000000000000057").unwrap())
+    ///         .unwrap(),
+    ///     Natural::from_str("395746790562060615678394010553").unwrap()
+    /// );
+    /// ```
+    #[inline]
+    fn mod_sqrt(self, p: &'b Natural) -> Option<Natural> {
+        mod_sqrt_prime(self, p)
+    }
+
+    /// Finds a square root of a [`Natural`] modulo $p^e$, where $p$ is a prime [`Natural`]. Assumes
+    /// the first [`Natural`] is already reduced modulo $p^e$. Both [`Natural`]s are taken by
+    /// reference.
+    ///
+    /// Returns `None` if the number is not a square modulo $p^e$. Otherwise, the smallest square
+    /// root is returned. A square root modulo $p$ is found and then lifted to a square root modulo
+    /// $p^e$ using Hensel's lemma. Whether $p$ is prime is not checked.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^3)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is $e$ times `p.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `e` is 0 or if `self` is greater than or equal to $p^e$.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::ModSqrt;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!(
+    ///     (&Natural::from(2u32))
+    ///         .mod_sqrt_prime_power(&Natural::from(7u32), 3)
+    ///         .unwrap(),
+    ///     108
+    /// );
+    /// assert_eq!((&Natural::from(7u32)).mod_sqrt_prime_power(&Natural::from(7u32), 3), None);
+    /// assert_eq!(
+    ///     (&Natural::from(17u32)).mod_sqrt_prime_power(&Natural::from(2u32), 100).unwrap(),
+    ///     217788382556221841343574235415u128
+    /// );
+    /// ```
+    #[inline]
+    fn mod_sqrt_prime_power(self, p: &'b Natural, e: u64) -> Option<Natural> {
+        mod_sqrt_prime_power(self, p, e)
+    }
+}
+
+impl ModSqrtFromFactorization for Natural {
+    type Output = Natural;
+
+    /// Finds a square root of a [`Natural`] modulo $m$, given the prime factorization of $m$.
+    /// Assumes the [`Natural`] is already reduced modulo $m$. The [`Natural`] is taken by
+    /// value.
+    ///
+    /// `factors` must be the prime factorization of $m$, in the format returned by
+    /// [`factor`](malachite_base::num::factorization::traits::Factor::factor); this is not
+    /// checked. The empty factorization represents 1.
+    ///
+    /// Returns `None` if the number is not a square modulo $m$. Square roots modulo each prime
+    /// power dividing $m$ are found using [`mod_sqrt_prime_power`](ModSqrt::mod_sqrt_prime_power)
+    /// and then combined using the Chinese remainder theorem.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^3)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the number of bits of $m$.
+    ///
+    /// # Panics
+    /// Panics if `self` is greater than or equal to $m$.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::{ModSqrtFromFactorization, ModSquare};
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// let factors = [
+    ///     (Natural::from(3u32), 1),
+    ///     (Natural::from(5u32), 1),
+    ///     (Natural::from(7u32), 1),
+    /// ];
+    /// let r = Natural::from(46u32).mod_sqrt_from_factorization(&factors).unwrap();
+    /// assert_eq!(r.mod_square(Natural::from(105u32)), 46);
+    /// assert_eq!(Natural::from(2u32).mod_sqrt_from_factorization(&factors), None);
+    /// ```
+    #[inline]
+    fn mod_sqrt_from_factorization(self, factors: &[(Natural, u64)]) -> Option<Natural> {
+        mod_sqrt_from_factorization(&self, factors)
+    }
+}
+
+impl<'a> ModSqrtFromFactorization for &'a Natural {
+    type Output = Natural;
+
+    /// Finds a square root of a [`Natural`] modulo $m$, given the prime factorization of $m$.
+    /// Assumes the [`Natural`] is already reduced modulo $m$. The [`Natural`] is taken by
+    /// reference.
+    ///
+    /// `factors` must be the prime factorization of $m$, in the format returned by
+    /// [`factor`](malachite_base::num::factorization::traits::Factor::factor); this is not
+    /// checked. The empty factorization represents 1.
+    ///
+    /// Returns `None` if the number is not a square modulo $m$. Square roots modulo each prime
+    /// power dividing $m$ are found using [`mod_sqrt_prime_power`](ModSqrt::mod_sqrt_prime_power)
+    /// and then combined using the Chinese remainder theorem.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^3)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the number of bits of $m$.
+    ///
+    /// # Panics
+    /// Panics if `self` is greater than or equal to $m$.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::{ModSqrtFromFactorization, ModSquare};
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// let factors = [
+    ///     (Natural::from(3u32), 1),
+    ///     (Natural::from(5u32), 1),
+    ///     (Natural::from(7u32), 1),
+    /// ];
+    /// let r = (&Natural::from(46u32)).mod_sqrt_from_factorization(&factors).unwrap();
+    /// assert_eq!(r.mod_square(Natural::from(105u32)), 46);
+    /// assert_eq!((&Natural::from(2u32)).mod_sqrt_from_factorization(&factors), None);
+    /// ```
+    #[inline]
+    fn mod_sqrt_from_factorization(self, factors: &[(Natural, u64)]) -> Option<Natural> {
+        mod_sqrt_from_factorization(self, factors)
+    }
+}
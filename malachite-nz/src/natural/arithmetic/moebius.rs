@@ -0,0 +1,75 @@
+use crate::natural::Natural;
+use malachite_base::num::arithmetic::traits::{Moebius, Parity};
+use malachite_base::num::factorization::traits::Factor;
+
+fn moebius_from_factorization(factors: &[(Natural, u64)]) -> i8 {
+    if factors.iter().any(|&(_, e)| e > 1) {
+        0
+    } else if factors.len().even() {
+        1
+    } else {
+        -1
+    }
+}
+
+impl Moebius for Natural {
+    /// Computes the Möbius function of a [`Natural`].
+    ///
+    /// $\mu(n)$ is 0 if $n$ is divisible by the square of a prime. Otherwise, it is 1 if $n$ has
+    /// an even number of prime factors and $-1$ if it has an odd number.
+    ///
+    /// # Worst-case complexity
+    /// The running time is dominated by the factorization of `self`; see
+    /// [`factor`](Factor::factor).
+    ///
+    /// # Panics
+    /// Panics if `self` is 0.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::Moebius;
+    /// use malachite_nz::natural::Natural;
+    /// use std::str::FromStr;
+    ///
+    /// assert_eq!(Natural::from(1u32).moebius(), 1);
+    /// assert_eq!(Natural::from(30u32).moebius(), -1);
+    /// assert_eq!(Natural::from(360u32).moebius(), 0);
+    /// assert_eq!(Natural::from_str("18446744073709551617").unwrap().moebius(), 1);
+    /// ```
+    #[inline]
+    fn moebius(&self) -> i8 {
+        moebius_from_factorization(&self.factor())
+    }
+
+    /// Computes the Möbius function of a [`Natural`], given its prime factorization.
+    ///
+    /// `factors` must be the prime factorization of the number, in the format returned by
+    /// [`factor`](Factor::factor); this is not checked. The empty factorization represents 1.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(1)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `factors.len()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::Moebius;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!(Natural::moebius_from_factorization(&[]), 1);
+    /// assert_eq!(
+    ///     Natural::moebius_from_factorization(&[
+    ///         (Natural::from(2u32), 1),
+    ///         (Natural::from(3u32), 1),
+    ///         (Natural::from(5u32), 1)
+    ///     ]),
+    ///     -1
+    /// );
+    /// ```
+    #[inline]
+    fn moebius_from_factorization(factors: &[(Natural, u64)]) -> i8 {
+        moebius_from_factorization(factors)
+    }
+}
@@ -0,0 +1,394 @@
+use crate::natural::arithmetic::div_exact::{
+    limbs_modular_invert, limbs_modular_invert_limb, limbs_modular_invert_scratch_len,
+};
+use crate::natural::arithmetic::mod_pow::{
+    limbs_redc_limb, limbs_redc_scratch_len, limbs_redc_with_scratch, REDC_1_TO_REDC_N_THRESHOLD,
+};
+use crate::natural::arithmetic::mul::{
+    limbs_mul_same_length_to_out, limbs_mul_same_length_to_out_scratch_len,
+};
+use crate::natural::arithmetic::square::{limbs_square_to_out, limbs_square_to_out_scratch_len};
+use crate::natural::arithmetic::sub::limbs_sub_same_length_in_place_left;
+use crate::natural::comparison::cmp::limbs_cmp_same_length;
+use crate::natural::InnerNatural::{Large, Small};
+use crate::natural::Natural;
+use crate::platform::Limb;
+use malachite_base::num::arithmetic::traits::{ModInverse, Parity, PowerOf2, WrappingNegAssign};
+use malachite_base::num::basic::integers::PrimitiveInt;
+use malachite_base::num::basic::traits::Zero;
+use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_base::num::logic::traits::BitIterable;
+use malachite_base::slices::slice_set_zero;
+use std::cmp::Ordering;
+
+/// A precomputed context for Montgomery multiplication modulo a fixed odd [`Natural`] $m$.
+///
+/// Let $n$ be the number of limbs of $m$ and let $R = 2^{Wn}$, where $W$ is the width of a
+/// [`Limb`]. The Montgomery form of a residue $x$ is $xR \mod m$. Products of residues in
+/// Montgomery form can be reduced using REDC, which replaces the division by $m$ with cheap
+/// operations modulo $R$; this requires the inverse of $m$ modulo $R$, which the context computes
+/// once. The context also owns the scratch space used by its operations, so long chains of
+/// multiplications, such as ladder steps or polynomial evaluation, don't allocate it each time.
+///
+/// Addition, subtraction, and negation commute with conversion to Montgomery form, so residues in
+/// Montgomery form can be combined directly using
+/// [`ModAdd`](malachite_base::num::arithmetic::traits::ModAdd),
+/// [`ModSub`](malachite_base::num::arithmetic::traits::ModSub), and
+/// [`ModNeg`](malachite_base::num::arithmetic::traits::ModNeg).
+#[derive(Clone, Debug)]
+pub struct MontgomeryModulus {
+    modulus: Natural,
+    ms: Vec<Limb>,
+    // If `ms` is shorter than `REDC_1_TO_REDC_N_THRESHOLD`, a single limb equal to $-m^{-1} \mod
+    // B$; otherwise $m^{-1} \mod R$.
+    is: Vec<Limb>,
+    // $R^2 \mod m$, used to convert residues to Montgomery form.
+    r_squared: Natural,
+    // $R \mod m$, the Montgomery form of 1.
+    one: Natural,
+    // Scratch space; `xs` and `ys` hold `ms.len()` limbs, and `product` holds twice as many.
+    xs: Vec<Limb>,
+    ys: Vec<Limb>,
+    product: Vec<Limb>,
+    mul_scratch: Vec<Limb>,
+    square_scratch: Vec<Limb>,
+    // Scratch space for REDC; empty if `ms` is shorter than `REDC_1_TO_REDC_N_THRESHOLD`.
+    redc_scratch: Vec<Limb>,
+}
+
+// Copies the limbs of `x` into `out`, padding with zeros. Assumes that `x` has at most
+// `out.len()` limbs.
+fn load_limbs(out: &mut [Limb], x: &Natural) {
+    match *x {
+        Natural(Small(small)) => {
+            out[0] = small;
+            slice_set_zero(&mut out[1..]);
+        }
+        Natural(Large(ref xs)) => {
+            let (out_lo, out_hi) = out.split_at_mut(xs.len());
+            out_lo.copy_from_slice(xs);
+            slice_set_zero(out_hi);
+        }
+    }
+}
+
+impl MontgomeryModulus {
+    /// Creates a [`MontgomeryModulus`] for an odd modulus $m$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `m.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `m` is even; in particular, if `m` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::natural::arithmetic::montgomery::MontgomeryModulus;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// let ctx = MontgomeryModulus::new(&Natural::from(101u32));
+    /// assert_eq!(ctx.modulus(), &101u32);
+    /// ```
+    pub fn new(m: &Natural) -> MontgomeryModulus {
+        assert!(m.odd(), "The modulus of a MontgomeryModulus must be odd");
+        let ms = m.to_limbs_asc();
+        let n = ms.len();
+        let (is, redc_scratch) = if n < REDC_1_TO_REDC_N_THRESHOLD {
+            let mut inverse = limbs_modular_invert_limb(ms[0]);
+            inverse.wrapping_neg_assign();
+            (vec![inverse], Vec::new())
+        } else {
+            let mut is = vec![0; n];
+            let mut scratch = vec![0; limbs_modular_invert_scratch_len(n)];
+            limbs_modular_invert(&mut is, &ms, &mut scratch);
+            (is, vec![0; limbs_redc_scratch_len(n)])
+        };
+        let r_bits = u64::exact_from(n) * Limb::WIDTH;
+        MontgomeryModulus {
+            modulus: m.clone(),
+            is,
+            r_squared: Natural::power_of_2(r_bits << 1) % m,
+            one: Natural::power_of_2(r_bits) % m,
+            xs: vec![0; n],
+            ys: vec![0; n],
+            product: vec![0; n << 1],
+            mul_scratch: vec![0; limbs_mul_same_length_to_out_scratch_len(n)],
+            square_scratch: vec![0; limbs_square_to_out_scratch_len(n)],
+            redc_scratch,
+            ms,
+        }
+    }
+
+    /// Returns the modulus of a [`MontgomeryModulus`].
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::natural::arithmetic::montgomery::MontgomeryModulus;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!(MontgomeryModulus::new(&Natural::from(101u32)).modulus(), &101u32);
+    /// ```
+    #[inline]
+    pub const fn modulus(&self) -> &Natural {
+        &self.modulus
+    }
+
+    /// Returns the Montgomery form of 1, which is $R \mod m$.
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::natural::arithmetic::montgomery::MontgomeryModulus;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// let mut ctx = MontgomeryModulus::new(&Natural::from(101u32));
+    /// let one = ctx.one().clone();
+    /// assert_eq!(ctx.from_montgomery(&one), 1);
+    /// ```
+    #[inline]
+    pub const fn one(&self) -> &Natural {
+        &self.one
+    }
+
+    // Reduces the value in `product`, which must be less than $mR$, returning a `Natural` less
+    // than $m$.
+    fn reduce_product(&mut self) -> Natural {
+        let n = self.ms.len();
+        let mut out = vec![0; n];
+        if n < REDC_1_TO_REDC_N_THRESHOLD {
+            limbs_redc_limb(&mut out, &mut self.product, &self.ms, self.is[0]);
+        } else {
+            limbs_redc_with_scratch(
+                &mut out,
+                &self.product,
+                &self.ms,
+                &self.is,
+                &mut self.redc_scratch,
+            );
+        }
+        if limbs_cmp_same_length(&out, &self.ms) != Ordering::Less {
+            limbs_sub_same_length_in_place_left(&mut out, &self.ms);
+        }
+        Natural::from_owned_limbs_asc(out)
+    }
+
+    // Multiplies the values in `xs` and `ys` and reduces the product.
+    fn mul_loaded(&mut self) -> Natural {
+        limbs_mul_same_length_to_out(&mut self.product, &self.xs, &self.ys, &mut self.mul_scratch);
+        self.reduce_product()
+    }
+
+    /// Converts a [`Natural`] to Montgomery form, returning $xR \mod m$. The [`Natural`] need not
+    /// be reduced modulo $m$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(x.significant_bits(), self.modulus().significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::natural::arithmetic::montgomery::MontgomeryModulus;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// let mut ctx = MontgomeryModulus::new(&Natural::from(101u32));
+    /// let x = ctx.to_montgomery(&Natural::from(1234u32));
+    /// assert!(x < 101u32);
+    /// assert_eq!(ctx.from_montgomery(&x), 22);
+    /// ```
+    pub fn to_montgomery(&mut self, x: &Natural) -> Natural {
+        if *x < self.modulus {
+            load_limbs(&mut self.xs, x);
+        } else {
+            load_limbs(&mut self.xs, &(x % &self.modulus));
+        }
+        load_limbs(&mut self.ys, &self.r_squared);
+        self.mul_loaded()
+    }
+
+    /// Converts a residue out of Montgomery form, returning $xR^{-1} \mod m$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `self.modulus().significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `x` is greater than or equal to the modulus.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::natural::arithmetic::montgomery::MontgomeryModulus;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// let mut ctx = MontgomeryModulus::new(&Natural::from(101u32));
+    /// let x = ctx.to_montgomery(&Natural::from(5u32));
+    /// assert_eq!(ctx.from_montgomery(&x), 5);
+    /// ```
+    pub fn from_montgomery(&mut self, x: &Natural) -> Natural {
+        assert!(*x < self.modulus, "x must be reduced modulo m");
+        let n = self.ms.len();
+        let (product_lo, product_hi) = self.product.split_at_mut(n);
+        load_limbs(product_lo, x);
+        slice_set_zero(product_hi);
+        self.reduce_product()
+    }
+
+    /// Multiplies two residues in Montgomery form, returning $xyR^{-1} \mod m$. If $x$ and $y$
+    /// are the Montgomery forms of $a$ and $b$, the result is the Montgomery form of $ab$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `self.modulus().significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `x` or `y` are greater than or equal to the modulus.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::natural::arithmetic::montgomery::MontgomeryModulus;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// let mut ctx = MontgomeryModulus::new(&Natural::from(101u32));
+    /// let x = ctx.to_montgomery(&Natural::from(12u32));
+    /// let y = ctx.to_montgomery(&Natural::from(20u32));
+    /// let product = ctx.mul(&x, &y);
+    /// assert_eq!(ctx.from_montgomery(&product), 38);
+    /// ```
+    pub fn mul(&mut self, x: &Natural, y: &Natural) -> Natural {
+        assert!(*x < self.modulus, "x must be reduced modulo m");
+        assert!(*y < self.modulus, "y must be reduced modulo m");
+        load_limbs(&mut self.xs, x);
+        load_limbs(&mut self.ys, y);
+        self.mul_loaded()
+    }
+
+    /// Squares a residue in Montgomery form, returning $x^2R^{-1} \mod m$. If $x$ is the
+    /// Montgomery form of $a$, the result is the Montgomery form of $a^2$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `self.modulus().significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `x` is greater than or equal to the modulus.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::natural::arithmetic::montgomery::MontgomeryModulus;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// let mut ctx = MontgomeryModulus::new(&Natural::from(101u32));
+    /// let x = ctx.to_montgomery(&Natural::from(12u32));
+    /// let square = ctx.square(&x);
+    /// assert_eq!(ctx.from_montgomery(&square), 43);
+    /// ```
+    pub fn square(&mut self, x: &Natural) -> Natural {
+        assert!(*x < self.modulus, "x must be reduced modulo m");
+        load_limbs(&mut self.xs, x);
+        limbs_square_to_out(&mut self.product, &self.xs, &mut self.square_scratch);
+        self.reduce_product()
+    }
+
+    /// Raises a residue in Montgomery form to a power. If $x$ is the Montgomery form of $a$, the
+    /// result is the Montgomery form of $a^e$. In particular, if $e = 0$ the result is
+    /// [`one`](Self::one).
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(mn \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `self.modulus().significant_bits()`,
+    /// and $m$ is `exp.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `x` is greater than or equal to the modulus.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::natural::arithmetic::montgomery::MontgomeryModulus;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// let mut ctx = MontgomeryModulus::new(&Natural::from(101u32));
+    /// let x = ctx.to_montgomery(&Natural::from(3u32));
+    /// let power = ctx.pow(&x, &Natural::from(100u32));
+    /// assert_eq!(ctx.from_montgomery(&power), 1);
+    /// let power = ctx.pow(&x, &Natural::from(5u32));
+    /// assert_eq!(ctx.from_montgomery(&power), 41);
+    /// ```
+    pub fn pow(&mut self, x: &Natural, exp: &Natural) -> Natural {
+        assert!(*x < self.modulus, "x must be reduced modulo m");
+        let mut power = self.one.clone();
+        for bit in exp.bits().rev() {
+            power = self.square(&power);
+            if bit {
+                power = self.mul(&power, x);
+            }
+        }
+        power
+    }
+
+    /// Inverts a residue in Montgomery form. If $x$ is the Montgomery form of $a$, the result is
+    /// the Montgomery form of $a^{-1}$. Returns `None` if $x$ is not invertible modulo $m$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `self.modulus().significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `x` is greater than or equal to the modulus.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::natural::arithmetic::montgomery::MontgomeryModulus;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// let mut ctx = MontgomeryModulus::new(&Natural::from(15u32));
+    /// let x = ctx.to_montgomery(&Natural::from(7u32));
+    /// let inverse = ctx.inverse(&x).unwrap();
+    /// assert_eq!(ctx.from_montgomery(&inverse), 13);
+    ///
+    /// let x = ctx.to_montgomery(&Natural::from(6u32));
+    /// assert_eq!(ctx.inverse(&x), None);
+    /// ```
+    pub fn inverse(&mut self, x: &Natural) -> Option<Natural> {
+        let a = self.from_montgomery(x);
+        if a == 0u32 {
+            return if self.modulus == 1u32 {
+                Some(Natural::ZERO)
+            } else {
+                None
+            };
+        }
+        let inverse = a.mod_inverse(&self.modulus)?;
+        Some(self.to_montgomery(&inverse))
+    }
+}
@@ -0,0 +1,63 @@
+use crate::natural::Natural;
+use malachite_base::num::arithmetic::traits::{BinomialCoefficient, Multinomial};
+use malachite_base::num::basic::traits::{One, Zero};
+
+impl Multinomial for Natural {
+    /// Computes a multinomial coefficient.
+    ///
+    /// The multinomial coefficient $\binom{n}{k_1, k_2, \ldots, k_m}$, where
+    /// $n = k_1 + k_2 + \cdots + k_m$, counts the ways of dividing a set of $n$ elements into $m$
+    /// labeled subsets of sizes $k_1, k_2, \ldots, k_m$.
+    ///
+    /// $$
+    /// f(k_1, k_2, \ldots, k_m) = \binom{n}{k_1, k_2, \ldots, k_m} =
+    /// \frac{n!}{k_1!k_2!\cdots k_m!}.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the sum of `ks`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::Multinomial;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!(Natural::multinomial(&[]), 1);
+    /// assert_eq!(Natural::multinomial(&[5]), 1);
+    /// assert_eq!(Natural::multinomial(&[2, 3]), 10);
+    /// assert_eq!(Natural::multinomial(&[1, 4, 4, 2]), 34650);
+    /// assert_eq!(
+    ///     Natural::multinomial(&[10, 20, 30]).to_string(),
+    ///     "3553261127084984957001360"
+    /// );
+    /// ```
+    fn multinomial(ks: &[u64]) -> Natural {
+        // Uses
+        //
+        // $$
+        // \binom{k_1 + k_2 + \cdots + k_m}{k_1, k_2, \ldots, k_m} =
+        // \prod_{i=1}^m \binom{k_1 + k_2 + \cdots + k_i}{k_i},
+        // $$
+        //
+        // with the largest $k_i$ first, since its factor is 1.
+        let mut ks = ks.to_vec();
+        ks.sort_unstable_by(|x, y| y.cmp(x));
+        let mut product = Natural::ONE;
+        let mut sum = Natural::ZERO;
+        for (i, k) in ks.into_iter().enumerate() {
+            if k == 0 {
+                break;
+            }
+            let k = Natural::from(k);
+            sum += &k;
+            if i != 0 {
+                product *= Natural::binomial_coefficient(&sum, &k);
+            }
+        }
+        product
+    }
+}
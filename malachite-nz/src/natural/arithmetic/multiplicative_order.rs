@@ -0,0 +1,318 @@
+use crate::natural::Natural;
+use malachite_base::num::arithmetic::traits::{
+    CoprimeWith, ModPow, MultiplicativeOrder, Parity, Pow, PrimitiveRoot,
+};
+use malachite_base::num::basic::traits::{One, Two, Zero};
+use malachite_base::num::factorization::traits::Factor;
+use std::collections::BTreeMap;
+
+// Returns the Carmichael function of a number, together with its prime factorization, given the
+// prime factorization of the number. The factorization of $\lambda(m)$ is assembled from the
+// factorizations of $p - 1$ for the odd primes $p$ dividing $m$, so $\lambda(m)$ itself is never
+// factored.
+pub(crate) fn carmichael_lambda_and_factors(
+    factors: &[(Natural, u64)],
+) -> (Natural, Vec<(Natural, u64)>) {
+    let mut lambda_factors = BTreeMap::new();
+    let mut insert = |q: Natural, e: u64| {
+        let exponent = lambda_factors.entry(q).or_insert(0);
+        *exponent = (*exponent).max(e);
+    };
+    for (p, k) in factors {
+        let k = *k;
+        if *p == 2u32 {
+            match k {
+                1 => {}
+                2 => insert(Natural::TWO, 1),
+                _ => insert(Natural::TWO, k - 2),
+            }
+        } else {
+            for (q, e) in (p - Natural::ONE).factor() {
+                insert(q, e);
+            }
+            if k > 1 {
+                insert(p.clone(), k - 1);
+            }
+        }
+    }
+    let lambda = lambda_factors
+        .iter()
+        .fold(Natural::ONE, |acc, (q, &e)| acc * q.pow(e));
+    (lambda, lambda_factors.into_iter().collect())
+}
+
+// Returns the order of $g$ modulo $m$, together with its factorization, given the factorization of
+// a multiple of the order. Assumes that $g$ is coprime to $m$.
+pub(crate) fn mod_order_from_multiple(
+    g: &Natural,
+    m: &Natural,
+    multiple: Natural,
+    factors: Vec<(Natural, u64)>,
+) -> (Natural, Vec<(Natural, u64)>) {
+    let mut order = multiple;
+    let mut order_factors = Vec::with_capacity(factors.len());
+    for (q, e) in factors {
+        let mut e_out = e;
+        while e_out != 0 && g.mod_pow(&order / &q, m) == 1u32 {
+            order /= &q;
+            e_out -= 1;
+        }
+        if e_out != 0 {
+            order_factors.push((q, e_out));
+        }
+    }
+    (order, order_factors)
+}
+
+fn multiplicative_order(x: &Natural, m: &Natural) -> Option<Natural> {
+    assert!(x < m, "x must be reduced mod m, but {} >= {}", x, m);
+    if !x.coprime_with(m) {
+        return None;
+    }
+    let (lambda, lambda_factors) = carmichael_lambda_and_factors(&m.factor());
+    Some(mod_order_from_multiple(x, m, lambda, lambda_factors).0)
+}
+
+impl MultiplicativeOrder<Natural> for Natural {
+    type Output = Natural;
+
+    /// Computes the multiplicative order of a [`Natural`] modulo another [`Natural`] $m$. Assumes
+    /// the input is already reduced modulo $m$. Both [`Natural`]s are taken by value.
+    ///
+    /// Returns the smallest $k > 0$ such that $x^k \equiv 1 \mod m$, or `None` if $x$ is not
+    /// coprime to $m$.
+    ///
+    /// The modulus is factored, and the factorization of $\lambda(m)$, a multiple of the order, is
+    /// assembled from it, where $\lambda$ is the Carmichael function. Each prime factor is then
+    /// divided out of $\lambda(m)$ for as long as the result is still a multiple of the order.
+    ///
+    /// # Worst-case complexity
+    /// The running time is dominated by the factorization of $m$ and of $p - 1$ for each prime $p$
+    /// dividing $m$.
+    ///
+    /// # Panics
+    /// Panics if `self` is greater than or equal to `m`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::MultiplicativeOrder;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!(
+    ///     Natural::from(3u32).multiplicative_order(Natural::from(7u32)),
+    ///     Some(Natural::from(6u32))
+    /// );
+    /// assert_eq!(
+    ///     Natural::from(6u32).multiplicative_order(Natural::from(9u32)),
+    ///     None
+    /// );
+    /// ```
+    #[inline]
+    fn multiplicative_order(self, m: Natural) -> Option<Natural> {
+        multiplicative_order(&self, &m)
+    }
+}
+
+impl<'a> MultiplicativeOrder<&'a Natural> for Natural {
+    type Output = Natural;
+
+    /// Computes the multiplicative order of a [`Natural`] modulo another [`Natural`] $m$. Assumes
+    /// the input is already reduced modulo $m$. The first [`Natural`] is taken by value and the
+    /// second by reference.
+    ///
+    /// Returns the smallest $k > 0$ such that $x^k \equiv 1 \mod m$, or `None` if $x$ is not
+    /// coprime to $m$.
+    ///
+    /// The modulus is factored, and the factorization of $\lambda(m)$, a multiple of the order, is
+    /// assembled from it, where $\lambda$ is the Carmichael function. Each prime factor is then
+    /// divided out of $\lambda(m)$ for as long as the result is still a multiple of the order.
+    ///
+    /// # Worst-case complexity
+    /// The running time is dominated by the factorization of $m$ and of $p - 1$ for each prime $p$
+    /// dividing $m$.
+    ///
+    /// # Panics
+    /// Panics if `self` is greater than or equal to `m`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::MultiplicativeOrder;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!(
+    ///     Natural::from(3u32).multiplicative_order(&Natural::from(7u32)),
+    ///     Some(Natural::from(6u32))
+    /// );
+    /// assert_eq!(
+    ///     Natural::from(6u32).multiplicative_order(&Natural::from(9u32)),
+    ///     None
+    /// );
+    /// ```
+    #[inline]
+    fn multiplicative_order(self, m: &'a Natural) -> Option<Natural> {
+        multiplicative_order(&self, m)
+    }
+}
+
+impl<'a> MultiplicativeOrder<Natural> for &'a Natural {
+    type Output = Natural;
+
+    /// Computes the multiplicative order of a [`Natural`] modulo another [`Natural`] $m$. Assumes
+    /// the input is already reduced modulo $m$. The first [`Natural`] is taken by reference and the
+    /// second by value.
+    ///
+    /// Returns the smallest $k > 0$ such that $x^k \equiv 1 \mod m$, or `None` if $x$ is not
+    /// coprime to $m$.
+    ///
+    /// The modulus is factored, and the factorization of $\lambda(m)$, a multiple of the order, is
+    /// assembled from it, where $\lambda$ is the Carmichael function. Each prime factor is then
+    /// divided out of $\lambda(m)$ for as long as the result is still a multiple of the order.
+    ///
+    /// # Worst-case complexity
+    /// The running time is dominated by the factorization of $m$ and of $p - 1$ for each prime $p$
+    /// dividing $m$.
+    ///
+    /// # Panics
+    /// Panics if `self` is greater than or equal to `m`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::MultiplicativeOrder;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!(
+    ///     (&Natural::from(3u32)).multiplicative_order(Natural::from(7u32)),
+    ///     Some(Natural::from(6u32))
+    /// );
+    /// assert_eq!(
+    ///     (&Natural::from(6u32)).multiplicative_order(Natural::from(9u32)),
+    ///     None
+    /// );
+    /// ```
+    #[inline]
+    fn multiplicative_order(self, m: Natural) -> Option<Natural> {
+        multiplicative_order(self, &m)
+    }
+}
+
+impl<'a, 'b> MultiplicativeOrder<&'b Natural> for &'a Natural {
+    type Output = Natural;
+
+    /// Computes the multiplicative order of a [`Natural`] modulo another [`Natural`] $m$. Assumes
+    /// the input is already reduced modulo $m$. Both [`Natural`]s are taken by reference.
+    ///
+    /// Returns the smallest $k > 0$ such that $x^k \equiv 1 \mod m$, or `None` if $x$ is not
+    /// coprime to $m$.
+    ///
+    /// The modulus is factored, and the factorization of $\lambda(m)$, a multiple of the order, is
+    /// assembled from it, where $\lambda$ is the Carmichael function. Each prime factor is then
+    /// divided out of $\lambda(m)$ for as long as the result is still a multiple of the order.
+    ///
+    /// # Worst-case complexity
+    /// The running time is dominated by the factorization of $m$ and of $p - 1$ for each prime $p$
+    /// dividing $m$.
+    ///
+    /// # Panics
+    /// Panics if `self` is greater than or equal to `m`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::MultiplicativeOrder;
+    /// use malachite_nz::natural::Natural;
+    /// use std::str::FromStr;
+    ///
+    /// assert_eq!(
+    ///     (&Natural::from(3u32)).multiplicative_order(&Natural::from(7u32)),
+    ///     Some(Natural::from(6u32))
+    /// );
+    /// assert_eq!(
+    ///     (&Natural::from(6u32)).multiplicative_order(&Natural::from(9u32)),
+    ///     None
+    /// );
+    /// assert_eq!(
+    ///     (&Natural::from(3u32))
+    ///         .multiplicative_order(
+    ///             &Natural::from_str("170141183460469231731687303715884105727").unwrap()
+    ///         )
+    ///         .unwrap()
+    ///         .to_string(),
+    ///     "56713727820156410577229101238628035242"
+    /// );
+    /// ```
+    #[inline]
+    fn multiplicative_order(self, m: &'b Natural) -> Option<Natural> {
+        multiplicative_order(self, m)
+    }
+}
+
+impl PrimitiveRoot for Natural {
+    /// Finds the smallest primitive root modulo a [`Natural`] $m$.
+    ///
+    /// A primitive root modulo $m$ is a number whose powers are exactly the residues coprime to
+    /// $m$. It exists iff $m$ is 1, 2, 4, $p^k$, or $2p^k$, where $p$ is an odd prime; otherwise,
+    /// `None` is returned. Modulo 1, the primitive root is 0.
+    ///
+    /// Candidates $g = 2, 3, \ldots$ are tried in order; $g$ is a primitive root iff it is coprime
+    /// to $m$ and $g^{\lambda(m)/q} \not\equiv 1 \mod m$ for every prime $q$ dividing
+    /// $\lambda(m)$, where $\lambda$ is the Carmichael function.
+    ///
+    /// # Worst-case complexity
+    /// The running time is dominated by the factorization of $m$ and of $p - 1$, where $p$ is the
+    /// odd prime dividing $m$. The smallest primitive root is small in practice.
+    ///
+    /// # Panics
+    /// Panics if `self` is 0.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::PrimitiveRoot;
+    /// use malachite_nz::natural::Natural;
+    /// use std::str::FromStr;
+    ///
+    /// assert_eq!(Natural::from(1u32).primitive_root(), Some(Natural::from(0u32)));
+    /// assert_eq!(Natural::from(7u32).primitive_root(), Some(Natural::from(3u32)));
+    /// assert_eq!(Natural::from(8u32).primitive_root(), None);
+    /// assert_eq!(Natural::from(18u32).primitive_root(), Some(Natural::from(5u32)));
+    /// assert_eq!(
+    ///     Natural::from_str("170141183460469231731687303715884105727")
+    ///         .unwrap()
+    ///         .primitive_root(),
+    ///     Some(Natural::from(43u32))
+    /// );
+    /// ```
+    fn primitive_root(&self) -> Option<Natural> {
+        let m = self;
+        assert_ne!(*m, 0u32);
+        if *m == 1u32 {
+            return Some(Natural::ZERO);
+        } else if *m == 2u32 {
+            return Some(Natural::ONE);
+        } else if *m == 4u32 {
+            return Some(Natural::from(3u32));
+        }
+        // There is a primitive root iff m is 1, 2, 4, p^k, or 2p^k, where p is an odd prime.
+        let factors = m.factor();
+        let odd_factors = if m.even() {
+            if factors[0] != (Natural::TWO, 1) {
+                return None;
+            }
+            &factors[1..]
+        } else {
+            &factors[..]
+        };
+        if odd_factors.len() != 1 {
+            return None;
+        }
+        // The group of units is cyclic of order lambda(m), so g generates it iff g^(lambda(m) / q)
+        // is not 1 for any prime q dividing lambda(m).
+        let (lambda, lambda_factors) = carmichael_lambda_and_factors(&factors);
+        let exponents: Vec<Natural> = lambda_factors.iter().map(|(q, _)| &lambda / q).collect();
+        let mut g = Natural::TWO;
+        loop {
+            if (&g).coprime_with(m) && exponents.iter().all(|e| (&g).mod_pow(e, m) != 1u32) {
+                return Some(g);
+            }
+            g += Natural::ONE;
+        }
+    }
+}
@@ -0,0 +1,283 @@
+use crate::integer::Integer;
+use crate::natural::Natural;
+use malachite_base::num::arithmetic::traits::{FloorSqrt, Parity, PartitionCount, Square};
+use malachite_base::num::basic::traits::{One, Zero};
+use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_base::num::logic::traits::SignificantBits;
+use std::f64::consts::{LOG2_E, PI};
+
+// Below this, the pentagonal number recurrence is faster than the Hardy-Ramanujan-Rademacher
+// formula.
+const HRR_THRESHOLD: u64 = 1000;
+
+// Computes $p(n)$ using Euler's pentagonal number recurrence, with the positive and negative terms
+// accumulated separately.
+//
+// $T(n) = O(n^2)$
+//
+// $M(n) = O(n^{3/2})$
+//
+// where $T$ is time, $M$ is additional memory, and $n$ is `n`.
+fn partition_count_recurrence(n: u64) -> Natural {
+    let mut ps = vec![Natural::ONE];
+    for i in 1..=n {
+        let mut positive = Natural::ZERO;
+        let mut negative = Natural::ZERO;
+        for k in 1.. {
+            let g = (k * (3 * k - 1)) >> 1;
+            if g > i {
+                break;
+            }
+            let sum = if k.odd() {
+                &mut positive
+            } else {
+                &mut negative
+            };
+            *sum += &ps[usize::exact_from(i - g)];
+            let g = g + k;
+            if g <= i {
+                *sum += &ps[usize::exact_from(i - g)];
+            }
+        }
+        ps.push(positive - negative);
+    }
+    ps.pop().unwrap()
+}
+
+// In the functions below, a fixed-point number with `prec` fractional bits is a `Natural` or
+// `Integer` $x$ representing $x/2^\mathrm{prec}$.
+
+// Returns $\arctan(1/q)$, for $q > 1$, as a fixed-point number with `prec` fractional bits. Each
+// term of the series is truncated, so the error is less than the number of terms, which is at most
+// `prec`, in units of the last place.
+fn atan_recip_fixed(q: u32, prec: u64) -> Natural {
+    let q_squared = Natural::from(q).square();
+    let mut power = (Natural::ONE << prec) / Natural::from(q);
+    let mut positive = power.clone();
+    let mut negative = Natural::ZERO;
+    for i in 1u64.. {
+        power /= &q_squared;
+        if power == 0u32 {
+            break;
+        }
+        let term = &power / Natural::from((i << 1) + 1);
+        if i.odd() {
+            negative += term;
+        } else {
+            positive += term;
+        }
+    }
+    positive - negative
+}
+
+// Returns $\pi$ as a fixed-point number with `prec` fractional bits and an error of less than one
+// unit in the last place, using Machin's formula $\pi = 16\arctan(1/5) - 4\arctan(1/239)$.
+fn pi_fixed(prec: u64) -> Natural {
+    let guard = prec.significant_bits() + 8;
+    let w = prec + guard;
+    ((atan_recip_fixed(5, w) << 4) - (atan_recip_fixed(239, w) << 2)) >> guard
+}
+
+// Returns $e^z$, where $z \geq 0$, as a fixed-point number with `prec` fractional bits. The input
+// is also a fixed-point number with `prec` fractional bits. The relative error is less than
+// $2^{-\mathrm{prec}}$.
+//
+// The argument is divided by $2^s$, the exponential of the quotient is computed with a Taylor
+// series, and the result is squared $s$ times. $s$ is chosen to balance the number of terms of the
+// series against the number of squarings.
+fn exp_fixed(z: &Natural, prec: u64) -> Natural {
+    let s = z.significant_bits().saturating_sub(prec) + prec.floor_sqrt() + 8;
+    let w = prec + s + prec.significant_bits() + 16;
+    let y = (z << (w - prec)) >> s;
+    let mut term = Natural::ONE << w;
+    let mut sum = term.clone();
+    for i in 1u64.. {
+        term = ((term * &y) >> w) / Natural::from(i);
+        if term == 0u32 {
+            break;
+        }
+        sum += &term;
+    }
+    for _ in 0..s {
+        sum = sum.square() >> w;
+    }
+    sum >> (w - prec)
+}
+
+// Returns $\cos \theta$, where $0 \leq \theta \leq \pi/2$, as a fixed-point number with `prec`
+// fractional bits. The input is also a fixed-point number with `prec` fractional bits. The error is
+// less than the number of terms of the Taylor series, in units of the last place.
+fn cos_fixed(theta: &Natural, prec: u64) -> Natural {
+    let theta_squared = theta.square() >> prec;
+    let mut term = Natural::ONE << prec;
+    let mut positive = term.clone();
+    let mut negative = Natural::ZERO;
+    for i in 1u64.. {
+        term = ((term * &theta_squared) >> prec) / Natural::from((i << 1) * ((i << 1) - 1));
+        if term == 0u32 {
+            break;
+        }
+        if i.odd() {
+            negative += &term;
+        } else {
+            positive += &term;
+        }
+    }
+    positive - negative
+}
+
+// Returns the number of terms of the Hardy-Ramanujan-Rademacher series needed to compute $p(n)$.
+// This is the smallest $N$ for which Rademacher's bound on the error of the truncated series,
+//
+// $$
+// R(n, N) = \frac{44\pi^2}{225\sqrt{3}}N^{-1/2}
+//     + \frac{\pi\sqrt{2}}{75}\left ( \frac{N}{n-1} \right )^{1/2}
+//     \sinh\left ( \frac{\pi}{N}\sqrt{\frac{2n}{3}} \right ),
+// $$
+//
+// is less than 1/4. Floating-point errors are insignificant here, since the bound is not tight.
+fn hrr_term_count(n: u64) -> u64 {
+    let n = n as f64;
+    let c = PI * (2.0 * n / 3.0).sqrt();
+    let a = 44.0 * PI * PI / (225.0 * 3.0f64.sqrt());
+    let b = PI * 2.0f64.sqrt() / 75.0;
+    let mut terms = 1u64;
+    loop {
+        let t = terms as f64;
+        if a / t.sqrt() + b * (t / (n - 1.0)).sqrt() * (c / t).sinh() < 0.25 {
+            return terms;
+        }
+        terms += 1;
+    }
+}
+
+// Computes $p(n)$ using the Hardy-Ramanujan-Rademacher formula, which in Selberg's form is
+//
+// $$
+// p(n) = \frac{4}{24n - 1}\sum_{k=1}^\infty S_k(n)
+//     \left ( \cosh z_k - \frac{\sinh z_k}{z_k} \right ),
+// $$
+//
+// where $z_k = \pi\sqrt{24n - 1}/(6k)$ and
+//
+// $$
+// S_k(n) = \sum_{\substack{0 \leq l < 2k \\ (3l^2 + l)/2 \equiv -n \pmod k}}
+//     (-1)^l\cos\frac{(6l + 1)\pi}{6k}.
+// $$
+//
+// The series is truncated after `hrr_term_count(n)` terms, so that the truncation error is less
+// than 1/4. Each term is computed with fixed-point arithmetic, using just enough fractional bits
+// that its error, after it is scaled down to `frac_bits` fractional bits, is less than $2^{-8}/N$,
+// where $N$ is the number of terms. The sum is therefore within 1/2 of $p(n)$, and rounding it
+// gives $p(n)$ exactly. Since the terms decrease quickly, most of the work goes into the first
+// few.
+fn partition_count_hrr(n: u64) -> Natural {
+    let term_count = hrr_term_count(n);
+    let frac_bits = term_count.significant_bits() + 10;
+    let m = Natural::from(n) * Natural::from(24u32) - Natural::ONE;
+    let m_bits = m.significant_bits();
+    let m_f64 = 24.0 * (n as f64) - 1.0;
+    // the working precision for term k, which exceeds `frac_bits` by an upper bound for the
+    // number of bits in the integer part of the term, plus guard bits
+    let prec = |k: u64| {
+        let z = PI * m_f64.sqrt() / (6.0 * k as f64);
+        frac_bits + (z * LOG2_E).ceil() as u64 + k.significant_bits() + m_bits + 40
+    };
+    let max_prec = prec(1);
+    let pi = pi_fixed(max_prec);
+    let mut sum = Integer::ZERO;
+    for k in 1..=term_count {
+        let n_mod_k = n % k;
+        let w = prec(k);
+        let pi_k = &pi >> (max_prec - w);
+        // S_k(n), with `w` fractional bits
+        let mut s_k = Integer::ZERO;
+        let mut found = false;
+        for l in 0..k << 1 {
+            if (((3 * l * l + l) >> 1) + n_mod_k) % k != 0 {
+                continue;
+            }
+            found = true;
+            // Reduce (6l + 1)/(6k) to a fraction in [0, 1/2], using the symmetries of cosine.
+            let den = 6 * k;
+            let mut num = (6 * l + 1) % (den << 1);
+            if num > den {
+                num = (den << 1) - num;
+            }
+            let mut negative = l.odd();
+            if num << 1 > den {
+                num = den - num;
+                negative = !negative;
+            }
+            let theta = &pi_k * Natural::from(num) / Natural::from(den);
+            let c = Integer::from(cos_fixed(&theta, w));
+            if negative {
+                s_k -= c;
+            } else {
+                s_k += c;
+            }
+        }
+        if !found {
+            continue;
+        }
+        let one = Natural::ONE << w;
+        let z = ((&pi_k * (&m << (w << 1)).floor_sqrt()) >> w) / Natural::from(6 * k);
+        let e = exp_fixed(&z, w);
+        let e_recip = (&one << w) / &e;
+        let z_recip = Integer::from((&one << w) / &z);
+        let one = Integer::from(one);
+        // cosh z - sinh z / z = (e^z(1 - 1/z) + e^(-z)(1 + 1/z)) / 2
+        let h = (Integer::from(e) * (&one - &z_recip) + Integer::from(e_recip) * (one + z_recip))
+            >> (w + 1);
+        sum += (((s_k * h) << 2) / Integer::from(&m)) >> (w + w - frac_bits);
+    }
+    Natural::exact_from((sum + Integer::from(Natural::ONE << (frac_bits - 1))) >> frac_bits)
+}
+
+impl PartitionCount for Natural {
+    /// Computes the number of partitions of a number.
+    ///
+    /// A partition of $n$ is a way of writing $n$ as a sum of positive integers, where the order
+    /// of the summands does not matter.
+    ///
+    /// $$
+    /// f(n) = p(n) = \sum_{k \geq 1} (-1)^{k+1} \left ( p\left ( n - \frac{k(3k - 1)}{2} \right ) +
+    /// p\left ( n - \frac{k(3k + 1)}{2} \right ) \right ),
+    /// $$
+    /// where $p(0) = 1$ and $p(n) = 0$ for negative $n$.
+    ///
+    /// $p(n) = O(e^{\pi\sqrt{2n/3}}/n)$.
+    ///
+    /// Small values are computed with the recurrence above. Larger values are computed with the
+    /// Hardy-Ramanujan-Rademacher formula, using enough precision that the result can be rounded to
+    /// the exact value.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/4} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n^{1/2})$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `n`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::PartitionCount;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!(Natural::partition_count(0), 1);
+    /// assert_eq!(Natural::partition_count(1), 1);
+    /// assert_eq!(Natural::partition_count(5), 7);
+    /// assert_eq!(Natural::partition_count(100), 190569292);
+    /// assert_eq!(
+    ///     Natural::partition_count(1000).to_string(),
+    ///     "24061467864032622473692149727991"
+    /// );
+    /// ```
+    fn partition_count(n: u64) -> Natural {
+        if n < HRR_THRESHOLD {
+            partition_count_recurrence(n)
+        } else {
+            partition_count_hrr(n)
+        }
+    }
+}
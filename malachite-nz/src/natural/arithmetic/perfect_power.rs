@@ -0,0 +1,161 @@
+use crate::natural::Natural;
+use malachite_base::num::arithmetic::traits::{
+    CheckedRoot, IsPerfectPower, ModPow, PerfectPowerDecomposition,
+};
+use malachite_base::num::basic::integers::PrimitiveInt;
+use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_base::num::factorization::traits::Primes;
+use malachite_base::num::logic::traits::SignificantBits;
+
+// For each small prime $p$, some primes $q \equiv 1 \mod p$. If $n$ is a $p$th power and $q$ does
+// not divide $n$, then $n^{(q-1)/p} \equiv 1 \mod q$. Each $q$ lets through only about $1/p$ of the
+// numbers that are not $p$th powers.
+const PERFECT_POWER_FILTERS: [(u64, &[u64]); 4] = [
+    (2, &[3, 5, 7, 11, 13, 17, 19, 23]),
+    (3, &[7, 13, 19, 31, 37]),
+    (5, &[11, 31, 41, 61]),
+    (7, &[29, 43, 71, 113]),
+];
+
+// Returns whether `n` might be a `p`th power, where `p` is prime and `n` has `twos` trailing zeros.
+// If this returns `false`, `n` is certainly not a `p`th power.
+fn may_be_perfect_power(n: &Natural, twos: u64, p: u64) -> bool {
+    if twos % p != 0 {
+        return false;
+    }
+    PERFECT_POWER_FILTERS
+        .iter()
+        .find(|&&(k, _)| k == p)
+        .map_or(true, |(_, qs)| {
+            qs.iter().all(|&q| {
+                let r = u64::exact_from(&(n % Natural::from(q)));
+                r == 0 || r.mod_pow((q - 1) / p, q) == 1
+            })
+        })
+}
+
+// Returns a prime $p$ and the $p$th root of `n`, if `n` is a perfect $p$th power for some prime
+// $p$. Assumes `n` is greater than 1.
+//
+// # Worst-case complexity
+// $T(n) = O(n^2 \log n)$
+//
+// $M(n) = O(n)$
+//
+// where $T$ is time, $M$ is additional memory, and $n$ is `n.significant_bits()`.
+fn prime_root(n: &Natural) -> Option<(Natural, u64)> {
+    let twos = n.trailing_zeros().unwrap();
+    u64::primes_less_than(&n.significant_bits())
+        .filter(|&p| may_be_perfect_power(n, twos, p))
+        .find_map(|p| n.checked_root(p).map(|root| (root, p)))
+}
+
+// Returns $(b, k)$ such that $b^k$ = `n` and $k$ is as large as possible.
+//
+// # Worst-case complexity
+// $T(n) = O(n^2 \log n)$
+//
+// $M(n) = O(n)$
+//
+// where $T$ is time, $M$ is additional memory, and $n$ is `n.significant_bits()`.
+pub(crate) fn perfect_power_decomposition(n: &Natural) -> (Natural, u64) {
+    if n.significant_bits() <= u128::WIDTH {
+        let (base, exponent) = u128::exact_from(n).perfect_power_decomposition();
+        return (Natural::from(base), exponent);
+    }
+    let mut base = n.clone();
+    let mut exponent = 1;
+    while let Some((root, p)) = prime_root(&base) {
+        base = root;
+        exponent *= p;
+        if base.significant_bits() <= u128::WIDTH {
+            let (small_base, small_exponent) =
+                u128::exact_from(&base).perfect_power_decomposition();
+            return (Natural::from(small_base), exponent * small_exponent);
+        }
+    }
+    (base, exponent)
+}
+
+impl IsPerfectPower for Natural {
+    /// Determines whether a [`Natural`] is a perfect power.
+    ///
+    /// A number is a perfect power if it equals $b^k$ for some integer $b$ and some $k > 1$. In
+    /// particular, 0 and 1 are perfect powers.
+    ///
+    /// Only prime exponents $k$ less than the bit length of the number need to be checked. Most are
+    /// ruled out by the number's 2-adic valuation and by its residues modulo a few small primes;
+    /// for the rest, [`checked_root`](CheckedRoot::checked_root) is called.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::{IsPerfectPower, Pow};
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!(Natural::from(0u32).is_perfect_power(), true);
+    /// assert_eq!(Natural::from(1u32).is_perfect_power(), true);
+    /// assert_eq!(Natural::from(10u32).is_perfect_power(), false);
+    /// assert_eq!(Natural::from(1000u32).is_perfect_power(), true);
+    /// assert_eq!(Natural::from(3u32).pow(100).is_perfect_power(), true);
+    /// assert_eq!((Natural::from(3u32).pow(100) + Natural::from(1u32)).is_perfect_power(), false);
+    /// ```
+    fn is_perfect_power(&self) -> bool {
+        if self.significant_bits() <= u128::WIDTH {
+            u128::exact_from(self).is_perfect_power()
+        } else {
+            prime_root(self).is_some()
+        }
+    }
+}
+
+impl PerfectPowerDecomposition for Natural {
+    /// Writes a [`Natural`] as a perfect power with the largest possible exponent.
+    ///
+    /// Returns $(b, k)$ such that $b^k$ is the number and $k$ is as large as possible. If the
+    /// number is not a perfect power, $k$ is 1. Since 0 and 1 are $k$th powers for every $k$, their
+    /// decomposition is $(0, 1)$ or $(1, 1)$.
+    ///
+    /// Prime roots are taken for as long as the base is a perfect power; see
+    /// [`is_perfect_power`](IsPerfectPower::is_perfect_power).
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::{PerfectPowerDecomposition, Pow};
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!(
+    ///     Natural::from(0u32).perfect_power_decomposition(),
+    ///     (Natural::from(0u32), 1)
+    /// );
+    /// assert_eq!(
+    ///     Natural::from(10u32).perfect_power_decomposition(),
+    ///     (Natural::from(10u32), 1)
+    /// );
+    /// assert_eq!(
+    ///     Natural::from(1000000u32).perfect_power_decomposition(),
+    ///     (Natural::from(10u32), 6)
+    /// );
+    /// assert_eq!(
+    ///     Natural::from(12u32).pow(150).perfect_power_decomposition(),
+    ///     (Natural::from(12u32), 150)
+    /// );
+    /// ```
+    #[inline]
+    fn perfect_power_decomposition(&self) -> (Natural, u64) {
+        perfect_power_decomposition(self)
+    }
+}
@@ -0,0 +1,369 @@
+use crate::natural::arithmetic::div_mod::{
+    div_mod_by_preinversion, limbs_div_mod_barrett_preinverse_scratch_len,
+    limbs_div_mod_barrett_preinverted, limbs_div_mod_by_two_limb_normalized,
+    limbs_div_mod_divide_and_conquer, limbs_div_mod_schoolbook, limbs_invert_approx,
+    limbs_invert_approx_scratch_len, limbs_invert_limb, limbs_two_limb_inverse_helper,
+};
+use crate::natural::arithmetic::shl::{limbs_shl_to_out, limbs_slice_shl_in_place};
+use crate::natural::arithmetic::shr::limbs_slice_shr_in_place;
+use crate::natural::InnerNatural::{Large, Small};
+use crate::natural::Natural;
+use crate::platform::{Limb, DC_DIV_QR_THRESHOLD};
+use malachite_base::num::arithmetic::traits::DivMod;
+use malachite_base::num::basic::integers::PrimitiveInt;
+use malachite_base::num::basic::traits::Zero;
+use malachite_base::num::logic::traits::LeadingZeros;
+use malachite_base::slices::slice_move_left;
+
+// With the inverse of the divisor already known, Barrett division beats divide-and-conquer division
+// at a much smaller size than `MU_DIV_QR_THRESHOLD`.
+//
+//TODO tune
+const PRECOMPUTED_BARRETT_DIV_QR_THRESHOLD: usize = 250;
+
+/// A [`Natural`] divisor together with precomputed data that speeds up repeated division by it.
+///
+/// Dividing by a [`Natural`] normalizes the divisor, shifting it left so that its most significant
+/// bit is set, and then computes an inverse of it. When many numbers are divided by the same
+/// divisor, as in base conversion or residue number system code, a [`PrecomputedDivisor`] does
+/// this work once. For large divisors, it stores an approximate inverse with as many limbs as the
+/// divisor, so each division only costs a few multiplications.
+#[derive(Clone, Debug)]
+pub struct PrecomputedDivisor {
+    divisor: Natural,
+    // The number of bits by which `ds` was shifted left to normalize it.
+    shift: u64,
+    // The limbs of the divisor, shifted left so that the most significant bit is set.
+    ds: Vec<Limb>,
+    // If `ds` has 1 limb, its inverse, as computed by `limbs_invert_limb`. If `ds` has 2 limbs,
+    // empty. If `ds` is shorter than `PRECOMPUTED_BARRETT_DIV_QR_THRESHOLD`, the inverse of its
+    // two highest limbs, as computed by `limbs_two_limb_inverse_helper`. Otherwise, an approximate
+    // inverse of `ds` with the same length as `ds`, which never exceeds the true inverse.
+    is: Vec<Limb>,
+}
+
+impl PrecomputedDivisor {
+    /// Creates a [`PrecomputedDivisor`] for a nonzero divisor $d$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `d.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `d` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::natural::arithmetic::precomputed_divisor::PrecomputedDivisor;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// let d = PrecomputedDivisor::new(&Natural::from(10u32));
+    /// assert_eq!(d.divisor(), &10u32);
+    /// ```
+    pub fn new(d: &Natural) -> PrecomputedDivisor {
+        assert_ne!(*d, 0u32, "division by zero");
+        let mut ds = d.to_limbs_asc();
+        let d_len = ds.len();
+        let shift = LeadingZeros::leading_zeros(ds[d_len - 1]);
+        if shift != 0 {
+            limbs_slice_shl_in_place(&mut ds, shift);
+        }
+        let is = if d_len == 1 {
+            vec![limbs_invert_limb(ds[0])]
+        } else if d_len == 2 {
+            Vec::new()
+        } else if d_len < PRECOMPUTED_BARRETT_DIV_QR_THRESHOLD {
+            vec![limbs_two_limb_inverse_helper(ds[d_len - 1], ds[d_len - 2])]
+        } else {
+            // As in `limbs_div_mod_barrett_helper`, invert `ds` with a low limb of 1 appended, so
+            // that the result does not exceed the true inverse, and then drop its lowest limb.
+            let d_len_plus_1 = d_len + 1;
+            let mut is = vec![0; d_len_plus_1];
+            let mut scratch = vec![0; d_len_plus_1 + limbs_invert_approx_scratch_len(d_len_plus_1)];
+            let (scratch_lo, scratch_hi) = scratch.split_at_mut(d_len_plus_1);
+            let (scratch_first, scratch_lo_tail) = scratch_lo.split_first_mut().unwrap();
+            scratch_lo_tail.copy_from_slice(&ds);
+            *scratch_first = 1;
+            limbs_invert_approx(&mut is, scratch_lo, scratch_hi);
+            slice_move_left(&mut is, 1);
+            is.truncate(d_len);
+            is
+        };
+        PrecomputedDivisor {
+            divisor: d.clone(),
+            shift,
+            ds,
+            is,
+        }
+    }
+
+    /// Returns the divisor.
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::natural::arithmetic::precomputed_divisor::PrecomputedDivisor;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// let d = PrecomputedDivisor::new(&Natural::from(10u32));
+    /// assert_eq!(d.divisor(), &10u32);
+    /// ```
+    pub const fn divisor(&self) -> &Natural {
+        &self.divisor
+    }
+
+    // Divides the limbs of a `Natural` by a divisor of one limb, returning the limbs of the
+    // quotient and the remainder.
+    fn limbs_div_limb_mod(&self, ns: &[Limb]) -> (Vec<Limb>, Limb) {
+        let d = self.ds[0];
+        let d_inv = self.is[0];
+        let n_len = ns.len();
+        let mut qs = vec![0; n_len];
+        let mut r = 0;
+        if self.shift == 0 {
+            for (q, &n) in qs.iter_mut().zip(ns.iter()).rev() {
+                (*q, r) = div_mod_by_preinversion(r, n, d, d_inv);
+            }
+        } else {
+            // Shift the numerator limbs on the fly. The bits shifted out of the highest limb are
+            // less than `d`, so they form the initial remainder.
+            let cobits = Limb::WIDTH - self.shift;
+            r = ns[n_len - 1] >> cobits;
+            for i in (1..n_len).rev() {
+                let n = (ns[i] << self.shift) | (ns[i - 1] >> cobits);
+                (qs[i], r) = div_mod_by_preinversion(r, n, d, d_inv);
+            }
+            (qs[0], r) = div_mod_by_preinversion(r, ns[0] << self.shift, d, d_inv);
+            r >>= self.shift;
+        }
+        (qs, r)
+    }
+
+    // Divides the limbs of a `Natural` at least as long as `ds` by the divisor, returning the
+    // limbs of the quotient and remainder. `ds` must have at least two limbs.
+    fn limbs_div_mod(&self, ns: &[Limb]) -> (Vec<Limb>, Vec<Limb>) {
+        let ds = &self.ds;
+        let d_len = ds.len();
+        let n_len = ns.len();
+        assert!(n_len >= d_len);
+        // Shift the numerator by the same amount as the divisor, into one extra limb.
+        let mut ns_shifted = vec![0; n_len + 1];
+        if self.shift == 0 {
+            ns_shifted[..n_len].copy_from_slice(ns);
+        } else {
+            let (ns_shifted_last, ns_shifted_init) = ns_shifted.split_last_mut().unwrap();
+            *ns_shifted_last = limbs_shl_to_out(ns_shifted_init, ns, self.shift);
+        }
+        let q_len = n_len + 1 - d_len;
+        let mut qs = vec![0; q_len + 1];
+        let mut rs = if d_len == 2 {
+            qs[q_len] = Limb::from(limbs_div_mod_by_two_limb_normalized(
+                &mut qs,
+                &mut ns_shifted,
+                ds,
+            ));
+            ns_shifted.truncate(2);
+            ns_shifted
+        } else if d_len < PRECOMPUTED_BARRETT_DIV_QR_THRESHOLD {
+            let d_inv = self.is[0];
+            qs[q_len] = Limb::from(if d_len < DC_DIV_QR_THRESHOLD || q_len < 3 {
+                limbs_div_mod_schoolbook(&mut qs[..q_len], &mut ns_shifted, ds, d_inv)
+            } else {
+                limbs_div_mod_divide_and_conquer(&mut qs[..q_len], &mut ns_shifted, ds, d_inv)
+            });
+            ns_shifted.truncate(d_len);
+            ns_shifted
+        } else {
+            let mut rs = vec![0; d_len];
+            let mut scratch = vec![0; limbs_div_mod_barrett_preinverse_scratch_len(d_len, d_len)];
+            qs[q_len] = Limb::from(limbs_div_mod_barrett_preinverted(
+                &mut qs,
+                &mut rs,
+                &ns_shifted,
+                ds,
+                &self.is,
+                &mut scratch,
+            ));
+            rs
+        };
+        if self.shift != 0 {
+            limbs_slice_shr_in_place(&mut rs, self.shift);
+        }
+        (qs, rs)
+    }
+
+    /// Divides a [`Natural`] by the divisor, returning the quotient and remainder. The quotient is
+    /// rounded towards negative infinity.
+    ///
+    /// The quotient and remainder satisfy $x = qd + r$ and $0 \leq r < d$.
+    ///
+    /// $$
+    /// f(x, d) = \left ( \left \lfloor \frac{x}{d} \right \rfloor, \space
+    /// x - d\left \lfloor \frac{x}{d} \right \rfloor \right ).
+    /// $$
+    ///
+    /// This gives the same result as [`DivMod`], but doesn't recompute the inverse of the divisor.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `x.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::strings::ToDebugString;
+    /// use malachite_nz::natural::arithmetic::precomputed_divisor::PrecomputedDivisor;
+    /// use malachite_nz::natural::Natural;
+    /// use std::str::FromStr;
+    ///
+    /// let d = PrecomputedDivisor::new(&Natural::from(10u32));
+    /// // 2 * 10 + 3 = 23
+    /// assert_eq!(d.div_mod(&Natural::from(23u32)).to_debug_string(), "(2, 3)");
+    ///
+    /// let d = PrecomputedDivisor::new(&Natural::from_str("1234567987654").unwrap());
+    /// // 809999943300 * 1234567987654 + 264899981800 = 1000000000000000000000000
+    /// assert_eq!(
+    ///     d.div_mod(&Natural::from_str("1000000000000000000000000").unwrap())
+    ///         .to_debug_string(),
+    ///     "(809999943300, 264899981800)"
+    /// );
+    /// ```
+    pub fn div_mod(&self, x: &Natural) -> (Natural, Natural) {
+        match (x, &self.divisor) {
+            (&Natural(Small(small_x)), &Natural(Small(small_d))) => {
+                let (q, r) = small_x.div_mod(small_d);
+                (Natural::from(q), Natural::from(r))
+            }
+            (&Natural(Small(_)), _) => (Natural::ZERO, x.clone()),
+            (&Natural(Large(ref xs)), &Natural(Small(_))) => {
+                let (qs, r) = self.limbs_div_limb_mod(xs);
+                (Natural::from_owned_limbs_asc(qs), Natural::from(r))
+            }
+            (&Natural(Large(ref xs)), _) => {
+                if xs.len() < self.ds.len() {
+                    (Natural::ZERO, x.clone())
+                } else {
+                    let (qs, rs) = self.limbs_div_mod(xs);
+                    (
+                        Natural::from_owned_limbs_asc(qs),
+                        Natural::from_owned_limbs_asc(rs),
+                    )
+                }
+            }
+        }
+    }
+
+    /// Divides a [`Natural`] by the divisor, returning the quotient. The quotient is rounded
+    /// towards negative infinity.
+    ///
+    /// $$
+    /// f(x, d) = \left \lfloor \frac{x}{d} \right \rfloor.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `x.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::natural::arithmetic::precomputed_divisor::PrecomputedDivisor;
+    /// use malachite_nz::natural::Natural;
+    /// use std::str::FromStr;
+    ///
+    /// let d = PrecomputedDivisor::new(&Natural::from(10u32));
+    /// // 2 * 10 + 3 = 23
+    /// assert_eq!(d.div(&Natural::from(23u32)), 2);
+    ///
+    /// let d = PrecomputedDivisor::new(&Natural::from_str("1234567987654").unwrap());
+    /// // 809999943300 * 1234567987654 + 264899981800 = 1000000000000000000000000
+    /// assert_eq!(
+    ///     d.div(&Natural::from_str("1000000000000000000000000").unwrap()),
+    ///     809999943300u64
+    /// );
+    /// ```
+    #[inline]
+    pub fn div(&self, x: &Natural) -> Natural {
+        self.div_mod(x).0
+    }
+
+    /// Divides a [`Natural`] by the divisor, returning the remainder.
+    ///
+    /// $$
+    /// f(x, d) = x - d\left \lfloor \frac{x}{d} \right \rfloor.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `x.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::natural::arithmetic::precomputed_divisor::PrecomputedDivisor;
+    /// use malachite_nz::natural::Natural;
+    /// use std::str::FromStr;
+    ///
+    /// let d = PrecomputedDivisor::new(&Natural::from(10u32));
+    /// // 2 * 10 + 3 = 23
+    /// assert_eq!(d.mod_op(&Natural::from(23u32)), 3);
+    ///
+    /// let d = PrecomputedDivisor::new(&Natural::from_str("1234567987654").unwrap());
+    /// // 809999943300 * 1234567987654 + 264899981800 = 1000000000000000000000000
+    /// assert_eq!(
+    ///     d.mod_op(&Natural::from_str("1000000000000000000000000").unwrap()),
+    ///     264899981800u64
+    /// );
+    /// ```
+    #[inline]
+    pub fn mod_op(&self, x: &Natural) -> Natural {
+        self.div_mod(x).1
+    }
+
+    /// Returns whether a [`Natural`] is divisible by the divisor.
+    ///
+    /// $f(x, d) = (\exists k \in \N : x = kd)$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `x.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::natural::arithmetic::precomputed_divisor::PrecomputedDivisor;
+    /// use malachite_nz::natural::Natural;
+    /// use std::str::FromStr;
+    ///
+    /// let d = PrecomputedDivisor::new(&Natural::from(10u32));
+    /// assert_eq!(d.divisible_by(&Natural::from(23u32)), false);
+    /// assert_eq!(d.divisible_by(&Natural::from(230u32)), true);
+    ///
+    /// let d = PrecomputedDivisor::new(&Natural::from_str("1234567987654").unwrap());
+    /// assert_eq!(
+    ///     d.divisible_by(&Natural::from_str("1000000000000000000000000").unwrap()),
+    ///     false
+    /// );
+    /// assert_eq!(
+    ///     d.divisible_by(&Natural::from_str("1234567987654000000000000").unwrap()),
+    ///     true
+    /// );
+    /// ```
+    #[inline]
+    pub fn divisible_by(&self, x: &Natural) -> bool {
+        self.mod_op(x) == 0u32
+    }
+}
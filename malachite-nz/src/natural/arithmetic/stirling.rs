@@ -0,0 +1,217 @@
+use crate::natural::Natural;
+use malachite_base::num::arithmetic::traits::{
+    Bell, DivExact, Factorial, Parity, Pow, StirlingFirst, StirlingSecond,
+};
+use malachite_base::num::basic::traits::{One, Zero};
+use malachite_base::num::conversion::traits::{ExactFrom, WrappingFrom};
+use std::mem::swap;
+
+// Both kinds of Stirling numbers satisfy a recurrence of the form
+//
+// $$
+// X(i, j) = m(i, j)X(i - 1, j) + X(i - 1, j - 1),
+// $$
+//
+// with $m(i, j) = i - 1$ for the first kind and $m(i, j) = j$ for the second. Writing
+// $i = j + d$, only the cells with $0 \leq j \leq k$ and $0 \leq d \leq n - k$ contribute to
+// $X(n, k)$, so we sweep over that rectangle, storing only a single row or column, whichever is
+// shorter.
+//
+// Assumes $1 \leq k < n$.
+fn stirling(n: u64, k: u64, multiplier: &dyn Fn(u64, u64) -> u64) -> Natural {
+    let n_minus_k = n - k;
+    if n_minus_k <= k {
+        // a[d] holds X(j + d, j)
+        let mut a = vec![Natural::ZERO; usize::exact_from(n_minus_k + 1)];
+        a[0] = Natural::ONE;
+        for j in 1..=k {
+            for d in 1..=n_minus_k {
+                let d_usize = usize::wrapping_from(d);
+                let product = &a[d_usize - 1] * Natural::from(multiplier(j, d));
+                a[d_usize] += product;
+            }
+        }
+        a.pop().unwrap()
+    } else {
+        // b[j] holds X(j + d, j)
+        let mut b = vec![Natural::ONE; usize::exact_from(k + 1)];
+        for d in 1..=n_minus_k {
+            b[0] = Natural::ZERO;
+            for j in 1..=k {
+                let j_usize = usize::wrapping_from(j);
+                b[j_usize] *= Natural::from(multiplier(j, d));
+                let (previous, current) = b.split_at_mut(j_usize);
+                current[0] += &previous[j_usize - 1];
+            }
+        }
+        b.pop().unwrap()
+    }
+}
+
+// Uses
+//
+// $$
+// \left\\{{n \atop k}\right\\} = \frac{1}{k!}\sum_{j=0}^k (-1)^{k-j}\binom{k}{j}j^n,
+// $$
+//
+// accumulating the positive and negative terms separately. This is faster than the recurrence when
+// $k$ is small compared to $n$.
+//
+// Assumes $1 \leq k \leq n$.
+fn stirling_second_explicit(n: u64, k: u64) -> Natural {
+    let mut positive = Natural::ZERO;
+    let mut negative = Natural::ZERO;
+    let mut binomial = Natural::ONE;
+    // The sign of the $j$th term is $(-1)^{k-j}$.
+    let mut sign = k.odd();
+    for j in 1..=k {
+        binomial *= Natural::from(k - j + 1);
+        binomial = binomial.div_exact(Natural::from(j));
+        let term = &binomial * Natural::from(j).pow(n);
+        if sign {
+            positive += term;
+        } else {
+            negative += term;
+        }
+        sign = !sign;
+    }
+    (positive - negative).div_exact(Natural::factorial(k))
+}
+
+impl StirlingFirst for Natural {
+    /// Computes an unsigned Stirling number of the first kind.
+    ///
+    /// The unsigned Stirling number of the first kind $\left[{n \atop k}\right]$ counts the
+    /// permutations of $n$ elements with exactly $k$ cycles.
+    ///
+    /// $f(n, k) = \left[{n \atop k}\right]$, where
+    /// $\left[{n + 1 \atop k}\right] = n\left[{n \atop k}\right] + \left[{n \atop k - 1}\right]$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, k) = O(nk(n - k)\log n)$
+    ///
+    /// $M(n, k) = O(n \min(k, n - k) \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `n`, and $k$ is `k`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::StirlingFirst;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!(Natural::stirling_first(0, 0), 1);
+    /// assert_eq!(Natural::stirling_first(4, 0), 0);
+    /// assert_eq!(Natural::stirling_first(4, 2), 11);
+    /// assert_eq!(Natural::stirling_first(10, 3), 1172700);
+    /// assert_eq!(
+    ///     Natural::stirling_first(30, 10).to_string(),
+    ///     "215760462268683520394805979744"
+    /// );
+    /// ```
+    fn stirling_first(n: u64, k: u64) -> Natural {
+        if k > n {
+            Natural::ZERO
+        } else if k == n {
+            Natural::ONE
+        } else if k == 0 {
+            Natural::ZERO
+        } else {
+            stirling(n, k, &|j, d| j + d - 1)
+        }
+    }
+}
+
+impl StirlingSecond for Natural {
+    /// Computes a Stirling number of the second kind.
+    ///
+    /// The Stirling number of the second kind $\left\\{{n \atop k}\right\\}$ counts the partitions
+    /// of a set of $n$ elements into exactly $k$ nonempty subsets.
+    ///
+    /// $f(n, k) = \left\\{{n \atop k}\right\\}$, where
+    /// $\left\\{{n + 1 \atop k}\right\\} = k\left\\{{n \atop k}\right\\} +
+    /// \left\\{{n \atop k - 1}\right\\}$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, k) = O(nk \min(k, n - k) \log n)$
+    ///
+    /// $M(n, k) = O(n \min(k, n - k) \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `n`, and $k$ is `k`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::StirlingSecond;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!(Natural::stirling_second(0, 0), 1);
+    /// assert_eq!(Natural::stirling_second(4, 0), 0);
+    /// assert_eq!(Natural::stirling_second(4, 2), 7);
+    /// assert_eq!(Natural::stirling_second(10, 3), 9330);
+    /// assert_eq!(
+    ///     Natural::stirling_second(50, 10).to_string(),
+    ///     "26154716515862881292012777396577993781727011"
+    /// );
+    /// assert_eq!(
+    ///     Natural::stirling_second(50, 40).to_string(),
+    ///     "149377949042637543000150"
+    /// );
+    /// ```
+    fn stirling_second(n: u64, k: u64) -> Natural {
+        if k > n {
+            Natural::ZERO
+        } else if k == n || k == 1 {
+            Natural::ONE
+        } else if k == 0 {
+            Natural::ZERO
+        } else if k < n - k {
+            stirling_second_explicit(n, k)
+        } else {
+            stirling(n, k, &|j, _| j)
+        }
+    }
+}
+
+impl Bell for Natural {
+    /// Computes the $n$th Bell number.
+    ///
+    /// The Bell number $B_n$ counts the partitions of a set of $n$ elements.
+    ///
+    /// $$
+    /// f(n) = B_n = \sum_{k=0}^n \left\\{{n \atop k}\right\\}.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^3 \log n)$
+    ///
+    /// $M(n) = O(n^2 \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `n`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::Bell;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!(Natural::bell(0), 1);
+    /// assert_eq!(Natural::bell(1), 1);
+    /// assert_eq!(Natural::bell(5), 52);
+    /// assert_eq!(
+    ///     Natural::bell(50).to_string(),
+    ///     "185724268771078270438257767181908917499221852770"
+    /// );
+    /// ```
+    fn bell(n: u64) -> Natural {
+        // Uses the Bell triangle; see the comment on `checked_bell` in malachite-base. Each row is
+        // overwritten by the next one in place.
+        let mut row = vec![Natural::ONE];
+        for _ in 1..n {
+            let mut x = row.last().unwrap().clone();
+            for y in &mut row {
+                swap(&mut x, y);
+                x += &*y;
+            }
+            row.push(x);
+        }
+        row.pop().unwrap()
+    }
+}
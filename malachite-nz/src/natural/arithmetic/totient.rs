@@ -0,0 +1,167 @@
+use crate::natural::Natural;
+use malachite_base::num::arithmetic::traits::{
+    CarmichaelLambda, LcmAssign, Pow, PowerOf2, Totient,
+};
+use malachite_base::num::basic::traits::One;
+use malachite_base::num::factorization::traits::Factor;
+
+fn totient_from_factorization(factors: &[(Natural, u64)]) -> Natural {
+    let mut totient = Natural::ONE;
+    for (p, e) in factors {
+        totient *= p.pow(e - 1) * (p - Natural::ONE);
+    }
+    totient
+}
+
+// Returns the Carmichael function of $p^e$, where $p$ is prime and $e > 0$.
+fn carmichael_lambda_of_prime_power(p: &Natural, e: u64) -> Natural {
+    if *p == 2u32 && e > 2 {
+        Natural::power_of_2(e - 2)
+    } else {
+        p.pow(e - 1) * (p - Natural::ONE)
+    }
+}
+
+fn carmichael_lambda_from_factorization(factors: &[(Natural, u64)]) -> Natural {
+    let mut lambda = Natural::ONE;
+    for (p, e) in factors {
+        lambda.lcm_assign(carmichael_lambda_of_prime_power(p, *e));
+    }
+    lambda
+}
+
+impl Totient for Natural {
+    /// Computes Euler's totient function of a [`Natural`].
+    ///
+    /// $\varphi(n)$ is the number of integers in $[1, n]$ that are coprime to $n$. If $n =
+    /// \prod_i p_i^{e_i}$, then
+    ///
+    /// $$
+    /// \varphi(n) = \prod_i p_i^{e_i - 1}(p_i - 1).
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// The running time is dominated by the factorization of `self`; see
+    /// [`factor`](Factor::factor).
+    ///
+    /// # Panics
+    /// Panics if `self` is 0.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::Totient;
+    /// use malachite_nz::natural::Natural;
+    /// use std::str::FromStr;
+    ///
+    /// assert_eq!(Natural::from(1u32).totient(), 1);
+    /// assert_eq!(Natural::from(360u32).totient(), 96);
+    /// assert_eq!(
+    ///     Natural::from_str("1000000000000000000000000000000").unwrap().totient(),
+    ///     Natural::from_str("400000000000000000000000000000").unwrap()
+    /// );
+    /// ```
+    #[inline]
+    fn totient(&self) -> Natural {
+        totient_from_factorization(&self.factor())
+    }
+
+    /// Computes Euler's totient function of a [`Natural`], given its prime factorization.
+    ///
+    /// `factors` must be the prime factorization of the number, in the format returned by
+    /// [`factor`](Factor::factor); this is not checked. The empty factorization represents 1.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the number of bits in the
+    /// factored number.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::Totient;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!(Natural::totient_from_factorization(&[]), 1);
+    /// assert_eq!(
+    ///     Natural::totient_from_factorization(&[
+    ///         (Natural::from(2u32), 3),
+    ///         (Natural::from(3u32), 2),
+    ///         (Natural::from(5u32), 1)
+    ///     ]),
+    ///     96
+    /// );
+    /// ```
+    #[inline]
+    fn totient_from_factorization(factors: &[(Natural, u64)]) -> Natural {
+        totient_from_factorization(factors)
+    }
+}
+
+impl CarmichaelLambda for Natural {
+    /// Computes the Carmichael function of a [`Natural`].
+    ///
+    /// $\lambda(n)$ is the exponent of the multiplicative group of integers modulo $n$: the
+    /// smallest positive $m$ such that $a^m \equiv 1 \mod n$ for every $a$ coprime to $n$. It is
+    /// the least common multiple of $\lambda(p^e)$ over the prime powers $p^e$ dividing $n$, where
+    /// $\lambda(2^e) = 2^{e-2}$ for $e > 2$, and $\lambda(p^e) = \varphi(p^e)$ otherwise.
+    ///
+    /// # Worst-case complexity
+    /// The running time is dominated by the factorization of `self`; see
+    /// [`factor`](Factor::factor).
+    ///
+    /// # Panics
+    /// Panics if `self` is 0.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::CarmichaelLambda;
+    /// use malachite_nz::natural::Natural;
+    /// use std::str::FromStr;
+    ///
+    /// assert_eq!(Natural::from(1u32).carmichael_lambda(), 1);
+    /// assert_eq!(Natural::from(360u32).carmichael_lambda(), 12);
+    /// assert_eq!(
+    ///     Natural::from_str("1267650600228229401496703205375").unwrap().carmichael_lambda(),
+    ///     14499000
+    /// );
+    /// ```
+    #[inline]
+    fn carmichael_lambda(&self) -> Natural {
+        carmichael_lambda_from_factorization(&self.factor())
+    }
+
+    /// Computes the Carmichael function of a [`Natural`], given its prime factorization.
+    ///
+    /// `factors` must be the prime factorization of the number, in the format returned by
+    /// [`factor`](Factor::factor); this is not checked. The empty factorization represents 1.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the number of bits in the
+    /// factored number.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::CarmichaelLambda;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!(Natural::carmichael_lambda_from_factorization(&[]), 1);
+    /// assert_eq!(
+    ///     Natural::carmichael_lambda_from_factorization(&[
+    ///         (Natural::from(2u32), 3),
+    ///         (Natural::from(3u32), 2),
+    ///         (Natural::from(5u32), 1)
+    ///     ]),
+    ///     12
+    /// );
+    /// ```
+    #[inline]
+    fn carmichael_lambda_from_factorization(factors: &[(Natural, u64)]) -> Natural {
+        carmichael_lambda_from_factorization(factors)
+    }
+}
@@ -0,0 +1,415 @@
+use crate::natural::arithmetic::mod_mul::ModMulData;
+use crate::natural::Natural;
+use crate::platform::Limb;
+use malachite_base::num::arithmetic::traits::{
+    CheckedRoot, DivExactAssign, DivisibleBy, Gcd, ModAdd, ModInverse, ModMulPrecomputed,
+    ModMulPrecomputedAssign, ModSub,
+};
+use malachite_base::num::basic::integers::PrimitiveInt;
+use malachite_base::num::basic::traits::{One, Two, Zero};
+use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_base::num::factorization::traits::{Factor, IsPrime, Primes};
+use malachite_base::num::logic::traits::{BitIterable, SignificantBits};
+
+// Every number is first checked for divisibility by the primes less than this bound.
+const TRIAL_DIVISION_LIMIT: Limb = 1 << 16;
+
+// The base-2 logarithm of `TRIAL_DIVISION_LIMIT`. After trial division, every remaining prime
+// factor has at least this many bits.
+const TRIAL_DIVISION_BITS: u64 = 16;
+
+// The number of iterations of Pollard-Brent rho that are performed for each polynomial before the
+// polynomial is abandoned.
+const RHO_ITERATION_LIMIT: u64 = 1 << 16;
+
+// The number of polynomials that Pollard-Brent rho tries before ECM is used.
+const RHO_ATTEMPTS: u64 = 2;
+
+// The number of iterations of Pollard-Brent rho whose differences are multiplied together before
+// a gcd is taken.
+const RHO_BATCH_SIZE: u64 = 128;
+
+// Pairs of (stage 1 bound, number of curves) for ECM. The bounds are those recommended by GMP-ECM
+// for finding factors of 15, 20, 25, 30, 35, and 40 digits. If all the curves fail, the last bound
+// is repeatedly increased.
+const ECM_SCHEDULE: [(u64, u64); 6] =
+    [(2000, 25), (11000, 90), (50000, 300), (250000, 700), (1000000, 1800), (3000000, 5100)];
+
+// The ratio between the stage 2 bound and the stage 1 bound used by ECM.
+const ECM_STAGE_2_RATIO: u64 = 50;
+
+// The giant-step size used by stage 2 of ECM. This is the product of the first four primes.
+const ECM_STAGE_2_STEP: u64 = 210;
+
+// Generates the (stage 1 bound, number of curves) pairs to try, in order: first those in
+// `ECM_SCHEDULE`, and then the last entry of `ECM_SCHEDULE` with the bound doubled, quadrupled,
+// and so on.
+fn ecm_schedule() -> impl Iterator<Item = (u64, u64)> {
+    let (last_b1, last_curves) = ECM_SCHEDULE[ECM_SCHEDULE.len() - 1];
+    ECM_SCHEDULE
+        .iter()
+        .copied()
+        .chain((1..).map(move |i| (last_b1 << i, last_curves)))
+}
+
+// Finds all odd $j < D/2$ coprime to $D$, where $D$ is `ECM_STAGE_2_STEP`.
+fn ecm_stage_2_baby_steps() -> impl Iterator<Item = u64> {
+    (1..ECM_STAGE_2_STEP >> 1)
+        .step_by(2)
+        .filter(|j| j % 3 != 0 && j % 5 != 0 && j % 7 != 0)
+}
+
+// Divides out the primes less than `TRIAL_DIVISION_LIMIT` from `n`, pushing them onto `factors`.
+// Stops early once the cofactor fits in a `u128`. Returns the cofactor.
+//
+// # Worst-case complexity
+// $T(n) = O(n)$
+//
+// $M(n) = O(n)$
+//
+// where $T$ is time, $M$ is additional memory, and $n$ is `n.significant_bits()`.
+fn trial_division(mut n: Natural, factors: &mut Vec<(Natural, u64)>) -> Natural {
+    for p in Limb::primes_less_than(&TRIAL_DIVISION_LIMIT) {
+        if n.significant_bits() <= u128::WIDTH {
+            break;
+        }
+        let p = Natural::from(p);
+        if (&n).divisible_by(&p) {
+            let mut exponent = 0;
+            while (&n).divisible_by(&p) {
+                n.div_exact_assign(&p);
+                exponent += 1;
+            }
+            factors.push((p, exponent));
+        }
+    }
+    n
+}
+
+// Returns `n` as a perfect power $r^k$ with $k > 1$ prime, or `None` if `n` is not a perfect power.
+// `n` must have no prime factors less than `TRIAL_DIVISION_LIMIT`.
+//
+// # Worst-case complexity
+// $T(n) = O(n^2 \log n)$
+//
+// $M(n) = O(n)$
+//
+// where $T$ is time, $M$ is additional memory, and $n$ is `n.significant_bits()`.
+fn perfect_power(n: &Natural) -> Option<(Natural, u64)> {
+    u64::primes_less_than_or_equal_to(&(n.significant_bits() / TRIAL_DIVISION_BITS))
+        .find_map(|k| n.checked_root(k).map(|r| (r, k)))
+}
+
+// Attempts to find a nontrivial factor of the odd composite number `n` using Brent's variant of
+// Pollard's rho algorithm, iterating the polynomial $x^2 + c$ starting from 2. Gives up after
+// `RHO_ITERATION_LIMIT` iterations.
+//
+// # Worst-case complexity
+// $T(n) = O(n \log n \log\log n)$
+//
+// $M(n) = O(n \log n)$
+//
+// where $T$ is time, $M$ is additional memory, and $n$ is `n.significant_bits()`.
+pub_test! {pollard_brent_rho(n: &Natural, c: &Natural) -> Option<Natural> {
+    let data = <&Natural as ModMulPrecomputed<&Natural, &Natural>>::precompute_mod_mul_data(&n);
+    let f = |x: &Natural| x.mod_mul_precomputed(x, n, &data).mod_add(c, n);
+    let diff = |x: &Natural, y: &Natural| if x >= y { x - y } else { y - x };
+    let mut y = Natural::TWO;
+    let mut x;
+    let mut saved_y = y.clone();
+    let mut q = Natural::ONE;
+    let mut g = Natural::ONE;
+    let mut r = 1;
+    let mut iterations = 0;
+    loop {
+        x = y.clone();
+        for _ in 0..r {
+            y = f(&y);
+        }
+        let mut k = 0;
+        while k < r && g == 1u32 {
+            saved_y = y.clone();
+            for _ in 0..RHO_BATCH_SIZE.min(r - k) {
+                y = f(&y);
+                q.mod_mul_precomputed_assign(diff(&x, &y), n, &data);
+            }
+            g = (&q).gcd(n);
+            k += RHO_BATCH_SIZE;
+        }
+        if g != 1u32 {
+            break;
+        }
+        iterations += r;
+        if iterations >= RHO_ITERATION_LIMIT {
+            return None;
+        }
+        r <<= 1;
+    }
+    if g == *n {
+        // The batch overshot; step through it one iteration at a time.
+        loop {
+            saved_y = f(&saved_y);
+            g = diff(&x, &saved_y).gcd(n);
+            if g != 1u32 {
+                break;
+            }
+        }
+    }
+    if g == *n {
+        None
+    } else {
+        Some(g)
+    }
+}}
+
+type Point = (Natural, Natural);
+
+// A Montgomery curve $By^2 = x^3 + Ax^2 + x$ over $\Z/n\Z$, represented by $(A + 2) / 4$. Points
+// are represented in projective $(X : Z)$ coordinates, with the $y$-coordinate dropped.
+struct MontgomeryCurve<'a> {
+    n: &'a Natural,
+    a24: Natural,
+    data: ModMulData,
+}
+
+impl<'a> MontgomeryCurve<'a> {
+    // Constructs the curve with Suyama parameter `sigma`, returning the curve and a starting point
+    // on it. If the construction fails because some number is not invertible modulo `n`, a factor
+    // of `n` may be returned instead.
+    fn suyama(n: &'a Natural, sigma: u64) -> Result<(MontgomeryCurve<'a>, Point), Option<Natural>> {
+        let mut curve = MontgomeryCurve {
+            n,
+            a24: Natural::ZERO,
+            data: <&Natural as ModMulPrecomputed<&Natural, &Natural>>::precompute_mod_mul_data(&n),
+        };
+        let sigma = Natural::from(sigma);
+        let u = curve.mul(&sigma, &sigma).mod_sub(Natural::from(5u32), n);
+        let v = (sigma << 2u32) % n;
+        let x = curve.mul(&curve.mul(&u, &u), &u);
+        let z = curve.mul(&curve.mul(&v, &v), &v);
+        let v_minus_u = (&v).mod_sub(&u, n);
+        let numerator = curve.mul(
+            &curve.mul(&curve.mul(&v_minus_u, &v_minus_u), &v_minus_u),
+            &(Natural::from(3u32) * u % n).mod_add(&v, n),
+        );
+        let denominator = (curve.mul(&x, &v) << 4u32) % n;
+        if denominator == 0u32 {
+            return Err(None);
+        }
+        match (&denominator).mod_inverse(n) {
+            Some(inverse) => {
+                curve.a24 = curve.mul(&numerator, &inverse);
+                Ok((curve, (x, z)))
+            }
+            None => Err(Some(denominator.gcd(n))),
+        }
+    }
+
+    fn mul(&self, x: &Natural, y: &Natural) -> Natural {
+        x.mod_mul_precomputed(y, self.n, &self.data)
+    }
+
+    // Computes $2P$.
+    fn double(&self, (x, z): &Point) -> Point {
+        let n = self.n;
+        let sum = x.mod_add(z, n);
+        let difference = x.mod_sub(z, n);
+        let sum_squared = self.mul(&sum, &sum);
+        let difference_squared = self.mul(&difference, &difference);
+        let t = (&sum_squared).mod_sub(&difference_squared, n);
+        let new_z = self.mul(&t, &self.mul(&self.a24, &t).mod_add(&difference_squared, n));
+        (self.mul(&sum_squared, &difference_squared), new_z)
+    }
+
+    // Computes $P + Q$, given $P - Q$.
+    fn add(&self, (xp, zp): &Point, (xq, zq): &Point, (xd, zd): &Point) -> Point {
+        let n = self.n;
+        let u = self.mul(&xp.mod_sub(zp, n), &xq.mod_add(zq, n));
+        let v = self.mul(&xp.mod_add(zp, n), &xq.mod_sub(zq, n));
+        let sum = (&u).mod_add(&v, n);
+        let difference = u.mod_sub(v, n);
+        (
+            self.mul(zd, &self.mul(&sum, &sum)),
+            self.mul(xd, &self.mul(&difference, &difference)),
+        )
+    }
+
+    // Computes $kP$, where $k > 0$, using the Montgomery ladder.
+    fn multiply(&self, k: u64, p: &Point) -> Point {
+        let mut r0 = p.clone();
+        let mut r1 = self.double(p);
+        for bit in k.bits().rev().skip(1) {
+            if bit {
+                r0 = self.add(&r0, &r1, p);
+                r1 = self.double(&r1);
+            } else {
+                r1 = self.add(&r0, &r1, p);
+                r0 = self.double(&r0);
+            }
+        }
+        r0
+    }
+}
+
+// Attempts to find a nontrivial factor of the odd composite number `n` using Lenstra's
+// elliptic-curve method with a single curve, given by the Suyama parameter `sigma`. Stage 1 uses
+// the bound `b1`, and stage 2 uses the bound `ECM_STAGE_2_RATIO * b1`.
+//
+// # Worst-case complexity
+// $T(n, m) = O(mn \log n \log\log n)$
+//
+// $M(n) = O(n \log n)$
+//
+// where $T$ is time, $M$ is additional memory, $n$ is `n.significant_bits()`, and $m$ is `b1`.
+pub_test! {ecm_one_curve(n: &Natural, sigma: u64, b1: u64) -> Option<Natural> {
+    let (curve, mut p) = match MontgomeryCurve::suyama(n, sigma) {
+        Ok(result) => result,
+        Err(factor) => return factor.filter(|g| g != n),
+    };
+    // Stage 1: multiply by every prime power no greater than b1.
+    for q in u64::primes_less_than_or_equal_to(&b1) {
+        let mut power = q;
+        while power <= b1 / q {
+            power *= q;
+        }
+        p = curve.multiply(power, &p);
+    }
+    let g = (&p.1).gcd(n);
+    if g != 1u32 {
+        return if g == *n { None } else { Some(g) };
+    }
+    // Stage 2: look for a prime q with b1 < q <= b2 such that qP is the identity modulo a factor of
+    // n. Every such q is of the form mD ± j, where D is the step size and j is coprime to D.
+    let b2 = b1 * ECM_STAGE_2_RATIO;
+    let two_p = curve.double(&p);
+    let mut baby_steps = Vec::new();
+    let mut previous = p.clone();
+    let mut current = p.clone();
+    let mut j = 1;
+    for target in ecm_stage_2_baby_steps() {
+        while j < target {
+            let next = if j == 1 {
+                curve.add(&two_p, &p, &p)
+            } else {
+                curve.add(&current, &two_p, &previous)
+            };
+            previous = current;
+            current = next;
+            j += 2;
+        }
+        baby_steps.push(current.clone());
+    }
+    let m_start = (b1 / ECM_STAGE_2_STEP).max(1);
+    let step = curve.multiply(ECM_STAGE_2_STEP, &p);
+    let mut current = curve.multiply(m_start * ECM_STAGE_2_STEP, &p);
+    // When m_start is 1, the previous giant step is never used, since 2 * step is computed by
+    // doubling.
+    let mut previous = if m_start == 1 {
+        current.clone()
+    } else {
+        curve.multiply((m_start - 1) * ECM_STAGE_2_STEP, &p)
+    };
+    let mut product = Natural::ONE;
+    for m in m_start..=b2 / ECM_STAGE_2_STEP + 1 {
+        for (xs, zs) in &baby_steps {
+            let term = curve
+                .mul(&current.0, zs)
+                .mod_sub(curve.mul(xs, &current.1), n);
+            product = curve.mul(&product, &term);
+        }
+        let next = if m == 1 {
+            curve.double(&current)
+        } else {
+            curve.add(&current, &step, &previous)
+        };
+        previous = current;
+        current = next;
+    }
+    let g = product.gcd(n);
+    if g == 1u32 || g == *n {
+        None
+    } else {
+        Some(g)
+    }
+}}
+
+// Finds a nontrivial factor of the odd composite number `n`, which must have no prime factors less
+// than `TRIAL_DIVISION_LIMIT` and must not be a perfect power.
+fn find_factor(n: &Natural) -> Natural {
+    for c in 1..=RHO_ATTEMPTS {
+        if let Some(factor) = pollard_brent_rho(n, &Natural::from(c)) {
+            return factor;
+        }
+    }
+    let mut sigma = 6;
+    for (b1, curves) in ecm_schedule() {
+        for _ in 0..curves {
+            if let Some(factor) = ecm_one_curve(n, sigma, b1) {
+                return factor;
+            }
+            sigma += 1;
+        }
+    }
+    unreachable!()
+}
+
+impl Factor for Natural {
+    /// Finds the prime factorization of a [`Natural`].
+    ///
+    /// The factorization is returned as a list of (prime, exponent) pairs, sorted by prime. Small
+    /// factors are found using trial division, medium-sized ones using Pollard-Brent rho, and
+    /// large ones using Lenstra's elliptic-curve method. Before it is reported, every factor is
+    /// checked for primality using [`is_prime`](IsPrime::is_prime).
+    ///
+    /// # Worst-case complexity
+    /// The running time depends on the size of the second-largest prime factor $p$. Heuristically,
+    /// the expected time is
+    ///
+    /// $T(n, p) = O(\exp((\sqrt{2} + o(1))\sqrt{\log p \log\log p}) n \log n \log\log n)$
+    ///
+    /// and the additional memory is
+    ///
+    /// $M(n) = O(n \log n)$,
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `self` is 0.
+    ///
+    /// # Examples
+    /// See [here](super::factor#factor).
+    fn factor(&self) -> Vec<(Natural, u64)> {
+        assert_ne!(*self, 0u32, "Cannot factor 0");
+        let mut factors = Vec::new();
+        let n = trial_division(self.clone(), &mut factors);
+        let mut large_primes = Vec::new();
+        let mut composites = vec![n];
+        while let Some(n) = composites.pop() {
+            if n.significant_bits() <= u128::WIDTH {
+                for (p, e) in u128::exact_from(&n).factor() {
+                    for _ in 0..e {
+                        large_primes.push(Natural::from(p));
+                    }
+                }
+            } else if n.is_prime() {
+                large_primes.push(n);
+            } else if let Some((root, k)) = perfect_power(&n) {
+                for _ in 0..k {
+                    composites.push(root.clone());
+                }
+            } else {
+                let factor = find_factor(&n);
+                composites.push(&n / &factor);
+                composites.push(factor);
+            }
+        }
+        large_primes.sort_unstable();
+        for p in large_primes {
+            match factors.last_mut() {
+                Some((q, exponent)) if *q == p => *exponent += 1,
+                _ => factors.push((p, 1)),
+            }
+        }
+        factors
+    }
+}
@@ -0,0 +1,236 @@
+use crate::natural::arithmetic::mod_op::limbs_mod_limb;
+use crate::natural::random::uniform_random_natural_range;
+use crate::natural::InnerNatural::{Large, Small};
+use crate::natural::Natural;
+use crate::platform::Limb;
+use malachite_base::num::arithmetic::traits::{
+    CheckedSqrt, JacobiSymbol, ModAdd, ModMul, ModMulAssign, ModPow, ModSquareAssign, ModSubAssign,
+    Parity,
+};
+use malachite_base::num::basic::integers::PrimitiveInt;
+use malachite_base::num::basic::traits::{One, Two};
+use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_base::num::factorization::traits::{IsPrime, IsProbablePrime, Primes};
+use malachite_base::num::logic::traits::{BitIterable, SignificantBits};
+use malachite_base::random::Seed;
+
+// Every candidate is checked for divisibility by the odd primes less than this bound before any
+// more expensive test is run.
+const TRIAL_DIVISION_LIMIT: Limb = 1000;
+
+// The seed used to generate the bases for the additional Miller-Rabin rounds performed by
+// `is_probable_prime`. A fixed seed keeps the results reproducible.
+const MILLER_RABIN_SEED: Seed = Seed::from_bytes([
+    0x6d, 0x69, 0x6c, 0x6c, 0x65, 0x72, 0x2d, 0x72, 0x61, 0x62, 0x69, 0x6e, 0x2d, 0x62, 0x61, 0x73,
+    0x65, 0x73, 0x2d, 0x66, 0x6f, 0x72, 0x2d, 0x6e, 0x61, 0x74, 0x75, 0x72, 0x61, 0x6c, 0x73, 0x21,
+]);
+
+// Returns whether the odd number whose limbs are `xs` is divisible by any odd prime less than
+// `TRIAL_DIVISION_LIMIT`. The number must be greater than every such prime.
+//
+// # Worst-case complexity
+// $T(n) = O(n)$
+//
+// $M(n) = O(1)$
+//
+// where $T$ is time, $M$ is additional memory, and $n$ is `xs.len()`.
+fn limbs_has_small_odd_prime_factor(xs: &[Limb]) -> bool {
+    Limb::primes_less_than(&TRIAL_DIVISION_LIMIT)
+        .skip(1)
+        .any(|p| limbs_mod_limb(xs, p) == 0)
+}
+
+// Returns whether `n` is a strong probable prime to base `a`, where `n` is odd and greater than
+// 2, $1 < a < n$, and $n - 1 = 2^sd$ with $d$ odd.
+//
+// # Worst-case complexity
+// $T(n) = O(n^2 \log n \log\log n)$
+//
+// $M(n) = O(n \log n)$
+//
+// where $T$ is time, $M$ is additional memory, and $n$ is `n.significant_bits()`.
+fn is_strong_probable_prime(
+    n: &Natural,
+    n_minus_1: &Natural,
+    d: &Natural,
+    s: u64,
+    a: Natural,
+) -> bool {
+    let mut x = a.mod_pow(d, n);
+    if x == 1u32 || x == *n_minus_1 {
+        return true;
+    }
+    for _ in 1..s {
+        x.mod_square_assign(n);
+        if x == *n_minus_1 {
+            return true;
+        } else if x == 1u32 {
+            return false;
+        }
+    }
+    false
+}
+
+// Divides `x` by 2 modulo the odd number `n`, assuming `x < n`.
+fn mod_half_assign(x: &mut Natural, n: &Natural) {
+    if x.odd() {
+        *x += n;
+    }
+    *x >>= 1;
+}
+
+// Returns whether the odd number `n`, where $n > 2$ and `n` is not a perfect square, is a strong
+// Lucas probable prime, using Selfridge's method A to choose the parameters $P$ and $Q$. `n` must
+// be larger than any $|D|$ that is tried; in practice this holds for any `n` that does not fit in
+// a `u64`.
+//
+// # Worst-case complexity
+// $T(n) = O(n^2 \log n \log\log n)$
+//
+// $M(n) = O(n \log n)$
+//
+// where $T$ is time, $M$ is additional memory, and $n$ is `n.significant_bits()`.
+pub_test! {is_strong_lucas_probable_prime(n: &Natural) -> bool {
+    // Find the first D in 5, -7, 9, -11, 13, ... for which (D / n) = -1.
+    let mut abs_d = 5u32;
+    let mut negative = false;
+    let d = loop {
+        let d = if negative {
+            n - Natural::from(abs_d)
+        } else {
+            Natural::from(abs_d)
+        };
+        match (&d).jacobi_symbol(n) {
+            -1 => break d,
+            0 => return false,
+            _ => {}
+        }
+        abs_d += 2;
+        negative = !negative;
+    };
+    // P = 1 and Q = (1 - D) / 4.
+    let q = if negative {
+        Natural::from((abs_d + 1) >> 2)
+    } else {
+        n - Natural::from((abs_d - 1) >> 2)
+    };
+    let n_plus_1 = n + Natural::ONE;
+    let s = n_plus_1.trailing_zeros().unwrap();
+    let k = n_plus_1 >> s;
+    // Compute U_k, V_k, and Q^k, scanning the bits of k from most to least significant.
+    let mut u = Natural::ONE;
+    let mut v = Natural::ONE;
+    let mut q_k = q.clone();
+    for bit in k.bits().rev().skip(1) {
+        u.mod_mul_assign(&v, n);
+        v.mod_square_assign(n);
+        v.mod_sub_assign((&q_k).mod_add(&q_k, n), n);
+        q_k.mod_square_assign(n);
+        if bit {
+            let mut new_u = (&u).mod_add(&v, n);
+            mod_half_assign(&mut new_u, n);
+            v = (&d).mod_mul(&u, n).mod_add(v, n);
+            mod_half_assign(&mut v, n);
+            u = new_u;
+            q_k.mod_mul_assign(&q, n);
+        }
+    }
+    if u == 0u32 || v == 0u32 {
+        return true;
+    }
+    for _ in 1..s {
+        v.mod_square_assign(n);
+        v.mod_sub_assign((&q_k).mod_add(&q_k, n), n);
+        if v == 0u32 {
+            return true;
+        }
+        q_k.mod_square_assign(n);
+    }
+    false
+}}
+
+// Returns whether `n` is a Baillie-PSW probable prime, and, if it is, whether it also passes
+// `extra_rounds` Miller-Rabin tests with pseudorandom bases. `n` must not fit in a `u64`.
+//
+// # Worst-case complexity
+// $T(n, m) = O((m + 1) n^2 \log n \log\log n)$
+//
+// $M(n) = O(n \log n)$
+//
+// where $T$ is time, $M$ is additional memory, $n$ is `n.significant_bits()`, and $m$ is
+// `extra_rounds`.
+fn is_large_probable_prime(n: &Natural, extra_rounds: u64) -> bool {
+    let xs = match n {
+        Natural(Large(xs)) => xs,
+        _ => unreachable!(),
+    };
+    if xs[0].even() || limbs_has_small_odd_prime_factor(xs) {
+        return false;
+    }
+    let n_minus_1 = n - Natural::ONE;
+    let s = n_minus_1.trailing_zeros().unwrap();
+    let d = &n_minus_1 >> s;
+    if !is_strong_probable_prime(n, &n_minus_1, &d, s, Natural::TWO)
+        || n.checked_sqrt().is_some()
+        || !is_strong_lucas_probable_prime(n)
+    {
+        return false;
+    }
+    uniform_random_natural_range(MILLER_RABIN_SEED, Natural::TWO, n_minus_1.clone())
+        .take(usize::exact_from(extra_rounds))
+        .all(|a| is_strong_probable_prime(n, &n_minus_1, &d, s, a))
+}
+
+impl IsPrime for Natural {
+    /// Determines whether a [`Natural`] is prime.
+    ///
+    /// If the [`Natural`] is less than $2^{64}$, the result is always correct. Otherwise, the
+    /// [`Natural`] is checked for small factors and then subjected to the Baillie-PSW test: a
+    /// Miller-Rabin test to base 2, followed by a strong Lucas test. No composite number is known
+    /// to pass this test, but it has not been proven that none exist.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Examples
+    /// See [here](super::is_prime#is_prime).
+    fn is_prime(&self) -> bool {
+        match self {
+            Natural(Small(x)) => x.is_prime(),
+            n if n.significant_bits() <= u64::WIDTH => u64::exact_from(n).is_prime(),
+            n => is_large_probable_prime(n, 0),
+        }
+    }
+}
+
+impl IsProbablePrime for Natural {
+    /// Determines whether a [`Natural`] is a probable prime.
+    ///
+    /// If the [`Natural`] is less than $2^{64}$, the result is always correct, and `extra_rounds`
+    /// is ignored. Otherwise, the [`Natural`] must first pass the Baillie-PSW test used by
+    /// [`is_prime`](IsPrime::is_prime), followed by `extra_rounds` Miller-Rabin tests with
+    /// pseudorandom bases. The bases are generated from a fixed seed, so the result for a given
+    /// input never changes.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O((m + 1) n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `self.significant_bits()`, and $m$ is
+    /// `extra_rounds`.
+    ///
+    /// # Examples
+    /// See [here](super::is_prime#is_probable_prime).
+    fn is_probable_prime(&self, extra_rounds: u64) -> bool {
+        match self {
+            Natural(Small(x)) => x.is_prime(),
+            n if n.significant_bits() <= u64::WIDTH => u64::exact_from(n).is_prime(),
+            n => is_large_probable_prime(n, extra_rounds),
+        }
+    }
+}
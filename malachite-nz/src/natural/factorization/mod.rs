@@ -1,3 +1,124 @@
+/// An implementation of [`Factor`](malachite_base::num::factorization::traits::Factor), a trait
+/// for finding the prime factorization of a number.
+///
+/// # factor
+/// ```
+/// use malachite_base::num::factorization::traits::Factor;
+/// use malachite_base::strings::ToDebugString;
+/// use malachite_nz::natural::Natural;
+/// use std::str::FromStr;
+///
+/// assert_eq!(Natural::from(1u32).factor().to_debug_string(), "[]");
+/// assert_eq!(Natural::from(360u32).factor().to_debug_string(), "[(2, 3), (3, 2), (5, 1)]");
+/// assert_eq!(
+///     Natural::from_str("2156280139809231176600527990492211447259").unwrap().factor()
+///         .to_debug_string(),
+///     "[(3, 5), (7, 1), (1000003, 1), (1267650600228229401496703205653, 1)]"
+/// );
+/// assert_eq!(
+///     Natural::from_str("1000000000000000000000000000000").unwrap().factor()
+///         .to_debug_string(),
+///     "[(2, 30), (5, 30)]"
+/// );
+/// ```
+pub mod factor;
+/// Implementations of [`IsPrime`](malachite_base::num::factorization::traits::IsPrime) and
+/// [`IsProbablePrime`](malachite_base::num::factorization::traits::IsProbablePrime), traits for
+/// determining whether a number is prime.
+///
+/// # is_prime
+/// ```
+/// use malachite_base::num::factorization::traits::IsPrime;
+/// use malachite_nz::natural::Natural;
+/// use std::str::FromStr;
+///
+/// assert_eq!(Natural::from(0u32).is_prime(), false);
+/// assert_eq!(Natural::from(97u32).is_prime(), true);
+/// assert_eq!(Natural::from(561u32).is_prime(), false);
+/// assert_eq!(
+///     Natural::from_str("170141183460469231731687303715884105727").unwrap().is_prime(),
+///     true
+/// );
+/// assert_eq!(
+///     Natural::from_str("170141183460469231731687303715884105729").unwrap().is_prime(),
+///     false
+/// );
+/// ```
+///
+/// # is_probable_prime
+/// ```
+/// use malachite_base::num::factorization::traits::IsProbablePrime;
+/// use malachite_nz::natural::Natural;
+/// use std::str::FromStr;
+///
+/// assert_eq!(Natural::from(97u32).is_probable_prime(0), true);
+/// assert_eq!(Natural::from(561u32).is_probable_prime(10), false);
+/// assert_eq!(
+///     Natural::from_str("170141183460469231731687303715884105727")
+///         .unwrap()
+///         .is_probable_prime(10),
+///     true
+/// );
+/// ```
+pub mod is_prime;
+/// Implementations of [`NextPrime`](malachite_base::num::factorization::traits::NextPrime) and
+/// [`PrevPrime`](malachite_base::num::factorization::traits::PrevPrime), traits for finding the
+/// nearest prime greater than or less than a number.
+///
+/// # next_prime
+/// ```
+/// use malachite_base::num::factorization::traits::NextPrime;
+/// use malachite_nz::natural::Natural;
+/// use std::str::FromStr;
+///
+/// assert_eq!(Natural::from(0u32).next_prime(), 2);
+/// assert_eq!(Natural::from(10u32).next_prime(), 11);
+/// assert_eq!(
+///     Natural::from_str("18446744073709551616").unwrap().next_prime(),
+///     Natural::from_str("18446744073709551629").unwrap()
+/// );
+/// assert_eq!(
+///     Natural::from_str("1267650600228229401496703205376").unwrap().next_prime(),
+///     Natural::from_str("1267650600228229401496703205653").unwrap()
+/// );
+/// ```
+///
+/// # prev_prime
+/// ```
+/// use malachite_base::num::factorization::traits::PrevPrime;
+/// use malachite_nz::natural::Natural;
+/// use std::str::FromStr;
+///
+/// assert_eq!(Natural::from(3u32).prev_prime(), 2);
+/// assert_eq!(Natural::from(100u32).prev_prime(), 97);
+/// assert_eq!(
+///     Natural::from_str("1267650600228229401496703205376").unwrap().prev_prime(),
+///     Natural::from_str("1267650600228229401496703205361").unwrap()
+/// );
+/// ```
+pub mod next_prime;
+/// Implementations of [`PrimeCount`](malachite_base::num::factorization::traits::PrimeCount) and
+/// [`NthPrime`](malachite_base::num::factorization::traits::NthPrime), traits for counting the
+/// primes up to a number and for finding the $n$th prime.
+///
+/// # prime_count
+/// ```
+/// use malachite_base::num::factorization::traits::PrimeCount;
+/// use malachite_nz::natural::Natural;
+///
+/// assert_eq!(Natural::from(100u32).prime_count(), 25);
+/// assert_eq!(Natural::from(10u64.pow(10)).prime_count(), 455052511);
+/// ```
+///
+/// # nth_prime
+/// ```
+/// use malachite_base::num::factorization::traits::NthPrime;
+/// use malachite_nz::natural::Natural;
+///
+/// assert_eq!(Natural::nth_prime(25), 97);
+/// assert_eq!(Natural::nth_prime(1000000000), 22801763489u64);
+/// ```
+pub mod prime_count;
 /// An implementation of [`Primes`](malachite_base::num::factorization::traits::Primes), a trait
 /// for generating prime numbers.
 ///
@@ -64,3 +185,56 @@
 /// );
 /// ```
 pub mod primes;
+/// An implementation of [`PrimesInRange`](malachite_base::num::factorization::traits::PrimesInRange),
+/// a trait for generating the primes in a range.
+///
+/// # primes_in_range
+/// ```
+/// use itertools::Itertools;
+/// use malachite_base::num::factorization::traits::PrimesInRange;
+/// use malachite_base::strings::ToDebugString;
+/// use malachite_nz::natural::Natural;
+/// use std::str::FromStr;
+///
+/// assert_eq!(
+///     Natural::primes_in_range(&Natural::from(10u32), &Natural::from(30u32))
+///         .collect_vec()
+///         .to_debug_string(),
+///     "[11, 13, 17, 19, 23, 29]"
+/// );
+/// assert_eq!(
+///     Natural::primes_in_range(
+///         &Natural::from_str("1267650600228229401496703205376").unwrap(),
+///         &Natural::from_str("1267650600228229401496703205876").unwrap()
+///     )
+///     .collect_vec()
+///     .to_debug_string(),
+///     "[1267650600228229401496703205653, 1267650600228229401496703205707, \
+///     1267650600228229401496703205823]"
+/// );
+/// ```
+///
+/// # primes_greater_than_or_equal_to
+/// ```
+/// use itertools::Itertools;
+/// use malachite_base::num::factorization::traits::PrimesInRange;
+/// use malachite_base::strings::ToDebugString;
+/// use malachite_nz::natural::Natural;
+/// use std::str::FromStr;
+///
+/// assert_eq!(
+///     Natural::primes_greater_than_or_equal_to(&Natural::from(100u32))
+///         .take(5)
+///         .collect_vec()
+///         .to_debug_string(),
+///     "[101, 103, 107, 109, 113]"
+/// );
+/// assert_eq!(
+///     Natural::primes_greater_than_or_equal_to(&Natural::from_str("18446744073709551616").unwrap())
+///         .take(3)
+///         .collect_vec()
+///         .to_debug_string(),
+///     "[18446744073709551629, 18446744073709551653, 18446744073709551667]"
+/// );
+/// ```
+pub mod primes_in_range;
@@ -0,0 +1,81 @@
+use crate::natural::factorization::primes_in_range::{segment_length, sieve_primes, sieve_segment};
+use crate::natural::Natural;
+use malachite_base::num::basic::integers::PrimitiveInt;
+use malachite_base::num::basic::traits::One;
+use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_base::num::factorization::traits::{IsPrime, NextPrime, PrevPrime, PrimesInRange};
+use malachite_base::num::logic::traits::SignificantBits;
+
+// The largest prime that fits in a `u64`.
+const LARGEST_U64_PRIME: u64 = 18446744073709551557;
+
+impl NextPrime for Natural {
+    /// Finds the smallest prime greater than a [`Natural`].
+    ///
+    /// If the result fits in a `u64`, it is found by the `u64` implementation. Otherwise, the
+    /// numbers above `self` are sieved in segments and the survivors are checked with
+    /// [`is_prime`](IsPrime::is_prime).
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^3 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Examples
+    /// See [here](super::next_prime#next_prime).
+    fn next_prime(&self) -> Natural {
+        if *self < LARGEST_U64_PRIME {
+            Natural::from(u64::exact_from(self).next_prime())
+        } else {
+            Natural::primes_greater_than_or_equal_to(&(self + Natural::ONE))
+                .next()
+                .unwrap()
+        }
+    }
+}
+
+impl PrevPrime for Natural {
+    /// Finds the largest prime less than a [`Natural`].
+    ///
+    /// If `self` fits in a `u64`, the result is found by the `u64` implementation. Otherwise, the
+    /// numbers below `self` are sieved in segments, from the top down, and the survivors are
+    /// checked with [`is_prime`](IsPrime::is_prime).
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^3 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `self` is less than or equal to 2.
+    ///
+    /// # Examples
+    /// See [here](super::next_prime#prev_prime).
+    fn prev_prime(&self) -> Natural {
+        if self.significant_bits() <= u64::WIDTH {
+            return Natural::from(u64::exact_from(self).prev_prime());
+        }
+        // Since `self` is at least 2^64, a prime is found long before any segment would extend
+        // below zero.
+        let primes = sieve_primes();
+        let len = segment_length(self);
+        let mut end = self.clone();
+        loop {
+            let start = &end - Natural::from(len);
+            let segment = sieve_segment(&start, len, &primes);
+            for (i, &survivor) in segment.iter().enumerate().rev() {
+                if survivor {
+                    let n = &start + Natural::from(i);
+                    if n.is_prime() {
+                        return n;
+                    }
+                }
+            }
+            end = start;
+        }
+    }
+}
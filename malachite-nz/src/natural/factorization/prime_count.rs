@@ -0,0 +1,54 @@
+use crate::natural::Natural;
+use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_base::num::factorization::traits::{NthPrime, PrimeCount};
+
+impl PrimeCount for Natural {
+    /// Counts the primes less than or equal to a [`Natural`].
+    ///
+    /// $f(x) = \pi(x)$.
+    ///
+    /// The count is found by the `u64` implementation, which uses the combinatorial algorithm of
+    /// Lagarias, Miller, and Odlyzko.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(2^{2n/3})$
+    ///
+    /// $M(n) = O(2^{n/3})$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `self` is greater than `u64::MAX`.
+    ///
+    /// # Examples
+    /// See [here](super::prime_count#prime_count).
+    #[inline]
+    fn prime_count(&self) -> u64 {
+        u64::exact_from(self).prime_count()
+    }
+}
+
+impl NthPrime for Natural {
+    /// Finds the $n$th prime, where the first prime is 2.
+    ///
+    /// $f(n) = p_n$.
+    ///
+    /// The prime is found by the `u64` implementation.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{2/3})$
+    ///
+    /// $M(n) = O(n^{1/3})$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `n`.
+    ///
+    /// # Panics
+    /// Panics if `n` is zero, or if the $n$th prime is greater than `u64::MAX`.
+    ///
+    /// # Examples
+    /// See [here](super::prime_count#nth_prime).
+    #[inline]
+    fn nth_prime(n: u64) -> Natural {
+        Natural::from(u64::nth_prime(n))
+    }
+}
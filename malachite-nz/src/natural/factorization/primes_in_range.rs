@@ -0,0 +1,162 @@
+use crate::natural::Natural;
+use crate::platform::Limb;
+use malachite_base::num::conversion::traits::{ExactFrom, SaturatingFrom};
+use malachite_base::num::factorization::traits::{IsPrime, Primes, PrimesInRange};
+use malachite_base::num::logic::traits::SignificantBits;
+
+// Each segment is sieved using the primes less than this bound; the numbers that survive sieving
+// are then checked using `is_prime`.
+const SIEVE_LIMIT: Limb = 1 << 12;
+
+// The smallest number of integers in a segment of the sieve. Larger numbers use longer segments,
+// since the gaps between their primes are longer.
+const MIN_SEGMENT_LENGTH: u64 = 1 << 10;
+
+pub(crate) fn sieve_primes() -> Vec<Limb> {
+    Limb::primes_less_than(&SIEVE_LIMIT).collect()
+}
+
+// Returns the number of integers in each segment of the sieve, when the segments start at `n`.
+pub(crate) fn segment_length(n: &Natural) -> usize {
+    usize::exact_from(MIN_SEGMENT_LENGTH.max(n.significant_bits() << 3))
+}
+
+// Returns a vector whose `i`th entry is `false` if `start + i` is less than 2, or is divisible by
+// one of the `primes` without being equal to it. Each prime must be less than `SIEVE_LIMIT`.
+//
+// # Worst-case complexity
+// $T(n, m, k) = O(n + mk)$
+//
+// $M(n) = O(n)$
+//
+// where $T$ is time, $M$ is additional memory, $n$ is `len`, $m$ is `primes.len()`, and $k$ is
+// `start.significant_bits()`.
+pub(crate) fn sieve_segment(start: &Natural, len: usize, primes: &[Limb]) -> Vec<bool> {
+    let mut segment = vec![true; len];
+    if *start < 2u32 {
+        let small = usize::exact_from(2 - Limb::exact_from(start));
+        for x in segment.iter_mut().take(small) {
+            *x = false;
+        }
+    }
+    for &p in primes {
+        // Smaller multiples of p have smaller prime factors, so crossing out starts at p^2.
+        let square = p * p;
+        let mut i = if *start <= square {
+            usize::exact_from(square - Limb::exact_from(start))
+        } else {
+            let remainder = start.rem_limb_ref(p);
+            if remainder == 0 {
+                0
+            } else {
+                usize::exact_from(p - remainder)
+            }
+        };
+        let p = usize::exact_from(p);
+        while i < len {
+            segment[i] = false;
+            i += p;
+        }
+    }
+    segment
+}
+
+/// An iterator that generates all prime [`Natural`]s in a range.
+///
+/// This `struct` is created by [`Natural::primes_in_range`] and
+/// [`Natural::primes_greater_than_or_equal_to`]; see their documentation for more.
+#[derive(Clone, Debug)]
+pub struct NaturalPrimesInRangeIterator {
+    // The first number of the current segment
+    start: Natural,
+    // The end of the range, which is excluded, or `None` if the range is unbounded
+    end: Option<Natural>,
+    sieve_primes: Vec<Limb>,
+    segment: Vec<bool>,
+    i: usize,
+}
+
+impl NaturalPrimesInRangeIterator {
+    fn new(a: Natural, b: Option<Natural>) -> NaturalPrimesInRangeIterator {
+        NaturalPrimesInRangeIterator {
+            start: a,
+            end: b,
+            sieve_primes: sieve_primes(),
+            segment: Vec::new(),
+            i: 0,
+        }
+    }
+}
+
+impl Iterator for NaturalPrimesInRangeIterator {
+    type Item = Natural;
+
+    fn next(&mut self) -> Option<Natural> {
+        loop {
+            while self.i < self.segment.len() {
+                let i = self.i;
+                self.i += 1;
+                if self.segment[i] {
+                    let n = &self.start + Natural::from(i);
+                    if n.is_prime() {
+                        return Some(n);
+                    }
+                }
+            }
+            self.start += Natural::from(self.segment.len());
+            let mut len = segment_length(&self.start);
+            if let Some(end) = &self.end {
+                if self.start >= *end {
+                    return None;
+                }
+                len = len.min(usize::saturating_from(&(end - &self.start)));
+            }
+            self.segment = sieve_segment(&self.start, len, &self.sieve_primes);
+            self.i = 0;
+        }
+    }
+}
+
+impl PrimesInRange for Natural {
+    type I = NaturalPrimesInRangeIterator;
+
+    /// Returns an iterator that generates all primes $p$ with $a \leq p < b$.
+    ///
+    /// The range is sieved in segments using the primes less than $2^{12}$, and each number that
+    /// survives sieving is then checked with [`is_prime`](IsPrime::is_prime).
+    ///
+    /// # Worst-case complexity (amortized)
+    /// $T(n) = O(n^3 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `b.significant_bits()`.
+    ///
+    /// # Examples
+    /// See [here](super::primes_in_range#primes_in_range).
+    #[inline]
+    fn primes_in_range(a: &Natural, b: &Natural) -> NaturalPrimesInRangeIterator {
+        NaturalPrimesInRangeIterator::new(a.clone(), Some(b.clone()))
+    }
+
+    /// Returns an iterator that generates all primes greater than or equal to a given value.
+    ///
+    /// The range is sieved in segments using the primes less than $2^{12}$, and each number that
+    /// survives sieving is then checked with [`is_prime`](IsPrime::is_prime). Unlike the iterators
+    /// for primitive integers, this iterator never ends.
+    ///
+    /// # Worst-case complexity (amortized)
+    /// $T(n) = O(n^3 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the number of significant bits of
+    /// the generated prime.
+    ///
+    /// # Examples
+    /// See [here](super::primes_in_range#primes_greater_than_or_equal_to).
+    #[inline]
+    fn primes_greater_than_or_equal_to(a: &Natural) -> NaturalPrimesInRangeIterator {
+        NaturalPrimesInRangeIterator::new(a.clone(), None)
+    }
+}
@@ -8,6 +8,7 @@ use malachite_base::num::arithmetic::traits::{
 use malachite_base::num::basic::integers::PrimitiveInt;
 use malachite_base::num::basic::traits::{One, Zero};
 use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_base::num::factorization::traits::{IsPrime, PrimesInRange};
 #[cfg(feature = "32_bit_limbs")]
 use malachite_base::num::iterators::iterator_to_bit_chunks;
 use malachite_base::num::logic::traits::{BitAccess, LowMask, SignificantBits};
@@ -1302,3 +1303,485 @@ pub fn striped_random_natural_range_to_infinity(
         ),
     }
 }
+
+// Among the numbers with `bits` bits, only odd ones can be prime, except when `bits` is 2.
+fn prime_candidate(mut x: Natural, bits: u64) -> Natural {
+    if bits > 2 {
+        x.set_bit(0);
+    }
+    x
+}
+
+// Given a candidate $q$ for a Sophie Germain prime, returns $2q + 1$ if it is a safe prime.
+fn safe_prime_from_candidate(q: Natural) -> Option<Natural> {
+    if q.is_prime() {
+        let p = (q << 1u64) | Natural::ONE;
+        if p.is_prime() {
+            return Some(p);
+        }
+    }
+    None
+}
+
+/// Uniformly generates random prime [`Natural`]s with a given bit length.
+#[derive(Clone, Debug)]
+pub struct RandomPrimesWithBits {
+    bits: u64,
+    limbs: RandomPrimitiveInts<u64>,
+}
+
+impl Iterator for RandomPrimesWithBits {
+    type Item = Natural;
+
+    fn next(&mut self) -> Option<Natural> {
+        loop {
+            let x = prime_candidate(
+                get_random_natural_with_bits(&mut self.limbs, self.bits),
+                self.bits,
+            );
+            if x.is_prime() {
+                return Some(x);
+            }
+        }
+    }
+}
+
+/// Uniformly generates random prime [`Natural`]s with a given bit length.
+///
+/// Odd [`Natural`]s with the given bit length are generated uniformly and checked with
+/// [`is_prime`](IsPrime::is_prime) until one passes. For inputs of $2^{64}$ or more, this is the
+/// Baillie-PSW test, so the output is only known to consist of probable primes; however, no
+/// Baillie-PSW pseudoprime is known.
+///
+/// $$
+/// P(p) = \\begin{cases}
+///     \frac{1}{\pi(2^b) - \pi(2^{b-1})} & \text{if} \\quad 2^{b-1} \leq p < 2^b
+///         \\ \\text{and} \\ p \\ \\text{is prime}, \\\\
+///     0 & \\text{otherwise},
+/// \\end{cases}
+/// $$
+/// where $b$ is `bits`.
+///
+/// The output length is infinite.
+///
+/// # Expected complexity per iteration
+/// $T(n) = O(n^3 \log n \log\log n)$
+///
+/// $M(n) = O(n \log n)$
+///
+/// where $T$ is time, $M$ is additional memory, and $n$ is `bits`.
+///
+/// # Panics
+/// Panics if `bits` is less than 2.
+///
+/// # Examples
+/// ```
+/// use malachite_base::iterators::prefix_to_string;
+/// use malachite_base::random::EXAMPLE_SEED;
+/// use malachite_nz::natural::random::random_primes_with_bits;
+///
+/// assert_eq!(
+///     prefix_to_string(random_primes_with_bits(EXAMPLE_SEED, 10), 10),
+///     "[881, 599, 727, 641, 647, 797, 839, 647, 599, 593, ...]"
+/// );
+/// assert_eq!(
+///     prefix_to_string(random_primes_with_bits(EXAMPLE_SEED, 100), 3),
+///     "[834112884250571316017711689193, 1096064561690550858427390195543, \
+///     1247194096045807604465004471959, ...]"
+/// );
+/// ```
+pub fn random_primes_with_bits(seed: Seed, bits: u64) -> RandomPrimesWithBits {
+    assert!(bits >= 2, "There are no primes with fewer than 2 bits");
+    RandomPrimesWithBits {
+        bits,
+        limbs: random_primitive_ints(seed),
+    }
+}
+
+/// Generates striped random prime [`Natural`]s with a given bit length.
+#[derive(Clone, Debug)]
+pub struct StripedRandomPrimesWithBits {
+    bits: u64,
+    bit_source: StripedBitSource,
+}
+
+impl Iterator for StripedRandomPrimesWithBits {
+    type Item = Natural;
+
+    fn next(&mut self) -> Option<Natural> {
+        loop {
+            let x = prime_candidate(
+                get_striped_random_natural_with_bits(&mut self.bit_source, self.bits),
+                self.bits,
+            );
+            if x.is_prime() {
+                return Some(x);
+            }
+        }
+    }
+}
+
+/// Generates striped random prime [`Natural`]s with a given bit length.
+///
+/// Candidates are generated using a striped bit sequence with mean run length $m$ =
+/// `mean_stripe_numerator / mean_stripe_denominator`, their lowest bit is set, and they are
+/// checked with [`is_prime`](IsPrime::is_prime) until one passes. Since primes are rare among
+/// numbers with long runs of ones or zeros, the output is not dominated by any one pattern, but it
+/// exercises edge cases, such as primes just above a power of 2 or Mersenne primes, much more
+/// often than uniform generation does.
+///
+/// See [`StripedBitSource`] for information about generating striped random numbers.
+///
+/// The output length is infinite.
+///
+/// # Expected complexity per iteration
+/// $T(n) = O(n^3 \log n \log\log n)$
+///
+/// $M(n) = O(n \log n)$
+///
+/// where $T$ is time, $M$ is additional memory, and $n$ is `bits`.
+///
+/// # Panics
+/// Panics if `bits` is less than 2, if `mean_stripe_denominator` is zero, or if
+/// `mean_stripe_numerator <= mean_stripe_denominator`.
+///
+/// # Examples
+/// ```
+/// use malachite_base::iterators::prefix_to_string;
+/// use malachite_base::random::EXAMPLE_SEED;
+/// use malachite_base::strings::ToBinaryString;
+/// use malachite_nz::natural::random::striped_random_primes_with_bits;
+///
+/// assert_eq!(
+///     prefix_to_string(
+///         striped_random_primes_with_bits(EXAMPLE_SEED, 16, 8, 1).map(|x| x.to_binary_string()),
+///         10
+///     ),
+///     "[1111011111111111, 1111111101111111, 1110000000000011, 1111100000100001, \
+///     1000000011111111, 1110000000000011, 1001110011111101, 1000000000000011, \
+///     1110001111111011, 1000000011111111, ...]"
+/// );
+/// ```
+pub fn striped_random_primes_with_bits(
+    seed: Seed,
+    bits: u64,
+    mean_stripe_numerator: u64,
+    mean_stripe_denominator: u64,
+) -> StripedRandomPrimesWithBits {
+    assert!(bits >= 2, "There are no primes with fewer than 2 bits");
+    StripedRandomPrimesWithBits {
+        bits,
+        bit_source: StripedBitSource::new(seed, mean_stripe_numerator, mean_stripe_denominator),
+    }
+}
+
+/// Uniformly generates random safe prime [`Natural`]s with a given bit length.
+#[derive(Clone, Debug)]
+pub struct RandomSafePrimesWithBits {
+    bits: u64,
+    limbs: RandomPrimitiveInts<u64>,
+}
+
+impl Iterator for RandomSafePrimesWithBits {
+    type Item = Natural;
+
+    fn next(&mut self) -> Option<Natural> {
+        let q_bits = self.bits - 1;
+        loop {
+            if let Some(p) = safe_prime_from_candidate(prime_candidate(
+                get_random_natural_with_bits(&mut self.limbs, q_bits),
+                q_bits,
+            )) {
+                return Some(p);
+            }
+        }
+    }
+}
+
+/// Uniformly generates random safe prime [`Natural`]s with a given bit length.
+///
+/// A safe prime is a prime $p$ such that $(p - 1)/2$ is also prime. Candidates $q$ with one bit
+/// fewer than $p$ are generated uniformly, and $p = 2q + 1$ is returned once both $q$ and $p$ pass
+/// [`is_prime`](IsPrime::is_prime). For inputs of $2^{64}$ or more, this is the Baillie-PSW test,
+/// so the output is only known to consist of probable primes.
+///
+/// $$
+/// P(p) = \\begin{cases}
+///     \frac{1}{S_b} & \text{if} \\quad 2^{b-1} \leq p < 2^b
+///         \\ \\text{and} \\ p \\ \\text{is a safe prime}, \\\\
+///     0 & \\text{otherwise},
+/// \\end{cases}
+/// $$
+/// where $b$ is `bits` and $S_b$ is the number of safe primes with $b$ bits.
+///
+/// The output length is infinite.
+///
+/// # Expected complexity per iteration
+/// $T(n) = O(n^4 \log n \log\log n)$
+///
+/// $M(n) = O(n \log n)$
+///
+/// where $T$ is time, $M$ is additional memory, and $n$ is `bits`.
+///
+/// # Panics
+/// Panics if `bits` is less than 3.
+///
+/// # Examples
+/// ```
+/// use malachite_base::iterators::prefix_to_string;
+/// use malachite_base::random::EXAMPLE_SEED;
+/// use malachite_nz::natural::random::random_safe_primes_with_bits;
+///
+/// assert_eq!(
+///     prefix_to_string(random_safe_primes_with_bits(EXAMPLE_SEED, 10), 10),
+///     "[983, 863, 983, 719, 887, 587, 719, 563, 839, 719, ...]"
+/// );
+/// assert_eq!(
+///     prefix_to_string(random_safe_primes_with_bits(EXAMPLE_SEED, 100), 3),
+///     "[802284609635159727988428024359, 1257874898996497608100316123087, \
+///     1245339936798944633289852044519, ...]"
+/// );
+/// ```
+pub fn random_safe_primes_with_bits(seed: Seed, bits: u64) -> RandomSafePrimesWithBits {
+    assert!(bits >= 3, "There are no safe primes with fewer than 3 bits");
+    RandomSafePrimesWithBits {
+        bits,
+        limbs: random_primitive_ints(seed),
+    }
+}
+
+/// Generates striped random safe prime [`Natural`]s with a given bit length.
+#[derive(Clone, Debug)]
+pub struct StripedRandomSafePrimesWithBits {
+    bits: u64,
+    bit_source: StripedBitSource,
+}
+
+impl Iterator for StripedRandomSafePrimesWithBits {
+    type Item = Natural;
+
+    fn next(&mut self) -> Option<Natural> {
+        let q_bits = self.bits - 1;
+        loop {
+            if let Some(p) = safe_prime_from_candidate(prime_candidate(
+                get_striped_random_natural_with_bits(&mut self.bit_source, q_bits),
+                q_bits,
+            )) {
+                return Some(p);
+            }
+        }
+    }
+}
+
+/// Generates striped random safe prime [`Natural`]s with a given bit length.
+///
+/// A safe prime is a prime $p$ such that $(p - 1)/2$ is also prime. Candidates $q$ with one bit
+/// fewer than $p$ are generated using a striped bit sequence with mean run length $m$ =
+/// `mean_stripe_numerator / mean_stripe_denominator`, and $p = 2q + 1$ is returned once both $q$
+/// and $p$ pass [`is_prime`](IsPrime::is_prime).
+///
+/// See [`StripedBitSource`] for information about generating striped random numbers.
+///
+/// The output length is infinite.
+///
+/// # Expected complexity per iteration
+/// $T(n) = O(n^4 \log n \log\log n)$
+///
+/// $M(n) = O(n \log n)$
+///
+/// where $T$ is time, $M$ is additional memory, and $n$ is `bits`.
+///
+/// # Panics
+/// Panics if `bits` is less than 3, if `mean_stripe_denominator` is zero, or if
+/// `mean_stripe_numerator <= mean_stripe_denominator`.
+///
+/// # Examples
+/// ```
+/// use malachite_base::iterators::prefix_to_string;
+/// use malachite_base::random::EXAMPLE_SEED;
+/// use malachite_base::strings::ToBinaryString;
+/// use malachite_nz::natural::random::striped_random_safe_primes_with_bits;
+///
+/// assert_eq!(
+///     prefix_to_string(
+///         striped_random_safe_primes_with_bits(EXAMPLE_SEED, 16, 8, 1)
+///             .map(|x| x.to_binary_string()),
+///         10
+///     ),
+///     "[1110111111000011, 1110111110011111, 1111111001111011, 1001000000111011, \
+///     1110000011110011, 1100001111100011, 1000011000001111, 1000011000111111, \
+///     1100001111100011, 1101000111111111, ...]"
+/// );
+/// ```
+pub fn striped_random_safe_primes_with_bits(
+    seed: Seed,
+    bits: u64,
+    mean_stripe_numerator: u64,
+    mean_stripe_denominator: u64,
+) -> StripedRandomSafePrimesWithBits {
+    assert!(bits >= 3, "There are no safe primes with fewer than 3 bits");
+    StripedRandomSafePrimesWithBits {
+        bits,
+        bit_source: StripedBitSource::new(seed, mean_stripe_numerator, mean_stripe_denominator),
+    }
+}
+
+/// Uniformly generates random prime [`Natural`]s in an interval.
+#[derive(Clone, Debug)]
+pub struct RandomPrimesInRange {
+    xs: UniformRandomNaturalRange,
+}
+
+impl Iterator for RandomPrimesInRange {
+    type Item = Natural;
+
+    fn next(&mut self) -> Option<Natural> {
+        loop {
+            let x = self.xs.next().unwrap();
+            if x.is_prime() {
+                return Some(x);
+            }
+        }
+    }
+}
+
+/// Uniformly generates random prime [`Natural`]s in the half-open interval $[a, b)$.
+///
+/// [`Natural`]s in the interval are generated uniformly and checked with
+/// [`is_prime`](IsPrime::is_prime) until one passes. The interval must contain at least one prime.
+///
+/// $$
+/// P(p) = \\begin{cases}
+///     \frac{1}{\pi(b - 1) - \pi(a - 1)} & \text{if} \\quad a \leq p < b
+///         \\ \\text{and} \\ p \\ \\text{is prime}, \\\\
+///     0 & \\text{otherwise}.
+/// \\end{cases}
+/// $$
+///
+/// The output length is infinite.
+///
+/// # Expected complexity per iteration
+/// $T(n) = O(n^3 \log n \log\log n)$
+///
+/// $M(n) = O(n \log n)$
+///
+/// where $T$ is time, $M$ is additional memory, and $n$ is `b.significant_bits()`. This assumes
+/// that the primes in $[a, b)$ are not much sparser than the primes near $b$.
+///
+/// # Panics
+/// Panics if $a \geq b$ or if there are no primes in $[a, b)$.
+///
+/// # Examples
+/// ```
+/// use malachite_base::iterators::prefix_to_string;
+/// use malachite_base::random::EXAMPLE_SEED;
+/// use malachite_nz::natural::random::random_primes_in_range;
+/// use malachite_nz::natural::Natural;
+///
+/// assert_eq!(
+///     prefix_to_string(
+///         random_primes_in_range(EXAMPLE_SEED, Natural::from(10u32), Natural::from(100u32)),
+///         10
+///     ),
+///     "[97, 17, 37, 11, 17, 17, 97, 17, 17, 31, ...]"
+/// );
+/// ```
+pub fn random_primes_in_range(seed: Seed, a: Natural, b: Natural) -> RandomPrimesInRange {
+    assert!(a < b);
+    assert!(
+        Natural::primes_in_range(&a, &b).next().is_some(),
+        "There are no primes in the range"
+    );
+    RandomPrimesInRange {
+        xs: uniform_random_natural_range(seed, a, b),
+    }
+}
+
+/// Generates striped random prime [`Natural`]s from an interval.
+#[derive(Clone, Debug)]
+pub struct StripedRandomPrimesInRange {
+    xs: StripedRandomNaturalInclusiveRange,
+}
+
+impl Iterator for StripedRandomPrimesInRange {
+    type Item = Natural;
+
+    fn next(&mut self) -> Option<Natural> {
+        loop {
+            let x = self.xs.next().unwrap();
+            if x.is_prime() {
+                return Some(x);
+            }
+        }
+    }
+}
+
+/// Generates striped random prime [`Natural`]s in the half-open interval $[a, b)$.
+///
+/// Candidates are generated by [`striped_random_natural_range`] and checked with
+/// [`is_prime`](IsPrime::is_prime) until one passes. The interval must contain at least one prime.
+///
+/// See [`StripedBitSource`] for information about generating striped random numbers.
+///
+/// The output length is infinite.
+///
+/// # Expected complexity per iteration
+/// $T(n) = O(n^3 \log n \log\log n)$
+///
+/// $M(n) = O(n \log n)$
+///
+/// where $T$ is time, $M$ is additional memory, and $n$ is `b.significant_bits()`. This assumes
+/// that the striped candidates are not much less likely to be prime than uniform ones.
+///
+/// # Panics
+/// Panics if `mean_stripe_denominator` is zero, if
+/// `mean_stripe_numerator <= mean_stripe_denominator`, if $a \geq b$, or if there are no primes in
+/// $[a, b)$.
+///
+/// # Examples
+/// ```
+/// use malachite_base::iterators::prefix_to_string;
+/// use malachite_base::random::EXAMPLE_SEED;
+/// use malachite_base::strings::ToBinaryString;
+/// use malachite_nz::natural::random::striped_random_primes_in_range;
+/// use malachite_nz::natural::Natural;
+///
+/// assert_eq!(
+///     prefix_to_string(
+///         striped_random_primes_in_range(
+///             EXAMPLE_SEED,
+///             Natural::from(1000u32),
+///             Natural::from(100000u32),
+///             8,
+///             1
+///         )
+///         .map(|x| x.to_binary_string()),
+///         10
+///     ),
+///     "[1111111101, 1111000000011111, 11000000000010001, 11000000001000001, 1111111100001, \
+///     1111111111111, 1111111111111, 11000010001100111, 11000011010000011, \
+///     11000011000011111, ...]"
+/// );
+/// ```
+pub fn striped_random_primes_in_range(
+    seed: Seed,
+    a: Natural,
+    b: Natural,
+    mean_stripe_numerator: u64,
+    mean_stripe_denominator: u64,
+) -> StripedRandomPrimesInRange {
+    assert!(a < b);
+    assert!(
+        Natural::primes_in_range(&a, &b).next().is_some(),
+        "There are no primes in the range"
+    );
+    StripedRandomPrimesInRange {
+        xs: striped_random_natural_range(
+            seed,
+            a,
+            b,
+            mean_stripe_numerator,
+            mean_stripe_denominator,
+        ),
+    }
+}
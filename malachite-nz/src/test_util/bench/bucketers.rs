@@ -162,6 +162,13 @@ pub fn triple_3_natural_bit_bucketer<T, U>(var_name: &str) -> Bucketer<(T, U, Na
     }
 }
 
+pub fn quadruple_4_natural_bit_bucketer<T, U, V>(var_name: &str) -> Bucketer<(T, U, V, Natural)> {
+    Bucketer {
+        bucketing_function: &|(_, _, _, x)| usize::exact_from(x.significant_bits()),
+        bucketing_label: format!("{}.significant_bits()", var_name),
+    }
+}
+
 pub fn triple_3_pair_1_natural_bit_bucketer<T, U, V>(
     var_name: &str,
 ) -> Bucketer<(T, U, (Natural, V))> {
@@ -254,6 +261,29 @@ pub fn triple_integer_max_bit_bucketer<'a>(
     }
 }
 
+pub fn quadruple_integer_max_bit_bucketer<'a>(
+    x_name: &'a str,
+    y_name: &'a str,
+    z_name: &'a str,
+    w_name: &'a str,
+) -> Bucketer<'a, (Integer, Integer, Integer, Integer)> {
+    Bucketer {
+        bucketing_function: &|(x, y, z, w)| {
+            usize::exact_from(max!(
+                x.significant_bits(),
+                y.significant_bits(),
+                z.significant_bits(),
+                w.significant_bits()
+            ))
+        },
+        bucketing_label: format!(
+            "max({}.significant_bits(), {}.significant_bits(), {}.significant_bits(), \
+            {}.significant_bits())",
+            x_name, y_name, z_name, w_name
+        ),
+    }
+}
+
 pub fn triple_1_2_natural_max_bit_bucketer<'a, T>(
     x_name: &'a str,
     y_name: &'a str,
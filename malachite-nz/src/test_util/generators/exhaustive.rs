@@ -81,7 +81,7 @@ use malachite_base::iterators::bit_distributor::BitDistributorOutputType;
 use malachite_base::iterators::iter_windows;
 use malachite_base::num::arithmetic::traits::{
     ArithmeticCheckedShl, CoprimeWith, DivRound, DivisibleBy, DivisibleByPowerOf2, EqMod,
-    EqModPowerOf2, Parity, PowerOf2,
+    EqModPowerOf2, FloorLogBase2, Parity, Pow, PowerOf2, Square,
 };
 use malachite_base::num::basic::floats::PrimitiveFloat;
 use malachite_base::num::basic::integers::PrimitiveInt;
@@ -99,6 +99,7 @@ use malachite_base::num::exhaustive::{
     primitive_int_increasing_range, PrimitiveIntIncreasingRange,
 };
 use malachite_base::num::factorization::prime_sieve::n_to_bit;
+use malachite_base::num::factorization::traits::{Primes, PrimesInRange};
 use malachite_base::num::iterators::{bit_distributor_sequence, ruler_sequence};
 use malachite_base::num::logic::traits::{
     BitAccess, BitConvertible, LeadingZeros, SignificantBits,
@@ -288,6 +289,12 @@ pub fn exhaustive_integer_triple_gen_var_3() -> It<(Integer, Integer, Integer)>
     )
 }
 
+// -- (Integer, Integer, Integer, Integer) --
+
+pub fn exhaustive_integer_quadruple_gen() -> It<(Integer, Integer, Integer, Integer)> {
+    Box::new(exhaustive_quadruples_from_single(exhaustive_integers()))
+}
+
 // -- (Integer, Integer, Integer, PrimitiveUnsigned) --
 
 pub fn exhaustive_integer_integer_integer_unsigned_quadruple_gen_var_1<T: PrimitiveUnsigned>(
@@ -1101,6 +1108,28 @@ pub fn exhaustive_natural_pair_gen_var_15() -> It<(Natural, Natural)> {
     ))
 }
 
+// Powers of small primes, squares of primes greater than $2^{32}$, and products of two such primes.
+// All are greater than $2^{64}$.
+fn exhaustive_large_moduli() -> It<Natural> {
+    let large_primes = || Natural::primes_greater_than_or_equal_to(&Natural::power_of_2(32));
+    Box::new(
+        Natural::primes()
+            .map(|p| {
+                let e = 64 / p.floor_log_base_2() + 1;
+                p.pow(e)
+            })
+            .interleave(large_primes().map(Square::square))
+            .interleave(large_primes().tuple_windows().map(|(p, q)| p * q)),
+    )
+}
+
+pub fn exhaustive_natural_pair_gen_var_16() -> It<(Natural, Natural)> {
+    Box::new(exhaustive_pairs(
+        exhaustive_naturals(),
+        exhaustive_large_moduli(),
+    ))
+}
+
 // -- (Natural, Natural, bool) --
 
 pub fn exhaustive_natural_natural_bool_triple_gen_var_1() -> It<(Natural, Natural, bool)> {
@@ -1181,6 +1210,16 @@ pub fn exhaustive_natural_triple_gen_var_9() -> It<(Natural, Natural, Natural)>
     )
 }
 
+pub fn exhaustive_natural_triple_gen_var_10() -> It<(Natural, Natural, Natural)> {
+    Box::new(
+        exhaustive_pairs(
+            exhaustive_pairs_from_single(exhaustive_naturals()),
+            exhaustive_large_moduli(),
+        )
+        .map(|((n, k), m)| (n + &k, k, m)),
+    )
+}
+
 // -- (Natural, Natural, Natural, Natural) --
 
 pub fn exhaustive_natural_quadruple_gen_var_1() -> It<(Natural, Natural, Natural, Natural)> {
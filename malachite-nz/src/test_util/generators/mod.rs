@@ -364,6 +364,16 @@ pub fn integer_triple_gen_var_3() -> Generator<(Integer, Integer, Integer)> {
     )
 }
 
+// -- (Integer, Integer, Integer, Integer) --
+
+pub fn integer_quadruple_gen() -> Generator<(Integer, Integer, Integer, Integer)> {
+    Generator::new(
+        &exhaustive_integer_quadruple_gen,
+        &random_integer_quadruple_gen,
+        &special_random_integer_quadruple_gen,
+    )
+}
+
 // -- (Integer, Integer, Integer, PrimitiveUnsigned) --
 
 // All `(Integer, Integer, Integer, T)` where `T` is unsigned and small.
@@ -1381,6 +1391,15 @@ pub fn natural_pair_gen_var_15_rm() -> Generator<((rug::Integer, u32), (Natural,
     )
 }
 
+// All pairs of `Natural`s `(n, m)` where `n` is small and `m` is greater than $2^{64}$ and has a
+// large prime-power factor or large prime factors.
+pub fn natural_pair_gen_var_16() -> Generator<(Natural, Natural)> {
+    Generator::new_no_special(
+        &exhaustive_natural_pair_gen_var_16,
+        &random_natural_pair_gen_var_16,
+    )
+}
+
 // -- (Natural, Natural, bool) --
 
 // All `(Natural, Natural, bool)` where the second `Natural` is positive.
@@ -1509,6 +1528,15 @@ pub fn natural_triple_gen_var_9() -> Generator<(Natural, Natural, Natural)> {
     )
 }
 
+// All triples of `Natural`s `(n, k, m)` where `n` and `k` are small, $k \leq n$, and `m` is greater
+// than $2^{64}$ and has a large prime-power factor or large prime factors.
+pub fn natural_triple_gen_var_10() -> Generator<(Natural, Natural, Natural)> {
+    Generator::new_no_special(
+        &exhaustive_natural_triple_gen_var_10,
+        &random_natural_triple_gen_var_10,
+    )
+}
+
 // -- (Natural, Natural, Natural, Natural) --
 
 // All quadruples of `Natural` where the first three elements are each less than the fourth.
@@ -47,8 +47,8 @@ use crate::natural::conversion::digits::general_digits::{
 };
 use crate::natural::random::{
     get_random_natural_with_up_to_bits, random_natural_range_to_infinity, random_naturals,
-    random_naturals_less_than, random_positive_naturals, RandomNaturalRangeToInfinity,
-    RandomNaturals, RandomNaturalsLessThan,
+    random_naturals_less_than, random_positive_naturals, random_primes_in_range,
+    RandomNaturalRangeToInfinity, RandomNaturals, RandomNaturalsLessThan,
 };
 use crate::natural::Natural;
 use crate::platform::{
@@ -75,7 +75,8 @@ use malachite_base::bools::random::{random_bools, RandomBools};
 use malachite_base::iterators::with_special_value;
 use malachite_base::num::arithmetic::traits::{
     ArithmeticCheckedShl, CeilingLogBase2, CoprimeWith, DivRound, DivisibleBy, DivisibleByPowerOf2,
-    EqMod, EqModPowerOf2, Parity, PowerOf2, RoundToMultipleOfPowerOf2Assign,
+    EqMod, EqModPowerOf2, FloorLogBase2, Parity, Pow, PowerOf2, RoundToMultipleOfPowerOf2Assign,
+    Square,
 };
 use malachite_base::num::basic::floats::PrimitiveFloat;
 use malachite_base::num::basic::integers::PrimitiveInt;
@@ -472,6 +473,18 @@ pub fn random_integer_triple_gen_var_3(config: &GenConfig) -> It<(Integer, Integ
     )
 }
 
+// -- (Integer, Integer, Integer, Integer) --
+
+pub fn random_integer_quadruple_gen(
+    config: &GenConfig,
+) -> It<(Integer, Integer, Integer, Integer)> {
+    Box::new(random_quadruples_from_single(random_integers(
+        EXAMPLE_SEED,
+        config.get_or("mean_bits_n", 64),
+        config.get_or("mean_bits_d", 1),
+    )))
+}
+
 // -- (Integer, Integer, Integer, PrimitiveUnsigned) --
 
 pub fn random_integer_integer_integer_unsigned_quadruple_gen_var_1<T: PrimitiveUnsigned>(
@@ -1968,6 +1981,51 @@ pub fn random_natural_pair_gen_var_15(config: &GenConfig) -> It<(Natural, Natura
     ))
 }
 
+// Powers of small primes, squares of primes between $2^{32}$ and $2^{34}$, and products of a small
+// prime power and two such primes. All are greater than $2^{64}$.
+fn random_large_moduli(seed: Seed) -> It<Natural> {
+    let mut large_primes = random_primes_in_range(
+        seed.fork("large"),
+        Natural::power_of_2(32),
+        Natural::power_of_2(34),
+    );
+    Box::new(
+        random_unsigneds_less_than(seed.fork("kind"), 3u8)
+            .zip(random_primes_in_range(
+                seed.fork("small"),
+                Natural::TWO,
+                Natural::from(100u32),
+            ))
+            .zip(geometric_random_unsigneds::<u64>(seed.fork("extra"), 1, 1))
+            .map(move |((kind, p), extra)| {
+                let q = large_primes.next().unwrap();
+                match kind {
+                    0 => {
+                        let e = 64 / p.floor_log_base_2() + 1 + extra;
+                        p.pow(e)
+                    }
+                    1 => q.square(),
+                    _ => p.pow(extra + 1) * q * large_primes.next().unwrap(),
+                }
+            }),
+    )
+}
+
+pub fn random_natural_pair_gen_var_16(config: &GenConfig) -> It<(Natural, Natural)> {
+    Box::new(random_pairs(
+        EXAMPLE_SEED,
+        &|seed| {
+            geometric_random_unsigneds::<u64>(
+                seed,
+                config.get_or("mean_small_n", 32),
+                config.get_or("mean_small_d", 1),
+            )
+            .map(Natural::from)
+        },
+        &random_large_moduli,
+    ))
+}
+
 // -- (Natural, Natural, bool) --
 
 pub fn random_natural_natural_bool_triple_gen_var_1(
@@ -2115,6 +2173,24 @@ pub fn random_natural_triple_gen_var_9(config: &GenConfig) -> It<(Natural, Natur
     )
 }
 
+pub fn random_natural_triple_gen_var_10(config: &GenConfig) -> It<(Natural, Natural, Natural)> {
+    Box::new(
+        random_triples_xxy(
+            EXAMPLE_SEED,
+            &|seed| {
+                geometric_random_unsigneds::<u64>(
+                    seed,
+                    config.get_or("mean_small_n", 32),
+                    config.get_or("mean_small_d", 1),
+                )
+                .map(Natural::from)
+            },
+            &random_large_moduli,
+        )
+        .map(|(n, k, m)| (n + &k, k, m)),
+    )
+}
+
 // -- (Natural, Natural, Natural, Natural) --
 
 pub fn random_natural_quadruple_gen_var_1(
@@ -549,6 +549,20 @@ pub fn special_random_integer_triple_gen_var_3(
     )
 }
 
+// -- (Integer, Integer, Integer, Integer) --
+
+pub fn special_random_integer_quadruple_gen(
+    config: &GenConfig,
+) -> It<(Integer, Integer, Integer, Integer)> {
+    Box::new(random_quadruples_from_single(striped_random_integers(
+        EXAMPLE_SEED,
+        config.get_or("mean_stripe_n", 32),
+        config.get_or("mean_stripe_d", 1),
+        config.get_or("mean_bits_n", 64),
+        config.get_or("mean_bits_d", 1),
+    )))
+}
+
 // -- (Integer, Integer, Integer, PrimitiveUnsigned) --
 
 pub fn special_random_integer_integer_integer_unsigned_quadruple_gen_var_1<T: PrimitiveUnsigned>(
@@ -0,0 +1,21 @@
+use crate::integer::Integer;
+use crate::natural::Natural;
+use malachite_base::num::arithmetic::traits::{BinomialCoefficient, Parity};
+use malachite_base::num::basic::traits::{One, Zero};
+
+// Uses the recurrence $\sum_{k=0}^{m/2} \binom{m}{2k} E_{2k} = 0$, which holds for even $m > 0$.
+pub fn euler_number_naive(n: u64) -> Integer {
+    if n.odd() {
+        return Integer::ZERO;
+    }
+    let mut es = vec![Integer::ONE];
+    for m in (2..=n).step_by(2) {
+        let m_natural = Natural::from(m);
+        let mut sum = Integer::ZERO;
+        for (k, e) in (0u64..).step_by(2).zip(es.iter()) {
+            sum += Integer::from(Natural::binomial_coefficient(&m_natural, &Natural::from(k))) * e;
+        }
+        es.push(-sum);
+    }
+    es.pop().unwrap()
+}
@@ -1,4 +1,5 @@
 pub mod add;
 pub mod divisible_by;
+pub mod euler_number;
 pub mod mul;
 pub mod sign;
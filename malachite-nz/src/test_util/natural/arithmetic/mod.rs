@@ -20,6 +20,7 @@ pub mod mod_power_of_2_pow;
 pub mod mod_power_of_2_square;
 pub mod mul;
 pub mod neg;
+pub mod partition_count;
 pub mod pow;
 pub mod primorial;
 pub mod root;
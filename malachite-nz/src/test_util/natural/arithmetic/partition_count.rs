@@ -0,0 +1,29 @@
+use crate::natural::Natural;
+use malachite_base::num::arithmetic::traits::Parity;
+use malachite_base::num::basic::traits::{One, Zero};
+use malachite_base::num::conversion::traits::ExactFrom;
+
+pub fn partition_counts_naive(n: u64) -> Vec<Natural> {
+    let mut ps = vec![Natural::ONE];
+    for i in 1..=n {
+        let mut p = Natural::ZERO;
+        let mut negative = Natural::ZERO;
+        for k in 1.. {
+            let g = (k * (3 * k - 1)) >> 1;
+            if g > i {
+                break;
+            }
+            let sum = if k.odd() { &mut p } else { &mut negative };
+            *sum += &ps[usize::exact_from(i - g)];
+            if g + k <= i {
+                *sum += &ps[usize::exact_from(i - g - k)];
+            }
+        }
+        ps.push(p - negative);
+    }
+    ps
+}
+
+pub fn partition_count_naive(n: u64) -> Natural {
+    partition_counts_naive(n).pop().unwrap()
+}
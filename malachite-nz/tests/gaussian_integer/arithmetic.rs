@@ -0,0 +1,148 @@
+use malachite_base::num::arithmetic::traits::Square;
+use malachite_base::num::basic::traits::{One, Zero};
+use malachite_nz::gaussian_integer::GaussianInteger;
+use malachite_nz::integer::Integer;
+use malachite_nz::natural::Natural;
+use malachite_nz::test_util::generators::{
+    integer_pair_gen, integer_quadruple_gen, integer_triple_gen,
+};
+
+fn gaussian(a: i32, b: i32) -> GaussianInteger {
+    GaussianInteger::new(Integer::from(a), Integer::from(b))
+}
+
+#[test]
+fn test_add_sub_mul() {
+    let test = |x: GaussianInteger, y: GaussianInteger, sum, difference, product| {
+        assert_eq!((&x + &y).to_string(), sum);
+        assert_eq!((x.clone() + &y).to_string(), sum);
+        assert_eq!((&x + y.clone()).to_string(), sum);
+        assert_eq!((x.clone() + y.clone()).to_string(), sum);
+
+        assert_eq!((&x - &y).to_string(), difference);
+        assert_eq!((x.clone() - &y).to_string(), difference);
+        assert_eq!((&x - y.clone()).to_string(), difference);
+        assert_eq!((x.clone() - y.clone()).to_string(), difference);
+
+        assert_eq!((&x * &y).to_string(), product);
+        assert_eq!((x.clone() * &y).to_string(), product);
+        assert_eq!((&x * y.clone()).to_string(), product);
+        assert_eq!((x * y).to_string(), product);
+    };
+    test(gaussian(0, 0), gaussian(0, 0), "0", "0", "0");
+    test(gaussian(1, 2), gaussian(0, 0), "1+2i", "1+2i", "0");
+    test(gaussian(1, 2), gaussian(1, 0), "2+2i", "2i", "1+2i");
+    test(gaussian(0, 1), gaussian(0, 1), "2i", "0", "-1");
+    test(gaussian(1, 2), gaussian(3, -5), "4-3i", "-2+7i", "13+i");
+    test(gaussian(3, 4), gaussian(3, -4), "6", "8i", "25");
+    test(
+        gaussian(-7, 11),
+        gaussian(-7, 11),
+        "-14+22i",
+        "0",
+        "-72-154i",
+    );
+}
+
+#[test]
+fn test_neg_conjugate_norm() {
+    let test = |a, b, negative, conjugate, norm: u32| {
+        let x = gaussian(a, b);
+        assert_eq!((-&x).to_string(), negative);
+        assert_eq!((-x.clone()).to_string(), negative);
+        assert_eq!(x.conjugate().to_string(), conjugate);
+        assert_eq!(x.norm(), norm);
+    };
+    test(0, 0, "0", "0", 0);
+    test(1, 0, "-1", "1", 1);
+    test(0, 1, "-i", "-i", 1);
+    test(3, -4, "-3+4i", "3+4i", 25);
+    test(-2, -7, "2+7i", "-2+7i", 53);
+}
+
+#[test]
+fn add_sub_properties() {
+    integer_quadruple_gen().test_properties(|(a, b, c, d)| {
+        let x = GaussianInteger::new(a, b);
+        let y = GaussianInteger::new(c, d);
+        let sum = &x + &y;
+        assert_eq!(x.clone() + y.clone(), sum);
+        assert_eq!(x.clone() + &y, sum);
+        assert_eq!(&x + y.clone(), sum);
+        assert_eq!(&y + &x, sum);
+        assert_eq!(&sum - &y, x);
+        assert_eq!(*sum.real(), x.real() + y.real());
+        assert_eq!(*sum.imag(), x.imag() + y.imag());
+
+        let difference = &x - &y;
+        assert_eq!(x.clone() - y.clone(), difference);
+        assert_eq!(x.clone() - &y, difference);
+        assert_eq!(&x - y.clone(), difference);
+        assert_eq!(-(&y - &x), difference);
+        assert_eq!(&difference + &y, x);
+    });
+
+    integer_pair_gen().test_properties(|(a, b)| {
+        let x = GaussianInteger::new(a, b);
+        assert_eq!(&x + GaussianInteger::ZERO, x);
+        assert_eq!(&x - GaussianInteger::ZERO, x);
+        assert_eq!(&x - &x, GaussianInteger::ZERO);
+        assert_eq!(-(-&x), x);
+        assert_eq!(&x + -&x, GaussianInteger::ZERO);
+    });
+}
+
+#[test]
+fn mul_properties() {
+    integer_quadruple_gen().test_properties(|(a, b, c, d)| {
+        let x = GaussianInteger::new(a, b);
+        let y = GaussianInteger::new(c, d);
+        let product = &x * &y;
+        assert_eq!(x.clone() * y.clone(), product);
+        assert_eq!(x.clone() * &y, product);
+        assert_eq!(&x * y.clone(), product);
+        assert_eq!(&y * &x, product);
+        assert_eq!(*product.real(), x.real() * y.real() - x.imag() * y.imag());
+        assert_eq!(*product.imag(), x.real() * y.imag() + x.imag() * y.real());
+        assert_eq!(product.norm(), x.norm() * y.norm());
+        assert_eq!(product.conjugate(), x.conjugate() * y.conjugate());
+        assert_eq!(-&x * &y, -&product);
+    });
+
+    integer_triple_gen().test_properties(|(a, b, c)| {
+        let x = GaussianInteger::new(a, b);
+        let y = GaussianInteger::from(c);
+        let z = &x + &y;
+        assert_eq!(&x * (&y + &z), &x * &y + &x * &z);
+    });
+
+    integer_pair_gen().test_properties(|(a, b)| {
+        let x = GaussianInteger::new(a, b);
+        assert_eq!(&x * GaussianInteger::ONE, x);
+        assert_eq!(&x * GaussianInteger::ZERO, GaussianInteger::ZERO);
+        assert_eq!(&x * GaussianInteger::I * GaussianInteger::I, -&x);
+        assert_eq!(
+            &x * x.conjugate(),
+            GaussianInteger::from(Integer::from(x.norm()))
+        );
+    });
+}
+
+#[test]
+fn conjugate_and_norm_properties() {
+    integer_pair_gen().test_properties(|(a, b)| {
+        let x = GaussianInteger::new(a, b);
+        let conjugate = x.conjugate();
+        assert_eq!(conjugate.conjugate(), x);
+        assert_eq!(conjugate.norm(), x.norm());
+        assert_eq!(conjugate.real(), x.real());
+        assert_eq!(*conjugate.imag(), -x.imag());
+        let norm = x.norm();
+        assert_eq!(
+            norm,
+            x.real().unsigned_abs_ref().square() + x.imag().unsigned_abs_ref().square()
+        );
+        assert_eq!(norm == 0u32, x == GaussianInteger::ZERO);
+        assert_eq!(norm == Natural::ONE, x.is_unit());
+    });
+}
@@ -0,0 +1,108 @@
+use malachite_base::num::arithmetic::traits::Abs;
+use malachite_base::num::basic::traits::{One, Zero};
+use malachite_base::strings::ToDebugString;
+use malachite_nz::gaussian_integer::GaussianInteger;
+use malachite_nz::integer::Integer;
+use malachite_nz::test_util::generators::{integer_gen, integer_pair_gen};
+
+fn gaussian(a: i32, b: i32) -> GaussianInteger {
+    GaussianInteger::new(Integer::from(a), Integer::from(b))
+}
+
+#[test]
+fn test_to_string() {
+    let test = |a, b, out| {
+        let x = gaussian(a, b);
+        assert_eq!(x.to_string(), out);
+        assert_eq!(x.to_debug_string(), out);
+    };
+    test(0, 0, "0");
+    test(1, 0, "1");
+    test(-5, 0, "-5");
+    test(0, 1, "i");
+    test(0, -1, "-i");
+    test(0, 3, "3i");
+    test(0, -3, "-3i");
+    test(1, 1, "1+i");
+    test(1, -1, "1-i");
+    test(-2, 1, "-2+i");
+    test(3, 4, "3+4i");
+    test(-3, -4, "-3-4i");
+}
+
+#[test]
+fn test_constants() {
+    assert_eq!(GaussianInteger::ZERO, gaussian(0, 0));
+    assert_eq!(GaussianInteger::ONE, gaussian(1, 0));
+    assert_eq!(GaussianInteger::I, gaussian(0, 1));
+}
+
+#[test]
+fn test_units() {
+    assert_eq!(GaussianInteger::units().to_debug_string(), "[1, i, -1, -i]");
+    for u in GaussianInteger::units() {
+        assert!(u.is_unit());
+        assert_eq!(u.norm(), 1u32);
+        assert_eq!(u.normalize(), GaussianInteger::ONE);
+    }
+    assert!(!GaussianInteger::ZERO.is_unit());
+    assert!(!gaussian(1, 1).is_unit());
+    assert!(!gaussian(2, 0).is_unit());
+}
+
+#[test]
+fn test_normalize() {
+    let test = |a, b, normalized, unit| {
+        let x = gaussian(a, b);
+        assert_eq!(x.normalize().to_string(), normalized);
+        assert_eq!(x.unit_part().to_string(), unit);
+    };
+    test(0, 0, "0", "1");
+    test(3, 0, "3", "1");
+    test(0, 3, "3", "i");
+    test(-3, 0, "3", "-1");
+    test(0, -3, "3", "-i");
+    test(2, 5, "2+5i", "1");
+    test(-5, 2, "2+5i", "i");
+    test(-2, -5, "2+5i", "-1");
+    test(5, -2, "2+5i", "-i");
+}
+
+#[test]
+fn test_associates() {
+    assert_eq!(
+        gaussian(2, 1).associates().to_debug_string(),
+        "[2+i, -1+2i, -2-i, 1-2i]"
+    );
+    assert_eq!(
+        GaussianInteger::ZERO.associates().to_debug_string(),
+        "[0, 0, 0, 0]"
+    );
+}
+
+#[test]
+fn normalize_properties() {
+    integer_pair_gen().test_properties(|(a, b)| {
+        let x = GaussianInteger::new(a, b);
+        let normalized = x.normalize();
+        let unit = x.unit_part();
+        assert!(unit.is_unit());
+        assert_eq!(&unit * &normalized, x);
+        assert_eq!(normalized.normalize(), normalized);
+        assert_eq!(normalized.norm(), x.norm());
+        if x != GaussianInteger::ZERO {
+            assert!(*normalized.real() > 0u32);
+            assert!(*normalized.imag() >= 0u32);
+        }
+        for y in x.associates() {
+            assert_eq!(y.normalize(), normalized);
+        }
+    });
+
+    integer_gen().test_properties(|x| {
+        let y = GaussianInteger::from(&x);
+        assert_eq!(GaussianInteger::from(x.clone()), y);
+        assert_eq!(y.normalize(), GaussianInteger::from((&x).abs()));
+        assert_eq!(y.into_parts(), (x, Integer::ZERO));
+    });
+}
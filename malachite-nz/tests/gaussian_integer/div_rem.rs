@@ -0,0 +1,77 @@
+use malachite_base::num::arithmetic::traits::DivRem;
+use malachite_base::num::basic::traits::{One, Zero};
+use malachite_base::strings::ToDebugString;
+use malachite_nz::gaussian_integer::GaussianInteger;
+use malachite_nz::integer::Integer;
+use malachite_nz::test_util::generators::{integer_pair_gen, integer_quadruple_gen};
+
+fn gaussian(a: i32, b: i32) -> GaussianInteger {
+    GaussianInteger::new(Integer::from(a), Integer::from(b))
+}
+
+#[test]
+fn test_div_rem() {
+    let test = |x: GaussianInteger, y: GaussianInteger, out| {
+        assert_eq!((&x).div_rem(&y).to_debug_string(), out);
+        assert_eq!((&x).div_rem(y.clone()).to_debug_string(), out);
+        assert_eq!(x.clone().div_rem(&y).to_debug_string(), out);
+        assert_eq!(x.div_rem(y).to_debug_string(), out);
+    };
+    test(gaussian(0, 0), gaussian(1, 0), "(0, 0)");
+    test(gaussian(0, 0), gaussian(3, 4), "(0, 0)");
+    test(gaussian(7, 0), gaussian(2, 0), "(4, -1)");
+    test(gaussian(5, 0), gaussian(2, 0), "(2, 1)");
+    test(gaussian(3, 4), gaussian(0, 1), "(4-3i, 0)");
+    test(gaussian(25, 0), gaussian(3, 4), "(3-4i, 0)");
+    test(gaussian(27, -23), gaussian(8, 1), "(3-3i, -2i)");
+    test(gaussian(11, 3), gaussian(1, 8), "(1-i, 2-4i)");
+    test(gaussian(-100, 57), gaussian(-3, 7), "(12+9i, -1)");
+}
+
+#[test]
+#[should_panic]
+fn div_rem_fail() {
+    gaussian(1, 2).div_rem(GaussianInteger::ZERO);
+}
+
+#[test]
+fn div_rem_properties() {
+    integer_quadruple_gen().test_properties(|(a, b, c, d)| {
+        let x = GaussianInteger::new(a, b);
+        let y = GaussianInteger::new(c, d);
+        if y == GaussianInteger::ZERO {
+            return;
+        }
+        let (q, r) = (&x).div_rem(&y);
+        assert_eq!((&x).div_rem(y.clone()), (q.clone(), r.clone()));
+        assert_eq!(x.clone().div_rem(&y), (q.clone(), r.clone()));
+        assert_eq!(x.clone().div_rem(y.clone()), (q.clone(), r.clone()));
+        assert_eq!(&q * &y + &r, x);
+        assert!(r.norm() << 1 <= y.norm());
+        let (q_alt, r_alt) = (-&x).div_rem(-&y);
+        assert_eq!(&q_alt * -&y + &r_alt, -&x);
+        assert_eq!((&x * &y).div_rem(&y), (x, GaussianInteger::ZERO));
+    });
+
+    integer_pair_gen().test_properties(|(a, b)| {
+        let x = GaussianInteger::new(a, b);
+        assert_eq!(
+            (&x).div_rem(GaussianInteger::ONE),
+            (x.clone(), GaussianInteger::ZERO)
+        );
+        assert_eq!(
+            (&x).div_rem(GaussianInteger::I),
+            (&x * -GaussianInteger::I, GaussianInteger::ZERO)
+        );
+        if x != GaussianInteger::ZERO {
+            assert_eq!(
+                (&x).div_rem(&x),
+                (GaussianInteger::ONE, GaussianInteger::ZERO)
+            );
+            assert_eq!(
+                GaussianInteger::ZERO.div_rem(&x),
+                (GaussianInteger::ZERO, GaussianInteger::ZERO)
+            );
+        }
+    });
+}
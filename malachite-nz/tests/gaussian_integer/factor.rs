@@ -0,0 +1,85 @@
+use malachite_base::num::arithmetic::traits::DivRem;
+use malachite_base::num::basic::traits::{One, Zero};
+use malachite_base::num::factorization::traits::{Factor, IsPrime};
+use malachite_base::num::logic::traits::BitAccess;
+use malachite_base::strings::ToDebugString;
+use malachite_base::test_util::generators::signed_pair_gen;
+use malachite_nz::gaussian_integer::GaussianInteger;
+use malachite_nz::integer::Integer;
+
+fn gaussian(a: i32, b: i32) -> GaussianInteger {
+    GaussianInteger::new(Integer::from(a), Integer::from(b))
+}
+
+fn is_gaussian_prime(x: &GaussianInteger) -> bool {
+    if *x.imag() == 0u32 {
+        let p = x.real().unsigned_abs_ref();
+        p.is_prime() && p.get_bit(1)
+    } else if *x.real() == 0u32 {
+        let p = x.imag().unsigned_abs_ref();
+        p.is_prime() && p.get_bit(1)
+    } else {
+        x.norm().is_prime()
+    }
+}
+
+#[test]
+fn test_factor() {
+    let test = |x: GaussianInteger, out| {
+        assert_eq!(x.factor().to_debug_string(), out);
+    };
+    test(gaussian(1, 0), "[]");
+    test(gaussian(0, -1), "[]");
+    test(gaussian(2, 0), "[(1+i, 2)]");
+    test(gaussian(1, 1), "[(1+i, 1)]");
+    test(gaussian(3, 0), "[(3, 1)]");
+    test(gaussian(0, 9), "[(3, 2)]");
+    test(gaussian(5, 0), "[(1+2i, 1), (2+i, 1)]");
+    test(gaussian(3, -4), "[(1+2i, 2)]");
+    test(gaussian(3, 4), "[(2+i, 2)]");
+    test(gaussian(10, 0), "[(1+i, 2), (1+2i, 1), (2+i, 1)]");
+    test(gaussian(-21, 0), "[(3, 1), (7, 1)]");
+    test(gaussian(11, 3), "[(1+i, 1), (2+i, 1), (3+2i, 1)]");
+    test(
+        gaussian(0, 65),
+        "[(1+2i, 1), (2+i, 1), (2+3i, 1), (3+2i, 1)]",
+    );
+    test(gaussian(1000, 1), "[(1+10i, 1), (99+10i, 1)]");
+}
+
+#[test]
+#[should_panic]
+fn factor_fail() {
+    GaussianInteger::ZERO.factor();
+}
+
+#[test]
+fn factor_properties() {
+    signed_pair_gen::<i32>().test_properties(|(a, b)| {
+        let x = GaussianInteger::new(Integer::from(a), Integer::from(b));
+        if x == GaussianInteger::ZERO {
+            return;
+        }
+        let factors = x.factor();
+        let mut product = GaussianInteger::ONE;
+        for (p, e) in &factors {
+            assert_ne!(*e, 0);
+            assert!(is_gaussian_prime(p));
+            assert_eq!(p.normalize(), *p);
+            for _ in 0..*e {
+                product = product * p;
+            }
+        }
+        let (unit, remainder) = (&x).div_rem(&product);
+        assert_eq!(remainder, GaussianInteger::ZERO);
+        assert!(unit.is_unit());
+        assert_eq!(product.norm(), x.norm());
+        assert_eq!(factors.is_empty(), x.is_unit());
+        for pair in factors.windows(2) {
+            let (p, q) = (&pair[0].0, &pair[1].0);
+            assert!(p.norm() < q.norm() || p.norm() == q.norm() && p.real() < q.real());
+        }
+        assert_eq!((-&x).factor(), factors);
+        assert_eq!((&x * GaussianInteger::I).factor(), factors);
+    });
+}
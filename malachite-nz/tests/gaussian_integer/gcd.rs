@@ -0,0 +1,106 @@
+use malachite_base::num::arithmetic::traits::{DivRem, ExtendedGcd, Gcd};
+use malachite_base::num::basic::traits::Zero;
+use malachite_base::strings::ToDebugString;
+use malachite_nz::gaussian_integer::GaussianInteger;
+use malachite_nz::integer::Integer;
+use malachite_nz::test_util::generators::{integer_pair_gen, integer_quadruple_gen};
+
+fn gaussian(a: i32, b: i32) -> GaussianInteger {
+    GaussianInteger::new(Integer::from(a), Integer::from(b))
+}
+
+fn divides(x: &GaussianInteger, y: &GaussianInteger) -> bool {
+    if *x == GaussianInteger::ZERO {
+        *y == GaussianInteger::ZERO
+    } else {
+        y.div_rem(x).1 == GaussianInteger::ZERO
+    }
+}
+
+#[test]
+fn test_gcd() {
+    let test = |x: GaussianInteger, y: GaussianInteger, out| {
+        assert_eq!((&x).gcd(&y).to_string(), out);
+        assert_eq!((&x).gcd(y.clone()).to_string(), out);
+        assert_eq!(x.clone().gcd(&y).to_string(), out);
+        assert_eq!(x.gcd(y).to_string(), out);
+    };
+    test(gaussian(0, 0), gaussian(0, 0), "0");
+    test(gaussian(0, 0), gaussian(3, 4), "3+4i");
+    test(gaussian(-3, -4), gaussian(0, 0), "3+4i");
+    test(gaussian(1, 0), gaussian(3, 4), "1");
+    test(gaussian(5, 0), gaussian(3, 4), "2+i");
+    test(gaussian(11, 3), gaussian(1, 8), "2+i");
+    test(gaussian(4, 0), gaussian(0, 6), "2");
+    test(gaussian(12, 0), gaussian(0, 18), "6");
+}
+
+#[test]
+fn test_extended_gcd() {
+    let test = |x: GaussianInteger, y: GaussianInteger, out| {
+        let result = (&x).extended_gcd(&y);
+        assert_eq!(result.to_debug_string(), out);
+        assert_eq!((&x).extended_gcd(y.clone()), result);
+        assert_eq!(x.clone().extended_gcd(&y), result);
+        assert_eq!(x.clone().extended_gcd(y.clone()), result);
+        let (gcd, s, t) = result;
+        assert_eq!(s * x + t * y, gcd);
+    };
+    test(gaussian(0, 0), gaussian(0, 0), "(0, 0, 0)");
+    test(gaussian(0, 0), gaussian(3, 4), "(3+4i, 0, 1)");
+    test(gaussian(3, 4), gaussian(0, 0), "(3+4i, 1, 0)");
+    test(gaussian(5, 0), gaussian(3, 4), "(2+i, -1, 1-i)");
+    test(gaussian(11, 3), gaussian(1, 8), "(2+i, 1+2i, -3)");
+    test(gaussian(4, 0), gaussian(0, 6), "(2, -1, -i)");
+}
+
+#[test]
+fn gcd_properties() {
+    integer_quadruple_gen().test_properties(|(a, b, c, d)| {
+        let x = GaussianInteger::new(a, b);
+        let y = GaussianInteger::new(c, d);
+        let gcd = (&x).gcd(&y);
+        assert_eq!((&x).gcd(y.clone()), gcd);
+        assert_eq!(x.clone().gcd(&y), gcd);
+        assert_eq!(x.clone().gcd(y.clone()), gcd);
+        assert_eq!((&y).gcd(&x), gcd);
+        assert_eq!(gcd.normalize(), gcd);
+        assert_eq!(
+            x.conjugate().gcd(y.conjugate()),
+            gcd.conjugate().normalize()
+        );
+        assert_eq!((-&x).gcd(&y * GaussianInteger::I), gcd);
+        assert!(divides(&gcd, &x));
+        assert!(divides(&gcd, &y));
+        assert_eq!(
+            gcd == GaussianInteger::ZERO,
+            x == GaussianInteger::ZERO && y == GaussianInteger::ZERO
+        );
+
+        let (extended_gcd, s, t) = (&x).extended_gcd(&y);
+        assert_eq!(extended_gcd, gcd);
+        assert_eq!(
+            (&x).extended_gcd(y.clone()),
+            (gcd.clone(), s.clone(), t.clone())
+        );
+        assert_eq!(
+            x.clone().extended_gcd(&y),
+            (gcd.clone(), s.clone(), t.clone())
+        );
+        assert_eq!(
+            x.clone().extended_gcd(y.clone()),
+            (gcd.clone(), s.clone(), t.clone())
+        );
+        assert_eq!(s * &x + t * &y, gcd);
+    });
+
+    integer_pair_gen().test_properties(|(a, b)| {
+        let x = GaussianInteger::new(a, b);
+        assert_eq!((&x).gcd(&x), x.normalize());
+        assert_eq!((&x).gcd(GaussianInteger::ZERO), x.normalize());
+        assert_eq!(
+            (&x).gcd(GaussianInteger::from(Integer::from(x.norm()))),
+            x.normalize()
+        );
+    });
+}
@@ -0,0 +1,49 @@
+use malachite_base::num::arithmetic::traits::CheckedSub;
+use malachite_base::strings::ToDebugString;
+use malachite_base::test_util::generators::signed_pair_gen;
+use malachite_nz::integer::Integer;
+use malachite_nz::test_util::generators::integer_pair_gen;
+use std::str::FromStr;
+
+#[test]
+fn test_checked_sub() {
+    let test = |s, t, out| {
+        let x = Integer::from_str(s).unwrap();
+        let y = Integer::from_str(t).unwrap();
+
+        assert_eq!(x.clone().checked_sub(y.clone()).to_debug_string(), out);
+        assert_eq!(x.clone().checked_sub(&y).to_debug_string(), out);
+        assert_eq!((&x).checked_sub(y.clone()).to_debug_string(), out);
+        assert_eq!((&x).checked_sub(&y).to_debug_string(), out);
+    };
+    test("0", "0", "Some(0)");
+    test("3", "10", "Some(-7)");
+    test("-5", "-8", "Some(3)");
+    test(
+        "-1000000000000000000000",
+        "1000000000000000000000",
+        "Some(-2000000000000000000000)",
+    );
+}
+
+#[test]
+fn checked_sub_properties() {
+    integer_pair_gen().test_properties(|(x, y)| {
+        let difference = (&x).checked_sub(&y).unwrap();
+        assert!(difference.is_valid());
+        assert_eq!(x.clone().checked_sub(y.clone()), Some(difference.clone()));
+        assert_eq!((&x).checked_sub(y.clone()), Some(difference.clone()));
+        assert_eq!(x.clone().checked_sub(&y), Some(difference.clone()));
+
+        assert_eq!(&x - &y, difference);
+        assert_eq!((&y).checked_sub(&x), Some(-&difference));
+        assert_eq!(difference + y, x);
+    });
+
+    signed_pair_gen::<i32>().test_properties(|(x, y)| {
+        assert_eq!(
+            Integer::from(x).checked_sub(Integer::from(y)),
+            Some(Integer::from(i64::from(x) - i64::from(y)))
+        );
+    });
+}
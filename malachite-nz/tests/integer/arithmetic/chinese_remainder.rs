@@ -0,0 +1,64 @@
+use malachite_base::num::arithmetic::traits::{ChineseRemainder, Mod, UnsignedAbs};
+use malachite_base::strings::ToDebugString;
+use malachite_base::test_util::generators::signed_quadruple_gen;
+use malachite_nz::integer::Integer;
+use malachite_nz::natural::Natural;
+use std::panic::catch_unwind;
+use std::str::FromStr;
+
+fn residues(xs: &[(&str, &str)]) -> Vec<(Integer, Natural)> {
+    xs.iter()
+        .map(|&(a, m)| (Integer::from_str(a).unwrap(), Natural::from_str(m).unwrap()))
+        .collect()
+}
+
+#[test]
+fn test_chinese_remainder() {
+    let test = |xs: &[(&str, &str)], out: &str| {
+        assert_eq!(
+            Integer::chinese_remainder(&residues(xs)).to_debug_string(),
+            out
+        );
+    };
+    test(&[], "Some((0, 1))");
+    test(&[("-5", "7")], "Some((2, 7))");
+    test(&[("-1", "3"), ("-1", "5")], "Some((14, 15))");
+    test(&[("2", "3"), ("-2", "5"), ("-5", "7")], "Some((23, 105))");
+    test(&[("-2", "4"), ("4", "6")], "Some((10, 12))");
+    test(&[("1", "4"), ("-4", "6")], "None");
+    test(
+        &[("-1", "18446744073709551616"), ("-1", "18446744073709551617")],
+        "Some((340282366920938463481821351505477763071, \
+        340282366920938463481821351505477763072))",
+    );
+}
+
+#[test]
+fn chinese_remainder_fail() {
+    assert_panic!(Integer::chinese_remainder(&residues(&[("-1", "0")])));
+}
+
+#[test]
+fn chinese_remainder_properties() {
+    signed_quadruple_gen::<i32>().test_properties(|(a, b, m, n)| {
+        let m = Natural::from(m.unsigned_abs());
+        let n = Natural::from(n.unsigned_abs());
+        if m == 0u32 || n == 0u32 {
+            return;
+        }
+        let a = Integer::from(a);
+        let b = Integer::from(b);
+        let result = Integer::chinese_remainder(&[(a.clone(), m.clone()), (b.clone(), n.clone())]);
+        let a_mod = (&a).mod_op(Integer::from(&m)).unsigned_abs();
+        let b_mod = (&b).mod_op(Integer::from(&n)).unsigned_abs();
+        assert_eq!(
+            Natural::chinese_remainder(&[(a_mod, m.clone()), (b_mod, n.clone())])
+                .map(|(x, l)| (Integer::from(x), l)),
+            result
+        );
+        if let Some((x, _)) = result {
+            assert_eq!((&x).mod_op(Integer::from(&m)), a.mod_op(Integer::from(m)));
+            assert_eq!((&x).mod_op(Integer::from(&n)), b.mod_op(Integer::from(n)));
+        }
+    });
+}
@@ -0,0 +1,49 @@
+use malachite_base::num::arithmetic::traits::{CoprimeWith, Gcd};
+use malachite_base::test_util::generators::signed_pair_gen;
+use malachite_nz::integer::Integer;
+use malachite_nz::test_util::generators::integer_pair_gen;
+use std::str::FromStr;
+
+#[test]
+fn test_coprime_with() {
+    let test = |s, t, out| {
+        let x = Integer::from_str(s).unwrap();
+        let y = Integer::from_str(t).unwrap();
+
+        assert_eq!(x.clone().coprime_with(y.clone()), out);
+        assert_eq!(x.clone().coprime_with(&y), out);
+        assert_eq!((&x).coprime_with(y.clone()), out);
+        assert_eq!((&x).coprime_with(&y), out);
+    };
+    test("0", "0", false);
+    test("0", "1", true);
+    test("-1", "0", true);
+    test("0", "-5", false);
+    test("-3", "5", true);
+    test("12", "-90", false);
+    test("-12", "-35", true);
+    test("-1000000000000000000000", "3541774862152233910272", false);
+    test("-1000000000000000000001", "3541774862152233910272", true);
+}
+
+#[test]
+fn coprime_with_properties() {
+    integer_pair_gen().test_properties(|(x, y)| {
+        let coprime = (&x).coprime_with(&y);
+        assert_eq!(x.clone().coprime_with(y.clone()), coprime);
+        assert_eq!((&x).coprime_with(y.clone()), coprime);
+        assert_eq!(x.clone().coprime_with(&y), coprime);
+
+        assert_eq!((&x).gcd(&y) == 1u32, coprime);
+        assert_eq!((&y).coprime_with(&x), coprime);
+        assert_eq!((-&x).coprime_with(&y), coprime);
+        assert_eq!((&x).coprime_with(-&y), coprime);
+    });
+
+    signed_pair_gen::<i32>().test_properties(|(x, y)| {
+        assert_eq!(
+            Integer::from(x).coprime_with(Integer::from(y)),
+            x.unsigned_abs().coprime_with(y.unsigned_abs())
+        );
+    });
+}
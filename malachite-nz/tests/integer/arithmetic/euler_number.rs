@@ -0,0 +1,61 @@
+use malachite_base::num::arithmetic::traits::{EulerNumber, Parity};
+use malachite_base::num::basic::traits::Zero;
+use malachite_base::test_util::generators::common::TINY_LIMIT;
+use malachite_base::test_util::generators::{unsigned_gen_var_5, unsigned_gen_var_7};
+use malachite_nz::integer::Integer;
+use malachite_nz::test_util::integer::arithmetic::euler_number::euler_number_naive;
+
+#[test]
+fn test_euler_number() {
+    fn test(n: u64, out: &str) {
+        let e = Integer::euler_number(n);
+        assert!(e.is_valid());
+        assert_eq!(e.to_string(), out);
+    }
+    test(0, "1");
+    test(1, "0");
+    test(2, "-1");
+    test(3, "0");
+    test(4, "5");
+    test(6, "-61");
+    test(8, "1385");
+    test(10, "-50521");
+    test(20, "370371188237525");
+    test(30, "-441543893249023104553682821");
+    test(
+        50,
+        "-6053285248188621896314383785111649088103498225146815121",
+    );
+    test(
+        100,
+        "2903528346661097497054603834764435875077553006646158945080492319146997643370625023889353\
+        447129967354174648294748510553528692457632980625125",
+    );
+}
+
+#[test]
+fn test_euler_numbers() {
+    let es = Integer::euler_numbers().take(101).collect::<Vec<_>>();
+    for (n, e) in es.iter().enumerate() {
+        assert!(e.is_valid());
+        assert_eq!(*e, Integer::euler_number(n as u64));
+    }
+}
+
+#[test]
+fn euler_number_properties() {
+    unsigned_gen_var_5().test_properties_with_limit(TINY_LIMIT, |n| {
+        let e = Integer::euler_number(n);
+        assert!(e.is_valid());
+        if n.odd() {
+            assert_eq!(e, 0);
+        } else {
+            assert!(e.odd());
+            assert_eq!(e > Integer::ZERO, (n >> 1).even());
+        }
+    });
+
+    unsigned_gen_var_7::<u64>().test_properties(|n| {
+        assert_eq!(Integer::euler_number(n), euler_number_naive(n));
+    });
+}
@@ -0,0 +1,65 @@
+use malachite_base::num::arithmetic::traits::{Gcd, GcdAssign};
+use malachite_base::test_util::generators::signed_pair_gen;
+use malachite_nz::integer::Integer;
+use malachite_nz::test_util::generators::integer_pair_gen;
+use std::str::FromStr;
+
+#[test]
+fn test_gcd() {
+    let test = |s, t, out| {
+        let x = Integer::from_str(s).unwrap();
+        let y = Integer::from_str(t).unwrap();
+
+        assert_eq!(x.clone().gcd(y.clone()).to_string(), out);
+        assert_eq!(x.clone().gcd(&y).to_string(), out);
+        assert_eq!((&x).gcd(y.clone()).to_string(), out);
+        assert_eq!((&x).gcd(&y).to_string(), out);
+
+        let mut mut_x = x.clone();
+        mut_x.gcd_assign(y.clone());
+        assert!(mut_x.is_valid());
+        assert_eq!(mut_x.to_string(), out);
+        let mut mut_x = x;
+        mut_x.gcd_assign(&y);
+        assert_eq!(mut_x.to_string(), out);
+    };
+    test("0", "0", "0");
+    test("0", "-5", "5");
+    test("-5", "0", "5");
+    test("-3", "5", "1");
+    test("12", "-90", "6");
+    test("-12", "-90", "6");
+    test(
+        "-1000000000000000000000",
+        "3541774862152233910272",
+        "2097152",
+    );
+}
+
+#[test]
+fn gcd_properties() {
+    integer_pair_gen().test_properties(|(x, y)| {
+        let result = (&x).gcd(&y);
+        assert!(result.is_valid());
+        assert_eq!(x.clone().gcd(y.clone()), result);
+        assert_eq!((&x).gcd(y.clone()), result);
+        assert_eq!(x.clone().gcd(&y), result);
+
+        let mut mut_x = x.clone();
+        mut_x.gcd_assign(&y);
+        assert!(mut_x.is_valid());
+        assert_eq!(mut_x, result);
+
+        assert_eq!(x.unsigned_abs_ref().gcd(y.unsigned_abs_ref()), result);
+        assert_eq!((&y).gcd(&x), result);
+        assert_eq!((-&x).gcd(&y), result);
+        assert_eq!((&x).gcd(-&y), result);
+    });
+
+    signed_pair_gen::<i32>().test_properties(|(x, y)| {
+        assert_eq!(
+            Integer::from(x).gcd(Integer::from(y)),
+            x.unsigned_abs().gcd(y.unsigned_abs())
+        );
+    });
+}
@@ -0,0 +1,66 @@
+use malachite_base::num::arithmetic::traits::{Gcd, Lcm, LcmAssign, UnsignedAbs};
+use malachite_base::test_util::generators::signed_pair_gen;
+use malachite_nz::integer::Integer;
+use malachite_nz::test_util::generators::integer_pair_gen;
+use std::str::FromStr;
+
+#[test]
+fn test_lcm() {
+    let test = |s, t, out| {
+        let x = Integer::from_str(s).unwrap();
+        let y = Integer::from_str(t).unwrap();
+
+        assert_eq!(x.clone().lcm(y.clone()).to_string(), out);
+        assert_eq!(x.clone().lcm(&y).to_string(), out);
+        assert_eq!((&x).lcm(y.clone()).to_string(), out);
+        assert_eq!((&x).lcm(&y).to_string(), out);
+
+        let mut mut_x = x.clone();
+        mut_x.lcm_assign(y.clone());
+        assert!(mut_x.is_valid());
+        assert_eq!(mut_x.to_string(), out);
+        let mut mut_x = x;
+        mut_x.lcm_assign(&y);
+        assert_eq!(mut_x.to_string(), out);
+    };
+    test("0", "0", "0");
+    test("0", "-5", "0");
+    test("-5", "0", "0");
+    test("-3", "5", "15");
+    test("12", "-90", "180");
+    test("-12", "-90", "180");
+    test(
+        "-1000000000000000000000",
+        "3541774862152233910272",
+        "1688849860263936000000000000000000000",
+    );
+}
+
+#[test]
+fn lcm_properties() {
+    integer_pair_gen().test_properties(|(x, y)| {
+        let result = (&x).lcm(&y);
+        assert!(result.is_valid());
+        assert_eq!(x.clone().lcm(y.clone()), result);
+        assert_eq!((&x).lcm(y.clone()), result);
+        assert_eq!(x.clone().lcm(&y), result);
+
+        let mut mut_x = x.clone();
+        mut_x.lcm_assign(&y);
+        assert!(mut_x.is_valid());
+        assert_eq!(mut_x, result);
+
+        assert_eq!(x.unsigned_abs_ref().lcm(y.unsigned_abs_ref()), result);
+        assert_eq!((&y).lcm(&x), result);
+        assert_eq!((-&x).lcm(&y), result);
+        assert_eq!((&x).lcm(-&y), result);
+        assert_eq!((&x).gcd(&y) * result, (x * y).unsigned_abs());
+    });
+
+    signed_pair_gen::<i32>().test_properties(|(x, y)| {
+        assert_eq!(
+            Integer::from(x).lcm(Integer::from(y)),
+            u64::from(x.unsigned_abs()).lcm(u64::from(y.unsigned_abs()))
+        );
+    });
+}
@@ -0,0 +1,66 @@
+use malachite_base::num::arithmetic::traits::{Fibonacci, Lucas, LucasSequence, Pow, Square};
+use malachite_base::num::basic::traits::{NegativeOne, One};
+use malachite_base::test_util::generators::unsigned_gen_var_5;
+use malachite_nz::integer::Integer;
+use malachite_nz::natural::Natural;
+use malachite_nz::test_util::generators::integer_integer_unsigned_triple_gen_var_1;
+use std::str::FromStr;
+
+#[test]
+fn test_lucas_sequence() {
+    let test = |p: &str, q: &str, n: u64, u_out: &str, v_out: &str| {
+        let p = Integer::from_str(p).unwrap();
+        let q = Integer::from_str(q).unwrap();
+        let (u, v) = Integer::lucas_sequence(p.clone(), q.clone(), n);
+        assert!(u.is_valid());
+        assert!(v.is_valid());
+        assert_eq!(u.to_string(), u_out);
+        assert_eq!(v.to_string(), v_out);
+        assert_eq!(Integer::lucas_sequence(&p, &q, n), (u, v));
+    };
+    test("0", "0", 0, "0", "2");
+    test("0", "0", 1, "1", "0");
+    test("0", "0", 2, "0", "0");
+    test("1", "-1", 10, "55", "123");
+    test("3", "1", 10, "6765", "15127");
+    test("2", "-1", 10, "2378", "6726");
+    test("1", "2", 20, "-627", "1201");
+    test("2", "1", 12345, "12345", "2");
+    test("-1", "-1", 11, "89", "-199");
+    test("3", "2", 64, "18446744073709551615", "18446744073709551617");
+    test(
+        "-5",
+        "7",
+        50,
+        "1273582412555111174875",
+        "-1525720710313836035989",
+    );
+}
+
+#[test]
+fn lucas_sequence_properties() {
+    integer_integer_unsigned_triple_gen_var_1::<u64>().test_properties(|(p, q, n)| {
+        let (u, v) = Integer::lucas_sequence(&p, &q, n);
+        assert!(u.is_valid());
+        assert!(v.is_valid());
+        assert_eq!(
+            Integer::lucas_sequence(p.clone(), q.clone(), n),
+            (u.clone(), v.clone())
+        );
+
+        let (u_next, v_next) = Integer::lucas_sequence(&p, &q, n + 1);
+        let (u_next_next, v_next_next) = Integer::lucas_sequence(&p, &q, n + 2);
+        assert_eq!(u_next_next, &p * &u_next - &q * &u);
+        assert_eq!(v_next_next, &p * &v_next - &q * &v);
+
+        // V_n^2 - D U_n^2 = 4Q^n, where D = P^2 - 4Q
+        let d = (&p).square() - (&q << 2);
+        assert_eq!(v.square() - d * u.square(), (&q).pow(n) << 2);
+    });
+
+    unsigned_gen_var_5().test_properties(|n| {
+        let (u, v) = Integer::lucas_sequence(Integer::ONE, Integer::NEGATIVE_ONE, n);
+        assert_eq!(u, Natural::fibonacci(n));
+        assert_eq!(v, Natural::lucas(n));
+    });
+}
@@ -0,0 +1,99 @@
+use malachite_base::num::arithmetic::traits::{Mod, ModAdd, ModAddAssign};
+use malachite_base::test_util::generators::signed_triple_gen;
+use malachite_nz::integer::Integer;
+use malachite_nz::natural::Natural;
+use malachite_nz::test_util::generators::integer_integer_natural_triple_gen;
+use std::panic::catch_unwind;
+use std::str::FromStr;
+
+#[test]
+fn test_mod_add() {
+    let test = |s, t, u, out| {
+        let x = Integer::from_str(s).unwrap();
+        let y = Integer::from_str(t).unwrap();
+        let m = Natural::from_str(u).unwrap();
+
+        assert_eq!(x.clone().mod_add(y.clone(), m.clone()).to_string(), out);
+        assert_eq!(x.clone().mod_add(y.clone(), &m).to_string(), out);
+        assert_eq!(x.clone().mod_add(&y, m.clone()).to_string(), out);
+        assert_eq!(x.clone().mod_add(&y, &m).to_string(), out);
+        assert_eq!((&x).mod_add(y.clone(), m.clone()).to_string(), out);
+        assert_eq!((&x).mod_add(y.clone(), &m).to_string(), out);
+        assert_eq!((&x).mod_add(&y, m.clone()).to_string(), out);
+        assert_eq!((&x).mod_add(&y, &m).to_string(), out);
+
+        let mut mut_x = x.clone();
+        mut_x.mod_add_assign(y.clone(), m.clone());
+        assert!(mut_x.is_valid());
+        assert_eq!(mut_x.to_string(), out);
+        let mut mut_x = x.clone();
+        mut_x.mod_add_assign(y.clone(), &m);
+        assert_eq!(mut_x.to_string(), out);
+        let mut mut_x = x.clone();
+        mut_x.mod_add_assign(&y, m.clone());
+        assert_eq!(mut_x.to_string(), out);
+        let mut mut_x = x;
+        mut_x.mod_add_assign(&y, &m);
+        assert_eq!(mut_x.to_string(), out);
+    };
+    test("0", "0", "1", "0");
+    test("-5", "7", "1", "0");
+    test("0", "-3", "5", "2");
+    test("-3", "10", "5", "2");
+    test("-7", "-8", "10", "5");
+    test("123", "-123", "7", "0");
+    test("-1", "0", "1000000000000", "999999999999");
+    test(
+        "-1000000000000000000000",
+        "-1",
+        "18446744073709551616",
+        "14570924054025338879",
+    );
+}
+
+#[test]
+fn mod_add_fail() {
+    assert_panic!(Integer::from(1).mod_add(Integer::from(2), Natural::from(0u32)));
+    assert_panic!((&Integer::from(-1)).mod_add(&Integer::from(2), &Natural::from(0u32)));
+    assert_panic!({
+        let mut x = Integer::from(1);
+        x.mod_add_assign(Integer::from(2), Natural::from(0u32));
+    });
+}
+
+#[test]
+fn mod_add_properties() {
+    integer_integer_natural_triple_gen().test_properties(|(x, y, m)| {
+        if m == 0u32 {
+            return;
+        }
+        let sum = (&x).mod_add(&y, &m);
+        assert!(sum.is_valid());
+        assert!(sum < m);
+        assert_eq!(x.clone().mod_add(y.clone(), m.clone()), sum);
+        assert_eq!((&x).mod_add(y.clone(), m.clone()), sum);
+        assert_eq!(x.clone().mod_add(&y, &m), sum);
+
+        let mut mut_x = x.clone();
+        mut_x.mod_add_assign(&y, &m);
+        assert!(mut_x.is_valid());
+        assert_eq!(mut_x, sum);
+
+        let m_int = Integer::from(&m);
+        assert_eq!(Integer::from(&sum), (&x + &y).mod_op(&m_int));
+        assert_eq!((&y).mod_add(&x, &m), sum);
+        assert_eq!((&x).mod_add(&y + &m_int, &m), sum);
+        assert_eq!((&x - &m_int).mod_add(&y, &m), sum);
+    });
+
+    signed_triple_gen::<i32>().test_properties(|(x, y, m)| {
+        if m == 0 {
+            return;
+        }
+        let m_abs = m.unsigned_abs();
+        assert_eq!(
+            Integer::from(x).mod_add(Integer::from(y), Natural::from(m_abs)),
+            (i64::from(x) + i64::from(y)).rem_euclid(i64::from(m_abs))
+        );
+    });
+}
@@ -0,0 +1,64 @@
+use malachite_base::num::arithmetic::traits::{CoprimeWith, Mod, ModInverse, ModMul};
+use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_base::strings::ToDebugString;
+use malachite_nz::integer::Integer;
+use malachite_nz::natural::Natural;
+use malachite_nz::test_util::generators::integer_natural_pair_gen;
+use std::panic::catch_unwind;
+use std::str::FromStr;
+
+#[test]
+fn test_mod_inverse() {
+    let test = |s, t, out| {
+        let x = Integer::from_str(s).unwrap();
+        let m = Natural::from_str(t).unwrap();
+
+        assert_eq!(x.clone().mod_inverse(m.clone()).to_debug_string(), out);
+        assert_eq!(x.clone().mod_inverse(&m).to_debug_string(), out);
+        assert_eq!((&x).mod_inverse(m.clone()).to_debug_string(), out);
+        assert_eq!((&x).mod_inverse(&m).to_debug_string(), out);
+    };
+    test("0", "1", "Some(0)");
+    test("-5", "1", "Some(0)");
+    test("0", "5", "None");
+    test("10", "5", "None");
+    test("-3", "10", "Some(3)");
+    test("7", "10", "Some(3)");
+    test("-4", "10", "None");
+    test("-1", "1000000000000", "Some(999999999999)");
+    test(
+        "-1000000000000000000000",
+        "18446744073709551617",
+        "Some(8325886196216101008)",
+    );
+}
+
+#[test]
+fn mod_inverse_fail() {
+    assert_panic!(Integer::from(1).mod_inverse(Natural::from(0u32)));
+    assert_panic!((&Integer::from(-1)).mod_inverse(&Natural::from(0u32)));
+}
+
+#[test]
+fn mod_inverse_properties() {
+    integer_natural_pair_gen().test_properties(|(x, m)| {
+        if m == 0u32 {
+            return;
+        }
+        let inverse = (&x).mod_inverse(&m);
+        assert_eq!(x.clone().mod_inverse(m.clone()), inverse);
+        assert_eq!((&x).mod_inverse(m.clone()), inverse);
+        assert_eq!(x.clone().mod_inverse(&m), inverse);
+        assert_eq!(inverse.is_some(), x.unsigned_abs_ref().coprime_with(&m));
+        if let Some(inverse) = inverse {
+            assert!(inverse.is_valid());
+            assert!(inverse < m);
+            assert_eq!(
+                (&x).mod_mul(Integer::from(&inverse), &m),
+                if m == 1u32 { 0u32 } else { 1u32 }
+            );
+            let residue = Natural::exact_from((&x).mod_op(Integer::from(&m)));
+            assert_eq!(Integer::from(inverse).mod_inverse(&m), Some(residue));
+        }
+    });
+}
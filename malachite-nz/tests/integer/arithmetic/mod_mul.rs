@@ -0,0 +1,98 @@
+use malachite_base::num::arithmetic::traits::{Mod, ModMul, ModMulAssign};
+use malachite_base::test_util::generators::signed_triple_gen;
+use malachite_nz::integer::Integer;
+use malachite_nz::natural::Natural;
+use malachite_nz::test_util::generators::integer_integer_natural_triple_gen;
+use std::panic::catch_unwind;
+use std::str::FromStr;
+
+#[test]
+fn test_mod_mul() {
+    let test = |s, t, u, out| {
+        let x = Integer::from_str(s).unwrap();
+        let y = Integer::from_str(t).unwrap();
+        let m = Natural::from_str(u).unwrap();
+
+        assert_eq!(x.clone().mod_mul(y.clone(), m.clone()).to_string(), out);
+        assert_eq!(x.clone().mod_mul(y.clone(), &m).to_string(), out);
+        assert_eq!(x.clone().mod_mul(&y, m.clone()).to_string(), out);
+        assert_eq!(x.clone().mod_mul(&y, &m).to_string(), out);
+        assert_eq!((&x).mod_mul(y.clone(), m.clone()).to_string(), out);
+        assert_eq!((&x).mod_mul(y.clone(), &m).to_string(), out);
+        assert_eq!((&x).mod_mul(&y, m.clone()).to_string(), out);
+        assert_eq!((&x).mod_mul(&y, &m).to_string(), out);
+
+        let mut mut_x = x.clone();
+        mut_x.mod_mul_assign(y.clone(), m.clone());
+        assert!(mut_x.is_valid());
+        assert_eq!(mut_x.to_string(), out);
+        let mut mut_x = x.clone();
+        mut_x.mod_mul_assign(y.clone(), &m);
+        assert_eq!(mut_x.to_string(), out);
+        let mut mut_x = x.clone();
+        mut_x.mod_mul_assign(&y, m.clone());
+        assert_eq!(mut_x.to_string(), out);
+        let mut mut_x = x;
+        mut_x.mod_mul_assign(&y, &m);
+        assert_eq!(mut_x.to_string(), out);
+    };
+    test("0", "0", "1", "0");
+    test("-5", "7", "1", "0");
+    test("0", "-3", "5", "0");
+    test("-3", "10", "7", "5");
+    test("-4", "-6", "10", "4");
+    test("-1", "1", "1000000000000", "999999999999");
+    test(
+        "-1000000000000000000000",
+        "1000000000000000000000",
+        "18446744073709551617",
+        "1767817129779450778",
+    );
+}
+
+#[test]
+fn mod_mul_fail() {
+    assert_panic!(Integer::from(1).mod_mul(Integer::from(2), Natural::from(0u32)));
+    assert_panic!((&Integer::from(-1)).mod_mul(&Integer::from(2), &Natural::from(0u32)));
+    assert_panic!({
+        let mut x = Integer::from(1);
+        x.mod_mul_assign(Integer::from(2), Natural::from(0u32));
+    });
+}
+
+#[test]
+fn mod_mul_properties() {
+    integer_integer_natural_triple_gen().test_properties(|(x, y, m)| {
+        if m == 0u32 {
+            return;
+        }
+        let product = (&x).mod_mul(&y, &m);
+        assert!(product.is_valid());
+        assert!(product < m);
+        assert_eq!(x.clone().mod_mul(y.clone(), m.clone()), product);
+        assert_eq!((&x).mod_mul(y.clone(), m.clone()), product);
+        assert_eq!(x.clone().mod_mul(&y, &m), product);
+
+        let mut mut_x = x.clone();
+        mut_x.mod_mul_assign(&y, &m);
+        assert!(mut_x.is_valid());
+        assert_eq!(mut_x, product);
+
+        let m_int = Integer::from(&m);
+        assert_eq!(Integer::from(&product), (&x * &y).mod_op(&m_int));
+        assert_eq!((&y).mod_mul(&x, &m), product);
+        assert_eq!((&x).mod_mul(&y + &m_int, &m), product);
+        assert_eq!((-&x).mod_mul(-&y, &m), product);
+    });
+
+    signed_triple_gen::<i32>().test_properties(|(x, y, m)| {
+        if m == 0 {
+            return;
+        }
+        let m_abs = m.unsigned_abs();
+        assert_eq!(
+            Integer::from(x).mod_mul(Integer::from(y), Natural::from(m_abs)),
+            (i64::from(x) * i64::from(y)).rem_euclid(i64::from(m_abs))
+        );
+    });
+}
@@ -0,0 +1,84 @@
+use malachite_base::num::arithmetic::traits::{Mod, ModAdd, ModNeg, ModNegAssign, UnsignedAbs};
+use malachite_base::test_util::generators::signed_pair_gen;
+use malachite_nz::integer::Integer;
+use malachite_nz::natural::Natural;
+use malachite_nz::test_util::generators::integer_natural_pair_gen;
+use std::panic::catch_unwind;
+use std::str::FromStr;
+
+#[test]
+fn test_mod_neg() {
+    let test = |s, t, out| {
+        let x = Integer::from_str(s).unwrap();
+        let m = Natural::from_str(t).unwrap();
+
+        assert_eq!(x.clone().mod_neg(m.clone()).to_string(), out);
+        assert_eq!(x.clone().mod_neg(&m).to_string(), out);
+        assert_eq!((&x).mod_neg(m.clone()).to_string(), out);
+        assert_eq!((&x).mod_neg(&m).to_string(), out);
+
+        let mut mut_x = x.clone();
+        mut_x.mod_neg_assign(m.clone());
+        assert!(mut_x.is_valid());
+        assert_eq!(mut_x.to_string(), out);
+        let mut mut_x = x;
+        mut_x.mod_neg_assign(&m);
+        assert_eq!(mut_x.to_string(), out);
+    };
+    test("0", "1", "0");
+    test("-5", "1", "0");
+    test("0", "5", "0");
+    test("-3", "5", "3");
+    test("12", "10", "8");
+    test("-12", "10", "2");
+    test("1", "1000000000000", "999999999999");
+    test(
+        "-1000000000000000000000",
+        "18446744073709551616",
+        "3875820019684212736",
+    );
+}
+
+#[test]
+fn mod_neg_fail() {
+    assert_panic!(Integer::from(1).mod_neg(Natural::from(0u32)));
+    assert_panic!((&Integer::from(-1)).mod_neg(&Natural::from(0u32)));
+}
+
+#[test]
+fn mod_neg_properties() {
+    integer_natural_pair_gen().test_properties(|(x, m)| {
+        if m == 0u32 {
+            return;
+        }
+        let neg = (&x).mod_neg(&m);
+        assert!(neg.is_valid());
+        assert!(neg < m);
+        assert_eq!(x.clone().mod_neg(m.clone()), neg);
+        assert_eq!((&x).mod_neg(m.clone()), neg);
+        assert_eq!(x.clone().mod_neg(&m), neg);
+
+        let mut mut_x = x.clone();
+        mut_x.mod_neg_assign(&m);
+        assert!(mut_x.is_valid());
+        assert_eq!(mut_x, neg);
+
+        assert_eq!(Integer::from(&neg), (-&x).mod_op(Integer::from(&m)));
+        assert_eq!(
+            (-&x).mod_neg(&m),
+            (&x).mod_op(Integer::from(&m)).unsigned_abs()
+        );
+        assert_eq!((&x).mod_add(Integer::from(neg), &m), 0u32);
+    });
+
+    signed_pair_gen::<i32>().test_properties(|(x, m)| {
+        if m == 0 {
+            return;
+        }
+        let m_abs = m.unsigned_abs();
+        assert_eq!(
+            Integer::from(x).mod_neg(Natural::from(m_abs)),
+            (-i64::from(x)).rem_euclid(i64::from(m_abs))
+        );
+    });
+}
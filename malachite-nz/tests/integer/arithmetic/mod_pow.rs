@@ -0,0 +1,128 @@
+use malachite_base::num::arithmetic::traits::{
+    Mod, ModInverse, ModMul, ModPow, ModPowAssign, Pow, UnsignedAbs,
+};
+use malachite_base::num::basic::traits::One;
+use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_nz::integer::Integer;
+use malachite_nz::natural::Natural;
+use malachite_nz::test_util::generators::{
+    integer_integer_natural_triple_gen, integer_natural_pair_gen,
+};
+use std::panic::catch_unwind;
+use std::str::FromStr;
+
+#[test]
+fn test_mod_pow() {
+    let test = |s, t, u, out| {
+        let x = Integer::from_str(s).unwrap();
+        let exp = Integer::from_str(t).unwrap();
+        let m = Natural::from_str(u).unwrap();
+
+        assert_eq!(x.clone().mod_pow(exp.clone(), m.clone()).to_string(), out);
+        assert_eq!(x.clone().mod_pow(exp.clone(), &m).to_string(), out);
+        assert_eq!(x.clone().mod_pow(&exp, m.clone()).to_string(), out);
+        assert_eq!(x.clone().mod_pow(&exp, &m).to_string(), out);
+        assert_eq!((&x).mod_pow(exp.clone(), m.clone()).to_string(), out);
+        assert_eq!((&x).mod_pow(exp.clone(), &m).to_string(), out);
+        assert_eq!((&x).mod_pow(&exp, m.clone()).to_string(), out);
+        assert_eq!((&x).mod_pow(&exp, &m).to_string(), out);
+
+        let mut mut_x = x.clone();
+        mut_x.mod_pow_assign(exp.clone(), m.clone());
+        assert!(mut_x.is_valid());
+        assert_eq!(mut_x.to_string(), out);
+        let mut mut_x = x.clone();
+        mut_x.mod_pow_assign(exp.clone(), &m);
+        assert_eq!(mut_x.to_string(), out);
+        let mut mut_x = x.clone();
+        mut_x.mod_pow_assign(&exp, m.clone());
+        assert_eq!(mut_x.to_string(), out);
+        let mut mut_x = x;
+        mut_x.mod_pow_assign(&exp, &m);
+        assert_eq!(mut_x.to_string(), out);
+    };
+    test("0", "0", "1", "0");
+    test("0", "-3", "1", "0");
+    test("0", "0", "10", "1");
+    test("-5", "0", "10", "1");
+    test("0", "5", "10", "0");
+    test("-3", "3", "10", "3");
+    test("-3", "-1", "10", "3");
+    test("-3", "-5", "10", "3");
+    test("7", "-2", "10", "9");
+    test("-2", "100", "1000000000000", "496703205376");
+    test(
+        "-1000000000000000000000",
+        "-3",
+        "18446744073709551617",
+        "1968631563929832830",
+    );
+}
+
+#[test]
+fn mod_pow_fail() {
+    assert_panic!(Integer::from(2).mod_pow(Integer::from(3), Natural::from(0u32)));
+    assert_panic!((&Integer::from(-2)).mod_pow(&Integer::from(-3), &Natural::from(0u32)));
+    assert_panic!(Integer::from(0).mod_pow(Integer::from(-1), Natural::from(5u32)));
+    assert_panic!(Integer::from(-4).mod_pow(Integer::from(-1), Natural::from(10u32)));
+    assert_panic!({
+        let mut x = Integer::from(-4);
+        x.mod_pow_assign(Integer::from(-1), Natural::from(10u32));
+    });
+}
+
+#[test]
+fn mod_pow_properties() {
+    integer_integer_natural_triple_gen().test_properties(|(x, exp, m)| {
+        if m == 0u32 {
+            return;
+        }
+        let m_int = Integer::from(&m);
+        let residue = Natural::exact_from((&x).mod_op(&m_int));
+        let inverse = (&x).mod_inverse(&m);
+        if exp < 0u32 && inverse.is_none() {
+            assert_panic!((&x).mod_pow(&exp, &m));
+            return;
+        }
+        let power = (&x).mod_pow(&exp, &m);
+        assert!(power.is_valid());
+        assert!(power < m);
+        assert_eq!(x.clone().mod_pow(exp.clone(), m.clone()), power);
+        assert_eq!((&x).mod_pow(exp.clone(), m.clone()), power);
+        assert_eq!(x.clone().mod_pow(&exp, &m), power);
+
+        let mut mut_x = x.clone();
+        mut_x.mod_pow_assign(&exp, &m);
+        assert!(mut_x.is_valid());
+        assert_eq!(mut_x, power);
+
+        let exp_abs = (&exp).unsigned_abs();
+        if exp >= 0u32 {
+            assert_eq!(residue.mod_pow(&exp_abs, &m), power);
+        } else {
+            assert_eq!(inverse.unwrap().mod_pow(&exp_abs, &m), power);
+            let positive_power = (&x).mod_pow(-&exp, &m);
+            assert_eq!(
+                power.mod_mul(positive_power, &m),
+                if m == 1u32 { 0u32 } else { 1u32 }
+            );
+        }
+    });
+
+    integer_natural_pair_gen().test_properties(|(x, m)| {
+        if m == 0u32 {
+            return;
+        }
+        let m_int = Integer::from(&m);
+        for k in 0..4u64 {
+            assert_eq!(
+                Integer::from((&x).mod_pow(Integer::from(k), &m)),
+                (&x).pow(k).mod_op(&m_int)
+            );
+        }
+        assert_eq!(
+            (&x).mod_pow(Integer::ONE, &m),
+            Natural::exact_from((&x).mod_op(&m_int))
+        );
+    });
+}
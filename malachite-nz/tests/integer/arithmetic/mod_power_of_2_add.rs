@@ -0,0 +1,82 @@
+use malachite_base::num::arithmetic::traits::{
+    ModPowerOf2, ModPowerOf2Add, ModPowerOf2AddAssign, ModPowerOf2Neg,
+};
+use malachite_base::num::logic::traits::SignificantBits;
+use malachite_base::test_util::generators::signed_pair_gen;
+use malachite_nz::integer::Integer;
+use malachite_nz::test_util::generators::integer_integer_unsigned_triple_gen_var_1;
+use std::str::FromStr;
+
+#[test]
+fn test_mod_power_of_2_add() {
+    let test = |s, t, pow, out| {
+        let x = Integer::from_str(s).unwrap();
+        let y = Integer::from_str(t).unwrap();
+
+        assert_eq!(
+            x.clone().mod_power_of_2_add(y.clone(), pow).to_string(),
+            out
+        );
+        assert_eq!(x.clone().mod_power_of_2_add(&y, pow).to_string(), out);
+        assert_eq!((&x).mod_power_of_2_add(y.clone(), pow).to_string(), out);
+        assert_eq!((&x).mod_power_of_2_add(&y, pow).to_string(), out);
+
+        let mut mut_x = x.clone();
+        mut_x.mod_power_of_2_add_assign(y.clone(), pow);
+        assert!(mut_x.is_valid());
+        assert_eq!(mut_x.to_string(), out);
+        let mut mut_x = x;
+        mut_x.mod_power_of_2_add_assign(&y, pow);
+        assert_eq!(mut_x.to_string(), out);
+    };
+    test("0", "0", 0, "0");
+    test("-5", "7", 0, "0");
+    test("0", "-3", 4, "13");
+    test("-3", "10", 4, "7");
+    test("-4", "-6", 4, "6");
+    test("7", "-8", 4, "15");
+    test("-1", "1", 40, "0");
+    test(
+        "-1000000000000000000000",
+        "1000000000000000000001",
+        100,
+        "1",
+    );
+    test(
+        "-1000000000000000000000",
+        "-999999999999999999999",
+        64,
+        "10695104034341126145",
+    );
+}
+
+#[test]
+fn mod_power_of_2_add_properties() {
+    integer_integer_unsigned_triple_gen_var_1::<u64>().test_properties(|(x, y, pow)| {
+        let sum = (&x).mod_power_of_2_add(&y, pow);
+        assert!(sum.is_valid());
+        assert!(sum.significant_bits() <= pow);
+        assert_eq!(x.clone().mod_power_of_2_add(y.clone(), pow), sum);
+        assert_eq!((&x).mod_power_of_2_add(y.clone(), pow), sum);
+        assert_eq!(x.clone().mod_power_of_2_add(&y, pow), sum);
+
+        let mut mut_x = x.clone();
+        mut_x.mod_power_of_2_add_assign(&y, pow);
+        assert!(mut_x.is_valid());
+        assert_eq!(mut_x, sum);
+
+        assert_eq!((&x + &y).mod_power_of_2(pow), sum);
+        assert_eq!((&y).mod_power_of_2_add(&x, pow), sum);
+        assert_eq!(
+            (-&x).mod_power_of_2_add(-&y, pow),
+            (&sum).mod_power_of_2_neg(pow)
+        );
+    });
+
+    signed_pair_gen::<i32>().test_properties(|(x, y)| {
+        assert_eq!(
+            Integer::from(x).mod_power_of_2_add(Integer::from(y), 16),
+            (i64::from(x) + i64::from(y)).rem_euclid(1 << 16)
+        );
+    });
+}
@@ -0,0 +1,45 @@
+use malachite_base::num::arithmetic::traits::{ModPowerOf2Inverse, ModPowerOf2Mul, Parity};
+use malachite_base::num::logic::traits::SignificantBits;
+use malachite_base::strings::ToDebugString;
+use malachite_nz::integer::Integer;
+use malachite_nz::test_util::generators::integer_unsigned_pair_gen_var_2;
+use std::str::FromStr;
+
+#[test]
+fn test_mod_power_of_2_inverse() {
+    let test = |s, pow, out| {
+        let x = Integer::from_str(s).unwrap();
+
+        assert_eq!(x.clone().mod_power_of_2_inverse(pow).to_debug_string(), out);
+        assert_eq!((&x).mod_power_of_2_inverse(pow).to_debug_string(), out);
+    };
+    test("0", 0, "Some(0)");
+    test("-5", 0, "Some(0)");
+    test("0", 4, "None");
+    test("-3", 4, "Some(5)");
+    test("12", 4, "None");
+    test("-12", 4, "None");
+    test("1", 40, "Some(1)");
+    test(
+        "-999999999999999999999",
+        100,
+        "Some(14904464828693276835067199489)",
+    );
+}
+
+#[test]
+fn mod_power_of_2_inverse_properties() {
+    integer_unsigned_pair_gen_var_2::<u64>().test_properties(|(x, pow)| {
+        let inverse = (&x).mod_power_of_2_inverse(pow);
+        assert_eq!(x.clone().mod_power_of_2_inverse(pow), inverse);
+        assert_eq!(inverse.is_some(), pow == 0 || x.odd());
+        if let Some(inverse) = inverse {
+            assert!(inverse.is_valid());
+            assert!(inverse.significant_bits() <= pow);
+            assert_eq!(
+                (&x).mod_power_of_2_mul(Integer::from(inverse), pow),
+                if pow == 0 { 0u32 } else { 1u32 }
+            );
+        }
+    });
+}
@@ -0,0 +1,77 @@
+use malachite_base::num::arithmetic::traits::{ModPowerOf2, ModPowerOf2Mul, ModPowerOf2MulAssign};
+use malachite_base::num::logic::traits::SignificantBits;
+use malachite_base::test_util::generators::signed_pair_gen;
+use malachite_nz::integer::Integer;
+use malachite_nz::test_util::generators::integer_integer_unsigned_triple_gen_var_1;
+use std::str::FromStr;
+
+#[test]
+fn test_mod_power_of_2_mul() {
+    let test = |s, t, pow, out| {
+        let x = Integer::from_str(s).unwrap();
+        let y = Integer::from_str(t).unwrap();
+
+        assert_eq!(
+            x.clone().mod_power_of_2_mul(y.clone(), pow).to_string(),
+            out
+        );
+        assert_eq!(x.clone().mod_power_of_2_mul(&y, pow).to_string(), out);
+        assert_eq!((&x).mod_power_of_2_mul(y.clone(), pow).to_string(), out);
+        assert_eq!((&x).mod_power_of_2_mul(&y, pow).to_string(), out);
+
+        let mut mut_x = x.clone();
+        mut_x.mod_power_of_2_mul_assign(y.clone(), pow);
+        assert!(mut_x.is_valid());
+        assert_eq!(mut_x.to_string(), out);
+        let mut mut_x = x;
+        mut_x.mod_power_of_2_mul_assign(&y, pow);
+        assert_eq!(mut_x.to_string(), out);
+    };
+    test("0", "0", 0, "0");
+    test("-5", "7", 0, "0");
+    test("0", "-3", 4, "0");
+    test("-3", "10", 4, "2");
+    test("-4", "-6", 4, "8");
+    test("7", "-8", 4, "8");
+    test("-1", "1", 40, "1099511627775");
+    test(
+        "-1000000000000000000000",
+        "1000000000000000000001",
+        100,
+        "1252685461964572241580336873472",
+    );
+    test(
+        "-1000000000000000000000",
+        "-999999999999999999999",
+        64,
+        "7930898567095386112",
+    );
+}
+
+#[test]
+fn mod_power_of_2_mul_properties() {
+    integer_integer_unsigned_triple_gen_var_1::<u64>().test_properties(|(x, y, pow)| {
+        let product = (&x).mod_power_of_2_mul(&y, pow);
+        assert!(product.is_valid());
+        assert!(product.significant_bits() <= pow);
+        assert_eq!(x.clone().mod_power_of_2_mul(y.clone(), pow), product);
+        assert_eq!((&x).mod_power_of_2_mul(y.clone(), pow), product);
+        assert_eq!(x.clone().mod_power_of_2_mul(&y, pow), product);
+
+        let mut mut_x = x.clone();
+        mut_x.mod_power_of_2_mul_assign(&y, pow);
+        assert!(mut_x.is_valid());
+        assert_eq!(mut_x, product);
+
+        assert_eq!((&x * &y).mod_power_of_2(pow), product);
+        assert_eq!((&y).mod_power_of_2_mul(&x, pow), product);
+        assert_eq!((-&x).mod_power_of_2_mul(-&y, pow), product);
+    });
+
+    signed_pair_gen::<i32>().test_properties(|(x, y)| {
+        assert_eq!(
+            Integer::from(x).mod_power_of_2_mul(Integer::from(y), 16),
+            (i64::from(x) * i64::from(y)).rem_euclid(1 << 16)
+        );
+    });
+}
@@ -0,0 +1,57 @@
+use malachite_base::num::arithmetic::traits::{
+    ModPowerOf2, ModPowerOf2Add, ModPowerOf2Neg, ModPowerOf2NegAssign,
+};
+use malachite_base::num::logic::traits::SignificantBits;
+use malachite_base::test_util::generators::signed_gen;
+use malachite_nz::integer::Integer;
+use malachite_nz::test_util::generators::integer_unsigned_pair_gen_var_2;
+use std::str::FromStr;
+
+#[test]
+fn test_mod_power_of_2_neg() {
+    let test = |s, pow, out| {
+        let x = Integer::from_str(s).unwrap();
+
+        assert_eq!(x.clone().mod_power_of_2_neg(pow).to_string(), out);
+        assert_eq!((&x).mod_power_of_2_neg(pow).to_string(), out);
+
+        let mut mut_x = x;
+        mut_x.mod_power_of_2_neg_assign(pow);
+        assert!(mut_x.is_valid());
+        assert_eq!(mut_x.to_string(), out);
+    };
+    test("0", 0, "0");
+    test("-5", 0, "0");
+    test("0", 4, "0");
+    test("-3", 4, "3");
+    test("12", 4, "4");
+    test("-12", 4, "12");
+    test("1", 40, "1099511627775");
+    test("-1000000000000000000000", 100, "1000000000000000000000");
+}
+
+#[test]
+fn mod_power_of_2_neg_properties() {
+    integer_unsigned_pair_gen_var_2::<u64>().test_properties(|(x, pow)| {
+        let neg = (&x).mod_power_of_2_neg(pow);
+        assert!(neg.is_valid());
+        assert!(neg.significant_bits() <= pow);
+        assert_eq!(x.clone().mod_power_of_2_neg(pow), neg);
+
+        let mut mut_x = x.clone();
+        mut_x.mod_power_of_2_neg_assign(pow);
+        assert!(mut_x.is_valid());
+        assert_eq!(mut_x, neg);
+
+        assert_eq!((-&x).mod_power_of_2(pow), neg);
+        assert_eq!((-&x).mod_power_of_2_neg(pow), (&x).mod_power_of_2(pow));
+        assert_eq!((&x).mod_power_of_2_add(Integer::from(neg), pow), 0u32);
+    });
+
+    signed_gen::<i32>().test_properties(|x| {
+        assert_eq!(
+            Integer::from(x).mod_power_of_2_neg(16),
+            (-i64::from(x)).rem_euclid(1 << 16)
+        );
+    });
+}
@@ -0,0 +1,102 @@
+use malachite_base::num::arithmetic::traits::{
+    ModPowerOf2, ModPowerOf2Inverse, ModPowerOf2Mul, ModPowerOf2Pow, ModPowerOf2PowAssign, Parity,
+    Pow, UnsignedAbs,
+};
+use malachite_base::num::logic::traits::SignificantBits;
+use malachite_nz::integer::Integer;
+use malachite_nz::test_util::generators::{
+    integer_integer_unsigned_triple_gen_var_1, integer_unsigned_pair_gen_var_2,
+};
+use std::panic::catch_unwind;
+use std::str::FromStr;
+
+#[test]
+fn test_mod_power_of_2_pow() {
+    let test = |s, t, pow, out| {
+        let x = Integer::from_str(s).unwrap();
+        let exp = Integer::from_str(t).unwrap();
+
+        assert_eq!(
+            x.clone().mod_power_of_2_pow(exp.clone(), pow).to_string(),
+            out
+        );
+        assert_eq!(x.clone().mod_power_of_2_pow(&exp, pow).to_string(), out);
+        assert_eq!((&x).mod_power_of_2_pow(exp.clone(), pow).to_string(), out);
+        assert_eq!((&x).mod_power_of_2_pow(&exp, pow).to_string(), out);
+
+        let mut mut_x = x.clone();
+        mut_x.mod_power_of_2_pow_assign(exp.clone(), pow);
+        assert!(mut_x.is_valid());
+        assert_eq!(mut_x.to_string(), out);
+        let mut mut_x = x;
+        mut_x.mod_power_of_2_pow_assign(&exp, pow);
+        assert_eq!(mut_x.to_string(), out);
+    };
+    test("0", "0", 0, "0");
+    test("0", "-3", 0, "0");
+    test("0", "0", 4, "1");
+    test("-5", "0", 4, "1");
+    test("0", "5", 4, "0");
+    test("-3", "3", 4, "5");
+    test("-3", "-1", 4, "5");
+    test("-3", "-3", 5, "13");
+    test("7", "-2", 4, "1");
+    test("-5", "101", 100, "936992046724952341900900266827");
+    test("-999999999999999999999", "-3", 64, "8680795284892024833");
+}
+
+#[test]
+fn mod_power_of_2_pow_fail() {
+    assert_panic!(Integer::from(0).mod_power_of_2_pow(Integer::from(-1), 4));
+    assert_panic!((&Integer::from(-4)).mod_power_of_2_pow(&Integer::from(-1), 4));
+    assert_panic!({
+        let mut x = Integer::from(-4);
+        x.mod_power_of_2_pow_assign(Integer::from(-1), 4);
+    });
+}
+
+#[test]
+fn mod_power_of_2_pow_properties() {
+    integer_integer_unsigned_triple_gen_var_1::<u64>().test_properties(|(x, exp, pow)| {
+        if exp < 0u32 && pow != 0 && x.even() {
+            assert_panic!((&x).mod_power_of_2_pow(&exp, pow));
+            return;
+        }
+        let power = (&x).mod_power_of_2_pow(&exp, pow);
+        assert!(power.is_valid());
+        assert!(power.significant_bits() <= pow);
+        assert_eq!(x.clone().mod_power_of_2_pow(exp.clone(), pow), power);
+        assert_eq!((&x).mod_power_of_2_pow(exp.clone(), pow), power);
+        assert_eq!(x.clone().mod_power_of_2_pow(&exp, pow), power);
+
+        let mut mut_x = x.clone();
+        mut_x.mod_power_of_2_pow_assign(&exp, pow);
+        assert!(mut_x.is_valid());
+        assert_eq!(mut_x, power);
+
+        let exp_abs = (&exp).unsigned_abs();
+        if exp >= 0u32 {
+            assert_eq!(
+                (&x).mod_power_of_2(pow).mod_power_of_2_pow(&exp_abs, pow),
+                power
+            );
+        } else {
+            let inverse = (&x).mod_power_of_2_inverse(pow).unwrap();
+            assert_eq!(inverse.mod_power_of_2_pow(&exp_abs, pow), power);
+            let positive_power = (&x).mod_power_of_2_pow(-&exp, pow);
+            assert_eq!(
+                power.mod_power_of_2_mul(positive_power, pow),
+                if pow == 0 { 0u32 } else { 1u32 }
+            );
+        }
+    });
+
+    integer_unsigned_pair_gen_var_2::<u64>().test_properties(|(x, pow)| {
+        for k in 0..4u64 {
+            assert_eq!(
+                (&x).mod_power_of_2_pow(Integer::from(k), pow),
+                (&x).pow(k).mod_power_of_2(pow)
+            );
+        }
+    });
+}
@@ -0,0 +1,75 @@
+use malachite_base::num::arithmetic::traits::{
+    ModPowerOf2, ModPowerOf2Shl, ModPowerOf2ShlAssign, ModPowerOf2Shr,
+};
+use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_base::num::logic::traits::SignificantBits;
+use malachite_nz::integer::Integer;
+use malachite_nz::test_util::generators::integer_unsigned_pair_gen_var_2;
+use std::str::FromStr;
+
+#[test]
+fn test_mod_power_of_2_shl() {
+    let test = |s, bits: i64, pow, out| {
+        let x = Integer::from_str(s).unwrap();
+
+        assert_eq!(x.clone().mod_power_of_2_shl(bits, pow).to_string(), out);
+        assert_eq!((&x).mod_power_of_2_shl(bits, pow).to_string(), out);
+
+        let mut mut_x = x.clone();
+        mut_x.mod_power_of_2_shl_assign(bits, pow);
+        assert!(mut_x.is_valid());
+        assert_eq!(mut_x.to_string(), out);
+
+        if bits >= 0 {
+            let bits = u64::exact_from(bits);
+            assert_eq!((&x).mod_power_of_2_shl(bits, pow).to_string(), out);
+            let mut mut_x = x;
+            mut_x.mod_power_of_2_shl_assign(bits, pow);
+            assert_eq!(mut_x.to_string(), out);
+        }
+    };
+    test("0", 0, 0, "0");
+    test("-5", 10, 0, "0");
+    test("0", 5, 4, "0");
+    test("-3", 2, 5, "20");
+    test("-12", -2, 4, "1");
+    test("-1", -1, 10, "511");
+    test("-3", -1, 4, "6");
+    test(
+        "-1000000000000000000000",
+        5,
+        100,
+        "1267650568228229401496703205376",
+    );
+    test(
+        "-1000000000000000000000",
+        -5,
+        100,
+        "39614081225882168796771975168",
+    );
+}
+
+#[test]
+fn mod_power_of_2_shl_properties() {
+    integer_unsigned_pair_gen_var_2::<u64>().test_properties(|(x, pow)| {
+        let residue = (&x).mod_power_of_2(pow);
+        for bits in [0u64, 1, 5, 32, 64, 100] {
+            let shifted = (&x).mod_power_of_2_shl(bits, pow);
+            assert!(shifted.is_valid());
+            assert!(shifted.significant_bits() <= pow);
+            assert_eq!(x.clone().mod_power_of_2_shl(bits, pow), shifted);
+            assert_eq!((&x << bits).mod_power_of_2(pow), shifted);
+            assert_eq!((&residue).mod_power_of_2_shl(bits, pow), shifted);
+
+            let i_bits = i64::exact_from(bits);
+            assert_eq!((&x).mod_power_of_2_shl(i_bits, pow), shifted);
+            assert_eq!((&x).mod_power_of_2_shr(-i_bits, pow), shifted);
+            assert_eq!((&x).mod_power_of_2_shl(-i_bits, pow), &residue >> bits);
+
+            let mut mut_x = x.clone();
+            mut_x.mod_power_of_2_shl_assign(bits, pow);
+            assert!(mut_x.is_valid());
+            assert_eq!(mut_x, shifted);
+        }
+    });
+}
@@ -0,0 +1,60 @@
+use malachite_base::num::arithmetic::traits::{
+    ModPowerOf2, ModPowerOf2Mul, ModPowerOf2Square, ModPowerOf2SquareAssign, Square,
+};
+use malachite_base::num::logic::traits::SignificantBits;
+use malachite_base::test_util::generators::signed_gen;
+use malachite_nz::integer::Integer;
+use malachite_nz::test_util::generators::integer_unsigned_pair_gen_var_2;
+use std::str::FromStr;
+
+#[test]
+fn test_mod_power_of_2_square() {
+    let test = |s, pow, out| {
+        let x = Integer::from_str(s).unwrap();
+
+        assert_eq!(x.clone().mod_power_of_2_square(pow).to_string(), out);
+        assert_eq!((&x).mod_power_of_2_square(pow).to_string(), out);
+
+        let mut mut_x = x;
+        mut_x.mod_power_of_2_square_assign(pow);
+        assert!(mut_x.is_valid());
+        assert_eq!(mut_x.to_string(), out);
+    };
+    test("0", 0, "0");
+    test("-5", 0, "0");
+    test("0", 4, "0");
+    test("-3", 4, "9");
+    test("12", 4, "0");
+    test("-1", 40, "1");
+    test(
+        "-1000000000000000000000",
+        100,
+        "14965137263657159916366331904",
+    );
+}
+
+#[test]
+fn mod_power_of_2_square_properties() {
+    integer_unsigned_pair_gen_var_2::<u64>().test_properties(|(x, pow)| {
+        let square = (&x).mod_power_of_2_square(pow);
+        assert!(square.is_valid());
+        assert!(square.significant_bits() <= pow);
+        assert_eq!(x.clone().mod_power_of_2_square(pow), square);
+
+        let mut mut_x = x.clone();
+        mut_x.mod_power_of_2_square_assign(pow);
+        assert!(mut_x.is_valid());
+        assert_eq!(mut_x, square);
+
+        assert_eq!((&x).square().mod_power_of_2(pow), square);
+        assert_eq!((&x).mod_power_of_2_mul(&x, pow), square);
+        assert_eq!((-&x).mod_power_of_2_square(pow), square);
+    });
+
+    signed_gen::<i32>().test_properties(|x| {
+        assert_eq!(
+            Integer::from(x).mod_power_of_2_square(16),
+            (i64::from(x) * i64::from(x)).rem_euclid(1 << 16)
+        );
+    });
+}
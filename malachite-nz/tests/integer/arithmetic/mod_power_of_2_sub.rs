@@ -0,0 +1,82 @@
+use malachite_base::num::arithmetic::traits::{
+    ModPowerOf2, ModPowerOf2Add, ModPowerOf2Neg, ModPowerOf2Sub, ModPowerOf2SubAssign,
+};
+use malachite_base::num::logic::traits::SignificantBits;
+use malachite_base::test_util::generators::signed_pair_gen;
+use malachite_nz::integer::Integer;
+use malachite_nz::test_util::generators::integer_integer_unsigned_triple_gen_var_1;
+use std::str::FromStr;
+
+#[test]
+fn test_mod_power_of_2_sub() {
+    let test = |s, t, pow, out| {
+        let x = Integer::from_str(s).unwrap();
+        let y = Integer::from_str(t).unwrap();
+
+        assert_eq!(
+            x.clone().mod_power_of_2_sub(y.clone(), pow).to_string(),
+            out
+        );
+        assert_eq!(x.clone().mod_power_of_2_sub(&y, pow).to_string(), out);
+        assert_eq!((&x).mod_power_of_2_sub(y.clone(), pow).to_string(), out);
+        assert_eq!((&x).mod_power_of_2_sub(&y, pow).to_string(), out);
+
+        let mut mut_x = x.clone();
+        mut_x.mod_power_of_2_sub_assign(y.clone(), pow);
+        assert!(mut_x.is_valid());
+        assert_eq!(mut_x.to_string(), out);
+        let mut mut_x = x;
+        mut_x.mod_power_of_2_sub_assign(&y, pow);
+        assert_eq!(mut_x.to_string(), out);
+    };
+    test("0", "0", 0, "0");
+    test("-5", "7", 0, "0");
+    test("0", "-3", 4, "3");
+    test("-3", "10", 4, "3");
+    test("-4", "-6", 4, "2");
+    test("7", "-8", 4, "15");
+    test("-1", "1", 40, "1099511627774");
+    test(
+        "-1000000000000000000000",
+        "1000000000000000000001",
+        100,
+        "1267650598228229401496703205375",
+    );
+    test(
+        "-1000000000000000000000",
+        "-999999999999999999999",
+        64,
+        "18446744073709551615",
+    );
+}
+
+#[test]
+fn mod_power_of_2_sub_properties() {
+    integer_integer_unsigned_triple_gen_var_1::<u64>().test_properties(|(x, y, pow)| {
+        let difference = (&x).mod_power_of_2_sub(&y, pow);
+        assert!(difference.is_valid());
+        assert!(difference.significant_bits() <= pow);
+        assert_eq!(x.clone().mod_power_of_2_sub(y.clone(), pow), difference);
+        assert_eq!((&x).mod_power_of_2_sub(y.clone(), pow), difference);
+        assert_eq!(x.clone().mod_power_of_2_sub(&y, pow), difference);
+
+        let mut mut_x = x.clone();
+        mut_x.mod_power_of_2_sub_assign(&y, pow);
+        assert!(mut_x.is_valid());
+        assert_eq!(mut_x, difference);
+
+        assert_eq!((&x - &y).mod_power_of_2(pow), difference);
+        assert_eq!(
+            (&y).mod_power_of_2_sub(&x, pow),
+            (&difference).mod_power_of_2_neg(pow)
+        );
+        assert_eq!((&x).mod_power_of_2_add(-&y, pow), difference);
+    });
+
+    signed_pair_gen::<i32>().test_properties(|(x, y)| {
+        assert_eq!(
+            Integer::from(x).mod_power_of_2_sub(Integer::from(y), 16),
+            (i64::from(x) - i64::from(y)).rem_euclid(1 << 16)
+        );
+    });
+}
@@ -0,0 +1,91 @@
+use malachite_base::num::arithmetic::traits::{Mod, ModShl, ModShlAssign, ModShr};
+use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_nz::integer::Integer;
+use malachite_nz::natural::Natural;
+use malachite_nz::test_util::generators::integer_natural_pair_gen;
+use std::panic::catch_unwind;
+use std::str::FromStr;
+
+#[test]
+fn test_mod_shl() {
+    let test = |s, bits: i64, t, out| {
+        let x = Integer::from_str(s).unwrap();
+        let m = Natural::from_str(t).unwrap();
+
+        assert_eq!(x.clone().mod_shl(bits, m.clone()).to_string(), out);
+        assert_eq!(x.clone().mod_shl(bits, &m).to_string(), out);
+        assert_eq!((&x).mod_shl(bits, m.clone()).to_string(), out);
+        assert_eq!((&x).mod_shl(bits, &m).to_string(), out);
+
+        let mut mut_x = x.clone();
+        mut_x.mod_shl_assign(bits, m.clone());
+        assert!(mut_x.is_valid());
+        assert_eq!(mut_x.to_string(), out);
+        let mut mut_x = x.clone();
+        mut_x.mod_shl_assign(bits, &m);
+        assert_eq!(mut_x.to_string(), out);
+
+        if bits >= 0 {
+            let bits = u64::exact_from(bits);
+            assert_eq!(x.clone().mod_shl(bits, &m).to_string(), out);
+            let mut mut_x = x;
+            mut_x.mod_shl_assign(bits, &m);
+            assert_eq!(mut_x.to_string(), out);
+        }
+    };
+    test("0", 0, "1", "0");
+    test("-5", 10, "1", "0");
+    test("0", 5, "10", "0");
+    test("-3", 2, "5", "3");
+    test("-12", 70, "1000000000000", "391064358912");
+    test("-3", -1, "5", "1");
+    test("-12", -2, "10", "2");
+    test("-1", -1, "1000000000000", "499999999999");
+    test(
+        "-1000000000000000000000",
+        5,
+        "18446744073709551617",
+        "5100967886072055495",
+    );
+    test(
+        "-1000000000000000000000",
+        -5,
+        "18446744073709551617",
+        "455341376688291841",
+    );
+}
+
+#[test]
+fn mod_shl_fail() {
+    assert_panic!(Integer::from(1).mod_shl(2u8, Natural::from(0u32)));
+    assert_panic!((&Integer::from(-1)).mod_shl(-2i16, &Natural::from(0u32)));
+}
+
+#[test]
+fn mod_shl_properties() {
+    integer_natural_pair_gen().test_properties(|(x, m)| {
+        if m == 0u32 {
+            return;
+        }
+        let m_int = Integer::from(&m);
+        let residue = Natural::exact_from((&x).mod_op(&m_int));
+        for bits in [0u64, 1, 5, 32, 64, 100] {
+            let shifted = (&x).mod_shl(bits, &m);
+            assert!(shifted.is_valid());
+            assert!(shifted < m);
+            assert_eq!(x.clone().mod_shl(bits, m.clone()), shifted);
+            assert_eq!(Integer::from(&shifted), (&x << bits).mod_op(&m_int));
+            assert_eq!((&residue).mod_shl(bits, &m), shifted);
+
+            let i_bits = i64::exact_from(bits);
+            assert_eq!((&x).mod_shl(i_bits, &m), shifted);
+            assert_eq!((&x).mod_shr(-i_bits, &m), shifted);
+            assert_eq!((&x).mod_shl(-i_bits, &m), (&residue).mod_shr(i_bits, &m));
+
+            let mut mut_x = x.clone();
+            mut_x.mod_shl_assign(bits, &m);
+            assert!(mut_x.is_valid());
+            assert_eq!(mut_x, shifted);
+        }
+    });
+}
@@ -0,0 +1,80 @@
+use malachite_base::num::arithmetic::traits::{Mod, ModShl, ModShr, ModShrAssign};
+use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_nz::integer::Integer;
+use malachite_nz::natural::Natural;
+use malachite_nz::test_util::generators::integer_natural_pair_gen;
+use std::panic::catch_unwind;
+use std::str::FromStr;
+
+#[test]
+fn test_mod_shr() {
+    let test = |s, bits: i64, t, out| {
+        let x = Integer::from_str(s).unwrap();
+        let m = Natural::from_str(t).unwrap();
+
+        assert_eq!(x.clone().mod_shr(bits, m.clone()).to_string(), out);
+        assert_eq!(x.clone().mod_shr(bits, &m).to_string(), out);
+        assert_eq!((&x).mod_shr(bits, m.clone()).to_string(), out);
+        assert_eq!((&x).mod_shr(bits, &m).to_string(), out);
+
+        let mut mut_x = x.clone();
+        mut_x.mod_shr_assign(bits, m.clone());
+        assert!(mut_x.is_valid());
+        assert_eq!(mut_x.to_string(), out);
+        let mut mut_x = x;
+        mut_x.mod_shr_assign(bits, &m);
+        assert_eq!(mut_x.to_string(), out);
+    };
+    test("0", 0, "1", "0");
+    test("-5", -10, "1", "0");
+    test("0", 5, "10", "0");
+    test("-3", -2, "5", "3");
+    test("-3", 1, "5", "1");
+    test("-12", 2, "10", "2");
+    test("-1", 1, "1000000000000", "499999999999");
+    test(
+        "-1000000000000000000000",
+        -5,
+        "18446744073709551617",
+        "5100967886072055495",
+    );
+    test(
+        "-1000000000000000000000",
+        5,
+        "18446744073709551617",
+        "455341376688291841",
+    );
+}
+
+#[test]
+fn mod_shr_fail() {
+    assert_panic!(Integer::from(1).mod_shr(2i8, Natural::from(0u32)));
+    assert_panic!((&Integer::from(-1)).mod_shr(-2i16, &Natural::from(0u32)));
+}
+
+#[test]
+fn mod_shr_properties() {
+    integer_natural_pair_gen().test_properties(|(x, m)| {
+        if m == 0u32 {
+            return;
+        }
+        let residue = Natural::exact_from((&x).mod_op(Integer::from(&m)));
+        for bits in [-100i64, -64, -5, -1, 0, 1, 5, 64, 100] {
+            let shifted = (&x).mod_shr(bits, &m);
+            assert!(shifted.is_valid());
+            assert!(shifted < m);
+            assert_eq!(x.clone().mod_shr(bits, m.clone()), shifted);
+            assert_eq!((&x).mod_shl(-bits, &m), shifted);
+            assert_eq!((&residue).mod_shr(bits, &m), shifted);
+            assert_eq!(
+                Integer::from(&shifted),
+                (Integer::from(&residue) >> bits).mod_op(Integer::from(&m))
+            );
+
+            let mut mut_x = x.clone();
+            mut_x.mod_shr_assign(bits, &m);
+            assert!(mut_x.is_valid());
+            assert_eq!(mut_x, shifted);
+        }
+    });
+}
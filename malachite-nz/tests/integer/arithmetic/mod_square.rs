@@ -0,0 +1,83 @@
+use malachite_base::num::arithmetic::traits::{Mod, ModMul, ModSquare, ModSquareAssign, Square};
+use malachite_base::test_util::generators::signed_pair_gen;
+use malachite_nz::integer::Integer;
+use malachite_nz::natural::Natural;
+use malachite_nz::test_util::generators::integer_natural_pair_gen;
+use std::panic::catch_unwind;
+use std::str::FromStr;
+
+#[test]
+fn test_mod_square() {
+    let test = |s, t, out| {
+        let x = Integer::from_str(s).unwrap();
+        let m = Natural::from_str(t).unwrap();
+
+        assert_eq!(x.clone().mod_square(m.clone()).to_string(), out);
+        assert_eq!(x.clone().mod_square(&m).to_string(), out);
+        assert_eq!((&x).mod_square(m.clone()).to_string(), out);
+        assert_eq!((&x).mod_square(&m).to_string(), out);
+
+        let mut mut_x = x.clone();
+        mut_x.mod_square_assign(m.clone());
+        assert!(mut_x.is_valid());
+        assert_eq!(mut_x.to_string(), out);
+        let mut mut_x = x;
+        mut_x.mod_square_assign(&m);
+        assert_eq!(mut_x.to_string(), out);
+    };
+    test("0", "1", "0");
+    test("-5", "1", "0");
+    test("0", "5", "0");
+    test("-3", "5", "4");
+    test("-12", "10", "4");
+    test("-1", "1000000000000", "1");
+    test(
+        "-1000000000000000000000",
+        "18446744073709551617",
+        "16678926943930100839",
+    );
+}
+
+#[test]
+fn mod_square_fail() {
+    assert_panic!(Integer::from(1).mod_square(Natural::from(0u32)));
+    assert_panic!((&Integer::from(-1)).mod_square(&Natural::from(0u32)));
+}
+
+#[test]
+fn mod_square_properties() {
+    integer_natural_pair_gen().test_properties(|(x, m)| {
+        if m == 0u32 {
+            return;
+        }
+        let square = (&x).mod_square(&m);
+        assert!(square.is_valid());
+        assert!(square < m);
+        assert_eq!(x.clone().mod_square(m.clone()), square);
+        assert_eq!((&x).mod_square(m.clone()), square);
+        assert_eq!(x.clone().mod_square(&m), square);
+
+        let mut mut_x = x.clone();
+        mut_x.mod_square_assign(&m);
+        assert!(mut_x.is_valid());
+        assert_eq!(mut_x, square);
+
+        assert_eq!(
+            Integer::from(&square),
+            (&x).square().mod_op(Integer::from(&m))
+        );
+        assert_eq!((&x).mod_mul(&x, &m), square);
+        assert_eq!((-&x).mod_square(&m), square);
+    });
+
+    signed_pair_gen::<i32>().test_properties(|(x, m)| {
+        if m == 0 {
+            return;
+        }
+        let m_abs = m.unsigned_abs();
+        assert_eq!(
+            Integer::from(x).mod_square(Natural::from(m_abs)),
+            (i64::from(x) * i64::from(x)).rem_euclid(i64::from(m_abs))
+        );
+    });
+}
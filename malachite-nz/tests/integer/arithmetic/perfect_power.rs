@@ -0,0 +1,78 @@
+use malachite_base::num::arithmetic::traits::{
+    IsPerfectPower, Parity, PerfectPowerDecomposition, Pow, UnsignedAbs,
+};
+use malachite_base::test_util::generators::signed_gen;
+use malachite_nz::integer::Integer;
+use malachite_nz::platform::SignedLimb;
+use malachite_nz::test_util::generators::integer_gen;
+use std::str::FromStr;
+
+#[test]
+fn test_perfect_power() {
+    let test = |s, is_perfect_power, base, exponent| {
+        let n = Integer::from_str(s).unwrap();
+        assert_eq!(n.is_perfect_power(), is_perfect_power);
+        let (b, k) = n.perfect_power_decomposition();
+        assert!(b.is_valid());
+        assert_eq!(b.to_string(), base);
+        assert_eq!(k, exponent);
+    };
+    test("0", true, "0", 1);
+    test("1", true, "1", 1);
+    test("-1", true, "-1", 1);
+    test("4", true, "2", 2);
+    test("-4", false, "-4", 1);
+    test("-8", true, "-2", 3);
+    test("64", true, "2", 6);
+    test("-64", true, "-4", 3);
+    test("-128", true, "-2", 7);
+    test("1000000", true, "10", 6);
+    test("-1000000", true, "-100", 3);
+    test("-4294967296", false, "-4294967296", 1);
+    test("-9223372036854775808", true, "-2", 63);
+    // -(2^128)
+    test(
+        "-340282366920938463463374607431768211456",
+        false,
+        "-340282366920938463463374607431768211456",
+        1,
+    );
+    // -(2^129)
+    test("-680564733841876926926749214863536422912", true, "-2", 129);
+    // -(3^81)
+    test("-443426488243037769948249630619149892803", true, "-3", 81);
+}
+
+#[test]
+fn perfect_power_properties() {
+    integer_gen().test_properties(|n| {
+        let (base, exponent) = n.perfect_power_decomposition();
+        assert!(base.is_valid());
+        assert_ne!(exponent, 0);
+        assert_eq!((&base).pow(exponent), n);
+        assert_eq!(
+            n.is_perfect_power(),
+            (&n).unsigned_abs() <= 1u32 || exponent > 1
+        );
+        if n < 0u32 {
+            assert!(exponent.odd());
+            assert!(base < 0u32);
+        }
+        if n >= 0u32 {
+            assert_eq!(n.is_perfect_power(), (&n).unsigned_abs().is_perfect_power());
+        }
+        let cube = (&n).pow(3);
+        assert!(cube.is_perfect_power());
+        assert!((-cube).is_perfect_power());
+    });
+
+    signed_gen::<SignedLimb>().test_properties(|i| {
+        let n = Integer::from(i);
+        assert_eq!(n.is_perfect_power(), i.is_perfect_power());
+        let (base, exponent) = i.perfect_power_decomposition();
+        assert_eq!(
+            n.perfect_power_decomposition(),
+            (Integer::from(base), exponent)
+        );
+    });
+}
@@ -44,12 +44,22 @@ extern crate malachite_nz;
 extern crate num;
 extern crate rug;
 
+pub mod gaussian_integer {
+    pub mod arithmetic;
+    pub mod basic;
+    pub mod div_rem;
+    pub mod factor;
+    pub mod gcd;
+}
 pub mod integer {
     pub mod arithmetic {
         pub mod abs;
         pub mod add;
         pub mod add_mul;
         pub mod binomial_coefficient;
+        pub mod checked_sub;
+        pub mod chinese_remainder;
+        pub mod coprime_with;
         pub mod div;
         pub mod div_exact;
         pub mod div_mod;
@@ -58,13 +68,37 @@ pub mod integer {
         pub mod divisible_by_power_of_2;
         pub mod eq_mod;
         pub mod eq_mod_power_of_2;
+        pub mod euler_number;
         pub mod extended_gcd;
+        pub mod gcd;
         pub mod kronecker_symbol;
+        pub mod lcm;
+        pub mod lucas_sequence;
+        pub mod mod_add;
+        pub mod mod_inverse;
+        pub mod mod_mul;
+        pub mod mod_natural;
+        pub mod mod_neg;
         pub mod mod_op;
+        pub mod mod_pow;
         pub mod mod_power_of_2;
+        pub mod mod_power_of_2_add;
+        pub mod mod_power_of_2_inverse;
+        pub mod mod_power_of_2_mul;
+        pub mod mod_power_of_2_neg;
+        pub mod mod_power_of_2_pow;
+        pub mod mod_power_of_2_shl;
+        pub mod mod_power_of_2_shr;
+        pub mod mod_power_of_2_square;
+        pub mod mod_power_of_2_sub;
+        pub mod mod_shl;
+        pub mod mod_shr;
+        pub mod mod_square;
+        pub mod mod_sub;
         pub mod mul;
         pub mod neg;
         pub mod parity;
+        pub mod perfect_power;
         pub mod pow;
         pub mod power_of_2;
         pub mod root;
@@ -190,8 +224,10 @@ pub mod natural {
         pub mod add;
         pub mod add_mul;
         pub mod binomial_coefficient;
+        pub mod catalan;
         pub mod checked_sub;
         pub mod checked_sub_mul;
+        pub mod chinese_remainder;
         pub mod coprime_with;
         pub mod div;
         pub mod div_exact;
@@ -199,10 +235,12 @@ pub mod natural {
         pub mod div_round;
         pub mod divisible_by;
         pub mod divisible_by_power_of_2;
+        pub mod divisors;
         pub mod eq_mod;
         pub mod eq_mod_power_of_2;
         pub mod extended_gcd;
         pub mod factorial;
+        pub mod fibonacci;
         pub mod gcd;
         pub mod is_power_of_2;
         pub mod kronecker_symbol;
@@ -211,8 +249,11 @@ pub mod natural {
         pub mod log_base_2;
         pub mod log_base_power_of_2;
         pub mod mod_add;
+        pub mod mod_binomial_coefficient;
+        pub mod mod_factorial;
         pub mod mod_inverse;
         pub mod mod_is_reduced;
+        pub mod mod_log;
         pub mod mod_mul;
         pub mod mod_neg;
         pub mod mod_op;
@@ -230,14 +271,22 @@ pub mod natural {
         pub mod mod_power_of_2_sub;
         pub mod mod_shl;
         pub mod mod_shr;
+        pub mod mod_sqrt;
         pub mod mod_square;
         pub mod mod_sub;
+        pub mod moebius;
+        pub mod montgomery;
         pub mod mul;
+        pub mod multinomial;
+        pub mod multiplicative_order;
         pub mod neg;
         pub mod next_power_of_2;
         pub mod parity;
+        pub mod partition_count;
+        pub mod perfect_power;
         pub mod pow;
         pub mod power_of_2;
+        pub mod precomputed_divisor;
         pub mod primorial;
         pub mod root;
         pub mod round_to_multiple;
@@ -251,8 +300,10 @@ pub mod natural {
         pub mod sign;
         pub mod sqrt;
         pub mod square;
+        pub mod stirling;
         pub mod sub;
         pub mod sub_mul;
+        pub mod totient;
     }
     pub mod basic {
         pub mod constants;
@@ -309,7 +360,12 @@ pub mod natural {
         pub mod exhaustive_positive_naturals;
     }
     pub mod factorization {
+        pub mod factor;
+        pub mod is_prime;
+        pub mod next_prime;
+        pub mod prime_count;
         pub mod primes;
+        pub mod primes_in_range;
     }
     pub mod logic {
         pub mod and;
@@ -346,11 +402,17 @@ pub mod natural {
         pub mod random_naturals;
         pub mod random_naturals_less_than;
         pub mod random_positive_naturals;
+        pub mod random_primes_in_range;
+        pub mod random_primes_with_bits;
+        pub mod random_safe_primes_with_bits;
         pub mod striped_random_natural_inclusive_range;
         pub mod striped_random_natural_range;
         pub mod striped_random_natural_range_to_infinity;
         pub mod striped_random_naturals;
         pub mod striped_random_positive_naturals;
+        pub mod striped_random_primes_in_range;
+        pub mod striped_random_primes_with_bits;
+        pub mod striped_random_safe_primes_with_bits;
         pub mod uniform_random_natural_inclusive_range;
         pub mod uniform_random_natural_range;
     }
@@ -0,0 +1,63 @@
+use malachite_base::num::arithmetic::traits::{BinomialCoefficient, Catalan, CheckedCatalan};
+use malachite_base::num::basic::traits::Zero;
+use malachite_base::test_util::generators::{unsigned_gen_var_5, unsigned_gen_var_7};
+use malachite_nz::natural::Natural;
+use malachite_nz::platform::Limb;
+
+#[test]
+fn test_catalan() {
+    fn test(n: u64, out: &str) {
+        let c = Natural::catalan(n);
+        assert!(c.is_valid());
+        assert_eq!(c.to_string(), out);
+    }
+    test(0, "1");
+    test(1, "1");
+    test(2, "2");
+    test(3, "5");
+    test(4, "14");
+    test(5, "42");
+    test(10, "16796");
+    test(
+        100,
+        "896519947090131496687170070074100632420837521538745909320",
+    );
+    test(
+        200,
+        "512201493211017079467541693136328292324432464582475861864920694407578768023144072628540\
+        276213813397768975366156750120",
+    );
+}
+
+#[test]
+fn catalan_properties() {
+    unsigned_gen_var_5().test_properties(|n| {
+        let c = Natural::catalan(n);
+        assert!(c.is_valid());
+        assert_ne!(c, 0u32);
+        // $C_n = \binom{2n}{n} - \binom{2n}{n + 1}$
+        let two_n = Natural::from(n << 1);
+        assert_eq!(
+            c,
+            Natural::binomial_coefficient(&two_n, &Natural::from(n))
+                - Natural::binomial_coefficient(&two_n, &Natural::from(n + 1))
+        );
+        // $(n + 2)C_{n+1} = 2(2n + 1)C_n$
+        assert_eq!(
+            Natural::from(n + 2) * Natural::catalan(n + 1),
+            Natural::from((n << 2) + 2) * c
+        );
+        if let Some(c) = Limb::checked_catalan(n) {
+            assert_eq!(Natural::catalan(n), c);
+        }
+    });
+
+    unsigned_gen_var_7::<u64>().test_properties(|n| {
+        // $C_{n+1} = \sum_{i=0}^n C_iC_{n-i}$
+        let mut sum = Natural::ZERO;
+        for i in 0..=n {
+            sum += Natural::catalan(i) * Natural::catalan(n - i);
+        }
+        assert_eq!(sum, Natural::catalan(n + 1));
+    });
+}
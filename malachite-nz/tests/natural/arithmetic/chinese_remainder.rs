@@ -0,0 +1,187 @@
+use malachite_base::num::arithmetic::traits::{
+    ChineseRemainder, CoprimeWith, DivisibleBy, Gcd, Lcm, Pow,
+};
+use malachite_base::num::basic::traits::{One, Zero};
+use malachite_base::strings::ToDebugString;
+use malachite_base::test_util::generators::signed_quadruple_gen;
+use malachite_nz::natural::arithmetic::chinese_remainder::ChineseRemainderBasis;
+use malachite_nz::natural::Natural;
+use malachite_nz::test_util::generators::natural_gen_var_2;
+use std::panic::catch_unwind;
+use std::str::FromStr;
+
+fn residues(xs: &[(&str, &str)]) -> Vec<(Natural, Natural)> {
+    xs.iter()
+        .map(|&(a, m)| (Natural::from_str(a).unwrap(), Natural::from_str(m).unwrap()))
+        .collect()
+}
+
+#[test]
+fn test_chinese_remainder() {
+    let test = |xs: &[(&str, &str)], out: &str| {
+        assert_eq!(
+            Natural::chinese_remainder(&residues(xs)).to_debug_string(),
+            out
+        );
+    };
+    test(&[], "Some((0, 1))");
+    test(&[("5", "7")], "Some((5, 7))");
+    test(&[("12", "7")], "Some((5, 7))");
+    test(&[("5", "1")], "Some((0, 1))");
+    test(&[("2", "3"), ("3", "5"), ("2", "7")], "Some((23, 105))");
+    test(&[("2", "4"), ("4", "6")], "Some((10, 12))");
+    test(&[("1", "4"), ("2", "6")], "None");
+    test(&[("3", "6"), ("3", "6")], "Some((3, 6))");
+    test(&[("3", "6"), ("4", "6")], "None");
+    test(&[("1", "2"), ("1", "4"), ("5", "8")], "Some((5, 8))");
+    test(&[("1", "2"), ("5", "8"), ("1", "4")], "Some((5, 8))");
+    test(&[("1", "2"), ("5", "8"), ("3", "4")], "None");
+    test(
+        &[
+            ("1", "18446744073709551616"),
+            ("2", "18446744073709551617"),
+            ("3", "18446744073709551615"),
+        ],
+        "Some((3138550867693340382428318261985240903246239633379669573633, \
+        6277101735386680763835789423207666416083908700390324961280))",
+    );
+    test(
+        &[
+            ("1000000000000", "1000000000000000000000"),
+            ("1000000000000", "1000000000000000000000000"),
+        ],
+        "Some((1000000000000, 1000000000000000000000000))",
+    );
+    test(
+        &[("1", "1000000000000000000000"), ("2", "1000000000000000000000000")],
+        "None",
+    );
+}
+
+#[test]
+fn chinese_remainder_fail() {
+    assert_panic!(Natural::chinese_remainder(&residues(&[("1", "0")])));
+    assert_panic!(Natural::chinese_remainder(&residues(&[
+        ("1", "2"),
+        ("0", "0")
+    ])));
+}
+
+#[test]
+fn test_chinese_remainder_basis() {
+    let test = |moduli: &[&str], rs: &[&str], out: &str| {
+        let moduli: Vec<Natural> = moduli
+            .iter()
+            .map(|s| Natural::from_str(s).unwrap())
+            .collect();
+        let rs: Vec<Natural> = rs.iter().map(|s| Natural::from_str(s).unwrap()).collect();
+        let basis = ChineseRemainderBasis::new(&moduli).unwrap();
+        assert_eq!(basis.moduli(), moduli);
+        let x = basis.recombine(&rs);
+        assert_eq!(x.to_string(), out);
+        assert!(x < *basis.modulus());
+        let pairs: Vec<(Natural, Natural)> = rs.into_iter().zip(moduli.into_iter()).collect();
+        assert_eq!(Natural::chinese_remainder(&pairs).unwrap().0, x);
+        let reduced = basis.reduce(&x);
+        assert_eq!(
+            reduced,
+            pairs.iter().map(|(a, m)| a % m).collect::<Vec<_>>()
+        );
+    };
+    test(&[], &[], "0");
+    test(&["1"], &["5"], "0");
+    test(&["7"], &["5"], "5");
+    test(&["1", "7", "1"], &["3", "12", "4"], "5");
+    test(&["3", "5", "7"], &["2", "3", "2"], "23");
+    test(&["3", "5", "7"], &["5", "8", "100"], "23");
+    test(
+        &["2", "3", "5", "7", "11"],
+        &["1", "2", "3", "4", "5"],
+        "1523",
+    );
+    test(
+        &["18446744073709551616", "18446744073709551617", "18446744073709551615"],
+        &["1", "2", "3"],
+        "3138550867693340382428318261985240903246239633379669573633",
+    );
+}
+
+#[test]
+fn test_chinese_remainder_basis_new() {
+    let test = |moduli: &[u32], out: bool| {
+        let moduli: Vec<Natural> = moduli.iter().copied().map(Natural::from).collect();
+        assert_eq!(ChineseRemainderBasis::new(&moduli).is_some(), out);
+    };
+    test(&[], true);
+    test(&[1], true);
+    test(&[1, 1], true);
+    test(&[2, 2], false);
+    test(&[4, 6], false);
+    test(&[3, 5, 7], true);
+    test(&[3, 5, 9], false);
+    test(&[6, 35, 143], true);
+    test(&[6, 35, 143, 221], false);
+}
+
+#[test]
+fn chinese_remainder_basis_fail() {
+    assert_panic!(ChineseRemainderBasis::new(&[Natural::ZERO]));
+    assert_panic!(ChineseRemainderBasis::new(&[
+        Natural::from(3u32),
+        Natural::ZERO
+    ]));
+    let basis = ChineseRemainderBasis::new(&[Natural::from(3u32), Natural::from(5u32)]).unwrap();
+    assert_panic!(basis.recombine(&[Natural::ONE]));
+}
+
+#[test]
+fn chinese_remainder_properties() {
+    signed_quadruple_gen::<i32>().test_properties(|(a, b, m, n)| {
+        let a = Natural::from(a.unsigned_abs());
+        let b = Natural::from(b.unsigned_abs());
+        let m = Natural::from(m.unsigned_abs());
+        let n = Natural::from(n.unsigned_abs());
+        if m == 0u32 || n == 0u32 {
+            return;
+        }
+        let pairs = [(a.clone(), m.clone()), (b.clone(), n.clone())];
+        let result = Natural::chinese_remainder(&pairs);
+        let g = (&m).gcd(&n);
+        let consistent = if a >= b {
+            (&a - &b).divisible_by(&g)
+        } else {
+            (&b - &a).divisible_by(&g)
+        };
+        assert_eq!(result.is_some(), consistent);
+        if let Some((x, l)) = result {
+            assert_eq!(l, (&m).lcm(&n));
+            assert!(x < l);
+            assert_eq!(&x % &m, &a % &m);
+            assert_eq!(&x % &n, &b % &n);
+            let reversed = [(b, n.clone()), (a, m.clone())];
+            assert_eq!(Natural::chinese_remainder(&reversed), Some((x, l)));
+        }
+        if m.coprime_with(n) {
+            assert!(Natural::chinese_remainder(&pairs).is_some());
+        }
+    });
+
+    natural_gen_var_2().test_properties(|n| {
+        // n, n + 1, and 2n + 1 are pairwise coprime.
+        let moduli = [n.clone(), &n + Natural::ONE, (&n << 1u32) + Natural::ONE];
+        let basis = ChineseRemainderBasis::new(&moduli).unwrap();
+        assert_eq!(
+            *basis.modulus(),
+            moduli.iter().fold(Natural::ONE, |acc, m| acc * m)
+        );
+        let x = (&n).pow(3) + Natural::from(5u32);
+        let rs = basis.reduce(&x);
+        let y = basis.recombine(&rs);
+        assert_eq!(y, &x % basis.modulus());
+        let pairs: Vec<(Natural, Natural)> = rs.into_iter().zip(moduli.into_iter()).collect();
+        assert_eq!(
+            Natural::chinese_remainder(&pairs),
+            Some((y, basis.modulus().clone()))
+        );
+    });
+}
@@ -0,0 +1,167 @@
+use itertools::Itertools;
+use malachite_base::iterators::comparison::is_strictly_ascending;
+use malachite_base::num::arithmetic::traits::{
+    CoprimeWith, DivisibleBy, DivisorCount, DivisorSum, Divisors, Pow,
+};
+use malachite_base::num::basic::traits::{One, Zero};
+use malachite_base::num::factorization::traits::{Factor, IsPrime};
+use malachite_base::num::logic::traits::SignificantBits;
+use malachite_base::strings::ToDebugString;
+use malachite_base::test_util::generators::{unsigned_gen_var_1, unsigned_pair_gen_var_27};
+use malachite_nz::natural::Natural;
+use malachite_nz::test_util::generators::natural_gen_var_2;
+use std::panic::catch_unwind;
+use std::str::FromStr;
+
+#[test]
+fn test_divisor_count() {
+    let test = |s: &str, out: u64| {
+        let n = Natural::from_str(s).unwrap();
+        assert_eq!(n.divisor_count(), out);
+        assert_eq!(Natural::divisor_count_from_factorization(&n.factor()), out);
+    };
+    test("1", 1);
+    test("2", 2);
+    test("360", 24);
+    test("18446744073709551615", 128);
+    test("18446744073709551617", 4);
+    test("1000000000000000000000000000000", 961);
+    // 2^100 - 1
+    test("1267650600228229401496703205375", 8192);
+}
+
+#[test]
+fn divisor_count_fail() {
+    assert_panic!(Natural::from(0u32).divisor_count());
+}
+
+#[test]
+fn test_divisor_sum() {
+    let test = |s: &str, k: u64, out: &str| {
+        let n = Natural::from_str(s).unwrap();
+        let out = Natural::from_str(out).unwrap();
+        assert_eq!(n.divisor_sum(k), out);
+        assert_eq!(Natural::divisor_sum_from_factorization(&n.factor(), k), out);
+    };
+    test("1", 0, "1");
+    test("1", 5, "1");
+    test("2", 1, "3");
+    test("360", 0, "24");
+    test("360", 1, "1170");
+    test("360", 2, "201110");
+    test("18446744073709551615", 1, "31421980989189888768");
+    test(
+        "18446744073709551617",
+        2,
+        "340282366925465118592016216137989881860",
+    );
+    test(
+        "1000000000000000000000000000000",
+        1,
+        "2499999998835846781730114984557",
+    );
+    test(
+        "1000000000000000000000000000000",
+        2,
+        "1388888888888888888587721618754026546109040824596043713112451",
+    );
+}
+
+#[test]
+fn divisor_sum_fail() {
+    assert_panic!(Natural::from(0u32).divisor_sum(1));
+}
+
+#[test]
+fn test_divisors() {
+    let test = |s: &str, out: &str| {
+        let n = Natural::from_str(s).unwrap();
+        assert_eq!(n.divisors().collect_vec().to_debug_string(), out);
+        assert_eq!(
+            Natural::divisors_from_factorization(&n.factor())
+                .collect_vec()
+                .to_debug_string(),
+            out
+        );
+    };
+    test("1", "[1]");
+    test("2", "[1, 2]");
+    test(
+        "360",
+        "[1, 2, 3, 4, 5, 6, 8, 9, 10, 12, 15, 18, 20, 24, 30, 36, 40, 45, 60, 72, 90, 120, 180, \
+        360]",
+    );
+    test(
+        "18446744073709551617",
+        "[1, 274177, 67280421310721, 18446744073709551617]",
+    );
+    // 2^89 - 1
+    test(
+        "618970019642690137449562111",
+        "[1, 618970019642690137449562111]",
+    );
+}
+
+#[test]
+fn divisors_fail() {
+    assert_panic!(Natural::from(0u32).divisors());
+}
+
+#[test]
+fn divisors_properties() {
+    // Numbers with more bits may have two large prime factors, which are too slow to find in tests
+    natural_gen_var_2().test_properties(|n| {
+        if n.significant_bits() > 80 {
+            return;
+        }
+        let factors = n.factor();
+        let divisors = n.divisors().collect_vec();
+        assert!(is_strictly_ascending(divisors.iter()));
+        assert_eq!(divisors.first(), Some(&Natural::ONE));
+        assert_eq!(divisors.last(), Some(&n));
+        assert!(divisors.iter().all(|d| (&n).divisible_by(d)));
+        assert_eq!(
+            Natural::divisors_from_factorization(&factors).collect_vec(),
+            divisors
+        );
+
+        let count = Natural::divisor_count_from_factorization(&factors);
+        assert_eq!(count, divisors.len());
+        assert_eq!(Natural::divisor_sum_from_factorization(&factors, 0), count);
+        assert_eq!(count == 2u32, n.is_prime());
+
+        let sum: Natural = divisors.iter().sum();
+        assert_eq!(Natural::divisor_sum_from_factorization(&factors, 1), sum);
+        let sum_of_squares = divisors.iter().fold(Natural::ZERO, |acc, d| acc + d.pow(2));
+        assert_eq!(
+            Natural::divisor_sum_from_factorization(&factors, 2),
+            sum_of_squares
+        );
+    });
+
+    unsigned_gen_var_1::<u64>().test_properties(|n| {
+        let m = Natural::from(n);
+        assert_eq!(m.divisor_count(), n.divisor_count());
+        assert_eq!(m.divisor_sum(1), u128::from(n).divisor_sum(1));
+        assert_eq!(
+            m.divisors().collect_vec(),
+            n.divisors().map(Natural::from).collect_vec()
+        );
+    });
+
+    unsigned_pair_gen_var_27::<u32>().test_properties(|(x, y)| {
+        if x == 0 || y == 0 || !x.coprime_with(y) {
+            return;
+        }
+        let x = Natural::from(x);
+        let y = Natural::from(y);
+        assert_eq!(
+            (&x * &y).divisor_count(),
+            x.divisor_count() * y.divisor_count()
+        );
+        assert_eq!(
+            (&x * &y).divisor_sum(1),
+            x.divisor_sum(1) * y.divisor_sum(1)
+        );
+    });
+}
@@ -0,0 +1,246 @@
+use malachite_base::num::arithmetic::traits::{
+    CheckedFibonacci, CheckedLucas, Fibonacci, Lucas, ModFibonacci, ModLucas, ModLucasSequence,
+    Square,
+};
+use malachite_base::num::basic::traits::One;
+use malachite_base::test_util::generators::{
+    unsigned_gen, unsigned_gen_var_5, unsigned_pair_gen_var_12, unsigned_quadruple_gen_var_6,
+};
+use malachite_nz::natural::Natural;
+use malachite_nz::platform::Limb;
+use malachite_nz::test_util::generators::natural_quadruple_gen_var_2;
+use std::panic::catch_unwind;
+use std::str::FromStr;
+
+#[test]
+fn test_fibonacci() {
+    let test = |n, out| {
+        let f = Natural::fibonacci(n);
+        assert!(f.is_valid());
+        assert_eq!(f.to_string(), out);
+    };
+    test(0, "0");
+    test(1, "1");
+    test(2, "1");
+    test(10, "55");
+    test(93, "12200160415121876738");
+    test(94, "19740274219868223167");
+    test(95, "31940434634990099905");
+    test(150, "9969216677189303386214405760200");
+    test(
+        1000,
+        "434665576869374564356885276750406258025646605173717804024817290895365554179490518904038798\
+        400792551692959225930803226347752096896232398733224711616429964409065331879382989696499285\
+        16003704476137795166849228875",
+    );
+}
+
+#[test]
+fn test_lucas() {
+    let test = |n, out| {
+        let l = Natural::lucas(n);
+        assert!(l.is_valid());
+        assert_eq!(l.to_string(), out);
+    };
+    test(0, "2");
+    test(1, "1");
+    test(2, "3");
+    test(10, "123");
+    test(92, "16860207025497407047");
+    test(93, "27280388024614569596");
+    test(94, "44140595050111976643");
+    test(151, "36068964779283188188226718906749");
+    test(
+        1000,
+        "971941777359081752079819820793264737377978791553456850827280810847725188184448152690806191\
+        490459682976795783054032093474011630369076605739717408624637518016412014902840973090963226\
+        81531675707666695323797578127",
+    );
+}
+
+#[test]
+fn test_mod_fibonacci_and_lucas() {
+    let test = |n: &str, m: &str, f_out: &str, l_out: &str| {
+        let n = Natural::from_str(n).unwrap();
+        let m = Natural::from_str(m).unwrap();
+        let f = Natural::mod_fibonacci(n.clone(), m.clone());
+        assert!(f.is_valid());
+        assert_eq!(f.to_string(), f_out);
+        let l = Natural::mod_lucas(n.clone(), m.clone());
+        assert!(l.is_valid());
+        assert_eq!(l.to_string(), l_out);
+        if let Ok(n) = u64::try_from(&n) {
+            assert_eq!(Natural::mod_fibonacci(n, m.clone()), f);
+            assert_eq!(Natural::mod_lucas(n, m), l);
+        }
+    };
+    test("0", "1", "0", "0");
+    test("0", "10", "0", "2");
+    test("10", "16", "7", "11");
+    test("100", "1000000007", "687995182", "876413006");
+    test("1000000008", "1000000007", "0", "1000000005");
+    test(
+        "618970019642690137449562110",
+        "618970019642690137449562111",
+        "0",
+        "2",
+    );
+    test(
+        "1000000000000000000000000000000",
+        "1000000000000000000000",
+        "359183788299560546875",
+        "823231220245361328127",
+    );
+}
+
+#[test]
+fn mod_fibonacci_fail() {
+    assert_panic!(Natural::mod_fibonacci(10, Natural::from(0u32)));
+    assert_panic!(Natural::mod_lucas(10, Natural::from(0u32)));
+}
+
+#[test]
+fn test_mod_lucas_sequence() {
+    let test = |p: &str, q: &str, n: &str, m: &str, u_out: &str, v_out: &str| {
+        let p = Natural::from_str(p).unwrap();
+        let q = Natural::from_str(q).unwrap();
+        let n = Natural::from_str(n).unwrap();
+        let m = Natural::from_str(m).unwrap();
+        let (u, v) = Natural::mod_lucas_sequence(p.clone(), q.clone(), n.clone(), m.clone());
+        assert!(u.is_valid());
+        assert!(v.is_valid());
+        assert_eq!(u.to_string(), u_out);
+        assert_eq!(v.to_string(), v_out);
+        if let Ok(n) = u64::try_from(&n) {
+            assert_eq!(Natural::mod_lucas_sequence(p, q, n, m), (u, v));
+        }
+    };
+    test("0", "0", "0", "1", "0", "0");
+    test("0", "0", "0", "3", "0", "2");
+    test("3", "1", "10", "100", "65", "27");
+    test("4", "2", "1000", "7", "6", "3");
+    test("5", "3", "100", "1000000007", "150049008", "448234207");
+    test(
+        "2",
+        "1",
+        "123456789012345678901234567890",
+        "1000000000000",
+        "901234567890",
+        "2",
+    );
+    // 2^127 - 1 is prime and D = 5 is not a square modulo it
+    test(
+        "1",
+        "170141183460469231731687303715884105726",
+        "170141183460469231731687303715884105728",
+        "170141183460469231731687303715884105727",
+        "0",
+        "170141183460469231731687303715884105725",
+    );
+}
+
+#[test]
+fn mod_lucas_sequence_fail() {
+    assert_panic!(Natural::mod_lucas_sequence(
+        Natural::from(3u32),
+        Natural::ONE,
+        10,
+        Natural::from(3u32)
+    ));
+    assert_panic!(Natural::mod_lucas_sequence(
+        Natural::ONE,
+        Natural::from(3u32),
+        10,
+        Natural::from(3u32)
+    ));
+}
+
+#[test]
+fn fibonacci_properties() {
+    unsigned_gen_var_5().test_properties(|n| {
+        let f = Natural::fibonacci(n);
+        assert!(f.is_valid());
+        let f_next = Natural::fibonacci(n + 1);
+        assert_eq!(Natural::fibonacci(n + 2), &f + &f_next);
+        // Cassini's identity: F_{n+1}^2 - F_n F_{n+2} = (-1)^n
+        let lhs = (&f_next).square();
+        let rhs = &f * (&f + &f_next);
+        if n % 2 == 0 {
+            assert_eq!(lhs, rhs + Natural::ONE);
+        } else {
+            assert_eq!(lhs + Natural::ONE, rhs);
+        }
+        // F_{2n} = F_n L_n
+        assert_eq!(Natural::fibonacci(n << 1), &f * Natural::lucas(n));
+    });
+
+    unsigned_gen().test_properties(|n| {
+        if let Some(f) = Limb::checked_fibonacci(n) {
+            assert_eq!(Natural::fibonacci(n), f);
+        }
+    });
+}
+
+#[test]
+fn lucas_properties() {
+    unsigned_gen_var_5().test_properties(|n| {
+        let l = Natural::lucas(n);
+        assert!(l.is_valid());
+        assert_eq!(Natural::lucas(n + 2), &l + Natural::lucas(n + 1));
+        // L_n = F_{n-1} + F_{n+1}
+        if n != 0 {
+            assert_eq!(l, Natural::fibonacci(n - 1) + Natural::fibonacci(n + 1));
+        }
+    });
+
+    unsigned_gen().test_properties(|n| {
+        if let Some(l) = Limb::checked_lucas(n) {
+            assert_eq!(Natural::lucas(n), l);
+        }
+    });
+}
+
+#[test]
+fn mod_lucas_sequence_properties() {
+    natural_quadruple_gen_var_2().test_properties(|(p, q, n, m)| {
+        let (u, v) = Natural::mod_lucas_sequence(p.clone(), q.clone(), n.clone(), m.clone());
+        assert!(u.is_valid());
+        assert!(v.is_valid());
+        assert!(u < m);
+        assert!(v < m);
+        let f = Natural::mod_fibonacci(n.clone(), m.clone());
+        let l = Natural::mod_lucas(n.clone(), m.clone());
+        assert_eq!(
+            Natural::mod_lucas_sequence(Natural::ONE % &m, &m - Natural::ONE, n.clone(), m.clone()),
+            (f, l)
+        );
+        if let Ok(small_n) = u64::try_from(&n) {
+            assert_eq!(
+                Natural::mod_lucas_sequence(p.clone(), q.clone(), small_n, m.clone()),
+                (u.clone(), v.clone())
+            );
+        }
+        // U_{2n} = U_n V_n
+        let (u_double, _) = Natural::mod_lucas_sequence(p, q, n << 1, m.clone());
+        assert_eq!(u_double, u * v % m);
+    });
+
+    unsigned_quadruple_gen_var_6::<Limb, u64>().test_properties(|(p, q, n, m)| {
+        let (u, v) = Limb::mod_lucas_sequence(p, q, n, m);
+        assert_eq!(
+            Natural::mod_lucas_sequence(Natural::from(p), Natural::from(q), n, Natural::from(m)),
+            (Natural::from(u), Natural::from(v))
+        );
+    });
+
+    unsigned_pair_gen_var_12::<u64, Limb>().test_properties(|(n, m)| {
+        assert_eq!(
+            Natural::mod_fibonacci(n, Natural::from(m)),
+            Limb::mod_fibonacci(n, m)
+        );
+        assert_eq!(
+            Natural::mod_lucas(n, Natural::from(m)),
+            Limb::mod_lucas(n, m)
+        );
+    });
+}
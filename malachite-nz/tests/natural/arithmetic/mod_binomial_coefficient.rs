@@ -0,0 +1,94 @@
+use malachite_base::num::arithmetic::traits::{BinomialCoefficient, ModBinomialCoefficient};
+use malachite_base::test_util::generators::common::TINY_LIMIT;
+use malachite_base::test_util::generators::unsigned_triple_gen_var_24;
+use malachite_nz::natural::Natural;
+use malachite_nz::platform::Limb;
+use malachite_nz::test_util::generators::natural_triple_gen_var_10;
+use std::panic::catch_unwind;
+use std::str::FromStr;
+
+#[test]
+fn test_mod_binomial_coefficient() {
+    fn test(n: &str, k: &str, m: &str, out: &str) {
+        let n = Natural::from_str(n).unwrap();
+        let k = Natural::from_str(k).unwrap();
+        let m = Natural::from_str(m).unwrap();
+        let b = Natural::mod_binomial_coefficient(n.clone(), k.clone(), m.clone());
+        assert!(b.is_valid());
+        assert_eq!(b.to_string(), out);
+
+        let b_alt = Natural::mod_binomial_coefficient(&n, &k, &m);
+        assert!(b_alt.is_valid());
+        assert_eq!(b_alt, b);
+    }
+    test("0", "0", "1", "0");
+    test("0", "0", "2", "1");
+    test("5", "6", "7", "0");
+    test("10", "3", "7", "1");
+    test("100", "50", "1000", "256");
+    test("1000", "500", "10000000000", "9821216320");
+    test(
+        "1000000000000000000000000000000",
+        "3",
+        "1000000007",
+        "55798620",
+    );
+    test(
+        "1000000000000000000000000000000",
+        "12345",
+        "1000000007",
+        "624000094",
+    );
+    test(
+        "100",
+        "50",
+        "717897987691852588770249",
+        "115049314306066808013543",
+    );
+    test(
+        "200",
+        "100",
+        "1267650600228229401496703205376",
+        "960421375634077641479271570536",
+    );
+    test(
+        "300",
+        "150",
+        "1159445329576199417209625244140625",
+        "783186134648517480965110474689549",
+    );
+}
+
+#[test]
+fn mod_binomial_coefficient_fail() {
+    assert_panic!(Natural::mod_binomial_coefficient(
+        Natural::from(1u32),
+        Natural::from(1u32),
+        Natural::from(0u32)
+    ));
+}
+
+#[test]
+fn mod_binomial_coefficient_properties() {
+    unsigned_triple_gen_var_24::<Limb>().test_properties(|(n, k, m)| {
+        let b =
+            Natural::mod_binomial_coefficient(Natural::from(n), Natural::from(k), Natural::from(m));
+        assert!(b.is_valid());
+        assert_eq!(b, Limb::mod_binomial_coefficient(n, k, m));
+        assert_eq!(
+            b,
+            Natural::binomial_coefficient(Natural::from(n), Natural::from(k)) % Natural::from(m)
+        );
+    });
+
+    natural_triple_gen_var_10().test_properties_with_limit(TINY_LIMIT, |(n, k, m)| {
+        let b = Natural::mod_binomial_coefficient(&n, &k, &m);
+        assert!(b.is_valid());
+        assert!(b < m);
+        assert_eq!(
+            Natural::mod_binomial_coefficient(n.clone(), k.clone(), m.clone()),
+            b
+        );
+        assert_eq!(b, Natural::binomial_coefficient(n, k) % m);
+    });
+}
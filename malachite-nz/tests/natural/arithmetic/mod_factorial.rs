@@ -0,0 +1,73 @@
+use malachite_base::num::arithmetic::traits::{Factorial, ModFactorial};
+use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_base::test_util::generators::common::TINY_LIMIT;
+use malachite_base::test_util::generators::unsigned_triple_gen_var_24;
+use malachite_nz::natural::Natural;
+use malachite_nz::platform::Limb;
+use malachite_nz::test_util::generators::natural_pair_gen_var_16;
+use std::panic::catch_unwind;
+use std::str::FromStr;
+
+#[test]
+fn test_mod_factorial() {
+    fn test(n: &str, m: &str, out: &str) {
+        let n = Natural::from_str(n).unwrap();
+        let m = Natural::from_str(m).unwrap();
+        let f = Natural::mod_factorial(n.clone(), m.clone());
+        assert!(f.is_valid());
+        assert_eq!(f.to_string(), out);
+
+        let f_alt = Natural::mod_factorial(&n, &m);
+        assert!(f_alt.is_valid());
+        assert_eq!(f_alt, f);
+    }
+    test("0", "1", "0");
+    test("0", "2", "1");
+    test("6", "7", "6");
+    test("10", "7", "0");
+    test("12", "1001", "77");
+    test("30", "1099511627776", "950597058560");
+    test("1000000000", "1000000007", "698611116");
+    test(
+        "50",
+        "618970019642690137449562111",
+        "235364994304100179268860335",
+    );
+    test(
+        "100",
+        "10000000000000000000000000000000000000000",
+        "8251185210916864000000000000000000000000",
+    );
+    test("1000", "1000000000000000000000000000000", "0");
+}
+
+#[test]
+fn mod_factorial_fail() {
+    assert_panic!(Natural::mod_factorial(
+        Natural::from(1u32),
+        Natural::from(0u32)
+    ));
+}
+
+#[test]
+fn mod_factorial_properties() {
+    unsigned_triple_gen_var_24::<Limb>().test_properties(|(n, _, m)| {
+        let f = Natural::mod_factorial(Natural::from(n), Natural::from(m));
+        assert!(f.is_valid());
+        assert!(f < m);
+        assert_eq!(f, Limb::mod_factorial(n, m));
+        let n_plus_1 = Natural::from(n) + Natural::from(1u32);
+        assert_eq!(
+            Natural::mod_factorial(&n_plus_1, &Natural::from(m)),
+            f * n_plus_1 % Natural::from(m)
+        );
+    });
+
+    natural_pair_gen_var_16().test_properties_with_limit(TINY_LIMIT, |(n, m)| {
+        let f = Natural::mod_factorial(&n, &m);
+        assert!(f.is_valid());
+        assert!(f < m);
+        assert_eq!(Natural::mod_factorial(n.clone(), m.clone()), f);
+        assert_eq!(f, Natural::factorial(u64::exact_from(&n)) % m);
+    });
+}
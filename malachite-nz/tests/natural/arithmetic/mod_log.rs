@@ -0,0 +1,136 @@
+use malachite_base::num::arithmetic::traits::{ModLog, ModPow};
+use malachite_base::test_util::generators::unsigned_triple_gen_var_12;
+use malachite_nz::natural::arithmetic::mod_log::{
+    mod_log_baby_step_giant_step, mod_log_pollard_rho,
+};
+use malachite_nz::natural::Natural;
+use std::panic::catch_unwind;
+use std::str::FromStr;
+
+#[test]
+fn test_mod_log() {
+    let test = |x, base, m, out: Option<&str>| {
+        let x = Natural::from_str(x).unwrap();
+        let base = Natural::from_str(base).unwrap();
+        let m = Natural::from_str(m).unwrap();
+        let out = out.map(|s| Natural::from_str(s).unwrap());
+        let result = x.clone().mod_log(base.clone(), m.clone());
+        assert!(result.as_ref().map_or(true, Natural::is_valid));
+        assert_eq!(result, out);
+        assert_eq!(x.clone().mod_log(&base, &m), out);
+        assert_eq!((&x).mod_log(base.clone(), m.clone()), out);
+        assert_eq!((&x).mod_log(&base, &m), out);
+    };
+    test("0", "0", "1", Some("0"));
+    test("0", "0", "2", Some("1"));
+    test("1", "0", "2", Some("0"));
+    test("9", "3", "10", Some("2"));
+    test("5", "3", "10", None);
+    test("3", "2", "11", Some("8"));
+    test("3", "4", "7", None);
+    test("0", "2", "8", Some("3"));
+    test("6", "2", "8", None);
+    test("5", "3", "8", None);
+    test("33", "3", "64", Some("8"));
+    test("125", "5", "250", Some("3"));
+    test("56", "6", "100", Some("6"));
+    test("10", "6", "100", None);
+    test("7", "3", "1000000007", Some("70669385"));
+    test(
+        "12345",
+        "5",
+        "18446744073709551557",
+        Some("1641943267442573239"),
+    );
+    test(
+        "12345",
+        "43",
+        "170141183460469231731687303715884105727",
+        Some("62722898202088724190779602817757027275"),
+    );
+    test(
+        "12345",
+        "3",
+        "170141183460469231731687303715884105727",
+        None,
+    );
+    test(
+        "12345",
+        "3",
+        "618970019642690137449562111",
+        Some("313568898202173276348660521"),
+    );
+}
+
+#[test]
+fn mod_log_fail() {
+    assert_panic!(Natural::from(7u32).mod_log(Natural::from(2u32), Natural::from(7u32)));
+    assert_panic!(Natural::from(1u32).mod_log(Natural::from(7u32), Natural::from(7u32)));
+    assert_panic!(Natural::from(0u32).mod_log(Natural::from(0u32), Natural::from(0u32)));
+}
+
+#[test]
+fn test_mod_log_baby_step_giant_step() {
+    let test = |g: u32, h: u32, n: u32, m: u32, out: Option<u32>| {
+        assert_eq!(
+            mod_log_baby_step_giant_step(
+                &Natural::from(g),
+                &Natural::from(h),
+                &Natural::from(n),
+                &Natural::from(m)
+            ),
+            out.map(Natural::from)
+        );
+    };
+    test(2, 3, 10, 11, Some(8));
+    test(2, 3, 8, 11, None);
+    test(4, 3, 3, 7, None);
+    test(4, 1, 3, 7, Some(0));
+    test(3, 7, 1000000006, 1000000007, Some(70669385));
+}
+
+#[test]
+fn test_mod_log_pollard_rho() {
+    // 7293648916228302286 has order 5594472617641 modulo 2^64 - 59.
+    assert_eq!(
+        mod_log_pollard_rho(
+            &Natural::from(7293648916228302286u64),
+            &Natural::from(9961191577463352832u64),
+            &Natural::from(5594472617641u64),
+            &Natural::from(18446744073709551557u64)
+        ),
+        1234567890123u64
+    );
+    assert_eq!(
+        mod_log_pollard_rho(
+            &Natural::from(4u32),
+            &Natural::from(2u32),
+            &Natural::from(3u32),
+            &Natural::from(7u32)
+        ),
+        2
+    );
+}
+
+#[test]
+fn mod_log_properties() {
+    unsigned_triple_gen_var_12::<u16>().test_properties(|(x, base, m)| {
+        let result = Natural::from(x).mod_log(Natural::from(base), Natural::from(m));
+        assert_eq!(result, x.mod_log(base, m).map(Natural::from));
+        if let Some(log) = result {
+            assert_eq!(Natural::from(base).mod_pow(log, Natural::from(m)), x);
+        }
+    });
+
+    // 2^89 - 1 is prime, and the largest prime factor of 2^89 - 2 is 2931542417.
+    let m = Natural::from_str("618970019642690137449562111").unwrap();
+    for base in [2u32, 3, 12345] {
+        let base = Natural::from(base);
+        for k in [0u64, 1, 89, 1000, 123456789, 98765432109876543] {
+            let x = (&base).mod_pow(Natural::from(k), &m);
+            let log = (&x).mod_log(&base, &m).unwrap();
+            assert!(log <= k);
+            assert_eq!((&base).mod_pow(log, &m), x);
+        }
+    }
+}
@@ -0,0 +1,175 @@
+use malachite_base::num::arithmetic::traits::{
+    ModAdd, ModInverse, ModIsReduced, ModMul, ModNeg, ModPow, ModSub, Pow, PowAssign,
+};
+use malachite_base::num::basic::traits::{One, Zero};
+use malachite_nz::natural::arithmetic::mod_natural::ModNatural;
+use malachite_nz::natural::Natural;
+use malachite_nz::test_util::generators::{natural_pair_gen_var_8, natural_triple_gen_var_3};
+use std::panic::catch_unwind;
+use std::str::FromStr;
+
+#[test]
+fn test_mod_natural() {
+    let m = Natural::from(10u32);
+    let x = ModNatural::new(Natural::from(7u32), &m);
+    let y = ModNatural::new(Natural::from(3u32), &m);
+    assert_eq!(x.value(), &7u32);
+    assert_eq!(x.modulus(), &m);
+    assert_eq!(x.to_string(), "7 mod 10");
+    assert_eq!(format!("{:?}", y), "3 mod 10");
+    assert_eq!((&x + &y).to_string(), "0 mod 10");
+    assert_eq!((&x - &y).to_string(), "4 mod 10");
+    assert_eq!((&y - &x).to_string(), "6 mod 10");
+    assert_eq!((&x * &y).to_string(), "1 mod 10");
+    assert_eq!((&x / &y).to_string(), "9 mod 10");
+    assert_eq!((-&x).to_string(), "3 mod 10");
+    assert_eq!((&x).pow(3).to_string(), "3 mod 10");
+    assert_eq!(x.inverse().unwrap().to_string(), "3 mod 10");
+    assert_eq!(ModNatural::new(Natural::from(4u32), &m).inverse(), None);
+
+    let test = |x, m, out| {
+        let m = Natural::from_str(m).unwrap();
+        let x = ModNatural::reduce(Natural::from_str(x).unwrap(), &m);
+        assert!(x.value().is_valid());
+        assert_eq!(x.to_string(), out);
+    };
+    test("0", "1", "0 mod 1");
+    test("123", "1", "0 mod 1");
+    test("123", "10", "3 mod 10");
+    test(
+        "1000000000000000000000000",
+        "1234567987654",
+        "264899981800 mod 1234567987654",
+    );
+
+    let m = Natural::ONE;
+    let zero = ModNatural::new(Natural::ZERO, &m);
+    assert_eq!(zero.inverse(), Some(zero.clone()));
+    assert_eq!((&zero / &zero).to_string(), "0 mod 1");
+
+    // Residues with equal moduli may be combined even if the moduli are stored separately.
+    let m_1 = Natural::from(7u32);
+    let m_2 = Natural::from(7u32);
+    let x = ModNatural::new(Natural::from(5u32), &m_1);
+    let y = ModNatural::new(Natural::from(4u32), &m_2);
+    assert_eq!((x + y).to_string(), "2 mod 7");
+}
+
+#[test]
+fn mod_natural_fail() {
+    let m = Natural::from(10u32);
+    assert_panic!(ModNatural::new(Natural::from(10u32), &m));
+    let zero = Natural::ZERO;
+    assert_panic!(ModNatural::new(Natural::ZERO, &zero));
+    assert_panic!(ModNatural::reduce(Natural::ONE, &zero));
+    let m_2 = Natural::from(11u32);
+    assert_panic!(ModNatural::new(Natural::ONE, &m) + ModNatural::new(Natural::ONE, &m_2));
+    assert_panic!({
+        let x = ModNatural::new(Natural::ONE, &m);
+        let y = ModNatural::new(Natural::from(5u32), &m);
+        x / y
+    });
+}
+
+#[test]
+fn mod_natural_properties() {
+    natural_triple_gen_var_3().test_properties(|(x, y, m)| {
+        let x_r = ModNatural::new(x.clone(), &m);
+        let y_r = ModNatural::new(y.clone(), &m);
+
+        let sum = &x_r + &y_r;
+        assert!(sum.value().is_valid());
+        assert!(sum.value().mod_is_reduced(&m));
+        assert_eq!(sum.value(), &(&x).mod_add(&y, &m));
+        assert_eq!(x_r.clone() + y_r.clone(), sum);
+        assert_eq!(x_r.clone() + &y_r, sum);
+        assert_eq!(&x_r + y_r.clone(), sum);
+        let mut sum_alt = x_r.clone();
+        sum_alt += &y_r;
+        assert_eq!(sum_alt, sum);
+        let mut sum_alt = x_r.clone();
+        sum_alt += y_r.clone();
+        assert_eq!(sum_alt, sum);
+
+        let diff = &x_r - &y_r;
+        assert!(diff.value().is_valid());
+        assert_eq!(diff.value(), &(&x).mod_sub(&y, &m));
+        assert_eq!(x_r.clone() - y_r.clone(), diff);
+        assert_eq!(x_r.clone() - &y_r, diff);
+        assert_eq!(&x_r - y_r.clone(), diff);
+        let mut diff_alt = x_r.clone();
+        diff_alt -= &y_r;
+        assert_eq!(diff_alt, diff);
+        let mut diff_alt = x_r.clone();
+        diff_alt -= y_r.clone();
+        assert_eq!(diff_alt, diff);
+        assert_eq!(&diff + &y_r, x_r);
+
+        let product = &x_r * &y_r;
+        assert!(product.value().is_valid());
+        assert_eq!(product.value(), &(&x).mod_mul(&y, &m));
+        assert_eq!(x_r.clone() * y_r.clone(), product);
+        assert_eq!(x_r.clone() * &y_r, product);
+        assert_eq!(&x_r * y_r.clone(), product);
+        let mut product_alt = x_r.clone();
+        product_alt *= &y_r;
+        assert_eq!(product_alt, product);
+        let mut product_alt = x_r.clone();
+        product_alt *= y_r.clone();
+        assert_eq!(product_alt, product);
+
+        let inverse = y_r.inverse();
+        assert_eq!(
+            inverse.as_ref().map(|i| i.value().clone()),
+            if m == 1u32 {
+                Some(Natural::ZERO)
+            } else if y == 0u32 {
+                None
+            } else {
+                (&y).mod_inverse(&m)
+            }
+        );
+        if let Some(inverse) = inverse {
+            assert_eq!(&inverse * &y_r, ModNatural::reduce(Natural::ONE, &m));
+            let quotient = &x_r / &y_r;
+            assert!(quotient.value().is_valid());
+            assert_eq!(quotient, &x_r * &inverse);
+            assert_eq!(x_r.clone() / y_r.clone(), quotient);
+            assert_eq!(x_r.clone() / &y_r, quotient);
+            assert_eq!(&x_r / y_r.clone(), quotient);
+            let mut quotient_alt = x_r.clone();
+            quotient_alt /= &y_r;
+            assert_eq!(quotient_alt, quotient);
+            let mut quotient_alt = x_r.clone();
+            quotient_alt /= y_r.clone();
+            assert_eq!(quotient_alt, quotient);
+            assert_eq!(&quotient * &y_r, x_r);
+        }
+    });
+
+    natural_pair_gen_var_8().test_properties(|(x, m)| {
+        let x_r = ModNatural::new(x.clone(), &m);
+        assert_eq!(x_r.value(), &x);
+        assert_eq!(x_r.modulus(), &m);
+        assert_eq!(ModNatural::reduce(x.clone(), &m), x_r);
+        assert_eq!(x_r.clone().into_value(), x);
+        assert_eq!(x_r.to_string(), format!("{} mod {}", x, m));
+
+        let neg = -&x_r;
+        assert!(neg.value().is_valid());
+        assert_eq!(neg.value(), &(&x).mod_neg(&m));
+        assert_eq!(-x_r.clone(), neg);
+        assert_eq!(&x_r + &neg, ModNatural::new(Natural::ZERO, &m));
+        assert_eq!(-neg, x_r);
+
+        for exp in [0, 1, 2, 3, 10, 100] {
+            let power = (&x_r).pow(exp);
+            assert!(power.value().is_valid());
+            assert_eq!(power.value(), &(&x).mod_pow(Natural::from(exp), &m));
+            assert_eq!(x_r.clone().pow(exp), power);
+            let mut power_alt = x_r.clone();
+            power_alt.pow_assign(exp);
+            assert_eq!(power_alt, power);
+        }
+    });
+}
@@ -0,0 +1,195 @@
+use malachite_base::num::arithmetic::traits::{
+    LegendreSymbol, ModSqrt, ModSqrtFromFactorization, ModSquare, Pow,
+};
+use malachite_base::num::basic::traits::One;
+use malachite_base::num::factorization::traits::{Factor, NextPrime, PrevPrime};
+use malachite_base::num::logic::traits::SignificantBits;
+use malachite_base::test_util::generators::unsigned_pair_gen_var_27;
+use malachite_nz::natural::Natural;
+use malachite_nz::test_util::generators::natural_gen_var_2;
+use std::panic::catch_unwind;
+use std::str::FromStr;
+
+#[test]
+fn test_mod_sqrt() {
+    let test = |x: &str, p: &str, out: Option<&str>| {
+        let x = Natural::from_str(x).unwrap();
+        let p = Natural::from_str(p).unwrap();
+        let out = out.map(|s| Natural::from_str(s).unwrap());
+        assert_eq!(x.clone().mod_sqrt(p.clone()), out);
+        assert_eq!(x.clone().mod_sqrt(&p), out);
+        assert_eq!((&x).mod_sqrt(p.clone()), out);
+        assert_eq!((&x).mod_sqrt(&p), out);
+        assert_eq!((&x).mod_sqrt_prime_power(&p, 1), out);
+        assert_eq!((&x).mod_sqrt_from_factorization(&[(p, 1)]), out);
+    };
+    test("0", "2", Some("0"));
+    test("1", "2", Some("1"));
+    test("2", "7", Some("3"));
+    test("3", "7", None);
+    test("10", "13", Some("6"));
+    test("2", "998244353", Some("116195171"));
+    test("5", "998244353", None);
+    test("11", "18446744073709551557", Some("6461983710974175130"));
+    test("2", "18446744069414584321", Some("1099494850304"));
+    test("7", "18446744069414584321", None);
+    test(
+        "2",
+        "170141183460469231731687303715884105727",
+        Some("18446744073709551616"),
+    );
+    test("3", "170141183460469231731687303715884105727", None);
+    test(
+        "2",
+        "1000000000000000000000000000057",
+        Some("395746790562060615678394010553"),
+    );
+    test(
+        "7",
+        "1000000000000000000000000000057",
+        Some("13733928394710563667881019727"),
+    );
+    test("5", "1000000000000000000000000000057", None);
+}
+
+#[test]
+fn mod_sqrt_fail() {
+    assert_panic!(Natural::from(7u32).mod_sqrt(Natural::from(7u32)));
+    assert_panic!(Natural::from(8u32).mod_sqrt(&Natural::from(7u32)));
+}
+
+#[test]
+fn test_mod_sqrt_prime_power() {
+    let test = |x: &str, p: u32, e: u64, out: Option<&str>| {
+        let x = Natural::from_str(x).unwrap();
+        let p = Natural::from(p);
+        let out = out.map(|s| Natural::from_str(s).unwrap());
+        assert_eq!(x.clone().mod_sqrt_prime_power(p.clone(), e), out);
+        assert_eq!(x.clone().mod_sqrt_prime_power(&p, e), out);
+        assert_eq!((&x).mod_sqrt_prime_power(p.clone(), e), out);
+        assert_eq!((&x).mod_sqrt_prime_power(&p, e), out);
+        assert_eq!((&x).mod_sqrt_from_factorization(&[(p, e)]), out);
+    };
+    test("1", 2, 1, Some("1"));
+    test("3", 2, 2, None);
+    test("17", 2, 5, Some("7"));
+    test("17", 2, 100, Some("217788382556221841343574235415"));
+    test("3", 2, 100, None);
+    test("2", 7, 3, Some("108"));
+    test("49", 7, 3, Some("7"));
+    test("7", 7, 3, None);
+    test("0", 7, 3, Some("0"));
+    test("2", 7, 30, Some("9738858034492596758892959"));
+    test("10", 3, 40, Some("5205248770455236438"));
+    test("567", 3, 20, Some("132583113"));
+    test("150", 5, 27, Some("259351889827524455"));
+}
+
+#[test]
+fn mod_sqrt_prime_power_fail() {
+    assert_panic!(Natural::from(1u32).mod_sqrt_prime_power(Natural::from(3u32), 0));
+    assert_panic!(Natural::from(9u32).mod_sqrt_prime_power(Natural::from(3u32), 2));
+}
+
+#[test]
+fn test_mod_sqrt_from_factorization() {
+    let test = |x: &str, factors: &[(u32, u64)], is_square: bool| {
+        let x = Natural::from_str(x).unwrap();
+        let factors: Vec<(Natural, u64)> = factors
+            .iter()
+            .map(|&(p, e)| (Natural::from(p), e))
+            .collect();
+        let m: Natural = factors.iter().map(|(p, e)| p.pow(*e)).product();
+        let result = (&x).mod_sqrt_from_factorization(&factors);
+        assert_eq!(x.clone().mod_sqrt_from_factorization(&factors), result);
+        assert_eq!(result.is_some(), is_square);
+        if let Some(r) = result {
+            assert!(r < m);
+            assert_eq!(r.mod_square(m), x);
+        }
+    };
+    test("0", &[], true);
+    test("4", &[(3, 1), (5, 1)], true);
+    test("2", &[(3, 1), (5, 1)], false);
+    test("46", &[(3, 1), (5, 1), (7, 1)], true);
+    test("100", &[(7, 1), (11, 1), (13, 1)], true);
+    test("1000", &[(7, 1), (11, 1), (13, 1)], false);
+    test("17", &[(2, 70), (3, 1), (2147483647, 1)], false);
+    test("25", &[(2, 70), (3, 1), (2147483647, 1)], true);
+    test("10", &[(3, 40), (13, 1)], true);
+}
+
+#[test]
+fn mod_sqrt_from_factorization_fail() {
+    let factors = [(Natural::from(3u32), 1), (Natural::from(5u32), 1)];
+    assert_panic!(Natural::from(15u32).mod_sqrt_from_factorization(&factors));
+    assert_panic!(Natural::from(1u32).mod_sqrt_from_factorization(&[]));
+}
+
+#[test]
+fn mod_sqrt_properties() {
+    natural_gen_var_2().test_properties(|n| {
+        if n.significant_bits() > 256 {
+            return;
+        }
+        let p = (&n).next_prime();
+        let x = (&n % &p).mod_square(&p);
+        let r = (&x).mod_sqrt(&p).unwrap();
+        assert_eq!((&r).mod_square(&p), x);
+        assert!(r <= &p - &r);
+        let x = (&n * Natural::from(3u32) + Natural::ONE) % &p;
+        let result = (&x).mod_sqrt(&p);
+        if p != 2u32 {
+            assert_eq!(result.is_none(), (&x).legendre_symbol(&p) == -1);
+        }
+        if let Some(r) = result {
+            assert_eq!(r.mod_square(&p), x);
+        }
+    });
+
+    natural_gen_var_2().test_properties(|n| {
+        for (p, e) in [(2u32, 100), (3, 50), (5, 40)] {
+            let p = Natural::from(p);
+            let q = (&p).pow(e);
+            let y = &n % &q;
+            let x = (&y).mod_square(&q);
+            let r = (&x).mod_sqrt_prime_power(&p, e).unwrap();
+            assert_eq!((&r).mod_square(&q), x);
+            // The smallest root is returned.
+            assert!(r <= y);
+            assert!(r <= &q - &y);
+        }
+    });
+
+    unsigned_pair_gen_var_27::<u64>().test_properties(|(x, n)| {
+        if n <= 2 {
+            return;
+        }
+        let p = n.prev_prime();
+        let x = x % p;
+        assert_eq!(
+            Natural::from(x).mod_sqrt(Natural::from(p)),
+            x.mod_sqrt(p).map(Natural::from)
+        );
+    });
+
+    unsigned_pair_gen_var_27::<u32>().test_properties(|(x, m)| {
+        if m == 0 {
+            return;
+        }
+        let x = x % m;
+        let factors = m.factor();
+        let natural_factors: Vec<(Natural, u64)> = factors
+            .iter()
+            .map(|&(p, e)| (Natural::from(p), e))
+            .collect();
+        let result = Natural::from(x).mod_sqrt_from_factorization(&natural_factors);
+        assert_eq!(
+            result.is_some(),
+            x.mod_sqrt_from_factorization(&factors).is_some()
+        );
+        if let Some(r) = result {
+            assert_eq!(r.mod_square(Natural::from(m)), x);
+        }
+    });
+}
@@ -0,0 +1,59 @@
+use malachite_base::num::arithmetic::traits::{CoprimeWith, Moebius};
+use malachite_base::num::factorization::traits::{Factor, IsPrime};
+use malachite_base::num::logic::traits::SignificantBits;
+use malachite_base::test_util::generators::{unsigned_gen_var_1, unsigned_pair_gen_var_27};
+use malachite_nz::natural::Natural;
+use malachite_nz::test_util::generators::natural_gen_var_2;
+use std::panic::catch_unwind;
+use std::str::FromStr;
+
+#[test]
+fn test_moebius() {
+    let test = |s: &str, out: i8| {
+        let n = Natural::from_str(s).unwrap();
+        assert_eq!(n.moebius(), out);
+        assert_eq!(Natural::moebius_from_factorization(&n.factor()), out);
+    };
+    test("1", 1);
+    test("2", -1);
+    test("30", -1);
+    test("360", 0);
+    test("18446744073709551615", -1);
+    test("18446744073709551617", 1);
+    test("1000000000000000000000000000000", 0);
+    test("1000000000000000000000000000001", -1);
+}
+
+#[test]
+fn moebius_fail() {
+    assert_panic!(Natural::from(0u32).moebius());
+}
+
+#[test]
+fn moebius_properties() {
+    // Numbers with more bits may have two large prime factors, which are too slow to find in tests
+    natural_gen_var_2().test_properties(|n| {
+        if n.significant_bits() > 80 {
+            return;
+        }
+        let mu = n.moebius();
+        assert!(mu == -1 || mu == 0 || mu == 1);
+        assert_eq!(Natural::moebius_from_factorization(&n.factor()), mu);
+        if n.is_prime() {
+            assert_eq!(mu, -1);
+        }
+    });
+
+    unsigned_gen_var_1::<u64>().test_properties(|n| {
+        assert_eq!(Natural::from(n).moebius(), n.moebius());
+    });
+
+    unsigned_pair_gen_var_27::<u32>().test_properties(|(x, y)| {
+        if x == 0 || y == 0 || !x.coprime_with(y) {
+            return;
+        }
+        let x = Natural::from(x);
+        let y = Natural::from(y);
+        assert_eq!((&x * &y).moebius(), x.moebius() * y.moebius());
+    });
+}
@@ -0,0 +1,177 @@
+use malachite_base::num::arithmetic::traits::{
+    ModAdd, ModInverse, ModMul, ModPow, ModSquare, Pow, PowerOf2,
+};
+use malachite_base::num::basic::traits::{One, Zero};
+use malachite_base::strings::ToDebugString;
+use malachite_nz::natural::arithmetic::montgomery::MontgomeryModulus;
+use malachite_nz::natural::Natural;
+use malachite_nz::test_util::generators::natural_triple_gen;
+use std::panic::catch_unwind;
+use std::str::FromStr;
+
+#[test]
+fn test_montgomery_modulus() {
+    let test = |m, x, y, product, square| {
+        let m = Natural::from_str(m).unwrap();
+        let x = Natural::from_str(x).unwrap();
+        let y = Natural::from_str(y).unwrap();
+        let mut ctx = MontgomeryModulus::new(&m);
+        assert_eq!(ctx.modulus(), &m);
+        let x_m = ctx.to_montgomery(&x);
+        let y_m = ctx.to_montgomery(&y);
+        assert!(x_m.is_valid());
+        assert!(x_m < m);
+        let product_m = ctx.mul(&x_m, &y_m);
+        assert!(product_m.is_valid());
+        assert_eq!(ctx.from_montgomery(&product_m).to_string(), product);
+        let square_m = ctx.square(&x_m);
+        assert!(square_m.is_valid());
+        assert_eq!(ctx.from_montgomery(&square_m).to_string(), square);
+    };
+    test("1", "0", "0", "0", "0");
+    test("1", "5", "7", "0", "0");
+    test("3", "2", "2", "1", "1");
+    test("101", "12", "20", "38", "43");
+    test("101", "1234", "5678", "80", "80");
+    test(
+        "18446744073709551617",
+        "1000000000000000000000",
+        "18446744073709551616",
+        "14570924054025338935",
+        "16678926943930100839",
+    );
+    test(
+        "340282366920938463463374607431768211455",
+        "12345678987654321",
+        "340282366920938463463374607431768211454",
+        "340282366920938463463362261752780557134",
+        "152415789666209420210333789971041",
+    );
+}
+
+#[test]
+fn test_montgomery_modulus_pow() {
+    let test = |m, x, exp, out| {
+        let m = Natural::from_str(m).unwrap();
+        let x = Natural::from_str(x).unwrap();
+        let exp = Natural::from_str(exp).unwrap();
+        let mut ctx = MontgomeryModulus::new(&m);
+        let x_m = ctx.to_montgomery(&x);
+        let power_m = ctx.pow(&x_m, &exp);
+        assert!(power_m.is_valid());
+        assert_eq!(ctx.from_montgomery(&power_m).to_string(), out);
+    };
+    test("1", "0", "0", "0");
+    test("101", "0", "0", "1");
+    test("101", "0", "5", "0");
+    test("101", "3", "0", "1");
+    test("101", "3", "5", "41");
+    test("101", "3", "100", "1");
+    test(
+        "18446744073709551617",
+        "3",
+        "1000000000000",
+        "369043514245404494",
+    );
+}
+
+#[test]
+fn test_montgomery_modulus_inverse() {
+    let test = |m, x, out| {
+        let m = Natural::from_str(m).unwrap();
+        let x = Natural::from_str(x).unwrap();
+        let mut ctx = MontgomeryModulus::new(&m);
+        let x_m = ctx.to_montgomery(&x);
+        let inverse = ctx.inverse(&x_m).map(|y| ctx.from_montgomery(&y));
+        assert_eq!(inverse.to_debug_string(), out);
+    };
+    test("1", "0", "Some(0)");
+    test("15", "0", "None");
+    test("15", "6", "None");
+    test("15", "7", "Some(13)");
+    test("101", "12", "Some(59)");
+    test("18446744073709551617", "3", "Some(6148914691236517206)");
+}
+
+#[test]
+fn test_montgomery_modulus_large() {
+    // A modulus this large uses the multi-limb form of REDC.
+    let m = Natural::power_of_2(64 * 120) + Natural::from(12345u32);
+    let mut ctx = MontgomeryModulus::new(&m);
+    let x = Natural::from(3u32).pow(5000) % &m;
+    let y = Natural::from(7u32).pow(4000) % &m;
+    let x_m = ctx.to_montgomery(&x);
+    let y_m = ctx.to_montgomery(&y);
+    let product_m = ctx.mul(&x_m, &y_m);
+    assert_eq!(ctx.from_montgomery(&product_m), (&x).mod_mul(&y, &m));
+    let square_m = ctx.square(&x_m);
+    assert_eq!(ctx.from_montgomery(&square_m), (&x).mod_square(&m));
+    let exp = Natural::from(1000000007u32);
+    let power_m = ctx.pow(&x_m, &exp);
+    assert_eq!(ctx.from_montgomery(&power_m), (&x).mod_pow(&exp, &m));
+    let inverse_m = ctx.inverse(&y_m).unwrap();
+    assert_eq!(ctx.mul(&inverse_m, &y_m), *ctx.one());
+}
+
+#[test]
+fn montgomery_modulus_fail() {
+    assert_panic!(MontgomeryModulus::new(&Natural::ZERO));
+    assert_panic!(MontgomeryModulus::new(&Natural::from(10u32)));
+    let ctx = MontgomeryModulus::new(&Natural::from(101u32));
+    assert_panic!(ctx.clone().mul(&Natural::from(101u32), &Natural::ONE));
+    assert_panic!(ctx.clone().square(&Natural::from(200u32)));
+    assert_panic!(ctx.clone().pow(&Natural::from(101u32), &Natural::ONE));
+    assert_panic!(ctx.clone().from_montgomery(&Natural::from(101u32)));
+}
+
+#[test]
+fn montgomery_modulus_properties() {
+    natural_triple_gen().test_properties(|(x, y, m)| {
+        // Montgomery moduli must be odd.
+        let m = (m << 1u32) | Natural::ONE;
+        let mut ctx = MontgomeryModulus::new(&m);
+        let x_r = &x % &m;
+        let y_r = &y % &m;
+        let x_m = ctx.to_montgomery(&x);
+        let y_m = ctx.to_montgomery(&y);
+        assert!(x_m.is_valid());
+        assert!(x_m < m);
+        assert_eq!(ctx.to_montgomery(&x_r), x_m);
+        assert_eq!(ctx.from_montgomery(&x_m), x_r);
+
+        let product_m = ctx.mul(&x_m, &y_m);
+        assert!(product_m.is_valid());
+        assert_eq!(ctx.mul(&y_m, &x_m), product_m);
+        assert_eq!(ctx.from_montgomery(&product_m), (&x_r).mod_mul(&y_r, &m));
+
+        let square_m = ctx.square(&x_m);
+        assert!(square_m.is_valid());
+        assert_eq!(ctx.mul(&x_m, &x_m), square_m);
+        assert_eq!(ctx.from_montgomery(&square_m), (&x_r).mod_square(&m));
+
+        // Conversion to Montgomery form commutes with addition.
+        let sum_m = (&x_m).mod_add(&y_m, &m);
+        assert_eq!(ctx.from_montgomery(&sum_m), (&x_r).mod_add(&y_r, &m));
+
+        let exp = &y % Natural::from(1000u32);
+        let power_m = ctx.pow(&x_m, &exp);
+        assert!(power_m.is_valid());
+        assert_eq!(ctx.from_montgomery(&power_m), (&x_r).mod_pow(&exp, &m));
+
+        let one = ctx.one().clone();
+        assert_eq!(ctx.mul(&x_m, &one), x_m);
+        match ctx.inverse(&x_m) {
+            Some(inverse_m) => {
+                assert!(inverse_m.is_valid());
+                assert_eq!(ctx.mul(&inverse_m, &x_m), one);
+                if x_r != 0u32 {
+                    assert_eq!(
+                        ctx.from_montgomery(&inverse_m),
+                        (&x_r).mod_inverse(&m).unwrap()
+                    );
+                }
+            }
+            None => assert!(x_r == 0u32 || (&x_r).mod_inverse(&m).is_none()),
+        }
+    });
+}
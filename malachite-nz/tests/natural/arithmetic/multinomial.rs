@@ -0,0 +1,56 @@
+use malachite_base::num::arithmetic::traits::{
+    BinomialCoefficient, CheckedMultinomial, Factorial, Multinomial,
+};
+use malachite_base::test_util::generators::{unsigned_pair_gen_var_28, unsigned_vec_gen_var_7};
+use malachite_nz::natural::Natural;
+use malachite_nz::platform::Limb;
+
+#[test]
+fn test_multinomial() {
+    fn test(ks: &[u64], out: &str) {
+        let m = Natural::multinomial(ks);
+        assert!(m.is_valid());
+        assert_eq!(m.to_string(), out);
+    }
+    test(&[], "1");
+    test(&[0], "1");
+    test(&[5], "1");
+    test(&[0, 0, 3, 0], "1");
+    test(&[2, 3], "10");
+    test(&[1, 1, 1, 1, 1], "120");
+    test(&[1, 4, 4, 2], "34650");
+    test(&[5, 7, 8], "99768240");
+    test(&[10, 20, 30], "3553261127084984957001360");
+}
+
+#[test]
+fn multinomial_properties() {
+    unsigned_vec_gen_var_7().test_properties(|ks| {
+        let m = Natural::multinomial(&ks);
+        assert!(m.is_valid());
+        assert_ne!(m, 0u32);
+        let mut ks_reversed = ks.clone();
+        ks_reversed.reverse();
+        assert_eq!(Natural::multinomial(&ks_reversed), m);
+        // $n! = \binom{n}{k_1, k_2, \ldots, k_m}k_1!k_2!\cdots k_m!$
+        let mut product = m.clone();
+        for &k in &ks {
+            product *= Natural::factorial(k);
+        }
+        assert_eq!(product, Natural::factorial(ks.iter().sum()));
+        if let Some(m) = Limb::checked_multinomial(&ks) {
+            assert_eq!(Natural::multinomial(&ks), m);
+        }
+    });
+
+    unsigned_pair_gen_var_28::<u64, u64>().test_properties(|(n, k)| {
+        assert_eq!(
+            Natural::multinomial(&[n, k]),
+            Natural::binomial_coefficient(Natural::from(n + k), Natural::from(k))
+        );
+        assert_eq!(
+            Natural::multinomial(&[n, k, 1]),
+            Natural::multinomial(&[n, k]) * Natural::from(n + k + 1)
+        );
+    });
+}
@@ -0,0 +1,123 @@
+use malachite_base::num::arithmetic::traits::{
+    CarmichaelLambda, CoprimeWith, DivisibleBy, ModPow, MultiplicativeOrder, PrimitiveRoot, Totient,
+};
+use malachite_base::num::basic::traits::One;
+use malachite_base::test_util::generators::{unsigned_gen_var_1, unsigned_pair_gen_var_38};
+use malachite_nz::natural::Natural;
+use std::panic::catch_unwind;
+use std::str::FromStr;
+
+#[test]
+fn test_multiplicative_order() {
+    let test = |x, m, out: Option<&str>| {
+        let x = Natural::from_str(x).unwrap();
+        let m = Natural::from_str(m).unwrap();
+        let out = out.map(|s| Natural::from_str(s).unwrap());
+        let order = x.clone().multiplicative_order(m.clone());
+        assert!(order.as_ref().map_or(true, Natural::is_valid));
+        assert_eq!(order, out);
+        assert_eq!(x.clone().multiplicative_order(&m), out);
+        assert_eq!((&x).multiplicative_order(m.clone()), out);
+        assert_eq!((&x).multiplicative_order(&m), out);
+    };
+    test("0", "1", Some("1"));
+    test("0", "2", None);
+    test("1", "10", Some("1"));
+    test("3", "10", Some("4"));
+    test("5", "10", None);
+    test("2", "7", Some("3"));
+    test("3", "7", Some("6"));
+    test("4", "9", Some("3"));
+    test("6", "9", None);
+    test("5", "128", Some("32"));
+    test("3", "1000000007", Some("500000003"));
+    test(
+        "7",
+        "18446744073709551557",
+        Some("18446744073709551556"),
+    );
+    test(
+        "3",
+        "618970019642690137449562111",
+        Some("618970019642690137449562110"),
+    );
+    test(
+        "3",
+        "1267650600228229401496703205376",
+        Some("316912650057057350374175801344"),
+    );
+    test(
+        "5",
+        "24315330918113857602",
+        Some("8105110306037952534"),
+    );
+    test(
+        "3",
+        "170141183460469231731687303715884105727",
+        Some("56713727820156410577229101238628035242"),
+    );
+}
+
+#[test]
+fn multiplicative_order_fail() {
+    assert_panic!(Natural::from(7u32).multiplicative_order(Natural::from(7u32)));
+    assert_panic!(Natural::from(0u32).multiplicative_order(Natural::from(0u32)));
+}
+
+#[test]
+fn test_primitive_root() {
+    let test = |m, out: Option<&str>| {
+        let m = Natural::from_str(m).unwrap();
+        let root = m.primitive_root();
+        assert!(root.as_ref().map_or(true, Natural::is_valid));
+        assert_eq!(root, out.map(|s| Natural::from_str(s).unwrap()));
+    };
+    test("1", Some("0"));
+    test("2", Some("1"));
+    test("3", Some("2"));
+    test("4", Some("3"));
+    test("7", Some("3"));
+    test("8", None);
+    test("18", Some("5"));
+    test("40", None);
+    test("1000000007", Some("5"));
+    test("18446744073709551557", Some("2"));
+    test("18446744073709551615", None);
+    test("618970019642690137449562111", Some("3"));
+    test("12157665459056928801", Some("2"));
+    test("24315330918113857602", Some("5"));
+    test("170141183460469231731687303715884105727", Some("43"));
+}
+
+#[test]
+fn primitive_root_fail() {
+    assert_panic!(Natural::from(0u32).primitive_root());
+}
+
+#[test]
+fn multiplicative_order_properties() {
+    unsigned_pair_gen_var_38::<u64>().test_properties(|(x, m)| {
+        let order = Natural::from(x).multiplicative_order(Natural::from(m));
+        assert_eq!(order, x.multiplicative_order(m).map(Natural::from));
+        assert_eq!(order.is_some(), x.coprime_with(m));
+        if let Some(order) = order {
+            assert_eq!(
+                Natural::from(x).mod_pow(&order, Natural::from(m)),
+                Natural::ONE % Natural::from(m)
+            );
+            assert!(Natural::from(m).carmichael_lambda().divisible_by(order));
+        }
+    });
+}
+
+#[test]
+fn primitive_root_properties() {
+    unsigned_gen_var_1::<u64>().test_properties(|m| {
+        let n = Natural::from(m);
+        let root = n.primitive_root();
+        assert_eq!(root, m.primitive_root().map(Natural::from));
+        if let Some(root) = root {
+            assert_eq!(root.multiplicative_order(&n), Some(n.totient()));
+        }
+    });
+}
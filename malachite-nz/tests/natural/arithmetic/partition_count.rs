@@ -0,0 +1,62 @@
+use malachite_base::num::arithmetic::traits::{CheckedPartitionCount, PartitionCount};
+use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_base::test_util::generators::common::TINY_LIMIT;
+use malachite_base::test_util::generators::unsigned_gen_var_5;
+use malachite_nz::natural::Natural;
+use malachite_nz::platform::Limb;
+use malachite_nz::test_util::natural::arithmetic::partition_count::{
+    partition_count_naive, partition_counts_naive,
+};
+
+#[test]
+fn test_partition_count() {
+    fn test(n: u64, out: &str) {
+        let p = Natural::partition_count(n);
+        assert!(p.is_valid());
+        assert_eq!(p.to_string(), out);
+    }
+    test(0, "1");
+    test(1, "1");
+    test(2, "2");
+    test(3, "3");
+    test(4, "5");
+    test(5, "7");
+    test(100, "190569292");
+    test(1000, "24061467864032622473692149727991");
+    test(2000, "4720819175619413888601432406799959512200344166");
+    test(
+        10000,
+        "361672513256362939888204718909536954950160303393156504220818686058879525687540664205923\
+        10556052906916435144",
+    );
+}
+
+#[test]
+fn test_partition_count_large() {
+    // Most of these are computed with the Hardy-Ramanujan-Rademacher formula
+    let ps = partition_counts_naive(5000);
+    for n in (0..=5000).step_by(97).chain(995..=1005).chain(4990..=5000) {
+        assert_eq!(Natural::partition_count(n), ps[usize::exact_from(n)]);
+    }
+    for n in [12345, 20000] {
+        assert_eq!(Natural::partition_count(n), partition_count_naive(n));
+    }
+}
+
+#[test]
+fn partition_count_properties() {
+    unsigned_gen_var_5().test_properties_with_limit(TINY_LIMIT, |n| {
+        let p = Natural::partition_count(n);
+        assert!(p.is_valid());
+        assert_ne!(p, 0u32);
+        if n != 0 {
+            let p_previous = Natural::partition_count(n - 1);
+            assert!(p_previous <= p);
+            assert!(p <= p_previous << 1);
+        }
+        assert_eq!(partition_count_naive(n), p);
+        if let Some(p) = Limb::checked_partition_count(n) {
+            assert_eq!(Natural::partition_count(n), p);
+        }
+    });
+}
@@ -0,0 +1,99 @@
+use malachite_base::num::arithmetic::traits::{IsPerfectPower, PerfectPowerDecomposition, Pow};
+use malachite_base::test_util::generators::unsigned_gen;
+use malachite_nz::natural::Natural;
+use malachite_nz::platform::Limb;
+use malachite_nz::test_util::generators::{natural_gen, natural_gen_var_2};
+use std::str::FromStr;
+
+#[test]
+fn test_perfect_power() {
+    let test = |s, is_perfect_power, base, exponent| {
+        let n = Natural::from_str(s).unwrap();
+        assert_eq!(n.is_perfect_power(), is_perfect_power);
+        let (b, k) = n.perfect_power_decomposition();
+        assert!(b.is_valid());
+        assert_eq!(b.to_string(), base);
+        assert_eq!(k, exponent);
+    };
+    test("0", true, "0", 1);
+    test("1", true, "1", 1);
+    test("2", false, "2", 1);
+    test("4", true, "2", 2);
+    test("10", false, "10", 1);
+    test("64", true, "2", 6);
+    test("1000000", true, "10", 6);
+    test("4294967296", true, "2", 32);
+    test("18446744073709551615", false, "18446744073709551615", 1);
+    test("18446744073709551616", true, "2", 64);
+    test("3433683820292512484657849089281", true, "3", 64);
+    test("340282366920938463463374607431768211456", true, "2", 128);
+    test(
+        "340282366920938463463374607431768211457",
+        false,
+        "340282366920938463463374607431768211457",
+        1,
+    );
+    // 3^81
+    test("443426488243037769948249630619149892803", true, "3", 81);
+    // 12^150
+    test(
+        "753679854846047267899384440144864253917750139939839468390372811092240440144627161522250280\
+        026637671624061320056138726201249669469866719941822424321388911079194624",
+        true,
+        "12",
+        150,
+    );
+    // 10^40 + 1
+    test(
+        "10000000000000000000000000000000000000001",
+        false,
+        "10000000000000000000000000000000000000001",
+        1,
+    );
+    // (2^64 + 1)^3
+    test(
+        "6277101735386680764856636523970481806547819498980467802113",
+        true,
+        "18446744073709551617",
+        3,
+    );
+}
+
+#[test]
+fn perfect_power_properties() {
+    natural_gen().test_properties(|n| {
+        let (base, exponent) = n.perfect_power_decomposition();
+        assert!(base.is_valid());
+        assert_ne!(exponent, 0);
+        assert_eq!((&base).pow(exponent), n);
+        assert_eq!(n.is_perfect_power(), n <= 1u32 || exponent > 1);
+        if base > 1u32 {
+            assert!(!base.is_perfect_power());
+        }
+    });
+
+    natural_gen_var_2().test_properties(|n| {
+        let (base, exponent) = n.perfect_power_decomposition();
+        for k in [2, 3, 6] {
+            let power = (&n).pow(k);
+            assert!(power.is_perfect_power());
+            let (power_base, power_exponent) = power.perfect_power_decomposition();
+            if n == 1u32 {
+                assert_eq!(power_base, 1u32);
+            } else {
+                assert_eq!(power_base, base);
+                assert_eq!(power_exponent, exponent * k);
+            }
+        }
+    });
+
+    unsigned_gen::<Limb>().test_properties(|u| {
+        let n = Natural::from(u);
+        assert_eq!(n.is_perfect_power(), u.is_perfect_power());
+        let (base, exponent) = u.perfect_power_decomposition();
+        assert_eq!(
+            n.perfect_power_decomposition(),
+            (Natural::from(base), exponent)
+        );
+    });
+}
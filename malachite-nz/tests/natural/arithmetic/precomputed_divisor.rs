@@ -0,0 +1,115 @@
+use malachite_base::num::arithmetic::traits::{DivMod, DivisibleBy, Pow, PowerOf2};
+use malachite_base::num::basic::integers::PrimitiveInt;
+use malachite_base::num::basic::traits::{One, Zero};
+use malachite_base::test_util::generators::common::GenConfig;
+use malachite_nz::natural::arithmetic::precomputed_divisor::PrecomputedDivisor;
+use malachite_nz::natural::Natural;
+use malachite_nz::platform::Limb;
+use malachite_nz::test_util::generators::{natural_pair_gen_var_5, natural_pair_gen_var_6};
+use std::panic::catch_unwind;
+use std::str::FromStr;
+
+#[test]
+fn test_precomputed_divisor() {
+    let test = |d, x, q, r| {
+        let d = PrecomputedDivisor::new(&Natural::from_str(d).unwrap());
+        let x = Natural::from_str(x).unwrap();
+        let (q_out, r_out) = d.div_mod(&x);
+        assert!(q_out.is_valid());
+        assert!(r_out.is_valid());
+        assert_eq!(q_out.to_string(), q);
+        assert_eq!(r_out.to_string(), r);
+        assert_eq!(d.div(&x).to_string(), q);
+        assert_eq!(d.mod_op(&x).to_string(), r);
+        assert_eq!(d.divisible_by(&x), r == "0");
+    };
+    test("1", "0", "0", "0");
+    test("1", "123", "123", "0");
+    test("10", "0", "0", "0");
+    test("10", "9", "0", "9");
+    test("10", "23", "2", "3");
+    test("10", "230", "23", "0");
+    test(
+        "1234567987654",
+        "1000000000000000000000000",
+        "809999943300",
+        "264899981800",
+    );
+    test(
+        "18446744073709551617",
+        "1000000000000000000000000000000",
+        "54210108624",
+        "5076944216095154992",
+    );
+    test(
+        "18446744073709551615",
+        "340282366920938463463374607431768211456",
+        "18446744073709551617",
+        "1",
+    );
+    test(
+        "340282366920938463463374607431768211455",
+        "100000000000000000000000000000000000000000000000000",
+        "293873587705",
+        "194599656488044247630319707748071839225",
+    );
+    test(
+        "100000000000000000000",
+        "10000000000000000000",
+        "0",
+        "10000000000000000000",
+    );
+}
+
+#[test]
+fn test_precomputed_divisor_large() {
+    // Divisors this large use a cached Barrett inverse. The first is not normalized; the second
+    // has its most significant bit set.
+    let unnormalized = Natural::from(3u32).pow(12000);
+    let normalized = Natural::power_of_2(Limb::WIDTH * 300) - Natural::ONE;
+    for d in [unnormalized, normalized] {
+        let pd = PrecomputedDivisor::new(&d);
+        for e in (0..40000).step_by(997) {
+            let x = Natural::from(7u32).pow(e) + Natural::from(e);
+            let (q, r) = pd.div_mod(&x);
+            assert!(q.is_valid());
+            assert!(r.is_valid());
+            assert_eq!((q, r), (&x).div_mod(&d));
+        }
+        let x = &d * Natural::from(11u32).pow(3000);
+        assert!(pd.divisible_by(&x));
+        assert!(!pd.divisible_by(&(x + Natural::ONE)));
+    }
+}
+
+#[test]
+fn precomputed_divisor_fail() {
+    assert_panic!(PrecomputedDivisor::new(&Natural::ZERO));
+}
+
+#[test]
+fn precomputed_divisor_properties() {
+    let mut config = GenConfig::new();
+    config.insert("mean_bits_n", 2048);
+    config.insert("mean_stripe_n", 256 << Limb::LOG_WIDTH);
+    natural_pair_gen_var_5().test_properties_with_config(&config, |(x, y)| {
+        let d = PrecomputedDivisor::new(&y);
+        assert_eq!(d.divisor(), &y);
+        let (q, r) = d.div_mod(&x);
+        assert!(q.is_valid());
+        assert!(r.is_valid());
+        assert!(r < y);
+        assert_eq!(&q * &y + &r, x);
+        assert_eq!((q.clone(), r.clone()), (&x).div_mod(&y));
+        assert_eq!(d.div(&x), q);
+        assert_eq!(d.mod_op(&x), r);
+        assert_eq!(d.divisible_by(&x), (&x).divisible_by(&y));
+        assert_eq!(d.divisible_by(&x), r == 0u32);
+    });
+
+    natural_pair_gen_var_6().test_properties_with_config(&config, |(x, y)| {
+        let d = PrecomputedDivisor::new(&y);
+        assert!(d.divisible_by(&x));
+        assert_eq!(d.div_mod(&x), (&x).div_mod(&y));
+    });
+}
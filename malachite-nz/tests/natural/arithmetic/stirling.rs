@@ -0,0 +1,145 @@
+use malachite_base::num::arithmetic::traits::{
+    Bell, BinomialCoefficient, CheckedBell, CheckedStirlingFirst, CheckedStirlingSecond, Factorial,
+    StirlingFirst, StirlingSecond,
+};
+use malachite_base::num::basic::traits::{One, Zero};
+use malachite_base::test_util::generators::{unsigned_gen_var_7, unsigned_pair_gen_var_28};
+use malachite_nz::natural::Natural;
+use malachite_nz::platform::Limb;
+
+#[test]
+fn test_stirling_first() {
+    fn test(n: u64, k: u64, out: &str) {
+        let c = Natural::stirling_first(n, k);
+        assert!(c.is_valid());
+        assert_eq!(c.to_string(), out);
+    }
+    test(0, 0, "1");
+    test(1, 0, "0");
+    test(1, 1, "1");
+    test(3, 5, "0");
+    test(4, 1, "6");
+    test(4, 2, "11");
+    test(4, 3, "6");
+    test(10, 3, "1172700");
+    test(30, 10, "215760462268683520394805979744");
+    test(50, 25, "506473132983732155508942707968971278382005365625");
+}
+
+#[test]
+fn test_stirling_second() {
+    fn test(n: u64, k: u64, out: &str) {
+        let s = Natural::stirling_second(n, k);
+        assert!(s.is_valid());
+        assert_eq!(s.to_string(), out);
+    }
+    test(0, 0, "1");
+    test(1, 0, "0");
+    test(1, 1, "1");
+    test(3, 5, "0");
+    test(4, 1, "1");
+    test(4, 2, "7");
+    test(4, 3, "6");
+    test(10, 3, "9330");
+    test(50, 10, "26154716515862881292012777396577993781727011");
+    test(50, 40, "149377949042637543000150");
+    test(100, 3, "85896253455335221205584888180155511368666317646");
+    test(100, 97, "18637582425");
+}
+
+#[test]
+fn test_bell() {
+    fn test(n: u64, out: &str) {
+        let b = Natural::bell(n);
+        assert!(b.is_valid());
+        assert_eq!(b.to_string(), out);
+    }
+    test(0, "1");
+    test(1, "1");
+    test(2, "2");
+    test(3, "5");
+    test(5, "52");
+    test(50, "185724268771078270438257767181908917499221852770");
+    test(
+        100,
+        "475853912767648336587907688413872078263636696868256114666163346375591144978924426226727\
+        24044217756306953557882560751",
+    );
+}
+
+#[test]
+fn stirling_properties() {
+    unsigned_pair_gen_var_28::<u64, u64>().test_properties(|(n, k)| {
+        let c = Natural::stirling_first(n, k);
+        assert!(c.is_valid());
+        let s = Natural::stirling_second(n, k);
+        assert!(s.is_valid());
+        assert!(s <= c);
+        if k > n {
+            assert_eq!(c, 0);
+            assert_eq!(s, 0);
+        }
+        // The recurrences that define the two kinds, which exercise both evaluation strategies
+        if k != 0 {
+            assert_eq!(
+                Natural::stirling_first(n + 1, k),
+                Natural::from(n) * &c + Natural::stirling_first(n, k - 1)
+            );
+            assert_eq!(
+                Natural::stirling_second(n + 1, k),
+                Natural::from(k) * &s + Natural::stirling_second(n, k - 1)
+            );
+        }
+        if let Some(c) = Limb::checked_stirling_first(n, k) {
+            assert_eq!(Natural::stirling_first(n, k), c);
+        }
+        if let Some(s) = Limb::checked_stirling_second(n, k) {
+            assert_eq!(Natural::stirling_second(n, k), s);
+        }
+    });
+
+    unsigned_gen_var_7::<u64>().test_properties(|n| {
+        assert_eq!(Natural::stirling_first(n, n), 1);
+        assert_eq!(Natural::stirling_second(n, n), 1);
+        if n != 0 {
+            assert_eq!(Natural::stirling_first(n, 0), 0);
+            assert_eq!(Natural::stirling_second(n, 0), 0);
+            assert_eq!(Natural::stirling_first(n, 1), Natural::factorial(n - 1));
+            assert_eq!(Natural::stirling_second(n, 1), 1);
+            let b = Natural::binomial_coefficient(Natural::from(n), Natural::from(2u32));
+            assert_eq!(Natural::stirling_first(n, n - 1), b);
+            assert_eq!(Natural::stirling_second(n, n - 1), b);
+        }
+        // $\sum_{k=0}^n \left[{n \atop k}\right] = n!$
+        let mut sum = Natural::ZERO;
+        for k in 0..=n {
+            sum += Natural::stirling_first(n, k);
+        }
+        assert_eq!(sum, Natural::factorial(n));
+    });
+}
+
+#[test]
+fn bell_properties() {
+    unsigned_gen_var_7::<u64>().test_properties(|n| {
+        let b = Natural::bell(n);
+        assert!(b.is_valid());
+        assert_ne!(b, 0u32);
+        let mut sum = Natural::ZERO;
+        for k in 0..=n {
+            sum += Natural::stirling_second(n, k);
+        }
+        assert_eq!(sum, b);
+        // $B_{n+1} = \sum_{k=0}^n \binom{n}{k} B_k$
+        let mut sum = Natural::ZERO;
+        let mut binomial = Natural::ONE;
+        for k in 0..=n {
+            sum += &binomial * Natural::bell(k);
+            binomial = binomial * Natural::from(n - k) / Natural::from(k + 1);
+        }
+        assert_eq!(sum, Natural::bell(n + 1));
+        if let Some(b) = Limb::checked_bell(n) {
+            assert_eq!(Natural::bell(n), b);
+        }
+    });
+}
@@ -0,0 +1,105 @@
+use malachite_base::num::arithmetic::traits::{
+    CarmichaelLambda, CoprimeWith, DivisibleBy, Lcm, Totient,
+};
+use malachite_base::num::factorization::traits::Factor;
+use malachite_base::num::logic::traits::SignificantBits;
+use malachite_base::test_util::generators::{unsigned_gen_var_1, unsigned_pair_gen_var_27};
+use malachite_nz::natural::Natural;
+use malachite_nz::test_util::generators::natural_gen_var_2;
+use std::panic::catch_unwind;
+use std::str::FromStr;
+
+#[test]
+fn test_totient() {
+    let test = |s: &str, out: &str| {
+        let n = Natural::from_str(s).unwrap();
+        let out = Natural::from_str(out).unwrap();
+        assert_eq!(n.totient(), out);
+        assert_eq!(Natural::totient_from_factorization(&n.factor()), out);
+    };
+    test("1", "1");
+    test("2", "1");
+    test("360", "96");
+    test("18446744073709551615", "9208981628670443520");
+    test("18446744073709551617", "18446676793287966720");
+    test(
+        "1000000000000000000000000000000",
+        "400000000000000000000000000000",
+    );
+    // 2^100 - 1
+    test(
+        "1267650600228229401496703205375",
+        "570767634000000000000000000000",
+    );
+}
+
+#[test]
+fn totient_fail() {
+    assert_panic!(Natural::from(0u32).totient());
+}
+
+#[test]
+fn test_carmichael_lambda() {
+    let test = |s: &str, out: &str| {
+        let n = Natural::from_str(s).unwrap();
+        let out = Natural::from_str(out).unwrap();
+        assert_eq!(n.carmichael_lambda(), out);
+        assert_eq!(
+            Natural::carmichael_lambda_from_factorization(&n.factor()),
+            out
+        );
+    };
+    test("1", "1");
+    test("2", "1");
+    test("8", "2");
+    test("360", "12");
+    test("18446744073709551615", "17153064960");
+    test("18446744073709551617", "72057331223781120");
+    test(
+        "1000000000000000000000000000000",
+        "50000000000000000000000000000",
+    );
+    // 2^100 - 1
+    test("1267650600228229401496703205375", "14499000");
+}
+
+#[test]
+fn carmichael_lambda_fail() {
+    assert_panic!(Natural::from(0u32).carmichael_lambda());
+}
+
+#[test]
+fn totient_properties() {
+    // Numbers with more bits may have two large prime factors, which are too slow to find in tests
+    natural_gen_var_2().test_properties(|n| {
+        if n.significant_bits() > 80 {
+            return;
+        }
+        let factors = n.factor();
+        let totient = n.totient();
+        assert_eq!(Natural::totient_from_factorization(&factors), totient);
+        assert_ne!(totient, 0u32);
+        assert!(totient <= n);
+        let lambda = Natural::carmichael_lambda_from_factorization(&factors);
+        assert!(lambda <= totient);
+        assert!(totient.divisible_by(lambda));
+    });
+
+    unsigned_gen_var_1::<u64>().test_properties(|n| {
+        assert_eq!(Natural::from(n).totient(), n.totient());
+        assert_eq!(Natural::from(n).carmichael_lambda(), n.carmichael_lambda());
+    });
+
+    unsigned_pair_gen_var_27::<u32>().test_properties(|(x, y)| {
+        if x == 0 || y == 0 || !x.coprime_with(y) {
+            return;
+        }
+        let x = Natural::from(x);
+        let y = Natural::from(y);
+        assert_eq!((&x * &y).totient(), x.totient() * y.totient());
+        assert_eq!(
+            (&x * &y).carmichael_lambda(),
+            x.carmichael_lambda().lcm(y.carmichael_lambda())
+        );
+    });
+}
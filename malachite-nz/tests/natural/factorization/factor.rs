@@ -0,0 +1,134 @@
+use malachite_base::iterators::comparison::is_strictly_ascending;
+use malachite_base::num::arithmetic::traits::{DivisibleBy, Pow};
+use malachite_base::num::basic::traits::One;
+use malachite_base::num::factorization::traits::{Factor, IsPrime};
+use malachite_base::num::logic::traits::SignificantBits;
+use malachite_base::strings::ToDebugString;
+use malachite_base::test_util::generators::{unsigned_gen_var_1, unsigned_pair_gen_var_27};
+use malachite_nz::natural::factorization::factor::{ecm_one_curve, pollard_brent_rho};
+use malachite_nz::natural::Natural;
+use malachite_nz::test_util::generators::natural_gen_var_2;
+use std::panic::catch_unwind;
+use std::str::FromStr;
+
+#[test]
+fn test_factor() {
+    let test = |s: &str, out: &str| {
+        let n = Natural::from_str(s).unwrap();
+        assert_eq!(n.factor().to_debug_string(), out);
+    };
+    test("1", "[]");
+    test("2", "[(2, 1)]");
+    test("360", "[(2, 3), (3, 2), (5, 1)]");
+    test("4294967291", "[(4294967291, 1)]");
+    test("4294967296", "[(2, 32)]");
+    test(
+        "18446744073709551615",
+        "[(3, 1), (5, 1), (17, 1), (257, 1), (641, 1), (65537, 1), (6700417, 1)]",
+    );
+    test("18446744073709551616", "[(2, 64)]");
+    test("1000000000000000000000000000000", "[(2, 30), (5, 30)]");
+    test(
+        "1000000000000000000000000000001",
+        "[(61, 1), (101, 1), (3541, 1), (9901, 1), (27961, 1), (4188901, 1), (39526741, 1)]",
+    );
+    // 2^89 - 1
+    test(
+        "618970019642690137449562111",
+        "[(618970019642690137449562111, 1)]",
+    );
+    test(
+        "2156280139809231176600527990492211447259",
+        "[(3, 5), (7, 1), (1000003, 1), (1267650600228229401496703205653, 1)]",
+    );
+    // 1000003^3 * nextprime(2^70)
+    test(
+        "1180602246073873765786074905563228193123",
+        "[(1000003, 3), (1180591620717411303449, 1)]",
+    );
+    // nextprime(2^30) * nextprime(2^50) * nextprime(2^60)
+    test(
+        "1393796578802454716844006200150576806761797",
+        "[(1073741827, 1), (1125899906842679, 1), (1152921504606847009, 1)]",
+    );
+    // 2^200 - 1
+    test(
+        "1606938044258990275541962092341162602522202993782792835301375",
+        "[(3, 1), (5, 3), (11, 1), (17, 1), (31, 1), (41, 1), (101, 1), (251, 1), (401, 1), \
+        (601, 1), (1801, 1), (4051, 1), (8101, 1), (61681, 1), (268501, 1), (340801, 1), \
+        (2787601, 1), (3173389601, 1)]",
+    );
+}
+
+#[test]
+fn factor_fail() {
+    assert_panic!(Natural::from(0u32).factor());
+}
+
+#[test]
+fn test_pollard_brent_rho() {
+    let test = |s: &str| {
+        let n = Natural::from_str(s).unwrap();
+        let factor = pollard_brent_rho(&n, &Natural::ONE).unwrap();
+        assert!(factor > 1u32 && factor < n);
+        assert!((&n).divisible_by(&factor));
+    };
+    test("1180595162492273455682910347");
+    test("1180602246073873765786074905563228193123");
+}
+
+#[test]
+fn test_ecm_one_curve() {
+    let test = |s: &str, sigma: u64, b1: u64| {
+        let n = Natural::from_str(s).unwrap();
+        let factor = ecm_one_curve(&n, sigma, b1).unwrap();
+        assert!(factor > 1u32 && factor < n);
+        assert!((&n).divisible_by(&factor));
+    };
+    test("340282366920938463463374607431768211457", 26, 11000);
+    test("42535295865117307778430344311653531707", 37, 11000);
+}
+
+#[test]
+fn factor_properties() {
+    // Numbers with more bits may have two large prime factors, which are too slow to find in tests
+    natural_gen_var_2().test_properties(|n| {
+        if n.significant_bits() > 80 {
+            return;
+        }
+        let factors = n.factor();
+        assert!(is_strictly_ascending(factors.iter().map(|(p, _)| p)));
+        let mut product = Natural::ONE;
+        for (p, e) in &factors {
+            assert!(p.is_prime());
+            assert_ne!(*e, 0);
+            product *= p.pow(*e);
+        }
+        assert_eq!(product, n);
+    });
+
+    unsigned_gen_var_1::<u64>().test_properties(|n| {
+        assert_eq!(
+            Natural::from(n).factor(),
+            n.factor()
+                .into_iter()
+                .map(|(p, e)| (Natural::from(p), e))
+                .collect::<Vec<_>>()
+        );
+    });
+
+    unsigned_pair_gen_var_27::<u32>().test_properties(|(x, y)| {
+        if x == 0 || y == 0 {
+            return;
+        }
+        let mut factors = Natural::from(x).factor();
+        for (p, e) in Natural::from(y).factor() {
+            match factors.iter_mut().find(|(q, _)| *q == p) {
+                Some((_, f)) => *f += e,
+                None => factors.push((p, e)),
+            }
+        }
+        factors.sort_unstable();
+        assert_eq!((Natural::from(x) * Natural::from(y)).factor(), factors);
+    });
+}
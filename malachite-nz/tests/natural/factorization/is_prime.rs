@@ -0,0 +1,93 @@
+use malachite_base::num::arithmetic::traits::Parity;
+use malachite_base::num::basic::traits::{One, Two};
+use malachite_base::num::factorization::traits::{IsPrime, IsProbablePrime};
+use malachite_base::test_util::generators::unsigned_gen;
+use malachite_nz::natural::factorization::is_prime::is_strong_lucas_probable_prime;
+use malachite_nz::natural::Natural;
+use malachite_nz::test_util::generators::{natural_gen, natural_pair_gen};
+use std::str::FromStr;
+
+#[test]
+fn test_is_prime() {
+    let test = |s: &str, out: bool| {
+        let n = Natural::from_str(s).unwrap();
+        assert_eq!(n.is_prime(), out);
+        assert_eq!(n.is_probable_prime(0), out);
+        assert_eq!(n.is_probable_prime(10), out);
+    };
+    test("0", false);
+    test("1", false);
+    test("2", true);
+    test("3", true);
+    test("4", false);
+    test("97", true);
+    test("561", false);
+    test("4294967291", true);
+    test("4294967296", false);
+    test("18446744073709551557", true);
+    test("18446744073709551615", false);
+    // strong pseudoprime to every prime base up to 23
+    test("3825123056546413051", false);
+    // strong pseudoprimes to every prime base up to 37
+    test("318665857834031151167461", false);
+    test("3317044064679887385961981", false);
+    // Carmichael number
+    test("1296005097246682578520326409", false);
+    // 2^127 - 1
+    test("170141183460469231731687303715884105727", true);
+    // 2^127 + 1
+    test("170141183460469231731687303715884105729", false);
+    test("10000000000000000000000000000000000000121", true);
+    // product of the primes just below and just above 2^100
+    test(
+        "1606938044258990275541962092673287059781999096974929075105733",
+        false,
+    );
+    // (2^64 - 59)^2
+    test("340282366920938461286658806734041124249", false);
+    // 2^521 - 1
+    test(
+        "686479766013060971498190079908139321726943530014330540939446345918554318339765605212255964\
+        0661454554977296311391480858037121987999716643812574028291115057151",
+        true,
+    );
+}
+
+#[test]
+fn test_is_strong_lucas_probable_prime() {
+    let test = |s: &str, out: bool| {
+        assert_eq!(
+            is_strong_lucas_probable_prime(&Natural::from_str(s).unwrap()),
+            out
+        );
+    };
+    test("5459", true);
+    test("5777", true);
+    test("10877", true);
+    test("2047", false);
+    test("4294967291", true);
+    test("170141183460469231731687303715884105727", true);
+    test("3317044064679887385961981", false);
+}
+
+#[test]
+fn is_prime_properties() {
+    natural_gen().test_properties(|n| {
+        let is_prime = n.is_prime();
+        assert_eq!(n.is_probable_prime(0), is_prime);
+        assert_eq!(n.is_probable_prime(4), is_prime);
+        if is_prime && n > Natural::TWO {
+            assert!(n.odd());
+        }
+    });
+
+    natural_pair_gen().test_properties(|(x, y)| {
+        if x > Natural::ONE && y > Natural::ONE {
+            assert!(!(x * y).is_prime());
+        }
+    });
+
+    unsigned_gen::<u64>().test_properties(|n| {
+        assert_eq!(Natural::from(n).is_prime(), n.is_prime());
+    });
+}
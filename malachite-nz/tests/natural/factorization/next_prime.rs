@@ -0,0 +1,105 @@
+use malachite_base::num::basic::traits::Two;
+use malachite_base::num::factorization::traits::{IsPrime, NextPrime, PrevPrime, PrimesInRange};
+use malachite_base::test_util::generators::unsigned_gen;
+use malachite_nz::natural::Natural;
+use malachite_nz::test_util::generators::natural_gen;
+use std::panic::catch_unwind;
+use std::str::FromStr;
+
+#[test]
+fn test_next_prime() {
+    let test = |s: &str, out: &str| {
+        let n = Natural::from_str(s).unwrap();
+        let p = n.next_prime();
+        assert!(p.is_valid());
+        assert_eq!(p.to_string(), out);
+    };
+    test("0", "2");
+    test("1", "2");
+    test("2", "3");
+    test("10", "11");
+    test("1000000", "1000003");
+    test("4294967291", "4294967311");
+    test("18446744073709551556", "18446744073709551557");
+    test("18446744073709551557", "18446744073709551629");
+    test("18446744073709551615", "18446744073709551629");
+    test("18446744073709551616", "18446744073709551629");
+    test("18446744073709556616", "18446744073709556723");
+    test(
+        "1267650600228229401496703205376",
+        "1267650600228229401496703205653",
+    );
+}
+
+#[test]
+fn test_prev_prime() {
+    let test = |s: &str, out: &str| {
+        let n = Natural::from_str(s).unwrap();
+        let p = n.prev_prime();
+        assert!(p.is_valid());
+        assert_eq!(p.to_string(), out);
+    };
+    test("3", "2");
+    test("4", "3");
+    test("100", "97");
+    test("1000003", "999983");
+    test("18446744073709551615", "18446744073709551557");
+    test("18446744073709551616", "18446744073709551557");
+    test("18446744073709551629", "18446744073709551557");
+    test("18446744073709551716", "18446744073709551709");
+    test("18446744073709556616", "18446744073709556543");
+    test(
+        "1267650600228229401496703205376",
+        "1267650600228229401496703205361",
+    );
+}
+
+#[test]
+fn prev_prime_fail() {
+    assert_panic!(Natural::from(0u32).prev_prime());
+    assert_panic!(Natural::from(1u32).prev_prime());
+    assert_panic!(Natural::from(2u32).prev_prime());
+}
+
+#[test]
+fn next_prime_properties() {
+    natural_gen().test_properties(|n| {
+        let p = n.next_prime();
+        assert!(p.is_valid());
+        assert!(p > n);
+        assert!(p.is_prime());
+        if p > Natural::TWO {
+            assert!(p.prev_prime() <= n);
+        }
+        assert_eq!(
+            Natural::primes_greater_than_or_equal_to(&n).find(|q| *q > n),
+            Some(p)
+        );
+    });
+
+    unsigned_gen::<u64>().test_properties(|n| {
+        if n < 18446744073709551557 {
+            assert_eq!(Natural::from(n).next_prime(), n.next_prime());
+        }
+    });
+}
+
+#[test]
+fn prev_prime_properties() {
+    natural_gen().test_properties(|n| {
+        if n <= 2u32 {
+            return;
+        }
+        let p = n.prev_prime();
+        assert!(p.is_valid());
+        assert!(p < n);
+        assert!(p.is_prime());
+        assert!(p.next_prime() >= n);
+    });
+
+    unsigned_gen::<u64>().test_properties(|n| {
+        if n > 2 {
+            assert_eq!(Natural::from(n).prev_prime(), n.prev_prime());
+        }
+    });
+}
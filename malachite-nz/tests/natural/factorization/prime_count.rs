@@ -0,0 +1,72 @@
+use malachite_base::num::factorization::traits::{IsPrime, NthPrime, PrimeCount};
+use malachite_base::test_util::generators::{unsigned_gen_var_26, unsigned_gen_var_5};
+use malachite_nz::natural::Natural;
+use std::panic::catch_unwind;
+use std::str::FromStr;
+
+#[test]
+fn test_prime_count() {
+    let test = |s: &str, out: u64| {
+        assert_eq!(Natural::from_str(s).unwrap().prime_count(), out);
+    };
+    test("0", 0);
+    test("1", 0);
+    test("2", 1);
+    test("10", 4);
+    test("100", 25);
+    test("65536", 6542);
+    test("1000000", 78498);
+    test("4294967296", 203280221);
+    test("10000000000", 455052511);
+}
+
+#[test]
+fn prime_count_fail() {
+    assert_panic!(Natural::from_str("18446744073709551616")
+        .unwrap()
+        .prime_count());
+}
+
+#[test]
+fn test_nth_prime() {
+    let test = |n: u64, out: &str| {
+        let p = Natural::nth_prime(n);
+        assert!(p.is_valid());
+        assert_eq!(p.to_string(), out);
+    };
+    test(1, "2");
+    test(2, "3");
+    test(25, "97");
+    test(1000, "7919");
+    test(1000000, "15485863");
+    test(203280221, "4294967291");
+    test(203280222, "4294967311");
+}
+
+#[test]
+fn nth_prime_fail() {
+    assert_panic!(Natural::nth_prime(0));
+}
+
+#[test]
+fn prime_count_properties() {
+    unsigned_gen_var_5::<u64>().test_properties(|n| {
+        let x = Natural::from(n);
+        let count = x.prime_count();
+        assert_eq!(count, n.prime_count());
+        if x.is_prime() {
+            assert_eq!(Natural::nth_prime(count), x);
+        }
+    });
+}
+
+#[test]
+fn nth_prime_properties() {
+    unsigned_gen_var_26::<u64>().test_properties(|n| {
+        let p = Natural::nth_prime(n);
+        assert!(p.is_valid());
+        assert!(p.is_prime());
+        assert_eq!(p, u64::nth_prime(n));
+        assert_eq!(p.prime_count(), n);
+    });
+}
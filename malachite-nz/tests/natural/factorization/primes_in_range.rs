@@ -0,0 +1,118 @@
+use itertools::Itertools;
+use malachite_base::iterators::comparison::is_strictly_ascending;
+use malachite_base::num::basic::traits::One;
+use malachite_base::num::factorization::traits::{IsPrime, NextPrime, PrimesInRange};
+use malachite_base::strings::ToDebugString;
+use malachite_base::test_util::generators::unsigned_pair_gen_var_27;
+use malachite_nz::natural::Natural;
+use malachite_nz::test_util::generators::{natural_gen, natural_pair_gen};
+use std::str::FromStr;
+
+#[test]
+fn test_primes_in_range() {
+    let test = |a: &str, b: &str, out: &str| {
+        let a = Natural::from_str(a).unwrap();
+        let b = Natural::from_str(b).unwrap();
+        let primes = Natural::primes_in_range(&a, &b).collect_vec();
+        assert!(primes.iter().all(Natural::is_valid));
+        assert_eq!(primes.to_debug_string(), out);
+    };
+    test("0", "0", "[]");
+    test("0", "2", "[]");
+    test("0", "3", "[2]");
+    test("3", "3", "[]");
+    test("5", "3", "[]");
+    test("0", "20", "[2, 3, 5, 7, 11, 13, 17, 19]");
+    test("10", "30", "[11, 13, 17, 19, 23, 29]");
+    test("90", "97", "[]");
+    test("90", "98", "[97]");
+    test(
+        "18446744073709551500",
+        "18446744073709551700",
+        "[18446744073709551521, 18446744073709551533, 18446744073709551557, \
+        18446744073709551629, 18446744073709551653, 18446744073709551667, 18446744073709551697]",
+    );
+    test(
+        "1267650600228229401496703205376",
+        "1267650600228229401496703205876",
+        "[1267650600228229401496703205653, 1267650600228229401496703205707, \
+        1267650600228229401496703205823]",
+    );
+    // The range spans several segments of the sieve.
+    assert_eq!(
+        Natural::primes_in_range(&Natural::from(1000000u32), &Natural::from(1100000u32)).count(),
+        7216
+    );
+}
+
+#[test]
+fn test_primes_greater_than_or_equal_to() {
+    let test = |a: &str, out: &str| {
+        let a = Natural::from_str(a).unwrap();
+        let primes = Natural::primes_greater_than_or_equal_to(&a)
+            .take(5)
+            .collect_vec();
+        assert!(primes.iter().all(Natural::is_valid));
+        assert_eq!(primes.to_debug_string(), out);
+    };
+    test("0", "[2, 3, 5, 7, 11]");
+    test("3", "[3, 5, 7, 11, 13]");
+    test("100", "[101, 103, 107, 109, 113]");
+    test(
+        "18446744073709551557",
+        "[18446744073709551557, 18446744073709551629, 18446744073709551653, \
+        18446744073709551667, 18446744073709551697]",
+    );
+}
+
+#[test]
+fn primes_in_range_properties() {
+    natural_pair_gen().test_properties(|(a, b)| {
+        let primes = Natural::primes_in_range(&a, &b).take(5).collect_vec();
+        assert!(primes.iter().all(Natural::is_valid));
+        assert!(is_strictly_ascending(primes.iter()));
+        // Check that no primes are skipped
+        let mut n = a.clone();
+        for p in &primes {
+            assert!(*p < b);
+            while n < *p {
+                assert!(!n.is_prime());
+                n += Natural::ONE;
+            }
+            assert!(p.is_prime());
+            n += Natural::ONE;
+        }
+        if primes.len() < 5 {
+            while n < b {
+                assert!(!n.is_prime());
+                n += Natural::ONE;
+            }
+        }
+    });
+
+    unsigned_pair_gen_var_27::<u64>().test_properties(|(a, b)| {
+        assert_eq!(
+            Natural::primes_in_range(&Natural::from(a), &Natural::from(b))
+                .take(5)
+                .collect_vec(),
+            u64::primes_in_range(&a, &b)
+                .take(5)
+                .map(Natural::from)
+                .collect_vec()
+        );
+    });
+}
+
+#[test]
+fn primes_greater_than_or_equal_to_properties() {
+    natural_gen().test_properties(|a| {
+        let mut primes = Natural::primes_greater_than_or_equal_to(&a);
+        let p = primes.next().unwrap();
+        assert!(p.is_prime());
+        assert!(p >= a);
+        if a != 0u32 {
+            assert_eq!((&a - Natural::ONE).next_prime(), p);
+        }
+        assert_eq!(p.next_prime(), primes.next().unwrap());
+    });
+}
@@ -0,0 +1,59 @@
+use itertools::Itertools;
+use malachite_base::num::factorization::traits::IsPrime;
+use malachite_base::random::EXAMPLE_SEED;
+use malachite_nz::natural::random::random_primes_in_range;
+use malachite_nz::natural::Natural;
+use std::panic::catch_unwind;
+use std::str::FromStr;
+
+fn random_primes_in_range_helper(a: &str, b: &str, expected_values: &[&str]) {
+    let a = Natural::from_str(a).unwrap();
+    let b = Natural::from_str(b).unwrap();
+    let xs = random_primes_in_range(EXAMPLE_SEED, a.clone(), b.clone())
+        .take(100)
+        .collect_vec();
+    assert_eq!(
+        xs.iter()
+            .take(expected_values.len())
+            .map(ToString::to_string)
+            .collect_vec(),
+        expected_values
+    );
+    for x in xs {
+        assert!(x.is_valid());
+        assert!(x >= a);
+        assert!(x < b);
+        assert!(x.is_prime());
+    }
+}
+
+#[test]
+fn test_random_primes_in_range() {
+    random_primes_in_range_helper("0", "3", &["2", "2", "2"]);
+    random_primes_in_range_helper("24", "30", &["29", "29", "29"]);
+    random_primes_in_range_helper(
+        "10",
+        "100",
+        &["97", "17", "37", "11", "17", "17", "97", "17", "17", "31"],
+    );
+    random_primes_in_range_helper("18446744073709551557", "18446744073709551630", &[]);
+    random_primes_in_range_helper(
+        "1267650600228229401496703205376",
+        "2535301200456458802993406410752",
+        &[],
+    );
+}
+
+#[test]
+fn random_primes_in_range_fail() {
+    assert_panic!(random_primes_in_range(
+        EXAMPLE_SEED,
+        Natural::from(10u32),
+        Natural::from(10u32)
+    ));
+    assert_panic!(random_primes_in_range(
+        EXAMPLE_SEED,
+        Natural::from(24u32),
+        Natural::from(29u32)
+    ));
+}
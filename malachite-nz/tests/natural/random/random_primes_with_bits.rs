@@ -0,0 +1,48 @@
+use itertools::Itertools;
+use malachite_base::num::factorization::traits::IsPrime;
+use malachite_base::num::logic::traits::SignificantBits;
+use malachite_base::random::EXAMPLE_SEED;
+use malachite_nz::natural::random::random_primes_with_bits;
+use std::panic::catch_unwind;
+
+fn random_primes_with_bits_helper(bits: u64, expected_values: &[&str]) {
+    let xs = random_primes_with_bits(EXAMPLE_SEED, bits)
+        .take(100)
+        .collect_vec();
+    assert_eq!(
+        xs.iter()
+            .take(expected_values.len())
+            .map(ToString::to_string)
+            .collect_vec(),
+        expected_values
+    );
+    for x in xs {
+        assert!(x.is_valid());
+        assert_eq!(x.significant_bits(), bits);
+        assert!(x.is_prime());
+    }
+}
+
+#[test]
+fn test_random_primes_with_bits() {
+    random_primes_with_bits_helper(2, &["3", "3", "3", "3", "3", "3", "2", "2", "2", "3"]);
+    random_primes_with_bits_helper(3, &["5", "7", "5", "5", "7", "5", "7", "5", "7", "7"]);
+    random_primes_with_bits_helper(
+        10,
+        &["881", "599", "727", "641", "647", "797", "839", "647", "599", "593"],
+    );
+    random_primes_with_bits_helper(
+        100,
+        &[
+            "834112884250571316017711689193",
+            "1096064561690550858427390195543",
+            "1247194096045807604465004471959",
+        ],
+    );
+}
+
+#[test]
+fn random_primes_with_bits_fail() {
+    assert_panic!(random_primes_with_bits(EXAMPLE_SEED, 0));
+    assert_panic!(random_primes_with_bits(EXAMPLE_SEED, 1));
+}
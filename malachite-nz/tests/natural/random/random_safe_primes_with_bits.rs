@@ -0,0 +1,48 @@
+use itertools::Itertools;
+use malachite_base::num::factorization::traits::IsPrime;
+use malachite_base::num::logic::traits::SignificantBits;
+use malachite_base::random::EXAMPLE_SEED;
+use malachite_nz::natural::random::random_safe_primes_with_bits;
+use std::panic::catch_unwind;
+
+fn random_safe_primes_with_bits_helper(bits: u64, expected_values: &[&str]) {
+    let xs = random_safe_primes_with_bits(EXAMPLE_SEED, bits)
+        .take(20)
+        .collect_vec();
+    assert_eq!(
+        xs.iter()
+            .take(expected_values.len())
+            .map(ToString::to_string)
+            .collect_vec(),
+        expected_values
+    );
+    for x in xs {
+        assert!(x.is_valid());
+        assert_eq!(x.significant_bits(), bits);
+        assert!(x.is_prime());
+        assert!((x >> 1u64).is_prime());
+    }
+}
+
+#[test]
+fn test_random_safe_primes_with_bits() {
+    random_safe_primes_with_bits_helper(3, &["7", "7", "7", "7", "7", "7", "5", "5", "5", "7"]);
+    random_safe_primes_with_bits_helper(
+        10,
+        &["983", "863", "983", "719", "887", "587", "719", "563", "839", "719"],
+    );
+    random_safe_primes_with_bits_helper(
+        100,
+        &[
+            "802284609635159727988428024359",
+            "1257874898996497608100316123087",
+            "1245339936798944633289852044519",
+        ],
+    );
+}
+
+#[test]
+fn random_safe_primes_with_bits_fail() {
+    assert_panic!(random_safe_primes_with_bits(EXAMPLE_SEED, 1));
+    assert_panic!(random_safe_primes_with_bits(EXAMPLE_SEED, 2));
+}
@@ -0,0 +1,91 @@
+use itertools::Itertools;
+use malachite_base::num::factorization::traits::IsPrime;
+use malachite_base::random::EXAMPLE_SEED;
+use malachite_base::strings::ToBinaryString;
+use malachite_nz::natural::random::striped_random_primes_in_range;
+use malachite_nz::natural::Natural;
+use std::panic::catch_unwind;
+use std::str::FromStr;
+
+fn striped_random_primes_in_range_helper(
+    a: &str,
+    b: &str,
+    mean_stripe_numerator: u64,
+    mean_stripe_denominator: u64,
+    expected_values: &[&str],
+) {
+    let a = Natural::from_str(a).unwrap();
+    let b = Natural::from_str(b).unwrap();
+    let xs = striped_random_primes_in_range(
+        EXAMPLE_SEED,
+        a.clone(),
+        b.clone(),
+        mean_stripe_numerator,
+        mean_stripe_denominator,
+    )
+    .take(100)
+    .collect_vec();
+    assert_eq!(
+        xs.iter()
+            .take(expected_values.len())
+            .map(ToBinaryString::to_binary_string)
+            .collect_vec(),
+        expected_values
+    );
+    for x in xs {
+        assert!(x.is_valid());
+        assert!(x >= a);
+        assert!(x < b);
+        assert!(x.is_prime());
+    }
+}
+
+#[test]
+fn test_striped_random_primes_in_range() {
+    striped_random_primes_in_range_helper("0", "3", 2, 1, &["10", "10", "10"]);
+    striped_random_primes_in_range_helper(
+        "1000",
+        "100000",
+        8,
+        1,
+        &[
+            "1111111101",
+            "1111000000011111",
+            "11000000000010001",
+            "11000000001000001",
+            "1111111100001",
+        ],
+    );
+    striped_random_primes_in_range_helper(
+        "1267650600228229401496703205376",
+        "2535301200456458802993406410752",
+        32,
+        1,
+        &[],
+    );
+}
+
+#[test]
+fn striped_random_primes_in_range_fail() {
+    assert_panic!(striped_random_primes_in_range(
+        EXAMPLE_SEED,
+        Natural::from(10u32),
+        Natural::from(10u32),
+        2,
+        1
+    ));
+    assert_panic!(striped_random_primes_in_range(
+        EXAMPLE_SEED,
+        Natural::from(24u32),
+        Natural::from(29u32),
+        2,
+        1
+    ));
+    assert_panic!(striped_random_primes_in_range(
+        EXAMPLE_SEED,
+        Natural::from(10u32),
+        Natural::from(100u32),
+        1,
+        1
+    ));
+}
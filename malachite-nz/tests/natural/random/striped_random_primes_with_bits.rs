@@ -0,0 +1,60 @@
+use itertools::Itertools;
+use malachite_base::num::factorization::traits::IsPrime;
+use malachite_base::num::logic::traits::SignificantBits;
+use malachite_base::random::EXAMPLE_SEED;
+use malachite_base::strings::ToBinaryString;
+use malachite_nz::natural::random::striped_random_primes_with_bits;
+use std::panic::catch_unwind;
+
+fn striped_random_primes_with_bits_helper(
+    bits: u64,
+    mean_stripe_numerator: u64,
+    mean_stripe_denominator: u64,
+    expected_values: &[&str],
+) {
+    let xs = striped_random_primes_with_bits(
+        EXAMPLE_SEED,
+        bits,
+        mean_stripe_numerator,
+        mean_stripe_denominator,
+    )
+    .take(100)
+    .collect_vec();
+    assert_eq!(
+        xs.iter()
+            .take(expected_values.len())
+            .map(ToBinaryString::to_binary_string)
+            .collect_vec(),
+        expected_values
+    );
+    for x in xs {
+        assert!(x.is_valid());
+        assert_eq!(x.significant_bits(), bits);
+        assert!(x.is_prime());
+    }
+}
+
+#[test]
+fn test_striped_random_primes_with_bits() {
+    striped_random_primes_with_bits_helper(2, 2, 1, &["10", "10", "10", "11", "10"]);
+    striped_random_primes_with_bits_helper(
+        16,
+        8,
+        1,
+        &[
+            "1111011111111111",
+            "1111111101111111",
+            "1110000000000011",
+            "1111100000100001",
+            "1000000011111111",
+        ],
+    );
+    striped_random_primes_with_bits_helper(100, 32, 1, &[]);
+}
+
+#[test]
+fn striped_random_primes_with_bits_fail() {
+    assert_panic!(striped_random_primes_with_bits(EXAMPLE_SEED, 1, 2, 1));
+    assert_panic!(striped_random_primes_with_bits(EXAMPLE_SEED, 10, 1, 1));
+    assert_panic!(striped_random_primes_with_bits(EXAMPLE_SEED, 10, 1, 0));
+}
@@ -0,0 +1,61 @@
+use itertools::Itertools;
+use malachite_base::num::factorization::traits::IsPrime;
+use malachite_base::num::logic::traits::SignificantBits;
+use malachite_base::random::EXAMPLE_SEED;
+use malachite_base::strings::ToBinaryString;
+use malachite_nz::natural::random::striped_random_safe_primes_with_bits;
+use std::panic::catch_unwind;
+
+fn striped_random_safe_primes_with_bits_helper(
+    bits: u64,
+    mean_stripe_numerator: u64,
+    mean_stripe_denominator: u64,
+    expected_values: &[&str],
+) {
+    let xs = striped_random_safe_primes_with_bits(
+        EXAMPLE_SEED,
+        bits,
+        mean_stripe_numerator,
+        mean_stripe_denominator,
+    )
+    .take(20)
+    .collect_vec();
+    assert_eq!(
+        xs.iter()
+            .take(expected_values.len())
+            .map(ToBinaryString::to_binary_string)
+            .collect_vec(),
+        expected_values
+    );
+    for x in xs {
+        assert!(x.is_valid());
+        assert_eq!(x.significant_bits(), bits);
+        assert!(x.is_prime());
+        assert!((x >> 1u64).is_prime());
+    }
+}
+
+#[test]
+fn test_striped_random_safe_primes_with_bits() {
+    striped_random_safe_primes_with_bits_helper(
+        16,
+        8,
+        1,
+        &[
+            "1110111111000011",
+            "1110111110011111",
+            "1111111001111011",
+            "1001000000111011",
+            "1110000011110011",
+        ],
+    );
+    striped_random_safe_primes_with_bits_helper(3, 2, 1, &[]);
+    striped_random_safe_primes_with_bits_helper(100, 32, 1, &[]);
+}
+
+#[test]
+fn striped_random_safe_primes_with_bits_fail() {
+    assert_panic!(striped_random_safe_primes_with_bits(EXAMPLE_SEED, 2, 2, 1));
+    assert_panic!(striped_random_safe_primes_with_bits(EXAMPLE_SEED, 10, 1, 1));
+    assert_panic!(striped_random_safe_primes_with_bits(EXAMPLE_SEED, 10, 1, 0));
+}
@@ -0,0 +1,228 @@
+use crate::Rational;
+use malachite_base::num::arithmetic::traits::{
+    BernoulliNumber, Divisors, Factorial, FloorLogBase2, ModInverse, ModPow, Parity, Pow, PowerOf2,
+    PrimitiveRoot,
+};
+use malachite_base::num::basic::traits::{One, Two, Zero};
+use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_base::num::factorization::traits::{IsPrime, PrimesInRange};
+use malachite_base::num::logic::traits::SignificantBits;
+use malachite_nz::natural::arithmetic::chinese_remainder::ChineseRemainderBasis;
+use malachite_nz::natural::Natural;
+
+// Returns $B_n \mod p$, where $n \geq 4$ is even and $p > n + 1$ is prime.
+//
+// This uses Voronoi's congruence. If $g$ is a primitive root modulo $p$, $y_i = g^i \mod p$, and
+// $q_i = \lfloor gy_i/p \rfloor$, then
+//
+// $$
+// (g^n - 1)B_n \equiv ng^{n-1} \sum_{i=0}^{p-2} q_i g^{i(n-1)} \mod p.
+// $$
+//
+// Each term costs a constant number of operations, since $q_i$ is the quotient computed when
+// reducing $gy_i$ to get $y_{i+1}$. Since $p < 2^{32}$ in practice, the products fit in a `u64`.
+fn bernoulli_number_mod_prime(n: u64, p: u64) -> u64 {
+    let g = p.primitive_root().unwrap();
+    let g_pow = g.mod_pow(n - 1, p);
+    let mut y = 1;
+    let mut y_pow = 1;
+    let mut sum = 0;
+    for _ in 1..p {
+        let gy = g * y;
+        sum = (sum + gy / p * y_pow) % p;
+        y = gy % p;
+        y_pow = y_pow * g_pow % p;
+    }
+    // $g^n \not\equiv 1 \mod p$, since $p - 1 > n$.
+    let denominator = (g.mod_pow(n, p) - 1).mod_inverse(p).unwrap();
+    n % p * g_pow % p * sum % p * denominator % p
+}
+
+// Uses a multi-modular algorithm. By the von Staudt-Clausen theorem, the denominator of $B_n$ is
+// $D = \prod_{(p - 1) \mid n} p$. Since
+//
+// $$
+// |B_n| = \frac{2n!\zeta(n)}{(2\pi)^n} < \frac{4n!}{6^n},
+// $$
+//
+// the numerator $DB_n$ is computed modulo enough primes greater than $n + 1$ for their product to
+// exceed $4Dn!/6^n$, and the residues are combined using the Chinese remainder theorem. The sign of
+// $B_n$ is $(-1)^{n/2+1}$.
+fn bernoulli_number(n: u64) -> Rational {
+    match n {
+        0 => return Rational::ONE,
+        1 => {
+            return Rational {
+                sign: false,
+                numerator: Natural::ONE,
+                denominator: Natural::TWO,
+            }
+        }
+        2 => {
+            return Rational {
+                sign: true,
+                numerator: Natural::ONE,
+                denominator: Natural::from(6u32),
+            }
+        }
+        _ if n.odd() => return Rational::ZERO,
+        _ => {}
+    }
+    let denominator_primes: Vec<u64> = n
+        .divisors()
+        .map(|d| d + 1)
+        .filter(IsPrime::is_prime)
+        .collect();
+    let denominator = denominator_primes
+        .iter()
+        .fold(Natural::ONE, |d, &p| d * Natural::from(p));
+    let bound = ((Natural::factorial(n) * &denominator) << 2u64) / Natural::from(6u32).pow(n);
+    let bound_bits = bound.significant_bits();
+    let sign = (n >> 1).odd();
+    let mut moduli = Vec::new();
+    let mut residues = Vec::new();
+    let mut bits = 0;
+    for p in u64::primes_greater_than_or_equal_to(&(n + 2)) {
+        if bits >= bound_bits {
+            break;
+        }
+        let d = denominator_primes.iter().fold(1, |d, &q| d * (q % p) % p);
+        let mut r = bernoulli_number_mod_prime(n, p) * d % p;
+        if !sign && r != 0 {
+            r = p - r;
+        }
+        moduli.push(Natural::from(p));
+        residues.push(Natural::from(r));
+        bits += p.floor_log_base_2();
+    }
+    Rational {
+        sign,
+        numerator: ChineseRemainderBasis::new(&moduli)
+            .unwrap()
+            .recombine(&residues),
+        denominator,
+    }
+}
+
+/// An iterator that generates the Bernoulli numbers.
+///
+/// This `struct` is created by [`Rational::bernoulli_numbers`]; see its documentation for more.
+#[derive(Clone, Debug)]
+pub struct BernoulliNumbersIterator {
+    // The most recently computed row of the Seidel-Entringer-Arnold triangle. If it is row $n$,
+    // its second element is the zigzag number $A_{n-1}$, and $A_{2k-1}$ is the tangent number
+    // $T_k$.
+    row: Vec<Natural>,
+}
+
+impl Iterator for BernoulliNumbersIterator {
+    type Item = Rational;
+
+    fn next(&mut self) -> Option<Rational> {
+        if self.row.is_empty() {
+            self.row.push(Natural::ONE);
+            return Some(Rational::ONE);
+        }
+        // Row $n + 1$ is 0, followed by the partial sums of row $n$ read from right to left.
+        let mut sum = Natural::ZERO;
+        for x in self.row.iter_mut().rev() {
+            sum += &*x;
+            x.clone_from(&sum);
+        }
+        self.row.reverse();
+        self.row.insert(0, Natural::ZERO);
+        let n = u64::exact_from(self.row.len() - 1);
+        Some(if n == 1 {
+            Rational {
+                sign: false,
+                numerator: Natural::ONE,
+                denominator: Natural::TWO,
+            }
+        } else if n.odd() {
+            Rational::ZERO
+        } else {
+            // $B_n = (-1)^{n/2+1} \frac{nT_{n/2}}{2^n(2^n - 1)}$
+            let power = Natural::power_of_2(n);
+            Rational::from_sign_and_naturals(
+                (n >> 1).odd(),
+                Natural::from(n) * &self.row[1],
+                (&power - Natural::ONE) * power,
+            )
+        })
+    }
+}
+
+impl BernoulliNumber for Rational {
+    type I = BernoulliNumbersIterator;
+
+    /// Computes the $n$th Bernoulli number.
+    ///
+    /// The Bernoulli numbers are the coefficients of the Taylor series
+    /// $$
+    /// \frac{x}{e^x - 1} = \sum_{n=0}^\infty \frac{B_n}{n!} x^n,
+    /// $$
+    /// so $B_1 = -\frac{1}{2}$. $B_n$ is zero when $n > 1$ is odd, and its sign is $(-1)^{n/2+1}$
+    /// when $n > 0$ is even.
+    ///
+    /// The denominator is found using the von Staudt-Clausen theorem. The numerator is computed
+    /// modulo many primes larger than $n + 1$, using Voronoi's congruence, which takes $O(p)$
+    /// operations modulo each prime $p$, and the residues are combined using the Chinese
+    /// remainder theorem.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `n`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::BernoulliNumber;
+    /// use malachite_q::Rational;
+    ///
+    /// assert_eq!(Rational::bernoulli_number(0), 1);
+    /// assert_eq!(Rational::bernoulli_number(1).to_string(), "-1/2");
+    /// assert_eq!(Rational::bernoulli_number(2).to_string(), "1/6");
+    /// assert_eq!(Rational::bernoulli_number(3), 0);
+    /// assert_eq!(Rational::bernoulli_number(10).to_string(), "5/66");
+    /// assert_eq!(Rational::bernoulli_number(20).to_string(), "-174611/330");
+    /// assert_eq!(
+    ///     Rational::bernoulli_number(50).to_string(),
+    ///     "495057205241079648212477525/66"
+    /// );
+    /// ```
+    #[inline]
+    fn bernoulli_number(n: u64) -> Rational {
+        bernoulli_number(n)
+    }
+
+    /// Returns an iterator that generates the Bernoulli numbers $B_0, B_1, B_2, \ldots$.
+    ///
+    /// The values are computed from the tangent numbers, which are read off the
+    /// Seidel-Entringer-Arnold triangle. Generating the first $n$ Bernoulli numbers this way is
+    /// much faster than computing each of them separately.
+    ///
+    /// # Worst-case complexity per iteration
+    /// $T(i) = O(i^2 \log i)$
+    ///
+    /// $M(i) = O(i^2 \log i)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $i$ is the iteration index.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::iterators::prefix_to_string;
+    /// use malachite_base::num::arithmetic::traits::BernoulliNumber;
+    /// use malachite_q::Rational;
+    ///
+    /// assert_eq!(
+    ///     prefix_to_string(Rational::bernoulli_numbers(), 13),
+    ///     "[1, -1/2, 1/6, 0, -1/30, 0, 1/42, 0, -1/30, 0, 5/66, 0, -691/2730, ...]"
+    /// );
+    /// ```
+    #[inline]
+    fn bernoulli_numbers() -> BernoulliNumbersIterator {
+        BernoulliNumbersIterator { row: Vec::new() }
+    }
+}
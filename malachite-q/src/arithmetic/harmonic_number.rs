@@ -0,0 +1,111 @@
+use crate::Rational;
+use malachite_base::num::arithmetic::traits::HarmonicNumber;
+use malachite_base::num::basic::traits::{One, Zero};
+use malachite_nz::natural::Natural;
+
+// Returns $(P, Q)$, where $P/Q = \sum_{i=a+1}^b 1/i$ and $Q = \prod_{i=a+1}^b i$. Assumes that
+// $a < b$.
+//
+// This uses binary splitting: the range is divided in half, and the two fractions are added without
+// reducing them, so that the numbers being multiplied have similar sizes.
+fn harmonic_sum(a: u64, b: u64) -> (Natural, Natural) {
+    if b - a == 1 {
+        return (Natural::ONE, Natural::from(b));
+    }
+    let mid = a + ((b - a) >> 1);
+    let (p_1, q_1) = harmonic_sum(a, mid);
+    let (p_2, q_2) = harmonic_sum(mid, b);
+    (p_1 * &q_2 + p_2 * &q_1, q_1 * q_2)
+}
+
+/// An iterator that generates the harmonic numbers.
+///
+/// This `struct` is created by [`Rational::harmonic_numbers`]; see its documentation for more.
+#[derive(Clone, Debug)]
+pub struct HarmonicNumbersIterator {
+    n: u64,
+    h: Rational,
+}
+
+impl Iterator for HarmonicNumbersIterator {
+    type Item = Rational;
+
+    fn next(&mut self) -> Option<Rational> {
+        let h = self.h.clone();
+        self.n += 1;
+        self.h += Rational::from_naturals(Natural::ONE, Natural::from(self.n));
+        Some(h)
+    }
+}
+
+impl HarmonicNumber for Rational {
+    type I = HarmonicNumbersIterator;
+
+    /// Computes the $n$th harmonic number.
+    ///
+    /// $$
+    /// f(n) = H_n = \sum_{i=1}^n \frac{1}{i}.
+    /// $$
+    ///
+    /// The sum is computed using binary splitting, and only reduced at the end.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^3 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `n`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::HarmonicNumber;
+    /// use malachite_q::Rational;
+    ///
+    /// assert_eq!(Rational::harmonic_number(0), 0);
+    /// assert_eq!(Rational::harmonic_number(1), 1);
+    /// assert_eq!(Rational::harmonic_number(2).to_string(), "3/2");
+    /// assert_eq!(Rational::harmonic_number(10).to_string(), "7381/2520");
+    /// assert_eq!(
+    ///     Rational::harmonic_number(30).to_string(),
+    ///     "9304682830147/2329089562800"
+    /// );
+    /// ```
+    fn harmonic_number(n: u64) -> Rational {
+        if n == 0 {
+            Rational::ZERO
+        } else {
+            let (p, q) = harmonic_sum(0, n);
+            Rational::from_naturals(p, q)
+        }
+    }
+
+    /// Returns an iterator that generates the harmonic numbers $H_0, H_1, H_2, \ldots$.
+    ///
+    /// Each harmonic number is obtained from the previous one by adding $1/n$.
+    ///
+    /// # Worst-case complexity per iteration
+    /// $T(i) = O(i (\log i)^2 \log\log i)$
+    ///
+    /// $M(i) = O(i \log i)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $i$ is the iteration index.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::iterators::prefix_to_string;
+    /// use malachite_base::num::arithmetic::traits::HarmonicNumber;
+    /// use malachite_q::Rational;
+    ///
+    /// assert_eq!(
+    ///     prefix_to_string(Rational::harmonic_numbers(), 8),
+    ///     "[0, 1, 3/2, 11/6, 25/12, 137/60, 49/20, 363/140, ...]"
+    /// );
+    /// ```
+    #[inline]
+    fn harmonic_numbers() -> HarmonicNumbersIterator {
+        HarmonicNumbersIterator {
+            n: 0,
+            h: Rational::ZERO,
+        }
+    }
+}
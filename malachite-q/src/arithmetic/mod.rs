@@ -6,6 +6,10 @@ pub mod add;
 /// [`ApproximateAssign`](traits::ApproximateAssign), traits for approximating a
 /// [`Rational`](super::Rational) by a [`Rational`](super::Rational) with a bounded denominator.
 pub mod approximate;
+/// An implementation of
+/// [`BernoulliNumber`](malachite_base::num::arithmetic::traits::BernoulliNumber), a trait for
+/// computing Bernoulli numbers.
+pub mod bernoulli_number;
 /// Implementations of [`Ceiling`](malachite_base::num::arithmetic::traits::Ceiling) and
 /// [`CeilingAssign`](malachite_base::num::arithmetic::traits::CeilingAssign), traits for taking
 /// the ceiling of a number.
@@ -18,6 +22,10 @@ pub mod div;
 /// [`FloorAssign`](malachite_base::num::arithmetic::traits::FloorAssign), traits for taking the
 /// floor of a number.
 pub mod floor;
+/// An implementation of
+/// [`HarmonicNumber`](malachite_base::num::arithmetic::traits::HarmonicNumber), a trait for
+/// computing harmonic numbers.
+pub mod harmonic_number;
 /// Implementations of [`IsPowerOf2`](malachite_base::num::arithmetic::traits::IsPowerOf2), a trait
 /// for determining whether a number is an integer power of 2.
 pub mod is_power_of_2;
@@ -0,0 +1,42 @@
+use malachite_base::num::arithmetic::traits::BernoulliNumber;
+use malachite_base::test_util::bench::bucketers::unsigned_direct_bucketer;
+use malachite_base::test_util::bench::{run_benchmark, BenchmarkType};
+use malachite_base::test_util::generators::common::{GenConfig, GenMode};
+use malachite_base::test_util::generators::unsigned_gen_var_5;
+use malachite_base::test_util::runner::Runner;
+use malachite_q::Rational;
+
+pub(crate) fn register(runner: &mut Runner) {
+    register_demo!(runner, demo_bernoulli_number);
+    register_demo!(runner, demo_bernoulli_numbers);
+    register_bench!(runner, benchmark_bernoulli_number);
+}
+
+fn demo_bernoulli_number(gm: GenMode, config: GenConfig, limit: usize) {
+    for n in unsigned_gen_var_5().get(gm, &config).take(limit) {
+        println!(
+            "bernoulli_number({}) = {}",
+            n,
+            Rational::bernoulli_number(n)
+        );
+    }
+}
+
+fn demo_bernoulli_numbers(_gm: GenMode, _config: GenConfig, limit: usize) {
+    for (n, x) in Rational::bernoulli_numbers().enumerate().take(limit) {
+        println!("bernoulli_number({}) = {}", n, x);
+    }
+}
+
+fn benchmark_bernoulli_number(gm: GenMode, config: GenConfig, limit: usize, file_name: &str) {
+    run_benchmark(
+        "Rational::bernoulli_number(u64)",
+        BenchmarkType::Single,
+        unsigned_gen_var_5().get(gm, &config),
+        gm.name(),
+        limit,
+        file_name,
+        &unsigned_direct_bucketer(),
+        &mut [("Malachite", &mut |n| no_out!(Rational::bernoulli_number(n)))],
+    );
+}
@@ -0,0 +1,38 @@
+use malachite_base::num::arithmetic::traits::HarmonicNumber;
+use malachite_base::test_util::bench::bucketers::unsigned_direct_bucketer;
+use malachite_base::test_util::bench::{run_benchmark, BenchmarkType};
+use malachite_base::test_util::generators::common::{GenConfig, GenMode};
+use malachite_base::test_util::generators::unsigned_gen_var_5;
+use malachite_base::test_util::runner::Runner;
+use malachite_q::Rational;
+
+pub(crate) fn register(runner: &mut Runner) {
+    register_demo!(runner, demo_harmonic_number);
+    register_demo!(runner, demo_harmonic_numbers);
+    register_bench!(runner, benchmark_harmonic_number);
+}
+
+fn demo_harmonic_number(gm: GenMode, config: GenConfig, limit: usize) {
+    for n in unsigned_gen_var_5().get(gm, &config).take(limit) {
+        println!("harmonic_number({}) = {}", n, Rational::harmonic_number(n));
+    }
+}
+
+fn demo_harmonic_numbers(_gm: GenMode, _config: GenConfig, limit: usize) {
+    for (n, x) in Rational::harmonic_numbers().enumerate().take(limit) {
+        println!("harmonic_number({}) = {}", n, x);
+    }
+}
+
+fn benchmark_harmonic_number(gm: GenMode, config: GenConfig, limit: usize, file_name: &str) {
+    run_benchmark(
+        "Rational::harmonic_number(u64)",
+        BenchmarkType::Single,
+        unsigned_gen_var_5().get(gm, &config),
+        gm.name(),
+        limit,
+        file_name,
+        &unsigned_direct_bucketer(),
+        &mut [("Malachite", &mut |n| no_out!(Rational::harmonic_number(n)))],
+    );
+}
@@ -4,10 +4,12 @@ pub(crate) fn register(runner: &mut Runner) {
     abs::register(runner);
     add::register(runner);
     approximate::register(runner);
+    bernoulli_number::register(runner);
     ceiling::register(runner);
     denominators_in_closed_interval::register(runner);
     div::register(runner);
     floor::register(runner);
+    harmonic_number::register(runner);
     is_power_of_2::register(runner);
     log_base::register(runner);
     log_base_2::register(runner);
@@ -33,10 +35,12 @@ pub(crate) fn register(runner: &mut Runner) {
 mod abs;
 mod add;
 mod approximate;
+mod bernoulli_number;
 mod ceiling;
 mod denominators_in_closed_interval;
 mod div;
 mod floor;
+mod harmonic_number;
 mod is_power_of_2;
 mod log_base;
 mod log_base_2;
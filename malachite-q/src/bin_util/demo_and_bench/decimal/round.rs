@@ -0,0 +1,56 @@
+use malachite_base::rounding_modes::RoundingMode;
+use malachite_base::test_util::bench::{run_benchmark, BenchmarkType};
+use malachite_base::test_util::generators::common::{GenConfig, GenMode};
+use malachite_base::test_util::runner::Runner;
+use malachite_nz::test_util::bench::bucketers::triple_1_integer_bit_bucketer;
+use malachite_nz::test_util::generators::integer_integer_signed_triple_gen;
+use malachite_q::decimal::Decimal;
+
+pub(crate) fn register(runner: &mut Runner) {
+    register_demo!(runner, demo_decimal_div_round_to_scale);
+    register_bench!(runner, benchmark_decimal_div_round_to_scale);
+}
+
+fn demo_decimal_div_round_to_scale(gm: GenMode, config: GenConfig, limit: usize) {
+    for (m, n, s) in integer_integer_signed_triple_gen::<i8>()
+        .get(gm, &config)
+        .take(limit)
+    {
+        if n == 0u32 {
+            continue;
+        }
+        let x = Decimal::from_mantissa_and_scale(m, i64::from(s));
+        let y = Decimal::from_mantissa_and_scale(n, 0);
+        println!(
+            "{}.div_round_to_scale({}, {}, Nearest) = {}",
+            x,
+            y,
+            s,
+            x.div_round_to_scale(&y, i64::from(s), RoundingMode::Nearest)
+        );
+    }
+}
+
+fn benchmark_decimal_div_round_to_scale(
+    gm: GenMode,
+    config: GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "Decimal.div_round_to_scale(&Decimal, i64, RoundingMode)",
+        BenchmarkType::Single,
+        integer_integer_signed_triple_gen::<i8>().get(gm, &config),
+        gm.name(),
+        limit,
+        file_name,
+        &triple_1_integer_bit_bucketer("m"),
+        &mut [("Malachite", &mut |(m, n, s)| {
+            if n != 0u32 {
+                let x = Decimal::from_mantissa_and_scale(m, i64::from(s));
+                let y = Decimal::from_mantissa_and_scale(n, 0);
+                no_out!(x.div_round_to_scale(&y, i64::from(s), RoundingMode::Nearest))
+            }
+        })],
+    );
+}
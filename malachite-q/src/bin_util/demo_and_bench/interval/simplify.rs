@@ -0,0 +1,77 @@
+use malachite_base::num::arithmetic::traits::Abs;
+use malachite_base::test_util::bench::{run_benchmark, BenchmarkType};
+use malachite_base::test_util::generators::common::{GenConfig, GenMode};
+use malachite_base::test_util::runner::Runner;
+use malachite_nz::natural::Natural;
+use malachite_q::interval::Interval;
+use malachite_q::test_util::bench::bucketers::triple_1_rational_bit_bucketer;
+use malachite_q::test_util::generators::rational_triple_gen;
+use malachite_q::Rational;
+
+pub(crate) fn register(runner: &mut Runner) {
+    register_demo!(runner, demo_interval_simplify);
+    register_demo!(runner, demo_interval_round_outward);
+    register_bench!(runner, benchmark_interval_simplify);
+    register_bench!(runner, benchmark_interval_round_outward);
+}
+
+fn interval_from_endpoints(x: Rational, y: Rational) -> Interval<Rational> {
+    if x <= y {
+        Interval::new(x, y)
+    } else {
+        Interval::new(y, x)
+    }
+}
+
+fn demo_interval_simplify(gm: GenMode, config: GenConfig, limit: usize) {
+    for (x, y, z) in rational_triple_gen().get(gm, &config).take(limit) {
+        let i = interval_from_endpoints(x, y);
+        let tolerance = z.abs();
+        println!("{}.simplify({}) = {}", i, tolerance, i.simplify(&tolerance));
+    }
+}
+
+fn demo_interval_round_outward(gm: GenMode, config: GenConfig, limit: usize) {
+    for (x, y, z) in rational_triple_gen().get(gm, &config).take(limit) {
+        let i = interval_from_endpoints(x, y);
+        let max_denominator: Natural = z.into_numerator_and_denominator().1;
+        println!(
+            "{}.round_outward({}) = {}",
+            i,
+            max_denominator,
+            i.round_outward(&max_denominator)
+        );
+    }
+}
+
+fn benchmark_interval_simplify(gm: GenMode, config: GenConfig, limit: usize, file_name: &str) {
+    run_benchmark(
+        "Interval<Rational>::simplify(&Rational)",
+        BenchmarkType::Single,
+        rational_triple_gen().get(gm, &config),
+        gm.name(),
+        limit,
+        file_name,
+        &triple_1_rational_bit_bucketer("x"),
+        &mut [("Malachite", &mut |(x, y, z)| {
+            no_out!(interval_from_endpoints(x, y).simplify(&z.abs()))
+        })],
+    );
+}
+
+fn benchmark_interval_round_outward(gm: GenMode, config: GenConfig, limit: usize, file_name: &str) {
+    run_benchmark(
+        "Interval<Rational>::round_outward(&Natural)",
+        BenchmarkType::Single,
+        rational_triple_gen().get(gm, &config),
+        gm.name(),
+        limit,
+        file_name,
+        &triple_1_rational_bit_bucketer("x"),
+        &mut [("Malachite", &mut |(x, y, z)| {
+            no_out!(
+                interval_from_endpoints(x, y).round_outward(&z.into_numerator_and_denominator().1)
+            )
+        })],
+    );
+}
@@ -0,0 +1,77 @@
+use malachite_base::num::arithmetic::traits::FloorSqrt;
+use malachite_base::test_util::bench::bucketers::vec_len_bucketer;
+use malachite_base::test_util::bench::{run_benchmark, BenchmarkType};
+use malachite_base::test_util::generators::common::{GenConfig, GenMode};
+use malachite_base::test_util::runner::Runner;
+use malachite_nz::integer::Integer;
+use malachite_nz::test_util::generators::integer_vec_gen;
+use malachite_q::matrix::Matrix;
+use malachite_q::test_util::generators::rational_vec_gen;
+use malachite_q::Rational;
+
+pub(crate) fn register(runner: &mut Runner) {
+    register_demo!(runner, demo_integer_matrix_determinant);
+    register_demo!(runner, demo_rational_matrix_determinant);
+    register_bench!(runner, benchmark_integer_matrix_determinant);
+    register_bench!(runner, benchmark_rational_matrix_determinant);
+}
+
+// Arranges as many of the elements of `xs` as possible into a square matrix.
+fn square_matrix_from_vec<T: Clone>(xs: &[T]) -> Matrix<T> {
+    let n = xs.len().floor_sqrt();
+    Matrix::from_rows(xs[..n * n].chunks(n).map(<[T]>::to_vec).collect())
+}
+
+fn demo_integer_matrix_determinant(gm: GenMode, config: GenConfig, limit: usize) {
+    for xs in integer_vec_gen().get(gm, &config).take(limit) {
+        let m = square_matrix_from_vec(&xs);
+        println!("det({:?}) = {}", m.clone().into_rows(), m.determinant());
+    }
+}
+
+fn demo_rational_matrix_determinant(gm: GenMode, config: GenConfig, limit: usize) {
+    for xs in rational_vec_gen().get(gm, &config).take(limit) {
+        let m = square_matrix_from_vec(&xs);
+        println!("det({:?}) = {}", m.clone().into_rows(), m.determinant());
+    }
+}
+
+fn benchmark_integer_matrix_determinant(
+    gm: GenMode,
+    config: GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "Matrix<Integer>::determinant()",
+        BenchmarkType::Single,
+        integer_vec_gen().get(gm, &config),
+        gm.name(),
+        limit,
+        file_name,
+        &vec_len_bucketer(),
+        &mut [("Malachite", &mut |xs: Vec<Integer>| {
+            no_out!(square_matrix_from_vec(&xs).determinant())
+        })],
+    );
+}
+
+fn benchmark_rational_matrix_determinant(
+    gm: GenMode,
+    config: GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "Matrix<Rational>::determinant()",
+        BenchmarkType::Single,
+        rational_vec_gen().get(gm, &config),
+        gm.name(),
+        limit,
+        file_name,
+        &vec_len_bucketer(),
+        &mut [("Malachite", &mut |xs: Vec<Rational>| {
+            no_out!(square_matrix_from_vec(&xs).determinant())
+        })],
+    );
+}
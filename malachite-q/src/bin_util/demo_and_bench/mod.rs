@@ -5,9 +5,17 @@ pub(crate) fn register(runner: &mut Runner) {
     basic::register(runner);
     comparison::register(runner);
     conversion::register(runner);
+    decimal::register(runner);
+    interval::register(runner);
+    matrix::register(runner);
+    polynomial::register(runner);
 }
 
 mod arithmetic;
 mod basic;
 mod comparison;
 mod conversion;
+mod decimal;
+mod interval;
+mod matrix;
+mod polynomial;
@@ -0,0 +1,9 @@
+use malachite_base::test_util::runner::Runner;
+
+pub(crate) fn register(runner: &mut Runner) {
+    mul::register(runner);
+    roots::register(runner);
+}
+
+mod mul;
+mod roots;
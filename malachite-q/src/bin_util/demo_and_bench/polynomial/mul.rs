@@ -0,0 +1,67 @@
+use malachite_base::num::basic::traits::Zero;
+use malachite_base::test_util::bench::bucketers::vec_len_bucketer;
+use malachite_base::test_util::bench::{run_benchmark, BenchmarkType};
+use malachite_base::test_util::generators::common::{GenConfig, GenMode};
+use malachite_base::test_util::runner::Runner;
+use malachite_nz::integer::Integer;
+use malachite_nz::test_util::generators::integer_vec_gen;
+use malachite_q::polynomial::Polynomial;
+
+pub(crate) fn register(runner: &mut Runner) {
+    register_demo!(runner, demo_integer_polynomial_mul);
+    register_bench!(runner, benchmark_integer_polynomial_mul_algorithms);
+}
+
+// Splits `xs` into the coefficients of two polynomials.
+fn polynomial_pair_from_vec(mut xs: Vec<Integer>) -> (Polynomial<Integer>, Polynomial<Integer>) {
+    let ys = xs.split_off(xs.len() >> 1);
+    (Polynomial::from(xs), Polynomial::from(ys))
+}
+
+fn mul_naive(p: &Polynomial<Integer>, q: &Polynomial<Integer>) -> Polynomial<Integer> {
+    let (xs, ys) = (p.coefficients(), q.coefficients());
+    if xs.is_empty() || ys.is_empty() {
+        return Polynomial::from(Vec::new());
+    }
+    let mut out = vec![Integer::ZERO; xs.len() + ys.len() - 1];
+    for (i, x) in xs.iter().enumerate() {
+        for (j, y) in ys.iter().enumerate() {
+            out[i + j] += x * y;
+        }
+    }
+    Polynomial::from(out)
+}
+
+fn demo_integer_polynomial_mul(gm: GenMode, config: GenConfig, limit: usize) {
+    for xs in integer_vec_gen().get(gm, &config).take(limit) {
+        let (p, q) = polynomial_pair_from_vec(xs);
+        println!("({}) * ({}) = {}", p, q, &p * &q);
+    }
+}
+
+fn benchmark_integer_polynomial_mul_algorithms(
+    gm: GenMode,
+    config: GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "Polynomial<Integer> * Polynomial<Integer>",
+        BenchmarkType::Algorithms,
+        integer_vec_gen().get(gm, &config),
+        gm.name(),
+        limit,
+        file_name,
+        &vec_len_bucketer(),
+        &mut [
+            ("default", &mut |xs| {
+                let (p, q) = polynomial_pair_from_vec(xs);
+                no_out!(p * q)
+            }),
+            ("naive", &mut |xs| {
+                let (p, q) = polynomial_pair_from_vec(xs);
+                no_out!(mul_naive(&p, &q))
+            }),
+        ],
+    );
+}
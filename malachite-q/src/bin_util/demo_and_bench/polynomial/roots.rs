@@ -0,0 +1,49 @@
+use malachite_base::test_util::bench::bucketers::vec_len_bucketer;
+use malachite_base::test_util::bench::{run_benchmark, BenchmarkType};
+use malachite_base::test_util::generators::common::{GenConfig, GenMode};
+use malachite_base::test_util::runner::Runner;
+use malachite_nz::integer::Integer;
+use malachite_nz::test_util::generators::integer_vec_gen;
+use malachite_q::polynomial::Polynomial;
+
+pub(crate) fn register(runner: &mut Runner) {
+    register_demo!(runner, demo_integer_polynomial_isolate_real_roots);
+    register_bench!(runner, benchmark_integer_polynomial_isolate_real_roots);
+}
+
+fn demo_integer_polynomial_isolate_real_roots(gm: GenMode, config: GenConfig, limit: usize) {
+    for xs in integer_vec_gen().get(gm, &config).take(limit) {
+        let p = Polynomial::from(xs);
+        if p.degree().is_some() {
+            let roots: Vec<String> = p
+                .isolate_real_roots()
+                .iter()
+                .map(ToString::to_string)
+                .collect();
+            println!("real roots of {}: {:?}", p, roots);
+        }
+    }
+}
+
+fn benchmark_integer_polynomial_isolate_real_roots(
+    gm: GenMode,
+    config: GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "Polynomial<Integer>::isolate_real_roots()",
+        BenchmarkType::Single,
+        integer_vec_gen().get(gm, &config),
+        gm.name(),
+        limit,
+        file_name,
+        &vec_len_bucketer(),
+        &mut [("Malachite", &mut |xs: Vec<Integer>| {
+            let p = Polynomial::from(xs);
+            if p.degree().is_some() {
+                no_out!(p.isolate_real_roots())
+            }
+        })],
+    );
+}
@@ -0,0 +1,565 @@
+use crate::complex_rational::ComplexRational;
+use crate::Rational;
+use malachite_base::num::arithmetic::traits::{Pow, Square};
+use malachite_base::num::basic::traits::One;
+use std::ops::{Add, Mul, Neg, Sub};
+
+fn add_helper(x: &ComplexRational, y: &ComplexRational) -> ComplexRational {
+    ComplexRational {
+        real: &x.real + &y.real,
+        imag: &x.imag + &y.imag,
+    }
+}
+
+fn sub_helper(x: &ComplexRational, y: &ComplexRational) -> ComplexRational {
+    ComplexRational {
+        real: &x.real - &y.real,
+        imag: &x.imag - &y.imag,
+    }
+}
+
+pub(crate) fn mul_helper(x: &ComplexRational, y: &ComplexRational) -> ComplexRational {
+    ComplexRational {
+        real: &x.real * &y.real - &x.imag * &y.imag,
+        imag: &x.real * &y.imag + &x.imag * &y.real,
+    }
+}
+
+impl Add<ComplexRational> for ComplexRational {
+    type Output = ComplexRational;
+
+    /// Adds two [`ComplexRational`]s, taking both by value.
+    ///
+    /// $f(a + bi, c + di) = (a + c) + (b + d)i$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the largest number of significant
+    /// bits of any of the numerators and denominators.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_q::complex_rational::ComplexRational;
+    /// use malachite_q::Rational;
+    ///
+    /// let x = ComplexRational::new(Rational::from_signeds(1, 2), Rational::from(1));
+    /// let y = ComplexRational::new(Rational::from_signeds(1, 3), Rational::from(-2));
+    /// assert_eq!((x + y).to_string(), "5/6-i");
+    /// ```
+    #[inline]
+    fn add(self, other: ComplexRational) -> ComplexRational {
+        add_helper(&self, &other)
+    }
+}
+
+impl<'a> Add<&'a ComplexRational> for ComplexRational {
+    type Output = ComplexRational;
+
+    /// Adds two [`ComplexRational`]s, taking the first by value and the second by reference.
+    ///
+    /// $f(a + bi, c + di) = (a + c) + (b + d)i$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the largest number of significant
+    /// bits of any of the numerators and denominators.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_q::complex_rational::ComplexRational;
+    /// use malachite_q::Rational;
+    ///
+    /// let x = ComplexRational::new(Rational::from_signeds(1, 2), Rational::from(1));
+    /// let y = ComplexRational::new(Rational::from_signeds(1, 3), Rational::from(-2));
+    /// assert_eq!((x + &y).to_string(), "5/6-i");
+    /// ```
+    #[inline]
+    fn add(self, other: &'a ComplexRational) -> ComplexRational {
+        add_helper(&self, other)
+    }
+}
+
+impl<'a> Add<ComplexRational> for &'a ComplexRational {
+    type Output = ComplexRational;
+
+    /// Adds two [`ComplexRational`]s, taking the first by reference and the second by value.
+    ///
+    /// $f(a + bi, c + di) = (a + c) + (b + d)i$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the largest number of significant
+    /// bits of any of the numerators and denominators.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_q::complex_rational::ComplexRational;
+    /// use malachite_q::Rational;
+    ///
+    /// let x = ComplexRational::new(Rational::from_signeds(1, 2), Rational::from(1));
+    /// let y = ComplexRational::new(Rational::from_signeds(1, 3), Rational::from(-2));
+    /// assert_eq!((&x + y).to_string(), "5/6-i");
+    /// ```
+    #[inline]
+    fn add(self, other: ComplexRational) -> ComplexRational {
+        add_helper(self, &other)
+    }
+}
+
+impl<'a, 'b> Add<&'a ComplexRational> for &'b ComplexRational {
+    type Output = ComplexRational;
+
+    /// Adds two [`ComplexRational`]s, taking both by reference.
+    ///
+    /// $f(a + bi, c + di) = (a + c) + (b + d)i$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the largest number of significant
+    /// bits of any of the numerators and denominators.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_q::complex_rational::ComplexRational;
+    /// use malachite_q::Rational;
+    ///
+    /// let x = ComplexRational::new(Rational::from_signeds(1, 2), Rational::from(1));
+    /// let y = ComplexRational::new(Rational::from_signeds(1, 3), Rational::from(-2));
+    /// assert_eq!((&x + &y).to_string(), "5/6-i");
+    /// ```
+    #[inline]
+    fn add(self, other: &'a ComplexRational) -> ComplexRational {
+        add_helper(self, other)
+    }
+}
+
+impl Sub<ComplexRational> for ComplexRational {
+    type Output = ComplexRational;
+
+    /// Subtracts two [`ComplexRational`]s, taking both by value.
+    ///
+    /// $f(a + bi, c + di) = (a - c) + (b - d)i$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the largest number of significant
+    /// bits of any of the numerators and denominators.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_q::complex_rational::ComplexRational;
+    /// use malachite_q::Rational;
+    ///
+    /// let x = ComplexRational::new(Rational::from_signeds(1, 2), Rational::from(1));
+    /// let y = ComplexRational::new(Rational::from_signeds(1, 3), Rational::from(-2));
+    /// assert_eq!((x - y).to_string(), "1/6+3i");
+    /// ```
+    #[inline]
+    fn sub(self, other: ComplexRational) -> ComplexRational {
+        sub_helper(&self, &other)
+    }
+}
+
+impl<'a> Sub<&'a ComplexRational> for ComplexRational {
+    type Output = ComplexRational;
+
+    /// Subtracts two [`ComplexRational`]s, taking the first by value and the second by reference.
+    ///
+    /// $f(a + bi, c + di) = (a - c) + (b - d)i$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the largest number of significant
+    /// bits of any of the numerators and denominators.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_q::complex_rational::ComplexRational;
+    /// use malachite_q::Rational;
+    ///
+    /// let x = ComplexRational::new(Rational::from_signeds(1, 2), Rational::from(1));
+    /// let y = ComplexRational::new(Rational::from_signeds(1, 3), Rational::from(-2));
+    /// assert_eq!((x - &y).to_string(), "1/6+3i");
+    /// ```
+    #[inline]
+    fn sub(self, other: &'a ComplexRational) -> ComplexRational {
+        sub_helper(&self, other)
+    }
+}
+
+impl<'a> Sub<ComplexRational> for &'a ComplexRational {
+    type Output = ComplexRational;
+
+    /// Subtracts two [`ComplexRational`]s, taking the first by reference and the second by value.
+    ///
+    /// $f(a + bi, c + di) = (a - c) + (b - d)i$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the largest number of significant
+    /// bits of any of the numerators and denominators.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_q::complex_rational::ComplexRational;
+    /// use malachite_q::Rational;
+    ///
+    /// let x = ComplexRational::new(Rational::from_signeds(1, 2), Rational::from(1));
+    /// let y = ComplexRational::new(Rational::from_signeds(1, 3), Rational::from(-2));
+    /// assert_eq!((&x - y).to_string(), "1/6+3i");
+    /// ```
+    #[inline]
+    fn sub(self, other: ComplexRational) -> ComplexRational {
+        sub_helper(self, &other)
+    }
+}
+
+impl<'a, 'b> Sub<&'a ComplexRational> for &'b ComplexRational {
+    type Output = ComplexRational;
+
+    /// Subtracts two [`ComplexRational`]s, taking both by reference.
+    ///
+    /// $f(a + bi, c + di) = (a - c) + (b - d)i$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the largest number of significant
+    /// bits of any of the numerators and denominators.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_q::complex_rational::ComplexRational;
+    /// use malachite_q::Rational;
+    ///
+    /// let x = ComplexRational::new(Rational::from_signeds(1, 2), Rational::from(1));
+    /// let y = ComplexRational::new(Rational::from_signeds(1, 3), Rational::from(-2));
+    /// assert_eq!((&x - &y).to_string(), "1/6+3i");
+    /// ```
+    #[inline]
+    fn sub(self, other: &'a ComplexRational) -> ComplexRational {
+        sub_helper(self, other)
+    }
+}
+
+impl Mul<ComplexRational> for ComplexRational {
+    type Output = ComplexRational;
+
+    /// Multiplies two [`ComplexRational`]s, taking both by value.
+    ///
+    /// $f(a + bi, c + di) = (ac - bd) + (ad + bc)i$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the largest number of significant
+    /// bits of any of the numerators and denominators.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_q::complex_rational::ComplexRational;
+    /// use malachite_q::Rational;
+    ///
+    /// let x = ComplexRational::new(Rational::from_signeds(1, 2), Rational::from(1));
+    /// let y = ComplexRational::new(Rational::from_signeds(1, 3), Rational::from(-2));
+    /// assert_eq!((x * y).to_string(), "13/6-2/3i");
+    /// ```
+    #[inline]
+    fn mul(self, other: ComplexRational) -> ComplexRational {
+        mul_helper(&self, &other)
+    }
+}
+
+impl<'a> Mul<&'a ComplexRational> for ComplexRational {
+    type Output = ComplexRational;
+
+    /// Multiplies two [`ComplexRational`]s, taking the first by value and the second by reference.
+    ///
+    /// $f(a + bi, c + di) = (ac - bd) + (ad + bc)i$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the largest number of significant
+    /// bits of any of the numerators and denominators.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_q::complex_rational::ComplexRational;
+    /// use malachite_q::Rational;
+    ///
+    /// let x = ComplexRational::new(Rational::from_signeds(1, 2), Rational::from(1));
+    /// let y = ComplexRational::new(Rational::from_signeds(1, 3), Rational::from(-2));
+    /// assert_eq!((x * &y).to_string(), "13/6-2/3i");
+    /// ```
+    #[inline]
+    fn mul(self, other: &'a ComplexRational) -> ComplexRational {
+        mul_helper(&self, other)
+    }
+}
+
+impl<'a> Mul<ComplexRational> for &'a ComplexRational {
+    type Output = ComplexRational;
+
+    /// Multiplies two [`ComplexRational`]s, taking the first by reference and the second by value.
+    ///
+    /// $f(a + bi, c + di) = (ac - bd) + (ad + bc)i$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the largest number of significant
+    /// bits of any of the numerators and denominators.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_q::complex_rational::ComplexRational;
+    /// use malachite_q::Rational;
+    ///
+    /// let x = ComplexRational::new(Rational::from_signeds(1, 2), Rational::from(1));
+    /// let y = ComplexRational::new(Rational::from_signeds(1, 3), Rational::from(-2));
+    /// assert_eq!((&x * y).to_string(), "13/6-2/3i");
+    /// ```
+    #[inline]
+    fn mul(self, other: ComplexRational) -> ComplexRational {
+        mul_helper(self, &other)
+    }
+}
+
+impl<'a, 'b> Mul<&'a ComplexRational> for &'b ComplexRational {
+    type Output = ComplexRational;
+
+    /// Multiplies two [`ComplexRational`]s, taking both by reference.
+    ///
+    /// $f(a + bi, c + di) = (ac - bd) + (ad + bc)i$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the largest number of significant
+    /// bits of any of the numerators and denominators.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_q::complex_rational::ComplexRational;
+    /// use malachite_q::Rational;
+    ///
+    /// let x = ComplexRational::new(Rational::from_signeds(1, 2), Rational::from(1));
+    /// let y = ComplexRational::new(Rational::from_signeds(1, 3), Rational::from(-2));
+    /// assert_eq!((&x * &y).to_string(), "13/6-2/3i");
+    /// ```
+    #[inline]
+    fn mul(self, other: &'a ComplexRational) -> ComplexRational {
+        mul_helper(self, other)
+    }
+}
+
+impl Neg for ComplexRational {
+    type Output = ComplexRational;
+
+    /// Negates a [`ComplexRational`], taking it by value.
+    ///
+    /// $f(a + bi) = -a - bi$.
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_q::complex_rational::ComplexRational;
+    /// use malachite_q::Rational;
+    ///
+    /// let x = ComplexRational::new(Rational::from(3), Rational::from(-4));
+    /// assert_eq!((-x).to_string(), "-3+4i");
+    /// ```
+    #[inline]
+    fn neg(self) -> ComplexRational {
+        ComplexRational {
+            real: -self.real,
+            imag: -self.imag,
+        }
+    }
+}
+
+impl<'a> Neg for &'a ComplexRational {
+    type Output = ComplexRational;
+
+    /// Negates a [`ComplexRational`], taking it by reference.
+    ///
+    /// $f(a + bi) = -a - bi$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the largest number of significant
+    /// bits of any of the numerators and denominators.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_q::complex_rational::ComplexRational;
+    /// use malachite_q::Rational;
+    ///
+    /// let x = ComplexRational::new(Rational::from(3), Rational::from(-4));
+    /// assert_eq!((-&x).to_string(), "-3+4i");
+    /// ```
+    #[inline]
+    fn neg(self) -> ComplexRational {
+        ComplexRational {
+            real: -&self.real,
+            imag: -&self.imag,
+        }
+    }
+}
+
+impl ComplexRational {
+    /// Returns the complex conjugate of a [`ComplexRational`].
+    ///
+    /// $f(a + bi) = a - bi$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the largest number of significant
+    /// bits of any of the numerators and denominators.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_q::complex_rational::ComplexRational;
+    /// use malachite_q::Rational;
+    ///
+    /// let x = ComplexRational::new(Rational::from(3), Rational::from(-4));
+    /// assert_eq!(x.conjugate().to_string(), "3+4i");
+    /// assert_eq!((&x * x.conjugate()).to_string(), "25");
+    /// ```
+    pub fn conjugate(&self) -> ComplexRational {
+        ComplexRational {
+            real: self.real.clone(),
+            imag: -&self.imag,
+        }
+    }
+
+    /// Returns the norm of a [`ComplexRational`]; that is, the square of its absolute value.
+    ///
+    /// $f(a + bi) = a^2 + b^2$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the largest number of significant
+    /// bits of any of the numerators and denominators.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::Zero;
+    /// use malachite_q::complex_rational::ComplexRational;
+    /// use malachite_q::Rational;
+    ///
+    /// assert_eq!(ComplexRational::ZERO.norm(), 0);
+    /// assert_eq!(ComplexRational::I.norm(), 1);
+    /// let x = ComplexRational::new(Rational::from_signeds(3, 5), Rational::from_signeds(-4, 5));
+    /// assert_eq!(x.norm(), 1);
+    /// assert_eq!(
+    ///     ComplexRational::new(Rational::from_signeds(1, 2), Rational::from(1))
+    ///         .norm()
+    ///         .to_string(),
+    ///     "5/4"
+    /// );
+    /// ```
+    pub fn norm(&self) -> Rational {
+        (&self.real).square() + (&self.imag).square()
+    }
+}
+
+fn pow_helper(x: &ComplexRational, exp: u64) -> ComplexRational {
+    let mut result = ComplexRational::ONE;
+    for i in (0..u64::BITS - exp.leading_zeros()).rev() {
+        result = mul_helper(&result, &result);
+        if exp >> i & 1 != 0 {
+            result = mul_helper(&result, x);
+        }
+    }
+    result
+}
+
+impl Pow<u64> for ComplexRational {
+    type Output = ComplexRational;
+
+    /// Raises a [`ComplexRational`] to a power, taking the [`ComplexRational`] by value.
+    ///
+    /// $f(x, n) = x^n$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(nm \log (nm) \log\log (nm))$
+    ///
+    /// $M(n, m) = O(nm \log (nm))$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is the largest number of significant bits
+    /// of any of the numerators and denominators, and $m$ is `exp`.
+    ///
+    /// # Examples
+    /// See [here](super::arithmetic#pow).
+    #[inline]
+    fn pow(self, exp: u64) -> ComplexRational {
+        pow_helper(&self, exp)
+    }
+}
+
+impl<'a> Pow<u64> for &'a ComplexRational {
+    type Output = ComplexRational;
+
+    /// Raises a [`ComplexRational`] to a power, taking the [`ComplexRational`] by reference.
+    ///
+    /// $f(x, n) = x^n$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(nm \log (nm) \log\log (nm))$
+    ///
+    /// $M(n, m) = O(nm \log (nm))$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is the largest number of significant bits
+    /// of any of the numerators and denominators, and $m$ is `exp`.
+    ///
+    /// # Examples
+    /// See [here](super::arithmetic#pow).
+    #[inline]
+    fn pow(self, exp: u64) -> ComplexRational {
+        pow_helper(self, exp)
+    }
+}
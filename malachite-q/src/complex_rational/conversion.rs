@@ -0,0 +1,110 @@
+use crate::complex_rational::ComplexRational;
+use crate::Rational;
+use malachite_base::num::basic::traits::Zero;
+use malachite_nz::gaussian_integer::GaussianInteger;
+
+impl From<Rational> for ComplexRational {
+    /// Converts a [`Rational`] to a [`ComplexRational`] with imaginary part 0, taking the
+    /// [`Rational`] by value.
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_q::complex_rational::ComplexRational;
+    /// use malachite_q::Rational;
+    ///
+    /// assert_eq!(ComplexRational::from(Rational::from_signeds(-2, 3)).to_string(), "-2/3");
+    /// ```
+    #[inline]
+    fn from(real: Rational) -> ComplexRational {
+        ComplexRational {
+            real,
+            imag: Rational::ZERO,
+        }
+    }
+}
+
+impl<'a> From<&'a Rational> for ComplexRational {
+    /// Converts a [`Rational`] to a [`ComplexRational`] with imaginary part 0, taking the
+    /// [`Rational`] by reference.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `x.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_q::complex_rational::ComplexRational;
+    /// use malachite_q::Rational;
+    ///
+    /// assert_eq!(ComplexRational::from(&Rational::from_signeds(-2, 3)).to_string(), "-2/3");
+    /// ```
+    #[inline]
+    fn from(x: &'a Rational) -> ComplexRational {
+        ComplexRational {
+            real: x.clone(),
+            imag: Rational::ZERO,
+        }
+    }
+}
+
+impl From<GaussianInteger> for ComplexRational {
+    /// Converts a [`GaussianInteger`] to a [`ComplexRational`], taking the [`GaussianInteger`]
+    /// by value.
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::gaussian_integer::GaussianInteger;
+    /// use malachite_nz::integer::Integer;
+    /// use malachite_q::complex_rational::ComplexRational;
+    ///
+    /// let x = GaussianInteger::new(Integer::from(3), Integer::from(-4));
+    /// assert_eq!(ComplexRational::from(x).to_string(), "3-4i");
+    /// ```
+    #[inline]
+    fn from(x: GaussianInteger) -> ComplexRational {
+        let (real, imag) = x.into_parts();
+        ComplexRational {
+            real: Rational::from(real),
+            imag: Rational::from(imag),
+        }
+    }
+}
+
+impl<'a> From<&'a GaussianInteger> for ComplexRational {
+    /// Converts a [`GaussianInteger`] to a [`ComplexRational`], taking the [`GaussianInteger`]
+    /// by reference.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the larger number of significant
+    /// bits of the real and imaginary parts.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::gaussian_integer::GaussianInteger;
+    /// use malachite_nz::integer::Integer;
+    /// use malachite_q::complex_rational::ComplexRational;
+    ///
+    /// let x = GaussianInteger::new(Integer::from(3), Integer::from(-4));
+    /// assert_eq!(ComplexRational::from(&x).to_string(), "3-4i");
+    /// ```
+    #[inline]
+    fn from(x: &'a GaussianInteger) -> ComplexRational {
+        ComplexRational {
+            real: Rational::from(x.real()),
+            imag: Rational::from(x.imag()),
+        }
+    }
+}
@@ -0,0 +1,209 @@
+use crate::complex_rational::arithmetic::mul_helper;
+use crate::complex_rational::ComplexRational;
+use malachite_base::num::arithmetic::traits::Reciprocal;
+use std::ops::Div;
+
+fn reciprocal_helper(x: &ComplexRational) -> ComplexRational {
+    let norm = x.norm();
+    if norm == 0u32 {
+        panic!("division by zero");
+    }
+    ComplexRational {
+        real: &x.real / &norm,
+        imag: -&x.imag / norm,
+    }
+}
+
+fn div_helper(x: &ComplexRational, y: &ComplexRational) -> ComplexRational {
+    mul_helper(x, &reciprocal_helper(y))
+}
+
+impl Div<ComplexRational> for ComplexRational {
+    type Output = ComplexRational;
+
+    /// Divides a [`ComplexRational`] by another [`ComplexRational`], taking both by value.
+    ///
+    /// The quotient is exact.
+    ///
+    /// $$
+    /// f(x, y) = \frac{x}{y} = \frac{x\overline{y}}{N(y)}.
+    /// $$
+    ///
+    /// where $N$ is the [`norm`](ComplexRational::norm).
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the largest number of significant
+    /// bits of any of the numerators and denominators.
+    ///
+    /// # Panics
+    /// Panics if `other` is zero.
+    ///
+    /// # Examples
+    /// See [here](super::div#div).
+    #[inline]
+    fn div(self, other: ComplexRational) -> ComplexRational {
+        div_helper(&self, &other)
+    }
+}
+
+impl<'a> Div<&'a ComplexRational> for ComplexRational {
+    type Output = ComplexRational;
+
+    /// Divides a [`ComplexRational`] by another [`ComplexRational`], taking the first by value and
+    /// the second by reference.
+    ///
+    /// The quotient is exact.
+    ///
+    /// $$
+    /// f(x, y) = \frac{x}{y} = \frac{x\overline{y}}{N(y)}.
+    /// $$
+    ///
+    /// where $N$ is the [`norm`](ComplexRational::norm).
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the largest number of significant
+    /// bits of any of the numerators and denominators.
+    ///
+    /// # Panics
+    /// Panics if `other` is zero.
+    ///
+    /// # Examples
+    /// See [here](super::div#div).
+    #[inline]
+    fn div(self, other: &'a ComplexRational) -> ComplexRational {
+        div_helper(&self, other)
+    }
+}
+
+impl<'a> Div<ComplexRational> for &'a ComplexRational {
+    type Output = ComplexRational;
+
+    /// Divides a [`ComplexRational`] by another [`ComplexRational`], taking the first by reference
+    /// and the second by value.
+    ///
+    /// The quotient is exact.
+    ///
+    /// $$
+    /// f(x, y) = \frac{x}{y} = \frac{x\overline{y}}{N(y)}.
+    /// $$
+    ///
+    /// where $N$ is the [`norm`](ComplexRational::norm).
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the largest number of significant
+    /// bits of any of the numerators and denominators.
+    ///
+    /// # Panics
+    /// Panics if `other` is zero.
+    ///
+    /// # Examples
+    /// See [here](super::div#div).
+    #[inline]
+    fn div(self, other: ComplexRational) -> ComplexRational {
+        div_helper(self, &other)
+    }
+}
+
+impl<'a, 'b> Div<&'a ComplexRational> for &'b ComplexRational {
+    type Output = ComplexRational;
+
+    /// Divides a [`ComplexRational`] by another [`ComplexRational`], taking both by reference.
+    ///
+    /// The quotient is exact.
+    ///
+    /// $$
+    /// f(x, y) = \frac{x}{y} = \frac{x\overline{y}}{N(y)}.
+    /// $$
+    ///
+    /// where $N$ is the [`norm`](ComplexRational::norm).
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the largest number of significant
+    /// bits of any of the numerators and denominators.
+    ///
+    /// # Panics
+    /// Panics if `other` is zero.
+    ///
+    /// # Examples
+    /// See [here](super::div#div).
+    #[inline]
+    fn div(self, other: &'a ComplexRational) -> ComplexRational {
+        div_helper(self, other)
+    }
+}
+
+impl Reciprocal for ComplexRational {
+    type Output = ComplexRational;
+
+    /// Reciprocates a [`ComplexRational`], taking it by value.
+    ///
+    /// $$
+    /// f(x) = \frac{1}{x} = \frac{\overline{x}}{N(x)}.
+    /// $$
+    ///
+    /// where $N$ is the [`norm`](ComplexRational::norm).
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the largest number of significant
+    /// bits of any of the numerators and denominators.
+    ///
+    /// # Panics
+    /// Panics if `self` is zero.
+    ///
+    /// # Examples
+    /// See [here](super::div#reciprocal).
+    #[inline]
+    fn reciprocal(self) -> ComplexRational {
+        reciprocal_helper(&self)
+    }
+}
+
+impl<'a> Reciprocal for &'a ComplexRational {
+    type Output = ComplexRational;
+
+    /// Reciprocates a [`ComplexRational`], taking it by reference.
+    ///
+    /// $$
+    /// f(x) = \frac{1}{x} = \frac{\overline{x}}{N(x)}.
+    /// $$
+    ///
+    /// where $N$ is the [`norm`](ComplexRational::norm).
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the largest number of significant
+    /// bits of any of the numerators and denominators.
+    ///
+    /// # Panics
+    /// Panics if `self` is zero.
+    ///
+    /// # Examples
+    /// See [here](super::div#reciprocal).
+    #[inline]
+    fn reciprocal(self) -> ComplexRational {
+        reciprocal_helper(self)
+    }
+}
@@ -0,0 +1,239 @@
+use crate::Rational;
+use malachite_base::num::basic::traits::{One, Zero};
+use std::fmt::{Debug, Display, Formatter, Result, Write};
+
+/// A complex number $a + bi$ whose real and imaginary parts $a$ and $b$ are [`Rational`]s.
+///
+/// All arithmetic, including division, is exact. This makes [`ComplexRational`] suitable for
+/// checking identities symbolically; for example, identities between roots of unity whose real
+/// and imaginary parts are rational.
+#[derive(Clone, Hash, Eq, PartialEq)]
+pub struct ComplexRational {
+    pub(crate) real: Rational,
+    pub(crate) imag: Rational,
+}
+
+impl ComplexRational {
+    /// The imaginary unit, $i$.
+    pub const I: ComplexRational = ComplexRational {
+        real: Rational::ZERO,
+        imag: Rational::ONE,
+    };
+
+    /// Creates a [`ComplexRational`] from its real and imaginary parts.
+    ///
+    /// $f(a, b) = a + bi$.
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_q::complex_rational::ComplexRational;
+    /// use malachite_q::Rational;
+    ///
+    /// assert_eq!(
+    ///     ComplexRational::new(Rational::from_signeds(1, 2), Rational::from(-3)).to_string(),
+    ///     "1/2-3i"
+    /// );
+    /// ```
+    pub const fn new(real: Rational, imag: Rational) -> ComplexRational {
+        ComplexRational { real, imag }
+    }
+
+    /// Returns a reference to the real part of a [`ComplexRational`].
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_q::complex_rational::ComplexRational;
+    /// use malachite_q::Rational;
+    ///
+    /// let x = ComplexRational::new(Rational::from_signeds(1, 2), Rational::from(-3));
+    /// assert_eq!(x.real().to_string(), "1/2");
+    /// ```
+    pub const fn real(&self) -> &Rational {
+        &self.real
+    }
+
+    /// Returns a reference to the imaginary part of a [`ComplexRational`].
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_q::complex_rational::ComplexRational;
+    /// use malachite_q::Rational;
+    ///
+    /// let x = ComplexRational::new(Rational::from_signeds(1, 2), Rational::from(-3));
+    /// assert_eq!(x.imag().to_string(), "-3");
+    /// ```
+    pub const fn imag(&self) -> &Rational {
+        &self.imag
+    }
+
+    /// Consumes a [`ComplexRational`] and returns its real and imaginary parts.
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_q::complex_rational::ComplexRational;
+    /// use malachite_q::Rational;
+    ///
+    /// let x = ComplexRational::new(Rational::from_signeds(1, 2), Rational::from(-3));
+    /// let (real, imag) = x.into_parts();
+    /// assert_eq!(real.to_string(), "1/2");
+    /// assert_eq!(imag.to_string(), "-3");
+    /// ```
+    #[allow(clippy::missing_const_for_fn)]
+    pub fn into_parts(self) -> (Rational, Rational) {
+        (self.real, self.imag)
+    }
+}
+
+/// The constant 0.
+impl Zero for ComplexRational {
+    const ZERO: ComplexRational = ComplexRational {
+        real: Rational::ZERO,
+        imag: Rational::ZERO,
+    };
+}
+
+/// The constant 1.
+impl One for ComplexRational {
+    const ONE: ComplexRational = ComplexRational {
+        real: Rational::ONE,
+        imag: Rational::ZERO,
+    };
+}
+
+impl Display for ComplexRational {
+    /// Converts a [`ComplexRational`] to a [`String`].
+    ///
+    /// The number is written as `a+bi` or `a-bi`, where the whole imaginary part $b$ comes before
+    /// the `i`; for example, `1/2+3/4i` represents $1/2 + (3/4)i$. A zero real or imaginary part
+    /// is omitted (unless the number is 0), and an imaginary part of 1 or $-1$ is written as `i`
+    /// or `-i`.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the largest number of significant
+    /// bits of any of the numerators and denominators.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::Zero;
+    /// use malachite_q::complex_rational::ComplexRational;
+    /// use malachite_q::Rational;
+    ///
+    /// assert_eq!(ComplexRational::ZERO.to_string(), "0");
+    /// assert_eq!(ComplexRational::I.to_string(), "i");
+    /// assert_eq!((-ComplexRational::I).to_string(), "-i");
+    /// assert_eq!(
+    ///     ComplexRational::new(Rational::from_signeds(1, 2), Rational::from_signeds(3, 4))
+    ///         .to_string(),
+    ///     "1/2+3/4i"
+    /// );
+    /// assert_eq!(
+    ///     ComplexRational::new(Rational::from(0), Rational::from_signeds(-2, 3)).to_string(),
+    ///     "-2/3i"
+    /// );
+    /// ```
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        if self.imag == 0u32 {
+            return Display::fmt(&self.real, f);
+        }
+        if self.real != 0u32 {
+            Display::fmt(&self.real, f)?;
+            if self.imag > 0u32 {
+                f.write_char('+')?;
+            }
+        }
+        if self.imag == -1i32 {
+            f.write_char('-')?;
+        } else if self.imag != 1u32 {
+            Display::fmt(&self.imag, f)?;
+        }
+        f.write_char('i')
+    }
+}
+
+impl Debug for ComplexRational {
+    /// Converts a [`ComplexRational`] to a [`String`].
+    ///
+    /// This is the same as the [`Display::fmt`] implementation.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the largest number of significant
+    /// bits of any of the numerators and denominators.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::strings::ToDebugString;
+    /// use malachite_q::complex_rational::ComplexRational;
+    /// use malachite_q::Rational;
+    ///
+    /// let x = ComplexRational::new(Rational::from(3), Rational::from_signeds(-1, 5));
+    /// assert_eq!(x.to_debug_string(), "3-1/5i");
+    /// ```
+    #[inline]
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        Display::fmt(self, f)
+    }
+}
+
+/// Addition, subtraction, multiplication, negation, conjugation, norms, and powers of
+/// [`ComplexRational`]s.
+///
+/// # pow
+/// ```
+/// use malachite_base::num::arithmetic::traits::Pow;
+/// use malachite_base::num::basic::traits::One;
+/// use malachite_q::complex_rational::ComplexRational;
+/// use malachite_q::Rational;
+///
+/// let x = ComplexRational::new(Rational::from(1), Rational::from(1));
+/// assert_eq!((&x).pow(2).to_string(), "2i");
+/// assert_eq!(x.pow(8).to_string(), "16");
+/// assert_eq!(ComplexRational::I.pow(4), ComplexRational::ONE);
+/// ```
+pub mod arithmetic;
+/// Conversions to [`ComplexRational`]s from [`Rational`]s and
+/// [`GaussianInteger`](malachite_nz::gaussian_integer::GaussianInteger)s.
+pub mod conversion;
+/// Exact division and reciprocals of [`ComplexRational`]s.
+///
+/// # div
+/// ```
+/// use malachite_q::complex_rational::ComplexRational;
+/// use malachite_q::Rational;
+///
+/// let x = ComplexRational::new(Rational::from(1), Rational::from(2));
+/// let y = ComplexRational::new(Rational::from(3), Rational::from(-4));
+/// assert_eq!((&x / &y).to_string(), "-1/5+2/5i");
+/// assert_eq!((x / y).to_string(), "-1/5+2/5i");
+/// ```
+///
+/// # reciprocal
+/// ```
+/// use malachite_base::num::arithmetic::traits::Reciprocal;
+/// use malachite_q::complex_rational::ComplexRational;
+/// use malachite_q::Rational;
+///
+/// let x = ComplexRational::new(Rational::from(3), Rational::from(-4));
+/// assert_eq!((&x).reciprocal().to_string(), "3/25+4/25i");
+/// assert_eq!(x.reciprocal().to_string(), "3/25+4/25i");
+/// ```
+pub mod div;
@@ -0,0 +1,543 @@
+use crate::decimal::{power_of_10, Decimal};
+use malachite_nz::integer::Integer;
+use std::cmp::max;
+use std::ops::{Add, Mul, Neg, Sub};
+
+// Returns the mantissa of `x` when it is expressed with the scale `scale`, which must be at least
+// `x.scale`.
+pub(crate) fn mantissa_at_scale(x: &Decimal, scale: i64) -> Integer {
+    let shift = scale.abs_diff(x.scale);
+    if shift == 0 {
+        x.mantissa.clone()
+    } else {
+        &x.mantissa * Integer::from(power_of_10(shift))
+    }
+}
+
+fn add_helper(x: &Decimal, y: &Decimal) -> Decimal {
+    let scale = max(x.scale, y.scale);
+    Decimal {
+        mantissa: mantissa_at_scale(x, scale) + mantissa_at_scale(y, scale),
+        scale,
+    }
+}
+
+fn sub_helper(x: &Decimal, y: &Decimal) -> Decimal {
+    let scale = max(x.scale, y.scale);
+    Decimal {
+        mantissa: mantissa_at_scale(x, scale) - mantissa_at_scale(y, scale),
+        scale,
+    }
+}
+
+fn mul_helper(x: &Decimal, y: &Decimal) -> Decimal {
+    Decimal {
+        mantissa: &x.mantissa * &y.mantissa,
+        scale: x.scale.checked_add(y.scale).expect("Scale overflow"),
+    }
+}
+
+impl Add<Decimal> for Decimal {
+    type Output = Decimal;
+
+    /// Adds two [`Decimal`]s, taking both by value. The result is exact, and its scale is the
+    /// larger of the two scales.
+    ///
+    /// $$
+    /// f(x, y) = x + y.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the number of significant bits of
+    /// the larger mantissa, after both mantissas have been converted to a common scale.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_q::decimal::Decimal;
+    ///
+    /// let x: Decimal = "1.25".parse().unwrap();
+    /// let y: Decimal = "0.5".parse().unwrap();
+    /// assert_eq!((x + y).to_string(), "1.75");
+    ///
+    /// let x: Decimal = "1".parse().unwrap();
+    /// let y: Decimal = "0.10".parse().unwrap();
+    /// assert_eq!((x + y).to_string(), "1.10");
+    /// ```
+    #[inline]
+    fn add(self, other: Decimal) -> Decimal {
+        add_helper(&self, &other)
+    }
+}
+
+impl<'a> Add<&'a Decimal> for Decimal {
+    type Output = Decimal;
+
+    /// Adds two [`Decimal`]s, taking the first by value and the second by reference. The result is
+    /// exact, and its scale is the larger of the two scales.
+    ///
+    /// $$
+    /// f(x, y) = x + y.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the number of significant bits of
+    /// the larger mantissa, after both mantissas have been converted to a common scale.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_q::decimal::Decimal;
+    ///
+    /// let x: Decimal = "1.25".parse().unwrap();
+    /// let y: Decimal = "0.5".parse().unwrap();
+    /// assert_eq!((x + &y).to_string(), "1.75");
+    ///
+    /// let x: Decimal = "1".parse().unwrap();
+    /// let y: Decimal = "0.10".parse().unwrap();
+    /// assert_eq!((x + &y).to_string(), "1.10");
+    /// ```
+    #[inline]
+    fn add(self, other: &'a Decimal) -> Decimal {
+        add_helper(&self, other)
+    }
+}
+
+impl<'a> Add<Decimal> for &'a Decimal {
+    type Output = Decimal;
+
+    /// Adds two [`Decimal`]s, taking the first by reference and the second by value. The result is
+    /// exact, and its scale is the larger of the two scales.
+    ///
+    /// $$
+    /// f(x, y) = x + y.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the number of significant bits of
+    /// the larger mantissa, after both mantissas have been converted to a common scale.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_q::decimal::Decimal;
+    ///
+    /// let x: Decimal = "1.25".parse().unwrap();
+    /// let y: Decimal = "0.5".parse().unwrap();
+    /// assert_eq!((&x + y).to_string(), "1.75");
+    ///
+    /// let x: Decimal = "1".parse().unwrap();
+    /// let y: Decimal = "0.10".parse().unwrap();
+    /// assert_eq!((&x + y).to_string(), "1.10");
+    /// ```
+    #[inline]
+    fn add(self, other: Decimal) -> Decimal {
+        add_helper(self, &other)
+    }
+}
+
+impl<'a, 'b> Add<&'a Decimal> for &'b Decimal {
+    type Output = Decimal;
+
+    /// Adds two [`Decimal`]s, taking both by reference. The result is exact, and its scale is the
+    /// larger of the two scales.
+    ///
+    /// $$
+    /// f(x, y) = x + y.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the number of significant bits of
+    /// the larger mantissa, after both mantissas have been converted to a common scale.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_q::decimal::Decimal;
+    ///
+    /// let x: Decimal = "1.25".parse().unwrap();
+    /// let y: Decimal = "0.5".parse().unwrap();
+    /// assert_eq!((&x + &y).to_string(), "1.75");
+    ///
+    /// let x: Decimal = "1".parse().unwrap();
+    /// let y: Decimal = "0.10".parse().unwrap();
+    /// assert_eq!((&x + &y).to_string(), "1.10");
+    /// ```
+    #[inline]
+    fn add(self, other: &'a Decimal) -> Decimal {
+        add_helper(self, other)
+    }
+}
+
+impl Sub<Decimal> for Decimal {
+    type Output = Decimal;
+
+    /// Subtracts two [`Decimal`]s, taking both by value. The result is exact, and its scale is the
+    /// larger of the two scales.
+    ///
+    /// $$
+    /// f(x, y) = x - y.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the number of significant bits of
+    /// the larger mantissa, after both mantissas have been converted to a common scale.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_q::decimal::Decimal;
+    ///
+    /// let x: Decimal = "1.25".parse().unwrap();
+    /// let y: Decimal = "0.5".parse().unwrap();
+    /// assert_eq!((x - y).to_string(), "0.75");
+    ///
+    /// let x: Decimal = "1".parse().unwrap();
+    /// let y: Decimal = "0.10".parse().unwrap();
+    /// assert_eq!((x - y).to_string(), "0.90");
+    /// ```
+    #[inline]
+    fn sub(self, other: Decimal) -> Decimal {
+        sub_helper(&self, &other)
+    }
+}
+
+impl<'a> Sub<&'a Decimal> for Decimal {
+    type Output = Decimal;
+
+    /// Subtracts two [`Decimal`]s, taking the first by value and the second by reference. The
+    /// result is exact, and its scale is the larger of the two scales.
+    ///
+    /// $$
+    /// f(x, y) = x - y.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the number of significant bits of
+    /// the larger mantissa, after both mantissas have been converted to a common scale.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_q::decimal::Decimal;
+    ///
+    /// let x: Decimal = "1.25".parse().unwrap();
+    /// let y: Decimal = "0.5".parse().unwrap();
+    /// assert_eq!((x - &y).to_string(), "0.75");
+    ///
+    /// let x: Decimal = "1".parse().unwrap();
+    /// let y: Decimal = "0.10".parse().unwrap();
+    /// assert_eq!((x - &y).to_string(), "0.90");
+    /// ```
+    #[inline]
+    fn sub(self, other: &'a Decimal) -> Decimal {
+        sub_helper(&self, other)
+    }
+}
+
+impl<'a> Sub<Decimal> for &'a Decimal {
+    type Output = Decimal;
+
+    /// Subtracts two [`Decimal`]s, taking the first by reference and the second by value. The
+    /// result is exact, and its scale is the larger of the two scales.
+    ///
+    /// $$
+    /// f(x, y) = x - y.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the number of significant bits of
+    /// the larger mantissa, after both mantissas have been converted to a common scale.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_q::decimal::Decimal;
+    ///
+    /// let x: Decimal = "1.25".parse().unwrap();
+    /// let y: Decimal = "0.5".parse().unwrap();
+    /// assert_eq!((&x - y).to_string(), "0.75");
+    ///
+    /// let x: Decimal = "1".parse().unwrap();
+    /// let y: Decimal = "0.10".parse().unwrap();
+    /// assert_eq!((&x - y).to_string(), "0.90");
+    /// ```
+    #[inline]
+    fn sub(self, other: Decimal) -> Decimal {
+        sub_helper(self, &other)
+    }
+}
+
+impl<'a, 'b> Sub<&'a Decimal> for &'b Decimal {
+    type Output = Decimal;
+
+    /// Subtracts two [`Decimal`]s, taking both by reference. The result is exact, and its scale is
+    /// the larger of the two scales.
+    ///
+    /// $$
+    /// f(x, y) = x - y.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the number of significant bits of
+    /// the larger mantissa, after both mantissas have been converted to a common scale.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_q::decimal::Decimal;
+    ///
+    /// let x: Decimal = "1.25".parse().unwrap();
+    /// let y: Decimal = "0.5".parse().unwrap();
+    /// assert_eq!((&x - &y).to_string(), "0.75");
+    ///
+    /// let x: Decimal = "1".parse().unwrap();
+    /// let y: Decimal = "0.10".parse().unwrap();
+    /// assert_eq!((&x - &y).to_string(), "0.90");
+    /// ```
+    #[inline]
+    fn sub(self, other: &'a Decimal) -> Decimal {
+        sub_helper(self, other)
+    }
+}
+
+impl Mul<Decimal> for Decimal {
+    type Output = Decimal;
+
+    /// Multiplies two [`Decimal`]s, taking both by value. The result is exact, and its scale is the
+    /// sum of the two scales.
+    ///
+    /// $$
+    /// f(x, y) = xy.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.mantissa().significant_bits(), other.mantissa().significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if the sum of the scales does not fit in an [`i64`].
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_q::decimal::Decimal;
+    ///
+    /// let x: Decimal = "1.25".parse().unwrap();
+    /// let y: Decimal = "0.5".parse().unwrap();
+    /// assert_eq!((x * y).to_string(), "0.625");
+    ///
+    /// let x: Decimal = "1.50".parse().unwrap();
+    /// let y: Decimal = "2.0".parse().unwrap();
+    /// assert_eq!((x * y).to_string(), "3.000");
+    /// ```
+    #[inline]
+    fn mul(self, other: Decimal) -> Decimal {
+        mul_helper(&self, &other)
+    }
+}
+
+impl<'a> Mul<&'a Decimal> for Decimal {
+    type Output = Decimal;
+
+    /// Multiplies two [`Decimal`]s, taking the first by value and the second by reference. The
+    /// result is exact, and its scale is the sum of the two scales.
+    ///
+    /// $$
+    /// f(x, y) = xy.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.mantissa().significant_bits(), other.mantissa().significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if the sum of the scales does not fit in an [`i64`].
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_q::decimal::Decimal;
+    ///
+    /// let x: Decimal = "1.25".parse().unwrap();
+    /// let y: Decimal = "0.5".parse().unwrap();
+    /// assert_eq!((x * &y).to_string(), "0.625");
+    ///
+    /// let x: Decimal = "1.50".parse().unwrap();
+    /// let y: Decimal = "2.0".parse().unwrap();
+    /// assert_eq!((x * &y).to_string(), "3.000");
+    /// ```
+    #[inline]
+    fn mul(self, other: &'a Decimal) -> Decimal {
+        mul_helper(&self, other)
+    }
+}
+
+impl<'a> Mul<Decimal> for &'a Decimal {
+    type Output = Decimal;
+
+    /// Multiplies two [`Decimal`]s, taking the first by reference and the second by value. The
+    /// result is exact, and its scale is the sum of the two scales.
+    ///
+    /// $$
+    /// f(x, y) = xy.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.mantissa().significant_bits(), other.mantissa().significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if the sum of the scales does not fit in an [`i64`].
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_q::decimal::Decimal;
+    ///
+    /// let x: Decimal = "1.25".parse().unwrap();
+    /// let y: Decimal = "0.5".parse().unwrap();
+    /// assert_eq!((&x * y).to_string(), "0.625");
+    ///
+    /// let x: Decimal = "1.50".parse().unwrap();
+    /// let y: Decimal = "2.0".parse().unwrap();
+    /// assert_eq!((&x * y).to_string(), "3.000");
+    /// ```
+    #[inline]
+    fn mul(self, other: Decimal) -> Decimal {
+        mul_helper(self, &other)
+    }
+}
+
+impl<'a, 'b> Mul<&'a Decimal> for &'b Decimal {
+    type Output = Decimal;
+
+    /// Multiplies two [`Decimal`]s, taking both by reference. The result is exact, and its scale is
+    /// the sum of the two scales.
+    ///
+    /// $$
+    /// f(x, y) = xy.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.mantissa().significant_bits(), other.mantissa().significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if the sum of the scales does not fit in an [`i64`].
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_q::decimal::Decimal;
+    ///
+    /// let x: Decimal = "1.25".parse().unwrap();
+    /// let y: Decimal = "0.5".parse().unwrap();
+    /// assert_eq!((&x * &y).to_string(), "0.625");
+    ///
+    /// let x: Decimal = "1.50".parse().unwrap();
+    /// let y: Decimal = "2.0".parse().unwrap();
+    /// assert_eq!((&x * &y).to_string(), "3.000");
+    /// ```
+    #[inline]
+    fn mul(self, other: &'a Decimal) -> Decimal {
+        mul_helper(self, other)
+    }
+}
+
+impl Neg for Decimal {
+    type Output = Decimal;
+
+    /// Negates a [`Decimal`], taking it by value. The scale is unchanged.
+    ///
+    /// $$
+    /// f(x) = -x.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_q::decimal::Decimal;
+    ///
+    /// let x: Decimal = "1.50".parse().unwrap();
+    /// assert_eq!((-x).to_string(), "-1.50");
+    /// ```
+    #[inline]
+    fn neg(self) -> Decimal {
+        Decimal {
+            mantissa: -self.mantissa,
+            scale: self.scale,
+        }
+    }
+}
+
+impl<'a> Neg for &'a Decimal {
+    type Output = Decimal;
+
+    /// Negates a [`Decimal`], taking it by reference. The scale is unchanged.
+    ///
+    /// $$
+    /// f(x) = -x.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `self.mantissa().significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_q::decimal::Decimal;
+    ///
+    /// let x: Decimal = "1.50".parse().unwrap();
+    /// assert_eq!((-&x).to_string(), "-1.50");
+    /// ```
+    #[inline]
+    fn neg(self) -> Decimal {
+        Decimal {
+            mantissa: -&self.mantissa,
+            scale: self.scale,
+        }
+    }
+}
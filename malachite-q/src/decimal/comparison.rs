@@ -0,0 +1,106 @@
+use crate::decimal::arithmetic::mantissa_at_scale;
+use crate::decimal::Decimal;
+use malachite_base::num::arithmetic::traits::{DivExactAssign, DivisibleBy, Sign};
+use malachite_nz::integer::Integer;
+use std::cmp::{max, Ordering};
+use std::hash::{Hash, Hasher};
+
+// Returns the mantissa and scale of the representation of `x` with the smallest possible scale.
+// Zero is represented with scale 0.
+fn normalize(x: &Decimal) -> (Integer, i64) {
+    if x.mantissa == 0u32 {
+        return (Integer::from(0), 0);
+    }
+    let ten = Integer::from(10);
+    let mut mantissa = x.mantissa.clone();
+    let mut scale = x.scale;
+    while (&mantissa).divisible_by(&ten) {
+        mantissa.div_exact_assign(&ten);
+        scale -= 1;
+    }
+    (mantissa, scale)
+}
+
+impl PartialEq for Decimal {
+    /// Determines whether two [`Decimal`]s are equal. Two [`Decimal`]s are equal if they
+    /// represent the same number, even if their scales are different.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the number of significant bits of
+    /// the larger mantissa, after both mantissas have been converted to a common scale.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_q::decimal::Decimal;
+    ///
+    /// assert!("1.5".parse::<Decimal>().unwrap() == "1.50".parse::<Decimal>().unwrap());
+    /// assert!("1.5".parse::<Decimal>().unwrap() != "1.05".parse::<Decimal>().unwrap());
+    /// ```
+    fn eq(&self, other: &Decimal) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for Decimal {}
+
+impl PartialOrd for Decimal {
+    /// Compares two [`Decimal`]s.
+    ///
+    /// See the documentation for the [`Ord`] implementation.
+    #[inline]
+    fn partial_cmp(&self, other: &Decimal) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Decimal {
+    /// Compares two [`Decimal`]s by value, regardless of their scales.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the number of significant bits of
+    /// the larger mantissa, after both mantissas have been converted to a common scale.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_q::decimal::Decimal;
+    ///
+    /// assert!("1.5".parse::<Decimal>().unwrap() > "1.05".parse::<Decimal>().unwrap());
+    /// assert!("-1.5".parse::<Decimal>().unwrap() < "-1.05".parse::<Decimal>().unwrap());
+    /// assert!("1e3".parse::<Decimal>().unwrap() >= "1000.000".parse::<Decimal>().unwrap());
+    /// ```
+    fn cmp(&self, other: &Decimal) -> Ordering {
+        if self.scale == other.scale {
+            return self.mantissa.cmp(&other.mantissa);
+        }
+        let sign_cmp = self.mantissa.sign().cmp(&other.mantissa.sign());
+        if sign_cmp != Ordering::Equal || self.mantissa == 0u32 {
+            return sign_cmp;
+        }
+        let scale = max(self.scale, other.scale);
+        mantissa_at_scale(self, scale).cmp(&mantissa_at_scale(other, scale))
+    }
+}
+
+impl Hash for Decimal {
+    /// Computes a hash of a [`Decimal`]. Equal [`Decimal`]s have equal hashes, even if their
+    /// scales are different.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `self.mantissa().significant_bits()`.
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        normalize(self).hash(state);
+    }
+}
@@ -0,0 +1,259 @@
+use crate::decimal::{power_of_10, Decimal};
+use crate::Rational;
+use malachite_base::num::arithmetic::traits::{CheckedLogBase, DivExact};
+use malachite_base::num::conversion::traits::ConvertibleFrom;
+use malachite_nz::integer::Integer;
+use malachite_nz::natural::Natural;
+use std::cmp::max;
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct DecimalFromRationalError;
+
+// Returns the smallest $k$ such that $10^k$ is divisible by `d`, or `None` if no such $k$ exists;
+// that is, if `d` has a prime factor other than 2 or 5.
+fn decimal_scale(d: &Natural) -> Option<u64> {
+    let twos = d.trailing_zeros().unwrap();
+    let odd = d >> twos;
+    let fives = if odd == 1u32 {
+        0
+    } else {
+        (&odd).checked_log_base(&Natural::from(5u32))?
+    };
+    Some(max(twos, fives))
+}
+
+fn decimal_from_rational(x: &Rational) -> Result<Decimal, DecimalFromRationalError> {
+    let scale = decimal_scale(&x.denominator).ok_or(DecimalFromRationalError)?;
+    let multiplier = power_of_10(scale).div_exact(&x.denominator);
+    Ok(Decimal {
+        mantissa: Integer::from_sign_and_abs(x.sign, &x.numerator * multiplier),
+        scale: i64::try_from(scale).unwrap(),
+    })
+}
+
+impl From<Integer> for Decimal {
+    /// Converts an [`Integer`] to a [`Decimal`] with scale 0, taking the [`Integer`] by value.
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::integer::Integer;
+    /// use malachite_q::decimal::Decimal;
+    ///
+    /// assert_eq!(Decimal::from(Integer::from(-123)).to_string(), "-123");
+    /// ```
+    #[inline]
+    fn from(x: Integer) -> Decimal {
+        Decimal {
+            mantissa: x,
+            scale: 0,
+        }
+    }
+}
+
+impl<'a> From<&'a Integer> for Decimal {
+    /// Converts an [`Integer`] to a [`Decimal`] with scale 0, taking the [`Integer`] by reference.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `x.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::integer::Integer;
+    /// use malachite_q::decimal::Decimal;
+    ///
+    /// assert_eq!(Decimal::from(&Integer::from(-123)).to_string(), "-123");
+    /// ```
+    #[inline]
+    fn from(x: &'a Integer) -> Decimal {
+        Decimal {
+            mantissa: x.clone(),
+            scale: 0,
+        }
+    }
+}
+
+impl<'a> From<&'a Decimal> for Rational {
+    /// Converts a [`Decimal`] to a [`Rational`], taking the [`Decimal`] by reference.
+    ///
+    /// $$
+    /// f(m \times 10^{-s}) = m \times 10^{-s}.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(x.mantissa().significant_bits(), x.scale().unsigned_abs())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_q::decimal::Decimal;
+    /// use malachite_q::Rational;
+    ///
+    /// let x: Decimal = "-1.250".parse().unwrap();
+    /// assert_eq!(Rational::from(&x).to_string(), "-5/4");
+    /// let x: Decimal = "1.2e3".parse().unwrap();
+    /// assert_eq!(Rational::from(&x).to_string(), "1200");
+    /// ```
+    fn from(x: &'a Decimal) -> Rational {
+        let power = power_of_10(x.scale.unsigned_abs());
+        if x.scale >= 0 {
+            Rational::from_integers_ref(&x.mantissa, &Integer::from(power))
+        } else {
+            Rational::from(&x.mantissa * Integer::from(power))
+        }
+    }
+}
+
+impl From<Decimal> for Rational {
+    /// Converts a [`Decimal`] to a [`Rational`], taking the [`Decimal`] by value.
+    ///
+    /// $$
+    /// f(m \times 10^{-s}) = m \times 10^{-s}.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(x.mantissa().significant_bits(), x.scale().unsigned_abs())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_q::decimal::Decimal;
+    /// use malachite_q::Rational;
+    ///
+    /// let x: Decimal = "-1.250".parse().unwrap();
+    /// assert_eq!(Rational::from(x).to_string(), "-5/4");
+    /// let x: Decimal = "1.2e3".parse().unwrap();
+    /// assert_eq!(Rational::from(x).to_string(), "1200");
+    /// ```
+    fn from(x: Decimal) -> Rational {
+        let power = power_of_10(x.scale.unsigned_abs());
+        if x.scale >= 0 {
+            Rational::from_integers(x.mantissa, Integer::from(power))
+        } else {
+            Rational::from(x.mantissa * Integer::from(power))
+        }
+    }
+}
+
+impl TryFrom<Rational> for Decimal {
+    type Error = DecimalFromRationalError;
+
+    /// Converts a [`Rational`] to a [`Decimal`], taking the [`Rational`] by value. If the
+    /// [`Rational`]'s decimal expansion does not terminate, an error is returned.
+    ///
+    /// A [`Rational`] has a terminating decimal expansion if and only if its denominator has no
+    /// prime factors other than 2 and 5; equivalently, if the fractional digits returned by
+    /// [`Rational::to_digits`] with base 10 form a finite
+    /// [`RationalSequence`](malachite_base::rational_sequences::RationalSequence). The result
+    /// has the smallest nonnegative scale that can represent the [`Rational`] exactly.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `x.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_q::decimal::conversion::DecimalFromRationalError;
+    /// use malachite_q::decimal::Decimal;
+    /// use malachite_q::Rational;
+    ///
+    /// assert_eq!(
+    ///     Decimal::try_from(Rational::from_signeds(-5, 4)).unwrap().to_string(),
+    ///     "-1.25"
+    /// );
+    /// assert_eq!(Decimal::try_from(Rational::from(1200)).unwrap().to_string(), "1200");
+    /// assert_eq!(
+    ///     Decimal::try_from(Rational::from_signeds(1, 3)),
+    ///     Err(DecimalFromRationalError)
+    /// );
+    /// ```
+    #[inline]
+    fn try_from(x: Rational) -> Result<Decimal, Self::Error> {
+        decimal_from_rational(&x)
+    }
+}
+
+impl<'a> TryFrom<&'a Rational> for Decimal {
+    type Error = DecimalFromRationalError;
+
+    /// Converts a [`Rational`] to a [`Decimal`], taking the [`Rational`] by reference. If the
+    /// [`Rational`]'s decimal expansion does not terminate, an error is returned.
+    ///
+    /// A [`Rational`] has a terminating decimal expansion if and only if its denominator has no
+    /// prime factors other than 2 and 5; equivalently, if the fractional digits returned by
+    /// [`Rational::to_digits`] with base 10 form a finite
+    /// [`RationalSequence`](malachite_base::rational_sequences::RationalSequence). The result
+    /// has the smallest nonnegative scale that can represent the [`Rational`] exactly.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `x.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_q::decimal::conversion::DecimalFromRationalError;
+    /// use malachite_q::decimal::Decimal;
+    /// use malachite_q::Rational;
+    ///
+    /// assert_eq!(
+    ///     Decimal::try_from(&Rational::from_signeds(-5, 4)).unwrap().to_string(),
+    ///     "-1.25"
+    /// );
+    /// assert_eq!(Decimal::try_from(&Rational::from(1200)).unwrap().to_string(), "1200");
+    /// assert_eq!(
+    ///     Decimal::try_from(&Rational::from_signeds(1, 3)),
+    ///     Err(DecimalFromRationalError)
+    /// );
+    /// ```
+    #[inline]
+    fn try_from(x: &'a Rational) -> Result<Decimal, Self::Error> {
+        decimal_from_rational(x)
+    }
+}
+
+impl<'a> ConvertibleFrom<&'a Rational> for Decimal {
+    /// Determines whether a [`Rational`] can be converted to a [`Decimal`]; that is, whether its
+    /// decimal expansion terminates.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `x.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::conversion::traits::ConvertibleFrom;
+    /// use malachite_q::decimal::Decimal;
+    /// use malachite_q::Rational;
+    ///
+    /// assert_eq!(Decimal::convertible_from(&Rational::from_signeds(-5, 4)), true);
+    /// assert_eq!(Decimal::convertible_from(&Rational::from_signeds(1, 3)), false);
+    /// assert_eq!(Decimal::convertible_from(&Rational::from_signeds(7, 80)), true);
+    /// ```
+    #[inline]
+    fn convertible_from(x: &'a Rational) -> bool {
+        decimal_scale(&x.denominator).is_some()
+    }
+}
@@ -0,0 +1,130 @@
+use malachite_base::num::arithmetic::traits::Pow;
+use malachite_base::num::basic::traits::{One, Zero};
+use malachite_nz::integer::Integer;
+use malachite_nz::natural::Natural;
+
+/// A decimal number with a fixed scale; that is, a number of the form $m \times 10^{-s}$, where
+/// the mantissa $m$ is an [`Integer`] and the scale $s$ is an [`i64`].
+///
+/// For nonnegative $s$, the scale is the number of digits after the decimal point, so that the
+/// mantissa 12345 with scale 2 represents 123.45. Addition, subtraction, and multiplication are
+/// exact, and the scale of their result is determined by the scales of the inputs, as in SQL's
+/// `DECIMAL` type. Division and changes of scale require an explicit
+/// [`RoundingMode`](malachite_base::rounding_modes::RoundingMode).
+///
+/// Two [`Decimal`]s with different scales may represent the same number; for example, 1.5 and
+/// 1.50. Such [`Decimal`]s are considered equal, and they have the same hash, but they are
+/// displayed differently.
+#[derive(Clone)]
+pub struct Decimal {
+    pub(crate) mantissa: Integer,
+    pub(crate) scale: i64,
+}
+
+// Returns $10^k$.
+pub(crate) fn power_of_10(k: u64) -> Natural {
+    Natural::from(10u32).pow(k)
+}
+
+impl Decimal {
+    /// Creates a [`Decimal`] from a mantissa and a scale.
+    ///
+    /// $$
+    /// f(m, s) = m \times 10^{-s}.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::integer::Integer;
+    /// use malachite_q::decimal::Decimal;
+    ///
+    /// assert_eq!(Decimal::from_mantissa_and_scale(Integer::from(12345), 2).to_string(), "123.45");
+    /// assert_eq!(Decimal::from_mantissa_and_scale(Integer::from(-5), 3).to_string(), "-0.005");
+    /// assert_eq!(Decimal::from_mantissa_and_scale(Integer::from(12), -3).to_string(), "12000");
+    /// ```
+    pub const fn from_mantissa_and_scale(mantissa: Integer, scale: i64) -> Decimal {
+        Decimal { mantissa, scale }
+    }
+
+    /// Returns a reference to the mantissa of a [`Decimal`].
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_q::decimal::Decimal;
+    ///
+    /// let x: Decimal = "123.450".parse().unwrap();
+    /// assert_eq!(*x.mantissa(), 123450);
+    /// ```
+    pub const fn mantissa(&self) -> &Integer {
+        &self.mantissa
+    }
+
+    /// Returns the scale of a [`Decimal`].
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_q::decimal::Decimal;
+    ///
+    /// assert_eq!("123.450".parse::<Decimal>().unwrap().scale(), 3);
+    /// assert_eq!("123".parse::<Decimal>().unwrap().scale(), 0);
+    /// assert_eq!("1.2e5".parse::<Decimal>().unwrap().scale(), -4);
+    /// ```
+    pub const fn scale(&self) -> i64 {
+        self.scale
+    }
+
+    /// Consumes a [`Decimal`] and returns its mantissa and scale.
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_q::decimal::Decimal;
+    ///
+    /// let (mantissa, scale) = "-0.05".parse::<Decimal>().unwrap().into_mantissa_and_scale();
+    /// assert_eq!(mantissa, -5);
+    /// assert_eq!(scale, 2);
+    /// ```
+    #[allow(clippy::missing_const_for_fn)]
+    pub fn into_mantissa_and_scale(self) -> (Integer, i64) {
+        (self.mantissa, self.scale)
+    }
+}
+
+/// The constant 0, with scale 0.
+impl Zero for Decimal {
+    const ZERO: Decimal = Decimal {
+        mantissa: Integer::ZERO,
+        scale: 0,
+    };
+}
+
+/// The constant 1, with scale 0.
+impl One for Decimal {
+    const ONE: Decimal = Decimal {
+        mantissa: Integer::ONE,
+        scale: 0,
+    };
+}
+
+/// Addition, subtraction, multiplication, and negation of [`Decimal`]s.
+pub mod arithmetic;
+/// Equality, ordering, and hashing of [`Decimal`]s. [`Decimal`]s are compared by value, regardless
+/// of their scales.
+pub mod comparison;
+/// Conversions between [`Decimal`]s and [`Integer`]s or [`Rational`](crate::Rational)s.
+pub mod conversion;
+/// Division and rescaling of [`Decimal`]s, with a specified rounding mode.
+pub mod round;
+/// Conversions between [`Decimal`]s and strings.
+pub mod string;
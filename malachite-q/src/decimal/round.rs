@@ -0,0 +1,115 @@
+use crate::decimal::{power_of_10, Decimal};
+use malachite_base::num::arithmetic::traits::DivRound;
+use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_base::rounding_modes::RoundingMode;
+use malachite_nz::integer::Integer;
+
+impl Decimal {
+    /// Converts a [`Decimal`] to a given scale, rounding according to a specified rounding mode
+    /// if the scale is decreased.
+    ///
+    /// Increasing the scale is always exact. Decreasing the scale removes digits from the end of
+    /// the mantissa; the removed digits are used to round, as if by
+    /// [`DivRound`](malachite_base::num::arithmetic::traits::DivRound).
+    ///
+    /// Let $q = x \times 10^s$, where $s$ is `scale`. Then the mantissa of the result is
+    ///
+    /// $f(x, s, \mathrm{Down}) = \operatorname{sgn}(q) \lfloor |q| \rfloor.$
+    ///
+    /// $f(x, s, \mathrm{Up}) = \operatorname{sgn}(q) \lceil |q| \rceil.$
+    ///
+    /// $f(x, s, \mathrm{Floor}) = \lfloor q \rfloor.$
+    ///
+    /// $f(x, s, \mathrm{Ceiling}) = \lceil q \rceil.$
+    ///
+    /// $f(x, s, \mathrm{Nearest})$ is the integer nearest to $q$, choosing the even one in case of
+    /// a tie.
+    ///
+    /// $f(x, s, \mathrm{Exact}) = q$, but panics if $q$ is not an integer.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.mantissa().significant_bits(), scale.abs_diff(self.scale()))`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but `self` cannot be represented exactly with the given scale.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_q::decimal::Decimal;
+    ///
+    /// let x: Decimal = "-2.675".parse().unwrap();
+    /// assert_eq!(x.round_to_scale(2, RoundingMode::Down).to_string(), "-2.67");
+    /// assert_eq!(x.round_to_scale(2, RoundingMode::Floor).to_string(), "-2.68");
+    /// assert_eq!(x.round_to_scale(2, RoundingMode::Nearest).to_string(), "-2.68");
+    /// assert_eq!(x.round_to_scale(0, RoundingMode::Ceiling).to_string(), "-2");
+    /// assert_eq!(x.round_to_scale(-1, RoundingMode::Up).to_string(), "-10");
+    /// assert_eq!(x.round_to_scale(5, RoundingMode::Exact).to_string(), "-2.67500");
+    /// ```
+    pub fn round_to_scale(&self, scale: i64, rm: RoundingMode) -> Decimal {
+        let shift = scale.abs_diff(self.scale);
+        let power = Integer::from(power_of_10(shift));
+        Decimal {
+            mantissa: if scale >= self.scale {
+                &self.mantissa * power
+            } else {
+                (&self.mantissa).div_round(power, rm)
+            },
+            scale,
+        }
+    }
+
+    /// Divides a [`Decimal`] by another [`Decimal`], returning a result with a given scale and
+    /// rounding according to a specified rounding mode.
+    ///
+    /// Let $q = (x / y) \times 10^s$, where $s$ is `scale`. Then the mantissa of the result is
+    /// $q$, rounded to an integer in the same way as by
+    /// [`round_to_scale`](Decimal::round_to_scale).
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the maximum of the numbers of
+    /// significant bits of the mantissas and the absolute value of
+    /// `scale - self.scale() + other.scale()`.
+    ///
+    /// # Panics
+    /// Panics if `other` is zero, or if `rm` is `Exact` but the quotient cannot be represented
+    /// exactly with the given scale.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_q::decimal::Decimal;
+    ///
+    /// let x: Decimal = "10.00".parse().unwrap();
+    /// let y: Decimal = "3".parse().unwrap();
+    /// assert_eq!(x.div_round_to_scale(&y, 2, RoundingMode::Floor).to_string(), "3.33");
+    /// assert_eq!(x.div_round_to_scale(&y, 2, RoundingMode::Ceiling).to_string(), "3.34");
+    /// assert_eq!(x.div_round_to_scale(&y, 0, RoundingMode::Nearest).to_string(), "3");
+    ///
+    /// let y: Decimal = "0.08".parse().unwrap();
+    /// assert_eq!(x.div_round_to_scale(&y, 1, RoundingMode::Exact).to_string(), "125.0");
+    /// ```
+    pub fn div_round_to_scale(&self, other: &Decimal, scale: i64, rm: RoundingMode) -> Decimal {
+        assert_ne!(other.mantissa, 0u32, "Division by zero");
+        // x / y * 10^s = (m_x / m_y) * 10^(s - s_x + s_y)
+        let shift = i128::from(scale) - i128::from(self.scale) + i128::from(other.scale);
+        let power = Integer::from(power_of_10(u64::exact_from(shift.unsigned_abs())));
+        Decimal {
+            mantissa: if shift >= 0 {
+                (&self.mantissa * power).div_round(&other.mantissa, rm)
+            } else {
+                (&self.mantissa).div_round(&other.mantissa * power, rm)
+            },
+            scale,
+        }
+    }
+}
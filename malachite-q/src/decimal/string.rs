@@ -0,0 +1,260 @@
+use crate::decimal::Decimal;
+use crate::Rational;
+use malachite_base::num::arithmetic::traits::Pow;
+use malachite_base::num::conversion::string::from_sci_string::preprocess_sci_string;
+use malachite_base::num::conversion::string::options::{FromSciStringOptions, ToSciOptions};
+use malachite_base::num::conversion::traits::{FromSciString, ToSci};
+use malachite_nz::integer::Integer;
+use malachite_nz::natural::conversion::string::from_sci_string::FromSciStringHelper;
+use std::fmt::{Debug, Display, Formatter, Result};
+use std::str::FromStr;
+
+impl FromSciString for Decimal {
+    /// Converts a string, possibly in scientfic notation, to a [`Decimal`].
+    ///
+    /// Use [`FromSciStringOptions`] to specify the base (from 2 to 36, inclusive). The rounding
+    /// mode option is ignored.
+    ///
+    /// If the base is 10, the scale of the result is determined by the string: it is the number
+    /// of digits after the decimal point, minus the exponent. So `"1.50"` is parsed with scale 2,
+    /// and `"1.5e3"` with scale $-2$. In other bases, the number must have a terminating decimal
+    /// expansion, and the result has the smallest nonnegative scale that can represent it. If the
+    /// expansion does not terminate, `None` is returned.
+    ///
+    /// If the base is greater than 10, the higher digits are represented by the letters `'a'`
+    /// through `'z'` or `'A'` through `'Z'`; the case doesn't matter and doesn't need to be
+    /// consistent.
+    ///
+    /// Exponents are allowed, and are indicated using the character `'e'` or `'E'`. If the base is
+    /// 15 or greater, an ambiguity arises where it may not be clear whether `'e'` is a digit or
+    /// an exponent indicator. To resolve this ambiguity, always use a `'+'` or `'-'` sign after
+    /// the exponent indicator when the base is 15 or greater.
+    ///
+    /// The exponent itself is always parsed using base 10.
+    ///
+    /// Decimal (or other-base) points are allowed.
+    ///
+    /// If the string is unparseable, `None` is returned.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(m^n n \log m (\log n + \log\log m))$
+    ///
+    /// $M(n, m) = O(m^n n \log m)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `s.len()`, and $m$ is
+    /// `options.base`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::conversion::string::options::FromSciStringOptions;
+    /// use malachite_base::num::conversion::traits::FromSciString;
+    /// use malachite_q::decimal::Decimal;
+    ///
+    /// let x = Decimal::from_sci_string("123.450").unwrap();
+    /// assert_eq!(*x.mantissa(), 123450);
+    /// assert_eq!(x.scale(), 3);
+    ///
+    /// let x = Decimal::from_sci_string("-1.5e3").unwrap();
+    /// assert_eq!(*x.mantissa(), -15);
+    /// assert_eq!(x.scale(), -2);
+    ///
+    /// assert!(Decimal::from_sci_string("1.2.3").is_none());
+    ///
+    /// let mut options = FromSciStringOptions::default();
+    /// options.set_base(16);
+    /// assert_eq!(
+    ///     Decimal::from_sci_string_with_options("ff.8", options).unwrap().to_string(),
+    ///     "255.5"
+    /// );
+    /// options.set_base(3);
+    /// assert!(Decimal::from_sci_string_with_options("0.1", options).is_none());
+    /// ```
+    fn from_sci_string_with_options(s: &str, options: FromSciStringOptions) -> Option<Decimal> {
+        let (s, exponent) = preprocess_sci_string(s, options)?;
+        let mantissa = Integer::parse_int(&s, options.get_base())?;
+        if options.get_base() == 10 {
+            Some(Decimal {
+                mantissa,
+                scale: exponent.checked_neg()?,
+            })
+        } else {
+            Decimal::try_from(
+                Rational::from(mantissa) * Rational::from(options.get_base()).pow(exponent),
+            )
+            .ok()
+        }
+    }
+}
+
+impl ToSci for Decimal {
+    /// Determines whether a [`Decimal`] can be converted to a string using
+    /// [`to_sci`](malachite_base::num::conversion::traits::ToSci::to_sci) and a particular set of
+    /// options.
+    ///
+    /// The result is the same as that for the [`Rational`] with the same value.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.mantissa().significant_bits(), self.scale().unsigned_abs(), s)`, where `s`
+    /// depends on the size type specified in `options`, as described in the documentation for
+    /// [`Rational`]'s implementation of [`ToSci`].
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::conversion::string::options::ToSciOptions;
+    /// use malachite_base::num::conversion::traits::ToSci;
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_q::decimal::Decimal;
+    ///
+    /// let x: Decimal = "1.2345".parse().unwrap();
+    /// let mut options = ToSciOptions::default();
+    /// options.set_rounding_mode(RoundingMode::Exact);
+    /// assert!(x.fmt_sci_valid(options));
+    /// options.set_scale(2);
+    /// assert!(!x.fmt_sci_valid(options));
+    /// ```
+    fn fmt_sci_valid(&self, options: ToSciOptions) -> bool {
+        Rational::from(self).fmt_sci_valid(options)
+    }
+
+    /// Converts a [`Decimal`] to a string using a specified base, possibly formatting the number
+    /// using scientific notation.
+    ///
+    /// The result is the same as that for the [`Rational`] with the same value, so the scale of
+    /// the [`Decimal`] is ignored; the number of digits shown is determined by `options`. See
+    /// [`ToSciOptions`] for details on the available options.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.mantissa().significant_bits(), self.scale().unsigned_abs(), s)`, where `s`
+    /// depends on the size type specified in `options`, as described in the documentation for
+    /// [`Rational`]'s implementation of [`ToSci`].
+    ///
+    /// # Panics
+    /// Panics if `options.rounding_mode` is `Exact`, but the size options are such that the input
+    /// must be rounded.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::conversion::string::options::ToSciOptions;
+    /// use malachite_base::num::conversion::traits::ToSci;
+    /// use malachite_q::decimal::Decimal;
+    ///
+    /// let x: Decimal = "12345.6700".parse().unwrap();
+    /// assert_eq!(x.to_sci().to_string(), "12345.67");
+    ///
+    /// let mut options = ToSciOptions::default();
+    /// options.set_precision(3);
+    /// assert_eq!(x.to_sci_with_options(options).to_string(), "1.23e4");
+    ///
+    /// options = ToSciOptions::default();
+    /// options.set_base(16);
+    /// assert_eq!(x.to_sci_with_options(options).to_string(), "3039.ab851eb851ec");
+    /// ```
+    fn fmt_sci(&self, f: &mut Formatter, options: ToSciOptions) -> Result {
+        Rational::from(self).fmt_sci(f, options)
+    }
+}
+
+impl Display for Decimal {
+    /// Converts a [`Decimal`] to a [`String`].
+    ///
+    /// The number is written in positional notation, without an exponent. If the scale $s$ is
+    /// positive, exactly $s$ digits are written after the decimal point, including trailing
+    /// zeros. Otherwise, no decimal point is written.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.mantissa().significant_bits(), self.scale().unsigned_abs())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::integer::Integer;
+    /// use malachite_q::decimal::Decimal;
+    ///
+    /// assert_eq!(Decimal::from_mantissa_and_scale(Integer::from(0), 2).to_string(), "0.00");
+    /// assert_eq!(Decimal::from_mantissa_and_scale(Integer::from(1500), 3).to_string(), "1.500");
+    /// assert_eq!(
+    ///     Decimal::from_mantissa_and_scale(Integer::from(-1), 8).to_string(),
+    ///     "-0.00000001"
+    /// );
+    /// assert_eq!(Decimal::from_mantissa_and_scale(Integer::from(15), -2).to_string(), "1500");
+    /// ```
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        let mut options = ToSciOptions::default();
+        options.set_scale(u64::try_from(self.scale).unwrap_or(0));
+        options.set_include_trailing_zeros(true);
+        options.set_neg_exp_threshold(i64::MIN);
+        self.fmt_sci(f, options)
+    }
+}
+
+impl Debug for Decimal {
+    /// Converts a [`Decimal`] to a [`String`].
+    ///
+    /// This is the same implementation as for [`Display`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.mantissa().significant_bits(), self.scale().unsigned_abs())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::strings::ToDebugString;
+    /// use malachite_q::decimal::Decimal;
+    ///
+    /// assert_eq!("-1.50".parse::<Decimal>().unwrap().to_debug_string(), "-1.50");
+    /// ```
+    #[inline]
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        Display::fmt(self, f)
+    }
+}
+
+impl FromStr for Decimal {
+    type Err = ();
+
+    /// Converts a string to a [`Decimal`].
+    ///
+    /// The string may contain a decimal point and an exponent, as accepted by the
+    /// [`FromSciString`] implementation.
+    /// The scale of the result is the number of digits after the decimal point, minus the
+    /// exponent.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `s.len()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_q::decimal::Decimal;
+    /// use std::str::FromStr;
+    ///
+    /// assert_eq!(Decimal::from_str("0.10").unwrap().to_string(), "0.10");
+    /// assert_eq!(Decimal::from_str("-12.5e-1").unwrap().to_string(), "-1.25");
+    /// assert_eq!(Decimal::from_str("1/2"), Err(()));
+    /// ```
+    #[inline]
+    fn from_str(s: &str) -> std::result::Result<Decimal, ()> {
+        Decimal::from_sci_string(s).ok_or(())
+    }
+}
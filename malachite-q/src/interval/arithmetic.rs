@@ -0,0 +1,512 @@
+use crate::interval::Interval;
+use crate::Rational;
+use std::ops::{Add, Mul, Neg, Sub};
+
+fn add_helper(xs: &Interval<Rational>, ys: &Interval<Rational>) -> Interval<Rational> {
+    Interval {
+        lower: &xs.lower + &ys.lower,
+        upper: &xs.upper + &ys.upper,
+    }
+}
+
+fn sub_helper(xs: &Interval<Rational>, ys: &Interval<Rational>) -> Interval<Rational> {
+    Interval {
+        lower: &xs.lower - &ys.upper,
+        upper: &xs.upper - &ys.lower,
+    }
+}
+
+fn mul_helper(xs: &Interval<Rational>, ys: &Interval<Rational>) -> Interval<Rational> {
+    let mut products = [
+        &xs.lower * &ys.lower,
+        &xs.lower * &ys.upper,
+        &xs.upper * &ys.lower,
+        &xs.upper * &ys.upper,
+    ];
+    products.sort_unstable();
+    let [lower, _, _, upper] = products;
+    Interval { lower, upper }
+}
+
+impl Add<Interval<Rational>> for Interval<Rational> {
+    type Output = Interval<Rational>;
+
+    /// Adds two `Interval<Rational>`s, taking both by value.
+    ///
+    /// The result is the smallest interval containing every $x + y$, where $x$ and $y$ are
+    /// points of the first and second intervals.
+    ///
+    /// $$
+    /// f([a, b], [c, d]) = [a + c, b + d].
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the maximum number of significant
+    /// bits of an endpoint.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_q::interval::Interval;
+    /// use malachite_q::Rational;
+    ///
+    /// let x = Interval::new(Rational::from(-1), Rational::from_signeds(1, 2));
+    /// let y = Interval::new(Rational::from(2), Rational::from(3));
+    /// assert_eq!((x + y).to_string(), "[1, 7/2]");
+    /// ```
+    #[inline]
+    fn add(self, other: Interval<Rational>) -> Interval<Rational> {
+        add_helper(&self, &other)
+    }
+}
+
+impl<'a> Add<&'a Interval<Rational>> for Interval<Rational> {
+    type Output = Interval<Rational>;
+
+    /// Adds two `Interval<Rational>`s, taking the first by value and the second by reference.
+    ///
+    /// The result is the smallest interval containing every $x + y$, where $x$ and $y$ are
+    /// points of the first and second intervals.
+    ///
+    /// $$
+    /// f([a, b], [c, d]) = [a + c, b + d].
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the maximum number of significant
+    /// bits of an endpoint.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_q::interval::Interval;
+    /// use malachite_q::Rational;
+    ///
+    /// let x = Interval::new(Rational::from(-1), Rational::from_signeds(1, 2));
+    /// let y = Interval::new(Rational::from(2), Rational::from(3));
+    /// assert_eq!((x + &y).to_string(), "[1, 7/2]");
+    /// ```
+    #[inline]
+    fn add(self, other: &'a Interval<Rational>) -> Interval<Rational> {
+        add_helper(&self, other)
+    }
+}
+
+impl<'a> Add<Interval<Rational>> for &'a Interval<Rational> {
+    type Output = Interval<Rational>;
+
+    /// Adds two `Interval<Rational>`s, taking the first by reference and the second by value.
+    ///
+    /// The result is the smallest interval containing every $x + y$, where $x$ and $y$ are
+    /// points of the first and second intervals.
+    ///
+    /// $$
+    /// f([a, b], [c, d]) = [a + c, b + d].
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the maximum number of significant
+    /// bits of an endpoint.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_q::interval::Interval;
+    /// use malachite_q::Rational;
+    ///
+    /// let x = Interval::new(Rational::from(-1), Rational::from_signeds(1, 2));
+    /// let y = Interval::new(Rational::from(2), Rational::from(3));
+    /// assert_eq!((&x + y).to_string(), "[1, 7/2]");
+    /// ```
+    #[inline]
+    fn add(self, other: Interval<Rational>) -> Interval<Rational> {
+        add_helper(self, &other)
+    }
+}
+
+impl<'a, 'b> Add<&'a Interval<Rational>> for &'b Interval<Rational> {
+    type Output = Interval<Rational>;
+
+    /// Adds two `Interval<Rational>`s, taking both by reference.
+    ///
+    /// The result is the smallest interval containing every $x + y$, where $x$ and $y$ are
+    /// points of the first and second intervals.
+    ///
+    /// $$
+    /// f([a, b], [c, d]) = [a + c, b + d].
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the maximum number of significant
+    /// bits of an endpoint.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_q::interval::Interval;
+    /// use malachite_q::Rational;
+    ///
+    /// let x = Interval::new(Rational::from(-1), Rational::from_signeds(1, 2));
+    /// let y = Interval::new(Rational::from(2), Rational::from(3));
+    /// assert_eq!((&x + &y).to_string(), "[1, 7/2]");
+    /// ```
+    #[inline]
+    fn add(self, other: &'a Interval<Rational>) -> Interval<Rational> {
+        add_helper(self, other)
+    }
+}
+
+impl Sub<Interval<Rational>> for Interval<Rational> {
+    type Output = Interval<Rational>;
+
+    /// Subtracts two `Interval<Rational>`s, taking both by value.
+    ///
+    /// The result is the smallest interval containing every $x - y$, where $x$ and $y$ are
+    /// points of the first and second intervals.
+    ///
+    /// $$
+    /// f([a, b], [c, d]) = [a - d, b - c].
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the maximum number of significant
+    /// bits of an endpoint.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_q::interval::Interval;
+    /// use malachite_q::Rational;
+    ///
+    /// let x = Interval::new(Rational::from(-1), Rational::from_signeds(1, 2));
+    /// let y = Interval::new(Rational::from(2), Rational::from(3));
+    /// assert_eq!((x - y).to_string(), "[-4, -3/2]");
+    /// ```
+    #[inline]
+    fn sub(self, other: Interval<Rational>) -> Interval<Rational> {
+        sub_helper(&self, &other)
+    }
+}
+
+impl<'a> Sub<&'a Interval<Rational>> for Interval<Rational> {
+    type Output = Interval<Rational>;
+
+    /// Subtracts two `Interval<Rational>`s, taking the first by value and the second by reference.
+    ///
+    /// The result is the smallest interval containing every $x - y$, where $x$ and $y$ are
+    /// points of the first and second intervals.
+    ///
+    /// $$
+    /// f([a, b], [c, d]) = [a - d, b - c].
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the maximum number of significant
+    /// bits of an endpoint.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_q::interval::Interval;
+    /// use malachite_q::Rational;
+    ///
+    /// let x = Interval::new(Rational::from(-1), Rational::from_signeds(1, 2));
+    /// let y = Interval::new(Rational::from(2), Rational::from(3));
+    /// assert_eq!((x - &y).to_string(), "[-4, -3/2]");
+    /// ```
+    #[inline]
+    fn sub(self, other: &'a Interval<Rational>) -> Interval<Rational> {
+        sub_helper(&self, other)
+    }
+}
+
+impl<'a> Sub<Interval<Rational>> for &'a Interval<Rational> {
+    type Output = Interval<Rational>;
+
+    /// Subtracts two `Interval<Rational>`s, taking the first by reference and the second by value.
+    ///
+    /// The result is the smallest interval containing every $x - y$, where $x$ and $y$ are
+    /// points of the first and second intervals.
+    ///
+    /// $$
+    /// f([a, b], [c, d]) = [a - d, b - c].
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the maximum number of significant
+    /// bits of an endpoint.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_q::interval::Interval;
+    /// use malachite_q::Rational;
+    ///
+    /// let x = Interval::new(Rational::from(-1), Rational::from_signeds(1, 2));
+    /// let y = Interval::new(Rational::from(2), Rational::from(3));
+    /// assert_eq!((&x - y).to_string(), "[-4, -3/2]");
+    /// ```
+    #[inline]
+    fn sub(self, other: Interval<Rational>) -> Interval<Rational> {
+        sub_helper(self, &other)
+    }
+}
+
+impl<'a, 'b> Sub<&'a Interval<Rational>> for &'b Interval<Rational> {
+    type Output = Interval<Rational>;
+
+    /// Subtracts two `Interval<Rational>`s, taking both by reference.
+    ///
+    /// The result is the smallest interval containing every $x - y$, where $x$ and $y$ are
+    /// points of the first and second intervals.
+    ///
+    /// $$
+    /// f([a, b], [c, d]) = [a - d, b - c].
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the maximum number of significant
+    /// bits of an endpoint.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_q::interval::Interval;
+    /// use malachite_q::Rational;
+    ///
+    /// let x = Interval::new(Rational::from(-1), Rational::from_signeds(1, 2));
+    /// let y = Interval::new(Rational::from(2), Rational::from(3));
+    /// assert_eq!((&x - &y).to_string(), "[-4, -3/2]");
+    /// ```
+    #[inline]
+    fn sub(self, other: &'a Interval<Rational>) -> Interval<Rational> {
+        sub_helper(self, other)
+    }
+}
+
+impl Mul<Interval<Rational>> for Interval<Rational> {
+    type Output = Interval<Rational>;
+
+    /// Multiplies two `Interval<Rational>`s, taking both by value.
+    ///
+    /// The result is the smallest interval containing every $x * y$, where $x$ and $y$ are
+    /// points of the first and second intervals.
+    ///
+    /// $$
+    /// f([a, b], [c, d]) = [\min(ac, ad, bc, bd), \max(ac, ad, bc, bd)].
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the maximum number of significant
+    /// bits of an endpoint.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_q::interval::Interval;
+    /// use malachite_q::Rational;
+    ///
+    /// let x = Interval::new(Rational::from(-1), Rational::from_signeds(1, 2));
+    /// let y = Interval::new(Rational::from(2), Rational::from(3));
+    /// assert_eq!((x * y).to_string(), "[-3, 3/2]");
+    /// ```
+    #[inline]
+    fn mul(self, other: Interval<Rational>) -> Interval<Rational> {
+        mul_helper(&self, &other)
+    }
+}
+
+impl<'a> Mul<&'a Interval<Rational>> for Interval<Rational> {
+    type Output = Interval<Rational>;
+
+    /// Multiplies two `Interval<Rational>`s, taking the first by value and the second by reference.
+    ///
+    /// The result is the smallest interval containing every $x * y$, where $x$ and $y$ are
+    /// points of the first and second intervals.
+    ///
+    /// $$
+    /// f([a, b], [c, d]) = [\min(ac, ad, bc, bd), \max(ac, ad, bc, bd)].
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the maximum number of significant
+    /// bits of an endpoint.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_q::interval::Interval;
+    /// use malachite_q::Rational;
+    ///
+    /// let x = Interval::new(Rational::from(-1), Rational::from_signeds(1, 2));
+    /// let y = Interval::new(Rational::from(2), Rational::from(3));
+    /// assert_eq!((x * &y).to_string(), "[-3, 3/2]");
+    /// ```
+    #[inline]
+    fn mul(self, other: &'a Interval<Rational>) -> Interval<Rational> {
+        mul_helper(&self, other)
+    }
+}
+
+impl<'a> Mul<Interval<Rational>> for &'a Interval<Rational> {
+    type Output = Interval<Rational>;
+
+    /// Multiplies two `Interval<Rational>`s, taking the first by reference and the second by value.
+    ///
+    /// The result is the smallest interval containing every $x * y$, where $x$ and $y$ are
+    /// points of the first and second intervals.
+    ///
+    /// $$
+    /// f([a, b], [c, d]) = [\min(ac, ad, bc, bd), \max(ac, ad, bc, bd)].
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the maximum number of significant
+    /// bits of an endpoint.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_q::interval::Interval;
+    /// use malachite_q::Rational;
+    ///
+    /// let x = Interval::new(Rational::from(-1), Rational::from_signeds(1, 2));
+    /// let y = Interval::new(Rational::from(2), Rational::from(3));
+    /// assert_eq!((&x * y).to_string(), "[-3, 3/2]");
+    /// ```
+    #[inline]
+    fn mul(self, other: Interval<Rational>) -> Interval<Rational> {
+        mul_helper(self, &other)
+    }
+}
+
+impl<'a, 'b> Mul<&'a Interval<Rational>> for &'b Interval<Rational> {
+    type Output = Interval<Rational>;
+
+    /// Multiplies two `Interval<Rational>`s, taking both by reference.
+    ///
+    /// The result is the smallest interval containing every $x * y$, where $x$ and $y$ are
+    /// points of the first and second intervals.
+    ///
+    /// $$
+    /// f([a, b], [c, d]) = [\min(ac, ad, bc, bd), \max(ac, ad, bc, bd)].
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the maximum number of significant
+    /// bits of an endpoint.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_q::interval::Interval;
+    /// use malachite_q::Rational;
+    ///
+    /// let x = Interval::new(Rational::from(-1), Rational::from_signeds(1, 2));
+    /// let y = Interval::new(Rational::from(2), Rational::from(3));
+    /// assert_eq!((&x * &y).to_string(), "[-3, 3/2]");
+    /// ```
+    #[inline]
+    fn mul(self, other: &'a Interval<Rational>) -> Interval<Rational> {
+        mul_helper(self, other)
+    }
+}
+
+impl Neg for Interval<Rational> {
+    type Output = Interval<Rational>;
+
+    /// Negates an `Interval<Rational>`, taking it by value.
+    ///
+    /// $$
+    /// f([a, b]) = [-b, -a].
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_q::interval::Interval;
+    /// use malachite_q::Rational;
+    ///
+    /// let x = Interval::new(Rational::from(-1), Rational::from_signeds(1, 2));
+    /// assert_eq!((-x).to_string(), "[-1/2, 1]");
+    /// ```
+    #[inline]
+    fn neg(self) -> Interval<Rational> {
+        Interval {
+            lower: -self.upper,
+            upper: -self.lower,
+        }
+    }
+}
+
+impl<'a> Neg for &'a Interval<Rational> {
+    type Output = Interval<Rational>;
+
+    /// Negates an `Interval<Rational>`, taking it by reference.
+    ///
+    /// $$
+    /// f([a, b]) = [-b, -a].
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the maximum number of significant
+    /// bits of an endpoint.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_q::interval::Interval;
+    /// use malachite_q::Rational;
+    ///
+    /// let x = Interval::new(Rational::from(-1), Rational::from_signeds(1, 2));
+    /// assert_eq!((-&x).to_string(), "[-1/2, 1]");
+    /// ```
+    #[inline]
+    fn neg(self) -> Interval<Rational> {
+        Interval {
+            lower: -&self.upper,
+            upper: -&self.lower,
+        }
+    }
+}
@@ -0,0 +1,343 @@
+use crate::interval::Interval;
+use crate::Rational;
+use malachite_base::num::arithmetic::traits::Sign;
+use std::cmp::Ordering;
+use std::ops::Div;
+
+/// The result of dividing an `Interval<Rational>` by an `Interval<Rational>` that may contain
+/// zero.
+///
+/// Each variant describes a closed subset of $\mathbb{R}$ that contains every quotient $x / y$,
+/// where $x$ is a point of the dividend and $y$ is a nonzero point of the divisor.
+#[derive(Clone, Debug, Hash, Eq, PartialEq)]
+pub enum ExtendedInterval {
+    /// The empty set; this is the result when the divisor is $[0, 0]$.
+    Empty,
+    /// The bounded interval $[a, b]$.
+    Bounded(Interval<Rational>),
+    /// The half-line $(-\infty, a]$.
+    AtMost(Rational),
+    /// The half-line $[a, \infty)$.
+    AtLeast(Rational),
+    /// The union $(-\infty, a] \cup [b, \infty)$, where $a < b$.
+    Split(Rational, Rational),
+    /// All of $\mathbb{R}$.
+    Whole,
+}
+
+impl ExtendedInterval {
+    /// Determines whether an [`ExtendedInterval`] contains a [`Rational`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the maximum number of significant
+    /// bits of `x` and the endpoints of `self`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_q::interval::div::ExtendedInterval;
+    /// use malachite_q::Rational;
+    ///
+    /// let s = ExtendedInterval::Split(Rational::from(-1), Rational::from(2));
+    /// assert_eq!(s.contains(&Rational::from(-3)), true);
+    /// assert_eq!(s.contains(&Rational::from(0)), false);
+    /// assert_eq!(s.contains(&Rational::from(2)), true);
+    /// ```
+    pub fn contains(&self, x: &Rational) -> bool {
+        match self {
+            ExtendedInterval::Empty => false,
+            ExtendedInterval::Bounded(i) => i.contains(x),
+            ExtendedInterval::AtMost(a) => x <= a,
+            ExtendedInterval::AtLeast(b) => x >= b,
+            ExtendedInterval::Split(a, b) => x <= a || x >= b,
+            ExtendedInterval::Whole => true,
+        }
+    }
+}
+
+fn div_helper(xs: &Interval<Rational>, ys: &Interval<Rational>) -> Interval<Rational> {
+    let mut quotients = [
+        &xs.lower / &ys.lower,
+        &xs.lower / &ys.upper,
+        &xs.upper / &ys.lower,
+        &xs.upper / &ys.upper,
+    ];
+    quotients.sort_unstable();
+    let [lower, _, _, upper] = quotients;
+    Interval { lower, upper }
+}
+
+impl Interval<Rational> {
+    /// Divides an `Interval<Rational>` by another `Interval<Rational>`, returning `None` if the
+    /// divisor contains zero.
+    ///
+    /// The result is the smallest interval containing every $x / y$, where $x$ and $y$ are points
+    /// of the first and second intervals.
+    ///
+    /// $$
+    /// f([a, b], [c, d]) = [\min(a/c, a/d, b/c, b/d), \max(a/c, a/d, b/c, b/d)].
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the maximum number of significant
+    /// bits of an endpoint.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_q::interval::Interval;
+    /// use malachite_q::Rational;
+    ///
+    /// let x = Interval::new(Rational::from(1), Rational::from(2));
+    /// let y = Interval::new(Rational::from(-4), Rational::from(-2));
+    /// assert_eq!(x.checked_div(&y).unwrap().to_string(), "[-1, -1/4]");
+    /// assert_eq!(y.checked_div(&x).unwrap().to_string(), "[-4, -1]");
+    /// assert_eq!(
+    ///     x.checked_div(&Interval::new(Rational::from(-1), Rational::from(1))),
+    ///     None
+    /// );
+    /// ```
+    pub fn checked_div(&self, other: &Interval<Rational>) -> Option<Interval<Rational>> {
+        if other.contains_zero() {
+            None
+        } else {
+            Some(div_helper(self, other))
+        }
+    }
+
+    /// Divides an `Interval<Rational>` by another `Interval<Rational>`, allowing the divisor to
+    /// contain zero.
+    ///
+    /// The result is the smallest [`ExtendedInterval`] containing every $x / y$, where $x$ is a
+    /// point of the first interval and $y$ is a nonzero point of the second. If the divisor does
+    /// not contain zero, the result is the same as that of
+    /// [`checked_div`](Interval::checked_div). Otherwise the result is unbounded, unless the
+    /// divisor is $[0, 0]$, in which case the result is empty.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the maximum number of significant
+    /// bits of an endpoint.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_q::interval::div::ExtendedInterval;
+    /// use malachite_q::interval::Interval;
+    /// use malachite_q::Rational;
+    ///
+    /// let x = Interval::new(Rational::from(1), Rational::from(2));
+    /// assert_eq!(
+    ///     x.div_extended(&Interval::new(Rational::from(-1), Rational::from(4))),
+    ///     ExtendedInterval::Split(Rational::from(-1), Rational::from_signeds(1, 4))
+    /// );
+    /// assert_eq!(
+    ///     x.div_extended(&Interval::new(Rational::from(0), Rational::from(4))),
+    ///     ExtendedInterval::AtLeast(Rational::from_signeds(1, 4))
+    /// );
+    /// assert_eq!(
+    ///     x.div_extended(&Interval::new(Rational::from(-2), Rational::from(-1))),
+    ///     ExtendedInterval::Bounded(Interval::new(
+    ///         Rational::from(-2),
+    ///         Rational::from_signeds(-1, 2)
+    ///     ))
+    /// );
+    /// assert_eq!(
+    ///     x.div_extended(&Interval::point(Rational::from(0))),
+    ///     ExtendedInterval::Empty
+    /// );
+    /// ```
+    pub fn div_extended(&self, other: &Interval<Rational>) -> ExtendedInterval {
+        if !other.contains_zero() {
+            return ExtendedInterval::Bounded(div_helper(self, other));
+        }
+        let c_sign = other.lower.sign();
+        let d_sign = other.upper.sign();
+        if c_sign == Ordering::Equal && d_sign == Ordering::Equal {
+            return ExtendedInterval::Empty;
+        }
+        if self.contains_zero() {
+            return ExtendedInterval::Whole;
+        }
+        // Now the dividend is either entirely positive or entirely negative. Dividing by a
+        // divisor that approaches zero from one side makes the quotient grow without bound.
+        let positive = self.lower.sign() == Ordering::Greater;
+        // The endpoint of the dividend closest to zero determines the finite endpoints.
+        let near = if positive { &self.lower } else { &self.upper };
+        match (c_sign, d_sign) {
+            (Ordering::Equal, _) => {
+                let q = near / &other.upper;
+                if positive {
+                    ExtendedInterval::AtLeast(q)
+                } else {
+                    ExtendedInterval::AtMost(q)
+                }
+            }
+            (_, Ordering::Equal) => {
+                let q = near / &other.lower;
+                if positive {
+                    ExtendedInterval::AtMost(q)
+                } else {
+                    ExtendedInterval::AtLeast(q)
+                }
+            }
+            _ => {
+                let q_lower = near / &other.lower;
+                let q_upper = near / &other.upper;
+                if positive {
+                    ExtendedInterval::Split(q_lower, q_upper)
+                } else {
+                    ExtendedInterval::Split(q_upper, q_lower)
+                }
+            }
+        }
+    }
+}
+
+impl Div<Interval<Rational>> for Interval<Rational> {
+    type Output = Interval<Rational>;
+
+    /// Divides two `Interval<Rational>`s, taking both by value.
+    ///
+    /// The result is the smallest interval containing every $x / y$, where $x$ and $y$ are points
+    /// of the first and second intervals.
+    ///
+    /// $$
+    /// f([a, b], [c, d]) = [\min(a/c, a/d, b/c, b/d), \max(a/c, a/d, b/c, b/d)].
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the maximum number of significant
+    /// bits of an endpoint.
+    ///
+    /// # Panics
+    /// Panics if `other` contains zero. Use [`div_extended`](Interval::div_extended) to divide by
+    /// such intervals.
+    ///
+    /// # Examples
+    /// See [here](super::div#div).
+    fn div(self, other: Interval<Rational>) -> Interval<Rational> {
+        assert!(
+            !other.contains_zero(),
+            "Division by an interval containing zero"
+        );
+        div_helper(&self, &other)
+    }
+}
+
+impl<'a> Div<&'a Interval<Rational>> for Interval<Rational> {
+    type Output = Interval<Rational>;
+
+    /// Divides two `Interval<Rational>`s, taking the first by value and the second by reference.
+    ///
+    /// The result is the smallest interval containing every $x / y$, where $x$ and $y$ are points
+    /// of the first and second intervals.
+    ///
+    /// $$
+    /// f([a, b], [c, d]) = [\min(a/c, a/d, b/c, b/d), \max(a/c, a/d, b/c, b/d)].
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the maximum number of significant
+    /// bits of an endpoint.
+    ///
+    /// # Panics
+    /// Panics if `other` contains zero. Use [`div_extended`](Interval::div_extended) to divide by
+    /// such intervals.
+    ///
+    /// # Examples
+    /// See [here](super::div#div).
+    fn div(self, other: &'a Interval<Rational>) -> Interval<Rational> {
+        assert!(
+            !other.contains_zero(),
+            "Division by an interval containing zero"
+        );
+        div_helper(&self, other)
+    }
+}
+
+impl<'a> Div<Interval<Rational>> for &'a Interval<Rational> {
+    type Output = Interval<Rational>;
+
+    /// Divides two `Interval<Rational>`s, taking the first by reference and the second by value.
+    ///
+    /// The result is the smallest interval containing every $x / y$, where $x$ and $y$ are points
+    /// of the first and second intervals.
+    ///
+    /// $$
+    /// f([a, b], [c, d]) = [\min(a/c, a/d, b/c, b/d), \max(a/c, a/d, b/c, b/d)].
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the maximum number of significant
+    /// bits of an endpoint.
+    ///
+    /// # Panics
+    /// Panics if `other` contains zero. Use [`div_extended`](Interval::div_extended) to divide by
+    /// such intervals.
+    ///
+    /// # Examples
+    /// See [here](super::div#div).
+    fn div(self, other: Interval<Rational>) -> Interval<Rational> {
+        assert!(
+            !other.contains_zero(),
+            "Division by an interval containing zero"
+        );
+        div_helper(self, &other)
+    }
+}
+
+impl<'a, 'b> Div<&'a Interval<Rational>> for &'b Interval<Rational> {
+    type Output = Interval<Rational>;
+
+    /// Divides two `Interval<Rational>`s, taking both by reference.
+    ///
+    /// The result is the smallest interval containing every $x / y$, where $x$ and $y$ are points
+    /// of the first and second intervals.
+    ///
+    /// $$
+    /// f([a, b], [c, d]) = [\min(a/c, a/d, b/c, b/d), \max(a/c, a/d, b/c, b/d)].
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the maximum number of significant
+    /// bits of an endpoint.
+    ///
+    /// # Panics
+    /// Panics if `other` contains zero. Use [`div_extended`](Interval::div_extended) to divide by
+    /// such intervals.
+    ///
+    /// # Examples
+    /// See [here](super::div#div).
+    fn div(self, other: &'a Interval<Rational>) -> Interval<Rational> {
+        assert!(
+            !other.contains_zero(),
+            "Division by an interval containing zero"
+        );
+        div_helper(self, other)
+    }
+}
@@ -0,0 +1,193 @@
+use std::fmt::{Display, Formatter, Result};
+
+/// A closed, bounded interval $[a, b]$, with $a \leq b$.
+///
+/// The endpoints are usually [`Rational`](crate::Rational)s. Every arithmetic operation on
+/// `Interval<Rational>`s returns an interval that is guaranteed to contain every possible result of
+/// applying the operation to points of the input intervals. Since [`Rational`](crate::Rational)
+/// arithmetic is exact, most operations return the tightest such interval. The exceptions are
+/// [`sqrt_enclosure`](Interval::sqrt_enclosure), since square roots are usually irrational, and the
+/// functions in [`simplify`], which deliberately widen an interval so that its endpoints have
+/// smaller numerators and denominators.
+#[derive(Clone, Debug, Hash, Eq, PartialEq)]
+pub struct Interval<T> {
+    pub(crate) lower: T,
+    pub(crate) upper: T,
+}
+
+impl<T: PartialOrd> Interval<T> {
+    /// Creates the interval $[a, b]$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(1)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(lower.significant_bits(), upper.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `lower` is greater than `upper`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_q::interval::Interval;
+    /// use malachite_q::Rational;
+    ///
+    /// let x = Interval::new(Rational::from_signeds(1, 3), Rational::from(2));
+    /// assert_eq!(x.to_string(), "[1/3, 2]");
+    /// ```
+    pub fn new(lower: T, upper: T) -> Interval<T> {
+        assert!(
+            lower <= upper,
+            "The lower endpoint must not exceed the upper endpoint"
+        );
+        Interval { lower, upper }
+    }
+
+    /// Returns the lower endpoint of an interval.
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_q::interval::Interval;
+    /// use malachite_q::Rational;
+    ///
+    /// let x = Interval::new(Rational::from_signeds(1, 3), Rational::from(2));
+    /// assert_eq!(x.lower().to_string(), "1/3");
+    /// ```
+    pub const fn lower(&self) -> &T {
+        &self.lower
+    }
+
+    /// Returns the upper endpoint of an interval.
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_q::interval::Interval;
+    /// use malachite_q::Rational;
+    ///
+    /// let x = Interval::new(Rational::from_signeds(1, 3), Rational::from(2));
+    /// assert_eq!(x.upper().to_string(), "2");
+    /// ```
+    pub const fn upper(&self) -> &T {
+        &self.upper
+    }
+
+    /// Consumes an interval and returns its lower and upper endpoints.
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_q::interval::Interval;
+    /// use malachite_q::Rational;
+    ///
+    /// let x = Interval::new(Rational::from(-1), Rational::from(2));
+    /// assert_eq!(x.into_endpoints(), (Rational::from(-1), Rational::from(2)));
+    /// ```
+    #[allow(clippy::missing_const_for_fn)]
+    pub fn into_endpoints(self) -> (T, T) {
+        (self.lower, self.upper)
+    }
+}
+
+impl<T: Clone + PartialOrd> Interval<T> {
+    /// Creates the degenerate interval $[x, x]$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `x.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_q::interval::Interval;
+    /// use malachite_q::Rational;
+    ///
+    /// assert_eq!(Interval::point(Rational::from_signeds(-1, 2)).to_string(), "[-1/2, -1/2]");
+    /// ```
+    pub fn point(x: T) -> Interval<T> {
+        Interval {
+            lower: x.clone(),
+            upper: x,
+        }
+    }
+
+    /// Determines whether an interval contains exactly one point.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(1)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.lower().significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_q::interval::Interval;
+    /// use malachite_q::Rational;
+    ///
+    /// assert_eq!(Interval::point(Rational::from(3)).is_point(), true);
+    /// assert_eq!(Interval::new(Rational::from(3), Rational::from(4)).is_point(), false);
+    /// ```
+    pub fn is_point(&self) -> bool {
+        self.lower == self.upper
+    }
+}
+
+impl<T: Display> Display for Interval<T> {
+    /// Converts an interval to a [`String`] of the form `[a, b]`.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.lower().significant_bits(), self.upper().significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_q::interval::Interval;
+    /// use malachite_q::Rational;
+    ///
+    /// let x = Interval::new(Rational::from_signeds(-22, 7), Rational::from(0));
+    /// assert_eq!(x.to_string(), "[-22/7, 0]");
+    /// ```
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        write!(f, "[{}, {}]", self.lower, self.upper)
+    }
+}
+
+/// Addition, subtraction, multiplication, and negation of `Interval<Rational>`s.
+pub mod arithmetic;
+/// Division of `Interval<Rational>`s, including extended division by intervals that contain zero.
+///
+/// # div
+/// ```
+/// use malachite_q::interval::Interval;
+/// use malachite_q::Rational;
+///
+/// let x = Interval::new(Rational::from(1), Rational::from(2));
+/// let y = Interval::new(Rational::from(-4), Rational::from(-2));
+/// assert_eq!((&x / &y).to_string(), "[-1, -1/4]");
+/// assert_eq!((x / y).to_string(), "[-1, -1/4]");
+/// ```
+pub mod div;
+/// Raising `Interval<Rational>`s to integer powers.
+pub mod pow;
+/// Containment, intersection, and hulls of `Interval<Rational>`s.
+pub mod set;
+/// Functions that widen `Interval<Rational>`s so that their endpoints have small denominators.
+pub mod simplify;
+/// Enclosures of the square roots of `Interval<Rational>`s.
+pub mod sqrt;
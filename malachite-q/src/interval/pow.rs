@@ -0,0 +1,106 @@
+use crate::interval::Interval;
+use crate::Rational;
+use malachite_base::num::arithmetic::traits::{Abs, Parity, Pow};
+use malachite_base::num::basic::traits::{One, Zero};
+
+fn pow_helper(xs: &Interval<Rational>, exp: u64) -> Interval<Rational> {
+    if exp == 0 {
+        Interval::point(Rational::ONE)
+    } else if exp.odd() {
+        // x ↦ x^n is increasing when n is odd.
+        Interval {
+            lower: (&xs.lower).pow(exp),
+            upper: (&xs.upper).pow(exp),
+        }
+    } else {
+        let lower_abs = (&xs.lower).abs();
+        let upper_abs = (&xs.upper).abs();
+        let (small, large) = if lower_abs <= upper_abs {
+            (lower_abs, upper_abs)
+        } else {
+            (upper_abs, lower_abs)
+        };
+        Interval {
+            lower: if xs.contains_zero() {
+                Rational::ZERO
+            } else {
+                small.pow(exp)
+            },
+            upper: large.pow(exp),
+        }
+    }
+}
+
+impl Pow<u64> for Interval<Rational> {
+    type Output = Interval<Rational>;
+
+    /// Raises an `Interval<Rational>` to a power, taking the interval by value.
+    ///
+    /// The result is the smallest interval containing every $x^n$, where $x$ is a point of the
+    /// interval. When $n$ is even and the interval contains zero, this is tighter than the
+    /// result of multiplying the interval by itself $n$ times.
+    ///
+    /// $0^0 = 1$, so every interval raised to the power 0 is $[1, 1]$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(nm \log (nm) \log\log (nm))$
+    ///
+    /// $M(n, m) = O(nm \log (nm))$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is the maximum number of significant bits
+    /// of an endpoint, and $m$ is `exp`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::Pow;
+    /// use malachite_q::interval::Interval;
+    /// use malachite_q::Rational;
+    ///
+    /// let x = Interval::new(Rational::from(-2), Rational::from_signeds(1, 2));
+    /// assert_eq!(x.clone().pow(0).to_string(), "[1, 1]");
+    /// assert_eq!(x.clone().pow(2).to_string(), "[0, 4]");
+    /// assert_eq!(x.clone().pow(3).to_string(), "[-8, 1/8]");
+    /// assert_eq!((&x * &x).to_string(), "[-1, 4]");
+    /// ```
+    #[inline]
+    fn pow(self, exp: u64) -> Interval<Rational> {
+        pow_helper(&self, exp)
+    }
+}
+
+impl<'a> Pow<u64> for &'a Interval<Rational> {
+    type Output = Interval<Rational>;
+
+    /// Raises an `Interval<Rational>` to a power, taking the interval by reference.
+    ///
+    /// The result is the smallest interval containing every $x^n$, where $x$ is a point of the
+    /// interval. When $n$ is even and the interval contains zero, this is tighter than the
+    /// result of multiplying the interval by itself $n$ times.
+    ///
+    /// $0^0 = 1$, so every interval raised to the power 0 is $[1, 1]$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(nm \log (nm) \log\log (nm))$
+    ///
+    /// $M(n, m) = O(nm \log (nm))$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is the maximum number of significant bits
+    /// of an endpoint, and $m$ is `exp`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::Pow;
+    /// use malachite_q::interval::Interval;
+    /// use malachite_q::Rational;
+    ///
+    /// let x = Interval::new(Rational::from(-2), Rational::from_signeds(1, 2));
+    /// assert_eq!((&x).pow(0).to_string(), "[1, 1]");
+    /// assert_eq!((&x).pow(2).to_string(), "[0, 4]");
+    /// assert_eq!((&x).pow(3).to_string(), "[-8, 1/8]");
+    /// assert_eq!((&x * &x).to_string(), "[-1, 4]");
+    /// ```
+    #[inline]
+    fn pow(self, exp: u64) -> Interval<Rational> {
+        pow_helper(self, exp)
+    }
+}
@@ -0,0 +1,187 @@
+use crate::interval::Interval;
+use crate::Rational;
+use malachite_base::num::arithmetic::traits::Sign;
+use std::cmp::Ordering;
+
+impl Interval<Rational> {
+    /// Determines whether an `Interval<Rational>` contains a [`Rational`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.lower().significant_bits(), self.upper().significant_bits(),
+    /// x.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_q::interval::Interval;
+    /// use malachite_q::Rational;
+    ///
+    /// let x = Interval::new(Rational::from_signeds(1, 3), Rational::from(2));
+    /// assert_eq!(x.contains(&Rational::from(1)), true);
+    /// assert_eq!(x.contains(&Rational::from_signeds(1, 3)), true);
+    /// assert_eq!(x.contains(&Rational::from_signeds(1, 4)), false);
+    /// ```
+    pub fn contains(&self, x: &Rational) -> bool {
+        self.lower <= *x && *x <= self.upper
+    }
+
+    /// Determines whether an `Interval<Rational>` contains zero.
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_q::interval::Interval;
+    /// use malachite_q::Rational;
+    ///
+    /// assert_eq!(Interval::new(Rational::from(-1), Rational::from(2)).contains_zero(), true);
+    /// assert_eq!(Interval::new(Rational::from(0), Rational::from(2)).contains_zero(), true);
+    /// assert_eq!(Interval::new(Rational::from(1), Rational::from(2)).contains_zero(), false);
+    /// ```
+    pub fn contains_zero(&self) -> bool {
+        self.lower.sign() != Ordering::Greater && self.upper.sign() != Ordering::Less
+    }
+
+    /// Determines whether an `Interval<Rational>` contains another `Interval<Rational>`.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the maximum number of significant
+    /// bits of an endpoint.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_q::interval::Interval;
+    /// use malachite_q::Rational;
+    ///
+    /// let x = Interval::new(Rational::from(-1), Rational::from(2));
+    /// let y = Interval::new(Rational::from(0), Rational::from(1));
+    /// assert_eq!(x.contains_interval(&y), true);
+    /// assert_eq!(y.contains_interval(&x), false);
+    /// ```
+    pub fn contains_interval(&self, other: &Interval<Rational>) -> bool {
+        self.lower <= other.lower && other.upper <= self.upper
+    }
+
+    /// Returns the intersection of two `Interval<Rational>`s, or `None` if they are disjoint.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the maximum number of significant
+    /// bits of an endpoint.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_q::interval::Interval;
+    /// use malachite_q::Rational;
+    ///
+    /// let x = Interval::new(Rational::from(-1), Rational::from(2));
+    /// let y = Interval::new(Rational::from(1), Rational::from(3));
+    /// let z = Interval::new(Rational::from(3), Rational::from(4));
+    /// assert_eq!(x.intersection(&y).unwrap().to_string(), "[1, 2]");
+    /// assert_eq!(y.intersection(&z).unwrap().to_string(), "[3, 3]");
+    /// assert_eq!(x.intersection(&z), None);
+    /// ```
+    pub fn intersection(&self, other: &Interval<Rational>) -> Option<Interval<Rational>> {
+        let lower = std::cmp::max(&self.lower, &other.lower);
+        let upper = std::cmp::min(&self.upper, &other.upper);
+        if lower <= upper {
+            Some(Interval {
+                lower: lower.clone(),
+                upper: upper.clone(),
+            })
+        } else {
+            None
+        }
+    }
+
+    /// Returns the hull of two `Interval<Rational>`s; that is, the smallest interval that
+    /// contains both of them.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the maximum number of significant
+    /// bits of an endpoint.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_q::interval::Interval;
+    /// use malachite_q::Rational;
+    ///
+    /// let x = Interval::new(Rational::from(-1), Rational::from(2));
+    /// let z = Interval::new(Rational::from(3), Rational::from(4));
+    /// assert_eq!(x.hull(&z).to_string(), "[-1, 4]");
+    /// ```
+    pub fn hull(&self, other: &Interval<Rational>) -> Interval<Rational> {
+        Interval {
+            lower: std::cmp::min(&self.lower, &other.lower).clone(),
+            upper: std::cmp::max(&self.upper, &other.upper).clone(),
+        }
+    }
+
+    /// Returns the width of an `Interval<Rational>`.
+    ///
+    /// $$
+    /// f([a, b]) = b - a.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the maximum number of significant
+    /// bits of an endpoint.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_q::interval::Interval;
+    /// use malachite_q::Rational;
+    ///
+    /// let x = Interval::new(Rational::from_signeds(1, 3), Rational::from_signeds(1, 2));
+    /// assert_eq!(x.width().to_string(), "1/6");
+    /// ```
+    pub fn width(&self) -> Rational {
+        &self.upper - &self.lower
+    }
+
+    /// Returns the midpoint of an `Interval<Rational>`.
+    ///
+    /// $$
+    /// f([a, b]) = \frac{a + b}{2}.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the maximum number of significant
+    /// bits of an endpoint.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_q::interval::Interval;
+    /// use malachite_q::Rational;
+    ///
+    /// let x = Interval::new(Rational::from_signeds(1, 3), Rational::from_signeds(1, 2));
+    /// assert_eq!(x.midpoint().to_string(), "5/12");
+    /// ```
+    pub fn midpoint(&self) -> Rational {
+        (&self.lower + &self.upper) >> 1u32
+    }
+}
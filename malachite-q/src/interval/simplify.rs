@@ -0,0 +1,152 @@
+use crate::arithmetic::traits::{Approximate, SimplestRationalInInterval};
+use crate::interval::Interval;
+use crate::Rational;
+use malachite_base::num::arithmetic::traits::{DivExact, Mod, ModInverse, UnsignedAbs};
+use malachite_base::num::basic::traits::One;
+use malachite_nz::integer::Integer;
+use malachite_nz::natural::Natural;
+
+// Given a reduced fraction $a/b$ with $b \leq m$, returns its left or right neighbor in the Farey
+// sequence of order $m$; that is, the largest fraction less than $a/b$, or the smallest fraction
+// greater than $a/b$, whose denominator is at most $m$.
+//
+// The left neighbor $p/r$ satisfies $ar - pb = 1$, so $r \equiv a^{-1} \pmod b$, and the right
+// neighbor satisfies $pb - ar = 1$, so $r \equiv -a^{-1} \pmod b$. In each case $r$ is the largest
+// such value that does not exceed $m$.
+fn farey_neighbor(x: &Rational, max_denominator: &Natural, left: bool) -> Rational {
+    let a = Integer::from_sign_and_abs_ref(x.sign, &x.numerator);
+    let b = &x.denominator;
+    let r = if *b == 1u32 {
+        max_denominator.clone()
+    } else {
+        let inverse = (&a)
+            .mod_op(Integer::from(b))
+            .unsigned_abs()
+            .mod_inverse(b)
+            .unwrap();
+        let r = if left { inverse } else { b - inverse };
+        (max_denominator - &r) / b * b + r
+    };
+    let ar = a * Integer::from(&r);
+    let p = if left {
+        ar - Integer::ONE
+    } else {
+        ar + Integer::ONE
+    };
+    Rational::from_integers(p.div_exact(Integer::from(b)), Integer::from(r))
+}
+
+// Returns the largest (if `lower`) or smallest (otherwise) fraction with denominator at most
+// `max_denominator` that is no greater than, or no less than, `x`.
+fn round_to_denominator(x: &Rational, max_denominator: &Natural, lower: bool) -> Rational {
+    let q = x.approximate(max_denominator);
+    // `q` is the closest fraction to `x` with a small enough denominator, so no such fraction lies
+    // strictly between `x` and `q`. If `q` is on the wrong side of `x`, its Farey neighbor on the
+    // other side is the answer.
+    if lower && q > *x {
+        farey_neighbor(&q, max_denominator, true)
+    } else if !lower && q < *x {
+        farey_neighbor(&q, max_denominator, false)
+    } else {
+        q
+    }
+}
+
+impl Interval<Rational> {
+    /// Widens an `Interval<Rational>` by at most a given tolerance at each end, so that its
+    /// endpoints are as simple as possible.
+    ///
+    /// The lower endpoint $a$ is replaced by the simplest [`Rational`] in $[a - \varepsilon, a]$,
+    /// and the upper endpoint $b$ by the simplest [`Rational`] in $[b, b + \varepsilon]$, where
+    /// $\varepsilon$ is `tolerance`. (See
+    /// [`SimplestRationalInInterval`](crate::arithmetic::traits::SimplestRationalInInterval) for
+    /// the meaning of "simplest".) The result always contains `self`. Applying this function
+    /// between the steps of a long computation keeps the sizes of the endpoints from growing
+    /// without bound, at the cost of some precision.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the maximum number of significant
+    /// bits of an endpoint or of `tolerance`.
+    ///
+    /// # Panics
+    /// Panics if `tolerance` is negative.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_q::interval::Interval;
+    /// use malachite_q::Rational;
+    ///
+    /// let x = Interval::new(
+    ///     Rational::from_signeds(314159, 100000),
+    ///     Rational::from_signeds(314160, 100000),
+    /// );
+    /// let tolerance = Rational::from_signeds(1, 1000);
+    /// assert_eq!(x.simplify(&tolerance).to_string(), "[201/64, 377/120]");
+    /// assert_eq!(x.simplify(&Rational::from(0)), x);
+    /// ```
+    pub fn simplify(&self, tolerance: &Rational) -> Interval<Rational> {
+        assert!(*tolerance >= 0u32, "The tolerance cannot be negative");
+        if *tolerance == 0u32 {
+            return self.clone();
+        }
+        Interval {
+            lower: Rational::simplest_rational_in_closed_interval(
+                &(&self.lower - tolerance),
+                &self.lower,
+            ),
+            upper: Rational::simplest_rational_in_closed_interval(
+                &self.upper,
+                &(&self.upper + tolerance),
+            ),
+        }
+    }
+
+    /// Widens an `Interval<Rational>` as little as possible, so that the denominators of its
+    /// endpoints do not exceed a given bound.
+    ///
+    /// The lower endpoint is replaced by the largest [`Rational`] that is no greater than it and
+    /// whose denominator is at most `max_denominator`, and the upper endpoint by the smallest
+    /// [`Rational`] that is no less than it and whose denominator is at most `max_denominator`.
+    /// The result always contains `self`, and each endpoint moves by at most
+    /// $1/\mathrm{max\\_denominator}$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the maximum number of significant
+    /// bits of an endpoint or of `max_denominator`.
+    ///
+    /// # Panics
+    /// Panics if `max_denominator` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::natural::Natural;
+    /// use malachite_q::interval::Interval;
+    /// use malachite_q::Rational;
+    ///
+    /// let x = Interval::new(
+    ///     Rational::from_signeds(314159, 100000),
+    ///     Rational::from_signeds(314160, 100000),
+    /// );
+    /// assert_eq!(x.round_outward(&Natural::from(10u32)).to_string(), "[25/8, 22/7]");
+    /// assert_eq!(x.round_outward(&Natural::from(100u32)).to_string(), "[311/99, 22/7]");
+    /// assert_eq!(x.round_outward(&Natural::from(100000u32)), x);
+    /// ```
+    pub fn round_outward(&self, max_denominator: &Natural) -> Interval<Rational> {
+        assert_ne!(
+            *max_denominator, 0u32,
+            "The maximum denominator cannot be zero"
+        );
+        Interval {
+            lower: round_to_denominator(&self.lower, max_denominator, true),
+            upper: round_to_denominator(&self.upper, max_denominator, false),
+        }
+    }
+}
@@ -0,0 +1,74 @@
+use crate::interval::Interval;
+use crate::Rational;
+use malachite_base::num::arithmetic::traits::{CeilingSqrt, FloorSqrt};
+use malachite_base::num::basic::traits::Zero;
+use malachite_nz::natural::Natural;
+
+// Returns a lower or upper bound for the square root of a nonnegative `Rational` $n/d$, with an
+// error of at most $2^{-b}/d$. Since $\sqrt{n/d} = \sqrt{nd}/d$, scaling $nd$ by $4^b$ and taking
+// an integer square root gives the bound $\lfloor \sqrt{4^b nd} \rfloor / (2^b d)$ (or the
+// corresponding ceiling).
+fn sqrt_bound(x: &Rational, bits: u64, upper: bool) -> Rational {
+    let (n, d) = x.numerator_and_denominator_ref();
+    let scaled = (n * d) << (bits << 1);
+    let root: Natural = if upper {
+        scaled.ceiling_sqrt()
+    } else {
+        scaled.floor_sqrt()
+    };
+    Rational::from_naturals(root, d << bits)
+}
+
+impl Interval<Rational> {
+    /// Returns an `Interval<Rational>` that contains the square root of every nonnegative point of
+    /// an `Interval<Rational>`.
+    ///
+    /// Each endpoint of the result is within $2^{-b}$ of the square root of the corresponding
+    /// endpoint of the input, where $b$ is `bits`. If an endpoint is the square of a
+    /// [`Rational`], the corresponding endpoint of the result is exact. Negative points of the
+    /// input are ignored, so a lower endpoint that is negative is treated as 0.
+    ///
+    /// $$
+    /// f([a, b], p) = [\ell, u],
+    /// $$
+    /// where $\sqrt{\max(a, 0)} - 2^{-p} \leq \ell \leq \sqrt{\max(a, 0)}$ and
+    /// $\sqrt{b} \leq u \leq \sqrt{b} + 2^{-p}$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the maximum number of significant
+    /// bits of an endpoint, plus `bits`.
+    ///
+    /// # Panics
+    /// Panics if the upper endpoint is negative.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_q::interval::Interval;
+    /// use malachite_q::Rational;
+    ///
+    /// let x = Interval::new(Rational::from(2), Rational::from(3));
+    /// assert_eq!(x.sqrt_enclosure(10).to_string(), "[181/128, 887/512]");
+    ///
+    /// let x = Interval::new(Rational::from(-1), Rational::from_signeds(9, 4));
+    /// assert_eq!(x.sqrt_enclosure(10).to_string(), "[0, 3/2]");
+    /// ```
+    pub fn sqrt_enclosure(&self, bits: u64) -> Interval<Rational> {
+        assert!(
+            self.upper >= 0u32,
+            "Cannot take the square root of an interval with a negative upper endpoint"
+        );
+        let lower = if self.lower > 0u32 {
+            sqrt_bound(&self.lower, bits, false)
+        } else {
+            Rational::ZERO
+        };
+        Interval {
+            lower,
+            upper: sqrt_bound(&self.upper, bits, true),
+        }
+    }
+}
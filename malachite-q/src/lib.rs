@@ -221,11 +221,30 @@ impl_named!(Rational);
 pub mod arithmetic;
 /// Traits for comparing [`Rational`]s for equality or order.
 pub mod comparison;
+/// [`ComplexRational`](complex_rational::ComplexRational), a type representing complex numbers
+/// whose real and imaginary parts are [`Rational`]s, with exact arithmetic.
+pub mod complex_rational;
 /// Traits for converting to and from [`Rational`]s, converting to and from strings, and extracting
 /// digits and continued fractions.
 pub mod conversion;
+/// [`Decimal`](decimal::Decimal), a fixed-scale decimal type built on
+/// [`Integer`](malachite_nz::integer::Integer).
+pub mod decimal;
 /// Iterators that generate [`Rational`]s without repetition.
 pub mod exhaustive;
+/// [`Interval`](interval::Interval), a closed interval type, and interval arithmetic over
+/// [`Rational`]s with guaranteed enclosures.
+pub mod interval;
+/// [`Matrix`](matrix::Matrix), a dense matrix type, and exact linear algebra over [`Integer`]s and
+/// [`Rational`]s.
+///
+/// [`Integer`]: malachite_nz::integer::Integer
+pub mod matrix;
+/// [`Polynomial`](polynomial::Polynomial), a dense univariate polynomial type, with arithmetic,
+/// GCDs, and real root isolation over [`Integer`]s, [`Rational`]s, and the integers modulo $m$.
+///
+/// [`Integer`]: malachite_nz::integer::Integer
+pub mod polynomial;
 /// Iterators that generate [`Rational`]s randomly.
 pub mod random;
 
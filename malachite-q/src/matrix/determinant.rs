@@ -0,0 +1,160 @@
+use crate::matrix::{clear_denominators, fraction_free_eliminate, Matrix};
+use crate::Rational;
+use malachite_base::num::basic::traits::Zero;
+use malachite_nz::integer::Integer;
+
+fn determinant_helper(m: Matrix<Integer>) -> Integer {
+    assert!(m.is_square(), "Only square matrices have determinants");
+    let n = m.rows;
+    let elimination = fraction_free_eliminate(m, n, false);
+    if elimination.pivots.len() < n {
+        Integer::ZERO
+    } else if elimination.negated {
+        -elimination.d
+    } else {
+        elimination.d
+    }
+}
+
+impl Matrix<Integer> {
+    /// Computes the determinant of a square [`Matrix`] of [`Integer`]s.
+    ///
+    /// Bareiss's fraction-free elimination is used. Every intermediate value is a minor of the
+    /// matrix, so, unlike in ordinary Gaussian elimination over the rationals, the sizes of the
+    /// numbers involved stay bounded and no GCDs are ever computed.
+    ///
+    /// The determinant of the $0 \times 0$ matrix is 1.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, b) = O(n^3 M(nb))$
+    ///
+    /// $M(n, b) = O(n^3 b)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, the matrix is $n \times n$, $b$ is the maximum
+    /// number of bits in an entry, and $M(k)$ is the time needed to multiply two $k$-bit numbers.
+    ///
+    /// # Panics
+    /// Panics if `self` is not square.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::integer::Integer;
+    /// use malachite_q::matrix::Matrix;
+    ///
+    /// let m = Matrix::from_rows(vec![
+    ///     vec![Integer::from(2), Integer::from(-1), Integer::from(0)],
+    ///     vec![Integer::from(-1), Integer::from(2), Integer::from(-1)],
+    ///     vec![Integer::from(0), Integer::from(-1), Integer::from(2)],
+    /// ]);
+    /// assert_eq!(m.determinant(), 4);
+    ///
+    /// let m = Matrix::from_rows(vec![
+    ///     vec![Integer::from(1), Integer::from(2)],
+    ///     vec![Integer::from(2), Integer::from(4)],
+    /// ]);
+    /// assert_eq!(m.determinant(), 0);
+    /// ```
+    #[inline]
+    pub fn determinant(&self) -> Integer {
+        determinant_helper(self.clone())
+    }
+
+    /// Computes the rank of a [`Matrix`] of [`Integer`]s.
+    ///
+    /// The rank is the number of pivots found by Bareiss's fraction-free elimination.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m, b) = O(nm \min(n, m) M(\min(n, m) b))$
+    ///
+    /// $M(n, m, b) = O(nm \min(n, m) b)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, the matrix is $n \times m$, $b$ is the maximum
+    /// number of bits in an entry, and $M(k)$ is the time needed to multiply two $k$-bit numbers.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::integer::Integer;
+    /// use malachite_q::matrix::Matrix;
+    ///
+    /// let m = Matrix::from_rows(vec![
+    ///     vec![Integer::from(1), Integer::from(2), Integer::from(3)],
+    ///     vec![Integer::from(4), Integer::from(5), Integer::from(6)],
+    ///     vec![Integer::from(7), Integer::from(8), Integer::from(9)],
+    /// ]);
+    /// assert_eq!(m.rank(), 2);
+    /// assert_eq!(Matrix::<Integer>::zero(2, 3).rank(), 0);
+    /// ```
+    pub fn rank(&self) -> usize {
+        fraction_free_eliminate(self.clone(), self.cols, false)
+            .pivots
+            .len()
+    }
+}
+
+impl Matrix<Rational> {
+    /// Computes the determinant of a square [`Matrix`] of [`Rational`]s.
+    ///
+    /// Each row is multiplied by the least common multiple of its denominators, the determinant of
+    /// the resulting [`Integer`] matrix is computed using Bareiss's fraction-free elimination, and
+    /// the result is divided by the product of the multipliers.
+    ///
+    /// The determinant of the $0 \times 0$ matrix is 1.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, b) = O(n^3 M(n^2 b))$
+    ///
+    /// $M(n, b) = O(n^4 b)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, the matrix is $n \times n$, $b$ is the maximum
+    /// number of bits in an entry, and $M(k)$ is the time needed to multiply two $k$-bit numbers.
+    ///
+    /// # Panics
+    /// Panics if `self` is not square.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_q::matrix::Matrix;
+    /// use malachite_q::Rational;
+    ///
+    /// let m = Matrix::from_rows(vec![
+    ///     vec![Rational::from_signeds(1, 2), Rational::from_signeds(1, 3)],
+    ///     vec![Rational::from_signeds(1, 4), Rational::from_signeds(1, 5)],
+    /// ]);
+    /// assert_eq!(m.determinant().to_string(), "1/60");
+    /// ```
+    pub fn determinant(&self) -> Rational {
+        let (m, scale) = clear_denominators(self);
+        Rational::from_integers(determinant_helper(m), Integer::from(scale))
+    }
+
+    /// Computes the rank of a [`Matrix`] of [`Rational`]s.
+    ///
+    /// Each row is multiplied by the least common multiple of its denominators, and the rank of the
+    /// resulting [`Integer`] matrix is computed using Bareiss's fraction-free elimination.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m, b) = O(nm \min(n, m) M(\min(n, m) mb))$
+    ///
+    /// $M(n, m, b) = O(nm^2 \min(n, m) b)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, the matrix is $n \times m$, $b$ is the maximum
+    /// number of bits in an entry, and $M(k)$ is the time needed to multiply two $k$-bit numbers.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_q::matrix::Matrix;
+    /// use malachite_q::Rational;
+    ///
+    /// let m = Matrix::from_rows(vec![
+    ///     vec![Rational::from_signeds(1, 2), Rational::from_signeds(1, 3)],
+    ///     vec![Rational::from_signeds(3, 2), Rational::from(1)],
+    /// ]);
+    /// assert_eq!(m.rank(), 1);
+    /// ```
+    pub fn rank(&self) -> usize {
+        let cols = self.cols;
+        fraction_free_eliminate(clear_denominators(self).0, cols, false)
+            .pivots
+            .len()
+    }
+}
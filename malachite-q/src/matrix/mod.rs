@@ -0,0 +1,457 @@
+use crate::Rational;
+use malachite_base::num::arithmetic::traits::{DivExact, Lcm};
+use malachite_base::num::basic::traits::{One, Zero};
+use malachite_nz::integer::Integer;
+use malachite_nz::natural::Natural;
+use std::ops::{Index, IndexMut};
+
+/// A dense matrix, stored in row-major order.
+///
+/// The entries are usually [`Integer`]s or [`Rational`]s. Operations on a `Matrix<Rational>` first
+/// clear the denominators of each row, and then use the same fraction-free algorithms as a
+/// `Matrix<Integer>`, so that no GCDs are computed until the final result is assembled.
+#[derive(Clone, Debug, Hash, Eq, PartialEq)]
+pub struct Matrix<T> {
+    pub(crate) rows: usize,
+    pub(crate) cols: usize,
+    // The entries, row by row.
+    pub(crate) entries: Vec<T>,
+}
+
+impl<T> Matrix<T> {
+    /// Creates a [`Matrix`] from a [`Vec`] of rows.
+    ///
+    /// If there are no rows, the result is the $0 \times 0$ matrix.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the number of entries.
+    ///
+    /// # Panics
+    /// Panics if the rows do not all have the same length.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::integer::Integer;
+    /// use malachite_q::matrix::Matrix;
+    ///
+    /// let m = Matrix::from_rows(vec![
+    ///     vec![Integer::from(1), Integer::from(2), Integer::from(3)],
+    ///     vec![Integer::from(4), Integer::from(5), Integer::from(6)],
+    /// ]);
+    /// assert_eq!(m.row_count(), 2);
+    /// assert_eq!(m.column_count(), 3);
+    /// assert_eq!(m[(1, 0)], 4);
+    /// ```
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Matrix<T> {
+        let row_count = rows.len();
+        let cols = rows.first().map_or(0, Vec::len);
+        let mut entries = Vec::with_capacity(row_count * cols);
+        for row in rows {
+            assert_eq!(row.len(), cols, "Rows must all have the same length");
+            entries.extend(row);
+        }
+        Matrix {
+            rows: row_count,
+            cols,
+            entries,
+        }
+    }
+
+    /// Returns the number of rows of a [`Matrix`].
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::integer::Integer;
+    /// use malachite_q::matrix::Matrix;
+    ///
+    /// assert_eq!(Matrix::<Integer>::zero(2, 3).row_count(), 2);
+    /// ```
+    pub const fn row_count(&self) -> usize {
+        self.rows
+    }
+
+    /// Returns the number of columns of a [`Matrix`].
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::integer::Integer;
+    /// use malachite_q::matrix::Matrix;
+    ///
+    /// assert_eq!(Matrix::<Integer>::zero(2, 3).column_count(), 3);
+    /// ```
+    pub const fn column_count(&self) -> usize {
+        self.cols
+    }
+
+    /// Determines whether a [`Matrix`] has as many rows as columns.
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::integer::Integer;
+    /// use malachite_q::matrix::Matrix;
+    ///
+    /// assert_eq!(Matrix::<Integer>::zero(2, 3).is_square(), false);
+    /// assert_eq!(Matrix::<Integer>::identity(3).is_square(), true);
+    /// ```
+    pub const fn is_square(&self) -> bool {
+        self.rows == self.cols
+    }
+
+    /// Returns a row of a [`Matrix`] as a slice.
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Panics
+    /// Panics if `i` is greater than or equal to the number of rows.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::integer::Integer;
+    /// use malachite_q::matrix::Matrix;
+    ///
+    /// assert_eq!(Matrix::<Integer>::identity(3).row(1), &[0, 1, 0]);
+    /// ```
+    pub fn row(&self, i: usize) -> &[T] {
+        assert!(i < self.rows, "Row index out of range");
+        &self.entries[i * self.cols..(i + 1) * self.cols]
+    }
+
+    /// Converts a [`Matrix`] into a [`Vec`] of rows.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the number of entries.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::integer::Integer;
+    /// use malachite_q::matrix::Matrix;
+    ///
+    /// assert_eq!(
+    ///     Matrix::<Integer>::identity(2).into_rows(),
+    ///     [[1, 0], [0, 1]]
+    /// );
+    /// ```
+    pub fn into_rows(self) -> Vec<Vec<T>> {
+        if self.cols == 0 {
+            return (0..self.rows).map(|_| Vec::new()).collect();
+        }
+        let mut entries = self.entries.into_iter();
+        (0..self.rows)
+            .map(|_| entries.by_ref().take(self.cols).collect())
+            .collect()
+    }
+
+    pub(crate) fn swap_rows(&mut self, i: usize, j: usize) {
+        if i != j {
+            for k in 0..self.cols {
+                self.entries.swap(i * self.cols + k, j * self.cols + k);
+            }
+        }
+    }
+}
+
+impl<T: Clone> Matrix<T> {
+    /// Returns the transpose of a [`Matrix`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the total number of bits in the
+    /// entries.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::integer::Integer;
+    /// use malachite_q::matrix::Matrix;
+    ///
+    /// let m = Matrix::from_rows(vec![
+    ///     vec![Integer::from(1), Integer::from(2), Integer::from(3)],
+    ///     vec![Integer::from(4), Integer::from(5), Integer::from(6)],
+    /// ]);
+    /// assert_eq!(m.transpose().into_rows(), [[1, 4], [2, 5], [3, 6]]);
+    /// ```
+    pub fn transpose(&self) -> Matrix<T> {
+        let mut entries = Vec::with_capacity(self.entries.len());
+        for j in 0..self.cols {
+            for i in 0..self.rows {
+                entries.push(self[(i, j)].clone());
+            }
+        }
+        Matrix {
+            rows: self.cols,
+            cols: self.rows,
+            entries,
+        }
+    }
+}
+
+impl<T: Clone + Zero> Matrix<T> {
+    /// Returns the `rows` by `cols` zero matrix.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `rows * cols`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_q::matrix::Matrix;
+    /// use malachite_q::Rational;
+    ///
+    /// assert_eq!(Matrix::<Rational>::zero(2, 2).into_rows(), [[0, 0], [0, 0]]);
+    /// ```
+    pub fn zero(rows: usize, cols: usize) -> Matrix<T> {
+        Matrix {
+            rows,
+            cols,
+            entries: vec![T::ZERO; rows * cols],
+        }
+    }
+}
+
+impl<T: Clone + One + Zero> Matrix<T> {
+    /// Returns the $n \times n$ identity matrix.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2)$
+    ///
+    /// $M(n) = O(n^2)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `n`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_q::matrix::Matrix;
+    /// use malachite_q::Rational;
+    ///
+    /// assert_eq!(Matrix::<Rational>::identity(2).into_rows(), [[1, 0], [0, 1]]);
+    /// ```
+    pub fn identity(n: usize) -> Matrix<T> {
+        let mut m = Matrix::zero(n, n);
+        for i in 0..n {
+            m[(i, i)] = T::ONE;
+        }
+        m
+    }
+}
+
+impl<T> Index<(usize, usize)> for Matrix<T> {
+    type Output = T;
+
+    /// Returns the entry of a [`Matrix`] in row `i` and column `j`.
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Panics
+    /// Panics if `i` or `j` is out of range.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::integer::Integer;
+    /// use malachite_q::matrix::Matrix;
+    ///
+    /// assert_eq!(Matrix::<Integer>::identity(2)[(0, 0)], 1);
+    /// assert_eq!(Matrix::<Integer>::identity(2)[(0, 1)], 0);
+    /// ```
+    fn index(&self, (i, j): (usize, usize)) -> &T {
+        assert!(i < self.rows && j < self.cols, "Index out of range");
+        &self.entries[i * self.cols + j]
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Matrix<T> {
+    /// Returns a mutable reference to the entry of a [`Matrix`] in row `i` and column `j`.
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Panics
+    /// Panics if `i` or `j` is out of range.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::integer::Integer;
+    /// use malachite_q::matrix::Matrix;
+    ///
+    /// let mut m = Matrix::<Integer>::identity(2);
+    /// m[(0, 1)] = Integer::from(5);
+    /// assert_eq!(m.into_rows(), [[1, 5], [0, 1]]);
+    /// ```
+    fn index_mut(&mut self, (i, j): (usize, usize)) -> &mut T {
+        assert!(i < self.rows && j < self.cols, "Index out of range");
+        &mut self.entries[i * self.cols + j]
+    }
+}
+
+impl<'a> From<&'a Matrix<Integer>> for Matrix<Rational> {
+    /// Converts a [`Matrix`] of [`Integer`]s to a [`Matrix`] of [`Rational`]s, taking the
+    /// [`Matrix`] by reference.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the total number of bits in the
+    /// entries.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::integer::Integer;
+    /// use malachite_q::matrix::Matrix;
+    /// use malachite_q::Rational;
+    ///
+    /// assert_eq!(
+    ///     Matrix::<Rational>::from(&Matrix::<Integer>::identity(2)),
+    ///     Matrix::<Rational>::identity(2)
+    /// );
+    /// ```
+    fn from(m: &'a Matrix<Integer>) -> Matrix<Rational> {
+        Matrix {
+            rows: m.rows,
+            cols: m.cols,
+            entries: m.entries.iter().map(Rational::from).collect(),
+        }
+    }
+}
+
+// The result of fraction-free elimination.
+pub(crate) struct Elimination {
+    // The eliminated matrix.
+    pub(crate) m: Matrix<Integer>,
+    // The columns that contain pivots, in increasing order. The `i`th pivot is in row `i`.
+    pub(crate) pivots: Vec<usize>,
+    // The last pivot, or 1 if there are none. After a full reduction, every pivot equals `d`.
+    pub(crate) d: Integer,
+    // Whether an odd number of row swaps were performed.
+    pub(crate) negated: bool,
+}
+
+// Performs Bareiss's fraction-free Gaussian elimination on `m`, only choosing pivots from the first
+// `pivot_cols` columns.
+//
+// After each pivot $p$ in row $r$ and column $c$ is chosen, every other row $i$ is updated by
+// $$
+// a_{ij} \gets \frac{p a_{ij} - a_{ic} a_{rj}}{p'},
+// $$
+// where $p'$ is the previous pivot. The division is always exact, and each entry is a minor of the
+// original matrix, so the entries stay small. If `reduce` is `false`, only the rows below the pivot
+// are updated, producing an echelon form. If `reduce` is `true`, the rows above are updated as
+// well, and the result is $d$ times the reduced row echelon form of `m`, where $d$ is the last
+// pivot.
+pub(crate) fn fraction_free_eliminate(
+    mut m: Matrix<Integer>,
+    pivot_cols: usize,
+    reduce: bool,
+) -> Elimination {
+    let cols = m.cols;
+    let mut pivots = Vec::new();
+    let mut previous = Integer::ONE;
+    let mut negated = false;
+    let mut r = 0;
+    for c in 0..pivot_cols {
+        if r == m.rows {
+            break;
+        }
+        let i = match (r..m.rows).find(|&i| m[(i, c)] != 0u32) {
+            Some(i) => i,
+            None => continue,
+        };
+        if i != r {
+            m.swap_rows(i, r);
+            negated = !negated;
+        }
+        let pivot_row = m.row(r).to_vec();
+        let p = &pivot_row[c];
+        for i in if reduce { 0 } else { r + 1 }..m.rows {
+            if i == r {
+                continue;
+            }
+            let factor = m[(i, c)].clone();
+            // Rows below the pivot are zero to the left of column `c`.
+            let start = if i < r { 0 } else { c + 1 };
+            let row = &mut m.entries[i * cols + start..(i + 1) * cols];
+            for (j, (x, q)) in (start..).zip(row.iter_mut().zip(&pivot_row[start..])) {
+                if j == c {
+                    continue;
+                }
+                let mut y = p * &*x;
+                if factor != 0u32 {
+                    y -= &factor * q;
+                }
+                *x = y.div_exact(&previous);
+            }
+            m.entries[i * cols + c] = Integer::ZERO;
+        }
+        previous = pivot_row[c].clone();
+        pivots.push(c);
+        r += 1;
+    }
+    Elimination {
+        m,
+        pivots,
+        d: previous,
+        negated,
+    }
+}
+
+// Multiplies each row of a `Matrix<Rational>` by the least common multiple of its denominators,
+// producing a `Matrix<Integer>` with the same row space. Also returns the product of the
+// multipliers.
+pub(crate) fn clear_denominators(m: &Matrix<Rational>) -> (Matrix<Integer>, Natural) {
+    let mut entries = Vec::with_capacity(m.entries.len());
+    let mut product = Natural::ONE;
+    for i in 0..m.rows {
+        let row = m.row(i);
+        let lcm = row
+            .iter()
+            .fold(Natural::ONE, |lcm, x| lcm.lcm(&x.denominator));
+        for x in row {
+            entries.push(Integer::from_sign_and_abs(
+                x.sign,
+                &x.numerator * (&lcm).div_exact(&x.denominator),
+            ));
+        }
+        product *= lcm;
+    }
+    (
+        Matrix {
+            rows: m.rows,
+            cols: m.cols,
+            entries,
+        },
+        product,
+    )
+}
+
+/// Determinants and ranks of [`Matrix`]es.
+pub mod determinant;
+/// Multiplication of [`Matrix`]es.
+pub mod mul;
+/// Reduced row echelon forms and nullspaces of [`Matrix`]es.
+pub mod rref;
+/// Inverses of [`Matrix`]es and solutions of linear systems.
+pub mod solve;
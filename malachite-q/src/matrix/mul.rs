@@ -0,0 +1,376 @@
+use crate::matrix::Matrix;
+use crate::Rational;
+use malachite_base::num::basic::traits::Zero;
+use malachite_nz::integer::Integer;
+use std::ops::{AddAssign, Mul};
+
+fn mul_helper<T>(xs: &Matrix<T>, ys: &Matrix<T>) -> Matrix<T>
+where
+    T: AddAssign + Zero,
+    for<'a> &'a T: Mul<&'a T, Output = T>,
+{
+    assert_eq!(
+        xs.cols, ys.rows,
+        "The number of columns of the first matrix must equal the number of rows of the second"
+    );
+    let mut entries = Vec::with_capacity(xs.rows * ys.cols);
+    for i in 0..xs.rows {
+        let row = xs.row(i);
+        for j in 0..ys.cols {
+            let mut sum = T::ZERO;
+            for (k, x) in row.iter().enumerate() {
+                sum += x * &ys.entries[k * ys.cols + j];
+            }
+            entries.push(sum);
+        }
+    }
+    Matrix {
+        rows: xs.rows,
+        cols: ys.cols,
+        entries,
+    }
+}
+
+impl Mul<Matrix<Integer>> for Matrix<Integer> {
+    type Output = Matrix<Integer>;
+
+    /// Multiplies two [`Matrix`]es with [`Integer`] entries, taking both by value.
+    ///
+    /// $$
+    /// f(A, B) = AB.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m, p, b) = O(nmp M(b))$
+    ///
+    /// $M(n, p, b) = O(npb)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $A$ is $n \times m$, $B$ is $m \times p$,
+    /// $b$ is the maximum number of bits in an entry, and $M(b)$ is the time needed to multiply
+    /// two $b$-bit numbers.
+    ///
+    /// # Panics
+    /// Panics if the number of columns of `self` is not equal to the number of rows of `other`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::integer::Integer;
+    /// use malachite_q::matrix::Matrix;
+    ///
+    /// let a = Matrix::from_rows(vec![
+    ///     vec![Integer::from(1), Integer::from(2)],
+    ///     vec![Integer::from(3), Integer::from(4)],
+    /// ]);
+    /// let b = Matrix::from_rows(vec![
+    ///     vec![Integer::from(5), Integer::from(6), Integer::from(7)],
+    ///     vec![Integer::from(8), Integer::from(9), Integer::from(10)],
+    /// ]);
+    /// assert_eq!((a * b).into_rows(), [[21, 24, 27], [47, 54, 61]]);
+    /// ```
+    #[inline]
+    fn mul(self, other: Matrix<Integer>) -> Matrix<Integer> {
+        mul_helper(&self, &other)
+    }
+}
+
+impl<'a> Mul<&'a Matrix<Integer>> for Matrix<Integer> {
+    type Output = Matrix<Integer>;
+
+    /// Multiplies two [`Matrix`]es with [`Integer`] entries, taking the first by value and the
+    /// second by reference.
+    ///
+    /// $$
+    /// f(A, B) = AB.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m, p, b) = O(nmp M(b))$
+    ///
+    /// $M(n, p, b) = O(npb)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $A$ is $n \times m$, $B$ is $m \times p$,
+    /// $b$ is the maximum number of bits in an entry, and $M(b)$ is the time needed to multiply
+    /// two $b$-bit numbers.
+    ///
+    /// # Panics
+    /// Panics if the number of columns of `self` is not equal to the number of rows of `other`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::integer::Integer;
+    /// use malachite_q::matrix::Matrix;
+    ///
+    /// let a = Matrix::from_rows(vec![
+    ///     vec![Integer::from(1), Integer::from(2)],
+    ///     vec![Integer::from(3), Integer::from(4)],
+    /// ]);
+    /// let b = Matrix::from_rows(vec![
+    ///     vec![Integer::from(5), Integer::from(6), Integer::from(7)],
+    ///     vec![Integer::from(8), Integer::from(9), Integer::from(10)],
+    /// ]);
+    /// assert_eq!((a * &b).into_rows(), [[21, 24, 27], [47, 54, 61]]);
+    /// ```
+    #[inline]
+    fn mul(self, other: &'a Matrix<Integer>) -> Matrix<Integer> {
+        mul_helper(&self, other)
+    }
+}
+
+impl<'a> Mul<Matrix<Integer>> for &'a Matrix<Integer> {
+    type Output = Matrix<Integer>;
+
+    /// Multiplies two [`Matrix`]es with [`Integer`] entries, taking the first by reference and the
+    /// second by value.
+    ///
+    /// $$
+    /// f(A, B) = AB.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m, p, b) = O(nmp M(b))$
+    ///
+    /// $M(n, p, b) = O(npb)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $A$ is $n \times m$, $B$ is $m \times p$,
+    /// $b$ is the maximum number of bits in an entry, and $M(b)$ is the time needed to multiply
+    /// two $b$-bit numbers.
+    ///
+    /// # Panics
+    /// Panics if the number of columns of `self` is not equal to the number of rows of `other`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::integer::Integer;
+    /// use malachite_q::matrix::Matrix;
+    ///
+    /// let a = Matrix::from_rows(vec![
+    ///     vec![Integer::from(1), Integer::from(2)],
+    ///     vec![Integer::from(3), Integer::from(4)],
+    /// ]);
+    /// let b = Matrix::from_rows(vec![
+    ///     vec![Integer::from(5), Integer::from(6), Integer::from(7)],
+    ///     vec![Integer::from(8), Integer::from(9), Integer::from(10)],
+    /// ]);
+    /// assert_eq!((&a * b).into_rows(), [[21, 24, 27], [47, 54, 61]]);
+    /// ```
+    #[inline]
+    fn mul(self, other: Matrix<Integer>) -> Matrix<Integer> {
+        mul_helper(self, &other)
+    }
+}
+
+impl<'a, 'b> Mul<&'a Matrix<Integer>> for &'b Matrix<Integer> {
+    type Output = Matrix<Integer>;
+
+    /// Multiplies two [`Matrix`]es with [`Integer`] entries, taking both by reference.
+    ///
+    /// $$
+    /// f(A, B) = AB.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m, p, b) = O(nmp M(b))$
+    ///
+    /// $M(n, p, b) = O(npb)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $A$ is $n \times m$, $B$ is $m \times p$,
+    /// $b$ is the maximum number of bits in an entry, and $M(b)$ is the time needed to multiply
+    /// two $b$-bit numbers.
+    ///
+    /// # Panics
+    /// Panics if the number of columns of `self` is not equal to the number of rows of `other`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::integer::Integer;
+    /// use malachite_q::matrix::Matrix;
+    ///
+    /// let a = Matrix::from_rows(vec![
+    ///     vec![Integer::from(1), Integer::from(2)],
+    ///     vec![Integer::from(3), Integer::from(4)],
+    /// ]);
+    /// let b = Matrix::from_rows(vec![
+    ///     vec![Integer::from(5), Integer::from(6), Integer::from(7)],
+    ///     vec![Integer::from(8), Integer::from(9), Integer::from(10)],
+    /// ]);
+    /// assert_eq!((&a * &b).into_rows(), [[21, 24, 27], [47, 54, 61]]);
+    /// ```
+    #[inline]
+    fn mul(self, other: &'a Matrix<Integer>) -> Matrix<Integer> {
+        mul_helper(self, other)
+    }
+}
+
+impl Mul<Matrix<Rational>> for Matrix<Rational> {
+    type Output = Matrix<Rational>;
+
+    /// Multiplies two [`Matrix`]es with [`Rational`] entries, taking both by value.
+    ///
+    /// $$
+    /// f(A, B) = AB.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m, p, b) = O(nmp M(b))$
+    ///
+    /// $M(n, p, b) = O(npb)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $A$ is $n \times m$, $B$ is $m \times p$,
+    /// $b$ is the maximum number of bits in an entry, and $M(b)$ is the time needed to multiply
+    /// two $b$-bit numbers.
+    ///
+    /// # Panics
+    /// Panics if the number of columns of `self` is not equal to the number of rows of `other`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::strings::ToDebugString;
+    /// use malachite_q::matrix::Matrix;
+    /// use malachite_q::Rational;
+    ///
+    /// let a = Matrix::from_rows(vec![
+    ///     vec![Rational::from_signeds(1, 2), Rational::from(2)],
+    ///     vec![Rational::from(3), Rational::from_signeds(-1, 3)],
+    /// ]);
+    /// let b = Matrix::from_rows(vec![
+    ///     vec![Rational::from(4)],
+    ///     vec![Rational::from_signeds(1, 4)],
+    /// ]);
+    /// assert_eq!((a * b).into_rows().to_debug_string(), "[[5/2], [143/12]]");
+    /// ```
+    #[inline]
+    fn mul(self, other: Matrix<Rational>) -> Matrix<Rational> {
+        mul_helper(&self, &other)
+    }
+}
+
+impl<'a> Mul<&'a Matrix<Rational>> for Matrix<Rational> {
+    type Output = Matrix<Rational>;
+
+    /// Multiplies two [`Matrix`]es with [`Rational`] entries, taking the first by value and the
+    /// second by reference.
+    ///
+    /// $$
+    /// f(A, B) = AB.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m, p, b) = O(nmp M(b))$
+    ///
+    /// $M(n, p, b) = O(npb)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $A$ is $n \times m$, $B$ is $m \times p$,
+    /// $b$ is the maximum number of bits in an entry, and $M(b)$ is the time needed to multiply
+    /// two $b$-bit numbers.
+    ///
+    /// # Panics
+    /// Panics if the number of columns of `self` is not equal to the number of rows of `other`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::strings::ToDebugString;
+    /// use malachite_q::matrix::Matrix;
+    /// use malachite_q::Rational;
+    ///
+    /// let a = Matrix::from_rows(vec![
+    ///     vec![Rational::from_signeds(1, 2), Rational::from(2)],
+    ///     vec![Rational::from(3), Rational::from_signeds(-1, 3)],
+    /// ]);
+    /// let b = Matrix::from_rows(vec![
+    ///     vec![Rational::from(4)],
+    ///     vec![Rational::from_signeds(1, 4)],
+    /// ]);
+    /// assert_eq!((a * &b).into_rows().to_debug_string(), "[[5/2], [143/12]]");
+    /// ```
+    #[inline]
+    fn mul(self, other: &'a Matrix<Rational>) -> Matrix<Rational> {
+        mul_helper(&self, other)
+    }
+}
+
+impl<'a> Mul<Matrix<Rational>> for &'a Matrix<Rational> {
+    type Output = Matrix<Rational>;
+
+    /// Multiplies two [`Matrix`]es with [`Rational`] entries, taking the first by reference and the
+    /// second by value.
+    ///
+    /// $$
+    /// f(A, B) = AB.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m, p, b) = O(nmp M(b))$
+    ///
+    /// $M(n, p, b) = O(npb)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $A$ is $n \times m$, $B$ is $m \times p$,
+    /// $b$ is the maximum number of bits in an entry, and $M(b)$ is the time needed to multiply
+    /// two $b$-bit numbers.
+    ///
+    /// # Panics
+    /// Panics if the number of columns of `self` is not equal to the number of rows of `other`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::strings::ToDebugString;
+    /// use malachite_q::matrix::Matrix;
+    /// use malachite_q::Rational;
+    ///
+    /// let a = Matrix::from_rows(vec![
+    ///     vec![Rational::from_signeds(1, 2), Rational::from(2)],
+    ///     vec![Rational::from(3), Rational::from_signeds(-1, 3)],
+    /// ]);
+    /// let b = Matrix::from_rows(vec![
+    ///     vec![Rational::from(4)],
+    ///     vec![Rational::from_signeds(1, 4)],
+    /// ]);
+    /// assert_eq!((&a * b).into_rows().to_debug_string(), "[[5/2], [143/12]]");
+    /// ```
+    #[inline]
+    fn mul(self, other: Matrix<Rational>) -> Matrix<Rational> {
+        mul_helper(self, &other)
+    }
+}
+
+impl<'a, 'b> Mul<&'a Matrix<Rational>> for &'b Matrix<Rational> {
+    type Output = Matrix<Rational>;
+
+    /// Multiplies two [`Matrix`]es with [`Rational`] entries, taking both by reference.
+    ///
+    /// $$
+    /// f(A, B) = AB.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m, p, b) = O(nmp M(b))$
+    ///
+    /// $M(n, p, b) = O(npb)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $A$ is $n \times m$, $B$ is $m \times p$,
+    /// $b$ is the maximum number of bits in an entry, and $M(b)$ is the time needed to multiply
+    /// two $b$-bit numbers.
+    ///
+    /// # Panics
+    /// Panics if the number of columns of `self` is not equal to the number of rows of `other`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::strings::ToDebugString;
+    /// use malachite_q::matrix::Matrix;
+    /// use malachite_q::Rational;
+    ///
+    /// let a = Matrix::from_rows(vec![
+    ///     vec![Rational::from_signeds(1, 2), Rational::from(2)],
+    ///     vec![Rational::from(3), Rational::from_signeds(-1, 3)],
+    /// ]);
+    /// let b = Matrix::from_rows(vec![
+    ///     vec![Rational::from(4)],
+    ///     vec![Rational::from_signeds(1, 4)],
+    /// ]);
+    /// assert_eq!((&a * &b).into_rows().to_debug_string(), "[[5/2], [143/12]]");
+    /// ```
+    #[inline]
+    fn mul(self, other: &'a Matrix<Rational>) -> Matrix<Rational> {
+        mul_helper(self, other)
+    }
+}
@@ -0,0 +1,219 @@
+use crate::matrix::{clear_denominators, fraction_free_eliminate, Elimination, Matrix};
+use crate::Rational;
+use malachite_base::num::arithmetic::traits::{DivExactAssign, Gcd};
+use malachite_base::num::basic::traits::Zero;
+use malachite_nz::integer::Integer;
+use malachite_nz::natural::Natural;
+
+fn rref_helper(m: Matrix<Integer>) -> Matrix<Rational> {
+    let cols = m.cols;
+    let Elimination { m, pivots, d, .. } = fraction_free_eliminate(m, cols, true);
+    let rank = pivots.len();
+    let d = &d;
+    Matrix {
+        rows: m.rows,
+        cols,
+        entries: m
+            .entries
+            .into_iter()
+            .enumerate()
+            .map(|(k, x)| {
+                if k / cols < rank {
+                    Rational::from_integers(x, d.clone())
+                } else {
+                    Rational::ZERO
+                }
+            })
+            .collect(),
+    }
+}
+
+// Returns a basis of the nullspace of `m`, where each vector is $d$ times the vector whose entry
+// at a free column is 1, and whose other free entries are 0.
+fn nullspace_helper(m: Matrix<Integer>) -> (Vec<Vec<Integer>>, Integer) {
+    let cols = m.cols;
+    let Elimination { m, pivots, d, .. } = fraction_free_eliminate(m, cols, true);
+    let mut basis = Vec::new();
+    let mut pivot_index = 0;
+    for f in 0..cols {
+        if pivot_index < pivots.len() && pivots[pivot_index] == f {
+            pivot_index += 1;
+            continue;
+        }
+        let mut v = vec![Integer::ZERO; cols];
+        v[f] = d.clone();
+        for (i, &c) in pivots.iter().enumerate() {
+            v[c] = -&m[(i, f)];
+        }
+        basis.push(v);
+    }
+    (basis, d)
+}
+
+impl Matrix<Integer> {
+    /// Computes the reduced row echelon form of a [`Matrix`] of [`Integer`]s.
+    ///
+    /// The fraction-free Gauss-Jordan variant of Bareiss's elimination produces $d$ times the
+    /// reduced row echelon form, where $d$ is the last pivot, using only exact [`Integer`]
+    /// divisions. Each entry is then divided by $d$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m, b) = O(n^2 m M(\min(n, m) b))$
+    ///
+    /// $M(n, m, b) = O(nm \min(n, m) b)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, the matrix is $n \times m$, $b$ is the maximum
+    /// number of bits in an entry, and $M(k)$ is the time needed to multiply two $k$-bit numbers.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::strings::ToDebugString;
+    /// use malachite_nz::integer::Integer;
+    /// use malachite_q::matrix::Matrix;
+    ///
+    /// let m = Matrix::from_rows(vec![
+    ///     vec![Integer::from(1), Integer::from(2), Integer::from(3)],
+    ///     vec![Integer::from(4), Integer::from(5), Integer::from(6)],
+    ///     vec![Integer::from(7), Integer::from(8), Integer::from(10)],
+    /// ]);
+    /// assert_eq!(m.rref(), Matrix::identity(3));
+    ///
+    /// let m = Matrix::from_rows(vec![
+    ///     vec![Integer::from(2), Integer::from(4), Integer::from(1)],
+    ///     vec![Integer::from(3), Integer::from(6), Integer::from(2)],
+    /// ]);
+    /// assert_eq!(m.rref().into_rows().to_debug_string(), "[[1, 2, 0], [0, 0, 1]]");
+    ///
+    /// let m = Matrix::from_rows(vec![
+    ///     vec![Integer::from(3), Integer::from(1)],
+    ///     vec![Integer::from(6), Integer::from(2)],
+    /// ]);
+    /// assert_eq!(m.rref().into_rows().to_debug_string(), "[[1, 1/3], [0, 0]]");
+    /// ```
+    #[inline]
+    pub fn rref(&self) -> Matrix<Rational> {
+        rref_helper(self.clone())
+    }
+
+    /// Computes a basis of the (right) nullspace of a [`Matrix`] of [`Integer`]s; that is, of the
+    /// set of vectors $x$ such that $Ax = 0$.
+    ///
+    /// There is one basis vector for each column without a pivot in the reduced row echelon form.
+    /// Each vector has [`Integer`] entries with no common factor, and its entry at its own
+    /// non-pivot column is positive, while its entries at the other non-pivot columns are zero.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m, b) = O(n^2 m M(\min(n, m) b))$
+    ///
+    /// $M(n, m, b) = O(nm \min(n, m) b)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, the matrix is $n \times m$, $b$ is the maximum
+    /// number of bits in an entry, and $M(k)$ is the time needed to multiply two $k$-bit numbers.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::strings::ToDebugString;
+    /// use malachite_nz::integer::Integer;
+    /// use malachite_q::matrix::Matrix;
+    ///
+    /// let m = Matrix::from_rows(vec![
+    ///     vec![Integer::from(1), Integer::from(2), Integer::from(3)],
+    ///     vec![Integer::from(4), Integer::from(5), Integer::from(6)],
+    /// ]);
+    /// assert_eq!(m.nullspace().to_debug_string(), "[[1, -2, 1]]");
+    ///
+    /// let m = Matrix::from_rows(vec![vec![Integer::from(2), Integer::from(3), Integer::from(4)]]);
+    /// assert_eq!(m.nullspace().to_debug_string(), "[[-3, 2, 0], [-2, 0, 1]]");
+    /// ```
+    pub fn nullspace(&self) -> Vec<Vec<Integer>> {
+        let (mut basis, d) = nullspace_helper(self.clone());
+        for v in &mut basis {
+            let g = v
+                .iter()
+                .fold(Natural::ZERO, |g, x| g.gcd(x.unsigned_abs_ref()));
+            let g = if d < 0u32 {
+                -Integer::from(g)
+            } else {
+                Integer::from(g)
+            };
+            for x in v.iter_mut() {
+                x.div_exact_assign(&g);
+            }
+        }
+        basis
+    }
+}
+
+impl Matrix<Rational> {
+    /// Computes the reduced row echelon form of a [`Matrix`] of [`Rational`]s.
+    ///
+    /// Each row is multiplied by the least common multiple of its denominators, and the resulting
+    /// [`Integer`] matrix is reduced using the fraction-free Gauss-Jordan variant of Bareiss's
+    /// elimination. The only divisions of [`Rational`]s happen at the end, when each entry is
+    /// divided by the last pivot.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m, b) = O(n^2 m M(\min(n, m) mb))$
+    ///
+    /// $M(n, m, b) = O(nm^2 \min(n, m) b)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, the matrix is $n \times m$, $b$ is the maximum
+    /// number of bits in an entry, and $M(k)$ is the time needed to multiply two $k$-bit numbers.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::strings::ToDebugString;
+    /// use malachite_q::matrix::Matrix;
+    /// use malachite_q::Rational;
+    ///
+    /// let m = Matrix::from_rows(vec![
+    ///     vec![Rational::from_signeds(1, 2), Rational::from_signeds(1, 3), Rational::from(1)],
+    ///     vec![Rational::from_signeds(1, 4), Rational::from_signeds(1, 5), Rational::from(2)],
+    /// ]);
+    /// assert_eq!(m.rref().into_rows().to_debug_string(), "[[1, 0, -28], [0, 1, 45]]");
+    /// ```
+    #[inline]
+    pub fn rref(&self) -> Matrix<Rational> {
+        rref_helper(clear_denominators(self).0)
+    }
+
+    /// Computes a basis of the (right) nullspace of a [`Matrix`] of [`Rational`]s; that is, of
+    /// the set of vectors $x$ such that $Ax = 0$.
+    ///
+    /// There is one basis vector for each column without a pivot in the reduced row echelon form.
+    /// Each vector's entry at its own non-pivot column is 1, and its entries at the other non-pivot
+    /// columns are zero.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m, b) = O(n^2 m M(\min(n, m) mb))$
+    ///
+    /// $M(n, m, b) = O(nm^2 \min(n, m) b)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, the matrix is $n \times m$, $b$ is the maximum
+    /// number of bits in an entry, and $M(k)$ is the time needed to multiply two $k$-bit numbers.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::strings::ToDebugString;
+    /// use malachite_q::matrix::Matrix;
+    /// use malachite_q::Rational;
+    ///
+    /// let m = Matrix::from_rows(vec![vec![
+    ///     Rational::from(2),
+    ///     Rational::from(3),
+    ///     Rational::from_signeds(1, 2),
+    /// ]]);
+    /// assert_eq!(m.nullspace().to_debug_string(), "[[-3/2, 1, 0], [-1/4, 0, 1]]");
+    /// ```
+    pub fn nullspace(&self) -> Vec<Vec<Rational>> {
+        let (basis, d) = nullspace_helper(clear_denominators(self).0);
+        basis
+            .into_iter()
+            .map(|v| {
+                v.into_iter()
+                    .map(|x| Rational::from_integers_ref(&x, &d))
+                    .collect()
+            })
+            .collect()
+    }
+}
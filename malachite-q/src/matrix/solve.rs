@@ -0,0 +1,238 @@
+use crate::matrix::{clear_denominators, fraction_free_eliminate, Elimination, Matrix};
+use crate::Rational;
+use malachite_nz::integer::Integer;
+
+// Returns $[A | B]$.
+fn augment<T: Clone>(a: &Matrix<T>, b: &Matrix<T>) -> Matrix<T> {
+    assert_eq!(
+        a.rows, b.rows,
+        "The right-hand side must have as many rows as the matrix"
+    );
+    let mut entries = Vec::with_capacity(a.entries.len() + b.entries.len());
+    for i in 0..a.rows {
+        entries.extend_from_slice(a.row(i));
+        entries.extend_from_slice(b.row(i));
+    }
+    Matrix {
+        rows: a.rows,
+        cols: a.cols + b.cols,
+        entries,
+    }
+}
+
+fn column<T>(xs: Vec<T>) -> Matrix<T> {
+    Matrix {
+        rows: xs.len(),
+        cols: 1,
+        entries: xs,
+    }
+}
+
+// Given $[A | B]$, where $A$ has `cols` columns, finds a solution $X$ of $AX = B$, with every entry
+// corresponding to a non-pivot column of $A$ set to zero. Returns `None` if there is no solution.
+// Also returns the rank of $A$.
+fn solve_augmented(m: Matrix<Integer>, cols: usize) -> (Option<Matrix<Rational>>, usize) {
+    let rows = m.rows;
+    let rhs_cols = m.cols - cols;
+    let Elimination { m, pivots, d, .. } = fraction_free_eliminate(m, cols, true);
+    let rank = pivots.len();
+    // The rows below the last pivot are zero in $A$, so they must be zero in $B$ as well.
+    for i in rank..rows {
+        if m.row(i)[cols..].iter().any(|x| *x != 0u32) {
+            return (None, rank);
+        }
+    }
+    let mut x = Matrix::zero(cols, rhs_cols);
+    for (i, &c) in pivots.iter().enumerate() {
+        for j in 0..rhs_cols {
+            x[(c, j)] = Rational::from_integers_ref(&m[(i, cols + j)], &d);
+        }
+    }
+    (Some(x), rank)
+}
+
+impl Matrix<Integer> {
+    /// Finds a solution $x$ of $Ax = b$, where $A$ is a [`Matrix`] of [`Integer`]s, or returns
+    /// `None` if there is none.
+    ///
+    /// If there is more than one solution, the one whose entries at the non-pivot columns of the
+    /// reduced row echelon form of $A$ are zero is returned. Every other solution differs from it by
+    /// an element of the [`nullspace`](Matrix::nullspace).
+    ///
+    /// The augmented matrix $[A | b]$ is reduced using the fraction-free Gauss-Jordan variant of
+    /// Bareiss's elimination, so the only non-[`Integer`] divisions happen at the end.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m, b) = O(n^2 m M(\min(n, m) b))$
+    ///
+    /// $M(n, m, b) = O(nm \min(n, m) b)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, the matrix is $n \times m$, $b$ is the maximum
+    /// number of bits in an entry, and $M(k)$ is the time needed to multiply two $k$-bit numbers.
+    ///
+    /// # Panics
+    /// Panics if the length of `b` is not equal to the number of rows of `self`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::strings::ToDebugString;
+    /// use malachite_nz::integer::Integer;
+    /// use malachite_q::matrix::Matrix;
+    ///
+    /// let m = Matrix::from_rows(vec![
+    ///     vec![Integer::from(2), Integer::from(1)],
+    ///     vec![Integer::from(1), Integer::from(3)],
+    /// ]);
+    /// assert_eq!(
+    ///     m.solve(&[Integer::from(1), Integer::from(2)]).to_debug_string(),
+    ///     "Some([1/5, 3/5])"
+    /// );
+    ///
+    /// let m = Matrix::from_rows(vec![
+    ///     vec![Integer::from(1), Integer::from(2)],
+    ///     vec![Integer::from(2), Integer::from(4)],
+    /// ]);
+    /// assert_eq!(
+    ///     m.solve(&[Integer::from(3), Integer::from(6)]).to_debug_string(),
+    ///     "Some([3, 0])"
+    /// );
+    /// assert_eq!(m.solve(&[Integer::from(3), Integer::from(5)]), None);
+    /// ```
+    pub fn solve(&self, b: &[Integer]) -> Option<Vec<Rational>> {
+        let m = augment(self, &column(b.to_vec()));
+        solve_augmented(m, self.cols).0.map(|x| x.entries)
+    }
+
+    /// Computes the inverse of a square [`Matrix`] of [`Integer`]s, or returns `None` if the
+    /// [`Matrix`] is singular.
+    ///
+    /// The augmented matrix $[A | I]$ is reduced using the fraction-free Gauss-Jordan variant of
+    /// Bareiss's elimination, producing $[dI | dA^{-1}]$ with only exact [`Integer`] divisions.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, b) = O(n^3 M(nb))$
+    ///
+    /// $M(n, b) = O(n^3 b)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, the matrix is $n \times n$, $b$ is the maximum
+    /// number of bits in an entry, and $M(k)$ is the time needed to multiply two $k$-bit numbers.
+    ///
+    /// # Panics
+    /// Panics if `self` is not square.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::strings::ToDebugString;
+    /// use malachite_nz::integer::Integer;
+    /// use malachite_q::matrix::Matrix;
+    ///
+    /// let m = Matrix::from_rows(vec![
+    ///     vec![Integer::from(2), Integer::from(1)],
+    ///     vec![Integer::from(1), Integer::from(3)],
+    /// ]);
+    /// assert_eq!(
+    ///     m.inverse().unwrap().into_rows().to_debug_string(),
+    ///     "[[3/5, -1/5], [-1/5, 2/5]]"
+    /// );
+    ///
+    /// let m = Matrix::from_rows(vec![
+    ///     vec![Integer::from(1), Integer::from(2)],
+    ///     vec![Integer::from(2), Integer::from(4)],
+    /// ]);
+    /// assert_eq!(m.inverse(), None);
+    /// ```
+    pub fn inverse(&self) -> Option<Matrix<Rational>> {
+        assert!(self.is_square(), "Only square matrices have inverses");
+        let m = augment(self, &Matrix::identity(self.rows));
+        match solve_augmented(m, self.cols) {
+            (Some(x), rank) if rank == self.rows => Some(x),
+            _ => None,
+        }
+    }
+}
+
+impl Matrix<Rational> {
+    /// Finds a solution $x$ of $Ax = b$, where $A$ is a [`Matrix`] of [`Rational`]s, or returns
+    /// `None` if there is none.
+    ///
+    /// If there is more than one solution, the one whose entries at the non-pivot columns of the
+    /// reduced row echelon form of $A$ are zero is returned. Every other solution differs from it by
+    /// an element of the [`nullspace`](Matrix::nullspace).
+    ///
+    /// Each row of the augmented matrix $[A | b]$ is multiplied by the least common multiple of its
+    /// denominators, and the resulting [`Integer`] matrix is reduced using the fraction-free
+    /// Gauss-Jordan variant of Bareiss's elimination.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m, b) = O(n^2 m M(\min(n, m) mb))$
+    ///
+    /// $M(n, m, b) = O(nm^2 \min(n, m) b)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, the matrix is $n \times m$, $b$ is the maximum
+    /// number of bits in an entry, and $M(k)$ is the time needed to multiply two $k$-bit numbers.
+    ///
+    /// # Panics
+    /// Panics if the length of `b` is not equal to the number of rows of `self`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::strings::ToDebugString;
+    /// use malachite_q::matrix::Matrix;
+    /// use malachite_q::Rational;
+    ///
+    /// let m = Matrix::from_rows(vec![
+    ///     vec![Rational::from_signeds(1, 2), Rational::from_signeds(1, 3)],
+    ///     vec![Rational::from_signeds(1, 4), Rational::from_signeds(1, 5)],
+    /// ]);
+    /// assert_eq!(
+    ///     m.solve(&[Rational::from(1), Rational::from(1)]).to_debug_string(),
+    ///     "Some([-8, 15])"
+    /// );
+    /// ```
+    pub fn solve(&self, b: &[Rational]) -> Option<Vec<Rational>> {
+        let (m, _) = clear_denominators(&augment(self, &column(b.to_vec())));
+        solve_augmented(m, self.cols).0.map(|x| x.entries)
+    }
+
+    /// Computes the inverse of a square [`Matrix`] of [`Rational`]s, or returns `None` if the
+    /// [`Matrix`] is singular.
+    ///
+    /// Each row of the augmented matrix $[A | I]$ is multiplied by the least common multiple of its
+    /// denominators, and the resulting [`Integer`] matrix is reduced using the fraction-free
+    /// Gauss-Jordan variant of Bareiss's elimination.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, b) = O(n^3 M(n^2 b))$
+    ///
+    /// $M(n, b) = O(n^4 b)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, the matrix is $n \times n$, $b$ is the maximum
+    /// number of bits in an entry, and $M(k)$ is the time needed to multiply two $k$-bit numbers.
+    ///
+    /// # Panics
+    /// Panics if `self` is not square.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::strings::ToDebugString;
+    /// use malachite_q::matrix::Matrix;
+    /// use malachite_q::Rational;
+    ///
+    /// let m = Matrix::from_rows(vec![
+    ///     vec![Rational::from_signeds(1, 2), Rational::from_signeds(1, 3)],
+    ///     vec![Rational::from_signeds(1, 4), Rational::from_signeds(1, 5)],
+    /// ]);
+    /// assert_eq!(
+    ///     m.inverse().unwrap().into_rows().to_debug_string(),
+    ///     "[[12, -20], [-15, 30]]"
+    /// );
+    /// ```
+    pub fn inverse(&self) -> Option<Matrix<Rational>> {
+        assert!(self.is_square(), "Only square matrices have inverses");
+        let (m, _) = clear_denominators(&augment(self, &Matrix::identity(self.rows)));
+        match solve_augmented(m, self.cols) {
+            (Some(x), rank) if rank == self.rows => Some(x),
+            _ => None,
+        }
+    }
+}
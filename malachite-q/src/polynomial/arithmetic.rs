@@ -0,0 +1,1049 @@
+use crate::polynomial::{trim, Coefficient, Polynomial};
+use crate::Rational;
+use malachite_nz::integer::Integer;
+use malachite_nz::natural::arithmetic::mod_natural::ModNatural;
+use std::ops::{Add, Neg, Sub};
+
+fn add_helper<T: Coefficient>(xs: &[T], ys: &[T]) -> Polynomial<T>
+where
+    for<'a> &'a T: Add<&'a T, Output = T>,
+{
+    T::check_compatible(xs, ys);
+    let (xs, ys) = if xs.len() >= ys.len() {
+        (xs, ys)
+    } else {
+        (ys, xs)
+    };
+    let mut coeffs: Vec<T> = xs.iter().zip(ys).map(|(x, y)| x + y).collect();
+    coeffs.extend_from_slice(&xs[ys.len()..]);
+    trim(&mut coeffs);
+    Polynomial { coeffs }
+}
+
+fn sub_helper<T: Coefficient>(xs: &[T], ys: &[T]) -> Polynomial<T>
+where
+    for<'a> &'a T: Sub<&'a T, Output = T> + Neg<Output = T>,
+{
+    T::check_compatible(xs, ys);
+    let mut coeffs: Vec<T> = xs.iter().zip(ys).map(|(x, y)| x - y).collect();
+    if xs.len() >= ys.len() {
+        coeffs.extend_from_slice(&xs[ys.len()..]);
+    } else {
+        coeffs.extend(ys[xs.len()..].iter().map(|y| -y));
+    }
+    trim(&mut coeffs);
+    Polynomial { coeffs }
+}
+
+impl Add<Polynomial<Integer>> for Polynomial<Integer> {
+    type Output = Polynomial<Integer>;
+
+    /// Adds two [`Polynomial`]s with [`Integer`] coefficients, taking both by value.
+    ///
+    /// $$
+    /// f(p, q) = p + q.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the total number of bits in the
+    /// coefficients.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::integer::Integer;
+    /// use malachite_q::polynomial::Polynomial;
+    ///
+    /// let p = Polynomial::from(vec![Integer::from(1), Integer::from(2)]);
+    /// let q = Polynomial::from(vec![Integer::from(3), Integer::from(-2), Integer::from(1)]);
+    /// assert_eq!((p + q).to_string(), "x^2+4");
+    /// ```
+    #[inline]
+    fn add(self, other: Polynomial<Integer>) -> Polynomial<Integer> {
+        add_helper(&self.coeffs, &other.coeffs)
+    }
+}
+
+impl<'a> Add<&'a Polynomial<Integer>> for Polynomial<Integer> {
+    type Output = Polynomial<Integer>;
+
+    /// Adds two [`Polynomial`]s with [`Integer`] coefficients, taking the first by value and the
+    /// second by reference.
+    ///
+    /// $$
+    /// f(p, q) = p + q.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the total number of bits in the
+    /// coefficients.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::integer::Integer;
+    /// use malachite_q::polynomial::Polynomial;
+    ///
+    /// let p = Polynomial::from(vec![Integer::from(1), Integer::from(2)]);
+    /// let q = Polynomial::from(vec![Integer::from(3), Integer::from(-2), Integer::from(1)]);
+    /// assert_eq!((p + &q).to_string(), "x^2+4");
+    /// ```
+    #[inline]
+    fn add(self, other: &'a Polynomial<Integer>) -> Polynomial<Integer> {
+        add_helper(&self.coeffs, &other.coeffs)
+    }
+}
+
+impl<'a> Add<Polynomial<Integer>> for &'a Polynomial<Integer> {
+    type Output = Polynomial<Integer>;
+
+    /// Adds two [`Polynomial`]s with [`Integer`] coefficients, taking the first by reference and
+    /// the second by value.
+    ///
+    /// $$
+    /// f(p, q) = p + q.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the total number of bits in the
+    /// coefficients.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::integer::Integer;
+    /// use malachite_q::polynomial::Polynomial;
+    ///
+    /// let p = Polynomial::from(vec![Integer::from(1), Integer::from(2)]);
+    /// let q = Polynomial::from(vec![Integer::from(3), Integer::from(-2), Integer::from(1)]);
+    /// assert_eq!((&p + q).to_string(), "x^2+4");
+    /// ```
+    #[inline]
+    fn add(self, other: Polynomial<Integer>) -> Polynomial<Integer> {
+        add_helper(&self.coeffs, &other.coeffs)
+    }
+}
+
+impl<'a, 'b> Add<&'a Polynomial<Integer>> for &'b Polynomial<Integer> {
+    type Output = Polynomial<Integer>;
+
+    /// Adds two [`Polynomial`]s with [`Integer`] coefficients, taking both by reference.
+    ///
+    /// $$
+    /// f(p, q) = p + q.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the total number of bits in the
+    /// coefficients.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::integer::Integer;
+    /// use malachite_q::polynomial::Polynomial;
+    ///
+    /// let p = Polynomial::from(vec![Integer::from(1), Integer::from(2)]);
+    /// let q = Polynomial::from(vec![Integer::from(3), Integer::from(-2), Integer::from(1)]);
+    /// assert_eq!((&p + &q).to_string(), "x^2+4");
+    /// ```
+    #[inline]
+    fn add(self, other: &'a Polynomial<Integer>) -> Polynomial<Integer> {
+        add_helper(&self.coeffs, &other.coeffs)
+    }
+}
+
+impl Sub<Polynomial<Integer>> for Polynomial<Integer> {
+    type Output = Polynomial<Integer>;
+
+    /// Subtracts a [`Polynomial`] with [`Integer`] coefficients from another, taking both by value.
+    ///
+    /// $$
+    /// f(p, q) = p - q.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the total number of bits in the
+    /// coefficients.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::integer::Integer;
+    /// use malachite_q::polynomial::Polynomial;
+    ///
+    /// let p = Polynomial::from(vec![Integer::from(1), Integer::from(2)]);
+    /// let q = Polynomial::from(vec![Integer::from(3), Integer::from(-2), Integer::from(1)]);
+    /// assert_eq!((p - q).to_string(), "-x^2+4*x-2");
+    /// ```
+    #[inline]
+    fn sub(self, other: Polynomial<Integer>) -> Polynomial<Integer> {
+        sub_helper(&self.coeffs, &other.coeffs)
+    }
+}
+
+impl<'a> Sub<&'a Polynomial<Integer>> for Polynomial<Integer> {
+    type Output = Polynomial<Integer>;
+
+    /// Subtracts a [`Polynomial`] with [`Integer`] coefficients from another, taking the first by
+    /// value and the second by reference.
+    ///
+    /// $$
+    /// f(p, q) = p - q.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the total number of bits in the
+    /// coefficients.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::integer::Integer;
+    /// use malachite_q::polynomial::Polynomial;
+    ///
+    /// let p = Polynomial::from(vec![Integer::from(1), Integer::from(2)]);
+    /// let q = Polynomial::from(vec![Integer::from(3), Integer::from(-2), Integer::from(1)]);
+    /// assert_eq!((p - &q).to_string(), "-x^2+4*x-2");
+    /// ```
+    #[inline]
+    fn sub(self, other: &'a Polynomial<Integer>) -> Polynomial<Integer> {
+        sub_helper(&self.coeffs, &other.coeffs)
+    }
+}
+
+impl<'a> Sub<Polynomial<Integer>> for &'a Polynomial<Integer> {
+    type Output = Polynomial<Integer>;
+
+    /// Subtracts a [`Polynomial`] with [`Integer`] coefficients from another, taking the first by
+    /// reference and the second by value.
+    ///
+    /// $$
+    /// f(p, q) = p - q.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the total number of bits in the
+    /// coefficients.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::integer::Integer;
+    /// use malachite_q::polynomial::Polynomial;
+    ///
+    /// let p = Polynomial::from(vec![Integer::from(1), Integer::from(2)]);
+    /// let q = Polynomial::from(vec![Integer::from(3), Integer::from(-2), Integer::from(1)]);
+    /// assert_eq!((&p - q).to_string(), "-x^2+4*x-2");
+    /// ```
+    #[inline]
+    fn sub(self, other: Polynomial<Integer>) -> Polynomial<Integer> {
+        sub_helper(&self.coeffs, &other.coeffs)
+    }
+}
+
+impl<'a, 'b> Sub<&'a Polynomial<Integer>> for &'b Polynomial<Integer> {
+    type Output = Polynomial<Integer>;
+
+    /// Subtracts a [`Polynomial`] with [`Integer`] coefficients from another, taking both by
+    /// reference.
+    ///
+    /// $$
+    /// f(p, q) = p - q.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the total number of bits in the
+    /// coefficients.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::integer::Integer;
+    /// use malachite_q::polynomial::Polynomial;
+    ///
+    /// let p = Polynomial::from(vec![Integer::from(1), Integer::from(2)]);
+    /// let q = Polynomial::from(vec![Integer::from(3), Integer::from(-2), Integer::from(1)]);
+    /// assert_eq!((&p - &q).to_string(), "-x^2+4*x-2");
+    /// ```
+    #[inline]
+    fn sub(self, other: &'a Polynomial<Integer>) -> Polynomial<Integer> {
+        sub_helper(&self.coeffs, &other.coeffs)
+    }
+}
+
+impl Neg for Polynomial<Integer> {
+    type Output = Polynomial<Integer>;
+
+    /// Negates a [`Polynomial`] with [`Integer`] coefficients, taking it by value.
+    ///
+    /// $$
+    /// f(p) = -p.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the total number of bits in the
+    /// coefficients.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::integer::Integer;
+    /// use malachite_q::polynomial::Polynomial;
+    ///
+    /// let p = Polynomial::from(vec![Integer::from(1), Integer::from(2)]);
+    /// assert_eq!((-p).to_string(), "-2*x-1");
+    /// ```
+    #[inline]
+    fn neg(self) -> Polynomial<Integer> {
+        Polynomial {
+            coeffs: self.coeffs.into_iter().map(Neg::neg).collect(),
+        }
+    }
+}
+
+impl<'a> Neg for &'a Polynomial<Integer> {
+    type Output = Polynomial<Integer>;
+
+    /// Negates a [`Polynomial`] with [`Integer`] coefficients, taking it by reference.
+    ///
+    /// $$
+    /// f(p) = -p.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the total number of bits in the
+    /// coefficients.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::integer::Integer;
+    /// use malachite_q::polynomial::Polynomial;
+    ///
+    /// let p = Polynomial::from(vec![Integer::from(1), Integer::from(2)]);
+    /// assert_eq!((-&p).to_string(), "-2*x-1");
+    /// ```
+    #[inline]
+    fn neg(self) -> Polynomial<Integer> {
+        Polynomial {
+            coeffs: self.coeffs.iter().map(Neg::neg).collect(),
+        }
+    }
+}
+
+impl Add<Polynomial<Rational>> for Polynomial<Rational> {
+    type Output = Polynomial<Rational>;
+
+    /// Adds two [`Polynomial`]s with [`Rational`] coefficients, taking both by value.
+    ///
+    /// $$
+    /// f(p, q) = p + q.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the total number of bits in the
+    /// coefficients.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_q::polynomial::Polynomial;
+    /// use malachite_q::Rational;
+    ///
+    /// let p = Polynomial::from(vec![Rational::from_signeds(1, 2), Rational::from(1)]);
+    /// let q = Polynomial::from(vec![Rational::from_signeds(1, 3), Rational::from(-1)]);
+    /// assert_eq!((p + q).to_string(), "5/6");
+    /// ```
+    #[inline]
+    fn add(self, other: Polynomial<Rational>) -> Polynomial<Rational> {
+        add_helper(&self.coeffs, &other.coeffs)
+    }
+}
+
+impl<'a> Add<&'a Polynomial<Rational>> for Polynomial<Rational> {
+    type Output = Polynomial<Rational>;
+
+    /// Adds two [`Polynomial`]s with [`Rational`] coefficients, taking the first by value and the
+    /// second by reference.
+    ///
+    /// $$
+    /// f(p, q) = p + q.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the total number of bits in the
+    /// coefficients.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_q::polynomial::Polynomial;
+    /// use malachite_q::Rational;
+    ///
+    /// let p = Polynomial::from(vec![Rational::from_signeds(1, 2), Rational::from(1)]);
+    /// let q = Polynomial::from(vec![Rational::from_signeds(1, 3), Rational::from(-1)]);
+    /// assert_eq!((p + &q).to_string(), "5/6");
+    /// ```
+    #[inline]
+    fn add(self, other: &'a Polynomial<Rational>) -> Polynomial<Rational> {
+        add_helper(&self.coeffs, &other.coeffs)
+    }
+}
+
+impl<'a> Add<Polynomial<Rational>> for &'a Polynomial<Rational> {
+    type Output = Polynomial<Rational>;
+
+    /// Adds two [`Polynomial`]s with [`Rational`] coefficients, taking the first by reference and
+    /// the second by value.
+    ///
+    /// $$
+    /// f(p, q) = p + q.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the total number of bits in the
+    /// coefficients.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_q::polynomial::Polynomial;
+    /// use malachite_q::Rational;
+    ///
+    /// let p = Polynomial::from(vec![Rational::from_signeds(1, 2), Rational::from(1)]);
+    /// let q = Polynomial::from(vec![Rational::from_signeds(1, 3), Rational::from(-1)]);
+    /// assert_eq!((&p + q).to_string(), "5/6");
+    /// ```
+    #[inline]
+    fn add(self, other: Polynomial<Rational>) -> Polynomial<Rational> {
+        add_helper(&self.coeffs, &other.coeffs)
+    }
+}
+
+impl<'a, 'b> Add<&'a Polynomial<Rational>> for &'b Polynomial<Rational> {
+    type Output = Polynomial<Rational>;
+
+    /// Adds two [`Polynomial`]s with [`Rational`] coefficients, taking both by reference.
+    ///
+    /// $$
+    /// f(p, q) = p + q.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the total number of bits in the
+    /// coefficients.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_q::polynomial::Polynomial;
+    /// use malachite_q::Rational;
+    ///
+    /// let p = Polynomial::from(vec![Rational::from_signeds(1, 2), Rational::from(1)]);
+    /// let q = Polynomial::from(vec![Rational::from_signeds(1, 3), Rational::from(-1)]);
+    /// assert_eq!((&p + &q).to_string(), "5/6");
+    /// ```
+    #[inline]
+    fn add(self, other: &'a Polynomial<Rational>) -> Polynomial<Rational> {
+        add_helper(&self.coeffs, &other.coeffs)
+    }
+}
+
+impl Sub<Polynomial<Rational>> for Polynomial<Rational> {
+    type Output = Polynomial<Rational>;
+
+    /// Subtracts a [`Polynomial`] with [`Rational`] coefficients from another, taking both by
+    /// value.
+    ///
+    /// $$
+    /// f(p, q) = p - q.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the total number of bits in the
+    /// coefficients.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_q::polynomial::Polynomial;
+    /// use malachite_q::Rational;
+    ///
+    /// let p = Polynomial::from(vec![Rational::from_signeds(1, 2), Rational::from(1)]);
+    /// let q = Polynomial::from(vec![Rational::from_signeds(1, 3), Rational::from(-1)]);
+    /// assert_eq!((p - q).to_string(), "2*x+1/6");
+    /// ```
+    #[inline]
+    fn sub(self, other: Polynomial<Rational>) -> Polynomial<Rational> {
+        sub_helper(&self.coeffs, &other.coeffs)
+    }
+}
+
+impl<'a> Sub<&'a Polynomial<Rational>> for Polynomial<Rational> {
+    type Output = Polynomial<Rational>;
+
+    /// Subtracts a [`Polynomial`] with [`Rational`] coefficients from another, taking the first by
+    /// value and the second by reference.
+    ///
+    /// $$
+    /// f(p, q) = p - q.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the total number of bits in the
+    /// coefficients.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_q::polynomial::Polynomial;
+    /// use malachite_q::Rational;
+    ///
+    /// let p = Polynomial::from(vec![Rational::from_signeds(1, 2), Rational::from(1)]);
+    /// let q = Polynomial::from(vec![Rational::from_signeds(1, 3), Rational::from(-1)]);
+    /// assert_eq!((p - &q).to_string(), "2*x+1/6");
+    /// ```
+    #[inline]
+    fn sub(self, other: &'a Polynomial<Rational>) -> Polynomial<Rational> {
+        sub_helper(&self.coeffs, &other.coeffs)
+    }
+}
+
+impl<'a> Sub<Polynomial<Rational>> for &'a Polynomial<Rational> {
+    type Output = Polynomial<Rational>;
+
+    /// Subtracts a [`Polynomial`] with [`Rational`] coefficients from another, taking the first by
+    /// reference and the second by value.
+    ///
+    /// $$
+    /// f(p, q) = p - q.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the total number of bits in the
+    /// coefficients.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_q::polynomial::Polynomial;
+    /// use malachite_q::Rational;
+    ///
+    /// let p = Polynomial::from(vec![Rational::from_signeds(1, 2), Rational::from(1)]);
+    /// let q = Polynomial::from(vec![Rational::from_signeds(1, 3), Rational::from(-1)]);
+    /// assert_eq!((&p - q).to_string(), "2*x+1/6");
+    /// ```
+    #[inline]
+    fn sub(self, other: Polynomial<Rational>) -> Polynomial<Rational> {
+        sub_helper(&self.coeffs, &other.coeffs)
+    }
+}
+
+impl<'a, 'b> Sub<&'a Polynomial<Rational>> for &'b Polynomial<Rational> {
+    type Output = Polynomial<Rational>;
+
+    /// Subtracts a [`Polynomial`] with [`Rational`] coefficients from another, taking both by
+    /// reference.
+    ///
+    /// $$
+    /// f(p, q) = p - q.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the total number of bits in the
+    /// coefficients.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_q::polynomial::Polynomial;
+    /// use malachite_q::Rational;
+    ///
+    /// let p = Polynomial::from(vec![Rational::from_signeds(1, 2), Rational::from(1)]);
+    /// let q = Polynomial::from(vec![Rational::from_signeds(1, 3), Rational::from(-1)]);
+    /// assert_eq!((&p - &q).to_string(), "2*x+1/6");
+    /// ```
+    #[inline]
+    fn sub(self, other: &'a Polynomial<Rational>) -> Polynomial<Rational> {
+        sub_helper(&self.coeffs, &other.coeffs)
+    }
+}
+
+impl Neg for Polynomial<Rational> {
+    type Output = Polynomial<Rational>;
+
+    /// Negates a [`Polynomial`] with [`Rational`] coefficients, taking it by value.
+    ///
+    /// $$
+    /// f(p) = -p.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the total number of bits in the
+    /// coefficients.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_q::polynomial::Polynomial;
+    /// use malachite_q::Rational;
+    ///
+    /// let p = Polynomial::from(vec![Rational::from_signeds(1, 2), Rational::from(-3)]);
+    /// assert_eq!((-p).to_string(), "3*x-1/2");
+    /// ```
+    #[inline]
+    fn neg(self) -> Polynomial<Rational> {
+        Polynomial {
+            coeffs: self.coeffs.into_iter().map(Neg::neg).collect(),
+        }
+    }
+}
+
+impl<'a> Neg for &'a Polynomial<Rational> {
+    type Output = Polynomial<Rational>;
+
+    /// Negates a [`Polynomial`] with [`Rational`] coefficients, taking it by reference.
+    ///
+    /// $$
+    /// f(p) = -p.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the total number of bits in the
+    /// coefficients.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_q::polynomial::Polynomial;
+    /// use malachite_q::Rational;
+    ///
+    /// let p = Polynomial::from(vec![Rational::from_signeds(1, 2), Rational::from(-3)]);
+    /// assert_eq!((-&p).to_string(), "3*x-1/2");
+    /// ```
+    #[inline]
+    fn neg(self) -> Polynomial<Rational> {
+        Polynomial {
+            coeffs: self.coeffs.iter().map(Neg::neg).collect(),
+        }
+    }
+}
+
+impl<'m> Add<Polynomial<ModNatural<'m>>> for Polynomial<ModNatural<'m>> {
+    type Output = Polynomial<ModNatural<'m>>;
+
+    /// Adds two [`Polynomial`]s modulo $m$, taking both by value.
+    ///
+    /// $$
+    /// f(p, q) = p + q.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the total number of bits in the
+    /// coefficients.
+    ///
+    /// # Panics
+    /// Panics if `self` and `other` have different moduli.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::natural::Natural;
+    /// use malachite_q::polynomial::Polynomial;
+    ///
+    /// let m = Natural::from(5u32);
+    /// let p = Polynomial::from_naturals(vec![Natural::from(1u32), Natural::from(2u32)], &m);
+    /// let q = Polynomial::from_naturals(vec![Natural::from(3u32), Natural::from(2u32)], &m);
+    /// assert_eq!((p + q).to_string(), "4*x+4 mod 5");
+    /// ```
+    #[inline]
+    fn add(self, other: Polynomial<ModNatural<'m>>) -> Polynomial<ModNatural<'m>> {
+        add_helper(&self.coeffs, &other.coeffs)
+    }
+}
+
+impl<'a, 'm> Add<&'a Polynomial<ModNatural<'m>>> for Polynomial<ModNatural<'m>> {
+    type Output = Polynomial<ModNatural<'m>>;
+
+    /// Adds two [`Polynomial`]s modulo $m$, taking the first by value and the second by reference.
+    ///
+    /// $$
+    /// f(p, q) = p + q.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the total number of bits in the
+    /// coefficients.
+    ///
+    /// # Panics
+    /// Panics if `self` and `other` have different moduli.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::natural::Natural;
+    /// use malachite_q::polynomial::Polynomial;
+    ///
+    /// let m = Natural::from(5u32);
+    /// let p = Polynomial::from_naturals(vec![Natural::from(1u32), Natural::from(2u32)], &m);
+    /// let q = Polynomial::from_naturals(vec![Natural::from(3u32), Natural::from(2u32)], &m);
+    /// assert_eq!((p + &q).to_string(), "4*x+4 mod 5");
+    /// ```
+    #[inline]
+    fn add(self, other: &'a Polynomial<ModNatural<'m>>) -> Polynomial<ModNatural<'m>> {
+        add_helper(&self.coeffs, &other.coeffs)
+    }
+}
+
+impl<'a, 'm> Add<Polynomial<ModNatural<'m>>> for &'a Polynomial<ModNatural<'m>> {
+    type Output = Polynomial<ModNatural<'m>>;
+
+    /// Adds two [`Polynomial`]s modulo $m$, taking the first by reference and the second by value.
+    ///
+    /// $$
+    /// f(p, q) = p + q.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the total number of bits in the
+    /// coefficients.
+    ///
+    /// # Panics
+    /// Panics if `self` and `other` have different moduli.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::natural::Natural;
+    /// use malachite_q::polynomial::Polynomial;
+    ///
+    /// let m = Natural::from(5u32);
+    /// let p = Polynomial::from_naturals(vec![Natural::from(1u32), Natural::from(2u32)], &m);
+    /// let q = Polynomial::from_naturals(vec![Natural::from(3u32), Natural::from(2u32)], &m);
+    /// assert_eq!((&p + q).to_string(), "4*x+4 mod 5");
+    /// ```
+    #[inline]
+    fn add(self, other: Polynomial<ModNatural<'m>>) -> Polynomial<ModNatural<'m>> {
+        add_helper(&self.coeffs, &other.coeffs)
+    }
+}
+
+impl<'a, 'b, 'm> Add<&'a Polynomial<ModNatural<'m>>> for &'b Polynomial<ModNatural<'m>> {
+    type Output = Polynomial<ModNatural<'m>>;
+
+    /// Adds two [`Polynomial`]s modulo $m$, taking both by reference.
+    ///
+    /// $$
+    /// f(p, q) = p + q.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the total number of bits in the
+    /// coefficients.
+    ///
+    /// # Panics
+    /// Panics if `self` and `other` have different moduli.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::natural::Natural;
+    /// use malachite_q::polynomial::Polynomial;
+    ///
+    /// let m = Natural::from(5u32);
+    /// let p = Polynomial::from_naturals(vec![Natural::from(1u32), Natural::from(2u32)], &m);
+    /// let q = Polynomial::from_naturals(vec![Natural::from(3u32), Natural::from(2u32)], &m);
+    /// assert_eq!((&p + &q).to_string(), "4*x+4 mod 5");
+    /// ```
+    #[inline]
+    fn add(self, other: &'a Polynomial<ModNatural<'m>>) -> Polynomial<ModNatural<'m>> {
+        add_helper(&self.coeffs, &other.coeffs)
+    }
+}
+
+impl<'m> Sub<Polynomial<ModNatural<'m>>> for Polynomial<ModNatural<'m>> {
+    type Output = Polynomial<ModNatural<'m>>;
+
+    /// Subtracts a [`Polynomial`] modulo $m$ from another, taking both by value.
+    ///
+    /// $$
+    /// f(p, q) = p - q.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the total number of bits in the
+    /// coefficients.
+    ///
+    /// # Panics
+    /// Panics if `self` and `other` have different moduli.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::natural::Natural;
+    /// use malachite_q::polynomial::Polynomial;
+    ///
+    /// let m = Natural::from(5u32);
+    /// let p = Polynomial::from_naturals(vec![Natural::from(1u32), Natural::from(2u32)], &m);
+    /// let q = Polynomial::from_naturals(vec![Natural::from(3u32), Natural::from(2u32)], &m);
+    /// assert_eq!((p - q).to_string(), "3 mod 5");
+    /// ```
+    #[inline]
+    fn sub(self, other: Polynomial<ModNatural<'m>>) -> Polynomial<ModNatural<'m>> {
+        sub_helper(&self.coeffs, &other.coeffs)
+    }
+}
+
+impl<'a, 'm> Sub<&'a Polynomial<ModNatural<'m>>> for Polynomial<ModNatural<'m>> {
+    type Output = Polynomial<ModNatural<'m>>;
+
+    /// Subtracts a [`Polynomial`] modulo $m$ from another, taking the first by value and the second
+    /// by reference.
+    ///
+    /// $$
+    /// f(p, q) = p - q.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the total number of bits in the
+    /// coefficients.
+    ///
+    /// # Panics
+    /// Panics if `self` and `other` have different moduli.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::natural::Natural;
+    /// use malachite_q::polynomial::Polynomial;
+    ///
+    /// let m = Natural::from(5u32);
+    /// let p = Polynomial::from_naturals(vec![Natural::from(1u32), Natural::from(2u32)], &m);
+    /// let q = Polynomial::from_naturals(vec![Natural::from(3u32), Natural::from(2u32)], &m);
+    /// assert_eq!((p - &q).to_string(), "3 mod 5");
+    /// ```
+    #[inline]
+    fn sub(self, other: &'a Polynomial<ModNatural<'m>>) -> Polynomial<ModNatural<'m>> {
+        sub_helper(&self.coeffs, &other.coeffs)
+    }
+}
+
+impl<'a, 'm> Sub<Polynomial<ModNatural<'m>>> for &'a Polynomial<ModNatural<'m>> {
+    type Output = Polynomial<ModNatural<'m>>;
+
+    /// Subtracts a [`Polynomial`] modulo $m$ from another, taking the first by reference and the
+    /// second by value.
+    ///
+    /// $$
+    /// f(p, q) = p - q.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the total number of bits in the
+    /// coefficients.
+    ///
+    /// # Panics
+    /// Panics if `self` and `other` have different moduli.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::natural::Natural;
+    /// use malachite_q::polynomial::Polynomial;
+    ///
+    /// let m = Natural::from(5u32);
+    /// let p = Polynomial::from_naturals(vec![Natural::from(1u32), Natural::from(2u32)], &m);
+    /// let q = Polynomial::from_naturals(vec![Natural::from(3u32), Natural::from(2u32)], &m);
+    /// assert_eq!((&p - q).to_string(), "3 mod 5");
+    /// ```
+    #[inline]
+    fn sub(self, other: Polynomial<ModNatural<'m>>) -> Polynomial<ModNatural<'m>> {
+        sub_helper(&self.coeffs, &other.coeffs)
+    }
+}
+
+impl<'a, 'b, 'm> Sub<&'a Polynomial<ModNatural<'m>>> for &'b Polynomial<ModNatural<'m>> {
+    type Output = Polynomial<ModNatural<'m>>;
+
+    /// Subtracts a [`Polynomial`] modulo $m$ from another, taking both by reference.
+    ///
+    /// $$
+    /// f(p, q) = p - q.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the total number of bits in the
+    /// coefficients.
+    ///
+    /// # Panics
+    /// Panics if `self` and `other` have different moduli.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::natural::Natural;
+    /// use malachite_q::polynomial::Polynomial;
+    ///
+    /// let m = Natural::from(5u32);
+    /// let p = Polynomial::from_naturals(vec![Natural::from(1u32), Natural::from(2u32)], &m);
+    /// let q = Polynomial::from_naturals(vec![Natural::from(3u32), Natural::from(2u32)], &m);
+    /// assert_eq!((&p - &q).to_string(), "3 mod 5");
+    /// ```
+    #[inline]
+    fn sub(self, other: &'a Polynomial<ModNatural<'m>>) -> Polynomial<ModNatural<'m>> {
+        sub_helper(&self.coeffs, &other.coeffs)
+    }
+}
+
+impl<'m> Neg for Polynomial<ModNatural<'m>> {
+    type Output = Polynomial<ModNatural<'m>>;
+
+    /// Negates a [`Polynomial`] modulo $m$, taking it by value.
+    ///
+    /// $$
+    /// f(p) = -p.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the total number of bits in the
+    /// coefficients.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::natural::Natural;
+    /// use malachite_q::polynomial::Polynomial;
+    ///
+    /// let m = Natural::from(5u32);
+    /// let p = Polynomial::from_naturals(vec![Natural::from(1u32), Natural::from(2u32)], &m);
+    /// assert_eq!((-p).to_string(), "3*x+4 mod 5");
+    /// ```
+    #[inline]
+    fn neg(self) -> Polynomial<ModNatural<'m>> {
+        Polynomial {
+            coeffs: self.coeffs.into_iter().map(Neg::neg).collect(),
+        }
+    }
+}
+
+impl<'a, 'm> Neg for &'a Polynomial<ModNatural<'m>> {
+    type Output = Polynomial<ModNatural<'m>>;
+
+    /// Negates a [`Polynomial`] modulo $m$, taking it by reference.
+    ///
+    /// $$
+    /// f(p) = -p.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the total number of bits in the
+    /// coefficients.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::natural::Natural;
+    /// use malachite_q::polynomial::Polynomial;
+    ///
+    /// let m = Natural::from(5u32);
+    /// let p = Polynomial::from_naturals(vec![Natural::from(1u32), Natural::from(2u32)], &m);
+    /// assert_eq!((-&p).to_string(), "3*x+4 mod 5");
+    /// ```
+    #[inline]
+    fn neg(self) -> Polynomial<ModNatural<'m>> {
+        Polynomial {
+            coeffs: self.coeffs.iter().map(Neg::neg).collect(),
+        }
+    }
+}
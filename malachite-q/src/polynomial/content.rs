@@ -0,0 +1,135 @@
+use crate::polynomial::Polynomial;
+use crate::Rational;
+use malachite_base::num::arithmetic::traits::{DivExact, DivExactAssign, Gcd, Lcm};
+use malachite_base::num::basic::traits::{One, Zero};
+use malachite_nz::integer::Integer;
+use malachite_nz::natural::Natural;
+
+// Multiplies a list of `Rational`s by the least common multiple of their denominators, producing
+// a list of `Integer`s. Also returns the multiplier.
+pub(crate) fn clear_denominators(xs: &[Rational]) -> (Vec<Integer>, Natural) {
+    let lcm = xs
+        .iter()
+        .fold(Natural::ONE, |lcm, x| lcm.lcm(&x.denominator));
+    let ys = xs
+        .iter()
+        .map(|x| {
+            Integer::from_sign_and_abs(x.sign, &x.numerator * (&lcm).div_exact(&x.denominator))
+        })
+        .collect();
+    (ys, lcm)
+}
+
+// Returns the GCD of the absolute values of `xs`, or 0 if `xs` is empty.
+pub(crate) fn content_helper(xs: &[Integer]) -> Natural {
+    let mut gcd = Natural::ZERO;
+    for x in xs {
+        gcd = gcd.gcd(x.unsigned_abs_ref());
+        if gcd == 1u32 {
+            break;
+        }
+    }
+    gcd
+}
+
+// Divides `xs` by the GCD of their absolute values.
+pub(crate) fn make_primitive(xs: &mut [Integer]) {
+    let content = content_helper(xs);
+    if content > 1u32 {
+        let content = Integer::from(content);
+        for x in xs {
+            x.div_exact_assign(&content);
+        }
+    }
+}
+
+impl Polynomial<Integer> {
+    /// Returns the content of a [`Polynomial`] with [`Integer`] coefficients: the GCD of its
+    /// coefficients.
+    ///
+    /// The content is nonnegative, and the content of the zero polynomial is 0.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the total number of bits in the
+    /// coefficients.
+    ///
+    /// # Examples
+    /// See [here](super::content#content).
+    pub fn content(&self) -> Natural {
+        content_helper(&self.coeffs)
+    }
+
+    /// Returns the primitive part of a [`Polynomial`] with [`Integer`] coefficients: the
+    /// polynomial divided by its [`content`](Polynomial::content).
+    ///
+    /// The coefficients of the primitive part have no common factor, and its leading coefficient
+    /// has the same sign as the original polynomial's. The primitive part of the zero polynomial
+    /// is zero.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the total number of bits in the
+    /// coefficients.
+    ///
+    /// # Examples
+    /// See [here](super::content#primitive_part).
+    pub fn primitive_part(&self) -> Polynomial<Integer> {
+        let mut coeffs = self.coeffs.clone();
+        make_primitive(&mut coeffs);
+        Polynomial { coeffs }
+    }
+}
+
+impl Polynomial<Rational> {
+    /// Returns the content of a [`Polynomial`] with [`Rational`] coefficients.
+    ///
+    /// The content is the positive [`Rational`] $c$ such that dividing the polynomial by $c$
+    /// produces a polynomial whose coefficients are [`Integer`]s with no common factor. It is
+    /// the GCD of the numerators of the coefficients divided by the LCM of their denominators.
+    /// The content of the zero polynomial is 0.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the total number of bits in the
+    /// coefficients.
+    ///
+    /// # Examples
+    /// See [here](super::content#content).
+    pub fn content(&self) -> Rational {
+        let (xs, denominator) = clear_denominators(&self.coeffs);
+        Rational::from_naturals(content_helper(&xs), denominator)
+    }
+
+    /// Returns the primitive part of a [`Polynomial`] with [`Rational`] coefficients: the
+    /// polynomial divided by its [`content`](Polynomial::content), which has [`Integer`]
+    /// coefficients with no common factor.
+    ///
+    /// The leading coefficient of the primitive part has the same sign as the original
+    /// polynomial's. The primitive part of the zero polynomial is zero.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the total number of bits in the
+    /// coefficients.
+    ///
+    /// # Examples
+    /// See [here](super::content#primitive_part).
+    pub fn primitive_part(&self) -> Polynomial<Integer> {
+        let mut coeffs = clear_denominators(&self.coeffs).0;
+        make_primitive(&mut coeffs);
+        Polynomial { coeffs }
+    }
+}
@@ -0,0 +1,479 @@
+use crate::polynomial::{trim, Coefficient, Polynomial};
+use crate::Rational;
+use malachite_base::num::arithmetic::traits::{DivRem, Reciprocal};
+use malachite_base::num::basic::traits::Zero;
+use malachite_nz::integer::Integer;
+use malachite_nz::natural::arithmetic::mod_natural::ModNatural;
+use std::ops::{Mul, MulAssign, SubAssign};
+
+// Returns $q$ and $r$ such that $d^e x = qy + r$ and $\deg r < \deg y$, where $d$ is the leading
+// coefficient of $y$ and $e = \max(\deg x - \deg y + 1, 0)$. `ys` must be nonempty.
+pub(crate) fn pseudo_div_rem_helper(
+    xs: &[Integer],
+    ys: &[Integer],
+) -> (Vec<Integer>, Vec<Integer>) {
+    let n = ys.len();
+    if xs.len() < n {
+        return (Vec::new(), xs.to_vec());
+    }
+    let d = ys.last().unwrap();
+    let mut r = xs.to_vec();
+    let mut q = vec![Integer::ZERO; xs.len() - n + 1];
+    for i in (0..q.len()).rev() {
+        let c = r.pop().unwrap();
+        for x in &mut q[i + 1..] {
+            *x *= d;
+        }
+        for x in &mut r {
+            *x *= d;
+        }
+        if c != 0u32 {
+            for (x, y) in r[i..].iter_mut().zip(ys) {
+                *x -= &c * y;
+            }
+        }
+        q[i] = c;
+    }
+    trim(&mut r);
+    (q, r)
+}
+
+// Returns $q$ and $r$ such that $x = qy + r$ and $\deg r < \deg y$, given the inverse of the
+// leading coefficient of $y$. `ys` must be nonempty.
+fn div_rem_helper<T: Coefficient + MulAssign<T> + SubAssign<T>>(
+    xs: &[T],
+    ys: &[T],
+    inverse: &T,
+) -> (Polynomial<T>, Polynomial<T>)
+where
+    for<'a> &'a T: Mul<&'a T, Output = T>,
+{
+    T::check_compatible(xs, ys);
+    let n = ys.len();
+    if xs.len() < n {
+        return (
+            Polynomial { coeffs: Vec::new() },
+            Polynomial {
+                coeffs: xs.to_vec(),
+            },
+        );
+    }
+    let mut r = xs.to_vec();
+    let mut q = Vec::with_capacity(xs.len() - n + 1);
+    for i in (0..=xs.len() - n).rev() {
+        // Subtracting c * y * x^i removes the top coefficient of r.
+        let c = &r.pop().unwrap() * inverse;
+        if !c.is_zero() {
+            for (x, y) in r[i..].iter_mut().zip(ys) {
+                *x -= &c * y;
+            }
+        }
+        q.push(c);
+    }
+    q.reverse();
+    trim(&mut r);
+    (Polynomial { coeffs: q }, Polynomial { coeffs: r })
+}
+
+fn div_rem_rational_helper(
+    xs: &[Rational],
+    ys: &[Rational],
+) -> (Polynomial<Rational>, Polynomial<Rational>) {
+    let inverse = ys.last().expect("division by zero").reciprocal();
+    div_rem_helper(xs, ys, &inverse)
+}
+
+pub(crate) fn div_rem_mod_helper<'a>(
+    xs: &[ModNatural<'a>],
+    ys: &[ModNatural<'a>],
+) -> (Polynomial<ModNatural<'a>>, Polynomial<ModNatural<'a>>) {
+    let inverse = ys
+        .last()
+        .expect("division by zero")
+        .inverse()
+        .expect("The leading coefficient of the divisor is not invertible");
+    div_rem_helper(xs, ys, &inverse)
+}
+
+impl Polynomial<Integer> {
+    /// Performs pseudo-division of a [`Polynomial`] with [`Integer`] coefficients by another,
+    /// returning the pseudo-quotient and pseudo-remainder.
+    ///
+    /// Integer polynomials cannot generally be divided with remainder, but they can after being
+    /// multiplied by a power of the divisor's leading coefficient. Given $x$ and $y$, this
+    /// function returns $q$ and $r$ such that
+    /// $$
+    /// d^e x = qy + r,
+    /// $$
+    /// where $d$ is the leading coefficient of $y$, $e = \max(\deg x - \deg y + 1, 0)$, and
+    /// $\deg r < \deg y$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(nm M(b))$
+    ///
+    /// $M(n, b) = O(nb)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ and $m$ are the numbers of coefficients of
+    /// `self` and `other`, $b$ is the number of bits in the largest coefficient of the result, and
+    /// $M(b)$ is the time needed to multiply two $b$-bit numbers.
+    ///
+    /// # Panics
+    /// Panics if `other` is zero.
+    ///
+    /// # Examples
+    /// See [here](super::div#pseudo_div_rem).
+    pub fn pseudo_div_rem(
+        &self,
+        other: &Polynomial<Integer>,
+    ) -> (Polynomial<Integer>, Polynomial<Integer>) {
+        assert!(!other.coeffs.is_empty(), "division by zero");
+        let (q, r) = pseudo_div_rem_helper(&self.coeffs, &other.coeffs);
+        (Polynomial { coeffs: q }, Polynomial { coeffs: r })
+    }
+}
+
+impl DivRem<Polynomial<Rational>> for Polynomial<Rational> {
+    type DivOutput = Polynomial<Rational>;
+    type RemOutput = Polynomial<Rational>;
+
+    /// Divides a [`Polynomial`] with [`Rational`] coefficients by another, taking both by value and
+    /// returning the quotient and remainder.
+    ///
+    /// The quotient $q$ and remainder $r$ satisfy $x = qy + r$ and $\deg r < \deg y$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(nm M(b))$
+    ///
+    /// $M(n, b) = O(nb)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ and $m$ are the numbers of coefficients of
+    /// `self` and `other`, $b$ is the number of bits in the largest coefficient of the result, and
+    /// $M(b)$ is the time needed to multiply two $b$-bit numbers.
+    ///
+    /// # Panics
+    /// Panics if `other` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::DivRem;
+    /// use malachite_base::strings::ToDebugString;
+    /// use malachite_q::polynomial::Polynomial;
+    /// use malachite_q::Rational;
+    ///
+    /// let p = Polynomial::from(vec![Rational::from(1), Rational::from(0), Rational::from(1)]);
+    /// let q = Polynomial::from(vec![Rational::from(1), Rational::from(2)]);
+    /// assert_eq!(p.div_rem(q).to_debug_string(), "(1/2*x-1/4, 5/4)");
+    /// ```
+    #[inline]
+    fn div_rem(self, other: Polynomial<Rational>) -> (Polynomial<Rational>, Polynomial<Rational>) {
+        div_rem_rational_helper(&self.coeffs, &other.coeffs)
+    }
+}
+
+impl<'a> DivRem<&'a Polynomial<Rational>> for Polynomial<Rational> {
+    type DivOutput = Polynomial<Rational>;
+    type RemOutput = Polynomial<Rational>;
+
+    /// Divides a [`Polynomial`] with [`Rational`] coefficients by another, taking the first by
+    /// value and the second by reference and returning the quotient and remainder.
+    ///
+    /// The quotient $q$ and remainder $r$ satisfy $x = qy + r$ and $\deg r < \deg y$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(nm M(b))$
+    ///
+    /// $M(n, b) = O(nb)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ and $m$ are the numbers of coefficients of
+    /// `self` and `other`, $b$ is the number of bits in the largest coefficient of the result, and
+    /// $M(b)$ is the time needed to multiply two $b$-bit numbers.
+    ///
+    /// # Panics
+    /// Panics if `other` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::DivRem;
+    /// use malachite_base::strings::ToDebugString;
+    /// use malachite_q::polynomial::Polynomial;
+    /// use malachite_q::Rational;
+    ///
+    /// let p = Polynomial::from(vec![Rational::from(1), Rational::from(0), Rational::from(1)]);
+    /// let q = Polynomial::from(vec![Rational::from(1), Rational::from(2)]);
+    /// assert_eq!(p.div_rem(&q).to_debug_string(), "(1/2*x-1/4, 5/4)");
+    /// ```
+    #[inline]
+    fn div_rem(
+        self,
+        other: &'a Polynomial<Rational>,
+    ) -> (Polynomial<Rational>, Polynomial<Rational>) {
+        div_rem_rational_helper(&self.coeffs, &other.coeffs)
+    }
+}
+
+impl<'a> DivRem<Polynomial<Rational>> for &'a Polynomial<Rational> {
+    type DivOutput = Polynomial<Rational>;
+    type RemOutput = Polynomial<Rational>;
+
+    /// Divides a [`Polynomial`] with [`Rational`] coefficients by another, taking the first by
+    /// reference and the second by value and returning the quotient and remainder.
+    ///
+    /// The quotient $q$ and remainder $r$ satisfy $x = qy + r$ and $\deg r < \deg y$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(nm M(b))$
+    ///
+    /// $M(n, b) = O(nb)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ and $m$ are the numbers of coefficients of
+    /// `self` and `other`, $b$ is the number of bits in the largest coefficient of the result, and
+    /// $M(b)$ is the time needed to multiply two $b$-bit numbers.
+    ///
+    /// # Panics
+    /// Panics if `other` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::DivRem;
+    /// use malachite_base::strings::ToDebugString;
+    /// use malachite_q::polynomial::Polynomial;
+    /// use malachite_q::Rational;
+    ///
+    /// let p = Polynomial::from(vec![Rational::from(1), Rational::from(0), Rational::from(1)]);
+    /// let q = Polynomial::from(vec![Rational::from(1), Rational::from(2)]);
+    /// assert_eq!((&p).div_rem(q).to_debug_string(), "(1/2*x-1/4, 5/4)");
+    /// ```
+    #[inline]
+    fn div_rem(self, other: Polynomial<Rational>) -> (Polynomial<Rational>, Polynomial<Rational>) {
+        div_rem_rational_helper(&self.coeffs, &other.coeffs)
+    }
+}
+
+impl<'a, 'b> DivRem<&'a Polynomial<Rational>> for &'b Polynomial<Rational> {
+    type DivOutput = Polynomial<Rational>;
+    type RemOutput = Polynomial<Rational>;
+
+    /// Divides a [`Polynomial`] with [`Rational`] coefficients by another, taking both by reference
+    /// and returning the quotient and remainder.
+    ///
+    /// The quotient $q$ and remainder $r$ satisfy $x = qy + r$ and $\deg r < \deg y$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(nm M(b))$
+    ///
+    /// $M(n, b) = O(nb)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ and $m$ are the numbers of coefficients of
+    /// `self` and `other`, $b$ is the number of bits in the largest coefficient of the result, and
+    /// $M(b)$ is the time needed to multiply two $b$-bit numbers.
+    ///
+    /// # Panics
+    /// Panics if `other` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::DivRem;
+    /// use malachite_base::strings::ToDebugString;
+    /// use malachite_q::polynomial::Polynomial;
+    /// use malachite_q::Rational;
+    ///
+    /// let p = Polynomial::from(vec![Rational::from(1), Rational::from(0), Rational::from(1)]);
+    /// let q = Polynomial::from(vec![Rational::from(1), Rational::from(2)]);
+    /// assert_eq!((&p).div_rem(&q).to_debug_string(), "(1/2*x-1/4, 5/4)");
+    /// ```
+    #[inline]
+    fn div_rem(
+        self,
+        other: &'a Polynomial<Rational>,
+    ) -> (Polynomial<Rational>, Polynomial<Rational>) {
+        div_rem_rational_helper(&self.coeffs, &other.coeffs)
+    }
+}
+
+impl<'m> DivRem<Polynomial<ModNatural<'m>>> for Polynomial<ModNatural<'m>> {
+    type DivOutput = Polynomial<ModNatural<'m>>;
+    type RemOutput = Polynomial<ModNatural<'m>>;
+
+    /// Divides a [`Polynomial`] modulo $m$ by another, taking both by value and returning the
+    /// quotient and remainder.
+    ///
+    /// The quotient $q$ and remainder $r$ satisfy $x = qy + r$ and $\deg r < \deg y$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(nm M(b))$
+    ///
+    /// $M(n, b) = O(nb)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ and $m$ are the numbers of coefficients of
+    /// `self` and `other`, $b$ is the number of bits in the largest coefficient of the result, and
+    /// $M(b)$ is the time needed to multiply two $b$-bit numbers.
+    ///
+    /// # Panics
+    /// Panics if `other` is zero, if the leading coefficient of `other` is not invertible modulo
+    /// $m$, or if `self` and `other` have different moduli.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::DivRem;
+    /// use malachite_base::num::basic::traits::Zero;
+    /// use malachite_base::strings::ToDebugString;
+    /// use malachite_nz::natural::Natural;
+    /// use malachite_q::polynomial::Polynomial;
+    ///
+    /// let m = Natural::from(7u32);
+    /// let p = Polynomial::from_naturals(
+    ///     vec![Natural::from(1u32), Natural::ZERO, Natural::from(1u32)],
+    ///     &m,
+    /// );
+    /// let q = Polynomial::from_naturals(vec![Natural::from(1u32), Natural::from(2u32)], &m);
+    /// assert_eq!(p.div_rem(q).to_debug_string(), "(4*x+5 mod 7, 3 mod 7)");
+    /// ```
+    #[inline]
+    fn div_rem(
+        self,
+        other: Polynomial<ModNatural<'m>>,
+    ) -> (Polynomial<ModNatural<'m>>, Polynomial<ModNatural<'m>>) {
+        div_rem_mod_helper(&self.coeffs, &other.coeffs)
+    }
+}
+
+impl<'a, 'm> DivRem<&'a Polynomial<ModNatural<'m>>> for Polynomial<ModNatural<'m>> {
+    type DivOutput = Polynomial<ModNatural<'m>>;
+    type RemOutput = Polynomial<ModNatural<'m>>;
+
+    /// Divides a [`Polynomial`] modulo $m$ by another, taking the first by value and the second by
+    /// reference and returning the quotient and remainder.
+    ///
+    /// The quotient $q$ and remainder $r$ satisfy $x = qy + r$ and $\deg r < \deg y$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(nm M(b))$
+    ///
+    /// $M(n, b) = O(nb)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ and $m$ are the numbers of coefficients of
+    /// `self` and `other`, $b$ is the number of bits in the largest coefficient of the result, and
+    /// $M(b)$ is the time needed to multiply two $b$-bit numbers.
+    ///
+    /// # Panics
+    /// Panics if `other` is zero, if the leading coefficient of `other` is not invertible modulo
+    /// $m$, or if `self` and `other` have different moduli.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::DivRem;
+    /// use malachite_base::num::basic::traits::Zero;
+    /// use malachite_base::strings::ToDebugString;
+    /// use malachite_nz::natural::Natural;
+    /// use malachite_q::polynomial::Polynomial;
+    ///
+    /// let m = Natural::from(7u32);
+    /// let p = Polynomial::from_naturals(
+    ///     vec![Natural::from(1u32), Natural::ZERO, Natural::from(1u32)],
+    ///     &m,
+    /// );
+    /// let q = Polynomial::from_naturals(vec![Natural::from(1u32), Natural::from(2u32)], &m);
+    /// assert_eq!(p.div_rem(&q).to_debug_string(), "(4*x+5 mod 7, 3 mod 7)");
+    /// ```
+    #[inline]
+    fn div_rem(
+        self,
+        other: &'a Polynomial<ModNatural<'m>>,
+    ) -> (Polynomial<ModNatural<'m>>, Polynomial<ModNatural<'m>>) {
+        div_rem_mod_helper(&self.coeffs, &other.coeffs)
+    }
+}
+
+impl<'a, 'm> DivRem<Polynomial<ModNatural<'m>>> for &'a Polynomial<ModNatural<'m>> {
+    type DivOutput = Polynomial<ModNatural<'m>>;
+    type RemOutput = Polynomial<ModNatural<'m>>;
+
+    /// Divides a [`Polynomial`] modulo $m$ by another, taking the first by reference and the second
+    /// by value and returning the quotient and remainder.
+    ///
+    /// The quotient $q$ and remainder $r$ satisfy $x = qy + r$ and $\deg r < \deg y$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(nm M(b))$
+    ///
+    /// $M(n, b) = O(nb)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ and $m$ are the numbers of coefficients of
+    /// `self` and `other`, $b$ is the number of bits in the largest coefficient of the result, and
+    /// $M(b)$ is the time needed to multiply two $b$-bit numbers.
+    ///
+    /// # Panics
+    /// Panics if `other` is zero, if the leading coefficient of `other` is not invertible modulo
+    /// $m$, or if `self` and `other` have different moduli.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::DivRem;
+    /// use malachite_base::num::basic::traits::Zero;
+    /// use malachite_base::strings::ToDebugString;
+    /// use malachite_nz::natural::Natural;
+    /// use malachite_q::polynomial::Polynomial;
+    ///
+    /// let m = Natural::from(7u32);
+    /// let p = Polynomial::from_naturals(
+    ///     vec![Natural::from(1u32), Natural::ZERO, Natural::from(1u32)],
+    ///     &m,
+    /// );
+    /// let q = Polynomial::from_naturals(vec![Natural::from(1u32), Natural::from(2u32)], &m);
+    /// assert_eq!((&p).div_rem(q).to_debug_string(), "(4*x+5 mod 7, 3 mod 7)");
+    /// ```
+    #[inline]
+    fn div_rem(
+        self,
+        other: Polynomial<ModNatural<'m>>,
+    ) -> (Polynomial<ModNatural<'m>>, Polynomial<ModNatural<'m>>) {
+        div_rem_mod_helper(&self.coeffs, &other.coeffs)
+    }
+}
+
+impl<'a, 'b, 'm> DivRem<&'a Polynomial<ModNatural<'m>>> for &'b Polynomial<ModNatural<'m>> {
+    type DivOutput = Polynomial<ModNatural<'m>>;
+    type RemOutput = Polynomial<ModNatural<'m>>;
+
+    /// Divides a [`Polynomial`] modulo $m$ by another, taking both by reference and returning the
+    /// quotient and remainder.
+    ///
+    /// The quotient $q$ and remainder $r$ satisfy $x = qy + r$ and $\deg r < \deg y$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(nm M(b))$
+    ///
+    /// $M(n, b) = O(nb)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ and $m$ are the numbers of coefficients of
+    /// `self` and `other`, $b$ is the number of bits in the largest coefficient of the result, and
+    /// $M(b)$ is the time needed to multiply two $b$-bit numbers.
+    ///
+    /// # Panics
+    /// Panics if `other` is zero, if the leading coefficient of `other` is not invertible modulo
+    /// $m$, or if `self` and `other` have different moduli.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::DivRem;
+    /// use malachite_base::num::basic::traits::Zero;
+    /// use malachite_base::strings::ToDebugString;
+    /// use malachite_nz::natural::Natural;
+    /// use malachite_q::polynomial::Polynomial;
+    ///
+    /// let m = Natural::from(7u32);
+    /// let p = Polynomial::from_naturals(
+    ///     vec![Natural::from(1u32), Natural::ZERO, Natural::from(1u32)],
+    ///     &m,
+    /// );
+    /// let q = Polynomial::from_naturals(vec![Natural::from(1u32), Natural::from(2u32)], &m);
+    /// assert_eq!((&p).div_rem(&q).to_debug_string(), "(4*x+5 mod 7, 3 mod 7)");
+    /// ```
+    #[inline]
+    fn div_rem(
+        self,
+        other: &'a Polynomial<ModNatural<'m>>,
+    ) -> (Polynomial<ModNatural<'m>>, Polynomial<ModNatural<'m>>) {
+        div_rem_mod_helper(&self.coeffs, &other.coeffs)
+    }
+}
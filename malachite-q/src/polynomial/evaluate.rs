@@ -0,0 +1,171 @@
+use crate::polynomial::content::clear_denominators;
+use crate::polynomial::{trim, Coefficient, Polynomial};
+use crate::Rational;
+use malachite_base::num::basic::traits::{One, Zero};
+use malachite_nz::integer::Integer;
+use malachite_nz::natural::arithmetic::mod_natural::ModNatural;
+use malachite_nz::natural::Natural;
+
+// Evaluates the polynomial with coefficients `xs` at `x`. Writing $x = p/q$, this computes
+// $\sum_i x_i p^i q^{n-i}$, which is an integer, and divides it by $q^n$ at the end, so that no
+// GCDs are needed along the way.
+pub(crate) fn evaluate_integer_helper(xs: &[Integer], x: &Rational) -> Rational {
+    let (last, rest) = match xs.split_last() {
+        Some(split) => split,
+        None => return Rational::ZERO,
+    };
+    let p = Integer::from_sign_and_abs_ref(x.sign, &x.numerator);
+    let q = &x.denominator;
+    let mut q_power = Natural::ONE;
+    let mut sum = last.clone();
+    for c in rest.iter().rev() {
+        sum *= &p;
+        q_power *= q;
+        sum += c * Integer::from(&q_power);
+    }
+    Rational::from_integers(sum, Integer::from(q_power))
+}
+
+fn derivative_helper<T: Coefficient>(xs: &[T]) -> Polynomial<T> {
+    let mut coeffs: Vec<T> = xs
+        .iter()
+        .enumerate()
+        .skip(1)
+        .map(|(i, x)| x.mul_usize(i))
+        .collect();
+    trim(&mut coeffs);
+    Polynomial { coeffs }
+}
+
+impl Polynomial<Integer> {
+    /// Evaluates a [`Polynomial`] with [`Integer`] coefficients at a [`Rational`].
+    ///
+    /// $f(p, x) = p(x)$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n M(nm))$
+    ///
+    /// $M(n, m) = O(nm)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is the number of coefficients, $m$ is the
+    /// maximum of the number of bits of any coefficient and `x.significant_bits()`, and $M(b)$ is
+    /// the time needed to multiply two $b$-bit numbers.
+    ///
+    /// # Examples
+    /// See [here](super::evaluate#evaluate).
+    #[inline]
+    pub fn evaluate(&self, x: &Rational) -> Rational {
+        evaluate_integer_helper(&self.coeffs, x)
+    }
+
+    /// Returns the derivative of a [`Polynomial`] with [`Integer`] coefficients.
+    ///
+    /// $f(p) = p'$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the total number of bits in the
+    /// coefficients.
+    ///
+    /// # Examples
+    /// See [here](super::evaluate#derivative).
+    #[inline]
+    pub fn derivative(&self) -> Polynomial<Integer> {
+        derivative_helper(&self.coeffs)
+    }
+}
+
+impl Polynomial<Rational> {
+    /// Evaluates a [`Polynomial`] with [`Rational`] coefficients at a [`Rational`].
+    ///
+    /// $f(p, x) = p(x)$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n M(nm))$
+    ///
+    /// $M(n, m) = O(nm)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is the number of coefficients, $m$ is the
+    /// maximum of the number of bits of any coefficient and `x.significant_bits()`, and $M(b)$ is
+    /// the time needed to multiply two $b$-bit numbers.
+    ///
+    /// # Examples
+    /// See [here](super::evaluate#evaluate).
+    pub fn evaluate(&self, x: &Rational) -> Rational {
+        let (xs, denominator) = clear_denominators(&self.coeffs);
+        evaluate_integer_helper(&xs, x) / Rational::from(denominator)
+    }
+
+    /// Returns the derivative of a [`Polynomial`] with [`Rational`] coefficients.
+    ///
+    /// $f(p) = p'$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the total number of bits in the
+    /// coefficients.
+    ///
+    /// # Examples
+    /// See [here](super::evaluate#derivative).
+    #[inline]
+    pub fn derivative(&self) -> Polynomial<Rational> {
+        derivative_helper(&self.coeffs)
+    }
+}
+
+impl<'a> Polynomial<ModNatural<'a>> {
+    /// Evaluates a [`Polynomial`] modulo $m$ at a [`ModNatural`], using Horner's method.
+    ///
+    /// $f(p, x) = p(x)$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n M(m))$
+    ///
+    /// $M(m) = O(m)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is the number of coefficients, $m$ is
+    /// `m.significant_bits()`, and $M(b)$ is the time needed to multiply two $b$-bit numbers.
+    ///
+    /// # Panics
+    /// Panics if `self` and `x` have different moduli.
+    ///
+    /// # Examples
+    /// See [here](super::evaluate#evaluate).
+    pub fn evaluate(&self, x: &ModNatural<'a>) -> ModNatural<'a> {
+        let mut coeffs = self.coeffs.iter().rev();
+        let mut sum = match coeffs.next() {
+            Some(c) => c.clone(),
+            None => return x.zero_like(),
+        };
+        for c in coeffs {
+            sum *= x;
+            sum += c;
+        }
+        sum
+    }
+
+    /// Returns the derivative of a [`Polynomial`] modulo $m$.
+    ///
+    /// $f(p) = p'$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the total number of bits in the
+    /// coefficients.
+    ///
+    /// # Examples
+    /// See [here](super::evaluate#derivative).
+    #[inline]
+    pub fn derivative(&self) -> Polynomial<ModNatural<'a>> {
+        derivative_helper(&self.coeffs)
+    }
+}
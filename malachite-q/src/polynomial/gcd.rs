@@ -0,0 +1,625 @@
+use crate::polynomial::content::{clear_denominators, content_helper, make_primitive};
+use crate::polynomial::div::{div_rem_mod_helper, pseudo_div_rem_helper};
+use crate::polynomial::{Coefficient, Polynomial};
+use crate::Rational;
+use malachite_base::num::arithmetic::traits::{DivExact, Gcd, NegAssign, Pow};
+use malachite_base::num::basic::traits::One;
+use malachite_nz::integer::Integer;
+use malachite_nz::natural::arithmetic::mod_natural::ModNatural;
+use std::mem::swap;
+
+// Negates `xs` if its last element is negative.
+fn make_leading_coefficient_positive(xs: &mut [Integer]) {
+    if xs.last().map_or(false, |x| *x < 0u32) {
+        for x in xs {
+            x.neg_assign();
+        }
+    }
+}
+
+// Returns the primitive GCD of two nonzero primitive polynomials `xs` and `ys`, where `xs` has at
+// least as many coefficients as `ys`, using the primitive polynomial remainder sequence: each
+// pseudo-remainder is divided by its content.
+fn primitive_prs_gcd(mut xs: Vec<Integer>, mut ys: Vec<Integer>) -> Vec<Integer> {
+    loop {
+        let mut r = pseudo_div_rem_helper(&xs, &ys).1;
+        if r.is_empty() {
+            return ys;
+        }
+        make_primitive(&mut r);
+        xs = ys;
+        ys = r;
+    }
+}
+
+// Returns the primitive GCD of two nonzero primitive polynomials `xs` and `ys`, where `xs` has at
+// least as many coefficients as `ys`, using the subresultant polynomial remainder sequence. Each
+// pseudo-remainder is divided by a factor computed from the earlier leading coefficients, which
+// keeps the coefficients small without computing any contents. See Algorithm 3.3.1 of Henri
+// Cohen, *A Course in Computational Algebraic Number Theory*.
+fn subresultant_prs_gcd(mut xs: Vec<Integer>, mut ys: Vec<Integer>) -> Vec<Integer> {
+    let mut g = Integer::ONE;
+    let mut h = Integer::ONE;
+    loop {
+        let delta = (xs.len() - ys.len()) as u64;
+        let r = pseudo_div_rem_helper(&xs, &ys).1;
+        if r.is_empty() {
+            make_primitive(&mut ys);
+            return ys;
+        }
+        if r.len() == 1 {
+            return vec![Integer::ONE];
+        }
+        let divisor = &g * (&h).pow(delta);
+        xs = ys;
+        ys = r.into_iter().map(|c| c.div_exact(&divisor)).collect();
+        g = xs.last().unwrap().clone();
+        if delta != 0 {
+            h = (&g).pow(delta).div_exact(h.pow(delta - 1));
+        }
+    }
+}
+
+fn gcd_integer_helper(xs: &[Integer], ys: &[Integer], subresultant: bool) -> Polynomial<Integer> {
+    let mut coeffs = if xs.is_empty() || ys.is_empty() {
+        if xs.is_empty() {
+            ys.to_vec()
+        } else {
+            xs.to_vec()
+        }
+    } else {
+        let content = Integer::from(content_helper(xs).gcd(content_helper(ys)));
+        let mut xs = xs.to_vec();
+        let mut ys = ys.to_vec();
+        make_primitive(&mut xs);
+        make_primitive(&mut ys);
+        if xs.len() < ys.len() {
+            swap(&mut xs, &mut ys);
+        }
+        let mut gcd = if subresultant {
+            subresultant_prs_gcd(xs, ys)
+        } else {
+            primitive_prs_gcd(xs, ys)
+        };
+        for c in &mut gcd {
+            *c *= &content;
+        }
+        gcd
+    };
+    make_leading_coefficient_positive(&mut coeffs);
+    Polynomial { coeffs }
+}
+
+#[inline]
+fn gcd_primitive_helper(xs: &[Integer], ys: &[Integer]) -> Polynomial<Integer> {
+    gcd_integer_helper(xs, ys, false)
+}
+
+fn gcd_rational_helper(xs: &[Rational], ys: &[Rational]) -> Polynomial<Rational> {
+    let xs = clear_denominators(xs).0;
+    let ys = clear_denominators(ys).0;
+    let gcd = gcd_integer_helper(&xs, &ys, false).coeffs;
+    let leading_coefficient = match gcd.last() {
+        Some(c) => c.clone(),
+        None => return Polynomial { coeffs: Vec::new() },
+    };
+    Polynomial {
+        coeffs: gcd
+            .into_iter()
+            .map(|c| Rational::from_integers(c, leading_coefficient.clone()))
+            .collect(),
+    }
+}
+
+fn gcd_mod_helper<'a>(xs: &[ModNatural<'a>], ys: &[ModNatural<'a>]) -> Polynomial<ModNatural<'a>> {
+    ModNatural::check_compatible(xs, ys);
+    let mut xs = xs.to_vec();
+    let mut ys = ys.to_vec();
+    while !ys.is_empty() {
+        let r = div_rem_mod_helper(&xs, &ys).1;
+        xs = ys;
+        ys = r.coeffs;
+    }
+    if let Some(c) = xs.last() {
+        let inverse = c
+            .inverse()
+            .expect("The leading coefficient of a remainder is not invertible");
+        for x in &mut xs {
+            *x *= &inverse;
+        }
+    }
+    Polynomial { coeffs: xs }
+}
+
+impl Polynomial<Integer> {
+    /// Computes the GCD (greatest common divisor) of two [`Polynomial`]s with [`Integer`]
+    /// coefficients, using the subresultant polynomial remainder sequence.
+    ///
+    /// The result is the same as the result of [`gcd`](Gcd::gcd), which uses the primitive
+    /// polynomial remainder sequence instead. The subresultant algorithm avoids computing the
+    /// content of each remainder, at the cost of keeping somewhat larger coefficients.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, b) = O(n^3 M(nb))$
+    ///
+    /// $M(n, b) = O(n^2 b)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is the larger degree, $b$ is the number of
+    /// bits in the largest coefficient, and $M(b)$ is the time needed to multiply two $b$-bit
+    /// numbers.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::integer::Integer;
+    /// use malachite_q::polynomial::Polynomial;
+    ///
+    /// // gcd(2x^2 - 2, 4x + 4) = 2(x + 1)
+    /// let p = Polynomial::from(vec![Integer::from(-2), Integer::from(0), Integer::from(2)]);
+    /// let q = Polynomial::from(vec![Integer::from(4), Integer::from(4)]);
+    /// assert_eq!(p.subresultant_gcd(&q).to_string(), "2*x+2");
+    /// ```
+    #[inline]
+    pub fn subresultant_gcd(&self, other: &Polynomial<Integer>) -> Polynomial<Integer> {
+        gcd_integer_helper(&self.coeffs, &other.coeffs, true)
+    }
+}
+
+impl Gcd<Polynomial<Integer>> for Polynomial<Integer> {
+    type Output = Polynomial<Integer>;
+
+    /// Computes the GCD (greatest common divisor) of two [`Polynomial`]s with [`Integer`]
+    /// coefficients, taking both by value.
+    ///
+    /// The GCD is only defined up to sign; the result has a positive leading coefficient. Its
+    /// content is the GCD of the contents of the inputs. The GCD of 0 and 0 is 0. The GCD is
+    /// computed using the primitive polynomial remainder sequence; see also
+    /// [`subresultant_gcd`](Polynomial::subresultant_gcd).
+    ///
+    /// # Worst-case complexity
+    /// $T(n, b) = O(n^3 M(nb))$
+    ///
+    /// $M(n, b) = O(n^2 b)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is the larger degree, $b$ is the number of
+    /// bits in the largest coefficient, and $M(b)$ is the time needed to multiply two $b$-bit
+    /// numbers.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::Gcd;
+    /// use malachite_nz::integer::Integer;
+    /// use malachite_q::polynomial::Polynomial;
+    ///
+    /// let p = Polynomial::from(vec![Integer::from(-2), Integer::from(0), Integer::from(2)]);
+    /// let q = Polynomial::from(vec![Integer::from(4), Integer::from(4)]);
+    /// assert_eq!(p.gcd(q).to_string(), "2*x+2");
+    /// ```
+    #[inline]
+    fn gcd(self, other: Polynomial<Integer>) -> Polynomial<Integer> {
+        gcd_primitive_helper(&self.coeffs, &other.coeffs)
+    }
+}
+
+impl<'a> Gcd<&'a Polynomial<Integer>> for Polynomial<Integer> {
+    type Output = Polynomial<Integer>;
+
+    /// Computes the GCD (greatest common divisor) of two [`Polynomial`]s with [`Integer`]
+    /// coefficients, taking the first by value and the second by reference.
+    ///
+    /// The GCD is only defined up to sign; the result has a positive leading coefficient. Its
+    /// content is the GCD of the contents of the inputs. The GCD of 0 and 0 is 0. The GCD is
+    /// computed using the primitive polynomial remainder sequence; see also
+    /// [`subresultant_gcd`](Polynomial::subresultant_gcd).
+    ///
+    /// # Worst-case complexity
+    /// $T(n, b) = O(n^3 M(nb))$
+    ///
+    /// $M(n, b) = O(n^2 b)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is the larger degree, $b$ is the number of
+    /// bits in the largest coefficient, and $M(b)$ is the time needed to multiply two $b$-bit
+    /// numbers.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::Gcd;
+    /// use malachite_nz::integer::Integer;
+    /// use malachite_q::polynomial::Polynomial;
+    ///
+    /// let p = Polynomial::from(vec![Integer::from(-2), Integer::from(0), Integer::from(2)]);
+    /// let q = Polynomial::from(vec![Integer::from(4), Integer::from(4)]);
+    /// assert_eq!(p.gcd(&q).to_string(), "2*x+2");
+    /// ```
+    #[inline]
+    fn gcd(self, other: &'a Polynomial<Integer>) -> Polynomial<Integer> {
+        gcd_primitive_helper(&self.coeffs, &other.coeffs)
+    }
+}
+
+impl<'a> Gcd<Polynomial<Integer>> for &'a Polynomial<Integer> {
+    type Output = Polynomial<Integer>;
+
+    /// Computes the GCD (greatest common divisor) of two [`Polynomial`]s with [`Integer`]
+    /// coefficients, taking the first by reference and the second by value.
+    ///
+    /// The GCD is only defined up to sign; the result has a positive leading coefficient. Its
+    /// content is the GCD of the contents of the inputs. The GCD of 0 and 0 is 0. The GCD is
+    /// computed using the primitive polynomial remainder sequence; see also
+    /// [`subresultant_gcd`](Polynomial::subresultant_gcd).
+    ///
+    /// # Worst-case complexity
+    /// $T(n, b) = O(n^3 M(nb))$
+    ///
+    /// $M(n, b) = O(n^2 b)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is the larger degree, $b$ is the number of
+    /// bits in the largest coefficient, and $M(b)$ is the time needed to multiply two $b$-bit
+    /// numbers.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::Gcd;
+    /// use malachite_nz::integer::Integer;
+    /// use malachite_q::polynomial::Polynomial;
+    ///
+    /// let p = Polynomial::from(vec![Integer::from(-2), Integer::from(0), Integer::from(2)]);
+    /// let q = Polynomial::from(vec![Integer::from(4), Integer::from(4)]);
+    /// assert_eq!((&p).gcd(q).to_string(), "2*x+2");
+    /// ```
+    #[inline]
+    fn gcd(self, other: Polynomial<Integer>) -> Polynomial<Integer> {
+        gcd_primitive_helper(&self.coeffs, &other.coeffs)
+    }
+}
+
+impl<'a, 'b> Gcd<&'a Polynomial<Integer>> for &'b Polynomial<Integer> {
+    type Output = Polynomial<Integer>;
+
+    /// Computes the GCD (greatest common divisor) of two [`Polynomial`]s with [`Integer`]
+    /// coefficients, taking both by reference.
+    ///
+    /// The GCD is only defined up to sign; the result has a positive leading coefficient. Its
+    /// content is the GCD of the contents of the inputs. The GCD of 0 and 0 is 0. The GCD is
+    /// computed using the primitive polynomial remainder sequence; see also
+    /// [`subresultant_gcd`](Polynomial::subresultant_gcd).
+    ///
+    /// # Worst-case complexity
+    /// $T(n, b) = O(n^3 M(nb))$
+    ///
+    /// $M(n, b) = O(n^2 b)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is the larger degree, $b$ is the number of
+    /// bits in the largest coefficient, and $M(b)$ is the time needed to multiply two $b$-bit
+    /// numbers.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::Gcd;
+    /// use malachite_nz::integer::Integer;
+    /// use malachite_q::polynomial::Polynomial;
+    ///
+    /// let p = Polynomial::from(vec![Integer::from(-2), Integer::from(0), Integer::from(2)]);
+    /// let q = Polynomial::from(vec![Integer::from(4), Integer::from(4)]);
+    /// assert_eq!((&p).gcd(&q).to_string(), "2*x+2");
+    /// ```
+    #[inline]
+    fn gcd(self, other: &'a Polynomial<Integer>) -> Polynomial<Integer> {
+        gcd_primitive_helper(&self.coeffs, &other.coeffs)
+    }
+}
+
+impl Gcd<Polynomial<Rational>> for Polynomial<Rational> {
+    type Output = Polynomial<Rational>;
+
+    /// Computes the GCD (greatest common divisor) of two [`Polynomial`]s with [`Rational`]
+    /// coefficients, taking both by value.
+    ///
+    /// The GCD is only defined up to a constant factor; the result is monic. The GCD of 0 and 0 is
+    /// 0. The GCD is computed from the primitive parts of the inputs using the primitive polynomial
+    /// remainder sequence, which avoids the coefficient growth of the Euclidean algorithm.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, b) = O(n^3 M(nb))$
+    ///
+    /// $M(n, b) = O(n^2 b)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is the larger degree, $b$ is the number of
+    /// bits in the largest coefficient, and $M(b)$ is the time needed to multiply two $b$-bit
+    /// numbers.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::Gcd;
+    /// use malachite_q::polynomial::Polynomial;
+    /// use malachite_q::Rational;
+    ///
+    /// let p = Polynomial::from(vec![Rational::from(-2), Rational::from(0), Rational::from(2)]);
+    /// let q = Polynomial::from(vec![Rational::from(4), Rational::from(4)]);
+    /// assert_eq!(p.gcd(q).to_string(), "x+1");
+    /// ```
+    #[inline]
+    fn gcd(self, other: Polynomial<Rational>) -> Polynomial<Rational> {
+        gcd_rational_helper(&self.coeffs, &other.coeffs)
+    }
+}
+
+impl<'a> Gcd<&'a Polynomial<Rational>> for Polynomial<Rational> {
+    type Output = Polynomial<Rational>;
+
+    /// Computes the GCD (greatest common divisor) of two [`Polynomial`]s with [`Rational`]
+    /// coefficients, taking the first by value and the second by reference.
+    ///
+    /// The GCD is only defined up to a constant factor; the result is monic. The GCD of 0 and 0 is
+    /// 0. The GCD is computed from the primitive parts of the inputs using the primitive polynomial
+    /// remainder sequence, which avoids the coefficient growth of the Euclidean algorithm.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, b) = O(n^3 M(nb))$
+    ///
+    /// $M(n, b) = O(n^2 b)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is the larger degree, $b$ is the number of
+    /// bits in the largest coefficient, and $M(b)$ is the time needed to multiply two $b$-bit
+    /// numbers.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::Gcd;
+    /// use malachite_q::polynomial::Polynomial;
+    /// use malachite_q::Rational;
+    ///
+    /// let p = Polynomial::from(vec![Rational::from(-2), Rational::from(0), Rational::from(2)]);
+    /// let q = Polynomial::from(vec![Rational::from(4), Rational::from(4)]);
+    /// assert_eq!(p.gcd(&q).to_string(), "x+1");
+    /// ```
+    #[inline]
+    fn gcd(self, other: &'a Polynomial<Rational>) -> Polynomial<Rational> {
+        gcd_rational_helper(&self.coeffs, &other.coeffs)
+    }
+}
+
+impl<'a> Gcd<Polynomial<Rational>> for &'a Polynomial<Rational> {
+    type Output = Polynomial<Rational>;
+
+    /// Computes the GCD (greatest common divisor) of two [`Polynomial`]s with [`Rational`]
+    /// coefficients, taking the first by reference and the second by value.
+    ///
+    /// The GCD is only defined up to a constant factor; the result is monic. The GCD of 0 and 0 is
+    /// 0. The GCD is computed from the primitive parts of the inputs using the primitive polynomial
+    /// remainder sequence, which avoids the coefficient growth of the Euclidean algorithm.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, b) = O(n^3 M(nb))$
+    ///
+    /// $M(n, b) = O(n^2 b)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is the larger degree, $b$ is the number of
+    /// bits in the largest coefficient, and $M(b)$ is the time needed to multiply two $b$-bit
+    /// numbers.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::Gcd;
+    /// use malachite_q::polynomial::Polynomial;
+    /// use malachite_q::Rational;
+    ///
+    /// let p = Polynomial::from(vec![Rational::from(-2), Rational::from(0), Rational::from(2)]);
+    /// let q = Polynomial::from(vec![Rational::from(4), Rational::from(4)]);
+    /// assert_eq!((&p).gcd(q).to_string(), "x+1");
+    /// ```
+    #[inline]
+    fn gcd(self, other: Polynomial<Rational>) -> Polynomial<Rational> {
+        gcd_rational_helper(&self.coeffs, &other.coeffs)
+    }
+}
+
+impl<'a, 'b> Gcd<&'a Polynomial<Rational>> for &'b Polynomial<Rational> {
+    type Output = Polynomial<Rational>;
+
+    /// Computes the GCD (greatest common divisor) of two [`Polynomial`]s with [`Rational`]
+    /// coefficients, taking both by reference.
+    ///
+    /// The GCD is only defined up to a constant factor; the result is monic. The GCD of 0 and 0 is
+    /// 0. The GCD is computed from the primitive parts of the inputs using the primitive polynomial
+    /// remainder sequence, which avoids the coefficient growth of the Euclidean algorithm.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, b) = O(n^3 M(nb))$
+    ///
+    /// $M(n, b) = O(n^2 b)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is the larger degree, $b$ is the number of
+    /// bits in the largest coefficient, and $M(b)$ is the time needed to multiply two $b$-bit
+    /// numbers.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::Gcd;
+    /// use malachite_q::polynomial::Polynomial;
+    /// use malachite_q::Rational;
+    ///
+    /// let p = Polynomial::from(vec![Rational::from(-2), Rational::from(0), Rational::from(2)]);
+    /// let q = Polynomial::from(vec![Rational::from(4), Rational::from(4)]);
+    /// assert_eq!((&p).gcd(&q).to_string(), "x+1");
+    /// ```
+    #[inline]
+    fn gcd(self, other: &'a Polynomial<Rational>) -> Polynomial<Rational> {
+        gcd_rational_helper(&self.coeffs, &other.coeffs)
+    }
+}
+
+impl<'m> Gcd<Polynomial<ModNatural<'m>>> for Polynomial<ModNatural<'m>> {
+    type Output = Polynomial<ModNatural<'m>>;
+
+    /// Computes the GCD (greatest common divisor) of two [`Polynomial`]s modulo $m$, taking both by
+    /// value.
+    ///
+    /// The GCD is only defined up to a unit factor; the result is monic. The GCD of 0 and 0 is 0.
+    /// The GCD is computed using the Euclidean algorithm, which requires the leading coefficient of
+    /// each remainder to be invertible; this is always the case when $m$ is prime.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, b) = O(n^3 M(nb))$
+    ///
+    /// $M(n, b) = O(n^2 b)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is the larger degree, $b$ is the number of
+    /// bits in the largest coefficient, and $M(b)$ is the time needed to multiply two $b$-bit
+    /// numbers.
+    ///
+    /// # Panics
+    /// Panics if the leading coefficient of a remainder is not invertible modulo $m$, or if `self`
+    /// and `other` have different moduli.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::Gcd;
+    /// use malachite_base::num::basic::traits::Zero;
+    /// use malachite_nz::natural::Natural;
+    /// use malachite_q::polynomial::Polynomial;
+    ///
+    /// let m = Natural::from(5u32);
+    /// let p = Polynomial::from_naturals(
+    ///     vec![Natural::from(4u32), Natural::ZERO, Natural::from(1u32)],
+    ///     &m,
+    /// );
+    /// let q = Polynomial::from_naturals(vec![Natural::from(2u32), Natural::from(3u32)], &m);
+    /// assert_eq!(p.gcd(q).to_string(), "x+4 mod 5");
+    /// ```
+    #[inline]
+    fn gcd(self, other: Polynomial<ModNatural<'m>>) -> Polynomial<ModNatural<'m>> {
+        gcd_mod_helper(&self.coeffs, &other.coeffs)
+    }
+}
+
+impl<'a, 'm> Gcd<&'a Polynomial<ModNatural<'m>>> for Polynomial<ModNatural<'m>> {
+    type Output = Polynomial<ModNatural<'m>>;
+
+    /// Computes the GCD (greatest common divisor) of two [`Polynomial`]s modulo $m$, taking the
+    /// first by value and the second by reference.
+    ///
+    /// The GCD is only defined up to a unit factor; the result is monic. The GCD of 0 and 0 is 0.
+    /// The GCD is computed using the Euclidean algorithm, which requires the leading coefficient of
+    /// each remainder to be invertible; this is always the case when $m$ is prime.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, b) = O(n^3 M(nb))$
+    ///
+    /// $M(n, b) = O(n^2 b)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is the larger degree, $b$ is the number of
+    /// bits in the largest coefficient, and $M(b)$ is the time needed to multiply two $b$-bit
+    /// numbers.
+    ///
+    /// # Panics
+    /// Panics if the leading coefficient of a remainder is not invertible modulo $m$, or if `self`
+    /// and `other` have different moduli.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::Gcd;
+    /// use malachite_base::num::basic::traits::Zero;
+    /// use malachite_nz::natural::Natural;
+    /// use malachite_q::polynomial::Polynomial;
+    ///
+    /// let m = Natural::from(5u32);
+    /// let p = Polynomial::from_naturals(
+    ///     vec![Natural::from(4u32), Natural::ZERO, Natural::from(1u32)],
+    ///     &m,
+    /// );
+    /// let q = Polynomial::from_naturals(vec![Natural::from(2u32), Natural::from(3u32)], &m);
+    /// assert_eq!(p.gcd(&q).to_string(), "x+4 mod 5");
+    /// ```
+    #[inline]
+    fn gcd(self, other: &'a Polynomial<ModNatural<'m>>) -> Polynomial<ModNatural<'m>> {
+        gcd_mod_helper(&self.coeffs, &other.coeffs)
+    }
+}
+
+impl<'a, 'm> Gcd<Polynomial<ModNatural<'m>>> for &'a Polynomial<ModNatural<'m>> {
+    type Output = Polynomial<ModNatural<'m>>;
+
+    /// Computes the GCD (greatest common divisor) of two [`Polynomial`]s modulo $m$, taking the
+    /// first by reference and the second by value.
+    ///
+    /// The GCD is only defined up to a unit factor; the result is monic. The GCD of 0 and 0 is 0.
+    /// The GCD is computed using the Euclidean algorithm, which requires the leading coefficient of
+    /// each remainder to be invertible; this is always the case when $m$ is prime.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, b) = O(n^3 M(nb))$
+    ///
+    /// $M(n, b) = O(n^2 b)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is the larger degree, $b$ is the number of
+    /// bits in the largest coefficient, and $M(b)$ is the time needed to multiply two $b$-bit
+    /// numbers.
+    ///
+    /// # Panics
+    /// Panics if the leading coefficient of a remainder is not invertible modulo $m$, or if `self`
+    /// and `other` have different moduli.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::Gcd;
+    /// use malachite_base::num::basic::traits::Zero;
+    /// use malachite_nz::natural::Natural;
+    /// use malachite_q::polynomial::Polynomial;
+    ///
+    /// let m = Natural::from(5u32);
+    /// let p = Polynomial::from_naturals(
+    ///     vec![Natural::from(4u32), Natural::ZERO, Natural::from(1u32)],
+    ///     &m,
+    /// );
+    /// let q = Polynomial::from_naturals(vec![Natural::from(2u32), Natural::from(3u32)], &m);
+    /// assert_eq!((&p).gcd(q).to_string(), "x+4 mod 5");
+    /// ```
+    #[inline]
+    fn gcd(self, other: Polynomial<ModNatural<'m>>) -> Polynomial<ModNatural<'m>> {
+        gcd_mod_helper(&self.coeffs, &other.coeffs)
+    }
+}
+
+impl<'a, 'b, 'm> Gcd<&'a Polynomial<ModNatural<'m>>> for &'b Polynomial<ModNatural<'m>> {
+    type Output = Polynomial<ModNatural<'m>>;
+
+    /// Computes the GCD (greatest common divisor) of two [`Polynomial`]s modulo $m$, taking both by
+    /// reference.
+    ///
+    /// The GCD is only defined up to a unit factor; the result is monic. The GCD of 0 and 0 is 0.
+    /// The GCD is computed using the Euclidean algorithm, which requires the leading coefficient of
+    /// each remainder to be invertible; this is always the case when $m$ is prime.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, b) = O(n^3 M(nb))$
+    ///
+    /// $M(n, b) = O(n^2 b)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is the larger degree, $b$ is the number of
+    /// bits in the largest coefficient, and $M(b)$ is the time needed to multiply two $b$-bit
+    /// numbers.
+    ///
+    /// # Panics
+    /// Panics if the leading coefficient of a remainder is not invertible modulo $m$, or if `self`
+    /// and `other` have different moduli.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::Gcd;
+    /// use malachite_base::num::basic::traits::Zero;
+    /// use malachite_nz::natural::Natural;
+    /// use malachite_q::polynomial::Polynomial;
+    ///
+    /// let m = Natural::from(5u32);
+    /// let p = Polynomial::from_naturals(
+    ///     vec![Natural::from(4u32), Natural::ZERO, Natural::from(1u32)],
+    ///     &m,
+    /// );
+    /// let q = Polynomial::from_naturals(vec![Natural::from(2u32), Natural::from(3u32)], &m);
+    /// assert_eq!((&p).gcd(&q).to_string(), "x+4 mod 5");
+    /// ```
+    #[inline]
+    fn gcd(self, other: &'a Polynomial<ModNatural<'m>>) -> Polynomial<ModNatural<'m>> {
+        gcd_mod_helper(&self.coeffs, &other.coeffs)
+    }
+}
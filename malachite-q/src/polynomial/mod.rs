@@ -0,0 +1,720 @@
+use crate::Rational;
+use malachite_base::num::arithmetic::traits::{Abs, Mod, UnsignedAbs};
+use malachite_base::num::basic::traits::Zero;
+use malachite_nz::integer::Integer;
+use malachite_nz::natural::arithmetic::mod_natural::ModNatural;
+use malachite_nz::natural::Natural;
+use std::fmt::{Debug, Display, Formatter, Result, Write};
+
+/// A dense univariate polynomial.
+///
+/// The coefficients are usually [`Integer`]s, [`Rational`]s, or [`ModNatural`]s. In the last case
+/// the polynomial is an element of $(\Z/m\Z)\[x\]$, and all of its coefficients must have the same
+/// modulus $m$; operations that combine polynomials with different moduli panic.
+///
+/// The coefficients are stored from the constant term up, and the last coefficient is never zero,
+/// so two polynomials are equal exactly when their coefficient lists are. The zero polynomial has
+/// no coefficients.
+#[derive(Clone, Hash, Eq, PartialEq)]
+pub struct Polynomial<T> {
+    // The coefficients, from the constant term up. The last one is nonzero.
+    pub(crate) coeffs: Vec<T>,
+}
+
+// The operations that the generic polynomial algorithms need from a coefficient, beyond the
+// arithmetic operators.
+pub(crate) trait Coefficient: Clone + Eq {
+    fn is_zero(&self) -> bool;
+
+    // Returns 0. A `ModNatural` zero gets the modulus of `self`.
+    fn zero_like(&self) -> Self;
+
+    // Returns `self` times `n`.
+    fn mul_usize(&self, n: usize) -> Self;
+
+    // Panics unless the coefficients in `xs` can be combined with the coefficients in `ys`; that
+    // is, unless they are residues with the same modulus, or are not residues at all.
+    fn check_compatible(_xs: &[Self], _ys: &[Self]) {}
+}
+
+impl Coefficient for Integer {
+    #[inline]
+    fn is_zero(&self) -> bool {
+        *self == 0u32
+    }
+
+    #[inline]
+    fn zero_like(&self) -> Integer {
+        Integer::ZERO
+    }
+
+    #[inline]
+    fn mul_usize(&self, n: usize) -> Integer {
+        self * Integer::from(n)
+    }
+}
+
+impl Coefficient for Rational {
+    #[inline]
+    fn is_zero(&self) -> bool {
+        *self == 0u32
+    }
+
+    #[inline]
+    fn zero_like(&self) -> Rational {
+        Rational::ZERO
+    }
+
+    #[inline]
+    fn mul_usize(&self, n: usize) -> Rational {
+        self * Rational::from(n)
+    }
+}
+
+impl<'a> Coefficient for ModNatural<'a> {
+    #[inline]
+    fn is_zero(&self) -> bool {
+        *self.value() == 0u32
+    }
+
+    #[inline]
+    fn zero_like(&self) -> ModNatural<'a> {
+        ModNatural::new(Natural::ZERO, self.modulus())
+    }
+
+    #[inline]
+    fn mul_usize(&self, n: usize) -> ModNatural<'a> {
+        self * ModNatural::reduce(Natural::from(n), self.modulus())
+    }
+
+    #[inline]
+    fn check_compatible(xs: &[ModNatural<'a>], ys: &[ModNatural<'a>]) {
+        assert_same_modulus(xs, ys);
+    }
+}
+
+// Removes the zero coefficients at the top of `coeffs`.
+pub(crate) fn trim<T: Coefficient>(coeffs: &mut Vec<T>) {
+    while coeffs.last().map_or(false, Coefficient::is_zero) {
+        coeffs.pop();
+    }
+}
+
+// Panics unless the coefficients in `xs` and `ys` have the same modulus.
+fn assert_same_modulus(xs: &[ModNatural], ys: &[ModNatural]) {
+    if let (Some(x), Some(y)) = (xs.first(), ys.first()) {
+        assert!(
+            std::ptr::eq(x.modulus(), y.modulus()) || x.modulus() == y.modulus(),
+            "Cannot combine polynomials with different moduli: {} and {}",
+            x.modulus(),
+            y.modulus()
+        );
+    }
+}
+
+impl<T> Polynomial<T> {
+    /// Returns the coefficients of a [`Polynomial`], from the constant term up.
+    ///
+    /// The last coefficient is never zero; the zero polynomial has no coefficients.
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::integer::Integer;
+    /// use malachite_q::polynomial::Polynomial;
+    ///
+    /// let p = Polynomial::from(vec![Integer::from(1), Integer::from(-2)]);
+    /// assert_eq!(p.coefficients(), &[1, -2]);
+    /// ```
+    pub fn coefficients(&self) -> &[T] {
+        &self.coeffs
+    }
+
+    /// Consumes a [`Polynomial`] and returns its coefficients, from the constant term up.
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::integer::Integer;
+    /// use malachite_q::polynomial::Polynomial;
+    ///
+    /// let p = Polynomial::from(vec![Integer::from(1), Integer::from(-2)]);
+    /// assert_eq!(p.into_coefficients(), [1, -2]);
+    /// ```
+    #[allow(clippy::missing_const_for_fn)]
+    pub fn into_coefficients(self) -> Vec<T> {
+        self.coeffs
+    }
+
+    /// Returns the degree of a [`Polynomial`], or `None` if it is zero.
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::Zero;
+    /// use malachite_nz::integer::Integer;
+    /// use malachite_q::polynomial::Polynomial;
+    ///
+    /// let p = Polynomial::from(vec![Integer::from(1), Integer::from(-2)]);
+    /// assert_eq!(p.degree(), Some(1));
+    /// assert_eq!(Polynomial::<Integer>::ZERO.degree(), None);
+    /// ```
+    pub fn degree(&self) -> Option<usize> {
+        self.coeffs.len().checked_sub(1)
+    }
+
+    /// Returns the leading coefficient of a [`Polynomial`], or `None` if it is zero.
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::Zero;
+    /// use malachite_nz::integer::Integer;
+    /// use malachite_q::polynomial::Polynomial;
+    ///
+    /// let p = Polynomial::from(vec![Integer::from(1), Integer::from(-2)]);
+    /// assert_eq!(p.leading_coefficient(), Some(&Integer::from(-2)));
+    /// assert_eq!(Polynomial::<Integer>::ZERO.leading_coefficient(), None);
+    /// ```
+    pub fn leading_coefficient(&self) -> Option<&T> {
+        self.coeffs.last()
+    }
+}
+
+macro_rules! impl_basic {
+    ($t: ident, $import: expr) => {
+        impl From<Vec<$t>> for Polynomial<$t> {
+            /// Creates a [`Polynomial`] from a [`Vec`] of coefficients, listed from the constant
+            /// term up.
+            ///
+            /// Zero coefficients at the end of the list are removed.
+            ///
+            /// # Worst-case complexity
+            /// $T(n) = O(n)$
+            ///
+            /// $M(n) = O(1)$
+            ///
+            /// where $T$ is time, $M$ is additional memory, and $n$ is `coefficients.len()`.
+            ///
+            /// # Examples
+            /// ```
+            #[doc = concat!("use ", $import, ";")]
+            /// use malachite_q::polynomial::Polynomial;
+            ///
+            /// let p = Polynomial::from(vec![
+            #[doc = concat!("    ", stringify!($t), "::from(3),")]
+            #[doc = concat!("    ", stringify!($t), "::from(-1),")]
+            #[doc = concat!("    ", stringify!($t), "::from(0),")]
+            /// ]);
+            /// assert_eq!(p.to_string(), "-x+3");
+            /// ```
+            fn from(mut coefficients: Vec<$t>) -> Polynomial<$t> {
+                trim(&mut coefficients);
+                Polynomial {
+                    coeffs: coefficients,
+                }
+            }
+        }
+
+        /// The constant 0.
+        impl Zero for Polynomial<$t> {
+            const ZERO: Polynomial<$t> = Polynomial { coeffs: Vec::new() };
+        }
+
+        impl Display for Polynomial<$t> {
+            /// Converts a [`Polynomial`] to a [`String`].
+            ///
+            /// The terms are written from the highest degree down, as in `2*x^2-x+1/2`.
+            /// Coefficients of 1 and $-1$ are omitted from nonconstant terms, and the zero
+            /// polynomial is written as `0`.
+            ///
+            /// # Worst-case complexity
+            /// $T(n) = O(n)$
+            ///
+            /// $M(n) = O(n)$
+            ///
+            /// where $T$ is time, $M$ is additional memory, and $n$ is the total number of bits
+            /// in the coefficients.
+            ///
+            /// # Examples
+            /// ```
+            /// use malachite_base::num::basic::traits::Zero;
+            #[doc = concat!("use ", $import, ";")]
+            /// use malachite_q::polynomial::Polynomial;
+            ///
+            #[doc = concat!(
+                                "assert_eq!(Polynomial::<",
+                                stringify!($t),
+                                ">::ZERO.to_string(), \"0\");"
+                            )]
+            /// let p = Polynomial::from(vec![
+            #[doc = concat!("    ", stringify!($t), "::from(5),")]
+            #[doc = concat!("    ", stringify!($t), "::from(-1),")]
+            #[doc = concat!("    ", stringify!($t), "::from(0),")]
+            #[doc = concat!("    ", stringify!($t), "::from(2),")]
+            /// ]);
+            /// assert_eq!(p.to_string(), "2*x^3-x+5");
+            /// ```
+            fn fmt(&self, f: &mut Formatter) -> Result {
+                if self.coeffs.is_empty() {
+                    return f.write_char('0');
+                }
+                let mut first = true;
+                for (i, c) in self.coeffs.iter().enumerate().rev() {
+                    if *c == 0u32 {
+                        continue;
+                    }
+                    if *c < 0u32 {
+                        f.write_char('-')?;
+                    } else if !first {
+                        f.write_char('+')?;
+                    }
+                    first = false;
+                    let abs = c.abs();
+                    if i == 0 {
+                        Display::fmt(&abs, f)?;
+                        continue;
+                    }
+                    if abs != 1u32 {
+                        Display::fmt(&abs, f)?;
+                        f.write_char('*')?;
+                    }
+                    f.write_char('x')?;
+                    if i != 1 {
+                        write!(f, "^{}", i)?;
+                    }
+                }
+                Ok(())
+            }
+        }
+
+        impl Debug for Polynomial<$t> {
+            /// Converts a [`Polynomial`] to a [`String`].
+            ///
+            /// This is the same as the [`Display::fmt`] implementation.
+            ///
+            /// # Worst-case complexity
+            /// $T(n) = O(n)$
+            ///
+            /// $M(n) = O(n)$
+            ///
+            /// where $T$ is time, $M$ is additional memory, and $n$ is the total number of bits
+            /// in the coefficients.
+            ///
+            /// # Examples
+            /// ```
+            /// use malachite_base::strings::ToDebugString;
+            #[doc = concat!("use ", $import, ";")]
+            /// use malachite_q::polynomial::Polynomial;
+            ///
+            /// let p = Polynomial::from(vec![
+            #[doc = concat!("    ", stringify!($t), "::from(-1),")]
+            #[doc = concat!("    ", stringify!($t), "::from(1),")]
+            /// ]);
+            /// assert_eq!(p.to_debug_string(), "x-1");
+            /// ```
+            #[inline]
+            fn fmt(&self, f: &mut Formatter) -> Result {
+                Display::fmt(self, f)
+            }
+        }
+    };
+}
+impl_basic!(Integer, "malachite_nz::integer::Integer");
+impl_basic!(Rational, "malachite_q::Rational");
+
+impl<'a> From<Vec<ModNatural<'a>>> for Polynomial<ModNatural<'a>> {
+    /// Creates a [`Polynomial`] from a [`Vec`] of coefficients, listed from the constant term up.
+    ///
+    /// Zero coefficients at the end of the list are removed.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(1)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `coefficients.len()`.
+    ///
+    /// # Panics
+    /// Panics if the coefficients do not all have the same modulus.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::natural::arithmetic::mod_natural::ModNatural;
+    /// use malachite_nz::natural::Natural;
+    /// use malachite_q::polynomial::Polynomial;
+    ///
+    /// let m = Natural::from(7u32);
+    /// let p = Polynomial::from(vec![
+    ///     ModNatural::new(Natural::from(3u32), &m),
+    ///     ModNatural::new(Natural::from(1u32), &m),
+    ///     ModNatural::new(Natural::from(0u32), &m),
+    /// ]);
+    /// assert_eq!(p.to_string(), "x+3 mod 7");
+    /// ```
+    fn from(mut coefficients: Vec<ModNatural<'a>>) -> Polynomial<ModNatural<'a>> {
+        for c in coefficients.iter().skip(1) {
+            assert_same_modulus(&coefficients[..1], std::slice::from_ref(c));
+        }
+        trim(&mut coefficients);
+        Polynomial {
+            coeffs: coefficients,
+        }
+    }
+}
+
+impl<'a> Polynomial<ModNatural<'a>> {
+    /// Creates a [`Polynomial`] modulo $m$ from a list of [`Natural`] coefficients, reducing
+    /// each of them modulo $m$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the total number of bits in the
+    /// coefficients.
+    ///
+    /// # Panics
+    /// Panics if `m` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::natural::Natural;
+    /// use malachite_q::polynomial::Polynomial;
+    ///
+    /// let m = Natural::from(7u32);
+    /// let p = Polynomial::from_naturals(
+    ///     vec![Natural::from(10u32), Natural::from(0u32), Natural::from(8u32)],
+    ///     &m,
+    /// );
+    /// assert_eq!(p.to_string(), "x^2+3 mod 7");
+    /// ```
+    pub fn from_naturals(coefficients: Vec<Natural>, m: &'a Natural) -> Polynomial<ModNatural<'a>> {
+        let mut coeffs: Vec<ModNatural> = coefficients
+            .into_iter()
+            .map(|c| ModNatural::reduce(c, m))
+            .collect();
+        trim(&mut coeffs);
+        Polynomial { coeffs }
+    }
+
+    /// Reduces a [`Polynomial`] with [`Integer`] coefficients modulo $m$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the total number of bits in the
+    /// coefficients.
+    ///
+    /// # Panics
+    /// Panics if `m` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::integer::Integer;
+    /// use malachite_nz::natural::arithmetic::mod_natural::ModNatural;
+    /// use malachite_nz::natural::Natural;
+    /// use malachite_q::polynomial::Polynomial;
+    ///
+    /// let m = Natural::from(7u32);
+    /// let p = Polynomial::from(vec![Integer::from(-1), Integer::from(14)]);
+    /// assert_eq!(
+    ///     Polynomial::<ModNatural>::from_integer_polynomial(&p, &m).to_string(),
+    ///     "6 mod 7"
+    /// );
+    /// ```
+    pub fn from_integer_polynomial(
+        p: &Polynomial<Integer>,
+        m: &'a Natural,
+    ) -> Polynomial<ModNatural<'a>> {
+        let modulus = Integer::from(m);
+        let mut coeffs: Vec<ModNatural> = p
+            .coeffs
+            .iter()
+            .map(|c| ModNatural::new(c.mod_op(&modulus).unsigned_abs(), m))
+            .collect();
+        trim(&mut coeffs);
+        Polynomial { coeffs }
+    }
+
+    /// Returns the [`Polynomial`] with [`Integer`] coefficients in $[0, m)$ that is congruent to
+    /// a [`Polynomial`] modulo $m$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the total number of bits in the
+    /// coefficients.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::natural::Natural;
+    /// use malachite_q::polynomial::Polynomial;
+    ///
+    /// let m = Natural::from(7u32);
+    /// let p = Polynomial::from_naturals(vec![Natural::from(10u32), Natural::from(1u32)], &m);
+    /// assert_eq!(p.to_integer_polynomial().to_string(), "x+3");
+    /// ```
+    pub fn to_integer_polynomial(&self) -> Polynomial<Integer> {
+        Polynomial {
+            coeffs: self
+                .coeffs
+                .iter()
+                .map(|c| Integer::from(c.value()))
+                .collect(),
+        }
+    }
+}
+
+impl<'a> Display for Polynomial<ModNatural<'a>> {
+    /// Converts a [`Polynomial`] modulo $m$ to a [`String`].
+    ///
+    /// The terms are written from the highest degree down, using the least nonnegative
+    /// representative of each coefficient and followed by the modulus, as in `x^2+3*x+6 mod 7`.
+    /// The zero polynomial is written as `0`.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the total number of bits in the
+    /// coefficients.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::natural::Natural;
+    /// use malachite_q::polynomial::Polynomial;
+    ///
+    /// let m = Natural::from(7u32);
+    /// let p = Polynomial::from_naturals(
+    ///     vec![Natural::from(6u32), Natural::from(3u32), Natural::from(1u32)],
+    ///     &m,
+    /// );
+    /// assert_eq!(p.to_string(), "x^2+3*x+6 mod 7");
+    /// assert_eq!(Polynomial::from_naturals(vec![], &m).to_string(), "0");
+    /// ```
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        let last = match self.coeffs.last() {
+            Some(last) => last,
+            None => return f.write_char('0'),
+        };
+        let mut first = true;
+        for (i, c) in self.coeffs.iter().enumerate().rev() {
+            let c = c.value();
+            if *c == 0u32 {
+                continue;
+            }
+            if !first {
+                f.write_char('+')?;
+            }
+            first = false;
+            if i == 0 {
+                Display::fmt(c, f)?;
+                continue;
+            }
+            if *c != 1u32 {
+                Display::fmt(c, f)?;
+                f.write_char('*')?;
+            }
+            f.write_char('x')?;
+            if i != 1 {
+                write!(f, "^{}", i)?;
+            }
+        }
+        write!(f, " mod {}", last.modulus())
+    }
+}
+
+impl<'a> Debug for Polynomial<ModNatural<'a>> {
+    /// Converts a [`Polynomial`] modulo $m$ to a [`String`].
+    ///
+    /// This is the same as the [`Display::fmt`] implementation.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the total number of bits in the
+    /// coefficients.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::strings::ToDebugString;
+    /// use malachite_nz::natural::Natural;
+    /// use malachite_q::polynomial::Polynomial;
+    ///
+    /// let m = Natural::from(5u32);
+    /// let p = Polynomial::from_naturals(vec![Natural::from(4u32), Natural::from(2u32)], &m);
+    /// assert_eq!(p.to_debug_string(), "2*x+4 mod 5");
+    /// ```
+    #[inline]
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        Display::fmt(self, f)
+    }
+}
+
+impl<'a> From<&'a Polynomial<Integer>> for Polynomial<Rational> {
+    /// Converts a [`Polynomial`] with [`Integer`] coefficients to a [`Polynomial`] with
+    /// [`Rational`] coefficients, taking the [`Polynomial`] by reference.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the total number of bits in the
+    /// coefficients.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::integer::Integer;
+    /// use malachite_q::polynomial::Polynomial;
+    /// use malachite_q::Rational;
+    ///
+    /// let p = Polynomial::from(vec![Integer::from(1), Integer::from(-2)]);
+    /// assert_eq!(Polynomial::<Rational>::from(&p).to_string(), "-2*x+1");
+    /// ```
+    fn from(p: &'a Polynomial<Integer>) -> Polynomial<Rational> {
+        Polynomial {
+            coeffs: p.coeffs.iter().map(Rational::from).collect(),
+        }
+    }
+}
+
+/// Addition, subtraction, and negation of [`Polynomial`]s.
+pub mod arithmetic;
+/// Contents and primitive parts of [`Polynomial`]s.
+///
+/// # content
+/// ```
+/// use malachite_nz::integer::Integer;
+/// use malachite_q::polynomial::Polynomial;
+/// use malachite_q::Rational;
+///
+/// let p = Polynomial::from(vec![Integer::from(6), Integer::from(-4)]);
+/// assert_eq!(p.content(), 2);
+///
+/// let p = Polynomial::from(vec![Rational::from_signeds(3, 2), Rational::from(6)]);
+/// assert_eq!(p.content().to_string(), "3/2");
+/// ```
+///
+/// # primitive_part
+/// ```
+/// use malachite_nz::integer::Integer;
+/// use malachite_q::polynomial::Polynomial;
+/// use malachite_q::Rational;
+///
+/// let p = Polynomial::from(vec![Integer::from(6), Integer::from(-4)]);
+/// assert_eq!(p.primitive_part().to_string(), "-2*x+3");
+///
+/// let p = Polynomial::from(vec![Rational::from_signeds(3, 2), Rational::from(6)]);
+/// assert_eq!(p.primitive_part().to_string(), "4*x+1");
+/// ```
+pub mod content;
+/// Division of [`Polynomial`]s: pseudo-division over [`Integer`]s, and division with remainder
+/// over [`Rational`]s and over the integers modulo $m$.
+///
+/// # pseudo_div_rem
+/// ```
+/// use malachite_nz::integer::Integer;
+/// use malachite_q::polynomial::Polynomial;
+///
+/// // 8 * (x^3 + 1) = (4x^2 - 2x + 1)(2x + 1) + 7
+/// let p = Polynomial::from(vec![
+///     Integer::from(1),
+///     Integer::from(0),
+///     Integer::from(0),
+///     Integer::from(1),
+/// ]);
+/// let q = Polynomial::from(vec![Integer::from(1), Integer::from(2)]);
+/// let (quotient, remainder) = p.pseudo_div_rem(&q);
+/// assert_eq!(quotient.to_string(), "4*x^2-2*x+1");
+/// assert_eq!(remainder.to_string(), "7");
+/// ```
+pub mod div;
+/// Evaluation and derivatives of [`Polynomial`]s.
+///
+/// # evaluate
+/// ```
+/// use malachite_base::num::basic::traits::Zero;
+/// use malachite_nz::integer::Integer;
+/// use malachite_nz::natural::arithmetic::mod_natural::ModNatural;
+/// use malachite_nz::natural::Natural;
+/// use malachite_q::polynomial::Polynomial;
+/// use malachite_q::Rational;
+///
+/// let p = Polynomial::from(vec![Integer::from(-1), Integer::from(0), Integer::from(2)]);
+/// assert_eq!(p.evaluate(&Rational::from_signeds(1, 3)).to_string(), "-7/9");
+///
+/// let p = Polynomial::from(vec![Rational::from_signeds(1, 2), Rational::from(1)]);
+/// assert_eq!(p.evaluate(&Rational::from_signeds(1, 3)).to_string(), "5/6");
+///
+/// let m = Natural::from(7u32);
+/// let p = Polynomial::from_naturals(
+///     vec![Natural::from(1u32), Natural::ZERO, Natural::from(1u32)],
+///     &m,
+/// );
+/// assert_eq!(p.evaluate(&ModNatural::new(Natural::from(3u32), &m)).to_string(), "3 mod 7");
+/// ```
+///
+/// # derivative
+/// ```
+/// use malachite_base::num::basic::traits::Zero;
+/// use malachite_nz::integer::Integer;
+/// use malachite_nz::natural::Natural;
+/// use malachite_q::polynomial::Polynomial;
+///
+/// let p = Polynomial::from(vec![Integer::from(5), Integer::from(-1), Integer::from(3)]);
+/// assert_eq!(p.derivative().to_string(), "6*x-1");
+///
+/// // In characteristic 3, the derivative of x^3 is 0.
+/// let m = Natural::from(3u32);
+/// let p = Polynomial::from_naturals(
+///     vec![Natural::ZERO, Natural::from(1u32), Natural::ZERO, Natural::from(1u32)],
+///     &m,
+/// );
+/// assert_eq!(p.derivative().to_string(), "1 mod 3");
+/// ```
+pub mod evaluate;
+/// GCDs of [`Polynomial`]s.
+pub mod gcd;
+/// Multiplication of [`Polynomial`]s, using Kronecker substitution.
+pub mod mul;
+/// Isolation of the real roots of [`Polynomial`]s.
+///
+/// # isolate_real_roots
+/// ```
+/// use malachite_nz::integer::Integer;
+/// use malachite_q::polynomial::Polynomial;
+///
+/// // x^3 - 2x = x(x^2 - 2)
+/// let p = Polynomial::from(vec![
+///     Integer::from(0),
+///     Integer::from(-2),
+///     Integer::from(0),
+///     Integer::from(1),
+/// ]);
+/// let roots: Vec<String> = p.isolate_real_roots().iter().map(ToString::to_string).collect();
+/// assert_eq!(roots, ["[-8, -1/2]", "[0, 0]", "[1/2, 8]"]);
+///
+/// // 6x^2 - x - 1 = (2x - 1)(3x + 1)
+/// let p = Polynomial::from(vec![Integer::from(-1), Integer::from(-1), Integer::from(6)]);
+/// let roots: Vec<String> = p.isolate_real_roots().iter().map(ToString::to_string).collect();
+/// assert_eq!(roots, ["[-2, -1/16]", "[1/16, 2]"]);
+/// ```
+pub mod roots;
@@ -0,0 +1,546 @@
+use crate::polynomial::content::clear_denominators;
+use crate::polynomial::{trim, Coefficient, Polynomial};
+use crate::Rational;
+use malachite_base::num::arithmetic::traits::{
+    CeilingLogBase2, ModPowerOf2, PowerOf2, UnsignedAbs,
+};
+use malachite_base::num::basic::traits::Zero;
+use malachite_base::num::logic::traits::{BitAccess, SignificantBits};
+use malachite_nz::integer::Integer;
+use malachite_nz::natural::arithmetic::mod_natural::ModNatural;
+use std::ops::Mul;
+
+// Returns $\sum_i x_i 2^{ik}$, where $k$ is `bits`.
+fn pack(xs: &[Integer], bits: u64) -> Integer {
+    match xs.len() {
+        0 => Integer::ZERO,
+        1 => xs[0].clone(),
+        len => {
+            let half = len >> 1;
+            pack(&xs[..half], bits) + (pack(&xs[half..], bits) << (bits * half as u64))
+        }
+    }
+}
+
+// The inverse of `pack`: writes the `len` coefficients $x_i$ such that $x = \sum_i x_i 2^{ik}$ to
+// `out`, where $k$ is `bits`. Each $x_i$ must satisfy $|x_i| < 2^{k-1}$, which makes them unique.
+fn unpack(x: Integer, bits: u64, len: usize, out: &mut Vec<Integer>) {
+    if len == 1 {
+        out.push(x);
+        return;
+    }
+    let half = len >> 1;
+    let shift = bits * half as u64;
+    // The low coefficients contribute a value in $(-2^{s-1}, 2^{s-1})$, where $s$ is `shift`.
+    let mut low = Integer::from((&x).mod_power_of_2(shift));
+    if low.get_bit(shift - 1) {
+        low -= Integer::power_of_2(shift);
+    }
+    let high = (x - &low) >> shift;
+    unpack(low, bits, half, out);
+    unpack(high, bits, len - half, out);
+}
+
+// Multiplies two polynomials with `Integer` coefficients using Kronecker substitution: both are
+// evaluated at a power of 2 large enough to separate the coefficients of the product, the two
+// values are multiplied with a single large multiplication, and the product's coefficients are
+// read off from the result.
+pub(crate) fn mul_integer_helper(xs: &[Integer], ys: &[Integer]) -> Vec<Integer> {
+    if xs.is_empty() || ys.is_empty() {
+        return Vec::new();
+    }
+    let max_bits = |zs: &[Integer]| zs.iter().map(SignificantBits::significant_bits).max();
+    // Each coefficient of the product is a sum of at most `min(xs.len(), ys.len())` products, so
+    // its absolute value is less than `2^(bits - 1)`.
+    let bits = max_bits(xs).unwrap()
+        + max_bits(ys).unwrap()
+        + (xs.len().min(ys.len()) as u64).ceiling_log_base_2()
+        + 1;
+    let mut out = Vec::with_capacity(xs.len() + ys.len() - 1);
+    unpack(
+        pack(xs, bits) * pack(ys, bits),
+        bits,
+        xs.len() + ys.len() - 1,
+        &mut out,
+    );
+    out
+}
+
+fn mul_rational_helper(xs: &[Rational], ys: &[Rational]) -> Polynomial<Rational> {
+    let (xs, x_denominator) = clear_denominators(xs);
+    let (ys, y_denominator) = clear_denominators(ys);
+    let denominator = Integer::from(x_denominator * y_denominator);
+    Polynomial {
+        coeffs: mul_integer_helper(&xs, &ys)
+            .into_iter()
+            .map(|c| Rational::from_integers(c, denominator.clone()))
+            .collect(),
+    }
+}
+
+fn mul_mod_helper<'a>(xs: &[ModNatural<'a>], ys: &[ModNatural<'a>]) -> Polynomial<ModNatural<'a>> {
+    ModNatural::check_compatible(xs, ys);
+    let m = match xs.first() {
+        Some(x) => x.modulus(),
+        None => return Polynomial { coeffs: Vec::new() },
+    };
+    let lift = |zs: &[ModNatural]| {
+        zs.iter()
+            .map(|z| Integer::from(z.value()))
+            .collect::<Vec<_>>()
+    };
+    let mut coeffs: Vec<ModNatural> = mul_integer_helper(&lift(xs), &lift(ys))
+        .into_iter()
+        .map(|c| ModNatural::reduce(c.unsigned_abs(), m))
+        .collect();
+    trim(&mut coeffs);
+    Polynomial { coeffs }
+}
+
+fn mul_integer_polynomial_helper(xs: &[Integer], ys: &[Integer]) -> Polynomial<Integer> {
+    Polynomial {
+        coeffs: mul_integer_helper(xs, ys),
+    }
+}
+
+impl Mul<Polynomial<Integer>> for Polynomial<Integer> {
+    type Output = Polynomial<Integer>;
+
+    /// Multiplies two [`Polynomial`]s with [`Integer`] coefficients, taking both by value.
+    ///
+    /// $$
+    /// f(p, q) = pq.
+    /// $$
+    ///
+    /// The product is computed by Kronecker substitution, which reduces it to a single
+    /// multiplication of two large [`Integer`]s.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the number of bits needed to store
+    /// the product's coefficients.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::integer::Integer;
+    /// use malachite_q::polynomial::Polynomial;
+    ///
+    /// let p = Polynomial::from(vec![Integer::from(1), Integer::from(1)]);
+    /// let q = Polynomial::from(vec![Integer::from(-1), Integer::from(1)]);
+    /// assert_eq!((p * q).to_string(), "x^2-1");
+    /// ```
+    #[inline]
+    fn mul(self, other: Polynomial<Integer>) -> Polynomial<Integer> {
+        mul_integer_polynomial_helper(&self.coeffs, &other.coeffs)
+    }
+}
+
+impl<'a> Mul<&'a Polynomial<Integer>> for Polynomial<Integer> {
+    type Output = Polynomial<Integer>;
+
+    /// Multiplies two [`Polynomial`]s with [`Integer`] coefficients, taking the first by value and
+    /// the second by reference.
+    ///
+    /// $$
+    /// f(p, q) = pq.
+    /// $$
+    ///
+    /// The product is computed by Kronecker substitution, which reduces it to a single
+    /// multiplication of two large [`Integer`]s.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the number of bits needed to store
+    /// the product's coefficients.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::integer::Integer;
+    /// use malachite_q::polynomial::Polynomial;
+    ///
+    /// let p = Polynomial::from(vec![Integer::from(1), Integer::from(1)]);
+    /// let q = Polynomial::from(vec![Integer::from(-1), Integer::from(1)]);
+    /// assert_eq!((p * &q).to_string(), "x^2-1");
+    /// ```
+    #[inline]
+    fn mul(self, other: &'a Polynomial<Integer>) -> Polynomial<Integer> {
+        mul_integer_polynomial_helper(&self.coeffs, &other.coeffs)
+    }
+}
+
+impl<'a> Mul<Polynomial<Integer>> for &'a Polynomial<Integer> {
+    type Output = Polynomial<Integer>;
+
+    /// Multiplies two [`Polynomial`]s with [`Integer`] coefficients, taking the first by reference
+    /// and the second by value.
+    ///
+    /// $$
+    /// f(p, q) = pq.
+    /// $$
+    ///
+    /// The product is computed by Kronecker substitution, which reduces it to a single
+    /// multiplication of two large [`Integer`]s.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the number of bits needed to store
+    /// the product's coefficients.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::integer::Integer;
+    /// use malachite_q::polynomial::Polynomial;
+    ///
+    /// let p = Polynomial::from(vec![Integer::from(1), Integer::from(1)]);
+    /// let q = Polynomial::from(vec![Integer::from(-1), Integer::from(1)]);
+    /// assert_eq!((&p * q).to_string(), "x^2-1");
+    /// ```
+    #[inline]
+    fn mul(self, other: Polynomial<Integer>) -> Polynomial<Integer> {
+        mul_integer_polynomial_helper(&self.coeffs, &other.coeffs)
+    }
+}
+
+impl<'a, 'b> Mul<&'a Polynomial<Integer>> for &'b Polynomial<Integer> {
+    type Output = Polynomial<Integer>;
+
+    /// Multiplies two [`Polynomial`]s with [`Integer`] coefficients, taking both by reference.
+    ///
+    /// $$
+    /// f(p, q) = pq.
+    /// $$
+    ///
+    /// The product is computed by Kronecker substitution, which reduces it to a single
+    /// multiplication of two large [`Integer`]s.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the number of bits needed to store
+    /// the product's coefficients.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::integer::Integer;
+    /// use malachite_q::polynomial::Polynomial;
+    ///
+    /// let p = Polynomial::from(vec![Integer::from(1), Integer::from(1)]);
+    /// let q = Polynomial::from(vec![Integer::from(-1), Integer::from(1)]);
+    /// assert_eq!((&p * &q).to_string(), "x^2-1");
+    /// ```
+    #[inline]
+    fn mul(self, other: &'a Polynomial<Integer>) -> Polynomial<Integer> {
+        mul_integer_polynomial_helper(&self.coeffs, &other.coeffs)
+    }
+}
+
+impl Mul<Polynomial<Rational>> for Polynomial<Rational> {
+    type Output = Polynomial<Rational>;
+
+    /// Multiplies two [`Polynomial`]s with [`Rational`] coefficients, taking both by value.
+    ///
+    /// $$
+    /// f(p, q) = pq.
+    /// $$
+    ///
+    /// The product is computed by Kronecker substitution, which reduces it to a single
+    /// multiplication of two large [`Integer`]s.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the number of bits needed to store
+    /// the product's coefficients.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_q::polynomial::Polynomial;
+    /// use malachite_q::Rational;
+    ///
+    /// let p = Polynomial::from(vec![Rational::from_signeds(1, 2), Rational::from(1)]);
+    /// let q = Polynomial::from(vec![Rational::from_signeds(-1, 3), Rational::from(1)]);
+    /// assert_eq!((p * q).to_string(), "x^2+1/6*x-1/6");
+    /// ```
+    #[inline]
+    fn mul(self, other: Polynomial<Rational>) -> Polynomial<Rational> {
+        mul_rational_helper(&self.coeffs, &other.coeffs)
+    }
+}
+
+impl<'a> Mul<&'a Polynomial<Rational>> for Polynomial<Rational> {
+    type Output = Polynomial<Rational>;
+
+    /// Multiplies two [`Polynomial`]s with [`Rational`] coefficients, taking the first by value and
+    /// the second by reference.
+    ///
+    /// $$
+    /// f(p, q) = pq.
+    /// $$
+    ///
+    /// The product is computed by Kronecker substitution, which reduces it to a single
+    /// multiplication of two large [`Integer`]s.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the number of bits needed to store
+    /// the product's coefficients.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_q::polynomial::Polynomial;
+    /// use malachite_q::Rational;
+    ///
+    /// let p = Polynomial::from(vec![Rational::from_signeds(1, 2), Rational::from(1)]);
+    /// let q = Polynomial::from(vec![Rational::from_signeds(-1, 3), Rational::from(1)]);
+    /// assert_eq!((p * &q).to_string(), "x^2+1/6*x-1/6");
+    /// ```
+    #[inline]
+    fn mul(self, other: &'a Polynomial<Rational>) -> Polynomial<Rational> {
+        mul_rational_helper(&self.coeffs, &other.coeffs)
+    }
+}
+
+impl<'a> Mul<Polynomial<Rational>> for &'a Polynomial<Rational> {
+    type Output = Polynomial<Rational>;
+
+    /// Multiplies two [`Polynomial`]s with [`Rational`] coefficients, taking the first by reference
+    /// and the second by value.
+    ///
+    /// $$
+    /// f(p, q) = pq.
+    /// $$
+    ///
+    /// The product is computed by Kronecker substitution, which reduces it to a single
+    /// multiplication of two large [`Integer`]s.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the number of bits needed to store
+    /// the product's coefficients.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_q::polynomial::Polynomial;
+    /// use malachite_q::Rational;
+    ///
+    /// let p = Polynomial::from(vec![Rational::from_signeds(1, 2), Rational::from(1)]);
+    /// let q = Polynomial::from(vec![Rational::from_signeds(-1, 3), Rational::from(1)]);
+    /// assert_eq!((&p * q).to_string(), "x^2+1/6*x-1/6");
+    /// ```
+    #[inline]
+    fn mul(self, other: Polynomial<Rational>) -> Polynomial<Rational> {
+        mul_rational_helper(&self.coeffs, &other.coeffs)
+    }
+}
+
+impl<'a, 'b> Mul<&'a Polynomial<Rational>> for &'b Polynomial<Rational> {
+    type Output = Polynomial<Rational>;
+
+    /// Multiplies two [`Polynomial`]s with [`Rational`] coefficients, taking both by reference.
+    ///
+    /// $$
+    /// f(p, q) = pq.
+    /// $$
+    ///
+    /// The product is computed by Kronecker substitution, which reduces it to a single
+    /// multiplication of two large [`Integer`]s.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the number of bits needed to store
+    /// the product's coefficients.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_q::polynomial::Polynomial;
+    /// use malachite_q::Rational;
+    ///
+    /// let p = Polynomial::from(vec![Rational::from_signeds(1, 2), Rational::from(1)]);
+    /// let q = Polynomial::from(vec![Rational::from_signeds(-1, 3), Rational::from(1)]);
+    /// assert_eq!((&p * &q).to_string(), "x^2+1/6*x-1/6");
+    /// ```
+    #[inline]
+    fn mul(self, other: &'a Polynomial<Rational>) -> Polynomial<Rational> {
+        mul_rational_helper(&self.coeffs, &other.coeffs)
+    }
+}
+
+impl<'m> Mul<Polynomial<ModNatural<'m>>> for Polynomial<ModNatural<'m>> {
+    type Output = Polynomial<ModNatural<'m>>;
+
+    /// Multiplies two [`Polynomial`]s modulo $m$, taking both by value.
+    ///
+    /// $$
+    /// f(p, q) = pq.
+    /// $$
+    ///
+    /// The product is computed by Kronecker substitution, which reduces it to a single
+    /// multiplication of two large [`Integer`]s.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the number of bits needed to store
+    /// the product's coefficients.
+    ///
+    /// # Panics
+    /// Panics if `self` and `other` have different moduli.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::natural::Natural;
+    /// use malachite_q::polynomial::Polynomial;
+    ///
+    /// let m = Natural::from(5u32);
+    /// let p = Polynomial::from_naturals(vec![Natural::from(1u32), Natural::from(2u32)], &m);
+    /// let q = Polynomial::from_naturals(vec![Natural::from(3u32), Natural::from(2u32)], &m);
+    /// assert_eq!((p * q).to_string(), "4*x^2+3*x+3 mod 5");
+    /// ```
+    #[inline]
+    fn mul(self, other: Polynomial<ModNatural<'m>>) -> Polynomial<ModNatural<'m>> {
+        mul_mod_helper(&self.coeffs, &other.coeffs)
+    }
+}
+
+impl<'a, 'm> Mul<&'a Polynomial<ModNatural<'m>>> for Polynomial<ModNatural<'m>> {
+    type Output = Polynomial<ModNatural<'m>>;
+
+    /// Multiplies two [`Polynomial`]s modulo $m$, taking the first by value and the second by
+    /// reference.
+    ///
+    /// $$
+    /// f(p, q) = pq.
+    /// $$
+    ///
+    /// The product is computed by Kronecker substitution, which reduces it to a single
+    /// multiplication of two large [`Integer`]s.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the number of bits needed to store
+    /// the product's coefficients.
+    ///
+    /// # Panics
+    /// Panics if `self` and `other` have different moduli.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::natural::Natural;
+    /// use malachite_q::polynomial::Polynomial;
+    ///
+    /// let m = Natural::from(5u32);
+    /// let p = Polynomial::from_naturals(vec![Natural::from(1u32), Natural::from(2u32)], &m);
+    /// let q = Polynomial::from_naturals(vec![Natural::from(3u32), Natural::from(2u32)], &m);
+    /// assert_eq!((p * &q).to_string(), "4*x^2+3*x+3 mod 5");
+    /// ```
+    #[inline]
+    fn mul(self, other: &'a Polynomial<ModNatural<'m>>) -> Polynomial<ModNatural<'m>> {
+        mul_mod_helper(&self.coeffs, &other.coeffs)
+    }
+}
+
+impl<'a, 'm> Mul<Polynomial<ModNatural<'m>>> for &'a Polynomial<ModNatural<'m>> {
+    type Output = Polynomial<ModNatural<'m>>;
+
+    /// Multiplies two [`Polynomial`]s modulo $m$, taking the first by reference and the second by
+    /// value.
+    ///
+    /// $$
+    /// f(p, q) = pq.
+    /// $$
+    ///
+    /// The product is computed by Kronecker substitution, which reduces it to a single
+    /// multiplication of two large [`Integer`]s.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the number of bits needed to store
+    /// the product's coefficients.
+    ///
+    /// # Panics
+    /// Panics if `self` and `other` have different moduli.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::natural::Natural;
+    /// use malachite_q::polynomial::Polynomial;
+    ///
+    /// let m = Natural::from(5u32);
+    /// let p = Polynomial::from_naturals(vec![Natural::from(1u32), Natural::from(2u32)], &m);
+    /// let q = Polynomial::from_naturals(vec![Natural::from(3u32), Natural::from(2u32)], &m);
+    /// assert_eq!((&p * q).to_string(), "4*x^2+3*x+3 mod 5");
+    /// ```
+    #[inline]
+    fn mul(self, other: Polynomial<ModNatural<'m>>) -> Polynomial<ModNatural<'m>> {
+        mul_mod_helper(&self.coeffs, &other.coeffs)
+    }
+}
+
+impl<'a, 'b, 'm> Mul<&'a Polynomial<ModNatural<'m>>> for &'b Polynomial<ModNatural<'m>> {
+    type Output = Polynomial<ModNatural<'m>>;
+
+    /// Multiplies two [`Polynomial`]s modulo $m$, taking both by reference.
+    ///
+    /// $$
+    /// f(p, q) = pq.
+    /// $$
+    ///
+    /// The product is computed by Kronecker substitution, which reduces it to a single
+    /// multiplication of two large [`Integer`]s.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the number of bits needed to store
+    /// the product's coefficients.
+    ///
+    /// # Panics
+    /// Panics if `self` and `other` have different moduli.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::natural::Natural;
+    /// use malachite_q::polynomial::Polynomial;
+    ///
+    /// let m = Natural::from(5u32);
+    /// let p = Polynomial::from_naturals(vec![Natural::from(1u32), Natural::from(2u32)], &m);
+    /// let q = Polynomial::from_naturals(vec![Natural::from(3u32), Natural::from(2u32)], &m);
+    /// assert_eq!((&p * &q).to_string(), "4*x^2+3*x+3 mod 5");
+    /// ```
+    #[inline]
+    fn mul(self, other: &'a Polynomial<ModNatural<'m>>) -> Polynomial<ModNatural<'m>> {
+        mul_mod_helper(&self.coeffs, &other.coeffs)
+    }
+}
@@ -0,0 +1,244 @@
+use crate::interval::Interval;
+use crate::polynomial::content::make_primitive;
+use crate::polynomial::div::pseudo_div_rem_helper;
+use crate::polynomial::Polynomial;
+use crate::Rational;
+use malachite_base::num::arithmetic::traits::Gcd;
+use malachite_base::num::basic::traits::{One, Zero};
+use malachite_base::num::logic::traits::SignificantBits;
+use malachite_nz::integer::Integer;
+use malachite_nz::natural::Natural;
+
+// Replaces $p(x)$ by $p(x + 1)$, or by $p(x - 1)$ if `negative` is `true`.
+fn taylor_shift(xs: &mut [Integer], negative: bool) {
+    let n = xs.len();
+    for i in 1..n {
+        for j in (i - 1..n - 1).rev() {
+            let (low, high) = xs.split_at_mut(j + 1);
+            if negative {
+                low[j] -= &high[0];
+            } else {
+                low[j] += &high[0];
+            }
+        }
+    }
+}
+
+// Returns the number of sign changes in `xs`, ignoring zeros.
+fn sign_variations(xs: &[Integer]) -> usize {
+    let mut variations = 0;
+    let mut previous_negative = None;
+    for x in xs {
+        if *x == 0u32 {
+            continue;
+        }
+        let negative = *x < 0u32;
+        if previous_negative.map_or(false, |p| p != negative) {
+            variations += 1;
+        }
+        previous_negative = Some(negative);
+    }
+    variations
+}
+
+// Returns an upper bound for the number of roots of $p$ in $(0, 1)$, which is exact when it is 0
+// or 1: the number of sign variations of $(x + 1)^n p(1/(x + 1))$, by Descartes' rule of signs.
+fn descartes_bound(xs: &[Integer]) -> usize {
+    let mut ys: Vec<Integer> = xs.iter().rev().cloned().collect();
+    taylor_shift(&mut ys, false);
+    sign_variations(&ys)
+}
+
+// Returns the interval $[a/2^k, b/2^k]$.
+fn dyadic_interval(a: Natural, b: Natural, k: u64) -> Interval<Rational> {
+    Interval::new(Rational::from(a) >> k, Rational::from(b) >> k)
+}
+
+// Isolates the roots in $(0, 1)$ of the square-free polynomial $p$, which must not vanish at 0 or
+// 1, using the Vincent-Collins-Akritas bisection algorithm. Each node of the search is a
+// polynomial $q$ together with an interval $I = (c/2^k, (c + 1)/2^k)$, such that the roots of $q$
+// in $(0, 1)$ correspond to the roots of $p$ in $I$. A node is discarded when Descartes' rule
+// shows that $q$ has no roots in $(0, 1)$, and returned when it shows that $q$ has exactly one;
+// otherwise $I$ is split in half. A node also records whether each endpoint of $I$ is a root of
+// $p$ that was found at a midpoint; such a node is split further even if $q$ has exactly one root,
+// so that the returned interval doesn't contain the endpoint.
+fn isolate_roots_in_unit_interval(xs: Vec<Integer>) -> Vec<Interval<Rational>> {
+    let mut roots = Vec::new();
+    let mut stack = vec![(xs, Natural::ZERO, 0, false, false)];
+    while let Some((xs, c, k, lower_is_root, upper_is_root)) = stack.pop() {
+        match descartes_bound(&xs) {
+            0 => continue,
+            1 if !lower_is_root && !upper_is_root => {
+                roots.push(dyadic_interval(c.clone(), c + Natural::from(1u32), k));
+                continue;
+            }
+            _ => {}
+        }
+        // left(x) = 2^n p(x / 2) has the roots of p in (0, 1/2), and right(x) = left(x + 1) has
+        // those in (1/2, 1).
+        let n = xs.len() as u64 - 1;
+        let mut left: Vec<Integer> = xs
+            .into_iter()
+            .enumerate()
+            .map(|(i, x)| x << (n - i as u64))
+            .collect();
+        let mut right = left.clone();
+        taylor_shift(&mut right, false);
+        let c = c << 1;
+        let midpoint_is_root = right[0] == 0u32;
+        if midpoint_is_root {
+            // p(1/2) = 0. Remove this root from both halves, so that it doesn't lie on the
+            // boundary of either of them.
+            roots.push(Interval::point(
+                Rational::from(&c + Natural::from(1u32)) >> (k + 1),
+            ));
+            right.remove(0);
+            left = right.clone();
+            taylor_shift(&mut left, true);
+        }
+        make_primitive(&mut left);
+        make_primitive(&mut right);
+        stack.push((
+            right,
+            &c + Natural::from(1u32),
+            k + 1,
+            midpoint_is_root,
+            upper_is_root,
+        ));
+        stack.push((left, c, k + 1, lower_is_root, midpoint_is_root));
+    }
+    roots
+}
+
+// Returns the exponent $k$ of a power of 2 that is larger than the absolute value of every root
+// of $p$. The Cauchy bound shows that every root satisfies $|x| \leq 1 + \max_{i<n} |a_i/a_n|$.
+fn root_bound_exponent(xs: &[Integer]) -> u64 {
+    let (leading, rest) = xs.split_last().unwrap();
+    let max = rest
+        .iter()
+        .map(Integer::unsigned_abs_ref)
+        .max()
+        .cloned()
+        .unwrap_or(Natural::ZERO);
+    (max / leading.unsigned_abs_ref()).significant_bits() + 1
+}
+
+// Returns the square-free part of $p$: the primitive polynomial with the same roots as $p$, each
+// with multiplicity 1.
+fn square_free_part(p: &Polynomial<Integer>) -> Vec<Integer> {
+    let g = p.gcd(p.derivative());
+    let mut xs = pseudo_div_rem_helper(&p.coeffs, &g.coeffs).0;
+    make_primitive(&mut xs);
+    xs
+}
+
+fn isolate_real_roots_helper(p: &Polynomial<Integer>) -> Vec<Interval<Rational>> {
+    assert!(
+        !p.coeffs.is_empty(),
+        "Cannot isolate the real roots of the zero polynomial"
+    );
+    let mut xs = square_free_part(p);
+    let mut roots = Vec::new();
+    if xs[0] == 0u32 {
+        roots.push(Interval::point(Rational::ZERO));
+        xs.remove(0);
+    }
+    if xs.len() > 1 {
+        // Every root x satisfies |x| < 2^k, so the positive roots of p are the roots of p(2^k x)
+        // in (0, 1), scaled by 2^k, and the negative roots are the roots of p(-2^k x) in (0, 1),
+        // scaled by -2^k.
+        let k = root_bound_exponent(&xs);
+        // Every root x also satisfies |x| > 2^-j, since 1/x is a root of the reversed polynomial.
+        // Intervals ending at 0 are shrunk to end at 2^-j instead, so that they don't contain 0.
+        let reversed: Vec<Integer> = xs.iter().rev().cloned().collect();
+        let j = root_bound_exponent(&reversed);
+        let positive: Vec<Integer> = xs
+            .iter()
+            .enumerate()
+            .map(|(i, x)| x << (k * i as u64))
+            .collect();
+        let negative: Vec<Integer> = positive
+            .iter()
+            .enumerate()
+            .map(|(i, x)| if i & 1 == 0 { x.clone() } else { -x })
+            .collect();
+        let scale = |x: Rational| {
+            if x == 0u32 {
+                Rational::ONE >> j
+            } else {
+                x << k
+            }
+        };
+        for root in isolate_roots_in_unit_interval(positive) {
+            let (lower, upper) = root.into_endpoints();
+            roots.push(Interval::new(scale(lower), scale(upper)));
+        }
+        for root in isolate_roots_in_unit_interval(negative) {
+            let (lower, upper) = root.into_endpoints();
+            roots.push(Interval::new(-scale(upper), -scale(lower)));
+        }
+    }
+    roots.sort_by(|x, y| x.lower.cmp(&y.lower).then_with(|| x.upper.cmp(&y.upper)));
+    roots
+}
+
+impl Polynomial<Integer> {
+    /// Isolates the real roots of a nonzero [`Polynomial`] with [`Integer`] coefficients.
+    ///
+    /// Returns a list of closed intervals with [`Rational`] endpoints, sorted in increasing
+    /// order, such that each interval contains exactly one distinct real root and each real root
+    /// lies in exactly one interval. Rational roots that are found exactly are returned as
+    /// one-point intervals. The endpoints of the other intervals are dyadic rationals that are
+    /// not roots, and neighboring intervals may share an endpoint. Only the interval $[0, 0]$
+    /// contains 0. Repeated roots are only returned once.
+    ///
+    /// The roots are isolated by the Vincent-Collins-Akritas algorithm: the square-free part of
+    /// the polynomial is formed, an interval containing all the roots is bisected, and
+    /// Descartes' rule of signs is used to count the roots in each subinterval.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, b) = O(n^5 (b + \log n)^2)$
+    ///
+    /// $M(n, b) = O(n^3 (b + \log n))$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is the degree, and $b$ is the number of
+    /// bits in the largest coefficient.
+    ///
+    /// # Panics
+    /// Panics if `self` is zero.
+    ///
+    /// # Examples
+    /// See [here](super::roots#isolate_real_roots).
+    #[inline]
+    pub fn isolate_real_roots(&self) -> Vec<Interval<Rational>> {
+        isolate_real_roots_helper(self)
+    }
+}
+
+impl Polynomial<Rational> {
+    /// Isolates the real roots of a nonzero [`Polynomial`] with [`Rational`] coefficients.
+    ///
+    /// Returns a list of closed intervals with [`Rational`] endpoints, sorted in increasing
+    /// order, such that each interval contains exactly one distinct real root and each real root
+    /// lies in exactly one interval. See
+    /// [`Polynomial::<Integer>::isolate_real_roots`](Polynomial::isolate_real_roots) for details;
+    /// the roots are the roots of the [`primitive_part`](Polynomial::primitive_part).
+    ///
+    /// # Worst-case complexity
+    /// $T(n, b) = O(n^5 (b + \log n)^2)$
+    ///
+    /// $M(n, b) = O(n^3 (b + \log n))$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is the degree, and $b$ is the number of
+    /// bits in the largest coefficient of the primitive part.
+    ///
+    /// # Panics
+    /// Panics if `self` is zero.
+    ///
+    /// # Examples
+    /// See [here](super::roots#isolate_real_roots).
+    #[inline]
+    pub fn isolate_real_roots(&self) -> Vec<Interval<Rational>> {
+        isolate_real_roots_helper(&self.primitive_part())
+    }
+}
@@ -0,0 +1,18 @@
+use crate::Rational;
+use malachite_base::num::arithmetic::traits::BinomialCoefficient;
+use malachite_base::num::basic::traits::{One, Zero};
+use malachite_nz::natural::Natural;
+
+// Uses the recurrence $\sum_{j=0}^m \binom{m+1}{j} B_j = 0$, which holds for $m > 0$.
+pub fn bernoulli_number_naive(n: u64) -> Rational {
+    let mut bs = vec![Rational::ONE];
+    for m in 1..=n {
+        let m_plus_1 = Natural::from(m + 1);
+        let mut sum = Rational::ZERO;
+        for (j, b) in (0u64..).zip(bs.iter()) {
+            sum += Rational::from(Natural::binomial_coefficient(&m_plus_1, &Natural::from(j))) * b;
+        }
+        bs.push(-sum / Rational::from(m_plus_1));
+    }
+    bs.pop().unwrap()
+}
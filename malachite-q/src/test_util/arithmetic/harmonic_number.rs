@@ -0,0 +1,11 @@
+use crate::Rational;
+use malachite_base::num::basic::traits::{One, Zero};
+use malachite_nz::natural::Natural;
+
+pub fn harmonic_number_naive(n: u64) -> Rational {
+    let mut h = Rational::ZERO;
+    for i in 1..=n {
+        h += Rational::from_naturals(Natural::ONE, Natural::from(i));
+    }
+    h
+}
@@ -3,8 +3,9 @@ use crate::exhaustive::{
     exhaustive_positive_rationals, exhaustive_rationals,
 };
 use crate::test_util::extra_variadic::{
-    exhaustive_ordered_unique_triples, exhaustive_quadruples_xxyz, exhaustive_triples_from_single,
-    exhaustive_triples_xxy, exhaustive_triples_xxy_custom_output,
+    exhaustive_ordered_unique_triples, exhaustive_quadruples_from_single,
+    exhaustive_quadruples_xxyz, exhaustive_triples_from_single, exhaustive_triples_xxy,
+    exhaustive_triples_xxy_custom_output,
 };
 use crate::Rational;
 use itertools::Itertools;
@@ -424,6 +425,12 @@ pub fn exhaustive_rational_rational_primitive_float_triple_gen<T: PrimitiveFloat
     ))
 }
 
+// -- (Rational, Rational, Rational, Rational) --
+
+pub fn exhaustive_rational_quadruple_gen() -> It<(Rational, Rational, Rational, Rational)> {
+    Box::new(exhaustive_quadruples_from_single(exhaustive_rationals()))
+}
+
 // -- (Rational, Rational, RoundingMode) --
 
 pub(crate) fn round_to_multiple_rational_filter(t: &(Rational, Rational, RoundingMode)) -> bool {
@@ -745,6 +745,16 @@ pub fn rational_triple_gen_var_3() -> Generator<(Rational, Rational, Rational)>
     )
 }
 
+// -- (Rational, Rational, Rational, Rational) --
+
+pub fn rational_quadruple_gen() -> Generator<(Rational, Rational, Rational, Rational)> {
+    Generator::new(
+        &exhaustive_rational_quadruple_gen,
+        &random_rational_quadruple_gen,
+        &special_random_rational_quadruple_gen,
+    )
+}
+
 // -- (Rational, Rational, RoundingMode) --
 
 // All `(Rational, Rational, RoundingMode)` triples that are a valid input to
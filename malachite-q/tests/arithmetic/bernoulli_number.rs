@@ -0,0 +1,68 @@
+use malachite_base::num::arithmetic::traits::{BernoulliNumber, Parity};
+use malachite_base::num::basic::traits::One;
+use malachite_base::num::factorization::traits::IsPrime;
+use malachite_base::test_util::generators::common::TINY_LIMIT;
+use malachite_base::test_util::generators::{unsigned_gen_var_5, unsigned_gen_var_7};
+use malachite_nz::natural::Natural;
+use malachite_q::test_util::arithmetic::bernoulli_number::bernoulli_number_naive;
+use malachite_q::Rational;
+
+#[test]
+fn test_bernoulli_number() {
+    fn test(n: u64, out: &str) {
+        let b = Rational::bernoulli_number(n);
+        assert!(b.is_valid());
+        assert_eq!(b.to_string(), out);
+    }
+    test(0, "1");
+    test(1, "-1/2");
+    test(2, "1/6");
+    test(3, "0");
+    test(4, "-1/30");
+    test(6, "1/42");
+    test(8, "-1/30");
+    test(10, "5/66");
+    test(12, "-691/2730");
+    test(20, "-174611/330");
+    test(30, "8615841276005/14322");
+    test(50, "495057205241079648212477525/66");
+    test(
+        100,
+        "-94598037819122125295227433069493721872702841533066936133385696204311395415197247711/\
+        33330",
+    );
+}
+
+#[test]
+fn test_bernoulli_numbers() {
+    let bs = Rational::bernoulli_numbers().take(101).collect::<Vec<_>>();
+    for (n, b) in bs.iter().enumerate() {
+        assert!(b.is_valid());
+        assert_eq!(*b, Rational::bernoulli_number(n as u64));
+    }
+}
+
+#[test]
+fn bernoulli_number_properties() {
+    unsigned_gen_var_5().test_properties_with_limit(TINY_LIMIT, |n| {
+        let b = Rational::bernoulli_number(n);
+        assert!(b.is_valid());
+        if n > 1 && n.odd() {
+            assert_eq!(b, 0u32);
+        } else if n > 1 {
+            assert_eq!(b > 0u32, (n >> 1).odd());
+            // von Staudt-Clausen: $B_n + \sum_{(p - 1) | n} 1/p$ is an integer
+            let mut sum = b;
+            for d in 1..=n {
+                if n % d == 0 && (d + 1).is_prime() {
+                    sum += Rational::from_naturals(Natural::ONE, Natural::from(d + 1));
+                }
+            }
+            assert_eq!(*sum.denominator_ref(), 1u32);
+        }
+    });
+
+    unsigned_gen_var_7::<u64>().test_properties(|n| {
+        assert_eq!(Rational::bernoulli_number(n), bernoulli_number_naive(n));
+    });
+}
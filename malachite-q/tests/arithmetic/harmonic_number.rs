@@ -0,0 +1,60 @@
+use malachite_base::num::arithmetic::traits::HarmonicNumber;
+use malachite_base::num::basic::traits::One;
+use malachite_base::test_util::generators::common::TINY_LIMIT;
+use malachite_base::test_util::generators::{unsigned_gen_var_5, unsigned_gen_var_7};
+use malachite_nz::natural::Natural;
+use malachite_q::test_util::arithmetic::harmonic_number::harmonic_number_naive;
+use malachite_q::Rational;
+
+#[test]
+fn test_harmonic_number() {
+    fn test(n: u64, out: &str) {
+        let h = Rational::harmonic_number(n);
+        assert!(h.is_valid());
+        assert_eq!(h.to_string(), out);
+    }
+    test(0, "0");
+    test(1, "1");
+    test(2, "3/2");
+    test(3, "11/6");
+    test(4, "25/12");
+    test(5, "137/60");
+    test(10, "7381/2520");
+    test(20, "55835135/15519504");
+    test(50, "13943237577224054960759/3099044504245996706400");
+    test(
+        100,
+        "14466636279520351160221518043104131447711/2788815009188499086581352357412492142272",
+    );
+}
+
+#[test]
+fn test_harmonic_numbers() {
+    let hs = Rational::harmonic_numbers().take(101).collect::<Vec<_>>();
+    for (n, h) in hs.iter().enumerate() {
+        assert!(h.is_valid());
+        assert_eq!(*h, Rational::harmonic_number(n as u64));
+    }
+}
+
+#[test]
+fn harmonic_number_properties() {
+    unsigned_gen_var_5().test_properties_with_limit(TINY_LIMIT, |n| {
+        let h = Rational::harmonic_number(n);
+        assert!(h.is_valid());
+        if n != 0 {
+            assert_eq!(
+                &h - Rational::harmonic_number(n - 1),
+                Rational::from_naturals(Natural::ONE, Natural::from(n))
+            );
+        }
+        // $H_n$ is not an integer for $n > 1$
+        if n > 1 {
+            assert_ne!(*h.denominator_ref(), 1u32);
+        }
+    });
+
+    unsigned_gen_var_7::<u64>().test_properties(|n| {
+        assert_eq!(Rational::harmonic_number(n), harmonic_number_naive(n));
+    });
+}
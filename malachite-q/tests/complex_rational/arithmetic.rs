@@ -0,0 +1,203 @@
+use malachite_base::num::arithmetic::traits::{Pow, Square};
+use malachite_base::num::basic::traits::{One, Zero};
+use malachite_base::test_util::generators::unsigned_gen_var_5;
+use malachite_q::complex_rational::ComplexRational;
+use malachite_q::test_util::generators::{
+    rational_pair_gen, rational_quadruple_gen, rational_rational_unsigned_triple_gen_var_1,
+};
+use malachite_q::Rational;
+use std::str::FromStr;
+
+fn complex(a: &str, b: &str) -> ComplexRational {
+    ComplexRational::new(
+        Rational::from_str(a).unwrap(),
+        Rational::from_str(b).unwrap(),
+    )
+}
+
+#[test]
+fn test_add_sub_mul() {
+    let test = |x: ComplexRational, y: ComplexRational, sum, difference, product| {
+        assert_eq!((&x + &y).to_string(), sum);
+        assert_eq!((x.clone() + &y).to_string(), sum);
+        assert_eq!((&x + y.clone()).to_string(), sum);
+        assert_eq!((x.clone() + y.clone()).to_string(), sum);
+
+        assert_eq!((&x - &y).to_string(), difference);
+        assert_eq!((x.clone() - &y).to_string(), difference);
+        assert_eq!((&x - y.clone()).to_string(), difference);
+        assert_eq!((x.clone() - y.clone()).to_string(), difference);
+
+        assert_eq!((&x * &y).to_string(), product);
+        assert_eq!((x.clone() * &y).to_string(), product);
+        assert_eq!((&x * y.clone()).to_string(), product);
+        assert_eq!((x * y).to_string(), product);
+    };
+    test(complex("0", "0"), complex("0", "0"), "0", "0", "0");
+    test(
+        complex("1/2", "1"),
+        complex("0", "0"),
+        "1/2+i",
+        "1/2+i",
+        "0",
+    );
+    test(complex("0", "1"), complex("0", "1"), "2i", "0", "-1");
+    test(
+        complex("1/2", "1"),
+        complex("1/3", "-2"),
+        "5/6-i",
+        "1/6+3i",
+        "13/6-2/3i",
+    );
+    test(
+        complex("3/5", "4/5"),
+        complex("3/5", "-4/5"),
+        "6/5",
+        "8/5i",
+        "1",
+    );
+    test(
+        complex("1/2", "1/2"),
+        complex("1/2", "1/2"),
+        "1+i",
+        "0",
+        "1/2i",
+    );
+}
+
+#[test]
+fn test_neg_conjugate_norm() {
+    let test = |a, b, negative, conjugate, norm| {
+        let x = complex(a, b);
+        assert_eq!((-&x).to_string(), negative);
+        assert_eq!((-x.clone()).to_string(), negative);
+        assert_eq!(x.conjugate().to_string(), conjugate);
+        assert_eq!(x.norm().to_string(), norm);
+    };
+    test("0", "0", "0", "0", "0");
+    test("1", "0", "-1", "1", "1");
+    test("0", "1", "-i", "-i", "1");
+    test("3", "-4", "-3+4i", "3+4i", "25");
+    test("1/2", "-1/3", "-1/2+1/3i", "1/2+1/3i", "13/36");
+}
+
+#[test]
+fn test_pow() {
+    let test = |a, b, exp: u64, out| {
+        let x = complex(a, b);
+        assert_eq!((&x).pow(exp).to_string(), out);
+        assert_eq!(x.pow(exp).to_string(), out);
+    };
+    test("0", "0", 0, "1");
+    test("0", "0", 3, "0");
+    test("0", "1", 2, "-1");
+    test("0", "1", 3, "-i");
+    test("0", "1", 4, "1");
+    test("1", "1", 2, "2i");
+    test("1", "1", 8, "16");
+    test("1/2", "1/2", 4, "-1/4");
+    test("3/5", "4/5", 2, "-7/25+24/25i");
+}
+
+#[test]
+fn add_sub_properties() {
+    rational_quadruple_gen().test_properties(|(a, b, c, d)| {
+        let x = ComplexRational::new(a, b);
+        let y = ComplexRational::new(c, d);
+        let sum = &x + &y;
+        assert_eq!(x.clone() + y.clone(), sum);
+        assert_eq!(x.clone() + &y, sum);
+        assert_eq!(&x + y.clone(), sum);
+        assert_eq!(&y + &x, sum);
+        assert_eq!(&sum - &y, x);
+        assert_eq!(*sum.real(), x.real() + y.real());
+        assert_eq!(*sum.imag(), x.imag() + y.imag());
+
+        let difference = &x - &y;
+        assert_eq!(x.clone() - y.clone(), difference);
+        assert_eq!(x.clone() - &y, difference);
+        assert_eq!(&x - y.clone(), difference);
+        assert_eq!(-(&y - &x), difference);
+        assert_eq!(&difference + &y, x);
+    });
+
+    rational_pair_gen().test_properties(|(a, b)| {
+        let x = ComplexRational::new(a, b);
+        assert_eq!(&x + ComplexRational::ZERO, x);
+        assert_eq!(&x - ComplexRational::ZERO, x);
+        assert_eq!(&x - &x, ComplexRational::ZERO);
+        assert_eq!(-(-&x), x);
+        assert_eq!(&x + -&x, ComplexRational::ZERO);
+    });
+}
+
+#[test]
+fn mul_properties() {
+    rational_quadruple_gen().test_properties(|(a, b, c, d)| {
+        let x = ComplexRational::new(a, b);
+        let y = ComplexRational::new(c, d);
+        let product = &x * &y;
+        assert_eq!(x.clone() * y.clone(), product);
+        assert_eq!(x.clone() * &y, product);
+        assert_eq!(&x * y.clone(), product);
+        assert_eq!(&y * &x, product);
+        assert_eq!(product.norm(), x.norm() * y.norm());
+        assert_eq!(product.conjugate(), x.conjugate() * y.conjugate());
+        assert_eq!(-&x * &y, -&product);
+        let z = &x + &y;
+        assert_eq!(&x * (&y + &z), &x * &y + &x * &z);
+    });
+
+    rational_pair_gen().test_properties(|(a, b)| {
+        let x = ComplexRational::new(a, b);
+        assert_eq!(&x * ComplexRational::ONE, x);
+        assert_eq!(&x * ComplexRational::ZERO, ComplexRational::ZERO);
+        assert_eq!(&x * ComplexRational::I * ComplexRational::I, -&x);
+        assert_eq!(&x * x.conjugate(), ComplexRational::from(x.norm()));
+    });
+}
+
+#[test]
+fn conjugate_and_norm_properties() {
+    rational_pair_gen().test_properties(|(a, b)| {
+        let x = ComplexRational::new(a, b);
+        let conjugate = x.conjugate();
+        assert_eq!(conjugate.conjugate(), x);
+        assert_eq!(conjugate.norm(), x.norm());
+        assert_eq!(conjugate.real(), x.real());
+        assert_eq!(*conjugate.imag(), -x.imag());
+        let norm = x.norm();
+        assert_eq!(norm, x.real().square() + x.imag().square());
+        assert!(norm >= 0u32);
+        assert_eq!(norm == 0u32, x == ComplexRational::ZERO);
+    });
+}
+
+#[test]
+fn pow_properties() {
+    rational_rational_unsigned_triple_gen_var_1::<u64>().test_properties(|(a, b, exp)| {
+        let x = ComplexRational::new(a, b);
+        let power = (&x).pow(exp);
+        assert_eq!(x.clone().pow(exp), power);
+        assert_eq!(power.norm(), x.norm().pow(exp));
+        assert_eq!(x.conjugate().pow(exp), power.conjugate());
+        assert_eq!(&power * &x, (&x).pow(exp + 1));
+    });
+
+    rational_pair_gen().test_properties(|(a, b)| {
+        let x = ComplexRational::new(a, b);
+        assert_eq!((&x).pow(0), ComplexRational::ONE);
+        assert_eq!((&x).pow(1), x);
+        assert_eq!((&x).pow(2), &x * &x);
+    });
+
+    unsigned_gen_var_5::<u64>().test_properties(|exp| {
+        let expected = match exp & 3 {
+            0 => ComplexRational::ONE,
+            1 => ComplexRational::I,
+            2 => -ComplexRational::ONE,
+            _ => -ComplexRational::I,
+        };
+        assert_eq!(ComplexRational::I.pow(exp), expected);
+    });
+}
@@ -0,0 +1,84 @@
+use malachite_base::num::basic::traits::{One, Zero};
+use malachite_base::strings::ToDebugString;
+use malachite_nz::gaussian_integer::GaussianInteger;
+use malachite_nz::integer::Integer;
+use malachite_nz::test_util::generators::integer_pair_gen;
+use malachite_q::complex_rational::ComplexRational;
+use malachite_q::test_util::generators::{rational_gen, rational_pair_gen};
+use malachite_q::Rational;
+use std::str::FromStr;
+
+fn complex(a: &str, b: &str) -> ComplexRational {
+    ComplexRational::new(
+        Rational::from_str(a).unwrap(),
+        Rational::from_str(b).unwrap(),
+    )
+}
+
+#[test]
+fn test_to_string() {
+    let test = |a, b, out| {
+        let x = complex(a, b);
+        assert_eq!(x.to_string(), out);
+        assert_eq!(x.to_debug_string(), out);
+    };
+    test("0", "0", "0");
+    test("1/2", "0", "1/2");
+    test("-5", "0", "-5");
+    test("0", "1", "i");
+    test("0", "-1", "-i");
+    test("0", "2/3", "2/3i");
+    test("0", "-2/3", "-2/3i");
+    test("1", "1", "1+i");
+    test("1/2", "-1", "1/2-i");
+    test("-3", "1/4", "-3+1/4i");
+    test("-1/2", "-3/4", "-1/2-3/4i");
+}
+
+#[test]
+fn test_constants() {
+    assert_eq!(ComplexRational::ZERO, complex("0", "0"));
+    assert_eq!(ComplexRational::ONE, complex("1", "0"));
+    assert_eq!(ComplexRational::I, complex("0", "1"));
+}
+
+#[test]
+fn test_from_gaussian_integer() {
+    let test = |a: i32, b: i32, out| {
+        let x = GaussianInteger::new(Integer::from(a), Integer::from(b));
+        assert_eq!(ComplexRational::from(&x).to_string(), out);
+        assert_eq!(ComplexRational::from(x).to_string(), out);
+    };
+    test(0, 0, "0");
+    test(3, -4, "3-4i");
+    test(0, 1, "i");
+}
+
+#[test]
+fn conversion_properties() {
+    rational_gen().test_properties(|x| {
+        let y = ComplexRational::from(&x);
+        assert_eq!(ComplexRational::from(x.clone()), y);
+        assert_eq!(y.to_string(), x.to_string());
+        assert_eq!(y.into_parts(), (x, Rational::ZERO));
+    });
+
+    rational_pair_gen().test_properties(|(a, b)| {
+        let x = ComplexRational::new(a.clone(), b.clone());
+        assert_eq!(*x.real(), a);
+        assert_eq!(*x.imag(), b);
+        assert_eq!(x.into_parts(), (a, b));
+    });
+
+    integer_pair_gen().test_properties(|(a, b)| {
+        let x = GaussianInteger::new(a.clone(), b.clone());
+        let y = ComplexRational::from(&x);
+        assert_eq!(ComplexRational::from(x.clone()), y);
+        assert_eq!(y.to_string(), x.to_string());
+        assert_eq!(
+            y,
+            ComplexRational::new(Rational::from(a), Rational::from(b))
+        );
+        assert_eq!(y.norm(), x.norm());
+    });
+}
@@ -0,0 +1,94 @@
+use malachite_base::num::arithmetic::traits::Reciprocal;
+use malachite_base::num::basic::traits::{One, Zero};
+use malachite_q::complex_rational::ComplexRational;
+use malachite_q::test_util::generators::{rational_pair_gen, rational_quadruple_gen};
+use malachite_q::Rational;
+use std::str::FromStr;
+
+fn complex(a: &str, b: &str) -> ComplexRational {
+    ComplexRational::new(
+        Rational::from_str(a).unwrap(),
+        Rational::from_str(b).unwrap(),
+    )
+}
+
+#[test]
+fn test_div() {
+    let test = |x: ComplexRational, y: ComplexRational, out| {
+        assert_eq!((&x / &y).to_string(), out);
+        assert_eq!((x.clone() / &y).to_string(), out);
+        assert_eq!((&x / y.clone()).to_string(), out);
+        assert_eq!((x / y).to_string(), out);
+    };
+    test(complex("0", "0"), complex("1", "0"), "0");
+    test(complex("0", "0"), complex("3", "4"), "0");
+    test(complex("1", "0"), complex("0", "1"), "-i");
+    test(complex("1", "2"), complex("3", "-4"), "-1/5+2/5i");
+    test(complex("1/2", "1/3"), complex("1/2", "1/3"), "1");
+    test(complex("25", "0"), complex("3", "4"), "3-4i");
+    test(complex("2/3", "-1/2"), complex("0", "1/4"), "-2-8/3i");
+}
+
+#[test]
+#[should_panic]
+fn div_fail() {
+    complex("1", "2") / ComplexRational::ZERO;
+}
+
+#[test]
+fn test_reciprocal() {
+    let test = |a, b, out| {
+        let x = complex(a, b);
+        assert_eq!((&x).reciprocal().to_string(), out);
+        assert_eq!(x.reciprocal().to_string(), out);
+    };
+    test("1", "0", "1");
+    test("0", "1", "-i");
+    test("0", "-1/2", "2i");
+    test("1", "1", "1/2-1/2i");
+    test("3", "-4", "3/25+4/25i");
+    test("3/5", "4/5", "3/5-4/5i");
+}
+
+#[test]
+#[should_panic]
+fn reciprocal_fail() {
+    ComplexRational::ZERO.reciprocal();
+}
+
+#[test]
+fn div_properties() {
+    rational_quadruple_gen().test_properties(|(a, b, c, d)| {
+        let x = ComplexRational::new(a, b);
+        let y = ComplexRational::new(c, d);
+        if y == ComplexRational::ZERO {
+            return;
+        }
+        let quotient = &x / &y;
+        assert_eq!(x.clone() / y.clone(), quotient);
+        assert_eq!(x.clone() / &y, quotient);
+        assert_eq!(&x / y.clone(), quotient);
+        assert_eq!(&quotient * &y, x);
+        assert_eq!(&x * (&y).reciprocal(), quotient);
+        assert_eq!(quotient.norm(), x.norm() / y.norm());
+        assert_eq!(x.conjugate() / y.conjugate(), quotient.conjugate());
+        if x != ComplexRational::ZERO {
+            assert_eq!(&y / &x, quotient.reciprocal());
+        }
+    });
+
+    rational_pair_gen().test_properties(|(a, b)| {
+        let x = ComplexRational::new(a, b);
+        assert_eq!(&x / ComplexRational::ONE, x);
+        assert_eq!(&x / ComplexRational::I, &x * -ComplexRational::I);
+        if x != ComplexRational::ZERO {
+            let reciprocal = (&x).reciprocal();
+            assert_eq!(x.clone().reciprocal(), reciprocal);
+            assert_eq!(&x * &reciprocal, ComplexRational::ONE);
+            assert_eq!((&reciprocal).reciprocal(), x);
+            assert_eq!(reciprocal, x.conjugate() / ComplexRational::from(x.norm()));
+            assert_eq!(&x / &x, ComplexRational::ONE);
+            assert_eq!(ComplexRational::ZERO / &x, ComplexRational::ZERO);
+        }
+    });
+}
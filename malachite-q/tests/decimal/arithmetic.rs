@@ -0,0 +1,94 @@
+use malachite_base::num::basic::traits::{One, Zero};
+use malachite_nz::integer::Integer;
+use malachite_nz::test_util::generators::{
+    integer_integer_signed_triple_gen, integer_signed_pair_gen,
+};
+use malachite_q::decimal::Decimal;
+use malachite_q::Rational;
+
+fn decimal(s: &str) -> Decimal {
+    s.parse().unwrap()
+}
+
+#[test]
+fn test_neg() {
+    let test = |s: &str, out: &str| {
+        let x = decimal(s);
+        let neg = -x.clone();
+        assert_eq!(neg.to_string(), out);
+        assert_eq!(neg.scale(), x.scale());
+        assert_eq!(-&x, neg);
+    };
+    test("0", "0");
+    test("0.00", "0.00");
+    test("1.5", "-1.5");
+    test("-123.450", "123.450");
+    test("12e3", "-12000");
+}
+
+#[test]
+fn test_add_sub_mul() {
+    let test = |s: &str, t: &str, sum: &str, difference: &str, product: &str| {
+        let x = decimal(s);
+        let y = decimal(t);
+        assert_eq!((x.clone() + y.clone()).to_string(), sum);
+        assert_eq!((x.clone() + &y).to_string(), sum);
+        assert_eq!((&x + y.clone()).to_string(), sum);
+        assert_eq!((&x + &y).to_string(), sum);
+        assert_eq!((x.clone() - y.clone()).to_string(), difference);
+        assert_eq!((x.clone() - &y).to_string(), difference);
+        assert_eq!((&x - y.clone()).to_string(), difference);
+        assert_eq!((&x - &y).to_string(), difference);
+        assert_eq!((x.clone() * y.clone()).to_string(), product);
+        assert_eq!((x.clone() * &y).to_string(), product);
+        assert_eq!((&x * y.clone()).to_string(), product);
+        assert_eq!((&x * &y).to_string(), product);
+    };
+    test("0", "0", "0", "0", "0");
+    test("1.25", "0.5", "1.75", "0.75", "0.625");
+    test("0.10", "0.20", "0.30", "-0.10", "0.0200");
+    test("-3", "0.001", "-2.999", "-3.001", "-0.003");
+    test("1e3", "25", "1025", "975", "25000");
+    test("12e3", "3e2", "12300", "11700", "3600000");
+    test("99.99", "0.01", "100.00", "99.98", "0.9999");
+}
+
+#[test]
+#[should_panic]
+fn mul_fail() {
+    let x = Decimal::from_mantissa_and_scale(Integer::ONE, i64::MAX);
+    let _ = &x * &x;
+}
+
+#[test]
+fn arithmetic_properties() {
+    integer_integer_signed_triple_gen::<i8>().test_properties(|(m, n, s)| {
+        let x = Decimal::from_mantissa_and_scale(m, i64::from(s));
+        let y = Decimal::from_mantissa_and_scale(n, i64::from(s >> 2));
+        let sum = &x + &y;
+        let difference = &x - &y;
+        let product = &x * &y;
+        assert_eq!(sum.scale(), x.scale().max(y.scale()));
+        assert_eq!(difference.scale(), x.scale().max(y.scale()));
+        assert_eq!(product.scale(), x.scale() + y.scale());
+        assert_eq!(&y + &x, sum);
+        assert_eq!(&y * &x, product);
+        assert_eq!(&x + -&y, difference);
+        assert_eq!(&difference + &y, x);
+
+        let qx = Rational::from(&x);
+        let qy = Rational::from(&y);
+        assert_eq!(Rational::from(&sum), &qx + &qy);
+        assert_eq!(Rational::from(&difference), &qx - &qy);
+        assert_eq!(Rational::from(&product), qx * qy);
+    });
+
+    integer_signed_pair_gen::<i8>().test_properties(|(m, s)| {
+        let x = Decimal::from_mantissa_and_scale(m, i64::from(s));
+        assert_eq!(-(-&x), x);
+        assert_eq!(&x + Decimal::ZERO, x);
+        assert_eq!(&x * Decimal::ONE, x);
+        assert_eq!(&x - &x, Decimal::ZERO);
+        assert_eq!(Rational::from(-&x), -Rational::from(&x));
+    });
+}
@@ -0,0 +1,52 @@
+use malachite_base::rounding_modes::RoundingMode;
+use malachite_base::test_util::common::test_cmp_helper;
+use malachite_base::test_util::hash::hash;
+use malachite_nz::test_util::generators::{
+    integer_integer_signed_triple_gen, integer_signed_pair_gen,
+};
+use malachite_q::decimal::Decimal;
+use malachite_q::Rational;
+use std::cmp::Ordering;
+
+#[test]
+fn test_cmp() {
+    let strings = &["-1e3", "-2.5", "-2.05", "0", "0.001", "0.1", "1", "1.25", "12e2"];
+    test_cmp_helper::<Decimal>(strings);
+
+    let test = |s: &str, t: &str| {
+        let x: Decimal = s.parse().unwrap();
+        let y: Decimal = t.parse().unwrap();
+        assert_eq!(x, y);
+        assert_eq!(x.cmp(&y), Ordering::Equal);
+        assert_eq!(hash(&x), hash(&y));
+    };
+    test("0", "0.000");
+    test("0", "0e5");
+    test("1.5", "1.50");
+    test("-1.5", "-1.500000");
+    test("1200", "12e2");
+    test("1200.0", "1.2e3");
+}
+
+#[test]
+fn cmp_properties() {
+    integer_integer_signed_triple_gen::<i8>().test_properties(|(m, n, s)| {
+        let x = Decimal::from_mantissa_and_scale(m, i64::from(s));
+        let y = Decimal::from_mantissa_and_scale(n, i64::from(s >> 2));
+        let ord = x.cmp(&y);
+        assert_eq!(y.cmp(&x).reverse(), ord);
+        assert_eq!(x == y, ord == Ordering::Equal);
+        assert_eq!(Rational::from(&x).cmp(&Rational::from(&y)), ord);
+        assert_eq!((-&y).cmp(&-&x), ord);
+    });
+
+    integer_signed_pair_gen::<i8>().test_properties(|(m, s)| {
+        let x = Decimal::from_mantissa_and_scale(m, i64::from(s));
+        assert_eq!(x.cmp(&x), Ordering::Equal);
+        for shift in [0, 1, 5] {
+            let y = x.round_to_scale(x.scale() + shift, RoundingMode::Exact);
+            assert_eq!(x, y);
+            assert_eq!(hash(&x), hash(&y));
+        }
+    });
+}
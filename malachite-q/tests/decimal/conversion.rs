@@ -0,0 +1,93 @@
+use malachite_base::num::conversion::traits::ConvertibleFrom;
+use malachite_nz::integer::Integer;
+use malachite_nz::natural::Natural;
+use malachite_nz::test_util::generators::{integer_gen, integer_signed_pair_gen};
+use malachite_q::decimal::conversion::DecimalFromRationalError;
+use malachite_q::decimal::Decimal;
+use malachite_q::test_util::generators::rational_gen;
+use malachite_q::Rational;
+
+#[test]
+fn test_from_integer() {
+    let test = |s: &str| {
+        let n: Integer = s.parse().unwrap();
+        let x = Decimal::from(n.clone());
+        assert_eq!(x.to_string(), s);
+        assert_eq!(x.scale(), 0);
+        assert_eq!(Decimal::from(&n), x);
+    };
+    test("0");
+    test("123");
+    test("-1000000000000000000000000");
+}
+
+#[test]
+fn test_rational_from_decimal() {
+    let test = |s: &str, out: &str| {
+        let x: Decimal = s.parse().unwrap();
+        assert_eq!(Rational::from(&x).to_string(), out);
+        assert_eq!(Rational::from(x).to_string(), out);
+    };
+    test("0.000", "0");
+    test("1.5", "3/2");
+    test("-0.125", "-1/8");
+    test("1.2e3", "1200");
+    test("3.14159", "314159/100000");
+}
+
+#[test]
+fn test_try_from_rational() {
+    let test = |s: &str, out: Result<&str, DecimalFromRationalError>| {
+        let x: Rational = s.parse().unwrap();
+        let result = Decimal::try_from(&x);
+        assert_eq!(
+            result.as_ref().map(ToString::to_string).map_err(|e| *e),
+            out.map(ToString::to_string)
+        );
+        assert_eq!(Decimal::try_from(x.clone()), result);
+        assert_eq!(Decimal::convertible_from(&x), result.is_ok());
+    };
+    test("0", Ok("0"));
+    test("123", Ok("123"));
+    test("-3/2", Ok("-1.5"));
+    test("1/1024", Ok("0.0009765625"));
+    test("7/80", Ok("0.0875"));
+    test("1/3", Err(DecimalFromRationalError));
+    test("22/7", Err(DecimalFromRationalError));
+    test("1/30", Err(DecimalFromRationalError));
+}
+
+#[test]
+fn conversion_properties() {
+    rational_gen().test_properties(|x| {
+        let result = Decimal::try_from(&x);
+        assert_eq!(Decimal::try_from(x.clone()), result);
+        assert_eq!(Decimal::convertible_from(&x), result.is_ok());
+        if let Ok(d) = result {
+            assert!(d.scale() >= 0);
+            assert_eq!(Rational::from(&d), x);
+            if d.scale() > 0 {
+                // The scale is as small as possible.
+                assert_ne!(d.mantissa() % Integer::from(10u32), 0u32);
+            }
+        }
+        if *x.denominator_ref() < 10000u32 {
+            let (_, after_point) = x.to_digits(&Natural::from(10u32));
+            assert_eq!(Decimal::convertible_from(&x), after_point.is_finite());
+        }
+    });
+
+    integer_signed_pair_gen::<i8>().test_properties(|(m, s)| {
+        let x = Decimal::from_mantissa_and_scale(m, i64::from(s));
+        let q = Rational::from(&x);
+        let y = Decimal::try_from(&q).unwrap();
+        assert_eq!(y, x);
+        assert!(y.scale() <= x.scale().max(0));
+    });
+
+    integer_gen().test_properties(|n| {
+        let x = Decimal::from(&n);
+        assert_eq!(Rational::from(&x), Rational::from(&n));
+        assert_eq!(Decimal::try_from(Rational::from(n)).unwrap(), x);
+    });
+}
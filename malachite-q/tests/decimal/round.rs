@@ -0,0 +1,133 @@
+use malachite_base::num::arithmetic::traits::{DivRound, Pow};
+use malachite_base::num::basic::traits::Zero;
+use malachite_base::num::conversion::traits::RoundingFrom;
+use malachite_base::rounding_modes::RoundingMode;
+use malachite_nz::integer::Integer;
+use malachite_nz::test_util::generators::{
+    integer_integer_signed_triple_gen, integer_signed_signed_triple_gen,
+};
+use malachite_q::decimal::Decimal;
+use malachite_q::Rational;
+
+fn decimal(s: &str) -> Decimal {
+    s.parse().unwrap()
+}
+
+const ROUNDING_MODES: [RoundingMode; 5] = [
+    RoundingMode::Down,
+    RoundingMode::Up,
+    RoundingMode::Floor,
+    RoundingMode::Ceiling,
+    RoundingMode::Nearest,
+];
+
+#[test]
+fn test_round_to_scale() {
+    let test = |s: &str, scale: i64, rm: RoundingMode, out: &str| {
+        let x = decimal(s);
+        let rounded = x.round_to_scale(scale, rm);
+        assert_eq!(rounded.to_string(), out);
+        assert_eq!(rounded.scale(), scale);
+    };
+    test("1.5", 3, RoundingMode::Exact, "1.500");
+    test("1.500", 1, RoundingMode::Exact, "1.5");
+    test("2.675", 2, RoundingMode::Down, "2.67");
+    test("2.675", 2, RoundingMode::Up, "2.68");
+    test("2.675", 2, RoundingMode::Floor, "2.67");
+    test("2.675", 2, RoundingMode::Ceiling, "2.68");
+    test("2.675", 2, RoundingMode::Nearest, "2.68");
+    test("2.665", 2, RoundingMode::Nearest, "2.66");
+    test("-2.675", 2, RoundingMode::Floor, "-2.68");
+    test("-2.675", 2, RoundingMode::Ceiling, "-2.67");
+    test("1234.5", -2, RoundingMode::Nearest, "1200");
+    test("1250", -2, RoundingMode::Nearest, "1200");
+    test("1350", -2, RoundingMode::Nearest, "1400");
+    test("0.004", 2, RoundingMode::Nearest, "0.00");
+    test("0.004", 2, RoundingMode::Up, "0.01");
+}
+
+#[test]
+#[should_panic]
+fn round_to_scale_fail() {
+    decimal("2.675").round_to_scale(2, RoundingMode::Exact);
+}
+
+#[test]
+fn test_div_round_to_scale() {
+    let test = |s: &str, t: &str, scale: i64, rm: RoundingMode, out: &str| {
+        let quotient = decimal(s).div_round_to_scale(&decimal(t), scale, rm);
+        assert_eq!(quotient.to_string(), out);
+        assert_eq!(quotient.scale(), scale);
+    };
+    test("10", "3", 2, RoundingMode::Down, "3.33");
+    test("10", "3", 2, RoundingMode::Up, "3.34");
+    test("-10", "3", 2, RoundingMode::Floor, "-3.34");
+    test("-10", "3", 2, RoundingMode::Ceiling, "-3.33");
+    test("2", "3", 4, RoundingMode::Nearest, "0.6667");
+    test("1", "8", 3, RoundingMode::Exact, "0.125");
+    test("1", "8", 2, RoundingMode::Nearest, "0.12");
+    test("10.00", "0.08", 1, RoundingMode::Exact, "125.0");
+    test("1e5", "0.001", -6, RoundingMode::Nearest, "100000000");
+    test("0", "7.5", 3, RoundingMode::Exact, "0.000");
+}
+
+#[test]
+#[should_panic]
+fn div_round_to_scale_fail_1() {
+    decimal("1").div_round_to_scale(&Decimal::ZERO, 2, RoundingMode::Nearest);
+}
+
+#[test]
+#[should_panic]
+fn div_round_to_scale_fail_2() {
+    decimal("1").div_round_to_scale(&decimal("3"), 2, RoundingMode::Exact);
+}
+
+#[test]
+fn round_properties() {
+    integer_signed_signed_triple_gen::<i8>().test_properties(|(m, s, t)| {
+        let x = Decimal::from_mantissa_and_scale(m, i64::from(s));
+        let scale = i64::from(t);
+        let q = Rational::from(&x);
+        let scaled = &q * Rational::from(10u32).pow(scale);
+        for rm in ROUNDING_MODES {
+            let rounded = x.round_to_scale(scale, rm);
+            assert_eq!(rounded.scale(), scale);
+            assert_eq!(*rounded.mantissa(), Integer::rounding_from(&scaled, rm));
+            if scale >= x.scale() {
+                assert_eq!(rounded, x);
+            }
+        }
+        assert_eq!(
+            x.round_to_scale(x.scale(), RoundingMode::Exact).mantissa(),
+            x.mantissa()
+        );
+    });
+
+    integer_integer_signed_triple_gen::<i8>().test_properties(|(m, n, s)| {
+        if n == 0u32 {
+            return;
+        }
+        let x = Decimal::from_mantissa_and_scale(m, i64::from(s));
+        let y = Decimal::from_mantissa_and_scale(n, i64::from(s >> 2));
+        let q = Rational::from(&x) / Rational::from(&y);
+        for scale in [-3, 0, 2, 10] {
+            let scaled = &q * Rational::from(10u32).pow(scale);
+            for rm in ROUNDING_MODES {
+                let quotient = x.div_round_to_scale(&y, scale, rm);
+                assert_eq!(quotient.scale(), scale);
+                assert_eq!(*quotient.mantissa(), Integer::rounding_from(&scaled, rm));
+            }
+        }
+        let product = &x * &y;
+        assert_eq!(
+            product.div_round_to_scale(&y, x.scale(), RoundingMode::Exact),
+            x
+        );
+        assert_eq!(
+            x.mantissa().div_round(y.mantissa(), RoundingMode::Floor),
+            *x.div_round_to_scale(&y, x.scale() - y.scale(), RoundingMode::Floor)
+                .mantissa()
+        );
+    });
+}
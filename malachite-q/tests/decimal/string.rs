@@ -0,0 +1,118 @@
+use malachite_base::num::conversion::string::options::{FromSciStringOptions, ToSciOptions};
+use malachite_base::num::conversion::traits::{FromSciString, ToSci};
+use malachite_base::strings::ToDebugString;
+use malachite_nz::integer::Integer;
+use malachite_nz::test_util::generators::integer_signed_pair_gen;
+use malachite_q::decimal::Decimal;
+use malachite_q::Rational;
+use std::str::FromStr;
+
+#[test]
+fn test_from_str() {
+    let test = |s: &str, mantissa: &str, scale: i64, out: &str| {
+        let x = Decimal::from_str(s).unwrap();
+        assert_eq!(x.mantissa().to_string(), mantissa);
+        assert_eq!(x.scale(), scale);
+        assert_eq!(x.to_string(), out);
+        assert_eq!(Decimal::from_sci_string(s).unwrap().scale(), scale);
+    };
+    test("0", "0", 0, "0");
+    test("-0", "0", 0, "0");
+    test("0.00", "0", 2, "0.00");
+    test("123", "123", 0, "123");
+    test("-123.450", "-123450", 3, "-123.450");
+    test("0.001", "1", 3, "0.001");
+    test("1e3", "1", -3, "1000");
+    test("1.5E3", "15", -2, "1500");
+    test("1.5e-3", "15", 4, "0.0015");
+    test("-.5", "-5", 1, "-0.5");
+    test("5.", "5", 0, "5");
+    test("00012.3400", "123400", 4, "12.3400");
+
+    let test_fail = |s: &str| {
+        assert!(Decimal::from_str(s).is_err());
+        assert!(Decimal::from_sci_string(s).is_none());
+    };
+    test_fail("");
+    test_fail("-");
+    test_fail("1/2");
+    test_fail("1.2.3");
+    test_fail("e5");
+    test_fail("1e");
+    test_fail("abc");
+}
+
+#[test]
+fn test_from_sci_string_with_options() {
+    let test = |s: &str, base: u8, out: Option<&str>| {
+        let mut options = FromSciStringOptions::default();
+        options.set_base(base);
+        assert_eq!(
+            Decimal::from_sci_string_with_options(s, options).map(|x| x.to_string()),
+            out.map(ToString::to_string)
+        );
+    };
+    test("101.1", 2, Some("5.5"));
+    test("0.001", 2, Some("0.125"));
+    test("ff", 16, Some("255"));
+    test("ffE+2", 16, Some("65280"));
+    test("0.8", 16, Some("0.5"));
+    test("0.1", 3, None);
+    test("0.1", 5, Some("0.2"));
+    test("12.34", 10, Some("12.34"));
+}
+
+#[test]
+fn test_to_string() {
+    let test = |mantissa: i64, scale: i64, out: &str| {
+        let x = Decimal::from_mantissa_and_scale(Integer::from(mantissa), scale);
+        assert_eq!(x.to_string(), out);
+        assert_eq!(x.to_debug_string(), out);
+    };
+    test(0, 0, "0");
+    test(0, 3, "0.000");
+    test(0, -3, "0");
+    test(1, 0, "1");
+    test(1, 1, "0.1");
+    test(-1, 10, "-0.0000000001");
+    test(123456, 2, "1234.56");
+    test(-123456, 6, "-0.123456");
+    test(123456, 8, "0.00123456");
+    test(5, -3, "5000");
+    test(-5, -20, "-500000000000000000000");
+    test(1000, 3, "1.000");
+}
+
+#[test]
+fn test_to_sci() {
+    let x: Decimal = "-1234.5600".parse().unwrap();
+    assert_eq!(x.to_sci().to_string(), "-1234.56");
+    let mut options = ToSciOptions::default();
+    options.set_precision(2);
+    assert_eq!(x.to_sci_with_options(options).to_string(), "-1.2e3");
+    options.set_scale(1);
+    assert_eq!(x.to_sci_with_options(options).to_string(), "-1234.6");
+    options.set_include_trailing_zeros(true);
+    options.set_scale(6);
+    assert_eq!(x.to_sci_with_options(options).to_string(), "-1234.560000");
+}
+
+#[test]
+fn string_properties() {
+    integer_signed_pair_gen::<i8>().test_properties(|(m, s)| {
+        let x = Decimal::from_mantissa_and_scale(m, i64::from(s));
+        let string = x.to_string();
+        let y = Decimal::from_str(&string).unwrap();
+        assert_eq!(y, x);
+        assert_eq!(y.scale(), x.scale().max(0));
+        assert_eq!(x.to_debug_string(), string);
+        assert_eq!(
+            Rational::from(&x).to_sci().to_string(),
+            x.to_sci().to_string()
+        );
+        let sci = format!("{}e{}", x.mantissa(), -x.scale());
+        let z = Decimal::from_str(&sci).unwrap();
+        assert_eq!(z.mantissa(), x.mantissa());
+        assert_eq!(z.scale(), x.scale());
+    });
+}
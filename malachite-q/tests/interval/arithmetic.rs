@@ -0,0 +1,132 @@
+use malachite_q::interval::Interval;
+use malachite_q::test_util::generators::{rational_pair_gen, rational_vec_gen};
+use malachite_q::Rational;
+
+fn interval(x: &str, y: &str) -> Interval<Rational> {
+    Interval::new(x.parse().unwrap(), y.parse().unwrap())
+}
+
+fn interval_from_endpoints(x: Rational, y: Rational) -> Interval<Rational> {
+    if x <= y {
+        Interval::new(x, y)
+    } else {
+        Interval::new(y, x)
+    }
+}
+
+fn sample_points(x: &Interval<Rational>) -> [Rational; 3] {
+    [x.lower().clone(), x.midpoint(), x.upper().clone()]
+}
+
+#[test]
+fn test_neg() {
+    let test = |x: Interval<Rational>, out: &str| {
+        let neg = -x.clone();
+        assert_eq!(neg.to_string(), out);
+        assert_eq!(-&x, neg);
+    };
+    test(interval("0", "0"), "[0, 0]");
+    test(interval("-1", "1/2"), "[-1/2, 1]");
+    test(interval("1/3", "2"), "[-2, -1/3]");
+}
+
+#[test]
+fn test_add_sub_mul() {
+    let test = |x: Interval<Rational>, y: Interval<Rational>, sum: &str, diff: &str, prod: &str| {
+        assert_eq!((x.clone() + y.clone()).to_string(), sum);
+        assert_eq!((x.clone() + &y).to_string(), sum);
+        assert_eq!((&x + y.clone()).to_string(), sum);
+        assert_eq!((&x + &y).to_string(), sum);
+        assert_eq!((x.clone() - y.clone()).to_string(), diff);
+        assert_eq!((x.clone() - &y).to_string(), diff);
+        assert_eq!((&x - y.clone()).to_string(), diff);
+        assert_eq!((&x - &y).to_string(), diff);
+        assert_eq!((x.clone() * y.clone()).to_string(), prod);
+        assert_eq!((x.clone() * &y).to_string(), prod);
+        assert_eq!((&x * y.clone()).to_string(), prod);
+        assert_eq!((&x * &y).to_string(), prod);
+    };
+    test(
+        interval("0", "0"),
+        interval("-1", "1"),
+        "[-1, 1]",
+        "[-1, 1]",
+        "[0, 0]",
+    );
+    test(
+        interval("1", "2"),
+        interval("3", "4"),
+        "[4, 6]",
+        "[-3, -1]",
+        "[3, 8]",
+    );
+    test(
+        interval("-1", "1/2"),
+        interval("2", "3"),
+        "[1, 7/2]",
+        "[-4, -3/2]",
+        "[-3, 3/2]",
+    );
+    test(
+        interval("-2", "1"),
+        interval("-3", "1/2"),
+        "[-5, 3/2]",
+        "[-5/2, 4]",
+        "[-3, 6]",
+    );
+    test(
+        interval("-1/2", "-1/3"),
+        interval("-1/5", "-1/7"),
+        "[-7/10, -10/21]",
+        "[-5/14, -2/15]",
+        "[1/21, 1/10]",
+    );
+}
+
+#[test]
+fn arithmetic_properties() {
+    rational_pair_gen().test_properties(|(x, y)| {
+        let i = interval_from_endpoints(x, y);
+        let neg = -&i;
+        assert_eq!(-neg.clone(), i);
+        for p in sample_points(&i) {
+            assert!(neg.contains(&-p));
+        }
+        let zero = Interval::point(Rational::from(0));
+        assert_eq!(&i + &zero, i);
+        assert_eq!(&i - &zero, i);
+        assert_eq!(&i * Interval::point(Rational::from(1)), i);
+        assert_eq!(&i * &zero, zero);
+        assert!((&i - &i).contains_zero());
+    });
+
+    rational_vec_gen().test_properties(|xs| {
+        for chunk in xs.chunks_exact(4) {
+            let i = interval_from_endpoints(chunk[0].clone(), chunk[1].clone());
+            let j = interval_from_endpoints(chunk[2].clone(), chunk[3].clone());
+            let sum = &i + &j;
+            let difference = &i - &j;
+            let product = &i * &j;
+            assert_eq!(&j + &i, sum);
+            assert_eq!(&j * &i, product);
+            assert_eq!(&i + -&j, difference);
+            assert_eq!(sum.width(), i.width() + j.width());
+            for p in sample_points(&i) {
+                for q in sample_points(&j) {
+                    assert!(sum.contains(&(&p + &q)));
+                    assert!(difference.contains(&(&p - &q)));
+                    assert!(product.contains(&(&p * &q)));
+                }
+            }
+            // The product is tight: both of its endpoints are products of endpoints.
+            let corners = [
+                i.lower() * j.lower(),
+                i.lower() * j.upper(),
+                i.upper() * j.lower(),
+                i.upper() * j.upper(),
+            ];
+            assert!(corners.contains(product.lower()));
+            assert!(corners.contains(product.upper()));
+        }
+    });
+}
@@ -0,0 +1,45 @@
+use malachite_q::interval::Interval;
+use malachite_q::test_util::generators::{rational_gen, rational_pair_gen};
+use malachite_q::Rational;
+
+#[test]
+fn test_new() {
+    let test = |lower: &str, upper: &str, out: &str| {
+        let lower: Rational = lower.parse().unwrap();
+        let upper: Rational = upper.parse().unwrap();
+        let x = Interval::new(lower.clone(), upper.clone());
+        assert_eq!(x.to_string(), out);
+        assert_eq!(*x.lower(), lower);
+        assert_eq!(*x.upper(), upper);
+        assert_eq!(x.is_point(), lower == upper);
+        assert_eq!(x.into_endpoints(), (lower, upper));
+    };
+    test("0", "0", "[0, 0]");
+    test("-1", "1", "[-1, 1]");
+    test("1/3", "1/2", "[1/3, 1/2]");
+    test("-22/7", "-3", "[-22/7, -3]");
+}
+
+#[test]
+#[should_panic]
+fn new_fail() {
+    Interval::new(Rational::from(1), Rational::from(0));
+}
+
+#[test]
+fn new_properties() {
+    rational_pair_gen().test_properties(|(x, y)| {
+        if x <= y {
+            let i = Interval::new(x.clone(), y.clone());
+            assert_eq!(i.is_point(), x == y);
+            assert_eq!(i.to_string(), format!("[{}, {}]", x, y));
+            assert_eq!(i.into_endpoints(), (x, y));
+        }
+    });
+
+    rational_gen().test_properties(|x| {
+        let i = Interval::point(x.clone());
+        assert!(i.is_point());
+        assert_eq!(i, Interval::new(x.clone(), x));
+    });
+}
@@ -0,0 +1,132 @@
+use malachite_q::interval::div::ExtendedInterval;
+use malachite_q::interval::Interval;
+use malachite_q::test_util::generators::rational_vec_gen;
+use malachite_q::Rational;
+
+fn interval(x: &str, y: &str) -> Interval<Rational> {
+    Interval::new(x.parse().unwrap(), y.parse().unwrap())
+}
+
+fn interval_from_endpoints(x: Rational, y: Rational) -> Interval<Rational> {
+    if x <= y {
+        Interval::new(x, y)
+    } else {
+        Interval::new(y, x)
+    }
+}
+
+fn rational(x: &str) -> Rational {
+    x.parse().unwrap()
+}
+
+#[test]
+fn test_div() {
+    let test = |x: Interval<Rational>, y: Interval<Rational>, out: &str| {
+        assert_eq!((x.clone() / y.clone()).to_string(), out);
+        assert_eq!((x.clone() / &y).to_string(), out);
+        assert_eq!((&x / y.clone()).to_string(), out);
+        assert_eq!((&x / &y).to_string(), out);
+        assert_eq!(x.checked_div(&y).unwrap().to_string(), out);
+        assert_eq!(
+            x.div_extended(&y),
+            ExtendedInterval::Bounded(x.checked_div(&y).unwrap())
+        );
+    };
+    test(interval("0", "0"), interval("1", "2"), "[0, 0]");
+    test(interval("1", "2"), interval("-4", "-2"), "[-1, -1/4]");
+    test(interval("-1", "3"), interval("2", "4"), "[-1/2, 3/2]");
+    test(interval("-1", "3"), interval("-4", "-2"), "[-3/2, 1/2]");
+    test(interval("1/3", "1/2"), interval("1/3", "1/2"), "[2/3, 3/2]");
+}
+
+#[test]
+#[should_panic]
+fn div_fail() {
+    let _ = interval("1", "2") / interval("-1", "1");
+}
+
+#[test]
+fn test_div_extended() {
+    let test = |x: Interval<Rational>, y: Interval<Rational>, out: ExtendedInterval| {
+        assert_eq!(x.div_extended(&y), out);
+        assert_eq!(x.checked_div(&y), None);
+    };
+    test(
+        interval("1", "2"),
+        interval("0", "0"),
+        ExtendedInterval::Empty,
+    );
+    test(
+        interval("0", "0"),
+        interval("0", "0"),
+        ExtendedInterval::Empty,
+    );
+    test(
+        interval("-1", "2"),
+        interval("-1", "1"),
+        ExtendedInterval::Whole,
+    );
+    test(
+        interval("0", "2"),
+        interval("0", "1"),
+        ExtendedInterval::Whole,
+    );
+    test(
+        interval("1", "2"),
+        interval("0", "4"),
+        ExtendedInterval::AtLeast(rational("1/4")),
+    );
+    test(
+        interval("-2", "-1"),
+        interval("0", "4"),
+        ExtendedInterval::AtMost(rational("-1/4")),
+    );
+    test(
+        interval("1", "2"),
+        interval("-4", "0"),
+        ExtendedInterval::AtMost(rational("-1/4")),
+    );
+    test(
+        interval("-2", "-1"),
+        interval("-4", "0"),
+        ExtendedInterval::AtLeast(rational("1/4")),
+    );
+    test(
+        interval("1", "2"),
+        interval("-1", "4"),
+        ExtendedInterval::Split(rational("-1"), rational("1/4")),
+    );
+    test(
+        interval("-2", "-1"),
+        interval("-1", "4"),
+        ExtendedInterval::Split(rational("-1/4"), rational("1")),
+    );
+}
+
+#[test]
+fn div_properties() {
+    rational_vec_gen().test_properties(|xs| {
+        for chunk in xs.chunks_exact(4) {
+            let i = interval_from_endpoints(chunk[0].clone(), chunk[1].clone());
+            let j = interval_from_endpoints(chunk[2].clone(), chunk[3].clone());
+            let extended = i.div_extended(&j);
+            if let Some(quotient) = i.checked_div(&j) {
+                assert_eq!(&i / &j, quotient);
+                assert_eq!(extended, ExtendedInterval::Bounded(quotient.clone()));
+                assert!((&quotient * &j).contains_interval(&i));
+            } else {
+                assert!(j.contains_zero());
+            }
+            for p in [i.lower(), &i.midpoint(), i.upper()] {
+                for q in [j.lower(), &j.midpoint(), j.upper()] {
+                    if *q != 0u32 {
+                        assert!(extended.contains(&(p / q)));
+                    }
+                }
+            }
+            if let ExtendedInterval::Split(a, b) = extended {
+                assert!(a < b);
+            }
+        }
+    });
+}
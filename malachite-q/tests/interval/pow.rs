@@ -0,0 +1,54 @@
+use malachite_base::num::arithmetic::traits::Pow;
+use malachite_q::interval::Interval;
+use malachite_q::test_util::generators::rational_pair_gen;
+use malachite_q::Rational;
+
+fn interval(x: &str, y: &str) -> Interval<Rational> {
+    Interval::new(x.parse().unwrap(), y.parse().unwrap())
+}
+
+fn interval_from_endpoints(x: Rational, y: Rational) -> Interval<Rational> {
+    if x <= y {
+        Interval::new(x, y)
+    } else {
+        Interval::new(y, x)
+    }
+}
+
+#[test]
+fn test_pow() {
+    let test = |x: Interval<Rational>, exp: u64, out: &str| {
+        assert_eq!(x.clone().pow(exp).to_string(), out);
+        assert_eq!((&x).pow(exp).to_string(), out);
+    };
+    test(interval("0", "0"), 0, "[1, 1]");
+    test(interval("-2", "3"), 0, "[1, 1]");
+    test(interval("-2", "3"), 1, "[-2, 3]");
+    test(interval("-2", "3"), 2, "[0, 9]");
+    test(interval("-2", "3"), 3, "[-8, 27]");
+    test(interval("-3", "-2"), 2, "[4, 9]");
+    test(interval("-3", "-2"), 3, "[-27, -8]");
+    test(interval("1/2", "2/3"), 4, "[1/16, 16/81]");
+    test(interval("-1/2", "1/3"), 4, "[0, 1/16]");
+}
+
+#[test]
+fn pow_properties() {
+    rational_pair_gen().test_properties(|(x, y)| {
+        let i = interval_from_endpoints(x, y);
+        for exp in 0..5 {
+            let power = (&i).pow(exp);
+            assert_eq!(i.clone().pow(exp), power);
+            for p in [i.lower(), &i.midpoint(), i.upper()] {
+                assert!(power.contains(&p.pow(exp)));
+            }
+            let mut product = Interval::point(Rational::from(1));
+            for _ in 0..exp {
+                product = product * &i;
+            }
+            assert!(product.contains_interval(&power));
+        }
+        assert_eq!((&i).pow(1), i);
+        assert_eq!((&i).pow(2).contains_zero(), i.contains_zero());
+    });
+}
@@ -0,0 +1,125 @@
+use malachite_q::interval::Interval;
+use malachite_q::test_util::generators::{rational_triple_gen, rational_vec_gen};
+use malachite_q::Rational;
+
+fn interval(x: &str, y: &str) -> Interval<Rational> {
+    Interval::new(x.parse().unwrap(), y.parse().unwrap())
+}
+
+fn interval_from_endpoints(x: Rational, y: Rational) -> Interval<Rational> {
+    if x <= y {
+        Interval::new(x, y)
+    } else {
+        Interval::new(y, x)
+    }
+}
+
+#[test]
+fn test_contains() {
+    let test = |x: Interval<Rational>, y: &str, out: bool| {
+        assert_eq!(x.contains(&y.parse().unwrap()), out);
+    };
+    test(interval("0", "1"), "0", true);
+    test(interval("0", "1"), "1", true);
+    test(interval("0", "1"), "1/2", true);
+    test(interval("0", "1"), "-1/2", false);
+    test(interval("0", "1"), "3/2", false);
+    test(interval("1/3", "1/3"), "1/3", true);
+}
+
+#[test]
+fn test_contains_zero() {
+    assert!(interval("0", "0").contains_zero());
+    assert!(interval("-1", "0").contains_zero());
+    assert!(interval("0", "1").contains_zero());
+    assert!(interval("-1/2", "1/2").contains_zero());
+    assert!(!interval("1/100", "1").contains_zero());
+    assert!(!interval("-1", "-1/100").contains_zero());
+}
+
+#[test]
+fn test_intersection_and_hull() {
+    let test = |x: Interval<Rational>, y: Interval<Rational>, i: Option<&str>, h: &str| {
+        assert_eq!(x.intersection(&y).map(|i| i.to_string()).as_deref(), i);
+        assert_eq!(y.intersection(&x).map(|i| i.to_string()).as_deref(), i);
+        assert_eq!(x.hull(&y).to_string(), h);
+        assert_eq!(y.hull(&x).to_string(), h);
+    };
+    test(
+        interval("0", "2"),
+        interval("1", "3"),
+        Some("[1, 2]"),
+        "[0, 3]",
+    );
+    test(
+        interval("0", "1"),
+        interval("1", "3"),
+        Some("[1, 1]"),
+        "[0, 3]",
+    );
+    test(interval("0", "1"), interval("2", "3"), None, "[0, 3]");
+    test(
+        interval("-1/2", "1/2"),
+        interval("-1/3", "1/3"),
+        Some("[-1/3, 1/3]"),
+        "[-1/2, 1/2]",
+    );
+}
+
+#[test]
+fn test_width_and_midpoint() {
+    let test = |x: Interval<Rational>, width: &str, midpoint: &str| {
+        assert_eq!(x.width().to_string(), width);
+        assert_eq!(x.midpoint().to_string(), midpoint);
+    };
+    test(interval("0", "0"), "0", "0");
+    test(interval("0", "1"), "1", "1/2");
+    test(interval("1/3", "1/2"), "1/6", "5/12");
+    test(interval("-3", "-1"), "2", "-2");
+}
+
+#[test]
+fn set_properties() {
+    rational_triple_gen().test_properties(|(x, y, z)| {
+        let i = interval_from_endpoints(x, y);
+        assert_eq!(i.contains(&z), i.lower() <= &z && &z <= i.upper());
+        assert!(i.contains(i.lower()));
+        assert!(i.contains(i.upper()));
+        assert!(i.contains(&i.midpoint()));
+        assert_eq!(i.contains_zero(), i.contains(&Rational::from(0)));
+        assert!(i.width() >= 0u32);
+        assert_eq!(i.width() == 0u32, i.is_point());
+        assert!(i.contains_interval(&i));
+        assert_eq!(i.intersection(&i).as_ref(), Some(&i));
+        assert_eq!(i.hull(&i), i);
+        let p = Interval::point(z.clone());
+        assert_eq!(i.contains_interval(&p), i.contains(&z));
+        assert_eq!(i.intersection(&p).is_some(), i.contains(&z));
+        assert!(i.hull(&p).contains(&z));
+    });
+
+    rational_vec_gen().test_properties(|xs| {
+        for chunk in xs.chunks_exact(4) {
+            let i = interval_from_endpoints(chunk[0].clone(), chunk[1].clone());
+            let j = interval_from_endpoints(chunk[2].clone(), chunk[3].clone());
+            let hull = i.hull(&j);
+            assert_eq!(j.hull(&i), hull);
+            assert!(hull.contains_interval(&i));
+            assert!(hull.contains_interval(&j));
+            let intersection = i.intersection(&j);
+            assert_eq!(j.intersection(&i), intersection);
+            if let Some(intersection) = intersection {
+                assert!(i.contains_interval(&intersection));
+                assert!(j.contains_interval(&intersection));
+                assert!(i.contains(&intersection.midpoint()));
+                assert!(j.contains(&intersection.midpoint()));
+            } else {
+                assert!(i.upper() < j.lower() || j.upper() < i.lower());
+            }
+            assert_eq!(
+                i.contains_interval(&j),
+                i.intersection(&j).as_ref() == Some(&j)
+            );
+        }
+    });
+}
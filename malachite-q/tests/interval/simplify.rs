@@ -0,0 +1,124 @@
+use malachite_base::num::arithmetic::traits::Abs;
+use malachite_base::num::basic::traits::Zero;
+use malachite_nz::natural::Natural;
+use malachite_q::arithmetic::traits::SimplestRationalInInterval;
+use malachite_q::interval::Interval;
+use malachite_q::test_util::generators::rational_triple_gen;
+use malachite_q::Rational;
+
+fn interval(x: &str, y: &str) -> Interval<Rational> {
+    Interval::new(x.parse().unwrap(), y.parse().unwrap())
+}
+
+fn interval_from_endpoints(x: Rational, y: Rational) -> Interval<Rational> {
+    if x <= y {
+        Interval::new(x, y)
+    } else {
+        Interval::new(y, x)
+    }
+}
+
+#[test]
+fn test_simplify() {
+    let test = |x: Interval<Rational>, tolerance: &str, out: &str| {
+        assert_eq!(x.simplify(&tolerance.parse().unwrap()).to_string(), out);
+    };
+    test(interval("1/3", "1/2"), "0", "[1/3, 1/2]");
+    test(interval("1/3", "1/2"), "1/3", "[0, 1/2]");
+    test(
+        interval("314159/100000", "314160/100000"),
+        "1/1000",
+        "[201/64, 377/120]",
+    );
+    test(
+        interval("314159/100000", "314160/100000"),
+        "1/100",
+        "[47/15, 22/7]",
+    );
+    test(interval("-7/5", "7/5"), "1", "[-2, 2]");
+}
+
+#[test]
+#[should_panic]
+fn simplify_fail() {
+    interval("0", "1").simplify(&Rational::from(-1));
+}
+
+#[test]
+fn test_round_outward() {
+    let test = |x: Interval<Rational>, max_denominator: u32, out: &str| {
+        assert_eq!(
+            x.round_outward(&Natural::from(max_denominator)).to_string(),
+            out
+        );
+    };
+    test(interval("1/3", "1/2"), 1, "[0, 1]");
+    test(interval("1/3", "1/2"), 2, "[0, 1/2]");
+    test(interval("1/3", "1/2"), 3, "[1/3, 1/2]");
+    test(interval("-1/2", "-1/3"), 1, "[-1, 0]");
+    test(
+        interval("314159/100000", "314160/100000"),
+        10,
+        "[25/8, 22/7]",
+    );
+    test(
+        interval("314159/100000", "314160/100000"),
+        100,
+        "[311/99, 22/7]",
+    );
+    test(
+        interval("314159/100000", "314160/100000"),
+        1000,
+        "[2818/897, 2862/911]",
+    );
+    test(interval("5", "6"), 7, "[5, 6]");
+}
+
+#[test]
+#[should_panic]
+fn round_outward_fail() {
+    interval("0", "1").round_outward(&Natural::ZERO);
+}
+
+#[test]
+fn simplify_properties() {
+    rational_triple_gen().test_properties(|(x, y, z)| {
+        let i = interval_from_endpoints(x, y);
+        let tolerance = z.abs();
+        let simplified = i.simplify(&tolerance);
+        assert!(simplified.contains_interval(&i));
+        assert!(i.lower() - simplified.lower() <= tolerance);
+        assert!(simplified.upper() - i.upper() <= tolerance);
+        assert!(simplified.lower().denominator_ref() <= i.lower().denominator_ref());
+        assert!(simplified.upper().denominator_ref() <= i.upper().denominator_ref());
+        assert_eq!(i.simplify(&Rational::ZERO), i);
+    });
+
+    rational_triple_gen().test_properties(|(x, y, z)| {
+        let i = interval_from_endpoints(x, y);
+        let max_denominator = z.into_numerator_and_denominator().1;
+        let rounded = i.round_outward(&max_denominator);
+        assert!(rounded.contains_interval(&i));
+        assert!(*rounded.lower().denominator_ref() <= max_denominator);
+        assert!(*rounded.upper().denominator_ref() <= max_denominator);
+        let epsilon = Rational::from_naturals(Natural::from(1u32), max_denominator.clone());
+        assert!(i.lower() - rounded.lower() <= epsilon);
+        assert!(rounded.upper() - i.upper() <= epsilon);
+        // No fraction with a small enough denominator lies strictly between a rounded endpoint
+        // and the original endpoint.
+        for (x, y) in [(rounded.lower(), i.lower()), (i.upper(), rounded.upper())] {
+            if x < y {
+                assert!(
+                    *Rational::simplest_rational_in_open_interval(x, y).denominator_ref()
+                        > max_denominator
+                );
+            }
+        }
+        assert_eq!(rounded.round_outward(&max_denominator), rounded);
+        if *i.lower().denominator_ref() <= max_denominator
+            && *i.upper().denominator_ref() <= max_denominator
+        {
+            assert_eq!(rounded, i);
+        }
+    });
+}
@@ -0,0 +1,56 @@
+use malachite_base::num::arithmetic::traits::{Abs, CheckedSqrt, Pow};
+use malachite_q::interval::Interval;
+use malachite_q::test_util::generators::rational_pair_gen;
+use malachite_q::Rational;
+
+fn interval(x: &str, y: &str) -> Interval<Rational> {
+    Interval::new(x.parse().unwrap(), y.parse().unwrap())
+}
+
+#[test]
+fn test_sqrt_enclosure() {
+    let test = |x: Interval<Rational>, bits: u64, out: &str| {
+        assert_eq!(x.sqrt_enclosure(bits).to_string(), out);
+    };
+    test(interval("0", "0"), 10, "[0, 0]");
+    test(interval("-1", "0"), 10, "[0, 0]");
+    test(interval("1/4", "9"), 0, "[1/2, 3]");
+    test(interval("2", "3"), 0, "[1, 2]");
+    test(interval("2", "3"), 10, "[181/128, 887/512]");
+    test(interval("-1", "2"), 20, "[0, 1482911/1048576]");
+    test(interval("1/3", "1/3"), 4, "[9/16, 7/12]");
+}
+
+#[test]
+#[should_panic]
+fn sqrt_enclosure_fail() {
+    interval("-2", "-1").sqrt_enclosure(10);
+}
+
+#[test]
+fn sqrt_enclosure_properties() {
+    rational_pair_gen().test_properties(|(x, y)| {
+        let (x, y) = (x.abs(), y.abs());
+        let i = if x <= y {
+            Interval::new(x, y)
+        } else {
+            Interval::new(y, x)
+        };
+        for bits in [0, 1, 10, 100] {
+            let root = i.sqrt_enclosure(bits);
+            assert!(*root.lower() >= 0u32);
+            // Squaring the result gives an interval containing the original one.
+            assert!(root.clone().pow(2).contains_interval(&i));
+            let epsilon = Rational::from(1) >> bits;
+            for (endpoint, bound) in [(i.lower(), root.lower()), (i.upper(), root.upper())] {
+                if let Some(exact) = endpoint.checked_sqrt() {
+                    assert_eq!(*bound, exact);
+                }
+            }
+            assert!(root.lower().pow(2u64) <= *i.lower());
+            assert!((root.lower() + &epsilon).pow(2u64) >= *i.lower());
+            assert!(root.upper().pow(2u64) >= *i.upper());
+            assert!((root.upper() - &epsilon).pow(2u64) <= *i.upper() || *root.upper() < epsilon);
+        }
+    });
+}
@@ -49,10 +49,12 @@ pub mod arithmetic {
     pub mod abs;
     pub mod add;
     pub mod approximate;
+    pub mod bernoulli_number;
     pub mod ceiling;
     pub mod denominators_in_closed_interval;
     pub mod div;
     pub mod floor;
+    pub mod harmonic_number;
     pub mod is_power_of_2;
     pub mod log_base;
     pub mod log_base_2;
@@ -102,6 +104,11 @@ pub mod comparison {
     pub mod partial_eq_primitive_float;
     pub mod partial_eq_primitive_int;
 }
+pub mod complex_rational {
+    pub mod arithmetic;
+    pub mod basic;
+    pub mod div;
+}
 pub mod conversion {
     pub mod clone;
     pub mod continued_fraction {
@@ -138,6 +145,13 @@ pub mod conversion {
         pub mod to_string;
     }
 }
+pub mod decimal {
+    pub mod arithmetic;
+    pub mod comparison;
+    pub mod conversion;
+    pub mod round;
+    pub mod string;
+}
 pub mod exhaustive {
     pub mod exhaustive_negative_rationals;
     pub mod exhaustive_non_negative_rationals;
@@ -149,6 +163,32 @@ pub mod exhaustive {
     pub mod exhaustive_rationals_with_denominator_range_to_infinity;
     pub mod exhaustive_rationals_with_denominator_range_to_negative_infinity;
 }
+pub mod interval {
+    pub mod arithmetic;
+    pub mod basic;
+    pub mod div;
+    pub mod pow;
+    pub mod set;
+    pub mod simplify;
+    pub mod sqrt;
+}
+pub mod matrix {
+    pub mod basic;
+    pub mod determinant;
+    pub mod mul;
+    pub mod rref;
+    pub mod solve;
+}
+pub mod polynomial {
+    pub mod arithmetic;
+    pub mod basic;
+    pub mod content;
+    pub mod div;
+    pub mod evaluate;
+    pub mod gcd;
+    pub mod mul;
+    pub mod roots;
+}
 pub mod random {
     pub mod random_negative_rationals;
     pub mod random_non_negative_rationals;
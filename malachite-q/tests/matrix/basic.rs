@@ -0,0 +1,129 @@
+use malachite_base::strings::ToDebugString;
+use malachite_nz::integer::Integer;
+use malachite_nz::test_util::generators::integer_vec_gen;
+use malachite_q::matrix::Matrix;
+use malachite_q::test_util::generators::rational_vec_gen;
+use malachite_q::Rational;
+
+fn integer_matrix(rows: &[&[i32]]) -> Matrix<Integer> {
+    Matrix::from_rows(
+        rows.iter()
+            .map(|row| row.iter().copied().map(Integer::from).collect())
+            .collect(),
+    )
+}
+
+// Arranges as many of the elements of `xs` as possible into a matrix with `rows` rows.
+fn matrix_from_vec<T>(xs: Vec<T>, rows: usize) -> Matrix<T> {
+    let cols = xs.len() / rows;
+    let mut xs = xs.into_iter();
+    Matrix::from_rows(
+        (0..rows)
+            .map(|_| xs.by_ref().take(cols).collect())
+            .collect(),
+    )
+}
+
+#[test]
+fn test_from_rows() {
+    let m = integer_matrix(&[&[1, 2, 3], &[4, 5, 6]]);
+    assert_eq!(m.row_count(), 2);
+    assert_eq!(m.column_count(), 3);
+    assert!(!m.is_square());
+    assert_eq!(m.row(0), &[1, 2, 3]);
+    assert_eq!(m.row(1), &[4, 5, 6]);
+    assert_eq!(m[(1, 2)], 6);
+    assert_eq!(m.into_rows(), [[1, 2, 3], [4, 5, 6]]);
+
+    let m = Matrix::<Integer>::from_rows(vec![]);
+    assert_eq!(m.row_count(), 0);
+    assert_eq!(m.column_count(), 0);
+    assert!(m.is_square());
+
+    let m = Matrix::<Integer>::from_rows(vec![vec![], vec![]]);
+    assert_eq!(m.row_count(), 2);
+    assert_eq!(m.column_count(), 0);
+}
+
+#[test]
+#[should_panic]
+fn from_rows_fail() {
+    integer_matrix(&[&[1, 2], &[3]]);
+}
+
+#[test]
+#[should_panic]
+fn row_fail() {
+    Matrix::<Integer>::identity(2).row(2);
+}
+
+#[test]
+#[should_panic]
+fn index_fail() {
+    let _ = Matrix::<Integer>::identity(2)[(0, 2)];
+}
+
+#[test]
+#[should_panic]
+fn index_mut_fail() {
+    let mut m = Matrix::<Integer>::identity(2);
+    m[(2, 0)] = Integer::from(1);
+}
+
+#[test]
+fn test_index_mut() {
+    let mut m = Matrix::<Rational>::zero(2, 2);
+    m[(0, 1)] = Rational::from_signeds(1, 2);
+    m[(1, 0)] = Rational::from(-3);
+    assert_eq!(m.into_rows().to_debug_string(), "[[0, 1/2], [-3, 0]]");
+}
+
+#[test]
+fn test_transpose() {
+    let m = integer_matrix(&[&[1, 2, 3], &[4, 5, 6]]);
+    assert_eq!(m.transpose().into_rows(), [[1, 4], [2, 5], [3, 6]]);
+    assert_eq!(
+        Matrix::<Integer>::zero(2, 0).transpose(),
+        Matrix::zero(0, 2)
+    );
+}
+
+#[test]
+fn test_zero_and_identity() {
+    assert_eq!(Matrix::<Integer>::zero(1, 3).into_rows(), [[0, 0, 0]]);
+    assert_eq!(
+        Matrix::<Integer>::identity(3).into_rows(),
+        [[1, 0, 0], [0, 1, 0], [0, 0, 1]]
+    );
+    assert_eq!(Matrix::<Rational>::identity(0).row_count(), 0);
+}
+
+#[test]
+fn test_from_integer_matrix() {
+    let m = integer_matrix(&[&[1, -2], &[0, 5]]);
+    assert_eq!(
+        Matrix::<Rational>::from(&m).into_rows().to_debug_string(),
+        "[[1, -2], [0, 5]]"
+    );
+}
+
+#[test]
+fn transpose_properties() {
+    integer_vec_gen().test_properties(|xs| {
+        for rows in 1..=3 {
+            let m = matrix_from_vec(xs.clone(), rows);
+            let t = m.transpose();
+            assert_eq!(t.row_count(), m.column_count());
+            assert_eq!(t.column_count(), m.row_count());
+            assert_eq!(t.transpose(), m);
+            assert_eq!(Matrix::<Rational>::from(&t), Matrix::from(&m).transpose());
+        }
+    });
+
+    rational_vec_gen().test_properties(|xs| {
+        for rows in 1..=3 {
+            let m = matrix_from_vec(xs.clone(), rows);
+            assert_eq!(m.transpose().transpose(), m);
+        }
+    });
+}
@@ -0,0 +1,178 @@
+use malachite_base::num::arithmetic::traits::FloorSqrt;
+use malachite_nz::integer::Integer;
+use malachite_nz::test_util::generators::integer_vec_gen;
+use malachite_q::matrix::Matrix;
+use malachite_q::test_util::generators::rational_vec_gen;
+use malachite_q::Rational;
+use std::str::FromStr;
+
+fn integer_matrix(rows: &[&[i32]]) -> Matrix<Integer> {
+    Matrix::from_rows(
+        rows.iter()
+            .map(|row| row.iter().copied().map(Integer::from).collect())
+            .collect(),
+    )
+}
+
+fn rational_matrix(rows: &[&[&str]]) -> Matrix<Rational> {
+    Matrix::from_rows(
+        rows.iter()
+            .map(|row| row.iter().map(|s| Rational::from_str(s).unwrap()).collect())
+            .collect(),
+    )
+}
+
+// Arranges as many of the elements of `xs` as possible into a square matrix.
+fn square_matrix_from_vec<T>(xs: Vec<T>) -> Matrix<T> {
+    let n = xs.len().floor_sqrt();
+    let mut xs = xs.into_iter();
+    Matrix::from_rows((0..n).map(|_| xs.by_ref().take(n).collect()).collect())
+}
+
+// Arranges as many of the elements of `xs` as possible into a matrix with `rows` rows.
+fn matrix_from_vec<T>(xs: Vec<T>, rows: usize) -> Matrix<T> {
+    let cols = xs.len() / rows;
+    let mut xs = xs.into_iter();
+    Matrix::from_rows(
+        (0..rows)
+            .map(|_| xs.by_ref().take(cols).collect())
+            .collect(),
+    )
+}
+
+#[test]
+fn test_determinant() {
+    let test = |m: Matrix<Integer>, out: i32| {
+        let det = m.determinant();
+        assert_eq!(det, out);
+        assert_eq!(Matrix::<Rational>::from(&m).determinant(), out);
+    };
+    test(Matrix::zero(0, 0), 1);
+    test(integer_matrix(&[&[-7]]), -7);
+    test(integer_matrix(&[&[1, 2], &[3, 4]]), -2);
+    test(integer_matrix(&[&[0, 1], &[1, 0]]), -1);
+    test(integer_matrix(&[&[1, 2], &[2, 4]]), 0);
+    test(integer_matrix(&[&[2, -1, 0], &[-1, 2, -1], &[0, -1, 2]]), 4);
+    test(integer_matrix(&[&[0, 0, 1], &[0, 1, 0], &[1, 0, 0]]), -1);
+    test(integer_matrix(&[&[0, 2, 3], &[0, 4, 5], &[0, 6, 7]]), 0);
+    test(
+        integer_matrix(&[&[3, 1, 4, 1], &[5, 9, 2, 6], &[5, 3, 5, 8], &[9, 7, 9, 3]]),
+        98,
+    );
+    test(Matrix::identity(5), 1);
+
+    let test_rational = |m: Matrix<Rational>, out: &str| {
+        assert_eq!(m.determinant().to_string(), out);
+    };
+    test_rational(rational_matrix(&[&["1/2", "1/3"], &["1/4", "1/5"]]), "1/60");
+    test_rational(rational_matrix(&[&["1/2", "1/3"], &["3/2", "1"]]), "0");
+    test_rational(
+        rational_matrix(&[&["1", "1/2", "1/3"], &["1/2", "1/3", "1/4"], &["1/3", "1/4", "1/5"]]),
+        "1/2160",
+    );
+    test_rational(rational_matrix(&[&["-22/7"]]), "-22/7");
+}
+
+#[test]
+#[should_panic]
+fn determinant_fail() {
+    Matrix::<Integer>::zero(2, 3).determinant();
+}
+
+#[test]
+#[should_panic]
+fn determinant_rational_fail() {
+    Matrix::<Rational>::zero(3, 2).determinant();
+}
+
+#[test]
+fn test_rank() {
+    let test = |m: Matrix<Integer>, out: usize| {
+        assert_eq!(m.rank(), out);
+        assert_eq!(Matrix::<Rational>::from(&m).rank(), out);
+    };
+    test(Matrix::zero(0, 0), 0);
+    test(Matrix::zero(2, 3), 0);
+    test(Matrix::identity(4), 4);
+    test(integer_matrix(&[&[1, 2, 3], &[4, 5, 6], &[7, 8, 9]]), 2);
+    test(integer_matrix(&[&[1, 2, 3], &[2, 4, 6]]), 1);
+    test(integer_matrix(&[&[0, 0, 1], &[0, 1, 0]]), 2);
+    test(integer_matrix(&[&[0, 1], &[0, 2], &[0, 3]]), 1);
+    assert_eq!(rational_matrix(&[&["1/2", "1/3"], &["3/2", "1"]]).rank(), 1);
+}
+
+#[test]
+fn determinant_properties() {
+    integer_vec_gen().test_properties(|xs| {
+        let m = square_matrix_from_vec(xs);
+        let n = m.row_count();
+        let det = m.determinant();
+        assert_eq!(m.transpose().determinant(), det);
+        assert_eq!(Matrix::<Rational>::from(&m).determinant(), det);
+        assert_eq!(det != 0u32, m.rank() == n);
+        if n > 1 {
+            let mut swapped = m.clone().into_rows();
+            swapped.swap(0, n - 1);
+            assert_eq!(Matrix::from_rows(swapped).determinant(), -&det);
+        }
+        if n > 0 {
+            let mut negated = m.clone();
+            for j in 0..n {
+                negated[(0, j)] = -&m[(0, j)];
+            }
+            assert_eq!(negated.determinant(), -&det);
+        }
+    });
+
+    integer_vec_gen().test_properties(|mut xs| {
+        let ys = xs.split_off(xs.len() >> 1);
+        let a = square_matrix_from_vec(xs);
+        let mut b = square_matrix_from_vec(ys);
+        let n = a.row_count();
+        if b.row_count() != n {
+            b = Matrix::identity(n);
+        }
+        assert_eq!((&a * &b).determinant(), a.determinant() * b.determinant());
+    });
+
+    rational_vec_gen().test_properties(|xs| {
+        let m = square_matrix_from_vec(xs);
+        let det = m.determinant();
+        assert_eq!(m.transpose().determinant(), det);
+        assert_eq!(det != 0u32, m.rank() == m.row_count());
+        assert_eq!((&m * &m).determinant(), &det * &det);
+        if let Some(inverse) = m.inverse() {
+            assert_eq!(inverse.determinant() * det, 1u32);
+        }
+    });
+}
+
+#[test]
+fn rank_properties() {
+    integer_vec_gen().test_properties(|xs| {
+        for rows in 1..=3 {
+            let m = matrix_from_vec(xs.clone(), rows);
+            let rank = m.rank();
+            assert!(rank <= m.row_count());
+            assert!(rank <= m.column_count());
+            assert_eq!(m.transpose().rank(), rank);
+            assert_eq!(Matrix::<Rational>::from(&m).rank(), rank);
+            assert_eq!(
+                rank == 0,
+                m.into_rows().iter().flatten().all(|x| *x == 0u32)
+            );
+        }
+    });
+
+    rational_vec_gen().test_properties(|xs| {
+        for rows in 1..=3 {
+            let m = matrix_from_vec(xs.clone(), rows);
+            let rank = m.rank();
+            assert_eq!(m.transpose().rank(), rank);
+            let mut doubled = m.clone().into_rows();
+            doubled.push(doubled[0].clone());
+            assert_eq!(Matrix::from_rows(doubled).rank(), rank);
+            assert_eq!((&m * Matrix::zero(m.column_count(), 1)).rank(), 0);
+        }
+    });
+}
@@ -0,0 +1,115 @@
+use malachite_base::strings::ToDebugString;
+use malachite_nz::integer::Integer;
+use malachite_nz::test_util::generators::integer_vec_gen;
+use malachite_q::matrix::Matrix;
+use malachite_q::test_util::generators::rational_vec_gen;
+use malachite_q::Rational;
+
+fn integer_matrix(rows: &[&[i32]]) -> Matrix<Integer> {
+    Matrix::from_rows(
+        rows.iter()
+            .map(|row| row.iter().copied().map(Integer::from).collect())
+            .collect(),
+    )
+}
+
+// Arranges as many of the elements of `xs` as possible into a matrix with `rows` rows.
+fn matrix_from_vec<T>(xs: Vec<T>, rows: usize) -> Matrix<T> {
+    let cols = xs.len() / rows;
+    let mut xs = xs.into_iter();
+    Matrix::from_rows(
+        (0..rows)
+            .map(|_| xs.by_ref().take(cols).collect())
+            .collect(),
+    )
+}
+
+#[test]
+fn test_mul() {
+    let test = |a: Matrix<Integer>, b: Matrix<Integer>, out: &str| {
+        let product = a.clone() * b.clone();
+        assert_eq!(product.clone().into_rows().to_debug_string(), out);
+        assert_eq!(a.clone() * &b, product);
+        assert_eq!(&a * b.clone(), product);
+        assert_eq!(&a * &b, product);
+        assert_eq!(
+            Matrix::<Rational>::from(&a) * Matrix::from(&b),
+            Matrix::from(&product)
+        );
+    };
+    test(
+        integer_matrix(&[&[1, 2], &[3, 4]]),
+        integer_matrix(&[&[5, 6, 7], &[8, 9, 10]]),
+        "[[21, 24, 27], [47, 54, 61]]",
+    );
+    test(
+        integer_matrix(&[&[1, 2, 3]]),
+        integer_matrix(&[&[4], &[5], &[6]]),
+        "[[32]]",
+    );
+    test(
+        integer_matrix(&[&[4], &[5]]),
+        integer_matrix(&[&[1, -2]]),
+        "[[4, -8], [5, -10]]",
+    );
+    test(
+        Matrix::zero(2, 0),
+        Matrix::zero(0, 3),
+        "[[0, 0, 0], [0, 0, 0]]",
+    );
+    test(Matrix::zero(0, 2), Matrix::zero(2, 0), "[]");
+}
+
+#[test]
+#[should_panic]
+fn mul_fail() {
+    let _ = Matrix::<Integer>::identity(2) * Matrix::identity(3);
+}
+
+#[test]
+#[should_panic]
+fn mul_rational_fail() {
+    let _ = Matrix::<Rational>::zero(2, 3) * Matrix::zero(2, 3);
+}
+
+#[test]
+fn mul_properties() {
+    integer_vec_gen().test_properties(|mut xs| {
+        let ys = xs.split_off(xs.len() >> 1);
+        for k in 1..=3 {
+            let a = matrix_from_vec(xs.clone(), k).transpose();
+            let b = matrix_from_vec(ys.clone(), k);
+            let product = &a * &b;
+            assert_eq!(product.row_count(), a.row_count());
+            assert_eq!(product.column_count(), b.column_count());
+            assert_eq!(product.transpose(), b.transpose() * a.transpose());
+            assert_eq!(
+                Matrix::<Rational>::from(&a) * Matrix::from(&b),
+                Matrix::from(&product)
+            );
+        }
+    });
+
+    integer_vec_gen().test_properties(|xs| {
+        for rows in 1..=3 {
+            let m = matrix_from_vec(xs.clone(), rows);
+            assert_eq!(Matrix::identity(rows) * &m, m);
+            assert_eq!(&m * Matrix::identity(m.column_count()), m);
+            assert_eq!(
+                &m * Matrix::zero(m.column_count(), 2),
+                Matrix::zero(rows, 2)
+            );
+        }
+    });
+
+    rational_vec_gen().test_properties(|xs| {
+        for rows in 1..=3 {
+            let m = matrix_from_vec(xs.clone(), rows);
+            assert_eq!(Matrix::identity(rows) * &m, m);
+            assert_eq!(&m * Matrix::identity(m.column_count()), m);
+            let t = m.transpose();
+            let gram = &m * &t;
+            assert_eq!(gram.transpose(), gram);
+        }
+    });
+}
@@ -0,0 +1,191 @@
+use malachite_base::num::basic::traits::Zero;
+use malachite_base::strings::ToDebugString;
+use malachite_nz::integer::Integer;
+use malachite_nz::test_util::generators::integer_vec_gen;
+use malachite_q::matrix::Matrix;
+use malachite_q::test_util::generators::rational_vec_gen;
+use malachite_q::Rational;
+use std::str::FromStr;
+
+fn integer_matrix(rows: &[&[i32]]) -> Matrix<Integer> {
+    Matrix::from_rows(
+        rows.iter()
+            .map(|row| row.iter().copied().map(Integer::from).collect())
+            .collect(),
+    )
+}
+
+fn rational_matrix(rows: &[&[&str]]) -> Matrix<Rational> {
+    Matrix::from_rows(
+        rows.iter()
+            .map(|row| row.iter().map(|s| Rational::from_str(s).unwrap()).collect())
+            .collect(),
+    )
+}
+
+// Arranges as many of the elements of `xs` as possible into a matrix with `rows` rows.
+fn matrix_from_vec<T>(xs: Vec<T>, rows: usize) -> Matrix<T> {
+    let cols = xs.len() / rows;
+    let mut xs = xs.into_iter();
+    Matrix::from_rows(
+        (0..rows)
+            .map(|_| xs.by_ref().take(cols).collect())
+            .collect(),
+    )
+}
+
+fn column<T: Clone + Zero>(xs: Vec<T>) -> Matrix<T> {
+    let mut m = Matrix::zero(xs.len(), 1);
+    for (i, x) in xs.into_iter().enumerate() {
+        m[(i, 0)] = x;
+    }
+    m
+}
+
+// Checks that `m` is in reduced row echelon form, and returns its rank.
+fn check_rref(m: &Matrix<Rational>) -> usize {
+    let mut rank = 0;
+    let mut last_pivot = None;
+    for i in 0..m.row_count() {
+        match m.row(i).iter().position(|x| *x != 0u32) {
+            None => {
+                for k in i..m.row_count() {
+                    assert!(m.row(k).iter().all(|x| *x == 0u32));
+                }
+                break;
+            }
+            Some(c) => {
+                assert!(last_pivot.map_or(true, |p| c > p));
+                assert_eq!(m[(i, c)], 1u32);
+                for k in 0..m.row_count() {
+                    if k != i {
+                        assert_eq!(m[(k, c)], 0u32);
+                    }
+                }
+                last_pivot = Some(c);
+                rank += 1;
+            }
+        }
+    }
+    rank
+}
+
+#[test]
+fn test_rref() {
+    let test = |m: Matrix<Integer>, out: &str| {
+        let rref = m.rref();
+        assert_eq!(rref.clone().into_rows().to_debug_string(), out);
+        assert_eq!(Matrix::<Rational>::from(&m).rref(), rref);
+    };
+    test(Matrix::zero(0, 0), "[]");
+    test(Matrix::zero(2, 2), "[[0, 0], [0, 0]]");
+    test(integer_matrix(&[&[4]]), "[[1]]");
+    test(integer_matrix(&[&[1, 2], &[3, 4]]), "[[1, 0], [0, 1]]");
+    test(
+        integer_matrix(&[&[1, 2, 3], &[4, 5, 6], &[7, 8, 9]]),
+        "[[1, 0, -1], [0, 1, 2], [0, 0, 0]]",
+    );
+    test(
+        integer_matrix(&[&[2, 4, 1], &[3, 6, 2]]),
+        "[[1, 2, 0], [0, 0, 1]]",
+    );
+    test(integer_matrix(&[&[3, 1], &[6, 2]]), "[[1, 1/3], [0, 0]]");
+    test(
+        integer_matrix(&[&[0, 0, 2], &[0, 3, 1], &[0, 0, 0]]),
+        "[[0, 1, 0], [0, 0, 1], [0, 0, 0]]",
+    );
+    test(
+        integer_matrix(&[&[0, 2], &[3, 1], &[6, 4]]),
+        "[[1, 0], [0, 1], [0, 0]]",
+    );
+
+    assert_eq!(
+        rational_matrix(&[&["1/2", "1/3", "1"], &["1/4", "1/5", "2"]])
+            .rref()
+            .into_rows()
+            .to_debug_string(),
+        "[[1, 0, -28], [0, 1, 45]]"
+    );
+}
+
+#[test]
+fn test_nullspace() {
+    let test = |m: Matrix<Integer>, out: &str| {
+        assert_eq!(m.nullspace().to_debug_string(), out);
+    };
+    test(Matrix::zero(0, 0), "[]");
+    test(Matrix::identity(3), "[]");
+    test(Matrix::zero(1, 2), "[[1, 0], [0, 1]]");
+    test(integer_matrix(&[&[1, 2, 3], &[4, 5, 6]]), "[[1, -2, 1]]");
+    test(integer_matrix(&[&[2, 3, 4]]), "[[-3, 2, 0], [-2, 0, 1]]");
+    test(integer_matrix(&[&[-2, 3]]), "[[3, 2]]");
+    test(integer_matrix(&[&[0, 1], &[0, 2]]), "[[1, 0]]");
+
+    let test_rational = |m: Matrix<Rational>, out: &str| {
+        assert_eq!(m.nullspace().to_debug_string(), out);
+    };
+    test_rational(
+        rational_matrix(&[&["2", "3", "1/2"]]),
+        "[[-3/2, 1, 0], [-1/4, 0, 1]]",
+    );
+    test_rational(
+        rational_matrix(&[&["1/2", "1/3"], &["3/2", "1"]]),
+        "[[-2/3, 1]]",
+    );
+    test_rational(rational_matrix(&[&["1/2", "1/3"], &["1/4", "1/5"]]), "[]");
+}
+
+#[test]
+fn rref_properties() {
+    integer_vec_gen().test_properties(|xs| {
+        for rows in 1..=3 {
+            let m = matrix_from_vec(xs.clone(), rows);
+            let rref = m.rref();
+            assert_eq!(check_rref(&rref), m.rank());
+            assert_eq!(rref.rref(), rref);
+            assert_eq!(Matrix::<Rational>::from(&m).rref(), rref);
+        }
+    });
+
+    rational_vec_gen().test_properties(|xs| {
+        for rows in 1..=3 {
+            let m = matrix_from_vec(xs.clone(), rows);
+            let rref = m.rref();
+            assert_eq!(check_rref(&rref), m.rank());
+            assert_eq!(rref.rref(), rref);
+            let mut reversed = m.clone().into_rows();
+            reversed.reverse();
+            assert_eq!(Matrix::from_rows(reversed).rref(), rref);
+        }
+    });
+}
+
+#[test]
+fn nullspace_properties() {
+    integer_vec_gen().test_properties(|xs| {
+        for rows in 1..=3 {
+            let m = matrix_from_vec(xs.clone(), rows);
+            let nullspace = m.nullspace();
+            assert_eq!(m.rank() + nullspace.len(), m.column_count());
+            let zero = Matrix::zero(rows, 1);
+            for v in nullspace {
+                assert_eq!(v.len(), m.column_count());
+                assert!(v.iter().any(|x| *x != 0u32));
+                assert_eq!(&m * column(v), zero);
+            }
+        }
+    });
+
+    rational_vec_gen().test_properties(|xs| {
+        for rows in 1..=3 {
+            let m = matrix_from_vec(xs.clone(), rows);
+            let nullspace = m.nullspace();
+            assert_eq!(m.rank() + nullspace.len(), m.column_count());
+            let zero = Matrix::zero(rows, 1);
+            for v in nullspace {
+                assert_eq!(v.len(), m.column_count());
+                assert_eq!(&m * column(v), zero);
+            }
+        }
+    });
+}
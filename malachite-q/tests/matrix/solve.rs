@@ -0,0 +1,234 @@
+use malachite_base::num::arithmetic::traits::FloorSqrt;
+use malachite_base::num::basic::traits::Zero;
+use malachite_base::strings::ToDebugString;
+use malachite_nz::integer::Integer;
+use malachite_nz::test_util::generators::integer_vec_gen;
+use malachite_q::matrix::Matrix;
+use malachite_q::test_util::generators::rational_vec_gen;
+use malachite_q::Rational;
+use std::str::FromStr;
+
+fn integer_matrix(rows: &[&[i32]]) -> Matrix<Integer> {
+    Matrix::from_rows(
+        rows.iter()
+            .map(|row| row.iter().copied().map(Integer::from).collect())
+            .collect(),
+    )
+}
+
+fn rational_matrix(rows: &[&[&str]]) -> Matrix<Rational> {
+    Matrix::from_rows(
+        rows.iter()
+            .map(|row| row.iter().map(|s| Rational::from_str(s).unwrap()).collect())
+            .collect(),
+    )
+}
+
+fn integers(xs: &[i32]) -> Vec<Integer> {
+    xs.iter().copied().map(Integer::from).collect()
+}
+
+// Arranges as many of the elements of `xs` as possible into a square matrix.
+fn square_matrix_from_vec<T>(xs: Vec<T>) -> Matrix<T> {
+    let n = xs.len().floor_sqrt();
+    let mut xs = xs.into_iter();
+    Matrix::from_rows((0..n).map(|_| xs.by_ref().take(n).collect()).collect())
+}
+
+// Arranges as many of the elements of `xs` as possible into a matrix with `rows` rows.
+fn matrix_from_vec<T>(xs: Vec<T>, rows: usize) -> Matrix<T> {
+    let cols = xs.len() / rows;
+    let mut xs = xs.into_iter();
+    Matrix::from_rows(
+        (0..rows)
+            .map(|_| xs.by_ref().take(cols).collect())
+            .collect(),
+    )
+}
+
+fn column<T: Clone + Zero>(xs: Vec<T>) -> Matrix<T> {
+    let mut m = Matrix::zero(xs.len(), 1);
+    for (i, x) in xs.into_iter().enumerate() {
+        m[(i, 0)] = x;
+    }
+    m
+}
+
+#[test]
+fn test_solve() {
+    let test = |m: Matrix<Integer>, b: &[i32], out: &str| {
+        let b = integers(b);
+        let x = m.solve(&b);
+        assert_eq!(x.to_debug_string(), out);
+        let b: Vec<Rational> = b.into_iter().map(Rational::from).collect();
+        assert_eq!(Matrix::<Rational>::from(&m).solve(&b), x);
+    };
+    test(Matrix::zero(0, 0), &[], "Some([])");
+    test(Matrix::zero(2, 0), &[0, 0], "Some([])");
+    test(Matrix::zero(2, 0), &[0, 1], "None");
+    test(
+        integer_matrix(&[&[2, 1], &[1, 3]]),
+        &[1, 2],
+        "Some([1/5, 3/5])",
+    );
+    test(integer_matrix(&[&[1, 2], &[2, 4]]), &[3, 6], "Some([3, 0])");
+    test(integer_matrix(&[&[1, 2], &[2, 4]]), &[3, 5], "None");
+    test(
+        integer_matrix(&[&[0, 2], &[0, 4]]),
+        &[1, 2],
+        "Some([0, 1/2])",
+    );
+    test(
+        integer_matrix(&[&[1, 1], &[1, -1], &[2, 0]]),
+        &[4, 2, 6],
+        "Some([3, 1])",
+    );
+    test(
+        integer_matrix(&[&[1, 1], &[1, -1], &[2, 0]]),
+        &[4, 2, 7],
+        "None",
+    );
+
+    assert_eq!(
+        rational_matrix(&[&["1/2", "1/3"], &["1/4", "1/5"]])
+            .solve(&[Rational::from(1), Rational::from(1)])
+            .to_debug_string(),
+        "Some([-8, 15])"
+    );
+}
+
+#[test]
+#[should_panic]
+fn solve_fail() {
+    Matrix::<Integer>::identity(2).solve(&integers(&[1, 2, 3]));
+}
+
+#[test]
+#[should_panic]
+fn solve_rational_fail() {
+    Matrix::<Rational>::identity(2).solve(&[Rational::from(1)]);
+}
+
+#[test]
+fn test_inverse() {
+    let test = |m: Matrix<Integer>, out: &str| {
+        let inverse = m.inverse();
+        assert_eq!(
+            inverse.clone().map(Matrix::into_rows).to_debug_string(),
+            out
+        );
+        assert_eq!(Matrix::<Rational>::from(&m).inverse(), inverse);
+    };
+    test(Matrix::zero(0, 0), "Some([])");
+    test(integer_matrix(&[&[-3]]), "Some([[-1/3]])");
+    test(integer_matrix(&[&[0]]), "None");
+    test(
+        integer_matrix(&[&[2, 1], &[1, 3]]),
+        "Some([[3/5, -1/5], [-1/5, 2/5]])",
+    );
+    test(integer_matrix(&[&[1, 2], &[2, 4]]), "None");
+    test(
+        integer_matrix(&[&[0, 1], &[1, 0]]),
+        "Some([[0, 1], [1, 0]])",
+    );
+    test(
+        integer_matrix(&[&[2, -1, 0], &[-1, 2, -1], &[0, -1, 2]]),
+        "Some([[3/4, 1/2, 1/4], [1/2, 1, 1/2], [1/4, 1/2, 3/4]])",
+    );
+
+    assert_eq!(
+        rational_matrix(&[&["1/2", "1/3"], &["1/4", "1/5"]])
+            .inverse()
+            .unwrap()
+            .into_rows()
+            .to_debug_string(),
+        "[[12, -20], [-15, 30]]"
+    );
+}
+
+#[test]
+#[should_panic]
+fn inverse_fail() {
+    Matrix::<Integer>::zero(1, 2).inverse();
+}
+
+#[test]
+#[should_panic]
+fn inverse_rational_fail() {
+    Matrix::<Rational>::zero(2, 1).inverse();
+}
+
+#[test]
+fn solve_properties() {
+    integer_vec_gen().test_properties(|xs| {
+        for rows in 1..=3 {
+            let m = matrix_from_vec(xs.clone(), rows);
+            let q = Matrix::<Rational>::from(&m);
+            // A right-hand side in the column space always has a solution
+            let b: Vec<Integer> = (0..rows)
+                .map(|i| {
+                    m.row(i)
+                        .iter()
+                        .zip(1u32..)
+                        .map(|(x, j)| x * Integer::from(j))
+                        .sum()
+                })
+                .collect();
+            let x = m.solve(&b).unwrap();
+            let b: Vec<Rational> = b.into_iter().map(Rational::from).collect();
+            assert_eq!(&q * column(x.clone()), column(b.clone()));
+            assert_eq!(q.solve(&b), Some(x));
+
+            let b = integers(&[1, -1, 2][..rows]);
+            match m.solve(&b) {
+                Some(x) => assert_eq!(
+                    &q * column(x),
+                    column(b.into_iter().map(Rational::from).collect())
+                ),
+                None => assert!(m.rank() < rows),
+            }
+        }
+    });
+
+    rational_vec_gen().test_properties(|xs| {
+        for rows in 1..=3 {
+            let m = matrix_from_vec(xs.clone(), rows);
+            let b: Vec<Rational> = (0..rows)
+                .map(|i| Rational::from_unsigneds(1u64, i as u64 + 2))
+                .collect();
+            match m.solve(&b) {
+                Some(x) => assert_eq!(&m * column(x), column(b)),
+                None => assert!(m.rank() < rows),
+            }
+        }
+    });
+}
+
+#[test]
+fn inverse_properties() {
+    integer_vec_gen().test_properties(|xs| {
+        let m = square_matrix_from_vec(xs);
+        let n = m.row_count();
+        let q = Matrix::<Rational>::from(&m);
+        let inverse = m.inverse();
+        assert_eq!(inverse.is_some(), m.determinant() != 0u32);
+        assert_eq!(q.inverse(), inverse);
+        if let Some(inverse) = inverse {
+            assert_eq!(&q * &inverse, Matrix::identity(n));
+            assert_eq!(&inverse * &q, Matrix::identity(n));
+            assert_eq!(inverse.inverse(), Some(q));
+        }
+    });
+
+    rational_vec_gen().test_properties(|xs| {
+        let m = square_matrix_from_vec(xs);
+        let n = m.row_count();
+        match m.inverse() {
+            Some(inverse) => {
+                assert_eq!(&m * &inverse, Matrix::identity(n));
+                assert_eq!(inverse.transpose(), m.transpose().inverse().unwrap());
+            }
+            None => assert!(m.rank() < n),
+        }
+    });
+}
@@ -0,0 +1,124 @@
+use malachite_base::num::basic::traits::Zero;
+use malachite_nz::integer::Integer;
+use malachite_nz::natural::arithmetic::mod_natural::ModNatural;
+use malachite_nz::natural::Natural;
+use malachite_nz::test_util::generators::integer_vec_gen;
+use malachite_q::polynomial::Polynomial;
+use malachite_q::test_util::generators::rational_vec_gen;
+use malachite_q::Rational;
+
+fn integer_polynomial(xs: &[i32]) -> Polynomial<Integer> {
+    Polynomial::from(xs.iter().copied().map(Integer::from).collect::<Vec<_>>())
+}
+
+fn mod_polynomial<'a>(xs: &[u32], m: &'a Natural) -> Polynomial<ModNatural<'a>> {
+    Polynomial::from_naturals(xs.iter().copied().map(Natural::from).collect(), m)
+}
+
+#[test]
+fn test_add_sub_neg() {
+    let test = |xs: &[i32], ys: &[i32], sum: &str, difference: &str| {
+        let p = integer_polynomial(xs);
+        let q = integer_polynomial(ys);
+        let s = p.clone() + q.clone();
+        assert_eq!(s.to_string(), sum);
+        assert_eq!(p.clone() + &q, s);
+        assert_eq!(&p + q.clone(), s);
+        assert_eq!(&p + &q, s);
+        let d = p.clone() - q.clone();
+        assert_eq!(d.to_string(), difference);
+        assert_eq!(p.clone() - &q, d);
+        assert_eq!(&p - q.clone(), d);
+        assert_eq!(&p - &q, d);
+        assert_eq!(-&d, &q - &p);
+        assert_eq!(-d, q - p);
+    };
+    test(&[], &[], "0", "0");
+    test(&[1, 2], &[], "2*x+1", "2*x+1");
+    test(&[], &[1, 2], "2*x+1", "-2*x-1");
+    test(&[1, 2], &[3, -2, 1], "x^2+4", "-x^2+4*x-2");
+    test(&[1, 2, 3], &[1, 2, 3], "6*x^2+4*x+2", "0");
+    test(&[0, 0, 5], &[1, 0, -5], "1", "10*x^2-1");
+}
+
+#[test]
+fn test_add_sub_neg_mod() {
+    let m = Natural::from(7u32);
+    let test = |xs: &[u32], ys: &[u32], sum: &str, difference: &str, negative: &str| {
+        let p = mod_polynomial(xs, &m);
+        let q = mod_polynomial(ys, &m);
+        assert_eq!((&p + &q).to_string(), sum);
+        assert_eq!((&p - &q).to_string(), difference);
+        assert_eq!((-&p).to_string(), negative);
+        assert_eq!(-p.clone(), -&p);
+    };
+    test(&[], &[], "0", "0", "0");
+    test(&[1, 6], &[6, 1], "0", "5*x+2 mod 7", "x+6 mod 7");
+    test(
+        &[3, 0, 4],
+        &[5, 0, 3],
+        "1 mod 7",
+        "x^2+5 mod 7",
+        "3*x^2+4 mod 7",
+    );
+}
+
+#[test]
+#[should_panic]
+fn add_mod_fail() {
+    let m = Natural::from(7u32);
+    let n = Natural::from(5u32);
+    let _ = mod_polynomial(&[1], &m) + mod_polynomial(&[1], &n);
+}
+
+#[test]
+#[should_panic]
+fn sub_mod_fail() {
+    let m = Natural::from(7u32);
+    let n = Natural::from(5u32);
+    let _ = mod_polynomial(&[1], &m) - mod_polynomial(&[1], &n);
+}
+
+#[test]
+fn add_sub_neg_properties() {
+    integer_vec_gen().test_properties(|mut xs| {
+        let ys = xs.split_off(xs.len() >> 1);
+        let p = Polynomial::from(xs);
+        let q = Polynomial::from(ys);
+        let s = &p + &q;
+        assert_eq!(&q + &p, s);
+        assert_eq!(&s - &q, p);
+        assert_eq!(&p - &q, -(&q - &p));
+        assert_eq!(&p - &q, &p + -&q);
+        assert_eq!(-(-p.clone()), p);
+        assert_eq!(&p - &p, Polynomial::ZERO);
+        assert_eq!(&p + Polynomial::ZERO, p);
+        assert_eq!(
+            Polynomial::<Rational>::from(&p) + Polynomial::from(&q),
+            Polynomial::from(&s)
+        );
+
+        let m = Natural::from(101u32);
+        let reduce =
+            |p: &Polynomial<Integer>| Polynomial::<ModNatural>::from_integer_polynomial(p, &m);
+        assert_eq!(reduce(&p) + reduce(&q), reduce(&s));
+        assert_eq!(reduce(&p) - reduce(&q), reduce(&(&p - &q)));
+        assert_eq!(-reduce(&p), reduce(&-&p));
+    });
+
+    rational_vec_gen().test_properties(|mut xs| {
+        let ys = xs.split_off(xs.len() >> 1);
+        let p = Polynomial::from(xs);
+        let q = Polynomial::from(ys);
+        let s = &p + &q;
+        assert_eq!(&q + &p, s);
+        assert_eq!(&s - &q, p);
+        assert_eq!(-(-p.clone()), p);
+        assert_eq!(&p - &p, Polynomial::ZERO);
+        assert!(s.degree() <= p.degree().max(q.degree()));
+        assert!(s
+            .coefficients()
+            .last()
+            .map_or(true, |c| *c != Rational::ZERO));
+    });
+}
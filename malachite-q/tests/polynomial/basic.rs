@@ -0,0 +1,164 @@
+use malachite_base::num::basic::traits::Zero;
+use malachite_base::strings::ToDebugString;
+use malachite_nz::integer::Integer;
+use malachite_nz::natural::arithmetic::mod_natural::ModNatural;
+use malachite_nz::natural::Natural;
+use malachite_nz::test_util::generators::integer_vec_gen;
+use malachite_q::polynomial::Polynomial;
+use malachite_q::test_util::generators::rational_vec_gen;
+use malachite_q::Rational;
+use std::str::FromStr;
+
+fn integer_polynomial(xs: &[i32]) -> Polynomial<Integer> {
+    Polynomial::from(xs.iter().copied().map(Integer::from).collect::<Vec<_>>())
+}
+
+fn rational_polynomial(xs: &[&str]) -> Polynomial<Rational> {
+    Polynomial::from(
+        xs.iter()
+            .map(|x| Rational::from_str(x).unwrap())
+            .collect::<Vec<_>>(),
+    )
+}
+
+fn mod_polynomial<'a>(xs: &[u32], m: &'a Natural) -> Polynomial<ModNatural<'a>> {
+    Polynomial::from_naturals(xs.iter().copied().map(Natural::from).collect(), m)
+}
+
+#[test]
+fn test_from_integers() {
+    let test = |xs: &[i32], out: &str, degree: Option<usize>| {
+        let p = integer_polynomial(xs);
+        assert_eq!(p.to_string(), out);
+        assert_eq!(p.to_debug_string(), out);
+        assert_eq!(p.degree(), degree);
+        assert_eq!(p.coefficients().len(), degree.map_or(0, |d| d + 1));
+        assert!(p.leading_coefficient().map_or(true, |c| *c != 0u32));
+    };
+    test(&[], "0", None);
+    test(&[0, 0], "0", None);
+    test(&[5], "5", Some(0));
+    test(&[-5, 0], "-5", Some(0));
+    test(&[0, 1], "x", Some(1));
+    test(&[0, -1], "-x", Some(1));
+    test(&[1, 1, 1], "x^2+x+1", Some(2));
+    test(&[-1, 0, -3, 0], "-3*x^2-1", Some(2));
+    test(&[0, 0, 0, 2, -1], "-x^4+2*x^3", Some(4));
+}
+
+#[test]
+fn test_from_rationals() {
+    let test = |xs: &[&str], out: &str| {
+        let p = rational_polynomial(xs);
+        assert_eq!(p.to_string(), out);
+        assert_eq!(p.to_debug_string(), out);
+    };
+    test(&[], "0");
+    test(&["0"], "0");
+    test(&["1/2"], "1/2");
+    test(&["-1/2", "1"], "x-1/2");
+    test(&["0", "-2/3", "0", "3/4", "0"], "3/4*x^3-2/3*x");
+    test(&["1", "-1"], "-x+1");
+}
+
+#[test]
+fn test_from_naturals() {
+    let m = Natural::from(7u32);
+    let test = |xs: &[u32], out: &str| {
+        let p = mod_polynomial(xs, &m);
+        assert_eq!(p.to_string(), out);
+        assert_eq!(p.to_debug_string(), out);
+    };
+    test(&[], "0");
+    test(&[7, 14], "0");
+    test(&[3], "3 mod 7");
+    test(&[10, 8], "x+3 mod 7");
+    test(&[6, 3, 1], "x^2+3*x+6 mod 7");
+    test(&[0, 0, 15, 21], "x^2 mod 7");
+}
+
+#[test]
+fn test_from_mod_naturals() {
+    let m = Natural::from(5u32);
+    let p = Polynomial::from(vec![
+        ModNatural::new(Natural::from(1u32), &m),
+        ModNatural::new(Natural::from(4u32), &m),
+        ModNatural::new(Natural::ZERO, &m),
+    ]);
+    assert_eq!(p.to_string(), "4*x+1 mod 5");
+    assert_eq!(p, mod_polynomial(&[6, 9], &m));
+}
+
+#[test]
+#[should_panic]
+fn from_mod_naturals_fail() {
+    let m = Natural::from(5u32);
+    let n = Natural::from(7u32);
+    let _ = Polynomial::from(vec![
+        ModNatural::new(Natural::from(1u32), &m),
+        ModNatural::new(Natural::from(1u32), &n),
+    ]);
+}
+
+#[test]
+fn test_integer_polynomial_conversions() {
+    let m = Natural::from(7u32);
+    let test = |xs: &[i32], out: &str, rational_out: &str| {
+        let p = integer_polynomial(xs);
+        let q = Polynomial::<ModNatural>::from_integer_polynomial(&p, &m);
+        assert_eq!(q.to_string(), out);
+        assert_eq!(
+            q.to_integer_polynomial(),
+            Polynomial::<ModNatural>::from_integer_polynomial(&q.to_integer_polynomial(), &m)
+                .to_integer_polynomial()
+        );
+        assert_eq!(Polynomial::<Rational>::from(&p).to_string(), rational_out);
+    };
+    test(&[], "0", "0");
+    test(&[-1], "6 mod 7", "-1");
+    test(&[-1, 14], "6 mod 7", "14*x-1");
+    test(&[3, -10, 8], "x^2+4*x+3 mod 7", "8*x^2-10*x+3");
+}
+
+#[test]
+fn test_zero() {
+    assert_eq!(Polynomial::<Integer>::ZERO.to_string(), "0");
+    assert_eq!(Polynomial::<Integer>::ZERO.degree(), None);
+    assert_eq!(Polynomial::<Rational>::ZERO.leading_coefficient(), None);
+    assert!(Polynomial::<Rational>::ZERO.coefficients().is_empty());
+}
+
+#[test]
+fn polynomial_properties() {
+    integer_vec_gen().test_properties(|xs| {
+        let p = Polynomial::from(xs.clone());
+        assert!(xs.starts_with(p.coefficients()));
+        assert!(xs[p.coefficients().len()..].iter().all(|x| *x == 0u32));
+        assert!(p.leading_coefficient().map_or(true, |c| *c != 0u32));
+        assert_eq!(Polynomial::from(p.clone().into_coefficients()), p);
+        let q = Polynomial::<Rational>::from(&p);
+        assert_eq!(q.degree(), p.degree());
+        assert_eq!(q.to_string(), p.to_string());
+    });
+
+    rational_vec_gen().test_properties(|xs| {
+        let p = Polynomial::from(xs.clone());
+        assert!(xs.starts_with(p.coefficients()));
+        assert!(p.leading_coefficient().map_or(true, |c| *c != 0u32));
+        assert_eq!(Polynomial::from(p.clone().into_coefficients()), p);
+    });
+
+    let m = Natural::from(101u32);
+    integer_vec_gen().test_properties(|xs| {
+        let p = Polynomial::from(xs);
+        let q = Polynomial::<ModNatural>::from_integer_polynomial(&p, &m);
+        assert!(q.degree() <= p.degree());
+        let r = q.to_integer_polynomial();
+        assert!(r.coefficients().iter().all(|c| *c >= 0u32 && *c < 101u32));
+        assert_eq!(Polynomial::<ModNatural>::from_integer_polynomial(&r, &m), q);
+        assert_eq!(
+            Polynomial::<ModNatural>::from_integer_polynomial(&(&p - &r), &m),
+            Polynomial::from_naturals(Vec::new(), &m)
+        );
+    });
+}
@@ -0,0 +1,96 @@
+use malachite_base::num::basic::traits::Zero;
+use malachite_nz::integer::Integer;
+use malachite_nz::natural::Natural;
+use malachite_nz::test_util::generators::integer_vec_gen;
+use malachite_q::polynomial::Polynomial;
+use malachite_q::test_util::generators::rational_vec_gen;
+use malachite_q::Rational;
+use std::str::FromStr;
+
+fn integer_polynomial(xs: &[i32]) -> Polynomial<Integer> {
+    Polynomial::from(xs.iter().copied().map(Integer::from).collect::<Vec<_>>())
+}
+
+fn rational_polynomial(xs: &[&str]) -> Polynomial<Rational> {
+    Polynomial::from(
+        xs.iter()
+            .map(|x| Rational::from_str(x).unwrap())
+            .collect::<Vec<_>>(),
+    )
+}
+
+#[test]
+fn test_content_and_primitive_part() {
+    let test = |xs: &[i32], content: u32, primitive_part: &str| {
+        let p = integer_polynomial(xs);
+        assert_eq!(p.content(), content);
+        assert_eq!(p.primitive_part().to_string(), primitive_part);
+    };
+    test(&[], 0, "0");
+    test(&[-5], 5, "-1");
+    test(&[6, -4], 2, "-2*x+3");
+    test(&[0, 0, 3], 3, "x^2");
+    test(&[2, 3, 4], 1, "4*x^2+3*x+2");
+    test(&[-12, 0, 18, -30], 6, "-5*x^3+3*x^2-2");
+}
+
+#[test]
+fn test_content_and_primitive_part_rational() {
+    let test = |xs: &[&str], content: &str, primitive_part: &str| {
+        let p = rational_polynomial(xs);
+        assert_eq!(p.content().to_string(), content);
+        assert_eq!(p.primitive_part().to_string(), primitive_part);
+    };
+    test(&[], "0", "0");
+    test(&["-3/4"], "3/4", "-1");
+    test(&["3/2", "6"], "3/2", "4*x+1");
+    test(&["1/2", "1/3", "1/4"], "1/12", "3*x^2+4*x+6");
+    test(&["-2/3", "0", "-4/9"], "2/9", "-2*x^2-3");
+}
+
+#[test]
+fn content_properties() {
+    integer_vec_gen().test_properties(|xs| {
+        let p = Polynomial::from(xs);
+        let content = p.content();
+        let primitive_part = p.primitive_part();
+        assert_eq!(
+            Polynomial::from(vec![Integer::from(content.clone())]) * &primitive_part,
+            p
+        );
+        assert_eq!(primitive_part.degree(), p.degree());
+        if p == Polynomial::ZERO {
+            assert_eq!(content, 0u32);
+        } else {
+            assert_eq!(primitive_part.content(), 1u32);
+            assert_eq!(primitive_part.primitive_part(), primitive_part);
+            assert_eq!(
+                *primitive_part.leading_coefficient().unwrap() > 0u32,
+                *p.leading_coefficient().unwrap() > 0u32
+            );
+        }
+        assert_eq!(
+            Polynomial::<Rational>::from(&p).content(),
+            Rational::from(content)
+        );
+        assert_eq!(
+            Polynomial::<Rational>::from(&p).primitive_part(),
+            primitive_part
+        );
+        assert_eq!((-&p).content(), p.content());
+    });
+
+    rational_vec_gen().test_properties(|xs| {
+        let p = Polynomial::from(xs);
+        let content = p.content();
+        let primitive_part = p.primitive_part();
+        assert_eq!(
+            Polynomial::from(vec![content.clone()]) * Polynomial::<Rational>::from(&primitive_part),
+            p
+        );
+        assert!(content >= 0u32);
+        if p != Polynomial::ZERO {
+            assert_eq!(primitive_part.content(), Natural::from(1u32));
+        }
+    });
+}
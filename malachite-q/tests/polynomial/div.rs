@@ -0,0 +1,160 @@
+use malachite_base::num::arithmetic::traits::{DivRem, Pow};
+use malachite_base::strings::ToDebugString;
+use malachite_nz::integer::Integer;
+use malachite_nz::natural::arithmetic::mod_natural::ModNatural;
+use malachite_nz::natural::Natural;
+use malachite_nz::test_util::generators::integer_vec_gen;
+use malachite_q::polynomial::Polynomial;
+use malachite_q::test_util::generators::rational_vec_gen;
+use malachite_q::Rational;
+
+fn integer_polynomial(xs: &[i32]) -> Polynomial<Integer> {
+    Polynomial::from(xs.iter().copied().map(Integer::from).collect::<Vec<_>>())
+}
+
+fn mod_polynomial<'a>(xs: &[u32], m: &'a Natural) -> Polynomial<ModNatural<'a>> {
+    Polynomial::from_naturals(xs.iter().copied().map(Natural::from).collect(), m)
+}
+
+#[test]
+fn test_pseudo_div_rem() {
+    let test = |xs: &[i32], ys: &[i32], out: &str| {
+        let p = integer_polynomial(xs);
+        let q = integer_polynomial(ys);
+        assert_eq!(p.pseudo_div_rem(&q).to_debug_string(), out);
+    };
+    test(&[], &[1, 2], "(0, 0)");
+    test(&[3], &[1, 2], "(0, 3)");
+    test(&[1, 0, 0, 1], &[1, 2], "(4*x^2-2*x+1, 7)");
+    test(&[-1, 0, 1], &[-1, 1], "(x+1, 0)");
+    test(&[1, 2, 3, 4], &[5], "(500*x^3+375*x^2+250*x+125, 0)");
+    test(&[1, 1, 1, 1], &[1, 0, 2], "(2*x+2, 2*x+2)");
+}
+
+#[test]
+#[should_panic]
+fn pseudo_div_rem_fail() {
+    integer_polynomial(&[1, 2]).pseudo_div_rem(&integer_polynomial(&[]));
+}
+
+#[test]
+fn test_div_rem() {
+    let test = |xs: &[i32], ys: &[i32], out: &str| {
+        let p = Polynomial::<Rational>::from(&integer_polynomial(xs));
+        let q = Polynomial::<Rational>::from(&integer_polynomial(ys));
+        let (quotient, remainder) = p.clone().div_rem(q.clone());
+        assert_eq!((&quotient, &remainder).to_debug_string(), out);
+        assert_eq!(p.clone().div_rem(&q), (quotient.clone(), remainder.clone()));
+        assert_eq!(
+            (&p).div_rem(q.clone()),
+            (quotient.clone(), remainder.clone())
+        );
+        assert_eq!((&p).div_rem(&q), (quotient, remainder));
+    };
+    test(&[], &[1, 2], "(0, 0)");
+    test(&[3], &[1, 2], "(0, 3)");
+    test(&[1, 0, 1], &[1, 2], "(1/2*x-1/4, 5/4)");
+    test(&[1, 0, 0, 1], &[1, 1], "(x^2-x+1, 0)");
+    test(&[1, 2, 3], &[3], "(x^2+2/3*x+1/3, 0)");
+}
+
+#[test]
+#[should_panic]
+fn div_rem_fail() {
+    let p = Polynomial::<Rational>::from(&integer_polynomial(&[1, 2]));
+    p.div_rem(Polynomial::from(Vec::<Rational>::new()));
+}
+
+#[test]
+fn test_div_rem_mod() {
+    let m = Natural::from(7u32);
+    let test = |xs: &[u32], ys: &[u32], out: &str| {
+        let p = mod_polynomial(xs, &m);
+        let q = mod_polynomial(ys, &m);
+        assert_eq!((&p).div_rem(&q).to_debug_string(), out);
+    };
+    test(&[], &[1, 2], "(0, 0)");
+    test(&[1, 0, 1], &[1, 2], "(4*x+5 mod 7, 3 mod 7)");
+    test(&[6, 0, 1], &[1, 1], "(x+6 mod 7, 0)");
+    test(&[1, 2, 3], &[3], "(x^2+3*x+5 mod 7, 0)");
+
+    // Over Z/6Z, division works when the leading coefficient of the divisor is a unit.
+    let m = Natural::from(6u32);
+    let p = mod_polynomial(&[1, 2, 3], &m);
+    let q = mod_polynomial(&[2, 5], &m);
+    assert_eq!(p.div_rem(q).to_debug_string(), "(3*x+4 mod 6, 5 mod 6)");
+}
+
+#[test]
+#[should_panic]
+fn div_rem_mod_fail_1() {
+    let m = Natural::from(7u32);
+    mod_polynomial(&[1, 2], &m).div_rem(mod_polynomial(&[], &m));
+}
+
+#[test]
+#[should_panic]
+fn div_rem_mod_fail_2() {
+    let m = Natural::from(6u32);
+    mod_polynomial(&[1, 2, 3], &m).div_rem(mod_polynomial(&[1, 2], &m));
+}
+
+#[test]
+#[should_panic]
+fn div_rem_mod_fail_3() {
+    let m = Natural::from(7u32);
+    let n = Natural::from(5u32);
+    mod_polynomial(&[1, 2], &m).div_rem(mod_polynomial(&[1, 1], &n));
+}
+
+#[test]
+fn div_rem_properties() {
+    integer_vec_gen().test_properties(|mut xs| {
+        let ys = xs.split_off(xs.len() >> 1);
+        let p = Polynomial::from(xs);
+        let q = Polynomial::from(ys);
+        if q.degree().is_none() {
+            return;
+        }
+        let (quotient, remainder) = p.pseudo_div_rem(&q);
+        assert!(remainder.degree() < q.degree());
+        let e = (p.coefficients().len() + 1).saturating_sub(q.coefficients().len()) as u64;
+        let scale = Polynomial::from(vec![q.leading_coefficient().unwrap().pow(e)]);
+        assert_eq!(scale * &p, &quotient * &q + &remainder);
+
+        let p = Polynomial::<Rational>::from(&p);
+        let q = Polynomial::<Rational>::from(&q);
+        let (quotient, remainder) = (&p).div_rem(&q);
+        assert!(remainder.degree() < q.degree());
+        assert_eq!(&quotient * &q + remainder, p);
+        assert_eq!(
+            (&quotient * &q).div_rem(&q),
+            (quotient, Polynomial::from(Vec::new()))
+        );
+    });
+
+    rational_vec_gen().test_properties(|mut xs| {
+        let ys = xs.split_off(xs.len() >> 1);
+        let p = Polynomial::from(xs);
+        let q = Polynomial::from(ys);
+        if q.degree().is_none() {
+            return;
+        }
+        let (quotient, remainder) = (&p).div_rem(&q);
+        assert!(remainder.degree() < q.degree());
+        assert_eq!(quotient * &q + remainder, p);
+    });
+
+    let m = Natural::from(101u32);
+    integer_vec_gen().test_properties(|mut xs| {
+        let ys = xs.split_off(xs.len() >> 1);
+        let p = Polynomial::<ModNatural>::from_integer_polynomial(&Polynomial::from(xs), &m);
+        let q = Polynomial::<ModNatural>::from_integer_polynomial(&Polynomial::from(ys), &m);
+        if q.degree().is_none() {
+            return;
+        }
+        let (quotient, remainder) = (&p).div_rem(&q);
+        assert!(remainder.degree() < q.degree());
+        assert_eq!(quotient * &q + remainder, p);
+    });
+}
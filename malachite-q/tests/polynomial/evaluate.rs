@@ -0,0 +1,155 @@
+use malachite_base::num::arithmetic::traits::Pow;
+use malachite_base::num::basic::traits::Zero;
+use malachite_nz::integer::Integer;
+use malachite_nz::natural::arithmetic::mod_natural::ModNatural;
+use malachite_nz::natural::Natural;
+use malachite_nz::test_util::generators::integer_vec_gen;
+use malachite_q::polynomial::Polynomial;
+use malachite_q::test_util::generators::rational_vec_gen;
+use malachite_q::Rational;
+use std::str::FromStr;
+
+fn integer_polynomial(xs: &[i32]) -> Polynomial<Integer> {
+    Polynomial::from(xs.iter().copied().map(Integer::from).collect::<Vec<_>>())
+}
+
+fn mod_polynomial<'a>(xs: &[u32], m: &'a Natural) -> Polynomial<ModNatural<'a>> {
+    Polynomial::from_naturals(xs.iter().copied().map(Natural::from).collect(), m)
+}
+
+// Evaluates a polynomial term by term, without Horner's rule.
+fn evaluate_naive(xs: &[Rational], x: &Rational) -> Rational {
+    xs.iter()
+        .enumerate()
+        .map(|(i, c)| c * x.pow(i as u64))
+        .fold(Rational::ZERO, |acc, y| acc + y)
+}
+
+#[test]
+fn test_evaluate() {
+    let test = |xs: &[i32], x: &str, out: &str| {
+        let p = integer_polynomial(xs);
+        let x = Rational::from_str(x).unwrap();
+        assert_eq!(p.evaluate(&x).to_string(), out);
+        assert_eq!(
+            Polynomial::<Rational>::from(&p).evaluate(&x).to_string(),
+            out
+        );
+    };
+    test(&[], "3", "0");
+    test(&[], "-1/2", "0");
+    test(&[7], "-1/2", "7");
+    test(&[-1, 0, 2], "1/3", "-7/9");
+    test(&[-1, 0, 2], "0", "-1");
+    test(&[0, -2, 0, 1], "-3/2", "-3/8");
+    test(&[1, 1, 1, 1, 1], "2", "31");
+    test(&[1, 1, 1, 1, 1], "-1/2", "11/16");
+}
+
+#[test]
+fn test_evaluate_mod() {
+    let m = Natural::from(7u32);
+    let test = |xs: &[u32], x: u32, out: &str| {
+        let p = mod_polynomial(xs, &m);
+        let x = ModNatural::new(Natural::from(x), &m);
+        assert_eq!(p.evaluate(&x).to_string(), out);
+    };
+    test(&[], 3, "0 mod 7");
+    test(&[1, 0, 1], 3, "3 mod 7");
+    test(&[6, 0, 1], 6, "0 mod 7");
+    test(&[1, 1, 1, 1, 1, 1, 1], 2, "1 mod 7");
+}
+
+#[test]
+#[should_panic]
+fn evaluate_mod_fail() {
+    let m = Natural::from(7u32);
+    let n = Natural::from(5u32);
+    mod_polynomial(&[1, 1], &m).evaluate(&ModNatural::new(Natural::from(1u32), &n));
+}
+
+#[test]
+fn test_derivative() {
+    let test = |xs: &[i32], out: &str| {
+        let p = integer_polynomial(xs);
+        let derivative = p.derivative();
+        assert_eq!(derivative.to_string(), out);
+        assert_eq!(
+            Polynomial::<Rational>::from(&p).derivative(),
+            Polynomial::from(&derivative)
+        );
+    };
+    test(&[], "0");
+    test(&[5], "0");
+    test(&[5, -1], "-1");
+    test(&[5, -1, 3], "6*x-1");
+    test(&[0, 0, 0, 0, 1], "4*x^3");
+
+    let m = Natural::from(3u32);
+    let test = |xs: &[u32], out: &str| {
+        assert_eq!(mod_polynomial(xs, &m).derivative().to_string(), out);
+    };
+    test(&[], "0");
+    test(&[1, 2, 2], "x+2 mod 3");
+    test(&[0, 0, 0, 1], "0");
+    test(&[0, 0, 0, 1, 1], "x^3 mod 3");
+}
+
+#[test]
+fn evaluate_properties() {
+    integer_vec_gen().test_properties(|mut xs| {
+        let ys = xs.split_off(xs.len() >> 1);
+        let p = Polynomial::from(xs);
+        let q = Polynomial::from(ys);
+        let rational_p = Polynomial::<Rational>::from(&p);
+        for x in ["0", "1", "-1", "1/2", "-7/3"] {
+            let x = Rational::from_str(x).unwrap();
+            let y = p.evaluate(&x);
+            assert_eq!(rational_p.evaluate(&x), y);
+            assert_eq!(evaluate_naive(rational_p.coefficients(), &x), y);
+            assert_eq!((&p + &q).evaluate(&x), &y + q.evaluate(&x));
+            assert_eq!((&p * &q).evaluate(&x), &y * q.evaluate(&x));
+        }
+
+        let m = Natural::from(101u32);
+        let reduce =
+            |p: &Polynomial<Integer>| Polynomial::<ModNatural>::from_integer_polynomial(p, &m);
+        for x in [0u32, 1, 5, 100] {
+            let y = Integer::try_from(p.evaluate(&Rational::from(x))).unwrap();
+            let z = reduce(&p).evaluate(&ModNatural::new(Natural::from(x), &m));
+            assert_eq!(
+                Polynomial::from(vec![z]),
+                reduce(&Polynomial::from(vec![y]))
+            );
+        }
+    });
+
+    integer_vec_gen().test_properties(|mut xs| {
+        let ys = xs.split_off(xs.len() >> 1);
+        let p = Polynomial::from(xs);
+        let q = Polynomial::from(ys);
+        let derivative = p.derivative();
+        assert_eq!(
+            derivative.degree(),
+            p.degree().and_then(|d| d.checked_sub(1))
+        );
+        assert_eq!((&p + &q).derivative(), &derivative + q.derivative());
+        assert_eq!(
+            (&p * &q).derivative(),
+            &derivative * &q + &p * q.derivative()
+        );
+    });
+
+    rational_vec_gen().test_properties(|xs| {
+        let p = Polynomial::from(xs);
+        for x in ["0", "-1", "3/5"] {
+            let x = Rational::from_str(x).unwrap();
+            assert_eq!(p.evaluate(&x), evaluate_naive(p.coefficients(), &x));
+        }
+        let mut integral = vec![Rational::ZERO];
+        for (i, c) in p.coefficients().iter().enumerate() {
+            integral.push(c / Rational::from(i as u64 + 1));
+        }
+        assert_eq!(Polynomial::from(integral).derivative(), p);
+    });
+}
@@ -0,0 +1,148 @@
+use malachite_base::num::arithmetic::traits::{DivRem, Gcd};
+use malachite_nz::integer::Integer;
+use malachite_nz::natural::arithmetic::mod_natural::ModNatural;
+use malachite_nz::natural::Natural;
+use malachite_nz::test_util::generators::integer_vec_gen;
+use malachite_q::polynomial::Polynomial;
+use malachite_q::test_util::generators::rational_vec_gen;
+use malachite_q::Rational;
+
+fn integer_polynomial(xs: &[i32]) -> Polynomial<Integer> {
+    Polynomial::from(xs.iter().copied().map(Integer::from).collect::<Vec<_>>())
+}
+
+fn mod_polynomial<'a>(xs: &[u32], m: &'a Natural) -> Polynomial<ModNatural<'a>> {
+    Polynomial::from_naturals(xs.iter().copied().map(Natural::from).collect(), m)
+}
+
+fn divides(d: &Polynomial<Integer>, p: &Polynomial<Integer>) -> bool {
+    d.degree().is_none() && p.degree().is_none()
+        || d.degree().is_some() && p.pseudo_div_rem(d).1.degree().is_none()
+}
+
+#[test]
+fn test_gcd() {
+    let test = |xs: &[i32], ys: &[i32], out: &str, rational_out: &str| {
+        let p = integer_polynomial(xs);
+        let q = integer_polynomial(ys);
+        let gcd = p.clone().gcd(q.clone());
+        assert_eq!(gcd.to_string(), out);
+        assert_eq!(p.clone().gcd(&q), gcd);
+        assert_eq!((&p).gcd(q.clone()), gcd);
+        assert_eq!((&p).gcd(&q), gcd);
+        assert_eq!(p.subresultant_gcd(&q), gcd);
+        assert_eq!(
+            Polynomial::<Rational>::from(&p)
+                .gcd(Polynomial::from(&q))
+                .to_string(),
+            rational_out
+        );
+    };
+    test(&[], &[], "0", "0");
+    test(&[], &[6, -3], "3*x-6", "x-2");
+    test(&[-2, 3], &[], "3*x-2", "x-2/3");
+    test(&[6], &[0, 4], "2", "1");
+    test(&[1, 0, 1], &[1, 1], "1", "1");
+    test(&[-2, 0, 2], &[4, 4], "2*x+2", "x+1");
+    test(&[-6, 3, 3], &[18, -24, 6], "3*x-3", "x-1");
+    test(&[1, 0, 1, 0, 1], &[-1, 0, 0, 1], "x^2+x+1", "x^2+x+1");
+    test(&[2, 7, 3], &[4, 4, 1], "x+2", "x+2");
+    test(
+        &[-5, 2, 8, -3, -3, 0, 1, 0, 1],
+        &[21, -9, -4, 0, 5, 0, 3],
+        "1",
+        "1",
+    );
+}
+
+#[test]
+fn test_gcd_mod() {
+    let m = Natural::from(5u32);
+    let test = |xs: &[u32], ys: &[u32], out: &str| {
+        let p = mod_polynomial(xs, &m);
+        let q = mod_polynomial(ys, &m);
+        assert_eq!((&p).gcd(&q).to_string(), out);
+        assert_eq!(q.gcd(p).to_string(), out);
+    };
+    test(&[], &[], "0");
+    test(&[], &[2, 2], "x+1 mod 5");
+    test(&[4, 0, 1], &[2, 2], "x+1 mod 5");
+    test(&[4, 0, 1], &[3, 1], "1 mod 5");
+    test(&[4, 0, 1], &[2, 3], "x+4 mod 5");
+    test(&[1, 0, 0, 0, 0, 4], &[0, 4, 0, 0, 0, 1], "x+4 mod 5");
+}
+
+#[test]
+#[should_panic]
+fn gcd_mod_fail_1() {
+    let m = Natural::from(6u32);
+    mod_polynomial(&[0, 0, 1], &m).gcd(mod_polynomial(&[1, 2], &m));
+}
+
+#[test]
+#[should_panic]
+fn gcd_mod_fail_2() {
+    let m = Natural::from(7u32);
+    let n = Natural::from(5u32);
+    mod_polynomial(&[1, 2], &m).gcd(mod_polynomial(&[1, 1], &n));
+}
+
+#[test]
+fn gcd_properties() {
+    integer_vec_gen().test_properties(|mut xs| {
+        let third = xs.len() / 3;
+        let zs = xs.split_off(third << 1);
+        let ys = xs.split_off(third);
+        let p = Polynomial::from(xs);
+        let q = Polynomial::from(ys);
+        let r = Polynomial::from(zs);
+        let gcd = (&p).gcd(&q);
+        assert_eq!(p.subresultant_gcd(&q), gcd);
+        assert_eq!((&q).gcd(&p), gcd);
+        assert!(divides(&gcd, &p));
+        assert!(divides(&gcd, &q));
+        assert!(gcd.leading_coefficient().map_or(true, |c| *c > 0u32));
+        assert_eq!(gcd.content(), p.content().gcd(q.content()));
+        assert_eq!((-&p).gcd(&q), gcd);
+        assert_eq!((&p).gcd(&p), (&p).gcd(Polynomial::from(Vec::new())));
+
+        let pr = &p * &r;
+        let qr = &q * &r;
+        let gcd_r = (&pr).gcd(&qr);
+        assert!(divides(&r, &gcd_r));
+        assert!(divides(&gcd, &gcd_r));
+        assert_eq!(pr.subresultant_gcd(&qr), gcd_r);
+
+        let rational_gcd = Polynomial::<Rational>::from(&p).gcd(Polynomial::<Rational>::from(&q));
+        assert_eq!(rational_gcd.degree(), gcd.degree());
+
+        let m = Natural::from(101u32);
+        let reduce =
+            |p: &Polynomial<Integer>| Polynomial::<ModNatural>::from_integer_polynomial(p, &m);
+        let (p, q, r) = (reduce(&p), reduce(&q), reduce(&r));
+        let gcd = (&p).gcd(&q);
+        if gcd.degree().is_some() {
+            assert_eq!((&p).div_rem(&gcd).1.degree(), None);
+            assert_eq!((&q).div_rem(&gcd).1.degree(), None);
+        }
+        if r.degree().is_some() {
+            assert_eq!((&p * &r).gcd(&q * &r).div_rem(&r).1.degree(), None);
+        }
+    });
+
+    rational_vec_gen().test_properties(|mut xs| {
+        let ys = xs.split_off(xs.len() >> 1);
+        let p = Polynomial::from(xs);
+        let q = Polynomial::from(ys);
+        let gcd = (&p).gcd(&q);
+        assert_eq!((&q).gcd(&p), gcd);
+        if let Some(c) = gcd.leading_coefficient() {
+            assert_eq!(*c, 1u32);
+            assert_eq!((&p).div_rem(&gcd).1.degree(), None);
+            assert_eq!((&q).div_rem(&gcd).1.degree(), None);
+        } else {
+            assert_eq!(p.degree(), None);
+            assert_eq!(q.degree(), None);
+        }
+    });
+}
@@ -0,0 +1,143 @@
+use malachite_base::num::basic::traits::Zero;
+use malachite_nz::integer::Integer;
+use malachite_nz::natural::arithmetic::mod_natural::ModNatural;
+use malachite_nz::natural::Natural;
+use malachite_nz::test_util::generators::integer_vec_gen;
+use malachite_q::polynomial::Polynomial;
+use malachite_q::test_util::generators::rational_vec_gen;
+use malachite_q::Rational;
+
+fn integer_polynomial(xs: &[i32]) -> Polynomial<Integer> {
+    Polynomial::from(xs.iter().copied().map(Integer::from).collect::<Vec<_>>())
+}
+
+fn mod_polynomial<'a>(xs: &[u32], m: &'a Natural) -> Polynomial<ModNatural<'a>> {
+    Polynomial::from_naturals(xs.iter().copied().map(Natural::from).collect(), m)
+}
+
+fn mul_naive(xs: &[Integer], ys: &[Integer]) -> Polynomial<Integer> {
+    if xs.is_empty() || ys.is_empty() {
+        return Polynomial::ZERO;
+    }
+    let mut out = vec![Integer::ZERO; xs.len() + ys.len() - 1];
+    for (i, x) in xs.iter().enumerate() {
+        for (j, y) in ys.iter().enumerate() {
+            out[i + j] += x * y;
+        }
+    }
+    Polynomial::from(out)
+}
+
+#[test]
+fn test_mul() {
+    let test = |xs: &[i32], ys: &[i32], out: &str| {
+        let p = integer_polynomial(xs);
+        let q = integer_polynomial(ys);
+        let product = p.clone() * q.clone();
+        assert_eq!(product.to_string(), out);
+        assert_eq!(p.clone() * &q, product);
+        assert_eq!(&p * q.clone(), product);
+        assert_eq!(&p * &q, product);
+        assert_eq!(
+            Polynomial::<Rational>::from(&p) * Polynomial::from(&q),
+            Polynomial::from(&product)
+        );
+    };
+    test(&[], &[], "0");
+    test(&[1, 2], &[], "0");
+    test(&[3], &[1, -2], "-6*x+3");
+    test(&[1, 1], &[-1, 1], "x^2-1");
+    test(&[1, 2], &[3, -2, 1], "2*x^3-3*x^2+4*x+3");
+    test(&[-1, -1, -1], &[-1, 1], "-x^3+1");
+    test(
+        &[1000000, -1, 0, 1],
+        &[-1000000, 0, 0, 0, 7],
+        "7*x^7-7*x^5+7000000*x^4-1000000*x^3+1000000*x-1000000000000",
+    );
+}
+
+#[test]
+fn test_mul_rational() {
+    let p = Polynomial::from(vec![
+        Rational::from_signeds(1, 2),
+        Rational::from_signeds(-2, 3),
+    ]);
+    let q = Polynomial::from(vec![Rational::from_signeds(3, 4), Rational::from(3)]);
+    assert_eq!((&p * &q).to_string(), "-2*x^2+x+3/8");
+    assert_eq!((p * Polynomial::ZERO).to_string(), "0");
+}
+
+#[test]
+fn test_mul_mod() {
+    let m = Natural::from(6u32);
+    let test = |xs: &[u32], ys: &[u32], out: &str| {
+        let p = mod_polynomial(xs, &m);
+        let q = mod_polynomial(ys, &m);
+        let product = &p * &q;
+        assert_eq!(product.to_string(), out);
+        assert_eq!(q * p, product);
+    };
+    test(&[], &[1], "0");
+    test(&[1, 2], &[5, 3], "x+5 mod 6");
+    test(&[0, 3], &[0, 2], "0");
+    test(&[5, 5, 5], &[1, 1], "5*x^3+4*x^2+4*x+5 mod 6");
+}
+
+#[test]
+#[should_panic]
+fn mul_mod_fail() {
+    let m = Natural::from(7u32);
+    let n = Natural::from(5u32);
+    let _ = mod_polynomial(&[1], &m) * mod_polynomial(&[1], &n);
+}
+
+#[test]
+fn mul_properties() {
+    integer_vec_gen().test_properties(|mut xs| {
+        let ys = xs.split_off(xs.len() >> 1);
+        let p = Polynomial::from(xs.clone());
+        let q = Polynomial::from(ys.clone());
+        let product = &p * &q;
+        assert_eq!(product, mul_naive(&xs, &ys));
+        assert_eq!(&q * &p, product);
+        assert_eq!(
+            product.degree(),
+            p.degree().and_then(|d| q.degree().map(|e| d + e))
+        );
+        assert_eq!(&p * (-&q), -&product);
+        assert_eq!(
+            Polynomial::<Rational>::from(&p) * Polynomial::from(&q),
+            Polynomial::from(&product)
+        );
+
+        let m = Natural::from(101u32);
+        let reduce =
+            |p: &Polynomial<Integer>| Polynomial::<ModNatural>::from_integer_polynomial(p, &m);
+        assert_eq!(reduce(&p) * reduce(&q), reduce(&product));
+    });
+
+    integer_vec_gen().test_properties(|mut xs| {
+        let third = xs.len() / 3;
+        let zs = xs.split_off(third << 1);
+        let ys = xs.split_off(third);
+        let p = Polynomial::from(xs);
+        let q = Polynomial::from(ys);
+        let r = Polynomial::from(zs);
+        assert_eq!(&(&p * &q) * &r, &p * &(&q * &r));
+        assert_eq!(&p * &(&q + &r), &(&p * &q) + &(&p * &r));
+    });
+
+    rational_vec_gen().test_properties(|mut xs| {
+        let ys = xs.split_off(xs.len() >> 1);
+        let p = Polynomial::from(xs);
+        let q = Polynomial::from(ys);
+        let product = &p * &q;
+        assert_eq!(&q * &p, product);
+        assert_eq!(
+            product.leading_coefficient(),
+            p.leading_coefficient()
+                .and_then(|x| q.leading_coefficient().map(|y| x * y))
+                .as_ref()
+        );
+    });
+}
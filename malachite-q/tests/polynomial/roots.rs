@@ -0,0 +1,130 @@
+use malachite_base::num::arithmetic::traits::{DivRem, Gcd};
+use malachite_base::num::basic::traits::Zero;
+use malachite_nz::integer::Integer;
+use malachite_nz::test_util::generators::integer_vec_gen;
+use malachite_q::interval::Interval;
+use malachite_q::polynomial::Polynomial;
+use malachite_q::test_util::generators::rational_vec_gen;
+use malachite_q::Rational;
+
+fn integer_polynomial(xs: &[i32]) -> Polynomial<Integer> {
+    Polynomial::from(xs.iter().copied().map(Integer::from).collect::<Vec<_>>())
+}
+
+fn contains(interval: &Interval<Rational>, x: &Rational) -> bool {
+    interval.lower() <= x && x <= interval.upper()
+}
+
+// Checks that each interval in `roots` contains a root of the nonzero polynomial `p`, that the
+// intervals are sorted and disjoint, and that only the interval [0, 0] contains 0.
+fn check_roots(p: &Polynomial<Rational>, roots: &[Interval<Rational>]) {
+    let square_free_part = p.div_rem(p.gcd(p.derivative())).0;
+    for root in roots {
+        if root.is_point() {
+            assert_eq!(p.evaluate(root.lower()), 0u32);
+        } else {
+            assert!(*root.lower() != 0u32 && *root.upper() != 0u32);
+            let lower = square_free_part.evaluate(root.lower());
+            let upper = square_free_part.evaluate(root.upper());
+            assert!(lower * upper < 0u32);
+        }
+    }
+    for pair in roots.windows(2) {
+        assert!(pair[0].upper() <= pair[1].lower());
+        assert!(pair[0].upper() < pair[1].lower() || !pair[0].is_point() && !pair[1].is_point());
+    }
+    assert!(roots.len() <= p.degree().unwrap());
+    assert!(
+        roots
+            .iter()
+            .filter(|root| contains(root, &Rational::ZERO))
+            .count()
+            == usize::from(p.evaluate(&Rational::ZERO) == 0u32)
+    );
+}
+
+#[test]
+fn test_isolate_real_roots() {
+    let test = |xs: &[i32], out: &[&str]| {
+        let p = integer_polynomial(xs);
+        let roots = p.isolate_real_roots();
+        assert_eq!(
+            roots.iter().map(ToString::to_string).collect::<Vec<_>>(),
+            out
+        );
+        assert_eq!(Polynomial::<Rational>::from(&p).isolate_real_roots(), roots);
+        check_roots(&Polynomial::from(&p), &roots);
+    };
+    test(&[5], &[]);
+    test(&[1, 0, 1], &[]);
+    test(&[0, 1], &["[0, 0]"]);
+    test(&[-1, 2], &["[1/8, 2]"]);
+    test(&[0, -2, 0, 1], &["[-8, -1/2]", "[0, 0]", "[1/2, 8]"]);
+    test(&[-1, -1, 6], &["[-2, -1/16]", "[1/16, 2]"]);
+    test(&[1, -2, 1], &["[1/4, 4]"]);
+    test(&[0, 0, 0, 1], &["[0, 0]"]);
+    test(&[-2, 0, 1], &["[-8, -1/2]", "[1/2, 8]"]);
+    test(
+        &[24, -50, 35, -10, 1],
+        &["[1, 1]", "[2, 2]", "[3, 3]", "[4, 4]"],
+    );
+}
+
+#[test]
+fn test_isolate_real_roots_rational() {
+    let p = Polynomial::from(vec![
+        Rational::from_signeds(-1, 3),
+        Rational::ZERO,
+        Rational::from_signeds(3, 4),
+    ]);
+    let roots = p.isolate_real_roots();
+    assert_eq!(
+        roots.iter().map(ToString::to_string).collect::<Vec<_>>(),
+        &["[-2, -1/8]", "[1/8, 2]"]
+    );
+    check_roots(&p, &roots);
+}
+
+#[test]
+#[should_panic]
+fn isolate_real_roots_fail() {
+    integer_polynomial(&[]).isolate_real_roots();
+}
+
+#[test]
+#[should_panic]
+fn isolate_real_roots_rational_fail() {
+    Polynomial::from(Vec::<Rational>::new()).isolate_real_roots();
+}
+
+#[test]
+fn isolate_real_roots_properties() {
+    integer_vec_gen().test_properties(|xs| {
+        let p = Polynomial::from(xs);
+        if p.degree().is_none() {
+            return;
+        }
+        let roots = p.isolate_real_roots();
+        check_roots(&Polynomial::from(&p), &roots);
+        assert_eq!(Polynomial::<Rational>::from(&p).isolate_real_roots(), roots);
+        assert_eq!(p.primitive_part().isolate_real_roots(), roots);
+        assert_eq!((-&p).isolate_real_roots(), roots);
+        assert_eq!((&p * &p).isolate_real_roots(), roots);
+    });
+
+    rational_vec_gen().test_properties(|xs| {
+        let mut zeros: Vec<Rational> = xs.into_iter().take(4).collect();
+        zeros.sort();
+        zeros.dedup();
+        let mut p = Polynomial::from(vec![Rational::from(1u32)]);
+        for zero in &zeros {
+            p = p * Polynomial::from(vec![-zero, Rational::from(1u32)]);
+        }
+        let roots = p.isolate_real_roots();
+        check_roots(&p, &roots);
+        assert_eq!(roots.len(), zeros.len());
+        for (root, zero) in roots.iter().zip(zeros.iter()) {
+            assert!(contains(root, zero));
+        }
+    });
+}